@@ -0,0 +1,87 @@
+//! Generate the registries compiled into the program from their data files.
+//!
+//! - `data/soroswap_tokens.json` is a Soroswap token list. It becomes the
+//!   `SOROSWAP_TOKENS` array that seeds the token registry.
+//! - `data/scam_addresses.txt` lists scam issuers, one per line. It becomes
+//!   their sorted raw 32-byte account IDs, so that the program can binary
+//!   search them without formatting any strkeys, and the binary does not
+//!   carry thousands of strings.
+//!
+//! Both files are validated first, by `src/config/registry_data.rs`, which the
+//! unit tests share. Any malformed entry fails the build with its file and
+//! line or index, and every problem found is reported at once.
+
+use std::{env, fs, path::Path, process};
+
+#[path = "src/config/registry_data.rs"]
+mod registry_data;
+
+use registry_data::{parse_scam_account_ids, parse_tokens, Token, SCAMS_FILE, TOKENS_FILE};
+
+fn main() {
+    println!("cargo:rerun-if-changed={TOKENS_FILE}");
+    println!("cargo:rerun-if-changed={SCAMS_FILE}");
+    println!("cargo:rerun-if-changed=src/config/registry_data.rs");
+
+    let mut errors = Vec::new();
+    let tokens = read_tokens(&mut errors);
+    let scam_account_ids = read_scam_account_ids(&mut errors);
+
+    if !errors.is_empty() {
+        eprintln!("The registry data files are invalid:");
+        errors.iter().for_each(|error| eprintln!("  {error}"));
+        process::exit(1);
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("soroswap_tokens.rs"),
+        generate_tokens(&tokens),
+    )
+    .unwrap();
+    fs::write(
+        Path::new(&out_dir).join("scam_account_ids.bin"),
+        scam_account_ids.concat(),
+    )
+    .unwrap();
+}
+
+fn read_tokens(errors: &mut Vec<String>) -> Vec<Token> {
+    match fs::read_to_string(TOKENS_FILE) {
+        Ok(json) => parse_tokens(&json, errors),
+        Err(error) => {
+            errors.push(format!("{TOKENS_FILE}: {error}"));
+            Vec::new()
+        }
+    }
+}
+
+fn read_scam_account_ids(errors: &mut Vec<String>) -> Vec<[u8; 32]> {
+    match fs::read_to_string(SCAMS_FILE) {
+        Ok(list) => parse_scam_account_ids(&list, errors),
+        Err(error) => {
+            errors.push(format!("{SCAMS_FILE}: {error}"));
+            Vec::new()
+        }
+    }
+}
+
+fn generate_tokens(tokens: &[Token]) -> String {
+    let entries = tokens
+        .iter()
+        .map(|token| {
+            format!(
+                "    SwapAsset {{\n        code: Cow::Borrowed({:?}),\n        issuer: Cow::Borrowed({:?}),\n        contract: Cow::Borrowed({:?}),\n        decimals: {},\n    }},\n",
+                token.code,
+                token.issuer.as_ref().unwrap_or(&token.contract),
+                token.contract,
+                token.decimals,
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        "pub(crate) const SOROSWAP_TOKENS: [SwapAsset; {}] = [\n{entries}];\n",
+        tokens.len()
+    )
+}
@@ -50,6 +50,15 @@ deploy_to_network() {
         fi
     fi
 
+    # Contract IDs derived in open coverage depend on the network
+    if [ "$network" = "testnet" ]; then
+        KWICKBIT_NETWORK_PASSPHRASE="${KWICKBIT_NETWORK_PASSPHRASE:-Test SDF Network ; September 2015}"
+    fi
+
+    # The admin key, coverage mode and network are compiled into the program,
+    # so the build must see them
+    export KWICKBIT_ADMIN_KEY KWICKBIT_OPEN_COVERAGE KWICKBIT_NETWORK_PASSPHRASE
+
     # Call the CLI to actually deploy
     mercury-cli --jwt "${!jwt_var}" --local false --mainnet "$([[ $network == "mainnet" ]] && echo "true" || echo "false")" deploy $([[ $force_mode == true ]] && echo "--force true")
     return $?
@@ -6,6 +6,7 @@ build_query() {
   local asset_issuer="$3"
   local date="$4"
   local cat_text="$5"
+  local interval="$6"
 
   local fname
   local arguments
@@ -20,6 +21,7 @@ build_query() {
     arguments="{\\\"asset_code\\\": \\\"$asset_code\\\""
     [ -n "$asset_issuer" ] && arguments="$arguments, \\\"asset_issuer\\\": \\\"$asset_issuer\\\""
     [ -n "$date" ] && arguments="$arguments, \\\"date\\\": \\\"$date\\\""
+    [ -n "$interval" ] && arguments="$arguments, \\\"interval\\\": \\\"$interval\\\""
     arguments="$arguments}"
   elif [ "$command" = "cat" ]; then
     fname="cat"
@@ -27,17 +29,23 @@ build_query() {
   elif [ "$command" = "currencies" ]; then
     fname="get_all_currencies"
     arguments="{}"
-  elif [ "$command" = "duplicates" ]; then
-    fname="get_duplicate_swaps"
+  elif [ "$command" = "audit" ]; then
+    fname="audit"
     arguments="{}"
   elif [ "$command" = "history" ]; then
     fname="get_exchange_rate_history"
     arguments=$(cat "${BASE_DIR}/scripts/query/history_request.json" | tr -d '\n' | sed 's/"/\\"/g')
+  elif [ "$command" = "journal" ]; then
+    fname="get_journal"
+    arguments="{}"
   elif [ "$command" = "savepoint" ]; then
     fname="savepoint"
     arguments="{}"
-  elif [ "$command" = "soroswaps" ]; then
-    fname="get_soroswap_swaps"
+  elif [ "$command" = "status" ]; then
+    fname="get_status"
+    arguments="{}"
+  elif [ "$command" = "metrics" ]; then
+    fname="get_metrics"
     arguments="{}"
   fi
   echo "{\"project_name\": \"kwickbit\", \"mode\": {\"Function\": {\"fname\": \"$fname\", \"arguments\": \"$arguments\"}}}"
@@ -6,6 +6,7 @@ parse_arguments() {
     local asset_code=""         # With the `asset` command: asset code to query
     local asset_issuer=""       # (Optional) With the `asset` command: asset issuer to query
     local date=""               # (Optional) With the `asset` command: date to query
+    local interval=""           # (Optional) With the `asset` command: rate window, e.g. 5m, 1h, 1d
     local cat_text=""           # With the `cat` command: text to display
 
     while [[ $# -gt 0 ]]; do
@@ -39,6 +40,11 @@ parse_arguments() {
                         date=$1
                         shift
                     fi
+                    # Check for optional interval argument
+                    if [[ $# -gt 0 && "$1" =~ ^[0-9]+[mhd]$ ]]; then
+                        interval=$1
+                        shift
+                    fi
                 else
                     echo "Error: Asset symbol required for 'asset' command" >&2
                     return 1
@@ -54,7 +60,7 @@ parse_arguments() {
                 fi
                 ;;
             # Nullary functions; the command is the same as the passed argument
-            all|currencies|duplicates|history|savepoint|soroswaps)
+            all|audit|currencies|history|journal|metrics|savepoint|status)
                 command=$1
                 shift
                 ;;
@@ -71,6 +77,6 @@ parse_arguments() {
         return 1
     fi
 
-    echo "$raw_output@$command@$asset_code@$asset_issuer@$date@$cat_text"
+    echo "$raw_output@$command@$asset_code@$asset_issuer@$date@$cat_text@$interval"
     return 0
 }
\ No newline at end of file
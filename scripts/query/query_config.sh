@@ -16,7 +16,10 @@ HELP_MESSAGE="Usage: query [options] <command> [arguments]
 
 Commands:
   all                     Get all exchange rates
-  asset <asset_symbol> [asset_issuer] [datetime]    Get exchange rate for a specific asset, optionally with issuer and/or at a specific time
+  status                  Get the health of the indexer
+  metrics                 Get the ingestion metrics in Prometheus format
+  audit                   Check the data of the last day for integrity problems
+  asset <asset_symbol> [asset_issuer] [datetime] [interval]    Get exchange rate for a specific asset, optionally with issuer, at a specific time and/or over a given window (5m, 1h, 1d)
 
 Options:
   --raw                   Output raw JSON (don't pipe to jq)
@@ -26,5 +29,6 @@ Examples:
   query asset XLM
   query asset USDC GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN
   query asset BTC 2018-01-01T14:30:00
+  query asset EURC 5m
   query --raw asset ETH GBDEVU63Y6NTHJQQZIKVTC23NWLQVP3WJ2RI2OTSJTNYOIGICST6DUXR 2020-16-09T16:09:00
   query --help"
\ No newline at end of file
@@ -13,8 +13,8 @@ if [ $exit_code -ne 0 ]; then
 fi
 
 # Send the parsed arguments to build_query
-IFS='@' read -r raw_output command asset_code asset_issuer date cat_text <<< "$result"
-QUERY=$(build_query "$command" "$asset_code" "$asset_issuer" "$date" "$cat_text")
+IFS='@' read -r raw_output command asset_code asset_issuer date cat_text interval <<< "$result"
+QUERY=$(build_query "$command" "$asset_code" "$asset_issuer" "$date" "$cat_text" "$interval")
 
 # Call the API, suppressing progress output from `curl`
 curl -s -X POST https://mainnet.mercurydata.app/zephyr/execute \
@@ -0,0 +1,1143 @@
+#![warn(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use zephyr_sdk::{DatabaseInteract, EnvClient};
+
+use super::{
+    audit::audit_range,
+    shared::{parse_interval, parse_timestamp},
+};
+use crate::{
+    config::{
+        ADMIN_KEY, CLASSIC_DECIMALS, DEFAULT_RATE_STRATEGY, JOURNAL_CAPACITY, MAX_REPLAY_WINDOWS,
+        MAX_SCAM_REMOVAL_PERCENT, RATE_DECIMALS,
+    },
+    db::{
+        audit::{repair_issues, run_audit, Issue},
+        composite::{composite_asset, CompositeAsset},
+        exchange_rate::RateStrategy,
+        journal::{read_journal_head, save_journal_head, JournalHead},
+        latest_close_time,
+        lineage::{asset_links, closes_cycle, read_links, AssetLink},
+        migration::{migrate_fixed_point, repair_stripped_codes},
+        replay_window_rates,
+        risk::seed_asset_risks,
+        savepoint::active_savepoints,
+        scam::{flagged_issuers, save_scam_changes, scam_import_changes},
+        swap::SwapAsset,
+        token::{registered_tokens, seed_registry, TokenDbRow},
+    },
+    fixed_point::from_decimal_string,
+    utils::{classic_asset, parse_date, stellar_asset_contract},
+};
+
+#[derive(Debug)]
+pub(super) enum AdminError {
+    DatabaseError(String),
+    InvalidRequest(String),
+    Unauthorized,
+}
+
+// Administrative requests carry the key the program was built with
+pub(super) fn authorize(admin_key: &Option<String>) -> Result<(), AdminError> {
+    match (ADMIN_KEY, admin_key) {
+        (Some(expected), Some(given)) if expected == given => Ok(()),
+        _ => Err(AdminError::Unauthorized),
+    }
+}
+
+pub(super) fn build_admin_response(
+    result: Result<serde_json::Value, AdminError>,
+) -> serde_json::Value {
+    let (status, data) = match result {
+        Ok(data) => (200, data),
+        Err(AdminError::InvalidRequest(message)) => (400, serde_json::json!({ "error": message })),
+        Err(AdminError::Unauthorized) => (
+            401,
+            serde_json::json!({ "error": "Missing or invalid admin key." }),
+        ),
+        Err(AdminError::DatabaseError(message)) => (
+            500,
+            serde_json::json!({ "error": format!("An error occurred while accessing the database: {message}") }),
+        ),
+    };
+
+    serde_json::json!({
+        "status": status,
+        "data": data,
+    })
+}
+
+#[derive(Deserialize, Serialize)]
+struct ReplayRequest {
+    admin_key: Option<String>,
+    from: String,
+    to: String,
+    interval: Option<String>,
+    strategy: Option<String>,
+}
+
+/// Recomputes the exchange rates of past windows from the stored swaps.
+///
+/// Every window of the given length that lies within the range is replayed,
+/// up to a maximum per call; `next_from` tells where to resume if the range
+/// was not exhausted. Corrected rows get a new revision number and the rows
+/// they supersede are kept. Rates the window no longer yields get a void
+/// revision, so they stop being served. Only windows that have already been
+/// saved can be replayed.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "from": "2024-01-01T00:00:00",
+///     "to": "2024-01-02T00:00:00",
+///     "interval": "optional_window",       // "5m", "1h" (default) or "1d"
+///     "strategy": "optional_strategy"      // "volume_weighted" (default)
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "windows": 24,
+///         "rows_written": 311,
+///         "next_from": null
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid dates, interval or strategy
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn replay_rates() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<ReplayRequest>();
+
+    client.conclude(build_admin_response(handle_replay(&client, &request)));
+}
+
+fn handle_replay(
+    client: &EnvClient,
+    request: &ReplayRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+
+    let invalid = |message: &str| AdminError::InvalidRequest(message.to_string());
+    let from = parse_timestamp(&request.from).map_err(|_| invalid("Invalid 'from' date."))?;
+    let to = parse_timestamp(&request.to).map_err(|_| invalid("Invalid 'to' date."))?;
+    let interval = parse_interval(&request.interval).map_err(|_| invalid("Invalid interval."))?;
+    let strategy = match &request.strategy {
+        Some(name) => RateStrategy::from_name(name).ok_or(invalid("Invalid strategy."))?,
+        None => DEFAULT_RATE_STRATEGY,
+    };
+
+    if from < 0 || to <= from {
+        return Err(invalid(
+            "The 'from' date must be earlier than the 'to' date.",
+        ));
+    }
+
+    // We never replay a window the indexer has not saved yet
+    let savepoint = active_savepoints(client)
+        .iter()
+        .find(|savepoint| savepoint.interval == interval)
+        .map_or(0, |savepoint| savepoint.savepoint);
+    let range_end = (to as u64).min(savepoint);
+
+    // Windows are aligned, so the first one starts at the first boundary
+    let first_window_start = (from as u64).div_ceil(interval) * interval;
+    let window_ends = (1..=MAX_REPLAY_WINDOWS)
+        .map(|index| first_window_start + index * interval)
+        .take_while(|window_end| *window_end <= range_end)
+        .collect::<Vec<_>>();
+
+    let rows_written = window_ends
+        .iter()
+        .map(|window_end| {
+            replay_window_rates(
+                client,
+                window_end - interval,
+                *window_end,
+                interval,
+                strategy,
+            )
+        })
+        .sum::<Result<usize, String>>()
+        .map_err(AdminError::DatabaseError)?;
+
+    let next_from = window_ends
+        .last()
+        .filter(|last_window_end| **last_window_end + interval <= range_end)
+        .map(|last_window_end| parse_date(&(*last_window_end as i64)));
+
+    Ok(serde_json::json!({
+        "windows": window_ends.len(),
+        "rows_written": rows_written,
+        "next_from": next_from,
+    }))
+}
+
+#[derive(Deserialize, Serialize)]
+struct MigrationRequest {
+    admin_key: Option<String>,
+    from: String,
+    to: String,
+}
+
+/// Fills in the fixed-point amounts and rates of rows saved before they
+/// replaced floating-point numbers.
+///
+/// Swaps created and rates stamped within the range are migrated from their
+/// legacy columns. Migrating a range twice is harmless, so large histories can
+/// be migrated in chunks. Legacy NaN rates are stored as zero and never served.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "from": "2024-01-01T00:00:00",
+///     "to": "2024-02-01T00:00:00"
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "swaps_migrated": 5120,
+///         "rates_migrated": 744
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid dates
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn migrate_to_fixed_point() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<MigrationRequest>();
+
+    client.conclude(build_admin_response(handle_migration(&client, &request)));
+}
+
+fn handle_migration(
+    client: &EnvClient,
+    request: &MigrationRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+
+    let invalid = |message: &str| AdminError::InvalidRequest(message.to_string());
+    let from = parse_timestamp(&request.from).map_err(|_| invalid("Invalid 'from' date."))?;
+    let to = parse_timestamp(&request.to).map_err(|_| invalid("Invalid 'to' date."))?;
+
+    if from < 0 || to <= from {
+        return Err(invalid(
+            "The 'from' date must be earlier than the 'to' date.",
+        ));
+    }
+
+    let (swaps_migrated, rates_migrated) =
+        migrate_fixed_point(client, from as u64, to as u64).map_err(AdminError::DatabaseError)?;
+
+    Ok(serde_json::json!({
+        "swaps_migrated": swaps_migrated,
+        "rates_migrated": rates_migrated,
+    }))
+}
+
+#[derive(Deserialize, Serialize)]
+struct CodeRepairAsset {
+    asset_code: String,
+    asset_issuer: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CodeRepairRequest {
+    admin_key: Option<String>,
+    assets: Option<Vec<CodeRepairAsset>>,
+    dry_run: Option<bool>,
+}
+
+/// Restores the digits of asset codes that were saved without them.
+///
+/// Codes used to be stored with everything but letters stripped, so swaps,
+/// rates and discovered assets of "USDC2" were saved as "USDC". The registered
+/// tokens are always checked; other assets that may have been affected, e.g.
+/// those indexed in open coverage, must be listed. Rows are only repaired if
+/// a single asset of the issuer may have been stripped to their code. When
+/// several known assets were, or the discovery table shows that the issuer
+/// has another asset with a code that strips the same way, the rows cannot be
+/// told apart: they are left alone and reported as ambiguous, with every
+/// candidate. Unless `dry_run` is `false`, the rows to repair are counted but
+/// not changed.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "assets": [{                     // optional
+///         "asset_code": "AQUA1",
+///         "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA"
+///     }],
+///     "dry_run": true                  // optional
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "repaired": [{
+///             "stored_code": "AQUA",
+///             "asset_code": "AQUA1",
+///             "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA",
+///             "swaps": 310,
+///             "rates": 92,
+///             "discoveries": 0
+///         }],
+///         "ambiguous": [{
+///             "stored_code": "USDC",
+///             "asset_issuer": "GDNEWISSUER...",
+///             "candidates": ["USDC", "USDC2"]
+///         }],
+///         "dry_run": true
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid asset code or issuer
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn repair_asset_codes() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<CodeRepairRequest>();
+
+    client.conclude(build_admin_response(handle_code_repair(&client, &request)));
+}
+
+fn handle_code_repair(
+    client: &EnvClient,
+    request: &CodeRepairRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+
+    let requested_assets = request.assets.as_deref().unwrap_or_default();
+    for asset in requested_assets {
+        classic_asset(&asset.asset_code, &asset.asset_issuer)
+            .map_err(AdminError::InvalidRequest)?;
+    }
+
+    // Registered tokens without digits are included too, so that an asset
+    // stripped to the code of a registered one is found to be ambiguous
+    let assets = registered_tokens()
+        .iter()
+        .map(|token| (token.code.to_string(), token.issuer.to_string()))
+        .chain(
+            requested_assets
+                .iter()
+                .map(|asset| (asset.asset_code.clone(), asset.asset_issuer.clone())),
+        )
+        .collect::<Vec<_>>();
+
+    let dry_run = request.dry_run.unwrap_or(true);
+    let (repairs, ambiguous) =
+        repair_stripped_codes(client, &assets, dry_run).map_err(AdminError::DatabaseError)?;
+
+    Ok(serde_json::json!({
+        "repaired": repairs.iter().map(|repair| {
+            serde_json::json!({
+                "stored_code": repair.stored_code,
+                "asset_code": repair.code,
+                "asset_issuer": repair.issuer,
+                "swaps": repair.swaps,
+                "rates": repair.rates,
+                "discoveries": repair.discoveries,
+            })
+        }).collect::<Vec<_>>(),
+        "ambiguous": ambiguous.iter().map(|code| {
+            serde_json::json!({
+                "stored_code": code.stored_code,
+                "asset_issuer": code.issuer,
+                "candidates": code.codes,
+            })
+        }).collect::<Vec<_>>(),
+        "dry_run": dry_run,
+    }))
+}
+
+#[derive(Deserialize, Serialize)]
+struct AuditRepairRequest {
+    admin_key: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    issues: Option<Vec<String>>,
+}
+
+/// Audits the data and fixes the issues found.
+///
+/// The audit is the same as that of the `audit` function over the same range,
+/// which can be called first to see what would change. Rows cannot be deleted,
+/// so they are voided instead: swaps get zero amounts, and rates get a new
+/// revision with a zero rate, neither of which is ever served.
+/// - `invalid_rates`, `unbacked_rates`: the window is recomputed from its
+///   swaps; rates that are still not valid are voided
+/// - `multiple_savepoints`: the latest savepoint is kept
+/// - `duplicate_swaps`: every copy is voided, and one is saved again
+/// - `zero_denominators`, `future_timestamps`: the rows are voided
+///
+/// A row in more than one kind of issue is only repaired once: when
+/// `future_timestamps` is repaired too, rows dated in the future are voided,
+/// whatever else is wrong with them.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "from": "optional_ISO8601_timestamp",   // inclusive
+///     "to": "optional_ISO8601_timestamp",     // exclusive
+///     "issues": ["unbacked_rates", ...]       // optional, all by default
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "from": "2024-12-15T03:02:11",
+///         "to": "2024-12-16T03:02:12",
+///         "repaired": {
+///             "invalid_rates": 0,
+///             "unbacked_rates": 1
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid dates, or an unknown issue
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn repair_audit() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<AuditRepairRequest>();
+
+    client.conclude(build_admin_response(handle_audit_repair(&client, &request)));
+}
+
+fn handle_audit_repair(
+    client: &EnvClient,
+    request: &AuditRepairRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+
+    let issues = match &request.issues {
+        Some(names) => names
+            .iter()
+            .map(|name| {
+                Issue::from_name(name).ok_or(AdminError::InvalidRequest(format!(
+                    "Invalid issue '{name}'."
+                )))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None => Issue::ALL.to_vec(),
+    };
+
+    let checked_at = latest_close_time(client);
+    let (from, to) =
+        audit_range(&request.from, &request.to, checked_at).map_err(AdminError::InvalidRequest)?;
+    let audit = run_audit(client, from, to, checked_at).map_err(AdminError::DatabaseError)?;
+    let repaired = repair_issues(client, &audit, &issues).map_err(AdminError::DatabaseError)?;
+
+    Ok(serde_json::json!({
+        "from": parse_date(&(from as i64)),
+        "to": parse_date(&(to as i64)),
+        "repaired": repaired
+            .into_iter()
+            .map(|(issue, count)| (issue.name().to_string(), serde_json::json!(count)))
+            .collect::<serde_json::Map<_, _>>(),
+    }))
+}
+
+#[derive(Deserialize, Serialize)]
+struct TokenRequest {
+    admin_key: Option<String>,
+    asset_code: String,
+    asset_issuer: String,
+    contract: Option<String>,
+    decimals: Option<u32>,
+    enabled: Option<bool>,
+}
+
+/// Adds a token to the registry, so that its swaps are indexed and it is
+/// certified in responses.
+///
+/// If no contract is given, the token is assumed to be a classic asset and the
+/// contract ID of its Stellar Asset Contract is derived. Decimals default to 7,
+/// those of classic assets. The change applies from the next ledger close.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "asset_code": "AQUA",
+///     "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA",
+///     "contract": "optional_contract_id",
+///     "decimals": 7                    // optional
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "asset_code": "AQUA",
+///         "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA",
+///         "contract": "CAUIKL3IYGMERDRUN6YSCLWVAKIFG5Q4YJHUKM4S4NJZQIA3BAS6OJPK",
+///         "decimals": 7,
+///         "enabled": true
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid asset, contract, or the token is already registered
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn add_token() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<TokenRequest>();
+
+    client.conclude(build_admin_response(handle_add_token(&client, &request)));
+}
+
+/// Updates the contract, decimals or status of a registered token.
+///
+/// Only the fields present in the request are changed. Setting `enabled` to
+/// `true` restores a disabled token.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "asset_code": "AQUA",
+///     "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA",
+///     "contract": "optional_contract_id",
+///     "decimals": 7,                   // optional
+///     "enabled": true                  // optional
+/// }
+/// ```
+///
+/// # Response Format
+/// As for `add_token`, with the updated token.
+///
+/// # Errors
+/// - 400: Invalid contract, or the token is not registered
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn update_token() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<TokenRequest>();
+
+    client.conclude(build_admin_response(handle_update_token(&client, &request)));
+}
+
+/// Disables a registered token: its swaps stop being indexed and it is no
+/// longer certified. Its history is kept, and `update_token` can restore it.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "asset_code": "AQUA",
+///     "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA"
+/// }
+/// ```
+///
+/// # Response Format
+/// As for `add_token`, with the disabled token.
+///
+/// # Errors
+/// - 400: The token is not registered
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn disable_token() {
+    let client = EnvClient::empty();
+    let mut request = client.read_request_body::<TokenRequest>();
+    request.contract = None;
+    request.decimals = None;
+    request.enabled = Some(false);
+
+    client.conclude(build_admin_response(handle_update_token(&client, &request)));
+}
+
+fn handle_add_token(
+    client: &EnvClient,
+    request: &TokenRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+    seed_registry(client);
+
+    if read_token(client, request)?.is_some() {
+        return Err(AdminError::InvalidRequest(format!(
+            "{} ({}) is already registered.",
+            request.asset_code, request.asset_issuer
+        )));
+    }
+
+    let contract = match &request.contract {
+        Some(contract) => validate_contract(contract)?,
+        None => classic_asset(&request.asset_code, &request.asset_issuer)
+            .and_then(|asset| stellar_asset_contract(&asset))
+            .map_err(AdminError::InvalidRequest)?,
+    };
+
+    let token = TokenDbRow {
+        code: request.asset_code.clone(),
+        issuer: request.asset_issuer.clone(),
+        contract,
+        decimals: request.decimals.unwrap_or(CLASSIC_DECIMALS),
+        enabled: request.enabled.unwrap_or(true),
+    };
+    token.put(client);
+    seed_asset_risks(client, &[SwapAsset::from(token.clone())]);
+
+    Ok(token_json(&token))
+}
+
+fn handle_update_token(
+    client: &EnvClient,
+    request: &TokenRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+    seed_registry(client);
+
+    let Some(previous) = read_token(client, request)? else {
+        return Err(AdminError::InvalidRequest(format!(
+            "{} ({}) is not registered.",
+            request.asset_code, request.asset_issuer
+        )));
+    };
+
+    let token = TokenDbRow {
+        contract: match &request.contract {
+            Some(contract) => validate_contract(contract)?,
+            None => previous.contract,
+        },
+        decimals: request.decimals.unwrap_or(previous.decimals),
+        enabled: request.enabled.unwrap_or(previous.enabled),
+        ..previous
+    };
+
+    client
+        .update()
+        .column_equal_to("code", token.code.clone())
+        .column_equal_to("issuer", token.issuer.clone())
+        .execute(&token)
+        .map_err(|sdk_error| AdminError::DatabaseError(sdk_error.to_string()))?;
+
+    Ok(token_json(&token))
+}
+
+fn read_token(
+    client: &EnvClient,
+    request: &TokenRequest,
+) -> Result<Option<TokenDbRow>, AdminError> {
+    client
+        .read_filter()
+        .column_equal_to("code", request.asset_code.clone())
+        .column_equal_to("issuer", request.asset_issuer.clone())
+        .read::<TokenDbRow>()
+        .map(|rows| rows.into_iter().next())
+        .map_err(|sdk_error| AdminError::DatabaseError(sdk_error.to_string()))
+}
+
+fn validate_contract(contract: &str) -> Result<String, AdminError> {
+    match stellar_strkey::Contract::from_string(contract) {
+        Ok(_) => Ok(contract.to_string()),
+        Err(_) => Err(AdminError::InvalidRequest(format!(
+            "Invalid contract '{contract}'."
+        ))),
+    }
+}
+
+fn token_json(token: &TokenDbRow) -> serde_json::Value {
+    serde_json::json!({
+        "asset_code": token.code,
+        "asset_issuer": token.issuer,
+        "contract": token.contract,
+        "decimals": token.decimals,
+        "enabled": token.enabled,
+    })
+}
+
+#[derive(Deserialize, Serialize)]
+struct ScamImportRequest {
+    admin_key: Option<String>,
+    issuers: Vec<String>,
+    remove: Option<Vec<String>>,
+    source: String,
+    reason: Option<String>,
+    replace: Option<bool>,
+    force: Option<bool>,
+    dry_run: Option<bool>,
+}
+
+/// Flags and unflags scam issuers and reports the changes.
+///
+/// The issuers in `issuers` are flagged and those in `remove` are unflagged.
+/// With `replace`, `issuers` is instead the complete set of issuers to flag,
+/// e.g. a fresh export from StellarExpert, and issuers missing from it are
+/// unflagged. A replacement that would unflag more than a set share of the
+/// flagged issuers, which usually means a truncated export, is refused unless
+/// `force` is set. Newly flagged issuers record the source, reason and date of
+/// the import. Unless `dry_run` is `false`, the changes are reported but not
+/// saved. Swaps of flagged issuers are ignored from the next ledger close.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "issuers": ["GA223OFHVKVAH2NBXP4AURJRVJTSOVHGBMKJNL6GRJWNN4SARVGSITYG", ...],
+///     "remove": ["GCAZ2...", ...],     // optional, not with "replace"
+///     "source": "StellarExpert",
+///     "reason": "optional_reason",     // "Flagged as malicious" by default
+///     "replace": false,                // optional
+///     "force": false,                  // optional
+///     "dry_run": true                  // optional
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "added": ["GBWT4...", ...],
+///         "removed": ["GCAZ2...", ...],
+///         "flagged": 13102,
+///         "dry_run": true
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid issuer address, `remove` with `replace`, or a replacement
+///   unflagging too many issuers without `force`
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn import_scam_issuers() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<ScamImportRequest>();
+
+    client.conclude(build_admin_response(handle_scam_import(&client, &request)));
+}
+
+fn handle_scam_import(
+    client: &EnvClient,
+    request: &ScamImportRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+
+    let unflag = request.remove.as_deref().unwrap_or_default();
+    if let Some(invalid) = request
+        .issuers
+        .iter()
+        .chain(unflag)
+        .find(|issuer| stellar_strkey::ed25519::PublicKey::from_string(issuer).is_err())
+    {
+        return Err(AdminError::InvalidRequest(format!(
+            "Invalid issuer '{invalid}'."
+        )));
+    }
+
+    let replace = request.replace.unwrap_or(false);
+    if replace && !unflag.is_empty() {
+        return Err(AdminError::InvalidRequest(
+            "A replacing import cannot also remove issuers.".to_string(),
+        ));
+    }
+
+    let flagged = flagged_issuers();
+    let (added, removed) = scam_import_changes(&flagged, &request.issuers, unflag, replace);
+
+    if replace
+        && !request.force.unwrap_or(false)
+        && removed.len() * 100 > flagged.len() * MAX_SCAM_REMOVAL_PERCENT
+    {
+        return Err(AdminError::InvalidRequest(format!(
+            "The import would unflag {} of the {} flagged issuers, more than \
+             {MAX_SCAM_REMOVAL_PERCENT}%. Set 'force' to apply it anyway.",
+            removed.len(),
+            flagged.len(),
+        )));
+    }
+
+    let dry_run = request.dry_run.unwrap_or(true);
+    if !dry_run {
+        save_scam_changes(
+            client,
+            &added,
+            &removed,
+            &request.source,
+            request.reason.as_deref().unwrap_or("Flagged as malicious"),
+            latest_close_time(client),
+        )
+        .map_err(AdminError::DatabaseError)?;
+    }
+
+    Ok(serde_json::json!({
+        "added": added,
+        "removed": removed,
+        "flagged": flagged.len() + added.len() - removed.len(),
+        "dry_run": dry_run,
+    }))
+}
+
+#[derive(Deserialize, Serialize)]
+struct LineageRequest {
+    admin_key: Option<String>,
+    asset_code: String,
+    asset_issuer: String,
+    successor_code: String,
+    successor_issuer: String,
+    since: String,
+    ratio: Option<String>,
+}
+
+/// Declares that an asset is continued by another from a given date.
+///
+/// Anchors sometimes move an asset to a new issuer account, or redenominate
+/// it. Once the two are linked, the rate history of either one follows the
+/// other across the migration. The ratio is the number of units of the
+/// successor per unit of the previous asset, 1 by default. An asset has at
+/// most one successor, so declaring another one replaces it. A link that would
+/// lead back to an asset already in the chain is refused.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "asset_code": "EURT",
+///     "asset_issuer": "GAP5LETOV6YIE62YAM56STDANPRDO7ZFDBGSNHJQIYGGKSMOZAHOOS2S",
+///     "successor_code": "EURT",
+///     "successor_issuer": "GDNEWISSUER...",
+///     "since": "2024-06-01T00:00:00",
+///     "ratio": "1"                     // optional
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "asset_code": "EURT",
+///         "asset_issuer": "GAP5LETOV6YIE62YAM56STDANPRDO7ZFDBGSNHJQIYGGKSMOZAHOOS2S",
+///         "successor_code": "EURT",
+///         "successor_issuer": "GDNEWISSUER...",
+///         "since": "2024-06-01T00:00:00",
+///         "ratio": "1"
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid asset, date or ratio, the successor already continues
+///   another asset, or the link closes a cycle
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn link_assets() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<LineageRequest>();
+
+    client.conclude(build_admin_response(handle_link_assets(&client, &request)));
+}
+
+fn handle_link_assets(
+    client: &EnvClient,
+    request: &LineageRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+
+    let invalid = |message: String| AdminError::InvalidRequest(message);
+    classic_asset(&request.asset_code, &request.asset_issuer).map_err(invalid)?;
+    classic_asset(&request.successor_code, &request.successor_issuer).map_err(invalid)?;
+
+    if request.asset_code == request.successor_code
+        && request.asset_issuer == request.successor_issuer
+    {
+        return Err(invalid("An asset cannot continue itself.".to_string()));
+    }
+
+    let since = parse_timestamp(&request.since)
+        .ok()
+        .filter(|since| *since >= 0)
+        .ok_or_else(|| invalid("Invalid 'since' date.".to_string()))?;
+    let ratio = from_decimal_string(request.ratio.as_deref().unwrap_or("1"), RATE_DECIMALS)
+        .filter(|ratio| *ratio > 0)
+        .ok_or_else(|| invalid("The ratio must be a positive decimal number.".to_string()))?;
+
+    let links = read_links(client);
+    let (predecessor, _) = asset_links(&links, &request.successor_code, &request.successor_issuer);
+    if predecessor.is_some_and(|link| {
+        link.prevcode != request.asset_code || link.previssr != request.asset_issuer
+    }) {
+        return Err(invalid(format!(
+            "{} ({}) already continues another asset.",
+            request.successor_code, request.successor_issuer
+        )));
+    }
+
+    if closes_cycle(
+        &links,
+        &request.asset_code,
+        &request.asset_issuer,
+        &request.successor_code,
+        &request.successor_issuer,
+    ) {
+        return Err(invalid(format!(
+            "{} ({}) already leads to {} ({}), so linking them would close a cycle.",
+            request.successor_code,
+            request.successor_issuer,
+            request.asset_code,
+            request.asset_issuer
+        )));
+    }
+
+    let link = AssetLink {
+        prevcode: request.asset_code.clone(),
+        previssr: request.asset_issuer.clone(),
+        nextcode: request.successor_code.clone(),
+        nextissr: request.successor_issuer.clone(),
+        since: since as u64,
+        ratio,
+    };
+
+    let (_, successor) = asset_links(&links, &request.asset_code, &request.asset_issuer);
+    if successor.is_some() {
+        client
+            .update()
+            .column_equal_to("prevcode", link.prevcode.clone())
+            .column_equal_to("previssr", link.previssr.clone())
+            .execute(&link)
+            .map_err(|sdk_error| AdminError::DatabaseError(sdk_error.to_string()))?;
+    } else {
+        link.put(client);
+    }
+
+    Ok(serde_json::json!({
+        "asset_code": link.prevcode,
+        "asset_issuer": link.previssr,
+        "successor_code": link.nextcode,
+        "successor_issuer": link.nextissr,
+        "since": link.since_iso8601(),
+        "ratio": link.ratio_string(),
+    }))
+}
+
+#[derive(Deserialize, Serialize)]
+struct CompositeMember {
+    asset_code: String,
+    asset_issuer: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CompositeRequest {
+    admin_key: Option<String>,
+    asset_code: String,
+    members: Vec<CompositeMember>,
+}
+
+/// Sets the assets that are merged into a composite rate.
+///
+/// Composite rates merge the issuers of the same underlying asset, e.g. the
+/// BTC anchors, into one; `get_exchange_rate` returns them when asked for the
+/// composite of the code. The members replace any previous ones, and an empty
+/// list disables the composite.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "asset_code": "BTC",
+///     "members": [{
+///         "asset_code": "BTC",
+///         "asset_issuer": "GDPJALI4AZKUU2W426U5WKMAT6CN3AJRPIIRYR2YM54TL2GDWO5O2MZM"
+///     }, {
+///         "asset_code": "BTC",
+///         "asset_issuer": "GATEMHCCKCY67ZUCKTROYN24ZYT5GK4EQZ65JJLDHKHRUZI3EUEKMTCH"
+///     }]
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "asset_code": "BTC",
+///         "members": [{
+///             "asset_code": "BTC",
+///             "asset_issuer": "GDPJALI4AZKUU2W426U5WKMAT6CN3AJRPIIRYR2YM54TL2GDWO5O2MZM"
+///         }, ...]
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid member asset
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn set_composite_asset() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<CompositeRequest>();
+
+    client.conclude(build_admin_response(handle_set_composite(
+        &client, &request,
+    )));
+}
+
+fn handle_set_composite(
+    client: &EnvClient,
+    request: &CompositeRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+
+    for member in &request.members {
+        classic_asset(&member.asset_code, &member.asset_issuer)
+            .map_err(AdminError::InvalidRequest)?;
+    }
+
+    let members = request
+        .members
+        .iter()
+        .map(|member| (member.asset_code.clone(), member.asset_issuer.clone()))
+        .collect::<Vec<_>>();
+    let composite = CompositeAsset::new(&request.asset_code, &members);
+
+    let previous =
+        composite_asset(client, &request.asset_code).map_err(AdminError::DatabaseError)?;
+    if previous.is_some() {
+        client
+            .update()
+            .column_equal_to("code", composite.code.clone())
+            .execute(&composite)
+            .map_err(|sdk_error| AdminError::DatabaseError(sdk_error.to_string()))?;
+    } else {
+        composite.put(client);
+    }
+
+    Ok(serde_json::json!({
+        "asset_code": composite.code,
+        "members": composite.members().iter().map(|(code, issuer)| {
+            serde_json::json!({
+                "asset_code": code,
+                "asset_issuer": issuer,
+            })
+        }).collect::<Vec<_>>(),
+    }))
+}
+
+#[derive(Deserialize, Serialize)]
+struct JournalCapacityRequest {
+    admin_key: Option<String>,
+    capacity: u64,
+}
+
+/// Sets how many of the latest entries the ingestion journal keeps.
+///
+/// The journal starts with a capacity of `JOURNAL_CAPACITY`. Raising it keeps
+/// more entries from the next ledger close on. Lowering it stops serving the
+/// entries that fall out of it at once, and their rows are taken over by the
+/// next entries.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "admin_key": "secret",
+///     "capacity": 100000
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "previous_capacity": 50000,
+///         "capacity": 100000
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Capacity of zero
+/// - 401: Missing or invalid admin key
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn set_journal_capacity() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<JournalCapacityRequest>();
+
+    client.conclude(build_admin_response(handle_journal_capacity(
+        &client, &request,
+    )));
+}
+
+fn handle_journal_capacity(
+    client: &EnvClient,
+    request: &JournalCapacityRequest,
+) -> Result<serde_json::Value, AdminError> {
+    authorize(&request.admin_key)?;
+
+    if request.capacity == 0 {
+        return Err(AdminError::InvalidRequest(
+            "The journal capacity must be at least 1.".to_string(),
+        ));
+    }
+
+    let previous = read_journal_head(client);
+    let head = JournalHead {
+        capacity: request.capacity,
+        ..previous.clone().unwrap_or(JournalHead {
+            next: 0,
+            oldest: 0,
+            capacity: JOURNAL_CAPACITY,
+        })
+    };
+    save_journal_head(client, previous.as_ref(), &head).map_err(AdminError::DatabaseError)?;
+
+    Ok(serde_json::json!({
+        "previous_capacity": previous.map_or(JOURNAL_CAPACITY, |previous| previous.capacity),
+        "capacity": head.capacity,
+    }))
+}
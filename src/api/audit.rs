@@ -0,0 +1,220 @@
+#![warn(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use zephyr_sdk::EnvClient;
+
+use super::shared::parse_optional_timestamp;
+use crate::{
+    config::{AUDIT_RANGE, RATE_DECIMALS, USDC},
+    db::{
+        audit::{run_audit, Audit, Issue},
+        exchange_rate::RatesDbRow,
+        latest_close_time,
+        swap::SwapDbRow,
+    },
+    fixed_point::to_decimal_string,
+    utils::{interval_name, parse_date},
+};
+
+#[derive(Deserialize, Serialize)]
+struct AuditRequest {
+    from: Option<String>,
+    to: Option<String>,
+}
+
+/// Scans the swaps, rates and savepoints for data integrity problems.
+///
+/// The swaps and rates dated within the range are checked, by default over
+/// the day before the latest ledger, along with every savepoint and anything
+/// dated after the latest ledger. Rates are checked in their latest revision.
+/// Each kind of issue can be fixed with the `repair_audit` admin function.
+///
+/// - `duplicate_swaps`: copies of a swap, which share its ledger and position
+///   in it, with their number; swaps saved before they had an identity are not
+///   checked
+/// - `invalid_rates`: rates that are zero or negative, as NaNs were migrated
+/// - `unbacked_rates`: rates of windows in which their asset has no swaps
+/// - `multiple_savepoints`: rate windows with more than one savepoint, which
+///   stops their rates from being saved
+/// - `zero_denominators`: swaps without USDC, which have no price
+/// - `future_timestamps`: swaps and rates dated after the latest ledger
+///
+/// # Request Format
+/// ```json
+/// {
+///     "from": "optional_ISO8601_timestamp",   // inclusive
+///     "to": "optional_ISO8601_timestamp"      // exclusive
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "from": "2024-12-15T03:02:11",
+///         "to": "2024-12-16T03:02:12",
+///         "checked_at": "2024-12-16T03:02:11",
+///         "swaps_scanned": 18342,
+///         "rates_scanned": 2210,
+///         "total_issues": 2,
+///         "issues": {
+///             "duplicate_swaps": [{
+///                 "asset_code": "AQUA",
+///                 "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA",
+///                 "creation": "2024-12-15T09:14:52",
+///                 "ledger": 54975124,
+///                 "usdc_amount": "1.25",
+///                 "floating_amount": "1371",
+///                 "price": "1096.8",
+///                 "copies": 2
+///             }],
+///             "invalid_rates": [],
+///             "unbacked_rates": [{
+///                 "asset_code": "EURC",
+///                 "asset_issuer": "GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2",
+///                 "interval": "1h",
+///                 "window_end": "2024-12-15T18:00:00",
+///                 "revision": 0,
+///                 "rate": "0.95",
+///                 "volume": "20"
+///             }],
+///             "multiple_savepoints": [],
+///             "zero_denominators": [],
+///             "future_timestamps": {
+///                 "swaps": [],
+///                 "rates": []
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid dates
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn audit() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<AuditRequest>();
+    let checked_at = latest_close_time(&client);
+
+    let (from, to) = match audit_range(&request.from, &request.to, checked_at) {
+        Ok(range) => range,
+        Err(message) => {
+            return client.conclude(serde_json::json!({
+                "status": 400,
+                "data": {
+                    "error": message,
+                },
+            }))
+        }
+    };
+
+    client.conclude(match run_audit(&client, from, to, checked_at) {
+        Ok(audit) => serde_json::json!({
+            "status": 200,
+            "data": audit_json(&audit, from, to),
+        }),
+        Err(message) => serde_json::json!({
+            "status": 500,
+            "data": {
+                "error": format!("An error occurred while accessing the database: {message}"),
+            },
+        }),
+    });
+}
+
+/**
+ * The range to audit, from (inclusive) to (exclusive). It covers the latest
+ * ledger by default, and goes back as far as the config says.
+ */
+pub(super) fn audit_range(
+    from: &Option<String>,
+    to: &Option<String>,
+    checked_at: u64,
+) -> Result<(u64, u64), String> {
+    let from = parse_optional_timestamp(from).map_err(|_| "Invalid 'from' date.")?;
+    let to = parse_optional_timestamp(to).map_err(|_| "Invalid 'to' date.")?;
+
+    let to = to.map_or(checked_at + 1, |to| to.max(0) as u64);
+    let from = from.map_or(to.saturating_sub(AUDIT_RANGE), |from| from.max(0) as u64);
+
+    if to <= from {
+        return Err("The 'from' date must be earlier than the 'to' date.".to_string());
+    }
+
+    Ok((from, to))
+}
+
+pub(super) fn audit_json(audit: &Audit, from: u64, to: u64) -> serde_json::Value {
+    let duplicate_swaps = audit
+        .duplicate_swaps
+        .iter()
+        .map(|(swap, copies)| {
+            let mut json = swap_json(swap);
+            json["copies"] = serde_json::json!(copies);
+            json
+        })
+        .collect::<Vec<_>>();
+    let multiple_savepoints = audit
+        .multiple_savepoints
+        .iter()
+        .map(|(interval, savepoints)| {
+            serde_json::json!({
+                "interval": interval_name(*interval),
+                "savepoints": savepoints
+                    .iter()
+                    .map(|savepoint| parse_date(&(*savepoint as i64)))
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+    let rates = |rows: &[RatesDbRow]| rows.iter().map(rate_json).collect::<Vec<_>>();
+    let swaps = |rows: &[SwapDbRow]| rows.iter().map(swap_json).collect::<Vec<_>>();
+
+    serde_json::json!({
+        "from": parse_date(&(from as i64)),
+        "to": parse_date(&(to as i64)),
+        "checked_at": parse_date(&(audit.checked_at as i64)),
+        "swaps_scanned": audit.swaps_scanned,
+        "rates_scanned": audit.rates_scanned,
+        "total_issues": Issue::ALL.iter().map(|issue| audit.count(*issue)).sum::<usize>(),
+        "issues": {
+            Issue::DuplicateSwaps.name(): duplicate_swaps,
+            Issue::InvalidRates.name(): rates(&audit.invalid_rates),
+            Issue::UnbackedRates.name(): rates(&audit.unbacked_rates),
+            Issue::MultipleSavepoints.name(): multiple_savepoints,
+            Issue::ZeroDenominators.name(): swaps(&audit.zero_denominators),
+            Issue::FutureTimestamps.name(): {
+                "swaps": swaps(&audit.future_swaps),
+                "rates": rates(&audit.future_rates),
+            },
+        },
+    })
+}
+
+fn swap_json(swap: &SwapDbRow) -> serde_json::Value {
+    serde_json::json!({
+        "asset_code": swap.floatcode,
+        "asset_issuer": swap.fltissuer,
+        "creation": parse_date(&(swap.creation as i64)),
+        "ledger": swap.identity().map(|(ledger, ..)| ledger),
+        "usdc_amount": to_decimal_string(swap.usdcamnt, USDC.decimals),
+        "floating_amount": to_decimal_string(swap.fltamnt, swap.fltdecs),
+        "price": swap.price().map(|price| to_decimal_string(price, RATE_DECIMALS)),
+    })
+}
+
+fn rate_json(row: &RatesDbRow) -> serde_json::Value {
+    serde_json::json!({
+        "asset_code": row.floatcode,
+        "asset_issuer": row.fltissuer,
+        "interval": row.interval_name(),
+        "window_end": row.timestamp_iso8601(),
+        "revision": row.revision,
+        "rate": row.rate_string(),
+        "volume": row.volume_string(),
+    })
+}
@@ -0,0 +1,310 @@
+use zephyr_sdk::EnvClient;
+
+use super::{
+    build_error_response, resolve_timestamp,
+    shared::{coverage, missing_rate_error, query_db, read_gaps, ExchangeRateError},
+    validate_request, ExchangeRateRequest, RatesDbRow,
+};
+use crate::{
+    config::{COMPOSITE_TOLERANCE, RATE_DECIMALS, USDC},
+    db::composite::composite_asset,
+    fixed_point::{divide, from_decimal_string, multiply, to_decimal_string},
+};
+
+// Identifies composite rates, which are computed on request from stored rates
+const COMPOSITE_ALGORITHM: &str = "composite_volume_weighted/1";
+
+// The latest rate of a member, and why it was left out, if it was
+struct Contribution {
+    row: RatesDbRow,
+    excluded: Option<&'static str>,
+}
+
+struct CompositeRate {
+    asset_code: String,
+    window: RatesDbRow,
+    rate: i128,
+    volume: i128,
+    coverage: &'static str,
+    tolerance: i128,
+    contributions: Vec<Contribution>,
+}
+
+pub(super) fn handle_composite_request(request: &ExchangeRateRequest) -> serde_json::Value {
+    match composite_rate(request) {
+        Ok(composite) => build_ok_response(composite),
+        Err(error) => build_error_response(error),
+    }
+}
+
+/**
+ * Merge the latest rates of the members of a composite asset into one. Only
+ * members that traded in the latest window count, and those whose rate is
+ * too far from the median of that window are left out. The others are
+ * weighted by volume: summing units and dollars, rather than averaging rates,
+ * gives each dollar traded the same weight.
+ */
+fn composite_rate(request: &ExchangeRateRequest) -> Result<CompositeRate, ExchangeRateError> {
+    let validated_request = validate_request(request)?;
+    let timestamp = resolve_timestamp(&validated_request)?;
+    let tolerance = match &request.tolerance {
+        Some(tolerance) => from_decimal_string(tolerance, RATE_DECIMALS)
+            .ok_or(ExchangeRateError::InvalidTolerance(tolerance.clone()))?,
+        None => COMPOSITE_TOLERANCE,
+    };
+
+    let client = EnvClient::empty();
+    let members = composite_asset(&client, &request.asset_code)
+        .map_err(|_| ExchangeRateError::DatabaseError)?
+        .map(|composite| composite.members())
+        .filter(|members| !members.is_empty())
+        .ok_or(ExchangeRateError::NotFound(format!(
+            "composite asset {}",
+            request.asset_code
+        )))?;
+
+    let mut latest_rows = Vec::new();
+    for (code, issuer) in members {
+        let mut query = client.read_filter();
+        query.column_equal_to("floatcode", code);
+        query.column_equal_to("fltissuer", issuer);
+        query.column_equal_to("interval", validated_request.interval);
+
+        latest_rows.extend(query_db(query, timestamp, validated_request.as_of)?.pop());
+    }
+
+    let gaps = read_gaps();
+    let window_end = latest_rows
+        .iter()
+        .map(|row| row.timestamp)
+        .max()
+        .ok_or_else(|| missing_rate_error(timestamp, validated_request.interval, &gaps))?;
+
+    let (contributions, units, volume) = weigh_members(latest_rows, window_end, tolerance)?;
+
+    let included = contributions
+        .iter()
+        .filter(|contribution| contribution.excluded.is_none())
+        .map(|contribution| &contribution.row);
+    let window = included.max_by_key(|row| row.fixedvol).cloned();
+    let rate = divide(units, volume, USDC.decimals);
+    let (Some(window), Some(rate)) = (window, rate) else {
+        return Err(ExchangeRateError::NotFound(format!(
+            "traded volume for the composite asset {}",
+            request.asset_code
+        )));
+    };
+
+    Ok(CompositeRate {
+        asset_code: request.asset_code.clone(),
+        coverage: coverage(&window, timestamp, &gaps),
+        window,
+        rate,
+        volume,
+        tolerance,
+        contributions,
+    })
+}
+
+/**
+ * Weigh the latest rates of the members against the median of the window that
+ * ends at `window_end`. Returns each member's contribution along with the
+ * units and dollars of the included ones. Members whose amounts overflow the
+ * totals are left out rather than distorting the composite.
+ */
+fn weigh_members(
+    latest_rows: Vec<RatesDbRow>,
+    window_end: u64,
+    tolerance: i128,
+) -> Result<(Vec<Contribution>, i128, i128), ExchangeRateError> {
+    let mut window_rates = latest_rows
+        .iter()
+        .filter(|row| row.timestamp == window_end)
+        .map(|row| row.fixedrate)
+        .collect::<Vec<_>>();
+    window_rates.sort_unstable();
+    let median = window_rates
+        .get(window_rates.len().saturating_sub(1) / 2)
+        .copied()
+        .unwrap_or_default();
+    // Only a tolerance far beyond 100% can overflow
+    let max_divergence = multiply(median, tolerance, RATE_DECIMALS).ok_or_else(|| {
+        ExchangeRateError::InvalidTolerance(to_decimal_string(tolerance, RATE_DECIMALS))
+    })?;
+
+    let (mut units, mut volume) = (0_i128, 0_i128);
+    let contributions = latest_rows
+        .into_iter()
+        .map(|row| {
+            let excluded = if row.timestamp < window_end {
+                Some("No trades in the window")
+            } else if (row.fixedrate - median).abs() > max_divergence {
+                Some("Diverges from the median rate by more than the tolerance")
+            } else {
+                let totals = multiply(row.fixedrate, row.fixedvol, USDC.decimals)
+                    .and_then(|row_units| units.checked_add(row_units))
+                    .zip(volume.checked_add(row.fixedvol));
+                match totals {
+                    Some(totals) => {
+                        (units, volume) = totals;
+                        None
+                    }
+                    None => Some("Its volume overflows the totals of the composite"),
+                }
+            };
+            Contribution { row, excluded }
+        })
+        .collect::<Vec<_>>();
+
+    Ok((contributions, units, volume))
+}
+
+fn build_ok_response(composite: CompositeRate) -> serde_json::Value {
+    let window = &composite.window;
+
+    serde_json::json!({
+        "status": 200,
+        "data": [{
+            "asset_code": composite.asset_code,
+            "asset_issuer": null,
+            "composite": true,
+            "base_currency": "USD",
+            "rate_date_time": window.timestamp_iso8601(),
+            "window_start": window.window_start_iso8601(),
+            "window_end": window.timestamp_iso8601(),
+            "exchange_rate": to_decimal_string(composite.rate, RATE_DECIMALS),
+            "interval": window.interval_name(),
+            "coverage": composite.coverage,
+            "algorithm": COMPOSITE_ALGORITHM,
+            "tolerance": to_decimal_string(composite.tolerance, RATE_DECIMALS),
+            "volume": to_decimal_string(composite.volume, USDC.decimals),
+            "contributions": composite.contributions.iter().map(|contribution| {
+                let row = &contribution.row;
+
+                serde_json::json!({
+                    "asset_code": row.floatcode,
+                    "asset_issuer": row.fltissuer,
+                    "window_end": row.timestamp_iso8601(),
+                    "exchange_rate": row.rate_string(),
+                    "volume": row.volume_string(),
+                    "included": contribution.excluded.is_none(),
+                    "reason": contribution.excluded,
+                })
+            }).collect::<Vec<_>>(),
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: i128 = 10_i128.pow(RATE_DECIMALS);
+    const DOLLAR: i128 = 10_i128.pow(USDC.decimals);
+    const WINDOW_END: u64 = 1_734_307_200;
+
+    fn member(issuer: &str, timestamp: u64, fixedrate: i128, fixedvol: i128) -> RatesDbRow {
+        RatesDbRow {
+            timestamp,
+            floatcode: "BTC".to_string(),
+            fltissuer: issuer.to_string(),
+            fixedrate,
+            fixedvol,
+            interval: 3600,
+            winstart: timestamp - 3600,
+            revision: 0,
+            computed: timestamp,
+            algo: "volume_weighted/2".to_string(),
+        }
+    }
+
+    fn reasons(contributions: &[Contribution]) -> Vec<(&str, Option<&str>)> {
+        contributions
+            .iter()
+            .map(|contribution| (contribution.row.fltissuer.as_str(), contribution.excluded))
+            .collect()
+    }
+
+    #[test]
+    fn members_are_weighed_by_volume_against_the_median() {
+        let rows = vec![
+            member("A", WINDOW_END, ONE, 100 * DOLLAR),
+            member("B", WINDOW_END, ONE * 102 / 100, 50 * DOLLAR),
+            member("C", WINDOW_END, ONE * 150 / 100, 10 * DOLLAR),
+            member("D", WINDOW_END - 3600, ONE, 10 * DOLLAR),
+        ];
+
+        let (contributions, units, volume) =
+            weigh_members(rows, WINDOW_END, COMPOSITE_TOLERANCE).unwrap();
+
+        assert_eq!(
+            reasons(&contributions),
+            vec![
+                ("A", None),
+                ("B", None),
+                (
+                    "C",
+                    Some("Diverges from the median rate by more than the tolerance")
+                ),
+                ("D", Some("No trades in the window")),
+            ]
+        );
+        assert_eq!(volume, 150 * DOLLAR);
+        assert_eq!(units, ONE * 100 + ONE * 102 / 100 * 50);
+        assert_eq!(
+            to_decimal_string(divide(units, volume, USDC.decimals).unwrap(), RATE_DECIMALS),
+            "1.006666666666666666"
+        );
+    }
+
+    #[test]
+    fn a_member_that_overflows_the_totals_is_skipped() {
+        let rows = vec![
+            member("A", WINDOW_END, ONE, 100 * DOLLAR),
+            member("B", WINDOW_END, ONE, i128::MAX / 2),
+            member("C", WINDOW_END, ONE, 20 * DOLLAR),
+        ];
+
+        let (contributions, units, volume) =
+            weigh_members(rows, WINDOW_END, COMPOSITE_TOLERANCE).unwrap();
+
+        assert_eq!(
+            reasons(&contributions),
+            vec![
+                ("A", None),
+                (
+                    "B",
+                    Some("Its volume overflows the totals of the composite")
+                ),
+                ("C", None),
+            ]
+        );
+        assert_eq!((units, volume), (ONE * 120, 120 * DOLLAR));
+    }
+
+    #[test]
+    fn the_median_only_counts_the_latest_window() {
+        // The stale members would make 1.0 the median if they counted
+        let rows = vec![
+            member("A", WINDOW_END - 3600, ONE, DOLLAR),
+            member("B", WINDOW_END - 3600, ONE, DOLLAR),
+            member("C", WINDOW_END, ONE * 2, DOLLAR),
+        ];
+
+        let (contributions, _, volume) =
+            weigh_members(rows, WINDOW_END, COMPOSITE_TOLERANCE).unwrap();
+
+        assert_eq!(contributions[2].excluded, None);
+        assert_eq!(volume, DOLLAR);
+    }
+
+    #[test]
+    fn a_tolerance_that_overflows_is_rejected() {
+        let rows = vec![member("A", WINDOW_END, ONE * 2, DOLLAR)];
+
+        assert!(matches!(
+            weigh_members(rows, WINDOW_END, i128::MAX),
+            Err(ExchangeRateError::InvalidTolerance(_))
+        ));
+    }
+}
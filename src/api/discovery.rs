@@ -0,0 +1,80 @@
+#![warn(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use zephyr_sdk::EnvClient;
+
+use crate::{db::discovery::DiscoveredAsset, trust::TrustTier};
+
+// How many candidates are listed when the request does not say
+const DEFAULT_LIMIT: usize = 100;
+
+#[derive(Deserialize, Serialize)]
+struct DiscoveryRequest {
+    limit: Option<usize>,
+}
+
+/// Lists the assets that were traded against USDC but are not indexed.
+///
+/// Swaps of classic assets missing from the token registry are not saved, but
+/// their USDC volume and number of trades are added up from the first time the
+/// asset was seen. The candidates are listed by descending volume, so that the
+/// most traded ones can be considered for onboarding first. Assets that have
+/// since been added to the registry are left out; the others come with their
+/// trust tier, so that impersonators stand out.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "limit": 100  // optional, the number of candidates to list
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": [{
+///         "asset_code": "AQUA",
+///         "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA",
+///         "trust_tier": "established",
+///         "usdc_volume": "152031.5520913",
+///         "trade_count": 4210,
+///         "first_seen": "2024-11-02T08:14:31",
+///         "last_seen": "2024-12-16T03:02:11"
+///     }]
+/// }
+/// ```
+#[no_mangle]
+pub extern "C" fn get_discovered_assets() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<DiscoveryRequest>();
+
+    let mut candidates = client
+        .read::<DiscoveredAsset>()
+        .into_iter()
+        .filter(|asset| !asset.is_void())
+        .map(|asset| {
+            let tier = TrustTier::of(&asset.floatcode, &asset.fltissuer);
+            (asset, tier)
+        })
+        .filter(|(_, tier)| !tier.is_certified())
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|(asset, _)| std::cmp::Reverse(asset.usdcvol));
+    candidates.truncate(request.limit.unwrap_or(DEFAULT_LIMIT));
+
+    client.conclude(serde_json::json!({
+        "status": 200,
+        "data": candidates.iter().map(|(asset, tier)| {
+            serde_json::json!({
+                "asset_code": asset.floatcode,
+                "asset_issuer": asset.fltissuer,
+                "trust_tier": tier.name(),
+                "usdc_volume": asset.volume_string(),
+                "trade_count": asset.trades,
+                "first_seen": asset.first_seen_iso8601(),
+                "last_seen": asset.last_seen_iso8601(),
+            })
+        }).collect::<Vec<_>>(),
+    }));
+}
@@ -4,13 +4,12 @@ use serde::{Deserialize, Serialize};
 use zephyr_sdk::EnvClient;
 
 use crate::{
-    config::CONVERSION_FACTOR,
     db::{
-        exchange_rate::RatesDbRow,
-        savepoint::Savepoint,
-        swap::{Soroswap, SwapDbRow},
+        exchange_rate::{published_rates, RatesDbRow},
+        savepoint::active_savepoints,
     },
-    utils::is_certified_asset,
+    trust::{parse_min_tier, TrustTier},
+    utils::interval_name,
 };
 
 #[derive(Deserialize, Serialize)]
@@ -18,6 +17,25 @@ pub(crate) struct CatRequest {
     text: String,
 }
 
+// Listings can leave out the assets below a trust tier
+#[derive(Deserialize, Serialize)]
+pub(crate) struct TierRequest {
+    min_tier: Option<String>,
+}
+
+fn read_min_tier(client: &EnvClient) -> Result<TrustTier, serde_json::Value> {
+    let request = client.read_request_body::<TierRequest>();
+
+    parse_min_tier(&request.min_tier).map_err(|message| {
+        serde_json::json!({
+            "status": 400,
+            "data": {
+                "error": message,
+            },
+        })
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn cat() {
     let client = EnvClient::empty();
@@ -32,18 +50,36 @@ pub extern "C" fn cat() {
 #[no_mangle]
 pub extern "C" fn savepoint() {
     let client = EnvClient::empty();
-    let savepoint = client.read::<Savepoint>().first().unwrap().savepoint;
-    client.conclude(serde_json::json!({ "savepoint": format!("{savepoint}") }));
+    let savepoints = active_savepoints(&client)
+        .iter()
+        .map(|savepoint| {
+            (
+                interval_name(savepoint.interval).to_string(),
+                format!("{}", savepoint.savepoint),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    client.conclude(serde_json::json!({ "savepoints": savepoints }));
 }
 
 #[no_mangle]
 pub extern "C" fn get_all_currencies() {
     let client = EnvClient::empty();
-    let exchange_rates = client.read::<RatesDbRow>();
+    let min_tier = match read_min_tier(&client) {
+        Ok(min_tier) => min_tier,
+        Err(response) => return client.conclude(response),
+    };
+    let exchange_rates = published_rates(client.read::<RatesDbRow>(), None);
 
+    // A code is listed if any of its assets is trusted enough
     let mut currencies: Vec<String> = exchange_rates
         .iter()
-        .map(|row| row.floatcode.clone())
+        .map(|row| (&row.floatcode, &row.fltissuer))
+        .collect::<std::collections::HashSet<_>>()
+        .into_iter()
+        .filter(|(code, issuer)| TrustTier::of(code, issuer) >= min_tier)
+        .map(|(code, _)| code.clone())
         .collect::<std::collections::HashSet<_>>()
         .into_iter()
         .collect();
@@ -53,76 +89,15 @@ pub extern "C" fn get_all_currencies() {
     client.conclude(serde_json::json!(currencies));
 }
 
-#[no_mangle]
-pub extern "C" fn get_soroswap_swaps() {
-    let client = EnvClient::empty();
-    let soroswap_swaps = client.read::<Soroswap>();
-
-    let response = soroswap_swaps
-        .iter()
-        .map(|row| row.swap.clone())
-        .collect::<Vec<_>>();
-
-    client.conclude(serde_json::json!(response));
-}
-
-#[no_mangle]
-pub extern "C" fn get_duplicate_swaps() {
-    let client = EnvClient::empty();
-
-    // Get the latest savepoint
-    let savepoint = client.read::<Savepoint>().first().unwrap().savepoint;
-
-    // Read all exchange rates at the latest savepoint
-    let swaps = client
-        .read_filter()
-        .column_gt("creation", savepoint)
-        .read::<SwapDbRow>()
-        .unwrap();
-
-    // Create a map to store swaps with the same characteristics
-    use std::collections::HashMap;
-    let mut swap_groups: HashMap<(String, String, String, String), Vec<u64>> = HashMap::new();
-
-    // Group swaps by their characteristics and store creation times
-    for rate in &swaps {
-        let key = (
-            rate.floatcode.clone(),
-            rate.fltissuer.clone(),
-            (rate.usdc_amnt as f64 / CONVERSION_FACTOR).to_string(),
-            format!("{:.4}", rate.numerator as f64 / rate.denom as f64),
-        );
-        swap_groups.entry(key).or_default().push(rate.creation);
-    }
-
-    // Filter only duplicates (groups with more than 1 entry)
-    let duplicates: Vec<_> = swap_groups
-        .clone()
-        .into_iter()
-        .filter(|(_, creations)| creations.len() > 1)
-        .map(|((asset_code, asset_issuer, volume, price), creations)| {
-            serde_json::json!({
-                "asset_code": asset_code,
-                "asset_issuer": asset_issuer,
-                "volume": volume,
-                "price": price,
-                "creation_times": creations
-            })
-        })
-        .collect();
-
-    client.conclude(serde_json::json!({
-        "savepoint": savepoint,
-        "duplicates": duplicates,
-        "total_swaps": swaps.len(),
-        "unique_swaps": swap_groups.len() - duplicates.len(),
-    }));
-}
-
 #[no_mangle]
 pub extern "C" fn get_all_exchange_rates() {
     let client = EnvClient::empty();
-    let exchange_rates = client.read::<RatesDbRow>();
+    let min_tier = match read_min_tier(&client) {
+        Ok(min_tier) => min_tier,
+        Err(response) => return client.conclude(response),
+    };
+    let mut exchange_rates = published_rates(client.read::<RatesDbRow>(), None);
+    exchange_rates.sort_by_key(|row| row.timestamp);
 
     let response = exchange_rates
         .iter()
@@ -140,15 +115,24 @@ pub extern "C" fn get_all_exchange_rates() {
         )
         .into_iter()
         .map(|((asset_code, asset_issuer), rates)| {
+            let tier = TrustTier::of(asset_code, asset_issuer);
+            (asset_code, asset_issuer, tier, rates)
+        })
+        .filter(|(_, _, tier, _)| *tier >= min_tier)
+        .map(|(asset_code, asset_issuer, tier, rates)| {
             serde_json::json!({
                 "asset_code": asset_code,
                 "asset_issuer": asset_issuer,
-                "soroswap_certified_asset": is_certified_asset(asset_code, asset_issuer),
+                "soroswap_certified_asset": tier.is_certified(),
+                "trust_tier": tier.name(),
                 "rates": rates.into_iter().map(|row| {
                     serde_json::json!({
                         "date": row.timestamp_iso8601(),
-                        "rate": row.rate.to_string(),
-                        "volume": row.volume.to_string(),
+                        "window_start": row.window_start_iso8601(),
+                        "interval": row.interval_name(),
+                        "rate": row.rate_string(),
+                        "revision": row.revision,
+                        "volume": row.volume_string(),
                     })
                 }).collect::<Vec<_>>()
             })
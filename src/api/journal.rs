@@ -0,0 +1,180 @@
+#![warn(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use zephyr_sdk::EnvClient;
+
+use super::shared::parse_optional_timestamp;
+use crate::db::journal::{read_journal_head, JournalEntry};
+
+// How many entries are listed when the request does not say
+const DEFAULT_LIMIT: usize = 100;
+
+#[derive(Deserialize, Serialize)]
+struct JournalRequest {
+    asset_code: Option<String>,
+    asset_issuer: Option<String>,
+    outcome: Option<String>,
+    reason: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    limit: Option<usize>,
+}
+
+/// Lists the latest swaps that ingestion saved or rejected.
+///
+/// Every swap involving USDC that ingestion finds is journaled, along with
+/// each hop of Soroswap swaps and the events it could not read. The journal
+/// keeps as many of the latest entries as its capacity, which
+/// `set_journal_capacity` changes, and lists them from the most recent.
+/// Amounts are in the smallest unit of their asset. Soroban tokens we do not
+/// know have an empty code, and their contract address as issuer.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "asset_code": "optional_code",          // either side of the swap
+///     "asset_issuer": "optional_issuer",
+///     "outcome": "optional_outcome",          // "accepted" or "rejected"
+///     "reason": "optional_rejection_code",    // e.g. "scam_issuer"
+///     "from": "optional_ISO8601_timestamp",   // inclusive
+///     "to": "optional_ISO8601_timestamp",     // exclusive
+///     "limit": 100                            // optional
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": [{
+///         "entry": 81234,
+///         "ledger": 54983312,
+///         "close_time": "2024-12-16T03:02:11",
+///         "source": "claim_atom",         // or "soroswap_event"
+///         "venue": "order_book",          // or "liquidity_pool", "soroswap"
+///         "sold": {
+///             "asset_code": "USDC",
+///             "asset_issuer": "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
+///             "amount": "1000000000"
+///         },
+///         "bought": {
+///             "asset_code": "XLM",
+///             "asset_issuer": "Native",
+///             "amount": "2500000000"
+///         },
+///         "outcome": "rejected",
+///         "reason": "dust_price"          // null if accepted
+///     }]
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid outcome or date format
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn get_journal() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<JournalRequest>();
+
+    client.conclude(match read_journal(&client, &request) {
+        Ok(entries) => serde_json::json!({
+            "status": 200,
+            "data": entries.iter().map(entry_json).collect::<Vec<_>>(),
+        }),
+        Err((status, message)) => serde_json::json!({
+            "status": status,
+            "data": {
+                "error": message,
+            },
+        }),
+    });
+}
+
+fn read_journal(
+    client: &EnvClient,
+    request: &JournalRequest,
+) -> Result<Vec<JournalEntry>, (u16, String)> {
+    let invalid_date = |_| (400, "Invalid date format.".to_string());
+    let from = parse_optional_timestamp(&request.from).map_err(invalid_date)?;
+    let to = parse_optional_timestamp(&request.to).map_err(invalid_date)?;
+    if let Some(outcome) = &request.outcome {
+        if outcome != "accepted" && outcome != "rejected" {
+            return Err((
+                400,
+                format!("Invalid outcome '{outcome}'. Please use accepted or rejected."),
+            ));
+        }
+    }
+
+    let Some(head) = read_journal_head(client) else {
+        return Ok(Vec::new());
+    };
+
+    // Entries before the first kept one may still have their rows
+    let query = || {
+        let mut query = client.read_filter();
+        if head.first_kept() > 0 {
+            query.column_gt("entry", head.first_kept() - 1);
+        }
+        query.column_gt(
+            "closetime",
+            from.unwrap_or(0).saturating_sub(1).max(0) as u64,
+        );
+        if let Some(to) = to {
+            query.column_lt("closetime", to.max(0) as u64);
+        }
+        if let Some(outcome) = &request.outcome {
+            query.column_equal_to("outcome", outcome.clone());
+        }
+        if let Some(reason) = &request.reason {
+            query.column_equal_to("reason", reason.clone());
+        }
+        query
+    };
+    let database_error = |_| (500, "Database error".to_string());
+
+    let mut entries = match &request.asset_code {
+        // The asset may be on either side, which takes a query for each
+        Some(code) => {
+            let mut entries = Vec::new();
+            for (code_column, issuer_column) in [("sellcode", "sellissr"), ("buycode", "buyissr")] {
+                let mut side = query();
+                side.column_equal_to(code_column, code.clone());
+                if let Some(issuer) = &request.asset_issuer {
+                    side.column_equal_to(issuer_column, issuer.clone());
+                }
+                entries.extend(side.read::<JournalEntry>().map_err(database_error)?);
+            }
+            entries
+        }
+        None => query().read::<JournalEntry>().map_err(database_error)?,
+    };
+
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.entry));
+    entries.dedup_by_key(|entry| entry.entry);
+    entries.truncate(request.limit.unwrap_or(DEFAULT_LIMIT));
+    Ok(entries)
+}
+
+fn entry_json(entry: &JournalEntry) -> serde_json::Value {
+    serde_json::json!({
+        "entry": entry.entry,
+        "ledger": entry.ledger,
+        "close_time": entry.closetime_iso8601(),
+        "source": entry.source,
+        "venue": entry.venue,
+        "sold": {
+            "asset_code": entry.sellcode,
+            "asset_issuer": entry.sellissr,
+            "amount": entry.sellamnt.to_string(),
+        },
+        "bought": {
+            "asset_code": entry.buycode,
+            "asset_issuer": entry.buyissr,
+            "amount": entry.buyamnt.to_string(),
+        },
+        "outcome": entry.outcome,
+        "reason": (!entry.reason.is_empty()).then_some(&entry.reason),
+    })
+}
@@ -0,0 +1,252 @@
+#![warn(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use zephyr_sdk::EnvClient;
+
+use crate::{
+    config::METRICS_INTERVAL,
+    db::{
+        latest_close_time,
+        metrics::{window_metrics, MetricDbRow, METRICS},
+    },
+    fixed_point::to_decimal_string,
+};
+
+// Prometheus names are namespaced by the program
+const PREFIX: &str = "kwickbit";
+
+#[derive(Deserialize, Serialize)]
+struct MetricsRequest {
+    format: Option<String>,
+}
+
+/// Exports the ingestion metrics of the latest complete metrics window.
+///
+/// Every ledger close adds its swaps to counters for the window it falls in:
+/// ledgers ingested, swaps found by source, venue and outcome, rejections by
+/// reason, and the USDC volume accepted by venue. Windows last five minutes,
+/// and the one in progress is left out so that values do not jump as it
+/// fills up. Prometheus text exposition is the default; its metrics are
+/// gauges, as each scrape reads a new window. Windows are kept for an hour,
+/// after which new ones take over their rows.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "format": "optional_format"  // "prometheus" (default) or "json"
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200), in Prometheus format:
+/// ```json
+/// {
+///     "status": 200,
+///     "data": "# HELP kwickbit_ledgers Ledgers ingested\n# TYPE kwickbit_ledgers gauge\nkwickbit_ledgers 60\n…"
+/// }
+/// ```
+///
+/// In JSON format:
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "window_start": "2024-12-16T02:55:00",
+///         "window_end": "2024-12-16T03:00:00",
+///         "metrics": [{
+///             "name": "swaps",
+///             "labels": {
+///                 "source": "claim_atom",
+///                 "venue": "order_book",
+///                 "outcome": "accepted"
+///             },
+///             "value": "87"
+///         }]
+///     }
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid format
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn get_metrics() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<MetricsRequest>();
+
+    let latest_close = latest_close_time(&client);
+    let window_end = latest_close - latest_close % METRICS_INTERVAL;
+    let window_start = window_end.saturating_sub(METRICS_INTERVAL);
+
+    let rows = match window_metrics(&client, window_start) {
+        Ok(rows) => rows,
+        Err(message) => {
+            return client.conclude(serde_json::json!({
+                "status": 500,
+                "data": {
+                    "error": message,
+                },
+            }))
+        }
+    };
+
+    client.conclude(match request.format.as_deref() {
+        None | Some("prometheus") => serde_json::json!({
+            "status": 200,
+            "data": prometheus_text(&rows, window_end),
+        }),
+        Some("json") => serde_json::json!({
+            "status": 200,
+            "data": {
+                "window_start": parse_date(window_start),
+                "window_end": parse_date(window_end),
+                "metrics": rows.iter().map(|row| serde_json::json!({
+                    "name": row.metric,
+                    "labels": label_pairs(&row.labels)
+                        .collect::<serde_json::Map<_, _>>(),
+                    "value": value_string(row),
+                })).collect::<Vec<_>>(),
+            },
+        }),
+        Some(format) => serde_json::json!({
+            "status": 400,
+            "data": {
+                "error": format!("Invalid format '{format}'. Please use prometheus or json."),
+            },
+        }),
+    });
+}
+
+fn parse_date(timestamp: u64) -> String {
+    crate::utils::parse_date(&(timestamp as i64))
+}
+
+fn label_pairs(labels: &str) -> impl Iterator<Item = (String, serde_json::Value)> + '_ {
+    labels
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_string(), serde_json::Value::from(value)))
+}
+
+fn value_string(row: &MetricDbRow) -> String {
+    let decimals = METRICS
+        .iter()
+        .find(|metric| metric.name == row.metric)
+        .map_or(0, |metric| metric.decimals);
+
+    to_decimal_string(row.value, decimals)
+}
+
+// Every metric gets its help and type lines, even without values, so that
+// series do not vanish from the scrape when a window is quiet
+fn prometheus_text(rows: &[MetricDbRow], window_end: u64) -> String {
+    let mut text = format!(
+        "# HELP {PREFIX}_window_end_seconds End of the window the metrics cover\n\
+         # TYPE {PREFIX}_window_end_seconds gauge\n\
+         {PREFIX}_window_end_seconds {window_end}\n"
+    );
+
+    for metric in METRICS {
+        text.push_str(&format!(
+            "# HELP {PREFIX}_{name} {help}\n# TYPE {PREFIX}_{name} gauge\n",
+            name = metric.name,
+            help = metric.help,
+        ));
+
+        for row in rows.iter().filter(|row| row.metric == metric.name) {
+            let labels = label_pairs(&row.labels)
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>();
+            let labels = if labels.is_empty() {
+                String::new()
+            } else {
+                format!("{{{}}}", labels.join(","))
+            };
+
+            text.push_str(&format!(
+                "{PREFIX}_{}{labels} {}\n",
+                metric.name,
+                value_string(row)
+            ));
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(metric: &str, labels: &str, value: i128) -> MetricDbRow {
+        MetricDbRow {
+            winstart: 1_734_317_700,
+            metric: metric.to_string(),
+            labels: labels.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn labels_are_split_into_pairs() {
+        let pairs = label_pairs("source=claim_atom,venue=order_book,outcome=accepted")
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            pairs,
+            [
+                r#"source="claim_atom""#,
+                r#"venue="order_book""#,
+                r#"outcome="accepted""#
+            ]
+        );
+        assert_eq!(label_pairs("").count(), 0);
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        let pairs = label_pairs("reason=a\"b\\c\nd")
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(pairs, [r#"reason="a\"b\\c\nd""#]);
+    }
+
+    #[test]
+    fn every_metric_is_exported_in_order() {
+        let rows = [
+            row("usdc_volume", "venue=soroswap", 12_345_678),
+            row(
+                "swaps",
+                "source=claim_atom,venue=order_book,outcome=accepted",
+                87,
+            ),
+            row("ledgers", "", 60),
+            row(
+                "swaps",
+                "source=soroswap_event,venue=soroswap,outcome=rejected",
+                3,
+            ),
+        ];
+
+        assert_eq!(
+            prometheus_text(&rows, 1_734_318_000),
+            "# HELP kwickbit_window_end_seconds End of the window the metrics cover\n\
+             # TYPE kwickbit_window_end_seconds gauge\n\
+             kwickbit_window_end_seconds 1734318000\n\
+             # HELP kwickbit_ledgers Ledgers ingested\n\
+             # TYPE kwickbit_ledgers gauge\n\
+             kwickbit_ledgers 60\n\
+             # HELP kwickbit_swaps Swaps found, by source, venue and outcome\n\
+             # TYPE kwickbit_swaps gauge\n\
+             kwickbit_swaps{source=\"claim_atom\",venue=\"order_book\",outcome=\"accepted\"} 87\n\
+             kwickbit_swaps{source=\"soroswap_event\",venue=\"soroswap\",outcome=\"rejected\"} 3\n\
+             # HELP kwickbit_rejections Swaps rejected, by reason\n\
+             # TYPE kwickbit_rejections gauge\n\
+             # HELP kwickbit_usdc_volume USDC volume of the swaps accepted, by venue\n\
+             # TYPE kwickbit_usdc_volume gauge\n\
+             kwickbit_usdc_volume{venue=\"soroswap\"} 1.2345678\n"
+        );
+    }
+}
@@ -1,8 +1,17 @@
 #![warn(missing_docs)]
 
+pub(crate) mod admin;
+pub(crate) mod audit;
+pub(crate) mod composite;
+pub(crate) mod discovery;
 pub(crate) mod extras;
+pub(crate) mod journal;
+pub(crate) mod metrics;
 pub(crate) mod rates_history;
+pub(crate) mod revisions;
+pub(crate) mod scams;
 pub(crate) mod shared;
+pub(crate) mod status;
 
 use std::collections::HashMap;
 
@@ -10,11 +19,16 @@ use serde::{Deserialize, Serialize};
 use zephyr_sdk::EnvClient;
 
 use crate::{
-    db::{exchange_rate::RatesDbRow, savepoint::Savepoint},
-    utils::is_certified_asset,
+    db::{
+        exchange_rate::RatesDbRow, savepoint::active_savepoints, swap::SwapAsset,
+        token::impersonated_token,
+    },
+    trust::{parse_min_tier, TrustTier},
 };
 use shared::{
-    normalize_issuer, parse_timestamp, query_db, ExchangeRateError, NormalizeAssetIssuer,
+    coverage, interval_error_message, issuer_risk, missing_rate_error, normalize_issuer,
+    parse_interval, parse_optional_timestamp, parse_timestamp, query_db, read_gaps,
+    ExchangeRateError, NormalizeAssetIssuer,
 };
 
 #[derive(Deserialize, Serialize)]
@@ -22,6 +36,12 @@ pub(crate) struct ExchangeRateRequest {
     asset_code: String,
     asset_issuer: Option<String>,
     date: Option<String>,
+    interval: Option<String>,
+    as_of: Option<String>,
+    include_impersonators: Option<bool>,
+    min_tier: Option<String>,
+    composite: Option<bool>,
+    tolerance: Option<String>,
 }
 
 impl NormalizeAssetIssuer for ExchangeRateRequest {
@@ -32,23 +52,63 @@ impl NormalizeAssetIssuer for ExchangeRateRequest {
 
 struct ValidatedRequest {
     asset_code: String,
-    asset_issuer: Option<String>, // None means the asset is native (XLM)
-    timestamp: Option<i64>,       // Seconds since the Unix epoch; None means that the most recent
-                                  // exchange rates will be retrieved
+    // None means the asset is native (XLM)
+    asset_issuer: Option<String>,
+    // Length of the rate window, in seconds
+    interval: u64,
+    // Seconds since the Unix epoch; None means that the most recent exchange
+    // rates will be retrieved
+    timestamp: Option<i64>,
+    // Only revisions computed by then are considered; None means all of them
+    as_of: Option<i64>,
+    // Whether code-only lookups keep assets that impersonate others
+    include_impersonators: bool,
+    // Less trusted assets are left out
+    min_tier: TrustTier,
 }
 
 /// Retrieves the USD exchange rate for a given asset.
 ///
 /// Returns the latest exchange rate for the specified asset, up to an optional
 /// given time. For non-native assets (not XLM), an issuer may be specified;
-/// otherwise, all assets with the same code are retrieved.
+/// otherwise, all assets with the same code are retrieved. Rates are averaged
+/// over a window of the requested length, one hour by default. Windows are
+/// aligned to UTC boundaries and the response gives the start and end of the
+/// one that was used. If that is not the window right before the requested
+/// time, `coverage` says whether the windows since had no trades or were not
+/// ingested.
+///
+/// Rates may be corrected after the fact; each correction is a new revision.
+/// The latest revision is served, unless `as_of` is given, in which case the
+/// rate is returned as it was known at that time.
+///
+/// Without an issuer, assets that impersonate a registered token, i.e. whose
+/// code looks like its code but which have another issuer, are left out and
+/// listed in `excluded` with the reason, unless `include_impersonators` is
+/// set. Assets requested with their issuer are always served, and say which
+/// token they impersonate.
+///
+/// Each asset comes with its trust tier. Assets below `min_tier` are left out.
+///
+/// With `composite`, the configured issuers of an asset, e.g. the BTC anchors,
+/// are merged into a single volume-weighted rate for the latest window any of
+/// them traded in. Issuers whose rate differs from the median by more than
+/// `tolerance`, 5% by default, are left out; each one's contribution is listed
+/// with the reason it was left out, if it was. The issuer and the filters
+/// above do not apply.
 ///
 /// # Request Format
 /// ```json
 /// {
 ///     "asset_code": "XLM",
 ///     "asset_issuer": "optional_issuer",
-///     "date": "optional_ISO8601_timestamp"  // e.g. "2024-01-01T00:00:00"
+///     "date": "optional_ISO8601_timestamp",  // e.g. "2024-01-01T00:00:00"
+///     "interval": "optional_window",         // "5m", "1h" (default) or "1d"
+///     "as_of": "optional_ISO8601_timestamp", // rates as they were known then
+///     "include_impersonators": false,        // optional, false by default
+///     "min_tier": "optional_trust_tier",     // e.g. "verified_issuer"
+///     "composite": false,                    // optional, false by default
+///     "tolerance": "0.05"                    // optional, for composite rates
 /// }
 /// ```
 ///
@@ -61,35 +121,75 @@ struct ValidatedRequest {
 ///         "asset_code": "XLM",
 ///         "asset_issuer": "Native",
 ///         "base_currency": "USD",
-///         "rate_date_time": "2023-12-31T23:58:30",
+///         "rate_date_time": "2023-12-31T23:00:00",
+///         "window_start": "2023-12-31T22:00:00",
+///         "window_end": "2023-12-31T23:00:00",
 ///         "exchange_rate": "1.2345",
+///         "interval": "1h",
+///         "coverage": "complete",
+///         "revision": 0,
+///         "computed_at": "2023-12-31T23:00:04",
+///         "algorithm": "volume_weighted/2",
 ///         "soroswap_certified_asset": true,
-///         "volume": "10000.0"
+///         "trust_tier": "native",
+///         "impersonates": null,
+///         "issuer_risk": null,                // or, for registered classic assets:
+///                                             // "auth_required", "auth_revocable",
+///                                             // "auth_immutable", "clawback_enabled",
+///                                             // "flags_changed_at", "clawbacks",
+///                                             // "last_clawback", "freezes", "last_freeze"
+///         "volume": "10000"
+///     }],
+///     "excluded": [{
+///         "asset_code": "XLM",
+///         "asset_issuer": "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA",
+///         "reason": "Impersonates XLM (Native)"
 ///     }]
 /// }
 /// ```
 ///
+/// A composite rate has `"composite": true`, no issuer, and a `contributions`
+/// list with the `asset_code`, `asset_issuer`, `window_end`, `exchange_rate`
+/// and `volume` of each issuer, whether it was `included`, and the `reason` if
+/// not.
+///
 /// # Errors
-/// - 400: Invalid date format
-/// - 404: No exchange rate found
+/// - 400: Invalid date format, interval, trust tier or tolerance
+/// - 404: No exchange rate found, missing because of an ingestion gap, or only
+///   found for impersonators or assets below the minimum tier; or no composite
+///   asset configured for the code
 /// - 500: Database error
 #[no_mangle]
 pub extern "C" fn get_exchange_rate() {
     let client = EnvClient::empty();
     let request = client.read_request_body::<ExchangeRateRequest>();
 
-    let response = match handle_request(&request) {
-        Ok(data) => build_ok_response(data),
-        Err(error) => build_error_response(error),
+    let response = if request.composite.unwrap_or(false) {
+        composite::handle_composite_request(&request)
+    } else {
+        match handle_request(&request) {
+            Ok(data) => build_ok_response(data),
+            Err(error) => build_error_response(error),
+        }
     };
 
     client.conclude(&response);
 }
 
-fn handle_request(request: &ExchangeRateRequest) -> Result<Vec<RatesDbRow>, ExchangeRateError> {
+// Each rate comes with a description of how well it covers the requested time
+type RateWithCoverage = (RatesDbRow, &'static str);
+
+// The rates served, and those left out along with the token they impersonate
+struct ExchangeRates {
+    rates: Vec<RateWithCoverage>,
+    excluded: Vec<(RatesDbRow, &'static SwapAsset)>,
+}
+
+fn handle_request(request: &ExchangeRateRequest) -> Result<ExchangeRates, ExchangeRateError> {
     let validated_request = validate_request(request)?;
-    let db_results = query_database(&validated_request)?;
-    process_results(db_results, &validated_request)
+    let timestamp = resolve_timestamp(&validated_request)?;
+    let db_results = query_database(&validated_request, timestamp)?;
+    process_results(db_results, &validated_request, timestamp)
 }
 
 fn validate_request(request: &ExchangeRateRequest) -> Result<ValidatedRequest, ExchangeRateError> {
@@ -100,41 +200,55 @@ fn validate_request(request: &ExchangeRateRequest) -> Result<ValidatedRequest, E
 
     // We don't allow non-native tokens named XLM.
     let asset_issuer = request.normalize_issuer();
+    let interval = parse_interval(&request.interval)?;
+    let as_of = parse_optional_timestamp(&request.as_of)?;
+    let min_tier = parse_min_tier(&request.min_tier).map_err(ExchangeRateError::InvalidTier)?;
 
     Ok(ValidatedRequest {
         asset_code: request.asset_code.clone(),
         asset_issuer,
+        interval,
         timestamp,
+        as_of,
+        include_impersonators: request.include_impersonators.unwrap_or(false),
+        min_tier,
     })
 }
 
-fn query_database(params: &ValidatedRequest) -> Result<Vec<RatesDbRow>, ExchangeRateError> {
+fn resolve_timestamp(params: &ValidatedRequest) -> Result<i64, ExchangeRateError> {
+    match params.timestamp {
+        Some(timestamp) => Ok(timestamp),
+        // Rates for the latest window are stamped with the savepoint itself,
+        // so we look up to one second past it.
+        None => active_savepoints(&EnvClient::empty())
+            .iter()
+            .find(|savepoint| savepoint.interval == params.interval)
+            .map(|savepoint| savepoint.savepoint as i64 + 1)
+            .ok_or(ExchangeRateError::NotFound("timestamp".to_string())),
+    }
+}
+
+fn query_database(
+    params: &ValidatedRequest,
+    timestamp: i64,
+) -> Result<Vec<RatesDbRow>, ExchangeRateError> {
     let client = EnvClient::empty();
     let mut query = client.read_filter();
     query.column_equal_to("floatcode", params.asset_code.clone());
+    query.column_equal_to("interval", params.interval);
 
     if let Some(issuer) = &params.asset_issuer {
         query.column_equal_to("fltissuer", issuer.clone());
     }
 
-    let timestamp = match params.timestamp {
-        Some(timestamp) => timestamp,
-        None => {
-            client
-                .read::<Savepoint>()
-                .first()
-                .ok_or(ExchangeRateError::NotFound("timestamp".to_string()))?
-                .savepoint as i64
-        }
-    };
-
-    query_db(query, timestamp)
+    query_db(query, timestamp, params.as_of)
 }
 
 fn process_results(
     results: Vec<RatesDbRow>,
     request: &ValidatedRequest,
-) -> Result<Vec<RatesDbRow>, ExchangeRateError> {
+    timestamp: i64,
+) -> Result<ExchangeRates, ExchangeRateError> {
     // We keep only the most recent exchange rate for each issuer
     let processed_results = results.into_iter().fold(HashMap::new(), |mut acc, row| {
         if request.asset_issuer.as_ref() == Some(&row.fltissuer) || request.asset_issuer.is_none() {
@@ -145,25 +259,102 @@ fn process_results(
         acc
     });
 
-    if processed_results.is_empty() {
-        Err(ExchangeRateError::NotFound("exchange rate".to_string()))
+    let found_any = !processed_results.is_empty();
+    let trusted_results = processed_results
+        .into_values()
+        .filter(|row| TrustTier::of(&row.floatcode, &row.fltissuer) >= request.min_tier)
+        .collect::<Vec<_>>();
+
+    if found_any && trusted_results.is_empty() {
+        return Err(ExchangeRateError::NotFound(format!(
+            "exchange rate for an asset of the {} trust tier or above",
+            request.min_tier.name()
+        )));
+    }
+
+    // Naming the issuer is a deliberate choice, so impersonators are only
+    // left out of lookups by code
+    let (rows, excluded): (Vec<_>, Vec<_>) = trusted_results
+        .into_iter()
+        .map(|row| {
+            let impersonated = impersonated_token(&row.floatcode, &row.fltissuer);
+            (row, impersonated)
+        })
+        .partition(|(_, impersonated)| {
+            impersonated.is_none()
+                || request.asset_issuer.is_some()
+                || request.include_impersonators
+        });
+    let excluded = excluded
+        .into_iter()
+        .filter_map(|(row, impersonated)| Some((row, impersonated?)))
+        .collect::<Vec<_>>();
+
+    let gaps = read_gaps();
+
+    if rows.is_empty() && !excluded.is_empty() {
+        Err(ExchangeRateError::Impersonation(
+            excluded
+                .iter()
+                .map(|(row, token)| format!("{}: {}", row.fltissuer, impersonation_reason(token)))
+                .collect::<Vec<_>>()
+                .join("; "),
+        ))
+    } else if rows.is_empty() {
+        Err(missing_rate_error(timestamp, request.interval, &gaps))
     } else {
-        Ok(processed_results.into_values().collect::<Vec<_>>())
+        Ok(ExchangeRates {
+            rates: rows
+                .into_iter()
+                .map(|(row, _)| {
+                    let coverage = coverage(&row, timestamp, &gaps);
+                    (row, coverage)
+                })
+                .collect::<Vec<_>>(),
+            excluded,
+        })
     }
 }
 
-fn build_ok_response(rate_data: Vec<RatesDbRow>) -> serde_json::Value {
+fn impersonation_reason(token: &SwapAsset) -> String {
+    format!("Impersonates {} ({})", token.code, token.issuer)
+}
+
+fn build_ok_response(rate_data: ExchangeRates) -> serde_json::Value {
     serde_json::json!({
         "status": 200,
-        "data": rate_data.into_iter().map(|row| {
+        "data": rate_data.rates.into_iter().map(|(row, coverage)| {
+            let impersonated = impersonated_token(&row.floatcode, &row.fltissuer);
+            let tier = TrustTier::of(&row.floatcode, &row.fltissuer);
+
             serde_json::json!({
                 "asset_code": row.floatcode,
                 "asset_issuer": row.fltissuer,
                 "base_currency": "USD",
                 "rate_date_time": row.timestamp_iso8601(),
-                "exchange_rate": row.rate.to_string(),
-                "soroswap_certified_asset": is_certified_asset(&row.floatcode, &row.fltissuer),
-                "volume": row.volume.to_string(),
+                "window_start": row.window_start_iso8601(),
+                "window_end": row.timestamp_iso8601(),
+                "exchange_rate": row.rate_string(),
+                "interval": row.interval_name(),
+                "coverage": coverage,
+                "revision": row.revision,
+                "computed_at": row.computed_iso8601(),
+                "algorithm": row.algo,
+                "soroswap_certified_asset": tier.is_certified(),
+                "trust_tier": tier.name(),
+                "impersonates": impersonated.map(|token| serde_json::json!({
+                    "asset_code": token.code,
+                    "asset_issuer": token.issuer,
+                })),
+                "issuer_risk": issuer_risk(&row.floatcode, &row.fltissuer),
+                "volume": row.volume_string(),
+            })
+        }).collect::<Vec<_>>(),
+        "excluded": rate_data.excluded.into_iter().map(|(row, token)| {
+            serde_json::json!({
+                "asset_code": row.floatcode,
+                "asset_issuer": row.fltissuer,
+                "reason": impersonation_reason(token),
             })
         }).collect::<Vec<_>>(),
     })
@@ -177,7 +368,23 @@ fn build_error_response(error: ExchangeRateError) -> serde_json::Value {
             //       the use of a space between the day and hour
             "Invalid date format. Please use the format '2020-09-16T14:30:00'.",
         ),
+        ExchangeRateError::InvalidInterval(interval) => (400, &*interval_error_message(&interval)),
+        ExchangeRateError::InvalidTier(ref message) => (400, message.as_str()),
+        ExchangeRateError::InvalidTolerance(tolerance) => (
+            400,
+            &*format!("Invalid tolerance '{tolerance}'. Please use a decimal fraction, e.g. 0.05."),
+        ),
         ExchangeRateError::NotFound(object) => (404, &*format!("No {object} found.")),
+        ExchangeRateError::IngestionGap(gap) => {
+            (404, &*format!("No exchange rate found, because {gap}."))
+        }
+        ExchangeRateError::Impersonation(excluded) => (
+            404,
+            &*format!(
+                "No exchange rate found for a trusted asset. Excluded issuers: {excluded}. \
+                 Provide the issuer or set include_impersonators to get their rates."
+            ),
+        ),
         ExchangeRateError::DatabaseError => (500, "An error occurred while querying the database."),
         // Other error types can only happen in the batch exchange rate endpoint.
         _ => unreachable!(),
@@ -3,11 +3,22 @@
 use serde::{Deserialize, Serialize};
 use zephyr_sdk::EnvClient;
 
-use crate::utils::parse_date;
+use crate::{
+    config::RATE_DECIMALS,
+    db::{
+        ingestion::Gap,
+        lineage::{chain_ratios, lineage_chains, read_links, AssetLink},
+    },
+    fixed_point::{divide, multiply, to_decimal_string},
+    trust::TrustTier,
+    utils::parse_date,
+};
 
 use super::{
     shared::{
-        normalize_issuer, parse_timestamp, query_db, ExchangeRateError, NormalizeAssetIssuer,
+        coverage, interval_error_message, issuer_risk, normalize_issuer, parse_interval,
+        parse_optional_timestamp, parse_timestamp, query_db, read_gaps, ExchangeRateError,
+        NormalizeAssetIssuer,
     },
     RatesDbRow,
 };
@@ -46,6 +57,8 @@ struct HistoryRequestTransactions {
 #[derive(Debug, Deserialize, Serialize)]
 struct HistoryRequest {
     assets: Vec<HistoryRequestTransactions>,
+    interval: Option<String>,
+    as_of: Option<String>,
 }
 
 // Intermediate structures
@@ -65,6 +78,8 @@ struct HistoryTransactionsTimestamps {
 #[derive(Debug, Deserialize, Serialize)]
 struct ValidatedHistoryAssetsWithTimestamps {
     assets: Vec<HistoryTransactionsTimestamps>,
+    interval: u64,
+    as_of: Option<i64>,
 }
 
 // Response structures
@@ -72,12 +87,97 @@ struct ValidatedHistoryAssetsWithTimestamps {
 struct TransactionExchangeRate {
     transaction_date: String,
     exchange_rate_date: String,
+    window_start: String,
+    window_end: String,
     exchange_rate: String,
+    coverage: String,
+    revision: u64,
+    computed_at: String,
+    lineage: Option<LineageNote>,
+}
+
+// Set when the rate is that of an asset the requested one continues, or of
+// one that continues it, converted with the ratio between them
+#[derive(Debug, Deserialize, Serialize)]
+struct LineageNote {
+    asset_code: String,
+    asset_issuer: String,
+    since: String,
+    ratio: String,
+}
+
+// A link to an asset whose rates stand in for those of the requested one on
+// the other side of a migration, with the ratio between the two: units of the
+// later asset per unit of the earlier one
+struct Hop {
+    link: AssetLink,
+    ratio: i128,
+}
+
+// The chains of assets linked to the requested one, nearest first
+struct Lineage {
+    predecessors: Vec<Hop>,
+    successors: Vec<Hop>,
+}
+
+impl Lineage {
+    fn new(links: &[AssetLink], code: &str, issuer: &str) -> Self {
+        let hops = |chain: Vec<AssetLink>| {
+            let ratios = chain_ratios(&chain);
+            chain
+                .into_iter()
+                .zip(ratios)
+                .map(|(link, ratio)| Hop { link, ratio })
+                .collect()
+        };
+        let (predecessors, successors) = lineage_chains(links, code, issuer);
+
+        Lineage {
+            predecessors: hops(predecessors),
+            successors: hops(successors),
+        }
+    }
+
+    fn note(&self, row: &RatesDbRow) -> Option<LineageNote> {
+        let predecessor = self
+            .predecessors
+            .iter()
+            .find(|hop| hop.link.prevcode == row.floatcode && hop.link.previssr == row.fltissuer);
+        let successor = self
+            .successors
+            .iter()
+            .find(|hop| hop.link.nextcode == row.floatcode && hop.link.nextissr == row.fltissuer);
+
+        predecessor.or(successor).map(|hop| LineageNote {
+            asset_code: row.floatcode.clone(),
+            asset_issuer: row.fltissuer.clone(),
+            since: hop.link.since_iso8601(),
+            ratio: to_decimal_string(hop.ratio, RATE_DECIMALS),
+        })
+    }
+}
+
+impl TransactionExchangeRate {
+    fn new(transaction_timestamp: &i64, row: &RatesDbRow, gaps: &[Gap], lineage: &Lineage) -> Self {
+        TransactionExchangeRate {
+            transaction_date: parse_date(transaction_timestamp),
+            exchange_rate_date: row.timestamp_iso8601(),
+            window_start: row.window_start_iso8601(),
+            window_end: row.timestamp_iso8601(),
+            exchange_rate: row.rate_string(),
+            coverage: coverage(row, *transaction_timestamp, gaps).to_string(),
+            revision: row.revision,
+            computed_at: row.computed_iso8601(),
+            lineage: lineage.note(row),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 struct SuccessfulAsset {
     asset: ValidatedHistoryAsset,
+    trust_tier: String,
+    issuer_risk: serde_json::Value,
     transaction_rates: Vec<TransactionExchangeRate>,
     unrealized_rate: TransactionExchangeRate,
 }
@@ -95,7 +195,19 @@ type AssetHistoryResult = Result<SuccessfulAsset, FailedAsset>;
 ///
 /// For each asset, returns exchange rates at specified transaction dates and
 /// a final rate, useful for calculating unrealized gains in accounting reports.
-/// The final date must be after all transaction dates.
+/// The final date must be after all transaction dates. The rate window applies
+/// to every asset in the request and defaults to one hour. Each rate says
+/// whether it comes from the window right before the date, or whether the
+/// later windows had no trades or fell within an ingestion gap. The latest
+/// revision of each rate is used, unless `as_of` asks for the rates as they
+/// were known at a past time.
+///
+/// If the asset was migrated from or to another one, e.g. after its anchor
+/// moved it to a new issuer, the rates of the other asset are used on the other
+/// side of the migration, converted with the ratio between them. Chains of
+/// migrations are followed up to a set number of links each way. Those rates
+/// name the asset they come from in `lineage`, with the ratio to the
+/// requested asset.
 ///
 /// # Request Format
 /// ```json
@@ -107,7 +219,9 @@ type AssetHistoryResult = Result<SuccessfulAsset, FailedAsset>;
 ///         },
 ///         "transaction_dates": ["2024-01-01T12:00:00", "2024-01-02T12:00:00"],
 ///         "unrealized_date": "2024-01-03T12:00:00"
-///     }]
+///     }],
+///     "interval": "optional_window",          // "5m", "1h" (default) or "1d"
+///     "as_of": "optional_ISO8601_timestamp"   // rates as they were known then
 /// }
 /// ```
 ///
@@ -122,19 +236,40 @@ type AssetHistoryResult = Result<SuccessfulAsset, FailedAsset>;
 ///                 "asset_code": "XLM",
 ///                 "asset_issuer": "Native"
 ///             },
+///             "trust_tier": "native",
+///             "issuer_risk": null,        // as in get_exchange_rate
 ///             "transaction_rates": [{
 ///                 "transaction_date": "2024-01-01T12:00:00",
-///                 "exchange_rate_date": "2024-01-01T11:55:33",
-///                 "exchange_rate": "0.12345"
+///                 "exchange_rate_date": "2024-01-01T11:00:00",
+///                 "window_start": "2024-01-01T10:00:00",
+///                 "window_end": "2024-01-01T11:00:00",
+///                 "exchange_rate": "0.12345",
+///                 "coverage": "complete",
+///                 "revision": 0,
+///                 "computed_at": "2024-01-01T11:00:02",
+///                 "lineage": null         // or the asset the rate comes from,
+///                                         // with "since" and "ratio"
 ///             }, {
 ///                 "transaction_date": "2024-01-02T12:00:00",
-///                 "exchange_rate_date": "2024-01-02T11:04:33",
-///                 "exchange_rate": "0.13370"
+///                 "exchange_rate_date": "2024-01-02T11:00:00",
+///                 "window_start": "2024-01-02T10:00:00",
+///                 "window_end": "2024-01-02T11:00:00",
+///                 "exchange_rate": "0.13370",
+///                 "coverage": "complete",
+///                 "revision": 0,
+///                 "computed_at": "2024-01-02T11:00:05",
+///                 "lineage": null
 ///             }],
 ///             "unrealized_rate": {
 ///                 "transaction_date": "2024-01-03T12:00:00",
-///                 "exchange_rate_date": "2024-01-03T11:30:33",
-///                 "exchange_rate": "0.14159"
+///                 "exchange_rate_date": "2024-01-03T11:00:00",
+///                 "window_start": "2024-01-03T10:00:00",
+///                 "window_end": "2024-01-03T11:00:00",
+///                 "exchange_rate": "0.14159",
+///                 "coverage": "complete",
+///                 "revision": 0,
+///                 "computed_at": "2024-01-03T11:00:01",
+///                 "lineage": null
 ///             }
 ///         }],
 ///         "failed_assets": []
@@ -143,7 +278,7 @@ type AssetHistoryResult = Result<SuccessfulAsset, FailedAsset>;
 /// ```
 ///
 /// # Errors
-/// - 400: Empty request, missing transactions, invalid date format, invalid date order, or missing issuer
+/// - 400: Empty request, missing transactions, invalid date format, invalid date order, invalid interval, or missing issuer
 /// - 404: No exchange rates found
 /// - 500: Database error
 #[no_mangle]
@@ -160,10 +295,14 @@ pub extern "C" fn get_exchange_rate_history() {
 }
 
 fn handle_request(request: &HistoryRequest) -> Result<Vec<AssetHistoryResult>, ExchangeRateError> {
-    validate_request(request)?
+    let validated_request = validate_request(request)?;
+    let gaps = read_gaps();
+    let links = read_links(&EnvClient::empty());
+
+    validated_request
         .assets
         .iter()
-        .map(process_asset)
+        .map(|asset| process_asset(asset, &validated_request, &gaps, &links))
         .collect()
 }
 
@@ -182,6 +321,8 @@ fn validate_request(
 
     Ok(ValidatedHistoryAssetsWithTimestamps {
         assets: validated_assets,
+        interval: parse_interval(&request.interval)?,
+        as_of: parse_optional_timestamp(&request.as_of)?,
     })
 }
 
@@ -216,8 +357,17 @@ fn validate_asset(
 
 fn process_asset(
     asset: &HistoryTransactionsTimestamps,
+    request: &ValidatedHistoryAssetsWithTimestamps,
+    gaps: &[Gap],
+    links: &[AssetLink],
 ) -> Result<AssetHistoryResult, ExchangeRateError> {
-    let db_rows = query_database_for_one_asset(asset)?;
+    let lineage = Lineage::new(links, &asset.asset.asset_code, &asset.asset.asset_issuer);
+    let db_rows = follow_lineage(
+        query_database_for_one_asset(&asset.asset, asset, request)?,
+        &lineage,
+        asset,
+        request,
+    )?;
 
     if db_rows.is_empty() {
         return Err(ExchangeRateError::NotFound(format!(
@@ -236,18 +386,85 @@ fn process_asset(
         }));
     }
 
-    Ok(Ok(transaction_exchange_rates(asset, db_rows)?))
+    Ok(Ok(transaction_exchange_rates(
+        asset, db_rows, gaps, &lineage,
+    )?))
 }
 
 fn query_database_for_one_asset(
+    rated_asset: &ValidatedHistoryAsset,
     asset: &HistoryTransactionsTimestamps,
+    request: &ValidatedHistoryAssetsWithTimestamps,
 ) -> Result<Vec<RatesDbRow>, ExchangeRateError> {
     let client = EnvClient::empty();
     let mut query = client.read_filter();
-    query.column_equal_to("floatcode", asset.asset.asset_code.clone());
-    query.column_equal_to("fltissuer", asset.asset.asset_issuer.clone());
+    query.column_equal_to("floatcode", rated_asset.asset_code.clone());
+    query.column_equal_to("fltissuer", rated_asset.asset_issuer.clone());
+    query.column_equal_to("interval", request.interval);
 
-    query_db(query, asset.unrealized_timestamp)
+    query_db(query, asset.unrealized_timestamp, request.as_of)
+}
+
+/**
+ * Across a migration, the rates of the asset on the other side stand in for
+ * those of the requested one, converted with the ratio between them. Rates
+ * are stamped with the end of their window, so the windows that ended by the
+ * migration belong to the previous asset. Each link of a chain takes over
+ * from the one nearer to the requested asset.
+ */
+fn follow_lineage(
+    rows: Vec<RatesDbRow>,
+    lineage: &Lineage,
+    asset: &HistoryTransactionsTimestamps,
+    request: &ValidatedHistoryAssetsWithTimestamps,
+) -> Result<Vec<RatesDbRow>, ExchangeRateError> {
+    let mut rows = rows;
+
+    for Hop { link, ratio } in &lineage.predecessors {
+        let previous_asset = ValidatedHistoryAsset {
+            asset_code: link.prevcode.clone(),
+            asset_issuer: link.previssr.clone(),
+        };
+        // Rates are units of the asset per dollar, so a redenomination
+        // multiplies them
+        let previous_rows = query_database_for_one_asset(&previous_asset, asset, request)?
+            .into_iter()
+            .filter(|row| row.timestamp <= link.since)
+            .filter_map(|row| {
+                Some(RatesDbRow {
+                    fixedrate: multiply(row.fixedrate, *ratio, RATE_DECIMALS)?,
+                    ..row
+                })
+            });
+
+        rows = previous_rows
+            .chain(rows.into_iter().filter(|row| row.timestamp > link.since))
+            .collect();
+    }
+
+    for Hop { link, ratio } in &lineage.successors {
+        let next_asset = ValidatedHistoryAsset {
+            asset_code: link.nextcode.clone(),
+            asset_issuer: link.nextissr.clone(),
+        };
+        let next_rows = query_database_for_one_asset(&next_asset, asset, request)?
+            .into_iter()
+            .filter(|row| row.timestamp > link.since)
+            .filter_map(|row| {
+                Some(RatesDbRow {
+                    fixedrate: divide(row.fixedrate, *ratio, RATE_DECIMALS)?,
+                    ..row
+                })
+            });
+
+        rows = rows
+            .into_iter()
+            .filter(|row| row.timestamp <= link.since)
+            .chain(next_rows)
+            .collect();
+    }
+
+    Ok(rows)
 }
 
 fn row_timestamp(row: &RatesDbRow) -> Result<i64, ExchangeRateError> {
@@ -259,17 +476,25 @@ fn row_timestamp(row: &RatesDbRow) -> Result<i64, ExchangeRateError> {
 fn transaction_exchange_rates(
     asset: &HistoryTransactionsTimestamps,
     db_rows: Vec<RatesDbRow>,
+    gaps: &[Gap],
+    lineage: &Lineage,
 ) -> Result<SuccessfulAsset, ExchangeRateError> {
-    let unrealized_rate = TransactionExchangeRate {
-        transaction_date: parse_date(&asset.unrealized_timestamp),
-        exchange_rate_date: db_rows.last().unwrap().timestamp_iso8601(),
-        exchange_rate: db_rows.last().unwrap().rate.to_string(),
-    };
+    let unrealized_rate = TransactionExchangeRate::new(
+        &asset.unrealized_timestamp,
+        db_rows.last().unwrap(),
+        gaps,
+        lineage,
+    );
 
-    let transaction_rates = build_transaction_rates(&asset.transaction_timestamps, db_rows);
+    let transaction_rates =
+        build_transaction_rates(&asset.transaction_timestamps, db_rows, gaps, lineage);
 
     Ok(SuccessfulAsset {
         asset: asset.asset.clone(),
+        trust_tier: TrustTier::of(&asset.asset.asset_code, &asset.asset.asset_issuer)
+            .name()
+            .to_string(),
+        issuer_risk: issuer_risk(&asset.asset.asset_code, &asset.asset.asset_issuer),
         transaction_rates,
         unrealized_rate,
     })
@@ -278,6 +503,8 @@ fn transaction_exchange_rates(
 fn build_transaction_rates(
     transaction_timestamps: &[i64],
     db_rows: Vec<RatesDbRow>,
+    gaps: &[Gap],
+    lineage: &Lineage,
 ) -> Vec<TransactionExchangeRate> {
     // We have tested before; if the first row is higher than the first
     // transaction, that is an error. So we don't need to check it again.
@@ -297,11 +524,12 @@ fn build_transaction_rates(
 
             let last_row_before_transaction = &db_rows[possibly_too_late_row_index - 1];
 
-            let exchange_rate = TransactionExchangeRate {
-                transaction_date: parse_date(transaction_timestamp),
-                exchange_rate_date: last_row_before_transaction.timestamp_iso8601(),
-                exchange_rate: last_row_before_transaction.rate.to_string(),
-            };
+            let exchange_rate = TransactionExchangeRate::new(
+                transaction_timestamp,
+                last_row_before_transaction,
+                gaps,
+                lineage,
+            );
 
             acc.push(exchange_rate);
             acc
@@ -338,12 +566,22 @@ fn build_error_response(error: ExchangeRateError) -> serde_json::Value {
             400,
             "Invalid date order. The last transaction date must be earlier than the unrealized gains date.",
         ),
+        ExchangeRateError::InvalidInterval(interval) => (400, &*interval_error_message(&interval)),
         ExchangeRateError::MissingIssuer(asset) => (
             400,
             &*format!("Missing issuer. Must provide an issuer for the asset {asset}."),
         ),
         ExchangeRateError::NotFound(object) => (404, &*format!("No {object} found.")),
+        ExchangeRateError::IngestionGap(gap) => (
+            404,
+            &*format!("No exchange rate found, because {gap}."),
+        ),
         ExchangeRateError::DatabaseError => (500, "An error occurred while querying the database."),
+        // Issuers are required here, so impersonators are never left out, and
+        // no minimum tier is taken.
+        ExchangeRateError::Impersonation(_)
+        | ExchangeRateError::InvalidTier(_)
+        | ExchangeRateError::InvalidTolerance(_) => unreachable!(),
     };
 
     serde_json::json!({
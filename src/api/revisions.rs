@@ -0,0 +1,167 @@
+#![warn(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use zephyr_sdk::EnvClient;
+
+use super::{
+    shared::{
+        interval_error_message, normalize_issuer, parse_interval, parse_timestamp,
+        ExchangeRateError, NormalizeAssetIssuer,
+    },
+    RatesDbRow,
+};
+
+#[derive(Deserialize, Serialize)]
+struct RevisionsRequest {
+    asset_code: String,
+    asset_issuer: Option<String>,
+    date: String,
+    interval: Option<String>,
+}
+
+impl NormalizeAssetIssuer for RevisionsRequest {
+    fn normalize_issuer(&self) -> Option<String> {
+        normalize_issuer(&self.asset_code, &self.asset_issuer)
+    }
+}
+
+/// Lists every revision of the exchange rate of an asset in one window.
+///
+/// This is the audit trail for corrections: revision 0 is the rate computed
+/// when the window ended, and each replay adds a revision with the time it was
+/// computed and the algorithm that produced it. The window is the one that
+/// contains the given date.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "asset_code": "XLM",
+///     "asset_issuer": "optional_issuer",
+///     "date": "2024-01-01T10:30:00",
+///     "interval": "optional_window"  // "5m", "1h" (default) or "1d"
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": [{
+///         "asset_code": "XLM",
+///         "asset_issuer": "Native",
+///         "window_start": "2024-01-01T10:00:00",
+///         "window_end": "2024-01-01T11:00:00",
+///         "revisions": [{
+///             "revision": 0,
+///             "computed_at": "2024-01-01T11:00:02",
+///             "algorithm": "volume_weighted/1",
+///             "exchange_rate": "NaN",
+///             "volume": "0"
+///         }, {
+///             "revision": 1,
+///             "computed_at": "2024-02-10T09:12:45",
+///             "algorithm": "volume_weighted/2",
+///             "exchange_rate": "8.1234",
+///             "volume": "1520.5"
+///         }]
+///     }]
+/// }
+/// ```
+///
+/// # Errors
+/// - 400: Invalid date format or interval
+/// - 404: No exchange rate found
+/// - 500: Database error
+#[no_mangle]
+pub extern "C" fn get_rate_revisions() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<RevisionsRequest>();
+
+    let response = match handle_request(&client, &request) {
+        Ok(data) => build_ok_response(data),
+        Err(error) => build_error_response(error),
+    };
+
+    client.conclude(&response);
+}
+
+fn handle_request(
+    client: &EnvClient,
+    request: &RevisionsRequest,
+) -> Result<Vec<Vec<RatesDbRow>>, ExchangeRateError> {
+    let timestamp = parse_timestamp(&request.date)?;
+    let interval = parse_interval(&request.interval)?;
+    let window_end = (timestamp as u64 / interval + 1) * interval;
+
+    let mut query = client.read_filter();
+    query.column_equal_to("floatcode", request.asset_code.clone());
+    query.column_equal_to("interval", interval);
+    query.column_equal_to("timestamp", window_end);
+
+    if let Some(issuer) = request.normalize_issuer() {
+        query.column_equal_to("fltissuer", issuer);
+    }
+
+    let mut rows = query
+        .read::<RatesDbRow>()
+        .map_err(|_| ExchangeRateError::DatabaseError)?;
+
+    if rows.is_empty() {
+        return Err(ExchangeRateError::NotFound("exchange rate".to_string()));
+    }
+
+    rows.sort_by_key(|row| (row.fltissuer.clone(), row.revision));
+
+    // One group of revisions per issuer
+    Ok(rows
+        .chunk_by(|a, b| a.fltissuer == b.fltissuer)
+        .map(|revisions| revisions.to_vec())
+        .collect())
+}
+
+fn build_ok_response(data: Vec<Vec<RatesDbRow>>) -> serde_json::Value {
+    serde_json::json!({
+        "status": 200,
+        "data": data.into_iter().map(|revisions| {
+            let first = &revisions[0];
+
+            serde_json::json!({
+                "asset_code": first.floatcode,
+                "asset_issuer": first.fltissuer,
+                "window_start": first.window_start_iso8601(),
+                "window_end": first.timestamp_iso8601(),
+                "revisions": revisions.iter().map(|row| {
+                    serde_json::json!({
+                        "revision": row.revision,
+                        "computed_at": row.computed_iso8601(),
+                        "algorithm": row.algo,
+                        "exchange_rate": row.rate_string(),
+                        "volume": row.volume_string(),
+                    })
+                }).collect::<Vec<_>>(),
+            })
+        }).collect::<Vec<_>>(),
+    })
+}
+
+fn build_error_response(error: ExchangeRateError) -> serde_json::Value {
+    let (status, message) = match error {
+        ExchangeRateError::InvalidDate => (
+            400,
+            "Invalid date format. Please use the format '2020-09-16T14:30:00'.",
+        ),
+        ExchangeRateError::InvalidInterval(interval) => (400, &*interval_error_message(&interval)),
+        ExchangeRateError::NotFound(object) => (404, &*format!("No {object} found.")),
+        ExchangeRateError::DatabaseError => (500, "An error occurred while querying the database."),
+        // Other error types can only happen in the other exchange rate endpoints.
+        _ => unreachable!(),
+    };
+
+    serde_json::json!({
+        "status": status,
+        "data": {
+            "error": message,
+        },
+    })
+}
@@ -0,0 +1,70 @@
+#![warn(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use zephyr_sdk::EnvClient;
+
+use crate::db::scam::scam_entry;
+
+#[derive(Deserialize, Serialize)]
+struct ScamCheckRequest {
+    asset_issuer: String,
+}
+
+/// Tells whether an issuer is flagged as a scam.
+///
+/// Swaps of assets from flagged issuers are never indexed. The response says
+/// where the flag comes from and when it was last added or removed; issuers
+/// from the compiled list have no dates. Issuers we know nothing about are
+/// reported as not flagged, with no source.
+///
+/// # Request Format
+/// ```json
+/// {
+///     "asset_issuer": "GA223OFHVKVAH2NBXP4AURJRVJTSOVHGBMKJNL6GRJWNN4SARVGSITYG"
+/// }
+/// ```
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "asset_issuer": "GA223OFHVKVAH2NBXP4AURJRVJTSOVHGBMKJNL6GRJWNN4SARVGSITYG",
+///         "flagged": true,
+///         "reason": "Flagged as malicious",
+///         "source": "StellarExpert",
+///         "added": "2025-01-10T12:00:00",
+///         "removed": null
+///     }
+/// }
+/// ```
+#[no_mangle]
+pub extern "C" fn get_scam_status() {
+    let client = EnvClient::empty();
+    let request = client.read_request_body::<ScamCheckRequest>();
+
+    let data = match scam_entry(&request.asset_issuer) {
+        Some(entry) => serde_json::json!({
+            "asset_issuer": entry.issuer,
+            "flagged": entry.flagged,
+            "reason": entry.reason,
+            "source": entry.source,
+            "added": entry.added_iso8601(),
+            "removed": entry.removed_iso8601(),
+        }),
+        None => serde_json::json!({
+            "asset_issuer": request.asset_issuer,
+            "flagged": false,
+            "reason": null,
+            "source": null,
+            "added": null,
+            "removed": null,
+        }),
+    };
+
+    client.conclude(serde_json::json!({
+        "status": 200,
+        "data": data,
+    }));
+}
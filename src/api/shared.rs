@@ -1,7 +1,16 @@
 use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
-use zephyr_sdk::prelude::TableQueryWrapper;
+use zephyr_sdk::{prelude::TableQueryWrapper, EnvClient};
 
 use super::RatesDbRow;
+use crate::{
+    config::{DEFAULT_RATE_INTERVAL, RATE_INTERVALS},
+    db::{
+        exchange_rate::published_rates,
+        ingestion::Gap,
+        risk::{asset_risk, AssetRisk},
+    },
+    utils::{parse_date, window_end},
+};
 
 #[derive(Debug)]
 pub(super) enum ExchangeRateError {
@@ -10,6 +19,11 @@ pub(super) enum ExchangeRateError {
     EmptyRequest,
     InvalidDate,
     InvalidDateOrder,
+    IngestionGap(String),
+    Impersonation(String),
+    InvalidInterval(String),
+    InvalidTier(String),
+    InvalidTolerance(String),
     MissingIssuer(String),
     NotFound(String),
 }
@@ -28,6 +42,12 @@ pub(super) fn normalize_issuer(asset_code: &str, asset_issuer: &Option<String>)
     }
 }
 
+pub(super) fn parse_optional_timestamp(
+    date_str: &Option<String>,
+) -> Result<Option<i64>, ExchangeRateError> {
+    date_str.as_deref().map(parse_timestamp).transpose()
+}
+
 pub(super) fn parse_timestamp(date_str: &str) -> Result<i64, ExchangeRateError> {
     Ok(PrimitiveDateTime::parse(date_str, &Iso8601::DEFAULT)
         .map_err(|_| ExchangeRateError::InvalidDate)?
@@ -35,20 +55,283 @@ pub(super) fn parse_timestamp(date_str: &str) -> Result<i64, ExchangeRateError>
         .unix_timestamp())
 }
 
+// Requests name a rate window such as "1h"; we fall back to the default one.
+pub(super) fn parse_interval(interval: &Option<String>) -> Result<u64, ExchangeRateError> {
+    match interval {
+        Some(name) => RATE_INTERVALS
+            .iter()
+            .find(|(interval_name, _)| interval_name == name)
+            .map(|(_, length)| *length)
+            .ok_or(ExchangeRateError::InvalidInterval(name.clone())),
+        None => Ok(DEFAULT_RATE_INTERVAL),
+    }
+}
+
+pub(super) fn interval_error_message(interval: &str) -> String {
+    let names = RATE_INTERVALS
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("Invalid interval '{interval}'. Please use one of: {names}.")
+}
+
 pub(super) fn query_db(
     mut query: TableQueryWrapper,
     timestamp: i64,
+    as_of: Option<i64>,
 ) -> Result<Vec<RatesDbRow>, ExchangeRateError> {
-    let mut rows = query
+    let rows = query
         .column_lt("timestamp", timestamp)
         .read::<RatesDbRow>()
-        .map_err(|_| ExchangeRateError::DatabaseError)?
-        .into_iter()
-        // Maybe it was some blunder during development, but some NaN exchange
-        // rates crept into the DB. We filter them out.
-        .filter(|row| row.rate.to_string() != "NaN")
-        .collect::<Vec<RatesDbRow>>();
+        .map_err(|_| ExchangeRateError::DatabaseError)?;
+    let mut rows = published_rates(rows, as_of.map(|as_of| as_of as u64));
 
     rows.sort_by_key(|row| row.timestamp);
     Ok(rows)
 }
+
+pub(super) fn read_gaps() -> Vec<Gap> {
+    EnvClient::empty().read::<Gap>()
+}
+
+// Rates are only looked up in windows that ended strictly before a timestamp,
+// so this is the window we would ideally find a rate in.
+pub(super) fn expected_window_end(timestamp: i64, interval: u64) -> u64 {
+    window_end((timestamp as u64).saturating_sub(1), interval)
+}
+
+// Tells whether a rate comes from the window right before the timestamp and,
+// if not, whether the windows since then are missing because we did not
+// ingest them or because there were no trades.
+pub(super) fn coverage(row: &RatesDbRow, timestamp: i64, gaps: &[Gap]) -> &'static str {
+    let window_end = expected_window_end(timestamp, row.interval);
+
+    if row.timestamp >= window_end {
+        "complete"
+    } else if gaps
+        .iter()
+        .any(|gap| gap.overlaps(row.timestamp, window_end))
+    {
+        "ingestion_gap"
+    } else {
+        "no_trades"
+    }
+}
+
+// When there is no rate at all for a timestamp, we check whether the window
+// we would have used falls within an ingestion gap.
+pub(super) fn missing_rate_error(timestamp: i64, interval: u64, gaps: &[Gap]) -> ExchangeRateError {
+    let window_end = expected_window_end(timestamp, interval);
+
+    gaps.iter()
+        .find(|gap| gap.overlaps(window_end.saturating_sub(interval), window_end))
+        .map_or(
+            ExchangeRateError::NotFound("exchange rate".to_string()),
+            |gap| {
+                let first = gap
+                    .first_ledger()
+                    .map_or("?".to_string(), |first| first.to_string());
+                ExchangeRateError::IngestionGap(format!(
+                    "ledgers {first} to {} ({} to {}) were not ingested",
+                    gap.lastseq,
+                    parse_date(&(gap.gapstart as i64)),
+                    parse_date(&(gap.gapend as i64)),
+                ))
+            },
+        )
+}
+
+// What the issuer of a registered asset can do, and has done, to its holders.
+// Other assets are not followed, so their risk is null rather than clean.
+pub(super) fn issuer_risk(code: &str, issuer: &str) -> serde_json::Value {
+    risk_json(asset_risk(code, issuer))
+}
+
+fn risk_json(risk: Option<&AssetRisk>) -> serde_json::Value {
+    risk.map_or(serde_json::Value::Null, |risk| {
+        serde_json::json!({
+            "auth_required": risk.auth_required(),
+            "auth_revocable": risk.auth_revocable(),
+            "auth_immutable": risk.auth_immutable(),
+            "clawback_enabled": risk.clawback_enabled(),
+            "flags_changed_at": risk.flags_changed_iso8601(),
+            "clawbacks": risk.clawbacks,
+            "last_clawback": risk.last_clawback_iso8601(),
+            "freezes": risk.freezes,
+            "last_freeze": risk.last_freeze_iso8601(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 3600;
+    const MIDNIGHT: u64 = 1_734_307_200;
+
+    fn rate(timestamp: u64) -> RatesDbRow {
+        RatesDbRow {
+            timestamp,
+            floatcode: "AQUA".to_string(),
+            fltissuer: "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA".to_string(),
+            fixedrate: 1,
+            fixedvol: 1,
+            interval: HOUR,
+            winstart: timestamp - HOUR,
+            revision: 0,
+            computed: timestamp,
+            algo: "volume_weighted/2".to_string(),
+        }
+    }
+
+    fn gap(gapstart: u64, gapend: u64) -> Gap {
+        Gap {
+            firstseq: 1000,
+            lastseq: 1999,
+            gapstart,
+            gapend,
+        }
+    }
+
+    #[test]
+    fn a_rate_from_the_window_before_the_timestamp_is_complete() {
+        let timestamp = (MIDNIGHT + 2 * HOUR + 60) as i64;
+
+        assert_eq!(
+            coverage(&rate(MIDNIGHT + 2 * HOUR), timestamp, &[]),
+            "complete"
+        );
+        // A timestamp on a boundary asks for the window that ends on it
+        assert_eq!(
+            coverage(
+                &rate(MIDNIGHT + 2 * HOUR),
+                (MIDNIGHT + 2 * HOUR) as i64,
+                &[]
+            ),
+            "complete"
+        );
+    }
+
+    #[test]
+    fn an_older_rate_tells_a_gap_from_a_lack_of_trades() {
+        let timestamp = (MIDNIGHT + 3 * HOUR + 60) as i64;
+        let row = rate(MIDNIGHT + HOUR);
+
+        assert_eq!(coverage(&row, timestamp, &[]), "no_trades");
+        assert_eq!(
+            coverage(
+                &row,
+                timestamp,
+                &[gap(MIDNIGHT + HOUR + 60, MIDNIGHT + 2 * HOUR)]
+            ),
+            "ingestion_gap"
+        );
+        // Gaps outside the missing windows do not count
+        assert_eq!(
+            coverage(&row, timestamp, &[gap(MIDNIGHT, MIDNIGHT + HOUR)]),
+            "no_trades"
+        );
+        assert_eq!(
+            coverage(
+                &row,
+                timestamp,
+                &[gap(MIDNIGHT + 3 * HOUR, MIDNIGHT + 4 * HOUR)]
+            ),
+            "no_trades"
+        );
+    }
+
+    #[test]
+    fn a_missing_rate_in_a_gap_names_the_ledgers() {
+        let timestamp = (MIDNIGHT + 2 * HOUR + 60) as i64;
+
+        let error = missing_rate_error(
+            timestamp,
+            HOUR,
+            &[gap(MIDNIGHT + HOUR + 60, MIDNIGHT + 2 * HOUR)],
+        );
+        let ExchangeRateError::IngestionGap(message) = error else {
+            panic!("expected an ingestion gap, got {error:?}");
+        };
+        assert!(message.starts_with("ledgers 1000 to 1999 ("));
+
+        let error = missing_rate_error(
+            timestamp,
+            HOUR,
+            &[Gap {
+                firstseq: 0,
+                ..gap(MIDNIGHT + HOUR, MIDNIGHT + 3 * HOUR)
+            }],
+        );
+        let ExchangeRateError::IngestionGap(message) = error else {
+            panic!("expected an ingestion gap, got {error:?}");
+        };
+        assert!(message.starts_with("ledgers ? to 1999 ("));
+    }
+
+    #[test]
+    fn a_missing_rate_next_to_a_gap_is_not_found() {
+        let timestamp = (MIDNIGHT + 2 * HOUR + 60) as i64;
+        let gaps = [
+            gap(MIDNIGHT, MIDNIGHT + HOUR),
+            gap(MIDNIGHT + 2 * HOUR, MIDNIGHT + 3 * HOUR),
+        ];
+
+        assert!(matches!(
+            missing_rate_error(timestamp, HOUR, &gaps),
+            ExchangeRateError::NotFound(_)
+        ));
+    }
+
+    fn risk(flags: u32, clawbacks: u64, lastclawb: u64) -> AssetRisk {
+        AssetRisk {
+            floatcode: "USDC".to_string(),
+            fltissuer: "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
+            flags,
+            flagsat: 0,
+            clawbacks,
+            lastclawb,
+            freezes: 0,
+            lastfreez: 0,
+        }
+    }
+
+    #[test]
+    fn assets_that_are_not_followed_have_no_issuer_risk() {
+        assert_eq!(risk_json(None), serde_json::Value::Null);
+    }
+
+    #[test]
+    fn issuer_risk_decodes_the_account_flags() {
+        let clean = risk_json(Some(&risk(0, 0, 0)));
+        assert_eq!(
+            clean,
+            serde_json::json!({
+                "auth_required": false,
+                "auth_revocable": false,
+                "auth_immutable": false,
+                "clawback_enabled": false,
+                "flags_changed_at": null,
+                "clawbacks": 0,
+                "last_clawback": null,
+                "freezes": 0,
+                "last_freeze": null,
+            })
+        );
+
+        let revocable = risk_json(Some(&risk(0x2 | 0x8, 3, MIDNIGHT)));
+        assert_eq!(revocable["auth_required"], false);
+        assert_eq!(revocable["auth_revocable"], true);
+        assert_eq!(revocable["auth_immutable"], false);
+        assert_eq!(revocable["clawback_enabled"], true);
+        assert_eq!(revocable["clawbacks"], 3);
+        assert_eq!(revocable["last_clawback"], parse_date(&(MIDNIGHT as i64)));
+        assert_eq!(
+            risk_json(Some(&risk(0x1 | 0x4, 0, 0)))["auth_immutable"],
+            true
+        );
+    }
+}
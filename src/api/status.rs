@@ -0,0 +1,215 @@
+#![warn(missing_docs)]
+
+use std::collections::{BTreeMap, HashSet};
+
+use zephyr_sdk::EnvClient;
+
+use crate::{
+    config::{config_hash, DEFAULT_RATE_INTERVAL, RATE_INTERVALS, VERSION},
+    db::{
+        exchange_rate::{published_rates, RatesDbRow},
+        ingestion::{Gap, LastLedger},
+        journal::JournalEntry,
+        savepoint::active_savepoints,
+    },
+    utils::{interval_name, parse_date},
+};
+
+/// Reports the health of the indexer, so that monitors can alert on stalls.
+///
+/// Functions do not run on a ledger close, so ages are measured from the
+/// close time of the last ingested ledger; monitors compare that with the
+/// wall clock. For each rate window, the savepoint is the end of the last
+/// window whose rates were saved, and the current window began then. Swaps
+/// are counted from the ingestion journal, which only keeps its latest
+/// entries, and assets are counted by their published rates in the last saved
+/// window. A gap across a force-deploy has no known first ledger, and makes
+/// the number of ledgers missed unknown too. Every field that depends on a
+/// missing table is null rather than an error.
+///
+/// # Request Format
+/// No parameters.
+///
+/// # Response Format
+/// On success (status 200):
+/// ```json
+/// {
+///     "status": 200,
+///     "data": {
+///         "version": "0.1.1",
+///         "config_hash": "3f0a…",
+///         "default_interval": "1h",
+///         "last_ledger": {
+///             "sequence": 54983312,
+///             "close_time": "2024-12-16T03:02:11"
+///         },
+///         "windows": [{
+///             "interval": "1h",
+///             "savepoint": "2024-12-16T03:00:00",
+///             "current_window_age": 131,           // seconds
+///             "assets_priced": 42,                 // in the last saved window
+///             "swaps_by_venue": {                  // in the current window
+///                 "liquidity_pool": 12,
+///                 "order_book": 87,
+///                 "soroswap": 3
+///             }
+///         }],
+///         "gaps": {
+///             "count": 1,
+///             "ledgers": 17,
+///             "latest": {
+///                 "first_ledger": 54980001,
+///                 "last_ledger": 54980017,
+///                 "start": "2024-12-15T22:41:05",
+///                 "end": "2024-12-15T22:42:41"
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[no_mangle]
+pub extern "C" fn get_status() {
+    let client = EnvClient::empty();
+
+    let last_ledger = client
+        .read::<LastLedger>()
+        .into_iter()
+        .max_by_key(|ledger| ledger.sequence);
+    let latest_close = last_ledger.as_ref().map_or(0, |ledger| ledger.closetime);
+    let savepoints = active_savepoints(&client);
+
+    // One read of the journal covers the current window of every length
+    let earliest_savepoint = savepoints.iter().map(|savepoint| savepoint.savepoint).min();
+    let accepted_swaps = earliest_savepoint.map_or(vec![], |since| {
+        client
+            .read_filter()
+            .column_equal_to("outcome", "accepted".to_string())
+            .column_gt("closetime", since.saturating_sub(1))
+            .read::<JournalEntry>()
+            .unwrap_or_default()
+    });
+
+    let windows = RATE_INTERVALS
+        .iter()
+        .map(|(name, interval)| {
+            let savepoint = savepoints
+                .iter()
+                .find(|savepoint| savepoint.interval == *interval)
+                .map(|savepoint| savepoint.savepoint);
+            let age = savepoint.map(|savepoint| latest_close.saturating_sub(savepoint));
+            let assets =
+                savepoint.and_then(|savepoint| assets_priced(&client, *interval, savepoint));
+
+            serde_json::json!({
+                "interval": name,
+                "savepoint": savepoint.map(|savepoint| parse_date(&(savepoint as i64))),
+                "current_window_age": age,
+                "assets_priced": assets,
+                "swaps_by_venue": savepoint.map(|savepoint| {
+                    accepted_swaps
+                        .iter()
+                        .filter(|entry| entry.closetime >= savepoint)
+                        .fold(BTreeMap::new(), |mut counts, entry| {
+                            *counts.entry(entry.venue.as_str()).or_insert(0_u64) += 1;
+                            counts
+                        })
+                }),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let gaps = client.read::<Gap>();
+    let latest_gap = gaps.iter().max_by_key(|gap| gap.lastseq);
+    // Gaps across a force-deploy do not say where they start
+    let ledgers_missed = gaps
+        .iter()
+        .map(|gap| {
+            gap.first_ledger()
+                .map(|first| gap.lastseq.saturating_sub(first) + 1)
+        })
+        .sum::<Option<u64>>();
+
+    client.conclude(serde_json::json!({
+        "status": 200,
+        "data": {
+            "version": VERSION,
+            "config_hash": config_hash(),
+            "default_interval": interval_name(DEFAULT_RATE_INTERVAL),
+            "last_ledger": last_ledger.map(|ledger| serde_json::json!({
+                "sequence": ledger.sequence,
+                "close_time": parse_date(&(ledger.closetime as i64)),
+            })),
+            "windows": windows,
+            "gaps": {
+                "count": gaps.len(),
+                "ledgers": ledgers_missed,
+                "latest": latest_gap.map(|gap| serde_json::json!({
+                    "first_ledger": gap.first_ledger(),
+                    "last_ledger": gap.lastseq,
+                    "start": parse_date(&(gap.gapstart as i64)),
+                    "end": parse_date(&(gap.gapend as i64)),
+                })),
+            },
+        },
+    }));
+}
+
+// The number of assets with a rate in the window that ended at the savepoint
+fn assets_priced(client: &EnvClient, interval: u64, savepoint: u64) -> Option<usize> {
+    let rows = client
+        .read_filter()
+        .column_equal_to("interval", interval)
+        .column_equal_to("timestamp", savepoint)
+        .read::<RatesDbRow>()
+        .ok()?;
+
+    Some(count_priced_assets(rows))
+}
+
+// Corrections and voided rates keep the rows they supersede, so only the
+// published revision of each asset counts
+fn count_priced_assets(rows: Vec<RatesDbRow>) -> usize {
+    published_rates(rows, None)
+        .iter()
+        .map(|row| (&row.floatcode, &row.fltissuer))
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate(code: &str, revision: u64, fixedrate: i128) -> RatesDbRow {
+        RatesDbRow {
+            timestamp: 1_734_307_200,
+            floatcode: code.to_string(),
+            fltissuer: "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA".to_string(),
+            fixedrate,
+            fixedvol: 1,
+            interval: 3600,
+            winstart: 1_734_303_600,
+            revision,
+            computed: 1_734_307_200 + revision,
+            algo: "volume_weighted/2".to_string(),
+        }
+    }
+
+    #[test]
+    fn revisions_of_an_asset_are_counted_once() {
+        let rows = vec![rate("AQUA", 0, 5), rate("AQUA", 1, 6), rate("yXLM", 0, 7)];
+
+        assert_eq!(count_priced_assets(rows), 2);
+    }
+
+    #[test]
+    fn voided_assets_are_not_priced() {
+        let rows = vec![rate("AQUA", 0, 5), rate("AQUA", 1, 0), rate("yXLM", 0, 0)];
+
+        assert_eq!(count_priced_assets(rows), 0);
+        assert_eq!(
+            count_priced_assets(vec![rate("AQUA", 0, 0), rate("AQUA", 1, 5)]),
+            1
+        );
+    }
+}
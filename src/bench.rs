@@ -0,0 +1,130 @@
+//! Measures the asset checks ingestion runs on each ledger, against the
+//! strkey lookups they replaced.
+//!
+//! Run with `cargo test --release registry_lookups -- --ignored --nocapture`.
+//! A busy ledger is modelled as 300 claim atoms, each with two non-native
+//! assets: one in ten from a scam issuer, one in four registered, and the rest
+//! unknown. The baseline formats each issuer as a strkey, then scans the scam
+//! list and the token list linearly; the current run is the scam list binary
+//! search and the registry lookup by raw issuer ID and code. Both run on the
+//! same ledger against the registries compiled into the program, which is what
+//! the first ledger close sees. Timings on the host only give an idea of the
+//! cost in WebAssembly; compare runs on the same machine.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use stellar_strkey::ed25519::PublicKey;
+
+use crate::{
+    config::{soroswap_tokens::SOROSWAP_TOKENS, SCAM_ACCOUNT_IDS},
+    db::{scam::is_compiled_scam, token::TokenRegistry},
+    utils::decode_asset_code,
+};
+
+const CLAIMS_PER_LEDGER: usize = 300;
+const LEDGERS: usize = 200;
+
+#[test]
+#[ignore = "benchmark, run in release with --ignored --nocapture"]
+fn registry_lookups() {
+    let registry = TokenRegistry::new(SOROSWAP_TOKENS.to_vec(), false);
+    let assets = ledger_assets(&registry);
+
+    // The lists as strings, the way they were compiled in before the lookups
+    // moved to raw IDs
+    let scam_strkeys = scam_account_ids()
+        .map(|account_id| PublicKey(account_id).to_string())
+        .collect::<Vec<_>>();
+    let token_strings = SOROSWAP_TOKENS
+        .iter()
+        .map(|token| (token.code.to_string(), token.issuer.to_string()))
+        .collect::<Vec<_>>();
+
+    let baseline = measure(|| {
+        assets
+            .iter()
+            .filter(|(asset_code, account_id)| {
+                let issuer = PublicKey(*account_id).to_string();
+                !scam_strkeys.contains(&issuer)
+                    && decode_asset_code(asset_code).is_some_and(|code| {
+                        token_strings.iter().any(|(token_code, token_issuer)| {
+                            *token_code == code && *token_issuer == issuer
+                        })
+                    })
+            })
+            .count()
+    });
+    let current = measure(|| {
+        assets
+            .iter()
+            .filter(|(asset_code, account_id)| {
+                !is_compiled_scam(account_id)
+                    && decode_asset_code(asset_code)
+                        .is_some_and(|code| registry.classic_token(account_id, &code).is_some())
+            })
+            .count()
+    });
+
+    println!("Per-ledger cost of the asset checks ({CLAIMS_PER_LEDGER} claims):");
+    println!("  strkeys and linear scans: {baseline:?}");
+    println!("  raw IDs and indices:      {current:?}");
+}
+
+fn measure(lookups: impl Fn() -> usize) -> Duration {
+    let start = Instant::now();
+    for _ in 0..LEDGERS {
+        black_box(lookups());
+    }
+    start.elapsed() / LEDGERS as u32
+}
+
+fn scam_account_ids() -> impl Iterator<Item = [u8; 32]> {
+    SCAM_ACCOUNT_IDS
+        .chunks_exact(32)
+        .filter_map(|account_id| <[u8; 32]>::try_from(account_id).ok())
+}
+
+// Raw asset codes are padded with zeros to 4 or 12 bytes
+fn ledger_assets(registry: &TokenRegistry) -> Vec<(Vec<u8>, [u8; 32])> {
+    let scams = scam_account_ids().collect::<Vec<_>>();
+    let registered = registry
+        .by_issuer_and_code
+        .keys()
+        .map(|(issuer, code)| (code.as_bytes().to_vec(), *issuer))
+        .collect::<Vec<_>>();
+
+    (0..CLAIMS_PER_LEDGER * 2)
+        .map(|index| {
+            let (code, issuer) = if index % 10 == 0 {
+                (b"USDC".to_vec(), scams[index * 7 % scams.len()])
+            } else if index % 4 == 0 {
+                registered[index % registered.len()].clone()
+            } else {
+                (
+                    format!("TKN{}", index % 80).into_bytes(),
+                    issuer(index as u64),
+                )
+            };
+            (padded(code), issuer)
+        })
+        .collect()
+}
+
+fn padded(mut code: Vec<u8>) -> Vec<u8> {
+    code.resize(if code.len() <= 4 { 4 } else { 12 }, 0);
+    code
+}
+
+fn issuer(seed: u64) -> [u8; 32] {
+    let mut account_id = [0; 32];
+    for (index, byte) in account_id.iter_mut().enumerate() {
+        *byte = (seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(index as u64)
+            >> 24) as u8;
+    }
+    account_id
+}
@@ -1,26 +1,169 @@
-pub(crate) mod scam_addresses;
+// The registries are generated by build.rs from the files in `data`
+#[cfg(test)]
+mod registry_data;
 pub(crate) mod soroswap_tokens;
 
-use crate::db::swap::SwapAsset;
+use std::{borrow::Cow, sync::OnceLock};
 
-// On Soroban, every asset needs a contract address - even XLM.
-pub(crate) const XLM_ADDRESS: &str = "CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA";
+use sha2::{Digest, Sha256};
+use zephyr_sdk::soroban_sdk::xdr::Asset;
+
+use crate::{
+    db::{exchange_rate::RateStrategy, swap::SwapAsset},
+    utils::stellar_asset_contract,
+};
+use soroswap_tokens::SOROSWAP_TOKENS;
+
+/**
+ * On Soroban, every asset needs a contract address - even XLM. Like that of
+ * any Stellar Asset Contract, it is derived from the network passphrase, so it
+ * is worked out once per invocation. If it could not be derived, XLM is left
+ * without one, and its Soroban swaps are not recognised.
+ */
+pub(crate) fn xlm() -> &'static SwapAsset {
+    static XLM: OnceLock<SwapAsset> = OnceLock::new();
+
+    XLM.get_or_init(|| SwapAsset {
+        code: Cow::Borrowed("XLM"),
+        issuer: Cow::Borrowed("Native"),
+        contract: Cow::Owned(stellar_asset_contract(&Asset::Native).unwrap_or_default()),
+        decimals: CLASSIC_DECIMALS,
+    })
+}
 
 // We focus on USDC, the most-used stablecoin on the Stellar network.
 pub(crate) const USDC: SwapAsset = SwapAsset {
-    code: "USDC",
-    issuer: "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN",
-    contract: "CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75",
+    code: Cow::Borrowed("USDC"),
+    issuer: Cow::Borrowed("GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"),
+    contract: Cow::Borrowed("CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75"),
+    decimals: CLASSIC_DECIMALS,
+};
+
+// Contract IDs of Stellar Asset Contracts are derived from the network ID.
+// Deployments to another network than the public one must set its passphrase
+// when building.
+pub(crate) const NETWORK_PASSPHRASE: &str = match option_env!("KWICKBIT_NETWORK_PASSPHRASE") {
+    Some(passphrase) => passphrase,
+    None => "Public Global Stellar Network ; September 2015",
+};
+
+/**
+ * Which classic assets we save swaps for. Soroswap swaps always need tokens
+ * from the registry, since their contracts may be anything.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum AssetCoverage {
+    // Only the tokens in the registry
+    Registry,
+    // Any classic asset whose issuer is not flagged as a scam. The API still
+    // tells consumers which assets are certified.
+    Open,
+}
+
+// Open coverage is enabled by setting this variable to any value when building
+pub(crate) const ASSET_COVERAGE: AssetCoverage = match option_env!("KWICKBIT_OPEN_COVERAGE") {
+    Some(_) => AssetCoverage::Open,
+    None => AssetCoverage::Registry,
 };
 
 // We need to know the SoroswapRouter contract address to filter Soroswap swaps
 pub(crate) const SOROSWAP_ROUTER: &str = "CAG5LRYQ5JVEUI5TEID72EYOVX44TTUJT5BQR2J6J77FH65PCCFAJDDH";
 
-// Amounts are represented multiplied by this factor
-pub(crate) const CONVERSION_FACTOR: f64 = 10_000_000.0;
+// Classic assets and their Stellar Asset Contracts always have 7 decimals.
+// Custom Soroban tokens declare their own in their metadata. Volumes are
+// stored in USDC stroops.
+pub(crate) const CLASSIC_DECIMALS: u32 = 7;
 
-// Length of the exchange rate window
+// Exchange rates are stored as integers, multiplied by 10^RATE_DECIMALS
+pub(crate) const RATE_DECIMALS: u32 = 18;
+
+// Lengths of the exchange rate windows
 const MINUTE: u64 = 60;
-const _HOUR: u64 = 60 * MINUTE;
-const _DAY: u64 = 24 * _HOUR;
-pub(crate) const RATE_UPDATE_INTERVAL: u64 = 60 * MINUTE;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+// We maintain several exchange rate windows at once, each with its own
+// savepoint. Rates are labelled in the DB with the length of their window in
+// seconds; the API refers to the windows by these names.
+pub(crate) const RATE_INTERVALS: [(&str, u64); 3] = [("5m", 5 * MINUTE), ("1h", HOUR), ("1d", DAY)];
+
+// The window served by the API when the request does not name one
+pub(crate) const DEFAULT_RATE_INTERVAL: u64 = HOUR;
+
+// How the swaps of a window are distilled into exchange rates
+pub(crate) const DEFAULT_RATE_STRATEGY: RateStrategy = RateStrategy::VolumeWeighted;
+
+// In composite mode, issuers whose rate differs from the median rate of the
+// group by more than this fraction are left out. Scaled like rates: 5%.
+pub(crate) const COMPOSITE_TOLERANCE: i128 = 5 * 10_i128.pow(RATE_DECIMALS - 2);
+
+// After an ingestion gap, we catch up on at most this many windows of each
+// length per ledger close
+pub(crate) const MAX_CATCH_UP_WINDOWS: u64 = 12;
+
+// A replay recomputes at most this many windows per call
+pub(crate) const MAX_REPLAY_WINDOWS: u64 = 500;
+
+// Rate histories follow at most this many links between assets each way
+pub(crate) const MAX_LINEAGE_HOPS: usize = 8;
+
+// An audit without a range covers this much time before the latest ledger
+pub(crate) const AUDIT_RANGE: u64 = DAY;
+
+// Ingestion metrics are added up over windows of this length
+pub(crate) const METRICS_INTERVAL: u64 = 5 * MINUTE;
+
+// Metrics windows are kept this long, after which the series of new windows
+// take over their rows
+pub(crate) const METRICS_RETENTION: u64 = HOUR;
+
+// The ingestion journal keeps this many of the latest swaps it saw, until
+// `set_journal_capacity` stores another capacity in its head row
+pub(crate) const JOURNAL_CAPACITY: u64 = 50_000;
+
+// An import that replaces the list of scam issuers is refused if it would
+// unflag more than this percentage of them, unless it is forced
+pub(crate) const MAX_SCAM_REMOVAL_PERCENT: usize = 10;
+
+// An unregistered asset is established once it has been traded against USDC
+// this many times, over at least this long since it was first seen
+pub(crate) const ESTABLISHED_TRADES: u64 = 100;
+pub(crate) const ESTABLISHED_AGE: u64 = 30 * DAY;
+
+// Administrative functions are only enabled if this key is set when building,
+// and requests to them must include it.
+pub(crate) const ADMIN_KEY: Option<&str> = option_env!("KWICKBIT_ADMIN_KEY");
+
+// The scam issuers from `data/scam_addresses.txt`, as sorted raw 32-byte
+// account IDs
+pub(crate) static SCAM_ACCOUNT_IDS: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/scam_account_ids.bin"));
+
+// The version of the program, as in Cargo.toml
+pub(crate) const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/**
+ * A digest of the settings and registries compiled into the program, so that
+ * monitors can tell two builds of the same version apart. The admin key is
+ * left out; only whether it is set counts.
+ */
+pub(crate) fn config_hash() -> String {
+    let settings = format!(
+        "{ASSET_COVERAGE:?} {RATE_INTERVALS:?} {DEFAULT_RATE_INTERVAL} {} \
+         {COMPOSITE_TOLERANCE} {MAX_CATCH_UP_WINDOWS} {MAX_REPLAY_WINDOWS} {JOURNAL_CAPACITY} \
+         {MAX_LINEAGE_HOPS} {MAX_SCAM_REMOVAL_PERCENT} {ESTABLISHED_TRADES} {ESTABLISHED_AGE} \
+         {METRICS_INTERVAL} {METRICS_RETENTION} {AUDIT_RANGE} {NETWORK_PASSPHRASE} \
+         {SOROSWAP_ROUTER} {} \
+         {SOROSWAP_TOKENS:?}",
+        DEFAULT_RATE_STRATEGY.algorithm(),
+        ADMIN_KEY.is_some(),
+    );
+
+    Sha256::new()
+        .chain_update(settings)
+        .chain_update(SCAM_ACCOUNT_IDS)
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
@@ -0,0 +1,269 @@
+//! Validation of the registry data files, shared by build.rs, which generates
+//! the registries from them, and by the unit tests of the program.
+
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use stellar_strkey::{ed25519::PublicKey, Contract};
+
+pub(crate) const TOKENS_FILE: &str = "data/soroswap_tokens.json";
+pub(crate) const SCAMS_FILE: &str = "data/scam_addresses.txt";
+
+// Amounts are i128, which holds up to 10^38
+const MAX_DECIMALS: u32 = 38;
+
+#[derive(Deserialize)]
+struct TokenList {
+    assets: Vec<Token>,
+}
+
+// Token lists have more fields, like the name and icon, which we ignore
+#[derive(Deserialize)]
+pub(crate) struct Token {
+    pub(crate) code: String,
+    // Custom Soroban tokens have no issuer account; their contract stands in
+    pub(crate) issuer: Option<String>,
+    pub(crate) contract: String,
+    pub(crate) decimals: u32,
+}
+
+/**
+ * Parse a Soroswap token list, adding a message to `errors` for each problem.
+ */
+pub(crate) fn parse_tokens(json: &str, errors: &mut Vec<String>) -> Vec<Token> {
+    let list = match serde_json::from_str::<TokenList>(json) {
+        Ok(list) => list,
+        Err(error) => {
+            errors.push(format!("{TOKENS_FILE}: {error}"));
+            return Vec::new();
+        }
+    };
+
+    let mut seen_assets = HashSet::new();
+    let mut seen_contracts = HashSet::new();
+
+    for (index, token) in list.assets.iter().enumerate() {
+        let mut error = |message: String| {
+            errors.push(format!(
+                "{TOKENS_FILE}: asset {index} ({}): {message}",
+                token.code
+            ))
+        };
+
+        if !is_valid_code(&token.code) {
+            error("the code must be 1 to 12 ASCII letters or digits".to_string());
+        }
+
+        if Contract::from_string(&token.contract).is_err() {
+            error(format!("invalid contract '{}'", token.contract));
+        }
+
+        match &token.issuer {
+            Some(issuer) if PublicKey::from_string(issuer).is_err() => {
+                error(format!("invalid issuer '{issuer}'"))
+            }
+            _ => {}
+        }
+
+        if token.decimals > MAX_DECIMALS {
+            error(format!("at most {MAX_DECIMALS} decimals are supported"));
+        }
+
+        if !seen_assets.insert((&token.code, &token.issuer)) {
+            error("duplicate code and issuer".to_string());
+        }
+
+        if !seen_contracts.insert(&token.contract) {
+            error(format!("duplicate contract '{}'", token.contract));
+        }
+    }
+
+    list.assets
+}
+
+fn is_valid_code(code: &str) -> bool {
+    (1..=12).contains(&code.len()) && code.chars().all(|char| char.is_ascii_alphanumeric())
+}
+
+/**
+ * Parse a list of scam issuers into their sorted raw account IDs, adding a
+ * message to `errors` for each problem.
+ */
+pub(crate) fn parse_scam_account_ids(list: &str, errors: &mut Vec<String>) -> Vec<[u8; 32]> {
+    let mut account_ids = Vec::new();
+    let mut seen = HashSet::new();
+
+    // Blank lines and comments starting with '#' are ignored
+    for (index, line) in list.lines().enumerate() {
+        let address = line.trim();
+        if address.is_empty() || address.starts_with('#') {
+            continue;
+        }
+
+        let line_number = index + 1;
+        match PublicKey::from_string(address) {
+            Ok(public_key) if seen.insert(public_key.0) => account_ids.push(public_key.0),
+            Ok(_) => errors.push(format!(
+                "{SCAMS_FILE}:{line_number}: duplicate issuer '{address}'"
+            )),
+            Err(_) => errors.push(format!(
+                "{SCAMS_FILE}:{line_number}: invalid issuer '{address}'"
+            )),
+        }
+    }
+
+    account_ids.sort_unstable();
+    account_ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "GA223OFHVKVAH2NBXP4AURJRVJTSOVHGBMKJNL6GRJWNN4SARVGSITYG";
+    const CONTRACT: &str = "CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA";
+    const OTHER_CONTRACT: &str = "CAAV3AE3VKD2P4TY7LWTQMMJHIJ4WOCZ5ANCIJPC3NRSERKVXNHBU2W7";
+
+    fn token(code: &str, issuer: Option<&str>, contract: &str, decimals: u32) -> String {
+        let issuer = issuer.map_or("null".to_string(), |issuer| format!("{issuer:?}"));
+        format!(
+            r#"{{"code": "{code}", "issuer": {issuer}, "contract": "{contract}", "decimals": {decimals}, "icon": ""}}"#
+        )
+    }
+
+    fn token_errors(tokens: &[String]) -> Vec<String> {
+        let mut errors = Vec::new();
+        parse_tokens(
+            &format!(r#"{{"name": "List", "assets": [{}]}}"#, tokens.join(",")),
+            &mut errors,
+        );
+        errors
+    }
+
+    fn scam_errors(list: &str) -> (Vec<[u8; 32]>, Vec<String>) {
+        let mut errors = Vec::new();
+        let account_ids = parse_scam_account_ids(list, &mut errors);
+        (account_ids, errors)
+    }
+
+    #[test]
+    fn the_data_files_are_valid() {
+        let mut errors = Vec::new();
+        let tokens = parse_tokens(include_str!("../../data/soroswap_tokens.json"), &mut errors);
+        let scams =
+            parse_scam_account_ids(include_str!("../../data/scam_addresses.txt"), &mut errors);
+
+        assert!(errors.is_empty(), "{errors:?}");
+        assert!(!tokens.is_empty());
+        assert!(!scams.is_empty());
+    }
+
+    #[test]
+    fn valid_tokens_pass() {
+        let errors = token_errors(&[
+            token("USDC", Some(ISSUER), CONTRACT, 7),
+            token("SoroToken12", None, OTHER_CONTRACT, 38),
+        ]);
+
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+
+    #[test]
+    fn malformed_lists_are_rejected() {
+        let mut errors = Vec::new();
+        let tokens = parse_tokens(r#"{"assets": [{"code": "USDC"}]}"#, &mut errors);
+
+        assert!(tokens.is_empty());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with(TOKENS_FILE));
+    }
+
+    #[test]
+    fn invalid_tokens_are_rejected() {
+        let cases = [
+            (token("", Some(ISSUER), CONTRACT, 7), "the code must be"),
+            (
+                token("THIRTEENCHARS", Some(ISSUER), CONTRACT, 7),
+                "the code must be",
+            ),
+            (
+                token("US-DC", Some(ISSUER), CONTRACT, 7),
+                "the code must be",
+            ),
+            (
+                token("USDC", Some(ISSUER), "CNOTACONTRACT", 7),
+                "invalid contract",
+            ),
+            (
+                token("USDC", Some("GNOTANISSUER"), CONTRACT, 7),
+                "invalid issuer",
+            ),
+            (token("USDC", Some(CONTRACT), CONTRACT, 7), "invalid issuer"),
+            (
+                token("USDC", Some(ISSUER), CONTRACT, 39),
+                "at most 38 decimals",
+            ),
+        ];
+
+        for (token, message) in cases {
+            let errors = token_errors(std::slice::from_ref(&token));
+            assert_eq!(errors.len(), 1, "{token}: {errors:?}");
+            assert!(errors[0].contains(message), "{token}: {errors:?}");
+        }
+    }
+
+    #[test]
+    fn duplicate_tokens_are_rejected() {
+        let errors = token_errors(&[
+            token("USDC", Some(ISSUER), CONTRACT, 7),
+            token("USDC", Some(ISSUER), OTHER_CONTRACT, 7),
+            token("EURC", Some(ISSUER), CONTRACT, 7),
+        ]);
+
+        assert_eq!(
+            errors,
+            [
+                format!("{TOKENS_FILE}: asset 1 (USDC): duplicate code and issuer"),
+                format!("{TOKENS_FILE}: asset 2 (EURC): duplicate contract '{CONTRACT}'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_token_problem_is_reported() {
+        let errors = token_errors(&[token("", Some("GBAD"), "CBAD", 40)]);
+
+        assert_eq!(errors.len(), 4, "{errors:?}");
+    }
+
+    #[test]
+    fn scam_lists_skip_comments_and_blank_lines() {
+        let (account_ids, errors) = scam_errors(&format!("# Scams\n\n  {ISSUER}  \n"));
+
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(account_ids, [PublicKey::from_string(ISSUER).unwrap().0]);
+    }
+
+    #[test]
+    fn scam_lists_are_sorted() {
+        let other = PublicKey([0; 32]).to_string();
+        let (account_ids, _) = scam_errors(&format!("{ISSUER}\n{other}\n"));
+
+        assert_eq!(account_ids[0], [0; 32]);
+        assert_eq!(account_ids.len(), 2);
+    }
+
+    #[test]
+    fn invalid_and_duplicate_scams_are_reported_by_line() {
+        let (account_ids, errors) = scam_errors(&format!("{ISSUER}\n{CONTRACT}\n# x\n{ISSUER}\n"));
+
+        assert_eq!(account_ids.len(), 1);
+        assert_eq!(
+            errors,
+            [
+                format!("{SCAMS_FILE}:2: invalid issuer '{CONTRACT}'"),
+                format!("{SCAMS_FILE}:4: duplicate issuer '{ISSUER}'"),
+            ]
+        );
+    }
+}
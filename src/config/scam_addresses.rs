@@ -1,13053 +0,0 @@
-// We don't include transactions involving known scam issuers
-pub(crate) const SCAM_ADDRESSES: [&str; 13049] = [
-    // These addresses are taken from the StellarExpert data
-    "GA223OFHVKVAH2NBXP4AURJRVJTSOVHGBMKJNL6GRJWNN4SARVGSITYG",
-    "GA22TJ754NFQ4BHJWOWXO67XZ44LNPW25Y6GLZDAWT4IQ67KWRDYNNJT",
-    "GA22YYOMJ7JW7244KBYKMLU6L5KDA2ZJPUHQ6X743NIZ63O4YUYFREPS",
-    "GA23CQA6QFY5LR23F3FIRRILTM3EUOAGT553D5PB5A5GSR4S2VJTAELK",
-    "GA23GGWZN2FVT4D3YJG6RXIKWSCBF4OLA4UCM2A4J5XCR3J3EHRCEYTH",
-    "GA23KAGQ33IFW7GBHU5SB2GIHJ2W6VJZVLKSXQTA2DGNLQYW54GARUCY",
-    "GA23NYJO5MN5M4345TW4KX6HQTY2QORZAJZD57AHT3ELJVWA5HRHBMC6",
-    "GA23SBSDNHVGAFHMPJJ4AD34VNQVHRK265PVQI5LOWXF7567NYHAOTFJ",
-    "GA23SU3SJ6KYWMK2XODSTIRIUWSSNDJNYTIE7KTCDIV27EVJNPRH5RR5",
-    "GA24CYK3WA65237632RO3LSKHKPCJSCIVJGDDCSRHS3D2GZR646FXG2D",
-    "GA24LJXFG73JGARIBG2GP6V5TNUUOS6BD23KOFCW3INLDY5KPKS7GACZ",
-    "GA24NGQB7BGEXZRODX6FQXARAZ52AJLXCJD7RX3X3XQJ62UZR5P7ZWU3",
-    "GA24SYIMIYNZILCT32J76ECYWA3ZF2U5B4YHQ2FWVYJCPBEHCCGPC3A2",
-    "GA24TWQHESRTYRFHE5BCT6IR7QBKRUIIABOMIVNJMKGSKKMX2S5PATGY",
-    "GA255Z5FOWDQLDIGWIVNUYSPCJIMANQHQLDU2I6MA2YLUZMBU6UIM44E",
-    "GA25QBK4BOBUO7MB2V7WJAUPL42IWCM5TWSLTIXPPUNGASIO7CPRVPPV",
-    "GA25UI2CPA3J22AQKGAZZYYLC3LBUC37CSS3ERUSPXCFEZ46NPBJAGRO",
-    "GA25ZDTLBTI3RUNPL5N7XMRIVYYUPXIFIN4BZUL3SN7SO6VWIOOVCWP4",
-    "GA26VNII5RANL3J2FPETB4FOZ26SRAH3K7WCB3WTK7GIXRNIU2XYCELM",
-    "GA272U6UMNKYOBDCLH5CDAPZVDG4WAGJWFY2S2RV2DN6YSSOGYECEVOF",
-    "GA27KELSZ255SUKF3G2G6X7ZXIMLILU5N3YX2AYI3N4R6RNU4CWUBVI2",
-    "GA27L5YN3IIUM4VCU3YLKMT5S4EA55YLRXYJIXJAXU3JB2JT3VWKYWB3",
-    "GA2A2LJDVDOKRTYPRSW4NGPPXMZFCYXLZ4OPLRPKLEDRRRXRQFFOGOLD",
-    "GA2A3XPS6P5WUHZ6Y5BSO72YAEB3W2JXVTX533GCH3D47GHNEST6SA22",
-    "GA2AA646YTBF5QOCCOU6D6DZ42J6YQDCZ3DQHJA7RLMJ4XHOPVZNSAFE",
-    "GA2AFNJ43KQD5X6UIYWZ7SQJI5JGVCGTGHWWTBPU7PTNBHL3GVSBQXLM",
-    "GA2AFR7ZBT74H3RI2E4U3MP6BZWL4A7NXN7MYGCKJNDQGQZCLBW7ZY3P",
-    "GA2AGNCY7OTBRV6JHM7LAQIZEQNVSV4ZZBFFXM6NV6SBEIGRLFS7AEOD",
-    "GA2AQLMDSXBNXJFJPH2T76OLH2I32RL6E2Y64HANENBA5FWAWXPVIQEY",
-    "GA2AYU6KZWSEVUO6LAKW3TQZULQ6NWQ5IRQ73E2B3GSP2NXZCVICIXAJ",
-    "GA2BGGLAFR3NCQC5FRX4LP4QAXXAMCJ5R5OGBR6DQGWZTEGQ44RD7VHH",
-    "GA2BISQZDDFIIGRSGWF473OUN4VBAGHX7RIMRZ6UZBW5DO6N6G6ILOEA",
-    "GA2BKH4DCGGP6VFXXVBR2ATQCBGUWVEKY4K6OECSGWQ4VW3O7ZWBKX6S",
-    "GA2BNQSVU24N5WKH4SJQ5S3UHFAEEYTSX2A7KRMEAQC6MBUHANPRUQJV",
-    "GA2BWVHKH4372OCWGKSWMOZMFRBURS6HKD6B4RVMYNN2HDP2EDPETSTC",
-    "GA2C5CWN54LLHOTT64KUNDPQRTQF3CNICYI3JTFYCUKBFAXDRTF2KLK6",
-    "GA2CYER32EVLBFOUTTD2T7NTOQF46P2QWCXZTCAZGDG7LY2A2D7OITAZ",
-    "GA2DD33DTI6QEDHODO3X4F7Q5USD4BGETHGWNG67EH4LPRCBLT4NTUQ7",
-    "GA2DODG2JEI5GFDGVNRQIY2BYYCZI5CR32GDV3HNLXLSJLF2W7ALLXWA",
-    "GA2EQ7HVZJGSQOLFE4ZMT2FPI47XMOBPAKP2VO7F4LKUQCLO6QCYN4W4",
-    "GA2FBR3WL7ZSTGPKRIRWRDFDPSXNA6IWQMCMEV3GT2CDK45HJ5RMCRPG",
-    "GA2FCRDVWLTGOEZXAXM3RTZ6RI2S2ZI3XAAX6MILQWQB6Z4CEXLHM4WP",
-    "GA2FMIPROJLKWRVGQGHR34DDTX52X6CFHVWUEZRSYGROUFIUD5WRQG5B",
-    "GA2FWDGGBH2TKWY4CYYOHSWH3CWS5G5IFMKTBK76MPHXY5WSWLYM7VT6",
-    "GA2FWISWJKFC3PZY62PS332WB2VFEG7MEDVAH46XHVDL6G2RNILDQBZK",
-    "GA2FYLMVC2TR4KRHHOAYKYG35GKQF2TZAMBV2IIIIYQWMHC5FJH7M5EP",
-    "GA2GQFCXOJRZDWWSDR4ECOUXU2RNUED5YLIWYPF4T7OTNDDL6CFJRLJM",
-    "GA2GWVUVAJRMKKYMH5D3BRFFRLR67I7GYKQNMMF537PA34M6XP4PCPQ4",
-    "GA2H6MI4QIE2TQG25PZCBI3WD4VT343XKGKZ76IBITZEJSERSGJODELU",
-    "GA2HN7JUOJ74QCXPUB2OCFLHZ7GCEXCAG4LP3D3BWFVKGRXQ3753YTWW",
-    "GA2HPMMZQ4JOKPMMWICCXNMYXTZ4Y5TFE4FKDOOVWGNN4XCJGBDQQVLP",
-    "GA2HRVSKNODWNU7XBE3XV5BPXLVUKDWQQY7RQ54C25OWL3477C7WNIBX",
-    "GA2HSN4DBPOXNY56WS7WLOAJGX2DOLRPMAYKRFKCLR5Y3TDWINQQADJ4",
-    "GA2HVBHYD5THOS3GDHQKDSEIMVMGWKOWTP3D5PON7CDP4WXRI32SEXMR",
-    "GA2IBVEWB5OM4HMSHQRDQZAW35J5LAYJKTJYMHZWEA2GFQF7YC7OJPIU",
-    "GA2IJNUOHU32LVKSP2HAEXTN5NWIQORAT7FHNB32FZL7ILPHKS7FR2N2",
-    "GA2JDES2SPWQHOUGHAHOWNBQWK32NRH7VMA737IBC4QAJQCZ5QF5O5CL",
-    "GA2JQ4SZVOP3TB4FCS3KS7AIXQF3PG7YMQV4DGOKYORX2O4QXTEJ6EZQ",
-    "GA2JQJOVOSNG7HGXJIBTN3HSXMF2DDI2OW4IHW6IMBBWOSWAU3RY7FK4",
-    "GA2K5D4E44D6NU2ZZDLGWCZFX6VLDHD34VGYPIABRUJWHWPJPAWXGOLD",
-    "GA2K5LG73U4Z3LT7PGPQOJMLDHTWNOWSGWZ242CA43I2DLTPNSCNNAAM",
-    "GA2KLP5UHJP6GLRNAJJOW3YK5B2KLXCZKEAE2X6Z2X5VIBJQGAZSBJJN",
-    "GA2KUIKQKNPYIXGESJM55F45UF6I5AUH4UJP7VUKVQVZ56NN5DN7FHMN",
-    "GA2LK5EYCY63DGLJKCW7JOEB27YNPNIASIZMXCTKU75IHKKE5FIGQUSA",
-    "GA2LLVUHGLW2MLLSOGLKXQZQNIRQF3M5AM5LNICGHACGBG5IDIL6MCCA",
-    "GA2LNB4ZNOH433U24JEK5WH2DVJB3DDXJ54VN324B3BCOVQJ3OGIBVDC",
-    "GA2LZOEUX2SNVMUMJHNHOUHYWOZFAWIQBUCDEC3OFUSJBX42SVTGLB7F",
-    "GA2MMIWEHMXI7O3D7YU74UBOYLZSUMKGTCUNH366KNBFEFEJI52PU77B",
-    "GA2MQMZ62PIFFEZVO22E5B7URI4HCGENRPSBH7S4HBMUNLLUG3AI6WHP",
-    "GA2MS35XQLZOMJUHW4BWCLHZD3QGNXL5I67KEU3IBGASWPB6VVYMZ4AJ",
-    "GA2NGS5AOH4ZLHQ7AWNL6Y2D5NXCS3HTXKNNJCVBXTTRDVQDXQFQ2L2S",
-    "GA2NY57LKQHEI5NCHJGQWAY6GY45SEDVGFUENMNAMPMYCYWUSC3UEVXY",
-    "GA2P3HKTQFBZBWOWPLESMV5AEHJLWJKNAYV5HLXOPBRWUEHFR64FQTKN",
-    "GA2PAP544N2LDBKTETNRKZJHCB5DC5Z4KH4YWTK6THZ3NOVE7STXXQFS",
-    "GA2PFJBSUAV4N4TPMLYL3C2HMRHXP3CRKLIGYDEENJ5HJRXNQRIX2DF3",
-    "GA2PRARQY3OMKB3JXALI5ZIY433BZNOUYXJSNKXOJE2SWB5CILW4ELON",
-    "GA2PRPDESLIGFXROBU3CSS6JP5FVWMZH5442YQY533BN4VGQZ2DFYC64",
-    "GA2QILDDOZ2CAFARDKLJ5AWV4V47C2SOOJEDKMTOSHJ566G4LCP334QF",
-    "GA2QJPZOV2SMDNDRFV6KEBGLEU7X2PJ3UIZSTJ5UAYIBOSZQA2NOTTNF",
-    "GA2QXNQ4DBBG2UK63O7DLWMIDKI5E4O5ON7ZXAYCG4SQXEZFFQ2NZW5T",
-    "GA2R47WBQDAAO7XM6PGGNPA4H77HUPMNUEMOS6EAHOJF763RFBWCROS2",
-    "GA2REBEE7Y4KLMTSOCMR56MJAYAOWIFDNRTRP4GF5JKUEF2X6FBF4K2D",
-    "GA2RGTURMTY27DSXMVYWXD2EMKAMFGDOKPCAMPWQFV47V3J3ASGTAMZN",
-    "GA2TOU2F7MR5MXC6SJ7HIOCO7SADWRZGTV27SGY65ULSR722EH67MNPC",
-    "GA2TPVRM5IQSF6YGQIFU7UY2VRAJM32M3YWCAEIQOBL76BNK5RZT557H",
-    "GA2UG26F3S2DQCYWF4O5DTNHYVTEKTGJ2SCTIGJGIJC2GIKPRXB7XJ3W",
-    "GA2UGXPDPRSK6LNQTJVDQ7DHBRAKGLIPZBAVSDK7MCZC23ROZX67F7SP",
-    "GA2ULNUTXSGVMGU5XBYKAO3SHY7LB6WG4BQPZWCL5ZCKSQ5K7SGTCGMT",
-    "GA2UPYGGTQVHLPEH324NVC4XL42FAC7GLDPMD5EUKMJVUVLVJ4BQ2WQ4",
-    "GA2USUU2NYYD32AQWLY5INIC3OYVOVVQ7JDDFZ2XFXQ3X7WP3W3SO4CB",
-    "GA2VGY6TGAV5UUZ5BAE46FYBHIQ3YWOW7SEYYFLW7WSXZJFVCWQUPV3C",
-    "GA2VKHSIZ3WGQJ5AZ2JQXKSTBH3VJOL6LCZNKHW6PTHNOSOQGXTDYZHO",
-    "GA2WELZQATLOJAC4VZLJQFK63DP2TV4SG2HWKO6OZ7TKRXEOXTMZOXCV",
-    "GA2WXQGDJGRKTFYQ3GWSZWCMLCVBGZCOFZLH4JMIP6L4Q7RUXACD5NRB",
-    "GA2WYHMKTFLT7ZUVFI5H2JQTQK3ZVRB3UPXZYPFNQXZEH77HJ7COYUMS",
-    "GA2X2FFOBSHUKKZCFZ4AOOMBKWGRELWSS7CQXONN4HIG5CUBOY5YPYCV",
-    "GA2XXF25KLTWHXC3EV6DQ635FAOYJ6LK3MBTBXEETDS2DWE6UUJG52QI",
-    "GA2Y67TUXOI2KICCIVIPLVXF7KSIUAJ4W3VJS7ERW67FDGNFBAFBIMHJ",
-    "GA2YT3ITDNZ72LNQ7IG5KLPQHMTH6LOXFASCL7GIKRLILTCU2DFZFQNT",
-    "GA2ZBVLAVXCU47FWJI3WHDMDCPQNXACXONZEOG2WOFCY3ZYZVSIF5CRE",
-    "GA6TILSL5XIOECSL4J7WA23UFB65JP32SNESVKVHG6RBP5AAY76VUETH",
-    "GA6TNEOLBXCZ5N64BMAVJB6JJMHAOEWRKTEFGDGTPEPJIWKRPXFKLF7V",
-    "GA6TVU65MYG7F523MIUV27YIDTWZ34GJ5EFE6GWO53WH4NCZKSTXLFTR",
-    "GA6U5FXGP5R3NQKJBG7FH5NB2G2BXFUIXUKONVLJEJGQ6HFNH2XRNCC5",
-    "GA6U62MWTTZFQDEQDGR25JKEF5HFX2I3SVV76NK7RQTMOQTXWBXE2EFD",
-    "GA6UEGTNV352NTD7BELRAGOON7FS5OWRQ3JSJRPTGE3JQRF2XFO7CLEN",
-    "GA6UJRTWSYPYHEKMD6LHUL5PCEFDC5BWORQG4PSKSMIGDIWJ6OEKBTCQ",
-    "GA6VFLOGJB4P7J7UTIOPYY5P6ZA7KUT4YYJEFG2I47LFW2DNUTPZO5ZT",
-    "GA6VLEF4KQEMDB74FTBKOKKYF5OTWZ4RYC4S6DVQTDTWFJJMQCL7BACP",
-    "GA6VXTS64XUOCQZSBAYHD24ZYJYPNB3KJ2CVKSYIE43P2DQQIX74UT2A",
-    "GA6VZRPLGGGAHPOYS77SGOHUHVLD4WRS73OCR4ULJPNZFRT4GVH3WSW7",
-    "GA6WD5ZIZWOEA2PGBNMAQFHJMDJKOYPWVOPXMHIUNTCVFTD4QNWUFIZZ",
-    "GA6WP4UUF4AIXVM7BU5J2XQANOANF42PLIE3YWZ4UIC5OCLMXSD3QXTI",
-    "GA6WR4T4WJEGANFF7J4WCZMF6C66TFOJ5NVCS5ELLBDEVXDLOTCIP5XH",
-    "GA6WYVWYP5PCUHE2DQVVHKEH4N4F3T6QLGJQ3OFH7DFXLWLQYYKFDDBA",
-    "GA6XCUVTJNR75H62FFXBNIAN2T5RSVSBRHCB6MAEQIQB2IYS24MABDG3",
-    "GA6XKHVSJA6M3JWALO7BEFZA2T2HX4UHHGYFTTRY2FK6XOK4X7K4FCHN",
-    "GA6YDGCLKN3HPSBXINTP7WUD3G5WIBVLAL7ZXIRUUHC73HXVVTNV54YZ",
-    "GA6YORPLAZ6BLY5XT6NS7CAXIFHSWYJTOAMYSEHGWOP76EXHS2CWKNCX",
-    "GA6YZMLBGMSI5G6ORRRBI5TOPYEH4CKOULRULIQKLNRMJQNTOYKQ6D6Z",
-    "GA6ZAQGLDUEODDUUD3UD6PUFJYABWQA26SG5RK6E6CZ6OMD6AZKK5QNF",
-    "GA6ZCRQG6JJM3ZRHEEKQCXGMIRICMMB6F7XFUSTHVHBLCRA4Q73OQ653",
-    "GA7252J5YWJTN4CFGZV4LHMSUBY46J7PPL6WI73V6IL23HQJWOQD2BVS",
-    "GA72BFOKDXFMM7DJ6QKFLNE56MWBVDGEPO5DLSFMU335MIY6CRJPPMH5",
-    "GA72BQEVPMPZSY7Y6PDKTWK4WSBREE2I4MRAXLTAX76PRQ42Q6B2HBLK",
-    "GA72JMWCBZGGP5NMSOOYM3NIWBMZPKM5NHMZSYLKR2BMQXNJ5CMTZ6UH",
-    "GA72KGZEVMD2YEH5Q2JQNXL55E4DBYDCFXXJP3DWACWAZQPJSE7JALGO",
-    "GA73GDRRKDMPLP44X4G2SNUIOCVBHCTSG32UPJOY7SPOJRVWKVL3QTWM",
-    "GA73QLEXEVZL2RUIALP5CX4X3MHE55UU5A2IN7BGKWVIKSREEU4N3V56",
-    "GA73RERV7NKYF23CYMCRSJUPYJPOYEJ7WYSAK2GFYGS3ZJSAOCOHHRBA",
-    "GA73TGZOFJKD4275OT6NFKVYPYR5KENIGSWMICNEDFF5SS4YND6XPKBD",
-    "GA73UY76Y3UHWQETGVHDBL67HHSWLQR65SHPBBV2D6C6N4PGPXQDF776",
-    "GA744RPHAN3JLPX2SOWXEMBPW4EGE27PCMH3YGV7LAKIOCTPEFMNGSJF",
-    "GA745KYPUGXAXFSS7TJ3BD7R2TULUBJWY4KMVTIGMNRVMJJDGK2EGH7N",
-    "GA74CSBGWMRTAINFZNDEREPTL42E3XMSZ52N4VNP56SDH4FINSNQZA6G",
-    "GA74JKOREZIVVUNY2W6K37G7OXFAW2EUHQ2UXYX5ZMLACWOBOBWNGOLD",
-    "GA74NNNJ2ZDIN6GFHO435IVDQTJSTF47Q3RUU32OZX4XXC66KNV3LLG7",
-    "GA74ZX7FI3LL5XEKYBPVJTEBLWBEVYE54IOPQ3XDDGLTR6PHW473ONFT",
-    "GA752IAOT6RQH5YX7FMMK4BBY4U22TW6MSZEQSNWT37EMZWIB7ATLINE",
-    "GA757GPM2VOTHUOZRB4R4EVAERIU4O53WMHRUCPFXVJVJBSJR65HPPS4",
-    "GA765T7V2VHC5MTXN2LNLBKKW3OLISEC7IUGIECAP4WUAXZZRHRVIS2J",
-    "GA76KBVA7V3VWH476WIJ4A3MDIXJ6L5QGSBUNERDIVA7AD64XH5LF3UH",
-    "GA76UOXDZLY6FK6NUV5ZVINFHEYPCIDNL3SN2ZCOJ3IZDUVH4JPTA5E5",
-    "GA776WQS2CXGZGJZP7PRIEUYHBVGRT3H2T3IGYO53GMLPJ2BZ2Q4I6NO",
-    "GA77BJNYIIQ3SDUK6LWG5JIZB7PBRFEWTKVK6DSJSGCC7HURMKCMNYB7",
-    "GA77FNA7XAYZJLFJQY4P76ZWPEI6OIRCLTXUPJWBQX4W2FHSJYOM6AZ2",
-    "GA77FRMJSIIIMYIK3EKVFZSZFV2GEB43K5PHAK47YU6WYPSZV75HERBQ",
-    "GA77HW5DPJJX557GPFUUV6SQSHQXMSXTIMKRQTG27DGNY3TQHN2HWQ7I",
-    "GA77INE5IT5VIEBLJNIIFU2GAUAAK3L7QEUZ753VI4IAVKJTBCVNBREM",
-    "GA77IRRD45GKEXFTPTFB5OLBOFN5XPU7P7Z44XOUSGHWYUVVOTH6OUVS",
-    "GA77WIIKBF4YVIQQ666ZL6464VVB25CA5FTETD4AQ3LLM7OTBKMW37UN",
-    "GA7A2G5HVEQAKT2RIHSVQEMXY4B75KOPZUBVPBFT65PUXB5SDR3JFAEA",
-    "GA7AJKNIE236EPRIQYXRYMUEHT7GQ2MDH3B4G5HUNAPJXEG25ANCRQEB",
-    "GA7ALQIFKU2DHAKPURCJT36CKRZL4OHD4MZYWVPF34TBEMULFDXZVOKU",
-    "GA7ARTAE7SGNV7Z3PBHSV32BS6KVHGJOPNH3XVAJPUOGZA5MKDBXXXRK",
-    "GA7ASKBRHIEXS7WHHKY67J5XMONFY6DARWQEV46IH3BPVW3HXRMVFIOP",
-    "GA7B4BMP4ZMEWXS5XHFQ2KRMQTVIPTW7MN2KWMFVZIKITVOH6W43FJVJ",
-    "GA7BH4HEPK7FN65JG7PRY5QJOTTM5OXQ5GZXDDPR6PFZJZ2B7YKU6L33",
-    "GA7BRV2K3OM27NLVY2IJQGYZEQ7AEZSPR3Y3XA6COHPMOPOEUHQBDYBC",
-    "GA7C66WCVGMPVQ6QDGQFL6N4LGEG3OWNH33TKM3ST6QVFKM6HZVXSV72",
-    "GA7C6RJ6A5ULUAKN6AURCLENHP4QVPFW7BEZCJNTVO4KNMVAWPUIJU3W",
-    "GA7CPS7BDALKSXLLWYHQUEFIQNJX6MPVTMDC2RZ6NVBQ6UR2X42AHZDJ",
-    "GA7CQ4UECKTCO4ML7MZAAE3FOGKFWONR6NWQMQEBV4GX2TCGSENSZZTX",
-    "GA7DGGMYMBPIJRAVS2RYQCHP6UDC2GVMQY3UCWUZJAVXQ3JWGHLKVFLR",
-    "GA7DUBLTYFVVEJJQPNNJM4CKRWM4R34YE3HBOJLHKNBV3RPRKOIPVTHE",
-    "GA7EW6DZ7JQDYTKUQE3M3O7OFQC4HISY3SEFP3P5B5HV3XBDRUNGCXFJ",
-    "GA7EXZZXVMIQ5KJKJNR7277COW34YJWRHAADEQIF3L6F6KXB4HL5QXQR",
-    "GA7F2XDSW2PFQ6DX4WER6HNI4GVERLE6DGKK25Z6OE6PIO7J7FYMZ343",
-    "GA7FCCMTTSUIC37PODEL6EOOSPDRILP6OQI5FWCWDDVDBLJV72W6RINZ",
-    "GA7FLGQ7CVEWW63OS37YUBPKO4XWIWLE47YRCK4CAUZ2JC7Y76HSA2LD",
-    "GA7FMZXERVLA63GJM76QLADZMTLFZNFGZRQU6QUUC52FQ7IZ6CR3XLMS",
-    "GA7FUEJGQL3JNWZGDL7634JCXAG3CJORAZPE6ZB2DMYI4OM2DWQN64DP",
-    "GA7FWAUOXIH6VIB4WQ4YBACN7YILTIDKGFLGFBOKGMOTBPSLJH36YK72",
-    "GA7G6P6UJBKRIKGW6PIXD73KSVKNBOEYTNRYVV5Q5QR4UZEMNRJ4EPIA",
-    "GA7GKMJ4FWUDSYZGJDWOXV2KHELZVSJLJXE7TCB4TAUXWWERPDRTBRIC",
-    "GA7GPYCMO5LTVBKAUEFXKPZY3MTNDRATDVHNF4WAP5PJRFSRTXEYAJ2A",
-    "GA7GWCKHNPRTPNW4OGK4YXFJQNQ7HPY6YPXO5AG72MWT4IB4ANK3YBLK",
-    "GA7GWNFYGSSB6EQ6KV3MJUTMGPUXCF7MQFRYA7TXV6NFW5LGD55EWCJU",
-    "GA7HA6FOLDRDZHOGC4VQWW4R3WYHHQIOCEXTM56FP6ENWMJ2C6ETLRGN",
-    "GA7HM7O7PNXYU2TN75XDNQ2EHRQ7HPIBTAUXTGX25J6JAOBER5RD2QY5",
-    "GA7HMZ43HFRSTMSR7R23CHAEWARTP57ZG65J6AQ65KLEWDUUM74E337L",
-    "GA7HNTTH4ZJRFDOI35YRPZETD5MVTL7JZEEWIOP66EAQ7FP65Z2UO7VQ",
-    "GA7I4AELPJNFOOHQMGUT6T5UGTPFJFCPTERFFDHU3G2XKXL2UKZJ6MTX",
-    "GA7IGR5F43RM5WYDU2U6KTUBOMTPAG7WP3F7NWAQUEGYOLYFI2IZIGAL",
-    "GA7IN6M42Q4GBXRESDYJUMJI7APBI56TFHEDAZM2QRRLH2OS4PI5QYDU",
-    "GA7INASXD3R27QMFYVEEO7CC4CZA42LHTA4DBCFYCDOK7HPPOWJSWISS",
-    "GA7IT4FA7MCGAROD2ZFI3WDOHLXEWXOX72BQLC4YFROLH2KDSIWIT4IU",
-    "GA7IWMX3BY6XJAIGFTSXO6FW5WKHG7USSMILGNR345KZJ5TKTN4GU3J6",
-    "GA7J4UVI2Q5UVZWDWAY75YNIN6DXKTPYXIF6OLUKHJTLQG2OPA7LSFBC",
-    "GA7J6RIAOKRMI5HUAA7SBDOMWJKMLQ2OSJBNYL6E7SKD3F4YMIEJL2IX",
-    "GA7JD24QOMX4SRSLQ7LCITELHT2LZ5Y7SHZMBBZGOUWSPTDRSMOQUSLF",
-    "GA7JNTXAHMMDUTLX72UM5PWHOAMJEDUQ6GREQZBLMAPVF5WM2JH4XBTC",
-    "GA7JPU6LBA4WVR27LRHEEQZ7JZLBR6HMUCPR6U57RCE4X2SUQVJBTBTC",
-    "GA7JWXHR4DSWIATZSKHXG336CKPKNGWHU5VWQXELTVDTNLY2TZUSLYWG",
-    "GA7KTJ5JYQFL4SZSICEZ3WMRCTEBHM5QBYW7QHRRM7K4RPENOERT2WU7",
-    "GA7KUU6AM4ZDNM46HNMDFT6OKEJQ6RSWTFP2CM34QLVGYOFCJ52NCRB7",
-    "GA7LGUDC6UOHYLWIDBPEJY456NSGZKAI6XNGDZTYGV67BNMUBJPBQNHF",
-    "GA7LKC4E4CPOZR53KFRSQA4CSFYY5EYZZY7T3NWIPDWO2SD6UPNSSWIS",
-    "GA7LZT3CQ3X4ZUFGVN5KTQ557KVWNEF27W46MC3MN3Y5J43DLGGQJEAT",
-    "GA7MDFKSRSFAEW4OB7OIY3F4X5DNCK5XO5WBOSKFSQJVPJJEI6DM4WVN",
-    "GA7MPAJZ33G7UDR56OBFUWFAYDHS5I4LVZEZ5KDOBP2JAPZYCZWMUBTC",
-    "GA7MUU7UE2EEPOKGRYL57A3FIN6L7D42H4HDXABHT2V3DV54XIEAL7ND",
-    "GA7MXEM4FK7Q5AL3XBME56YVI7FOG3DZ47S3IERY4HNYT7J62BKRGU2V",
-    "GA7MZUO3Z6TJI6EO6DU26ZIVJPDICYJNHGAUTRGEEGQZ2G5UDDG2F3P7",
-    "GA7N3COHA6HJZHPQYQFH4KN6EABIMY5MQXFGGLUKGH3N4SQSWIT6S2OH",
-    "GA7NJNMNJLCF26PLU2PLHV2COCSULTFR4S6ATCYZVOTJF7CCU4PAOR66",
-    "GA7NVYTFGJYOMMUKEMDQTVWENUQV3PK42SNJ25Q7TTBQ3DJ7EZMSUBPG",
-    "GA7OGCSP7TLGK5RBGW76SC2OBBH6QEWQLUIWOEUTLK3SNTNET4URMSZQ",
-    "GA7OPWFHYDTGNN2RH63IDTOZCFJWWYA4FGBNCH2MGFWXGDJ4DOUJNCN6",
-    "GA7P75Y35JGOU7HXDS3NMILNHTBBGKHYMUIH4J7SRFU6Z4MFHSP5ERI4",
-    "GA7PEYWZYYZ7MZMF5XA4H5EXQOKZT4ES52DVJYVD3BYXKBA3IRBGZE3B",
-    "GA7PH42KCUSSVBB3RLXATVVMK7UGG53WHPMM4OX5AHPPINT5SI3TXLMP",
-    "GA7QBS4V3ODQLCLUMRL4EFT7276NE4QMHFN3DTMWGIZM7QF4GDYPYFQO",
-    "GA7QORVGZMCKTY7M6GVUX33VWWPXVZKLZYLTGWC2KJ4JYO5W4V5RLEM5",
-    "GA7QPEQOYNUHOBLC6X67BCFSCEAAJRTBQRVRQMTGNTG7SPMEATEWUFOX",
-    "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
-    "GA7RDJXYX55WAFFSWAEXIUQH6QKQ66NN5QVJFDPWU7TJW5EZG2MMY4AI",
-    "GA7RJMSZPEAF6TRBLBIJTRJBQDM7ASOEKBL2ZYN7ZMAXYM3EEICZMTK2",
-    "GA7SOBIZ6QU5JDQUOYOB5VHBKPZR7LXCXTJRHPUQ43N4V2QJ55LXGTZL",
-    "GA7SOVBFBNSQDMQLGVIMGVN4A2AKPIH3OO6DDTBZGIZ67GTF4GO7SHOT",
-    "GA7SPLLISIOELKH3AYXTJOABES7QXCFPY5GVRECA76XIWUQ2CFVMQQSF",
-    "GA7SZPUE4HAQPCXQW52GHHN3FM3KAFAOG2UH24FKHTLEZKHKEUJFFBTC",
-    "GA7TIGF3QHALPNN7YUJ4G7CPOTRPR732QY3XOWFNPCOLUGP2ZTRETK4I",
-    "GA7UDXG53IPCKUBPP3GTCQSKDCU74THOC66EE6JVDRBZM3NTZE6WKEDV",
-    "GA7UJMQTU6W36COR57SMGGUH66MORHHHHVX4WRQ7YOD2D724MTGBK3D7",
-    "GA7UMCY57TIXQGARXIMJS6ZDRO7T2MMMO3PK6FXJIA6M44Y4GI2F6SOY",
-    "GA7UOBDOAIVX7XCZVM4F6W4LF7GDAL5NDNTEEEJJNR45YUMF2QFSHARE",
-    "GA7VP5AHSL4BWIAG66SYIWYRBLQK6FWV4I3X7DF4IHWIN2ET3GCBBXWT",
-    "GA7VTTAEAOPVZJX7WNF4FP3DPTWXWR7SZ4LFJK332XALE2NPDJ3IEJ7Q",
-    "GA7WEHXREBJRVTSCABOWMP76EVLTNHBVVO6QWLJYLDHBCJJ7ONENFXRN",
-    "GA7WF65INNKXK6GBJL55BYWVDDDSN67U544ZTIGVAYBQJRYXXLJHQTUM",
-    "GA7WNZYU6I64EAD4SNITX6R2APWPU7RDWDK4EXGEUDMS3R3MGPVFLEYT",
-    "GA7WRNVC3AX5BVUYYYOAOXXLYNALNECGFTVXLO34T6ZTFU6WOXGS7G7E",
-    "GA7XYEQLOBANJ5OVZ373QOTVTWBOOP23HBRKDLUB6O3DMYMLROOENCMS",
-    "GA7YKOIXRY2PRH22KT5ZOGADGV6IYSDC66ZLWJL6TI5RZMCJVWJPDMU4",
-    "GA7Z7LBNY6HI3O4UZTECS2YGBR76EDQHUX6A6POUTHC4BR5HGSDXYNTW",
-    "GA7ZOZQS6JHD6G7C5VSQ6FOQEEV5UOEQR6LTTC3VAZDKTM7HKWLWEXLM",
-    "GA7ZUJHETEMVJVQL7X2FNTVFRVF2SYJO7IUXURIDD6O7MRPSBFOAYRJL",
-    "GAA25YC3ZQZGQQH2SOO3JGHC5COBVNBBS5A7A47TCH3JMSOR5KJUNTS2",
-    "GAA2Q2K5G7AXKCOQ23I5VE356B6DEBYKXSRWPOOSOOTOVPVOIVNU6JJN",
-    "GAA3ICCYPNY7YNLD5OFDPYXHDDAKSIEQS43PAGA4O4YON7BGGIAPXXDC",
-    "GAA3ODEFX4WNG347R33K3FRW5UMWGLY4QQ65P5N6GFIRZ2IVPCPPLFIA",
-    "GAA3SBXCI3726QLBCAXWM4X3PGEQZ2AL3JTPPNI7L7NS2YZ6OECPHBAR",
-    "GAA3WYXMSXN6EEXE2OPXLMZ57CYBKPBMCIKTTELYUDRIPDWQLLHQZDQ3",
-    "GAA434ZVQQX3DHQ52K424VJVDPWLBAREBO42SBGORXEKPB45JFOTALSX",
-    "GAA4AAYHJ3NYHFCMJ56ALQN4NVEMZG5AM3BJ2KMOIDQ2CKTI6O7DVWNB",
-    "GAA4GWD2BBRJSZII4Y7JC3DV6QVMZIIPZ5LVLI4HHQJQAUMZL4DXI5M2",
-    "GAA4LHXZMMXR5LR3N3Z5ILV7QRCJX3CHQT6KJVWIKUN2ZTF5QOD5OV33",
-    "GAA4MMCPIGNRBZWNFSNSEMT46Y6V6EEGVQ4YNWCKGPQSDQCDH7LXKZVI",
-    "GAA4OZT3R5P4AFDI3X7TPBNPHQZEVEWQ7UOJWKMU5O2MUT4R5KJGGYF2",
-    "GAA526FFJM2JNEI7NF6CDHHND6WPPZHRZARJTNSA4AYQLBKXXLQ6BWVF",
-    "GAA5BKYTJJDQBFRUMOACUP6IIVCM4PT7BQLJEZNWNTFPS43MDPBYGH52",
-    "GAA6MBFGIKPRKVXKFL7AXVIUSGTZW2QECOYG5UMBOEE4ZT2QSWUCRUTH",
-    "GAA6S4WAYTANAJAS5OTIHGIB2E4724E47CWXLT3QSLUTV253AWZDZH5M",
-    "GAA7RTPWM3N3UFEYBKRFVCRPQKFTOJARNZKLLQ25T72AD3VJ3BIEXDZO",
-    "GAA7W3EN6D2EFUKGXTHY426VAMNGQQ7CSTWKSHBPQXKWUWCZG6IHIONG",
-    "GAA7YEN5YZN4MIJT5XLOAJXAUVWT5JJKWCZUYYWNHZBOE6PMMGL7FXCA",
-    "GAAA6RMOLXRRCXFRVO2GOPBLKXENHQJDCGNP2X6HFM2UTHJU4O7OII53",
-    "GAAAMFEBJFPTMRTFWH3F4ILE3XS2GKXL3OY5EGECAZABZU2Z2I6FDSD2",
-    "GAAANDHJMUHAHCSWEIUR3SFLH6JZ7P7ESPQQ2OVDONEN2GPWJWCCZLLC",
-    "GAAASVA35Y7KPIPD6QOYE4J47BINDDX5PUPGKKFZK6VLK6JPEO6FLK5A",
-    "GAAAW5TRIWYGGKRZUNQSLNNMZWHCBJRGU77HB2QPK7ADPOIJ2QSYYN4M",
-    "GAABC6IHMCZV7CUH7FMIIRXYPKTSSR2B5O7LUHSMSYYD2YVSTBCU2Y6M",
-    "GAABE2OPRA2CHWMDZU2DVM35V6BXETYVHGMZO6Z5O7YHYNODR3NBOEHG",
-    "GAABKJNXGT6MFNEVAIJXWSBZHL6B36QVIIAGOSTVWTD6ZCGXSRFFLARE",
-    "GAABOVXTGV53R5PBUUHMOYXVJOZLQ7IM2RMC7RNZ4NAIQLPDQEM7CTGX",
-    "GAABTEQ5XQFR7XBN4MOKXOIVIIZ2FWQVTNXUVHZ2YGX42PQVCXTNQDAJ",
-    "GAAC4S3TCW2R3XEYMD2DWJQNNSIWAFSLIXA4OY5UVRNU2LSAZNGDV3ND",
-    "GAACQMNSKML3L6GDVSQ2CMQLCTZ2RXZQJF256MXU364RL56NQYKMX6JS",
-    "GAADEFLCG6P5UBN3VI25NGAOUHB2OSXQUJJLSM22GR6DJSEAF23XLRWJ",
-    "GAADITDFHA2DDSY3PSSKHCBKGP653IF5O7M7EPTLT5D4T6YSMB4DU5GH",
-    "GAADOPNFPLBVGFMXSM6BYLB6UPZQVJQXM7UFDTYJS5HSJ5M3AUZQ3OWO",
-    "GAADSLB4SGNY75BKR3M7FQRSD6J2UZAMFJWA5UPLBMQ2BLTAYFCLVDKW",
-    "GAADZBESH2F3BUKBQG5UT7XAS3JNOVNY367YOY4YM5BDZ4PV2UKVBTCD",
-    "GAAE474A2D2T2TVYVHVR3GPPC6PCQJJVIIWJ6EU5NLVXLMVN77WK5SOE",
-    "GAAE6GQ2UBT35R4MGS6PLZ3FTJDK3SVIDKCC44ERP435O4AQY3FBSHOT",
-    "GAAEKBJMLY6LHFC7BFTZT446TTAGKXPTXJ24R3VM75YEYRGRWW2YEBCH",
-    "GAAELKFD2X76QDPXI3EIYRFBYFI5GZU6LN5IBK3JPHISNU4L5O6JMKIG",
-    "GAAENGD4TCZPOF6HYVTA4MUID6LXAPFCKV2LFIAQUGHAFT3KMTO23YIB",
-    "GAAETEUUZKRCO53BQ7OWZVAIW6B66HSG7SWC5MW3WWPUHIXMYVHXKJA7",
-    "GAAFDV2LPBQMC7MK6M24PODOTEALMYLU2KBT2AXVHO7EKGKIUNEF2BVS",
-    "GAAFPSXLJL2ULC5OASVKVYIR2RKPR2CJOSM25DXH7TNMYIVEIN27YMEG",
-    "GAAGALFSGZJZWHEJT37BNH7IOYUDGRKKSDHNJHQ7RCGR6B3HJZTSCU75",
-    "GAAGBVCUW7JFCHNGF6GRGYJLSEM5CUW3AY3D3F526TE2FHV5U2HXXV5C",
-    "GAAGFPWKI7NHORSEJJQAZZ5SQ7WPAWKFRDQ4L45HTECE7QEONE3TCJ7M",
-    "GAAGUCSOI6M3BPMBGYNGEBJNZ36SDJGWGES2QNYCJE5K73LEQRPQABDC",
-    "GAAGWTZN5X5WB4G4XOET4GR24NT4PAASMWOYCEA6TCK2D6OX2T65CKSD",
-    "GAAH2VCWZB57DMEY7ABX6NC6TZFY6G6FN5UKQTQZ543RNXBBIWTK2Z2Y",
-    "GAAH33CW6GXL7SR4FQTJSHENGHEIPVPZP2WADDKSLXJUYN2NVTDLO3UG",
-    "GAAHEDED2RVACQECO7DTS4M7IXZLUVF5SU3X5ZYE6LYEFNWMERJESLVR",
-    "GAAHWDBRH2DCNOLSHI3VS5CPAMMH5FRZYKY5ZY52OMSA6QSUA2X66UYU",
-    "GAAI25VNKOXE62RIZRBSR6GZNCLVM3L4YEGIGB6MVYGWWKGDZ2Q6PR2B",
-    "GAAI2LURZ27XNLFLUSHXEDQUNQ4KC3LFR7IFA2JPKNDPFKOYGVOUPXAG",
-    "GAAITY2G4SMSEP2WHEB3GBWULEF4SPK43EMLGUYD2PFMER2UXV3ZGNKJ",
-    "GAAIWK4YU3YS6YIAICTESDBIGALOZYLMPZVU6WMZF4QVD2IKR6P6WHIP",
-    "GAAJ2HSOZJL6IYFW7MQ7PVJBZLD5725NEILLAWNWUR365JNF6LYLPA7G",
-    "GAAJFWT4HESYST5P3DDIU5NZSHLWYNJE6JAFJ3DM4QYT4YZ44SP32OZR",
-    "GAAKEO3R74ZKHVNQNC3DE2M545DF5KA6IPYHZVIV6XVYJWGTFFFXW7C3",
-    "GAAL44SJ5T4HGIMP4G6QVV5GZRWESHAZCO7GXLXDEW6TO52WPYLB5DJA",
-    "GAALFQZXUZBNQC3HBO2CROWB7O6GUCFRV5YE2COEWI5D35MY2BWOWISO",
-    "GAALIVQG552MPPWBUB4MXIIAY3KQ5Q4O4PFFYAI5KDE5PAX2UEKSHBIW",
-    "GAALKSWOSGVS5AMJL2FQRDBXUHH4SZNN2U7TRJ4PCOBEOQRBQIHHBTCS",
-    "GAALYCI6BRDOOWETZLX55ZD5PPUKYAF52QTUWVZZ2EBT6IL6H3UC4RCQ",
-    "GAALZX47N2MOBCZDNNPBXBRY6ZQGO27JNQDV2FUEKDODGAR77V34AXP7",
-    "GAAM4MG4SG6EOFJMOPLENPYEBPZ7TDHNJX5FI3W7MLNZJC5Y6HQONL45",
-    "GAAMEZSQJS2MV5R3WJGVDOHUJ7ZMWILXMAZVO6FGOTKI6KJ76ZLE7VVC",
-    "GAAMI27BF5MVEA6O6H3SHF4RWBXTVMEHOVE2VJQMW7RYPPV4R7GRFRSB",
-    "GAAMMU6CXAGJ24CRZWJHFBENNWWVPAPDDNLELZZ26OB6R2VAY32MQZB2",
-    "GAAMQRV7FQ2KH6URVSP5VD67YXO6R2CBHFGYUCVX5U4HUIX4XYFQRVQH",
-    "GAAMTCXEUCILVLHQ23ROIWEGAQY5GHBRBSBW3GRHCMBVON65MLF3RXAD",
-    "GAAN22ZB7MNO2P56G25CLAPLAWW2WJAXQTE5UAZ7GL4J4WXXNSPJCB4U",
-    "GAAN4A64Y3Z56Y2JV7KZHYIIBZ2MROPW3YFYUUZVBRBUBWMN4ZTNBBCA",
-    "GAAN5FQVFO7IM2JM3QQLZZCPWHDMKHJ7YLWEXSFTODUQVMEQX7GYUEHL",
-    "GAANHLIDL2EYYMFLQN7WWIH6UXYVSF5NBH2YQN5XT2XSTYZJZD5GCOST",
-    "GAANOGP77LVXJKZVVYW3IYBG4DDWRXZ2YWGXGBX4XKISC4QEGK6STJEJ",
-    "GAANXXRLHMH57DXV6DHMREI6OSKZ2W3XG2E6NFS4LLQEU5IRPID4EXLM",
-    "GAAO3SHYJ74PQ2B76CRG6P7O7753YQJAMDEVKIURFRXJY7PFX3OAKYMI",
-    "GAAODGPSQ3YIFSEADIJ5P5QC2F5S4MX3G6ABYVWNXBEP3NCL76E2AZDA",
-    "GAAOE3IQ65AB6O5RN4P62UJ7NBS2DEGESWLXSLCXKBGLSYHIALBP3XDC",
-    "GAAOJYEPV54WOWMFEQRB6UE4XV7SXCLHWDB3ZC733DTSWOK6HC3DYEA3",
-    "GAAOXCIIMYDH4XK3IHRTGHHRQZYRX6FH4Y5UEMODQYX3FKWJ5TBL5W3L",
-    "GAAOXD3J6SK3WJ3KM57TG3IN3KKUCZBHX7NJ4N3J2PCRWZUSM5QK3GMO",
-    "GAAPLWDDTMZIMAJNPVPHXYJUARHGR75JH5IVTBHLD4NRUS3GN6F5BMFO",
-    "GAAQ2UUWRDO32TVQRIZOEJFJ4WYS4OX7ZMJ6QHRKPKFCRBQD6LUWTJ6R",
-    "GAAQ7WW7HL4KRFR4Q4FQKCNX5OPHUPING43XQ4Y5MF4HLHB36W4PYUOB",
-    "GAAQKRRGMWI5JLXBZDMHEYITMHM4VGXRZ7HE2WLP3XLZJJIUBIGZLNNL",
-    "GAAR3QKPPD26BH5BQY44L6IWACYDCXUZ3CVU6FI4ELQKRO2QV5WB7K5D",
-    "GAARQAMWZX6ZJ7UUSW7OH2P2VEKA2ZE36HTBJWVCEEB33XFVK3UQFCHN",
-    "GAARRZ3HCQRHLOFF4ABLGAT5OKPWGV6REBPHIGFFQPEOZGNFTIJHELON",
-    "GAAS434N3DFEKTSLO7DUJG2JTQ6ME5CCNNVZUNKPHCIWBZY67HILVGBN",
-    "GAASBHO4ADIAQ2M6M36NGXZBMZBKDEIKRNV7EM5HTTNXHN32KBPSO4AF",
-    "GAASRRXGRYOJCNKPKIGBJI5IOAPHVDTMIABHHW6XNRIBOI5KSRTHPEDY",
-    "GAASV2HDWGYM7HP4L4U2JKENQYMGYGN3ITJKY7UVO3JBCUGUMTW4PDYU",
-    "GAATECTOETXQO27H7FORFWGHJAVT7RTNY7OPDATPTVENJM3XSTOKSXO7",
-    "GAATXH2W5I3VZQUZFZOBYFQ224NRVSRF4DN4L2KCSAZGC7JT32F2LDES",
-    "GAAUPWPAMBTVA2JEYJTAAPZ5ZMHPZGEXBK6S6BDVOHHP2ACBZTDUKGXE",
-    "GAAUR7QFLC5OA6V7QEPC54O4SQMMSEHAYY6A6OUOQWEBYYLUIDJTLRBH",
-    "GAAUTPM2BICMJI5D4MMI6M72DZDSUFDC6MDXZWGGMATRCLCAWY4SX4K4",
-    "GAAUV5M36T6PTW2R47YVUALWZ3D4VEN2DQJIF2LFBYTYNXUHN7EVEGLD",
-    "GAAUVH643ZOVD3ARFLSZ6GC27GCMOUATHQIOZOM4FW3ZN7QXAR7NFXRP",
-    "GAAVEI35LOVAVXLSY3YYVV6N2ZHEE3L6BHTCJWDYNHU7C2JKAU4VWYM3",
-    "GAAVMQVAO6QXMPEWIVXCQHE2N3FBWJKX5DHF3LX3JHD7PPT2RQFQLBQ5",
-    "GAAVXM33O2A3RY4WNKQD4ZGH6FNSR56UDR627U5AZQE3AVJK5YW6HO2B",
-    "GAAWAAGJVK7S7OFQGBWQPX3JAZZUGVMINMCY722N6ILNGOIOA2LACBOP",
-    "GAAWD3GGMXOJMPXTBIY55DB55LGXTTZ4ZMXXUH4Y7SNTLEZ4JC7FFZCN",
-    "GAAWOALDOWQUBT5I4P2XKXQXY27NJY4OORLESFD6GFOGDYNDCRECC5YD",
-    "GAAWOLYY7DQFUJ44AUB2XWKURJFHWB5NS3EJRUC4PY64H72EQD4V5EUX",
-    "GAAWS5Z6MCWGZRRJZ4SOHOIC52KY77EC26FUMYH4E73SATVNWFFQAHUG",
-    "GAAWSCVM62XMGCB3ADSRTPWSJOCHWJFC6ULCFC4D4VBIE73LBIZI73QA",
-    "GAAXAMZCNLIT7YY6E4TDZZPYWLMCG7TSZUCOAU45FB4ZNAVGGOHE7KF5",
-    "GAAXSDXUEY3JYEUJ7VVHB7ISRF74K5DCJB2HKTWBQTFUG6HZNSL6JFHO",
-    "GAAY76WSPPLL5JK5DRJ6Q3S5SQKEHO4BIQ2AE3GD36UPV4YEO2CGSKZP",
-    "GAAYANRDYRQ7BRQB5QZF4X36TDXTFQ2CWAMSQL2NIZLMYBUIZJT436KC",
-    "GAAYGFWOEU4F7LEYYVW5TOVTHS75P3CIUO5OJRAOOCZKMMGHOUFRBV7Y",
-    "GAAYWFQQ4H3OQL5B65APGUT5DIAIIEYBGIBAN6IMLM3JYU4VZIBPAADN",
-    "GAAZ2KPOPMQH4JGUBUZYNV667IEDTZY2JNMFXAJSAXAZ673KPE64TEQA",
-    "GAAZ623DSV5BGDADH4BTEZTPJIXDIVRY2CCE7A47JOUQDQRAQVT4CFCP",
-    "GAAZQWPCELTJHSLIRRXC5SY6EKOTBXCF26HI3VFZB272SJTKFAPSXVML",
-    "GAAZRB35WXC5WFQH44X2WSOOXQWGRAC45FML5CRJ5G7O54NNMZBD7YFH",
-    "GAB2FFJXNBHRD76YNAHRU4K6QEMA44CXXA3OXSIUY6I6OQB7SWC2PUOL",
-    "GAB2M75YMOKUH7E7F5SAQLVIYDRVEENCC3U52Y7OCCJM7E4GQLZ77ZD5",
-    "GAB2N35VFNC5ACKLLJMZRHMJ2SWGKEVFGGOFKSJH4GYANZMQH565ZXQY",
-    "GAB2O5FNAN6NTVKSGWQEOY3ZZILOR76VQKOFPDHNAZHNC7JRPE35HMSK",
-    "GAB2RNNKUVYQVW6WCQNGRRBULEIQDJNOBK4FGSNI6V4JY2ZVQOL6YUWX",
-    "GAB2YEHND4FSM2DBNXIICU4BDPCD2C7IGHZE4X4N3HRIZ23UWWQVGSHX",
-    "GAB2YUMFMUATREGFLPFOOT2TJIJD3JKQFEJSSR4NOMOQTELS6EKPDW64",
-    "GAB35G5ILWRMPLBI64ESE5N223ZVWESU676LDFPASU4UJSNWCTWEFD3M",
-    "GAB3KRXCPQ4GQR5DIIHDGY6UU7EJYS3DP5ZTXCA3CJFDITZTOM4ZBH4X",
-    "GAB3XRQYBLEYN62OMSGTLUJ7JJYEGRQ35HBVB4J7HNHKXESE4T4UFXW5",
-    "GAB3YUW6RFBJRW7X4LCPQPIVASDC2TCPNCXFZZWJWXND7YJGVZ6ZMBED",
-    "GAB43PO352ZCK56Y6ST4U4GOHIUSLZIQ7CKFTZSRPS4JFMRKYGXNPWBJ",
-    "GAB4DFYHHRHGQFNSVEF6CZBMMXR6KZGSKGL2UAMRP43CKNJPAMRZTRFU",
-    "GAB4TCUZ47ZIRFRUPORKIJSTY3GI4HZ5HZDL2JYYQYEEMFOZ5J4FDWAQ",
-    "GAB5EHLHN3NUYZCOW7ZLQPLPIBNATI274CMGMQW7CK65LO7UQGPHHVTW",
-    "GAB5F24DSPNGSCFYMKULK3M54U2SFWTOYQ6GGVR7QM7FB5ICXNEMJYCK",
-    "GAB5X3V6OJEDEYVS65E3UM346RUHIBYQAHOERJDNEGVCESC54VRIYR27",
-    "GAB5XELMVQJOEVUSG5A32GUPOLE2RSTKSC7HBZIMN7OS5KFFI6Y6G3MY",
-    "GAB6PMZTNLNTZ2B7YVI6KELBJ6YZKLY2GXNC36T5UBQ2EOHEZRMYVIET",
-    "GAB6QORYETWLZWWL7CRTFOT2A6CHZQTEBHFINZNE3T3ZJHUOCJ7UBUF7",
-    "GAB6VGHA6GQJTDWQVXZNO4KWXJ5CVU4NQ5JI6KPW27DQKYD7EX5N5BWF",
-    "GAB7AX3ZHNMUUX3FE5DJURTXMCQ5LKPILS3GNFCCYTTSH6I6B4UCGBD2",
-    "GAB7HBB3T3H7MLRJRLGJEJBWNQJVBCNG5TKR4XPG3U7RS3AGGR7CBANK",
-    "GAB7STGNN5GPDHCOXNXUPY6I5SKBQJE7UIEPY36AXGSDUNYZYZOXZ564",
-    "GAB7XODTB6NHFYRPFP2KVKHJAREI5TCW7ILD53PJ7UZK77XWDZKCMST5",
-    "GABAEEMOPPLE2UYJHJHU5JICKHZKQ2O6GGA432W6ZGJNKTHDLDOCI2IH",
-    "GABAJUF4OZPIY26DNKFBUI33NNZDKMUKD5RQCNVF5CW3K5WRCZW2FGKO",
-    "GABAXQSCCX3ZZIE6JTVS6FN75UL22L6ZDHVOZOTB7WDSLLBZMGKADP7E",
-    "GABAXUP4D4UP4OIPYXCD5NC2A5F5PJR6M6HX73G75TADOJ3PCPB5DPR7",
-    "GABAZWVRNH6JEOJRF323WIW2Q7AKIJYQNFBXBLMRPZYLU7KTBGTDKKA4",
-    "GABBIUJY3M656A3G4NZOOTT5HM6VKZ5CIBEZTBD6W7RKICPMHIES7F6U",
-    "GABBJP7NROQBECHGJ5KBHNV4ZUTBK2F3EIIQNXATE2I7TYS3TFZHMU3B",
-    "GABBMBVNOKVISGF6HMWMTGXSDMQMJBXP6XGBIRVCM62L6WKYXBR6JIWL",
-    "GABBPMH6EFP4QXL36ZVMQKMVE24XLS2DD74P6H76RV4OANOHCRL247DZ",
-    "GABBTD6KFAHRXGKBNX5EFBZ7A62TDVDX3EX62CJXCZ3N6WPEVYZZI4SY",
-    "GABC4VRT7FFH45NZKRZM7HFYZOSO2TPPQ2TOX7ZIYYVS77RRME5LSWFT",
-    "GABC7H3NPDJEBXEJ3E5FIOTF7437OXV5P6KY6UQMOX7V7PXA24JSBOTN",
-    "GABCALAFU4RHCIRILQ5VPLD5NSHAWYZHINSUIPUCPYZMH35VVGBSJTK2",
-    "GABCF7ZJODZYGFH6KRH26QT5LP3TYTBJU5EQ6H5F7TTWZGC72APDCSFQ",
-    "GABD2TVDMSOHDZUVTVPWG4FEBD2EIRSVSMW2AHCGQPFVT2SSGAZ7HRU2",
-    "GABDJONZVQA5NBF7OQ62N3WUGNSLHNUFUDZSQAAQYEFWGQU5GDJKXF4T",
-    "GABDNYFSKLABYTGXOF7M37BE72OMIK7F2LV5MOH5CSZVKXCDJASJFFVT",
-    "GABEKE2BJXX53JXTHY74ORQECR6RAHCJC74Q3C3SFC4F3NRSMCKLSX3N",
-    "GABESD53XLAURX2LBSWTZPEZKOX3XC2W2YFCF43DISYFFTA4O2AHMJOW",
-    "GABEUSYQH5I3RNQCPLNCNP6QOLIP6OTV77NU5FM44Y7NX4FR3UPZOUVJ",
-    "GABF6GVT4ARQ6UU7AAGSLIEHCZE5WVKOWDSKWALKQGDPYGZO3SLJNKHH",
-    "GABFYVWY7ZJZVWM2WLVJ2NN3C3DTCOQXNYSOHID4QJLD42APLAFLIZND",
-    "GABG4CGRJGUPVY66Z732MVD4D5OMVZTTZ7UNRW6WQECXOID365BRP6FB",
-    "GABG62LRVNFRQ6KXZLQUL2CZQILUT6V5MYOYWC2QMR54QWWWL7BWFLPQ",
-    "GABGF2BV6EZYK6XSJ7D3QH273RC6DEKI3JKY53PA72SR5VP42TGINLJO",
-    "GABHMWOTD4DOAXQYEIPRZ4WZWEO2ICYPT4UE5PZHKG4SQ5GG3YXV5USU",
-    "GABHOCNINVYS6VKJ47ZHWVYD7GVXW5F4MHV4K5T5QTGXXPKN5CX2G6C7",
-    "GABHPPVBFEQOFCW5ZXSVX3HMLBSTB7AEUJGSG5RC2HSAZT2EP3OVOYHZ",
-    "GABI65ZZWOGAS476SKVYEJR3MCLSJQKJ4BXWP4DLUPQGIOU6QKIJFMAT",
-    "GABIDZBAV7IZUOP4UGKZZQWO5SUIOLTMI6TBONBZ242EZR5UFKBGDYUU",
-    "GABIGBCBYJZR72TRMGVZ6Y5I5HOAMHQT7WMMSY3M5CIKOPKT3G5AFFJN",
-    "GABK3JZUL3TVYLN6CHMUMHYI5JAVIB2YK2DGTWMKHDNOMIRE672Z74LV",
-    "GABK5B24WTBYYWVFDU5GCMSM6KRZQMZDBMR347MCVA543DNYJQS27PW5",
-    "GABK6RNZDU5BBLFLYSNTYKXM5MVILWZMPPUIR4ANCIT33NA4JOHDH2ZN",
-    "GABL4ZD57JYZ2ZJMNUHDZDUKITW62VZJIUGZQCPW7A67CNSIBR65SLVR",
-    "GABLEHCDNWPZ3Z5FGWHAMKCUAHIVGC7VM2T6XFRDTFFXXFA6W3DPWJFW",
-    "GABLENXRYRYFL2XE5LOSEQ2YDGJ5PVYVRTM5QI6H66S7IXCB4B7CZRXQ",
-    "GABLKR2XHTFJ4I7UR2SQAOK5OF4A2OF5RWHOR6GMCXGJZBGLFT4UBGKB",
-    "GABLNKDKEPAWAQSPQ2YVYPDKH2JFDYAMCGUXKIZCDCMUCR2QEPZJBBOX",
-    "GABLU7ZRBY2CDXRLA4R2KZ7FQOKIRKKRSMWMRJ2KJ25GPGGBC5CMJ3WP",
-    "GABMC3ZONQ3JKRDLVILV3FND6WDQIZ465D6H2W5VUASL5WVQIODCDYEN",
-    "GABMFKVMDCF46TKNYMYPKZ2BO3JQKNQA7EZXV2FEASCT4PMXFU4THPSW",
-    "GABMK7AV2QOMOMZ4BSZNODTSGBPSQTMRGEMODZEQCNPXVIJBOW3QYUSA",
-    "GABMOLWQXSRGVGEJWFVEYLZ7RTCDIWOK4PWNTLLILM55633W7MB4ELON",
-    "GABMQT2WYXO4HLMZPPGOBZHTQ73ZOA7ZJJTWNYZRXDGPQIW7ARMOZ3WY",
-    "GABMVFHYVVCJECLRGFJXD4HLUYGW7PPUR3YER6H5QWK5P52322XSLAN4",
-    "GABMW25KRARB5AGFUXQKEL56LTS6JLSBDMQFU6KR3LY7A7KFUJAI2PZR",
-    "GABNBTRMHYKGGGRO3GHBC6T4D6WQJ3UJLA6GV76P2GOLEES3Y5RWXMMU",
-    "GABNTJONE7C7W2FCRZVQY2UT4Y5XYS6Q74S67V3TQZXH57DQK2K7WKWV",
-    "GABNVUROUNESR42TNKRP63CNK7326CYPBHFJYTESOWCM22CARET55FLR",
-    "GABOFIJ4SGIB6MKLQDY3JAFSXIHKNKJLVQEGXWXETOXJKHY3HPUV3ICP",
-    "GABOIEB6BNGZ3DKJCBS3H3VGLYZRWD2NCM77OCONSULE3RJ4MKGWF25H",
-    "GABOK6EL7OQFJYBCUN7QLRIADU5PIQZ6UMGIOOE27ESBQIWLXMPLNFKC",
-    "GABOPWUYP4YWBIDC7AQPCSEAWLLNHV3UA2MZLY7ORBVJWVKU6ABL3RKH",
-    "GABOXJYC3FOAOUB2ZY6OPFLGUNEAYXPXQNNYTTHQ3OTCIZGUOP5CIQY5",
-    "GABOXLCFP5TG64QLZUYRIVRJZA7UYTZ55MS6ZUY3377I6SVDR5GSMXXM",
-    "GABOYG7E5RSZNQ2TWFBYY2N6DRNNET2HNTKP4ZA7HHUJT2XPZSI4PTCL",
-    "GABP66N4CQW43URTBLLASKVYOTECLVF6FMAN2MPPMEO7OVKUKYNLCJDS",
-    "GABPBQJLOOTXESO7CKZJDJWCBGMVBOR2TGKTM3YHRRH76OGO377XRRSA",
-    "GABPGODWL7WKQVUSCFWG6JJX7DHMXFB3ZXLQ6O4AN4VLE7TQSM5ZEMNO",
-    "GABPJGRPMCBZHBVWT7A44MHAVRKGK2TZQCJP5EHFGEXSX2LQ3PGNLIZN",
-    "GABPOAF2NMM7WL3CH32Q4P6FLKNCVJI6AD3ZHK43FRGRBMZOK4AIQUSA",
-    "GABPR2RT3RDYFQBZFHTZRPUOH2ERORVVWCCWR6NSI43ZNA4NWELX7TN4",
-    "GABPWHLXXIHEBWEUV5KMJGAELZWHS3CVFLLZHZUIO25QPPY525KMLINE",
-    "GABPY7JCRN3KZPMZLXV2HI367KLR7TN6JVIOE5SBYLROCB4ZPMS2FA4H",
-    "GABQIK5MKQKU2JHBQIK23KFCUZLWBN2BCYZFLCCAEKAAMFWYLZQUYFSX",
-    "GABQJJJF4UYD4NGRILJR7APXLU4JX6OQMDDSWR5LMH2ZU3EEIAJUPVA4",
-    "GABQLADWBLQ2422KCJ3LZMXRUV4FSYOXXU2YJVMGHDXDA6HK6TLNEMKO",
-    "GABR432UNLWVCRQFX3WS5CMBYRBCFKCA7QZFQQ4TXMYIL3U52DEETWP4",
-    "GABRFUO6IVC3I3TXPTIVHU5XMZQRSBFM7LVWCWPZWVPVYMJ5TYG6LINK",
-    "GABRPGHHMLSOCOLWSVDJC55ZHMB5EJAWCX5RJCLLJ2WJT5C6L6UQSBW3",
-    "GABS4XR5ECSDS3WJOW3P6EU7XXKWOL5GPZLKFQYC2AHBYXYGMHKQANON",
-    "GABSZVZBYEO5F4V5LZKV7GR4SAJ5IKJGGOF43BIN42FNDUG7QPH6IMRQ",
-    "GABUISLUQCRKU4G6PZY7MZP2ZZ7AAZV53RFUUTCELW5H66GZYZQ2AQUA",
-    "GABUN5LGQFURTIJIC3EZ5SYD67R3WTB3WO6TGT6BR636STFYC77LXOYI",
-    "GABURJ6244GONDKKQ4NVI4FNAICP6Y2CXRE3ICAPT5JKTM7YQV2POG4D",
-    "GABUTUEUR2BQ4YEYPJAD23LV3SLQKYJDCVARF7VL3I7Q2BP2NUYBSIMO",
-    "GABV6XKLF3FE4KPQTNDJGWUNWN5F6K2JDPDBYUFYO6KQ3ILK4BHZ2KLG",
-    "GABV7ACTGVQQIHW5AIUMXGYRT5HJFQYG2XWAKMF2TR2ED6GL7CX5QVI2",
-    "GABWGUTWUVLNV436J5WGFYSK4ETW2AGQNFMLBJNFIF2N6VOYYG3UCJO4",
-    "GABWH3T3IXQVQUJHEUPIOAL6PB2YW57MCIBOEMGSYGMB5YBPYQHNCLMB",
-    "GABWJGF6QHRJ64W3GGJI2TT45QHHHJ35X25XHPH2H5DSYO5QIHHJ4YR6",
-    "GABWUHBVARY34MTP7GAH3HQEH5ZI7S2V3FK22JPMW3RYIHAXOK7BUCNA",
-    "GABXE46GC3XCOUPGBDFYH4SW7GAWMVZV7LITL5USVSGG7V35RTIDL366",
-    "GABXVQC3VQIXFJMI2GXPK3HUUN5XVEO5QPNASKDZWUQFXJJLXDR5RXXD",
-    "GABYFPJEABO2UDSZEJL53M2M6GZZM4MVBQWA7ROK4Q4OOAEPXSTRODDF",
-    "GABYLRO5R5RMJGPX2GFVPP4KH7W6TJLQ7FLRC5FOPNTPDODCLIEHT4VW",
-    "GABYMLLBVIZ7IKLLSMOANV7VV7III3QZ3MRDFZ67HEQUU7GNFBLEA6DT",
-    "GABYWJQ6YTLUEQ5755E25S432ZCGGR4KEJSWDSOLT2NHAO5BDS64MBM4",
-    "GABYWZELYNTHRPXUHL5FUCYA37FONSQNOREW7WY6FG5GDNRDFVFRB7PB",
-    "GAC24M3FVRDKOF6TGF3ZRSOU2CTCQE5DPZ37GAC2YZTNZ2ZCJ7H6UOXG",
-    "GAC2X25MYGAEHSI7FUW4ARV5MUSXLFNNGZI62Z2OXIVGRUBKLVHF2E6B",
-    "GAC35EARLJJZHVMLWO3UWLGDEKVY3AWO6CLVJAJHZPE4OBTOLDTDBREM",
-    "GAC3C5XNJ2SBIUPBESQNERWB4HHPPIIU6IG3YLAY6AK2LM3II467YEFE",
-    "GAC3IENRFTDJOY5OCCLDU6BMMAV5BMAADLBAUIAXYYDTD45IV77YNNMM",
-    "GAC43AO4LPAE2F7Y4QO57KNWVIJVS5FSIDRVYGJZ3VSM3ZFTGJIZAQLB",
-    "GAC44S26V7Z5BOCDN3KLF6ZFE3ZSPVP5PWL3UNWV2Y3SGMOJQ2WE5LOI",
-    "GAC4OOMCGXSNSALCUWWQY6GDUVGVHJJVZFWBBFKJSN2KYVU5VCFLM2QW",
-    "GAC4V3VGBQZSKYVM62EXCXQUOYORZYMJLOLB66G6W5HTNUTA53QCPDMG",
-    "GAC5HVJDTDIAWVUDBUNWFBWAVOFMLYKZ2WMFUVZFFCZYT7UBRM4BBFB2",
-    "GAC5QO5CPARYE5CJ6YKAEQ7JA6BB73HZFEPEMOIR5OB6ETTFDK5SVR2H",
-    "GAC6DPOEIKGFM6YGETNNBGP5FJLAUQILNVQE3GMOKFSQD4X774OQCNFE",
-    "GAC6IYY44RVAM5ICE6AJ675G6RMIQUPHKEVHQJ4SUFC43UWT4SHCGJZC",
-    "GAC6TCHAFPZO2Q6VRM3YTGW5BEI3VOZEBRBHXR3KB5XWSBUC5V5YYKQO",
-    "GAC6USFBPTCYWUOPQYU73Z5WSSFP2IKYZEG6V2HITZRTGBO5LUA22GPM",
-    "GAC7BCDALU5SAZF26MXPPQOY2BHXQG3EQTTAM7A523UB6INTWVLG4INV",
-    "GAC7HE3MQ6FITMSS4RRIJEDJIV765PIMXUCNJ3I274H7L5R3QQWP4CDP",
-    "GACAHN4KU3Y566WRBEFKJGB43T5GZMAVAYWLNNYDK2FXN6AYBGYWWLYR",
-    "GACASFEYMMGW7PKUXROYTO5G6DRJFC6ZX5DLPF5DKWLERCYXBH3ACIWN",
-    "GACBQHVA36ZNG6TN4KLI5XAKOEWINCOX2BNJAR7FR6GJN6CBBPEYFBL5",
-    "GACBZU7UNRBUTRXZ45XPND6PAGRNHDODHLGKDXJZKSM7DT7ZRVIHDRCA",
-    "GACC3NN3NN2MLF5L2AYHQAHPXNSC6KAFZIVPP5S27HF5U2QZZ3Y7NNL2",
-    "GACC6PYRZDZN7KTV4JMRI6FIFB7325SZ6GFLUDDNRKPGYYPHIOR4ROCZ",
-    "GACC77ATU7LXEXRSEWAXI364XPN44ESGTK6UMDXV6H6DDYGIFSZCF2EP",
-    "GACCIGTQTGUBKFPSNBOJC76667BFV2OFXVAE2WP6EFTGUMCYJ25EO2VN",
-    "GACCWNJU623BOPQIBZCFJF23EMHC64WDG2NROKGLLJO3IPQTLFDLQTOK",
-    "GACDLIA6DOVVZEMCM3K33N4YNHCGUHZXMQGGDK3XZPWUEWKLGTNWFDZM",
-    "GACDSJHTBRUY7WHISNOVM5RSUXPBTFGPAIETQKNGZE2CFOHZH73WL6Z5",
-    "GACE6U3RSZO6WHYXCDVEHMOQDRE3WPWXV34NQ6BLRX43PC7YTWDU56OV",
-    "GACEBDRGZUPXIM3QWOWV6NASH2Z7CMCEMQHQPQ2IRZJEYDQA7I5TPORN",
-    "GACEDND2RHKLOR7PGA3HV4FFR6LMGO3YS7E7ZVZNMQFXUYGXP2SGAUVZ",
-    "GACEFF5SO4JXA4OYDCF2AJ7Q32XVBVCTOBUH2VBATSMAF6BW4HUUFUND",
-    "GACFAC5XJOYSLLDOLWMARBBK7JZSAJNGKNLXNCOKTWTR3LGUUCADP7AS",
-    "GACFELUMX4YHFVE5CPLHSBEQRMF45EFI6IZV4L2SQRXINNVTM4YAB4GT",
-    "GACFJX77R2J5OGVSU5BTL7JF2OVCWW7EVYA6GQ4A4SRU7T3CIWYASEXR",
-    "GACG5EOY4WBQFPNJFQMVFVFWM6R5J2TMWF3YAYSWHYWNS5TGMQ5BCWVE",
-    "GACGB3HGZZRJ3EL5AWY6P4EAKIJNYXDCCIS2JFOOGG6JU6Q735MPLFEX",
-    "GACGB62O7AHUVT4A5KQQFLNFNKEDSSN3YHYJYSF3AJEZVYL3VACIRTLM",
-    "GACGF4TUHCVOCOWV5KUWW6CCAP7GGL5CCYZC5T7ECSVVLSFVII3VMGCC",
-    "GACHOMSCPWSPOBDHTNFFSIZ22KX4A5MEQ6UREXNIFGI22QUAOK4EIT23",
-    "GACIBJRD5ZZHUT66OROTHOCQ2QHLQ4TDO2Z4ETM73NLNWV5VYW3M5OWC",
-    "GACIWAMEADY6DGUV3KAFXOJP33DTHPO3HMUMKQ2DDMHLDRDWF6FSIHGW",
-    "GACIWSENKNLPYXRB5EE66OAYFW5X47QQ3I3CPWFTRWNYWOI3NAI2CF2R",
-    "GACJ66E6ZWI4KGZXFY3VHGHZJN4FZIATFAPVFSNMKRSYVT4ALXVO5ASD",
-    "GACJTZ2V5RN6WZOCHNCYR34FRSEOHJHJXKFIMCITZ2KMF2YMCPOIPHNR",
-    "GACJUWMWHZF2HGUPYFZG5R42AOENXIU3IMROYICPLM5ZVUVOJEKXN5T3",
-    "GACK3FMK4FTLTLLVYHBFFPGQ7FGPU4RI5ROA4SXRUTZQQDIDYKX74FA2",
-    "GACK4ETZ4JKXMR67BJV4S7NUSZ3FOQ4WUWRFNBRSVSLHZDIRTVOQ4DPR",
-    "GACK4Y2WCLX3TCUBJRXUCH3LQDAKZNERV3MGVDYS7EAYQWCFS2UV3R6H",
-    "GACKCOBOPSNKX2NELXZBZPPWC4IG44N2G2NYMTSAOJ5C4ZQ7B2NNSOFT",
-    "GACKRE52HKTSCGAW4773AOAFS72M3BZSDEFEJKYJWFC4QIDFNYMBGYKG",
-    "GACKYXACVJ6P4MBMOOKH6RYDRVV4SZ6G5E5UWCSA3ZM7NPBJ67N2SABI",
-    "GACKZVBX4SB6VAXKAGTQVVLX6STJN5REN3NSHAOMN6OV4Z7ST4AUZFA6",
-    "GACLXOD6JI3DOKXB2SVJ75ZMFKZLMLXKDB7KSVZXK4XUWVUBUKW5BINT",
-    "GACM4UAJQS4IDJ5L2M4MTLHTANAMNSRMG5GROVSLSQWYLDS3AFFHSGBG",
-    "GACMA3LLUAAO7EYZDUVQ4L4F3VLFRUXOACTSSPV4ZY77SNTJLSRNKQM2",
-    "GACMAGJZNR27RQMJ5CUCTWJXNPFE4FWPUES6JPJEWRKHX6UW2TMZJY6J",
-    "GACN725YNDOA45XJCZCF43AQ5VZ2AX5X3RBQLAOXM3XB32ET37JKX3LW",
-    "GACNMSIH77DJK2R2KC72E2EVBHDRRCEICDU5Y3LQC3V4V3CB2S7A5UTJ",
-    "GACNP7SJ2T364OFDTNUNP4NUROL3IBM2J225PBIWVW7G7JPRAW4OBEER",
-    "GACNZM2NNDYG4IS4Y6OFQKWITFOEAJAND2L5RBMOUEQIE7UC4V7FTGFW",
-    "GACOAYYRALR5VRGVEIFSWFAQPXRFREYSIVJ46SGIJCAKZYVE2PHGIIHN",
-    "GACOGMKPA23H5S3YRASUDKNOLXT6BFCW4QTVGXE7V3T5AWVM5IRE2ZY2",
-    "GACOOUGMTMZ3IR7F56CRG2WFTROGZR47ZRYETPFE32CXBXILTUJELG5W",
-    "GACP3AVU4VQDIDNVVEEHKX4RYJLC6TQ2H2UCRLA7FOJJGYJFECR62TTB",
-    "GACP7WN2CGAM334RST2MAIWF6KZEUDAHBCBJBPTRDLWQPPKTKIUYVC7L",
-    "GACPF7CB2NTWU3O55T5D2EHVNAO3QNKD3CY35MBLJLCRYC5VXHWYKFND",
-    "GACPJQ3BR74WYDLPAZZLR326CPU4IIURCUQMN36NEZ7OIAUYOHIRDROP",
-    "GACPNR5CB7SQG6K55MVYCDLFHT25URXJP5LVG6UVU2OUFTX52XU6XCBH",
-    "GACPSGCYOSFKWRXTKD6HAV6KUZRZ4PQFE4KTZZSBEKPIBPIF2PXHNPGF",
-    "GACPUKH77SBJBBZXYCMYTBES7ROQ6PMWPJTUVBCET7UUZWU2AP44EHRY",
-    "GACQAH3HKK2ABUNUGYNKWAZJQW3HEW7ZUCUWCDTH4Q5MSYGRNNNPFB5S",
-    "GACQAJ3EITG7VSLJW2RGSGSY2S5PDI3WWPF5UWSTQE7NI5KETXZI3ESA",
-    "GACQJPLM4BOY5EKAGWQECTOQPUGJQLMLDRQNPEHUPXHNDQ5NBXKU7HHL",
-    "GACQRMSBCMA2N3ZQWIF7K73YLFYAQIJM7EWOMLKQYULIKWLGVDIHU3MS",
-    "GACQVLBXFWOVVJEGTRVAOKS7CAMQW6HBLUIQA4HBCN335Z4CWNHVGPHZ",
-    "GACR4T2JTKTIBHJUOPKJRBQIXNETJMTTICL4Z7UMQRELUNCAWNJBQJ46",
-    "GACS56XFJ6D6YA6J4QJ2X4FPGPDYH2WL5WTVGAZK55USYYIW3R3KMQXS",
-    "GACSBUZE5DO3QA35IESB5UOVCTMNNOZE43U3CB264BNCXLSU6FX2EBNB",
-    "GACSIO4OJSGE4RQ3OWHKQ5UQUNXJMK6PZQ663SIT6S35ESUMRHXMN67R",
-    "GACSO6TXWA7SWK32KCGUJ2RKM6L6SIQ5MXJXAXMH4HPURJYTBEWGSCMA",
-    "GACSPJKPDHDEMLVDJPWTUYW5BMNIR5ZRTSWYWOMJDTCFBH7UTLVUXCXW",
-    "GACSS4HWYJ367T2YZOW5GBYVPQ36PCXXTTJAOWMK4SJW35JKZLX3XLTJ",
-    "GACSVTEUI55J4VJ5PJ3OI4LYCJ4ZEN3OICNRUJOO762XDOYW32BYJSV4",
-    "GACTB5KZLMRDFMWF2Y3XBCHNJAJF7IGBOAIBZQXE3IYZTNDLEVX4XUX6",
-    "GACTNW4KJZNE6VUIYI5JMT7V63OGXDVLAIFJC23YQLJEYUDYBCRXSUZ3",
-    "GACU2NOQ7TIADKR2YOGHGJDY7HPA7JWP2GFMCLVGF6FCYWVJ6FUPQKOA",
-    "GACU2WQVDWPUAA5UG5JM2I34YLZ76NRBSGB4UFZA3Q7KP2WHULPE4QPJ",
-    "GACU3HA63M2AFWZO2PVZXMP4NVTSTKE2NKZIGN4LIN7XYMNOLS4AEEFQ",
-    "GACU5ZZ37KBKF5ABHAJ5GVXRSIRI552B64YS4G57GMZMGTEKJZXU6B7T",
-    "GACUL3CMBG67QJRTXI7IVNHRKBH5D7FLV2XZYAYIGSNZY4DO2KMV2BKO",
-    "GACUPWMPM3JX744S6HXREDUTEO46Y56BLA33X3KXRS6UJHNBC3KKBE6N",
-    "GACVPPJTSXEJ32MAMMWRH62DEHN3QXQIGN6YPIHI2WGFOTFNFL5HQG3A",
-    "GACVTFCJNOD4FHR4EVMUH7NBVOEWPZDDHZDMNNXMKBQ4GXNV3FQ3LTHT",
-    "GACVWDM7DEUQDOKAHZGI75BSAFHZ44K72L2Y5I6EKFEEMBDJ2VYG27CA",
-    "GACW5ODQCN6WVSKU5KBFJBLLMDUMUYDTMEVLHWQU5NI4H4Q7GRSAXXL4",
-    "GACW6X4BESKKJTCXFEVWUZWILSFSE64X5HBRCFBHXOM533ELFUKZ75EV",
-    "GACW7Y2F3JN3CARAPU5H7C3A3ARPDDQPS4N56KZBXVXXEZKC7MPQTQNH",
-    "GACWBGI2IW3NHCWQ4SO2INNOFJBB43YZD6YR7PRGJQMWCBZHDL4EIRJ6",
-    "GACWEKVJ6ISN6X2UXXUQVGGPTMMPMU4WEBU62H7JBJGYVNPQPENBVBTC",
-    "GACWLWON7GD3US2JRCBJQWBGA33GFOCOYR2SOXCQMYHVUEP2UY65TKUW",
-    "GACX2777M5Q7CCRNLU4SHGS3H55RRR44DX6OI3RKRQ2SI3ID2I6I3AKT",
-    "GACX2VICSZT7BRJIPJVA6N4OK3E3MEOQCBOUO5ZDIFI3DOV46J24IAMH",
-    "GACXD23W37PDOMDYLC5YX5HYQL6IH3Z52YN2JMHKVL2DHLAYSKSUPTPH",
-    "GACXZR4PJTJC4JB3PZ3EDNBUCHETZ6CXJT324MSGM4T6UMQEGUUL4URU",
-    "GACYDIMQPATXX2YKWPH2SHLVU3ARS6IVD3RQNPH675FRHPJRHOFIBKC2",
-    "GACYLEC4TYX7U2CKE5A576RFUMLKC4EFV6CSUDCKAV253VK6PB4PYACA",
-    "GACZ2VCG4OT5XE22JBXRYWKRD2RRDZII22Z6KYBOK3M5MKKXOSI2RNSD",
-    "GACZ4RB636DVP5NA7GE3C7OZAWXOV7D4SKJEUYCO22ETHQJPHXRCFEYT",
-    "GACZM33ENKFH2PWPZPGKLS7L7YXDKUZFFVPEHQHT3ZT7ZX226FNFHWAJ",
-    "GACZRZHL73RPZYWV5FU4ANZELUF3KIVOD7VPYKDRIVQOELFVQBBPVCER",
-    "GAD2B7WFMS6NRAGFMKOS6H5SA7V42RUDHBE6APKQII7ILC2LN4WRDCUN",
-    "GAD2GS46LEIVEITZDJDKE6MRH7C6SR5V5D3ZZHUM6WOX3JH5GKY3WCSB",
-    "GAD2OMIEMIMFLSBDM53OYZCH4Y37GTNGTN6G4JEFSBUQP4OCTJZT47RL",
-    "GAD3LJNFH55ZF3LQYFO5AIV5Q4ZZHFUV37RDUNG74DT6O7TL4GUTKV6C",
-    "GAD3VMPV3V3I27W36NHPZVGOQ2J7AL4ZWTP7BZXNTZMBNBTXLNL5VXY6",
-    "GAD44OGEOUWZCKSU4UWVR3347GVUZGCKF6IABBQQGXXKZLMUDMD45W4G",
-    "GAD4M25E6OBGZPVI4222LSBZ3QON3H32L5VOPLJOXANCXTEVKE7J7OOQ",
-    "GAD4NDXGKHJR4IWTVTIEQ2T5JW24CYIKFJHG5ZB7A7EJ7HPONYPJLUDK",
-    "GAD4UXA7R6DXBVKNNDC6GC4EVMZG7OIYSKBPYTM6EXW3UWDGJYV5BFJY",
-    "GAD4X433AAB657YMDTQL5TN4TFBHXVNDV2FMNAMEJKNKWEWRN7FKQJEB",
-    "GAD4XLZLXJGPKYWEPNTQEUH7V4STRMDV2WETVKWUKM6C3D5HOR7LYCHN",
-    "GAD53BPPN3XQKBWPUSWGTE2TORI2K6ET7HXNQV6COKRJF2FX4I2H6O65",
-    "GAD5BM44IY6WOLXSTGBMAEDINA52OUUAZTFCH6T7AB6ZYXQGHDZHKQNG",
-    "GAD5BPZHVXJJALZZJ4B6P773VGMAVYXFWFAUQFKSHUJTDO3VTOW3OSHR",
-    "GAD5MXCFFXIBRBVXFB2Y7GQL52OGZ2WK5LRDTBB6HMIA7KYJV73LDAVP",
-    "GAD5ZYU5U7JTHJRUDHIGHDVCPWSIWNSZFIFC3SCWVEX6MQP2S3HCECLH",
-    "GAD6CNJYDBXOS3I7QFVDD25ZYUHMFDBIUE3XPYGACZ6NGFE7YXO5NUOU",
-    "GAD6EIBDKV65CSTLK2I5VORJYXP7ODI6OFVJ5I3DVEZW7UQIXH6EDR2W",
-    "GAD6FCEV2RBDMD2KEMWLI4ZJNCANFZNJMSPQD5REVM6YFFIZMSOUHISO",
-    "GAD6HXMX6MOOEXJLR65YONP2KHT3MB5B72HMW5D4NDJQO6BPHREXICQP",
-    "GAD6UNHROPEJISQ4WACIM6O67D2RWA3JVCLFVHQPVFI2APW7ARM3YBXD",
-    "GAD7NPBIN7CB43NRW75O2BALUGT5V57P7CSGLLFNX6F235MGD62VSSHX",
-    "GAD7UHORTMWSNLVTCJSIS23DIYCO2NGIF77OG2GGSLJUCVODMM6CHAIN",
-    "GAD7WVY2AQSISSM55CGFBK5OW6HQUA7CLPDE72XUEUOS3AZWEQZGJXLM",
-    "GADAEH7SN5STMGAMJUPLC3KLJNWHRXL35TP7OJEPIUMHCBPPNGA2HXDQ",
-    "GADAXHD4XNLD7HJD3V3EPCFSM4U33XKG544ZEVIHFH34WJNGH75QGUAR",
-    "GADB7UMWH3RVQZO6VDA4RZDNSQOLJLJ3YXGQAPN2EZIBPKJZGNH6MD35",
-    "GADBJUYRP6KQXOMJQNR6RVBARZDG4M2XLQ34HQ67QMH5WM2YO6VC3EZE",
-    "GADBKQLDPJ32TQWSJUO65RCO6XW6IRMP75O2W4KIUODP7J236VY2FZQH",
-    "GADBZ623S7YG2F6GEHQQVFACCVH7STTJY3HEC23SDNXC5KQE35HKQGAC",
-    "GADC6P32K3LUJKICLZSM2A7VCQV4FOSQGXTL53QNBSDGSZCV3WO7DINO",
-    "GADCWFP2GSBKXAJKUISQL4H3DAF7AAAUBULZYRW5DPMNGEIKNEIPTAHU",
-    "GADD74EAXV6PCXY6QTVE2A5BVFCTY75Q44TTL3LUYALP5DFF2TDF25VO",
-    "GADDD5Q6F5MCZ5LRGU5ZERDTNHWVNOJTX6U2YHIDYK2EP6ST52UM3J45",
-    "GADDFBNF4OGCDRROPSU27DTX36ORBREXKZ7BVNNOYAPL7F7WUPVX7WP5",
-    "GADDG5HY4LZXGNT7DXZFUEAP4ZIRPCWPDI7YUSZAUELTQEJACLCIAQVJ",
-    "GADDXROFHHCD4MCY5HATDH3IPIJCHYMOZXZLL36BAEYUJ4OV3W3SBASE",
-    "GADDYP5RND4PM62NAQBBU2X4XED26QFGNVGUC5MC54JIWUFULXT2KGRM",
-    "GADDZM5MT6CRCDTAV7KBS3EZOS6QGPW2NMXCMBECCDE5WYK2INJM5XFZ",
-    "GADE6AJC2UPTTXSAWAN5W5G7SVXX5BAQ53BQ66UNVKDSLWECBI3SSBNK",
-    "GADEUF7G7AELJR7KZPHTIQFKCF2U6XWPL3WQQPO4ZKMTZBAP2GLQPZYY",
-    "GADEXJMDOARIV4AU4GXEL3UZWGVDJOIH2BQUNQTG5C3IFV7WDN2KEH7U",
-    "GADF4DZG3MPSCBRPGQPGWPBSIXQRLF2DSMG6CL7ZGF2EVKZRBDABWQSI",
-    "GADGSR2ARVUMRT6HOKMJEB6YGV2DDIJL53KYF6UKLIFX4P3B65UISWDN",
-    "GADH233X6URUAMXUIMCTIG6YZDJTB2Y4F7BI2ATY2YBYYQGJS36BMMSJ",
-    "GADHXKZ2EW3QA7QMBS54ITL54QJD7E7FB7FAAORDYULESJBFVSTO5K3W",
-    "GADIE7DXN6PDTSEBKOGOMD4FXQZ3ARFFFIYUUUVGBAKUKJJTX247PELW",
-    "GADJAMLZYTTQFGYOHB2CUOOKZMEHTGMI3AT57KYGIZ4YXOL4NPFQT6F5",
-    "GADJBGP23HCR3FV46VAOW25CHTSBRPQG45IFKV6CCKWRODYYFM5FLARE",
-    "GADJHYNXRXWMBTCPXWK6MMBMGC7RUEMVOR4XFFRW426YNBIUALADEMTL",
-    "GADJRHVG6AP57M6WYQJ2RTEXH6RMTYOO5TFAIIVHBJ6IP436CRVGJ3RY",
-    "GADJZH6RR4B6BB3PI52FC7MVSXU7HO2EM2EQQZ2FRUFYXDZG4GUWYYZK",
-    "GADKAD5XWBY6EA3IY74JPMTSBE5IQM6HPAEI7WUTPD42V43FH3PJ7H33",
-    "GADKB4LJOO4MTQURIK5AVQBW6HQARBMGHBCE23XNJZYTE7WWXRILAPDG",
-    "GADKZTP65WNBSEVTELLRAJX42SDEZF5AGQI63GDAPHNJ3EY3KNNRPC23",
-    "GADLBEKV2M2NZYKEMXFOGCCSNUYACUQUZX35URRVNBPHABBO44ZFRRVC",
-    "GADLCY5OHINPDH5X645RUVMAOPWR2SHMF4NRYLCTNVZTT2WC3AQ5XBPS",
-    "GADLE6UK34YLCFNY7FLYSGIPSLNVUAXKVDMSNNNOLAP7PV7YOHFB4VIN",
-    "GADLPUDKYKV45CGV3XMZROJORFUMCKTHWPNGDQTISPUBLQ5BK7PVVF7U",
-    "GADMBWYKGHQSSQTAF24DLGOVLV3ORGCWUFQOVEJF2EUFFUOBV3E5JXRP",
-    "GADNUL4J2FDJCQTSRFKI6N7UNMRNHBHXECQEUVOBH62DVAHBEZG2GQTJ",
-    "GADNW3L2SYRDMK3GRV2TTM3NLK2OG4QW75D5O2AXRZXJXRM34UCVCN7W",
-    "GADOAANNCMI27JKBWB7SIW32FMEKTMNBJVUDGUAFZKS5ENOX3TGEFBK3",
-    "GADOGOA7IVM3CXITT6KC6SIUBL2ASXDHCS6AULJEC4QIV37JABVE2SAP",
-    "GADOJ7U5NPTMCOSOWWTWTWHJRLBWDYXNBJBCEAWF76VF3TSRCCWDWBML",
-    "GADOWI7IRNP2BTX57I4V5FS3BPER54KPXJE7RXFKIRYRGWUVQJD7KUWA",
-    "GADOXGWFUPZHX4ULXSWFKMQPPXEG32ZSJBWPEUZWDYLIBJUO4AOYE3D7",
-    "GADPBYUHIY732FKL6OPUJWYYOXDELSZH3ZB2XTMZFBYMMXLQQ4BIJI7Q",
-    "GADPPE2GIUFYP4TT5QT743KVM42TLJGFGIFW2H6GFYMU5IQQNN6UPM2Z",
-    "GADPWB4ZANUBP3NRM4MASZSOPOY7LLFIPBQQUIYDDOXOJH6RZKKPC3TZ",
-    "GADQKNK6B2IOW2W2YSVAIRZKDJDP76GESAPVRFYAKLNA4DYGNVPKB7BS",
-    "GADQXV34DUUU33CHISOUXDN6FMYAL2F6TKNZRYTVX7OAG74MZ5PGHAWD",
-    "GADR44XSPJVO555AYRQJOESXMECA4VEPU7LVNHCPUBKWGJGPFGJEG37R",
-    "GADR4PSFPNKPH2EPBJWYJQHROIUILW6YQXZX2UZGVBEWUWGSSYP3A7OB",
-    "GADRHA7E2IKXD2IXL6HF3KVEIUI5VCYXWTNNDOSIPTDTFMBYVQHZEZEV",
-    "GADRHOCTMYV5TSGZ4J2P5RDLQWIVQIAXUZDVA6VIT4GVIM6NYHSRTCAS",
-    "GADRPSKVCGZBAR3DUE4UFPNWGOYQ7H5PJMVQ45L6UTTHDNJPUOWD3XGG",
-    "GADRUZL7OAPCYHB6U5ST74B7L7SEDG2C6THVGV2WJS6NMHBXER3IWGJI",
-    "GADS5L4PILGYUNYWCKB5GV47CYZW3AV6GEVNYEGH6K7KTAALRDMFPWLL",
-    "GADS5MLBJS552I37GSQTEGPF66UNWDUZZCHAW5K6EADN6BHUBHZSOFUN",
-    "GADS7UYLBRXG6VFPGQZDRZEAEJG5UDOQLJYUAT4PAXHAH3LUOC6RSQ4E",
-    "GADS7VS23KHVWSLHUZ2KIGQKSD3IYITCTIK4LEVND24ZH4HPWTIUXSLE",
-    "GADSBHI4RUDKE55P437S72VVJHPYYLWMLNYJXUBGWHA4ODLXIGLA5ISO",
-    "GADSG3F4NYQNBQV56UYM5FMI54VTFGT6OEVSXE5VMN5FT2O26LEPULTR",
-    "GADSGTTVZOF3EBYWADZPFOCQZWBWCDS22IUYOMIZNWMPAUNXKODHVZC7",
-    "GADSJKUEGTM4QHINQXRTOOPPS6VOFIIQ7AEXZANQX7DUELVADXVERBHH",
-    "GADSNEIZRPFJUGGSGVJGLUQI5SMYUMQAYTKVLVSJ42X225MIFPSLTMJM",
-    "GADSOSTLROVJVLCMD2FZ6MCSA7BFUF5AFB2BBCEW5JYIVDMJVU6GPROJ",
-    "GADT2M6OKESLLWDTBJGLLU6G4O2UU3OYE6AI5LKPRLUWLOY25EV2GRE4",
-    "GADT36FAUNJMK62C6A2FEP4Y34TQ5FHLOAR6H4EJMF3QCIUJLUMTWXRP",
-    "GADTJFQIKYFKX4TPYSGIJ23GO3IY4K75M6DNLV5QQIRJVQLQX2MPDWEJ",
-    "GADTOXDPFGTHA6RTH2VA2QEX3IDCOMWNELNAUNZJRIMW2BFA5ZFNVM42",
-    "GADTQP5YPRINVZUTUJQQ7WZYPZTPUX2DQVOABHOZEKUBRZRSDO5BANP3",
-    "GADTVAC5BLUDZWU7IW5NAEJUPDFGI5RCYFAFVAYLUIERGULOXSB6XJOP",
-    "GADU7AKTXTYPCL54FSN6HPYYP7M4SIYN3YXWVTF7SRMXF5SUB6277HBX",
-    "GADU7OFMEZ5KFAZDTCIFH7VRRUKEZ4RI5AFDIHTUIKYRVDRCZZ35RADN",
-    "GADUAWWG5L7N3ZSPODWTIJSKL6ZRXN6DWZ5PWQAWR4OGR5LMWCYNCIGS",
-    "GADUYHSKEY4H7HD7Q7Y5GVIRMFOQMNY7YDKICXMBDAEJGG2VHLGEQWAS",
-    "GADVSRUJRT3OPHYBFB7JISAZZQAYU2J7G3PS6YBGD3VBODEG5FLG6OKX",
-    "GADVYE3CBXEC5MOUKT3JUPGMSH2USZJSA4G5G7UZ24CAQT7OQZUWDPIB",
-    "GADW7U2GDLAEUG3UNGCBJ5ST3V6DP6KHMKMCWFKVXVEGZRYIWLUQMOM6",
-    "GADWDDFNF2K3GIDQJFZDXMPRFR4O5SW4EVZ2ZA5MD2H4T3R2E3B32W7L",
-    "GADWH757SBR25RKK5EJKP7RVA66ZQPLTUZ63WE4QHD7PCX77WWUTITBO",
-    "GADWJDKBOXNDBQJ5VQX2SB576K6MC6DEBRCY63GGR6OSUTYSVRQITQNT",
-    "GADWRGFLBWOZYOQ2IPZBEML4F3GRRFHBSLQ7ZDWIGA5DHIVJM4PSRCPS",
-    "GADX5YLKHHFFGC3UQ6BUQLTWQYJPJPSLFLL5PXKHIPRF3VFHOPBFVGRH",
-    "GADXCVSFF5PKXIYOAAYDPQFGBO6KM4AXR6KHLBW376B53KVJNXS472KE",
-    "GADXKXIEPTLSPBT44VZLRYE5IHJ2D5WREEDPFW5FHSPUQKWZY6RMLHSE",
-    "GADXOJNZU4A7FTORTSMAFCAGZAN5TMNNDTEOIAF7H3E6BPLOIB5IYJJZ",
-    "GADXSJBTEIQRLGF26A2MGJMZU455S6IHNFKRS3JJL2VYO7IN45R2PEPE",
-    "GADXSUAABO5V647OSEQFR7T4ZOMIKUL5A43OJGLCCDBPT2DYX4WBQJHY",
-    "GADXUXLTJQN4UR5MJIXAN3UDHNGNLKV2MBWPXB7JMDHHQLAPGESNAWIO",
-    "GADYF7GS5D566BTGZHPV7NHKEN72FNEXLWSD3XQPZEKFTD3PJMAJLORI",
-    "GAE2E3SSO3CCDVV3DPZVWOT4HC7J5OEL4O65LT22GSZS5UTEB54VPVXZ",
-    "GAE2WGI4NVCA4NLC73OLH7B4IND7S7EDPRG7ESYNKCNJZY7O232KCXRB",
-    "GAE3AVOV2OPXP6V3YIXWE3E3U24ZI3BN6OODAC4GKOF3NBOMRG3IC4TB",
-    "GAE3BGDITDILBBCXB6DDKA2SLXBQHZBLZK4QDQDVDH4CGKZQOYNVQZFW",
-    "GAE3D4UZMUN3Y5BSGKIPS5OKX2IZFZNLBIGDVYMO4IXGAXMMC2QFASYF",
-    "GAE43TRQPJOFJIYX7DDK7ZZHWFFM4L3GFIKMIS646S6QWLOXQEZ65YEQ",
-    "GAE47E74P5SDLQRXNXS4VV744P64IS5Z5ZAIWZJTNZBFUHIYYV7TM4U3",
-    "GAE4GR5VMUR2ZTAZR3CJO6LJPIRU6FFML7GVHM2QCN36BXVCLE5ZUP2D",
-    "GAE4M2YOLRBLDOXWWDRI2AQVYOTU4UANE6BJ6NPVA2ZYGWCRIVCF2OIF",
-    "GAE5RVUXWRYBLVOTQJ4JDCFZRFJWNGXYYL42LLQPHMLL2GPCW5VAD2D3",
-    "GAE64PZWNRKJNEQD2UNBK342732KMQOOY3YJ3C4IKZGNRPLGBYMF6GD4",
-    "GAE73I3SNW54D5427FXUNAX5ZA7A24LXQIQFIFDLXSI3UOKNJZHRRFQJ",
-    "GAE7DA6HYC6C4NK62PGJLLOXGBNHRGP3VO5CM3KGIG3D3BWXP4N2EJKD",
-    "GAE7EYHFZ5VG7T2GDUV7SEFGPVW3J44OQKRINCHMQZONJVNGVPDSQNTM",
-    "GAE7WJFSZY766HF35YUXHBE7M72OC5PEO6AKMYWZGPQN7MRQGOILUXT3",
-    "GAEAJ6CFYTJMEM2FFFJRP7SVQ22Y33ZOPKZMNVAYLW5DEEYH4FNUAB6L",
-    "GAEBSUM6LE45CWVWGF676CMCJKCNIAYB5SC763EYHDXPA3HZJ4GTCQFS",
-    "GAEBWBCKUTZ4KLMBPZOWVHDIVNRPCPRQZU55TQYRXO2IPMLU5WYD7XLM",
-    "GAEC4YC6PWDXDA5ZDNWAEIRBVOXKXH7J77OTZTKVFDWSK26DNXCX2N6X",
-    "GAEDLNMCQZOSLY7Y4NW3DTEWQEVVCXYYMBDNGPPGBMQH4GFYECBI7YVK",
-    "GAEDUSRFYXOGTZZPCW2JIEW5KBBZC3EULDI6QUJ55KYGHNRYPIHKEKZH",
-    "GAEEQ7QFSQXLRWVWRXWA37OJ3AM3ZKCKG6LMYN4JDZWXIHE2B2XTSK7D",
-    "GAEFGJ6XQDG45RYQI4PJSPKYO73X7NPGVIDZQDAI5LPFZDPIIUYXYQ4T",
-    "GAEFII2UYX2WJK2M7UJH7DF7OWE476ETU227HMZADGXPY2QWBUMNAIF2",
-    "GAEFTUEO2KFJXQBJP6VCJY7LTMVCNHW2OOPVAUEFJPQWYKL5GW7KMRQX",
-    "GAEFYNKHJXLNOP3S2HZIVL4AVSZI2USZAPUYZMJ6MONZDYW6E7P33GYO",
-    "GAEGOS7I6TYZSVHPSN76RSPIVL3SELATXZWLFO4DIAFYJF7MPAHFE7H4",
-    "GAEGUSPAE4I3WK6G4PXTZIAMKXLDPE4FILCATGWOPHY2QSJQSKJ7AYGL",
-    "GAEHKNH35SSLMKN5PHOBJ2QSCN6KY2P2XWEKPEVITXRSZNV42Y75YY4Y",
-    "GAEHL5IHM4P5L7ZWRVF4ZOELLCXTEKE7RRFEEJCLO43MCXPYDRDZYXPJ",
-    "GAEHY2W4CJY7S44ATWOHDTDUJMGLVTGMI46C7V7E6EGOJOORWTP2H7UB",
-    "GAEIDAHUDZZPUU6MKS3FZAPMP2MCFNRJULN4OYTNL6QY4OS5CLG6QMSA",
-    "GAEIDDOZNURZ2FPTD3MS5WM4LHYGREJQ7QSAC6JEMMCEA5JHIOXB7FFV",
-    "GAEIRTSP5VVLLAZXWOY2WLTOMLICGBSHJ7I7EBIT74BL2GNMIUUXLZRY",
-    "GAEK5XORPGNRVEQRC352TTZYWKI45PDEATEQITH7JMADNFZNS5EYWNSJ",
-    "GAELZ5LISBOM4C2GEPSXB7B3MYWJ32TLJEK7CADGEXB63PJAXKRGZWUO",
-    "GAELZBVWCBXE5ZJA5X4QU64ZXOR6ITS24AAVCZ7YVE4XYTMAVMKIUHVT",
-    "GAEN5AQWPURNFZQIL33RLFX52IIKQBNQY2FT7IBJHLHV3BSNP4W6I2E2",
-    "GAEN5YMHOSKWAEC74RHAPAGBS763MGT5JHZKLSLMUOSNGWD6JBCUPLW3",
-    "GAENVR5JVZWGENLMVCKVC2HLFFN5AJRGAFJCNSNUWOMO6AYIUI6DVKK3",
-    "GAEOCUKWUHTDBQXAQWKG74T6VJUVYKXR5CELVLKNEDC4QZFLDTVOEEZC",
-    "GAEOCYPIX36WFIJH4JS4AYZPAJLQBI6BTA5TWCDACGHY3JFHTUOAFARM",
-    "GAEOGDJCIOWL3DRUSDBONHZQSIZ3DAD6XTWOYUNRP6LRDD32AORDZ54U",
-    "GAEOYHSE4X23GNZIVGLTCVNCMW7VQLIJSUDKNFPDQCXLZEWARLRQAQLO",
-    "GAEPESFBL2U2SX7SNLTJO73XMVF3HFPBRYS3AH6VETG7C34HLVEI37JM",
-    "GAEPH6GANFJ7IKHJ6GCU7EASRFJUZRBNDGG5NVWMB3MTKJBGN5GIZ4OW",
-    "GAEPPTLYO3TXTHNXAVLI3DVOBUA5RIKX6WCDXKIRQJGM4HMY5LJ762RM",
-    "GAEPR2IZK4QZVR5VIN3PVDUNCINOUA7GYQXT422L6Q7EDNVXVVE6THDS",
-    "GAEPSANBIUZP5KRWO6RMTYUO5XNEBNSYY7SZ6Y7ISDMD4WMSBXVR5B2Z",
-    "GAEPSRRJC2Z7S22V6BIDF3BFEV7PBYV6EFD73PN4PXAQZ4Z76Q6ST74V",
-    "GAEPVMTWR5YEXG6RLPYN53ESMJBQIRI2LTL5NS7A6YC6P55SZDB7C777",
-    "GAEQ5VP5WERGSRG66TB3XV7AWYZ6TCNTJSHS2UJOJDU43HADTQCBNBS3",
-    "GAEQA2V3PLX4DXWHUL65SZATE3ZOHWGAIE4GOTUDNB6F3J7HUDON5CFK",
-    "GAEQHFMY5P4GNUVBV6VOSF3B7D2O33MO4UYR64YYWOWDJU6WMRJUIOZA",
-    "GAEQIYCXCHUVUJEKMLD3PDQ2WD4OE5NDKSZXH76BAIEW3XBXBAQZL5OT",
-    "GAEQK4YYUBXOGV7OE2LZRESC5D5NPWWO2S3WRZORGL4YBUQHWOSLB2MU",
-    "GAEQMUUEBCZVH4AMD7VAH5LBC5TTDZWBYYCNAWMF6PSS7SDRFVPBRANI",
-    "GAEREPI7SYDZJDREG54KLLWP6ZBSQMBGKBKXX3YFF7S4XNMODRXAMCQE",
-    "GAERUSKNWUOFZYCVXXANTYY62IQTA4NXY4RSJWLC7EHYFB4CVCFEUZBX",
-    "GAERYYQGTW34HJYOJUHB55L4GCGD5WEHSBLRNWSO57CA4HCAMPATNYCA",
-    "GAESNSDTWU6NFBUJVT54UWE5IP3YOI2ONINTVWXJWBFC5OJCKQB6UGJE",
-    "GAEST2ZAMYLQ6NVMK2J6GH7H3R25IMIZACNNBWWB2PCNGZTZE55KYXQH",
-    "GAESWO25CH3VBP5Y2JZSBUY4RS26MOEDEGV5DKMSK6TFSZPTNVR6DKYD",
-    "GAET53YZN2A3G46PNBBUJSFFH32LNILKFWZVFQPHZLVQM3KZNNPQWMS7",
-    "GAET75OSQEXLN2DNQ5TNZDCLAAEGREFTN6I4G77FRF6S24COASNRO7V6",
-    "GAETBQITBVAGYJVTPEZ7VHZOWK5C22JVPJVHAQTTKFBK3DUFSX23TVGN",
-    "GAETQY6ZAP6YEPA6MH2EHYZ56VIKVINE2BBZCZQVH3JG3MVBECJU7NE6",
-    "GAEU2SWF66YQPOAFMRUZCSHR4KPYA5EUDTL7YJS37APGLXV6Z3TSFZFI",
-    "GAEU7K3YBAEIHEJIOTJQESJMWOJSTOAB2MFWK5USLXG46YSZW5Q3HR64",
-    "GAEUBUWTOEIPQQDYIODLL5XLHKLNKL4JN6JJRVAPFBRY7E7VN5VQHCSQ",
-    "GAEUHK4T66HDESLGYQQEOSELMR5YUDSU43BGPM7B4TP6DID52EFQFLTC",
-    "GAEULLLC4ZZ6Z6M43KZSIC7JRZP2UR4ACWWKSXYEC55TU4DKFRJCTADJ",
-    "GAEUMHTSRGBWN4N3QXKHTBKHDVWR7LP7MPDHRBQXKVAMEIP7SPNKBWIV",
-    "GAEVRS6ITV5EEIB6TSI5K744IZHDKTODZRFITW7WQGFBWBXXXNGXPYHC",
-    "GAEVSVLANHTIUV5PLTO4Z25CW6C3HFSU6IWBNGQNRCZ72FKTSY3I42ZY",
-    "GAEVUX33OIWRWBV7DVGAXOJQGCEWT65LTX4G2UVJ2EGCBIWJSOVODYEN",
-    "GAEVZTQ5M4WIXVWNFYIWP7ZJPKPDTLRJGZWTXKVVMYMFZXZZFZIKFQFS",
-    "GAEW6AONBX7JJVB3CWAIDHOSAR6DCR6LOQVX3GXIECUOCM2SM4WCI2NA",
-    "GAEWADXQXP6FIZ6FADEDYHHE6B2JYBMKQZ6RPSXMDNUS4NXNTQ3JCOFP",
-    "GAEWROQWZA2ZGWAYFQZYVMIAJNP4WFR5T3K26WXXDOLXFKHYAOAQGARM",
-    "GAEWTELPEVNYBLVEHKBKETZ66AQZZJOFUNW3BLRKTHXBHNUJFRDD24YX",
-    "GAEX727ZOHWAE6AWEKJEFHJYJ45QKFD3GNWNHUWU3SCHMSKOPU6N24SS",
-    "GAEXLKDXJ5GANDJZV2F3LV3GM6FZF2YX5VNMKBROCZVESDPN3JJ2PTO5",
-    "GAEXQ472ZSUY3QW7NYBKBPRBD3FB3BZJGM7T5M4IYH7M3HYSQZSNGQSI",
-    "GAEXVNBHQ72SL4AVGDTHPRHV3QPRDZ5HRG2JHBWOXWAWKFN3SD7YJFLR",
-    "GAEY6IGZGC43GNJP5VQWX6ZKDCRQQMZCTSKQNSYRQDPPCECNQDXZHMSU",
-    "GAEYDPBE4JYKQHPCNVM2H5I34B4BQCAFJC5FFRBF6L5KAF7ZD7THKGSG",
-    "GAEZ25BSKA4XJIVLICZE2GL62UPEG5PLQ6EDDNAM4WDJKQOVUJ4ZYVBV",
-    "GAEZ2R7L4OX3M5SOVV536VWLG6NS4QVPJ2JNEIU6WMAWGNRF552KK6WR",
-    "GAEZROCZZWIE74M2JH6XTB3DFGJTRLOEZMOSABOX4ZEF5VIASJQXJ6MZ",
-    "GAF2AWOIGBJSTSHG7WBFJUIQR4M4B6ZM3IMHNICUSG6LTRRP3LHVS7KH",
-    "GAF2CTT2OXW2ZCXQOYAN6SHRHQLQVB45EN3JLZVQIL7J2GSFIGJN2BCR",
-    "GAF3NAA7W4IUHHMBCH3W32HRQQAMZSOH7CF62KJ7I3CKYLNYPDODZ5ZN",
-    "GAF4334DJZI5VJC52KAIS6RIUDM3B5KGJMKTDP5DNH6AVFX7I4WDKIVR",
-    "GAF4BNUL6YSORQ4T3YFBLGX4XLABRQ44OOLCKQV3D5U5IXW3SL6AYIJX",
-    "GAF4E73CBIJIENTXY4W26ITOLSYS3VVGLUVRJRO6IRSMNRF6CO4H2BUK",
-    "GAF4FJJDYODA6GMHNB63NPGO5Q3LGDDK3KWS67NF6JK7QJYQGYUWQIOT",
-    "GAF4HEADSUGSKRL7VGUURYIZ5D4AQOOP4NCTMJPKNXYR5537A4CACEKM",
-    "GAF4JSK2Z6FKMQHETS4JF3XBIS35TN2NOQV5KZOQJUE6LKL5NLPGGG2F",
-    "GAF52R3LKPISLCANQDYIRWCDFSUDROBTOO3EFA7OHNFPRD75PUL2JBF6",
-    "GAF5SSBULEHDVJ4JXJHG6ZCB42XIAZC3OTASFFWJVXDFEFHVJODL6JUJ",
-    "GAF5UAOVWVQUS7H62N7DXSGU5V4KM27T2CLPKXC2OGV25MAUYMHTNII5",
-    "GAF6QK53KRT4R4IWTSXMMO7NMSY7WHXI6B5SRYZ6EZY27W6GLCL5WYJN",
-    "GAF72557MLA3OYTNJHLQMSP2Z2ADY5NCAO7NS3NYHJ4VHUKYYVXBWW7S",
-    "GAF72HRCPKHVYSSVDAIKN56XDRIH6IWIFS366HB5WFYZMKRMIIHKZGPJ",
-    "GAF7DQJW4DP54SDZRQVOZRS3ZWZMKK2JIILY6AJH7S7V7ONTBAYEBOJH",
-    "GAF7GKP4RACIR44BALN4NNLLWW3VHTA623YIWLXBHPJREHHP6UCOMRY6",
-    "GAF7IGBPFLXXTFFXG6BLJCZ4ZN73TA7WDISICZWVDJEBTCOMFI7JSTAR",
-    "GAF7UXD5PF3GEBVX3TZ4LCTET5UOEXEI4AOCCJ42HHIW6VBWPS356QL2",
-    "GAF7VSJMN5WDKO3NDVQJBLUKHVQHYI2PK3VS44B7GY73ZC7PXKSFAQBN",
-    "GAFAGIQGR4AUMHZ6IRZSCM7MUHFNWAKBXHTA5FJ4D6JILFXXY64YKHNZ",
-    "GAFAUHBHVSQAZORS3HXVRT7O6XGVH4R3GO3ZMZYUZLLDJEWCVWXUWRBD",
-    "GAFB72FATLSF24SKCPWMNRGDNXX7KNK5RGBCH63AAPYQ7RAGVQ5DDU4N",
-    "GAFBMP7PJ6DGRFRDONLOWTDEB3NABIB6YIMYYJZ3S7OKHDLJWHKCVOMB",
-    "GAFCG2HAEBLUN7T3VRIK2P6RCBWOVUWNHC2EK3ZC6236CBVT4GBSWISS",
-    "GAFCN2N6ELLWANBONKFJCTTHMOXGVKJP3ZC4VNABH5GCEDHEVT7UZIRY",
-    "GAFCPW2BP4LFDEZWKQNDY6Y4Y2C37QRSO75RG2YSOPQAMSTXCFNI6GXB",
-    "GAFCRHA7QRQMHAR4S4RUUPDUA6IGMT2KFLHHPFAKI3OV5JXFXCMXFTR7",
-    "GAFCZPOJM6PK5M3RDUW2R5HYIRGPXSPQAN4SKZVYLHS5UBCNMTLVDOGE",
-    "GAFDYQBY5H54UKC4UOOLWVAFIQHY5D37DYRJSJG6VJSRNCA5IJ3N5K2E",
-    "GAFEFO6XNTMYU4IFZ4V6OUHP27FTG2FSTP3O64GVNTEU2L3AFDBFQZ2D",
-    "GAFG3DDKIY4NTX3BACJZ4RLQIZFSRKC7IZHSAA7EW74BTD2HTBG2CDTO",
-    "GAFG6HG7IG5RVLON4PCQUWDU3NET7VISCPKQMUKLXXDRP752AI46VBEQ",
-    "GAFHND2BYMSR63LEZS4TYZV5MS5B3S42OFCUDNLYRG3NDWMSWG6XY4OT",
-    "GAFI46TSTTYLZZ3HM3OO7GAQTP75AKD3NLNNEDKYAYW7HJDL5RURPLNA",
-    "GAFIADFPKMOJY5SSILL6VIYC6MGQBI4HPKF2Y5QYESTFM56E5QG3ZMOE",
-    "GAFIAS65B2POLTOEAGQ5C2JG7LELSKEX6Z7IST726XNPRFFN37BHLXYX",
-    "GAFIV5RWDXIQA75VETEI6JT2H2HTJNWPR3BMJUXKF5OCXGPTQ37GSWLH",
-    "GAFJCUQEQ4MKWMFAV6GFKAJTSLE5NCAZSFYHH252S37UMB4D3TG4RPWN",
-    "GAFJFTBTHUP4LCKMJIELIC5KP2AMSC3DCOY4UBIBJWNNVNO4ENKAGJVU",
-    "GAFJXYICJS4UDR3NKITCECHPL7PNY72XDOVMLCKHDEPWCUX23NJOYM6R",
-    "GAFK5WMQNZ5UZXNSKOXIKRJRGLV7E5QBSAF5WG7UDHITEQ7YMD5UU4TA",
-    "GAFKAMLMOA2YCPFAS3EK6KSPMNHUWMQDFDA5PJESYA7JOQNB4D26PXPI",
-    "GAFKB3Y7QOEUWIE65DCRJ4H7XHAUBBRTS5H34RAPZ3CMIP5AZ27WASDL",
-    "GAFL22OKNR6MIERDSWQFSEKJPRURDV74ANVMSVDC73ZZW3CP3ISJANJY",
-    "GAFLOMSBHXVBU5LNEC43LUNHNJTG2SZRYAYPWTEBFEC6V7MFLZZ4STCK",
-    "GAFLUFPQXRXQMHAU33T7OE333GAURICWKLIBNUYPVZEAJBAJ5PYOSR6A",
-    "GAFM5RIYCPH34PCUHOW3LMCEKP2FXTAP2TTKEOTJUKHJH6D62N5G3HGK",
-    "GAFMBHCKNSGNVS5FX53Q4PI57KVUBOIWXHFAY63YBNA6BIPOY7IENWUM",
-    "GAFMMGOZ4WEO36D2RDZLJCBR563YPC4TRBAZAWLN3SAZKMZM2IJO7MP7",
-    "GAFNNROKZXN5RAFKS3FUUDDB7U3WNGJNV7GO43TPOU5JR4I2EPGWUNEO",
-    "GAFNTJCPNSJIJJKGVKVLD3XYZ4RRHNMOURY7NZJ57UO3JXXZL7K4NSJE",
-    "GAFOAIWZQ3DKVVKOZ4JYFOLX2BGDICAHELELB6TTBECH72RCTOTIQTZ6",
-    "GAFOEMTWHIMPFZXKG75FUPLH3G5U7K2YGZY55GGIO6LSIDK32KUFLARE",
-    "GAFOJ4ESVFDXROIBNXOF5B2YDWWX2SKCTL3JAEXKTWHT7QTOG2A4MNGW",
-    "GAFPH4J7F2BTRHQTCGW5Y6VMDHXYC3VKZRKE3DUVDFGIHITL64G6OUHG",
-    "GAFPJGXAIW5NP6SJZIVMEU4FMBFIKNSTEP6RC5IOT4WQIEGK6RT4ERML",
-    "GAFPOEFYZIEMLIECL36LGWTHEOK5FFFCVE3Z63LPE2DZVBQPMFVVUQVT",
-    "GAFPPCPSNVDCRMFW45O2JMX66DFTME7FCFQK4LN3IZJIBINUS7MT6HUB",
-    "GAFQ7L3OCT3X3SROTLHGQ36SFM7RGTJ4PI4POOAI5GFE7QXMWPBYRFRP",
-    "GAFQBCA4JSNKGQU5QL5RPIKHI5LOPKU2QSVR7G4AR3J7B5DV35ZUWOEC",
-    "GAFRQYVNWDKHBRJT37R4YDPUZ7KCKXZBLOK4RFH6TTN3JCMDNDCJFPUF",
-    "GAFS34J2HEHLI764EVZBLZAPYR7R6P4JQQ7MJESY5CZIHLPELJF2FXEG",
-    "GAFS3WZTZ5ZOAN6FGNN6UTOINOBQ5TCH3C6DSFYQQST4OKD73GX7X4Z4",
-    "GAFSLG37SMULCC7VJ3RW7LY76RP5UOAE3SJASYVZ552WKWSTA4MRY6E4",
-    "GAFSXKYRRNQID6DSV5EHN3TURCEN2AVV3QAXSHUX7FLSG2TSTVUINDUS",
-    "GAFTOYHUZNTXLPR455NSBLADAEEU7Q4XLVGGEWMQ3RUXK6U6RO4Y2YSJ",
-    "GAFTPGTDTRIEYPKBB7O3FZ6GVOEBNPNKPYDX7ZCHTCOC55U6CRLMHY6N",
-    "GAFTXHJ23GAA5CGYH4BSQ7V5FMIK5VD25MBVK7GNK6SVMRUOKNLTQS67",
-    "GAFU76HMXYQ2V6U2OVMJSIXPVG4AVVQNJUOU3VA5ZPN3OKD7RO44OYMT",
-    "GAFUFEY5UNBAQWDJBLQUCHPZAD6LZCOZTCBMNBJHTKZKYW6KFQBLBH6A",
-    "GAFUMJS74EBA3ZIMYRVXKZBJYN3I7IUTU4GH4VT4LDORQG3WSEIMXLXC",
-    "GAFUUJTR4J6QSBBT3R567BKMTIHUBLA7MCDVZTWDZJJNBZHMYZ4EVU2Q",
-    "GAFUYCKXRCRED57SCM67SVCWQOWST2CV6SM7EVKDKNV2AFNRAFCMSGUG",
-    "GAFV3NN7XFRAA57WAJ4E4HKJ6ZRH7ZUUF5XRA2R67BB3L3Q6FXCF2MZV",
-    "GAFVJSHW2PSDGOMF5KLEXTFOVP4FPOOG7UQS2COKIMR4AEXBF4HE66O5",
-    "GAFW6XSJC33GXI7Y5POOSNZHPT4V4JNCLQBOUL2OVEPZTENK3XBZIOK5",
-    "GAFWH64TXCQ34TD7SYAIZR7DC5BW22YY47L2MARSO3NZ5ZSCA3P653PG",
-    "GAFWKAISMAFXMAZJPS2AJUB6UMJGSNQZMUJYTPHPYHAN2TH3BGOVU5UA",
-    "GAFWVUGS7FNQMORQKRGQ5FUXQULUAKY5SWJBOEWYOBJXR3SNON24OITO",
-    "GAFY6TIT4K5NKYQUVBBSCDB2NBYFXTETIIH2VKK23EK5WYVAXUIMVT5W",
-    "GAFYQNEFV7F4VB3KB4AHNQZGLDQVTOC4UJKHX6CIE4EMPKZFB2NPFXLM",
-    "GAFYUDBGU335ZR6DOE7GN5QJ3D3OHTH7BQAK23UZV5M2WOW4UH73B623",
-    "GAFYXNRVLRVKNQPHXG4LVCW4VRDVRZRXNIIRKP5AZ3XWTDBVFGKFAUJD",
-    "GAFZLEUYUHANNLTWIZJXPQ6GMZKSYS7CFUWGTERBL6ZPL5ML72PL4AWY",
-    "GAFZNZYNTKXZAGPGVRUZRTPGXRDB2JL23S4QFUOA3C7QB3QOR5HAVPNC",
-    "GAG2776WA6FYSN7BDMWYYG6TTYGOHL4T5GZ7IZBRLBXYD72PXTBZ5FAE",
-    "GAG2OO5AUFECKAFMPDMCYYIP633C46SHYT4I3AIVESV63YY4EWLHRAK5",
-    "GAG32IY3BR25IXPO4IUIAND6CY475D4NJMPRDIBLCJAC4K64AZVJCN4N",
-    "GAG374I2NWALDP5KA2H3FHBNKK5SC3IRXI7ZWNPEEMY7OXXFKRYGWSOL",
-    "GAG3DYXFP7SSFZPTCZVEAXB7NEBWP2ZPACAKNFKAI3Y6WPEAYOTQZPIB",
-    "GAG3RNALFUDTXYXYNISZXCXLYLX4IOBSAC7QUZUBIBEWTBIM5OSGUBH3",
-    "GAG3S3Q7DNQT46BBWL4S2BFWN2UHLD6DQGSI3WPAED2XE5OKWTA4OQYA",
-    "GAG3ZEBKQ7A7AIEFENJTENB36YD2FPAC2BEB7VFML4X265SB7TS44ZMO",
-    "GAG43XEI2V2KV4XBO6ZVSXBMHCGULBZ55WLFCWJM46GRT2UFBPFPEKKO",
-    "GAG45OHFJRPLHY7OWFAC3UOGVPSXENNTTPBVWIQTDKXZEFSRFP7AWXR3",
-    "GAG4GOBXWWNUK7E6CKQOIERPKYZCRQTIIJSWPVO57GS44FKQ65JMYRPP",
-    "GAG4HFHE2VGZUK5RR5YY7JYESTHPFAYJ3RYITV4UJCCCN7NK6F77BBOX",
-    "GAG4QB25JVRYTZKBM2KZSC62N3QAN4O224KLTOKTCET23RIKUOJRGPFK",
-    "GAG4YKWKMZRPIP54TMA5R3ITUOPJI53PVATFVD46BFOIYT3JLATB2A7B",
-    "GAG52B4DKXFPR2TL55LA5TSC3FONZ4QF2UIDIWIB6PLB7RPRZ63GGOLD",
-    "GAG5F7YZ7J2QCDNLO3NA4COZ3GKVLNMLVWO5YRMYN5LAMEMGL4O5TAHV",
-    "GAG5FKIBB3ECXIPDGUG6ZP7C7AXHAFBQIRU2Z6BQSKPVUSZB6WXRRCYT",
-    "GAG64M3IO7DMWLCII64VTVVHH4IKBSEH665BJXCXJ64O6DPS5P5MNXTN",
-    "GAG67LT6347M5U2ZVJIHLSXOAWF2RUFTOSYGEUFOW6QZYYWG5DHTRUMP",
-    "GAG6EU3RUNNZ22L7KDW4NCNP3RW2XO46JE6JDR3GU5WWCNRAC43ZWNJQ",
-    "GAG76R54WDVX644GEY3AUZNBQGE4MXRU46HK33NBORQ5MOG2H27ENJKF",
-    "GAG7KOIV6AMXTVL6FPNRDK7UJ4TDS2SBQ66GNGVUXIJTQ54UB4PG6LSL",
-    "GAG7PCGBPRYRCYHUMWXCN3KVSEUAR4TERSO4BDINWAWHJHIAF5R6LC5Q",
-    "GAGA3GWEUKJTODTYH5IAAVIGYFGHFPK6QZZKPGZGIBEAZH5BF52XCSXF",
-    "GAGAA25E7EEKNRLLIIFURK6HZ3ODE6CSDYOXRZBJKASO6XXHHDLFO77C",
-    "GAGAC5CCQOENNRSQKKP4GNIF4OJHULEOQUNTNAFX6VQ7OVG7BVVJEM24",
-    "GAGATS47NPZN753XF2FQ64M2L3BE3D5NRSZLANDVXOCARHN4YZUEQB7C",
-    "GAGBFZKXDIF3BB2O5F5INYVQOTBPU6CPFCQN2Q66YUTXJNJDDK24LA6H",
-    "GAGBGFCNQ42OIPQMRPEPTK6QT5XMGUI3RI2G5FW4LA4MMX465NNGBMQ7",
-    "GAGBIMXDPQYAN5PKLJTZJMCIMWXPZL27H374Z3MXUIMSBZ2YM7R3U5TY",
-    "GAGBQL6TD6SAY52P27EODBP5UABJ2T7IIJXUHZWBR43NU6AP23RRMDN5",
-    "GAGCFXA3JT6X57KYHKVO4DXOVZL24T2K26Z4BLWZYLQB7EBEUXITETOK",
-    "GAGCG3EFJKZYYMSANE7I666YRA7M42J3VK7ISUU3YOZEZJDPPZN7HPX7",
-    "GAGCUMVC5BL5N5VTOMSJ2WYYDFF35XL74XKLWUYQPT7I4EK2ZKVOF7BG",
-    "GAGDAK3AN4SJNV6GIURZFYSKFHGEFO7BWHTK77SQOSXRSQ3UTCH4PPNA",
-    "GAGDHSZ3HGVV7NCKR4BVJDD7XHALGKW4OGRFZ3NM5WAOCV2ELHJ7G5CC",
-    "GAGDYE5M7RILI53NJPXB5B3AS3VB43HRNDCA4JECVXLVXM3C7FC3AIY5",
-    "GAGE2ZDTGJUDY7B57HOJ7GDEOH5XAAPITMLGJ3U3KE5ZZ74WJNUR6OYX",
-    "GAGEI5TJWVKV67B5EBJVEYVZ53YWNONH3G7RK2HGHNNBNKQBCSDT3ZK5",
-    "GAGENTS2CO6JAY44FL5RF3GNSKCHMTKJJS3JKKH34O2XICYWGG4IY7OF",
-    "GAGFIROWCQSEEDDXAICLH7SPZK3WTMFNSQH3SAHL5NTRRUKO7KBKBBOX",
-    "GAGFQOMWWZW2Q5CPS2X7EEHFSDGXJ34NH5WQ5QKD7463XIZNMG7VAY32",
-    "GAGGGVMSD4KN7PGXVILJ7KI7TMIVQVKBWFY37JFYMNSQNOZX4E5H77DJ",
-    "GAGGWZST74BALCAZNSDQLMDLOYRIOJTQUYW7HWWR7LZWT7RCOMLMJURA",
-    "GAGH4JIJCNREERQSX7HPPB7V6LYG6JF3M3BNGZQ46JIFOM2KRJQ5KBFR",
-    "GAGHEFI4MNTDGSUKE55LXY5YXWAJAINBL2OUVCX3NJDWKKTEZ66CFEIN",
-    "GAGHWCP6AFUFRRBW5EKDFOWVSF3JBIRHCBIZRLIXKLG4PJQFGVAXY4TJ",
-    "GAGISFAFWNCB5VTPGGFEGY2X66IDB62ZOMEM67ZF7WIW4FGOILWWVUSA",
-    "GAGJ6QMA3H6XYYV6KBVTYFXG43DPYHGTYDZ4EOEYRJ5GXYY65CNK6LV6",
-    "GAGJJX4XDSXZR4LZ5W5Q2LI4KI5P36RNVX65H5UVGUCT4JIGPSPMEHA3",
-    "GAGKFK4NEQY2GYELQP6ALPP5NPUBAJP5EOMBSBXIO7NS4XUUCXI2TK6S",
-    "GAGKI4P5XOKJFG6DNJQ4QEVMYC7F4TUF3454DJH2U3MA4ND4EA2ZECHF",
-    "GAGKNDIHARUY6HSS4W5MAQY3KJYHPTBZXD5UJSFDTDKLIXFAARESHF3R",
-    "GAGKR7K4ZDJLWEKDU3YUS626OLXJUO4JJZDO72VDKE2JANSBXPNOWDV6",
-    "GAGKSNFHZHXFIWH6ASP3GPXWKZHDPDUCFDSABF5EQAJDVL6IYRA5N6SS",
-    "GAGKTIAL22ME6XLCIGYLYNXNZ2422KYHIP7OB5RMAZITVSQGJ6EYWINT",
-    "GAGL5ZC6NUE2OBOBXK4O7BLZPC52PLCESJE7DTYPFKCFZ5BQDMSCHIBM",
-    "GAGLCB6DHOYS3AGGRUXRBOEUT35TSVEGZNHMAFNC33HYTUHY22CJ3XDC",
-    "GAGLQG5DEBWXTBBUWQVQ26R42M7GADRNVYNJJEO4ODRCEEXJXDJINDUS",
-    "GAGMBSCSWPJJKABUCVH4QDMSCVPQASYUQY2QPRN6YXKOZWZPBFA224VH",
-    "GAGMWYSEH4VS4W6ZYBYGY472H3CF5WAMT63DWQPHPLNMCDDJFBF3O6HO",
-    "GAGNJHTORBOY5YLF4UWCKUEGZPHUB5EWGZJXZ5YVBUBSCJRYN2FW74SB",
-    "GAGNOLAAOTVBBW52IR4WECOTV5KVA5YTIDGEKQJOVF45ZNCOSRWILXLM",
-    "GAGO3RA7KQR3CTL34QBXYII4HYOXZ3Z7N3UVQFOSUSB76ICOIDVB3LAG",
-    "GAGOIH3ZEVRDJJQAQ37THCDUL7FAOOGQKAPXAA4BJQF7BUXZWNVWTDPP",
-    "GAGP4Y4TGUREHOOESFQNMT4R66SWHXB7UZMYGUDGL4WHNFWJJLHSNM4O",
-    "GAGPGP2RZMA76LBQ6U44PH77D7XHGWDCNIWXTRNLJVD6NRHJ5EKNVMWA",
-    "GAGQQSVVWPSZQUA44FL6W4G4RBI65RWZPIWEH3PC3NPQRGLOETXZQ7MQ",
-    "GAGRLETBDQWIYEYJWECCAVCOUGKCSSKQVTPYSYE7N4UYKPRYXWYKZ5LA",
-    "GAGROAWVJ7IFDKRY4BHZ4MMKWLGH5E7QT4D5ZKVAIA6EXTMW667N3VJ4",
-    "GAGRXOFVJVFU4LXEDTHC5E4WNBBN436VZSKBONUQ5K37SGCJ3RTE5ZWQ",
-    "GAGS7KGA2T577XRD5T7OUYCQWK2EFTLYXTKTBFGJOCBJDOEPRXFYVFEI",
-    "GAGSLL2I4ZLF6MDQ2ZA2YTVWWHWSVRLXFBEDARTAT43HQ6YJDPZLQMDZ",
-    "GAGSM5R6YC7BANQREMBXON7AYTV4BYUDDPQGS2XKPUSYYDMA6YS735YL",
-    "GAGSMPCQ7CGRWQCC66IRZNODE6AUXT2H46SRL75JB5JJAOBJASPEBK66",
-    "GAGSNUDTCM3HSJEG5OCMMVHT3NIW6SSNAJO4PGP33JE5PAJKWREK32UF",
-    "GAGSQ4SA4QNVH4C6FVSNTSQPR2J3XR47TU2MKQM5KBO2DYKWSC7K5BTC",
-    "GAGSRRP2OYBHS72GOBRDT6S4EKOFWPGBNN4WQO6ID6G5QLUBT4PD3AUD",
-    "GAGT3QGNQOI4W77UTSJHX3R4DO2SPVESQWWFAYIZFEVYLZ67EXSQ6G7A",
-    "GAGTEQM4U2SMITPW6243653ALXBM7BPHWPPWBHDMQLOKWSK4BSV3GPUP",
-    "GAGTLC3N53P6K23FB2ZG7OO2KDMJSNQRFS5SWZ5XQ5TE2FJ2EZ6B5B2U",
-    "GAGTP7K5ZTGXKANRHX54SN426CF47J7AAATMDARCHMFWLAFUIFEN3HDQ",
-    "GAGTSCHKSAU3K3X5NTJ6VWGUHKFMTQSTRQHTJLPEKXT27OSDZFAS6SHX",
-    "GAGTTTZC53USSW4MVADUG52TYQTMAYTZLTM3VULRBEGYBMGIK3Q5M7LB",
-    "GAGU4DPMLFVBVQUFJ3DDRG5M6TBJIO6JKLEGL2NQC7B3QJISKCNE4MAT",
-    "GAGU4Z2BS6BM5ESOPJ5L2AJPZP6QLLTLZPBGQXW7TPFSI5QJWAA4LXIO",
-    "GAGUCDJNQ5EVIF6RW3WYMG6TBUFMWNLU7QCSZ43KQ4S2UY4G2QDO3FOX",
-    "GAGV36PEHU355TZ7QXESYYNUCFSX4VSZYQJFHABKUNHJZ3RWE23QRXLM",
-    "GAGV4ZDWEUN423CALGYCR5ZGTJD5YAWXMREAH7B2V76DPO5VZ5O2DACW",
-    "GAGVYIY56R3HFUPQ2HPBAMI6SMGDDAQQKGTMVR3JDKUH2MZNZE2FGRZU",
-    "GAGWJ3JOLJNBP2USZ7V6RL52O3AR36EGIJNDGHCSIQL2Q7GFX2GRIWBL",
-    "GAGWWBLH4UPYPFY6PWWBAYDBIJPTVKYZY7J7BAP6MFG6QRIG6DYWHDKT",
-    "GAGXOZWV5TRDQRLN7UPDEKZO6UVTSRUW652FCRRMHRBNE2R77U3IQVKZ",
-    "GAGY4Z3O2A7CTMGOQYMTRPGNARN3MLUBMU7O77RD4N65GTH6OAP5TPAP",
-    "GAGYHPRXA4SQIOZ5QTFMD33QEJV7TJCEGN3XXQ2UF3OA3QD2UODTFCKG",
-    "GAGYITUL4ZJOSUIWSD6YPCFU6YDTQSPVS3JXHO2H6XFQGLWMQWGDQQZR",
-    "GAGYLMLVA476Q3LGEYNRLBQZBQ5P5BIW4A3KHTRKQFAEYZ2252KBP4RI",
-    "GAGZASVBT4EDWWYHOSQSPZ4CFHDBODVMKMC5PBXH42OEQLNZ64NA7EGI",
-    "GAGZCA2DAPGXSDN4UYL6DPNWOQSSYH4HWPL6HGG3NNFLSJAL26OIIENP",
-    "GAGZDKD3KQV64QYDOY6ZEZ5ETRWCTPKLIAR3BHSPYUHOQGYV3VZI2VD7",
-    "GAGZXOSYUNWURIYU6RV3F64CLPMQ3NYHUZ4IIHJYASHF45AE7REGZQNT",
-    "GAH22ALJKSRJHCGYZETKQZ75MAZ7WNZKNRYAKQ7VCIG7RMORMYFBBNIT",
-    "GAH266YL7INEQZJYYXHLXKZ533MSPO4DZ4FZG3FIPBQ6ZKTKNMKNJXFF",
-    "GAH2RI5FUZE77ZOGJYOV25ONOSD5IJVX3O6CYB3SYUBN5T5CEM2SGLWP",
-    "GAH2RRP6FAGKXQVLDS6LBY6XDJRQNFDXA2DDGQ4AZVMQLNLPCCPVEZP4",
-    "GAH3A7OZYOQUB3GXBZJGDHMEA2R2QCKVNKCVY7GOB7JP5AR53IXKLKG7",
-    "GAH3OI5VU4255KQBEL4ZIZTZVS5RSYQFMV5S5Q4M62M3CMNFMORDWRFM",
-    "GAH3RPVIN4YQDRWAJ7PJI5YSF47NA74TCK4FFZDRTTHLV3LQP54KHR26",
-    "GAH3U4ZPZNYGZJWMMBD7XPOHIZY32EPKSEH57YAFTT2SFAFYRMVYFXAG",
-    "GAH3UDCTFU34VAP6BNGZGXLD2LV34Z4VBULZBNQ2UTFL63VAXU77ZINC",
-    "GAH4RIVREDH2HMFQCK2E3DCDDFUAQY4ZHCU6JTKNDMTCGLP7FWUWPEJ7",
-    "GAH4SQ4YQ2WMBPEBLOCDNI6TA7CSLKCFQAG4DPYGL3VPHS3WNOES3I5J",
-    "GAH4XBYEBC7M2HTBIQLC5GDQF5MDKPGHCHQIGVSFKVT5POAKJJMSTICP",
-    "GAH5DU7MALIKQZB3DIKXNPIOAPHXPTSRJ6PYID3LMIHOLARZKB6VSVGN",
-    "GAH5ETROEPOFD5DZU3CUSYGZNRQZXTL4SUFHSIDXR2NVJG62ITTCCYEH",
-    "GAH5G3BPJ4BHGRXBHKLBXPYSXOD72ESTUYCNEW6O7WONLJRO6HPFRBFW",
-    "GAH5G5EA77RZAR65ZFVDWHGKQM3NRCC5VPNKBT2GERW6L67TH2C4FCHN",
-    "GAH6EA5FHRG4RZQ2NYGLX746GCKRG7TDEIZR5OBHMIFK6OXHG5LL4FEE",
-    "GAH6G7O6MPMDV2RWI7TJ2QHWRFSMIUA64HX3JF5LRB2S55PWG255DTGD",
-    "GAH6GLFMHGIYCR5QORPDEQKL32437DK6245CJE5ZFY2JD5SBAF6ZALUM",
-    "GAH6HMYHIDYA5AXOSQPNGSFSR7MRKVV6QE5ZPD5Z6BVBMHNRG43XE7P3",
-    "GAH6I67F22QZEXWG42X3UF7VTAUAU4WUXNKRZRIPFEC2ZEI3OO2PJSWI",
-    "GAH72ZLUTGKT4H5O4UJAFDZR42O44KXYS6XWO2BQGUB5MPZW4MG3SNUO",
-    "GAH75IOFFF5CSDJWI7L6BD23S4ATJBEE4M2NGXR3AMGRVCGF3FXBYG6M",
-    "GAH75KLFPTYUGODDM6ETHNZUU2W3VNRPICPGQ5SDZ2KHOUXESA3WSAQ6",
-    "GAH7DWHUJOD2RGV35NWTIZIZRIJDOQVSWEESXB73TMX5DSPU3MZGPBTC",
-    "GAH7IGIMWD5LKPHV7PKMZUAFMYUEY6B252IFGPTDBXV5NXDGTAOU7AJG",
-    "GAH7JLFZHO3BINB3Z6I27SY2F5WXIPMBPXIU7ZGVPEIT7IF3GSZBRYOJ",
-    "GAH7ZRNM64D5ZWVSMJISBALYSFAHTBR3PLJQCHBCPMWSV4L7PJ5HPW6I",
-    "GAHA32HVDIKEB7X3YAS325GBYYZKM6P4GVASYKQMIEMU2PKU7IPH4BNN",
-    "GAHAHXXY7OEXSMJGRZAAHBZCZQBAI2WAR3MKYASASITBH7VDF7RTFUEQ",
-    "GAHATZMCQOCGN6NRMERPNYFVU6N3IUXHQKZBLWKJF72M7QW5XQFUSIJ2",
-    "GAHB5MEHW6SHFC5IUNF4QNWXBIK76Y5PAE32R55PJJCCMV7274ZPBJRN",
-    "GAHBCZZIWOJ25AUHZ37WQDCBHP3HCV6UVZMUEWLHIGPRY6FKUPGNFQGC",
-    "GAHBG4RJLHJOUFCQSGVWKLNOG4N6OFLNKTKF7RQB7RU273GXM357QXDC",
-    "GAHBSPZFLWDEWOWQO7WODW7DAV7HO2NSR5DTBBZK7LUTBTZUEOEEHEDA",
-    "GAHBT4MIDFIWEG6F2X2Z33PJZOXZOPXKNB5DH3NDJ67CXFTB3HIN3P2C",
-    "GAHCMGQ4WAIQ4UB777VM6E5UAN2EMV4L2ILTHOUANNCB65T544EINDUS",
-    "GAHCOL67W53JKPJDQEMPBJKWKSERRCJ2RZRGHDBZWQG7SRDQSKXNWOPM",
-    "GAHD6O6GC7Z7YZBJVKFQFMLIECKO25GCAYZXQM6WXTH6XDCSFJFEQ2XJ",
-    "GAHDQRAFVH5O3TWZH6GNZSJ47KD6RSXFFLEQXBWA3PFVPK2FE5FL3TRB",
-    "GAHECHGWSD32XJQICGYRQMWM7BZ7CYNHJLOMZWOT6ZPW4O55HTRY2NBI",
-    "GAHEJMGPU3XM6YGVY4HGOYO76FVXAAG6SYDLVBKOC3TBKOYP54UZ532M",
-    "GAHETGZQTKG4SFZMUS3UJOBR2B6IAW47KPGWX3I3LT22WPMQUCCTDREA",
-    "GAHF63JV57UB3TK2QXYXRLKHWEJKL4FOYALGKTFGY44W3IBJ5HY66ZCD",
-    "GAHFI4ZE36U5CTHGVF44ZYKP6P4NNPKSFIEBXEI6FOCFJOY6P4KJSPWW",
-    "GAHFLS4HPI4ER7ZUC4BLDI7VDMZ2VTNZYTJW2AIEPTKIYGKYLEZ3U2CK",
-    "GAHFOONIO6U6MCQDWPKA47WXX5TW2OC6PEKTUWPJYX5NJWLLGGYQJEA6",
-    "GAHFVYTQ4ZXH2HYV674FB3MGU4RR2HVM2SME4ADYKHVV5MY2EL4ILV5R",
-    "GAHGIEQ4COS6U2BALYPZP7TWXSEWLSG6ECAYKDVSFJ5R2OETHPSZQFU2",
-    "GAHGJU4DHOIMMOIW54ZP2RTWIBAZB2SF3B3SISJ4J6NJBJBQXMJ7WLX3",
-    "GAHGJY3J2L3ZTYS2IB5RZOU5FBR5Y4APVGD6MR3XEQD4N6G43Z5FFATM",
-    "GAHGXKIU6WO472PXNI2LOGWRTNXQMKDJU2MN5J5S7B562JJLIJNIXFW5",
-    "GAHHH754CYHTFQRCQAM6CFNPFLBKXK756XJJYWBEASDQ5OCRUXAI6EA6",
-    "GAHHWP7WNK7ZT6LR7WN5SCXCU672CYUUXFMVDU76DP7ADZL3RDMQXEYC",
-    "GAHIUBRXPTACQT7USGU5SBF5XHENRQ6LBISJZWYKDGJPOL75XMXEGVIU",
-    "GAHJBK24DQZFUHCT2PBDZXWRLYNBZ3PXBLASSZ2XI5LBU73VPJI6CUO4",
-    "GAHJP5ZCV4SZXVCPIOMC7GVQDPZHWYIILLA62KQ3CLCE2UA5P24IVKSP",
-    "GAHJVMJZP52K6FS3KYENYO5SM7TG4N4MDQTQR6KWUQHK5FK32XS6SHIB",
-    "GAHJWE4SUKKUEMH663R43MTI47BIAEWQ6PMNAFGCS2MOJHJUOPGTRWC2",
-    "GAHKCIPLVIOZCMH6V6G4DTDPEKOY3VEDLKGBNLHSEFOW4UKBJSGS4YOV",
-    "GAHKS6EAMJ2WJEIGSS5SBHKFON3BORFGNOYWWHG4EWIZALE4D3DOLLAR",
-    "GAHL5TWF75GTID7ILCP2JXYLMHA7C7PZ5EVP45GMMATVNZEQ27A5NLYL",
-    "GAHLDNQGLJ7F3YEX6EDWTXWVDGTN6DGJMQQKQXUCIJDEKPMZZ2IZTTKO",
-    "GAHLPDU7WWNLITDH3B25G2PZ7ITHW3HFFEIQ23KJUSYJBUYKOGALPVIT",
-    "GAHLVLT6WBWUT6O2J3YBBGNVW6GDABVYSKQ4OEZJSXLBVCRKUD6SSJ7P",
-    "GAHMHJCERZWXIUIUQTSGVG7LYY723KK2UQ244E2QVVULHCIL237KKHJ2",
-    "GAHMKK3O2JYYU6HC73K2UVAQT57MOSGD3V5QQD4ZQVCBGYXNN56EQ7SU",
-    "GAHN4F552HVBFIRFRZ6EPKGOZYNSPW3SAF2GBZYFTEVEKPYHE3XAAQFM",
-    "GAHNAIL2WYHAH2MLY47VALNPETEYPRBTWZKB5FN6RH6RPXXEHRMEFPIT",
-    "GAHOVTP7V6M34UFAID2NBVHMD5ECRPG4I4OJ6XNRJS47PGC2TEKI6HIB",
-    "GAHP4GHNKU44BF5FV6B3FPZACVZBNXT57AQ5LQLT65YJCPS55XFWSPSS",
-    "GAHPEROMWSRSSWA5V62NTPHQLWIDWYY5N7KOSDXHCVEO36RA3HIETMWS",
-    "GAHPNHRI5ZCZ3DZWBXM6YWYO2NBPGRQ2MOAAC47SRZ3BHQXI4HUSYD2C",
-    "GAHPUAWAZOYF3SWTEQN6EFSKXSJCEKEBZAVCQVO2FDBVRVSNAQSS4INE",
-    "GAHQFPDXKUX4OQEBCAQ3UK7MVPGB65BKSHXRG5FSP6JDCFVFO3TGVBTC",
-    "GAHQIDAC5B6RKDYBYH5NNSXYQ6NF5UKU5LNLTAAHCFMEXWZBPPUEH3KI",
-    "GAHQL37VM4BO2TJROBBHSMMPD4CULSIULA63D44IEDSGMQRUKPPVSXSO",
-    "GAHQTZB7PNPVCACQWNTOYR362MV7JUUOGS6W4ZWBOCIPYE52UHDW4I3I",
-    "GAHQU43WJFCPEJSGTTA2P5XAA2LD7LQEGD6MGAOVL37RQTZICB7Y634Q",
-    "GAHS2VS53NDE5YWTB45XFLPVLZH4T2N6TJ3Z4BBCURDEJMHND3B6ORGZ",
-    "GAHSAUZI3PNWD7I6Y6DW5WS5DVFOV7FGGNYDCNRLPKU7VU65EHPQHQQ2",
-    "GAHSCWWPHJQ6FI6KQTBUTE6SNIBWRRI55CF7NQHFD3LN3OYRZNAQAQA2",
-    "GAHSKR2MUG5NLO6VJ3QM7GMNWVL4PGYMNZ6LRGACZUYXEXXZMWZTGC7V",
-    "GAHSLGYO3UW5MBI7VWIZC5EDME74L6UGPQUT6WV4KKRUGFVMBP7RL64L",
-    "GAHTE7JZFVNUX7OWXWPNJTGUIAWKL5NTMP3PEKX4YY7K7ENRZ4W2SAPP",
-    "GAHTJEKUV7AFTBIZXTID5ORRAR3DISFOMGTIUNCFVVUNJBPHZKRXY3FD",
-    "GAHU46N3XG6HUM26NW5AJZKTKTHOL7WMYXYUSK7NVQLSR3MZPXD6X3NJ",
-    "GAHUQII6EZOCMKDPOUXFBVC7UPG4KV3I7YC27MIX5GN6NE5MPXQOYYAJ",
-    "GAHV77AHY7A5JYCTT75RJ7JDEYW7RKRZVJ5ID4IQQXGASP24NF3H2Q2P",
-    "GAHVA7AHU33VQO6ONHYJD5MBM4Y5KZFIY2I477RJK2E73X2DEUN2EHBL",
-    "GAHVB4IOG4TWOHEHRWGIPKD4QAE2WWW44NLOR7BKSUMG7PFCHX36HXPL",
-    "GAHVBYSKQZGEBAPUQULFUGHUSYQ3NCNVJM2HVEN2ZH6T4M34T2CONZT2",
-    "GAHVVQTWHUE5NB42MCRBHO76ASPIN3K6BZ3EBBMN4K7TINZDECLPC7GX",
-    "GAHW2ITAYIQ5QLJKBTAWFXEDXTDJ3BWXYWNTPNUZNQUAZYXV2I46PNJL",
-    "GAHWDF5YBRXMELDEJ6BEL4QDD2MJL3GLCS56A6HILYLJGCYTD3CVUMPH",
-    "GAHWLDRVYUSF37JYIPTGSS4KKYFJHPSHUHBXJFFNBRKWTXBJHLBLFXLM",
-    "GAHWSKPPEBSVLZSVCO6FCDDLCOLCZHDGADXGDJVBPPNJYSVD5G3LCBLF",
-    "GAHX7SQFYIGF5HWNDDPYTP2FBD3XY2FIEHRMEHLLN77BGOXZDHLJPEND",
-    "GAHXHDQTZBMEMWLUMYHUGR3BQ7HMOCNWKCLF3WITVD2GFGBNJ7WR6FI5",
-    "GAHXQPAJWEH7JTWZBWI6MKRDEIBP7RBITVF3VWIJDGOS6CW7ROASAJXA",
-    "GAHXUA4X5Q752DS6LVBFX7NT4STTN27766HVKROTPJ5NBYT4JXINMIWF",
-    "GAHY2BD4RURCH4FNNCRW44B74WBHWLJ3MRJFHYL7LAJA4FUWESZDAFDO",
-    "GAHY5WAPEZRQT7TEWYNBXDOMU2SNKTZEHOCOKPOIVLKTA7CS6KJ3LEWR",
-    "GAHYCVIXMUT4OWH75Y6JXWQNAUZZT4GNVA6AJFQWGQA5NB37IDP4ESXJ",
-    "GAHYPK5TR7CE4ICIPO3CV4P3L4MAKKUUF3NRXVSHGYVB7O5C5RPNEB3G",
-    "GAHYQFZDVB3SO42BIQDK4LZ5M7FZRHBXUUO2ZYYR26MB6IIUOH3ZKXCQ",
-    "GAHYZ33UYXHOTBTSMHO6AMHLEVQ5VNMMGLCZF2LM35UCV536ME33QTAR",
-    "GAHZ7MSFKXGWCFUBI674XQZ4JDXUNWRVPKQDVCBTYQVFAHTPR7GAX5AB",
-    "GAHZECLUZ5NONJKIST3CBRSMUMY5JR7EDCEURHI7RMWT7PP6IJWRWEWU",
-    "GAHZPMOIOKWAVQW2MN4Z7SRYDVBIN7MJTRTPO7CAU54LEV5ELSETEC5M",
-    "GAHZZR6XCCH6QJROXAMB6GM3CJRKPDQMZBQR7U4NDYMX7I2DTSJKDTHO",
-    "GAI2LYK6ISTRBEFEY2GJYPXJCCZHWYXCA6OG25R4LQDIAQT6XZKEBGJS",
-    "GAI37L3TMFJKUV674V5DYLKYAOMLIZSFL2Z3E6HGV73ZTMGWP6SVJAMJ",
-    "GAI3EMSXNGSQCX5EEJSONK4OXXIRWLMQUPDT6DPR47JYGQAJSXC3QQ2B",
-    "GAI3QJU4MDCOAZRU3ORJSIDNVTYPDIAFE3N7TVDYBAKIRRAP74JPOA22",
-    "GAI3Z5FIFGHEOCQ2OZJRMJFRVCFWTPDC6OQNIEAWA4FAXVAANTCTH7F6",
-    "GAI4AT3SF7XZTEBRLNPELHD2CT6SNYNWJA7H7YKOBZKINX5YDUR3WVPC",
-    "GAI4SNGP47HYYI3F6BXMO7KZA67OUAD3R6TJDR4QV2GDXPVLPWYCYPI4",
-    "GAI4TBH5MYJBGZI5BZ3OFZZ4TICKCK4L2MEGF23JOR5REGDVH6GXBYXX",
-    "GAI4XMQBH2RLYCE7ISTLE3YNFEA4TSV7ZVWVZIQZ45AIQYFX2RP3YTIJ",
-    "GAI55YT54AVC3IVA52Y76URDGPWCMUK7VE4L5YXFU54FLJXOCCIVEV4U",
-    "GAI5RWIGQEEBDFNZFSG2DRCMNK4Q6EB7LPYLHXSSPS42PVFUZME3EAO2",
-    "GAI6CJJVYM5OIQ4AVIJR25DNSJI3L365VSXIHB7NUJ4OBK7B3IJHFICA",
-    "GAI6HWONGUWKNO4JZ7S2YKKSKG5UGTUDJTB77DORXXHROU5PFBGC6ATW",
-    "GA2ZQXS7ZP3TFAU4CEXOUK7G2UFYFKTF3TVWUPIUPM3CEQ5BSQRJJICA",
-    "GA2ZWVHOESCJUWPMF5QYTNWX4R35MUAW5Y276OKT5W3XSJNSZBCRIMFQ",
-    "GA2ZYWE7KPIQPD2X3J5CQMFTPVSGGCDWKVV5VEPMPVGDVXGOASBNPCCV",
-    "GA32DJJI5AZA4ZSEZVJ2L5SAZSM3IRCI6WATOPDP4DFNOAD3BRNQYQWC",
-    "GA33A4FBHVGW3JGZLKID2JFBCMIJX4SJP57UNVGODLWXZM57MOH2J3MJ",
-    "GA33ARPSAF6UTG3O6XWJSZXHWYGUHQ57RR7MKVIYTTYUMP6IVY3DCLQI",
-    "GA33IBGT7LI6JWLTEVAEHLCCQXRZYLM6YAC5DBDLQEXOIWBHGE6ZIXRP",
-    "GA33KE2PVTENJCGCO2S25EXDISNPQG4N6Q3P2KEHCKKDE6REXWHLXETH",
-    "GA3465BBNJNJ3R4LS7PR7ZWWUTHPIWXRHAAAOJRGTTOFQW62TUMIJISO",
-    "GA34T43KIF4DIT2OIA4O6DZ3ED26CNHZUICE76SADGBAP42WNDXUGTSC",
-    "GA34ZSMU7CELXNGIXGGTCW7YZYYCOU6TC3KE3EWBSFTNJXZMU2FEWNYF",
-    "GA35X42XZWJ3UR3DPVZ6TQBMBJCT4R2VVQ3Q5LDA4IC34NVNL454PMER",
-    "GA363OYT36W2HVNBHF6PZOIAUV6IG72LHWIH7SHP5V7VSHQ63GUFCC7C",
-    "GA36AKN5XQB24W5UQTY3YIANKMAS3WO75PNUQAWG652NQRAYGLPA2CVT",
-    "GA36CP4G6NJ6Q7EFL5MFFTTC4SDHH4NO2YARYP3OSTEKR7E2MXKUZ66Y",
-    "GA36INIW7NGQOTARUPQG3L3MMSJC7LN3Y7UKL7H5SYSOPJR57OOHOVHL",
-    "GA374KGDRHVLWU674G33B35WPFXJ5O2BZ66O3WGDM7GPJNJHYFNS4XLM",
-    "GA37I4YTA22VSORTGBAX5MGSE54BWPRZKCQZZQPT6JFJD24PSI74JN62",
-    "GA3AGMEDE3XWFG42YMGHGNZTNYWR626Z4F4OHB2VGZRLIRUP6NXISHX6",
-    "GA3ARDI323BLI5DBSNBZZHVSLGDTAOAA2GZIC5BISE3XPNUBXTNZJ7Q7",
-    "GA3B6A6SK4VAXVUHYOXJQGED3UICS3BRI37XVVKDE7MVSFF24IYFJD6L",
-    "GA3BLJAJQ37HFBNPSEG7URKKBISWOTEOHCTQ3QCMVEQKAW2WU6EZEURB",
-    "GA3BXN3KS56EMUBNGSBGMVN72X34O5DFJXYUCAY33POJPZUUW7H77ETK",
-    "GA3C3WDM24BNKK3FAAKKYH6B7LCXARIZZIK2TXXYO5CN25RDFL7SMANA",
-    "GA3C74UJUDL6EXER6QYO7GAFOR4FOB3Z6O3RT6HFTZRDTWCT33QCKA4A",
-    "GA3CSJ3XFCQLWJAVYQNVXLIBYZQ5R62O3JUXTKZX56D74FKRJ63ILEL5",
-    "GA3D3D3OKCUND6LPEHTRQ4GAAPCRGZIX6LVKCZ6OUEN4GCPFDY5SSAXW",
-    "GA3DE44BGPBGOAK4EPWUJ7GNY5PMLGSHLJJG2BVLVMNWEVZTUFOYZ5SO",
-    "GA3DHLSENBBOHPPOPMXIERPGFHTKCP7AYS2DOAFP4J55J7LPM4QWEAOI",
-    "GA3DNHVT4Z5IUAYTX7KEU5RH3LARCDP3OZ2WLKVEMNMMXUXAAN3L2PIE",
-    "GA3DYPTADLFGJN43Y2UOJL55ZMG4B3342R2PH4NXXJX3XDQ5C3CFBDQB",
-    "GA3E2SBRA2XOGZ5AXL3SNTLWPEBPS4SJBUFS5EGDO42ETQ3YDNAP54PP",
-    "GA3EAF3KFZMMAUDPBXZCYKGLOZJQIEZPYK7VROCRIIPOF5KJJ7GM27G6",
-    "GA3EFDTKFXCOVA7BYMD4OJHEUG3IVNH4P3ZUF3R57ZYDGA2A3RBJGV56",
-    "GA3EKG24OJRTQ5ZDVVLHUCWNMYBJLSJV6V3TCSLN7KIJI3TRNSAJDZQB",
-    "GA3EXR7XKYRONU2ROOYKB2MN4FWKIP7NQZSRSO4Z7QRGBZPHOIRFSGLY",
-    "GA3GBHUXJCGH3NV4XCRG5NI3TL7HTTFZ54OBW5XSY2DQSF5YCBQIUVUD",
-    "GA3GFJHQEICZNS66QONK2USFYRMBIQDXNL7D6PZGZTHK5HRSIW2VZZ3D",
-    "GA3GG7Q3B366JO34RA5AZU4AMYTH6B66R2CEOQ4WILQDRWKGDP4YGTWM",
-    "GA3GJE3K7MDIFTKHGWHX6XHORPVTRKHPBPLRXKHWICUFD4TK4Q2A5UDB",
-    "GA3GPMRSA3YIC6HJBR4BN6JIYSQ57VVL36JOSIKRQJFWS4JVN4SZ3RYP",
-    "GA3GU6TCLV5Y4PFWWLCSXLFT75IIIJBVGTYWSU5C2DF5SAGFI336YPL4",
-    "GA3H2A2JQZV3J4J4KAW5JTW54ZC4VBR7Y4RAHWRE5XZCW7UMYVU4INSS",
-    "GA3H5QD2B5JCU5TM66J5XTFZGT7H73NQGWOEXIVTPKCDQFH3ABAM3CZU",
-    "GA3HCNVWT54ZNWDY7SZBG43PFRHXX2JNQDTC534BZLPWVZJBGS7D6MVD",
-    "GA3HF2UL6XNNNNI6GF4NF4U525VDT4AZFTJZOICMHLYFIVQE7FATJKO3",
-    "GA3HXBLZ562ECE5JZECQBNZW2VOM2OX2HD5UXA3WEWYFBB4TW5DSX2GJ",
-    "GA3I3QVW5EBR3CWZIUIIVH3MJFFS34KKBYENLPSEH3USRVFKZQSCQQZ6",
-    "GA3IOEQGRTUISKNEEBCT7PCSREPFBFLGVDSPNEDB3HUPEP5WZP3QBSP7",
-    "GA3IUPJE7HX7HEGEXKR4SHV6SUUAANEP362IM7RQVYOWP73JTHLNED3P",
-    "GA3IYLCP63BENUHA2WUOMU4WTJP3TO7F5YQOJFLY5JG34NG5URN3SO3E",
-    "GA3J23XNKFMTII6V4RTFEQATGSXSK2FQN4G3BF6WAKBLPCVP5TJZPTBW",
-    "GA3J4RNKPXRCVDN2ZYHDDLVHFQOZWCMGIJHNOKCASXB4QGLSFUAYHYTW",
-    "GA3J6LHMD5RBOD7GUOOAWYIEQ4BZ5ZL6TZRKDAVY4TQGMQ6NZRZSVLKD",
-    "GA3JDA5ARIGHSZQBFKFMHVZXPH5VCEJXSHCJTDILALH4JORBFM7NMSS5",
-    "GA3JDBF6OPDI6UZKBS6Y3KBGUXMP6X4MGEDKVBICHYL74JPAUJ3ULEDG",
-    "GA3JOJRXW3CSDLQFILO5CX2EGVXTVBJMUKTGOKOBELGSBGWHIPAKX3ST",
-    "GA3K6V3O3PDFIZRD4BIHPVOH5BHSELPHZSQ6RC5G2MDS73ZSAVJFA62S",
-    "GA3KQOMY4O6VGKKXKBSNXMPPPNIRX6XJWGBUYMLHUM73NF457WCLG22M",
-    "GA3L5FAHFVFK36QJTYLGB2PY3KAZXXNKTISGROBRJMDHMJ352MXFHDHD",
-    "GA3LLJYLVS36J5XELMDCKCEMQQR4XMRKC2QYFYDZRN2PLI2MHUWPC5OB",
-    "GA3LMVCRKSDYW2AHWNEO2H2JMA55T3UIIMRPB2TEZ4TYPNDX7DD6CGJ6",
-    "GA3LSHSUFBHIP4Z7X4BEEK7FORUFLQ764ZHA5GNX352DVNGY66CCMCMW",
-    "GA3LTVZQFBNP7IMTKFOUWNB7QNXB46NEXLUKREJ2ITD2AXOZYUKLQSMN",
-    "GA3N6JZDDFALJUYMMSQLOUCRID5VLQCIVX3BRTUN233UX5JZSWZ7VPPI",
-    "GA3NC7IOFTXS5JB2CPT5AVNHJHBUHQGLI3ZFIFCCB3AYNOYMWDXMOJXR",
-    "GA3NHOU7NK2OKANK4I6NY4O2HQK4DCOAAGNWIXTLCVHCHQ4CWY5GZDQC",
-    "GA3NLPJIWIZTN3B6XKV7FLGDJWOWVQ5U46SZGPDK2PF3BNOZA2IR3Y3S",
-    "GA3NLYY4JTQG6CNSRGDJF3DTGHAFWPBNITUYLX2JRQFZWVMKPBYS2IQS",
-    "GA3NVO3ARDPXIZ2QJV4QBPFD5CHRTWMUP6FUMFEOTS5U2SZTYYA77QNE",
-    "GA3OHFW36P5K2ZZUAK2NDFBJC3S3PNO7REFWGQEV62RL2YVF7OVCIBTC",
-    "GA3OU2RRU74OBJFEA4XVK7PQ2D5DRECFZ5LXRZOUXDSWV6ELOZK6PJQZ",
-    "GA3OZ6AGSMDDRUMN7PPNQHKX3GLSPABW25B6LTNNDVR6H45XMKESWISS",
-    "GA3PBCFLHYGO7ZZ5JZTMFUK47LZZ66JOOLHS7XT4XI6LYS2HYEWELFD5",
-    "GA3PNKZYZ3EDVBLERTBAP745XIV2OJREORSU6LTDBG7QFUJZBKPGDZJA",
-    "GA3PQB2NKNSKFFEUK7RVINLISCLBHAWIEOHHAIAURAXPB4RR4N7LIUOL",
-    "GA3QBF6HYN6PTETU3UZQK7YHXBY5TLKKLRMNVMRULJDCUJR4Z5PLVSNS",
-    "GA3QEZSYHKKZEVY7PWRTYWPKS6KOHSOI2EHXXGTJYA4TQIRNZGCEV3KR",
-    "GA3QHHOE7LZKWYROK66WDQQEDTOYUF7GW7KHZRKO6B66DUXQBWFCEXRP",
-    "GA3QHHUWHMN6XQ35TNZ7BXXP5ESZ4IQ4O7MTNEAZDWW4L434JPKQ7VLP",
-    "GA3QIRL5CALJWT4MGXGAJYUJKA53M5WHGQ4IVSCQ4T3IGDPURHXZR3NS",
-    "GA3QM6PTYR3MLGXSPMWP5F2PTAQKORTSWMKMFOYLIGV4NI2X7SKWCOSM",
-    "GA3QWJ74P6YKGPLVQUSY2CDJF4TWN4YEBVX54JLD5BQII67NFXOU3EYQ",
-    "GA3R26GDVMWHMPGCOIRKIU4QXUIYZS2SJC2XEY5Z43H63MCOIKQ73KA5",
-    "GA3R3TK7VRL47K4UJVPTE4IQ4HF4CZGEG33XGMAEBWLXVT6E5W34GFBQ",
-    "GA3RAEHQZBFDTGAG6J35Z3JILHCFYZPLREYLHOFIR7TQAESZ5LG5YTKN",
-    "GA3S2REXNZMARH4JL7OEKIIU4BEHJOLBEYXEC47CYONML3F6ZNLF6YGE",
-    "GA3SWIERUMBVCLUI2YNEHYWB5WCPWHBGO2GD6VKYJVYIYQYVEANPQLVQ",
-    "GA3TBJWJYJKWOLEOWTLK2EVIJLIWI5ULUTP2FRL6PVACC3EHEJ5JSZWZ",
-    "GA3THONJXQO5OKWZDYK2U2J34FF7UBEDE3B62UKEEEHAJVNRKFHCMXRP",
-    "GA3TO3WOMAUCMYNBEU46L6ORO2RPI4OKVCUYN3PDBEWVV62QGVCYU45O",
-    "GA3TV5V42MOPEY3FUWR4KMBFWDE7JRVDBQZRQIDCLT2GAT2JDTWGCCQP",
-    "GA3TZAIPUZ76K4PCWXMC7TR66J7VPGQS5NKZ2LC5EWF6PUNW675T7B6N",
-    "GA3V4IALDSEGMEZM32OBMNUMTC27AVK4DWXQ6MM3TGA3K65LUJXBWICO",
-    "GA3VGJWGE2XLJBZ7RFP6KG2XCKBHE5J7NL4G6YF7C33BVPMM3TKQRNUP",
-    "GA3VPGKLCY5CKM6QZ5WVKYQQUSVQJLFTXUDJBSBP4BUWBRV363ZADXRP",
-    "GA3W3W2VZAVYGKAQNHD2QJGT27YKZQ5IVUNCKK5WA6HJGPNUE5ZSWY7O",
-    "GA3WETUTWW5IYPAHKHHY5GDYJJ5VDHURHUAC7LLVHARSY3IJH67YXXAG",
-    "GA3WJKJJ3RVSQK6QR57HCIMOBDC7DI72WYR6PRQP3Q6LA2AGT4W3H6XA",
-    "GA3WKI3NHYHN7VQQZE2XA2PNUDLLET7VQO5JIMSMEVWUHJEJBLHWGJDI",
-    "GA3WLBLMXOHKU5LKUMJSNSEMG4DXAD5SZWYEMZUCNYYXKITKUFHGNHLX",
-    "GA3WXEDRB3FTARBVIGH2JXZVNJMVVR3JIWVTGQDZVN2U5IVZKL4NCR2T",
-    "GA3Y4UGQUTLEYLB2IIQ56VMGK5WIEXAEKBBQT55SJNFT2ASP7JJXD4ZJ",
-    "GA3Y7LI6F54TTCW4CDOC43Q5JEJU3YIR6IPT57SMACMDL5ZTOIFA2NGN",
-    "GA3YGGNJVFLDEWCPFPGBXTMQ25TJW5Q6KCG2OLNIPVAM3T4R3OY2PFU5",
-    "GA3YKXXVP7MNDBZKSFVGO3MK36TTDZYK4GQXQP46KFRI75OKSARSAQFS",
-    "GA42BNMV54MXKBUSY3KCQJZWICE4PD7CI2COTQ3XWYNU3J65TAUXG44G",
-    "GA42TK6JQGSYIDG6MOX2GPHPBDEBLYR6WA5TQPBF7MFUMM2PV2P66WLG",
-    "GA432DETS2COMHAGQUPOVLHJU5L343NJHUVKJXADZ5A3QDIQYF3SQXGE",
-    "GA436WLQHUJ3OSKXBCVA5XT3DMID3OQUVU3GHFIDNI4VZRPYS3VERCSJ",
-    "GA437UO64IIZXSWGYYKE7T2XGX4JPSQHYXKWZDO6JHSLIUVM5LJYSE5P",
-    "GA43QBFU3CELWVRWRXOQRLKJOYVOSBRC7COCZDRX2TFLBTVXNFPUWSDM",
-    "GA43T2FDVDQHDASPDGQ7TSMXHN327L7DCAXJS7DPVU7CULLNDBVJKBH7",
-    "GA43TQ4KQH3T34WKNRB3YP4SAQOJ3E7VIG576IPJSNRPEOCZO5SXZK6E",
-    "GA44CAJKJLQFMI7I5WCVDQ7AF4EQDTZNXBQZVEHWPJODFTK5RP7JFCHN",
-    "GA44UGN3VODGR6PE7QGQ4FAWIZTWKIWHHA6V5HSMOHVRFRNNYB2AGNKQ",
-    "GA44XJBIPC5DEFLFSFJTOMDZVSWKHISOH2UZQELV5NVRDY4VCT63YRLB",
-    "GA44ZDRQF5IA2D533E67PMP2SLK2QJ5XU2OKZCYVZMWKSVTOFKJPPWOD",
-    "GA455UGO6ANDLEOZ3GGNJ2JL6QGMC2NBZNCJDJQ43EZE4UL5TUIGSVBN",
-    "GA45PYIHM653AFNCEOJJNCMQTOOSFGRJ3DXIY72ZKGEEGMYQKU7QUPD6",
-    "GA46LAFJDBDM4BV5FXTBSUJDJHR6LFEWDBMTTLGRSNVL6JPLC4FHSXJL",
-    "GA46MEDHCMM4ZYVYBMZZJ7Q5MQDWBCMREAK2JM3X6ED4LMDDZZZONYO2",
-    "GA46R6LSPO5FK6T7ZSBX36Y2TW4J2ZFNHJFAMCVZZMR4RPNPPGJ3WPBC",
-    "GA47OTQIOTTBA6ZDOVSKJIGF5O437AMCT53RY7MFPE2PTV4G3RFWJT2F",
-    "GA4AKJGYWIUV5DAUDB6BK6BPX3OYYDCA35DDMLB3OGX7ZFIEJBNRDW7K",
-    "GA4ALBQ3VBCHGUCP6JSKSZTB5SAPPTNSRJLYVH3FY225M7RJR2JV2BAM",
-    "GA4AOCINFDUXRID6IW4ONCW5SNWSFJF3CBFQCZ53ALJPEUDV2RVUAHDG",
-    "GA4AUL7UORF6WANLDH3NK22BACMY6YEOFBBUXAVZMFGXVXYVBMWKZE62",
-    "GA4BJAUSTZPLZZTAESFEOTYMQUE2DCGS5Y5Z4KDMNH6TKMRWC3IAGCPC",
-    "GA4BLCOLDPLFTA2KW3QQD73B56T3HA7KNAJIB4MDWMJXJ7Y7IDU67XKG",
-    "GA4BYMUO5D7OLGVJWZ2D5FCWU7SB63FNZ4QUU574SMNA6ELK5TZD3SO3",
-    "GA4C7L2XWXNUJENQYWQISLLIYN24TCLFWDOWRFC5IJAPJWZ2SLPJSHIB",
-    "GA4COPB2C53QOE7LLEADQSSRJCZLZQ6OPVLN75IL2IS4LOE2RINRBTCP",
-    "GA4CR7FQSJIBYAJGX2VYCGOKSMDOM2UMOHTIHNSUTPYJKZB7HQP5BOAJ",
-    "GA4D2LHYVYGV2V4MZO4XIGHWYMNK2L5LASFQN67KESD5742HUW4OTKIP",
-    "GA4D7OUZU7F6EONGDRI2LETZSMUQIMBQHLPZYR7UP5X5EZIANXGYYSY6",
-    "GA4DHPTPCDLVJAOL74XDGB4P2KM6S2BEF3WB2J4TYQJPWYZQHGTDD3O3",
-    "GA4DRF6JCKYUI6IYOG32HZ4UVAVHQ473ZN6SACVJOOZ4NBKOGU2R2YTQ",
-    "GA4DZWDZM3ETAOKFJXSWOECE45ERXOV7ERECDQFAHKXI2CWUSL3SXYUB",
-    "GA4E6WHEJNMYM4CYJWAYXKQXW7HUJZ2IMPFRWBAYRVXA6P3BJDENSIMB",
-    "GA4ETCAHLBWABKG2L3A2RN6FRJMZ54C3CU3S7VS6XWYW25AX6E5B6DSY",
-    "GA4F6V2SLSYVJ73OU3MG7TXH3J4NYFYCT36MCCQDL4K4XJN7ZINCKVZP",
-    "GA4FB26P5EYM6OPBOIOPE6AEIAOXRLTJTX7L6XQ6I474JFWHPSOU5WQA",
-    "GA4FM775ZVWM2WFSNNWEIHXPCQW3JQRENAHUAAECC5SNVIPCJENE2X4L",
-    "GA4GSKF3NINTN7UHBHJ4TWFSUXYD5YLSWU4MKZKCCY53YX4ZPQDXFS4I",
-    "GA4GXWXZY2QQJRRCMH4X6GRFIXPX3YEEKVBNQYOMQUGQJIYSOCSALBNE",
-    "GA4HLY4IRYR7JZR2D6HSL3ONF4QUGNDTCDX35JCTY7KWHI6LQ32VZUSD",
-    "GA4I4GJSI4BXZDHKHZKMYTJ6DRGO4UHHYE4ILA5CFYVFPWJML6ZBXWXX",
-    "GA4I4JIYTOVHRIEEBRB3UYDM3TKNTJHZKDYYA5W4GNIACENRRGUJMTDP",
-    "GA4IJYBD3TFUKK6HK62JCYS57TCUWIJN2DISW25H65A4ZFYR6INYRJ3X",
-    "GA4IKD66DFQHLOAU3HB76SYNWSTE3IKK65NZF46S2SWM45LRHKYC6IWH",
-    "GA4IKNCK6BOOGBOUJE4W5LMPH7PXQLKXQESLI5JNRHO6LXLEXJYZPOOK",
-    "GA4ILGFXR5OOLQ4O5EX3UJDCSSFR7NMCIM6HJ2FV3MQBCGV76H25DG2R",
-    "GA4IPUVLAHLFMJ5USGTTPNAXKBK3IVDO3WN64DVFC4MMM4VVUBI2NXRP",
-    "GA4ITK3KMJ4II3DDSKRYGDKHPBK6JCGTMQA26QAUFLUKO35NX2IHCU6S",
-    "GA4J5HVB3WSNGMEE36KLH4JOHWNLIFD2IPJ7VW5YIYXODIKUULSUZWZO",
-    "GA4J6ZNUWN5XBIFM7YZHMHK3CU27KRA6II33BB5MGR7X7MGXICF4PJ3E",
-    "GA4JDYIYE5E532WJOQK6BXKLA6L526DQ23TYJRNH3AIPE7KYEVKVY3MC",
-    "GA4JHXV7PRCNWDGI6APNQNHFYDLFFVDZ3QUK4FNETOHY34Z6GLAING76",
-    "GA4KC7ELO6AEJ7CTSIGUCCT7IIRKJRNYLYE6LLN6QROQCHYDQFVSJH4P",
-    "GA4KE4LSWWM5DL2PSOT6DTRWKL774CLSFPVKEDGCOP2AB66XPBV26DNG",
-    "GA4KXIDTO3ZPJA7BLOQHZX6UL6SM7KHHW3QOG3R66D33XFNY7KKYWHIP",
-    "GA4LL5YMOEOS3QAUKCQRO3V6GMS66B46ZGBEUCZHDC6B5QWVRSU2WWPL",
-    "GA4LM7CBZPZA3HOFQTYDKIGHS7U6JEYNYSICLY5PS3MKS6CGO62CDBCH",
-    "GA4LRMQBB45TRW3IGBJ5GPAYWCUS5VBWRJ6V4DX3X5YT5GS36LNKAZBP",
-    "GA4LXNLRHG522IBMNXYDL67BHICQZC2TDV2SAA7KOY2SEYYGCS3EL2KB",
-    "GA4MNOKM4C3OHO3N7BNMOWPWLUP7OJM3M4QH53CJ2Q5FIRSTJ3ZFWQPL",
-    "GA4NA4DREW5XOTNB32NPRKLEQLPU3TJOABDM6MK5G74VDQPJMZ2BRH27",
-    "GA4NGARMDE3WOUIAWHEEKY6QDCGQGYAOBHEXYRCCPUF6I63SKN5TCW3S",
-    "GA4O5WFRXTZQ7HBQNIBI36NS7CLHYG2XEVF5LFYACFHYLC45GQNSYGWB",
-    "GA4ONYZSNVSRSQJ5BJM2VRSVVCQICWBBFRNNAELJBVO6PXYSKU2SAHUU",
-    "GA4OT7NNEKSHJLTUYGJUT5CJTCUJXBLDCAF2JYZ2DKOI7OC563GBUTNP",
-    "GA4PCQIDCRRC653ULKTYK4HBTCWKGR6NEQPUFUUV6PSIZW7ZBTYXBW6N",
-    "GA4PDN5NWOHL7KPJRCD34UGUTGEG6RM5USOBSB4GQEIVSMNPEO3DM3BP",
-    "GA4PHGQGXY3FOES3WPR42PNMAISDQJARULFHOR4UTGOPZY3ZS24FQ6ZL",
-    "GA4PS45U3WCEGB5WTYX33K2TBVQ35DR5S55PYD455TP3GDNMBTHSMGTM",
-    "GA4PSRALRRV7XPYDNJ46LAQE45ZKTKTVJXMFKXYLLCXJNS666UFSMAIN",
-    "GA4PTGJWK6HMTMAAZHJ2VJ6262BSDRUEVBGLJTWGP2QQU756TGYA2XI4",
-    "GA4QQM42XH6VDXJTCHF45CNDEN64TN4TGY4NXHJSJDYQYTXEONGZ6LY5",
-    "GA4QRGLHWUWF272M4DBD2SQEJ3SH3A6ZEET7R7Q52X2CIZUDNT73AB7X",
-    "GA4R3PD3VMQGURGC2I73ADQKTBFLTYATKM647WLBKR2GMG3LZYRJUYCU",
-    "GA4RFZX6A6MZ2RZQADZOUNAQ3JBWKNVJGAHDDE5XQ53L6EGJVAWYKO77",
-    "GA4RL3MSXDRLO3QINDMZSLT6KWIR544LTUXYLWUGW3CIMWO6IEKNI7JX",
-    "GA4SAHCHB4E4NNRJQ5FVISTAFLNJLI65L4AGYJR3AIYZSXT43E7XDB2U",
-    "GA4SBLUV77LE5B462DS7WCHGEOZTLVGDAX2EELOY6J2QCVFPFSC3PKOU",
-    "GA4SFMYKQHLHYYKLPKJWJZ7USJ6PHQCLZ3JAEKSJDNLZHQKGKQMQN5E3",
-    "GA4SHJ3VOILRS5BNQHOEZ7IF5DWAYXMDYBVLZ53FVNVIIYJOROMOLLVC",
-    "GA4SIUUHJZGCBZE4G2M5I5WY6BDBXGNOIW3A5C6HLHYPYGTHNUU7DVDQ",
-    "GA4SQBJDSRXD5TBAT3FATU7A3YPWQFFJTFD4WSTLVQX3OT2S75HLGSYO",
-    "GA4TCY3SKC34AY3GV2WZJERA4FRKZIBOTWPSMVWKE3NYPYN6QJX4QBNB",
-    "GA4TWJ4NHAWX2S6LIJGKN66FKPW3YMEBBZSMVZQVH5IHB7EQ7TRKLPTI",
-    "GA4U3RMWXKXJZO3OMFE62A3E4O6COTZ4M7PBVG4Z5DPBCDDQTN76GX5P",
-    "GA4UAYPDZ4J3PJXEBDK2NUEUN6BJ3IBGTK2BEUDMKGBN3V2GNKQG2WRP",
-    "GA4UEOASKATIF5MDB46PYY65XV5HXH35E4OGPB4IG6FQXTZU3M4TX7UK",
-    "GA4UJBENOUAUBVDOGRN4BI27LRRBM4PIFXIJWJQTH3F3YTEKFWUK7NJR",
-    "GA4URTTAH7XPIHAIAEZTO2LHPX3DXWLYRXXPFEJPRJXODOUVVBBBUBSS",
-    "GA4VQMRPYY3HNA4PZ2MEFFLBRVTAGXXLXOWXERTVK67M5SUJHOENZSJO",
-    "GA4VSWO4XX2QKLECJ5BVHMMOB3KCHBA4RPUD43OER6Z5W47VIRXFKSY3",
-    "GA4W5SZG5MJQGUR4OR4T27EREAPHILLHLI3WPNHCCRGRZYUV3E7FHNEL",
-    "GA4W5ZCYNNCYYCOIOMVJZ65SKD57R725SUSYHECFCF5O7IVJ2OVQQJGQ",
-    "GA4WMR7SOY6AVVDWSSD2RU3W5DMIONANNPJ4TWCFBO4SSNJGMSG6JFFR",
-    "GA4WQ5BV7SZWCZZGFARBA25U45435PX3DTLR5EUDUYJUYSUZHRMFHOBN",
-    "GA4XDKCNESOVSY255HIDFGQUO5TXC7ST4TGHVNHQXXFPANS7CWXRDL7X",
-    "GA4XNT5FKD5F4BNNSQIUUMAXRGWNKTZ3I7BFPNHK3VSCARFGJ3CTIQON",
-    "GA4XXCKDZSGON6GDUVTQIFACELG4WEROSGP7BTARXXDDVYZFETM33XDC",
-    "GA4Y6IUGACSM6TGQ4AZQWBZ6X6A6BIF5M5TSRWDD3LT7SSEXEFJ6IPZU",
-    "GA4Y6MFLHTW4U2KWCTACYIBBIGXPXHJZNL2TAWTGAOMZN7ALW47NVXDC",
-    "GA4YEOVBJYWTRAIL2TQBAA6EWG2OYC6RNIZE7QIKOA7JGRZICL4LRMMN",
-    "GA4YGSLCGPZHNJ4FEGDOX6BPQZPB7WR6FVS3X5KMMONSPN4BTR2IG53K",
-    "GA4ZONZXVJADEB5PBNRBR7FKIITUNJQ44AAFCLPWDEI2R6P4IGXRADOD",
-    "GA4ZPYPVOCBVC4QL47RUHMYYTTC5FVIEYOOPN5LV7JOW4ZDHHA72OVYM",
-    "GA4ZQZBHOUK4NDHDKA4VANYOTAEBCXWKU4ATZKWEAGZHW6REU5QC7C3A",
-    "GA4ZRTDI7MNRR5XMHB37JIDNPYYZKJ467YBKUGO5KTW2GNLUFYAGVS74",
-    "GA52BNH4XYKCW7ZL6LAC4SUSCTWTMI6QYD74XIHI5ZG4736ZG6UKEJ6T",
-    "GA52ZTAGOPUYJZFVM7GZLBHOCK4IZ4IIEC56NSE746QBZBCGISND3HHM",
-    "GA5325RF4TSDW5ZKEQYKNM3GTUXBEHHKY7T6R6ZCZ3G7JHP2W6OETRH5",
-    "GA53L4EGJPHH72JODDKPQ5E5SSUOAONKDMAMC2CGVCHSCAXZSNNYCV6I",
-    "GA54ACDVQXZUUISXTPF57GBQMX6P7LMBSI7SFFQPC2F5JSGIDAQWQLYN",
-    "GA55QJHETDWT35MMLZIQC7F3JHK6CK434CECCCMNHP7J7YMAQ2Q3XUQ7",
-    "GA55RCMECVG6U25HNKIPE4VIITHWXRWFLOPRNKUSBCKPY2D7LWVZRABO",
-    "GA55RIPAWKNWYCS6QY5PWT627GGEMGXDQMLQ3EA62PWDVNB2ZQCKTELB",
-    "GA566JTP5H4YN6BTPHCFG2WVQRSAIHAPA3W4JPFADKOOL4QEMOGATARM",
-    "GA56N4JAVBGT32JQHMXNV6VTFGJP6YZBLERI242JA6ELTQOPTBMCPWQP",
-    "GA56ZL47AFP3OZDYQ53GIGQIT6D6KM533DOTH2DBVJMT2JBDD7L2RILE",
-    "GA57FBGAKN5JEIDK3RWJQF6RZVPFOC4Z2NOQDOXJLETID4X7YKDSIVS3",
-    "GA57JIFYLILJCYCMXYHLSRJ5CQ7MSIMCVI7C4BSNDJCMGOX2MA5BGV6K",
-    "GA5A7QUMBZH6OOBHUN5KRGQXJMIQ3M2NKLIB3MTRX2VTOGD4JP6SJXRP",
-    "GA5AC55AY2MTZZGCGDVHW255UK5DA7UNQXZVEVN6DQFV22R2OI5QRUTH",
-    "GA5ANPH3V5KPKKNQRHRSXOQ7MXRX3H54AWGYLJYLNRG5W5J36BQ3BHKM",
-    "GA5ATLPYVZSWFT2ABDIA6HXDBNVVMVLD3R7JJAAWGLWUZSGSGR6VKD7N",
-    "GA5AUTQWYCQKT3LPF7NVNMCEVOID5DYP6ZRNXNGSCJQQZQADHAVF2EZ2",
-    "GA5B7OF5EXYGFQI7R3FRN6F4VMNIX5XAV3DSIMK2PCKQMTZFMLL4AZ4P",
-    "GA5B7UMOM56FDHDEC7TZ5C57DBJP5GGJMJEOPCMWE55LSMUG4WQRHILP",
-    "GA5BHTY5U3MVZYFFWE5SJ2JDTXNFNXOXIGCNRWBN5NFXH7FSXRDESOJO",
-    "GA5BHXIUEITJCUCPAQQMS2UC7MGVCC3JGRVTSUHQIQDDCRRLFNZBWVVJ",
-    "GA5BK4HTPEKCSXRSJOWM244OHZVSFJN53VP7Y7ZV47GMSCR3R62SSSL6",
-    "GA5BXZCYWAKODNFBU5WP5WG42ZUPNLBJORAYSTVJZGWHRGDZMCNNCIUH",
-    "GA5C7B77JT5Q4KDOHX5Z53WD5OO2MCMPCH5CTEUXTPKEIMBAS2PPNEKA",
-    "GA5CCFIAV5IZJ5GOPA6VRY7KWYOYPAH6Q4G666GQDKQMISQTWDJ466CJ",
-    "GA5CCSPX7TQVCWC57AFYZXS4QU2N6V5KMSZW4FUOQDO55242UCS4LTHU",
-    "GA5COMGHRPMDTXWTROQRSGRWRRC6YHZQUYJ3J2GSX75JQP5FAKPODN7W",
-    "GA5CRAT3Y3MSFZZKVZ6RYSRQPL6VK3HPZA2HQGY52ZVTR5BUEW75GT5N",
-    "GA5CRYP234EQILQQGFFVY5NOGJCZCUSOZVMF4DBNLGHL2LL2PENYJRDX",
-    "GA5CWEQYETRZBQGMY6DRXDCJQDENGPKHS3Z7QA23WPXV526FI4LU7XT6",
-    "GA5CZEB22YJVIPB6CXLEOQTCCYSBEPOXIYVZQAMSMN5VBX4FBUFTH2K3",
-    "GA5D23XV6IBKZGFUJO6SL4QCICQBIOR7DGKWO2JX75H7S7ODNDIAZRFD",
-    "GA5DCVHIWPN44Z3ZHYC7TKBKDHQZTKOCLMEIX37R4VM4XQGH3P5NZOCM",
-    "GA5DFRAMLSCMGOUG4DVBACX64QZO3IG36CLF2KFS2YZFJ6TGLZAL34MK",
-    "GA5DJLD2SLDOE5XYHK72Z32XW5M5MC74HKCV6W326CMYIKL2QHCUJMMR",
-    "GA5E2RZM4HJYZUQKAOUYSSQ4PSKTXHCCS34PM6DQE4E7FLGES453QC4K",
-    "GA5E5L5HYWEEZWUWIQB66K223LKX7MM2T5OVMHP7BRRIRCG6AXZQUSDT",
-    "GA5EES56FWUOSLE3TVTYSRCPPDKSAYREW52TWM5ZZFAZENKFDAMJQXLM",
-    "GA5ETGJ6774XGLYUNEW47D3YX7HR5YNVT5HACJXJOQDJPWPS4PWANQPZ",
-    "GA5F4OGFGBCW7YAZECHNYA2D6ED7D434MQUUXSSCOODJFPYV5GFAYYQK",
-    "GA5FAWKUXW3M56FDBNTAS5EAO3M2VWW4NHZ4IVMKAAFDKCPMIZBRP4BF",
-    "GA5FDLC62UHZT6UBBELZJXOF3BSFXEP7ZVLSWDDK57IZRNUHIMGDQBQK",
-    "GA5FKJCWXP2WHXT3XBPUEOSN6YFV7PBZA6FBIYJEEW6NOOTVM6XLEGXY",
-    "GA5H3TO4TZAO2BEV3IOZI7SII4NEDHMPR5AETUFBGBJ3QCPL33STV5FC",
-    "GA5H3XHGPQZV477IXT6YPTT46TNDL3XTQBFT4COGSOIXZ7LKX6XESZ4H",
-    "GA5HCQ3IPIZI6Z2WN3PYYZJWPGBG54YD4KEV6CXAZYURVR3UPJ6HJURA",
-    "GA5HEEBNJ5PV6R5O5K245GLJMZENV4GP3NXF5WAPVYMNSG6ZI4J72HKT",
-    "GA5HY3MTWIB4EVEFERWGPBGTJMQKTRYQIPOTALXWTVDEN2W5KCUSG4Q5",
-    "GA5IRV7ZKJJ5D6ZLWHRYKGAHLLL6ZO5GJ657UKLU6EJNYET3Z4SVNGRD",
-    "GA5ITU7SWVTV6OBJ5BFBIMLTA3VGGLWWSOXBMH4VEGPJDBXNN5NVMEF6",
-    "GA5J4NUWCXS7X3IS677QDWZL352WINQU4GCWMZIKZHWFU4OC6VHAFDLH",
-    "GA5JHW2TVFXL237GH3PXWQT2SWEDT4CZNRSSFRYVLXQS5A7JG446IOIL",
-    "GA5K4RUKYVXEX755ZM6LRWZ7XPJES5QMOYP2VTFLRD7JB6XBCHX7JURF",
-    "GA5KHHIKIQ3PQJO5MRZGCRAW3KGKH5CCLBCLZQZP5NIA2V3NJQHJWZAL",
-    "GA5KJTLYRL7ZF3V22E6JJMPAD7KBGF4RJKMXFCKAFVWQVVY6HKWJR23L",
-    "GA5KOXQY4RAV7NCYEMUTN4KI5NPY7NSFBBL22YG3NI4AMSDDBZXR6BEV",
-    "GA5KTYDD5AGL2WTN772PHBJPEWWEJ6ZEZJHSHC6G3VCXSGCSS3O3XNB4",
-    "GA5KVE3MS4ZNLNW5KDACHGDU4Y5IAJ77SZTKS7VNKEBSVQWVIG7K57WE",
-    "GA5KWM4IVOIDKZGZAH2TCFKKORRELZL5XMENHTTDRAJ2HHI5QJY7K46V",
-    "GA5L7WZIVJLUR45PL5BRF5SVWUXPGPE2ETMJ46VVEW6Z56F6WRHJIBEZ",
-    "GA5LSSFDOT7THZTKTCTT76UUFNVSYM256JRNI54E5INAEU4G2S3IJSKP",
-    "GA5M3DQC3CRX4BHESH55IUIIWFU3EGUIRCHARMTPGEWUT6LCZLTPJHSU",
-    "GA5MCH3XO4IMDM4VDV6CQZGIEFSY7MEU6Q4WTHVKN7Y2U7FZPEW2RWNQ",
-    "GA5MUSM5HLIMQ2XTMTM5ZF6R7FK6NXGGJYYO3RLI46JKJSCPGK5PZP5F",
-    "GA5MXOL2VNODO3JKLS2ZAGOFIXM4GSQTJSCV7Z4PHVILAHETQTMTF3TB",
-    "GA5N5OEXJD5IL3VJQ4I6BCBM4GDCFYBPAZCFFTXR6J5F4LYZO6M7HP35",
-    "GA5NNS5A3LC5DIK6HHYQJYUWFTTTALGAYXO7L2S6K6ZJD5BCPNPABHSV",
-    "GA5O24MBAKBNJT3KNYXBVJN637VG6MHZWLSRAQCWC7H2PFB26GBIHIUJ",
-    "GA5OC7NL3DIJY3IBTHC7ZINGXOSOAKCBUEB5AYVIGAFQQKXBK3WDJOGP",
-    "GA5ODJGOTJS6IYY72IPROHZ4LK7RVZMK374J3C6UXL2UVITVSXWKCRM5",
-    "GA5OSQO4AZ4RNEEF2GHIXOBMXOZ7R6JAIQVQT2U6CGEAAZZPSQNTFSOL",
-    "GA5OWFNVZBSOI2RYXCRFZVYKROTIVHRFG2QWMAVKMVBI7Y4YA2HN4IFN",
-    "GA5OZZ3K3VJC7AAY2PUA2S7IY5K22EA32T7NN25AJQXYAONPY2DFYWEB",
-    "GA5P7V7YQJTFKIX7MRKVWAUGDLG5ALEQIRNLDTB4SNQN53RWKRTPCZI4",
-    "GA5PZDLJOJDDI7K52E54RL2C3SSY2F4DX3C52HB5AZ3LUWJHONSN5L5B",
-    "GA5QLDD6KU5TCPMWRH5BCCGF7BTZW2XYYXK6G2EQ7K4DFVK5PNWY5NF5",
-    "GA5RG4NH6FEVPH7XQWSLQDETQZY7S4LYNUE3UREHPJUEVWKPP6LBVAKJ",
-    "GA5RNFXVKS3D3QCATSXH7MFOBDOHRGVDRO2NVYKA26VBSKXKT7LZKKIA",
-    "GA5RNS7HEAKAFWWKJB4M23PBS6VCVD5TBKZLH5SRTMZNWR4SSQTWXOQD",
-    "GA5RYUOQTO64TPLFQ7PABCLH6RN62ZGXR4M5IMA7OMPATMCHMQ5GQNET",
-    "GA5S4OCPIO6ANMXYMN5BU5IMOTRYJ6OAFQPA5T6AS4E2IJWEP4DDR4UG",
-    "GA5SHCJ4AJHWMRP6CN6W2RHSOGZTEPZT4J2MY6AUXAU3HMLXZ36DIBLU",
-    "GA5TCFBC4M6WNRJEUJH2UM7ZA2RR3FBAG5OUY24GNUUGCXCCYUGMLSBL",
-    "GA5TG7HGJEH3DXDAUAJ3G6A7BNBNPGAWO3QTWLFQH4ZQJ4H22WHCSZGH",
-    "GA5TTCSEMLREYC2TM56K4PNGX2HJACZFCBSZXO3CW3DG2MQ7OA327H33",
-    "GA5U3QQQRZABN5ENSNZ3ELCCRPJBPJRKZL5RA5URQMCKQN5OLB7SJVI4",
-    "GA5UIIHLFNLX4JLT27VVNOKYSHTG3II4IQZSYSTZIBANZW5WDR6SKHYP",
-    "GA5UM6RU6HURHTNNJTRHTRCFQ4V6MDBBWUPENMDF5MUDL2P5GOS7AWBT",
-    "GA5UMVOE5AZJTEFIZUAPSBRSBF6I6U4GFWRD2FUEH2MXBF2CF5KEPROJ",
-    "GA5V2K62ZYMCMA4SWUXHRSNQLFGS6NXNDE33QJEIRF3GPH4VGPTQOLQE",
-    "GA5VKZE7BJYYADC4RN4EWFJGCVV7XHC2OJGF4AIVLB5BFDNBR6ERZOR3",
-    "GA5VLYBHYCTEDQZAQMTPVVG5F2S5HWSV75A224RGDH7VCYCKMCRFWULC",
-    "GA5VRDJJTJC5OFPKE3QVR4O73MCSKPAJHYFJZ5RKOCDJQ6I5FE5JTML6",
-    "GA5VRJNS2WKNEY2H4XLUZPG6G57GTPVIMRJL7UIDKL7KTWAM75PRLXRP",
-    "GA5VS7XVJ3435BI2MLRM2IQPMIGI3ZPUVJQKQLX4XCZZRP64W3EXAG3W",
-    "GA5VWTPXQ23SLYZX4TMCKRFBT5AL7WEDROT5LAD3JIBFDKIPK5OH7DCR",
-    "GA5WGFHSO45PIG4NQ65UXJ7EQ2IR3ICRXE3VEG2HPYCBS33EFNKPKENF",
-    "GA5WIKQ3EDDJCQV6GZCHENXBHLIB7ANA7U6NFGK2MVC4ULCH4RYETAV5",
-    "GA5X6PLE5H2IRA5W2YTQELRC5Q6SOY7WXDJLXP4BWDEN37HR4SO7BBCA",
-    "GA5XIEC7UJG5UKYS52E2BURMUULECUMNGMEZRS5WGSB3KBOUOB3ZSOIL",
-    "GA5XJ4N6UGJXDHDF473Z73DPBSHYXFLJXSAAYMEZO3OMM6HSFS5HS5FR",
-    "GA5Y4CJZ6OS5JC7RTOX6TVHMEPVSV67QXKE42SAAGBY4J5YQ67U2ZJKO",
-    "GA5YPMZHD7IKGAQZOTXDOXY4USABLIUDZHLI5PJMAT6TPAQ66EC5CTH2",
-    "GA5YZZFQDDJD3GN27IJBUUCNFAEYH2DCAUXQDXHZYM5KCJXR7TE2XVQY",
-    "GA5ZLINQWRY2DRORJWXMQESAVT3C2KXX5KWXH36S7QBX2734DIAZQXRP",
-    "GA5ZRD3OSUAB4N7IOKNRBPGSQNTMLTSNI4UX2VQFGJAIKYMBARUZD23C",
-    "GA62D4ZUDI7BAPIBC5RTLIYBCN6OYBFJ3INU3MLOYAIK4JD35M3FJH6G",
-    "GA62DG5PCR3RLR5GNDXPUP23IFJEDODCXAO6LQNX4DW7SY7TAGO6KECQ",
-    "GA62QYBYD5TFVJDMFQTDA72LQVZSW36HIDO3LOEQ2B6RNHH5QY5D4QYT",
-    "GA63KVKMUMCVQQP6TQYAV626M2UWRZNIHOCCGWM7SG43DTL64F5CGRKO",
-    "GA63VG4ZBTEMXRSJK6T6AU5BHFOPKFUQ5EWES4OSFL5OSKAPBGUNMXRY",
-    "GA64HFNVPTGZWW6I3LC5LCPNOENOAKE4YXQJGA7PA5EBC45GVZOMUXHU",
-    "GA64IWIUTT2PLFFPUOPJ3PXATXFC4Y2HLA2OHZN3IUK5PZNRV7ZHWHIP",
-    "GA64JUYAXMWPMUXMOJ4CQMLCD3OFPYDCEHMZBNY2GQXLAMM26SQGSFUY",
-    "GA64R6GHTLFO4MI26QHR2WD3XLSANHRPMTJPH3RY5DGLV624BPQO65VL",
-    "GA6562QGO6RAEYFIKBKOHI7HFMCIO5QZY7AOC7SIL4JIJHOPAPNELWNC",
-    "GA65UJBQP5RS4KHAK3J3WTQHPMEMVBCLOK7OAF3EX3SKTOVCRPD4GDOH",
-    "GA663ZRHQPZOFXLEZWIQI2CIYOV7Z4YFSYXMLVNIBZNQQ2X6FVLEZ6P3",
-    "GA66KINWQFNMMQ655VICHRCHIWZ5WQJTYVPJQYUKGDYPLKN3OUNA7DK4",
-    "GA66WASA7CS477HVLTF26ID6SRMX4OSPXKCIVUPSLN3RFQEVVXUV3HFN",
-    "GA67N6AT7PIND32G2CYAP55MSQK4AQL32JRZ2NLFOFZ6FXRGUXUETPHI",
-    "GA67OKHILE6NK66LV7NSWTYA7RMUOL7RCVVABJRQNAGYIVNIHY7AGBTC",
-    "GA6A4GU4AFZHX635OGUNOO7DU6CH7VOPEZ7ILOA65RVDQ3MMA2JSID4H",
-    "GA6AIRZ7QER7I24JP2OQLSKKKXZANYSKXWC3FXZ6NMICBC7ALMM5AAZR",
-    "GA6AOTVLI736FYIRQUFGMGXELOT7ZXQI4T6BTRAHTONKYFEAP66EELON",
-    "GA6BFVXFXBJOEM6SJ4UFUNCWBW52WC7A3Z2INV6OG5HL7MSOX2RS7ESU",
-    "GA6BRMYV6HI7ERJ7MDDNK7XAMCWYZAZNSYK3FXC2LM6OK7RXX735GRAM",
-    "GA6BVZDPTJVHXOGWKKDM35NBOVRG56WTDWM25Y2PJBZVSTMS5OJDVSDC",
-    "GA6C7JW53XHRVWTOS5QDSOZY7TPDXGPJ32MI74ITGFOZ7TBUA6EMICNA",
-    "GA6CPAFORCUS4M4RI6YXIF3XHMCCDLO6IH4ZQQ2FJWK6YLRLT6TW65UB",
-    "GA6DFFGB4VASAUDTOU2I5TRR5OUF3GJO24IBSHOOTOKO6DVYZUOY7XX5",
-    "GA6E55IBSIY73C4TXFEWUCTXJY7VAP76NFKFEKQAGGB4EFFTONVPESL4",
-    "GA6ESD33WKVQRMP7LUWKSPERFZWGQ3UZ22Y6UDB3LIYLE4FVATZKNVBA",
-    "GA6EZJW5IFVL3GZMATPJ3VV7JFKKJ7F53VHLDYPQHMYUTHBOLQP4Z5ZP",
-    "GA6FCUFUG5D2YX2PP5WPGAZAY5ISX37W4D33NRGM5I6JVBU2RPNYIMET",
-    "GA6FEFHSQR7DI7IYD5A6YNQRAP4CYZSOCTC6SGAUPYBDB6636XKUE7MF",
-    "GA6FRYK6FNH7V57DGNLH2AN536RCSFB3HL3MCEEKINS7QKUOP4FPPVLN",
-    "GA6G2WMPQHJBAN4UZVHMTFSS5FDQFIVZ253HXM7F4E5N36APSS5Z2VD6",
-    "GA6GGT2WW5AVSB3NEPFKGSFU6LOXU34O6DVPXIGFRMUJIRY5QW6DQNBA",
-    "GA6HEF5CXJRON2HEPNAQJKT7DF3LVFZRXU33WJDYZCWOVBAWWMX7JSL5",
-    "GA6HFPRGOERVNKW7EMFEMKB24GPKK47VMRKU6VYYEFTPRAHTSJWWVQ63",
-    "GA6HJWATJDFFBW3YCWXC6BCCZMCJEQQ7UT347WMZRQ5W6QDYZTWPBVDJ",
-    "GA6HZSJ2PPM27RDOOK7BFVMUUNCXHUZNR34GMHX7TW4IDFZFSWA5XVIY",
-    "GA6I55TTLXXL7WYAZ2IIIUIHID4VGPU5REAJYTMVGI6VTVPSDDAFKUHO",
-    "GA6IE2FFQZDOYECGOH4IUY2I3WWAZYVL6J4JOMVLV4XFN3SEKJU32SKP",
-    "GA6JA4F3OQFNVFRJUXC2YEC4FQK6PMHQYTEPZXVN2ZKSDJOP3UNNPQNT",
-    "GA6JEWIKJWEQT5FY74T4EGDYBKU2LT4JQ5RMHPRXLAZUIZ6XBAHJYQ7N",
-    "GA6JL6LS62JTXTRMJUS5OGHDX5SKSL6U2QKSGXJ4ZLRTVW5TKMAMXYGC",
-    "GA6KLJA5EQM5CKI6MQRKW7RWK4BVX47JUW4QNPGOX6XFTMPJYXVQQBTC",
-    "GA6KRLRRHXPYT4LWUPOY2DLHEVYM477ZSXG3SQJUNVUMAGAMMQVYEBFT",
-    "GA6KYKIVIZDABVQNLFXTZQYMYSVLEGF23DTXSDSVLJI7QAOICU24M32A",
-    "GA6L6OBEZGVLUJKVCKP42N6GJO2MPVTYQZUSJJVJFPYLSBCOBEK7BANK",
-    "GA6LKWCLMQNMP2RSIVB5CDTFFH5X2ZVD2IPTFVHK6ZQ5TALAG3VQ6W3S",
-    "GA6LLNQ5VQ3PLJAFJ5GG2M5KZTXLDVG23UPPRXCSXA6AHIBCGLTWEHAO",
-    "GA6LRRTGSYL7JC2NDEHP65WL5FCJCYKA2OCMDTNTWT3I77RCGF72PHEO",
-    "GA6LX2YVKN253OSI5YM3U3ILXEYWOXL6ANRJ3MP4TPZW4DRNJW34RIZG",
-    "GA6LYXKKOTE3AHUGAQPDUJTHOLZTULCV5KTF3VGRXRLF2MQ2ZSBADLRD",
-    "GA6M32VNZY3HJDGGWI2UZX3UY6RLHB5CNGKYZYB5OEYGJPNQNQMWPIHH",
-    "GA6MEKBB7WBAXE4KCE6EEAJL44SXXAYJCJZ5H35YLSD6RUQCZQT73SGB",
-    "GA6MF4KMTNBR22JTTAAWY2W3NMHB2XQU6KA2FBKLPEHEX2R6PNBPUBFR",
-    "GA6MT7E7E6JCJXTGW3XAAFNPI3YWB3IUAFUYAIJVQAPZKRFZTFKDPJED",
-    "GA6MTPCPS7HJEYVKGHBYRTMRTNPLB2XQEUI45WZU4CG4Y6XTVJYCXMD5",
-    "GA6N7MUUUTEYPIP33JRS46YKOOJG2MEMUGAUUSREVIFWXOJK2LOIS6LJ",
-    "GA6NGPJSGWSRL3U376SNHUS47PL4BTYBXDBYLAYX3IOUSARRRG5RBZ4Y",
-    "GA6NQ3C35M6DE2ZZ6NGCL2G4BJIQNREVSZMM2TEZRMQIRXJYVLFBSLVR",
-    "GA6O6PJEMK4VNEUWYDVKQKKVZH553YTO3ABKRICCIZXWYBGJJZPRIUAX",
-    "GA6OETZBFDAKWDDMAXEX7KHC7SRYQATB4MC3DNF3X6ECMN7UQZYRIZ3I",
-    "GA6OQWAET6LTPXQOMKUC6RX7WN3PBPPAM6W4O7SYS4JYT6YUPG5RXK7W",
-    "GA6OSCRZKTGNUSUMF5YW6UTDAFPHONNGQZ3UCJBF4UMOBXOTRJW2LRSB",
-    "GA6P7KMQ2OA2UJWKTK7RP6NPXUKDV3DCPYMCNRZKSYTGUYTCUCXOE6WT",
-    "GA6PDJN6J6N76RUOCKPWV4WDIIPMURUF75NQWK3BXI5ESJ6UNGC7LCHJ",
-    "GA6PGGMELA2NDWJYYAN43XRRPXFRI24IQR7C5D5DCBS6MXMQFZA3YCIK",
-    "GA6QNGZY72H3MWQSUI4WAAWPYMDQETKN26WJZTR6NGJXW4VNULV26GSF",
-    "GA6QUE7FLHV6D2LSYTOBJ6CBA7OSDD2E3U6FPJX7DPBSJBP5YLQNUSDE",
-    "GA6RD52MFVBBV63BKDI6VNFVHPC6EFRZTE6WX2DQ3QETTNIXWC64WJSB",
-    "GA6RDTUSSDOPSTKORX4QT3FKNLZCH6UACK7T3VSIAUZKRANW5LDOLLAR",
-    "GA6RELIKCBSG2NYORDOEDNU2OJVH6ICZVSOHAJ4EJDBDIIXO3C5Y45BM",
-    "GA6RRZ4E27VSN37TA3KREXXBU2PCMT2QM2M6WFCUCVZZ6BGE6KURLOG4",
-    "GA6S6T65E3UWISO4JGUJNQQDZBX4EK5HR56CUEMRIMP6M3OEJ57NDDGN",
-    "GA6S72H7LCUVQKUOJIM57EN2II6ZEFD7N32D6GHHZP5ZNCUMGGY37VU4",
-    "GA6SA36WODFXHOYOCJXIGYOKNX4K4EYWK3MLYY44XVMATKTPZRB54BOP",
-    "GA6SPMD3K3MGM3CKNZMOHDZDATRIM4IYLD5QJ5Y2U4PWZGWLT35UJT7B",
-    "GA6SPYBSR3ANJUDTYCAZ7RAOAQFNSPBN7HHXAR4H4FOCLFYNIN4IFBAP",
-    "GA6SXOLN7X3ZCMFLISYCNSZXXEEV6YYNQEIUYJGFQPKGHJ2RGU7PIFEA",
-    "GA6T3H65IA43CT4QUNG364PI72BQ3WHZVIQMGNFA227Q7NS7VSLWXPSX",
-    "GA6T6I3GES2IEXHJDOXXYCZPNUTO4237N6J3CFGL6KMWGHNG6V77KOLT",
-    "GAI73YO6BGL3YG6DLIGOESKU5VX7KJTC672SKNZKSW6GZB3Z3JAAGXW3",
-    "GAI7B6IIT7POXP2CR2COT2UZOHZFK7WMPYQRY3ESWOGNSZEYN2Z3MTP5",
-    "GAI7HPVHX5QY3YFYZ5JL5TDKLOSOK5XUIY6NZ3R77I57D2NCPD5KWWAP",
-    "GAI7WKJP6NPXU5R3TE7IT36UDK6IYYEJNXOPKFYLTDNI5IESPAJRC7RE",
-    "GAIAHGD6QCZCSDYBEDK7WWA7A7UXJGTBVB2TVX2P56IGRP62CECFNDBP",
-    "GAIAHOQH2LDAZL7AKPEX6HPV3I4N57GHQQFKQK4YVJ6LGHIWXTF4XRPB",
-    "GAIAI2PGVXJQ7BJCOPHYK4KHK24PR4FHYIAD7KR3TYXUUWNUVC5T7CT2",
-    "GAIALVACGIW2FYBAOTXB3XPBWMI2AQAKU6OGCBJ6C4WTJK5PDBZIWHV4",
-    "GAIB6S5LO3O75FC63RPANE3WQPERFIILXJIB5OLCDHEO424MYIFMYA25",
-    "GAIBRNAIHRMSEC6QNOGARELKGIKNS73KPMBBNLXTMGK6HD5JWY2VQEA6",
-    "GAICBDNQGHRL7S2JVUHIJMWZMOCGQ6PUQZTPXDYRIGXSUPC3BOEKXGDI",
-    "GAICJFN6BD66ABNPS3GOOLQIZUH5J7UDNNXVQOC63MDM3DWAEUOL6I3I",
-    "GAICJIM7NUUUMIZT77G5RP2YOFYNERY3YMKDBFR2OOB3OVHZTMAMORXN",
-    "GAICRBDPSHNTLLQFM3VQ2UDPVXGS6ZVXIME6OCPRCNXCEVKMKONV5MLR",
-    "GAID3NUPSINFR4VQ2W344VVNX3ACZSLHD2KXL6YAIKBROUDXUQSDWBTC",
-    "GAIDHU7KEXOJB7NW5QYID2G2ZF7D5T2JOFZ7D5DGRKKXME7RBXYOLDEX",
-    "GAIDIEPQVEM7VEBSYD6QGMQMEPZD7DTFVXRJLCAQWAHR6DAQRG2B3DTM",
-    "GAIDVY3JLRLBCC4GTQJM5RC6F6FKXYKVFYZNXMNARHZL5GATGI2JDRCS",
-    "GAIE7ZQSVQDHNAGZ2DOBTL4FG3ASGCGOLGD3FO24M7LUPGJAYVXV6DGY",
-    "GAIFL2NALL752YOXKDFSAOQEPRQNVIFXOSJ4X7HINUHRMXSB7UKYRYHU",
-    "GAIGF2F3HUAWHRUOZH7IO472PKUKNOOYPRYQZ6NNS7JXNE4H4H2VDAVO",
-    "GAIGG2ICK2ATV7VUQC6MZHOSMRMFJK3T65IVNUZ7EJEEAU5UJQPPDIST",
-    "GAIGSPT3G62HLYAVO25XDDFHMGC2FOQW5CC5UWTQZT46E24SM7KU4X6Q",
-    "GAIGSY2FBVPJ3K2BYGBRV75QALAIMD6UBRIYFPTS5P5YZI6U2EBRBG52",
-    "GAIGVJOWKW5VGI7YU5EI5RKD7SXGW3UOLC3WPJOX2426ZZBXBRKHXZJA",
-    "GAIHAYMNYOYXI3EDSQZ7PEDRDTT57BCK55BSP23E37Y7IDP34ALVHHYF",
-    "GAIHDO437MNWXTFVKPHT3CQD22UXD4L5U6MWIMJPDQMXMIKI3FZE373Z",
-    "GAIHMKGVNNRNQJMVUG3G2TJ4643I5I266E2QONFV7GOOVDCXMHSF7O7U",
-    "GAIHPUKXVATFSIPSNGO2WJY6U4WGLERQSZVJ2GEBE3F6BZ44H3BYWA3E",
-    "GAIHXDL3JKCPLV2L3NUQ6BIFW6BFSQ5OKGNKSTSY3VMQBVBQJHWQFXND",
-    "GAII2KEQTOFMPTCZJSKVZCHC6QO6Z5KDCYJP6PTMNPQACJRYE6LMJZW4",
-    "GAIIDRCY5KW5M5YQZIUKSJ6KJ5BQQRTB6DPFOAD2MORMCSASKXVW6SXY",
-    "GAIIDU75VR2NJIMTOXTKIJPIXBTRRDLYKTBOCTRLGSF2BEIWEE4SIHMN",
-    "GAIJNFNOCAAGIGFZEQ2K6NN4ENA2G6REBO2NEMNIBYMEFALKYK4PUT5I",
-    "GAIKF6IXZRU3I3DL2CA3MAXNWS5T36TXHWTCLCIJA4Z3YBGCYD34YSHX",
-    "GAIKGVDLG4ZQJOQHMWTKFDYIVN7TFJZQZISDEMIEYUKPQPKAZOTGQ62W",
-    "GAIKHEFBNRFSSIFQMOZU22TT7PTFH3YFAKCKB2JBFCXJCVIO2YZEIGQJ",
-    "GAIKIHZS7DBK3HB4YI5VAOA3HXZMMECBQX4V4Y2AHI35ZJZSBLO5EUAP",
-    "GAIKOSOJENF367IAB3R4TADYT5UCKN23XGXRMDFZBJSP2JDCPZBWSHLL",
-    "GAIKSTY5EOXB3U3DX5B2AFN4PHYUONWTOUK3GRFX6ZHCYG3W2DGDTUOG",
-    "GAILAKVTXEZUACGDC4GK36E5CVXNHTR5KLGZXADF5KIV34JALKZOV6WO",
-    "GAILDICPSEUQHIAWHEGCKZILCBOGQN3YPFGZ7PGCP6GZKWA2S76AIRKA",
-    "GAILSTAETVKSXR3VGWXNOEAFKBSPYFIMLLJXKWZSLOQOEOERFFKUG7SN",
-    "GAILWB7ULRBVCPUW3CPO6UMIPTQ4Q7H5RQ3JJFO7T7SBY4UKDYUVDDIG",
-    "GAIMSYOA7OS53OHSRS6IWXHKEM2WFXDI64YADNY5RD3JOHJOYLTBUYDT",
-    "GAIMV36WSTXZTW22DAGKMO34N4CWTOC4CZCKEAUIEHT47DQ2VS5FHJLY",
-    "GAIMWHOM437FHOXQFZDAEO4RU5G263HDVXYTEEH2LTFJJLKGM4QQ7IAC",
-    "GAIN2FW3FAHWY2MGG4YPRSCQ2LJJSHXS4FUSNKI74U42IVJDAN56KEVN",
-    "GAINIKHKQUTB2IWL5SNB6Z5R7FLWKDT6GYVH5ELM66QGASRE3ZEU2KUT",
-    "GAINNQILGCDNAZNBXK7INC4VAO5QSXO5ZBZAW4DLXQOHPESWMCENFM3R",
-    "GAINQMIHA3YRN6UDSVMAWBCNXSTAZ7NHHHC6275DQB6PGQQD42RGRAUE",
-    "GAINW4VNMR2DS4JN3LWL3G7BSYGO2QQI3CCSJLAFR6BJJHHX7RDW335E",
-    "GAIO25JN2DAEI6V27LHI3K5QOVIHTQ4DOESNXRDDUKE4ODQR4RKQ2FLP",
-    "GAIO26OT5SGPRZTLSBSWNXO2MQFKR73GWFDYK6VX32QAKPJASXB7JI7E",
-    "GAION2K4IXEU7PMWWEQ2SNTJAOLBKQHRYO7UDIBI7Z5AHP2WXWHQSJDL",
-    "GAIOSVDRVWNEILXSZVJ3VH2KFNSS6BOLJYXR33KT5P2KMCGZQIKEKG4G",
-    "GAIOTP52WISP2UL2KELWJQIC6ZJ5G6YWQ2JU7Z6Y26LEZNEO5INBQW7D",
-    "GAIOYTQMZY6UQUDB7TZ3O5O3INQYJTH5VZPX5UYKLC2PVCAXUAGGLB7B",
-    "GAIP2WWI267FOXKSGZ53UHB44ITLSRGFAFAIQB6X7SGCEZUKZSRJKVIM",
-    "GAIQ3DKHDRXKLA6A36I454B24LCDGGCWRUSDLJIP5JZFTXVMV4KDFOE2",
-    "GAIQ543ATQHAKIEIB564WUE22ENIYYXCGYOWMDN6TC4CWUG6SIF4STOQ",
-    "GAIRVXSGLN77PHBHUMM4HWQDCVPZCNHCK5UCLBBJEOHV25W6IMTZISGA",
-    "GAIS54VI3HLRX4P27BIQWY2BTPCUKSK4K4NDTP3EUKH5AA6YCH36AQUA",
-    "GAISPFSTKW77A7LEO2YRAIFVY5WCK5QVPMWEEXU5DIYK5D5CQK5VKAGC",
-    "GAISQZX2KTMVEOHD3GLRBMQQH2ZY56BUMCLKYGVGT2XWO55FV57KRIGB",
-    "GAITEECNHDYCPZRSED2JVHP2NWHTEZEFBE35GJNQHAAMHNMKHY5URW63",
-    "GAITJ62ZXS37XXM7BZY7HLWEXSGDCPYJX6PVEQ7A5BLWFNMSLZGMSZNK",
-    "GAITV4VNCDMKIT5YU6KPK42JOWRRBZUVWM3HYDNT5XOQFQ66SUYP4HYN",
-    "GAITWTIFUXXTEBQ7VPQKBO6QSMFFCUPOXU56BQ4ZJ5FXPBUZB4FPVXXY",
-    "GAIUCNLONT6UJSLWYEV4X66ON2HLABUAZ5Q4IB5UA7QBAZ44ZTQ5V3J7",
-    "GAIUD5NPLO2L6WWA7KLH4LIF54BBX24ELW3JSEINPVDRXZRIENQJYFLR",
-    "GAIUHRYO7JBSMB5H4WYQH6TKIE7IOKTLNLUJIPLODCTWX7BOW4RWATQE",
-    "GAIUIIDA4G2GP5JEPC4KZ7OEPZKVM3P6MBAFTR7FEZRQ4NEBDPZSMNBI",
-    "GAIV63BFTUAWYZFNHZJZHIFXU6OIUWP2ODV6CZX7C2ZI3JL5MEI5WXDC",
-    "GAIVPVLX6SHVAJRPKXGSICODLSJIYRLCA45DMSQLHCF4YJHGNPHF6SD5",
-    "GAIVRETEFJ6RG6WEXAH7H2OSZASQH65BJ3IREM4IFWZHSMYPH4SAOLER",
-    "GAIVX2NE3J44FUMJJDXGH4S3OMQHIGU2IP2UJFMIACWTCLDOP34BAGZD",
-    "GAIWZYTYFZPE5O34UP5DWFOKMCRTFKJVLQL3YBAKEITJEVIA2J46LJPU",
-    "GAIX3WBICFOYKXSBJZYOWIIKSPQ7FYGDIZ6O57XIMAFRNZJUL73SMD2F",
-    "GAIXEF4GO4FCTVVCQ73V2HLVPRWHEL4QJ7ZWWHEOUY4AFYRZFSEW7AP2",
-    "GAIXIYDT4JJ4Z662CW2T7X5SRK6NZZA2U4HY233YNHDJMOWHDBWJWFUL",
-    "GAIXM2A7BFCIRSBB5CFGLLCAD4L3WOWWWTAJ2LB4DOVZDB6U6IKWN5CK",
-    "GAIXMYOKISG6LPBKIAIZDUKFNQ4QJYQC6URS3PAXMLNUVSFCN4SQQ65U",
-    "GAIXNACMN3GNM3UX7HKOLK4ECFZOOJRYW6XYOHIKBXQZEAX6BFKCRVH7",
-    "GAIXOVHYJH5TF7YVILRB6OJKSPLUTOPJBH3GE4APZY5RHNL4NYNPDHVR",
-    "GAIZ2MNWXNVQR6TG7J5NA3QAW5ZCU7LIJ72MCESNZ4HMLSEZMBVQ2WAS",
-    "GAIZKLEF2V4P3YNYMV647UK6I2KEQ5AOBCWGECMV3E7IPUVB2WMRD5JL",
-    "GAJ22A376ZNAN7HRGDMBAHZL6C5GEDR4C6O7BZD7BHGMOWJLJD6YHZ6A",
-    "GAJ25O2TZECAJEESTCNUBVSU6YPCYYAFVHYYRE24I6BLE2JVVOFWMDVP",
-    "GAJ2BQISIDPCTK5ART5AYNNHHSLLXTKYQ4ORHWZ2DSG46ERZSF6C3KX6",
-    "GAJ2D6JUK77PPXH6DEDT6M3V3QZTX363RSHHZ4IYDYSCBFPUHFRJR77U",
-    "GAJ2HIB3PHIWIF5WK25Z2BCEAZG7QGMNGTZ7IMWZFS7O3VZWXB4IEF4Z",
-    "GAJ2KNEUDW2NFZAYGKDCZTWTGF6JYMMZOTE3ZMSDQYKDHBMJ3V6A4TRC",
-    "GAJ2NO4M5PQF6X4KHGJDKDR57VM27ZBSRRDDICA4AIBXEL27TWJXTKDH",
-    "GAJ36EPNDQ7QGP65C2SUWTW4MYGFN3QKXECFDZMPBVJIZ5BXGCONRRXX",
-    "GAJ3A2VHVA4HSCLSUQJC4YILT27R2A63AYIZ6IJBOXK4L3SFR2Y3T57N",
-    "GAJ3US7WL4F377VACLM7HVMA4KU2BUXB7EV4RKBZHKQBZUZB6XQNEL3S",
-    "GAJ3Z7XFSZSOCX3FULMVRQ22YJV6WXNGTK3UAVUM2IBTDPZUM5VSDZEI",
-    "GAJ46YDBI4C7FOBICAMYFLK77G3RLAZ5PZAZ7SWQEHHBNJBAYUZ3BAGK",
-    "GAJ4RV4ZHAN4OZVOGWXV4P7PZQ37ZEZ6TJGSRFD6EPRTU4TPXSGUQFXZ",
-    "GAJ4ZXL7AV4NTVNTKJHX3N7NZRBG5YOJTJ6L3WSWANQYN2QWN23QOK3U",
-    "GAJ5STR7GSZ6VBKLXBMHBITAHGVOAVHQILEE5D5UKNLRLISNZNHPJN2V",
-    "GAJ66QHDWMRLTSFNJOJCG4PHV65OBMFQSYIDRNFMPFD5AAVURTQEMEXC",
-    "GAJ6FLA4K645QN5X2VAAQHXQZDGWIBE77NQK5A7FGXNSHJL7I6UAQUAM",
-    "GAJ6HPQLEZFR4APQR3BC5RF72ZD7O5YEDK3RXTQBBRUN6PAWUDMHZQFS",
-    "GAJ7AK25Z72ZDFCTW6TQS2QZAD7LZDZQQVUMWYX7VVGE2P4Q7C4EJZVE",
-    "GAJ7IBQT2264I3YLWOKFHOFZFG43KGBMW4ZPP3ZVD6SRAE2724D6O5D3",
-    "GAJ7NI5HZ7UYW4TEW55GAZVCJE2FOUAEITTFDLE7ENNMEVBTH5HTESLA",
-    "GAJA72VE4IV4JAQA3ANTJGUKQYJRBVOYJZ2DKPBDYWMNARJYPYYOZYAA",
-    "GAJASINCZUSTE7KECUS4DP5GGBEEWUI3JCCHRTL3ASZRDS7G4P7XR3XA",
-    "GAJAUTINJJXPC3NVEZ3NATROE75HTLPBDLV5QXN6DO65GTPYQUQHDX7I",
-    "GAJBDV5HKOXEI7BGMLRFRUMLM2KDCLF5YPMSM5ZOQEHBALQ54IBTYXDC",
-    "GAJBKBYDORR4BYYC4ASCHXIIIKYLGPCQ47JOQTM6ZOU63OPAD6D46EBR",
-    "GAJCFKECO3M2O33F4LZO5HV6CZJ7SEPET5G47WOCCRR2RQ6FLZNYZG5D",
-    "GAJCGXRK4LIVOOK642VMTDXLMYMPDJCLZJ4HZY2VWKD2HSEHG2QQELON",
-    "GAJCHOHCQ3S5TWEDVC4S5DQAGUFS2TNVJIAL5HDRNDTCNR7XMNOTWMGZ",
-    "GAJCPB4SYRTKS5R62SWUIGWLQIU3ZPNDTLJXBXNQSFF3SZSFEI6TWQFS",
-    "GAJCQNKMJ3E3LMPQ3ZBRWXZWCOK6VMJ5ODXHIICWCJYDDXENU5GNGDUK",
-    "GAJCUNH2PIJ3FWEKWET35DM7U6PHP6NFCYNGAYD227PEECF63NDKDEFI",
-    "GAJCXPEVSFPY6F7T32BZ3TGTB33U2ZNMGCKRPZLA3EIXHEDUL25DGHN4",
-    "GAJDROZTSJWUOJXAAJDXCJNRNJVV5TSGBWHP4IYVGDQ3A4OSY5WEZ7Q7",
-    "GAJEAGFJQXZMSBYOMY5ENJBS4WL4GA4REVLRHGNZYU2VLN6V3TME62PV",
-    "GAJES7HOHNPVLM4IOOMC2ADH5HOPG73B4AAFLYESCYHLHN6E4USRMZMP",
-    "GAJEWDMI3PC2V3WC6VAV3QDXKSWMN2VFQ53A2NJJCMZ6BKUY7TJDQZQP",
-    "GAJEWM57ZHQBCP35U6SXNWLZQZ4MN2OU3NOGLXRAB3M2LO453EELKUEP",
-    "GAJEZRRKTE2UR3GKUSEUE7TWYXQ3RT3UM5NFUVAXFPMKT6RPNSJKFBPE",
-    "GAJFGR3G4AICWES2PNFD4LN37FNMZUWPZLOWUZ7RLAFWMZHPLB6UX3SJ",
-    "GAJFR4Q476VUOUTAF34HAVOWTGVN6PXHN65XAKS4FDIGENOO5GRRBVRL",
-    "GAJFXGMZYEVAQZX5VLPX5M7DPGH3KKCTHM3JQSVG4YPQZMIA4GFDI7Z5",
-    "GAJGRPGZ27Q4XNVH56LUGCYINTHSS7EM2OLCH7VTLXYCM5OCMTRJLLMW",
-    "GAJH5PF5ZZOFSUY4SRMEGCKYQPRCDNWB24GKHS5WK5RA4KEJFHJUOBWS",
-    "GAJHHRXKN5CM2UYG7CF7DJUA6PV6KCQNQEUD4K65Q2N6KLCFKVA5HQD7",
-    "GAJIOVU2ZNUEWBV54RX3ZVCQ2MMKXPVKTH4UZI6I55RFJPNTY76X3CRV",
-    "GAJJ4M2FV4TCS47PNSGTIUEVFZMQ6GI42W6M57FQYOKDKSZVJDRSATOM",
-    "GAJJCXHOEHUKFPFC3T5KQUUDNZLMU26ISSSST2Q5HSSM7WHOZM42TJF7",
-    "GAJJEOO64G6FHHWL5GKM7RO4W63H3GU7UUM63AWQ332PPWUPMJWBM57T",
-    "GAJJLJPYRTTTSRW66ZMSEA3D3VXR43MBE2FXXU7XP6ZCEREM7M5XVBTC",
-    "GAJK4J2DBGUVHQWRGBTYENS27L6TXSXK33M4QD5ZIQRBBU5TCUKWTNIS",
-    "GAJK7I2XHH4UYXBQOB7SS5TKKEPGAIM66FBDGBPDXEDSQHWCRKM3CAAY",
-    "GAJLDSS5G2RJ5JSWMFZZZ2IWBPF2FWPH2ZI77JMNISVN4W54H5I35USE",
-    "GAJLJNZOYNZY3BA3WZMZBZWN2R5XXK6HK5YFW6JUTJQ4GVXC3P4BHFHS",
-    "GAJLQPDBN7M32PCGWL5VCH3QQZQSH7LG3WYCZDLRHTFVXBGI7Z2HPH6Z",
-    "GAJMAQV6IUM5Q5PLU2DFYCDPCQAY6ZS74AWVECQEILYH5VWG5BRZ24BK",
-    "GAJMBSK3326Z2LPZ7HG24O4I6X726EAU2EPUJHYZU75GCCEHV45WYOZA",
-    "GAJMEHUGUGZOZXFJYPMK7K4ETO7NGVWCRKKVW2D2K3ROEW4Q34ZBJC5X",
-    "GAJMWPZVKMY5QKW4X623NOVWCQJQGJBB4NPLEIANHFOXGN4GLASQOMK7",
-    "GAJMZPKJF67Z52S7RNWT6GAT3AALA7ISSL7I6F2S3QKMG2RLVVWRUAPU",
-    "GAJNATQEGPWYP3DKKJW7VLKQCOZMXPJZUEWPR5AL2HYZESXK6EKLLEQP",
-    "GAJNJVYDWQHHUV2Q3UVQWV3675JMT4T2TKB2UE3LVIFQYZKJX3J4EEK5",
-    "GAJNJXDW5RLA2A4UXIBKYHL3FVZSFNBIYHEYTL4BGPOOF2HKVMG3BOYE",
-    "GAJNMRH66JLREJUUX2ICHHL6PCXS4HZEGV4HGTC7YQPPVMECEPLPG6EK",
-    "GAJO2EKW4AKQYQKHRWEKHEXVLI4U4QR5PXIINCNM3KAFHZDNUAPEEEUQ",
-    "GAJO66TDKKAD5BCZVYRYDJLTHDTOFSDGV23KUKTVQYQYZHQFMGNBYG24",
-    "GAJO72PCXJBMLUZDFEMCSJIZVFOFDAMNVHPTIITZY7RODRLQXJ5ENOP6",
-    "GAJOLYMCU4UYK7YFTFRU4IPWQB4FCFC4GPFKSAFE4BVAWOREFCYVT65E",
-    "GAJOSNRX2LDOP6ATHQK7U7IKLY77HCEAPI6CUUA2KAYZ7QFZPTPV6WC5",
-    "GAJPRTF5O322O7VOSHLLADNQMYXC5RIUT6D72IXLSPZTGJIK77ALPJOI",
-    "GAJPTTPM66OFQ2XRFIWFILO2ZKFS4KFLZMYDRZTLMQ7UEJELD4BYNPSX",
-    "GAJPWSATJYYN5PMMUDD5DT35XH2LJAXQ5H554R4YYAFBVOXCQ37AAN6D",
-    "GAJQEAKBNURE3LGG5YZIMCOZK6PFODXJ52SRWAAS3DPLNW66JCLAYXLM",
-    "GAJQNKGK7DCWDWMT57OBW5EATALBO74MFIZHM2C52V6Q73KYTRHWVGT5",
-    "GAJQZJ23Z2IEGXV56A3ACIHXBLGTVZKOGCHSK2C4OKRGCCPMORGDWMD2",
-    "GAJR3OO4YSK2YJGM4BVQVSJ6TNTMWQB366PTTZB2MAQAL23AYZE2MVWX",
-    "GAJRWXRBREG4JDZMVH747336IQXYWVRWKSSTIPTUL7YPLVWK5RKQWMFQ",
-    "GAJRZN6BJYOCLEUJOONCHI2MCRDY5O2XOJUUNJD26V3LJEHDMKKT3BDY",
-    "GAJSEO523LLZUE3WNFSGOFK2JAM73OLGOWNUZKYU4HQKJ4J5TUPXP3WA",
-    "GAJSGF4M44PWLGSH5LMA6SQNMJ3VVJNZMAWYYKNOTEKWAKLY6KSIY6AL",
-    "GAJSLVC6DQKASW5EMKMY3S22T7FXCEVT36PATNDVXWSCEIO6O6CJ5CBM",
-    "GAJSMUIZCV5MS3MGJPRRNZ474WMJBGFLJXMYQKO35ROX3CHHXIBFFRCC",
-    "GAJSSMEV6GH2A47H7XMKUJR7KYM7HDPGX5FPWLBZW4HIXM5MNFRR6OIY",
-    "GAJT5THTJNS4P7X4E4HPSCSWL3J5OYZLALBFZJV2ASEY43UQTM5I3GSH",
-    "GAJTBCEO3VQ3WBSSY4TVADHZD5M6LGZW7EQOV7LHCNTUAP7TSSZIXZPZ",
-    "GAJTGQ4KBXW5PKN46PWVO6BQN2DSRCADR2DOTSPMWSN2OFA5U6U7TGAX",
-    "GAJU6OMN2E7LUVG7GJ7GUSSAQHKLJUXY2SZ3ZILH43NZZS76FG6UCSLC",
-    "GAJUDP5OYHCL4ZZ23YUTNWD6DHKO2T4G77VXNJFR753KO6SXN6XVGPFS",
-    "GAJUSDIAGYH63ISWD4GDUTKIGXIIKMBAJ2DO4NPNY475NKIOUYOE4BTC",
-    "GAJVAEKACYFDHH2ZAPO2FKHBO62K5SFJEOAADVQXV4QP4KSJ7CH4PXSN",
-    "GAJVF6YYZHX5A3JCHJSQHTA7NAFJ6DWPQRRUROGQJ6D45FY2DQNZANPR",
-    "GAJVRYHOEZDKBHGH5R5G4KXA44DJBOE6HV3HBTYVJQKLRYVS5SG575R6",
-    "GAJWJTV5GW5J2WCBPQNWLAL2EYUIIUB6OIROHOV7YVJJWZXPXQWSMHRH",
-    "GAJWTMYZP2XD3KLDHEZV2BU4XUWQDOEZGWJCSQDC2M3XJ42UFDUUFLCX",
-    "GAJXHQICX5CUKTT6SIWXJRRO36FH7OOPWDTHALJNGYRJI2SC6AAJYORT",
-    "GAJXTVBZ3SA3QXRMNJNUXZ3IO7TAH5HNON5KVCBWHKZS34FUZJPKNZBF",
-    "GAJYQBXL75M4OJ47KN3FWLNW5HJLF4YDT2TYNJWZSLRGGRN5A3T2OC2Q",
-    "GAJYSQJYNKJ7GCQ2IM7NMW4T5DDBCF6UEECQK2XTZLUKZFEW4NGTQIXK",
-    "GAJZDWPJLUVS6A6PFLTW2G5Z4T5RZUD2EABJJWNPHUZH46AC43V6PQNJ",
-    "GAJZHXJPPRSGMGKX2657IML7EOLCG642AOYD3KNUSWYJVZ5G2JPBLRIF",
-    "GAJZUZ3VAWBWGTIKUC4BORQNARYJG376QH5N5LHIZ5MMBEYVTC3HGN67",
-    "GAJZWW7FBDFZM2BF6MTEEORQV5OVXOVUKGH5EFZFXAI4VTUJFOS334RV",
-    "GAJZYFOOTLKZP4D7ZOLP5WXW7NYSAN3BEZCSBKFYU2ZV2MQOCLJ37QTM",
-    "GAK2HMXWIOVDUTFLPHHUQLORDRBET47HFEOMKPVQ3ZHKNY25FNGBU7XD",
-    "GAK2SL6376T3DV4RSNMOWIMTZQ2EW2JPQ7Q3ZVMDVUKSA5A4HV4ULVDL",
-    "GAK34WISF74O7WYSONDMXWQTDEWWDQRE5F6XJZVB3CPUGDSORHCCYRHQ",
-    "GAK3FZ2NC3SVA3JAADWTU63VXRKGR5BLKU3MUOCYTBPOII42N6OKVHRQ",
-    "GAK446IKMWZAZ2JOCAX6CCX4WX7WKC626FEFLFUHG5E2C5QOFXEA5UGG",
-    "GAK4AMNAAMVR4IVAQPN2K2ILKOSY7ZMYXMUGT45YTOFTMVSYW2IBYRHM",
-    "GAK4OLWFBEV2E6ZMQOEW7LQQ2KTP2PLKPFYN5RH2DVJE2F4VZZMJHBCH",
-    "GAK4V246B3VMZRKT3Q2T5TUBF2ZGOEMO4HUYOHRXZKJ5SHNDTSZDESOL",
-    "GAK5SEJSVA4OP4XD7UV3QMZIGRYRBN6OPHQ2N5F2TNSYR5PQFUFMLDZZ",
-    "GAK6OXMHP2C4QISVFXBU6OPXS2NN5KATHPMNIJMJIYASVEL2MGZQC7GX",
-    "GAK6QJLC34V4Q5Q5DF6ATWQPIICGKYXI3FGDSOKLL5SJVO6GXIKGGQSA",
-    "GAK6YDM6LFOFA75QZYGVM3SHXX363N3UWJJOGCCQQ2ALKUE7CAPCOVFG",
-    "GAK6YIHYWMYL7ESDDKYKJ36AMG74TVX7MUS6RNJZHLS7XZ3NJHWL53JN",
-    "GAK7KXFFLXKSAG4WP7UQ77A7ASFBURFHD34CB242BSHCEWKCKUOPP663",
-    "GAK7NU47C6ESVOJSCV7MW4BZHPWHDHDONE6FXNCHK6P6QK76RZKRUDQE",
-    "GAK7QSPYB3IEDZODRS6KSQ6HKCZXNBST7J5Z2MCFPMHMKUKJP4GLKJHU",
-    "GAK7SCM6UFBHYFL4COVKRXC6LWQWFYN7NTTSFL5KB44XH6BOYDRWQUNV",
-    "GAKAHRIADG6ZGPDKENHOHR7MLCXFUZYS7AZUHMGDTO55WAZNHZP7QICP",
-    "GAKAR4WM2PLT5TQN64KDH4MXMNRQDM5FCECYWXBNO7PTUKN47NKA2AHK",
-    "GAKB6EWZ3LLHSUXD5LQS2VY3NGJHFWRCPUN6PFHDTFFIJYNVDWFSAKL4",
-    "GAKBNEQHITYURG4LNVFZ25XSULRZP66NKFVP3UBF4KQMZXF6WBVRNBNN",
-    "GAKC2M76UU2WQ7D7ADMFWKDOVVY577FEYMCR35GJY27VOUXS6WTTLSSJ",
-    "GAKCLWALBKNSQMWEGA3A6NFWNCUSGE4QMYYHAPATTZNR2DDV42BEUNN2",
-    "GAKCMGXLJNYAI5WWYI4FG77IYZ4BI4KTEEV2FXFVBV4D6XKTJ4PE34RV",
-    "GAKCPQALRFOVGT7UBYV2672VTWQAAWGQZUXQE6GZDKXFFDSHEYVDWHIP",
-    "GAKCVKJIPXDMQ4NRASJMK3P474GZ3TZXZLTYS4BVGOHGZJRELZY53PMX",
-    "GAKD53T6L633WOCPTS3ONF6VYRTYKT2PIAJTGVZXVA5BUN3A4GYDP6CE",
-    "GAKDJNHVPAE3GEXTYWJEFCRFO5GMDV7IVHLJWX4L52OFKIEDDEPINDUS",
-    "GAKDKLZBM3A477BPEW33HBVIPUGEC7GXFTTSGU7PKAXQLFEG2UMQJA5D",
-    "GAKDVX2TUQCSNRU7JXJPLS76WSZ54OWSTMADAABK3NBEL7AVRMEPE4UL",
-    "GAKEFLZDWIOS3MYAKDOM65I4ELP7LRIHRA3JBP7J7WWJUC6RB2VINDUS",
-    "GAKEHJVD4GO5BZ56BWDZQJATEGV4DBKSGCT3LHBG47IEEIYS5NZ5OFVO",
-    "GAKEQ4625LUOXL7SNT3DOQFLX5HYB62JZ2SK4ZR2UE2F6OZZDMIOEUOJ",
-    "GAKFMNLCBOULQZXDO2QH366ZGRHUCKJZE5EPMZQQ5DMAYLXQBUXMS4FH",
-    "GAKFQH5RWZXE5XMJ5FXFKGN2XS75BKAYMCZCX5XP5JDWFLPUQ4XF4BGF",
-    "GAKGGSZTHFSMF3MOAQUIPERME46MFTP4XR4DTXRGRW673NTYCZHMSN2K",
-    "GAKGTDY7XNM2RHMUPG7FGX5EOIKI42JS2ACKJSSWTNVGUJMXDJOHIRST",
-    "GAKGY23Q63CMX5DRZOY6WBB3KGSPMND5D4CATQHCJVZVPMG45MWVQIHR",
-    "GAKHOMSSGHCYTEVY4GI7D6CUUHZ5UDCIKH7E4UTFPPTSQHVRMKYSPGIC",
-    "GAKHSYW2M7TEZ33P5DPE5IDHCP4KGYDLTRKWO6UB4BX5IGGUW6R45XLT",
-    "GAKHUQDGHPGL2STLE6H7ZJQBVNY4TZE34RFBZKXDLBNT3ARGNJW5EEDD",
-    "GAKJHTGE3PASVVYEX62XYQVMG7NYXLKUKW5BZVKVCSICVE6AI5ZTIWCJ",
-    "GAKJUHWAIC7WRMYZLCIRONR3O7NHEO7LFOD3KYLC46QNYTX7NIX2JCC7",
-    "GAKK6T3CRXYQOWAE5JRN7XB7Y4RXAYAGWSSOBZVRHDOKPMKEEALAZM5X",
-    "GAKKFLI5ZNLDXXHR2A6HLFIE7FSU7B25IRJGEQPMLIG2FD7B73HANHWX",
-    "GAKKI73Y4XJYWLCVZYCAAAKNM3VRDROXYAYXTMH3FEMQ2SXLKOM2DDDK",
-    "GAKKOGR73YWO4J6EKPUOAR5UMIP5GC77MWY2O3JH3DKMNWPNL2DQACNO",
-    "GAKLXKRJTLRNJ5YWVOAVFSL6HFUHJMGDUBA77OPVTOF2UJ32AATLNZ5U",
-    "GAKLYMO2ACNGG6PVFULSKAW7PKWZV7SDQV2JEIY3M3WKCVO3AFJMSFTC",
-    "GAKMJEWCCOTTWYAKUMBXQYGNRLMDFQFM33ABOBHL2UYTLR54SFDRCFF2",
-    "GAKMRGGW2ZMPUPGWY6VPFSUWAWHV2SBS3P3TR3P64PFGAHJTXSNYIPTS",
-    "GAKMW5KWHHIPYY5RQEHS5NLLTCDEMSING5O3KDNSR2MOVA3QLCCF3WRD",
-    "GAKMXLUWGIHHFAQHGYPGHK6IFZRM6O2I4OCLIAHDVBC6FNOJZP6W4MF2",
-    "GAKN4FLAT4CE3AP7FTWGI67TD5XJUXNTZSZG5YSVIZ5VXQX4JQAHDRCA",
-    "GAKN4TJOHJEEODV2HS7AJC4Q5LWO4TTLQJ5CCCL4KUKXMV2XG7GX4AGM",
-    "GAKN5TSOBGM7DTKQ6YPSFG72TCULLBZOJG72RKNYG5U57I746IJI2IO6",
-    "GAKNA5Q45X3TVPWCJSGUGQC5GFLGEUE7DTREGPHH2SEZPRSQPJU7ZOZ7",
-    "GAKNTA3ZA5WSDFMDZOCUD5VFTEZSNBIGE3XI6ZL6NQ2M4W2UZPAPTCQA",
-    "GAKOI6S2CLYUHWS3I5QVGUWX7EGXMMI3EVZE2TR3EWUCL2D5QSGAY7U7",
-    "GAKP2IU2EBPAFR3AICCGTJJ2ZUGDDISGPZ44RBI3QXW5WB2KFY3YHXL5",
-    "GAKPDXYM2RPGZJARGJPRRIORZ5ANVYZVDT4KNEPCU7JQBON6P3DINYBX",
-    "GAKPQSM2HNJA6M3PSQ44RJ5QIOH4R5IJD4TFPCF2SBWB4JZLH3JVK3XC",
-    "GAKPSD526FZWPTVZBQ7H6AV2SYLOBFQH3T5Q4NLOLMUMJZ7H2WG3O3PF",
-    "GAKQAOQRKJTG2Z7QT36N3HJJGRNXU6JBH6GLDTFJWHJKRQT467MFJZ2A",
-    "GAKQECOTJOG2NREDEDUSLHRI52WXYTZ5KA2FY7Z3D4N2DEV5WY3C7VRZ",
-    "GAKQFFL5DQ6E44VPR24VT44VFSJNXP6XDOXP7XBN3XVQ5EHKV6MSKQT5",
-    "GAKQNTBRYNTHA2D7FQU7PNLOFJ7JXRZOD64CAM4753JD6A7DYRBZCIPS",
-    "GAKQYZDJHNEL3AOLWLGOLJ4E2JJ26WPHHOAY5GPFFLSIO2WURNKUEO4O",
-    "GAKR2JYDFRPUILCTAGAOLI647QLC32VOR6TIYE5TAHNRB2VK5EHDPVGJ",
-    "GAKRFTLO2AQUGB6TUXHZXYNBITCAOUTDBIJAG3OVPMRCIRLA2ODXUVG3",
-    "GAKRMLHODMUHMBGPWMMTOBDVYUICCCRSBBWMDQKBM5ZYATWRS74ISO2O",
-    "GAKRSCYI36DT5R6C4CRB5EGAGBWR3VNFM57NKDUI7HDKMA7BLX5QQUSA",
-    "GAKRSWINPJUAEFGCFT44YOHEDKQW5FKKSEJCPIPPDDUIVWGFGN7PKSMT",
-    "GAKSC2TA6W3GAY5Z72MNVMROR3INREKFBXSARRQQG7B27SFT42BCALNI",
-    "GAKSO3AOQZPRTDNQ45KS5A256OZKLITQWIPIX3LCNYFHSK2D2CXSTKTO",
-    "GAKSPFLPBOCS62MCA5MTH55RQE7SQOBLH4WLJCMI36KNKFRFIQIZD3NE",
-    "GAKSX5YH3WL7JQ55WP4U2DQDVPZXR372RJ7YGFFVWGM5X4LYMUZE6QFQ",
-    "GAKTDFPGQVVLCGE5I4URS5E7YCY2ZUQKIYT7R3JKK6EIVYW4GFS4GF6M",
-    "GAKTIJWNH47DSLZLHN6XVIDV7J7EQ3ZT63LNNWUH5JTYA43A2BYBHRTC",
-    "GAKTTC6T6XE3IHNTBNTI4LPJJGVKQHLUGPPRLRXX4P2KPVEVJ7OWMGXK",
-    "GAKTTZ4O7W5525FUOCG5JQA4UVYLUFNRIEGZV3QEJIJXTDM6TAGMVAAL",
-    "GAKUIVCNU45I5IAG3O32TXM3DZLFATGEITSYFG6DUMFKWLV3422UI6BY",
-    "GAKUUMFATNXKWPRNZCLDZKVLXH3HUTLLBLXFPOKC3W7WDYKJ7AT3YLPL",
-    "GAKUZYOLVYRIR6QDZKNYOGQXF4CGFP5US4ZWQZIGTV6XRHW2D7AIWDM5",
-    "GAKV7XBAJUI4NIPUU427C2CIQSPUKER72K2N7QTAPRS7YM5TKN5ZLFC4",
-    "GAKVLPLUXIPGH6PDT36L5WMZKUIPUITPN6G26ZYX43QOQF7GO4KVTVVX",
-    "GAKVRAKLZA4MPAPWAIT5Y3HCCGXJBQMC7HG2M5E5REAJJD2OUHEVEFEL",
-    "GAKVUJL53TTO3C2DV24IJEPEATIBQIQ7TOGRJAJU4RWKQFIAZUAHJ5LB",
-    "GAKVUXMYW42P2DRBO3NG5FOZFEGZLS6ZQZWZ4EBYD3NQRYB546SESFTC",
-    "GAKVWW2AP4VOYS7GFH3QB6SC22KPZUTXHCQSPENZZRB4F425KWDPBUBS",
-    "GAKWBW6HNTNIMJYAEBDOFTAPPX46YHJY42CBAUMIV7CJRGAFXHUURQDF",
-    "GAKWHJPL3WDTIXRSF5XVDQUIEEBJC7YYUR5L25MVHPHLAGHGMIRNPWLL",
-    "GAKWSDPAYV5AGC4MIF4MMSOCZCEPBKU2V5QJ665CXX7PMAQE52NULE57",
-    "GAKX37D46VZHBCJRJZWINHE7MGPJHL4SYXH3QPV4JHRY3U7TGMXWW5OV",
-    "GAKX7W6546DVXGBSGC43BVTBE7V36VOUY54X2ZNNWYF7EFSXKOIG2H36",
-    "GAKXLARKAKFE4FOR2HORWVBB3QXJEQENF2YBJYRWBKCDF4BWDNGQ5BLK",
-    "GAKY6TWFTT56HDS23OXTBROJB467IDUQA55WBJHYB4PQFXBIDRBIZS2N",
-    "GAKYDW7HZXGZXR44V4GERLRDEVKTFGIEHGVLTZMBUM6GQ3HRBIAMLTEC",
-    "GAKYFCG3J5HW7ZS7GNQIYPWS5ARUCR256DSCVTFCB2B5OYHE3W5ZFOR4",
-    "GAKYKRZFEKOZR6UCLQYQ3QUMVKEYGVXIUCE7PL3ERVS7JVJ4I3N3MTVA",
-    "GAKYLWEBXVAAQKN24K34KS3QWPRAPGL3PAFJDJ7TRDU7E4ZRRX2IY56I",
-    "GAKZ2M6RHYO57MRWLZKASFMRIO32A4I5ICTKSK5MNC45UURA6SPDVGAU",
-    "GAKZDR6FNYRWGZRMDGWJ2DV5BOYMO3YBDZI4R2OCVDE563PXWPXJNWQC",
-    "GAKZKAEK4NKDQAGXPX27WY4GR3WJUNCVSLCCAPWLBQHQAEZZKS3RUB5D",
-    "GAKZKRJ3GTJJWHNCVR2E3JJTE5HV5WPW6VLXYTGPZABK2R45O6C5K2YV",
-    "GAL2IKTBK4XUTYR6E4WNCY4MXNRFDGMBN44XI4JRCA45XYFEKZV7C3IG",
-    "GAL2XVAIYQOLI3L5AVGORQKOAYSOYCFRCWNSSNAEME34ADXJDMQCQNSR",
-    "GAL3NTDWGCRMNDOCBE33CWQFJCOZFOIHQPWOHTLJBVFPWOG4CRP3IQJE",
-    "GAL4J7CPBYXGWLDS3BKCLVWN2IT7KSN624HNBRYJKELTCAHBIGJYQPRO",
-    "GAL4Y4DNYNK5GTYT5FIQL7OAKDQYHTWFNTD5UF6JKD3VIMDM3XLM52EN",
-    "GAL5DTZFED3WAS43ZHM5KRYBNJKT5EOR4UENTKISG4YLAJ77AJLEMIX2",
-    "GAL5RTKMH2X3P7QSO7PVOK44GAUATJ4VGGFJGQLDIBKJ3XSTHR4TNRS3",
-    "GAL5SXRZCDLQXI4JIBT6RN7XZA7PAKREM2HC7STW7UFITF4KLTKIPEPE",
-    "GAL6TA7UZ6D4OUTEOYC6DJUJF54PUGPQZZC5IURL3SIHZJYMMK2CJCZF",
-    "GAL6TQMXDUAZA6JYUJMB5675GSQD23SWQPCYSCHZOGI4LURYY7VU4FJC",
-    "GAL6UQQSAJQF5ROE3YDHKTBZKPAEUXESLQGSJO3CWDVIO2FELDPZLXDC",
-    "GAL7WLWFQVP5VCZGGRR7LHDPAUZDG7JJUYSDWXZDZSWMTEQGUXTMBTCK",
-    "GAL7YPG6HZV5X2Z5NNABH5JR47ZQKC5GHKM4GDYI3RXU6CIOFAZPZNAV",
-    "GALACTCEQVPOU7YHW6ZLW2U3BEC54YQZQLGNZXMOUYH35VQC7SGT7ZFK",
-    "GALBG3YBGEIR5MFLPSVEL64H3YZETDMCKGLV7BKHXGSVYV5QLDLGIIZG",
-    "GALBKNBOPIMR3C73BGK6FOKGHIQNZAXDXRRJ4IRQZPNUE6WFWP3YJXLM",
-    "GALC5V3PMYLHV2QGAWSG64GRA3YO547LKCBU5X37OPFP6K57YSLFENQN",
-    "GALCKLBZLSUWC2HOH7R4Q46YHQP54TEOSAQDLAY6XBN7RSIFJNX5ZNY7",
-    "GALCQ6HYUPZ7I5FXO2DVOXRVZG6UHE33M3HCMK7DT5SK4KUMZ25R5PSS",
-    "GALCWHP2RGRGNYTST4ZRPJ4EIWZYI27BD35FDNCHYDRKMSYMODRDZ65R",
-    "GALDAT42UPMP5T3F553QGOHKV4R5CIK7ONXW5YSOZTYA7LZTITM4ELMK",
-    "GALDEXCTPJOT5RC2GIABIXY4PDD22645TQKOXIGMBXRCTGRTQ5K7BLPO",
-    "GALDOHQ3WIQI4E3Q4UZS7RYUSCXFCCAB6TCKR3FX7XB2CMPZ5UQMKROL",
-    "GALDXFIDPNZZMN2VCSXATXAKKVHRUDOUD7CVLS5JH4IMHKG5IRFTWGFA",
-    "GALDYMILTZ22SLOYCQRL4I4VPYTWCQKYOUVLEEIVMJOBWOOA3IZUHZNL",
-    "GALE45OWMDB75D4B74SSNVF7FQEYAL7HX3MRYN5FMESVBFAXS55NNOBL",
-    "GALEPJF3WIPVD5RNN63STF4AQJ7YJROPEY327JAGMEDDG6EZPE332YOL",
-    "GALESUOXMIFQ2YT4X7JAB3UIHC7U2ZGAQBFW2TUEARB5NKZQOOSSL337",
-    "GALEVFSWLK6TXLQGYOQ5HC2BM4YKAAM4NZ7AKH7TAKCGIRDUXHDBRU36",
-    "GALF522ASSCVY73VZ2QOY76Y3MJKJEMD5F6F3GIK7SGD3P4DCHOJAJ7X",
-    "GALFCMCLT6QPLOSYSMN2JU3HTM6I4AFGTF66QRYA63WH4GMCDZ3VLTLZ",
-    "GALFFDRDG2A3QLZLZMEXQTYBJLLINTTHCUGCPVY3BUBXCSTGWVNFOREX",
-    "GALFSYDQXTXXKHPBJXJJGWNWFB7322KAUQIGRS5FM7CG524DHPKVIRBS",
-    "GALGBJYONJ5UT7RUIHSWAYW6YLINQMWWGDW2Z7NOIWU5E3AVKQ4XR4PI",
-    "GALGKUO6OOMGRIFLC4GANKOOT5RFL6ENG7NOE22YFRYD5YZGVJWQA567",
-    "GALGNE2U3T62BVMUQVJJVWCURTZYYU7HHH25RMDERGIZGLUYM5KASTOK",
-    "GALH323BW7UNMAO66EXWPAX5TU3UYL5BJNLJN4DEBHG5LM5EZH3UBT2B",
-    "GALH6HOVGVDRBBZDL5EATSOHX656Q37XY2UHUZL6EBHMDQ3GDECYTZWO",
-    "GALH6VOKYX3CSNVVINXO4ZE3AZV4EIPPUSEOL2DGPGHIAJ23CYZDFJJG",
-    "GALHOYVN5TFCN362YF7CBKYNR4WRPVBVCJG5P4JPUVNCM22BW2YN3O3A",
-    "GALHWE36OCOJSHYD3GZQ3QZJZNDH2YVA7IPPSXDVCQMVX45D5BYDEUW2",
-    "GALIHIPXW2MTKOOBTDOYUCFIUPFRZ6F2UCWND2HD2QRRX2XQ375BKANY",
-    "GALISASBBC6TJUMOXXI3JKBZDAGA72RJYXXS5K5ZUFSQXW7NAJQMSOFT",
-    "GALIW27BYMT4FANRBYFKW6AE5NGLYBKOGYKVHPBP7ZBT5VNDYUEWKFW5",
-    "GALIYONFJ52HT6NUOZ4CGPVVBPT4UYCH4532I36FQAUQCT6TEC27SHIB",
-    "GALIZNWIBMVHRTG66NG7AKFOGP2HNNZC56PDIUTWAQQP3WV5NNAHFJVX",
-    "GALJ2U6DCYROVF3J5HSLGOSXW7WIFDCJCHGP7DVHXSIDTNVZYSDGWAFR",
-    "GALKCFR7THKVSF25X5JLRKAU7ZFXWGHLHPL4DFWUG3Y6WFWGMZTQEP2S",
-    "GALKMVZI2E5I7LKJLXCKSWKPFV64GE4EIGHDB7ZNBDPBNUPKSNMBNI6D",
-    "GALL4424LSMQRVHTVJRMSX6QPSH7R6HSX23RQNTMHY7AWASVCZSINVQK",
-    "GALL4AYE6FETIQT6HOXTB6EZPD23AJBUFRAQBJ4BUZLRBQNSDG4RMW5A",
-    "GALL73SIKAXZWKCFOES2V2IYHLLBWKJWCLOEP2MW2QMHPVKLSQUBTU7K",
-    "GALLHKN4ULYRVNMBKLW76M6VDB2ICJX35V6LWJU53XYXMJWE4ANI3TXS",
-    "GALMI3B7EKP3K5AJC5BU6GUK4XYIPYRZX3DMRZU7EXI7YTWLMWEYGIRZ",
-    "GALMNW2L3T6P6TZLELAXCKL5HEYJ7L6JSFCBWXYK2UKFK6GTEVGIL3JY",
-    "GALNAFNMPOFY45N7KXA4N2CXUSZS35J3QP672EVJVIPDWJV7K6LWBIN4",
-    "GALNKIEBG5JCQNBHLUKPVXRJDK3DJPXE4V3HOKXB3S7AO3T26VAT4OZH",
-    "GALNS4ZW6YXB6DMUAYIQRWQJPEHRHHKBFRTNGFU4RX2N46BQ4F2B2L4I",
-    "GALNWAHMUB5Z7BL4UA5B4P25S3DEUF4GSPGRSCRITF47SQMM3WOUGEQE",
-    "GALNXTJQNTU4HQ3MJNEKA3PY2YNHKTDGN7J4JMFM25RP36IGKV7R7WA5",
-    "GALOHQWI44MZKCZNNYNXUXCHJBG373CRFPJE2VV67FEFXJS6OIZE3CW4",
-    "GALOSHKOTWT3563OVTDPYFRRKBLPLIO3WBXZYAZDMTQDWXBUJPBC4CZJ",
-    "GALP4DJWJFS3WUC2CXHAFHIHX5TKGNZ4WD6G4WMPKKYLLLS2BTP4GPIR",
-    "GALPE64DMW6XQZIYMXPZWXVK2PYBIAHPXUS74ZRFSF7BHFFVHK53ELON",
-    "GALPGVEZEZU6NTCZYJEQM53IO4QUTR5YXNUUMLZBDLKKWV6QWKDC545K",
-    "GALPO3NI6WFQYDNKGGIB3CTVLGGNWR34CSDQDOBEJBBHOOAO5EUZN6TI",
-    "GALPZFKIYJA3KBJ7CAIJQ6FBY2QJDHCKCHNDUIO7YLMO6VLGMPMSELBF",
-    "GALQ76YJO3R35V3FBYIFCXEAF4VIDPNUJAJW3RGM5TAP63SSHC5MPUVY",
-    "GALQIK4MYNXTW7PT665UN7FD5JCPVOHQ5UYGDAF2F5J2GFVNODTMDROV",
-    "GALQKH7LRMDEJFL7PCJEWH4I55JHX7OC2R4GUUPYNZ3A2V4HXVSSBS2E",
-    "GALQM5LEZ47FLMXRDFA27QHJ37BQI7UR2O6JTDVNMB73MP56GNCXNNJF",
-    "GALRAKZTZUG6JMOJOWKLCAQ5CDAT6YN4VRBBRIU7C5RC3II72PH4DNTQ",
-    "GALRERRTWRJWAWZT5EE2O2IYAUCYIXLD2SMYXAWV7B47RZCGN4S4UVJ6",
-    "GALRPLQZ2M34VUSYAWJ5XS7KAESOFIZYT57YL6ICWWQ67CFVTRXHGHTF",
-    "GALS4S65HQSQ4AZBR3P3D2KFALVPYKVV4723JYBJNNXEUMJJANG34XM6",
-    "GALSNTZVOB5JLT6IWU2STR3A7XF42RV4PQP2NBTTTMJTJX5ADVJCN6OG",
-    "GALSNZAFUTBLMH2OHBRTG3T7ZTGC7YW6DSHZRCRSGQJ6ST7WRIODMLGI",
-    "GALSS3AGC3J2DWGB62TWX4SGAU7GZVVNLII46GEIBYLLBFJN5Y6UB5FK",
-    "GALST7KG3VVHKSKCRQQ3OEHPRIELAIFRASUFVNQOMD3YA3VK7A3FEW4C",
-    "GALT5G3XSTL6O54D3S5C2XJ7UGRRICRTOVOQMC34HW67PZ4OWGMAFQVT",
-    "GALTHSGTRGR7HIAFTKFXF5NC77ELANMLXVU2X62VZLWSN3GORLLKW4NG",
-    "GALTOT7FEN33HKDWOMVCDKB7FMPB5G4ODKDAY6WEZVOENHKLL2RAGOXW",
-    "GALTXLYFGFFLY7EGCDFJSF2FTFJ5W247KMVTQMJ3RA3B7L6APZ2NAOJO",
-    "GALU3FLTIUYZFP5UL2QRV57VVQZ7FG7UER2B6BU527D2ZRLFSHAGMT4D",
-    "GALU4K4OOWQCGBEQIZFTIJQKWUYYHF2VUAX7S5U6WP6FOPBT5IYOD7OZ",
-    "GALU5PX2HYFSXG577LJCULWHHLUV6HRZFOBPYOY3S6TEM4OHHTMODHC6",
-    "GALUMJK6THLOTJ6XXDRWSRSMMME7RZ7P4MF3KZRRKJO5Z7D4S2J5ELON",
-    "GALVCFATDR6CBESZH4QVTK3C4W25QWX55FYAUM2XS7LKJJEQQ3XFRQ5X",
-    "GALWNUM2XBP2WYFKDVX36XZULLTK2VEM3UKZ65CRPU3HA2SCOXGWN4WW",
-    "GALWQFKYZRIJMHKKHJ7SJFIPRKW2WXFX242HHRUQ27D4LI4GXZSIDG7N",
-    "GALWTOVSDMTRPDYJKA4P2EHFGYVYXOB45D53GUSIKT5CVHCZ6Y2DSLVR",
-    "GALWWFQ6WGPU3OUC6EUAAUFZC4T2R66OZA4RFT4FORLD7QOKJWB6KI3G",
-    "GALWZBITAJ7E4Q2CK7DMI5NWR6K6PV7YDWBTROBPEK72SX3RH6MXRASB",
-    "GALX2BKIB5WEAQ3BVFYGOZULXYBWZCWU75TLOCZO5XAWDMHKHOFLTZZB",
-    "GALXGRT6S4P4JX6T4JFSFJ3Y7VS4WWPCSJ7WV6KF53D5CPUH5IN4ZXUQ",
-    "GALYHOGBUSHQGNLLZYT2DQZUVJDU4EKURL3DQDTL5NPW3QQFD6EVBCJT",
-    "GALZ3N7DS3NV2W4AIVNXX2ALWLHMGD5SEPJ6IK7VEUA67SRA2XBAITFB",
-    "GALZ4R2SL5KO2RAG5TQDE6QA3ANFWDXQFF6QXZL7ADM4DKCTZJLCIG57",
-    "GALZR4HDO3AJETN22Y5BNXCKVNU2W2LN7DLMSUXCBPVXNHVO5GSFB6RP",
-    "GALZTL7HZP5IBHCUHREZHFJXMG2MCL7XZAE3MMTM26QU6IGGUPEXKYJJ",
-    "GALZVMDNRNIQOPXPCOTXSCSN6XC6F4M2JS42WAHRZPCRLDLJAVVPYKHT",
-    "GAM2CPNXNODNKEGP3ZSON2P5JXUBV7WO2OWRTSKYFJROR2VZLPQON2N6",
-    "GAM2ZX7STXVUTWOHFBZYGNUTGNBPLOZBJ5HKSDUFZKE2OFS5UEEWOWDZ",
-    "GAM35NI6NFX5AUMRWACPJWDBYTEXBDY4QOYNQGXHVK727UV73HWZATUX",
-    "GAM36CCY7XNJQINPC7GGJHMWE77ZXLZBM5BI56H6O4JNAOWDMLXYAQUA",
-    "GAM3AEG2TISV6YA7TXKBPRLY2Z5KF7VECTB4N7CGFXAZZKXKK6VNZOXC",
-    "GAM3PIWWWPMH5IVFKPNGLAKZSA25X5MD6PNORFQ24JXTTCXMV4IDAOOP",
-    "GAM4HXX3M6ED3QXH6CCPHKSKYSBJAEAC6D24OYXI2GMEB73ERPCQZGPZ",
-    "GAM4PFJBACSQXAT3AXNF2FOGQ2YXMT5DEGJ5TH4NYZFM4ZVQ3YWOB5S6",
-    "GAM4SLEJ6LFSYRG7IWS3WO2GJG2QHWO7FSMMS2TYJPX42G4SEQSV4AZJ",
-    "GAM4ZAI3BEVSN6WWZKO2WH33YFDL4J33PFY53B4DYUDYS6SCKDTKXZER",
-    "GAM5E45JMMLMZLU4BTY3PN5TO4GVPBU7MN35OAXJUQMWG7PT544METAL",
-    "GAM5IGCL6UN5HBO3QD3ZJLJ27F5TBTC5G6PDYS5YZ7OWWNZ7TNRNVSDK",
-    "GAM5L55UODSDDQ5WL4HSUHWKAPVETGT3MXQBEMB5JHYLSHCEGI5F2RGX",
-    "GAM5NPMEE7OQVUSAIH2V54UU2IAXHPP2KJFXX3KQXQMCWZLC6T7DF5GL",
-    "GAM5SHA4PFSQX7FCIHVA2QQCUL5OJSFDNJGQHMZC3GVACRXZAY22EYES",
-    "GAM5XJWHGEYXPVJCL3HKWPT6ZWEQRPAU6LJDB2GVVT77Z4ZZVVFS4IFE",
-    "GAM5XVC4G2RUEIBJOR2I7FNS3GVJVAK47OUIJIJSUVUYEFJUKOK7E5NW",
-    "GAM6I2U5PPMNWOLRGEHLSJ3Y6JW2N4R4M5UQUDQ6EWIZ6IUMUFLFFACH",
-    "GAM723CBOJSIDUOYSP7HDKSVJBWYKI6YHLD5242PHMOT6ZH4D7HW73NO",
-    "GAM7I5MM4QRA7ELELIVP3F465JFSEA7MMJBM3JLKDF4NSS7EC4KE6235",
-    "GAM7ILAGEHATD27X7HQVBWIOK6YDIE7IDTBNZD7T4XLIM6UCUJOHIBLB",
-    "GAM7NFWZR7W7RBZTPWB44CXCKJKAVUMAILTXX4AN3JMWYWFU2A5JJRG7",
-    "GAM7V6WKMLG65I2BIDV52OMAENWH26JSV3PK726KOEGUGRFWXZACW56I",
-    "GAM7YHWRCXAZIUUQGEXVSKTUR3IHHBCUPRKYOB5RAVKUZNQLA36YA4BR",
-    "GAMA33LC7FK5RF4XE5HOVX456GX4VHDZUCJ7APY4TZ4IHG65ZWVXD333",
-    "GAMAMSTQH7CSLQJDPEAYT7ZJIFCPBKPWHGQTCBGHELNVTM4HOMUYJBNH",
-    "GAMBEULXIR7K2FESXMJBIRKTIWEAI33COAB3KOFTAIVEVBU5Q5WHAHDF",
-    "GAMBEZU26VY6TK7CWBDE4QDMPMJSCFIQVO6YIJKYLG74CGAAC5GSFFKA",
-    "GAMBFWVDR2AL5PNRW5I36T2UAQZ2DESLCSGJK7QECPDOC2GEAULMZP7E",
-    "GAMBNINN7YQ5CO5AHEHUI3KN6QN5R4WIJE6BBFMEGF23ZKBRKQ4IREKP",
-    "GAMBQ7SRDGSPXVQKIMZIT4K3QP7ZPMZE4I4VAG56C5YPHEFHUV7O4MBG",
-    "GAMBTHH6OLDMGJ6ULVO6CAGPOAGNDM3G7KUHQP4O3F7PB2U3NWZJPME6",
-    "GAMBZ2OU6OI7CNQTD2W6ODWNBCT3ZU7TFROYEC3F2FLOBIFTPQNFND7L",
-    "GAMCDUCAO6LTAP3LJ7L3HOAUBN4MFXYD6HTLZ2PZMCYBDDCN7SMR5WBK",
-    "GAMD4NSIC5KQIFYO3EPZDM7JYLMYABG7AAAVIXCY4LXSRIB5DFFLITWY",
-    "GAMD4UZNDZZV6P2NO2XINVZ47W2OBJ5DVMHEULMI42HMYYAF7CJ36DXA",
-    "GAMDMRY6V5WTQNC3J5NDK4E5GAJZFAC63JMQLHE5EGKDVEEZKVA4XNNY",
-    "GAME5YNKHIPRSMN2BI3HLS3OYD4NE6FQ2WKUUJPYYM6GU4MRDUBPHN6B",
-    "GAME7NF5G4D6ICN6NSUGEGBT6BF6M2ZDOOWR5KL7NILOHDVMH3IMCR7Q",
-    "GAMEB6MCEYDV7BXAR6LVFKSOAPJ5CHYHDECZWWTFIO7R47HWTGBLKP7R",
-    "GAMECWPPSBORYW7TDJIHFEUPPEMSAMI7DE75XLQ246ZG3EQU4P55QQNT",
-    "GAMEDB22Q3KX5HALZELZOMBXC2EAXRT7OJUOUYV3TG6JSSPE2PXGJ7I6",
-    "GAMEDFMNDCMCIIEKOPQHJQ3VC32ZGU43LAJKA2R542PMRTGTODTPCTGX",
-    "GAMEIAJC7WOWKZHDTNQVPEPSQX5PVRERWDEQMAA2D3K3ELLDQXTPNOAG",
-    "GAMEVGX677MZVMLCM5A766ICXP7M45RPOHU3S2RQEQ3T4BTSBT5ZFR3U",
-    "GAMEZFPY4SKD7O6TXF33MCDUBV2UMK3E263XAVA6YRNZJG7EAPBXUUHB",
-    "GAMFMRKW3XMVYVTHL6MFTH5XX2QZCOBXEYVJNMYUJGLBM5OZFT4IQBNB",
-    "GAMFOLB6FIPVC72O2IU6C3J5AOHOZPSPF4ESUNBXQI5I2PMU7S4PZAKH",
-    "GAMGGU2SFKAI2S5FLI4SLFUKNJYWUDMAT7CKDU2D6FBQLN4XWAJGDCWH",
-    "GAMGRUTC2UJ5NFGIYRM56DIA3U7DMARQ4VQ6SFXZROUGWRVFQHMOUURX",
-    "GAMH5Y63DHQGESL4EZP7XIGWXGTCNY6ABG45OIPDWLLJ6PGULEFG5NSA",
-    "GAMHF6FBLEXGZQVEZXJZWTEEJLZ6T5JS3BDMKAUGJDUGIIIJVFOPTTOP",
-    "GAMHSIYLYTQA5VMGS6BWENJTA7OP7WTHQLB6PKVU23PETW5Y52NAI5XA",
-    "GAMI65WPVBOBGHFQMI72TTVEF7LE2FGZ4GRFEA3I6Z5WHJACNMOXPVYK",
-    "GAMIGNY2M2SSIASKLZUBK6BT42FXHICU2WLSYUAEVK7GJRMJJ5ULHWTI",
-    "GAMITJC3O7CMXSZUESZW4VK6LMENILR7V5572EOODIHPPW6U5FZ7PD4J",
-    "GAMJ3FXAO2NYA2ANHUIPTGG3LOPGI5AFCPSVKKVU2RCIXOAVYQAZLDNB",
-    "GAMJAQ6XG3NLTHCNCWRMMYDIKJ5NRURH2ZYJLH3I6I225ABEYNVGRE4D",
-    "GAMJFV2P5SAWFWCMEOFXJNE2EWZ5OR6IWHVB44IA7PQ2O5OU7LB6E5K7",
-    "GAMK6XEXUGT2KM3LSXNSD332S242BF2XIY66WOYSDGTW5HMPT2G7I2DG",
-    "GAMKA3NBS2HQJXGQKPCR45VI25X4ADAYUO6COLKZQLUPKD2J6QQ3NRML",
-    "GAMKH3VVNYBV6TMMDFFMU4XXOQD3OEWJ4OIVMIFB672QDER2YF4QPG7C",
-    "GAMKIQAS7RGBPNSNNR4REDUA3IMLGHETV5HKN72LUUDCFRE7FYY7UIQS",
-    "GAMKYU3HL6X6NKJ37C4ZAWZOR3GIB6KPWY4NYII7L5MTQ6KQ6CE555MJ",
-    "GAML6DRTNDZCMRV3UIJXMZT6QLGLE5RK2HRJFH6TMIUVTDGWINWH5Q77",
-    "GAMLOHOE3MSENS24GJOOOBMYUD5Y7IVD64SVSS57WMGREUG4YFUIFXDC",
-    "GAMLPAZBTMGWNYU2SOZVVANYECWU427B6YWIJEP3DQOCI7LDBQMXSOFT",
-    "GAMMBGZHJIU2L4PSH2KX2SARQ67DY6HKHKTPU47DI5XMSTREKZL3IYVA",
-    "GAMMQHFLNDEBAU4QRRTOLSDS4HRIQORHLDAZZZLQZHLWDCDNIIQ4GPUQ",
-    "GAMMTDRODL2PRSNI4EVTGBHBQ42OHJ4VE4LFDB5GZWCL7AV57SOFLARE",
-    "GAMN2G5LT6XKMTFNKV7RNYNXTTM47XPQM2YBSQQJT5JSCSDIUAUXQIXM",
-    "GAMN3XHMF5Y2CXZ5CJR5APHHE5JAWVB2PX4QKM35NCJXPZPKGTLEAEDF",
-    "GAMN4JI7G65G6XHDUG3SYPWGTKTZ53WLH34SOEOL4WRZBU54CI4W6SG5",
-    "GAMNTPLFSF2X4YHJR6DMQUTA6UHZUAODANQHK7373STPVIW4NCZJFTYQ",
-    "GAMNVPPLA36SEK2MUSM3W7TJKHPK3DIWTJKK47TSJ6ZVIBKX63BKSSAD",
-    "GAMO7O2Y2YP632OZ3OGFIGEPNCIMBIAHFFWWEDMFXZILVYQ53SJK3I7Z",
-    "GAMOIBWDWQ4ZUZH74F3BXE3UCOLISU2ECD4QS4PCFNE7KSZOHLNCK6DY",
-    "GAMONFQ43DYNGN55DKWDDPURRMRK2QOKBGXK7BLVAMEA6STGY2J2ERNE",
-    "GAMOYDJRSMJK3TGXDOKMG55IU5PMHZCWKUEKDPBUGMCLBM3DTBKJXCZE",
-    "GAMPJJJWGJZYPAQTG5PVNXIKPZLUAX3VA6PHNPON2MWVD555X35XMQRR",
-    "GAMPLSFOXSBZ2JKVATKUUVUR2MCWKW4X7FVPVV65BBXF42RMETRYTQMC",
-    "GAMPUGL2BAWQXGBWDURBZZ5AQOXE2J6GLXJCCPTUA4QUCFAVEPUP7NOG",
-    "GAMQGBBYBJNGDOWQXKX2DLGMI675WCBHIW6JPOFNAKTQJ22ASJ3K6LS7",
-    "GAMQJY7NSIXIS7TQPWV2QM7WZVQOKZLUHYD6X6EI5VBPXJN7QFBSINQA",
-    "GAMQXNIL2IV7YV3GIBQG56RCJAGTCW3WU64XDOM2M5N7A3OJWQZT5BNB",
-    "GAMRDSE4YWLEMIYQWMIRCN5RJSH3VVTY7NVUGYZ46P7ZYJ6MTIYQMKMB",
-    "GAMREYQI32GEY5PB2OHW5H2ZZSHJ5MLWQU37PT3P7PA7HAKAMKX32LZK",
-    "GAMRHYVZ7RWTEQ4JLOWVQLA6QVUE362QJYMH5UKYXVFZSPE75P2ANCCC",
-    "GAMRY47WYUFRZ4MLEMD3UEOVJ5MDZJEWVBAKG3IESMCQ75AAVCGERJJO",
-    "GAMSGFZRK4BVNUUA33DJNL7AKTHE3J54GHBNIT5WXM5LE5JIY6BLIHJW",
-    "GAMSRYVAEUI7UBMKT6ANE5RORCIYPWFBF7LEF374AW3ZFEOCIAUUOICI",
-    "GAMT6LX7YQEY37Y2XO3H7KIGI5QLIDSWUZULGWXPBVRD4Q3YYXCKCBC7",
-    "GAMUMWH7LEBQYNUM6H256DTLIWBIH4MAIJ4GVPY567UWFUD4D23W4JCP",
-    "GAMUNMNRK4L42V74IAK3UCO7Q7UEUVSZQIOSAZCY2PEQA2MDJXHAEVGG",
-    "GAMUQA2FERAVZJG72HXG7KXIWIVBZCEQINJDSCZVPQN36KZ7Z2AGTIAN",
-    "GAMVYJZ2M5UEHJYAHM6RPWCQTGAEYTM3WPXO5BAJRSQT2W6X43YLOH4X",
-    "GAMWSXHY2YYXJ5EX2K4ZRFY6OS3QHYRIXRTBJ2DSNF52Z5DXBPAA6IYE",
-    "GAMWW4XEFVA5ZYVOP643CR7MEQEYCUBBJI4FHZE5SU5QZFJ2CH2H6JIM",
-    "GAMX367TSEZLCN3D3DVY3EA5YO4GJTK7YTM6XOO3CZNZZX35CATDTUTN",
-    "GAMXAOXFLZZUVR7LBSJLJPIA4BMB4V4PEM5SE76EKJL3T33QQK4K4KYY",
-    "GAMXD4ZGHTYLGJ7JOIPRJSNZSX3D45CH3S3H5VE3VYON5QVZDPVMPZBI",
-    "GAMXGH5CCRADY5W4WKCFN7SYCSBML7EH3OBOGPFAE73BMCFNB3IHAVVJ",
-    "GAMXOYXMQKUXVIHWDMMLPAQ4VJTDXYK3KNO27JHDXL4PCDM2OX25DYLC",
-    "GAMXPUYCA7KS7FRKDAE24BRTBJTCFWRMHKNWK5OAPA5TPUNZ6UGQNUNA",
-    "GAMY6M2ZS6A4B4ISRZPV4BBQGWDAF7BMH2HZCTVF2TSH3HVTU5OFQYKJ",
-    "GAMYOXHVNV647IVGY67I7EIGP4X4YJDIMH4343L7GIVYQQCIMNMLTPER",
-    "GAMYXIG22JK25KJQVCNGHI4OWHAJ2LZXN3Q7UPREVOND4XADCKFUOWNB",
-    "GAMZ2UUY7WENCGNTNCZMZIWDDVZMYQXDC2UZQ5CF4ELISX6MIEOIAPOF",
-    "GAMZ6MO2VNTF6HMFGBA3B4JLJXAURTGVQHMMWBY66D5DYKU5KXFSMAER",
-    "GAMZBXMWERBKPBNJFVJHZBNIKOVTO6D2MUMTAIDSU5GHT4HAFWHBPYPL",
-    "GAMZL6JXCIOSLFZZ55LPNLVYQFWK2ZZCWA4VYKP3T7K5EPCREIYTESLA",
-    "GAMZWRMORSA7QQQH76TAWNBSJZV5E7XX3W3EFI4ELGJCAVIQR34YOQPM",
-    "GAN2I37VLAO4VYEOXNEWFMGZ44WWGNCEQJSHDNL2POI75KZUTR36ZEGR",
-    "GAN2PTLT4KX5TL4GZJ6YA7PA2J4R4N4LNYXIKCYXGLVR5QVSAVVDEVMY",
-    "GAN2QNUD7G4DH3OCEBBWNIURGJADRXGATYQKUYSSZ2APAUXS2GD42TZF",
-    "GAN2V5OJAPQNSKGHKNJBU6CC746BQJ3BVHTJZE2EYSJDMXYK7YE4CB44",
-    "GAN3ISVQDEDWWOCO6D7V77CFAJGZB4J2DUUVJV76CYDNKTRS5M74Q54V",
-    "GAN4CF2BQ7RPUMXLBQIC5NLY6LQLLB2SR4FWOT46WYBK2LEHSPCQEIRE",
-    "GAN5CKTMGVMPMUC7BVRH7SRXDL6PVZIFUTXT4HP2TD7RQYVGGWIYAXSE",
-    "GAN5JUEDVFHTTJ5XDK6OQBPKXARZIV7KHEU3ULUIMHD2X4NK6XCX7VLC",
-    "GAN6LGGKY2BUUCUNKO3NITN4IERIFESO5CJMBHEEHAWSSM6LAG57PFFF",
-    "GAN6LS3F6UJULQW7DO2OHV4EMLONZGKAMRTCWJ5ZNEEPEHFBD5C2TXKY",
-    "GAN7DTVWZMW2D3YGHSOKHR2WBBSO766RG6NNX7WCFL5CZR4H7KD2ZFSG",
-    "GAN7GVQM6AUUS3X5ZA45W6HFSABZF44TWWFM4AWAIQ5HILQ4XSUQSHIB",
-    "GAN7I2LDYYZZHDXTAQE376VIQSULHOCZ6LMWAN6U5MC6XV32STIRF55R",
-    "GAN7PNHSIFZFFAANTRYUEBVPHV3432KPTUMD5OYVSXXCHX6R36WHEKGI",
-    "GAN7VS452MLOZLT32FROYF55WWWKMFGEKKQX3I6E6PPSTGUTRIQLQYEJ",
-    "GANA6DXLKUMOT6ANCUWEYGUV6GJYDZDUUURCAHRVC3PQUFA4RX6SO3BY",
-    "GANAEN2NQZSL3UIRXC3CKXXDIUEXF4ZIGSYPBTPUDWIW2OXMGDGESU3S",
-    "GANARPBUMXGVUSZTINMHRH5NBQ5KEMHVN2QVYRDTDQ3REUGQCAW7KV23",
-    "GANAU7OBLFLQXXH7KHVUFWP36WVU4W2ZNKMGGKYQPX72W5FT26Q7DFJC",
-    "GANAYGITJJ6IA4D5LXVTVN76TWQVQ5G7EYXTM2BUEAH2HOXAIJJKA4UK",
-    "GANBJT2X5KAZZ3FDHYC535MZBBQG56ALYRAWJ5UPML7TZXA2ICUPZUHZ",
-    "GANBLUYC2PFFEDUPQPVNLKCALHAP6UQBYKPLGZ462ZIHSSUKHPYWZNZC",
-    "GANBWYFXNG52L3BK5ZVAWYPTRWZXPIH33MM32CNLXZ3IFR7GPTUWXSVT",
-    "GANBXKCDINUIJI7UUM3K5EIN5KLGAWKYV5YYICWX5EDPTT6BOJVT6APK",
-    "GANCWHSFS645IKBCHPTQHY636E6TI2CRVP6X6VBBN33I55INKFX6YMKN",
-    "GAND7VQIWQYIWHT2NNDLCMHPBOL6VT2DTTNJEZUYQV25RUDR2S4MWJQT",
-    "GANDK6AKNPJMB2UIYJEHUQXLPA7Q3CUQO5ORMAL7XXCJSFJEY3TVSNCN",
-    "GANDXBBMJFOEMDCEAJOK23657STEF33KP7KEKZXITGUOYM4YUSFDFGN3",
-    "GANFISVJRL47WIJQW3236BBJR43H5GCPXWIG3WUCNDLNXWJ74M44CHIN",
-    "GANFUJHLACOH3FJRNGB4A2H3CK2MLNTC25BHCTIWGQPSQHNGYF6TTBTU",
-    "GANGHLWKP4ZQCMG3DO7KXJKGDLFZN7V65KCZ426YNAQ6K6I3KTJSTRHB",
-    "GANGHWUSDIT26OWOLIULYHBTOINSCKMV53DPPO4GIYZIKWORFWBMNGQ4",
-    "GANH7AKXPDXRCYGIQTTMJYPWR6XB6D5TBAHLDUIBGWT334CBFUDSKR3O",
-    "GANHN4D4VX3KT3SJE2QZ7TOUV3JUCLZBEJ34LL5H6R5VKWB3V7BYPSZ4",
-    "GANHSXCBJYE5S2UXM446V4UXNKBHHZCLNMAX5S3YGOLM6K33SBCRMVUN",
-    "GANI56A7TSEUP77T63JVHXEULFE4JMKWG6LRCO4XCQ5ZIEXKWV5URUNV",
-    "GANIEF7CKKYHA6HDFLD45JFFPZMWRVV4T6SBXGBXKECYW3PPRMPFUHUB",
-    "GANITGVWGPGOPWF2QAV44NH3MUGUOFG5ZP5VUIISAITU4H7RHIUKARAM",
-    "GANJAJHYTF2M4QRL63UKKEORRD2TANOK6KW5OL6TUA24CCOHVZFTHORS",
-    "GANJYS4H2HWZCXKS3LQQTO4C4YK6MPE2ZNCITV7KB4RZNZ4CGZF7MMZW",
-    "GANKA7JHQECA3ASRDLNV5CEKTRNIYQAHAZXDR2632UV6WUJ75GUYLBNV",
-    "GANKGH2NISIUKUDZ7BV3ZNDZ7G7TR53AV4ODZ45WXGGE76ZHJLOYPORT",
-    "GANKQOQWOC4URTPRONU4SFH2NI4UD75XCGBFNUGS5L3JFSZLVZ6ZVEAT",
-    "GANKWINCV3GITQ3A4XDUKBQAN23JZPXTPEH5BCEKCWDVPC4UGH74RJEM",
-    "GANLK2VPHHRRQBKQJUQ6YJQVFUEKPUI2BLITBABTUOTWT32OC4VPSNEW",
-    "GANLPHUJ45FX4SMPRJR5F2HEHISTJM6S55KJAN76Y67Z4D4GZBI77ISO",
-    "GANLVXOAJVOEESG7HC4AQYEOQNUU2M2TMWXP3VMAOVR7W7HS7YYTUA55",
-    "GANLXFZTTV2PPTSCGAFWEOBUU4GMVIVLPZXZR4R5AOP3M4WE5SYVAHVK",
-    "GANMYEXMEMBQQXCNFFDA6TTQSZ47F7KASPO2RO6YXMWNVC2MYDXZRE6N",
-    "GANN44IRP7FD3BWMD4HVXI3ZRWMTTKOSH366CEICPG64C4IYPFT7CNSX",
-    "GANNDEBJ6V73A2Z5HV5FVIL3KKIYW4WIWR255R26YHZUJD724JUA5W75",
-    "GANNEKS2FQ2E6TBKSKQPQUULOMP5I5EODEJO3M5RFYNTF7HOP4YG5QVG",
-    "GANNM344PC5U65GDUC536YKIPCQI62XVZSABQI2YTY73Y2CTT645V456",
-    "GANNNNUA4DS3BUK7VYTDWGCVOEUT2AMHTX7EVROBASVLE7CMIPHFGXXK",
-    "GANNQRIAXV67ZAOI2UDZJACXSNHSI4CKTKQGCGCQ4OMKJABNUMRZRTUA",
-    "GANNWB7G2DO44E53TBIXYZ5Y4DG2GWKRCN3CBTN24LAW2SND75Q75ABT",
-    "GANO4ZB2WHPV5ESW55QXNZTY7SHSKH76ONAQYRNRALB65KKBDKBPV6MA",
-    "GANOKPXQJK2L4RYGQNWZDBHTOI6IY7RGUHT5B7YHHXM7JOEN5F3KNSFS",
-    "GANPV74QDX5HBE24MZV6FR2VL2NRMZVINI3O7OQ7JRLYSMG72X4FJF25",
-    "GANPX3IOYKATRTZM23UQFXWMCD57B6W542UH2EIKX2F7PAT4MULWAQNB",
-    "GANQBKUNN4J3SN53EO2CIUCAFSKIVPEBFXTGEVEA2AS5DSP3IPR6EASG",
-    "GANQP23ZHRZCEV5TYNQIFP6EKWME6B7LU5CFFDXBBACQPE37RONW3F4D",
-    "GANQSL2ADQGG5DYN5SWEYQLUJLX64EPGUSGBHZUU34VWYIZFR3RRZTHX",
-    "GANR6HZRP6JZM3AQVXFU2CRQK7KQXX3UAVZ6G7VMKLKMII23MNHJTFZW",
-    "GANRA4DF7OHGQFWL6UM2OR3RPHF2TP4IDCHMVLOW7MUVOPF3HBRGBANK",
-    "GANRC7ELQDFKJUCLJS7PKJZ7MFUYGAB3JGAJE4WDFKS5M63SC5RZIRON",
-    "GANRIVQ6UF5TBJKNY5WXE7XYTNAMEEONFZ2T2KPCNBZZZK6QQY3N3PTA",
-    "GANRMNX757OV5WJJWOL2KFHWVWPOFCM73OGWBBDXUZR3RUDP3BOUO7EM",
-    "GANRS7CLF5I5FW4GINLTPNC257ZY6TFDNQX2FARFHSGQR5GRMB3J6R5I",
-    "GANRTJKCA6J6TRUEJCOCCMNDEFXVYCT7VYQXZ65RTQIHBR2APOVGOTTI",
-    "GANSXZ6MO7VS6QJUQXSVDFC322XW7NJUINRJGCVXZCJF22FP5HLT3N77",
-    "GANTC7XDZG4CJTVXWPVCH3TJU4VJ2ERBJFLNB24XAT2DMHMLQIXQD4WH",
-    "GANTLD5VNAY3MZBFWCJZTNEWDXO2T2Z7CHBGAGVVWUXGHIWUDZ4AAERK",
-    "GANTNUO7MUGXEDTUS2EBWDASC3EZCZNA6CEAQU7OQDIFMP5OWWQROD5I",
-    "GANTYVWVUISNOK522PH5C26Q43WFVNZPLSWTEM4DACGA6MSYLRLDGVQN",
-    "GANUBAKXZJ3BM2PZMZUSQBO2PIIOA3CM47J4E2SYZKU5R4TDSWGWT7WD",
-    "GANUCLBMAY2AEAU3KPLUUBYLIGLMEY5NNTWKGES26EVFENAHTEYITETH",
-    "GANUHAUGRPA3DJDOMJOEXAGYPEK47DLAKJDYN7YMZPSIOENVQFMGLDFQ",
-    "GANV5UBZ4NQN3PMJRU2R6CSXZJ6CAGQF5AQNG2JYBVIMXEPF25UY6FJ2",
-    "GANV6UUCNQ2XEHHBILZ5ZORBNRD7FVE7WEPT3WNGU6Q3KOUR2KID7NA5",
-    "GANVTLC2AJ3X5GQ4ZD3YGUFGBIT5UDOYSVPZWWOHUG6GMGUJXORCWENM",
-    "GANW6IHF6KVVG6TK6DDWMIPSNOR7OOBJNE2X4Q62OR7M6GRAMOSKQ6KW",
-    "GANWJDQYNXON3QW5TUQUJXVW42CJIU55RAXJD7BXJVCVCFJSXH6M7C2D",
-    "GANXSPEVL5XP6YJ7R2FVVPB7JIGM5VJO6ZO3GRURMNE2ZASCW4FX63O7",
-    "GANY7GGE6TQGFTIJC5N7QH2IDW7XYSKF76CEEVD5D5LTKSY42UCKDZLD",
-    "GANYBP2JQ5MHWFOYFHMPGI4KTM4YL44MFKS4AAJWJQXTJ42ZERBV5HLL",
-    "GANYEAIS5CRQKGZD4MTQRCZYCHLPHFBLM55V2Z2E6ON3WX6BOSPF2F5X",
-    "GANYKEQZDNBF5FMVXHS4PXJHADXDP37UZB2W37CZAOFEECYYQQ2BDC54",
-    "GANYL7PMM7UPE3V4WK5HKRII2D7MCYKFTUZ5ANKEPV6XNP47QMT2WPEN",
-    "GANZ2KTPDF4X7XLE43LNKTFIYURZYSYNKDO4A6JK6REUEEIE7PF7LZBE",
-    "GANZGMX2FWFRDHY6KPQYQ7INS7VMELGC7C537EJ5EQWDWKNQ6T6HV6DY",
-    "GANZMGSS5QXLM2ANKFMF43PI7TRHKEG42FVCZMPPBCZXT427PDZFGWDX",
-    "GANZVQS4BRAF5T73MNBOJVWTIZBVQVODOIQ6ZYBSVHJVV26QIQ4MGDI3",
-    "GANZYRERVFBF3H6AE7ZWSF3NJLERZ445K32N63AUHUT4GFQ3U3YHFNJJ",
-    "GAO2QTJHSCVMVPHJFBUSNQJG5QEUOZMCLSPA6P5CCAIBNQL4BJSWDUOZ",
-    "GAO2UROJVKSDXCGVVRQQDF53CZWKV52AQODFSY6CXEO5QNJRSIBV6HRC",
-    "GAO3DQYC3OC336MLG2DLXQ7LOAK5DUN5WGCIG3KZHN56RFXGJG3LJQ5C",
-    "GAO3EFWBWK6T7UHWRHXBA5WSASD7APA3DZ7EA57JZMFWEU4WU4WB6LDG",
-    "GAO3OPI3CXSIYKSE5NK3JUKSEIT5RFB2BP2YPOBKGHFPT3MXTHRPVV7I",
-    "GAO4DADCRAHA35GD6J3KUNOB5ELZE5D6CGPSJX2WBMEQV7R2M4PGKJL5",
-    "GAO4JVLVBD44XQGKLHWB6EQKUKFG5EGIUYTM44PN6P4UNJUAGHDGU3DX",
-    "GAO4TIEHMTK3QR3C7YGQZGRPR74IBORHAXSTA7GB37CZI6TEQH2UBNJM",
-    "GAO65UU2DVI7XVHQCGFXWI7A2ZUZYV7QMD4ERKEWTLZB5DHXKRNUZFLY",
-    "GAO66JQIWO2VBOJAWVGMPALJGXDFSLSBD73WORZX3CDU2NEBUQRKBLSJ",
-    "GAO6AKGN3XVGX5MJVXRMZIUYO5GZSZUUXQS6YGEGAJ2YYPTNKAWXNA3D",
-    "GAO6I6ZG6SS5MVOI77NWGMUKFHMR2BKJHVVGFU2EOKSFUDKT2VOBMETA",
-    "GAO6IWQ66BHYODWYVRNZAWVXZBUX7APASSFGTUAVV2RI3XRYLXQ5ZDCN",
-    "GAO6XJRTCA3WZSH4XSOXAL6GYPXHTNIUMB2BD72WMJDOLFQRQ2WTKP4B",
-    "GAO7B5QBFTMAVDEHYLGMIDUGQ7HRG6Q5L7YG6OWPJY3S6VYFUK25ATTV",
-    "GAO7CTX6YGSFTSGSBM4CGNT73A6CIMQW5AG3XFH5OT4ELVGW2RFHSW3U",
-    "GAO7QNXKNHS2XKJW46BUA2F3ISY7NCU5EGINKRNNJHJ2UYQJI55NU5NU",
-    "GAOALCPA6MSJKOEPKBCF66SASR2AQHERBOTWVRSFAPR4L4WMVZ4PL5CH",
-    "GAOB7BRNCHRWBC264D74XEG3T542G4KS77NQEKBDT6YAH5QXWLHX77AI",
-    "GAOBJ22MRSYEPNF2VE5DBKMLSXXZG67R2GPBCDB4XKQGEHCXKMCSMFBS",
-    "GAOBUB76US4P7FMOPTBB5JWDNL2OCNYPFWW5WEJYFRMHEWQH7YFATCOX",
-    "GAOC7BTLJVC5OWKHKEY7CHWHKBVXPZLTYVDYFABPMM4A2EM434HECXHE",
-    "GAOCU2FARTE5VSI4THJEY5FTXTUV2MQA2REZFDLVJZKAYTNQTCQISOTO",
-    "GAODLQM7RCLRNFXXRQNEH4W3QDB3LXMSLRKQT2MDL77Q44BAYHPBFGXZ",
-    "GAODT3YK6GUNWCJU4IPV5HEJ3S7OQK5ZEFRCBQQNNP3W4EKBZXWDZKY5",
-    "GAODXASEGWMXQQOPMA3ATJ6FGGJSI5TAX33ODMP3LVAMLTBLL3CD6COJ",
-    "GAODYSNIV4Y7Y32FVSA4V2VUWZNOUN2XFJFJRBZTU24Z4LATX2T2ARMY",
-    "GAOEKTTPMEGKRHKHHJTFBOX7Z3RLXR3BHKN7ZGQTAXW6FCM4EUPWM6ZN",
-    "GAOEUVUE7WDSWFBYIGMAMAP3V2XALDGVKKBNAT4OW6S7C6KVEPJFSPUV",
-    "GAOFMMR7E6RFFPAKR7C6TQO5GRKQVHLLQWDVBL2ZAHQUW2LJ33K5NQTM",
-    "GAOFSP5T5EHPUDRJV6VGIYXAAYUHJIAE2GISTOHSSSLEF3B3QKK4DG24",
-    "GAOG5W4VNUKYIX276YZFLAL5V6NTHALCGNGJWWLBFYOPDFDXESRE3UIG",
-    "GAOGBEEAV7KY6LC52U6USWYXAOUFJZ5OHFFDZCNL234BVXVKQKE6BREB",
-    "GAOHCT2SNRHG5QMOJJSUQZXEWR72J4VGYMIXRE5URP5OVC6AABEZ3AEU",
-    "GAOHYH6RJILTNBVAIOMVUZZMEYI5IPUPYRPBVF4PV2UWSN3NUJ54FUNI",
-    "GAOI655SL5VJ3SNMCHLGIAJRITHHPFP43TQZSSDLPZ3KE4W76X2NGGG7",
-    "GAOIWCDU6VH7O4YFZF4FEPTZTINYNJR7OFWAKVK3LWBV7QIOJTE7R6QK",
-    "GAOJGD6RQ3ZFDSIVSHJZFQWAU2FW6PIX3VCURBWNAPQCUCWALV6L47PU",
-    "GAOJH7U2WAAPLCQITVC6K3KT722BBXKK25GFFHNOGCIZPKPQVWM2ZZXV",
-    "GAOJVUITAHJIFBBKBNY4OFKMQ6UGYRL5NESUUEA3QFLVRVPD7LLGKV5B",
-    "GAOJYG6TWRU7MQFDFYZMURNJP6O4M2XNXNLPXFMNMKJ6WFN4TBDUM7IW",
-    "GAOKF4W3KAXYA6L3HE4EOXC6SM7LWC5FXB6C65IMTTW7I4DOIJILQNTM",
-    "GAOKH6ZYBRYNXN2FQX3W2OK7ZOBSWZ6CVRGHQQN54YRDNU2H5I7PGLU3",
-    "GAOKLTOJDBNOVMUFALMEZQBLCMUXGQAZZQKTT7YT772IL4AFBBCQZNDE",
-    "GAOLDZJ45XFXOJLI7NPONMQFGLQHK4CGJFM7SALJJLZKK3N5TGH7Q2LF",
-    "GAOM26ZLHULJZVGKFS2OKPEKHD64NEEDQZW7SC2KMAPQRIH4JXEHAZUE",
-    "GAOMJSJ6TG53MWPGPF74LMLLSFFZUB2GN7ORFFKOEPLAKQMMM2XHZWM5",
-    "GAOMNC7HXL3PXMJCZYRUW7TFICJOBVQSEWPF55TFNFOIWO32K55ZXCPX",
-    "GAOMZ5EJTGARMT5BJ4UVYDBJVX4AFFAYWHTEIFZXCZUDRCARM3FE2SSM",
-    "GAON6IGVOJKSW2TPZLVMENTZDWPVJSRXYS3RJTQ5P5QIZT7YTQGGAFXA",
-    "GAONBON6HUMYXTGFMFM2FCSIR7IOCPRZCRWJHNWXG7I2XM2MIHSY2FSB",
-    "GAONE4FIAGA4NY4E2IFVBC2CWHVUPW2SOC3QIVBWQWRN4YTSILEIRROF",
-    "GAONMQLPVV7TVESVVU5Y6GZTHULRHYYBJVYR5LKDKQTZZKCHIF7HMN5R",
-    "GAONNHTJMRTK7TCLWE2I4BEY33L3MLWACY4GAYH6CJUH5ZH5EHXOGEHP",
-    "GAONYLWLW47QNKFNXLCJMEKN63OOIR2CKGWZ44R26RKDMFGU6NRDCXLM",
-    "GAOOILZDV2SE3BXZLFGY7TU4J7O6KPYWWD47M5PD62DGJNB3LDE45HNM",
-    "GAOOQWKQQ4KWJKOZBBYFUPQPI462EKZ3UHC5UAGWS3FQURBLRWR7NTZ2",
-    "GAOOX7NR6FVGOSJVY7MGWFLVGTYHEUVZWDZQP7MZTSOKNOYCTYYQM3HC",
-    "GAOPCFOKC7BHC7BCUB3EKWZYRS46ZNVTD5AWL5DPEMY32IM6IPHWFTIR",
-    "GAOPDUK2QO3LSVXKIYAOLL6D43IKFFRR2KDN3J4YQ6NTPOOZUHLOBSTR",
-    "GAOPE7XV7FGSH2IJJY3LH3ELJ7AOQ3HHCPRKZ2JI67LJGHRSXFUKGOLD",
-    "GAOPIRYC3GROESUPFL65UOTHTEOWF6T7KVEH2DWDRMZVSGNKQAVS3WJY",
-    "GAOPKCRVD5FY2GSHS6CNVVRKIYETC4SGHHAFPEHFMNVSRT5VO6ZA34D7",
-    "GAOQB2HHN7UC2HWCPFDAYAUF3KMAP5AR54FBET2A7A2SRMAV56I6GSRD",
-    "GAOQE46WNYYDTIBPZGFR2K7VNGL4ZBYWMS2TXTCE5Z7PQRW4BQDQ3SBI",
-    "GAOQOQSVJZN6QXASUAU3ECLT437UY7JMZUITA4NJTAKGMD262JSY2YTH",
-    "GAOR42H2AU4RSACRNUR5Y3JX7KOKCAVCV37NCXGJKE5HPVLLBNSAVO7N",
-    "GAORMRM4M574KNFCIYA4XM3XVQWWAHAWBD253GKFGZTJML74WLK364WL",
-    "GAORWBJXSQHXHXEA7P3OKTVYFI7JSNH5ZVA4IAN6WRTGUQVP4THO7TVW",
-    "GAOSNQEFJSPLD5QVGABE34GT3YEFXF3O7T5UG6Z5ZJGY4SKXZDTB2SET",
-    "GAOSPG2R3TE7BDWKTHRTESG3QQPFOFJSBFN5335VJMF5MN2XGF4224UZ",
-    "GAOSPZR5X2HUWZHG6MSMRAUPU5UVU6K2O363M5QR2SHJHPMANJ6AU6DZ",
-    "GAOUCB6V6DCM263OLHK5LLLCXCYFZORMFZ2QPAIMX4P6YINTWV6AWLLW",
-    "GAOUOHCMDFEACKCRTG25XYRD4XKOYRRCUEHGWKYKGQ7LY6T56HS6GHWU",
-    "GAOV7STIV6VY7WRAOGMKFA2GGWYZR27LS5DIQMKNR6ZOI7ELP6WCESXM",
-    "GAOVZHDM3VUMPCNTEFYHVGCN6YXCIDW2JYMHFYR2H6AZRIE3E6H4D7WP",
-    "GAOWIEUEQ3AZFVCV6HIKQ35ZTWM74LV74CBKAKEZFSA4SDRL4N5QVPXU",
-    "GAOWSU6742H32S5JWLTTPNQI6DKNSN3EXNCQK7TKQ2OVLPXB7L2N6YBI",
-    "GAOWZWFASPWSFYR3XYLCZ6E3PHATHNBTHM2BCML3YEEPFIW4DNJCMBPD",
-    "GAOXBJNANETDHLEUI7Z3TTE4NW5SEHB7IJHT5LQAWCLFV23UKBN2VLZY",
-    "GAOXNRFSZCGFMDLY366DR45ZST5LTMDAJC73FUJXN5PLJ3TBPGPKZQPJ",
-    "GAOXQ6DCQHILOH6XYB7RRTY2YYYMJNYPV4OG3NAWCAYP4I46FXXGSCIN",
-    "GAOXRRW3URX2GN45W3OOY6ELOGQTX5QODIJXIEN4QUWKEFK7KG5ALZTT",
-    "GAOXXR5Q357X6DQLOM2TZW27LPFGC2VYDF7HK6BN3X7JVWSYZCCYMIJE",
-    "GAOY7U52VS5BNLAQBHXWMIC3OOGUPKISKGMCQTCK3ZUNRU75GHI7NDSC",
-    "GAOYIHFS77CO2DT3JA6ZONGP36SJGL6HKOF6BUWZ37EDJKGPERIEDPA7",
-    "GAOYIITRKBI4RKPCBMSKWFSI7YZP7BGSWMLRFIZ7RTOUZOZGRCDHCGRU",
-    "GAOYMB6NYHAUKP2MKKRNRKASWJZQEF3O77EMSTDDVON4R2YY66AGAGPU",
-    "GAOZO3JEK3N7BUZOIOT757MJDWKG4ZHV2F7YDX3VIAIIGEQIU7D2GGI4",
-    "GAP23YTSKD74CCX7BJGQB4R2WIORYW47Y24PFAPSCGCJIM3DIYUU3IIM",
-    "GAP2H2PQMRSNURKIKO7UDABTO5SJKHOHPER5V2BO7LZHAE65UULIIZCQ",
-    "GAP2OUUD7W53OCFT3REGWUSI2TQ2ITX6XIERR264O66M5N4KV2MLECGF",
-    "GAP374FY5IODICI27R2RASJ6NS2J7DQD73EBEPLPGXJ2NTMS2HSIBTCG",
-    "GAP3BZE3ERMOOD6VXPSNH5CCKT35TZOJTTZJTHAUFRHOVGWWYUMBU2QF",
-    "GAP3EGZCH7HZQ4SX6QAP62JNVL72X2D34GEROA66CCCV2QWXIKB52LWF",
-    "GAP3IDL2TJROEYN45SQCZV2DIZ5XT765JZ5G24NOM3AIQMFZU3YG7SD3",
-    "GAP3MPBONTVIZKMI6QDSJSECUGKS6TEVPO4IKX76OSFRVUGKGB7P3UDF",
-    "GAP3S5W4Y6QEWZDA5ICZRDFXDW2F64QYSYECCXCZN66UP5FSHYVYALJS",
-    "GAP3YEMSLME6KT5UBXWUS6HFO76UVU5GIIAXT4YUN3JOZ7J2SBE74BUG",
-    "GAP4AVCAA63IDYYNJFLW3LUOB2ZSYXNU7ADKDLGSO77XZUNHOUZCLXCG",
-    "GAP4ENOYJN5JWPNZ352CYMTM5ZTX4SMEPQSMXCVJ3ZDEWTJ24LWZF6DW",
-    "GAP4IZ3GTQAWM7LUJDUQDAS5O5PN5BWEA5RNAFUNM54WXMLSMCV6LDP5",
-    "GAP4KURRDHATLXPYRIUZEPULMVDZPYYJXVKS5BYXIQI2UCDK3DZLSBCH",
-    "GAP4SKG4O53IWKCCLBAAQHZU67R6HXRG7KWFUIZAZU6SP4WOLFDXYPGB",
-    "GAP4TZF3S2Q2CWVA2YFVSZCH2RB2FV2AYZWR7L6VMSMZQMCQQXDZO5NX",
-    "GAP555IOXC4N7CI4UZGAJRDMGQEC6ZLH5X6VVLPVFZ5ABO7XR2MZHMKO",
-    "GAP55SINZ5PV57N4PXCRYSAR65I3ZEUQW5KA2PCLSL6Y2VLOCJRSUKDA",
-    "GAP5IEWWNXPRHAKGHXNWPD5434G7NKECSAX5BDDVLUU5CLB7LFUX2EP2",
-    "GAP5TBEDA7U33KOAPXGZEAZKTHE2B4Y4FQL7YBLL2STYYFR5FVWKHQHX",
-    "GAP645UMVMUWLDBJHW6SJTPDZVEQ6QFKUGQ2BA7Q6WNBRDGNFYD4MKPE",
-    "GAP6FTI2LG6NQZOCPRST2QQFOHVJBX35QSXP6L533JDNRFJI74PODM65",
-    "GAP754Z5CMQR2IEDSOY4GDZS25U7PNSWWFARAEBXRQOTDJ2O7VF7UABL",
-    "GAP7EOZED6HJZFKB7AJ6W3N4WFILN4JJDMKHAWDAGXHSI4HAFMOANROM",
-    "GAPAGFMBUPFUG3RK7NHC5PWUUAXOHXNX6GVKQ5F4XEPUETHIIOV4RAST",
-    "GAPAI4IYN7BIH33OUJB2HLF4ZAVNDOZC6376SKWKMLATXV5MFH7DRFDL",
-    "GAPAV3F4TPU6P7RNP5IBLP6I2KEY3NYBZVDSWQYNGK3T3OPKJULXAJ6I",
-    "GAPAWC6R664JJESJDQRP6SRAE2UQVQXG2DISQOATLWXFFU3Y5CUCHBYT",
-    "GAPAYBWA2I3L6YGVNSXNLKLUEXOWOOUBJJPV3JLD32NR4IGR7QP3HP3N",
-    "GAPAZL6GA6UAWNQGJOYLYYKEHNE5SIS5HH2JMURY3XGAH4QRFINMLQSA",
-    "GAPB5XFFTKNMBXWOSVJDCIBB6FU5FOHOJXSJ7327OH7RUIL2PT2NFBAT",
-    "GAPBDUEQPVG3RRH67ZG6HR7J4LP6LHDL6SHVJVNK7L6FRV3FUWZC5BYI",
-    "GAPBMCH2CTL7OTKO4NMBNLED3ZLBU3PEWAK6QSH274OXQCQDL6E6QRAG",
-    "GAPBSAA3NEMPPZKXBLET7LAYBD3JZYPI4JZ4DZUJSYDZBBQSETTINDUS",
-    "GAPBWYNGKSKUMTNZEHEBKSLKNUXLIS4ENMPUXU6VPO5AUW5EHI6X5SZU",
-    "GAPC5GYUYTNILCKORIODFEP6ZWYBMOU7MJQFWC35WMFU2IGHGFMIUMPO",
-    "GAPDKYAJNJRXDP7UTKCRP4IEDZQRQ4XVUEWMEY36KL6Z4ET535UWXLMG",
-    "GAPDKYJFCBZX4OFULKRBDBOK4SN2PEA2L7RQ5LO6ZAD4LPPVUHEWQ4VZ",
-    "GAPEEA4CKBAXEBF7MOESCLKLOKTTFYLZGTMP7LONMJ2EEDQXRHQSFEWE",
-    "GAPETUY7U2ZHXRCXY656DLSMP7NHV7PQDWFS74LH2YFI64LSOFCPEAO7",
-    "GAPF72TBNJLLCEVSGZCAI43LG3U36IUNRCGFFILM3FV2U5DYRZZ4D6HF",
-    "GAPF77BHG4EZOS5Y6274FID6Y5L7QVRRGO3VVVIJ3VSVPA2XQUPQXY4I",
-    "GAPFCEI4F5OKTDEOZTD3K6JZ4J27OK5EITMEG5ZIKYH6755PCP4VASVB",
-    "GAPGJ3KKNRG4BRXL7B2ROMOLJ6QR6STUKT3WTYYFOAM6C3XPFPWY6CL3",
-    "GAPGJQATZ5TDV5T7EDKHX3JMIHUSYT4XFFSM6V2J3AJBZN4ORX4MRETH",
-    "GAPHEDMJBJK466BTOCR2NMLYUQTKZJGMYA4CZAEMIUF6XOURAFO3EP5P",
-    "GAPIDBPOESTUJW5UGKHABRNT7UUDV2HZD3H3RSEHJV5QJCSBBGVHODH4",
-    "GAPIFSXYMYI5CGPG63PQQ73JKQMUOSJBHVKZCINB4YNN52SWA5PK2FKY",
-    "GAPITSOUN2OQVCNBIT5I7TQIB7DMX723AQE5MXBXVB6XFQWXTYGM3WH6",
-    "GAPJA2TT4WA6BFJIZS66AT736CEDOXEMRW7QEMPJJBMMTYAWV7PPGAN5",
-    "GAPJBAMFA657HY2OAIKDZQASMPMUQ3HE4IBEB4PCVOZY6Q3A6NHBAHUR",
-    "GAPJYDHUZ2NTJLXFEYFDULA6TQ2BLE3EGCIHN5RQSS3MSOWYJAHYFXLM",
-    "GAPK6GLL5HJTMIMN4LMLR7GE6B653MMGOLPA643CMRLNUJ45ZELHYQM3",
-    "GAPK6SRZQSCY254TPJHTZKAQOXE5MYN3M63Y5ULSZSBD7VQ3C3FQRMF4",
-    "GAPKVCXZVLO7WRWGZU5CPIC3VXSD6KKUNC6OS2INRIWLQHXZKBVJPUSD",
-    "GAPL4XAVZFTZX53YX3RQC6ETQY4AMLYCE5ICYS73R7VI3B5FLHVENJGW",
-    "GAPLOW3LMQDOYSYMT7BISDVELVUL4ERJM7FTDP3RGDHHKW5PHQ7FRION",
-    "GAPM4IZOYQEARHKKACMMREW47FBMFEAH62EXGNC53BVBAHOFGQ7N44MB",
-    "GAPMYSUVQTXHUPW4QBANZLBYFT22DNU4UHLS3X6X3UPAGEOIAI5O7UAZ",
-    "GAPN2227QEBM53WP22D4UW3R64F65I6A77VM3LR3RESLSLHGSHEAEPD5",
-    "GAPN727AI4QX53E6LQTHEKBKK5PW5W545XVWAN7YHBZT3AZLZOL6L7E4",
-    "GAPNA3HMWUTM4MCB7OIEZ2HI55HL52UMOUP4DMD35IDLMHD4UIYOCFGL",
-    "GAPNJVEAEBVC237BBE7FUMZWQDNCLZJBK3EJOO22DC7P2PQUFLYHMVDB",
-    "GAPNKWSCX5LJRER4L32Z6DOL5Y5AFWLJFM3JROMEY7EPDEZBITK6N5AL",
-    "GAPNMBOTKE7P7B6QWX66N3XZVJNJBYQUDK4IUGFLXHT3HWVWIYKLY6BA",
-    "GAPNN7RV7O2V3H5WGBAIT2CJ5KP7DU7BUQIQFFQZJ25PU4TBFYISUX36",
-    "GAPNQ2QES2S4Q4XHBMRZF7EZUYCSNROUX3FL6C4J7ZHJL47N7GBTPDVS",
-    "GAPNU2EBM3ISAJCWQATOB2XMYOMVU5INSQQGFLV2GLMFNMCVEL4PTOIB",
-    "GAPNU7MVZVCZSGGXGDHJNLQN4W6U4H3YNTMZNVKB7XHSXQSKRLNIKMIB",
-    "GAPOCZTO6DXJBEWMPVACM7C6HIUYAQ6W3V4AIKUBINUDZSWPE5RDVJI2",
-    "GAPOE2IFJIR7CMIEEKSE2Z4WYGGGXZN3D6Z3DHM73NJBMCZ6ZZBXSNDR",
-    "GAPOF5NUYJES67AGZR7JQYIOZK7PG3ICWRCXPSV2CBR3MBO5VYD7SHIB",
-    "GAPOPHMR3HOYBJB3UJCZ2VBVHHPIISDINJTQMADYTZXDHVOGC33WNJ7T",
-    "GAPP3N5HCK5ZQSVG6GYAJ7TMEQ35XCLWJZM6Y6PHAFAMZT4ENQ5IXPMY",
-    "GAPP75E4JES4RTJ7XH3BIVOMH5AJJKQKB7NK62EYAGDJGMXNUILV2NHK",
-    "GAPPA6MODJEKVZC2T55MUPDDLOQ2MZBP4NG7W56NZ5ULI6G65ZGPE3SE",
-    "GAPPKXFMMHH6G7IXNATGSYS26CDR54ZE25H5VP7OUHEFRJYBHJ6XLMW3",
-    "GAPPSD73YWKB63SN3PMQ3FAEJWHX37PBTV3LGKIXZNPDFFYXH2ZO5OAN",
-    "GAPPVKXVMWEBMNPORTVK2S5NPAPTVQSAJJW2Z3L7TTDHWO5AVAX2OOE4",
-    "GAPPYEBTTO5WABPVNMMLP4HKTLG6OPQZJFA3BV3NNXPBAZRDG5RASETH",
-    "GAPQEDYF7KAUEPBWURERGNMSQKZYGNT3L7NJKBQQE6BK47DQHH46ZPR7",
-    "GAPQMSBFRIXG33K3NNDDWJXICZGY3KJ4TRKOL6WJUTTVQ6GJCLDVBSNW",
-    "GAPQPWURTQSGCD723LHB3INNXHMFUBW743OMO4USMYLOTTV56CPACUNE",
-    "GAPQRJLEUKLLOAXSLV2L6L4HFJGX7MP6K4LTRJ4MRYW3TWY2PO6J6AAO",
-    "GAPQV3JNI42QJWVSEYDWX2AV5AKSQ6GKC47FEGPRQ7L5ZHOMPOPV5X4V",
-    "GAPRQYBIGWUP4MERFN22KHZVUDVOVNISR6HDYHG6LRRPLKJZD6LX4BCX",
-    "GAPSMMUCHFXNIKYNY2FMGFJQ5QSRQM25K4XFALLZPMWELXDWF3BDAS4K",
-    "GAPSN4HYTAHB4VIT2JYOFPXQJRKUQSHWELBWEFENXFUULZCVHNRWXNJQ",
-    "GAPSNVHFQZQKTICSNS7GVYAPSXGIMDQDYOWR5BG5JP2LEJWWXXR4QGWO",
-    "GAPTNUVER6KT4MN32U6PUHRCNA4GNKXM32ZRLCQOO3Z6AS3MKZQK2LDE",
-    "GAPTS674BVN2N2GK75543WL5WQICQHFCMA6DSNEMCBOPLJYOYM5YBDBT",
-    "GAPTV4LSAPJKLPFHO6LQGGRQMHQMOKZLM6O6GVBU7FVAK6S6JYV2QTUM",
-    "GAPTZPTCU2L4BSLR4T4KIHILXF54GVG75RVBR5X2GS7UTBOSPA2VXC5Z",
-    "GAPUO7EGSRZ2RFNSE3SG5V2CQCF4J5MZU3AUBQLNQKROR33RIHQQJXAS",
-    "GAPUTKZ3UXYOECEA6ECVRE3QMXSB3AGLHCO3NXKLRMW6GMHZ4F7O7XE6",
-    "GAPUU26Z2FPFYNMB4Q3SLTKS3XCF6ELVSJIKZR4J6YV7UGJP3JJZAUKC",
-    "GAPUYQNX5B657KKIHXBKREJ3IPBJTGSJSX2O4ZXY2VXLZRQYOBDIJOYK",
-    "GAPVELJ45NBXAQ3DJFSPUYD5TNM5SHHKX62LEYMKWMPXQWAPZ7ILJUXT",
-    "GAPVGSXNGS67O6NBX7LNGIPGPEGSPFXENVL5OFOALN7O7ZSRTYBTKM3Y",
-    "GAPVYFPB2UYBLVYE5XGXIA2WNGH6NZNZ2L5O6JZBACTBCVHHRZA6EE7P",
-    "GAPW5Y453CWJHLGW6TPDN5HCC32Y7JENYPREGB6RFN4UAAU7N2OF7LTC",
-    "GAPWDI2XBFBOA5P3NH4XJWIAHLSLYWTCWXIPQ3ZCLKKQJ6VHKMPDWCFY",
-    "GAPWHX3ARBYZY5CKCTLKWSYXOYTDDFU5YEUQGEG7NUDNFXAHTOX3AURM",
-    "GAPXHU2RCCPCANEDAW2ASTY6YLO7V3EB7QDZJ4MLB7QGCOR3OG7BHZAL",
-    "GAPXRUOUENORAYUDRINMLJCQBUJM4SNCLHHPNNMWEKVHUKSPIHG2PALM",
-    "GAPXVORSL2BVBBOQ3COZHFPI2D2UYSVWMXMT5KOP3X5HYFIVBEWKFVWV",
-    "GAPYBFXINIOU4YHSADGPX2TW5MBXTYK5R544ZTICLSHHPGG3C35ZOGKP",
-    "GAPYXZO6NTNHJUJ3H4M6M6HPUGDZ4N5YO522DICOAN4IP5S2DEXSEJLI",
-    "GAPYYRTGVLXYNXDOXU66BWVVSIVO2H5M6COBVLKUKCUEN6YH3NQEVHBW",
-    "GAPZ6LMZ5KKXFVS2P5JZ4SDDF7NGQ7NZR7Y25R2RP5XXPPZLFFCVI4C7",
-    "GAPZMT3TC644ICEWBVBRZXNDHKXDADS67Q2OPOP3IBAGSMYBPD2UDTSD",
-    "GAPZPCSZREVLYWDM4QSZBBSARYCREIU2FZUJ3IR4IMO6IWP2Z2VDTTPB",
-    "GAQ2CHJQA5DJ3EP5DJRST2ZDLWXEVRQMXJOTQYJLNKTL44BS7BYDSMOQ",
-    "GAQ2JNDPNDCJCXKLZXFOVHNQHLXH47O2JP2MU2E3SDTTJJACRMIO35OO",
-    "GAQ3J3NQYQVLUEVZBGDMVRPZD2HU2I5KEHH6RMSMIPH2LINV24BG34AS",
-    "GAQ3JKOZEE5OOR33ACHKFLZMQRWA72P7R2XOHCXUAJHXWQQPLWO5QUSA",
-    "GAQ3LZWX3IFGDCXZXRCDVNBI2HU2OFHLQFU5GDXAF3GOCG7HHF65SLVR",
-    "GAQ3SRTKNJKQTYP4FRSEW7J32NCTAYVGDS4APTLZPEYZZ2BUFRZABSND",
-    "GAQ4GR2J64RZTFTIVUBJKN34R5QFU7RCNZ75SY442NK2PQVO7VOXDRSN",
-    "GAQ4LYU2X4CIPSETPG3EFL42K4DZLB7GBPI2TCXB4L7MZ2AJXWGN26R3",
-    "GAQ4Z6V66DGAYHOJLEBP55KSUVOLVJYFVQ2SLH4G4KYADF7BNMTAVYLX",
-    "GAQ52XHF3K45XR6IMZI67JRSBGYJ77STC2C5FP7R2JZFG32SX6VKX3UG",
-    "GAQ5P6TEAXONSL556L7N7GRIKFAG3LTI43BITJGS32JQCB7IZ4BH7PZU",
-    "GAQ63WDQDWGPWTANMLNCX3XUYAI36ARPAMFZDI5JGYJK22BLFYBIOTLD",
-    "GAQ64ECPW6MYY3W33RKDN7WRYUONMSINAQINI4T46N6OU2FEJJQ3P73J",
-    "GAQ66S2X26DCSSZK6QMEPMWSA2B44AQ6UX36JSW6WORUL7QLH2EJYOWW",
-    "GAQ6GWK3WWKW67WQTBC7Q5J53XPIIKHTFKSZMXA6ITJA3TAHB2VNYNYE",
-    "GAQ6JF4VBZRE5W5O5V3MTSBRCANTUMHHHH7MCMUJMDUSP7XH3SJH7ARS",
-    "GAQ6OVRZVQWK3YCWGYAWXKVMRGA35SITFCQSP344FN7PG7OOVHNH3BUT",
-    "GAQ6PPV6R656KKQPYCW3ZYGIZWWVL2Q2E4UB557WQWWBDWQHZZ6HAGPA",
-    "GAQ6QVQYA5MMD357WC7MXP4CV7HY7VVZGEUYJYINAA3S27MBWD3E23EQ",
-    "GAQ6Z3GQNBBST4E7ED6GKB5ZE4V2XAFLUC2GDBFKNKRNZ6XVMXYLLTFD",
-    "GAQ7NCIXBHJBCEXMK4UHEMRSZ2FQE2XQUJJZTHZK2SCDLB2QKMA2WDND",
-    "GAQA37YU3S6RB2A2BG4JSYN6IVSUNHVGVDRFF2PQOU7FZVESBYD3JOWF",
-    "GAQA7MT64C4AFCOG4ZB3ILZCMGF24Y4WYMCO2ZBNTR77PH7B5JEFCV22",
-    "GAQAMKLONTYK4APCRIVLRZTFHPDDP2XIEJBO42YMTJEDIMWSDP7TITAU",
-    "GAQAT7TTPZI4GRE6E6WEV6CF7WIEN2UADEGD6BWVZNDVPFQPUHS2PBAI",
-    "GAQBQ5VOGA663GLTCQ3E4YMJJB4SY6VME6ZCE2UP34GKCLYUDT5I64FW",
-    "GAQCETRYXC7E7PZP7ZREB4WJE4TNDNK5GFHL3RXTJ3IUGHV2G2OUEFEW",
-    "GAQCKUIDG4NFXGYGVIEQ57SWRLYVHCR7ALNM2DS5GVNI2X5ZJLDA4BTA",
-    "GAQDETSAYA3TFBI3FQOUETYMJITECX7LW5ZIZPAC45WJ7MU4MZSQ7E75",
-    "GAQDHFFHPZPZZUPRVICSLOYZXYPS3ILZVLHE3MT3XSBLLMM7ATANPLRQ",
-    "GAQDW7GKBT33CRIBXJFIXQZFF5ENLXNPCXFPUJL6GWQ4CJAJV3JD7MAW",
-    "GAQDZDNLMHPNIGNDPNXJ5UW2FKB37BX33YMKMPXP373RRRLCYWA76PP7",
-    "GAQEMLG5Q4YEGUSZZBSV3QDAIMOKQAWQTOA4PVP7OIFGMXKPNNDQDOGE",
-    "GAQEQUSQJSRXFYF3MYKX4JRTV2OFZMN4FSZEECF6D7KCXFADWKEARF5X",
-    "GAQFSAW5NR5R4YVINHUWPW3KVPEZFCRB7KL5FBPVCGJLXUWSIBCGCLSL",
-    "GAQGAOZZVXHZG5EZRPSGJ4OAO7KRL4TNLB5M2ZPQGM44ZQ5RY4LTDRFL",
-    "GAQGCS4WZTUVGBBKVCFDYQYIFY7WVWMGGKSAUNJMRGNW6WFRDFNMXWXI",
-    "GAQHB6EXOYHQPZCIMT53VUQX24IS6DP6LAHEDGJTY6MIKJ5TNGPNHYU4",
-    "GAQHDYPV7Y7F2RG2OQSLPAP2XQHB5B6GSHZYAWW3PFZIPM6SHTSORVAZ",
-    "GAQHNPPAGNEVC3H42WUZD6ZACMYUR6JQTRNTOSU6DHVWFRGP3YYZFDPB",
-    "GAQINPG4Q6B7OIWKQFHU7VXOSKBURBM3UMEZ4K4PTEN72ASAB5SJUZ3A",
-    "GAQIRBPAJ57N6PF254BLHEJR3QU7HZ7XDSLAZHW7WXQHHWUSLEPYEX46",
-    "GAQJA2AG5J7PIM5EPSNUEPK57BJHWRKYQQJMVR7Y6B5KPWIJ5DKKNDYL",
-    "GAQJHU3D44QKHWAKZZXTXY2IR4W6VNIEVAMB7C7C6EFZJFUJTKCSQRWY",
-    "GAQJLF3B7T4LDIEXPUPH32HJFRZGDXSTRFNX2WGLLXMWH7OQEPTN5NLI",
-    "GAQJME46VBNPXQ6ESNG2AEL5XUA3ZNUJSFYBV4QHMB5AEMDY52X6D42G",
-    "GAQJXVQ3QJQR7ROC324VOUFW5OIN5BUE7LUBG2KYSEMJWVH2DRELFJCG",
-    "GAQJYYNUWFMW7PPKJIAMSEBZYS34FXIUBFC46XMHYBXSXFXQMUWO5SAW",
-    "GAQKQBKR6VXT5QURUI5X5UFBD43GWIQOD3SYMAFFUFSUSPTASOH2K5NA",
-    "GAQM26YBTLTZQ2JI6NAUMKSSKUWGY5GYPBJNMRFHGM3BM63WN4NDZ3FO",
-    "GAQMENMBOXU6J2YTOLOFRSBTYWQ55QOCNI4IMOVQ35EJATWV6XPBN7P4",
-    "GAQMVNKLLU63WQ7WDR7NJ5EKP6CDBRIXNXBIDWPNDFKQI2PNAQLTREGD",
-    "GAQN4UWGKZ4PEJ53LFEGAVG2YNW7VXMX4M7YCXN543ZQW7L5L6G5PORT",
-    "GAQO4CWW4UVGIMRP6GBEAPUACNOUJC27SISDRK5BQGFFEILZM5O7LOR5",
-    "GAQO5LTIGNWWNPSK4OII763XCZOMVM2U2T5YINGVOQQT4DM6VIPSPXJI",
-    "GAQOEJR7GP7ZLQDYPHLG4K5RKUJNB2PGGRKVVT5MNJI2JDUTAAVNWYI2",
-    "GAQOEUZVNVRYNMZDBAHEKC4VYREZKGMSNOGAOZNVG7GAVVA4AMO367CX",
-    "GAQOKFDWZUDXJSIXBAQB3Z6OENPUMLTLFPLK75IZHFULLBRBB7O5CTB5",
-    "GAQOUZFYZ4HRWOCNT5EW7KFUHHR4ECYDAFTVVPCRI5O4DANPHGA2PTTH",
-    "GAQP5LZNDOKKJMH72CH7HL2LACKGGTNM2RD6FC4BI6JSYNOOP3GVOSKY",
-    "GAQP73MWGSZLXQBGZHIVCKCZJTHZEHC34RG4ZPGUHQ52GXS4EAB5NS5R",
-    "GAQPBLN2F36MHTP3YLUO52IFQBNNGXUHJUCQ5DBHGVGDRROVIEQASSET",
-    "GAQPMSQ7PVHCLKQDQZXC55CAM2HXFU7AH2UVGM3533GX6T6TNKA6KSSW",
-    "GAQPUZYRQ5ZRKLZKZLBKU5QM2D5WT27JJMSH4Z5ONMXTKF56J64RP3KS",
-    "GAQQGXWB3DASCXBWX5XO4PZ6DFF3QDVXVN4UR7VUIJZ55PT6PKW33OVC",
-    "GAQQI3XKMWS2V2IUBFMGOHPWY5LIN6ND67JEDB7I5HSY63ARMKSJXKDY",
-    "GAQQRQLA6M2TCB77IAHVCTQIVTPDAHUD7UPITXENS2WDTZR3IO3QYWFN",
-    "GAQR3RFDAUHIDPZ3ZKUFYWSBGPYUV52JXDLPHJJSJSV7NTCSDAMQP2ZQ",
-    "GAQRBJCL4GKUCGKCWXJKCCA2IWWEZRLYG2QBZ2HNZRONWMO7CZBLO5NX",
-    "GAQRBJRIW5466MCLVAJZ6IV2V7772MW5W4CAYTQWQ5SHIMJI5H7G2XSC",
-    "GAQRKDBP2E7VMPEBE3BVVW4QUWGJ5MLBETETNXPYUMCCFN3SA5V3J2MS",
-    "GAQSJCRLVZNSMQCIUW2XGACVZPVHFXIDGJVXFC7XWPVNDCTLXZANHWAA",
-    "GAQSTSEOZMYJKGZ76IS3BFKGQJPS77INASUWNYAN7EPUXMURLNQESZI4",
-    "GAQT227CXEYLP72LR7TROA7XCBZRNT7BWKD7VO2WGVPOA4IALNNVHBAR",
-    "GAQTDFYKSIQNSA5KJMB4F3U3PBF2DMBU5ZD3W533BI7CD4RV3ZMDDDEX",
-    "GAQUHVTMPZ422MU77BE5JRWRZCGA2HAQLFZB3TPG54EK7UYDAHFN6XGC",
-    "GAQULLXY67YSSDJMI557YS742LBYE3SJKHNZLJCHYMD4WTO2J7V4USDS",
-    "GAQWFWOXHCPV3YJI3GOX4JI3AU74X7K3B5ROM3ZCNZICTYZELUUW3PQD",
-    "GAQWIHV3GXCIVMXEV5JYDIUW3LOHTNXGENFQEYQZDD6TFJ3NF4SGUWNY",
-    "GAQWNIWF4ENHBYRNOWD3GP4NMDZOZXUXA3TFWTXTL5JFJCXXWVLWYATV",
-    "GAQWU2PKW4SCYBAV2RXFFKDG5MJ7IP2RGL2HI36V4HE7NOSBFZBYQTXU",
-    "GAQXD5SGPU4D6XWVBJZIMRQ2CXTDWWAJBUKTA2ICNY53A7BCYZNUO3TF",
-    "GAQXNRTDOCRZVR6673GAOC7SPKOJ3CLBN3PLFMS7HSRJQFFH3C5SHARE",
-    "GAQXR3J3INPYJUKZUGXEJLEZHLOWYRON3M6MPUVXROVY2CBTYNRHRTR6",
-    "GAQXWOSPJ5QN3P6EAWEXBDPHPYBUTO77Q2UBRHHGCJV45DBJZ6N7BFWI",
-    "GAQY7ADXI5BSYZEGS6QDFKMMCL7Y4FMZJIQOBFGCB7CGDPEPRUXCNQMX",
-    "GAQYHL4YXWNIUFP7WG45C2UIFFR6AT7D5HGDQLXLTHIIIZJF7GXFUAL4",
-    "GAQYMNJ27TWX6FDGSFXR5X547N77A2MSFE37ZRK2Z5IHJWFBATUINDUS",
-    "GAQYSC5O63AZUXAQFFVVP2YFDQQHL7KR6TXGZB7K5QRXJ64RT4MY4EMC",
-    "GAQZASCNCUAFTCCTNBRN5WJFRZKREVSSBDNHPZSSBE4XQY65LV6QXJ2M",
-    "GAQZE2YMA35RWGB6WZZOQDQGX3OGI6PEDOT4JRZPRDZFZ6H3HR24VWW6",
-    "GAQZH4VQCDYV5PPVCHJYCL46KDONHMYSISXRPFX6AZJ3QR44R2FJWHIP",
-    "GAQZTSKA5BIDXYXQXYIMGRFANERKPPC6ZVIOY33VSOODO2NH6SJ4TZ7N",
-    "GAQZVKLS3U6LBPDEMMIIFSDIFZTXSHXIAATF3WHCNP526EBCQRXY445M",
-    "GAR2TTZP2NZDT3BJEU4DWECZIPPZ6A3HKVJ3PT2IWYNMBWOL5Y7JZZGE",
-    "GAR2W6UAQ3ZQK5WIJ5PZIFDZWXKL2R24RWKQJY3MDXRXM76K5BHMBFAI",
-    "GAR37WDX5WE5QLA3TWD4EJVNEGJJW7VTYGSWQ2V54QFBKAOL6TG3NJCC",
-    "GAR3LBXB4M6P4KIWVRVNLPSDU476XZWX5L2EN6643ELA4RI5VAPI2KX4",
-    "GAR44ZUH2TEA7JVRB7LAUWJKXEOC3PI3DSGVH6VLIOXWAIT2XITKEM6D",
-    "GAR4ALLO5OFJTISKFK66IR6OOHOVMMELOZJRS4FD6IZB7RKRAYO7QQNT",
-    "GAR4JNPBIQRDZTNCFN2BM7BKFLCLM2L525MGAW6ZAUX4KWPSC2COJIL3",
-    "GAR4W4NNCXJR3LFSJAZPVQHPWTSQKVIQXAYBBL47WJFGBPL7XXNGZ2J7",
-    "GAR4X4YJGXWR3SYAMBUFCVAXCPM55T7JLE6FXPR2UAPRVMFPLVNPU464",
-    "GAR4XN4ARTXWGHVCIIXTLTZ43EGOX2D5TS7GQNVZ5Z65LPBWL6VXW2DM",
-    "GAR5F72DMQ5P76VZ42WROHOCMFAPONIP5N4KB3DOOUZU7SDLUWAZL4Z2",
-    "GAR5I3OJGZUGGJ5ARQ2OHDOR5AZUHYKN4JEBQMGDWBC3EB6TVH6YJWPX",
-    "GAR5S3LKXYIHKKW2RVGO3WI3NV4LEGPMHFZCH3NRZOYJ7IRD2ZQOAFJ7",
-    "GAR5VU7MP52T4KZ7JE2TRDZFX5KX65ZG26XY2ZAMT33WTIONN4HSGZBJ",
-    "GAR64YUGX36CJJQZP77GHHFFKQNCK37B7MRGOZ4B5JJP3UOH3HK4XAHV",
-    "GAR66PLDM4L2G23F63HTEXK2UNTVEMJIOBD6ZMFEV7JLFDBSRHPEMSR3",
-    "GAR6SMKC4WFS3T4TVGT77FVWKKPFGE75FXXCAXSAINC4EUGQZTQNO7RI",
-    "GAR6VEYER5NZ37R3XXIHWCFLTOFH3SWHWIZYBFVE2F35UHAVGLG3PEPE",
-    "GAR6XKGNBZZVLT7V2XOILXPRYRBOV2BY2SPUPPH7SW4JXQAAE6SSHXFF",
-    "GAR742IRE5MQHF4N43D4NFRP34JDJNZLXYIMR3WDARKK4OGIOVRRMFRV",
-    "GAR7KSGTDLOZZYYMKKQLOQYGVMLMKUS47KRA4ETJCQVHHDARUJW5FX3V",
-    "GARAKVRMUJNKY5GQTJ7M4EEAW2DSBUTHTTLL6UKFRUXKHROS3OBT7JBM",
-    "GARAUG356LBYESKGSPBJNUNTV4XGFWFHLKIL33VJZLPSXD6HRYNKQFDU",
-    "GARAUJYODAM77C3XAQVBUXHKWESPYDBLJAUJMSADL6OOQGM2QL7ENEX4",
-    "GARAVAA4B373JSRQA3VKPL2KMGNO6GUCYS2CVFPRD2PJJ2N36ONAP5UI",
-    "GARAYP6NF3VD5QBFUIXDOEBZIC6AMOU36TVXRLKZGUSIKRCLL6E4STOK",
-    "GARAZC6F6DXEFI56ACLS6P7OLCCDSXGU5X4Y2SQFRDQS5F4G7E5DUOWP",
-    "GARBHZXJLH2RGP4K6SXF3XBDUT5ZMSPFI5PFDAPTOE4E4USYSVR7PECH",
-    "GARBQ5H2VCZPFAAIVZOG7G7RYBCLSDGSZDDUB5XN3AJ5KZSEXF5CACPQ",
-    "GARCATSIA2AEOG4IPHDPOTGSLF2PUCK3E6IPQ5LGDDN5YMIL4DMETTBG",
-    "GARCFSX7ZLPM22JXYSYLWA3VY5DLJBFZMXA3U4F6G4FRKBQYKRYE6MGZ",
-    "GARCSSWX2FDOEEUVWUQ7MORLIHAFFM5AYLXLVAHZJZKAJIPNIBVD4XZL",
-    "GARD2J3NZ6NQOLKF7RGX5WXHAXMVKVPUHHAN4H5SCBEFSXBUXJA55WEH",
-    "GAREELUB43IRHWEASCFBLKHURCGMHE5IF6XSE7EXDLACYHGRHM43RFOX",
-    "GAREEUJXZBYWLYHBVLL4F7WUOX43TWSUDSJZH2QCBVRREXICPJTG7SHW",
-    "GARF3WPIU7XP5XUGZLLXVYSOXY5I4V2OY7EKDOSAWCNOFO7EK4ODZFRX",
-    "GARG3SZGTALX24CHOOHQWT7GXIAYVO6K3U2ZAYJUX64MWF2R7S2AEBTC",
-    "GARGC5YUWMCRFKGPOMUC2UROG5ZHYX4NQUAQKYBVMI6OVDKF2OKGEVRU",
-    "GARGJL2GTTPAJZCNPOPCMRMKSMD34HUDSQSYNH7AKUTLJPFRE3BG25E4",
-    "GARHMUE6DSCODQW44RCI6T7OEXDAWAOFSDAWK5RGJGMK5KRD4EA7CIRS",
-    "GARHRCIEFKLHFFUY725BXQTKNIHZTJ2CNWP42UQOIQME5NCTDOUXMTUP",
-    "GARIKTU2OHUASWBBYXUD7D64H4WSLTHRXQ2LR7NGUBUVVVA5TLG62Q37",
-    "GARIZG5VCXSHQT5KDJ3HU2QRRTZQ37DIZ3K7LPTJTHLWHDWMZZTLV4FZ",
-    "GARJB6NPCJBKHIA5EEEN6AGZ6L43VVLTS4SOHTDUVBNO34XCFXWYMWBS",
-    "GARJCEBOEEJOVFXKBPKKM7JMZ4D4WFJYW2IZIIM7CBGM2UEU6LPE3SKC",
-    "GARJCGL4HITNWN6R375KXQQCCZMG5IUDJERVRGCTSDQINWXYSWLKRGB7",
-    "GARJUTC3VQN7GGIKDWG7PYHFMCODPLCNTERHSYEC5P2R77JMUMYMQKUJ",
-    "GARK3MKOU7J6QOUNFBMEAMWD3GMAFS5RQ6MQFYYY4VFM7CMAOITDIF42",
-    "GARKVQKMVRAYPBYDFBRDEFZ3GAIR7D5P4ZERYA6XUCOMB4D5DDET4OMR",
-    "GARL4JOY35HUP2GIQCVPBEQI7FRZPUYZPXPWAPTJKPBQ5LN25IV6A6ME",
-    "GARL6D3ZZSLPWGXLTMP2JGVN4BIUYEI6CQ4S3NCXCI6APOO2X7VHYSOW",
-    "GARL7OD3VOGDOUFYCGDWNONN4EFAFUVDMA6ROJZJDOPHWFUZUWHHFICP",
-    "GARLDNIULHTTPL7AQV6EMOEQLEFKSLNJRD4Z2ZCK73KNZCJHNHGJCYLL",
-    "GARM6W2NORCSS3PTGVKGCW4PN2R55RJUG2ECCKI3GDOICWE5SVRDGUFQ",
-    "GARMKMEPZ5J3GDXHA3VGWVKHYCGZGOQ6BTRGWHYLP2OO3HQ7KN4MIOTL",
-    "GARMQQ6APXWGIIUA7KJUQBL6PG2EXK2HUERXLD53UUZA3TJK4FZRCFTC",
-    "GARMYPXMW7J25VGWZ2QZGDYZVI4EKOFACRKIQFN52JVUKOXHODYD5OSC",
-    "GARN2JFF4SWWCQSQHAHSY2OS2HCUDH3XIJ2I55P6K2TKE5YO6YDX56ER",
-    "GARNCW4PZM5BM6POJO6XDQCW5GO246VBAJJ6C35SQ3IP2QMHE4SLSNDR",
-    "GARNXINUUR2CZK52CXTJUUYAD4VKAW2QQH3VYCAZVW4FN237L2FWRSPR",
-    "GARONC4TNIBEJSAVXTDLGQORISUAPYIATWVXEUVMDRHHLGKNHQEK7S3B",
-    "GARP5HD27KOJ6U7GLVFVL3GTVJBIZRIP2SXM35UINLXQHZBBFUYNSILV",
-    "GARP7NW645WSMAWMB4UHLOWGMEHJTVWPBYMPFLIXX3B2AS3JSTMRUUBP",
-    "GARPL3TRNQ4LIWEP4OFRLS7E24W5WGGBAHYYZAVPLEWSMI3VGORBT63A",
-    "GARPZJPMN3VUY74EFBHWH2N3S2L6D57PIWMOWHSIY5QOQF6CGGNJD6UD",
-    "GARQ3KFAOREB77AWBHUXRL6C66573UZ2RPKAFQU2VJM2UIXCUMEJAZMY",
-    "GARQKLPHRBWZP2ORKJYUIRGCNIISQQK6MEB5SNGEQEGIYPZ3J4CDFFPV",
-    "GARQN3DUZ27MYYVPBAQGIURCCVHDEAWJC4DMSDRQQ7HOJJQXF575KI2M",
-    "GARRGDVV2OAVZEHHV4SGR7UESCMUHTHCEIFUGLJCI7EN22TPIRT4KLJZ",
-    "GARRQ4EX6XMTZORDAIAA5LJQ7SHAWOAI7QFW5N4R2IGEA4NTRLOHLMTS",
-    "GARRVPAWWBFIF6XJGAYQA4QMNRUZUVAYYINCMRTGWAW3DRBIMDFJVHCY",
-    "GARRXTWAKMXNE2MN54XJPZBLNKO3WGBAB4KVDN3WOJTPBGQYVIETJND3",
-    "GART2MTOJL3VKR2LJJC2NXAPMGV3NS6H5BF26MGDL4J5XZSYEI2AVPWF",
-    "GARTDXVABAT6SG6M7NYAECK5U4BM23HN23BYBWNGSVRUFBAFHBVKI2YI",
-    "GARTJOHSJKQWE3V6NVP2OK3QWQRHKZLH5USKKONE47GNOVL6LUILX3GJ",
-    "GARU4AC5FZAF7FUH2CGBYWXFJ3CTYUB6EY4LECTSR3GNRZIEQYFDJOJF",
-    "GARUH2ZN7GEN5ME2Z654S45NMYNYZ6LMGWDKDEHW4KLNQYJBUJSZGZIK",
-    "GARUZR3YJCEJZZG7BQBYKZEVZ7INKCUT5BMDOVNDS6K6RD5BGTFJLPMQ",
-    "GARWDX26HEY6QY75XCKZAP4YWAG7XUZI6VIBNRL65DYPHGONOVOQQEPZ",
-    "GARWG5MF2DNJBVREADKMZSRZRZV77CAOIBYUPHKHIQRDVJ5H3VSCJQGO",
-    "GARWV5DGWMMAKVF5J2KIYHFGSC4KO6CYLH2GRTMLY55UC5B5TMJ7TJEX",
-    "GARX7SHTENCO7TTENPBBREKB44RNQRNPETAQP2IWIXDUN576BPOFICQO",
-    "GARXFP7KLAZPM3KVNUYGHV2NM6QLTVESJ2JKFNT7PYQLN4A5GHFXF3XO",
-    "GARXJENNL7JA7O6DD26T7YQGRXYZY7XERBGUGTXPFXZXB2QQENYHYAIQ",
-    "GARXY7MPCE473QML6YNG465YBBDGHJD5OZNZU3WLELUBRFWXBJXC5HOX",
-    "GARY4SM6CIKJOCJSXX4ZRVSE6SEEKN2JCZVTH3CYKHDVJIWPBM5LHES2",
-    "GARYCOO64MLTKTXJ65SHK4OAZD7TUGUVBBD3KY4MQNXMICCN6C5L3QFS",
-    "GARYDJCRWH2TUU443ULSPDOTDAFQFUWUSIOVCDY6VAC4NOMUFD6XQGVE",
-    "GARYKWNZA4I56KHU33MVEJMALTPNM6DQ5ZWU3SF6WPRCW6BH2GBUUK7K",
-    "GARYVIIJNJWD4ZLAHT35B3OFANCSQW5PENFQRY6FVLRGHQJWKA3SAH4M",
-    "GARZ22UJLTOIURP3CCEJWX2ZGREHFGHWEHV3HH7WSBIPQG4NDICUKXEM",
-    "GARZA75GJY7KK5PLQAU5SMSSX436MOGP3ANHWO4A2O33Z6IYQQGGKT4P",
-    "GARZB5J6G32DHS5KEWFUGHOSNR3TQ3BGRV6JBRJ7Q35GA74FAZLCH7S2",
-    "GARZO6NWQYTT3264FCN5NSSDG3SJM2765I7Z6SMCTPFO6NWYWDYI76GB",
-    "GAS2AD5M5N5C3DLV2HD4257RVFZETES2JMK5PVV5UOUSUVVN62QQSAOG",
-    "GAS3L6IUKF3SOEWYPIH5KAQYBSB2KNDYX6S2WF7ZB42J2TPMHR6G4BTC",
-    "GAS45NM36DCBWBLMICSWCJOQX6ZQNXWWM7BVUGNOSDSUIFLBPDTP6EWH",
-    "GAS4LWKJAK4IXQXYG5NEFO7KW4XPPDLEFZZ2KHGVVZ3C6JJ577ACOOSI",
-    "GAS4M7IA7VQQ24A2MSHQOVTCCKIW5K223PHHD5AXALZPF2VIQK4WGHTV",
-    "GAS4S7RYUJIAG6WIOGJBMO5W7DOF5SR65Z6NNZW66OGIZKOZES7NWLN2",
-    "GAS4ZCT7INZ3RS6W3NJW7IJXF42P6DBXZETWBF6EBJRBJL4Z4DOSTOCK",
-    "GAS5K5HG42A73DWRH45ISQOG3CJY5QK7ETUSE2L6WB6W3WZ7B56XTG7T",
-    "GAS5RMGIZXICQGXT5HIQ4CS6Y4QT2P5B2I2WWMWCNLZJ7QJ2YBACNSAP",
-    "GAS66K2AUG24PNMLFDA4VIISGXDJ5I47UEXY4F6H74DMPNMTP7BWTIK5",
-    "GAS6RYP2HPJPJ4DBXI3QHG5NPJUXWLAVAKKAT4FA4FHVSBB4CRAW7LH6",
-    "GAS6UZUHEGSFEVDW5E3CKEWHJV7VYP7I7VWGQZVOLW5TLHTK7VVOU6QJ",
-    "GAS743RXH26MMQ27EDQELVJPGLZ47EX6UUKQF7LSEBLW4BLUS3FJXPLA",
-    "GAS7TJMTWDKXC7ARIEHP2P6CG7Q7Y4LCYGCAKZJLV4SZ326LK4LEZTOC",
-    "GAS7ZMCLHZJNIXQGF3NXOETYVEZC5ONSTFMJUTKAPXLSVBTXVNCNPZ3S",
-    "GASAFKEUSALDWSMU5UR566NSBL26OGQQKT6V5C3AFC6OUMAZCRVL6JIM",
-    "GASAPP5JD25VJQDFB4DI2FZXOT7PDHFOAGABNREE23SXOHFBIHRGUEYX",
-    "GASC6MSMSLHXMRDK2LNAEPMTF4DYM5W56H4M3IHPKQWR5OR2QNDWWBYC",
-    "GASCCWGHMQ7QRLHHIPRPCCDHJLAKUPDQ3TRHMTEJQXZBZXPKPMUXUMWZ",
-    "GASCDASIRVR65MRSQMV76Q7PKCYOXCGFEHPJB7RNBNM3VTQBEOCGCP66",
-    "GASCLBUBVMPYENDWX352AI3AQCXMHULNVONTBP3J55UABWU5V77AO4KB",
-    "GASCXLYHALIAENT3O7MWBT5UJJ333Q3FEHLWHRATCNIXN5C7PKTGDUUI",
-    "GASDIIYYIL5LBVUHCJMW22FZZQU73AEHYEGV3SZMJVXRV4CJLBZJGIXB",
-    "GASEGO2WIUNOOLPAKOTIY52LONZCL33WYV7S7YFPYXK7XCKWEBOR3SHX",
-    "GASEKXJSS7VYGR4V3IUBWWFGFC7RKTQEGO6Y6YSHZZUI5EXPKUUA4EXF",
-    "GASEMHX2ZJEW3R6ZH5U43KP25D2WOXSGNECQN56L3SF2GQHWF5U5QEVR",
-    "GASESFWK3XYCSLF5XY34AGXDE7ZEQPXUXFPZ32A3XD72JLNPMJT52BSL",
-    "GASETJCMBVU6RMBNAWAEX4TWZPWTCXUROZQ433E6CRLUYP7NVDIURKMC",
-    "GASEWLOASRUA6N2VPIYTEOWUNIPZENTY7KB23EPQZAKRSY45YNRLORZI",
-    "GASFE7ZR67NV5MQC6OZNKYKZDCSLB3AYKQO6LMESVO7KP5QQ7YHKRSG5",
-    "GASGJOMSPRQOYDT2INVV6LZREEDGYWIKDOOLYRQJESYESMXAGELJRLCT",
-    "GASGTOADMEWP6AWWH7Q52LA3EOWBCHDNXIIPMUHQ4ORSEBZZBSA7YFPF",
-    "GASHBWMLCEKURIZQD46CG7FOB4I65PTVYTSC3CK3YPFUSOEJ4NRXMP7P",
-    "GASHKNEOT3LQ7SQSZRJADVSNUUHALYBZYEP7PXH46VDOID2C4XBPKFIS",
-    "GASHPLBAYYWYIQOTD6POWWCN23YHO52ZTGS5NEPTBIMTMTADXO2XUXTZ",
-    "GASHT2PS5BPT7TV7J6C4Y4UAZLV3LKXLNB33DES3ZLQDKED5W3OTJT7Q",
-    "GASIS7OTPP4BDS4U3DCMG7L3ELQNKKJYNIPKS64S2VP5AIUFVMJ3L7AW",
-    "GASJ5AF6WWSL4LBZOOK6F65E3I7SA6GV23AVMZUH7MZKEHVAMLKAKGOX",
-    "GASJWCNTJNCAMA2WFNJLUAZWZOXZUNMQEJDXPKWHN4B553S4RQ7BLF2O",
-    "GASK2YOPS5M7C2GZJIKCYRCOMH6MFQEAF4TIB6CVHTE6H7NC6MIYRQFS",
-    "GASK75MT477I7AYNUAKY4JTYDHHXIFE6QITMSY4MHZEM4AAERRFRQSI4",
-    "GASKP4A3ZLGUPDZOR4BRTWMVDPQLSNLHPMXV5LZN5P7S73WEFJW4WSSA",
-    "GASKREFJYZQ3ADNAKKHTA6WEMHWW7CC3JAUG23BUGBGAGBCPXMNF4PR4",
-    "GASKWS632SBXFM2ZTZJ3WZ2HKGGJQ6U6XZZSANMQ7OND7NDE2BTH552L",
-    "GASLIP4YLVLN2IWYDZ6OC5J3GADRV4BHMX2CVCEQ3LYZ6ROH2MW667AF",
-    "GASLW4EPXTOZUC546QXV5XIL5V7ZJXGOW6BN5LL5QBLZQ4OX7UCE5FAD",
-    "GASLZZ5R4WQW7JJWHJUPXJLYNWKTCG5SL5JXF7XPM52NVYNXUWYYH6BR",
-    "GASMADD2RQWNF252IR5MJBVGH2KBPRQKKILHCOOHAW5V4LKP2BWUAPAE",
-    "GASMFEEOTB36VAFJYTZGURTCV7ADDJMTJUA2XX77YLHEJXCM64CEB4SB",
-    "GASMMBAY6ZIIB2AS623KCDU3H7RDVZJQHPUCPCPCNYLUIDWOQXCQ7M3P",
-    "GASMQRVLSMCRYRNUW7K55BEBYXL63OQ2AJZIRFORPFEEASWKE2TVELLC",
-    "GASN4KN45PKO4YSOPGXW2XYFVONL5CJVC7WVTVKNQY7YMJHKA33EU423",
-    "GASN7O3OWZDAOQDTPMJYAMIDR7MA2GJRQYPMJBZAZCEY4BCBBWCYIXKU",
-    "GASNK3ZLIUNMO5O34LJGUZIJDF4OWSVG74AEIZZRXTO2PQDYZD25COZM",
-    "GASNXOL6PQZMU5BBXNYMGPNKU4FJ25UT6PXYGTURTUOEZTGJELUWWHIP",
-    "GASO2ZUKWYKH4PXYHOWYMJCAIK5P7I5SMH5F2HWIWWWNMJZAPHXIVR5R",
-    "GASOWSEFASXMABIQTPEXIGQHNVRG72T2OECKKR3RQHUHPP6VSA2TYYHV",
-    "GASPHHE2ZUYTYZCXBK24LRXBGJIMS3TEL27Q7JPYAL322VYOKXGHBFWZ",
-    "GASPJ5VBR2JGEJF35TYN4DTOEYKIFASVR5IHOS4VL4M5D4H3DIMTFCHN",
-    "GASPM54LDHE427IWTTAFRFC4SP7F3P7POL64UA6PHSDB3KYAKYHBUR66",
-    "GASPS4EY2SGZT3CQWSVRHPOFA47SM2CNBGVETIR44NV3OLS7IUUSCIBF",
-    "GASPX3APZHDBYU5QEDZBGYVQTGDLB7BIUP25UCOQCXXOAHZCRSWGTHBJ",
-    "GASQ47JPJ53XMZ334KDZUQP5ICNNT6AUNO44ZG3ULSETXL2Q5MWGAB4D",
-    "GASQA2GXGFVSRQJI4V6QGKE2OQO3BFSWOHNZFUD4ZSZXJLXO3VB25XLS",
-    "GASQCKJ3LHRSJ3BD645GRK27URSQI2RYRIW6XXU6BMWX4BPOD3UXREKT",
-    "GASQCXU4AFWLZZRNXVJ7K5NQM4SZ25MVGQIGCD4N7PRXMIOQS2JCQUZW",
-    "GASQKIHCBFRSJZJ5ZGU335G6WVU7HSIL3BWUVUA75CD64KEYDKVVL2AU",
-    "GASQP353UDX3A7V4JU73NOPFG4DIYOQYP2ITPBNGDI4KU32HS4HULUUT",
-    "GASRH4I655OCVUFCMBQFAKQ6XZ3MKQF4TCZ5E4QJOUAUETBPZY7LFI4U",
-    "GASRQJHTNC5B76AEFDD3DJ5SX7DGQJKTKVZBX4MC2ISY5XR2JM4ONDUS",
-    "GASRROKU4T6XP2RKZSWXZKFKILKJRQQLNZNKXE3WJ4ZOIA3Y36AVVXLM",
-    "GASSJFV5LHHB2TUF6AJWJ7IE2X7PKDF26ZQN5ENERPWLPUZQ3OIQ4SSD",
-    "GASSKNVVNBBOR23V3DM5GRVGNQN7HID7JHNKZO6S7QXCVXT44S5MW62P",
-    "GASTJ45DRKPHCJENDUSSGXS33BMPJGYSNWVQQ3B5JWLOJCRB7LIB4CGJ",
-    "GASTNKJYXNVBRPQMDFHSM5OLHEL2UZ74LVN3PRRZPF2SBAAGXKGO7KEZ",
-    "GASTWKAY6QNZYOSKJ67PQ2NLGMLLQ4JMLMBZRDDW7VYIU3IMOW2WD6KQ",
-    "GASTYQDHN25EQJNV2TA6UADCWKLUK6QGEWSTUUUS6UK32MDCUUD4W2W6",
-    "GASUM535SHGNC6KLW5KDE2QTNBH747XM76LIMAAKL5NZRR5KARNGOQNT",
-    "GASV2SMM2W6ICXW75FY4AC7HNBMMJZXCKYP73KVBOFOOUJQVFU37EDFR",
-    "GASV665DXVHXY4I2D3B5LBZE724WAWVUBSKOEG3TICDINSBJ6XSZONTX",
-    "GASVF6CC47NTREQPUSSQHAIIJBVFJNLSCUWT5RGXNOEFJIZ6ZG4CB466",
-    "GASVPWVGCRM5PCO34WQS7YXUKD62Y7X7LTTCLCZENBF43YJPQ4K2GAZR",
-    "GASWACOZFY3BPWDO6KSLOZIAHDUKIADWBSJOZV2FNQUL4R7J7R2QJ7FS",
-    "GASWDNMHDF3AEL73REPL63YIJMSM7JBHNIMEINCDW6E5NNERPT7YA7L7",
-    "GASWL6UXCIH4PBBU7DVSIEU5JKJCSOJI26UEXZR2RVKMBSIBNURF4OBC",
-    "GASWOFBJFBMFNFHJ2IBN3NHTSIRAXGHWIBFATDB4ODV6ZV35A3BPKJSK",
-    "GASWTWEVA2INRWN642MXJAR6LO5ZN2POSKIHOYXFSLZLEZJ5G6HO7T6X",
-    "GASWV65KO6CDOHGULGLRLCR6CKOUH7VHJVBOCYTE3PICNYAWQ5NI437N",
-    "GASWVREB5FOP2JFQLQXQV4ZLUFMR5S7CK2M5YCNHT4PFF5PIPJUXKT4V",
-    "GASWX3QX7W3B27HXNSK5T6WED3PQVCS46ONU67QGY5JJTC7ZBZPT6XIG",
-    "GASY2TV5IUJGSHES2W5SU3O7DDB7IEAE76FHFS6Z6WQJE3KP24YQ6JDK",
-    "GASYUZPZK7WTH2CIWWQTWX2Z6ODIHYX4JQE44V4ZOUYAEEEBIH24LDEX",
-    "GASZ2OXOQHG5LYYYOJOEVHU5HUYEBKC5VBMAPDNMWOZ4HIJKVDTSZ7H3",
-    "GASZ4VPQSXUGQ4XMTDCMUACFS4662CPYTUHRTVZSAPOU7N6DDNRIDJEN",
-    "GASZXELGZ5KNBJXIWZFHT3F2O3VKKICD6SNA4YGNUOVGPXHWO4NJ4DXN",
-    "GAT22KNMUPEVTN6WAU43V63QPD5JOA6G3OR4GENVHI7C7OFCIWQVQPFX",
-    "GAT24T5SUR2772PCZC6BYZZLUM5KP5ZO4HP3GTVDJAHWWKJ45ZDDGGET",
-    "GAT2EISLPKOZKFXM6RBHQN5EEPVBCMKBRRJYFOH6L5JBE5UPCSAGYJRB",
-    "GAT33OTQEZZWBRKDMTXDYNPFU6U3YDJB55NMKOJ7EJLG47R4CYBGEVQV",
-    "GAT37E4XU5BVSQJGG75ODAB54OOXCBNC45GKUTAJGCTRWK6G4DOU627D",
-    "GAT37MPRPHRKDLWBZN2GPJEAMY2B2RNW7LM2QURKMZRDN7XD5BZMIS73",
-    "GAT3G5WPJL6RXCQPT32EEOIS2J3BEFKA4DRHKTLVRPOENIURS2A5FAK5",
-    "GAT3OA6LVLZOMDOK7KII72MENQEY6QW3FNKQBBMKZRPDGZGPQBMZ3MAM",
-    "GAT4B5SUQVVRJWI57BFX43SDYEBFC3I2TCVO5IDEWB4YQ5CMZYO42BTC",
-    "GAT4EU52F3W5YOKEI647OAV7Q24DQKZZY2AZDADTW4DQSEN3FPSRIFON",
-    "GAT5UXVSEW4Z7QV3NGOIOA3VIDW7ECWWWVTE6UB2KCRO7ZDVQ72HKZBJ",
-    "GAT6C4SMNCFRMZ72QK2UBUVCBN2P43AI4ILPL36V7TMQPCMS3CA77XWI",
-    "GAT6J6ICYUUSNZX55GKFWE4CGJIGIDPB2JD5IKFDAT5FM4OFF4EGNTGC",
-    "GAT6ZDYPCV2UWAAVRZGJVJSBMYDT2IFYYE347DO6DMHTJDEYDWFHSZLG",
-    "GAT7DH7VLOO77BEBADX2B5WFPWOLONQU2AEYOAFGO7L3S6QKRMV3EJ6Z",
-    "GAT7DI7HXF3BZ3W4GZSG3OLXXWPVZHO7KSPYGTI4H7DBTQ7VQAIFZQUA",
-    "GAT7FQEXNXACJDHMDI6BBKD27DSZJAXTOQJXZ72LVA7V3PW4JLQELLAR",
-    "GAT7JTTHOI62SN7NSLRMLLOLZK5PVYCRBR4DBLJJRET36HGQRQ2CMNSB",
-    "GAT7OQ4XU5DMXIWZMKDCQVP6CVZNNO5YVZFCMWMYOSKTKYP43PN65HU5",
-    "GATA7P4UV5PM5VHIUVFXIKWOGXBWGRFVJIDLXBPG3FAY3QVL265344OK",
-    "GATAR3Z46GCXZI2B3AXDV2ND2POQCOZZW7YBRW4KEOQVQ7TE4QJKT6A3",
-    "GATAYE3NXUE7XX3JXBB2U6MZZ2WBIUPARXGHDO27ASWBDX3RRLS5SLDG",
-    "GATB6U2O7GKL6NEX377SHS2HHET6QR6PPU57PLUA7RLFVOZVA7FL473L",
-    "GATB7QOE5ZTHH7L4PPILQKSTIDYXDGRGRKGWCNI76C3BGXFL5TVJFPYF",
-    "GATBHM2NJUW4I73K5RJ4BYDQ5KOD722UTENSPSJLMBMSUZYRF2UZS3JX",
-    "GATBSGNBZENP2FXS4GMPNO6QRUZQ52NBCQHO7HCR4B7XYX47LPUGZWLH",
-    "GATC6DVXY6MHADOPMJKP36RLCA4YMXCYGO525LSC5IAOBXFYMYWMABTC",
-    "GATCB4TNPQYVNQURLM2W5A4FT6D25RFV6VSEDKMZD77FV5WZJRVPOWKG",
-    "GATCBSHHIX6GPGDRLIUFKCBDLCB664V4WNQYH6TIGF7I4NWIPYRFRKKX",
-    "GATCNGUL3ZIYYBXCDJBHGWPXBQQVUGTQYOV333Q3VGEVJHL6NILJETH2",
-    "GATCTT37L54E4R5KVFKV6AYWCWWCEM6U6UPV7SZJXRXRUSOOJC3EGAM6",
-    "GATCWNZSIBW3FXW37Z65TBPIZQ7K6ICGMZTUWXHSU6IDGISIH436ZKHX",
-    "GATCZVAV2O7PHMG6TQUQ4T7BGAL3XAKRRCTF7ZXCZCXOPQ6TEGLKQNTM",
-    "GATDSEEEQE6SLR3BNXTFY2WYN62PP7T6OHSLLJRT3CTMVAOKZ5GQWWOJ",
-    "GATDVDJIBRF4YC7Q3K6FRXC5UIH6UB4O5OKS5QDYID4TDHQ72YJYOPIN",
-    "GATE7HCIO6W4FWDPAUGYWMOOA2Q3GDHOWWFCZO3GQ3YZNRQIAU3SMB5M",
-    "GATEMHCCKCY67ZUCKTROYN24ZYT5GK4EQZ65JJLDHKHRUZI3EUEKMTCH",
-    "GATEMQYLLVE4VGS26K6WQZ467PSYWTLLACEIYAMZBC3LJPZM5RF3ICCY",
-    "GATEPXZFSINBQFQTBTH5274ZOSTWUZ6KTTO3XR46Z73YAQF4TARBYJWZ",
-    "GATF3XDYW5D5RABLAAXIM3EMTZLNY2XOCYXJFQK4HXHGKYTYZYMLPLJV",
-    "GATFNMNJSAZJO2ODH3EWGKZKJX3XANSPFYFTTQYVMLGMXRSNWS3AJGIR",
-    "GATFQ6VGCL2LFG7SHK6KXQDAX5FVK7TBQ7IC43ESMIGDVI4PUH2XVCIH",
-    "GATFSDMTCZAQUNIRJ75SB32CQTMBGLGJFNGTFLBNYPFEMDRSWZQWKAEP",
-    "GATG3GE2GYPQP3XHGHT22IRUU52V4PFNYFVADYMJBQ6G4KZYP3KWZJ3W",
-    "GATG6UKVQZKHK54WGHXA6M3UZQ22JNG7KFYWPBHJPORLSQ5GCMFWPLAT",
-    "GATGHXMIQ5SVDKANKB5VU2ECHT2AUW6O4CYRVLGRQCY2MBEUHUEJ6LSR",
-    "GATGRJJFI6P42BL2HUWUOAYEKC76PLMDW74ZQ5WAVKZJECG4V7JQHTV7",
-    "GATHGBQHQPMU26VWLZPYKTNFTFI524S4P52SV27PWS4IQMUPVIYBCFBO",
-    "GATI46LGCT46WAK7UOZZZRWKZFYC25J5XZDAZN24JTAHADTH66T757CK",
-    "GATIAZUNSY2HHCVV5Q7XBBXZE65G5PJYIVSHMGA4S7EWRQJEZLINWFYG",
-    "GATIIEPXDFTP2DWFT3DMMGOFFREAFRWNNYTAWIK5HO67SXLO6Y4SKRGD",
-    "GATIKJX6IE2DAJ6EDQSCKYQEMXVFDYHOUSSESS4OJARZVBUSAO45D65B",
-    "GATIMIOBPP6OMD6XDK7U5QVCG6EDX6X5HHT6DRRPAN2Y64MFYMDVYD7E",
-    "GATIPI7DE6JJFXJUXXUVSSINKJWVD5EJ5FVVCGNCD6WLVADVAOV2UXZ6",
-    "GATIS3IIWXLTF5IKF6ZDSGLWI24UUEOBGWTGV3ZSQQOCEBFQBRW4SVNZ",
-    "GATITFEP2MICHRG3WRUQVAO7MMYF3HWOR4ATEEY4JCQSNQBY2MXRB36K",
-    "GATIU7NY2EQ5PWSZLORPOTGK2RVY6UEIAN2O7L4S3BOOMHUFZ7MCWQJM",
-    "GATIWJ7A7WBRR2KR5HZ2UMVS7ENZKRBZZFHAKMT4XNOREESIZZPDTTNU",
-    "GATJD3RA5WQKLMA75HKONYIBTC7MTFEZ44ZSEBXP4Y4J4N6ON762LJU5",
-    "GATJZT7NAN7AFJCHP7RDESFHWJSVAI4A5LQGXASKU2Z3Z2KQWP4MT55U",
-    "GATK5U2CJ4D7ILPM74VW4LALDPPOMJWZVOKKDZOQZBVRXKH3Y4D5E7ZD",
-    "GATK7UHFN23WXJB2PGKSNWCAQGJUVNCPKU6KFJ5OH3CXX55YRCWZFB7O",
-    "GATKFNJ3GSN5GESFEFRQZX7F4FRIDSLNHZBVQMNDRTMHIHFX66SSG2PE",
-    "GATKUUHIWYNBS2HUCIER3ZZT3D5HAIGPHJ56UWD52JBYEE62QQQRIAOY",
-    "GATLJSP2Z7VDSRQ5YIO5H6H5JCLUHS5OJCINRQ34YAHHUGLELUVHH5BN",
-    "GATLT4ERHCWFSPEVGLV23GBQIZEG6LVOE5NGJT752LB52RRCBWU4PEUP",
-    "GATLZYL7O2H4BGU6JA77LR7C2QRDVRTVJAMBSPDQ3RHSXTYEXACBHHQM",
-    "GATMEA4SOZRTTILAULOFLWWEJXIF4QSN4RRUHEDVB6XGM52AQZ2K6HL6",
-    "GATMO3APB42KCVHZQ4A65KDVYKA3GTGWAZZFGSHPOCBC2FEMCCNSKUNN",
-    "GATMTJSJGBIHCCYEIXBQFYCRSJJNX4CSZ4QVJ7YWNQUSN7XLOX4L2YKD",
-    "GATN3YQSZ45RGQV7D2KILJUJO7HP73WAZMRKNK7G73CJABH7GBM4MDNZ",
-    "GATNI4B5EX3JJDVYIA3D7LHF6KC3DYPTZFFO4YZ3UVZKMBSBS4ACGC3D",
-    "GATNT56T6NADZUYTC5J22LNV7R3VFKFARJOJU4YTTQRXHGINOALYIFKR",
-    "GATOH3OWZLOQMVLD4DN5NYCBSCP2ZUOXVOP65MMBYGSRJQUUV2TQKLNR",
-    "GATPCW6SJ5OAKMTSB5XCQKMQDGDHKYRPTMGUETKQNJ6V5TT6SSQ6APDE",
-    "GATPPVUINCVBQ3TJZ63ZU7XCPLYFHK23TCIZ6F54XTJY7UXHO6J3ZTVR",
-    "GATQBBQ7MBW5GWZRHJQPV53OLSYGTKJN2PJASQDOSQXEWQC34ERUKUAL",
-    "GATQJDZPIXPXS2QXWSZ46PMW3ZY6PK7RVSHE5VGL3MKMEZSPDXXAKJ5V",
-    "GATQKUERVP7X3E22AL7UR5DWE6VEMNDNJEZ4WRMYPCWBOGIFBU6NH7HM",
-    "GATS3VMDGLV75VPK2IFZCOXZ7L2MXTYIQGMGFXZOZ2RLJSDRBWYP3K7P",
-    "GATSEYFTFVEC34HFY7UXY4DZ2CLKNKRRHCIMDCFZ3EA3A4THXQMN2MJB",
-    "GATSKSF66I7XQ42I5CMUM2YKK67M47V4RMS4DU55OV5GLFPGTLYVAT2V",
-    "GATSTEPDCMPZUIHKPQVTPO7XUQ4N52OEAYTLEULDIOBQGJRCUP4KGOLD",
-    "GATT6V63THVMD4PHA3IW5MGKXQPTNFZBE7H7SUKTG36FFZDOONYLC7MO",
-    "GATTBURWATEBDR4EWT4KUHHZGL3FNV4YFQUHSWSDBRGJ5EGENRVWC44N",
-    "GATTH3VPK4PMXLE7JSEJ3OC72OYOGJYINLHSCJX365BP6SAV6GKSHJAV",
-    "GATTY74LFACB5YRL337OXGSCQY7PIRKZD5WF4Z6BZJOTA7TT77MIMKRD",
-    "GATTYYWKRZS3GBM6ZP4YMHGV24FRWYB4QISRR36A7YA3BK5HS73KF5JX",
-    "GATU3ZRQIYDONSXTYNNWBLCM5H6J6YWHKXMJNUAU224GW5WNN5OMGEKP",
-    "GATUH4XPJDTT56W35XUQTMEG4SHUZ7IM7IUXTZVI5Q63TFPEVTMZ6YRX",
-    "GATUKPBEKHUEPU4BJOYGWVXS7ETBADNF5UZZET4JT6Q4XG2FMYAGDHMB",
-    "GATUU5LOBWPFD74Y6CYM2FPR6N5U3AK6V6TLTFBBMQ7CADDMNNVEBSHZ",
-    "GATWGIU5ETRD2CP6SBPIFBSRER46JJ53BO2RCQRYDUMAMOCA2DJUD4JT",
-    "GATWU66UKRB6ODGUNTCI7EPNRYJ3VYQTUHO7MUGFYXCPSVU2VDOONGPD",
-    "GATX22555KYJDMNMFNXK2FF2HBQ75GK6KRK6AUZH7WAPOAX4SGCEZM5P",
-    "GATX3JY2ULTXU7BD3K6L2LM33FS2AAGNRVUMSNEDL3C3M44CFEYZBOJH",
-    "GATXU2QVJCIVNUXGIGEMZZL7R67E7W7BUS5VQCOG5VGD4AJO4QIEQUSA",
-    "GATXZPQWP63V6BXJJLL23KYXOOZWIZTDEPMSHI45ZJ6253INAJCCJHTD",
-    "GATZDSEXMD3YAY66XXZ4IJWHDORAR3EGWRIKG7USYBQW7W36AI73GZ2R",
-    "GAU2JV6JSU2C3BXKI2MH3SZGEC4TS7GPUP76MH22XOMEVJTVEN36VZVU",
-    "GAU2N6SXW6GC7Z6YRNHK5NX27VWTAEZFZK2H7M5PI5TZ6QL6XFGVMDS3",
-    "GAU2U2GZ7CWXKIFTSLKFYM6PL4M5AZIDVLIMEP6FNQ6NNGP6NLC2CF2L",
-    "GAU35NTTEJ7LHFOPPWNHCBN4KBFMTNUF6OYZTI6ZV3DNDXM6OH5E4BRX",
-    "GAU3BQLMHZBWNAQ5WZ6FYQYCAVO42LHWQICTWVQP6DTOBKTDB3RE2LCX",
-    "GAU3EZADMK7P2MIUV6UVR56KIBMXPAKA52XZLTD5VZ6NNMZVYRUKEZLY",
-    "GAU3GAHWWVWD4K5N5WE4VFBKZEZVSPFPFTUXJSW6CYCTH2H3C7LQMKGG",
-    "GAU4E3DV3HOCZZQHUTVVUAPDYJHPI6XFVZ2NNVIDAWIANNSZI6ENPZQX",
-    "GAU4QSOWQCTQVAIKRGK7CRFQSJMH3YKQHC2DW4T2QW5JT57RETIHEO6G",
-    "GAU4VY2ORO5SQUFTUPSUWSAB5CDNPHT3L4O5GB3MQ7NY3V6B4KOXSL4F",
-    "GAU4XMP7D5RT2DR7YJLX5PHKRPT4WM6SYAQYCUX2UH6D7J4RQCZADROP",
-    "GAU55D5LBBUHTCRN3BG6ODBHNDRN7NRMK2LE7G5OCYBBXKT2WRD7MC3A",
-    "GAU5NTUQGPT7NUEE7L4H5QBPKHUSOT5F5EI7W65Y4XCOVTXODYXNM4WS",
-    "GAU5RTQDP5H22YXQPT63O6PWVPKQM3N7PZKEYHNISWHN5H5D5NT5VHYP",
-    "GAU5ZCYAZUDLBLYPYZQZQKH53QYMDQMOVVJVU53G5NNXW6IB6UDFPHXN",
-    "GAU67VNA3VAPXTGL5QQETETE7GGIVWJCZ6NENKPZAPT5QY7OG6FRRPF2",
-    "GAU6KL7MYCIJ2GJGLPQG4KX4FMXKPQZTDAAX5H4F65FQOBSDHSHMMEVT",
-    "GAU6UCTVUZ56FX33PJ3K33JMWAY676H2H7QY7JVFUTOSNPEKOSMRITTQ",
-    "GAU73BK4BECAGEW6PFIRDI33IA2GW5BFPFXBTJ7ORW5FVXLVLX3DMEIT",
-    "GAU7ACYN57A72HD54MKDPEUD7EUIEZZHJAODF5LTSUY3OIE2GSJ4OTQ2",
-    "GAU7X4IX56QCXHS4GP663W7VPM2HLQGMKJ7JGGDLT2KOZHHQXTTOKSKS",
-    "GAUATIQTVIPXMPUSBOIHBCJYOU23UVOBK3ATUHVTTXLVUTTCMVOSE5YG",
-    "GAUBKG75Y4WHSXJRM5N3KYGUUH3BO37J54PAUQ2NLYWQYMHIKEO5LYJR",
-    "GAUBZFV2AP2A2F42WHBBURHJXUHZLAEZTBGH7CJXB7CSEO33BWZ4HBAR",
-    "GAUC6PJNM5PC4JLBZZR4T62NHZV2LY7PXXKHLOTLGHSSZN2ZSZHQJBRD",
-    "GAUCDEVQ4LQXDTEGMVD5GGQTVVXEDEGEWWOWKBNYIF4BVZPO2LUVVTPM",
-    "GAUCI5JGYTBEYWQUEZRSPN2CIPJAJX4BQ3LZBUIIZ4LODALLJEPMSONY",
-    "GAUCPLSPBJKOSN7WZK6SDD2BYPQMC3YSWMLX4XXY7S4JPQFLJXEINDUS",
-    "GAUCWZIBAFB72F2FPEWP3DLZI7NF4KD65I4B6ADIAOXBYF3ZTJM62EUN",
-    "GAUDD622BI2GCXQ6CXDOOLXINXW2BMRMTNXEFAB7EMF6MR6HNVIZI5E2",
-    "GAUDOBEP323L4QUBBGG73MZODAURJRZEIOFDLZ3BZ4F6CUAOJHZPRVFM",
-    "GAUDXFKJTRRVRRVHDHDRZ7ME4VZOS7S2HJUEXZB5U3JVUC34AZYXT7GU",
-    "GAUELN24PNDHS3SFK6NQ3GRLJF6LSFQCV24C2ICO5WF5PLCOHPI5C77M",
-    "GAUF7VZQFHDKHT7436HTGWYICFRALOP6NJEWFL4NCLDKXZX64OWRWB3D",
-    "GAUFLTQBDMLNBUFMC7TRA4EXHNUXTFV557LX7IEF6BSCKYCHGHV7A3C7",
-    "GAUFVV3RDUF2QWDTSRMLRFFVPXQOK2DXUMTGQ5WB26E5MNAT5764RMYZ",
-    "GAUG4T6VWIDXCRJNPXNTGQIMNYMUVEZMV5AJNMW7J2FQ4VBPNGQ6H6TR",
-    "GAUG7EXB5COCEMAEKTXWRELHNTPLVXD4X2ZWVPK54JEZGZIKSPG6UOFF",
-    "GAUGA45QACOGRL4VGH2EYNXSYWQPZUEWKCN7LUWZQFKNXNIYO6DAGS5O",
-    "GAUGGDIEVVIZEXMIA3YG6UHHVUJ5RYNPS5O2ATHBI2HIAXIX5IINRXSE",
-    "GAUGWK274QSMYUOKPZ45SIGSYO2SYVZ7XHVWKLLEAUJS6M6HATFC6FUO",
-    "GAUHGVKPLJX2T5NSUPQLWW6FGQOPNT4IPP4OP5ZNSIYG7VNRLF3KATRX",
-    "GAUHMGEIDZUYX5OLVATN5PHRXFPOTTLHGNK2QUIBPD6KR5GQORFAY6YM",
-    "GAUHMRXD4NQ5A4QH73VRQ7YNQN77EVI2WAWLGYZVCZJY6OJ2DZZGLG2W",
-    "GAUHSFPLH2Y4TRKGLV7LPC6GH3VM6XNJSYYG3C7HRHX7WDZJKCMW3DTG",
-    "GAUIE3VBC5UGG6OIJX2TMLSGLWZQPXL6QFHA2ISRISZVGDI7LLFJMOVR",
-    "GAUIPNMHC6TGFY2T74B4DC6CGFVYUXIDG3Q2E6IJCVMOAJ53FUJMJENG",
-    "GAUITMXZTBSSL6I2FLOQX32BHIXA75LV4LPQHLPNOVTUDSG6AI3BJUZD",
-    "GAUJOM774KRFBMYNRIK5DJ2GJCFF2ZANITU6GOPVWRSKKLLQML5ZMNT5",
-    "GAUJR6M2AFPQ5NO2QDCQ2H66PHPYZ2ULALCO37OAOPG7Y5R7LZWRAIV4",
-    "GAUJUOXWLC77FX5BJYSWBNEXNQ6EUOMXHVWLW2ZB2L4DPSGRMGLJSL4T",
-    "GAUKIBWDMFAMYYHLA7VNFVD4VHWTZUBFXIWMKBPJJFBPSYI7RGGCK7YU",
-    "GAUKQ2LYV4HWBX76VNTDQGFLATDPSEX4MAXK6GE4KZIQEIDOHTYYUTMP",
-    "GAUKSRZANDMZJWGDUB5NRBNM3D22IXTCQ7QNAGKS6TS7H5B7XME7MLR7",
-    "GAUKWGTWNIKTEF6C33SSJIL2K76ZQRNKZHFV2DEZRGYEPUT5F6HQ6ZH4",
-    "GAUL4BNWDASEE25IM2FUI6WWHWPVV2Q5UKB7IXFLTW4LROHN6IYY5KDB",
-    "GAUL4OGCVXQACHALXWRF4HRC6WJ57LGFRAXAEDHUOZBLGC2C6C4XJ6VU",
-    "GAULXIO2ME3PIUYJILUJBK7JMAE3R7RAJYSTHSWBTWP4DFBOJA3VPLAT",
-    "GAUM3WOUDKRTVHTCXGR5Q7AMI2RUQK5VIF2Q6L27DN3AJZTIBCVTRMX7",
-    "GAUMDSYB2PIZ6ZBKBF7I3RH7NAAZOWHSYWK7UOZ2BJNV5QCNBYUKXLXH",
-    "GAUMKWEMETLQC2XXSNZM6UW5UDEIW4EYDIUR7JC6KEL5ERL5WN7LGEUR",
-    "GAUMMCHURWTE7NCWPBAKFZLNB6IKBSC77ZHKHIWAXJOWNYAFTAW7CJAN",
-    "GAUMPXFFO7KVOVJRFPWVPLAQ3Z3JXIVYEPNKRZI3M3WOOO2USQXXP33R",
-    "GAUNEJJGUIIV6MVFGNQV2ETDWTSVQV6D3DKYH5SREXUCTRUL2KQSWISS",
-    "GAUNMZVRGEEWFKJZY7RNMF2HJVOL4PPCHMJMUCOK7CLMRV5Q4N3CYBTC",
-    "GAUNTI3H5EC5WQSLZ4HN4C62ECJLN75E66F4SMABT7MZYDKWIE7J3XLM",
-    "GAUNVIQ5UVWOQWM6CYLDRL5WRKKNNNITQMYXYK2D6ASUOB765BJAO4ZE",
-    "GAUNY4WXQGSW5YNH4CYTVVEFHPQQDFAHYWECPWBUOT2VF6TRJPUWKQIA",
-    "GAUOEFNFHYYGLWZOU5KTJISY4357FGH6VXP2TBXXAY2MZILMB5V6XSGB",
-    "GAUOJXN3HABBKWDHVKWMHJN2RD2TAML62HVPAFC4XFUOJKGIUZNL6LJR",
-    "GAUOPZ6PXYCJV7WAS6QY2TI4KLN7MDFV47XMUABW6HEWE6TXUXKWUZN2",
-    "GAUORFL75GDDBRDUG7BRNBD6OVYCTAUGIGFZWZHBYXAP3SONUJVIATIF",
-    "GAUPCYURTXNQ3RTF2BN5DAR6CJEGSZXUMCU6PZXSQQBTY75OGII775YN",
-    "GAUPFUCNE7LCZ3Y2UQFJITHDBOFOF7VPIVC565TJKCNSC3T4S5UG4IFS",
-    "GAUQ4LHZAL7FT7RN6PBRSHI6244OB55X57HGXTJVHYMDU7672GCTXDLL",
-    "GAUQHHHK6OND4YYQUG4WVAQLLANRZFVLKC35A2EDWBVINBUZ26OFRG7H",
-    "GAUQMVNNIWTAQZ54P22AHGTJCIP5UZBTNSMCILP5MLHTJHC5R3QR37HW",
-    "GAUR6665OSHVETZP45TWYHCTVKJ4CJ6SESBO5YRPSHTILFJBX2ZZALGO",
-    "GAURX4ZAQB3WMIIUJXERAUM6FLIWFM2QQYSNDCZ5EUEJSZSQ6X7UIGGC",
-    "GAUS4GMHO3XEGQWDQIDB4HVSIMNFYMITD6VINTQA2LPEGNCYFO6XCDFX",
-    "GAUS6YAOF7XS3GXWADK56FVZGPCNKML54ZCY6P3HGLBC3RL6A4RVEX25",
-    "GAUSRDSA3GDBX2HGUCRWFTJBBAQFYXI7ENEZHAYE6NSMCD5QMRWNPLAT",
-    "GAUT7YPRFUPFZOYFHXTDLGKHEJLKHPRNGOIWNX4X73NOQP5DKDI2ZZE5",
-    "GAUU3S5DITE62CD6JSSIAORUO7ETVD3T4HMOYRBBPBO2DA5JXGDK3HTC",
-    "GAUU4WW3DENHR6EUEAZQ5AWMOMSKUKAFOEDMXYB4DRI4C5B6MM3EYC4B",
-    "GAUUIZ6KSZCEMYX2DTDZ45LC6BXZJG4JTBD72LANNCS4ICWSHN3MBGPT",
-    "GAUVRY6WQ4JKAJ55PA7SJI35W7GIZB4LLPNDCGHFAL4LDZYEVHPREI4I",
-    "GAUVTB5XBDCSYBDBXIC5DY2JLIFH3HH5JV6UNARCDZ2ME6CGJHSMIZTS",
-    "GAUWDYXZ2JQRU7N2M74BFDIEC4UI4IOZCV7AK5PRO7DHDYWCDWLFHEBA",
-    "GAUWES2POH347NNJGRI4OBM34LMOCMWSTZ3RAOZMOBH4PFVBWFMHLNTC",
-    "GAUWRU66NENBCMY4NAAG5RHLCA3ML4PO7SIZDBODQEANPCX75JM6MAIN",
-    "GAUXNWL3YGGFLHPE3OKVQ4VRDGC2DAS5XWA3JS4NYSYO4CGEXMIPPM2P",
-    "GAUXO6RR6RQV47OQDKWKYKDRJEQS2E7RUVPLD5XE3I5XQHQ2I6SIWKAM",
-    "GAUXQRGHIHNZSYMEHNISGFQ66OTBMI6S5E22JLTZ627DRGWS5M4B43IJ",
-    "GAUY2OKDFHFLHPZD44RFJFTAN73SOHSTSJ3V4SJL5EUSMRVAEE4Z2PCV",
-    "GAUYQJA2XES5AJPJE6SID372X2GQTX67UUXONJIYHH2IXZUAYTYRWXLM",
-    "GAUZ3JIUYXPSILT7ZKI5RHZDJ23AH4F2VNJEGUGRZFCBG5XQGNOEEHY6",
-    "GAUZ6D3IWOP5OKHGDZQELNCOJNLWSWH5AF5E5KG3MVTANVWDSKTMFV4E",
-    "GAUZ7JZ2W7CNFLTYFOEEPFWCUQOHDXTPMCOOP76YAMFHGV2WPSNO2D3A",
-    "GAV2MBBGRSVYLAFW7U4AYAVRQIA6KDCL7DAKLWSXW6EGCFEURV3RSOQK",
-    "GAV2PKBVEGY7MN4HD26ANIWJ2ITPTGGY4NRNQKGPTL224DVKIBMLOCS3",
-    "GAV2TLYF7I7FXS7CJ4HKPXE37Q7HVU3LSGI2Y5WWOSUR5O7NSVP7WZ56",
-    "GAV2X4IYRGMXE6JGEFSQUTTWF7KIMLQUWDO33YYXFGZLOK2BBMULXHHH",
-    "GAV32JPGYDNZVYG2CY57OZ2TD2CIY2OUMUPBYTX2C4RZGD2D4KGXPBTC",
-    "GAV3L276V2FK3SMNT3DGPW6WZI6VFS437EU6642ECBYMZSE4R5JETBK7",
-    "GAV3LI2OCSAGZQ6PZKD6C62VVE4CMHUAGDZBKD6RNZCAJQ3B5ZRFBCXC",
-    "GAV3P6IY4YIQQ3SJ7ILLDXGQ3BNNPK5SG72WHCIU2LC3CJBU45NPR6LR",
-    "GAV3RO6WAZO5PI2UQZESMUGHMNBALWH7WT2QMUZ4UKOYFGN6JIRIKLCO",
-    "GAV43MHNZ7X6IEBI25XGNPGFKKGKX2EP7JFL4VUUKSNMI42GT43SWISS",
-    "GAV4GRWUNJ3XC5HZ3YT5X3H7LCKBAEZVOUXF6OEI55LISJ7DDCV2ELON",
-    "GAV4NL5QH5AQDHDJ7C3T4IA7OJCDAL5NGP4UDVCL2M7TUNJLNCVRDE4Y",
-    "GAV4NNQ22S2Q7W6C3G35YOATA6ARSIKLGQ5Y5EG2X5UWSPAFLRBXKM36",
-    "GAV52UYDODCY5NAUIAMSXMHIJM55EQYJS4O4JBK5M3U3U4UF34NTUMCV",
-    "GAV5B4U7RUMPV5J4YSDWRY4F4AFI25QJ3ORLRNBU2NKINAEAVW3PQJRF",
-    "GAV5BPJ4VAWGEEUSL2KDFWA3WGIVMSYX22RBLDT3SOH62UTHXE5DELON",
-    "GAV5CZUTZBVCEJSJC7ZNN27VZEVX4QH74TUKHMGXYXX4N6PY237S6CEO",
-    "GAV5HDTCXPEE3PNCZQYFFEVE4RZRUMWBZEOQ7SUBFJQOJLL475PGYTZL",
-    "GAV5MTJNXI7C5HFJQFLQJ5YD7XROGQO3KJVNFSDN2R53LQORWAWOKAEQ",
-    "GAV5XX63UWYNHA7GWEXWSGZH2IRBSP7RO6MK7EHH65MFN7EXFBKAGJZM",
-    "GAV67FHR2IRZ3GQ6QWZKOYTYMVEAKRLDC3PNHCUAO2SZK42AFD263OOB",
-    "GAV6LZ73WGQBQ57UFF3QETSX52F3PP4D63DDARBZEOBUTV7F6TYXG3DH",
-    "GAV76CMXVWRX2OCTE2BGNJLHYMPCPDEH7GYAZWQ6FAPFAEHU6SD34623",
-    "GAV7LRM2C2IIHEOLQHVITKLQCAAQPSSYFQY4WT4JYDSFYEYYLRUKUSDX",
-    "GAV7M4GZEFQVSTDFRFECMUIYJTWFSQGODUVOWPOV5L4KR4H6WBVS6XXN",
-    "GAV7OS6KJSN75PFFY3ULKFW6BZQRWLXBXXI2AL6IPCACG63FXRMOWTUG",
-    "GAV7QI475HQ4SGNCVDIA4LTF3FT7ADNHPQIYZRSQ6DBRXZMI3YPSOCG7",
-    "GAV7QLAOK25R6KZMP65ZQS3CHEYORSZCP6G5BXB2FNPWKDUUEOEUMHMD",
-    "GAV7RAFECLUE7XD3ULUWGIK4TAOILNPIWE7RUUUVKKTWCU6OQ5UJJ6FK",
-    "GAV7WTUGBJANZW5GGVADPFXZXO7D5EYVOZHSCZLV4ZNQPRA3Z4FJSWHC",
-    "GAVAKXAWMOUUCK4ZTWJHU23SUWH5RX47SYGV3HNJMO3GYB2V4VL3IWJ3",
-    "GAVAO45EV6BHAMY24C7N3CKM4DZM5XDZZPATSAI5FYNNPMJB4W2MRFI3",
-    "GAVAPKZPGDEHBUMSVLXWM4RWSTVR4A5RR7LP7XDP7OMNSGFM4343PCT4",
-    "GAVB4MTCUHMX267AI3J44KDFGC4PPWASGQ4BIBSZ6LPDDZ4TUZOGWLUF",
-    "GAVB6IIAE2LHIJQFKSGUAX7AKLKPSR5LCAMASSDPYFW54FEP5U7WKT3M",
-    "GAVBRCPJPEGVA4FGPDMVKDOHR2AQ7LQ3IIXJ62CN4PZGMYHVJLHHQU4G",
-    "GAVBSLCSSEUYK65LPAPICXRK3ERSEHB52JPTOM26SNIWG2VQWSQESETH",
-    "GAVC255OW6MYNPJ6ILYSM5WDQW2KEVSQH2P6TOK2V6ABD7HZPE3D37RB",
-    "GAVC32UIFGOZ4EMELJYFP6FNE4RA5XM3XLSDUDGMC67QIMVEE3BE2GSD",
-    "GAVC4H6PZKWQ3SSEJGPSV3NI6PM276TKM5Z77VEWZVBM6H25BXOWZIAA",
-    "GAVCJELBIABQQUHRSBS6XYDOO7BJ32TJAEFBCBFNRIXYIIVR26323HSC",
-    "GAVD42PUETVTKNJC34ER43AEFSTB5VN3DYTR7IR3XDXEIYRMC2G7WQHK",
-    "GAVD4E4LKUYPOOO67MXIK6PUDDREC7BRPACXH7JLVI74DCIQ6XQ3ZBU3",
-    "GAVECPY2RAL4BP3KCYE3J6NPSKLIZBO3CLTHHJN72G4HX4RRMU6QBROC",
-    "GAVERKZI6ITVTD7RPRRY6LSCJRKURDWRLTGG36KT2YLFK4LZG4DRNDCQ",
-    "GAVEX2DJDAUASPYOSJQPYSNIHM72EV4Y45YIJJZNHKNVBVHBWCJSWISS",
-    "GAVFACEORMC7DU6ZNL3TSFXQRBZDS4PHE6FRJWDYAUDVCH7ED7T5ECVZ",
-    "GAVFCCDWBMCDIOKLFRT3TJS3D4KI7IU2PQBECBNFMED4UO4PDODJGT5A",
-    "GAVFOGMWE57DWJNFYVJ5FMPT57ARZUFNN766RBJ6CUE7TF4BZJDN6P6Q",
-    "GAVFVFS4PAXEK7Z2VSCEU3THDE6G3KDCZ6RBKO3Q2CHTM4UDGZTLNURZ",
-    "GAVG34TSSBC3GVWJ2EMMTK5DXIVG345L3FLG4UUUYR6CBEKN4AX47DOA",
-    "GAVG6OJ5XVQ7LFLY2BWTH7BTXKQ4EM7LOHBXOZNMOEVIRNAB2SQNIR4I",
-    "GAVGE3TOJ7YHWWILW3WO2KDIEIROTY3FSYTZGCG2FBXZKG5AEK6KCQIH",
-    "GAVGGRQSWCHIG63BNT5ZHRCQRHQEP6CKBYEFFZXJQXTRDGXZYPKRCPBL",
-    "GAVGQGGHTLTDG3POMPINYBQDCFSQNQVWLHSOMZC25MGDZXXZCSQ3RC74",
-    "GAVGWHAXUNKXSO5W2ERX4R3SKQGPPLGXODLUOYTNMANU4XBBVTYFXSBC",
-    "GAVGZYIOVC4SEKUNUCNRGWWR2PMZMGLYU7YLBITSP2OX3E7ADS3TN6IY",
-    "GAVH3TK5RVEEZWJIJFTOGBS3Y43KZPU345JSM6J7UAA2NG6X464UWF2S",
-    "GAVHLNWR4TBYV6CSCVI3IFBF6ZTV4ZRAV7Z5Q3P7P3KWVDMALVYEESLA",
-    "GAVHNZF66XD22CSBTG22FGGRBJT3GRKNWCSK66OJKICZXVP23V6DD7F5",
-    "GAVIBTFG4SE7NOLXAO6XIKQWPBRZMMOK6IWNS7PD54FACYYBFTAO7KPI",
-    "GAVIRFCJEXYJRCVM56SJGKA6MDEF7F4H54WPQ5QBBLF7RI4MLNQRFI44",
-    "GAVIW7G34QGQI4DQ2KDTFNPH3DIR5LXCKEFNENKPMI3KU3KEJ5F7ABXV",
-    "GAVK5JKUOVVWS3VCOFN73CW3OSQSVNBACZTJK23AYF63DXQPYPRFRGY3",
-    "GAVKOBV2EBXTFFXH3MWEJWSSXVDNIJG65NT3RBIYHNEBYPYJ3ZDEDXLM",
-    "GAVKWTAOSVB4BMQY5LDDMMJLRFEVAJXKJXN53PKJEHWOEQ6CZVIVCCGW",
-    "GAVL7XM75ZWRQLBCUD6GT5RFP344HPURVIY2N3ZHO2ONLY7X7YSWAT32",
-    "GAVLD5Z7OCGTLLHOWBRXGYBV3U5JEJPITEWJTJQKNQL2E66SODRW3QOW",
-    "GAVLUH235Z2Q5JB5P5QXUV3TNN36FHWB3Q7DMC4QPIUKI3DEZAJUAKGQ",
-    "GAVLUPERJODAT573SP4H3A2VDYBCXJFOFPDBKSSK7J72ECPNGLRQFMKR",
-    "GAVMX6RG3FLVOBCPW5HSII2U6XWVNPSVM4JLAF7O3AWDL7UU2Y4GVCWZ",
-    "GAVNNHV66FCCDGZTVGAZW2KZ6CUQBZKP6GL7HJFJZYP2HVERURNCSONY",
-    "GAVOC3CQY5TJSGRN4CRQ6XXVWTTWT4HRRSMC2KDXC5PPTWQIKMX2VVPJ",
-    "GAVOIRCYIVC72JY65KKXOOE55QPMAP2LVVNINJQOODO4INUZUAVOQ3NS",
-    "GAVOPD5W2SIRZ63RGH65HWGN7J24ML6HFMHKX6HMPDXE32XD5K6RC75T",
-    "GAVP33NL2Y7H236RM7UZHJ4U3BNZZMUF5K5HVQT2UDA56J7F7HHNYJMT",
-    "GAVPLRPNNUGFPZXGBISGDSRYYEIFDBR3FNE7CXSMN27MNJVO4E5YOIVT",
-    "GAVQ5RFK4NX3JWHCBF4MILM2PADMJW4FS3D222Q5MY74JBUBEGBOFBCH",
-    "GAVQK34CV2IILPATBRZJOVI64ZCERHZVFTSVDIWCCEWT6YDLGJ2EMDXU",
-    "GAVQQT7CG6IYBG324V675F35DOQY7PERLRKKIXFGNCNIW4VXD6N7TBOU",
-    "GAVR3BOAWW2ERU2DT66KME7H2YJSG3Q7GQPMJ4BZS7LUQAEBSL4YAZPN",
-    "GAVRTWGE5EFHYM27YEVQI56UJTQGMGTY7ZJKAEKZPO5MV5YO76APM3JI",
-    "GAVSSJD3KZPMM5SOJPO5Y2DD35MAWNIUNRXJPH2GADUOMHRPGJW5F3V4",
-    "GAVSWYXH77K6WZS26EUMPJ3TKH3PI32K7ELFKS3FNB5HL27XDK4ZB7JD",
-    "GAVSYFI6MPRL44TAJHSQJQDM6ABJPKR5LJA34ATQQUKL3R7QIKXOIG23",
-    "GAVTOA7DSO4B3IGV3KZMQ4UEP2EBIM7GEEM7KS6L555VZIRULQNCGBTC",
-    "GAVTZN5HLSF3O7UD2XV3JHZW3FRDWJ6EMUKRLBCKTKAYTYU52Q4L7G7Y",
-    "GAVV5BDP2FVBEURKPUFVUKKTGBTO72GJQBTHOOBCLKHXN2K2H4ZFNLPD",
-    "GAVVBNGQFZE77NI5W46KSCVMRUA4IUXS27PH6UEILB4NN42J44V6PORT",
-    "GAVVW3CWGNSGBYY56PZV5UHYCPO7Q4SCFIGXTE3AP5PY4FBTZPCCHQRL",
-    "GAVVZYIXEDTO4565MQOHRFCXIERNO5VAHT3T56A26FB5GO6SXDE4PZHC",
-    "GAVWRNFBHT2ZDCF5KUDOVY3BGNYQYADANLJ65ECIGSCL4ZSHSYKJ32XH",
-    "GAVWRPI7Q5STCG3TPRF2KK73QPIMJ6DKLSOAL3LW6HV25TDNHBZJPGKO",
-    "GAVX2KLJSFQ54ONMYT5PHXCAAY5JCAQYPIEPBW7LG2SERX24BL4KET4F",
-    "GAVYBS2624MSNGUWP3JIYGYC2U2YJGAYQHPZ77NKIQ6RAHPKQBBFKV2I",
-    "GAVZ53DZZUYIPLOVYPBXCLA2HDBNK3UO3OYB44DBAPJSNWH5AIM2O2TI",
-    "GAVZP3UGPJGSDLADS37VTPP2K6DHF7UBZTH7FM66QNPLXQMPQQTCBEA6",
-    "GAVZVBVXMCDKW2QHRV2Q6OZHZDWUKMCJ6TX4CCLKIZDTRFMPHDD2AC6M",
-    "GAW3CK23H6UCGMYVDZCGR5APUEZXU7P233KEFDPMZCJ7QM76AWLIADXO",
-    "GAW3DE2M5T4IKQ3XKLNUOA6XIUFVQXCAZXX6XY5LJQB4WDMQ65ARDRWJ",
-    "GAW4BN6ABFDIG5CYAURYZ6BJ6PNTJMYYHC6LNLLEVOXKHY3JGFVTIYRG",
-    "GAW4NEJZGCGSGTKYZIXFMZ7KHP22TV7XEZCSHMS3NCZBXO47V4JALAPW",
-    "GAW4ZWIZS7WRT3ECEAREHZKMH2E2G5K6BCQ5MZIGHFFAGFMMA66PG346",
-    "GAW5F2ZBP6FEB53KBOHHAGFQNIJP3ZBJ2GCJ3FNRQXGTZCTGC6M5QY4R",
-    "GAW5PTDXIALFPF5T3EQTUNX2N5RAKE67WP7VHDPGMN4XGAKGR4ELCXLM",
-    "GAW5QXPUAFIVGEKIGHWZX76PFNV2FSLYOUMXLHVPP7SO35FMYBVBA7ZT",
-    "GAW65D7YLZ4GHADU7GSZE5WN4IINLULTU42ICH5K7YLHUDRX4DXURZMD",
-    "GAW67TKX7BEHRUO5VXLVMQZGRLFECMKJWXJJCXXAAE36KIG6SVH4WJFR",
-    "GAW6EFBANNO43ZRLSBILJGOPUKWVORCR45KYQBI2XO2R5FFHE3VRA6FZ",
-    "GAW7W3ZPDEVY77B3J3XHTVYKLMQCMCD3OMVO5SUNDRAKDCLEAHFKTHVZ",
-    "GAW7WO2RMBEU6PPNX7OMCRT7PGFK7O42MY6WWJKIBFBRNUG2OBG3TGSI",
-    "GAW7XLX6SDSRPIA5PITM5E6YTETBXXBF5EHGMFYGOFR3PU2C55VU5NES",
-    "GAWAD57VYJJXECBXQSLNYZ6L2QKJD7E7EGOPOQXLAX6PSJ5V3VI6EYRI",
-    "GAWAVXSJWZS2A3KZFA6KU6T3BDNJLB7Z3SBY4XGGL5AELTGDOJ6VRNBI",
-    "GAWBBHNBC5THZMTWBH34H6RI2ZC67OD2Z5IVHDNBWB3V6OHPM3G4LGUM",
-    "GAWBGVO53QCODCSHYWJ5CAFXP2ZNP7PVFNT2X7PN7AKDSDUTA534QOKR",
-    "GAWBMJ2JTVGP4WQHNWMOY7ZUYWDW2DLSESKMPZ6YVCBCLLLM44WTRXTU",
-    "GAWBN66G72TU34MZGZDPYLEESXH6R3HCB4QYPZE7G33C5FMY65GZ4Z64",
-    "GAWBVDSNTTFPEY7SFGS3EFO2PFPHEWJODHYDI3F2RL4FFNMFZJZB5DQE",
-    "GAWBWLHJDZZ4Y3SLZFW2FFYXQOYZEOPPZ5CC5EMYISNQY3ADNXMSEOKL",
-    "GAWC2MIQ6YV5WUI6HUE637GT3EL5NMFSWPOJN6P4DAS3UQC2ZSWFEKOA",
-    "GAWCEBCNNNGYNQZPQHCINPNY5WNWH7TXLP4IF2SIBW63CRXHWOCE3SAS",
-    "GAWCIPK67KUGSBK6Y6K67Z7Q57EZVEKTIE3DQ5I6WDGTLDUQ23UFSAU5",
-    "GAWCK332NOJHOMT5FWZIWTYRNAZX2HTKBUL4J7KUN5LBBF5UXCHC2DFU",
-    "GAWCLRSDEHAEIH4IV62CNZCIAETFKYQWBLGGINUCJ3WXFP4RMJDXGBT5",
-    "GAWCPGUJTTGZHYTQTBU7FOCY7EZCWLQ327VHA5UMNKB5HRUYGQ256ZZL",
-    "GAWD5CAH7JBJHKJUY4YG262OOEYT4TZFD6PZZTHNUF7BRFNQKKMJNBFV",
-    "GAWDQ4R5HFPPNMEGXK5DKDUKIS3MVLK6XJZ4WD6L7XFXCLWEYUN2YVXL",
-    "GAWDXZDUNZBWKZ4QAELAEQKFXXC5UXB6DNOUDWIZSLKCZGJC5TVM7QDK",
-    "GAWE2U73J2WY6DF5MYIT3C4TKWTVTBVYMWHTSSDHVBZSLV553DOAAVOL",
-    "GAWEC2MNZ7NPD36EWLC5GFUZ4MWTNODYXLE2LPDDIRPOH3PCDSDGLQFS",
-    "GAWEDIWRWEFOLNY3HQ6UZGYAG5CYUPKWY3UMJHHSXB3QXCYFQO26SYLI",
-    "GAWEI7J5S7W6FTGQ7V735QO2RCEWYKOO4LZVD672AYVAOG5CKRQ3BROC",
-    "GAWELNOKTNNVSSEX4XRATKGAVJLDI3GYMMBXMP6CYVE5N23C3AZ654JU",
-    "GAWEQFAMSPKSVTAPZLQ7KOUEUZ3EF2VJJDQM23LZ4JQKTX7XJRECJAB3",
-    "GAWEYFLOO6L4KYML6IAMHZYWIKXDXQJWHLSTU5USX7KRIMPEUMAP47P4",
-    "GAWG3NPQXQEJYQY3SXAOI62W5MTORFVOGYLOWDXEHPJAG62PPKC47DGB",
-    "GAWGY242Q32M54M7556GVOKY2ZLDVSZPN5X5BABMYPJXCBFBHMMQBUSD",
-    "GAWGZ2324Y5EPSPB5ZVHUF7PQINAFQI3NCMDSUGBWAD2M74YRXHSWISS",
-    "GAWH76W2MZ4DP3UW2IIOPNO7LOAV2YWAS4UVJ6LF2JNAFMSKD4PXDFAU",
-    "GAWHRBXVL2LIZGBYRCFKW5Z5624FHHXFAC4ATWC7R3EOEPP7ZWELTN2K",
-    "GAWHYOHC6UEXDWJMBBHI3VTU76DELSMG7AHNS5ZOIUNFKQTPX5AIEYMZ",
-    "GAWHZSKDYEBEWDTCJ7XDDCTKDEA54HVQJNNSDOBMTXAGS6LYNDAW3PMT",
-    "GAWI3W7NE7E7WXU34I6QJFYL7QRQ63ZNNXL3HUL2N24G7FNHQLDWLNLP",
-    "GAWI4WKGUI4YXHOGZ2GGAZ4W3KGXX4SM4OXGVSGDGSKH7AE7JOOCBUBX",
-    "GAWIJPAHEI7LBOLHNNXNDUIMRTVGDE6HSD3N6GD3WECCBKLE4OBQREVI",
-    "GAWIZ5NUJEO6C4NMD44SDYECAOKQZJSB6ENN33XPM6KUHDAQC7TGR5EY",
-    "GAWJH6ZZZOGESXWJIVR4BK72G6CKECEFNQRWTJO6EZ7JAY5RREHCFUND",
-    "GAWJHWQCQ74EYSKWE3BJ7Q5B7SIS3JHFANIBF7OPDUIQMUVIDOMQLXCI",
-    "GAWJLBEVVYKHERVXATRVYYVAFRKYJOMSZX7U6BI5BCTAZMEPINL6MBCH",
-    "GAWJYL2WGM5B4PBMXCDONDQVX35WV64KHENVO4NLUOLVDF77RG3JR3MG",
-    "GAWKIJSBDFKDV7LLRREPWD5ADGBBF7THRTRQOBWPWBBSKVIH7PIJRHEQ",
-    "GAWKKCKXDQDP2CUGPPSVDJOHISYVWVO5IH3QU4FXNBYQ64LUMDZLYPW7",
-    "GAWKLW62DXYRUD5FL4JTJ2CQUNLQQI6SCEZBORZJFVBMO4B2XLXP7TDF",
-    "GAWKZ3ZQOIRTRVHAO3SKCCW7OV5Q53BXMI657L6H42VKUPW5NTQD3LAG",
-    "GAWLR3ERQZ3POIO7TIUIQNKP4MQKKGQJ37EJBK3EGU3HGIZ4XWBK6D37",
-    "GAWMBZP473VDTJZIUG5RLZJZUXQOPHE2HUOOUXH4CLT73ZOV573BSXVX",
-    "GAWMWZKKGXHXHKN5R6FOGIHWGSD66Z2U2HZGRLDZBLQPKUO5TCP6XXW6",
-    "GAWMXJ24BEFPK55BH64G77TFTLHXUXE4HU7RQDW7LGIYZE3LD2IYBNYA",
-    "GAWN7AHXUDTMPTMUXO5PV2FRX2QE2JR2SPVWLKAAUQIUECB2ITYVGTRV",
-    "GAWN7F7J4ADTPE3M7QT56YGRDI6IJFNGPRPU7JRNQ4C6U35XLFJSPBTC",
-    "GAWNGQVSZ2JBY2HJB3VOOUSJEWFHMD46I6WIFSYVFWGN52QYAHKLLFQI",
-    "GAWNLP6TXLEH5ISAC3STCM4H4FB6FITPTCWIWWHTVKG3W22CJGZJOJ6K",
-    "GAWNUMVBJM7G7TRCU2NKGB3WC36IEGUTCLRSWIOZWP5AQKWRNNKDVWVF",
-    "GAWO5ULNI3D5ILTGKONRGVTUXSDPFQG75LNUO7LHZHONUPQFEMVGO7E3",
-    "GAWOLN6O7QOGDYAITPDGILRHZDECSJGI7BZ3LM4IHLPD255O3UZHSCVI",
-    "GAWOPZKMDT2U6KRIBNUIIEOSTVKPYVLLLNZ6NVMBVM3QLAYLM62DG642",
-    "GAWOW3UQJK7KVVPUUJHBS6HVTBSUSDBE2L35XV7O6WZJ646XEJU2WTC6",
-    "GAWPEXHOIE5GJ6HH7JQSYKHXNQO5VFSBRMI66VMBCYBJ66B3E552V6BP",
-    "GAWQLA6IMNIJIU6WIOA33HVZAA4S57VPEWUHWT6JLXAZKK5XPU4AFORE",
-    "GAWQM64ZQWGFG5OBW33O3THQY2VUYWOFFIMRJQRLNTWMAFTSXCMRM6CG",
-    "GAWR6YHTQ6YXR7733IX6U3HZ5CYLAYONGOKFH7USVZRISQPUWSKWTZHV",
-    "GAWRCTH7FVPGL4UMJDV5DTAE7TGGGIJWZXYFQCHJ2HD5426LYFSPIGVS",
-    "GAWRVROJ5TVIA2BGCCLIU3YEF3JQGQT6KLDM3PVBZ34WJLT5SAOFXNYY",
-    "GAWS5PDL4MCMJW3EXAKBD6DH5RHRFIELRDS5XBIBF4LCYH6AYDVNXY7P",
-    "GAWSBG7SKRKLL2457AQ56M43VRX5UTMJOK7SSFTG4WRGSTCIWVNA332M",
-    "GAWSBTB6ZTJRIELLYSQPCQGRCMIG3TGWDPKX3CJ23BHOIJB257DCWA3R",
-    "GAWSGCXSKZQ2FSP6URKP4X2GBWYTKSWJAJPCT7VG37ZCKOB3B7L7HRDI",
-    "GAWSVPULZZNBDN46HUOMCD5W2BDCSPD2CCJFPJLPHWNDMVKIT6L3SBLL",
-    "GAWTKPYLCHKX6NTMDP3NQIY6OCEDEDNDR3HDEVU75YWCU6VDIIR4OWHP",
-    "GAWTLF3L3N55L2QYDQS7QNQMTLTFQG4DIUKLORB7WKRUCNV5XDG6Q7NR",
-    "GAWUFDNKY3AQCXEGERVAAAQPOGUL2CKMDLRUG3HJUNCN3MQQKBJTLYRR",
-    "GAWUHRC275QBDONNQK2JHXREY7E2TLMBDWSTVIO22A3F3YFVR5ERAAZG",
-    "GAWULXUQYLWWPN7JIUJYI6NIXSZZWL77ZQMCMSU7JBCGH7SBEA7TPCB5",
-    "GAWV2MM4AFIFOTDNDULD7VUK4CSLHKOXALXZ5DHEPWXRTCLXAAABQ6WT",
-    "GAWVHBG6JM2RUCLJR7QANRDUBFQ4G3GWJHHJ7C24JAP5GBRSZERCMT2U",
-    "GAWVQBEJJ7EWVJTK3OY7FIRCWKOU2VCVXXAMKRCGK5MCF2IBUOVRJ2VN",
-    "GAWWIR5JEJVPQRHM4GTRBUSQPXGANXR6R3YCU53U6QL5X4R7FVMR52TG",
-    "GAWWKFSO334JGPZ5IJJMEZMQDKHBQH77EEZR3LIE7D7AODSXDXQQVXBK",
-    "GAWXM6TONUWZBKUDWDKQCORURYRTMB3B7UPMNA5QHGZFGFBJS2UR2RA5",
-    "GAWXQMPNLDLFEIJN4X2K5I62WWAM2244PHN5OUCVOEXLJPULI2CNSFUQ",
-    "GAWXTMJKZ7AB62HR7N7DNF4IAM3WMBKTARDAQZY7VYC2QXJT63I2FPSR",
-    "GAWYEITP75MENBQNGORWJEMCDSG3QJTFU7SGEGPQZTAK2EGAQWC26BAY",
-    "GAWYZHL2EI3YAS6FHI6SEM62KXIFDGFANGZ2OTJX4JK6EDRG5NDG35R6",
-    "GAWZUXEUQUTIC7GTEX3WWYHTAXPRFSMRZG5HQZCNKMZLK4QNWVZLROF5",
-    "GAX26XXALYY6UKAWHUOLLKMQMMJUD5RDFE322YPPN7SVHCYKNBI5BMSA",
-    "GAX2CKO7D4RYQIK4TZOJ3NLDB2U46ENARTTIMQCOOBAEMR3JNY6FPSOQ",
-    "GAX2GLDA4WP4W6U7X6AEZ7MO3LK35V5BOSJ5RAFXAEFW4JF7QJL2CZ3U",
-    "GAX2MY46652A4OY34MALEA45KDBC7XKX3UZA4Z7JZEPDLZXURADKC3NU",
-    "GAX2RTIVMZDSFRK7NG2NJFK7TPYVUUZBBOF5JEQ5W2GZU2WAZZWFBSTS",
-    "GAX2SGMTTTGID2RUYRD6GZDUGD6XD6BEYVPGUXCZW4574NCYEOXFSTNK",
-    "GAX2U233CB5KK4VWQ73VMPQNALVGTUJCFYQK55QVVXOLTTJ27IIFKPXM",
-    "GAX3K3ZYQA5B6RU66A2CQ2NQ6VAWDHIFFVYJSVEKKLXNQDBIJM45LQB4",
-    "GAX3M3YEC5XI4MALASM2AH3CJB45ULY4L4JDJKWBEO6X5DMTVHLRGZFI",
-    "GAX3Q7FHJKN77XZQ4XLJGMDKEDWAXBPBZQITLLKL6VSRZAWSK44GSLVR",
-    "GAX4EI3F56FVK52BQGHNXWNCI7NZ3IS5COHNFUYVDVOXNPSVIP3RNBKV",
-    "GAX4MGRWGECYQMZ4CXW4ZWKROQIKBVDPWJUKPE3C6IN7UTMUFKC7ZB4E",
-    "GAX4MQW5UOFXRQYLSM5XHESZSWSOSBVVOQ3FYWZ5ZDS2KWZ475RBF5CY",
-    "GAX5OBIJDFJ26OVPXPIVH2KALM72JW2HYXHHZWYWAOYFL3BNK575PVSO",
-    "GAX6CKBDW2VQHAL23ZALJURB52N6VZI3SCDAK4NMLUBT4FDTNX6MDAEN",
-    "GAX6EUMHX3S3BSGLJSCPOFKFY7CA6X5MROVVXJXA5IED6TZNX3ORFDMI",
-    "GAX6WZF44VNCQCN77JCHICEYFIKC4KZ74FB4KTRNFYYBIJGOWLEAA36Z",
-    "GAX72NPU65AFLDQER7IL37456VHW6RYTAON62GE5QX6QZSCMWKQUQISO",
-    "GAX74LWXQMITFKLPSUDXY7UPCDS33SAZ6BWACWUZYI2ODALBQCTI5JHK",
-    "GAX7BC3HLZXAME2MPWEGHUUIXOOFN2U4YA7AHLB3KUPP3UH66SUANJVM",
-    "GAX7BOSFHMLKTISXYLGPTBLLH74UDBEEWPJX6KCC63L64PN2UE3HGPKH",
-    "GAX7O7ZHYDRSKIIMKAZC3T3IX536GXY7QYNI42S6F24HDPDF6Y3UM3JK",
-    "GAXA65MA3T7W2WXBLQZIAHSAAEJOIJMWG25RJM2I6AY427S5CISLOIWV",
-    "GAXARPV7BANRIAHK47GOJDKWP7JTKDYVPZQL2PR7QIZE7J6PI7U2J5UU",
-    "GAXASFPNXCV4OKNCY7MAQUBV3HZRTJDEMHBDM4NJXNNY5DODKRXVJED5",
-    "GAXBRNAEAPDHHYDX6VK7BFAYQ3OH2XSZECXHD3SVUIABMFB2SPAWHQ6P",
-    "GAXCEBOFTMOKJFXYH6YQJ72BRBVZY2KUYVR55Y4NZZXG3WT6K74I7JPM",
-    "GAXD4WIQU2CTVYB2OQEIOVBQ7ABPZ4UPVT6BHVZYEEW5WS47F6HRO3PJ",
-    "GAXDDY3EKGA3QFC5CXXPT3HOCO4YWIBG5A7223HQEAJBIODAGCR73SEN",
-    "GAXDTXDV4XFTJJPHAOOXEB4KX473V5DNVWVXBYD33E2DC53YBYQ7ATOM",
-    "GAXE5CXLR3CHG4NGXGT7A4PMNFGCJJBQW44QXMNSV6KNNJZEMCXQ5SGA",
-    "GAXEEJUXW44S6U5OYLYLPYY5NU7VDHYQSHZQIVXRRVWBI7V4E2XNOHNB",
-    "GAXELY4AOIRVONF7V25BUPDNKZYIVT6CWURG7R2I6NQU26IQSQODBVCS",
-    "GAXENTSWB4O6LSQSHPXMGZ7SBJYHLOGQZ25HMCKLF4X5G7ZNYZB62FLR",
-    "GAXETOTE2TJ4TISZQMAWHUSZG6L3K736S6B36XS5FDLOY52UEUMVKLMF",
-    "GAXFDWEJZM5XRHIGBGGJR6LRF6P3R76RS6AXX62PO4AMKLMDHKIR5CHA",
-    "GAXFG63V453EBQTDUAFX3BIH4ECKMXMFVXVTMAP5Y23G54PGSMAVXKCO",
-    "GAXFGAP67GDMZE2HDAUPH4PYK7BEJBJMMYFDNEUYPLXYYAYWUTJ42XEB",
-    "GAXFGD4Q3CZYEOKPOLDESC3E5MHRFTR7WCNIBX2RXZNFO6OKVD5IGMCT",
-    "GAXFHVBVN7SAEMDNAXSQZLXFEIGG6IPGUXVKBVZJLDADSZUDPWVA2I5G",
-    "GAXFIWLPHORE52BM7DZIUVNGR4PHN7EXIOFUWURD2BRZKJRRM7TBXAXQ",
-    "GAXFQOKZVYXOVNESSTLDR6BIZOQBP2AIILPKJRJ43EC4LEQCC2D7436G",
-    "GAXFUIGINL33LGFHVN7E3WGLUQ7X4NCU53BQYGZWURGTEW2DE6ORKXQ6",
-    "GAXFXQ4N43J3DJWKZIB47KU6SG5HZHGKHJEATPYMMDBMPZAA4DMTAB3I",
-    "GAXG7DBATDGDSKUGRZCXHS3EMKFBJU7EA2TWWW4BITALSXJG5XUIVFDA",
-    "GAXGW53AG5KXNA4UQD6J2PS6K2BBRDEW4MJKZMCIOETDZPZGQHN2UVMK",
-    "GAXGWA75UAJLHQCSHEZIT3BAGGCU5MHNQGLUPLG4AOJ7YK4SNPWZ4CMO",
-    "GAXH7K5HEIX7WAFLBSQTH524RDZCCIO4TN7MAT2BAIZYH5XWEQWE63ZZ",
-    "GAXHX3S6XZ7MCA72O2MY267I3KRKHS2OUOBXV66ODF2WHI5YM2ET7BGC",
-    "GAXI2QYTHQC74FN2YFG7EHCF5R2H55A5EX5ZAU6KMAYZK3NWZKZ6PIBM",
-    "GAXI2WKTSYXLMMZWVY77NB4OLEKGDQ4IXKXD4UGXD7IW2XYMBPLW6PRZ",
-    "GAXINAKATSLFNMQ4G3FUTJPVUZRJG5YMDECOJJTSARCJLEUVR7SM2FAX",
-    "GAXINVPCNYOHNNHZAMSXZ5Y2B3YJDPTSBIFPFZ3PUC7GBH5XZDPVWL6K",
-    "GAXISZAU7WIVG4PJEYTCHZYXH6BYDAADQC25S3I5DXF6BDB6IXXB3XOQ",
-    "GAXIYKWPX7FRFFHR7WNQPGNDKWP2DDWQOBUOQUAQDSFEETECZ6W3BBLK",
-    "GAXJ6VBPBJ6ZQPUQUJYPB3CEKPNBPM6SUBCTO2OTAF7SIVZGLMSKU3DB",
-    "GAXJH2UE2CTXNVZBF5M2DS2MH6GZE5SQVBCI2BIAEMT7OSJXUOC2DOGE",
-    "GAXK3OQKIVB5S5LZW5NZOOD3N4TURWXTPKB4LXFIWW2FENHB4QB6YYZJ",
-    "GAXKGQSN2NHNIYYITJ7HQEBCQ6ZXPP3WQMCRHIEAVYFJFUZITZ7347UZ",
-    "GAXKHTSIREE2KCNKB7GBJSWH4IOMYHN4T4HMJJSBXLGQPSTV5DGIGWU2",
-    "GAXKMYAXAYEZ2I6LHOYUUO2GGCJU552TVLEW42GI25XM3I56OZPSIXIN",
-    "GAXKOCH4SKMEKVJR2ECK4FGRGIRIJ7UAI3HPRC6BYOECZB26WJZ3KUST",
-    "GAXL4MJO6KH5BSNNHPC2VHL5ISGUPLU2CWFZRRPC7FGXOFH5SE3BUBD5",
-    "GAXL7LJHRLVN45RJHOZE2GD7XC3JTNMTSIPUVQFYYDMGVU4ZORMR5DTJ",
-    "GAXLU3BZR4CIXQZHVFTU5PVTER7N4GFLLKIUZERO62XX4E5H3HCNRXRP",
-    "GAXM2ZR6W4DRLZ2THIHHVUVS7LWMHOCB6CQWW7GYHE5N7RIQGWJP56UO",
-    "GAXMFHKJLOVZD635PU7DRZBISYOMREIMH2EORZ32QISJAWWZJPC6M2RO",
-    "GAXMOOK65HGCCLFJZ4BTVLQKYEP26LKTURPHAS5RK55XBX4PM5P4GTTI",
-    "GAXMRGUE62NRQGBEBEQIRWASWB5NCRK7MEYKODW65EFJ4UTVOIL6ECUA",
-    "GAXN34XV3YC6PHVWUWX7BFQBID254TICE5M2VGIDG5VTYBGYGP6XAJUE",
-    "GAXN5KIIGIALJFCEU3UUAMY3XSVGHQWVJFBM3KEX7AGZUMALUNV3DRFC",
-    "GAXNIT545DIIDBMLF55F2NOUQY5MEKSD3UWOIUY67CMDQZTRC4UWPUSD",
-    "GAXOCQJOP2ZQDNEQDZNOFEPWZYS6IPEPP3P6M54MCG56O5BZ5F3QALGO",
-    "GAXOMR6SOTNBDAH6LFQR2EZVKJY6YJPXDXWBF2ZOF5JIXUYYZBAKGMUA",
-    "GAXOQHCE4WXAZDD6V7355NAL4WPMDUPRSKDTXCD7VCG2HBDTEITTHMD3",
-    "GAXP7EKPQY3YWKDCHXXBAKOAMENN7GXAWTFE44B2LOSPCQIARNV6PI52",
-    "GAXPBKSFJWNPQUNKCD24W7NP6S5SRQP7Y7RHSCHB4NJFMPVL3ROYCUXX",
-    "GAXPEBIJIHADDZING63CAF6IT76CFMO6WQBVZWWZMXDK3C64RIST63H4",
-    "GAXPTJT7YBKZUM7V5AQGBLZZMODOUPDQFYSLIUYIORGHRJZEIWDF3JMA",
-    "GAXQ7JL45LGE2DUXCUDBSZAF35NXHHU4YO553PZPL4AD4GIRVX5WPFEE",
-    "GAXQNZ4TOXRNIBNKA5G7F2SPAW6ZSFHPIRMP3VWOUKDMY3QF6VKTELYG",
-    "GAXQRNLFWZLWJQBYQKFCSLLMT33AAH5ZOUNMAJEHFHRLU5PMYJOJSHOT",
-    "GAXR243LVHIDVMQCUX2IH7DPQZAE6LWUBLBC67XR6JFIO3E6PQTPNNVZ",
-    "GAXTE73QTKYFNQVJDPY6SUPTMAOV2QQ3DH63K34ZBAPPZ5OVQLWGWL6Y",
-    "GAXTP2F6UJM3PCFDZMHG73XE2F34QQPTVBDFN6S4CN73N742F5ZFPI5E",
-    "GAXTULK3Y5TJXCR4K2NABTVESDK57NCILYEVPQRVYXX6HEUA25K7MTUL",
-    "GAXTW4URQY6ZZQ2ILWHGD7AKIEAVVS4J6JUFT5TVTKQVHZOR7GPQQ347",
-    "GAXU6PM52BVCJXYOVGSRVDRLI5UC6TUJUKVZQXCXEPI6P5ZW7GZYCT34",
-    "GAXUAIEKMQB733JYQFY33YCPO2TO5KNI34I7GN4PPAUYAHWO2JE6RQMK",
-    "GAXUFJNAJBAJTPV42RIY726R5T24BVQYJ6FUNF5ABMTIG6F7GAGNXNEI",
-    "GAXUIDTXWDTR2JTXYDN55CEJU7XHYKLUUTRTPOCJMX7D3LVAOWOV3AKN",
-    "GAXUWQ4IREL2ZYKPQHGMNXCXUHCYX7LC2SZA2HMBZE24QKSNKZEZARBP",
-    "GAXV2KKAIIVY5452THJXNTZQRGZD2HIV6Q6ZVPKULZWXOJKEJEES5M3F",
-    "GAXVAKL7ZTFT3D6EY3H56QQNJZMLUF4CCX42J733LK2RWVRBJSPVJJQY",
-    "GAXVRYYLGWOKPLYBTHR6QDRTHK47NLUHJ7LGFXPVGTFYO27A7MBUFXRC",
-    "GAXVSQGKI5JCPKLVTUSBUZEOR5KS2WP7MPADA4SQBBB5EE6EUOF3ZATT",
-    "GAXVTBZY5IQY63XJ42ABCRS42XAFUXTVOTE4LUWDKY5RQHT2AXASZBUK",
-    "GAXXC2L5YZUH7F2MFJA5XO5CFTZBFOAQUY2G45BZKWOTU4BB7CZGE33M",
-    "GAXXN5SVNJK7CFDKH7S7BS2N434L6LJMU5A7XBZNBQGFBF3ON4FULISO",
-    "GAXY2PDSIDKKYUFE6TS7UVHB2JEVY62BZYWNVP5QZDQWZBA7OFUQTPXZ",
-    "GAXYMU35LDUPK7Y6227OA3XHAW6ZISRZ7VGXDTPDMWLT2RE3R4EYVOKW",
-    "GAY242UVPEQRP5XDBIJN4IPCOCPNZV5DKPOI7XTJGDNMSR4DBPL2BBNE",
-    "GAY37XWJZKA4EVASBCWYHQQWU5OKLYGTWKBHSWNJS5X5UVTVOEBZ6JHZ",
-    "GAY4TSMG4TP2IZV4VGFCLVFDXOHGLP7MRILTPFJBFG76PQ76Y6LVT3MT",
-    "GAY5J4GAS3TGHY257URPV3T5V5MSV37I2ZVO33DAEU2LXC2BDJPK7LDW",
-    "GAY5TXSUOFFOVPVQPOE7ZJNLFVGUWI6TJD7XRIUEE6DMYUDFCX73WBTC",
-    "GAY6LGJIUB6YOLBQ3NTYXQNRZCXVNTCTHBFXO3Z7AAKYACOPYFMDVS2L",
-    "GAY6OJIWDY3P4KQCXNBWBA5PSTGA5S7H7XFEG2CU2NN2P5NLZ4NCFNW7",
-    "GAY6VZDYBHQ5B2L6CB4OW7Q3HZFC7LD4WPVVDQTS45AVRZJJJBHJWWYC",
-    "GAY7DVJMC5TR2LLYZI2L537F7BHS2EMOTVDYSLATJ36GTNIROHDTUGXF",
-    "GAY7GGQ5FA7NDMW4LBJYN3YF5A3XCVTV325QWNK3CVO4NGGPQYSS5FGQ",
-    "GAYA3PS3MOSNEQDATYEODU4AVSQCI24TEHLOA6CZDDLHSFZ5JU42PYEH",
-    "GAYBDEPTYNWAJ23DI4NFT6MD6N27ENFHCBQGCPDM5K3EJJVPKQALUXM6",
-    "GAYBFN3SZL3L5VWW44RMSWDL3FUUI4I3NAX5JAQA32TUSNM2XK6TROVJ",
-    "GAYBKJHKXJ7AAFKC7SIZL2PZVSGL7QRDS3DVZVGQ44JTQTU2YYG2OJOV",
-    "GAYBT3E3PO3JMHKPBBU2EIYYYBCJMBRP2KQTCC7UDRAYUBMCK2XBIOTS",
-    "GAYCA6ZELLZJFR2B3SIQWUPOVVWBRH75QI3RWQWWGYH45B6HOY66GKN6",
-    "GAYCF7SIHHGIQANCRKWUVV5AFWGXG5MPUKGLFWN3AHSX4M5CKUWXQ3DB",
-    "GAYCGWQ5Z4O2W4EY5NZO2AUCFDYYRS3O6VMQVFQTIV6AYD37XRBYNSG6",
-    "GAYCXEWSG7YKAKP7R6DAOJGSIKPBSOBNVSYAXW73MZQLST2K55ZJROGL",
-    "GAYDALTIAMF6KSYOTNDX6MAO6UOJ5KONGRI4F2KG5YHDQDCRSLISUPLH",
-    "GAYDBWHI6EYG4ZGF5SAMXNTFBX2TLBLQ5AEE66LCAW6GMS7KRTQPGOLD",
-    "GAYDNQ2D73BESG2DMJKMEBIYTEKFVATACN5YV263YNNU6BMCN6WRRXUM",
-    "GAYDUJKJKAIB2BHJHDTBTD5Z6R77HGL3BCA3CCB6JX3NVO37CSLEJIZM",
-    "GAYEHT2PTAGJBGLU35UFHWINSTP267TATLCPLCMWXGANC4GN2PTEDT4F",
-    "GAYF6RF3TU2SB4ET53NFVKFAJTQOV4UPLLD2DYE7PSIUO2T6FO2IU6HY",
-    "GAYFOEO7G3EWMM5T3MIXRR437GRWIOUG2NGUY2XSXH3L5N24SXQOXVIT",
-    "GAYGHNTUAFYLGHXJBA2IHTH6P7E7UAQP77SS3FY63ONYTPOXGG7FCW3K",
-    "GAYGPIJWQVEQ7OR24QRGG7PFP5YEZWOL4ZDPACF6A32L6C32QYDY4SLB",
-    "GAYGTJHUOWZXANTWJGV3EOMKEACKADTEYU56EQR2YUHSW2HJ4V5YBRIJ",
-    "GAYHEVWDFFKPTTDB46Q6NTHYYLTRFUH754VQDMXA74IU6HMG7AGE6XLM",
-    "GAYHHEQZAA3TZRAOQTXDNGCY673CC724TIU3YVQBWM6GVGBOMK4RQGPN",
-    "GAYHW2TQG52HNELH5UVTB3QTKNZVJAEZEHHNPYXB3TENRLIJJ7PS7YG2",
-    "GAYIDIFYO6Y6Z5IHQFJZ5BBZT6AEKJOITV7E6KR6HURZQFZ2CZ7HHBAR",
-    "GAYJ67MFOB5VW44YYI2EML6BC33SAVXRLTBBUZR7SAH6O4NRIJKTIJTP",
-    "GAYJ6PXBKENJXNSTZ2DPFJ3DCRFH5CL2OE6C267YTAOPTYDIJCS66NTG",
-    "GAYJPWH2DUDJUH6TLNASJVF4CL35WT2CSPNTZPKXBTPI6VC5YGMAVERW",
-    "GAYK7DFRDY3Z5XNLKDJGZL7AU52AGFIEJAF57HZG5VFYEFLFFG5AJZKL",
-    "GAYKU4AFZM5VSMRHOKQRBPSODKBHVWHK2PCWJ5AID4QRO6USTNX4H6DK",
-    "GAYKW465FRGOKSGFOHNYZGDTKMRX5G4HS5JU3I2MYMLL6ZEL75KML2JD",
-    "GAYL452SIJC34MGBEXUOK3OKRKFU42MRAHRRLRUF3BHEROFPIBLSHJSF",
-    "GAYLVZK2SXPIDATPCFH7TEGDBIYUVNBETR4ZCY7Z6HVRRWL7LOSU5CM7",
-    "GAYM7ZBOBIVATKBVUHFGDRZM6QT62C657HYQB2WJWOADQRGR45JOP5HD",
-    "GAYMF4OEHO747WQBXU7ZK33TKYCXYGWQ76IVQJ3TFKXEM4JRAJOIJJCE",
-    "GAYMU7JC5J4JFQX3HXHDDAWUBYF77XWYHWVTQGIBA37T3SVWFN66A7PX",
-    "GAYMX3Z36IBAFDVWQ2HDZD4Z5PJKXBYT24IKFL5QUFRMDXE27JUI4OJJ",
-    "GAYNL4WBP4RZZ4GMTUFGDN4PSUULEPSUKATINJZTJZFAYKV2673AA3HR",
-    "GAYNOJ2ZN3ZBFIZDMPTYRA4CDSLJIC4MM2G66ZEWSGHKTVXFGCIYIELD",
-    "GAYNORWIAZTODHZEJOVSVKXLWUL6UYQ342CW4MVERIVU7QPUAMPTVGAS",
-    "GAYNUHUWWHIBNUG4CWN2UCKVIIORNAKZT4MZN675M2EFQVQVM6DMK7FD",
-    "GAYNVSNPQMA5RWO2RBXH6UD5FSMCYC7YVMCCB7OQJ53PUUNIRPF5D6HY",
-    "GAYO7VPKVB5BKD2PSUYDT5VEEN2IWBFMOMRUQX3WONNWSDBDAKOQAPQW",
-    "GAYOGPVKLLMOJL5X7QETY7N47CNR44MKD4HPAQWVGOEU3ZTQDKRVGXIJ",
-    "GAYOH2NKSH5LF3QMJJMP2SZVZ2NMMVASYZEXKBG6UP23ZNAQFNH4LIAA",
-    "GAYOHQZSUTLQ5VPO4XEUYCZRDHROSE3WMFFMYWZH4YWYCQKDF2ZNIARA",
-    "GAYOR36YLPXSR22WIJFQFVYFWNJEZVDTP4I7XEEAZM6T2GKGWYMXUYLD",
-    "GAYOVIAV3DFHW7WVO6AJGQXZPZYSYHAPXCDRS5BMEOLYCMETJZDIY54I",
-    "GAYOWLZF4DIXPRNONVKJFFUZGXOPXLTB4BKPHVUMBNI54ZG7RAW4BLUE",
-    "GAYPCN7MUCYWJDEVBV4AEAEGXJF7FXO3QYE2E4WPRJK6WPMGMWDKIVDJ",
-    "GAYPTPKKTVIHXAFTEWF6HLHC3HGA2KUNJLMVNVRBJNZMWLDJK2BMKTOP",
-    "GAYQ44DCYTIVIJ3RNWZSKCJ5JPVLNT47B6CXXA7Q3YZOSQP2BFDUIFUA",
-    "GAYQOB6O5HCQ56IXJM4D6YS35O65IX4MDOFIVJ7RRRRD7LHC7XULH5WU",
-    "GAYQR46GB27OSFESYZYJDHEZU7L65LDSJKKB2NCIRAWUFVGFBCBVMCQ7",
-    "GAYR5M727PJJX6MVDQ5XC5GVGZBK4IXUZNOSDAK2E3RERTGZSULNOEKE",
-    "GAYRDIY5WCRBQ2OBUK3B7NLZG7FV7HSU4WZ62Z6GGHSPYP4K5V4GGG6M",
-    "GAYRHRXF2Z5PCWQDYA7WDBDUZFUPQR3NLQYIRFKARGKS3HVZA2EC6UIX",
-    "GAYRPGWW34LLQH3MLYB2723K3IQ7ETRJ2UVKPCPVB4VTE537OB6CHKBP",
-    "GAYRPTGWUP6Y55CBYFJWKGQSDBB7K3NPZZROZ2O37RJWDYFY4KUHHMEA",
-    "GAYRSCHYDIOIHTGFY6PASUGJPGEH3H4KEZ7264J2DLJFCP7EV22SHFOS",
-    "GAYRW232G3BZNOHTNTHI5BO4UDUY5M3UETJUS3QRP6IB3XKKYC2JCBDC",
-    "GAYSEOIEHE4DBGF2NRPKTXKNPMXVUXV63E7XFN5LK3KMDAB7XNNCMETL",
-    "GAYTRZ2P4GVGX3PO257GQFC6RI75YVF67NYVR6K7ECRYAXAZX6HRUTSX",
-    "GAYTUMOK7OAAHHF4JEUDJE3LEXQEQUDYMTQS2VGTSGJZSVJV7ZFSES5W",
-    "GAYTUNWAYMPPYNCICVFM7O7424FR5J3Q6E4ROI2F3GNEJKRIKLPCTXMR",
-    "GAYTY4LZU6OJN2CNFXXJ5T5E4HBRCV66K2CG4VW3KDNSE3LP3ASUETVH",
-    "GAYUL4ZKZZJJ52OM53HZD2FX4PN5ZKNPXIETU74WJZAG2QR3CV3H4WCZ",
-    "GAYUT2N5QXLKCEGDZ2FI25X2ZLN6ZTCYGN3OZFBLPXLSZ4GOIW2GII7I",
-    "GAYUWL4SGYMRWPGDUH3MV434LYB6EFHKUU57BW6QCZKOLRHKXOOQSF3F",
-    "GAYV4S34VUJFQNOF44WU3BPCNOEW3AJR7OZF2DUTF4X6FRCYP3OINDUS",
-    "GAYVEOFUL42BHPBEACGINJK4TZR6DZHCHWVZBW6DVMF2OTSDTJGGXLIA",
-    "GAYVIWT2ADRXKBFV3DYXZK7JZ5WEYRM7HLJCMR4RFUYWCGNZVQX6QI7S",
-    "GAYWEGFBQFZJCVGACSCXTFNDGRKY7PVSMRJH76GJBWGGTVJXIVNDUV34",
-    "GAYWPIFMNADIHNJ7E6PPH3AOWCHKFCXJYLXTGH2HQ66PNLNDEMDVH2JW",
-    "GAYWT6MART3BRXNSY7EXYQDZEJTHLM3LXTITDU2TGB363G4DJD2QNPDR",
-    "GAYXIVN5YIGVDBODPSN23RAEONO6GYMJIPA6ATYJZ7VAQVUSYEJGPSBX",
-    "GAYXJKPPRYOH3V5GU53KZRRKOXVIZY37XYB3VRC5OECXGLRPVAK3CFKS",
-    "GAYXLTXIH3MLZJMBLO7YZKZNAT4L3CHSYDYCM7DJ5ERESPD2P4RXF7P4",
-    "GAYXQJJRGHK54ZI23UMEUZ5OEXWYESSW72WQJCDP22HENP67L2B433PK",
-    "GAYXUQET7K3YJZQH5W3RWHB7SOIPGPOG5DERJY4SNRKNXYQBAK7DPRZC",
-    "GAYYLVX5ABTJ6MQTFINTF4X2W7WNE55P4Q5T4TEJMW7UV7VSX2DBWENF",
-    "GAYYOOBCRVESUFGTKJ5ZOTY4UJ6M2GQO2MYELYZMBVMA2DQ3QXYJ7G65",
-    "GAYYYAMBWHZYHD2Y73KIQNXO6LDRY5M3XF4RU63QF7BSYTZOHDPQQURM",
-    "GAYZELDBCUYPYCQW22H74M5FSY6UOHGJB6DNYAG2KBK4FWXQAPLDQTV6",
-    "GAYZFL24GNGSLYZVM3J36QTE5YX3RJKIRERWKKG6ZZUUOADUCVU7MBQ3",
-    "GAYZK76B5XBNLGTASNXHRCDBMRUBUXZFHKVTOFOGGBR6NT7DFIOBP5OG",
-    "GAYZKOXJJMWOCXHDIEL5NZXNIWGA5NIUXNYZKZLXIKL2CQEZSQG6KKHE",
-    "GAYZQ2MXJPJ6D6JOG5EQBXGKBSYR67NXC4FJFKHESIV4OXAY5YOPSJ3L",
-    "GAYZSFEBKGKQDWM2A76OPZ5EDBXNJYR2YYL6WAKURWORXIBM6VD7W6CL",
-    "GAZ2TRU4URIAXQO45GHSNR36DL2M2ZSTBBNNGPK3BYE6YYIU75ZH5HMS",
-    "GAZ2WGLADIYW5NSXLBMXNCSFOUCLEOAAGOZB23ZLMN77Q2ESKMSCJZWH",
-    "GAZ3PO2WJL6MMGI6LZ33WB3X4R7KFWRQ3JJG2OZDQPD3LJTKXJGIHSF6",
-    "GAZ46NPWDEWXXDNYSIMLE7JYMPYRCW64CH4CQKNIG5Z3RDSS6CRNFDPF",
-    "GAZ477DF3OFXFMAXKKLMPLKDRKDLRMFYV6GSJESQOHPTHMHFJWWRKW3Q",
-    "GAZ4DF75Z6QOQX4ZNNN6R3PNXAWLLHZEWZMOU4O7BMFQYOJZGQSQWXRP",
-    "GAZ4E7JBSMTNOZEFL7HQQFQWZ6K625FTBZBAW5LCDKA56Z7KAXCHELON",
-    "GAZ4EK6J2YMW57CT5ADHXJJ65D45EFZIVMG7H7GYTWDR2IUBWHNRVIMO",
-    "GAZ4YL4Q4UDSDVWQMHMYJUR22IERYSUBBBV44W53QA72NSFBBXCEFZPG",
-    "GAZ5SRRP46QNMIB6ZXQGXNMQWXNQCGFOOS5AZNB3Y3JCI5FVBTDM672F",
-    "GAZ5T72T4RL3QIBMYRBXVTZBAROT4CE253TCQ3OOGU25YGAC7R2DXXTB",
-    "GAZ6HY4IAPI6P4OJJFGUOAR6GKLJGDDDCTLK7HMNTAIZMJASULU2KLN5",
-    "GAZ6LCM35JHSP7MVXLLTY62UDI7ASQJXNLLCB6NMJSRM32R7P3DEHH7E",
-    "GAZ6TGJBF7VSAWNKRZUYA6QF4RZFBB62M2U52V5SLVF54WTZC3AR7ILW",
-    "GAZ74UNT5WO62UK2MN735INWOYR73TYGIFYLHEQQQMZEYIUKJID6WQL2",
-    "GAZ7CQ2GMWBC5NODYYH7XVD5NKJYNLUMYSK4PRU3X65NLEY6BANORUXQ",
-    "GAZ7O7CUOUGXJYBPNYTB6NCPMX5DQLJW3UFE5XYKRSUI2NFJNDQ7T6FI",
-    "GAZAAR55P6CH3FSY444KXTS646PDNKOQQQLXIV46TONOLLYUXTVWCORD",
-    "GAZAH5QNRZMSXJPFZ3CIHIITBXFREEQ7HKZJSXUS5QWEZPJ5GFINFQ2N",
-    "GAZAN4YSC652TAUR4RFDMTVRQ364IC2IMSUTQXJC5JVQ57TBVGQGDI7Z",
-    "GAZAXRVMT6QSUB52LDKESTCZ6BVN5ESZVBJEMY7F7JTGNQ2YP5N6RA3S",
-    "GAZBF6YGJG76OXPRHJEYODW26W2Y5RBZRZF6SXJCSIF7NTHEOC22GFM2",
-    "GAZBNETYIUO2DRDAWWY3IUS7CFCITICG23ZDIEUDRJ7WHVXR56VT2CXM",
-    "GAZBWIDNCCOTWYP5LNZZET2JOP7OHPCBJZIYL2G6H35PU36SHDKS3R3W",
-    "GAZBXDQDPW7IEK5T7ZZFXRWSY76DYW4DJV6F65FNNSHI64WQ52LVS2OP",
-    "GAZC4KMYTZP44AGTMR3JWUZTZXL2VGMLKUQXLTH4DRVZYDKFPS4XHAST",
-    "GAZC7PEPYVFTXBOVGH2RBKVQWMHQC4FAZ6OIFDZKKIYXLWCHOOOPCW3B",
-    "GAZCWCJNZI5WMVD3QAYZBKPLILO5N6RVDQXTQ3HPKPNVYQ3XBGAO3GR7",
-    "GAZCWKFCDQ35EXGOSVIB6THB62CBXQGZDUJMSTCT6QRJS4H5YWVN6VTC",
-    "GAZEFO44UJLSR42AC7CYYG4WLXCXUMSNS6P26OIG6EDGAQXK7X2CUWRO",
-    "GAZEX2USUBMMWFRZFS77VDJYXUFLXI4ZGFPWX6TBNZCSTEQWNLFZMXFF",
-    "GAZEYNBR4KTHIUN66A3IQ6A7PKBSYCFYHYWLUJRDEJYLGNGGJ4BJLGXO",
-    "GAZF7OCWXHTJLTHJIVVFYCZNU6TTNOIVVF5DZOYJYGMKSEEWTTM4BW7F",
-    "GAZFGJGR4GLGPKRWGUBJS6IOXOHOVA7I5NSLH3YQS62S2ZT22P6CTQOB",
-    "GAZFGKTFZNWOEDTWKCJN42DWMR7V226ZXX6FRUMPBZ2URNCJHTOY6LA3",
-    "GAZFQG645FGEYXDXVU6LGXBBZ66FW3LYXHWVEJK4OZ5BCY5EGDFJLF6A",
-    "GAZGD7MV4WVSRZQNLJULOZG4MNG5M5IXWHPVJPY57W4ABMTUU5EZL7Q4",
-    "GAZGGUDM4QVKYVZ2QZFJ6EDUSFP4KYH7RRY3PDHR62QMOBFW6ZREBTIF",
-    "GAZGIYF4JIUH5N5XCJAG7FIMD7YNBEOUMMFBH3WM3LQ2GV7CBV3FT73B",
-    "GAZGNDAJRR3Z7PWUROI3HBMKZTVDAF2IC4S2UJ3X5OW43AF6TMIARDPM",
-    "GAZGQEVJAM74ZKXYR7MKPMEFATEFXO7YJASAVCDTVGPAJSKHLQDWRSMN",
-    "GAZGR3YNYZFF6R2VXI3EXXCXOGHTHSQZ5E5UIKPJCEBEDHNJLUWIFFIM",
-    "GAZHSW3ASIAPYJP2MIXZUF5LUG5GFD2PBE7LWIWYGT4F6UXQX6AM4CXB",
-    "GAZHYYIVA7VDZCH3OZICCH7FDKEN2BLCVBYE2YHQ5Q5EZ5MSSGK5XWAY",
-    "GAZIIIOJTL2PGXH7FPTQ7NK6TW6ZTYA466BLHNWLAFMVCKSCQC3DUCBR",
-    "GAZIPUGOENQS6Y54WOYMUN2M242ZLWEEVQPFDQZ4ZBBLWDEY3QIGPCDZ",
-    "GAZIQCC2F6MCTB7V24Q2TPECEE3NJ6YJWVXHNJ2BTVDXPVSZGQYYDPXY",
-    "GAZIUNAH7OSPGCQE7KLD2YRAC5ZTTZ6SBPAWRJMG3BSBFW54H3KWPM6N",
-    "GAZJCYKI67Y4BV3KDA7XHY24UP36V3FUYO3AF2DJFG4GBLIINVYFOFSY",
-    "GAZJDCTZRSBEPBYQINGO5VYFBMAKFPKLFCA4ORTZGBZCZIKDDFM2YV6M",
-    "GAZKDOTFBK6FSII3TGWNLOORBEIR3ALGIAFUTXGBZXEKVQSIW6N4D4DA",
-    "GAZLEWDXNB5KSA2ZGF267TY3TSUA4K4O36Y6X2FZBHWCDIKTZQUFONH6",
-    "GAZM2RKAGNUY5SQM6BXQHZZE2OLMQTBI3FFVNN4EGKLPIOJR2UVRSPCX",
-    "GAZM5MEN6EPEBWW7BQWJIRX2YZE35RMSKPPZJOZ4UPJOE36HPBXHL3AS",
-    "GAZMAUOF5TQEASHTGXZ7WXHNPGSIJPGS4Z5D7INOOH2ZCAMFUUFXCYVF",
-    "GAZMEQPPSQQS36CHBOF6AQZLZQEPCI5DAL4OGVZSBPVOC27K6XBZ4K2J",
-    "GAZMMRZQ6WZHML43UAPG6HZ74LRJX62PFFQEABXVKWQGLZ3YSXOAGBTC",
-    "GAZMQD7MDQ4BKC7WX7NGOGJI7VLTD6Y2RCYLXI52AG7WT3C3VILGSTH7",
-    "GAZMW4CEM4BCBNGG6Y54UBCMVFC2SLO26KGFAFKPBHUSNOTTUFRSMF3I",
-    "GAZMX7SBAZGISIYMBNHHPFS56VDE6DVVT5SKKBX7NEAX5Z3VWLSSGOUJ",
-    "GAZN6TT6KP2IE44RFSWGTJTKMCJOT2LTN6XA2AFPWCYI6ND5GAGBOEIJ",
-    "GAZNEQXLG4D5PQYLWOLQILMEYBMTNDHPIXGGQTNXSWRKPFNMYQUB7XC4",
-    "GAZNKND4MELYGKT7VKD7I7AJRESNZSINX4XMSEFFZOL4VMBA35MSC2SC",
-    "GAZNNGNOIG2ZSLP4PRLHGJ5U7L7YR2EFWOYOQSL2MX4IIXEUG6A4KYMM",
-    "GAZNO7RQTZCU6ZPZFGQEQH543CJPUFFPNG4SFGEKTVUFFWDPMSW2HX5W",
-    "GAZNYEZ3AMGCJM2XGGNNIQV5DHHZ3MRYO25NSRTBZTRRRP5SLCW4PBTC",
-    "GAZO7UYOMK6A6LPCXVQW2BXKMLQQ34EFKVAO2K7ZOC5QMW3DLKJWVRXJ",
-    "GAZOKOWKZMKJVHCBESQ23FPNMHKZHBYK47IS3ZN2CIGL7IUNO3NRQM2L",
-    "GAZOPZZ6CBOEBYIKBOC3P5653UX4PKJTMGU2BTPJ5DG7MGQI3FW5PMPG",
-    "GAZOUDYDV464RZYLRYIBZB4XASNKDFWFM6BU36COROPRAC2PPCVRNXVA",
-    "GAZP45SUDNBAI7T6WL35B6UMTUH2JJJWUSY4EGJCDJYEJDEPIGTS7OKL",
-    "GAZPEVOJKD7RUUJEVSOZHCAP6QE3M3O6XM5W6F3H7CEWMDWIFCCRYNYC",
-    "GAZPQGGW34XME32MOBFHORCGO3AW3CKKAAFIVIJVRK445JCSYQQOK23O",
-    "GAZQ6KQLOP5CFM7SONTNDFYS4DE77UOTJOBRFHUFP4BB2XZ5IN73FQUN",
-    "GAZQXSEYS2FCXMYNSL6W2QK2MZ6ZVGCDV7FABDVDDDNTMV3Y3V5ZZA7F",
-    "GAZRA5UJJIRRZIG3XSYHL63FY2UKAHZUEFF3MALYQ6AEKUEIVW5X27ZS",
-    "GAZRC2H7MCCL4CJFHEDSNK4OTRALCYUHILWN75NVLURY6K543OBFNFZF",
-    "GAZRKCHN4EIM7O53IM7J6UEPWXH72O55OQ7TSX5VPAM6D2KLTRBO5FOS",
-    "GAZRS6AOCEBAOIHRERAR66YDO75VRNXI7LDQ45SA5YIZ3OE66HQGNMUN",
-    "GAZRWHH3NOTCF6WALJ5BND3DVOYP2FGZQTRGHVFX5GJLZMVSZZQUAD5U",
-    "GAZRXJVJEAT6O7BCVOEMMQE2ZMWLOIG4B2BT5OCKGP5ITHO6IJ2X63JM",
-    "GAZS3HZHS5GR2WDME2CZ4NNTBGONPCHPAUKVSNP6A4UJMQXMFH526T3G",
-    "GAZS3JS7BOVE3MODMNOMNNIVSLLLHFXOUE6CMTSP2GVK5AP3WMCI6UAR",
-    "GAZS7DNZHSFP3S36SQGKEUX64TYXQUH3NPL5KCLAFMCXOUNCQPSFXJLQ",
-    "GAZSDVVZNLSEX3O7AR3TUWWIXECBM55POA4H5WBFUHNND6IGY7NYT5FS",
-    "GAZSJVV5WC4XTMJTYHOHLV47IDE376RLCKFUBPOLO532XLVI7NSYJURA",
-    "GAZSZXX4SGNWRRBFHAWIAKJON3KXMKCXE5R4GDYE5DSCPRK5RDVUNBRI",
-    "GAZTG5Q2XBNPNCVC7QJF6FRP4BWLU22ZZQWTY4CL6OKLH656V5VKDEDG",
-    "GAZTJ73ON4WNNVCULVW6JRAPGRNHRTYEZQE55UW5DJB5DP7XFESHXBMO",
-    "GAZU4BOS6OGE5XKN63PKBR73RRX7JVPOU5YUBLFACXTREJTHNOOH2PVH",
-    "GAZUAZVKHPOGAMZUAOBXLQOQT4FSF4PVL2KRP4H7JLM7DVVJ5EGVAQUA",
-    "GAZUSG7VE2ZC6DRM35RTOOZANYSDYUKAHFIDJPBPZXNQHS4IZKIUYBTC",
-    "GAZVTPGZCFJUNOOVC6WCBD6JPPKLFUWIYP7UPTGSXVQEKQUS5QQ2LYX3",
-    "GAZW2VEBYCNO3KR5EKZYSLXYDNB6IFV6LNB3MHJDHV4NLJTW4FACGHDY",
-    "GAZWGG6RUJW5E7O33VCY2N2PD5F574YT3DRMPALWSL3DVV2VWLMV6Y6M",
-    "GAZWPXMGDF4UQNKFDQVIWRMUAEUTLKDXZMJRYTFHFFVLBI25JKEY5XLM",
-    "GAZWU23IS2X7ARG7ITNKTJYMJ6QXGYTKHMSRHJJNNW7TPUQIEW6YUQYQ",
-    "GAZX5676VRBU35S4V3EROZOSYULBTZZXXMJRLKT2YYXDL4GUP3QW6ZD7",
-    "GAZXBC22OT4MVQKZAXF3B47Q7K452IG6YD65KMBYES7FYDTCZKL4PROJ",
-    "GAZXFKSX7M5RREZDDW7SVR6WJYN6MD6S5F2SNF7QMQV2YZMSAVIXVJL7",
-    "GAZXVBIT7SJGUM7SLWSGM4KRZY6CQBM5C56LHUX6QIRQDARFCPQTUCFD",
-    "GAZXZZMJE36JFCBL42V4SIET2KUNADO2CEEGHVJQ7JJLB4NTSNRSVYFM",
-    "GAZYJTONALB34MCDZ76NJDMXMD5KLLEDVPZHOLNT2KSQKBMJ4H5QRCL4",
-    "GAZYMFD7VK67O7KIL2KSX3P73HBNEFZRDKC3AIHXNZDALZEPHBEBU3M4",
-    "GAZYMXCRS6J4SVMN3YMLGFIMC3HK4MIJD3ULQXXN6H4NAQJXK5OGIDFA",
-    "GAZYN3ERULGATXSGDD32FFUGZ4EIRRIVJSBRIR4UX33NJCDE23UUUYED",
-    "GAZYY2WMTLNRNWINVXOFBEYHB4ES2S3TXEUSMAT7ZNFSUWA6SFR7GYIX",
-    "GAZZ7CIG5U4PBQWBPAMABZTT454TA2H3GQH7ER7VXWKEL4DCS3PDDUHZ",
-    "GAZZIPUA2QKU4HCHANRU33WY7AZG462KVX2WBPA7M2B3ZO22WCTPU5LD",
-    "GAZZJKBFHP42Y5GEKYL4VACMZ5ZGPCZDCOYYZP7NC5HPZBNZ4XEGS7MQ",
-    "GAZZLR23PLR6EJTJRXIDPW3KQXH4FGIULGPVMJVNCNYOCOORFBQZ2V2T",
-    "GAZZOCRSTLI3LNWA6WDXTMMUKOAFBMWQVL26BHB7HVQGDURJIBATGBNB",
-    "GB226SEQ6DQHRMHYHE2DJUHQYY6ULADGXRVR6H4DV4E2WA6L3BF4JQ3G",
-    "GB22IULJES7G4IVAX7CDKZOWIMYUAK7SQAMPNCT3MJNO7BZUNLN5CR4Q",
-    "GB22JAZKZY6ZTDBUNJJCALW34YJFOLI2PUII4OFOD3VD52BBY3N6EJWL",
-    "GB22OPTNJMVAJNKNH6ORNZYJXL3AKIJMFIVBPTWE6BXTFJXBV56H4T3X",
-    "GB22TYXHEGREHKT5QVC3I6TUR32LNTRDU5EONX22PUX4CDNT5HHW34Y4",
-    "GB22YTOM3KYT6ERUFV6KXSDX5IPUSIKTRRTOMR2KZ4G6EDM2UMTD4JDY",
-    "GB232BUCHHWKTKVFANNTN6KTHNU2C353JO3MSCCOGO6RELVCYAHTEXLM",
-    "GB235GPKNJGWB2E7QG564ZNVV3YOHAZE76JC7NCOPJUUGWR4F4XWLYZS",
-    "GB23QQFTETVYOFQDSGDSQFAQZ7R64EGTEAAPV3DVIVJ7GIGSOKH5YETX",
-    "GB244ANA6HRK5IFJJQDGR6XN6M45FHMCZSXSR3TQZDRQBNQOQ7HYUUOQ",
-    "GB24DLS7RTG4MJPRXRNBFAFQ26XKW3EKSN66A2XXKDYZNVYN5A6YERCO",
-    "GB24O4INLDJHAOO5CIE7PNTJUTSPU2YWXPWQIIKYBXVYUINHMILGI3XD",
-    "GB25GHMU7FLZXAPF2XXTDRY4VBLPJ3643TZBOJFEYBSDD7HTTQXBU346",
-    "GB25LNNTQK5AUSEMT4LGQJCAP6IU4VLNQOUSMW4BDCVS7PZY2PWA6IQE",
-    "GB25VVDVJYNANAXEB63TYW25NA2NAHNMLS3BLHHUS7CV72BBF7HUYBTC",
-    "GB25VVP2WOQCZDK6KANAIIPBIXZ2KOUCR45Q35VMWKETDSQC7OBRYGAX",
-    "GB266FZKKFRDH3TTDK3LDXX6673PXRQYC67L3TQ4B33PD3ZDS4XMGF3R",
-    "GB26DDI7UPEXWRIOL5PZUQMF66NLMA26LHONL252BBMWOTCJEBTSNEKU",
-    "GB26FXQ6PJTOIIDZZRZDKPC6CWBR45V5UO7FTYL2GYMDKQJZRQ4P7QV5",
-    "GB26GWTFXTF5WSVNNSQNPYEWYJHB73XJIUQPQTWIQCBCCZAMXTBTMMZS",
-    "GB26IFM76GQ5AXQZVSXO2VXC3IP6CSHJASJTWUGQPTQ2KY4QSF5P7V7M",
-    "GB26QVMZA7RMALDMU2R3SFSJ7T5JMWBP3L6GDS3RQIASOU7VTKEKH46J",
-    "GB274TI5TLBJRQVEB3PG2G4E5UXZT4RRVY2HP5PBISCFMNPWU2CJOYEL",
-    "GB27Q2CCHOUQIES5ZNVWMHLC7LUZOLWHMI4FL4B6DSTRMU56NWVXVFVL",
-    "GB2ABXDQMHLCBT4HYUWF5PRCQVB6HXPOSRWYVZIGZT5LQA5DHE6CAGRO",
-    "GB2AC7VYSJM3CZLILEDSM7H4AJGMFETHLZRNMBCI3D4LOWQLCMF3CQMF",
-    "GB2AHH4PLJIG6JHHXTZA2KDRRMNE67276DURMM46EMJ3EW4IUIEHIMWU",
-    "GB2AJHXIZ6JVWK45OBUFNJVBPRQPQD7UJPECJBHVPCTK3LS3CPL4A2KX",
-    "GB2APUVVROZBQKHGT7BFUMBW6DHXHSB43OQDKSGKTEX4RABN4M2NBBTC",
-    "GB2ATCSYFXN43KR5YCZIEZ4GEJA37OVV4ZEUUQDGENE72IJTUK4Q7OIL",
-    "GB2AY527OKHSJ565RCV2JKEUBFJMWFNRFGLT6IVTI3Z3DNE7YJ6BMPTL",
-    "GB2B2S3JKRDVAQ5T7SJPJJA3WHKEXGYUOBYGLH4QUSRBQXLB7HN67D2S",
-    "GB2BKISMMWP7EAFXACSDPSLZRBTYNCL72NIY7K3NSFHPDEWNFTGPAYSR",
-    "GB2BSHQNZ4FJUJYUYO5WAONGLUTDH74PCPIVDTV4XTAZATARV6RVSS6P",
-    "GB2C2ITNVH2WQMB4LYSW53CX63MSTI3INSJXQDRWGPJB3TQGPWMNKKSX",
-    "GB2C3FHY2YCH6KJ3N7Y7M4T3A446JJFJLNAHWM72JSWLFGB6N64R62P5",
-    "GB2CJCM4TOKNMZY4RN34UUWIX5ZAYZXLPMP2RZLGBA5I2SVZKEYLBRU6",
-    "GB2CKMIJMVKCIU4VYTM66RCNAMUSZZLFQT7TKTKU7KCJEIGYT27T66N7",
-    "GB2COW7VVAW4RQPKQBXC5V6JI7LZKX3ME3WRNVXJK35L5BOVCPMPFKEL",
-    "GB2CQXU35S7HVPHR3LPYXJC2N7KF3YYOUQOUFGG75YVBUPDXG5PMPQ4H",
-    "GB2D6H352M7IJU7AT2OAJDK3UNST4EC3IZUYYEQJQCJLLITOSRDGZ4UG",
-    "GB2DSEZSUX56OJDLAWP7IBXNMQ2FUOSTTUTHLFH4RNXWRBUA6CNXPXLC",
-    "GB2DTK7POIW3PKO5BS4PM5PO5VWKDNZZQ2HJ5LKJX2UY25MKMIML4DED",
-    "GB2DTMVRILGSLPNAE6KX7LXN7XHY55F6VSLEIGGYQXVHSDFJMJ3QXIEG",
-    "GB2EEX6QJHAVRCPPL4QY3GU4HY355XVB37SRHFGJQ76TXUPMGVP6FCHN",
-    "GB2EGXSLJQJRVKDPS36CACNWHFY3UE7WSIOOD2L4GTLRZO5NPS4UOVEQ",
-    "GB2EHIFKYN2CJNNISWZ5A3FMLIWNJXIVZOGBQ3K5VBOSL3S2JHEFCV55",
-    "GB2EQEDWM5RKH5TTNRHPFAH6E2CSNFDY44Y3AFAD7QK3WMTFFDTPFDDH",
-    "GB2FOSCGA6QXA75BQKPFY6YDX33YAZCYUGIHTTZNYSWYY7HIDVHULS6N",
-    "GB2FQPRRL32SQQA75B2M2UEJLHRMJN42XYBFD3OP4ITV5FVRE47OHVY3",
-    "GB2G2K6GT426PVYJP2QUGTOFCNHSWDEVPG5WQZHBWRN6FNETXBHXVGS5",
-    "GB2GBN6W6Z55FMUPT2KPFADIBHKH532DZMWVFCGV3EL7S5M7MG3ZRJUE",
-    "GB2GDLOMKSDMRWTCWVCSIN2KMEFMKBV5RIGSA65GG4GMWMPGJWZCJZD2",
-    "GB2GJSZGIFNNU3HEZ5IQ74LBZJXDFFLR6NA5XG43I2VACOJCCKNP4QZU",
-    "GB2GKPU5XDO4M5NB7ELTS4IDGGJTZIONSPH7RRHDOM2EQC7QUSQWCA2Z",
-    "GB2HFDMYRSYVTWQXD67U7SDEPYJLNSAJKI4OXSCQJIWSF7QJIVVG2RKI",
-    "GB2HTJMGFXW7WJSQWNNOJ3YD7IC3RC2NGFWMRI3AEFX7BOZ34DH6KQRG",
-    "GB2HV2RSAFBMXZ6NWLGXW5DQV57B2TP5S27X2NYL2E47PIOZWNBQRVX6",
-    "GB2HZDJGZNTCOL4FFIZTWSOQFUGKBX7RCRY6MTDXNZ5FFDVDC65GCDUK",
-    "GB2IEVFF5DFQXSYYVGGHCZCO2BVIUMF2B6K5DQ2PAZAEQTEELZIH4CI2",
-    "GB2IOLM7FJASUN3TURU6HO3VWA6EKKFFZY5UK23SRJTOHMZOWQJV6PSN",
-    "GB2IVT2RYVPZUAR5TLPGWKGV6Z5WXXZZEHQCBR2M4SY5W3CPMYNSFBXQ",
-    "GB2IZYF4MYPG6PY5ZG63KFIJ2NOPILNAXGHVKBUM7U4S4SADDXT6JIFO",
-    "GB2J2EKYJA4HPDKL6GFHVSO63MXJ5RUDLFH6GOP7XSD74LZ742DY3JNR",
-    "GB2JEO3NBNO6KKLY74KDYAFHANUWYZRUZUAR3O4G76HSF6QFJ2DP32SI",
-    "GB2JNR2ZJKZYJS5FXNGCSVEL77AIEL3F2DMDPQWZTBU4NQIGLL7ZMTLS",
-    "GB2K4KTB3IS5OQOSSFR5G24ETL5CZESN76VUYA76M5FFVVVLL4AY6QNT",
-    "GB2KYZYCXNSEXKLBK2ZMB4SXGBNMLUJQX7GRSMRSFR4KJZ5MVJEXW56D",
-    "GB2L327QKMXE2KM6KOBQWH6XOLSTCGG7MDXEFRT4ZHH35FNSSHI5ETMC",
-    "GB2L43S5TCWT3QSWF62PHD3ELOHI36S5XWUFDESVAN6XZG4C3AB2ALGO",
-    "GB2LMJVO7PSQHVSSJYWQFXGBHFZ7A2BD3GW4JVQ6FCSHKVLC2QFNKAHD",
-    "GB2LSX7PWC4F5AKZ4PIVXJL2I6VVL4IRFBI75ZQ2V2QOCY5FLQRRG2M7",
-    "GB2M2B4G7XLMTOZWVQORF3EQBVTAQATAC7YNZJJEKKWKXW5DKMW7JFD6",
-    "GB2M6KU3BC5MFBPWKTAYHJO7BY6OH5EDW6QCIMWPX34TS6UVS3NRNSTL",
-    "GB2MBQJG3YHXONIUMOQIMI456ZBSV76E7QTVN5FEWGKHQCNMGLGES444",
-    "GB2MLCMRVSISL6X5OAP6CEVZKNDTOL55D6JHSWV4H2M4X36MV2QBFSC5",
-    "GB2NBRD72S23VSQFESAVYBYSVC4GHNWG4BPB4MLJWEWR4ZXNWVMZIZ2E",
-    "GB2NNUXCN5443IOEAC4E3NLDZC4MWRDIOXUL6SPC634AEB656YGXK36Q",
-    "GB2OADEZ2DKNUYYSIIZKOAZP7HM7H7HI6D3EQBOVCGNMCO73SLWUDVIT",
-    "GB2OCHBCGWB4O5MBLO3AWANQUYSIPQ4DKEOAAAZK72GG2L74RCZNPZD4",
-    "GB2OESTA7W3YXHYGEZLSUGFITJWAA3YDIEHVJBKVNWAJI7AFKVEXBXBX",
-    "GB2OFB5DNQPMYW3BMPVLKLX4XEPDSXUBDQZOS2JACOUM7Y4AMRXJRRVW",
-    "GB2OISF5MEOOKJ5X4RI5V63JIOSQDWETQBULUHLOYU5GGPDDI4ZUPXRE",
-    "GB2PBERMSSAUQICX5D2DHFYI3B7TQZ7WHVEUTEWKKC4IZ36IFEMEUUJM",
-    "GB2PI7ZN6NEILO5DRT2RLF324WSVVKAFD3NT3NBYVNPF7B7XZ3K5RXED",
-    "GB2PO3YN3OTZ56C527IRDCQWCHUSLF6Q4TOQ4O4KMFYYWK7NIUY55WL7",
-    "GB2Q4O3WO2IEUQE6NA6COWXXSQWOQCF3COAUONF52YY2Y5S6XBIWPORT",
-    "GB2QIPWKT5ACFGR34PU6E5CSTTKMB3ZJIHMGIBAMH72F52H7R5BTYGXX",
-    "GB2R7E4NKTRFMQG3IF2S5XDAOSP2HLJSATOIRVKNNGFLUCHOIHO2IZ35",
-    "GB2SSICGNERGJMTL4WRXUY6RN32HNG62RSOGTPDGN2V2MYQXBGER7RRO",
-    "GB2SY6DIJ5FXAGPQVE6KNWSANN2P7IYKCIAU4G5VJZHETTEVBVHFTTG2",
-    "GB2T4E4U3ZABT3SJABEI2N3VJNIFJNGOABUOGVDJ7QUE6TIK5G3KWEBA",
-    "GB2TJH45NLMK5WGAJZMB2IW6CYH3XMKFNLAO7MHFDOL47SI3WKKKWTOC",
-    "GB2TLBRAH4JCKU3YPBNYQQ56QT7C45VYSQIFEQJVERK6FXXFCUMTZXDC",
-    "GB2U57K2TLLIIJ5QOM2TZYUTEKLIXWT625AHWOABT642CLEDRL5VJ64X",
-    "GB2UD7DZF6NUHJUC6BLM5IDI57ME3BXLQCNX4RT6436M4Y2ILKYWTCEN",
-    "GB2UMTIXCTHPBS2ZGWGJOUMCCG2SPYP2M7WS7PWCLNFKFDSL57UMSP4P",
-    "GB2UP3HYJF4Z2CUGTEABAYANV4SDC3GPC2EH7LFPBB5P33JTVXFG7CI7",
-    "GB2UPTJ6LSZNMJPQK55TZL6ATGYBBIUGGJGCLTRSNZDBBKD4WHFOPQTZ",
-    "GB2VGPBBJ5TZMOE2BFP4GMIU3NOJPNVHBHQCK2LFX4OWWGOKGEHFUOFP",
-    "GB2VQQ3ADWU2MTYCWXZ2CQZFWTNLTF5GLMUPNXZRMBCQO53NFYH5N7Y4",
-    "GB2VSK3APZMMKVOEOYSCKAOC4K7W5XGQACS4U5G6IPRSNCN3FRADBZP2",
-    "GB2VYM3SCOO5QCUYFFM57VEE4O2XTX2WO7B744MQUAEV63L64GG2ARMY",
-    "GB2WM4IR22SXOR2Z7UDGYADS6Z47FW4RYYXGGQJJU4O4KV7NYXIKPV4P",
-    "GB2X6DZ4CKE3CKGKK2ZUCLXMGORXLRHCTNSTHKPVPX3U35A4YXX5C3MR",
-    "GB2X6RCO2VAXOD7GXE5VJWXOUTYDKPHIAHJSM2CGZJXKZSIHREEP4654",
-    "GB2XD626A4VVVNUPX4LOTWFA4GJUJEDB2WB3MGTVB3KX2LK7G23YGOKE",
-    "GB2XLUJLDLX2ZG6COBMYRR7QWWNJM3F7DNNOPIW7L2P4Z7XG3NIABB4A",
-    "GB2XMUVST4NPN3JCKNIRGYF375HX4SPO4EZSDIQ3LJG2O5YOIJZVOOCE",
-    "GB2XWJX4HTCMMJDXK4X4UNKVIH5W5JLYPY3YNE4FG275Y6TCZHOXM2YP",
-    "GB2Y32F4PXQ7GAEPALSL6ZJMER3DMW7IRCZRYZDYZW4PB6YTHBPJTOMD",
-    "GB2Y5JFWFDX7C2ZD7JPWETH4T533ULMHLOXJI4QTOFCNIXGPA2RFIUN2",
-    "GB2YHOOHLAKEABSIEMXXKLBHHZFQBEYD2BIH6EI7Z6N4DCMJX4R7RFIL",
-    "GB2YIPP77AXWE4MXIKNSZT4P2JIXALY5YPW4FSU2HFSJPD56VUKYPICZ",
-    "GB2YSZPRE4CVCGGUBS25RBLCFPA6AMQM45LMQTWD5DKROWMUQH7RZQEB",
-    "GB2Z42DRDOLLRLH62JFM3EC35DHD2KPETP6KKMX7EYKLFZEUXGGXG7L6",
-    "GB2ZJCXX74AHTGXJX52WKX7D52ZS4OGSQRLQLEATACWSA5QLMVKVQLQM",
-    "GB2ZJHQY7PYQWGHO5FMUHIYGUWEJND7SXUUCHCML67WO6YDYY4JNU2M5",
-    "GB2ZNFACWJNR5J544TXSNTLBHESTZ4HZ3DEMD76OUCN3JVYDQXXHDROP",
-    "GB2ZO2ZPMYGXQ6QP4COK7VQRZ4CNWQ74IYTZCRXDFV7OCR3FZGS3I6VH",
-    "GB2ZR6AIRF25SEP5YBOOAGV7ZGOLJ4BTOPS26PVONES72L5L2PPRRHKQ",
-    "GB327DVFR6ZAHPWEKCJGCPVIKQTYIMSXIU55FMBTYJUTJKPVLOLDEBOK",
-    "GB32ACQP5OT4DYNWHEMSESSPHMYK6L5KNHCRTATSW352U6FD5NVCPZOX",
-    "GB32KBWH76YUSRRRNTVTOWU7SIHIBZXO5SIVFKTZPOMNNNT3JNREL22C",
-    "GB32QG6CMVTHM7QMTHKCA2JGG5EXAD6RRJIO3P2MDB7OBWDSYHH5YQ46",
-    "GB32TNZRWSIVBYZ4PKYMV6ZHXR2MY4LLYVDQCDP67RRVKPGC6BI7W5J3",
-    "GB32WHE4FW4OQC7ZXQ6YRWYAYNUGQD6EVOZRWNDLOPFYV75S6AQ5DOGE",
-    "GB337E4GK3VDJTQFSH37CKBZV2MP7SUAMTWNFYS7KYVIIK5ZN65K5L37",
-    "GB33KWYLG2XAHA3SEHFZP76R2IIINVVS7PVKZJONKBJM4JJVUTFN4IB7",
-    "GB33YSOTILKCQXUKCF5IUPODMG5U6JNIFTYRWAWEX37ZYKUB5XVPR4DC",
-    "GB343HV36TA6V3S22WFRHLVY5IX6ZX7QUSYX5SFIG7W7NKNNJ5NQRPOD",
-    "GB34N5FJN5EWJBZRBYFBJHIIBQDC35J6UFLX6DTURDBBRQG5NCBFSTWP",
-    "GB34RB43S5G7MEWA5DDELXZUIKY5KGEPEVJUOZ27F4LYGJWMYKHKMHTP",
-    "GB3522QE5EQ5RGI43P7HA4GANL53PJKKRC7BDPBZHQUY55FQU4IGRNVU",
-    "GB35KPWE52HJRTUVAWF26WFQDL5C37CQNFZLZEMEM7O7Q4WZYNINRUJD",
-    "GB35OVL3FSV7VD4Q5ZRKFXJO52UTJPUHTO5RPDLSLUDWVYFAQHGGCRRW",
-    "GB35UVCM6WHF3D66B6N5J2B6RIMTOUQ6QTUWCVZVIDPADJYVLO75BCBS",
-    "GB35VWJUAV6OICZISKIZ6YDG5I6OFM2MLXFEJQJ5BAGKTSRZREPSHXMK",
-    "GB37N3Q2Y55UDNX7ECIR5D7QFBK23I36HCXJJDUNPOGKRIYNQXF76FIX",
-    "GB3A37BMFHWFIECADBONYNOZTDEV7LTDQQSGAZSKNBEQQMOAX4L57M7Z",
-    "GB3ATVN3QP34E7QXK6OWGEARPZHFGIH3JDR3NSJZ5DZJJTNQX5EPIWPZ",
-    "GB3BJMXQFXAJNXB2HGCFCB3R4FU5MU3NDGUYWRZEW32NO7EJUPLPNJCD",
-    "GB3BPM7U456BKAL6URPVWDHD3ITLW6FOUDJZ3OTGQDHEFFTAROBXRORA",
-    "GB3CAW6LVXHWOQNLB4QXZEPIUBYBJXSNM3KDWZ4NXOM4DJY2UWI3STN2",
-    "GB3CCKTWIU3PAUCC4MBVVR3W77FMW6E7HOUZKKMB7I4ZSYA7IBC452SY",
-    "GB3CCYI7ABGDKAMMRSRALACGCU3XPU6OUITAN6MIDVLC62E5LP7MD4B2",
-    "GB3CD4JDXIBSJCHNGCYHBDGXY5K2OPPFNIUP2QDGVXQXEHF7N52L3FP2",
-    "GB3CRNADBGTVABSTWJMCZROF2FIQLTI4VZC25OFAHAYDHUU24BEUDSBI",
-    "GB3DL2CGZ7DD4OXZ7GXXOXUECZMDOIZO3RXOUPWL6OXM7EWANHA3X2SY",
-    "GB3DO53JKHJPOBC3K2ECDFUWM3DBNBEOJIJ4R3BPSFJCKPYW2RP332GF",
-    "GB3DXSUDIIHXTWU4TL5VECZ4JMYXBYPET4AXX5L6AGFCMHAIAG37WHIP",
-    "GB3E6NO57C3JXZVXUIWTFWG7J4ZJDSZX2ULW3OPBOQM43TBXSZ4G22VC",
-    "GB3E7GABJKY5RH5MOWLM3UHP55ETWTH2SLD3LLPWXHLOKZXBY4WBIYBL",
-    "GB3EF5Y46MTUG4XEHZVQCONOG5PRH7FR46A5WK3INQ255L2FMESIOYV6",
-    "GB3EGMZ7CJM55RANHAQTYEAYKCWG2JI2GHWU6FIKCJJBZS3ITBYUPIQR",
-    "GB3EQPIKHDRDO4T64GO4PO2QA4PLHUU7J3QZG7B2S77OWDSHDB5L2L4Z",
-    "GB3FLPD566RXHQMJEK477JPPKKDKF3BMOPOMMF5X4XGM2NPEAEJ7JLNC",
-    "GB3FLUKCJJPOPRC45T72U2H26DKGMEVGNDVFVEQWEVQM42FKZJJA3WMO",
-    "GB3G27JP667N2YGASJC2QPUAHWPUL3GWP3SQH6RECRJXHRHGHRD37XI5",
-    "GB3G6HAU2BWASUDOBLEGTCENBYHW34W4LSZHENIAR4E3H2G26SEDUN6M",
-    "GB3GBVU2FU4MWASQXLDUOL4JXAPREDK4QDQV7RFQFIRYQFFOWLX4XUIL",
-    "GB3GDV3FOKIOX7QSNYWOACW5OHT4AGBVKHVNMJDY65ZJJTOZ2ADIECH6",
-    "GB3HRIUTFTGXFG6EWT5AASFQ2AOWFS7PTQIW63I4HKBJW53IWEDZCRNR",
-    "GB3HVIZE5CBZHIXUYRRVWOZLIJ26MN2V4SL3YQK7J73Z6ORXW3OFV2QI",
-    "GB3ICKXFGIPIWCCM4PWBQZ7WGRYZBNA7UXUAPK3GIOX6XLXZAVIMWBKV",
-    "GB3IYTIJRXQDVVAG3KSGJEUMI3UQEPJTXOGILHRMAS7F7HAB5LSOQCJP",
-    "GB3J5JCAR4LVQGHWBB2JPMGYZYLYOXSV7UHBMXWYIF3EHQANPAQTFDYA",
-    "GB3JCYFA26WZXHLE4NY67JCS4VGHXVK3HLUSP3YEVODG7FILGBSOHA2Y",
-    "GB3JIU4NXEYCURHY7O3MCESJWG455UPUQURKTIFJ2MFLMFXNN53K6KJJ",
-    "GB3K54YAIEJVB2GFIBGCQFI2MVDRKK4SHN2Y377MBT3DOS3SGF6WXRT2",
-    "GB3KBOFUCVTWEZ7YIZ7PAKLQMKPCTGWU3LOMANMCT7V6I3AAK4USTEEM",
-    "GB3KMNDHQGLBUC7CVYF5GGPK6AC5KPZI6EL5KY2IOPYRKTPROXQSLHHK",
-    "GB3KWFPHNG2F5M3A4SXYUVKG4WZ3BTBUX4LVQQHEZJ4VEYLAOFUVEIND",
-    "GB3LEADAKI6K3C4LZOHRSFIHGIBEJZEOWRFZQWQGMFWQDK5OZOLUZSPG",
-    "GB3LK77V6PA56GL6ETUPGBIL5N3WTUEHFOJRTTUM2RJTLZ6VTMVYGMMG",
-    "GB3LMYW5F6QE2EG4NGJZRLRBD4N4L4626KOAAK6JVPDRPWKD7K7SKNR5",
-    "GB3LWRCZ7ZES3TESMUUXK5W3T22GQXUFEE7JFMOFKYOMLYEBP6GGYXIG",
-    "GB3LZZFVEAJSYBMF6LEVUHTUWYVRLTZOXFYI2FF4XAY2EDSNNL2V6MPG",
-    "GB3MMKZ7QON3M6WO4WYLG2FFNCEGYWMFTZLITA2O6HNG322YB6PDPJLH",
-    "GB3MWVS6ZXI5DG4MIH4IWZOGGPSGTRUOFLT7OKULODVJLTIRNIPZ63YR",
-    "GB3N6BYTYNP5U7S7ZXQ5ZQY3LPLZHTIRW2TDZWNWT7AWKFGZ7JYIDAZV",
-    "GB3OCN6UTG6EAL35N24BF7BLEGIHLZ3KMOSUWEZR5JGQPKCPNSI4E3FQ",
-    "GB3OE4IBQTYQFZZS5RXQHE4IPQL7ONOFOSAIS2NFRNMJKZEAI3AAUT2A",
-    "GB3OE5EDOBMZAQCUTJ6TZFLHDHIIKAZFN3OSHRMRFUJLGMXHAK6O4M7M",
-    "GB3OJEQHTQLB6XDJPNY7K3QBMXDDKZTU5U7EWFQTQQ7IYQDNZJ6LFXRP",
-    "GB3OPWATNKVWTHVHQZIUF6D6MBN3GWYFBOZRGR3NEPKP2UD6WJQ7YKPM",
-    "GB3P2QOMEMICI6HCHS4HSX2D2QNUMP2764PQ2QEOFKRZ2AOTROZ64TIA",
-    "GB3P4SL7DMZZOTUWYT4PKJJ6AGA2PLBCXHMJDA2XPVPERRTEDH7SQT25",
-    "GB3P54U5UXZUKLEK3GS42LHAQWHTHTJYX6Y63FZCBMK2SL4FELF25DFX",
-    "GB3PE5JYO3UYHBUN3P7K4GI7VUTQV3A3AMLSWHISHCMUGXTPDNSRXXEE",
-    "GB3PYFHLTEUIZFFGWTH6IFVOHS5KTGFKKHGC6D5FAKMMGECCM2VXYTU7",
-    "GB3QATUK6FNSPE6MLVAXYJVLWQH3P4ZOVYP6A6C6SRWDADC4UGHJTATE",
-    "GB3QL2KY5CC4422WXI35QCYMAI2GUBNLI4I2USW3QRC5OUR5DH2HHHAX",
-    "GB3QWIUYAIIN3IT7FT2IOGA2LZVXDO7HOPPUHS6AH3IWVDIICEOG2PKO",
-    "GB3R54HO2FZTEOB3QCVKZ5OIQZ2WE6JACTCNPWIS2RP6XANHCJQSF72S",
-    "GB3RNQMIRRK4ODUJIWV54477UT2KJ3RGUEXOCBNO5UYXHCMXJFYBXFLE",
-    "GB3RQ6NP47RV3CVADCONJAM4C65JX24I4X4YL5S3BKURDFXOOUEXRLMF",
-    "GB3S4COS4IT52HQ7ZREPUJLPEBPYYMLRO3QA46UN6B4CFINSSVCYELON",
-    "GB3SOJASITOQDDU2QH3E6JTEXNQ7FAQDMZXNKKIQUVWN36GPPSTDJ5DM",
-    "GB3TKPHNS6AH7TSFHX4SZUBLUMAEQELGRJKRZQ2YPS23XZREV2D3P2QA",
-    "GB3TORQPESHPJO4R62VV43ROKEFTFGLES4FF4JSJMFP7X7FU6HQ2SBSI",
-    "GB3TPASQR4GU3C54H6TUI4QHKVBY4NUNYSFHDGWKN3EL7OKYXNISSUSD",
-    "GB3UCOJ5MPWGY7DZLY2A6ILJYBR7NSMH22MW52CEDG6TB3DDE5XQEGLD",
-    "GB3UFHXDJQAVDUTBL2FYBVDONXBCAQSM7ZFQ4CKNN4HTEDRKZRYX2IJC",
-    "GB3VD766GCCKSZ3TNJYB3H242KTGKYIPYSPAC4EUKAUQI45IKT6OXIZP",
-    "GB3VIYZFKJ66CSKDNXTT6LM7MIWCRUORSFR6UZNV4BYRZOYZAXP3FVTM",
-    "GB3WOOGLVXMR6NWCL4S7PDACTTB7WCIXRDZ5D3SK4YIRA7QST3QHYSYS",
-    "GB3WPHOSPTG2P36JRYGGC3WA4ZEKQILKB6VY23UO5OEPQEIODSJV3QCZ",
-    "GB3WVJLKLCHJSVPUUVWXJKEL3ZAC6WHZ62NKU4XLLGS2YRWW5HMZZRVV",
-    "GB3WVTESMIMB5BOTNP4PQLE57MTNKJOBMNRKOPVF4AIHFVORU2BTMETL",
-    "GB3X2TZ5L5LYXHCAG3GHSZ7EVUHGYSIYOAXAKH74KQTRIGQ5CZ5WGU27",
-    "GB3XTFHESQ63QGSO4A57IZLPQIXXLU57JSHNKUSJ23HBQ2VGOJKB34EG",
-    "GB3XTTHNHS2OGONL4U57N2E4OAI35BTDYLIK3XZGL7RIIEYD7KYSMCNY",
-    "GB3YQ6RVXZFOE2LUAJJWTDJSYZGBN7RB3P2XGR76BIXRDFVSHALTC767",
-    "GB3Z375GFIPKELCJRCW6NLLFVKKFFHNO3EBLK3OEIDN2BYGBWPGQSWZD",
-    "GB3ZKOK55HE7EBK6WBWDRJBHGQKNSLVB5IIZQQ3FDTS5R3KB4KQ7CMAT",
-    "GB423WZXPPIZETZFSVXKNBOQSR6E4RV3BQBPBXJNFQRN767DC2YCGSYP",
-    "GB426GKBUUUXOHUWDNN4ED5F7ASHLYFX33SYTQXP2YFQMQRUBE6VXA3S",
-    "GB42CF6MOAQRYZDDCPPCDBJVGRP2XXNOWUUKUAGR7PWBGUXCDDC62B4T",
-    "GB42ZQXTF6MLAWSNRGFU6M4ARCMPTZW57RYDOWCELGTHFTK4U6F6UW3H",
-    "GB436XQAAS4ATHPZMCS5VSBTZ22IJOFL23AWBWV2VLJNH44MCGW7TMTG",
-    "GB43JGV3UFGZCWEIDJZ5F6IHSJ6XT7LNTSAWJ52MGQY2VLKZKLJDTF4R",
-    "GB43ZXVJDDJC47WLI77EAIGSTPTLFJ2AZODUNAQ3FZCLKHPFFZ2AQYUH",
-    "GB44CSAIYXUMWH763LR24WEZDTWQBN3JBIYONG3FEO2KLF4U2UK5XMBG",
-    "GB44LLHF36TPILMJKBY55CFTAPMG457Z7XEQLSKZRP6Z4Q4D3BLYO2YU",
-    "GB44ROCAQ3L4B5OGVCLGPEYVZMP2LSPR4QO6LOLC27TW3WHQES3DL7RL",
-    "GB44XLA2JNWTLFNLWK7BTQC7YR56JEINTW3ZBFLFATA5T6WO5J76UDGT",
-    "GB44ZDPDMY76OETGRSWAPQJLUZ5CCOQUIIVVU7CT6W3VKUJPVWUOPIEM",
-    "GB45QFU6HVHNFGP6A2L2G6462DD6QJDGOXZUPQFCVUSOL7MVYGV2IKK4",
-    "GB45QIHI6MSNLAQMUCPKRXPHIXQ2SMUEYGFPJVOH5GL6EQ3H65G6VKZ6",
-    "GB45SNCUTXIFYI2CGU4OJIW6NCNXUNUUBT5UNLLK4GU5ODKUZ3ATXYNW",
-    "GB4656DMGRPEBRCWUGJFRVXCVS3PSDQ62R3QKS4H5O3OHGMWUM25DQFS",
-    "GB466MDE455YE5XB766TVK4JZVS4M72DNR3MLLPNE7XWHP5QX7ZQYYEU",
-    "GB46CW5WV2CESKOBATO2UBG73NTHONNKXT76E5NP657ZVD4GG74AVOLV",
-    "GB46Q3BS4R7O5CLOCPX74JOGXO2Q3CQQKH5LYFRTTMUI3VDU76Z7YBCH",
-    "GB46Z5UPHK254P7ODRZC44R6R46MMCDRFYVML73GN4OXRSZLNBYJRMOH",
-    "GB47BF25URPYX24TI2Z6B2T2KMA7MOXAQWKZEQQ7AAMUEDJ6XZCLXOX4",
-    "GB47MEPMG5QABH3UNJZAZPBRBUYNGJBXK6ZAEWTRLA5ZKXIDYHSJOXLM",
-    "GB47XYKVKX2HKN2NWEXDVG5EK23FJST6YZIMVCDFLMWBAJF3OXKQEYPW",
-    "GB4A222RQYGMIVIJIAPTE2BING4ZQKTYE42C2YHDT5DCGAM6T2JJHXZP",
-    "GB4ADLYYMN3KFIAKCVGMVVUUZYYROP2M7ELPETJ3OHCIHMUXAVY5DUUQ",
-    "GB4AICEMV3EWBOBCPRAFPAU4NB6SK6ROC6QLSP4WX4OYM2ASQ63I54H5",
-    "GB4AQ5DCIZ6ZV7G6YRYHF4UXGFVUSBHEFEHKIJ7PLPAJJERYP3JHDOSL",
-    "GB4AVJSY66T5E7XX6XLVO7UHXVHOHLM7NVQFZ3TB7NK4HDVH6ACQA54Y",
-    "GB4AXNFBGX2NUOAY3DTIYB5N3LWRJVSMIS3M4376V5CYP7DZWO7RVVTO",
-    "GB4BCET4LFAOVBEEVADQK2WE455YI4TNFGL63QTS2HINXYOR2475VRHR",
-    "GB4BMMFDA2CYIOHRFCW7YYRLPYEKAKBM36I2QI6QSFQPNAH5BWF3UPKP",
-    "GB4BSIZRS6OEKUJEZN2VT3I5NC3EXLTJU7AS5AGU5Z4PSZODBDY7BTCT",
-    "GB4CME4B2UV65G2HKBK254YITVYH2DS3UHAUKGJO44VYEJX4GNWPZQ4A",
-    "GB4CU4EBF2HVSRGPWA7SSBGHRFUK7PD4KPY2N5BTBBJ5V6EQV3GBEUTD",
-    "GB4DABKKAGYTZKXROYTRJ62EZOYCK7KGB7U7ZHRASM4SLFXRQMFA7ORT",
-    "GB4EEI4ORDWBYDX5RWFC77FXUBTGUCCS5C3TZ7HAI75ZZ6LWTXVBDFUW",
-    "GB4ET2FJWHAF5F2SCQGJ3NXAOYSECST4DPEAI4MTDXCHS4Q5QZQDWRCI",
-    "GB4FF3RILJ43GHEHO5D32WHOWVSLDDMAE7RKAOLXVFB2EFJPNHTHRFEE",
-    "GB4FG6IKT2IZB3HACOIK3PKSCBOGAHZRG4M3F3XGNBMXC7EQLPRWX5TW",
-    "GB4FJ6TWTPU5CZ3EC3VGZOPZW4JANCY7MWKE3AXWJ36QDZDDUZT2QEQV",
-    "GB4FJBEMQZKYJ65QNZF5KYD7EWFHOZAQ4753U3NOOPYMXGBYGN65JSAV",
-    "GB4FUQGK47DYC5J2NG4CJNDCGVOC3ICTKZY3EF7SLVA7OQTYM2MIQ6QL",
-    "GB4FVQ2J3KLKZQOBSH224AC4L7TAPIPTIVQMZWY5ONGIDF3475IECIFQ",
-    "GB4G6U3NHE3AMWOV6FBVNERTQVO6MJGCLNLQEBR7WYDJ2RZ24XELPGHY",
-    "GB4GM7FZ5U6MQANPQVD2AF22NVTHEJUD7JJSI74U3TPOIUBE42SP5FO6",
-    "GB4GPDWYCXMB7GFDTH7ABMFFIZWNVVVOGOOKQL5MBIQSV2T57DTVHNND",
-    "GB4GWUMXLL722NGPO6SFRIZOX5GUJH2DQHBAHFGHIBN2VMYYTZGUQNFC",
-    "GB4H3XROJ7RDFWE467KDDWKJ7AIGKVRAR34EM3WIZD4TABYMO5C4CHGR",
-    "GB4HISMEPX5MRD7YC2LZXBFYO45LD2QJ6YCE3CNQZ4D7SUWGAF6T25JC",
-    "GB4HQUMI2O5F2OZTKZ4JGYPZSVDWYBMZYZRJEVCVIQI7VX3YCEUQW2F7",
-    "GB4HSNPK4VYDI4PNNFLPX62KSFWAC5PNQ23YKW2BIOE2CEA7S6JW7ZW7",
-    "GB4IF5K5KOOPKR72KNM22WKZR7LJWBHSYISH7LLNAOFQ77IFYSW7LINE",
-    "GB4IFUHAB4VOUHXDAFZBRGF4OD4NT2PO6AJGJYOH2PTFKJ7DG7ZV3KP5",
-    "GB4J4UYXVOTI2PHCCDMC44QUQTU3232JBHZ7YQNHREF27KJAG7TJKOGS",
-    "GB4JFOKJC4OXGPTBVQA5LAQDE5WAFEX6ML32CBQJYSDLF2NGELTZJUQA",
-    "GB4JGXCBILQKFRQ2B6IIUO7EG6E3WXKU7F5KNEELRW7CI2KK3JT6WUP4",
-    "GB4LAT3MI2IQAL6SJVQ2B4JTH36RWU5ELGYPXCB6JBUULKTDWTMMNNUD",
-    "GB4LUQFDBM5WGDHOMJ2QLJBN72RD4H5SMJ2SPL6AJVWYSJ5FAQRKEXLM",
-    "GB4M23ECTWMFN6TZKCPGWTCKUUPIOUMSU3BDOKSZTFV56BZPFG3UH7HX",
-    "GB4MFWE5T636MKOMXK3K3O4LPZPYGHFSUJKVGKJWVIL4UTHLLHCMOEQS",
-    "GB4MH4DQDSJTVG2HCI7S4XRJV5ZYHGLJZQHHVCSI3EYV4KPBNVSG3HAZ",
-    "GB4MLQLFM5NAJOK4ODDEVPOX5OZK7CGV4NL3XJEKHRF3EJLFYJSSRTRE",
-    "GB4MULJ7PGMBR3J2N6DMMKZG3AHQIWPOOKPYJTFZ5PXO5QM25RSUMHFJ",
-    "GB4N2Y33ZORJYMYPVVQSS72LRRXOLQQZ6S3W2HXTHPCKXDEYC7KPMSSU",
-    "GB4NG2CELMUE77ZIFLB3ETUENQDIH4HPNUAAA6UIGL5T4BIXJSE4U53Y",
-    "GB4NZ7F5DJO7L3MOIS7CH35WWTY7YH4PHJN6JMYEV5IJ4ZVNBLZGWDZH",
-    "GB4OI5LBGGGPJIGYQ52MI7DIYFOEYRXVWK7KNDQWVTHSRWLOVT4HMESC",
-    "GB4OZR7Z6XJVR7EYETZ2I3BGBK34KYNL2WOJ7V3KUD5VHLARYAS6DFUN",
-    "GB4P3ENAI6NEUE33VLJRJDJFDUZT22QBIDR63WY3OZGQJNFXHOB6AUOJ",
-    "GB4PCRFRWI2MT6XTNH3C7HPME3NKABO4ZEB63DGAI5C4DZAF4OT5OP5C",
-    "GB4PLYZ73POGZ65CAG3VQUKQD2DTPYG6TGQHVLEVHHXTX67JFBIZIDAJ",
-    "GB4PQ5QF4BNFIEVV4KZWX6NFVRLPSR4A3HOYBO5A3RL7NSLBWLEUYUOE",
-    "GB4QCISPWJSF6HMFSL4WXUP6VTIMBG7QTL77YIW6GON55DC3JM5RV7VU",
-    "GB4QGFMUSO2YVSD2YQM5A5CLEOTXXQCOO7KN6CEO4GXKISJZXGOGYOHK",
-    "GB4QKPE6QNWDVORQEWONFFGBBVXOFNBCL4GYMIDFY7ZNJ4LG5HX4QGE4",
-    "GB4QSOHEVOSFIKTQNPDT6TVUNO7GLEAX6EMI6QIBDXCILPLPDNUYM7HP",
-    "GB4QXWVM5SOKSEL54RW63ZZLTHUOJP23TOUUE4W3NNMPJSE2XNN7LQKH",
-    "GB4RZNGF3WPA7GDIFZFNGPEICM6ZVIS3VPSE3WZIKCBW5IYT2FQL3RZI",
-    "GB4S43EV4YAQJO3IYBD6SIWEB5ME6NTVCRUEDIHMCGN6H2YI7GB7Z2EI",
-    "GB4SLHNOD2L4ZUJZK2I7YMT5Q34K5TBA4SATDLYHE5DJCAMUOZDZXCRT",
-    "GB4SUBKQZ7NSKO7OUYBBD3V36233OFSQ2O7KWJ4YD7HG2DRH4ZJ27KV3",
-    "GB4T2LVF2QTVETO3DVT4ZISXKQMPFMDG5OTOTBKS226MWHQPK7YECSAP",
-    "GB4TUSWZDOOUKNZ4HWSHGJT4XDR6FNBLQUNUEBLAJJ3M3VWJXFEICZGN",
-    "GB4U3UXLS2CB2POLSRXLS3C3K53R2YNDDD4XNICXQLXJJDXQBRG744CV",
-    "GB4UIXUYHKXDEZHFE6OLFNHHCM4NDOCBRDCV22DT4WRQFMOHBDYRBR73",
-    "GB4UPBBFWIFTTML26OK5HP7HRY2FVUPCYHLKVELCJVC677DBYQKEWQQZ",
-    "GB4UYHZODN33RRBVWZCTQUOTLMDVFKS6PP7CCGLSABEXGZPTUJQXGW6C",
-    "GB4V66BXLHXQE3PSLXBBWPAQ3I57HEJGCJVD2QLKNIHLLQO5I7HEL4FL",
-    "GB4VCPUQJM5FQBQBRSRVITH6KOBAGVFOQ3EI7HX5NA4TUVJZ33P7AV4M",
-    "GB4W3SKE7PIFKDTAUBAPM3COBD3MTTCQCPQSOT5DNNXKCMJKOTTQND2G",
-    "GB4WH6Y3CZWHCP5QWLMHGLI5E7FJUDK7GDKDSLYTTXVQJZSVMSV5BPSC",
-    "GB4WNQ2IXLPX5OHMCOCB2UYNUJIAAOUNDVHIFC626KWPYZXZXDF5IKSY",
-    "GB4XBJCBAZLN6RU6Y4OH6IN655QNWGJ3TDOWCFQWLA4IVNZEJJRZAEOG",
-    "GB4XG43IUADFDXBCKYUPXI5YNSOIOOOKMIWX7HJYFAQAP5FQVSQLAALL",
-    "GB4XNYOCUYWGU6F4R3VZZNVUN2RDILQTWG27VMZLPJDAWFLTAPR4LITE",
-    "GB4YH227KLTZWKA4YJDHPJLWHJY4256CSXDND2EOFERQSAAKNMEOQHAL",
-    "GB4YPSCL3NCGE4X3XCBXYCG4M4NOUTAEBDU7YG5HV6E2ORBLSXTEECTT",
-    "GB4ZHC2YXNQYDPHWD7VYAQ2KSYKTDIFGNLRXE6CNRINOUCN6WO56GOLD",
-    "GB4ZJHSXVAC3COZFCBKBG2XBO3GGDJQXGLYMXMW2QCNOZ4JUFPXUJ2NB",
-    "GB52GLKRH6XZQDCOSOVWVZHUFW6UQI6TJIYTO2XJF32S2UWU7HJWIGPO",
-    "GB52HMVYUVEZGAJ7NJOCCSLVQJHZXFPXCSHFOBPKELPXYGNHYXHVFLTZ",
-    "GB52IRCKMWNE352FN2LG32OA2KAS6AX5BJCCP2HOS5QUFIQ3VWVHVUTH",
-    "GB535JXTHFWU7J6AO3YP2MRQD3DAE4NI6NVCFAZCBYFPS47PTCCJXSHX",
-    "GB53HAB3RIXDANJDU3KKMZRKEU422OH3GYU63GVNHV4TIXPMIIYRQICP",
-    "GB53SKLWN4S4MQIRIAVNU6IJ7KUOICWKROEGY34ZYFVRAFAVBGPOYR5V",
-    "GB54AFSH7U6AG7K7EDQU2VR5TS62ILS3ILP5ZKUIUUALSUSO2DPMDRGC",
-    "GB54UFSWPUMYRFWQQGKINO6XB2G3EUTK53N5X2EK2ESNROJQJ73HTHAI",
-    "GB55FN6KNHGHWJU3JELSICUZP4BZA2TD3G64SUGRA26THBNAMDFILABQ",
-    "GB55NYCAX4XHWWCLOO6KELFZ4I2ZPJDQQQ7Z2X2FWIVICEM4COVAKXYQ",
-    "GB55TAYUEZZOZ2GYRDX6ZV4TLQHQ6RJ5CKFZFUS42YZDWYDOTGRHZKPF",
-    "GB56GCSN2D3RBZXJRJALVTB4X5MW4ALYKI3PRWMNHL5FGV74BSGZC7KS",
-    "GB56HYVTY4K7WP4FV6EBYINYEMIHLVICNTSAWPQI3QZSHGKKCWEHNZIE",
-    "GB56I2YAF7BPJ6EJFAVUBR4S623G4WNQ6OXAJMNB57HVHYWKCTRWXLNI",
-    "GB56KPUDEUELFV2VZFD7QYFU3J25MKWKI4FBJ7VZ36KURNYVOAWG6XRP",
-    "GB56WK54Y4G5CXY4COFP3NGBDXC3UMFP6UQ2RDCGCLVZ5S7E2XVGC5DB",
-    "GB572X5IQR7XT3FUN3U4E6EK3RZC63AZU57C37ZQYNHY6NS5XLR3LJJR",
-    "GB57R3BKYW46RBEL47N3OXZINNUWYPTKXGGSHYK5SCGGPWLS6ONCS67F",
-    "GB57XWJHT3H2PLGOZRYBCONFTM5LI2XHCWIFG4A5V4GMP4ZZV4PGURT2",
-    "GB57YUK3XIVWJEQY5WQRC752TPRR4ZTKWAEDJS4FWBZKY6PZSQVAK7Q7",
-    "GB5A3Q5BEBGQNGM3VZ7AGTBKJK5ZDVGMIUQ3FMGEFQ6327D2M3P46YCO",
-    "GB5AXQHELEFF2SKNQEBX5PDTZWPO33WODYP7DJR5VM3PUJ5SAFRC4JCX",
-    "GB5AYUK5RQD3GLD4JV2GX4S4HTX2BES3EEEDGYH6K3C36ZU7XYVDY7XS",
-    "GB5BPL5W73XU5KQXBT6P7LUOKHNY2GANPAPFZWCR7B5FZVFUXYRWJDTP",
-    "GB5C3GAPKJEPIC43VA73DIBAGLMUU2BVKOHWZLKXKOSJGQGUUNHSBCNG",
-    "GB5CIDX6R66JMNVXZSMKRWFYBF6NGMOURWA7KRWDIYIPPFOCSVRVMXLM",
-    "GB5CRNBA3BLWBRAOQWWSQBJ34WIHO4KTLWUUDZ54I2ABJIFJYYC7UPQA",
-    "GB5CUCQH6MHBMJTSOIBIEPNT3MO2W555AIPVWNCLC4RR5INTVUU5VEIT",
-    "GB5F7UDYTEGGH3SZXU3XDH2W32YEXN7X6GXJFR47TR4WDO7M7OSFCB5X",
-    "GB5FAJKE2M47XJDXCCZBNYK324XOWC56EVSVATKFGLGC7ZQYQTN6X5OA",
-    "GB5FG6PXIMCCRL7KCHNWINCN56MPXT7ETSWZVOE676AIDDYS54CVVGQP",
-    "GB5FGDVT5O3HQJL36QOX224KXXS5LRPU5DCARE7SN2AH3WNUYIX43IR3",
-    "GB5FPL3GIP5Y3GVETVAK4LDRS7RAW7TQXMBQB7VDFV7USCDF55RAYFVT",
-    "GB5FQJKGS6UFYJHOI6626TBZGWFPL2ECHERSGL4MLR4XSGAHGFKZV25K",
-    "GB5GUBVH4Q5IKMJGXEV6NQ7PWUHO6KYNJGBQH5SXI37M2YHKQBXYKKKI",
-    "GB5H5OG3JS2LGONV5VRMUM4KQKPJGSF6AECED3BRZK7XQNVILUSVTZJL",
-    "GB5HNJ2H5RICGW25BPAB7TYZ5OXXBAVG4Q5UNIDE45ETIHXICGQBIQLD",
-    "GB5HWX7BHFQDLS4LJNPOBPAY7IZODF7UVCPCCE5I72WIF7XIJ33TE2KO",
-    "GB5HZTQJHNXDLGKAAEAFP7DICLEOTKZRRUZRQDV77F3ENVBKWE63F3M5",
-    "GB5IAEC4UUD73SRJJGPHOLBJBXSC5U4TOXRO4B7NGWQUYRUBDEAZWBCG",
-    "GB5IL3SJ5OXIT63LYFF7TG6HAUYEXULZRQTU7IQZU63KDNR5NLTHW7AG",
-    "GB5IRJAJUUGBMCLZD5DR6XUZB22CNRQE3Y3KJIQKEWHBACLD43T4CODE",
-    "GB5J7HUK53AYDTOOHRF2CLV3D3ZGJ4URVC7ERTNLWO6P5L3T6SSPWAST",
-    "GB5JJWRTQAIDX23HJQDRQRFCRVH7BTMVOAUK74ZSJPCZKZDA3CO674QP",
-    "GB5K5I6UIXG4752GKNF4EK5WK6WJYPIVHNPTBWFGOD4MH2OHE4SEZ7XT",
-    "GB5KEIPLWAPZB52Z4RLOLP47G5MOCV4SNOKO3EJGINQYVUSWGANXN6XF",
-    "GB5LSCPHVDNEATTAHSVQUZUNWSBAUA34JSOWP57AKJ5UJIKGH4LUEPGC",
-    "GB5LXPMC3ISOOECIAD2HNDMWYJQEWKCSXKHQEHYVJOKZOMNTIJDXVCMA",
-    "GB5M37EY2WHURIDHBBUGFXSXUPVWTFRBM6746PFV5ENUWU45P4DMW5L6",
-    "GB5M3POUNTLA52Z37CDT7VG5C3XSYOMCS3IWTCNP5QWIYFGMTKG25BCH",
-    "GB5MA3LAY64GGLQSP3XB2XTGOWUPL4AR5D77VGLKAC5EPXL5PJHSNXX7",
-    "GB5MQW5AF73ONBAI7VF77MEQW7YPFAWWIPRKCCSL5LUUPTMYKNL5HT2N",
-    "GB5MS3XTRUAGSYEN2YYM2V3OEERZ6NGJFWTCNQQC6OS6LIQXGVX7WA5U",
-    "GB5NOIIIKF7LPZYT6APO3QPR7KYLUNQBXEYLP3RBJL7MQDEU67SNJEJ4",
-    "GB5NVLYMEUZ3FEQWH7DLGYL3KCC73QDSCMPXUFSZXILFXENMD24P46PA",
-    "GB5NVPPV35WC2HXYTS73UGCQGYKJ5SZRD7P267LTRJRKGD53NDKKAXQ3",
-    "GB5OB3KNBODXWXAYIKKKGFFOMPPYIEOP7VTLNJ7WUCPUTDSNHYLH5OO7",
-    "GB5OIENQB64VPEB3IPAH2VAU5YKXHEN6I7EFKGXGSTXZXFQC35BZZ24A",
-    "GB5OQL4OGU6ITXT7CTR4JWDT2PF3R4IHOSEXIN6U7EBO7JQ7EHLVEYKH",
-    "GB5OZKHUGRTNQA7KX5VT2UI55OE4JIWDTFAVG6I6UHVFFJQJ7FP3FGAS",
-    "GB5PO2OOWEVVK6MIAO5XGJEIYX2FP675JHOCTXAUQRRRRC5ULXYUMR24",
-    "GB5POXOTK55VYU27XNFOYXWIJZWFJ6F6D7HNTW3ER7FF3J5NX4HFBLUE",
-    "GB5PZ465OKTKYKVLTGUPKIXQHBMNQTLPHGS22QWZ3ASRJEMVKYRJECSR",
-    "GB5QM5SADJM3UOFGNZTVBRVKNW3452PPU77HQJZMHRN6WAMTRNIVODI2",
-    "GB5QS64F556FQBJTJZEY7BARDKCL3IOI4OV24EYQDC63F4IGEZF6TGCB",
-    "GB5R2J732ZHB4QMZDZLRDJRT4NHA7QYDDEK6TDFLHRW2XB5HHATBVXFX",
-    "GB5RBZ4KEWKSIUDCSI7SCFCUGLQJWTGDOY2ESFFC4SDMZPOBD2YIDJAG",
-    "GB5RC4ZBPPKZK6X5YWJXCPPLWHPCCGLCOI3IGEGQYKTVK6J7L47322XX",
-    "GB5RDXQVOUJBI4UHJ6TUXUQ4SYEWTOE4NKWD3LMTYXU3AYUDL45TEKVJ",
-    "GB5RNIOCYKWLD6WGJPKBIMMOI3BVHO7XOIUTE4E22GMNAXH5PQL2IMDQ",
-    "GB5ROQPE42YLQ4DEUUQQLAWLF4PM5NU3AK4B4E3M4SRAVLETQQ6OFCHN",
-    "GB5RQMXNZB6PMPPGKMQNUCKPZYF5RGCZRD4DGCJBZGLOT6U6HLSRU5KX",
-    "GB5RV23N64V5K5S6PL254345SLB5PSVC2YVDHTRRYBKTBZ4Q2XG7RISO",
-    "GB5RVGMQN5LYHV6VG6NJBIHH5PZLPCYENA3SRASQIJY5BHJPKKSKZHFY",
-    "GB5SBGWZOCLU5MEK5HGT5RBN2EI2TX6EZ4AH4FGXMKF7N4QGYOBJLETH",
-    "GB5SOF32X7G7IPS4FQ4V3XF2F6AJCW66CALTA3L3IOPJZSQ3W6YIISHQ",
-    "GB5SOLCKSLO3ZSI25CXC5T55FDX42MGVAMYXPSKIYTVG7KEKLIY4EA2L",
-    "GB5SY55YHM4XTFXBOBCAV2YQM32H6HGF46FH7UGGXJ3MKQ5LZFZ5EFX6",
-    "GB5TEC2WXHFGSG55TEMPJYCUXROXLJ3G2WAM4ADXNREOQOL6YBCRW4U4",
-    "GB5TNEEGMBL3TVWADQANOHKOBHWIUTBISMFXZ5BJQGTHVNJVHF3UF3BU",
-    "GB5U4YOZWY3CXQGXYSPZ2X3A7ZA7NMJDXXHDWLEHIYGEVBDLS25YWV7N",
-    "GB5UGIWLHVPPDCPB6XB3Z4GGT5TS6VTJEIRCAYYZQPRDNESK7PYKDLDS",
-    "GB5UMOASR4BD6MGIGJQF4SY54G2GULTQBR6J4VHXT72KLS3V6FTGKBBR",
-    "GB5UV6P7TYOGZEZ47IL2SMBCIHUYK7HYW3EKHJ2D3Z5KPLWXPKJVIRFX",
-    "GB5VER3ZJUFKCWB3O4LYA3C663RDGYMWNRVDFIIZOVXTCHMSPESFAQUA",
-    "GB5VVT4UZTHAHVNAG7LQUWXCFHA7PYCSLDUIKFEHPXGDO3MCKCA5LGPT",
-    "GB5WLMK4JLEXYMK6TGBDKJDHMDGGBENGPFPRUXYIPHWK2O4S6QF2BP5N",
-    "GB5WPWBSQ43PV5VOAVXKYDVH2SWJQTAZTXX6PGNAXCZV6AP2LQV4QMHC",
-    "GB5XASZ675S3MRDDAYFTVFPVPBTRASWLYF6FUAQ63CJHQ5NC3ZLXW7HB",
-    "GB5XTM6YZGWAG2GYHPJGRGMNJNCS7EDI5QWYJSYYBRZZ6CWCDVAHIBMS",
-    "GB5XU23SB2J56JERMGQWHA7LLBPQNZS4BZS4KMU3NKT36DWVYMCNQOKI",
-    "GB5Y4BTTB32HDRAWTG2W7FOCOU7RDL2SZ3FN3BLGPQSFTYOP6CZXUXWE",
-    "GB5YE35P7ZIAXOJVEN5MIZRVS277FC5HWQR3LCWIZCUSEXLRS7E2RKSD",
-    "GB5Z5BRTTJLPGMIZQJLNT7V3Z7XM3MABSUDRXHPEGHVWIEIL3ZTCK4W4",
-    "GB5ZETYW5TB2A5WGPMWCPECYMF5YXGQFIOV7ZRUABBGY2YIL2WQZLR26",
-    "GB5ZHWJN6YJFHAEY4225YYASJBJ25ONJORJ3QSLU7QTQAAGCSLYJQGAS",
-    "GB62ABVYDGUCFMMRSNMU7TK6A4SB4PTQZNLCPFEH6KVCD3YWBOG6WGGH",
-    "GB62AKLEEKPZKN5W5DQJZNOPFBFK7ED7XXJEEPTMEBDRZH4PAUUJ3636",
-    "GB62X3FUT7A3FGCAJETEM5OMV4LD4BRXFJUTXUFJZIXHLI2KHBL56MTB",
-    "GB62ZOMLBMUGI4MEHXMJQXVY654MOP54H7ZVU4EQYITZFJHL7TGHRUSD",
-    "GB643EUAIAXHJROYVTBTCEU3MHHBLX75CDIOP2CJCBZTLIIRQBWLCDVH",
-    "GB64AMOBUDG3A5GTX7YJ7KAPUFL5EBOCFB436P3CLIBY3UMAOGM766W2",
-    "GB64G2MXXAPZOISF4TATTPOZ32YJYE4M65NHHGLLNMJLA3HR2NSHMXRP",
-    "GB64I4HKF3TSJ55PV2KECWUGAOKP4KXSH6ZWADHULMXPUCRHFCQBVHVA",
-    "GB64M4BLZ6IFITRZTHZ5CVITAN5LUDT24GHTVCP2EUZ6YAT6GHS72ZD6",
-    "GB64P4HEGR2F5FWGQEIPGPC2XAL4SMTZLKDWAY4WAXRHUIPFIYL7ORXX",
-    "GB65RLDQZ32YNBEKZJA6DRRSJPBW6SRMKGWISZVUQ4XRZSDLOWWJIZ7I",
-    "GB65X55JDZGMJUXU62KUOXX2XAH2NH5N74EQ5LTWZL2XMRZ6JIKEHXRP",
-    "GB66GTEUXKQYWPOLK5TYOSGE3FJVSPH5P3ARNO3O5KLSITRB7NC5MSZ4",
-    "GB66IXHPZ4AWJU23DA2YT7J25NRXENWTMTWHJE52N3LESBMYWONQCR4B",
-    "GB66MTG3WHMS3UT6F6XYCES6R67XRLEYDUBXXFW5T5YLHQ263Q2M2BLK",
-    "GB66TZMCVLBLJDGUREGU3PV2GWRSD4PHX3F6M6BASICRT6VWKKK4WJXS",
-    "GB67CUXXK7OUQGKGKF3UCIJLLKT6PWGPRD4DCRIIWSJYY3O5YNKLXX4H",
-    "GB67FC2Z5CUKKAUDIEBFNZA23PJ52L5YBW3WAEHWVEHVXX2SDVCEESAV",
-    "GB67I5LUYDJNI5KNZ454NGNRK2Z6SPLGRRJ5V5RNVEWBDO24XFOCELON",
-    "GB67RIUVXL3MPC3LUYZVEH6SY25POH3M4RKECWJVC7ZZBY636EYH32LK",
-    "GB6A22FGLCXZS6TIROI4Q6EU5SQVXTN3HUGJ6P3SQIKM5GBPOMTBXXVN",
-    "GB6AUXI634UPHPXB2WHQE2ZLMROSEOFGEAGV67JJJY7BUW7JNGDH5COH",
-    "GB6B5SXHCBEYBMVNF5DQPOMTR52BJ6SIAZEHEXJVT2QF3UFJ6XINF3JL",
-    "GB6BDADHS3EHP57KS5F26D24HOE36WRCZYW5AZB7CEDVROOXCZN4XTDC",
-    "GB6BPDZEQBCKR53VU743VGK2JJ5RQBOQEY2OD7TIAYPRHDKWNPMGZXXA",
-    "GB6BQ4DPFXOMJPXTI7ZTM4JRIKL5CEGLK2SYSZOFIDGNLBWCMXM266F5",
-    "GB6BWUDQE4GLOVXFUFIS3EZ7GBQINZNQR365KOH7GYUVUEZGHX6WY7X7",
-    "GB6BYM5CK4FVFWY5XVMOTJ7PQQBJPSRAMQ3Q7D4DBM3K2GBY7G4ULFI3",
-    "GB6BYNZDTGJWQUHJIVFKWZ75LQ3FOWM6RFQ4XC5SVNXSXD2W5L2YI2D6",
-    "GB6CNQTH5XLRTFK6OTOPGULEVXRMZ7QOX6AJXVK4JUPRFGA2JS7TZNO3",
-    "GB6CP6T2ZZTLSGISIMIDEXZONERFMDYRG2WMTSJS6QEW3UD5D6O46FEH",
-    "GB6CQIZECGU46GCCT6YWTRDXEX3IDB2ZZM36EMUCUD4PH3KWREY5XBUK",
-    "GB6DGCT2QN4I2HGFKLRTYB5GTE6V3ZNRXVVN7HQGSY66R7IK2T753LJR",
-    "GB6DMFBAOVTXSJVZFGTU5SKWUTWO5DJF6SPONNBYBUJF2J2SU7LCJCC6",
-    "GB6DMQ5IZUPRRMNTPU4H5FX32DRYCZ447REBEQ32YGVPPGZI5MX4AHOT",
-    "GB6DNDUKOPYNWJH6UJX44PAB5BFXBUU75MVOKKTYQ4AJ4AB2DRH5BKAA",
-    "GB6DQJJPBMJKLTTE2WCSNVCEAXOGDLHRF6U5EOO3HWWCAM2PMIJ37CJF",
-    "GB6DS3URBE7SGTHWAF2RMDARHF73C7ANYYOZTU5B2C73BS6RVZR64JLI",
-    "GB6DUTWV3L6OH3PWL32LTOXXBQG6IKRFLESYQYKG2MJSIDQGRV3WGWFL",
-    "GB6DV2WDLVSSAF47A3YTHZ4T7M5JETFI5DTGVPF5VSJ2RCD755BWL7FH",
-    "GB6DV3OZRH2Q7EDEUSNAMOBUTRUXIVFEBOJKUOVCWIORB6LRZGD4663C",
-    "GB6DYF4CF4VTO2VAVZKNX4AADCAMVVE2O2YT3ZY2KJBD3QZSOND77FFS",
-    "GB6ENOR3C3IHDTFHYV7SJLIFXCK54HV3BSOMVPIUDMEBL5LDSUUDL34L",
-    "GB6FAZEYG3UKJ7MVY2NFFYUZSXDQHLA7IYUTQMO5DNOVRYVKSWTC2SRM",
-    "GB6FDAJU4NFRGDEL7KNCOHX7X6HGUEVHN23BMVY2WTTIVBDOHDJ6B2US",
-    "GB6FDSVJRIO5KYPN5X2XNRRWSDFZNTPVQDALCSQBYSCRD7ZN5AVUIPJQ",
-    "GB6FKOUFYFRFOI5O2UISKZDRYUEP3RKQ4UGLJOK4NJ3WXBJTLFTOTXV2",
-    "GB6G3SBNBC2ALYRUDE7N7QAVIJQ34XF2P3WIIDLQHFUIQ426D2XM4I47",
-    "GB6GSLYXQTSKF6FX4ZHDQ7KENSGD5D6KPISPFMG3D4QQ765VMCKRDSHV",
-    "GB6GTEW5NSIIA32TGNOBCAUSXHITP6WNCLJ7GDBJOCWZELQ6K747OHKY",
-    "GB6GYGOSAK4YOXQO2FKF4FICRHGE2B55SIKQXT7XON3KLQJKMKE2IHQN",
-    "GB6GZHWFJ55OY3CPHJJ37TIDJVVLY7LEXTBNZ3W6K625Q22L7244W447",
-    "GB6HYRZ2CEFR4MDBCXZACYGJ5JXXA23GR4W6BXRKPHAPVMXBSPRCI4GQ",
-    "GB6I3YHRJRSHNFJCUSTHJBINNRYN34EMK3CKZBGZVUIIQ2ZPBDN3OIND",
-    "GB6IHPTVIZHFA4ZHB55US2AN4EKJYIG4TREQWVQGOQ3ACY6DXTFNXV63",
-    "GB6IOKEOUVPJPWOXL4GNFW4BJB2GRHTPXMHO3LDKCGDPJ6QUIDHLRC37",
-    "GB6J6C5Z57AW2NUDDJ5CWMTA2KDLY442FKB75A56E2MIOVJ7HK73WHIP",
-    "GB6JK5SXXIMFYI2IL4NQRZBIZDYNLZWC3MHKKFA6W5FNZTXKOVBJYBOX",
-    "GB6JK7OPKEOODDPVQAU3H5AH6MORI73TULPNAGCFJL2WGNJFMRKOPJ4I",
-    "GB6JOKP74X7SGRAKDR2XPO5XQSBOZXW64MLILUURZKRINHUQZZQJX2KZ",
-    "GB6KA6MNNB2MZY5QGXN5P4MZ62AJCL7S6ITI4SDFOHB74HE7262CA5RE",
-    "GB6KW7WMUXQF4ZXBC2K55LKJO22HNPNKEJYFJHKJDBYLHUDWD42OPYX6",
-    "GB6KWL3OXJCSDAY66OEMFWF5CXSAFX5IHEZMA6LWVE2CZNDHER5GYPJI",
-    "GB6L623HHI5AOJOF6LNTTSTJZXSIUFKF3G5ORGLLN4UDMOUDDCJGMRA5",
-    "GB6L6V3336NCS6QA6PUSRUVZKBRSJPATWBBLCWGOWMTIKNT45OHRW7P2",
-    "GB6LNQYNWO3IKYSRLEPYUMD3NHNVGL77KEYENBHLRVPFUGC5PWNBZ2BO",
-    "GB6LUQCBW7DA2NZ7J7T7O2QCXSKVX4QXIG7XQVXCGKJQXHSMFGTJZNJC",
-    "GB6LVLT5EGYDJTYXM362LU3H765LALH6SC74M236YITK7H4MDQPWTAQZ",
-    "GB6M2VJ6SU57TNZFEIMKXUKQLC3DKETJWTFXAX5ZWFQ63CTTUXYDHBAR",
-    "GB6MP7KSTF5HOVKNQBJT4WEXCKAGDPGD5NVF3NKAAVAY6FMNW5GNJB4B",
-    "GB6MWGHAZB2IHCCI7XHCUEETBZZD3CPQIZUGDDHGP7QLIAOLNXN77VIT",
-    "GB6NW7OYHZXGWAFIQBBHHMJ2ECZRIL6HMWFFHZE3DIK4YL3R23URRZ3K",
-    "GB6ORJ62LG5O2YSU25N5FBSDGNINUXCOBHZSIS6KBRGYE7RNKWH72RVZ",
-    "GB6PGOYWZ2MG3WMFYFPWBQJVVLDRP5AK2KOIPNXECUYMJ3WQUC6X2W4G",
-    "GB6QAICULBDXGTMYW5ZZKE2E6D2BD3NQC54KUB7CXALFECVC7GOWJGJK",
-    "GB6QWHWMA6RT3IGHZJZLQNNDGUFJVQ357UD6P53GMEZVYMOM2JKBGXM2",
-    "GB6QZGMKEECISYVDDMZ23USOVIXGEA2GK6IZJQAAUJMHVJCARUXJPXLM",
-    "GB6RHKHRRIJO5MZ27BOXLL52FWMBSZZHRM6NIHWRPKEGNTTMBXWJSWIS",
-    "GB6RQCJY56FEASAR3HCQHZHGJQZIHMK4XUWTVPGQYP3PBB2LUGKE4YJI",
-    "GB6S23ETCZIMEMXXUMP4EPRUVJHKHJIPTD2T4DJCG2FSA33NI2KLDFCI",
-    "GB6SGNBDFPDOVCPXJEVTNUKOMWE4IORZLABUJULCXCUWT6L7F3KSINKF",
-    "GB6SHTPCVIVSB7OJELYNRCNGQEZBADZENFIM7WALR7S6Y5O34RVUQQUD",
-    "GB6SUAXSRFYPWKWUEVF6DDY7OQ7T2COBBASJV2JQOZSBQS75F4F4USQX",
-    "GB6TFBFBSHA3PIOUTILXVBOFDNQLBPOJ7IT2PBTEWQNY3JZUFYMMEA4Q",
-    "GB6TIW2GSIH37A52RVQMERSQ46ASXZTEXAQZV4M7O7VARJIYMP5QEU3X",
-    "GB6TS7PTBGOWNRBVTETT4J2VCUZIGTZOYQ3UJEFPTGLBF3XMXN6P5XAQ",
-    "GB6TZF742FB5JTGZFJTF6VU3JR4E3QSNZLVCTI2Q6LBUKFPRO5KM4JLP",
-    "GB6TZXL5YWJW5YR6J7ESL72Y75ZMP625QRVOTZKN53XI2B2QJGF6C2AI",
-    "GB6UVTR36HDUJJYR5SW3HKH6PTIWOGX7WHKIUW4D6QL4SWI3AOV4EFTB",
-    "GB6UWODA6K2NWLJIMFJUJTT3ULK546P6E3MODUV2XP4HZ675VWJEKJYH",
-    "GB6VEZUQFL4YOL4GGPD2DNQVTSLQ2NFIL7S5KQPSRESVA7TMPBDKBER3",
-    "GB6VH2YTK452EUAU5IISPPTQOB2A5YIIRPWR54CWO3OQEJDIB5PAH6MG",
-    "GB6VZ3E6JYCNWRR54EPQORFJIOD2LPSEF64OLJGQRPSIWQWMYVX7FOQY",
-    "GB6WBGPUWSXRDBBTKROFROIJ6CIMN5SZTZF6RB73WAOLQMFFF2I7YNYF",
-    "GB6X32RTYFH472UKIW24IISTI3ODTJ54WVBOFZP6GDA6OT2I7PDZN26L",
-    "GB6X4MC4H5QHNPJSWAJ6XAX2RJANGN3E3TO3PBQX64QCSDYCUYZLZHA2",
-    "GB6X5JGNIXV4BFXD7ZGRPR3JDQSLUN6LKOYC5K2T4AIAEXL2XN5OTB7S",
-    "GB6X63ATBZAOWMLEW4W7XRQCMI7RO74SUPSR7YTIBBBWIZ6ARASB2KYA",
-    "GB6XBPSYPZAJY7RG7R4GGETLFZZAXRAQL3TSXJGXDT3BH25G4TI3XYGC",
-    "GB6XETXZSN5QTYEMWKEWAYRGRT6YDBMGA7PA45RFIC7NJRC4H2PKEI4F",
-    "GB6YGQGYFUQPMUMTBX5L6S27ZETRI2EOVZWTMFM4P7KO25P2VGXWHZNW",
-    "GB6YKWZE66QSWPCCRVHU3TQHZ7WUJWVOA25PQ6ZH5KLNDQ7PM4GO6ASC",
-    "GB6ZV3YBWQNY7W4SPMFIXK3QUOV2CRA3WIAWIXYB2HCXMEZHYX7ESHIB",
-    "GB722Y3DLABY6JJDMC2F3NSDHBGPEX26SDUW7A464UQ4SLSZZE4Q6HG6",
-    "GB7264HBWLIFBHLHTWPR6SEXHBUYDQBEYAXASW2UGAHCHBYIAIYGWEST",
-    "GB72EATK7VBEPYB7TB2DCIC4NCSE76QXUIUJFZTAPE43TETBGRZBU2VF",
-    "GB72F4OB3TUZ6URJ7INZK4LCNZASRU646P6WAUVJOPPXTUFJVLGDMEMT",
-    "GB72IZRVUTGVGOAGPYH5PAQLDBZV6HSKQEQYTY6LWCZ5WZRMUT2CHCVK",
-    "GB72TQUQTKPE2QJ2M2VWKIWMM3KJWL3UTYJJVL3YS6XLIYKLRZZECRZL",
-    "GB733XUBHBCAWLLRV5POXI2CN4RVJYIKRJAU5XQDF7CAEC6D2XLNRP2T",
-    "GB73MXS2IUCRH647CGV4JBNZDB4L2LB4FOPKYDTXBYSSQ72QTNOJJYXT",
-    "GB73XCHR5WMTTY43RONNWLKD455CD7KON5V2IKVZJRNU535ZQAJMYELR",
-    "GB74WJWZMRWIO6QEJWNMLKFHYYNMA5KNCG6QY5T2IJ3DIZFRUUIKU2LT",
-    "GB756RBS5YJEWHFFDXOSDRBHAHXDM3DTULGSAMJQWR75DR542RLCXZEC",
-    "GB7642IWZAA5BY4FMQSSIZVALC7BOMUILYPI4AFIJ7J6LQOUPMPMOWUW",
-    "GB76EHV7X735RBJJX3NJBZUTXTIX54ATMM3ZJIIGZQPQKKKPTZIBMM6A",
-    "GB76OX4USIJOBAKKFQI7K5FGTGJMOBIVRBJI5LKZY5FUNMF2APWIK4CW",
-    "GB76Q4UILIREYMEBQQRBBODLWSPEYEPE6NVRVOBPEKS6BHVI2TKPOEEL",
-    "GB76U6MXJIBMIKKP6TUENDMEZMOB6YA7BQMK65KZQOW3RSRGKTKGVNBB",
-    "GB76XEJSE2CEDODHLX3RWKBN5W7XR6NC35LKZNCMBHKKPXMBZO6ULCVL",
-    "GB774STWFSGETHCZTOIOBHEGT3IFAZLIIPS55Z4VAFIC5NIXK6VR76C5",
-    "GB77DSYY3H5PCSIHNXBU3QWNWNDMTXYZOAOD33A7V337RFK3YJSBLDVX",
-    "GB77U5X2MLUZA4HBQAFZXHPJPK7RFOFGZVJPDCXWZATJQXA2WS3E3HRQ",
-    "GB7AIP4WIUT2A4OJMEE2K4AVB6O5YPIUSPENI526F4S665FYUJDXMLUY",
-    "GB7AZEYHNJ5RLIY6FGKHKR34SYTSXNH6UGH2XTK46PRDSS5SLXJB2432",
-    "GB7B6YJBO7KGZNDOATJFP5GR5JJL7Z737ZNGCVE5OSYKEHNBWBRQOTIN",
-    "GB7BHAD3U7E67GWBFIPNOUAC6WCGXB5KXRC7WFEJOF7GXZKRQZHG5MUG",
-    "GB7BLNTAPWTDHJX3DZA3VSIVZNN5RR34A6KK2WEW4NBALOT5DFRVM7OX",
-    "GB7C4KKJVWAJGKX432JY3VKQKS2VEU42SMZ5C3YAE7LFABY57VEML3B6",
-    "GB7CVIDPUCL5CAGBMX2FFR2CU6OCVWCXQHBZEPDHFG4Q3SLULGABATOM",
-    "GB7D4OMATA33JOSOS2HOVOTJMZ5EPDBZ6PY2PKZISG6FJNCOYGRWNEUR",
-    "GB7DVP6WEDTC2TMCYWYMU4VGNOGTP3XDSHTKU6KVX22TL4VWHB4ZB7JJ",
-    "GB7DXF22OSK3KYODHHM6GZR5AEW6SIBQGGCDH73CO3HDGETWPPJEN2X6",
-    "GB7EW53HTXSUWR7ULCEZ3ZNSO4RXBF72YKTB3BWRK2ZDNRVKFRB7BPXF",
-    "GB7F3G23PJH6O574AET2LHOWFK2F3YZTWVM7ND4K5PQ3TXGJYYYXLRQ6",
-    "GB7F4QMVTVGP6WL5Z56I73MJTMF2I4WGDGENY4RYZNCFLPSEZGMY63XX",
-    "GB7F5ZRC4AFQASKNSG6BYJXZ2YVAXK5AGLS3KB547PUEWE3JWFZBSAK4",
-    "GB7FRNRBVQQC5W5KB64LZMSLWDUIXZWZS5OCKE7LSLOBTXCLCXOR2UBS",
-    "GB7G2TFMLAIQY5GVVVFEAPNR7CYFQ3IOJOMOCN4L3I4WVVTYF7VCGFCB",
-    "GB7GKG3TGS2KOUDOYJKMJKJKHZFHC56RBKYDVULLRLIJMCT63K5SSZWR",
-    "GB7GKT7G6E2A3IFKLQLLIE6HVZVADHSCTPU73WXWIILX4C3D6CMNPAXG",
-    "GB7GMO4ZAKAJFSPKLAWZXHEYUPOE77ALN3BGYKNKWNSR3SETSLNAQNMA",
-    "GB7GRJ5DTE3AA2TCVHQS2LAD3D7NFG7YLTOEWEBVRNUUI2Q3TJ5UQIFM",
-    "GB7GU4SUBU7ZK2P46VELXH2RHEA6AHHJNK37EB2TLSWV32RTK45KHQ5O",
-    "GB7H76TNDAZPDR3K7WWPTXN3XLXUYXXKUSDPCCBT3CPAXISKSPVQTAGX",
-    "GB7HF5E3PQHZ5NZFVO3VRBFONWDHU2JT2KRN5BW5QGIEL7KESNCDDIUD",
-    "GB7HNI2LL7LWQK3BAKVTNAMTVJYXVVF2LFEMHZP67ZG46ZCNBO22YVD3",
-    "GB7HSCUCTSQFMZJ6EJI3NALTTIHU54GSRAIE7IH466IQ2RB5ZAEMVJWB",
-    "GB7IH52QYUPSBLJW3TFFPZVDE3BCCZJX4YK62SJXCHTUEDONIBQRX26I",
-    "GB7IMG6MKOUDLZTOAFUHLVCAVG32GYYUVBMIIZHWQKIO67R4LXSDND3R",
-    "GB7INVDEOSMS73PMKKI6AWUTA5ZHQEJ4URUVU6JWCQK3BI6S3DR7TOKO",
-    "GB7IPVGTGL6UKUNNH55SZYZRKIPYGOLFEAXBTAWK6ISHHOPGYET74FDG",
-    "GB7IV46BCQVM3C2JYVKZPGYPBW7ZB7U3XHED35AHDK27W3LCOVISCIGK",
-    "GB7IWLB2HTA3UXT4NXEQGMU2AKFJVX2JNRNAFLD7SOYLQ2RR4GD5QBNB",
-    "GB7JAXEZWKVIURB7FRDWTHUM6ZPMYP27GA4WQNIS7MWQ7FHONVKBGR6Z",
-    "GB7JLSRKI3JGF65262RPIPY56DHOVJGY66FXR7WFNQF4ZGIWGR5A7ORS",
-    "GB7KF3COJY4YPLFIVJ2S2EY6SOS7UBMWQ6X7VFKBZTCT7STDGWA3NVSP",
-    "GB7KGDN4X47SQPFFMP73ZPGLSBX7GEY3YMI4CIRACPYIKDQRLMBESH2E",
-    "GB7KGRNKJ66K2ULRL2HCCF7ECPWCLCEUJGBSYN7GXY7Z72OWTWDPJQNT",
-    "GB7L3UPVDOAVBLSBMU6VP72A65MSCMBMT6LA34FL7RFOS2TFAWTUPILD",
-    "GB7LUYRPUGCVT5BVEKPGWTI6ONLJHC6DDW7VDPDFMML56LXTHXD3G5LQ",
-    "GB7LUYXYLV66MPOZ5JHM5VT7J5T4YS3QEG76DJVK7XH7BLZRM3WKCSIJ",
-    "GB7M6LJ6XAVW6UY6G5SMDNS35TCNS53OSPXDQS6XVJ7QILLSLGNTDDIW",
-    "GB7MBVOQ3POWL4NGQ5F4R4UDG5EOETMETDHLJH6YDGZQHZTYOGOGCNHE",
-    "GB7NUN2SUXHG6MRKJQR76RVKM3VSBDCRRRHGVDWBHZK2C66OUAKWCF25",
-    "GB7O6Y4TYM4DKUGKQR2A4NRHGYAQ25PFJZWCC3WCGG7GK3UED67UIWWW",
-    "GB7OBWIPC4HFQPPGJG2AS5YHNI7LM4CG2R6EWI47O6AWQQY45DRC7LI7",
-    "GB7OH72V7MVOGPDRAAJWIKFWGW2YMXLDBTR6UDXOMOXWQ3RGU5EJ4ICP",
-    "GB7OONKZCJGORNPHETM7BRQZUNNLPZ5P4TH5SDDX4FDGHWXYTJCT6HXP",
-    "GB7PGZOZ7BWYHI7TKZKRYPTHSA7YDYJGJHO3HO5C4I6CL756J4O3AUSA",
-    "GB7PMATJZGP6II2LWEPIBM2EFBU2K6FIVW4P2FDYJQ3G5RM4YPZHPMUN",
-    "GB7PTXKSWHXR6Y76FPT7WBITQD5VQLJU6PEYSQOKDTQ34MFDEWHUNXB4",
-    "GB7PXTP6F7PNIL4IIGT4F5YYZ5DW7BOXT7MTQCT5SHHUBQQ6TKUIVKU4",
-    "GB7PZR647J3BHHR5JCZG7WOKVMAPY4ZAEDXDNUHKQVAOLQWIRQCL7LQR",
-    "GB7QCZ4FKWL2XOUD5UKHDG5TN7Y7CVLFQVXOQILVZ4TW3EXGJE6WYL5O",
-    "GB7QRWBSXPTQL43GDWS3TY7P42UOKYONQ6PPQMRM4T2I4HFIT3ML2IJR",
-    "GB7QSWXLGXNXL3SXNB4SJFKIIBGSXYG7Y6KKO3M5SAPXBW6XRPJHUWLA",
-    "GB7QYY4WTFSI3WFTMBDFRH4GLGLUUILUUN4US5QXC3UQ54ZKMEE6376X",
-    "GB7QZLIXRRJSW3524ROKQPCRPNGBNUZP7MKQK724ZVUHDIAWCAASS5OP",
-    "GB7RCGWN4LGJ62GUMSVV3WYHJTN74YXZSFRWR2DMPM7I6T3ARO7KCYH5",
-    "GB7S6WO477QYSNTHFUBMXWDPHRFWRYSFS4UBE5746FN4BLKYUTBXGSCO",
-    "GB7SB4SUO5WBGHYZRKMD3KBS33WAKLB7XRGTVFFUBV275YYM33VRJURZ",
-    "GB7SNSSGX7H2WQZLWB4X7NITC7AR3377AOXUUVS7GFU6ILHEUGBSYKXO",
-    "GB7SZIAYLFUEHNRNYQPVKCBNHQXKE5QUUAJCDCKGB636UZLWYMUXHPZM",
-    "GB7TLE2GGU5WJTZGPPDRFZC4MIJCE2F5B37AD7S6TP7ZTIIMD52DUJXP",
-    "GB7TMHQHMNZJY4ACOY6FR4A2JKTBQBHVSTEX7UELDSGVY2OKQ3OPAQOZ",
-    "GB7TMJVUBVIVLLHMK4MLZZCTLXR3FK4FWPU4WPEM5CIIBGBKPPRETORO",
-    "GB7URPLXKQJZ4NCNQUBHYRQ7J5F5CZEEY3V72YPJLFEPGJJGKVDGHNZ2",
-    "GB7UYZ37UFCL6RKLD25KL5NUUTIOZXI6K77TOY6CQUH4ACSHH6IQBCN7",
-    "GB7UZGFAXL2WOMCPHA5HD3KNRHX4VUMLKXGNH6L773T4EHVEOEP362JQ",
-    "GB7WFIPNK4C7YSLZRPYQFIFY74N5BDCVFQ6WMOZTGL2K74BDMBZDDXQ7",
-    "GB7XKN5AX5LUHX4K34X3S3XGFSQKQHPSTQNJXOTOK6EPZIN3JSWJSLOL",
-    "GB7XW4ZLTU2W2RDYUYCQ5B5NWPVH4VV4O7LSHRY26CZJS6MGEXNJLUVA",
-    "GB7YECKZK2HBZV4PFZRNIAKGJ4S5NVQZNPPRM4L5OOMWCCOYDLNPFO7U",
-    "GB7YEKGH4JVD4QHQP6BIVH6D5BDWSQCRVFZHA67JBLMFRGVBWGDCBYQR",
-    "GB7YGGRGMCFU2N5WFU6QS2KKWDULYAN2KKUTYFR5IVGGQVTZ42B3VZ7V",
-    "GB7YQZQOYLZ6OZ23OWBN6RSGQOZNPRO4652TN6HXTXWKIEY67L65NPFD",
-    "GB7ZCXKH3NCLW4E47ZFH6HIOB4HCPO5X27WYPXTQUUI7MN6FJBDSPZNM",
-    "GB7ZHENLD7MU5SMWIUR6KDANQTAXIQBJ4URGKGJ5YKURZTIVXNDGGAMA",
-    "GB7ZLKVVRR2G7XLXMM4A4DMM5LZVKTXA7RUVYIZ2FE7GP6WFZVF7NFJO",
-    "GBA2275ILMDYS74PEPECIWJQWAXVCLDGRD5RWGXOMNGBA4MULB5PQOQP",
-    "GBA2MDKKF4SFM3M6E5WQWR4RP73HP44RITQGMQWPJ52WYOJFKSINIF75",
-    "GBA2MZGNUR6GOB5XHNMS6XNX4W47IJ62RJBNFNETETP42ZGBEFNDLXLM",
-    "GBA2SBLBITMZ62D3XWVJIKFPG6CV7ZEELN5UC5PTZTT3GESKTLO772T7",
-    "GBA2X76HODX2G7MZGSSEPOJEGEVBMDVDKN2T7FEIUDECWDMG7JCOQU44",
-    "GBA2XYYGREAD6T7O44GCSNICHMXO2UXTFV7A4FIVZGS223SMRWDM7BYW",
-    "GBA2YDURIAXAIGPEAX7JBXKTB74YTXVUCBXJO76S3VAUBFO4ZMETRNIU",
-    "GBA33NMJLRF22JE3WY6AZIHI2BIUX73ON6YDAIG5SY2B6CKAHFE6CFAG",
-    "GBA3DPS4D5IXWKPLKHTMZAAHM5KWET2S4P4AHYXHFVT54MCDR5ZXAIZX",
-    "GBA3FU3IF5T2BBXDFW2GD2YJL3FENWOJ6QAS3PX36OVENFWIA2D5TECH",
-    "GBA3IP7VN537LTDXCXUOOC7YXRGJ47WNAZ7OOR4LXHWADXR5X4PPXCRT",
-    "GBA3LS5MUHLA5EDP2UFV52KP4YEJHQIK6FDRVY5UGVAJESNXPMA5VD3J",
-    "GBA4G3IRDYFLH7S57PTJHII46JMOHBCOLFBSN5WSBDYQMQ5B3RL4Z36F",
-    "GBA52WKYON3C7RO7GS2TAVRX5RQ4TQAKS44O4I2N2GYCG5KPLNNLYCRS",
-    "GBA5RIZ4DGIOBC23MKFQ3I5LZE7UOLT42G66DMM2R7G52SBHXX2S7W5F",
-    "GBA6XEA5UAPDXOIZ6BK2XBOCU5D62RWAEYYRP74Y7AALS6QFLGKPIOTA",
-    "GBA7E2X7BNWJ2HZC7UBE76QHPEZZWBSIRX7TMTMZUHB4C6BWSEBL4EIK",
-    "GBA7K4O526KPJRILSE27BCRGQLH6PTPLHEVOKWAUZSAO3C3XB5J24TY2",
-    "GBAASUPEINF2XYZC3MZGXKK5ANT32XPA6QH2PMQWUJBQK2I6F6V3DD54",
-    "GBAATPSY3QZXFL5JP3TKKS674IVA54RXSDE47K5VRKXMT2LWOI3HCBTC",
-    "GBABJOAO5RP4ME2A6HBVWI7O4NL2WXSZ674B5RDE6QBLXBTT2PRCNU3W",
-    "GBABJZ2EOFMTCEM3FG3XOTDSLJ5PHNH3NXNW34FYJY5UVUEUC3CZGE3V",
-    "GBABP23ECL2CMA3SUM5XKB6JGRIX2RHWR7JVZOUCOTB32CGQAQVPITAU",
-    "GBABUP5RJMGJ2EPCKDV24CUICDO7Z4BOSX3HE2HJNRG73DVDNK2L3WLL",
-    "GBABVFUNQKXVVLVTQBUH7XU2EWTLZ6OOZNFL3UF4XMK72MDUSMWRTQCU",
-    "GBACBBSDS3KE5VFUOBQSZBBWHFKG5PESZAGIQOUKEHVHHACSUPNFUKZZ",
-    "GBACKRJVX7BCXNZFSZXS72J3BTLRIMAJEZ2OP35HVQ2VMFUHDZMNLEZJ",
-    "GBADAYCID3MJU4OSLWAH6EDNCS3CRXGSK42VN23NM3OV2NCPDJCQ75MU",
-    "GBAE6D6ECJO6EJCVTDBA5BFYRFWFZI3WUT5X6ZPYHOQ2M74PI324AE4D",
-    "GBAEHHRXTGQ7XYIZ56RVOEJJ5INRLLMCWYC2J7UKAP7VVKCUG45SGOLD",
-    "GBAELEPTGHINL4EEBN5YYKBVUCCH57J6Y5Z2JS7JK3KRGWNPRDMJZYQT",
-    "GBAENASCL4JII3EA7BKRKAR3KB2MGVHBKPJJIHISZ267VMATDRSLOAFJ",
-    "GBAETZT7BT7GCWZ3W3PBFDDNIURURWTQOBGAUHHAXHK724POG5C5ZVKE",
-    "GBAFHT7KP6S2WZAOKTLIBMNR4K7DWZAM6YHS3KAW65VWJKR3BQ52JFDA",
-    "GBAG5NKAE2IU34OX356R7POUJP4TJQZM6PRSEK7NUGQYKDSUS6CMY54H",
-    "GBAGI6FTMYYSD2FOHH5Z6VGO4K3GEOY2K7DL6FLORFDBFKRVXSQ6WBHJ",
-    "GBAGP3RQCSSCUGAWWKNHYMSDPZCIYRHIZYMEMY2JJ37VGX6KXEFEXUDA",
-    "GBAIDYQFE4WYPAF6Y66B4FZFDVMPLNGZUWBBWWIU655JXRS42LNHLQ4Z",
-    "GBAIGAYIDMNELUPO4XXRHARCKSTHMPVYWICWHTPY2Y5JWJDRA5AXKMAE",
-    "GBAIMW4VLUTKUZMIQGPNNCTX32ZRWQ747QKVXI6NPPIO5LCCPW2SC5LX",
-    "GBAJEXA7GFVGUQHVJWCYV25DMZAMMOHC5XDWSGRFYYCS3SZIU73MPD7W",
-    "GBAKJO4QAWXN6OY3LGFVO3KVE27JD4S2W3VJ6ZUJCIP4RNFHHPURJZ2H",
-    "GBAKJX2XP2KY62AIUSU4IDBXEI367N5R4WNCUH2SLHAC3R6YP5I6LMDE",
-    "GBAKSPDG74DJXSRP2UJNGF7HJVIQBZSINOF7VRBALDOB6KNHUI5B254E",
-    "GBALCEWXHTIXJJE2ZX2LW7AL7SUJ4VY2MHSLXAE7ZCZ2W5PDADS5MEUA",
-    "GBALEK2OD4WKE4NV76VLWHQFLDB57OX3TUJUGQ54Y4XHXA7RUJ7J7KUW",
-    "GBALF7JIALTHR6K53RL4J7KC5Q6S2WCUOPDICMKXWTOC337FZ2YR33TX",
-    "GBALKPJ4STN5JKNX5J42GCGP7YJJFKNXT4RENZWYTTBHBR3TVMKJQY6C",
-    "GBALMLKYW2DR5X53SZKRFVLCM4SD6JZVHAW6UO4HLWKXM6WSIUKTFVIS",
-    "GBALV5YQWQBLS54LXKJP2E6OJ4XUVSYCGQ3WJWE6NDQWLVNYU67OR7RZ",
-    "GBAMPXGFOFJ5GD32D7UNA5YCHY5NHPF5MG46XYMQFL2XFERNHTWJZUB6",
-    "GBAMZGNYURRPN34OLBRQ5VIAMZRN6VVXQCSGQLAMDRZUEDW2LPJKMCJI",
-    "GBAN26JZSQXBMLVTDKNCVJMDYW5BYYCJQIOQPC2EN7WUES2FRKOZM5GL",
-    "GBANAKOOCM2NKNKPI3EUGZU2T3H26N4A33J3TXWSPLG3V4NZXCCSMQ5B",
-    "GBAO6MQZZYF4HLDEUSR2OWTYT5NEMMKRVLVWDNIMWVOECFSDXICOW3MN",
-    "GBAOIPLSS7JQEYGWWJBGAIXLR4WOZCEROOM7LVQC5ZLBWKSHGSKL57IW",
-    "GBAOWZVTCMUBG7HTYEAUSKTYDNDYWVPX7CJPDBQYLYD7QTPRJ75WIXUB",
-    "GBAPAVI2VM35NKLFV32N37U6UGMHTGJ4SSGYG2FITELL24C3XUS5RKEF",
-    "GBAPBPDYOC54FZPEAM5EGHFMTH64HPWGMTEZTMODP6Z4SYJNVUGXU4LV",
-    "GBAPEDG6OR2MHG6KMWDPLZPK3IXJ4DUZPHTOGTUILQS4RVGBNPZGVCYB",
-    "GBAPP6N5DHTICCJLWV63APCVIZ4Q26SSA2RXHZPJUNL3D3YZILP7SFEI",
-    "GBAPQAIW3XIBQNAZWDFV2VRYYIBS72ZVBQSDVVYW5HTJP2355SBIZ5MU",
-    "GBAQ7NZEQ5UIFH4NDYVF7LLCBZNK6S6JYJ4RNBKAOJ5B74VUNCMN2YUL",
-    "GBAQAW3DFZV2CC3AUMSVLSXHMHUE7QAS57ZZYJORG757AAZLS43JGJ27",
-    "GBAQNSNSXOTZZIDJTDYXDGNBY2QQCKFLSFJ2PV6ISBVEYF35MRXIABDC",
-    "GBAQXDNTXEBZDNKKQIS3MBYC47XNAHS3M53V4EYGLAOHJHGS2TGZAP3S",
-    "GBAR43HU2R7ZX2A3KETIC2O5ZHCST4ZWST3WVSHKLKOVOYXXFPWHHCCH",
-    "GBAR6JSZBWZDOMIVAG2FZYYKQB3KBITFFKTIOTR7WQRNNXL6LLYBCTGX",
-    "GBARKAAHQSK2DDWDECRNDPN76UX3O654MFLZMGKVP6R3WBW6W36CLJPE",
-    "GBARKGQVTJLYVA2MYDDHY7GARP3J47VLVHKFGEZXXND37EL4DLRACL57",
-    "GBARNAQQCUSSTHPZBNYV6QACAMV3G4ESSNMUG6QRXAO333CVALGZRN4N",
-    "GBARZBG5OAGPOQQPQT7GY34XDFTPK5KWLLVNIIYZGGQXCRLEQ6I32JXQ",
-    "GBATL3FJROTRIC7L56SSZAFT4XMM3LA4HF2N5U3V447L5256Z4Y4GGVW",
-    "GBATWXWPVCLJUVACZ4LORGQEEGQTEBBBHWRYUAX6V4UDBH5G3ZPUXXFQ",
-    "GBAU6EDABJ5GH55ZAIHAF4NAWFZGM7IYVACXZB47KHPI3GGQBAQPPN3T",
-    "GBAU6X3WUIIC7CHQAZJYGZVMQ37AYPTFCGB65KDSUFVH6L5UQ4LGRMD2",
-    "GBAUKBQD3ZJMNFPCH46F73WGVCEA7EHMO5OYSICZDRSDYXXHNDMPQ2GH",
-    "GBAUMHPFDZZ3KNWZZA6K22XOIGYPQCZPFQQNXF3CVMGUU3XKPWTFR2IS",
-    "GBAV3WHUO3WUAQFWXIANK2UT4BF6NLULADVCDRFUOHB4UR6VXTPSZGV4",
-    "GBAVKX52KNC64YD7CKTKHO4PQQZXLL6RLL4SAD4KFO2H6L2PPZ5YOU7E",
-    "GBAVRTR2RR3HIFTKTZHBNQWFEROUYLU3OQN7WKVILPGHZAAKLKM34B7E",
-    "GBAVX6DDYCXUQGIQH6JKNF57BCRSL7JDQL5CQV2WWFDBTDYMBIPMCS5F",
-    "GBAWL36KJ4WIOABPSR7BBE2ZTVWJX7AWAG4NGYDVE7MHX23ZR4Q5ZNCY",
-    "GBAXBS7TDZ6354BKHNBJA2PO2NP44ZMUIBWFQ2VMDD4U23VF4VCHIMAL",
-    "GBAXOBPS6V3FFTWM55LXK4OTAVX4OEWEOWGVASTWIEDCKQIIU2HMNTP7",
-    "GBAXZNVJE2434J4RYM6NZQ4UF7HTNTKGZ5UM4DTBO7EG7ORQRMIO5S2O",
-    "GBAY4IWWBPZIKTYDUSOTBUTLOVGAJYTEQOLPFRYJTE2MBLLWBEPJK4ZS",
-    "GBAY5KZHBQWKXGDLUIBQHAYUYQR3DLPMTHWPONWQ3VVSKTMWIJIO3TYU",
-    "GBAZ4QPZWS6PAHGSRW35GUQPGJJPSPYXJULDZMXEQMO7Y6J2XSELZBQV",
-    "GBAZDRMZKPOYRQZBQX2M5RPEEB2MD7L67WZ5PNOKLDQ7GVLVL2SGYYJM",
-    "GBB2A452MDT3JO4S57BIZH7IB5FXCY22MPO353TG6LCMO632JSEIFNXP",
-    "GBB2NFFGFE7ADS2JVGHMPMURPRVGBUUPUELK6WJ5RUHXMMEPKEZTL4CA",
-    "GBB2YZAUPWQX2AT4XB3LIXLGIHOFBD4A2EIBNHEZDHNSLXLDN4RTHNJZ",
-    "GBB35QZZWRUVXDWDVZSB4JED5HDVNHKJ76OOOQZIHFPSCX2UTLFOL6X7",
-    "GBB35TMN7RSSO263HZHB7N7DVUTOS6ASJFVH6GVELMMLL3SA5C6E4LTC",
-    "GBB35VENFLNQCWYALHD2ICLL3TC4HLF6GLF7MUAJXDA3R5RX5TFRR545",
-    "GBB3ALLO2TYIE6OEYIWDRB4WKDZWW6ESYKBOGRJZRLAY64UM3SP2OLDJ",
-    "GBB3OYFPV5NBLHBUMAB2ROXPN4ZEW4ARRHV55LLXRU3ZG2LOA6WZUFLZ",
-    "GBB3V5XRZPE5IQL226W243ANGEVZ3EHWK2GOAJ3NL3CG6F2FVOZ73BLK",
-    "GBB3ZQ35FXFSPHY4DRSK5PPPIXW5TFSEPUUECBOAQOURVBFUEUNM2WPJ",
-    "GBB44FFLPNVAEU3ZWOSLHJSRPMOQENRX7DWOCDYKT7UPBBZ3ZU2OQUSA",
-    "GBB45J3SU3QUSHKNJ4P23FVXCEFCKUUEOV2GVFJBFSOTPXLMV6ZKNUMG",
-    "GBB4P4KSLEHIIMYI6VMMRSSG3EPHBDXTTIUZZRHQMZNU35PUQ3RN6657",
-    "GBB4XSAAWDPNESJQNBM5D6TSQK433F2JR4YMJKNNA2IFNAAYQ7E34MIF",
-    "GBB4YOSFIRAST4QIWHDODZQ67DOKJQRC7UGXJOGJS3ONPLSBM6PVCYQ7",
-    "GBB5GIU266MBCPDMZ2MH5DZ46FO7B6HOIZPBSYNCQQZGZP63FDVCRBEL",
-    "GBB5GMB7JVUZKJO4M3CMYVETGR2K3O5LKRE7FPRDKUXLTXJVGLYHYSXK",
-    "GBB5KSRJTGJDZ4OOHM7C6FILWEDKLGYLRULTLXOTPVM24TLNEAFZRDGI",
-    "GBB5UU56NX6OY7T5E3YH5WUYJBVWEAZUX2GTWIMOKRAZIRGNL3QKCM6H",
-    "GBB6IKUXZGMK62XQ7MRQ3B7YCL53RQ7WQZ3TV4KKWFHNWI4XYRN5UKD2",
-    "GBB7B5BLDE4WFKWQL22CUKJZDO3WBJT5V6KJ4B5UPTEOTBLQPQFKBLEX",
-    "GBB7JXE3AZFEH54WJGCA7I5L5DTUWSLIIK4YWE6ZQ5EZCE2SQDDVWLW2",
-    "GBB7KNTHEMQGX73JPKWK27UYY6V7OXWJEWBNDSGYHADTE4X3KO7GETXP",
-    "GBBAESWYB6MUOTXYZXSD623TVR47HFOWYQZCU6AVSXVI2USDXADZSJZB",
-    "GBBAMI2WU6WJHDL3CQKT4LPXUC76WCEMQJMJIVQGL2G5IKJ2JHEVHG3G",
-    "GBBANHXCGA2IWFSPXEEEGOAACUGLH4TOPIIXOQ2BVT2STCKLUDM2P7T7",
-    "GBBAON7EHKJZHDZKHXPTADCVZNXR67ZL6DSYRFMW3QEIJHQFXB3SESVJ",
-    "GBBAQP2NU5US5KB2PASA5O7E6WLVVNXGDSXX4AEO3HYWUQLXPXKF5ZKC",
-    "GBBAUTE6EUCJYDTZF67IPCVQ2RKVTZIKKLNF4IZ2SATWVSF4LM47VDVL",
-    "GBBBCQYRCLCOU77XCI3A5NQPUEADPYFGXYLMVVFJDPAQ7LJTS6SZ3BEU",
-    "GBBBKFZTTUX4C2F6SO6III6LSVRPUY5RYMH6CH6I5EB7UUOEVL5KJIFB",
-    "GBBBKVDTVYK3D6VPRM6T2I63XHM6PJAAS5T4T25WTVL3AD2Q5VX6HESG",
-    "GBBBMJZV5WISNZBBDTTUMPYMFGRRUEIIWV6FVKXX5RDNWKCO5MRWI5MO",
-    "GBBBOP24YYHN7QWD3DTXK2HTVHZW5JMS7CT7OL23HNYZCEZ5A67FNPUG",
-    "GBBCEUB6JTJ435VPIC34XU3B722FYXRJDNRNYRXELJDCN3TD4LX6TNLH",
-    "GBBCV6ZLTFMUWDIVF2YD6JHBLQUJ3RFTSUTZ7K7JXSGZJZODQQRUUKMI",
-    "GBBD44GLB4H52G5UQGVSB47HTZNVM5XHJONKWFOHYDL37W5O3YOJZ3PZ",
-    "GBBDSENTLCWLHRXECI6KNIJD4I6BVYNHW3P75KK4TV6X4MN4LHYJBRBC",
-    "GBBEE7R5DUL5PC2T5DWZ2QAZBOBUWVRM76D5JY7BRBUTPAMPHVCIK33J",
-    "GBBEMFIAHPZPO6SZN6SII55OY3WDNNG3SZAK3HYAYZDWIBELM6JQRSXJ",
-    "GBBENE5QIRKTAWPKRRGOR52K5LL6NRXBZLZ3JKCEZACQEH2CNVODGHNG",
-    "GBBENUY3C7QB7NJHT6SOWAOTAO66FBRQXGPKDHBJV5N6OHMTZ6CEPXR2",
-    "GBBF7E3BX2AXCGGUMSI3WSPBBVA5GUVFTSOX5IUK6CBGHSQ46FVNSFC6",
-    "GBBFAFDYCSRBCZ32ELCYROPOSYM6MOEFQEWWTSAVC3EJG3DAJMVNWTSJ",
-    "GBBFFBK5VDPHOWYGNTOBUGKDMHMASN7QLXNAAUAJBTPLXDX7XYBZNQKW",
-    "GBBFJYFEOGH26CUWJGJWFJ6MADTUYDQ7UBREJMTNUZARM2OCUMFLGDSQ",
-    "GBBG5KJZJWWES36H5LSGVOQCBRTBWXJMLMMBNH5SNOGATBVYPBYJ66UR",
-    "GBBG6MXUOM2GJ4B4B3QW6L2GRMI64NBYHKUEMLLLOUZ2WPDIWPXMMTH2",
-    "GBBGBYISK2YAAD4EHEQUDVADJMEY2GSU3ZT5E7OSOHZ655RCU2CQXAUV",
-    "GBBGJEAWHTHWLCFHEDJI5QOYWBKCPJRP3TKZP4KSTUSIZTNZLU4L3EFW",
-    "GBBHEDGR3C6XYFRKN4UDLVBQVBXNLJ3WNPR67PBSD4NTDMIWPGGIRT5A",
-    "GBBHPV36R34VGCHAEYTNCVPIG6OG7LBKNXSB2AIAYU4KOXC2EUAUXACP",
-    "GBBHUBEAR2D7B7VIXRUSDAUCQPBH2BXZ5EGDUZS2D4TJKU3VN4C6QFHN",
-    "GBBIIQAP7H6H5WIGUUFTAHJMH7FDHYAEYQN2F2XUUWN5BCTYOYAPPEND",
-    "GBBISNN2MXQOF25GZCKB3N5L5CRLNGBLCIXQXKTDFKTKBM23CB7W6CEM",
-    "GBBIV656VEFL6GM5MAM2SJM7XHTAQNUOPI2MV7OVNW4E532XROK3HUWD",
-    "GBBIWFBT5UDN6YWGFT7UNUGR3SZ4NTRVQNG5FGYHJE54PUQH4BTA24YG",
-    "GBBJ5PBVTMC4GWB3U4GTCOUG3YQ4SIGS34RK2J4H5WYGPFYWKTL6GULZ",
-    "GBBJC34JGUAMPHETAJRLAEJFF4MGUWMHRXBCQFHCFRIWL72F35GETPWT",
-    "GBBJCZBJG2DWEXBRFKRA3G3UKP3G6KDWU4SRLWASQOED54CFSGDFADZC",
-    "GBBJFIV6MWMPVD52LQCS6IGX7QGDLDKF7YDBYX6R64NZAJBTYGAMIT7G",
-    "GBBJGD4DSO7HPHUGGKUGZCAHXZOOX4FXJFHHKVVGUH24IY2JLJDQN2N5",
-    "GBBJHERA6Z2TGCQ6BMYTGRRNXCZ6ACUTZ6OSTVOCNXHJNHM773CN6ZZF",
-    "GBBJN3YLFANYV2W22G734YE4CJ47TTYRPLLNZTUIUDZEXOZ3KSAASBR5",
-    "GBBJO6IYYL7JLSTFO6JDEP2KJT6K6JXA5SRDRHFZUB4BUK7BQJEM7UJ5",
-    "GBBKUUTTOO4ANSOF2NGPODBIA5TMSRKKYG62XS7BTVN7YSKATCKBFTCW",
-    "GBBLOJ5POHBK4SYZUAJMSX5HWSS2P46KWXZTFJWHKN2XYCMGLJVXXLTC",
-    "GBBLUTN47PCGROT4IJKDQXHWDSLTHEH34RAUMZU4DSVU2HV2JS5DTNOW",
-    "GBBMKIYPSC2XLBHJMEIO5KFFLGVN3H3Q3JIOC6NGC7NZHZW74BYSISHP",
-    "GBBNM2OHERRWWGV6ZUJYP6VKC36XRJSVLKVZNPN573KFRHB5LRSZKH4G",
-    "GBBNYBEWAO3VLUQUQNN7KN37CQ7JXQS2YBZIWXBJO4ADMRNJNCWHIYPG",
-    "GBBO4Q4MWFWUKVDUUF2SBZBKYCQV2GW2BUEK3GBQ4AEET3YYFBY7V52W",
-    "GBBOPIFR6P6HRMCF7YAOK2VTH5KGHIGQH47C5PP66B7QJKB3A7V3TAKU",
-    "GBBP77HWOYQYLS74NPWR4CTKFML5EASIKIWQ7ZIQA2PVIAIHGK4D2HHL",
-    "GBBPF4EFCYAPZP27CLFRDVDAR6TADHSTI7MGOYQSG2C7R7R6E6JFCI6V",
-    "GBBPWN4CBH3EMSRYWJ6GRJWXUT22Z7YJSNJYTLJOHINZYANET6UM7ZLS",
-    "GBBQ5MO7VST6SMUAKYIIAWH226AFGP6OHP2AFXFMBIQWTH3LCEYNHS2M",
-    "GBBQ6USXZRCDYLNPXVBLGTQNGYP6JUXF4ZE2EI5GEHANEGMXI3QAH2M2",
-    "GBBR2EFAFNLULYFVJG36KK2JTJ4SQUZHR5YZ7C4A4225S5SELEIS7JCE",
-    "GBBR3WJSWBR3GTSNSMA6TNCCLSUJRAQF6FYW3QI6LDBGQOMAFO3NBUWJ",
-    "GBBRGKFNOVRNWPM7BLNGFNX5KJRDGBPWG5JT2M33HCA2LLGRALLRGWKM",
-    "GBBRKKJQVXPN2BXENB2E2JTZAWJ2LVM6ON5ERQC7WLXE5PU337SG3PRC",
-    "GBBRMEXJMS3L7Y3DZZ2AHBD545GZ72OAEHHEFKGZAHHASHGWMHK5P6PL",
-    "GBBRNSEXGLUDA5VGW3YJCF6UE2DNFDAWPN7J5TGXOMXTGTXOUWNN43LU",
-    "GBBRQRMOB6AME7SPWLA7OY3U62QICV7NGU6OD575CDCPFMHKXYODHYYT",
-    "GBBRVDOB35VKQCFQ2W6PZ7DK4J2ZDSKOMCYAEJ7ROJFZPPK5OOQKELBA",
-    "GBBS2QAGGW4WNTFY4ZHLUYDERWEIK5UH4AXP6E6OFNXRLW3RAUJVI3U6",
-    "GBBS5MS5CI5NI747XGYDDJB77B5Q6AVMWMQWORR6OHIM4ICYSJCJWN5F",
-    "GBBSQOAAKA7G3M57CR3PCGRNMKKILC3YEH2QFBZDYUJZWYNGVNL3SDPX",
-    "GBBSRUHOXGW36JEL7VS2ZVD3QHLIXK3GV4LHSEJIDSBP3TIQRK4COTLC",
-    "GBBSVCITXAX5BYXCD4KUJY22RZG3VIA2M5KY4VDOR3XM4LT6F7WJNOZM",
-    "GBBTMYWXK357RJ6OKM5D4DX44QVGK3VLZLTTUQVSFDQZQAAPOE2PILPW",
-    "GBBTQDW7MXLZHL53A66P6AHO5ZKG4LVTQUEBYYJIXJ47DFND4PWRM3S6",
-    "GBBTXWUVFIMVFAUJDKA6TYJMHCBDGA55PRFIJSQIIYRGP2OLTQOARYOY",
-    "GBBVAKWLASUEIZIEZCTMWVEEJFDHIHTGO4SS2BUJHL5CUDATDVYUVELO",
-    "GBBVGGCLSNEHVQJ7TPA3KLPLV3WKUDPU6IS5D2BGTGVAX6SNVLWB7W6P",
-    "GBBVK4UZGWLWGNXGY77XLTIM3R2FKAPP6FE4C7FBQYWDB5IQ3WMD3XZT",
-    "GBBWAYIGR2FJ5MTCREGSYQESTCIY5553IG4FRJ2CCAZE7SWK6C757BHQ",
-    "GBBWF7HJIYQ7YN43R546RTPLAZXA3Q7S6SACB4LON2IMHSABVNWBSET5",
-    "GBBWPXQSYCCX2Q2KIAIYUURL3CFGOODU462RAUHCQJZ4AYCZBB4BYKNI",
-    "GBBWUBWJHIXCR3MDALXKACIWK35GW4VQXT53NCVDFKP2PHGYNC3U7SWF",
-    "GBBWZEXUOUGIRTFIP6VMIVRZULTIUKBUCMKRE3WLMAGTLQGK3FVSGZE7",
-    "GBBX73KU27XST7MJ4BRLX7MQYWDHLEXLAMDJLSDDC4X7GIA3GNUQ7T7Q",
-    "GBBXOG6QCY2H5J3BW6D7Z7CG2ZVILRIM3KT22WFCPVEEUHWGNID7AXTZ",
-    "GBBXQESFAAG4JLCIGEZ2JU4QL37KP5BVOLNAG3NHW33RLXIVNBWY7RUS",
-    "GBBXWAPBBVWHPA64X44ZWHN47O7224FDCTQOOTF324BBEXTOEDBM54AY",
-    "GBBYANXPXJKVHJCJWBWBAAYHWROEIPVJCWZHZ2MFJKRFVCUOENXTNTK6",
-    "GBBYS54BBMXVFKDPFZAVIYFBBL6LF2IX5EX3GIQHSOGCBVPZQHTCOLEE",
-    "GBBZ4XC5NB2XZVOJ6QK2GYXADDINABV7FHSZFYM76XXKBRCMPMVDSQ3B",
-    "GBBZO6FY3EVBXCIBLFGMBVBPG6CRNKPUIAZQHVHRF75HNXMHOROFFKUA",
-    "GBBZXS4DZHH6X4B2SLCDOQ4AKOJPQRVOIAHH6VT5XT7XD45TNDSVNYCX",
-    "GBC266BDJIZ7L75TMO7HQY5MTR4CWE5CP6CLVDGAN3GNUSFFXHUC6OQH",
-    "GBC2CSPRI7YUXROTMAJ5AB6WID3G337O3DMZYMLPRB43XY6P473ZQNJR",
-    "GBC2EAOKUZZQHFQ2PXJFOYRKX22IQJZIJQD6J674X3MTHE546X6VBP5I",
-    "GBC2ROCVLVMECHW2GP2X4VCMHCDUWL3VJGU7U5QXBOSNC7RYQVA2FOR4",
-    "GBC3EA7MR5IZVUH4PUTE34PU63ZCHU5JJWQOX56RFAZJHKOWI3M7OXLM",
-    "GBC3I3V6JTPULLI7KLMG7SLUPJATLDQ25SMZQJYMVRQAYGX3SYK5FJQ4",
-    "GBC3VAOUY5ALU4HBYKX2ZGRDHK6LBBQCA6EPYUZM3C4KS6CV7CDKKUD5",
-    "GBC4ETCK225EPLBWIQBGTQNSQ73JFOIE4MCVKDRO2DST4NUSIGP4EWB6",
-    "GBC536LUI4ZIQMTUH7SOB4FEWTM2NIRJENA6SSXTM4QDFY67EL57OFUK",
-    "GBC56RZQQ7NIZST7GRMZQOC4W7KGA2ZVTQRG5FEUOV5SEW7RAKJFWPBH",
-    "GBC5GL4WSKRXKGC36DNVBLDWUVROTSXAYI7ONMKPCGNPYTAKMPSDQIND",
-    "GBC5GTCGYKQYYK335FFCLYNVKVPV3Q5HE4LUEFYSFPEW66LJGF5ABDVG",
-    "GBC5MAN4BEKMOQRT6ETNZJHSPXTBGEIIJH55YRHD5TSUBYAS7H5M6BTC",
-    "GBC5NKEB34PO7H4EARXOJLAU5DO6437SJUHZ67KJ2NQ4TTOBJPQOIOTA",
-    "GBC5OEPE2INODBISJR4SIF7ZRVMBPHDQ6REPMPNM2RGIIEOO5N3FAXXX",
-    "GBC5YRR5TESYH7KG23CK335BKJBNN4T3N4P5I3IVZJEZ5QPLFKTMFCHN",
-    "GBC66HTCA65AIOIGEBOEWMHOCUJRUAXPHFQHITCXSCNJUOXZVBCTIQ4W",
-    "GBC7TE3YW35W7TYXTWWXJO3TFIS6AFOUNIDURHH7BSCBCVZQHINPQNTM",
-    "GBCAV3OLMM266S2NT63ADZTFALHJKLDXPPCJWURAQJESG5ZBYW4PN2AD",
-    "GBCAX2CTKZFXQLJZ6PQ6J6ZPXAIFL5EGDDPZ2CFXM6M7QHNAEJ6VK7KR",
-    "GBCBB4ISCBFXYTTVO7CGQ3OAZZYEDYS4YSB2HXEJVYSD3KNN3KACELON",
-    "GBCBUJYC2YXWCP6LCEXBQI3HGA2RSZI56RT2LPYQDHDPME7FHYB6KGN2",
-    "GBCBVJZMIWJY7RYYF7QMVXBPR5MEERXGMX6EJY7GAJRQHBE2SXUIVWFS",
-    "GBCBYRPEAC6URJVGE2LADCVUMHMMRMH5ZNYHB354NC7DFPF5DV6K4VEE",
-    "GBCC4DOCXMRU3J6BCTQX6IDIHOLPNCJEQKNPX5OSYUWH2YDQKR36XUBA",
-    "GBCCD4P7L3KR4ANAMKB2F4R2N32PWSL5LFCRHRV2NCGFH72RYR2AKXEP",
-    "GBCCOMB4Y2PRNJ57HB6JN4Q2UNC3WUBVHB6YSHAB6HSDZPLAVHU3FBBB",
-    "GBCCTINV3WQR224LYWQIGFFTOPUIIXFMZR6MWOCZNN2WTX6CQJ3DOBAY",
-    "GBCCUVCYYG73OOX2EI5WZHBWVXNOUODMCI4MIGXRFKKNWOQTHCXLUJPX",
-    "GBCCV2BP7URFOGB2A74GXVXOFPGOTFHZM4PBBTMJY62QVHUXJYDAT253",
-    "GBCD7LCE4TL2HM4IZ7IRKQWZU7YQK4JW4UDH53NKV7AZE5E5HX7E6UKS",
-    "GBCDHN4HBYHX7RWUTNAU7I6FLR7WRMVB73NK777OD7NNQLWIZXJNKRKJ",
-    "GBCDNK5VJGLDZRYQDFXO46QEW4RBN267MVHKNFLG5YNJHNGZD6GL7Y4S",
-    "GBCDYSK3YHQQG6NGZJVH6M5UEA7UGKOSJWPIAXLNFM3LPU4AEF6ASNQL",
-    "GBCEOPG72E2SY245Q22JNEZVO7S2QIEGP4ILI7VTZMONOFP6VF26WWXF",
-    "GBCEV5YELYAKNTVV53X3GA6WA3O4RSEQI574RDKEIPP5NIOYLUIFBWYB",
-    "GBCF3MT45P7PHAHYGVEZ2ANFYXZUQSN3ZAQZBP2TV7ZWFTQS7JL3EKDY",
-    "GBCFHLTNKA2OIYRZ4HGIRRN45QP637A7L5KULP35LIAU62RQUQRR72UO",
-    "GBCFWH4WBBLNS3UGRFWYLYR77H7BC5VRI4ZVBKHIKKPLROSZPY4M7SF7",
-    "GBCFY7VTTLZBVSCR4U5WKH26HMIG42R57XTLYOSYYBPNZGQFVXSIYYS4",
-    "GBCGLBZCVIJMHK3XWDYWNBGVOBALWQ6AZUEZIEOCAMKPU3SQKBZVEGI3",
-    "GBCGTNC6QKQC7KB46VOLI5PQVQW5QI4JWUSMO4B3M3NQOARJ4XPYELON",
-    "GBCHBJANKCPZ6QRERYPV3UX72J2CCM5IP24QDSIBA5WE5OR5YOY6K5PH",
-    "GBCHEO2EB3I4ZX6X7TIOTELSLYLKMV4NC5ANNQGP6JRIKBK4LNOBOHE7",
-    "GBCHHHLN7CSB63N3TOMQLSYSND45C4YVWADEEXBIQY6FS6LADKJAOIJC",
-    "GBCI4LZBRQ2ABMKSEXIHN4FBLQEBFFVPGUVVJRNUIXS6Z7CGX6T4ELON",
-    "GBCI6MX3YX3UM3PHMJE3LSQGLGBMZZZTB4YL3TRMT33JZEOAXL6GZL7L",
-    "GBCJDIWF6J2A74WUZD3DDYHPIM7IRIXOODNX6WY53Z4VHK3G4UPCEGI3",
-    "GBCJRICUTJ6D5PZQAHGBODTLLVLWGY7VM2NB2I7ZQJKSQBFJV4DPBA67",
-    "GBCK4Q6OPXUOG4Q7PGONXNDWPZRGOASLYWCHXP3JVAP7MLH26JBXMETL",
-    "GBCKEDR6UZFG2ZCISVSM6ZPORJIZ2OFE7OMBE5WYUV44CYTM5Z5URXUB",
-    "GBCKFSCNGPEM23GX3P3AYIDKBQSWIETWMYI7RAKJALG5PCS6IQPYNUWP",
-    "GBCL6IQTVCTVZV6TPGTXPFPASES7JQNU6X5LOZLQQJRMRXCER22L4E7G",
-    "GBCLBPQ6FTYBADSZJWELTFXDVGL2UOYBGBT7KDJ33AOD5QQSWHPTBUIG",
-    "GBCLUHBNM2J4IPVPD7IMKRZ6PMJMOBZPX7PP4AL4ZXD6IDNUYOJR4KEX",
-    "GBCLWVAV3RBA7EE527CLGF27MGVF5QQQGWTQ2UEYOHFYXNF47UBNJQXO",
-    "GBCLXFLQB5DYDRT7ILYP7KC6HJOCANJLACZCIEMW5CZJIBDFFKAHA4TU",
-    "GBCM3OU5Q4DKWUCBCS4UXMPFKM3T4XZ2ZIAIDYJ2KTKRCVFH765EWVMP",
-    "GBCMK4HEPPA7HTYLIX5O7DJYAVVASAWY4O73BRBAW3OWJPOWY5QLSVUD",
-    "GBCMORRTFW6ANRNBGMUVGFRGLOIN4K2PJIRGXEHH3NLOJKGPRX4TEMZ3",
-    "GBCNTG3ETVWUMZO6N3I3KHBKENVJNCXNDNMFIGFEPPXA5VJBXGWHZWND",
-    "GBCNUMLECYGRMX2XYSM5MWPJC6F3ORXJEHF5HERGC7AYR4RGKOWYCSAP",
-    "GBCO5IWP2NK6FG6ACMKXVDOLG5WDGRCNVCJPNLZ3X6SHIFB4LKGS3AKP",
-    "GBCOAADHESBZQEQTT6REN6ZILI2PNYHIXM6DA5UQQWZZZ4NSLB3CZNHS",
-    "GBCOJXJUSRET5Z4ETBJTMDZOIUC5PTZIXLSE75DX5V4TDY6FSENXWQNT",
-    "GBCOVYR6TMZWFJ3KE3CKWF4XPR7ESPP2L4HNEPNXHQNSFF7OJRWI4RIC",
-    "GBCOZE6OAUXOZ4A3YMMYUUT4MXQB2VRKEITMX26RBAQK6HHT7K26PORN",
-    "GBCPGEAO42CAXL5NBHL6T5Y6Z7S2R2XQKSVGZII7UTH2T6PCJFXLBXZI",
-    "GBCPYUH7A4OAVAEEJCOISN3VGFC6LXG4M4NSRQEA2DF6FT5EANEXCGKW",
-    "GBCQGBMSNUROHJ23HBKVHRHXXMGX5MGTQOGHJVLTSNUCCZUC5JAC7NVE",
-    "GBCQOLKJJ6XUXLA75FRLNZC6USRKKTIZ3FWOIGDR6V5NVF64G2SVQSAP",
-    "GBCRFXZZXVCRVMCRBY2KB3VCUBAENH4X3P76WNVZOZPAISILISCECELR",
-    "GBCRXZWZCQV2V52H5WV35QJJ7L4CZ6GGMR4AMZCFZ33JHNCTMZ4XK6QB",
-    "GBCSGTWRF52D2Y3S2U27J4MIVWN2L4RIXA2B7U6S6PSGID4GSXBULTRA",
-    "GBCSK3UVPB73BO2VBMKX6MAU2ULT7U3X7XPVXDLOJS7BGJRTAK4MLHPX",
-    "GBCSPVUOMJGNDLWFF33FV7HIBIS4BGMBSRNYVPD6BK4IAFQPUEV4HXRP",
-    "GBCT63HMFCEDWKZ5AE6KEQL566L3CM7Q5VJDYZZTEXFGXCXNNH26LVAD",
-    "GBCTOWB3FAI3FX42VFZGRQZZGGHXH24EOWO65K3WMXH64XECM4JDQG3E",
-    "GBCUAD2TWCHLHKMKF2RNO32GMBWXTB2HNJEMU6PA2EJJSC7IC33GQSOL",
-    "GBCVEHXZQP3TTHGLW3IY4P5EUVI4VESRNTIBEMPX2YXKTFW6RO4426IO",
-    "GBCVH7QMKYWK53QKHFWETW7SMXYXH56F5HYUWWIALKDQAYVQZPBPQYAP",
-    "GBCVXH42LSZEH6SSRG5PN5V4MY5YKQMNTYY4RAVRRTEMSDCHF5HJ3ZUU",
-    "GBCVYFLKNBT2FCNHCBZQNZGGSTO3NBQJJ6RXG4UKRNZG3BOWVCLQUD5J",
-    "GBCWE4KT7CZEW4GFGMYMIYKH6HKCB27GDGRYHH3Y37LRYFD5ZA6M7WZ2",
-    "GBCWEILSSXDGIO2QPKW3YDKRX35XSXL7T5WA2HNN3FKM7NUWTLG3AV25",
-    "GBCWI3HCGPMZFWPR3RU44V7KUPRC7USWSQ2SYAUO3E4BVCJUH6YGFIMT",
-    "GBCWPXAUCBODE2O5ZHPPRIGMLLCI7CZFFVPQ2O5JIHSCTSZSGD2EH2EI",
-    "GBCWSPHBTFW5DXGPHAPCP4LZTKEAPGXHPVOPT5WQXRVQBUGSKZGCJBB7",
-    "GBCWTDTMJGEFM26CH4UXDLNNJLFIKOL6T2QEXH5TQHQ5ZZUKLZN3TUKF",
-    "GBCXNZV64RGXGXY5GQRMM37JAILM3RQBCYU3TILN7JSHVZELE5RKOCAJ",
-    "GBCXPMIKA2LMYFHOG6366645XWNPAOPVNF34LVNTRZUDXWNDR7Z3BDSM",
-    "GBCYJSJRSR7OFJXKWDLLJBAEANLCP65LLPBPVNI4NKKAZFF4XX5OLYXF",
-    "GBCYL2LC76QMDZLGMD3OKNEJDBIH2UHHCVEEP2ZSPOFTZGYYIV6OU5R7",
-    "GBCYP3JORI4Z6KJWNDBA7WDNQP25DYWJQ3XG62DIOR2SC4KRS66RHEDG",
-    "GBCYRFC7TY4VGKQDF7EVB6MTQDJDHTYAN6W7MG3G4Q7XVUMBTMMZPT3T",
-    "GBCYUNH7VBBVOP7A4GRQMXYUKBGOY7U35XKN6HPXXLB2RJ3644PKCARD",
-    "GBCYVBWM5QX7SBM5UK3MR7YMYD3437MJXA66TV3EYDZXFK36QLYUSQJY",
-    "GBCYXV7MGU7PNEGDXQYZC6VPVTDHLH3Z6QFVI7PGDMKMQHX4B3623ITA",
-    "GBCZPJAEIU5JQM7O3TDPKYC5KLNSXLJ2XAQ4A3DAA5W6UJUM7XCD2B7X",
-    "GBCZVM33L2QREGRQD5IIBWCXJLMEHM7NUUUUBM7OQJ5ZDGWQC3DPHAFX",
-    "GBD24JVUNR4DMK54NEV34BMSAED2T7MJI2IUMWYINDWBFDVSIHCOIADA",
-    "GBD2A634V4AJCN5ZSBR3YS37RVTLCTB5DCF6AEGWVNGJ5HPB33XIGT32",
-    "GBD2HOBY5E2COV2RHF4AMANBN5MYL2EP4X2KB47QF4QTDNGKUL7LARS3",
-    "GBD2ZQPPBAPGJB5UEF2UCNBBUIXR3QIAVUVZUGURXEQ7ULUPOF3SDPBF",
-    "GBD36MRAIHMKC3ZUDUITQUR2DXZLK4XM2ARCYJ6NTDIZDBWAKXBNIG2M",
-    "GBD3R37V65PXTLTHADA77SOHTIXOMPYTRIUECZSZ5KGQWIPZMM2M5L35",
-    "GBD4DMJPM6RFKP5YLVNKB45DQLZKWDJL73YZXXJ6N2LVVOR7Z427232T",
-    "GBD4UU277KGBZ22U7HMVPPRIAYEFDWLDPXNFNOGNCQZQR6QGYQWISLVR",
-    "GBD52HY4VAIMSEG4FS6Y4KDVI6QEB2WXMPBJYZ6HQM3HKRXM2BQS6YI7",
-    "GBD5BYE5G6HUVA5P7JT5OFR2QVF2G7L64NRS7JIHZMU3AJEYNNFSPJEK",
-    "GBD5JANGJIXWEU7PPLMP6XNSTWVDPWFBDQ6KTONRHIIBDYZ3LYPN6ER4",
-    "GBD5NHD6OQ2YMEBVESWOEFPSLJ6Y6PCXRDM25YPOMTGIF22R4ZZOYQIL",
-    "GBD6F3VRDRJHTR7HZXAQDSXFXIMCQAI5EW6DL6RBSFR3LI4ZDE63V7QU",
-    "GBD6JILC7R6VNSS2GB77UTZWHXCCRM4KONX7ISMOPM6OUDJZSG7W6FLR",
-    "GBD6QJA4MFWMP6O27HM5ZGSEFUGKNXTD7JXWMC5AEWYAYEEFI3VOU2UO",
-    "GBD6ZYLDRZ23ULI22HCHN6JS3Q3DJK6IKEIE4SHUZVDGOKQCWNSRXIV4",
-    "GBD7E3YI3DEYLBE5E4AY5LOA6AVZUXG34SLJF7VFEVGR5UKSGRV2743A",
-    "GBD7ICCXTV7IPJ4BP3X3VX42PSYE2HL3IBLEVUR7URGJZTSHLRUFGCQX",
-    "GBD7QFQVR4QWNEJSHP4VN7RAAUKXTMZ4EJ4EBMCR7CP3HMF7RXEASTD7",
-    "GBDALS7LT4SMICLTVAWHENG25EUM6LW5HIU6H4VIKNOFDMVICNKSTOCK",
-    "GBDAN36YJJKZPZTNH6T6EULXOCLLSYO4DQPDVNJI2QA3XIMKMUBMYJMR",
-    "GBDANPO5ACZM5SPD2IM6C7NJX2DWLEW2NGVJIHXKET34SZQUQVW7EUWT",
-    "GBDAONDQSQYNHU5PHC2T3UG3XYMHX37AGSTQNZH5NX7BXI3IPA44RFSW",
-    "GBDAVQJ7XKNY5ZHIQH5RZEKW3HKRIFCO3HLR25MQKZLRZXBSTZQE6A6O",
-    "GBDB76AEUDNNLITWQXSMHCV3EGU4PMCCACVHD24RFCB2SLLPRXLCJ63F",
-    "GBDB7S7D3GC2X7IYTCTBKQKA4NXUV6TROUPESZB6AVDDYFGH4W6HRGVT",
-    "GBDBPTKCBPWZKWBSXLERKDOHLPZGO5XZBWAE2GOR6U4U3BHGPKI6CPSC",
-    "GBDBTF3XLNCL4SOAEFUA35OWWTFRT6IN54CNRY32VQEBYSAXDC6PSAUS",
-    "GBDCDPXIP3CPN4RUTWP7UUMZJ3ENIHF3EFMVQ6ZQVEOATHQ3ZQPRIDW2",
-    "GBDCK3CEAZUHZNZE57BNGGDOKHQQ4CPMXWASIT27GARQGNFEKGSMQTMV",
-    "GBDCYVRVVXSAPDFODSZ2AKSHUIE7IK77PEAJHSN6UM5RNFAABQ6AMQVZ",
-    "GBDDT5JY5GWOTNCLRCXPPJS65LY2X5DOKKX4LYUJRBVCIP6GAGBABBOX",
-    "GBDE4KB7NPOZJIDAYWDUSKL5QLMOAHOZIMEGIDGTMKLXLTXUVBRKBSIX",
-    "GBDE6QU4IHPCEBXTFSHYL6PVNHF4XEOULJANTJX3RBVOQXBRNJZVUB44",
-    "GBDE6QY23JHLX5V3Q3VTDGGL2CGE4IYEKYQWZVXF6YEN42HAUCHXGHCD",
-    "GBDEMWU4PAQOHUVWDZ6UQ4QPQ37MPW7CSPOB7YWW2MUCPYKMZLJKIZBM",
-    "GBDENOJISSZBA7EZC5KZPBHRIXKI2525E6EPA7TRKVNL5N4K4UCNN6EY",
-    "GBDG4NWVWUO2NNZIJVZQICYEEFVUMJUUNNTFCYQBALVM3XPFEGSGIHAW",
-    "GBDGC5T5CN4HRPU57Q5KJKZLUVMRQTS4Q4ENAVNE3SW43VTANNO4OSZG",
-    "GBDGCKHLVVWG7GYZ7DNHDN4N35NM2PLH33VCBA6KFJ2WRV2SS2QVYPK4",
-    "GBDGDGTMXRLVZA7V2NMPI2ZXG2GUM3UQA4EKBAUK2LOFZHTCYIDSRKX7",
-    "GBDGHIA35STQAWR4X3JJBZFL5GJQUCPT5M5XTKJMQWHEO6ERXIH67H7N",
-    "GBDGKDPV7W65AYGQRPRXETZHYMW7FH7DTHVXQATSZQIKHAHKYBTS66IA",
-    "GBDGRMA7PM6R2ZOA26IXWYZ472JRXY5MBFVQYEZAKIYDHAKEZ3LFSW2X",
-    "GBDGXWNNY5UFT7YGQILXRCYMEVVMIJSPWN3WEXRHLUX6NZ77GVR5BKIE",
-    "GBDGZ55JEGTQUMF5SPFNEU6FU55M2MMBJGCOVZV4HKDERVCHPHHGLEQV",
-    "GBDHDDPAZ4FTPICFWAJPEWCTDLMJYMVOBONYE5FLUH2ANK3OZSFUWMNI",
-    "GBDHEMIBFVESG55H76YJYZVBA3WWUAGNEBZQJF6B5ABXPE7WMNUQAKHT",
-    "GBDHN3TYDAEMSZRGQLVRYF3QPZO3VCB6AH4U3GQP7OUCC3GVIRD3FLGI",
-    "GBDHOCNZPS2QKSV4IBPAP5K3I77SIGG4MRM7DVGYOJ46JWWJO7H7JKOA",
-    "GBDHXBJLPFZBZM3GMFRKE7CWJREBYYQQMKWHEM2SEK4MH5I7C35CUYWW",
-    "GBDICO57MUY46O6GYMJHMKIWUGGX2LW3AJVY4BZGB7DFRIWBFW2AX3X3",
-    "GBDIMGWSMM3D5B5HOBWUO5OFGCQB6MNAZUMHWOE2KNFZSYRWKRDFQNTM",
-    "GBDJHCWJQFJ5DCPGTAJEB3FCFFCL5IWFMPMVTZ4CLAW5R63MBF32KAMC",
-    "GBDJMCRPTIBVEOON63LX3D2GP6O6DSOLC64PVF6R76JKSDYC5IA3VMDU",
-    "GBDJP5VCNIGNYKHQSE2VBNIPLHLZ2KXFUEQGJKSSKNODWIAHQRGVN4EN",
-    "GBDJPAIC3H3SRRANOZX33UQS3SP5OBI6LPLUAVOIXHTT3XXL7X3TRGTV",
-    "GBDJTGIDSORSMUVWQLO53J6RH2KPNKFVZ6ETS3S57VH6XMANY3RB346X",
-    "GBDK57BFJRAX6RTBE27O7SH4S7DGUGZKWRNEP3QT6VCDFMV3J7JFXY5N",
-    "GBDKJSMVSAGGARWL4PCSWNECJYYAL4Q7RUTS6ZEXQBBUOTCDSLG7BVJV",
-    "GBDKKXWJW7PF6SUMVJ3ZEWDGELHVFJVWMRRRAPQ42R2X3BH5OYQJJTQJ",
-    "GBDKLIACDUXNZA6FFLXPYV2RBVMYXZFNEQHBBBRFVPYIEK2GKW6BAX67",
-    "GBDKQNDPMGB4X6RTZZZ3DZ6X7USXSYC2ZG72UIU6UWN7Q2W6XUBW6JHK",
-    "GBDKVHQ5YENPQ4NAK2ZVTXEZV3MGRVIG6MNO54TYPMJSANKCVPAITUNU",
-    "GBDLAWBD66E4UY3N7NJGQHUQU32YTWY2E6P64MZJQSPAY45YLTYMBHRT",
-    "GBDLFI4NIFCH6O23BO7KOAMQLCFUIGDEOQIGNLWDPWQF3WTP4Y7RYSLG",
-    "GBDMB64NWNICL6SLVNSJWRPRA56MMOFLZ3O3MSG4ZYRNSXLYY5FACDU5",
-    "GBDMBAASTDU6MFFAABJXQJHHDMNZ3NYNIGX7N64M3KRMPYOLSWDHIZTP",
-    "GBDMC425UUOB24TVYW6IWYAKPY7GUXPCPPR6BCZ4YKWWD3M4KBMK3E6R",
-    "GBDNC4ZP5VYV2LH6LKRKW3M4HZLOQ6DS663KJPGGKEAGBU5CKHNDO5WN",
-    "GBDNGMX4A5LZ6XJI4KOLINBUHEWDSTOXOG6CRMO26ELUW4XWJBVI4TG7",
-    "GBDNOQG73RUQAPCQDC3QV7K357M5HWH7G2HKH74N53IGDNE77X2LXVTA",
-    "GBDO3OCKUVKX7PH6G75BGJZTWGBWKGF7OBXTEMMZNZBGF4QYA5I5ETHW",
-    "GBDO5EHJB7EQ7XY6WHQT6PW2F7G6LFVM7AD6RX6UU3R3FRTDHA7XQHPG",
-    "GBDO6YFZ7MYZ2MYB2C5HYLBVKTBSNDORLPCE635JJMTMSOOCZ4KDWY4M",
-    "GBDOL5DVS2WZEXWXNAHKY3JQUK3ANZ6UIQL4P6FYTSZC6TL7S5JFOREX",
-    "GBDOV2UNTZJF5MNPZZBDOZPDB6XPICJDIKM5CY2YSC7Y4AWTN27TN3CD",
-    "GBDPIERNNLSYCT4NEIJEHU4BJ653HUPZDSASDGADDG6IYCR6CNWJKRMT",
-    "GBDPKZJ46VDU2WDDN6M4ABZE6YDCX353434OLZIARVT4B4GOVE3G2BI4",
-    "GBDQOPYGYWPQJBHWA4WLXXFGJHS3NND5H75ESD6UYTGSULYIJF7LUXWN",
-    "GBDRLEEHR2KSXNVWXOQPVDJ3EKZKY4UPNBTZ5OYRYUPXCINFZGZTQGE5",
-    "GBDRLV3323VRROFMU5I4R76LOZN6TOUQTGQ2JKFZUNBEIEVJM5JFSPCE",
-    "GBDRQIPHDTX35ZU4DQOZGVN7DKWONDLN5IWGL2W4OMYLWXN5JB4HEZRH",
-    "GBDRQRX7SCNWEIJYLFTMKNLAIJ7OGOEOCVXHTVH4SJ7E6PWOF4VFWDBI",
-    "GBDSH3IZRSOZBJLI23O36PCYGRXURKAFAN4QLGP3C4RJ2YKR42JD67DJ",
-    "GBDSK5PMR4MXK7SYYDBOYC7BAD56IYOCZ7F7CMNFZBGROWIEH7OHULTM",
-    "GBDTBUL5FQNF4JZVZUEUS5WIEAW2ZZCZFDAQH4JDJPIMH6AEMKFYSIMB",
-    "GBDTG52LPG5I6PT23TXTQMZ2RJ5QFIOMDSPBPATOTTIKU5F2IF7CTKZL",
-    "GBDTIZY7V4PQ4UJDRXEVUKWWGIPGLTJ7TADBHGHQDKZU56IDHPXXW3VZ",
-    "GBDTQC22VUIWLY4GNAMSZZV55YTSPF4OIRCONB62W5HD7TTXBS3Z7IEY",
-    "GBDTRDLW6FOD67GAANLDT4YCNXEU2V7A2APARUT3GGEY6X2NRNYBJXPW",
-    "GBDTULX46FRDPD45LWS6PZN3X2I5ORKTDUMCT7YO3Q235LDTBTELYCSR",
-    "GBDTZWN5O7EFTUJJDSMJE3IDG46WWTEQEIDETWPCRVWHGDPYHEGA6BNF",
-    "GBDUHVI4WTUXD7OZUYPKA22VC2QGOJI4MJLISMDUSRHHM6VA6ODS2GMX",
-    "GBDUKTFGMYQPUAI6T7HWEVLL5HCPDIUDA3U64CNRGR7C2T5NSOYPSUSD",
-    "GBDUO35DLZZ4PW6YXNPWF2NEQXBHB6J3RTLE37E6Y3KYZ74W4OHDI2C4",
-    "GBDUWNNAYNG5SUHL7R6TSYFR3IBVQX5ALSLYCD3BRJF2NC6RQZKAMZEK",
-    "GBDVCBGVP6VJGZEMKQJ6Z3Q25CTVONPVY6AEPIWO2IUOQLGP2Z4QCHQX",
-    "GBDVFMLNWIWISLANXNWHW7BCDPKOYWMDA3MYGAFSJVBUO5JEVEJR4Q75",
-    "GBDVKMIKEQ5YDF7UAYT5A7F2UELALJWZRRQ2SHVDPKZMHOQCENNQJESF",
-    "GBDVLOIOP2HXZEF3QBK653NNW45JY3D3777MCKLUSCV2P76EBT22LIR6",
-    "GBDVMAJRLQRY2Y22R22B7HKDHMF7FMVBSXNG44VAYHOBOHM2BEFXHCFU",
-    "GBDVS22M2WUFG5RZL3ZYHH36D4C7GVY6FFKG3JV7KIL4E26SBBPBOVXI",
-    "GBDW25P5LN2ZEHHMWKIWRVQS472YY4MNZVL7T3JDZNQ544J22L4PTDLD",
-    "GBDWCDBMCTHVGXAQU25NLZXO7WQFJAJWYWHO5BMPHRLYR2T4GFYAGTPD",
-    "GBDXII2EZLDCPTZA2VKVJJHSSBHFKTEJDXCDTPSPDGKLKAKEXT4FEESM",
-    "GBDXK3DZ5X7INUI7YDD7WMZSWUBKESNUXH2SNGN4KHBJJMBU4NNQGM7N",
-    "GBDZ6HO2RJSBPTLVKYTXHGI5NDKU7P3A57BW5PX2PYEWO2GE45BVWKX4",
-    "GBDZASJO7YSABEYY66UI52KQ33TOJ6WCJGP54ZRLM5RU6IAMNBN3XO2A",
-    "GBDZBFDAG2V3FE7QBYQKTC3ZFMFBEPOAL3XXPMPUUKM43TBY53WJFUXO",
-    "GBDZJ7EYEWKYVNP6YJ6ZMTD7TIORCNPEYZIZONMPYXSWWLIHBIHRVAIK",
-    "GBDZNRPHN7BTPBZIUGSG2X2RQWTAQ2SBCXW6OQDHU3KRQCP5BEA2KCTB",
-    "GBE2BUSS7KIMYTTMANH7USYKPRYDYRRK26QOOZHGKA25P32327HWSQ2N",
-    "GBE2COODPTZOUJFIYQJN7FIZQQBTKJBADXJNCHEGCXON4CJEXKUKRSYR",
-    "GBE2FDV3UC464LRSN3N7DVPAHM22OHGZ2HPMHQ3UMSL2KIAZP2NWB5X3",
-    "GBE2FQA4IQKBBYV7UB5VYHG6VPIP7KD6E4KS37QYEUMXRDMA6ZSUK3OS",
-    "GBE3GQVIXYENYIGJ5LQB22CLSDOWSK4ZH44CCA3B3IRBNISXUJ4A77PA",
-    "GBE3SRLSPKOO7BIWXU6RD3IXDS6UUFJXJRHS2ZXFJ4TBLTMALD7I6TKS",
-    "GBE4ER2UZQX5YRA7X6BDQVVZURTWIAMZ4QRC4MXR55EWRXZLJTSEHDS3",
-    "GBE4MUM6UTLXJ3MULPYXNBPXCLP7Y443DQIURBVUDQLB4KM47RJJKO2T",
-    "GBE4R2HALOSC2XTW4NIAB5JWOGILREBBYAD47DEPELODYHIZORECY6GU",
-    "GBE4YOKRZLVV5PMVY6L4AOMFSOZV4BJQ6ZB72BTHQWZNWZZSS4QBPBAY",
-    "GBE54OZ7DG23RTR5G6SPADGCS67AALA2X4YWWOQR4XZP2UJHYPSHI7XU",
-    "GBE5ID4FLWLSIYRSH4FMKV5LC5P75WHLCBULMU3SZRGH7IA64EUDW2A3",
-    "GBE5MZN2NWMKCNGPXKKJBWL2FR4EE2AZ4INEBGL7YYN6QFXHTTBJIBZU",
-    "GBE5TZKDLYLY3PASUK4V5MRAY4MAVOHBM275GQXHNTPEFIIWNXH6WHIP",
-    "GBE6SDHXL5BF3JSMSYWZOU2PVIMLTHLWC673B6742BFRD5CZ4M3CZSUY",
-    "GBE6XU5ZGNRW2O4L4SMZAARMCC2E5SD4NKYCDK3KW3T7KFVF766RPIIL",
-    "GBE7HT3GC5SE2W5T2PIQWK3X3SBMLL6XOZRQ2OWZDDDT4RQF674W7JN5",
-    "GBE7IXTWMO2QY6HIRLEZYKXWD3NLV2XMPKANOXX3OHWM67HTV6MADVNA",
-    "GBE7ZOTRLBICDLPWMECOOCIIQNAKXJJ2U5EUNZ2J27SAOE4RZELGOQYK",
-    "GBEA2KDFOJVWGN2SG5IVZD4M5UI74SKSGKFCNNWR3Q4TWENHWSAOHXKW",
-    "GBEAG6EKRBUHCPKVT4OFFU6AFUX6SAYHQBGHLRUA65HPRDPDLEEOLO7C",
-    "GBECQQ4JXDSNLG6D6IMY2X4R6654HFIE3F2QLD7AHKJVPDN6LIFPGS4Z",
-    "GBECX7RRQIWT5APLXVWDPC5HNR5MSJQNWALYY2LEDUEQQ2FLKU5LDPWU",
-    "GBED5OEAERNZ3JWYK3ZZGFKCENIF6HWWPNWQOXUI76DZGRBAM7WXEXSG",
-    "GBEE4WBEOCG4DUJA753DAH4L7KZP5VZDU5MHX2YDGHAXLTLBEVI3NXJ4",
-    "GBEEAVMP6JQ27XAK4MUKCTUPHB7EP4LQS4QTB6QPSTHGJGJFTHBQ7BPY",
-    "GBEEEGFA5BSD34C5LC3KH2U77CBTN2RNKJNV5BPOGKELWYNX2JNTPS3U",
-    "GBEEJXVDFGLDPDWHPMJDQQT5FNMPNI3TSWRQB52QYLISNIW7M7V3IX7H",
-    "GBEF5DSXFAES72GRK6UNIFOQJKJUIPAE6EKBNXUYIXN6OYXYM5RLATOM",
-    "GBEFAYCZYGIEYJ6ZMOBKGJG4CKGIQ46JYHH4MMJG7SCPMS3KOUSRH35I",
-    "GBEGNOCVPKP7VABY56EWR6OFZKFA3LZBXJK66EVXWNUPFPHZOH352ZCO",
-    "GBEGYY4CKRXHUDEBOIZPIPFYU3EDMRR6QDMMLXCF6DGEGYS3MGZOHFCR",
-    "GBEH6B6X6CGDOHVL7WMZGQA6INZJGLOOJYUV73U3FN35EW6OZWLNDICE",
-    "GBEH6VCRUI3OFM7WKNCI3R7QWHRMMNEIV2AJKQ7C3PXXNPCRUBJUWSBI",
-    "GBEH7OJWZDG4NDFAH4DRTSOBTFSPZ24UXGU5BNMHWUTYJWNM2U3MHPI3",
-    "GBEHNT2CU6EWSBSIAX6EOD7ILZGPFILJWUHGVNOW5N3FUS33KBVHRQFS",
-    "GBEHP7HNENAMIRJ5IIIKHR2PBJUSIT7VDGSMAZR4OHNNQZBZPD2M2YUL",
-    "GBEHWZOMSGWN3AWXDF356D2R6GWM7RPBAGT3P54NI7QC7BF2W26AI56A",
-    "GBEI3BRDNM77P2VJZZBXRVXW4Q7E5JGZJNH45Q64YIE5QHBDAZOFG7EW",
-    "GBEIBGLSLFBTJO66CG3Z33SM67OHSK5D23HZKV2YAPIRCT5GU742ORVT",
-    "GBEIRWXGWKYDCDV76M3ZNCG3FYS5WGOMTDPMG2IRFA6JJAK4N2IBQQNA",
-    "GBEJRGIFF7U7TS76IBAA4D7IWKVZJR5ALS6OKFHV6ALA3NGCOZJJ72WH",
-    "GBEKAPMEIPM5RATTLH4WIHVCGENZR6ZSBKWLQJIQ2ZRVI4JWIN2EI3AZ",
-    "GBEKRXUZ7NDGSVE6PLB3JWGQUTRZLB7IZ5ZC4F4A2DVIUWUNFC6UQEXX",
-    "GBELHHM2HGTXK3PO2QSURSEJ27XSJJLCJJD4PYMJXYFKQURCEMQ4U37E",
-    "GBELUU5JPXCUQGCZME2ZZA47HNEZMT73F5S7LEP5NACUZKZP5XOJMZOT",
-    "GBEM4JZ4IMWLADTMQ5TUEECWK4ZI5OGC6H7DB46WVS63QON6PRWKDMXU",
-    "GBEMDV5LGUZMBG7CR7JOCR62XQG6NZSGOBV24TGVWZ6NKGKQKEW5S5K3",
-    "GBEO3GIHXV5YCD6W242VCAHX4HM55N2P6NRA4QQFMTBGSYXGXPAEY4TB",
-    "GBEOA4UO5MNIGTOUJX7S2YEUZSB6BIOKTAQZRDKLTDPLILGY6IFDFARX",
-    "GBEP3GNYOT6DJH7HVPWJ4MQLHGLIFWGFVDK2EOLKJJHL53OOF7XV7XLF",
-    "GBEPS6UMYL4PH2ESME45KIYW25NFBWF7LSY5XCOTMG7KNQEFNC4RQMZC",
-    "GBEQJUJBNUARNK4NS4BUHJILXKM5AOS33P6BVBMMPX7BUMUHWTWJXNJF",
-    "GBEQOGFT6Y5PPETYO4W5ZFMU7J5KGJJNNOHGJNSZQKDT2QDOQSY4TJXU",
-    "GBER67EXX4QTYDB5VRWNC54R35LQ7EGYUEODFWF2ISMIKCQMECASJRYI",
-    "GBERYDLREDCF7RJQEMBRCW345NIABRF7B6ZCDQ7X3HGMRZ7GDOR2XOIY",
-    "GBES2L66Y6VQM5O46KYGSNWJ2DKTRDQ5XGS3RLIHKTAPCJDPUIYPVBGT",
-    "GBESR3JEBAPNN5IDUQIPYAUNF5LEHYPCAK3VIQDMZDGTMH4AJZOSSKGW",
-    "GBESUH37NKQZMOXN3LLBYPEZ35ZHZIPOYOSDWR64R7OSCM5FJ276HSTO",
-    "GBET7HTX7REEGL7Y4S64PSFCF6JSJECOFC357NQXU2OSUQ5GX2RQOMU4",
-    "GBETHKBL5TCUTQ3JPDIYOZ5RDARTMHMEKIO2QZQ7IOZ4YC5XV3C2IKYU",
-    "GBETKOCOJHHJJOPKGWVWKNDILJPEZSM4AHHDBY2MRVPGLMZBFCDDCJRA",
-    "GBETRFGBUPJHGEYMQPKGBWUOPGHZCSUMCJNTFETG46QA5OPNMC2JZKNJ",
-    "GBETUTAZDZKPI5F4YRAO3ZVOSZQCJMIRRAVCXP6KA2N53ZCUSOLE7AV7",
-    "GBEUHSVCY2NLMU67MOH3JZ3J5IRIUS6IIGRPXC26O63P6WFZUEUQLBUZ",
-    "GBEUYU6BMXN7UBHKNKXRB6RS44UJJSUTR5YYVNZQZ27BNUSRRT5OYMDE",
-    "GBEVGEY7L3AU6MLDN6TJCQUS6QCXH35FUNSO6GBORE62XX6MWTXPJO6E",
-    "GBEVL53SFQ2DKYY6MN65W4JPEM7JQFXOERGOJS4BSNLWSSBYUWABIJXG",
-    "GBEVP5ZQD7YIV5TMAXQ7KJZTY3NALWYVPJ4V5ZZCNT42C27F5HIK6PPL",
-    "GBEVYCQMDQGBD4HXEAZPUDPJ64ZCT4PD63WZJAU7IZDGB46KRC2NJVID",
-    "GBEW2DVMQYM3QFDG77EBYZVCAV3SYJGTWWKJNSVABQLIOPW2KIHAZDZE",
-    "GBEW6ERKP7VKWBYSNJSTELJKXKN5PMJBDC57QLD2CIMQMTCYY3YIGVYM",
-    "GBEWFBJJUICUJJDRM5FDXPJCEYRDLP7OKGHQNPCRVZ6NPDCPTTDTBTCQ",
-    "GBEWXUXPKKNEO2TMETHV3BGUQ2HQE4D2N3RBEJYQ4QFTBUEVB4U4PBQT",
-    "GBEWYO4ZCFSBQSHNT6KPVOM4HX3EEXA5CVNSJLOTZDOCFDVM3O7H4OWA",
-    "GBEX3FJYSNAMB55HME4I44XX3KXBHLUE27TI57GXQWID4OBASX5QRVI6",
-    "GBEX6OGY7VOA5VL6RKMXT5IIS3BNSJXQIY433I4Q7K3QP34HMG7PMO4S",
-    "GBEXBJ645TIKCSFRQDZ2QBPR5EXFSZUZB72QF2PDDEK3J6R2OAPMOWIF",
-    "GBEXBK4IR46BQ75KLXE4ATKPXXGSVHUE557LHVD4GQFM47X7CZV3KI5E",
-    "GBEXGGTRXASWMMQOFCE7SRMUJ4O6GHLKZGYHA3GPVUWLUEEXEKYX64S5",
-    "GBEXJQPV7KQDE5NPXL3DF6RWZSHODAWC5YRWJ33NNIMFWECP5Z3PICMH",
-    "GBEXPEBVPL7LJC2HUZ7ZKIRAIMXKHMRW7JIOH5YOICKSHDQ5ZBEW5KYL",
-    "GBEXYUYEIC6JE6GOPHFA4COZJSG3RI7OYQ2LSNGCRIHEBELG6VB6GN26",
-    "GBEXZ4DDZOIWVEDOPULY5OXOLECZD53EOQY2ODE7HT6NVTLSKNIMFCHN",
-    "GBEY64A43XNPUUNVA6QGYNT3JZA2BAKWLYX7X34K53X77XNR3TJOMW7P",
-    "GBEYNRAJSZCU6AVPSA7GDMPROLAVXOYR63SXVAEPVSAPEEGXW7XASIJI",
-    "GBEZBGZ2SOH44AWTG7YJ76OH6NH7PKLHWP3FIPBZN365UGKSRHDYQICP",
-    "GBEZKIULAM6SNUI5VP3WT5IGI3X445HASTSTCI4BXN454VD73GSBM7K6",
-    "GBF2AYPNAEFUC3ILWBSNLCPHLB5W5LSAK33F2WI7VF7UGR33QMDGH2T4",
-    "GBF2HBDB5FOOR3EWRBENXAUDSPOTJBHQQWQBIJUTK2LQWWDHLOPHSYDD",
-    "GBF2KHKMTDFNWCVNKYKJDJTYC26FNVBMXOM7JURGOWBQMNIXVUVFJ37K",
-    "GBF2P52EAEQF4GKP25EEVMAJCRKPR57CD2XTCBRKSXFYPXHN2TXOSYCP",
-    "GBF2RROO5JQNJWG6Z6J5RQYU4AVPUCTCRSYW7G6G63C23SBOS2YYCFTC",
-    "GBF3OJZOWMQKPPZBMNMTPOLXJYEI376L2QJ4JE5S7VFFYYF6NEQJVBEU",
-    "GBF4EJPRUHIUK4JYBBRPIUOZOMOXN73LS675YBD4IQHTVZLZMVDGHDBS",
-    "GBF4FRH7JEYUQUPQSSX3VW6PZX6H3CA5LA6KF2S7XCGSEK4I2ZTFTNEO",
-    "GBF4GE3ZMWJENS5F6KKPLBYILWAHASZWZW5NQWVIMPO4AFXXLWSHFNOL",
-    "GBF4OZHJZQDMUOCRNKVHNQU72NFFFKDTQXRCHYQKVMRLLS6YMH4YIM7H",
-    "GBF4SDGP5UCGNLMNIKW6TKRIOTB2MSHKZIKZAVLPZKQWB27YBGPJFCHN",
-    "GBF52QUKHP27ILFXE6IE4DFBWQFRDJA6ZUBHPYFEJL3762SWTOLCWTR7",
-    "GBF56G5G45TPIFYZZDJZYT3TWB6HIHS2WMPVF5H7STQBQYUDP2SARQB5",
-    "GBF5KU7R3ZINJHDXOU2AJD5EJI7A6TBFSWCRCAR66SMUQCXNRF5O7NG6",
-    "GBF6ELBIDUQOQT24J7XVTJ6RFKKQAXGDUOIPR6ASBYQ7CXHA36DCM3ZR",
-    "GBF6NSTCKQ6C2WTLNJOQGAYKJBZPTUFSNIEP2RUGKXMZ6XGBVNJYEHGD",
-    "GBF6XDJA5Z3XWW4K74YUQBFWSENJD7IQBYM4CIISMFI3E52KAV2O2LK6",
-    "GBF77RF2P34NBYHQNHXCVG2QD3DYV32WTQ7WQCZWX4OTIP7U6HPTIKP3",
-    "GBF7C45LSTQP6BOCEX4KE3NAEKBHOJMP7D4SA2U55SLQQWASTV5OUS6A",
-    "GBFA3DW2CLCLBA47DLV562RM4JVWR7NQ4FSODCVBLYLWFZ7WCIMR5T3E",
-    "GBFARVC3R3AK55F6H3TT5DEDQFA5F7UJE5L5ERZIF35G3URMC4P2X4X3",
-    "GBFBFLUC6FVKYPHPTTJIWCXYLNBOR3AANGEJHPVQKOHXZ7LW4GNCJPSL",
-    "GBFBJA4476OYF3MBNQXUVXQ37GSDFCMJNVX2ZXLF2VHRUHB3SLNMOQDN",
-    "GBFCCE3EZZWZJDO7QLMZGWMH65G4OWZO7UR6B2BGQUZU7AX6JQNFF3VL",
-    "GBFCRFU43JBA6F6DZ2TQSOQAYOSIVR5YJZLGFF4X2I3DHF4LWDLFXYJE",
-    "GBFDAEMQBSWAMQDNDFVEC5RZF5IBQUH3TWL7NPIUTBEVJ2ZSLABJU4NH",
-    "GBFDBEG7MDBUMAEC4ESIFWAHBABL3JAHS3LYNX7TRFQLA34F7TNRFPHB",
-    "GBFDDEOR4OWXXPYVOTIV7XEWR572B4MUZCEI42HU2FGQK32RIYLTE4EY",
-    "GBFDVQ5KF6A3MNEGBQUEW4EXGXS3VPIIDUSYE6NZT5A7DAOQEWKZTAKQ",
-    "GBFE74UBNFAGBUUYFALAXMAWNF6XHESIP3QLBPFDVVWTPW6V2ZWXXUGB",
-    "GBFEBXDBVYY2LR65WDQVJJK3AFFKAGZVKU3QFQQERSCON2OW62WI5QSA",
-    "GBFEEXGL42L5S5L7GEFYXJHTS6PWRVL4IYXQCKFOTSOI7WUOMT3EY6SS",
-    "GBFEULHPAXRSPYKYV743NTBC6KMGPINNJOVSOU6QDSHI5CNC7FSVWHOK",
-    "GBFEVCIYZ33GAM4E6EIMTJ3P5OQFRF27X6YWX75YLENUYJNNIOAOKM6E",
-    "GBFEZMEVPHIBPABISNFFCU2ROLQ2XDIDBWXTCZ72SNRFFIQUYBTNIKLZ",
-    "GBFF3DWA2AOGWKLVQML2IVH7T4ICZCEDEY5N2YQCY5IWMZU424PR3JON",
-    "GBFF3HYPCJGJY77XP6PZRVEO5QWZY3QU3YGHGBHRQ4ZORZW5TW3F4XH3",
-    "GBFFAIFB335GO7ATTSWKOPT6RFE3NQ6SFSTVG4KV65YULD3W4G3X32HY",
-    "GBFFMKCRSDT7MB6WE52KYFEEYJOZHW5FPBHMFOQQFI7ELY4FQLPRGEOK",
-    "GBFFNQIQ24NA3V46XOJEDDGDZU3ZYWTSUJJJ6SVYNISF5LIQFXMUY2XV",
-    "GBFFUXIV5WEMMQ3RV7UN6ZGLJFDDWEYOPZUQTBEMG5AXFTBFOYHQIEE2",
-    "GBFFWJOQNF45OJGZPXXOMWN3GHBRMIRTQLK5IPKVKKTTVAXTNFY7SAND",
-    "GBFG6SFC64JVZ6BLYSXFSLGC5PLKARXZ5PVUH2YT2J7RMZ3ZFC7D5LFU",
-    "GBFGSFXLGG4NYIQ4THM2RTXRJSW4ETQJLK6Z5Z6FB4GDDIPJYTC6KTZG",
-    "GBFGWX352HEJI7W7X6JPDQSYHT2KQWQSAS3WQKABVBTWW56U3R7BOYKO",
-    "GBFGX7OINSGJWVTTNSUYRPMPDSF2XFUQQ6766IALUU7LD42Y5AVONTMJ",
-    "GBFGY4AOF2BQNKB4NWOP2X2CJH3F2U4AMCWUK2CPVYHPKT3SXMQGXBMT",
-    "GBFGYSVFX7NAYWIF4UR4CEXFASHLRXL6EFNSKICH2ZDRZ6SX2HPV5QOR",
-    "GBFH32VEVOYGGWPNF6BEOYUM77HFE6ASGVQASDJETMVAUUR72IPL66GW",
-    "GBFHNSCPUUFZ5FH36YO3NCSTO56WM7UGYUX6WY4OEFW75OPY3KLEIXAT",
-    "GBFHUJFWS4QPQVUMYDS6WNVDJRKORHXOXKS6LTKMUCSLIU4DLUBV6O2F",
-    "GBFHZNPKVAS6E4XBV47VHDHASR6YMZVYIYFLLP3TIEYNGCIBAG5K6355",
-    "GBFI5NEEW3ZZVUKVGICNB5WHB7DUOCPZI2SDEXNISTUJQST57EVTEWAT",
-    "GBFIFAVNERFKCW664CJN2NUEBHVZF3D4TROXRBW4QWV3PEYCXNNPGTPW",
-    "GBFIHDNIDRSIZT5WRXMC4MFYS6Q2FTXCM7PJMHXRHUCUD4AJEHQ2UWN3",
-    "GBFIHLDGCLRX7Z5XQZT67WBVWBBJP65RPTLDH44TBEEVJJRYLX5SP36T",
-    "GBFIRNYFVGK25VCT5VUS6PTXVZB7H2JMQ3ADQQ3IMFYS34LV7HO6ULMI",
-    "GBFITV2KLD75Z5QMFDXD7JIW6C5H754AUTUG3X4T5AWLZHHPRV3GBBOX",
-    "GBFJ7TNN2SX34SD46DCNBOYM4O45KRERDHQ2NCN6GWCHMSQ3VKIPAWJB",
-    "GBFJGO46OV6E2QS2ZUMCF256ZL4BFOZWFHULRNLPSPW47HH5HFAKJTON",
-    "GBFJVL6W4DG4KAWSPQYI2Z4STHTZLQAEAB756YNBNMFIXVQRCAUBFFNR",
-    "GBFKI22OT4TKX7TNBESEGX3MCGTZIYJA6V7I6I4BRZJ77U6ZY3G53SW7",
-    "GBFKVPDLPIOH32YKXYGWH45ZAMWDZWTHOIJKPGZV6X7LSIGD2ZN5C5RB",
-    "GBFKYOBTQSMMOPUPDRHIBIPT2XHWPHRIHQZV77GRLVR6ZC3VOHE7K2YX",
-    "GBFLIXR5UXS7IWPQAGQMKHCRWDHYGACL5J7MTVL5F7TGLYK5MJU3HIFQ",
-    "GBFM5DP7KYSPHU5C3HPFGR2BXIIJRHTAVL2CVZ4BTZZ5JNTQBDXC47GE",
-    "GBFMBDMFXQRPH7ZDMHQSG54SMYMICI6BM2YRJHEQZAPZUCHQATVTWTQ3",
-    "GBFMELIFS7ZURZNOCXBJFDLI7T3WWFKMVUGLHK6T33PXMZ5CISCSSZCT",
-    "GBFMGBJPDZ3A3XEXO6AFDNDH33TWK6RZ2AUN2TKN6ZV5HDGACKMQXLQ5",
-    "GBFMLGKQMAFM6SK3TATSXNVQWU7F5MW244TVEDXJDZHH2UI43PFMVNQ6",
-    "GBFMPAQMIHXNGEEC5X45IXF2CLQO3AZS776VEWK7CLOZAV2PCG5XBADA",
-    "GBFMZ7POBFXFOLAMDGSBOOZZTIDWNBCGPPOAADCEHIFLF5XIGHBYEQVK",
-    "GBFO32VVFDW72UCELNEPXPS3EVRYQLOXSECQGNNAZFL44T3UDJ2LBNVI",
-    "GBFOAZPXQYOUE7F5T7T3HAXUELFRIFZ36XTIBWAPCB4MQ3NN4FIFINNY",
-    "GBFOIDZ7ZWF52SCFN6K6KKETYDPH3MLGC4LEILQYMLDA4VCJBC2IEK3D",
-    "GBFOKVYJ3DTWBLQRPRELUTW752DHAFKLYME4YHWBHDU6QYU7GHVUEHVQ",
-    "GBFOPZSKWRLNKFXTNWARUZOUZOYDU4DLWFWO5B7Z62Z3KSYCOGE25HCB",
-    "GBFORWWJLNMZP46662ZFYHTFJ622RA36NMACIXZSDMHGTCEAYLZYMUZV",
-    "GBFOVSJNPRHYECRHA2H7AVHHSKSWRKMLMSW35TDAQB2PLTNMYSJZWSQ7",
-    "GBFP4QWJWQM2VB7ZEEEZ244OWBTFFMB3PTBRNW4MGKDIWDCC5LI6NWDD",
-    "GBFP5AIVORIQSTM3GQWTBHYV3S5A7KKCO6DGR267QX5TXO7JOSGOAXRP",
-    "GBFP6VCQGN4YG2YVYIBMPLPKNNETX4EEROOXZN76NXOCBGA6VJGVMXLM",
-    "GBFQFBGGNRIOQFJ2DY4H3JVMECNBFULOZ7G5O6CKCBT2GF6JNGJRJPFF",
-    "GBFQHUXO4WNFXBJOYSOBDLQHXVXDHO37OS6RIZB4TCO7DOJQUJISV5MN",
-    "GBFQJU7L42X4KFKBWJOWSONNZ7Q3XW3SAPFFGREG3RBB3KSJSSCTGRAQ",
-    "GBFQQPD2PNSEBXSFB5RP46VGLO3T6JULEXBXFSFP3V5Q43OOWCR5ZAWN",
-    "GBFQUIGLRGYG2Y6O4VNIKAL4MYDC5BYYRJVFH7MTCNKCNL35G4RX2XLM",
-    "GBFRMEQF3G3I6T7NS43IGO55K7CUKDW2PNGDCVRFDRFYKWMNZYUDL2FJ",
-    "GBFSD2NLILNGTUA4TQKWRQ35S6WJ2ALG62KD3AHLLP4CU6VTKH6FQ7V6",
-    "GBFSO7RKUMSBLFH2HFC5DG76A5AI2UXWFESTYADSWBTYUW77ZFXBWGFX",
-    "GBFU7PQN5XMLIEQWG2MD7WFTXEOCBRNGXDQW4J4FWOM2MUV2TDGOPBWL",
-    "GBFUNCVFHHR6SVUFBSSZHFIBXTY3R3OGB7OVHZEEGALILFDFJAFFL6IQ",
-    "GBFUUBP5LSOJGKJJJ3NQSS27VUMA7UXXNZTVMBAPDG66MGBSYC35D5AW",
-    "GBFVSIC2CETOHEJSLLVUSBROEM4OLKN4FD6HU2GQULVAYJUFGCODZZYN",
-    "GBFVSSMIAERUOOFPGSIJYILEY6VHZK7JVXQMMN4UWPCNOSVGWGNFEGQT",
-    "GBFWKLPTRHNRSBEOQU52LSY327MBR64A6CIKJM5FTJHTEBMILT74VELO",
-    "GBFWLA3O5FNFSIWWP7GSG3CI7CMFQNQJPO5CKCLK3TD5COMABBRQKLRO",
-    "GBFWNQUOFYBFTGQOZZ5WSWOLOTRIFOU2R7BQEXUD55SQSKVIPFRHBSL3",
-    "GBFWSHG4R45AP4UNZKB33TJADMRTXV6WFD6E2EOQOSAMRKGUBF3QGPNS",
-    "GBFXG2OGXGNJRZMULYLOUFIECZWPUBN5WEBNSLGAXD4W7A27QUMTFQ2V",
-    "GBFXH47RB6I5Q6EFK4RDGDH63D7UCBTEZWSPE26LNALZPGITS7XRPEPQ",
-    "GBFXYVZBV46FDFVBZPKUD62BLJQJXQAVXSUKYMTF2KSCRT5LHLNY2XQW",
-    "GBFYPFZMJBE5XCSUPDAQVBKB3VU4VTVSFKP5PEGODDXZ3PS3CGRWHH4A",
-    "GBFYRU37CGVRZ6WO5TIN6DM2GYSIA5BLRTPTDZ37SNN5BXQJ5MYFIPNF",
-    "GBFYVID5GDNXNS3EOABYVIC7VLRAQ3JGMFNJA6LFMSPUNVYPU35473HF",
-    "GBFYZJN6MNVC6P7C6L3HSA5GX3PRLD25LPQRNB3GEBZGVQYSTWDCHODD",
-    "GBFZTCBSN6NH2RISF5YSYLX5AMXQMLCX5EZUJAC6NH763XAMAORWQCA3",
-    "GBFZXQFTXXILFVGTTWJ6EKJCTPSA6BCEY2LUJIQ4GD347SNZH524T433",
-    "GBG25DGMNREA5NAFWKFDCMEGLCXGCNMCJZZFO6HW7PGSSCL255RUQ6F7",
-    "GBG2G6C2CEPB3ENVDOZJNPZYECBFNDWUMGYP5EFXZDZGR2MPKXRSX2PP",
-    "GBG2ZH3IL4D6ZDSZ2AABAUVBL4UUUXVVQZLWHB5WXQR7HI3D467VEGEV",
-    "GBG3YB4AYJ2EY7UQPIKERJOJYOODA2XX7DDQY3ED6ZPYPLECVPLIHX4H",
-    "GBG4BFUGQZQYMAZ47G3L23UN4BS4SXXABPVULEJURVEVMS5PYBKQELON",
-    "GBG52CKALPL7NC5G6RJZAXJYMTOLCS7MAVPZ6HYV4QNXYDXELQBZ4QNQ",
-    "GBG52USCONKKS5D2KGQFQBQYUWCYPN6OHK47V5ORB4Y2YAVLDFZVWTNE",
-    "GBG5DANUO2DH4CR43CHP7XWAUYLSRB3OLWBSVTESIQ2RNH4Y6EUZ7JUK",
-    "GBG5LEK2BZ4HXUWRBEQFOMRCAIZBPXQDSD3WBE3OZY3U5OM2LULUI7XL",
-    "GBG5ZG5GH4ZU7CG2CS7VYNGOGXL4DKZ22G7VD4UQY65THW5HUYWRXLLL",
-    "GBG6CN57YHNTFV7M6Q3CCXD3F74GZ652GB2LKPF7YBXGDQVU4GULENWX",
-    "GBG72VKJGTMLAX7NNRFFGHHUU6IW7HNOPHLMW5HMDL2Q6JGFX4FRJR6Q",
-    "GBG73YVCZK5HUQRVKCHEX6DPN2NCSBDYNGKOV566Y5I34H63BGP65MQG",
-    "GBG7IFYL6ZC7AAVY2S3EQXKO4INFBJULMW3R5D37SWB53IBCIEH2Z5EG",
-    "GBG7RZCELAC66YNOG6CSRWILN47TWIBFNIV2WQE6VRPME2L5CNIRQT7N",
-    "GBG7WN5P7VRYZFFZBNGBVK5HEENRMY7CCHLJX4VUXTZME2VMU3SM6FIB",
-    "GBGAE2OZTJT4FRG72SGYKCROWGNMCYVKNZHN5ZYCKYKRLXTCER5LESZC",
-    "GBGBFCGGQMOCL4WIWFFAZGKDXMKHKYOGKNU6ZBMBZRH2WKFUINODQNMN",
-    "GBGBJ6ZR6GEFB5THYNZ3TG7PGDK4MGVKJKITWUQBAMEVZQV5ORDFJVK2",
-    "GBGBPZ6PGBMF2AKDGCJUVRTRHIPFWJIE5INIEQ2ESNRJJ7GMCBR6U572",
-    "GBGBVIMEALUMOKP23TRWXDM72FA6N5XOMW6VIPVWC2TXEQ2EYS2JUSDC",
-    "GBGBZLTGOCBGU4Q7O6PWRBO3DKUJQKJ75GCEKLRH4RHQKU5UNNY3JK4B",
-    "GBGC2RSHFERVZBBNM77HKV3SDTENFC5YAHOMG55DY4CTO4PJBWNCDQYA",
-    "GBGC4N4OVWW4LKUOR6R5WGB4AQMPINKOFXC76P7DP6YNW5NL2BU2VXMR",
-    "GBGCF6AV3G5KN2GNPYCD4XNGH3IDAVDN4DABJ3IJAADKI2ESEXVCUOK4",
-    "GBGCIWTVPUY4SVXUFTLOMVEN2T4R2CSF6BBLO2QWTT3HRWBS6P3FBDET",
-    "GBGDHXEGNJEKA5GTQADTPTLVGFAPK2NTPGRC4PXEPII6EELVNANDFXFE",
-    "GBGDQZHIK36ZVCCCD3VDEWE4PFQ73OIH3U2NKUCT5IMOJ6TU4LL3EXLM",
-    "GBGDVYUFEV3RML2HJWRVG3HTU6ZW6HXONGRSQR4R6H4TDTWWLDLRFTOM",
-    "GBGE42EVPEEW3D75WBW7CG3PHDELQXPPC2YFPU4GOW5B4WJ4YBQGGIEY",
-    "GBGEIJ3MWH22OAIYXLWQBIQVTP5GOUH4TDY2YCINES2E2TNH3UH6Z3V7",
-    "GBGEWTEPURUK5WM3R7Q5S4NBZJXYIC3YIU6RNQKPMODNOZHD3XJIEBBJ",
-    "GBGEX5R4ULMGPSMCTNNQ7ZI32TTDUXQIK26ZUZUGMVSPNZFSQMPRT7X3",
-    "GBGFIKXDY26FSVRLG6IEL2VY75S7QHMZOCFXP4STOCE7FAKIQB2O4XLM",
-    "GBGFOLFVIAS5I5UXSRRKRBOSVUHE67UHLV5QKDGOHOCJGOF7OAK3MHNB",
-    "GBGFSZH2XDQHY7ZVPWS6RGRLJNBLEEZHB3JN6YMZVOKJ2P3PEKAMOS3K",
-    "GBGFUZ6MLNM436YJL6NUKOFCT5HK3ORIVHZITQTC5YIXXU5S6JETPLAT",
-    "GBGFVB25ATKNHI3IEFLCVW6KIXEHZ73Q2NFEFXUVFW3YYQYJAFLDQEPZ",
-    "GBGG3OOBXFUEPWL5GCL4LQ2QJ4QE7ZIZYKQGE5IQDG5JAD7QWPDN5PM7",
-    "GBGGXBREW27QKPGJWBON5EQGSYMGW4FZBGNRFZMRGHYXSIKY77C6FURX",
-    "GBGGXE2HWPJEOPMKTJFR72SZTNN3QM6GNB6XFR5BDKFKNY57ITUBRNBC",
-    "GBGGXR2X3JMNKUTRRUBVXQTTAWFS2VRMCM56YK7GTWZCEFRRUNZJS3OM",
-    "GBGH4MX4QCAG5MS4RA3CAOP2UMRM5F5V4ZONZZPVPAUBFMICOYGSELON",
-    "GBGHE4TYD5JXSWC72GX4PQROHRFSPLHNSTA7U3CUIP377622FMIK2JNL",
-    "GBGHGGAQZWUDIWBSJS6CUFUU3Z23GFHZ7JO7Q7HKSO3KK5S2VXI2K6UM",
-    "GBGHH54GTOD3NVZLPKDA4PPPLNWLGYZD2IIZABPQC7Z7BSRX55FVBAUI",
-    "GBGHMGCRNOEBTLBL73ZR5R5P5WHC5X24244ITKUDYKXMILJBIPMCNPBK",
-    "GBGIQBELUQZYM5BDQHWTCGBVSJ4I5EWMJSZMOGSWGMXTMZIFRXD5ZEWE",
-    "GBGIVP2CQOUIIWDCG3LESOSVJBB6MQZI2DHJVRHW7S43M5K67T73TYM7",
-    "GBGIZTEHQCQKWXXBKSNNZPGRPIGWJFQBWEPALTHJI4GOYQH52CANLBLK",
-    "GBGJ42BI6WCVSEEGGW2P4GG3JT32GNUEOZEYXGYZLZY62H765TG2G4SC",
-    "GBGJGAPPF656KP355YPI7YKX4L2ITPAAC76DFOREEIODWFIC2EF2LLTA",
-    "GBGJNI3SDPY4ECPBHFIYLVPUMEMBFY7Q6BGFWL4X5EOR6CID567HFAVX",
-    "GBGJS7ERAYRICPL4MHOL5PQULRVREHRU4WVT55KLZGUQUCFFDRVLGLEE",
-    "GBGJUBIKVROW6XSWPKHW3FXLEM5O4SBGWZ55SXRSW2WEHSWSMLEJGMMG",
-    "GBGKLMFZFMUVTFMUPSII2XSOIH5PSCSHNN4RDFS5BJ7323U4XLQLLQZS",
-    "GBGKPXQTWWG5O725O6GC5LRSLM4FYRB7475B7RWP6W3RQRTWOCS2MHD6",
-    "GBGLUEDEWCRQIIVBKL322EWFIYJLBWJXMIB6FOCDQMZMQQIVNV552N5F",
-    "GBGM7TB2JGZ7434PR6K2C4RYTHJ5ZSU2UY6JTHBM375WKCXASJPM52M7",
-    "GBGMEBDDZ2355XCKM54WDRLETFYUWLPI4CN3QUVGF24CGXBBIQDHOJRP",
-    "GBGMHE7HTPSJWIYCFVY6KCPKHSLJJE3CXE4CVHYQGKAPOLM6N7T2SJU4",
-    "GBGMUTOIOJ4ELEIJ6SKWK7VN6XISKZXDYH3VXXBQKOJNMNGLNUO534Q3",
-    "GBGNCEKJZZUOSI6MNBPFQNH3GWA6ZAL5UHS32ROY5OKMGTBJREUGR47N",
-    "GBGNUKLMB4ODNZPORGYUHW2PGXMOHNKSZMOZJUTSXTFELAPPHNPSZGES",
-    "GBGOCWTNHRN2K5ZDPDCDYUUFTF2MQL7FMJ2YSDBVWJGP3CIS35YSG6GG",
-    "GBGOH5VHXAYDZBEXBCNZM3HHVDY5D4G4ZWWTCE6A53RLNI3E4IA7J25V",
-    "GBGP45532FOHOMW25WWALE6HTJ4FK36MMC6I6F55MFPKRCJSUYWWYFKJ",
-    "GBGPBSJSLUDX6ETZ6XW3M4DYIPEJ6FXFC73PLPW34VRCIEI3UDVCIVS5",
-    "GBGPOMZQPCWN3AFGXYM6SC32YUNG5MTFUT4ZD2XZNCRSLTD5XWUKQ5CI",
-    "GBGPTFRNNQWPZQSW4YZYVOE6V7DVL3IG4DFEHLJYBRQEAH2WDR2RQFNG",
-    "GBGQ4CP74WVBXIFKQXI3OJUN2DGWRU75ENG5O4ZLB7AGP2JQ7Z6YANIB",
-    "GBGQFBAP7GCBN62QC2Y7VDZ7BFQGEHVV7PNWVVE4OPFARCJZLR6P4MQR",
-    "GBGQGNHVKSGPBNBPFBCROJ3LRFWBWAL54PJY27LQUIOJQPETUCIPKKYK",
-    "GBGQIKUMILPW5LRO7LMOA7IJSALMEOVQKHSYHMEFUMHO6TYZ2TGQJ3PP",
-    "GBGR4QA6LKXIMZZVTZBHCNBI744MQH725LYL54Y4MWVNUKWB2UWXJQZ3",
-    "GBGRGTH3E5MA4NX3I4LAMZYP3WXNTAGT7JPWQD4DBHB5IDCCEYKOKIIU",
-    "GBGS3WYKUXREYUFNMBQQUFGGWM3PPMPRSTAF4JKF2DZIXNFAUFJLUCWB",
-    "GBGS77GGHZEOC6UCAHXTPIXLAD2HHLGNVBGSS54JPSY4JOEOHEBETORO",
-    "GBGSI7U4FUJGT2JUXYYKUT64KNW7PPOASC5NIDJLPCWE4NZKBG3REI4F",
-    "GBGSLWFW43NDKHR3HJIKZEIYGUOT2CFGXMCELS3ZIY6PFEL3SKYQLCSR",
-    "GBGSVGHGD4WVLFOUJLYRHM5GF7GS5A3ZWNLVHIWI65DVPKEWMN7ETUVG",
-    "GBGSVGR4COXYDTU7HFNZ4W2RL7XVQUHP7EMQGCAM7SQALPDIEGONXLM3",
-    "GBGT4ZSEUNSI6YMIOCZ4VTK5USNO7KRMMAXFIBSHNO4HNMM25BQX7JHU",
-    "GBGTJKGP6GCDMT34G5SA2IQFPBQKLXG5EW23ZCZDJRXNEUCG54JQUTUL",
-    "GBGTPWUDJYERHVCHO5GNLBLFYZLTA4ZXCYD7PPIWGXQREBBJ6X7TE3WG",
-    "GBGTSRIY52JKYSZQSCXFGGNEGLRNXD4SHVVW6CLHKBIMJ7QBWK2VYNK6",
-    "GBGTVHWSMU6WAUNGAYTZBQDK2SC6LA6PXSPTT3MNKNNYRM3ODW5NSBAB",
-    "GBGUGTEO6BB3SSSEMMIWYUKRD6BEDWD3BAVCN2RGNL55YTULL32KYBK5",
-    "GBGUOMQYK4JZWCSSTWPXSM7LG5TBF2Y3RWARA2UPUNOY7LKTWPJBTATE",
-    "GBGUYOCKFI6IQ66GJA3HRREKRXXXRJ7YU2LKFGAGE3B7SRVUFX2JFGO7",
-    "GBGVQGMNSQM236N5WNJA66TR4LEYLDHYPTA62HNITIEOFPXSINETHKH5",
-    "GBGVSTA7VTGNCBM6BWIMQIJUOSM3RNOZBCEMUQP7LIQQSZDAHP2ECVIA",
-    "GBGWHCT6GCDOFELCTFNLLEN2AIBQXYOI5QVKF2BGAK5LWL644ZLTS7PL",
-    "GBGWL2NBYF3SEV6ZPTMDOG2J2PLMYN7AGEZOLOWVKM333CLZYV5B57M2",
-    "GBGX7UKEF7Y5P67SK7HSFD6XIQ4HEEBUZSBYZVKIHAKFSYSRCNVILK6H",
-    "GBGXDICBAKDIQ5WMNLG34GZGM5B4PUL62T74SJ5ION7JVMZZR4AMX6AR",
-    "GBGXIJUABWZJ4K4CRBFZNEV44VFUFMZ7RHH45RGHHCR3F3ENI76A4NJM",
-    "GBGXRKBO4ONYE2ANQVTRGU4JR5GKU7CHQ6HWHXS3VGDANGIDYRCLUSDC",
-    "GBGXSBUKV4ZP5NBVBITADM27X6HCV6B2NKBGZTL3HPQWUQGFCCMB72T7",
-    "GBGXUNBXJVH7NO5M7AOB6LLBFRQV3PYLYL7SONIFBYUOSGIZNHZFKINZ",
-    "GBGY3CYJZWUH54YXTTW7LGS55YO3WJ7FVLWLJMVEQSFOPL3GSIDEXNW2",
-    "GBGY5PIGUOO4RMMY6U5DEFH6QXGRJN6ZRNFCXCUIRTSPJRXZJFSC3Q42",
-    "GBGY5QCT72K6RUCOZOUEICOD4ZQM5Z43KF35FGK77UYKUMS7EVPTBUIZ",
-    "GBGYRGXFGAJQVIKPRMPYHVCF63EKJQPRSYKRAEMWLOKY524SA6ZMNBTC",
-    "GBGYSIQJ7DREW6P5K65PZPR4N4TKJBQEY5CKDF57CFJB23LBDPOYABDC",
-    "GBGZDCTLRAHNJG4BQJT5PQGSGZOI4LGKVQLVKGTHCZFHGHAWW5TFCBKD",
-    "GBGZNER53X4QWDYTLCAIEJQTXLTZJAT3KMYVTVM3YWE4BB3X6XNJVXFF",
-    "GBGZVR2THDUREWERELAM3AWZ7QB5XIAMX7BLDFMQVEZC7AUZX6PNZMAL",
-    "GBH22WMO3UBFAPOXELSYKLKY47KCFFUXFV7WSRWPNWZPBJQ33ET5AERF",
-    "GBH2EQFS67EIFGZDO5AEIEL6FZKB7YVUEEU3LXCCRDK6AQCPJ5MOARK3",
-    "GBH2F7SZULGU435NUD4DD7O3FFZRE3NADGQU6ITNGEFH4VRDQYHVAAPL",
-    "GBH2GHACQ4NHNI2PCZC2SGAMML5BB5OKZ3CKDWGW5G6N3JYQ6G2J3RZ5",
-    "GBH2MMDQK5WRPAEQ6KQILMHU5CBTSSSBIONRAKI6C4AOZYW54NE7YTKN",
-    "GBH3BOGG3NH2JS5ZBAI44FEFVQVEGKWYWGTUPF77HDLYVQ52FNQ6AZI2",
-    "GBH3GICOMVOGNO4PB37XJWZ3VJREY7Y3RTL7WV546JUWY2FNUXC3F32K",
-    "GBH3N3ULTLIFP75YXRKQVDBRMXTW2YF2KWW6NXARGV3UHSDSUUNWUSTN",
-    "GBH3TDXYGGOITZVJTQVIASUR5ZL24MCJNCOW6GL77TUQT5HEU5WRYL2X",
-    "GBH4E3LOIQO6ZLO27HUCLZZ67MPRJ257EDXFEKHFKXCQ5JCWUJVGTG26",
-    "GBH56T5HJL47VZYAVY737LHP6CPG5COTKWDG2N2IQATNBVMWCQSOGNKB",
-    "GBH5CBDQVQLIJC7NBZ62GK3YLZ3DWHFLOZQCJXWCBGUY6GFZ7UCWIVM4",
-    "GBH5UA52XBCLM2EUCR3BRYUVPI5ALAPMZKM5VAPFOTTKWCFYNYECLTEJ",
-    "GBH5ZODBP2CZ527PCEM6ADYFPXLNASID6TZ77MNHJDUS4EFM6L7KIHRT",
-    "GBH62FNILYXHBCKTYX3KXPBPFA47IDH7TSDEMNZWW3WHD4LYOEWALINK",
-    "GBH6APNAOGOWA3V6RTMLFSTV4EIX7WODX2SEWR6Q4F5ZUNJMJGEDSHIB",
-    "GBH6HCPS435WEM3ZXN6ZX4CZWL5KOYSCPUGAV2AMF3YU33B24J26XDWF",
-    "GBH75HWELMJSM7GWK5YDAVGAUUX5VXHQKQUPV2OCUH3LIZA5VB4DDUXO",
-    "GBH7CRFOBZXQCZITZMHH3Y4HUE25KB3OYUJJLWMB6PJU5YVLYWRZUJL7",
-    "GBH7F6X3EGHVQMIU2AKJYQ7EEWS6ECPAGUUZEIW34QP4R2DFMPIUZQGE",
-    "GBH7PN6U7MMNLJPIANQKCSMY5KMA3DNF3IVGVJNPDOCM7WSDIK5IUDDS",
-    "GBH7QRTALA4JFXXCNHMS6V4MEICEIB4CJ2U7QILADI4Q6MVGZTC4JY2P",
-    "GBHAPZGS4ZB7CVQSYNIZ2MT327KQPLX3YZR6BGPF7MB466AZ3UMYQJXD",
-    "GBHARZKJ3MHLPSKVRQG7HNOOZTAPN5AZRR6HLLYX5LMHO5WOS2QOZUTC",
-    "GBHBNXMN7AUYMAVNMXJCSAQMWIKBJ6VEOCIXZQZGOVWZOMFRORDDBTCX",
-    "GBHBTE2WUV3RUFZR5YVS2FTUU3BXW3JE546D6USKKQGWB5Y63323RE57",
-    "GBHBVNZIT6TBXQDBSZOB56POVE26SYN6EF34SRTLGMRBVHVE3IBLWBTC",
-    "GBHDFZ734FST7EKRQFM2EF7CSXUMOKNHRKIEKIRLJ4JLK3DYZXBMH4WI",
-    "GBHDGGV6LJ2SOWLTULXKINB63UTBNW2CN7SZZJZTGLYD2V4XXH6IVPJX",
-    "GBHDQMQAWGGD7F2KMGUKMPZ6B7J22UNTI2N52JPMCPQZBQLAYDX3E435",
-    "GBHE2K7BOT3AOT7RP3E47K7VI2TEXPCVYGX5XV4LGSOUVFW776SHLI7D",
-    "GBHE66KBVOYX3XXO67QPIOOUAVLUS7LSXQ2NDIIQXQLRYJSBDIV3FIFA",
-    "GBHE7A3PMKNNXHZHOOBSVSFEIFB2QHLG3LSSYYNVM3ISX6OG5MPDNFEI",
-    "GBHEIQEKJD73IO5KG7EHTJKZHW2QQK7LHO2MWQEXZAZUJ5OUCAVGJ7IA",
-    "GBHEQJLC6OETVEYEVOBT3ISQKCMAV32KN4AOPPS24ZJFIY3L7A3YU4BD",
-    "GBHFSKCLPCHMC3BLRPU32ZQGT4DC5PDGHGILRV3TXP37KXJDXP74NQUQ",
-    "GBHFYAAPP46ZKSS7VUNWGVD2EOVCBTBCR3GHCELSTD5IZ2EOD6O72AXB",
-    "GBHGWYWDR5VJA473FJO6425TCVW34IVLEGEI7OIQ4VQRP2UPF74K6R5T",
-    "GBHHAMZN4CRAOSTLJEKMKLDUI23J3I5SORWLZN2FPKCI3TOIM243GVJH",
-    "GBHHJPIS35Z3TFR42DX5MZ4TU6ZRD42ZBNS4XTJISVY2TQWULMU4ZEF4",
-    "GBHHPDQQVCH4O2YDXCU37BPH6PAVQT7JTC7AIIZGMGWRDWRGGF2BWE5L",
-    "GBHHV6FJYFQZYFAYPEQN2PKFMDSVJFZB6F57RFGPY65HTJ3BTKY4PUMB",
-    "GBHHWUXHJ3EKSTOD6ULB7Z5E426OZQKWLCMD35NUYAMOHVYYQ7CUYZZA",
-    "GBHI2VUNAGX2JC4UNQHTGJSGSI24GEAMLYRKWWCAX7OD73ASDUH4EVF3",
-    "GBHIFAFDKZOUMQQH2QTQSPETFCFKZS3FMMNFS7R5HEVTHTHKICV7CALO",
-    "GBHIFSPL6B2XXXX4PDZNNRTA5XNA453SWJ5RLM4M6QPVRTSDLTSXCEXC",
-    "GBHJ7YQ5TTJSZFYFLHNWWOV32PFJQMZ7X5VU7DUS7WOHTBO5LK3JDNN6",
-    "GBHJDFA4T2TZYBDSB3MBHCV4EMXF4ZENM3EMHZ7B7HYJQTFKJJFWOBOG",
-    "GBHJTPYMFXA3JXKBFYGYRNX43Z2VI4P557343M7434EJYA6GMAN5BSFT",
-    "GBHJYDFKESZNFSBJIRIK26AXEQZWS3R36JNHLTBHYU7F4WKG3B3LQETH",
-    "GBHJZLA4UZWQDENVGP6BKGD42YCSTF6UWZ5F3FUTTCRV3XDGI2IR3SN6",
-    "GBHKGF53Q7OTNQXUPQYLY3WF6ROBFDG2RTLLAJHDYOTEHYPKN3MR7RGU",
-    "GBHKY2YBXZNMZX43PFM7KKO6ISYCAIDBDLGMYOBIL4OW3EJPIYENE7QV",
-    "GBHL4RIRTGKB7DV7XZZ7LHJED7DLWPM5L4LPNMGVGEKXYJNCN5ZFLOKI",
-    "GBHLAV3GW6XOW3G7R2MJJMRS5CWJSN6ZRY7AMPWHDZKZ7RB7NZOKNBCH",
-    "GBHLBXRI72XY5UWV4O722MUJVCUAYSJRVHQXCVIZRF6L6FN3EJE5LMFR",
-    "GBHLGDN7OHIABBNDYR3H2SH3PYLDGKTYNTF6U4WVD763JADCS4RYBBXU",
-    "GBHLTBAZWXGDXIY2NWPQIVIAYNPC4UQMLHP74IB4HHKWMXHADAJV4TKV",
-    "GBHLVKI3TV7PRRVCY4R3OUKMZFJ7UHFC4RW3HP35JGG4I7RZJBBKACPA",
-    "GBHLWFSHBSZZVRRXMAY5LQMNKS6C2WEDPIIDWWHTDNIOLE6QHXCXM7FO",
-    "GBHM2AYPPQVHJ3TUPIAPVMCIWF25C4O54XUDR4C4BLGPG3RKRFOWG5OO",
-    "GBHMZP6AWOPBKTARNV5BXY4TOOX5OKVL6MKNZWIILR6DVGHZPTVCO5WO",
-    "GBHNXBX4I2M7HC5UAD5XKCG53VPIWFJJAIVE5IT3PQ6JL7GC7YJIVCWR",
-    "GBHNY7CXRC7TOQBFYJK4XLVRI34BUQCZ7JZGUDQNDCTAG3F3I6RLEZG5",
-    "GBHPCIIRXTN3OHPHQN3X2UBT5HOOYXZXJZKGBVXTDZWYCXH35BIZU2TF",
-    "GBHPQH3L7XGDMFQTS2JSSUYMVPN3WE76C57SWIXW5HKPQGDWR6NUFMSL",
-    "GBHQ4EH25DJXLCDFBB644YZYSBMTLKIJG7TUJWQBF3SFDW4L34DZSUON",
-    "GBHQGL47CCGCYRTTJN474FRSMMK6OB6ISZCUJFAGAFOUU2MKMZMBWGEY",
-    "GBHQKIGPENW74YPHAUOPFOMAL7LXVNCMWMHWM7FQGUJHTV7AGBJP4Q33",
-    "GBHQUFIDB3CMV3FFXGF3L4B5B2HLNVRAQPBIHKAIJVKE5JOZ3DS633RW",
-    "GBHRNH5BELQEUQMAJGS54CPJLDYLEGNOGKSI3T4IQLGRXGQPOUHEEJEL",
-    "GBHS5QCCY4NMEYTSV6Y6AS6FQYOVA2QSBKYK5NE4RZROFPAPX2WGMMSL",
-    "GBHS7OOXHKT6DVS6JF22ZO6LHIO7NSN42SKIDPW3R5RYMPTQXYLGOGSZ",
-    "GBHSDFMHA43ZEGID7XQXKHIJ3SJSAB5W72DCFX6V2QB7FPRGRMJLUX4E",
-    "GBHTPABWFGOYRFBOORDKIU57KG574HFPZ5YNQCIDG5ORQWQQVDUKENA4",
-    "GBHTV3JOM2HWVKFTB32TPNEV4RZN5DRO4EWPGQ6GHIAUEDEY4EGJ2DE3",
-    "GBHVCOALTEXPP4VKCMFPMR7LISJNIQTKFXWGJI3SKSSFAGIJB5EVRYTW",
-    "GBHVFY4IACLB6TQSBVTGV4MOJ3P2J7WG2UUGF7OVA6HLJK5UUBIB6ZHF",
-    "GBHVHB4HGZVX6VKJHREGC6KE5KMZLAZ32VR4NCIQYECT33PRZSK2M7QT",
-    "GBHVXYORSMDFWQCIJ545YVNGMBGGWZWKOWRJ7AGFJAMKNVSWRZIACXOQ",
-    "GBHVYR6QCJKS55EUB5LVZLB3OQJAIWMUSCTRSTCSPREQ52VJDSQS2UGM",
-    "GBHWDUPWWY6TX6QRQS2KA5V2FYSH7JMHIGABXYRR5L4KDWTHM6ALV73M",
-    "GBHWKRXCCEM4ZVCDZFHHJY3X5SGXVFJYMAJJXBFEUWWFJVRHVRO7HB3N",
-    "GBHWMN35GIGW4W62KWIDMKXGXS55W3NTUGZ5WET2K7UP5JT2ZSAYRION",
-    "GBHWZCIP5JNGKD3HWCU2A2Q4L5TTZVKSL3EHGQUADSE733I7SYKVJXRP",
-    "GBHX7OJMQGTX5LS2DCR7AGX5QD5ZUVAM5Z3THQ67KMYPO36SQ65TSZXM",
-    "GBHXGKXT2ICM6J2VWRMMEXNKPYTZDCDZLKCCQVAEHGJAKF5IHZXT5W7U",
-    "GBHY37QDROJDPMQ7RKIJNAGHJDEXYZSXXDJFOD4VO2TKFSUECUG6IXDC",
-    "GBHY3F4OCTWYGGM7LFC5LNSZX6XNTVIMQVC5MLRINN4GY5YGDRHLJ755",
-    "GBHYA2RYC6Q3HOKCAX5BEIVZBXKTYC742IRYYZRSWGXENRJTDH3FMSDY",
-    "GBHYB6FHJZ7VNXLFGUJKF6MXMLVVE4OXGDEG2EJ27I3YDMY5S7I6BKNB",
-    "GBHYG46CGXBJWQI5PZKYP4MITHRIP32TT6V34QLN366KNP6T4UCFWUXV",
-    "GBHYH7ET65KRLKLQUV7A6BKJXILGMWIIEW4DNTUJXD3BUFVPFICDZPOP",
-    "GBHYHURCURVXNJMO5LR2GD46VEISR6ZGHYUBGQJUYUJ4LO4MR5K6STRG",
-    "GBHYPHKBT3PMRHF3UYTIJTESH3ZLXU27MZPBESZZLLY4SIWFFM3KBVKH",
-    "GBHZG5OSIS74BGIECGKCXTUIF4KDPAYLYSE5V7LYG7B3TYKUSZGWDASH",
-    "GBHZJLGPE3U636PMX52FTVHS6LNFYBYYYT4LSYM2WECAHRC7WZYZE7BA",
-    "GBHZNUB7DQDES633L3RC4OO6DXLW6LEI5OJWT3ODIAAFDBAXM33QWDU3",
-    "GBHZRV3T5WY5CDZWHF2MVV6L46QIWRVWRODTDHQ7JPYIV5LDKWFZ6SWB",
-    "GBHZWY7HUFDFRLLCRFO7I7UGGVX3KS7TXBA52UVT7BTMILTFFWGBDFOI",
-    "GBI2D2A5WDECXAX74DWKIDYV7WYQ6ZROSI6EVJUM2ZPEI5B3OU366E6I",
-    "GBI2GEKQFY2FWL3CMLGBRJTXIIQALY4VRFKZ3QMYFPPWMPXQQQKSHARE",
-    "GBI2VQP5R5FXSMEQJGVQGTQ2M5YWDMK2IR7KXVC55PIF7OPE3W55GC2O",
-    "GBI433OAENGLPRWAZ5IQIHWYFUKJ7PDH7FKWOJ3XAU5Y7X6AN3FVLBTC",
-    "GBI47V4YCZM5FT55ZTQS7CVBCPT3JFLFFEXAC7QZXWWAI54HDXIYGAD3",
-    "GBI4GFKQ6ZOP67TNZ4RWWNYB3H2EHWXPM2KIOQ5EPFRADF2VXPV2TDN7",
-    "GBI552HTNBQU2I3STIXVINCKM3PJM2SKC25NAW37V4RNNFIIGWH34FR7",
-    "GBI5DUYW7O26FTFZDKTLWXJX4HGXSBSBGDP6EIVESZRBJYV2VCBJZ6AO",
-    "GBI5NOXQSCRKJXLRXW3WEMWPPE7EL5UBBSWA4G5E25ZCHQQOVETLSSPC",
-    "GBI5UXHQQGEM4HHW7YYXD3K6JVMKS24GXKCLUJCXOJPBSCSEGHIQ2VCL",
-    "GBI6EXEFI6WN5YLQAWAKJUXFJEFOMVFNVXESE7ZSEICTY7DVFUCRQSYT",
-    "GBI6IC6SNGALMXARSWUPMGQI6FRVVCD6CRQSYNI2W53K4LF2P62D4P6V",
-    "GBI6K2HYEARAVCOM5SFU7V6KMGOGDOTKBDH43BRDF35CI7FC4Y4CDLXQ",
-    "GBI6TGWCGMXVORF3XG357NESRCFXM4GSZ3SSRPBPMF4JQZNRYEF5DXLM",
-    "GBI6TIRHPJKBY4QYOLGB3KHIEXQRWNY7NUORC6Q7K6A427RA7KGOGK5A",
-    "GBIA4S2WE7L3KLQ3OTUNP7MEQB2AM4CQFM3DWJ5JNYQTYUEX5C22QNS4",
-    "GBIAWQS52OWVWWBYWACF2UQDJTMQLXBVFD2LJU5ULHZYN7R3RRUVEXLM",
-    "GBICFPGLKRBGA4LJWJLJUV5CCXZBAXCFRP656FCXG4LJURD5NSS6Y37U",
-    "GBICHKSPC3QPUV5FG2RKER24POSTNX7YNUFBH7FX4S665J3XZ6M7ELON",
-    "GBICKWHULVIB6ESW743DRBPVPUIELIKG42WEWWGPVLP75CRJ76R3AOVM",
-    "GBIE6YBWPHSBABYHCDKZPAAXCD265CLDWO2WCFNDMOMPJ5VFU6RGPDKO",
-    "GBIF6NQOJP2MOOQH3SIUU36T4EX3JKUF2RJISSKBVK6JM3F5F6JXZAEM",
-    "GBIF6QPPC2G6HF6L7IGMARVA4BOEEIMJ7PQSMIT2GHICMDLBH25J553X",
-    "GBIFB7K27BTH4FOI5SMMG2CR4BSXC67333CFFT3U3TNOFUDXF2ODFSV3",
-    "GBIGM2CIMCG46RA7W22ET4AP5XCLR55C3VK5QMHJKFSLGJJMME3UHDDE",
-    "GBIGTJ6ISBZ4EZY4U75V42SWPZSLBG75RFXS6H3SSZ3AO6NIPXLBCTOE",
-    "GBIGWD5GKEGLU6VV6SKAU6RSF6ARU3NPR4IXGIRSRJCRLTJOR5AJXSTP",
-    "GBIGZ2LMCMZ2CPG673ASDZBYPKFYUNSIVA7NCOYCZJ2O4Y4H2Z5ZQUSA",
-    "GBIHVLTTWXQFLSKTYCB7TPZLWFJKEL54HZWSSJFKB2D5DDQ5WTGXHGBT",
-    "GBIHY74ASBUSVR3HU3DNFSJHSNHPBN42XGOCOV5SS7YBUHHR6YYXBHAA",
-    "GBII74QLXAJXW6TMQBHNJZ5HYP6GU45OCLRSIEVQEI5JDMKZNOEZNK4K",
-    "GBIIF3G75K5Y2COJT6TME3WT3KK3OTLSPIA7732VEAOIPHHQ7PKARUBY",
-    "GBIIFQSR6QQKOKKHCBDNF4INL6Q22GG2BX4XLVA72KWL5PSTRIMBK4M2",
-    "GBIIGAN3733EKPTBVP7GHNT7QSEUW7YOHDBDD4D7VCCP7ICAAFNIHSAM",
-    "GBIJ7SBNRC5DRJPFAZSMBFX5BLXLHGIDIXVOKIAEIDW6OQY2QBOHBD7Z",
-    "GBIJKFKYVH3N2M4D32YEVBNNRAGDWR3AHRGXYGS267G3BFTBON76BTZY",
-    "GBIJNKT2F5Z4FGPP2UZS2GSQBQS52SK3BE3K7XFLTDUCKTNGAW3B5RBH",
-    "GBIKGCKMB2SWRSUURW32PDLDHIFYVSCVKPTJQ6TSNSFQZLDWV6AR7JIQ",
-    "GBIKIDQXJC2ZHHI4DPSVPUHHSY6QPNUDT5F6EXTSGTP4NYSCZDDWYPXZ",
-    "GBIKZM3UVMIUXIDFBJNVQEKB2BTFCVEXFV7JHJS3TTC3RDP24VWDI2ZV",
-    "GBIL5TOVTZFNV3XS3E2LWTKU5SYOJ3UBCVBSKAMP4EE4MV2VSZQK7NRZ",
-    "GBILD4YI4HMBBMILUXG3VPHJLO3MBZCJZIY5ZJH7VNMB5PWH3FEPFUCK",
-    "GBILEV2ANAWHYQYOEWFRMH25QFMVQUFZRYC7N2AZAMSIDQAFIBO3JETH",
-    "GBILKAL6TH56OTUIWRVUCUGTV22NHLH5QFWBHCUPVHBX6B5FVJYSSIBD",
-    "GBILXYMKLYGWC2DNKKCKOEXIRT4KWXELLBDLTGWPYXAFVELU5OO4YL2S",
-    "GBIMVQ3UH4PPK44MSLPOCP4YNZUMENADEFRIBYIVWWSEEQHKEN567LUO",
-    "GBINQJ742ZFP5TVHTRDRUTHCP7NY3TCCKXXZGOM52B6PWVO7ODIQZWTY",
-    "GBINT4VWNQDQPVTP53C3JRGZPWDZGZDVMC5OURZDZWW2JGS7XL2YH2B7",
-    "GBIO5NLLV3US5FB4SBV2FU2SQH4ZO23OZT565NZZH3JWE7LKRYGL5DCA",
-    "GBIOUWERPYEMHRHQHHBVT3E5CEJEHANO22H33DUJQYLHV3FXHLWMYBTC",
-    "GBIP7JRNJQLLNYOOLKNHDBYCSPAHPKIO2F7CSE3YROM3LQ2GYIUDQE2U",
-    "GBIPDLNOFD2URY3YNPEMD3YCBOBGN4D5BZJPT4PIZAWAJBE5CUZ6RAKV",
-    "GBIPYIROAADC3SLJK6VGCWEYONL2PQVUHCOQAY4HCQCQS5AUWE7TACVX",
-    "GBIQ65YD2LTJOWPPRPY66KKKI2ZZ2NKLTJNQVKEAZ25FZRUOEGMQHBAR",
-    "GBIQJ4JO3IY2SKX54FKTSREFDA6SZALXXL5AKRP7ZJ65HOEFCC37TXB4",
-    "GBIQQHUYR4CPZ7RMCGZJ3NJTGKOBWQDCJS3ESQDWCL7VK5OJW3OZ7IOJ",
-    "GBIRDDRI4NTO5WQF3L5S2XK5HNRZH24N5QQF5LQ5X2NHOB7ICUEHN7GX",
-    "GBIRDFRM2EAEKYNFGQZDKHS55NFCIC6LV6LDWIG6O2VJP2S2MSHBXZSU",
-    "GBIRONG7G2MBSJLJGBT3XK37DS7MWIKIIWJ4JM23LR4FH3J5GECLMFJO",
-    "GBISTDWPAQMA5L3NZTCAQFSTUOWM6LUCA6GIQO7Q6SAFBITPSJXZKINT",
-    "GBITGCDBA4N4LBSXC6A57ZJAHIVE3C7VYLDC4NTUSBVAAMM57WOEYBTC",
-    "GBITJONEKGVG4QNIF4K7PDY2IPZFPIRSVCC6HYOI2S2J6EFODBMX64GH",
-    "GBITYRURAH76WKOGMRFJA3QZ4DLYH66CCJNXJSVQL7RGCOAEUV6VDPM3",
-    "GBIUZQO5SPUJG6PU4HGNVXKJEZTEPLQEZL4IGA6DSOSBIHYZCGG7AJGZ",
-    "GBIVABHB7PSMAG7IVTUQYVEVCULR6MYYPYUXAXHA5C7NEVUCH4EWLEAD",
-    "GBIVCCXVBDZ2JML27QE7VFXLOPCH3SKZ7KQ7LKU7EXVVFS6J45JBC5BE",
-    "GBIVVNKYHAPKA5HLVQEI2F4FSTD6SRT75V6RWNLJYUBAZDEBHUI3UUTU",
-    "GBIVXFL64WO4RBCCKRT33YDTSA2MWR4I4AWDA6BFOZFSF7ZVNEOBJAM4",
-    "GBIW6Q4GE5XYKTMR7NVZS5PVASJ5JNFR6JPTTWGREQ3IADIM7U3NFMGF",
-    "GBIWIQMUMLERZGT75I4LQFG6DITGA3WRD4QGIQUV7KWM33LJ5TKCLUI7",
-    "GBIWPJ6NPXHLAM5RSF25DWURH33354EZ5KDVGG5ZLL56QFBP3CMUEMOV",
-    "GBIWYF2SOJF4JQPRVCQJK5CUY77V7PLRELQ7BRGIF6NINNDM5V2REBTC",
-    "GBIX77XKTOLXZZ6C3YBBY3HZ2KHQHINLAFQOTYMSMQLPZPS7Q746EKSP",
-    "GBIXO7LKSMVKGGNOUMHNWPNDJCOZB5MIBUMLTTXN3O5TEDPAX6DFJWQ2",
-    "GBIXOV6CPFETK7GZOC6Q4MWRMPEDL2RJFLG5TGVMGTRXVLF6LSIXAXDC",
-    "GBIXZ2WC3T3GL37PDQXS42IMDD4ZJF6GBCKBNKKKAI5EEHBMDXQKQ5LZ",
-    "GBIY2TNJQOBTRAOL7POYKCIWX6EYAGU6VEPIWW5MXNEOGLPIAR3ZAXRP",
-    "GBIYC5JXOUL2GXNAYD3FVEXDOQMESK4QLDV4NXHEGYSA2TBZIHVATBMO",
-    "GBIYTFJFORXWRR6SHX6SXDMMMTKNQRUN6XY3PGJ2FZJKU37HW6PYNZQ5",
-    "GBIYUMUI4JBGMNKKPZ2LVMACGBCPIO432KYV7V6WS7B2REUYBHBVRAZ6",
-    "GBIZ6W6H5PXJEHFNQ2CW4NTHDICEILLII6QIPL3FQCZGNHBH5PPQLH5E",
-    "GBIZCZLVQMYPADYQR4KBPU2C5YWAYHVXOGG2TMG75AERA65TW55RUSBS",
-    "GBIZI6L7ZLMPLXNAHT35R472MDAP5RGCONW3FIYS4OKRZ7EDB4MDQGAS",
-    "GBIZY5PMTIH4EX5Q66TXWLFXIIBDIAXZ3A44363YT43RYWWCR6ELEWN4",
-    "GBJ22WAEBGXB54JHAL3BAJP7OB4HQAK2H4PDOIPONI2JKXAVMPQ6N7C4",
-    "GBJ2LWBF5BHRE5736TF6GRGIYSIU67LRNRJ2QC4QORJ642TU4HT6V556",
-    "GBJ2PGOLGEYQSPRFJDWEXQJ5IBECJO2B4O6UJWXWIGECJW4LRC57DJ72",
-    "GBJ2UX6FU57NEYPQZA5WOKWTSOENIKYJGBQ462LHWEZSAYGFB77FDTHO",
-    "GBJ3S5PFRHYZ3VNAKLI6I6E3KWUB6IFSUPZ4U3RDGMP6I3LXXSOERGE4",
-    "GBJ3TNKKRAQOXRQT4V2BPILYTUYBLIFGMKWOS5YAKDFCW2BHPSIPQUD6",
-    "GBJ3U5Y5HOLZGXRMUOCN4VTSCE3SLM3GKQYUE2NDWZX2OHL3ZRGQBV3M",
-    "GBJ4BU3DKIMZP7AEA4KIJFZFODU6I43TTYQ5FDQIL5BEPSMAE2G4I5IP",
-    "GBJ4DYKQQQYYRMURBIOWR6V6TCWDQL7RFALYZ5W3ZMPPHASZNW2C5FCN",
-    "GBJ5GH34KAPLUDQJXVCUQNMQZ4FVEMJ24GUIVC5OUCA4NY73A2RI33FA",
-    "GBJ5PROK42VQLQLWTJNKBXA65IURJKLPFODAR55QLIXROPXHLTODACAO",
-    "GBJ64XJMUQS7FBDLP7WYNDJQR4BXU2OORDGN3CBJR3JIPX65AZYU2I5R",
-    "GBJ666WQ2SOAVHMQ2OXT6OXZ3QHN2ELVFSOIVEAHGZD5VYMMZS42QG5L",
-    "GBJ6AFO2ILYWXCZDKPYF5QOFWL5AECUBBRBGF372VECD3M7O3YD372A6",
-    "GBJ6LMVWVWQCG72JHHFIO5RJC4XBF526MSOYCKT64PL4NU6WPJICO5LK",
-    "GBJ6NL5SS5OXHPXWGR45MWWV5WHKDBSL6N56YFS6DGYOUEZZ6QPRWEZQ",
-    "GBJ6QVADT4QSK2UQBUUKE6Z7A5CU7U6YYL3HZVSTUACAH45DDDPTUNMD",
-    "GBJ6VLGIKXGFZMQ6BHFQQ5OAU2UISRJV64337QAPDRQZ6ZV3KEVM3I7N",
-    "GBJ6WVVWULVZLDW4K2BW4UWBSATXTQGUGS6Q2YTITDC7OXSREY4PDEUN",
-    "GBJ7AZVCFWUCZV5SRIJAVQ7Q4WHKGKHWUIF53QKDXEJLUESWW3I4GUEQ",
-    "GBJ7S5PNQXO2JXQQ3KSYPXIG77ZOUYZTBEMDNJOXFHIA3HPO4RKVJ3CP",
-    "GBJB2Q7PJWW2GR7N2R4C4R5LIV4KBHUF7VJDQD6A2OFLFQVXSHT7ASHX",
-    "GBJBBGORFQGCTOWNF6G44FNM7SAO72E635BYSJTBVX7J3MNPU34XIOL6",
-    "GBJBJHK6LCDTSFUVICQQVRQ7AOLCRNEPGR5YUE4I7CSYPNPLURDXFIBM",
-    "GBJCRMN6PQ3B3OOLVOLATRMS62UDKUZ7ZLHFCOGY2YSRSFLE6MQB3R4G",
-    "GBJD7JBKMIWK4NZURK7ANJG77GWOZWXH7MNYPK2ZKNOKYSHGJSISVNFI",
-    "GBJDIJ5E3WEFA5Z6OGKKZCZI3T3HH4MK6SPTNJAKGHR5SQHVDEIUNALQ",
-    "GBJDOY7X4PJS6JJUB2AU5LHQPYFPHKNKMIXW43O6MXP6XWJWL3L6AOJA",
-    "GBJDXBWWWKHMMSRXPQHYFHZI3BV4PMNMCGOERGQOGKASAQBQLOYTW4W7",
-    "GBJE2JYUOIOT6YRWRJM73JFSPDLVU62CWCKSEVHRLZSUU4E6VFK5SSZX",
-    "GBJE62A5NACEKOZBZZNJDXDCFEYDO4UHFY5MMPYKM3SPEH6REICPGMZE",
-    "GBJEFG2WOP65TPE6O2KKLKQIEGKQLTFOB6BNFUR7PUTB5SABVUR7TXYN",
-    "GBJFA7OKV5VHBCIAP6CCPTLLRRDH5CZIHHKMMMDZVYCTRHTUFOZVIIXI",
-    "GBJFZCXHDHWFQMLYQFOZDG3ET4P5GY5Q2WNNCMND6WYMVYJ3PYDMC3FR",
-    "GBJG2DEDJVRTLALHCDMTLYUDLTKUNZ7BHVVQV3YZGNBEOS2PKS365BNK",
-    "GBJGBG3NFOQQHFGK565SXMKIP6CB6MBCI6363L7INFHW4WVV7MOXFXLM",
-    "GBJGFZQDHR3JLJT2MODNT6K6B4YPWZGMENF462QTCUKCKD7LEPGFFFM2",
-    "GBJGG3G577K7DXDCZVBEM2FTWOCXSBGKG4CIBNDBPCGUODOEUDSSHJS3",
-    "GBJGQX7PQ3FAL5OV42JXAM5CIUCCH4WK62YWKLSEHWU66UTQ2G7DPB2J",
-    "GBJGR7HDC4QPCZ6SA5VBH73V7L5SSZ5GAS6US23PFSGV4SQXJLYGERYX",
-    "GBJHEREANFOWEWW5IELPOJ7AJTQLRWJB66AV3KMYNEZHWCJK4YGUDVEA",
-    "GBJJWSBXWPP63NE7YJ4S5KGDMVYM3YHERGBLUN2SUMSOTWVADGR3GVBN",
-    "GBJK4ZFG6EV3O5V64RC3KAGA2XFA3M54EKVNE2P6AYW7EQLPOTP77I6G",
-    "GBJKDO4MEINBSGJWX74UPFHJ33MFKYM746QOZLAHSUNBS3NE34SSCJ7O",
-    "GBJKJB7NXZQTSN734NRSWYZPJONY2BZBTG5LNDYRPMKUUVTI7A7PNWIR",
-    "GBJKLVJ33CDSKYGASV3LADIYX7L6Z7KQG5QLCBGIBAV6VRSQXNFSFLGT",
-    "GBJKZPONWEDIL7MIRX4VAHIKC3S25WDZLYSWPAJLLVAGMRQGEJ2ZBQMI",
-    "GBJLAGFLWKJ33JC6ZI6BECRAVSYZHZMYUKIALMBC6XOTOGENDL4ZGLIU",
-    "GBJLESKY6XWMJGYCQMKTM36YFYCQD42UTNPONS6AQZHUN67LJSOZ4Q34",
-    "GBJLWL2Y2J4NNG2RUQUSPRY3E3OEOM3ZZOFJE3CLUUNCGM7YOE4UMTUG",
-    "GBJMDBKZ3ZGNXZOJBPUQW5KBP4NLM5FAZ3UZCPB7AFX3QKSEAA6XDYAQ",
-    "GBJNGONZR7KX22ROCGD2SSS4QS6K7R5DC25JPF6FQIYPKCCVLK5GN6E6",
-    "GBJNMULBXZEJ4LRCYPRQ63WUNXVR5W23WPEKXC3IVLAK3JGOJUJ4ZN6E",
-    "GBJNO6KQ5Y5PY2MTAZDUKMPI5VW4XYOKY5TEGWJE34SQ2Y2KP7CABQFS",
-    "GBJNPB2H3VIOM6WAND6Q75RG3L22MFRYOX2GMCMDSIAJD7CQSYCHEFT6",
-    "GBJNT7MUQMSVW3INCVDTZTM7NQJHW55I4V4K5Q26EGKNKWAWD47AKXZ6",
-    "GBJNXMBVLTDB7ER7EGRKRC7JAYCMT5JKGIHAV452A55UIV75S6RKREOW",
-    "GBJNXUSUK7Z5NKOR7C35F3PFHLDGZ5UKT4TZWCJJJDEKFTVR7BKSCS7Y",
-    "GBJO5OOBQ7OUAEUMRWNJJUTJW7WMBIUP7ZL5YLCNUPDCU25SKQGG5XBI",
-    "GBJO7CWGMCABAQOUNGQXHMIDLJUNSVX2FQXM334PLKLNSI6FS3LRRD5F",
-    "GBJODIPY23SGMFJECWWI4BK7AOQCN4LH6GUTHWX3JOGM7KKBEE7GYAU5",
-    "GBJOFNWPB2NUTOEBQMJE3CCNXLE5IB7CQB22ZXU34LDECC7PLXMJOXLM",
-    "GBJOFUDCHDBEBS2CP7CQY3RXWDXGFDFOQSQAY36ZGV55AUGYN4HCI5IP",
-    "GBJPGCQLNVLXRH2OBNOJFN2CC6RQWD3ZGYSTTC7E3W2JEWCMXGO6O7GN",
-    "GBJPQZTTIFWDU5C24UFIRHUDISODPZPCKXEUUWLRQWATFJOY5YBF7EUU",
-    "GBJPTHN364TUYUV5CXCK25U7PTQHTIPDLFBSXWEZNPX56LXJAN55QPF4",
-    "GBJPTKDRI6LGTZ5UCGRVLCJBEQLI2DU6ZQZ5HT4KF6QGRO2NSHDX5IP5",
-    "GBJPZ7KF2634C5CKANIJJDUSYDQVKASDDRQSVFGF7D4TN6D7MTZYG63A",
-    "GBJQ5FRS32EVRED6CHAXMTLNZLBKXCMNCCMQ34YDDW4RPI6HT66CH54F",
-    "GBJRIRR3BUIEEJDVYEHGTJELMZNSHSRDNFLUR7NGDXXYYRBIOVWO5QHZ",
-    "GBJRKFUZZI2AWPN72ATBTCG7I7AFWHCEMYKSFILJZATPZ2CRRF2OZQXQ",
-    "GBJRL2VPWRK6F5D7C3XRV35T3TH3KIMQIWX6TNIVEF6P3YLVRO43BY2R",
-    "GBJROOWKFEOZLH4VV7H7WKZICAVHWXQOXQVWQH6TSIIPDP5PYNBQRLVC",
-    "GBJROQU7X4L4MDD3BCO5BJPDEXN5FXLTSDRPKYMRPOTKXYL56I2K3Z2X",
-    "GBJROVGPSI4PLA2TH3IRVY6IGKNBGCKQFJNOIQJWFO5N77H5CPJJVCBS",
-    "GBJRQ6MKBUQILKBBQS7AWV6VGNQ6YYRD2QRW4SP3E7XURVWTJEBHDOQB",
-    "GBJRTQLCJH5QH7BISVINQ7CZHUEIBWE4P2B2DWRVCKJGASUM7J6BZM2O",
-    "GBJSB2NIVNQZNAXZATLF6HHDSVRJILKAUUJJKVXFVJZFOU7HO5AVJKGM",
-    "GBJU36YZWSSLDYTX34KDGLLO47WY3KFPV7A352AI3RM7PKYD4O6W7U43",
-    "GBJUBOIE6NJOBN34M3WL73UYNQCW7ZA6VPADTUBAIZ2BYBOESDUVPIDI",
-    "GBJUQLNZ37GUE3L3GO3IJZXM34XEOPURQ3HCMPBB6ZBJUWT4VO3IHSRK",
-    "GBJUS46IAHYBKVZ5RJLCGEA3ANDGRXQ2ZPGQ7LTG7QQVFLKBKDOGWT2S",
-    "GBJV5U7IDVPMPUFUGDUAV7HJ6N634USJM5SB3DHNKLVPMTBZSJNASDAQ",
-    "GBJWK6LRTTS5PF2FMWH7SJBFIEQVMQBJCSIZ72GUGFHCNFKVDVOSLKMR",
-    "GBJWQCY55B5O7ZHLQNT47NFKSYQNHN7R6PUKA6DBQ77C4X5X2DYI5SHX",
-    "GBJWSOMPWC5DS2MQR6M7N73CZXVDPP7IWA743JY632EIPVEAROXVVLZ6",
-    "GBJWSUXFKLCTWJDDUCGEAWQP24L4RR4EOZMYVALWFN65KWVJV7KY43WP",
-    "GBJXB245WJQUD5NWXTYTCQK3U2VYJ75WMAUX77BVBJ4UY2YPCCNBMWVN",
-    "GBJXGO2E3WIC2VOSHNFCKFOSSFUDYCYV6JZAGMCBZHOMWJYXBN6G6C4Z",
-    "GBJXJ4JMCUNX3PYO3QMCKCNIJ3DJYWWCVJWNGOR4DXJHZZ35XYOEMXRT",
-    "GBJXJ5WF4XHQTWW5YLH5TVBSDOKJAHNLMZABT2GRM7SW4PCXW6NNNA3C",
-    "GBJXSZQPAWGPSS5VN3J2A4V2XEOYQT5ID2T2NWLBEYEGOL45IG2IVCAD",
-    "GBJYUBRORKNDDG42B3RKSLIRYEK5BIMTI67CA4HDG3KM4ET6PBLOSSQ4",
-    "GBJYYZYBWGYLLOW2M7KKHDHRNFYQ2XHTFQVVIZB22EJCTKEXOOBBNZXL",
-    "GBJZ2FN4Y7MVEWTN5VQGKPOHGPN7WWZ36ZVBASA5EJELMC2HGOD6ZDRH",
-    "GBJZBI6YAUU76OUCNIZNZFUIMLIJUYMCRAC7KMJ5RASDUNOHLI7THOVA",
-    "GBJZKKWOEMGKHCRGZA6WYA3J2K5472COHSFYB33H3NR52C67O4SECAHN",
-    "GBJZYYVBXAKZQACJ4P5I2WZJWOX2EECQITZ67JQCD5342SVDYQMJVAXS",
-    "GBK2KMNHSTFLIDFCCLFCNEEADUTQTWRMA6O6WEM4VE6FSEKK3NOGINYZ",
-    "GBK2UDLGU4J6MUU76ZICLRXKXVS7V6XAKERDMYQ5H2KE55A7NWJ2DIAM",
-    "GBK32TJUJTOWGEI7BHOMPPAHPXOHEOEA5TW5FYYMX6E3ACBYWUO5GIQL",
-    "GBK34RQST4KE66BAYBW2CN5JTD6SFI2UDBTUUFS3BBNCWXCUJWGXW3EK",
-    "GBK37GXO4MEBN552UUSSCRZHZ5KQ3RLCUPOHF2NEPR225XH3KQJJBWRJ",
-    "GBK3BXZMZ44QKN4CK2GPP2I6TG4HH2IUECDZAUIFCKG2KOB5XOJYUK7W",
-    "GBK3JFAKDMDWZMZA3HGXR62BJZ5RPXVARJT3BDBMWCCOEOJAGXXYGOLD",
-    "GBK466OMWAVGNJBWLU2CKNFK527SPMU4IM6EUV57AHCPDSECCCWIZOGZ",
-    "GBK4CXN6MBBWRDM2G35NZHLN2ZIBNQ3DEBW2ONMZZ7JQIIW4QFQRR3LA",
-    "GBK4IHNCCRSHRI5YESSGPW5VH6WR2Q5PMMRMMHWCUEQ5Q5MCXYEZQPBC",
-    "GBK5QJUS5SAJQJ74GKYEVZ5C66VECELQATUFLL73BMASHZDOHUYLFBTC",
-    "GBK62V645NVBRVWRAEHYEG2JXMXZ5677FZSQLBF4MMY7HBYLWJ62H32O",
-    "GBK66AHZDU3WEPUA2EG2QX2GSX6Z7RT2T2STUQTVPN6LJUZL57JFMAJO",
-    "GBK67L5UANJO7ZTQAW3NE4JOFI7ZYRDXC3HLVPEWA7VNBEHK6T324KQZ",
-    "GBK6D5ZPZQD3BYZK74QRQNGHFEPMSPQT7UUQWBYW2U7VHVM2BRZENRZV",
-    "GBK6EMSRLCSBEL5L4HSN7VLWWLPPP7HGAMIL5C7OXP7SLRT7N7AC4HTF",
-    "GBK6HDRLZT7XCXF4DU6LK6BW4WQZFICOQYQ7DNZ4PBLDKXWHXJ6QAQUA",
-    "GBK6HZCAIRA2V7MV623E24Y6XJYBFVLP6MORYPRPL4T4LGOBIQKGG6IT",
-    "GBK6KB2P2EK5W44Z5W4PFHVFGB42SOMKOSUHDVRA45MJGRK46RLEZLMG",
-    "GBK6PMP6DL3ISTITRYXQSIL5VGSJMOTKMKECSFXOKXSU3XYQSXQJKELO",
-    "GBK76AQLXXKGB77UWKXAAJFSI7JSVBNX4D6RKIFERRRYFEHGK4RPAD5I",
-    "GBK7T7JZSUTI44DWEJN3BP2OZVIQ4AUPY7HTN756ECEZWZDDHFDMTBTC",
-    "GBKA2DNM2PPTHKGIT4AZAHCU5VL7CQ6NVIMYUSAG4R72BSP4WPZDSAFE",
-    "GBKAAWWPX2MAZCAWNJM4QDYPCKIOSWKNCN4WOWKTMJLU65KGFJGGLB6Z",
-    "GBKAVCWYWUYLGXKC76NZZMOPNIET33ILSUKRKHRBODTVQ3IVQRRC2EDF",
-    "GBKAXQC24F5ZFHH5D42FYQASLWYX5SSO3W3TWAL3UCKCMLYYX3PTNN2V",
-    "GBKAYSAQJNJSPCVXFMFWF447HB6RZJOKBDFXDJMDWB7MP6SAYKSAMRLX",
-    "GBKB2556XG4KORER22A4BUR7TZE43LHTL5PCMPT7W6RXGOGM6KUISSAV",
-    "GBKBEC6NQ2U7QVUTA77HGEND2ZI4TPFBLYTMLNEGJY4YA4SVKXEVPRUS",
-    "GBKBPMDF2UREXFZZY2Q55YWIHZGVYDQ4PWO3ARRBNEEOGGN5IV5WH4YA",
-    "GBKBXNIXR6ZNDLMDWPOFASM56JYBTQJ7RVS25XLGEF4CEDTJTSRX7BTC",
-    "GBKC3QSPCTD5E7BPARV3OO53FC7IWZMNZIWOQSPZHNT5EHZ7YOXGVFUY",
-    "GBKCC7SKPQB6M35BZUTYIIX7CJBVEPT5CPX2RCXZZEIZVS3QM2YKHVAD",
-    "GBKCU5NUHSKHJ2IKXE27UMTZAWRB5FN6HYL77L7GTLXMB4KQESDLKYMR",
-    "GBKDGP4RE2WEKUCIGZI57NNJOPTFH2IVUHOT4TBRTRSJPVNTV4ZPYMQL",
-    "GBKDMLGO42KYZFAM2VRPG4FY54PCV3J2SPNMQS2WZ2LMGUA75XJD6IXS",
-    "GBKDW4MFXF4WWSD2HER3FY72XVLA54PLC3NJGZFZEJ3NGYZVA3KANSTL",
-    "GBKDX3522M7JRDCT6WL2YASZEC24JZDGGVYWQ2OOWRCFA43IRQHYY2HQ",
-    "GBKEC633KPNDM3GFOELL2UYORGMFIVO6PQITEE6FSLKQ6RLNSKTPB4X4",
-    "GBKEUMKFWHMJRT62Z35TIUCLH2Y3SW7WSGIHECAFFVWO3YPDPF3H2AME",
-    "GBKEXOCI6AEPYZ5HHYN5DKCVHQPZ3NMNIG4Q3OOSANMJT5HIP63WJGEM",
-    "GBKF3UKDA4R2Z2RNJVPFACWWYIO7NPFELMFUBHPALMCZV42DUCPEFPIN",
-    "GBKFB7T36CGQOPO35GB7A3TVODI2YG4PMYNWWTYW36ZJQP4BQM5VAIH2",
-    "GBKFKV57P4IXDHAQN4WPL5WBMT6GF3CLGKPL3GVTBZHYMJT7AAYBXYQU",
-    "GBKFM4UBK3XXLL4SZ3KWWP2O3L3JRBTUB23O5LQJAASK2BT4KQU54BTC",
-    "GBKG657GV5OJN27TFQBZJSAHHEVJSNUW3SO7HZEPKVGFMM2443BSNKJD",
-    "GBKGHO2YI37Z6X7HTBRSVBYOUHL7BZEHHGWRZNFKXMPHHSC4PB2BQGN5",
-    "GBKGJEMEHMYP6CH47T53OKYHDUL2VYU6WSN2C2X5CONGAKP7BVWV5DOK",
-    "GBKHI755IAHSVSQKLMF7DSNJPEGBLLFSRX3O6OXJPU3H6YIGL7N4GZXG",
-    "GBKHIUMQIYQ2YAI3R4KXHKJXCAVOO6OBD4IXRBQ7NL73BSKDO3I6FFTM",
-    "GBKHJCBZSQENGRHKOSHO2PI6IY6TC56AMQK4NSGZDRV4256L6YCGP7QX",
-    "GBKIGNLQAW4NOQSBDA5EUJAPYKXO6FIUT5BR5TQSDNVB37ZATNOVGNQV",
-    "GBKISRYJ6GX4M3UX3XKNNYAIUDCHC5VPO6SDGV2NJOGUE45QXSNHRXRP",
-    "GBKIWFLMGY2PXTM7LZBJODBGM7J6KW26UFL7JAFUHUAPVRF6ECFA2TQM",
-    "GBKIX3ELYR6P6OHAIJ6P4XG5XB5YNYOLH2NKY5XN5HGZMFFOZ7JPVRCV",
-    "GBKKEK7RJ7BARJ4VXPQQFTXTVANW2CSM5FVH3XPHCLRA4H6CW5F4HTMR",
-    "GBKKLHBRHODJQHLZ7JLRCJM53O4DCW5BZLUITAMYVJPQ6GRXH47HEUZK",
-    "GBKKMOUM4LMYCPS27BDMY2KKRF53QTWJ2DGODBSLW4MA4RFQIOPRHBAM",
-    "GBKKWZI2MFMNSE7IWAVINIRXYIUL7SO6QMYJ55WWUID5RRC6FRZ6DLNX",
-    "GBKKYBY4H2H3XXWWRV75Z6DJHSCBGYDTTCVBHFVY2UWAZQTASZ4WXX5W",
-    "GBKL7X4QROH4EW3I7TTP5YROSIAQCC7TYC6A3HX6FBKXYHZMICRLFZEC",
-    "GBKLMZST4ALW7T35YMEWPAFOTOJE3URMINLGAKFMKWRQFKRLWALHMETA",
-    "GBKLNCG5ADXKISQSSBORHV3BVKITRTBXULDZAMFU3BX5B6JJPAO2E5OM",
-    "GBKMFS4XWZFE4HZH4DBAOLALDYBUSQWB7UJVQL65253PV5FTIW6YPYWU",
-    "GBKMIXXYQTPKSC3EPLTSI2MOTCGBBKZOFSCXSNCAW2B7NE7SPJGVO6SE",
-    "GBKMJOGSM4OSG3KMETQZOTHB2JKBCUHDABZTSVRZW7MTXHNRB26ILPR3",
-    "GBKNC7PFYOC6ASBL7WLXBPFOPHPGPVGVQWFGYYLNEZGLBQLXGDTNYCGW",
-    "GBKNIMALTNEG6TP6CSARYF42LXASBNJCW4FG3WHAAO73HK2T7IDMFUSD",
-    "GBKNLD5OBRSLVP4ZPHKE6PEGZ7VOGAQB5RELVM5JF4RM4ROTWTUMKRDQ",
-    "GBKNNBSCSN6ZOQEZNBQ3JZ3WACDRSKQ6TKOQ4Q5GRXRMICQHHFABXKWX",
-    "GBKOOQFM6CXMOEVTZJTG56GTA4BQSMYOZPKKUIQU23AN7XINJUF433Z6",
-    "GBKOWDTIA6KATPXGRLPTXKUZGYEZUQ5HJNOIQUGDCLWN37O24X3Z2AWX",
-    "GBKOXFWHCSLIXCHA3UW2EHIHS7ZLYR7GGILQOSGMGZXN7TYZVSO6OUN5",
-    "GBKP2KIKXGNWUKBSTRFVGCKVUITBVUN3YJYFAETCW7XWTG3YGWUX2OLX",
-    "GBKPK5IY7TOXJYP7QHXJNIVR7PK4D7EH2CCRCI4AVTPT4LJE4MWPNATG",
-    "GBKPQKIBNTBPBXBH3DWJBDDODVP6P3OO4SK4X3XNJMGH3HFKRZFXAOYB",
-    "GBKPRRPQLUFVNSKNJGHQPGIAJE6SNVR4EA63DYAZ4UEM5MEPAREZM77X",
-    "GBKPVNEDTVBC7IU6O764KJBRBXF6BKNTC33QCCM2PCGKKN2ZPK4ZSHIB",
-    "GBKQ4BN3ISZ6TRLJXGRDM2SFOFUB64K6NM7VVCZFMXLYZCOBB6C2TONM",
-    "GBKQ4EWEMVZKWZSTUTJQPI6W5MTVQDNV54K3PHVNPOYO5EITHDRLLV6V",
-    "GBKQ4RMY2FCDNEAOZJSLPIHLX3425EJFSHBPDY7XGK6ZCGTVYJBJWWWT",
-    "GBKQ6VCOPHWTQDFYQRP5RZZL5MYSFNIVXJJ64NVDL2QKSIM2WRNILOSV",
-    "GBKQU66IL6A5IOHY556JJC4LLT4XQCA6ZDO2ZPUTSOZH4T662PBZSWVG",
-    "GBKQUOWUPS4WQV6TQPRQSE2S3BJR3LOJTWWYSJSPRMABPAJFQYJ34TRQ",
-    "GBKQWFUETVZPDJZCWY7VAIBZBGT5MLAWUZUWT4Q7QUKU7RKALKIV2PI3",
-    "GBKR2AXHFPV66DDDZ6EBEMYRU3TD6MMMRGOARHSJERYK6PJW7Z6MKYLS",
-    "GBKR4EKVFGVMFHICAWLBP3NPDA75X4UCZOGKUBYXWKX5ZWBJI26AEF25",
-    "GBKRKDJR2XL5TEH4RQHIVJCAPHYCHC5TMH7ELGCGRXZIMYE6RHYQQXKR",
-    "GBKSYJTU5XKBX2XD2VOCMBCFNCZI36OUSXJNZQ4BSKJTPHUSJWHUSXZE",
-    "GBKSYS7VRGFZN3OMR36H25BOGGLO5LHVKAFGD7KNOYOHMYWUOYQJ7EQG",
-    "GBKTIF47XJ3KT2FHJ7E3LSMSDVSSTUHQAM5HLV3NKN2L26TIE4TDCICH",
-    "GBKTJSNUSR6OCXA5WDWGT33MNSCNQHOBQUBYC7TVS7BOXDKWFNHI4QNH",
-    "GBKTKZJDMRQAWUGFO6HJYSJBSKJ7JUPNA4B537G6OWOAL4KWZ6TF3VEV",
-    "GBKTLZ3W5HER53KBMY3Z45YLBCFCZWRZA5TONFWX6NDCS5ISAJQZZ4YY",
-    "GBKTTWUKNXZBLDQP5NJL7ZOKLJ6QC44QCK2OJOBVZSHFAHOCGXQJYT5H",
-    "GBKU7N3FHX3W2X32642PXPKTXM35K6FEWS27MVB7QI2QNKWVKO2NHSLC",
-    "GBKUEXWDL42IF53W5WV6OIYNO2YFGWFDYAT45VO2V45FFOGV5FQUESLA",
-    "GBKUGS74X26VWXW23RFBRJOLGTPDB657C5DSZIB3XMODPJXRJZXFKPEV",
-    "GBKVNYA7N36QKWQ2H3Z767HN6HGO2PLQ6CNRYULS3RV3OO7JIYZPURTD",
-    "GBKVPG7C5F4S3Z5JWCW26ES57JGAPQVMYG55LEK4KYJ7SXCHTYX4LINE",
-    "GBKVQ7SAPPM3ENNTFBZYN6YZOWB2TYRGE2B27GC7UQOLRZUFPOJVQDIG",
-    "GBKWR5OPHB4A3Q4WPUZHWIDN3G72YQMVBTDV4JMP5S66Q7VINNKOMQSR",
-    "GBKWTKPDBNM2MB2PI7WA2VMY5TYXTU5E2UKOQLAHMOE5A3NKA5JBD2AP",
-    "GBKX7GS4SUSSJ366Q24FTFHAU5LKYGI3MM3I2IHG7T64LXWXMXWIA3XW",
-    "GBKXI5LI5QFGZ2KNTEVTEX2PIRZOL3HLHUMGM233XRMXY5Y6KV537QSV",
-    "GBKXQB2W7HOUADW7R32QUKMQD64YTKUCEPCIFJOSINBOF74VWNAYKI5R",
-    "GBKXZ6S6HT6WFK34JASFNAGOYU7EIV2UAW4CXJUY3JKDDNWML5WRZJ2X",
-    "GBKY5DWVEILKIGUQYNRGCSUPQHTK3WV42GXXZSACGGJCRKCZARZAOECP",
-    "GBKZJAFAQGZJUZTCR7C2DPOZW5L2BRVHO4WAG27PQLMEQRMQ7GQY4LEE",
-    "GBKZPUHIM2BQ5HCMGAQJZ7JSALI5G2TOQ5ZJSW2K3O5TM4DLREUMGYEN",
-    "GBKZSUWV3GEG2W5PQ2NNQVZALOO2LVWHADG5GPCCN3E537MYTWVIBTCP",
-    "GBKZUSFHFHBB3LKNWWXWVMBJUG2ZQZPTV3AVOQS53GJ6PH74ASNJK33X",
-    "GBL2I7CJWIFYFJJRTZMBY5K4U2OYFUTRZKOH22MJ3X7BJWCGUBWRJ2W6",
-    "GBL2VSGCXENDTXYD764C3WHLIHPBSUU3HUYMADNYDD2I4OGJWPFXYAUY",
-    "GBL2XWZWDCUYRY6TFW7PFI2QWCU2ILFDSJZXIXONHAPLV57EQ2UB5OAT",
-    "GBL2Z3OTH35DGRE4YQPMUCLEL6OTAVPNX7G5ANNMZUX2NKA3X65LQL23",
-    "GBL3KF7LKUSY6TN66BHAWY7RPLPCP2P3JSWXBEEJTVODWKPATCSSIF7Q",
-    "GBL42AK2JLXVRMI7NK5BHAOM7U5GY3BDEYVPF7XDS7OXWHTK6GB6K5DG",
-    "GBL44ZYU7TR2ZYHR7RC4IWTE6IZD3LAWFWDE5DS6XMZ5WI4MRCCDTDTQ",
-    "GBL457G466RTH35BMGV7DNBSG5XAK4NIZP2YTFH4YVTN5THIVXG77NG5",
-    "GBL4E26LO56NDFDMVTQQQ3SOPNSECO4XXFJJLJVTOPRXICME4EM2LDGG",
-    "GBL4ECB4LVMMURIACCZPET5DL3AXCVXA2SIBEYPYKZNVSRAIVV6MZRQU",
-    "GBL4UHEZFG4SWQN6TGKBMIV2CUKMKVZX5Q5MXXALYCCM3A6CI3Y6IB7B",
-    "GBL4UHXGUUSKKNI7BCIGUZDVAA7BGZM72CSFL6O5D54YPYQATNUXEUJ2",
-    "GBL5IAVHAXKJKZTRG5W5JNKT7SAPODQHYYRO2BPM47SYCIY5VBPPAPPL",
-    "GBL5QPBJSS6OLESBYBENRQBJU3VAPBIM6GE452INVLNYKQ2TGVKBCTVM",
-    "GBL62VW7ICYRYO2V65DZ45I5YXPKLN5DNHBC5464SREGS4VS7XW4ULNM",
-    "GBL6PFD63FVYK2JXP237BTOVSSTFC7RGU64TRZ5MDVWZKIUOGFPAXBCH",
-    "GBL6U6JDM7NJX2Q55P3BFOLQ5HIAQTE2AVBGYVBCWJXPCX746DDNZMFV",
-    "GBL7MNC7YXYLLYI3GPVF43ROS336UH4WPZGINHI2MLP6HLOJNESGPQJ6",
-    "GBLA3RJNZAWVNW2WCUB2IRZOIOATHBIUL655NDGKBYSA4E6SC7PQDOGE",
-    "GBLARXOU3SRZSXKJZXYDSYLRPV6ILKQPHAFDOBALW5KG4K246ZP6XMAV",
-    "GBLASKLE2RUQOTYKRK3ZPND6D6EU7NPRNXCC3VZ7WSFIOQCC2E7NPAJQ",
-    "GBLB2QNT2DM4JKYOUB4DW2LRKDARLMG2474OM5IKQD2YXAVU2NYX6FRZ",
-    "GBLBBNRE3HJ4M4WYEYMX7NK53SXDGVPUGKTLV2VLI7TE36YWLUFLAVN4",
-    "GBLBEXZ5NPOTVV2ZVGDTK3QOJ6NJ4M554JDA5U42WNSLL2E57D2POQJC",
-    "GBLBH7ECNBEKTNE2PD5VGGWJXK3FGURITSNNG4TBXD5JACSSWX4GQYW3",
-    "GBLBL26EU55BJFHSIJ3I7A7C3VKJWZKC7VRQGLYB456X65DKLDGAKKFS",
-    "GBLBRBYRSA5YXY5O2GJMSKFCPD3WXRJ5VYYR7W2CEPRZSPZ7FPJFKJO3",
-    "GBLCFB6F7MIZ7JM6XIAHWO3Q65AYSFCL7WUBURC3RFNO4X6D3DTHACKQ",
-    "GBLCQILY5LM4EQCW2O7LLM2L5E3GT7GJXKXGLIFJOFDBGSPGLEG6TOOO",
-    "GBLDBTPNN4SOOX6NAODNXXEQAC2SDVZE2EF524Y6BYS3R4MC4YFPZSTT",
-    "GBLDFCJHYRJM7CWTZAIICRX6QWQYRHBM6B7MVDBQLCWLFJW6B62ABUSD",
-    "GBLEG5SW3PHHLFY7SBMG7BFIWNWWOHXQDXUEEGQYFYM6PBXCUH7MD5YM",
-    "GBLENTBYIVNV32BTL6CPRJUREUNVQZUSR2MT2GGQI2U6ZLGSEGHAUI54",
-    "GBLFDUB42IUDUUXEGMMSCFUXNZSPTHNIAKL6YNGJD4H5AVKBY63LCEER",
-    "GBLGIMHEZYGIZOP5GBWSTC53DZ36HB4GQYZMVVV464YTUKFZHRR66SV6",
-    "GBLGYTE6XOO2CXM4YWEHUYGFB3PWBNNWECLBAAE7DXQZYLI6BONNTBPY",
-    "GBLGZVLZMTS3AS4WYGSQ4WJ4ZHVDQMW26Z7WW2DX6IW2XFKZ33QLQXDC",
-    "GBLHTLRNPKPBOFXM6JXE4VPGWVCL7DDTCB4GIHG76KNHIIXQA2STQK5Z",
-    "GBLIQJF4AKMEUKTQSP5CLG6TDANPUBE5RFBH57J5QXDMORTAJHDTRZE5",
-    "GBLJATVMZV6CZME4HZ4C4DSQ2VJE6N6QRUY6QC5GSK6JEQUT2WAUN5ID",
-    "GBLJLDK4777USDUGSA3WXZGSKN2ZJ2QD6PK6DGSBNTLJLDHVVZKUY6CX",
-    "GBLKIOGN6VQ3DDKXNSKYA3QHV3BD5O7GUZW336BAMTOKPTX3C2NITZKJ",
-    "GBLKVBXTVJTWI26EGYTOLOOXAEI4RKGU33ZBFEO22SQCQY2TE5CFUGD6",
-    "GBLKVJN7MWUGE6AAU3L3DM2QPQNWBCUSTTEZ6OOO4ULQHQO2R424PRQQ",
-    "GBLLIY3OIS3ETM6A2QPE6LRLC2HW2ZRJIH7I56MZEJ6EJSJY73AHQXLM",
-    "GBLLWEJTJW4BONENGR5BE6QWKQLBNININUJUGOBHDIWVHKVRF23EQPDR",
-    "GBLM2NUF3WSDNOXFIUB3BVUT7LJOXP4S3TJW3KIFZXRASEN2LFVVU3HO",
-    "GBLM3EI4VWC4RA4EQGRRQWFPLQY6JFV3QZBXMOX4UXR277OWUQHADCVZ",
-    "GBLMCKXU3JL3KOMC727QTNRH2KCKLSU4ZSIK4L6GWWQ343XHXEAS3SHX",
-    "GBLML2GTEE54H5T5DBOCHDF4GFMWJYXOZ6XIRCW2POTOQT74CMWXPEC4",
-    "GBLMO4NHMAERLFTOQMHS7FFKRPJLPAE3DA3XA7MY57M5X6KD3JDBQBTC",
-    "GBLNNIEEVUJ3ISKQAN4KVWBWGTL6NPAYMO4KZASWOSE3IWZF5CGBLZGR",
-    "GBLNQUTXF42N3LVXAB3CZAUJTX4FMEYD2JFBEYKHGR2U7T6NMXCHAMUU",
-    "GBLNXSUCETCFZM36R3ZS2PGSE2LR5J6P2T4GWPHBUVIKXSVOYKYFKNUK",
-    "GBLO2VEURHKK3LXSMMA6K5WZFOYFYSJQCGCMHFWFAFFP25VL42AR3JWU",
-    "GBLOC6L23GNKW6BWK35S6CMJI5ZDPTOURNDRACYUH6HT724AVWVK2APZ",
-    "GBLOWCBO4TUGVBXDILH7Q7P2EQDYNRXSHXUUULHSF6SMIZMXSGYT7MFA",
-    "GBLP4CBUB6SCV5OIRVHRMVOQ2IKKAEIK53WR4NKZ2UYKPY3VWUKKD4Q6",
-    "GBLPFQEWTZKEOJJONFFP7JHFLUGDGYVJKGBEHIBRVAFFJ6U4WDU2Z7SY",
-    "GBLPJF7E5ACVTDDCB5FBJUJ7YFFL7Z3ZL3CC7L4F7TNJ4MS6MSNJ5SKY",
-    "GBLQFVUAIBDDCVHLRDQB27B3BUSNCSY6MBURX5HE5ACKXYTPGZJ2TADA",
-    "GBLQLTVGVHRTBQCE7YZKBTM7FYFZH3TQ5HEQXNPC3H2KUKNDTMDR2UK5",
-    "GBLQO2W254OOPUMMSTMPLFGAEH6PNXE4ZNMK2TWUYOI3MS7E5SFXULDQ",
-    "GBLRJXLGHGU4YIIXOZT4BVSXMI7GELZPYGJJPVRNJOFJU2VXZQQK3EWS",
-    "GBLRPMC72MGXLR6HGHMBIZUUETK2NERRPO46PQSP637YPHBVDN72U2HF",
-    "GBLRSKNYCH3SV5SZWSAQ46ILAPEEVLAX2VUT6IZIQ7V4P7SYBZYOU6AM",
-    "GBLRT2I43WEYQGILFFAAJR2MQH76MS3AB4ZTFCSATJI5R52PZFKJTECH",
-    "GBLSHWFSIJ4VVXL2OQ7MRASEARN323I7IG3Z23EQU2HAMAFQ6WKAV4HM",
-    "GBLSK4XE3DZ67VSB3XDA4ITD6PG7FRA5JRNCIQXNGWCSNFLXPVLIKHAI",
-    "GBLSOFLT3B7BKIDDSD7OXHWBOFLDM55M7D3BMP5JZGMMKBHTYAYAIZDU",
-    "GBLSYLIXUAXPMJOCN62ONPZLUFR3O2NSUQL5NMWA3JWC3NLRPVKDL7YR",
-    "GBLT7BIFWD3AIQAAQLAAK2PBDD6TF3TEPI4K2IF7JMCB3C62JGCCK5NR",
-    "GBLTA3FKDVVFEDKFDVY52XMAHJBKL6T3RVRFGZ67LQTBFEMYRAJSWISS",
-    "GBLTCI42AR3YZF6WDFUREHOBBKDG45JQVEG3TVYJDFXJZD2OQ5RNNLJH",
-    "GBLTR5UXQAHVBMZX5LHHYV4T3JWJIELHZXOEWG3YETM7IF6RZYAI7ZMH",
-    "GBLU7WWMZUVV3TD74X5ITM7Z27DA64PFFPOOVMEWEAF4ZESRTUIK73L7",
-    "GBLUJHZ6GIG3KRR4GEYS7LIKAENDPNWFWOT6MTTR3VPT3AHUJYY45PL5",
-    "GBLVZ7FUZCKIAZE5JCX6KO53R3JK3VBIDOAYL5DJ7FIJ56WHB2TMFKGF",
-    "GBLW2YGT2EAD4FTZNFGE5OZ7DJF3SFVX7Z7BXUQFCZ2RHZCCXEZAELON",
-    "GBLWK4NIZBSD3Y2WUPXW6LPPMR3NOB2M6XTIRXSKDMV2F5MM5GMUBXLM",
-    "GBLWXVY3WUEAFE3XQDHES7VJXPF6USALLFQNI2V2OP4YZI6KRSS6XN23",
-    "GBLXIINHKFKFSZCRFQCBKEKCH3GQ65KRZJ77YYYY3YGQ5M7GH2UNBLH6",
-    "GBLXJPA2ER4GRIHVCBNQXE5V2Z5ZM4LHWQ42BFQXMYQJ5PF4AM6KBPY7",
-    "GBLXZHEH2MOTKVHH7BKH6FYYCUCTGXK5UXZPMFWFC4MDGKLPOKNQHSHX",
-    "GBLY4FNTG3UCYW4BOZBUQYUCGKAGTUP2AFE2S3EH3WAZQUVHWZNPA6GD",
-    "GBLYCOVDQDFAZPOPEOL4C66QDT5PSL7JJY6P6XXYFSJOTECVSSGKATOM",
-    "GBLYRE73UHHXE6WHQOSHYFBPNHTTDUKOSESSQX4RHKVQTWTE73FVW5LF",
-    "GBLYYGGGQMCNREVDFIEWPX5PUXWS3VZMQHWIBLZZYENFRA2JZQFW7TG2",
-    "GBLZ5L7RJCRLPHPSMIT3YB42LJOB7FYI6RYZVPYLH4FA6C3RXEUB7HVH",
-    "GBLZJKIFNBAC25I6SCDE2AGLMNUEE3FXX4L4Y2Z5BLHWAECWE6UATXSG",
-    "GBLZVAQNZDYQPRRTSV5HJY6ZHEVCIW4SZXS4OQWJYPUFAACFPRCYSTES",
-    "GBLZX7CMY4QHHEXQI5OGHP5SYKQPPE2MJ2UJH4CUJHQVFKJIPC4HA4N6",
-    "GBM3GCC3KAGWDDFDLGRQGFT3ONIUKBCPPZFQSQK66OYI27THJNINUFLH",
-    "GBM3V3QBGXPXHZRDUJZ5D6AXXPRWJ6U36WI4HT4L4C7OOB2EFW3L754I",
-    "GBM4J3ULDSUP36PXCWINK7US7ZEBKBOAK4XE2RZRCKQNUTRETCET5WSU",
-    "GBM4MVOVJ7FD5S3LRB76YD4TIZLK32KKGSHM2RK5WKN7S7UBBDGHNS46",
-    "GBM5JNJJVOHOTEKCII7CNUD2IHUHQPPS3Y3XH3TEHHIC3MI6S2FDPAC7",
-    "GBM5KSB7H2C7CHHB4JLMV3D5PBO7OLGWKGYLVXTWPMSOVDKHXVRMRJQH",
-    "GBM5M5GFOJCA77CXWEN5VB3VRYPZBZDLEU3XTIED4X7YNHXDBIYH7ZNY",
-    "GBM5RL5OQP45UFS6I5UW4ZYZWTSDASYEVLKCV7QV366MCIPVQ2QYJ4EG",
-    "GBM6EPH76ZEWUQNL4SPVTUCPBY3W3ATQWY76UNPPJSADG3K2QDGKBO25",
-    "GBM7TZS2D7GLSXMDHAYUYMPVSL2FXSRYHHLJCADEG6YZUM6TAXZSFDF7",
-    "GBMAJMFJGGZ4SY64Q6CJX4MFX47UPHDVDY4SOLFKZUGULUEXQVRK2WXT",
-    "GBMALBYJT6A73SYQWOWVVCGSPUPJPBX4AFDJ7A63GG64QCNRCAFYWWEN",
-    "GBMB46ILRZD7XTU6PN4GR7FMXPAAAP6WO52UKB7NUZH4JDGGGPDMVXOR",
-    "GBMB65ZQTGNUBX4JT4GILVW6222UFCOBCJZBMEWMD6DNPNAQEEZCIZX3",
-    "GBMBCC5RSRBFK4BIRE2UEEIU75KX73KCOGFGOBWFIBBDQDZZCPKB3UFD",
-    "GBMBEEMVRGWHWYHWG7XYCDFFR64AID66PSUDYAX5I27ILLHDX5YDJRT5",
-    "GBMBH77YWUSOML6H7KNEX3VTEMBVHPEJ5CUAXAOCHTT5SV7RQGXBUMSO",
-    "GBMBWZWC6WKVQYPJ3RW6PGNQ2EHN2NBTMFENCTCVUQD7WHM5ZR5VBEQH",
-    "GBMC2SGJNADI3ZKM7RV6SDFP3RYAHL5TCO4VLRXMAKNTRFAEZXW6BZF4",
-    "GBMCSOQNPICLHW3YZZRNPL7N5WUY3JBQAETKM5M3G6AOUM54DUDLA74R",
-    "GBMD5ELLSHEEEPA4JZ3ZNIPJR3XDO4QCKER3W5TXOTULI36ZCDFNGXLR",
-    "GBMDX22WBTU46BWXBDMNVLMU3TFJXORWMFXCN27EQNIW2SBYMAWAHRFV",
-    "GBMEFYXSIPC6ZDD3DGIO74BLGS43Q3BSPY2GQK6B6KVGOWXKZEJUZQDP",
-    "GBMEN6INQXDO4KH7VEKZTZA3OSHTHO322KMI6JTISCR4SDEFCRG7YIND",
-    "GBMEXYY7NB2LGOLNISCWAQAMK56IKFMHZD7Q5N6YX5DSHLK2RTGMXS5M",
-    "GBMFIFVBREEM6565ZUTG5THFL4SWDDRCRNZ2L5HJLHT3ZI7BHPN5HMHR",
-    "GBMFIYIO3O5ALBTJB6A5J3HPNARF2PQXNWBWNVH7GUHKKP3N7VBYV4VC",
-    "GBMFTEZONAHADB67E7UZTLSZNVAZSSBP2TTN2HWFBXM4PI5S7CDV56GD",
-    "GBMGBTT7LMKCM45F3QSLRXNHEE3AHCQPYGCEXVPAZ4KG57YSUYXGDZUG",
-    "GBMGH2KHQM5IU3PCFF6K3DZXFEJH2XESWIVTHNA5RYOA6BVIQIMSEYUT",
-    "GBMH2UB7NPF3G2MLIY3VVNPK3ZRWX25HED6YH5XXGRN7O2SMTGT6RDBO",
-    "GBMHTZN3TF2VIPOYCPQZG6HRCW5SFSNL4WVC5AKTVOC3FPGF5KRCH2ZD",
-    "GBMI25YNOZNYGFI6FW35GHJGSA5TWVV6Z5KJVDHWZAJBG6MMTRTEEM33",
-    "GBMI77C2L3DHN5NPEVHNBNWWYNVVDL3OG2XEYH4Q6UGY3AQC3LTXBQEH",
-    "GBMIE5NSHD2LCD7F5NAZSSTRFGAERS3JFMOJEOMZZRKEQBJ2YP53O5JO",
-    "GBMIHBY7456RUM3EIROGUBFQWAKK44OD3M2S4ILCVJ32HAESNZFWRX73",
-    "GBMIQZ6MMRETAU3TUWZXCF5GI3CX7522OGA5ZHMVVLSIOXMJB43Y5GTJ",
-    "GBMITVNCNVYWRPLUHPROGYCWEZGWQRQZ6HEIEVWKUCARXHBKTOBPMM5F",
-    "GBMJHKWWUPCQ2QGWGTSXHXA4MMXEB5TJFVKPHGXLCSPXIEUQGVC244WR",
-    "GBMJNKKOBZYWVO46LRLWI4UXUPCX6NHASEE6NAA4W5KA5DTSXK4WIBSV",
-    "GBMKG3BQFB3D4NEGJ4W6XU34UBUBT2UKPISXVUMPP7F6OSKFEWER2RVE",
-    "GBMLH3AAZNQIGH6YKV7R2JBT2YJLWXFVAHNH4BBVYHC4U5M5TNEFXMHW",
-    "GBMLHN4UNALTCH2XUGPCF3D7R7LO5MWHN2NBBH4YJCBRBIETK2647PCN",
-    "GBMLKRF2VAKG7VNKABLWUNICY5W3QCL6NZD5EZ5QGQHRTVPQC5RAQAXI",
-    "GBMLPYBJGBVSDANTT4JGOE4EZITI7MWUF3KB5VODY5J3PT7SWGZA2GV6",
-    "GBMM3CDZ3PJPSM7NXP3RUJGWKIIQ7UXJ552P6ZL7CPIFW5XIK3M2IOCA",
-    "GBMM55Z4C32RGRDCUGVB7O2DBEJX2XYR7YK5ML3SDW25FLJFA5EJPEND",
-    "GBMM72B6LESIP6TOI7FLIIMF6YDQ3A3XAXAZENHRZL4Q4UTXCIJRLQJ4",
-    "GBMM7AIO2VRU7AGRUQD3IMRF3Q6WDPBGBMXAL65B37FZQNDBPAPHYURN",
-    "GBMMKRZKEYWYT2F7CMRHO2ALHQPHDHNZSLT4ITL74TVLQCQJXPAMAMI2",
-    "GBMML6PYEXHTECHCNQAGMCBCJ6HYKTZH7D7W27RXI46B7S4DZSZOFXDC",
-    "GBMN35UTEI3JX37IOVUKO53OWDNN3CQV5YP4YYR2Z4PQXHMMZPCCAZQR",
-    "GBMNC336VCPNQXZY32PFXBIMJSOU7FMGIREGAOG6LDJW5AEEUUSVNYH2",
-    "GBMNEYVUN6NKZ3BBGMZJ7THNB2UC5CZIPZLHFOH2E3634OTI7GI4ZQEN",
-    "GBMNHVPLLZP2MG7L7SXECNUVP7YSZY45O6FFLKFRWGIEBWENE5BLEQFS",
-    "GBMNIKK7YWGFO6OUGU2IULBVV5BBBKMTVBN62LFSM32T6ACHZGYPOGOI",
-    "GBMNLCYHUHVY5HEWY2DA4XPXRILL7BVXSI5YGNBRCZXAPRTLOHZAELON",
-    "GBMNS755YMUK7B5XUSOV73LXUXJJBQ67Q4NRQWEDEXJI2LK4BMDN27Y4",
-    "GBMNTJN5ZU4UUTSBS5P6U55GT3OPGFKTXEPHYFPXS72CU3JW3JBEJ5QP",
-    "GBMNWY6LWRKCTBTZCI6UYUXMQGHEXRNI6E5L6V5EPKCNQOS3MVO4ZQFS",
-    "GBMP3DGHIQ7WSCDVYDRWLDR2VJODRZC7P3UPA4SJRNVXVDCOZMGYBCQJ",
-    "GBMPP2KUPS6KDKCUOH5UK6OP7JCLTG4LF4FDBCR5YY24UBGLMUF3C2HM",
-    "GBMPTCNWSOWQKQPZPOOPJQ5NQDJQEBP33VQTJOBAW4WEDNMRHIVILYGL",
-    "GBMPUDP5BQRV7OVF44F7RIQIMXTIYBANEPZNTHEMWPAUZ764QCFMQJNH",
-    "GBMQ5ALFPVVHCCJBMV4V6Z3QBLKQGQTEOEAXFY5HT4TEV5PBFDM54IRL",
-    "GBMQCEB7FHZMOUUHK7FXPUYLDOCTYT3GAR5RL5VRE3L3FOBZ3ZM5KI5C",
-    "GBMQFTR734Q5B6CTYSWWOFU7AYHTAJYKDXWV5ZYKO6UYCNU6WSFMRENG",
-    "GBMQGW7PIOY6HXZXWVUWCGNF5NZMGG3ABMYORSCRUUKYOTDWATJDELON",
-    "GBMQIBXHZ275JVBCEQVZXNLPNZ6WVN4MUULVJODX76H7FTCFR7BWLBWO",
-    "GBMQMQ6GMTNICGGUZPR6CV4NHETO7KZAVW272Y4UVCLPZVK7YV63APEX",
-    "GBMQS6MYAAVGH4VBYVSZG7WMZLAWNRHPPSZEIW3GELPRWXO6LH7I2XHU",
-    "GBMRXRRAHJAQHB2GKKJC6A2SZT3MKO5UGJJDCGL6LVV3BWBAXK3WNZIW",
-    "GBMS656Y4PT2MJBH4LUPUSDII5C4P34D75QE2GZZKEOSZ2CFSIGXJBTC",
-    "GBMTDV2WJO7M2JUOYWNZM4PY22WSMMYRJIHYXADH3PPAVMFTJGXKH53Y",
-    "GBMTHQBTL43UVVADRZDQWXQKXFITI323PTHCVLBTMXDNQ3SHTH577C7G",
-    "GBMTQ4PDJPAPPZMYE3DFFFRWM5DT47VNQXMEXBB3X55JMOQU6C4AQFND",
-    "GBMTULFUMHLHSGTXV5RZXQZUYOJJ3G5EVJEEV2KCQRYNWJZQ74KESBTC",
-    "GBMU24MP7BVG2Y6X3S7ERPHE62ME6HOCB35XAJRF5ORT5RBTULO4RABQ",
-    "GBMU4P7CXTGGAOLUNQ44HW22EAZ2UBT7XPDFVOLPGGCFLPOULXRZOWST",
-    "GBMUQ5YZCDD7E5WI6C3UUFACHL2RB7YZKI3F5FCSV2P2MI2LMNFV6YOC",
-    "GBMUW6OM4MKNF2MQFZZTZPZNDIUFIJBNRVMIJ7ZAI2UQHR6D5MZUVYCY",
-    "GBMV5TEH6YKJHHE5URZKL3VF4ITFXVA7FECZTMASQNMULBS3REOHMCQ4",
-    "GBMWJEYGFSDTARD2DEEDFBBGUPQ7XM7JLLJOGHWQNLP6U43I4XF6Y6QG",
-    "GBMWN5CZ3W7QQRZMBQ24FUFSUPYX625W5LDWGF2GTYIBL4BOU2NDMGRA",
-    "GBMXBIMLSO2XFUXLT7SAHPZKO3Z6RG4SHPRKS6RORTUAMGSJ7ETRC6PT",
-    "GBMXQSY3DKGGVGC4UYD6ILICFUGSWCUZOO3RPMH5YR4A7RLSH5GOVJ67",
-    "GBMXZ64BRNP3IHTUE5J74EDBGYGUT2GWHDDS6MS4QIX75KASM2MTZXNC",
-    "GBMY6EBEDZH7RS3GHLVXPN53HW2C35HAWFRH22GPA4ZUOBRGUA7DWQCF",
-    "GBMYGRGJSJCGC7PLSN4IUOIO7SVK5QRONR766SIWRW3HZNBNWY6FQPO3",
-    "GBMYL44KZVAMAWMPORKUAH5U7ETVZA7IRYGH4OLJW7DZ6XRRDKDOFT6B",
-    "GBMZQU6P4UF4WTPW76NOHXIWNFHFYBT2PYRVT5CF3MVQCPXGXBHS6IUR",
-    "GBN25UURF4FRIZJCMD2U2INW6A24XTP3ECVEYTUKDQ7532T5ORJYALBE",
-    "GBN2XW3Z7ECNNJREVRZUY4AJJHVKNSO6ZQWOKRP2PLGF3IXGDMQULBTC",
-    "GBN3CJTY2UKSXDL6AO5LNKAXLK7M5BY24OE2332L7MKS3QSMXCXZJIFS",
-    "GBN474ZDJNG565LNPUSSOOKJFNBUSTZQLX6LXUV23BMO6VT6PJA2JAVG",
-    "GBN4AXDJY3EQVO4CZ5P634M5LZFXZTJWT6HDKQ7LTCUCZIRK6BIYDP65",
-    "GBN4CQIJD2U7MMPBI7MDF4XTITURR2I6Z7ZBPRBFSMGCF7Z47MO5LORD",
-    "GBN54OYGQDCN5HJME6Z3RL4VPHWXHKAVPCGQ5H7GPBUI3345SSD5HDHX",
-    "GBN55M5TIXFOZ435DNTNLZS75Y5X4QO4RPCRDN3NLPDTVE4RUUSTATOM",
-    "GBN55QZ645Q5QLJCBRDVEUKQB4IRQTMEBFJYC4IKTJAARBEBXIAZ6IYJ",
-    "GBN5NSCYVREWVL4PGWYGRICQ6KNQDYJU6ABV4NFR6C4FNSK3CQFFAVGA",
-    "GBN5R7ZF472BNVMJK3ACJU4WMLQTILJ4NB2OSWKFYXWMQWZRXAFWSNPS",
-    "GBN67UD62U73E77FKXSSYHC7GTVVRWME563BDQRSXQFIFESLE5IPQBXA",
-    "GBN6FPANEQX2B762UIDLLMMVXFOHLCNJMANILXSUE4W65EPZPJN3S6PE",
-    "GBN77GH56ITGBEWAFRTNDEARS7GQZ5VRYMM4AEMBAXCCI2FQTHS4MFEE",
-    "GBN7OT7ND3PJCIYN6I2GSRHCQRFXMPI4UT3LTVO7BXXXNSJL6CJICQHO",
-    "GBN7X57KOXUHWE6GPJXHUM56TUKBNZBWCPSNPMZHEALUX63CIISDAPUJ",
-    "GBNANASQSFQCEZ457YN3UFGZ7HYURP2V5YWX22WQVC4S5THIUZZXTWWK",
-    "GBNAX5EQLYJROYPHKJSQF644FSOIFQCOFX6OD23PBGKRFINV6BBVRL5W",
-    "GBNAZEKQBCDBTJJKXC2P5EJZYGOUHV74KVKAUGUUSLHFTEWBJHH2TA5Z",
-    "GBNBUBSWXVZPBKG35BJB6WSKJC4HAJ327R7TCKWGKTAMRWCSAGVGLO63",
-    "GBNCB3CO5TUQ6I3DUDQ7GLJI3L2N6BV2GHSEZHNUTM5VUR6GWFRBHWPP",
-    "GBNCEJPQLKTGZRPLY7KHVSKQPOJRJBUARY37VQZ4VMAAJVQL7ZVHMOFB",
-    "GBNCOYCP2T2V4FQYSPKL6KZWFCLZDGDSGLPDKWASMJHGBQX77KDGSBBD",
-    "GBND547BPATKR65ILPWQO4JA3EVCP72SII2MEXGJTRGTL4PWXMAKIPST",
-    "GBNDCFQT2C2GKQSFSBGSAXX3IGBFT6CMSVVCMZIW7CUYZ2I7MPDCXRUB",
-    "GBNDFACY33H7GDSOFZP57YXVE5UEASF3DEBVYZRD3TLMU6L4X4SKAALL",
-    "GBNDONN53S7XBST6FRBBBM6JZXYXQTCGGPVK7U7CAWALESSB2AE2W3W5",
-    "GBNDSXFRVEXKHEMECEMIAMWPXFUTG445Q6MJLUVNR3OWA3YWN7TZBXVB",
-    "GBNDYITLSYLSTPVXZNIFQQNHVWHPQZSNLJ5OV5J4IFY4QRZS5R5O3SZL",
-    "GBNEABETLHLTCT3BDQW6H4E5C46Y2CFKPDF2YQVG2L7O7CMGTWSZ3XNL",
-    "GBNEDF4MRWIOMT4HQBS7IDEAQNWYMM52Z67IXKJFT3DQRIPIFCH3KDYA",
-    "GBNEKKSWEAJVYUJ55FZBG34NA5DIBKSB4T4IJDHI22BW2W6SKKGNZ6F7",
-    "GBNEUEO3FJOVJPJ37HKIHHGQAXNPPM2WBLGDVEBHFGVWWMGBEKJ5GHLL",
-    "GBNFHYS6LVMEUQ2Z6IB344GU5O3UYHT636S3K7NDEDQUGHM6PD3ABONK",
-    "GBNGJDFFRND753QDEAP5AB6IIZW7UNLQNVIIXV64BWYRJBLMB5RXGAMA",
-    "GBNHIFP3HAVCC4LXOCZORKX2VUQ3YLKTTIM2K4OHEV2DKA3RF2US567K",
-    "GBNHMO5HMGQ6TKTHJPX5FXY3LW32PHMVF62PSS3YWTOTRAID3YUDHYHG",
-    "GBNI5Y7YZLSHYXJGIFZATNMFLMHRCHBMJPWSFENM45YLKEBMDXJ7UDXR",
-    "GBNIIIH2FEZYIPN7S4IJPVMKNOLFLVWD2DX5PLJGBZHQJKEN6LB5K5JC",
-    "GBNINAF6X67A4QUJEFJLZ7EWUPVXVMWAMX5WTSAUPK6D447LTKETZ7NP",
-    "GBNIZXCTSY6UHCWE3HKLGJP7YXXRBS2HFZXHBR52CHVSO63E7FJS5OTX",
-    "GBNIZXMPL7YDMB3V76VHLAHBDCTOFYP2GVATATV65XXLP63DNQZ2ELTS",
-    "GBNJFF7MTUB4EYLIU2QU6Z7R4AX26I2E77ETPB7PSZRXJOJCOLXYGXDC",
-    "GBNJN27JMCBBARDW7GMCOIKK5FNTX5MBLJT4L2JJ4FXLS2O4ADI6AABJ",
-    "GBNJVVCATUIQVMA44ANOM4TXKENWZOGZOCTLXZDIGUY6SBMXLLYNHOAI",
-    "GBNJYKEQD2RAVXTJQUQTBGZGKBHMMOY3I2OIXMELBO2YDPMX7Q5ZTGSY",
-    "GBNKKS4BL2BRKPDH4UIJM5QEEIYC7BT2NXDP3UOJBX2BG7WQDG4GOBCH",
-    "GBNKOYK35AZDPFJPGZYFA7WA52DAZDE7DYNWXVUDJEELYMO4TDYQL3PP",
-    "GBNKW5URNFTZAIJ7XDUHSWHUFPUAN2WPZ3M4PKTIROAKOFJEICJGCIUB",
-    "GBNL37LRFYQL24QP4QSKJXJFZHCK3YCBBPQU7DPKF4L2ELMIBYGHKIW6",
-    "GBNL5MGOTIDSEZANHDCC7WDPESGGBZSS2X64DZJATN4REHSGKCIVU725",
-    "GBNLGOH6MGY327YNNAL4HM7MM2ZWTRY5P4V7QLRDJPX4USD2V6FZDUCB",
-    "GBNLPGDL776UWJSPWSSF3KWP3Y6NCZ3R4VZV7MHJJNMD3SGWEXNYFHLM",
-    "GBNLY7PNRSJSV2RHOT3SIUDWDQFBPQDPNBFWWMN6QUYAYQNLNQ7HOZJA",
-    "GBNM4NGL6QALQOZJCZE5QDS3KMJ5ZFQ6A7UNALDGEDP2KQ2NJAMRSWD7",
-    "GBNMVVNOVZYMMOMWWPAZJAIUFZLZSE7JYIXPHCZSMS6XGBTVXYBIXBQO",
-    "GBNOD5RVA6TZGED3WKPD5H7COEFOVSJY7QFXBV6AL5REIYRBDQVJEEQS",
-    "GBNOFP7KKRIB5NFUW4EWQGEOAAWR5RSKPWMLQWX2MV4TA6NINVLKKZMV",
-    "GBNOJQZNSSUPYNOA7NIV3R4KEKFDAMEWOLIHZTWLVY2XKYARTK5WX6TN",
-    "GBNOQY4NE7BHUNRRJFZJSV22FP2UN2HS3XDBEI4ZUHAAM3T2NZQLAMV3",
-    "GBNOR36RSE7M6W6CZL7HUJ3V7HCVDFQZELAZMDOXIBVY7NSGF2HPSHCW",
-    "GBNP24BOZOGSJ226T4PKEKQP5HD4NERVVFO5MZJYSQNIZESTKCCLAIMS",
-    "GBNP3LCHMVY7YJVZ3BTQLUOHBZSTZRQQCC22JNQ6QYUWIDZLE23DUNCJ",
-    "GBNPQQBMA3SUU7BPHA7I6VLACKRNLSHW6NV5UNKRSXDUS2XPAUHMMSJ5",
-    "GBNQ7HBOBNDA5T3LJAJKYBGTUXNFSPYVUBPHZMWTRMMQGXUXKM2GQYPW",
-    "GBNQJ2RKJ2THUK4LMFRTHRHJBVV6JY4CU7JYYFGCGAIAFHTYYTCKFSS6",
-    "GBNQKLH5YAY2IML5ZPAYBGQWDJ7BVUTMQ73SYLU2BE4C5FY7PE5DCYGB",
-    "GBNQKWF6OCWWNM4LGHTTQMLILQ5XMRV4REJJXQ66ATVSHBSEKFMMXNML",
-    "GBNQOJ3GJNCRHNSR7OHPRWX5ALG3MME7OSQ6YQDK4ADSX4CNTKGQFTJE",
-    "GBNQQHSDLFVP2LSVHCRTE2LROSF6PJ7FK7FCFFW4TTWUWUU46MHT2CEG",
-    "GBNQQPFCN47QEXB7JYXGL23VIBNJTSKEFWNFKM523MV7CQVQUY3XOTHK",
-    "GBNQRN3ZQ7LUNGXJFYWXHPU3WBJOUK6R4KSHGPUZC5ORTE4Y6KANSGBA",
-    "GBNRATWY7MD6KOLB2JXK5WC3EWHOHN5IQPM5VQVWY23S5BIK4LVSRORA",
-    "GBNRC6Z6Q4YNXYYKFGY6QVW4RXSSBKA4MIUMY746HLNTCGBREUMXX2MS",
-    "GBNRIJVBVPRVABM6WHYK6PEXHO74PYRRJE6BTHYDN55LJVUQ5MO7FHIN",
-    "GBNRMHQPKTK4LOTR25TVLBSKQB7JGV2O27YVIKWMNJNUK67ZO2DX23FK",
-    "GBNROUYKE55BALNVY3P7LSHZYKOIUKEHLB4HSFG3LM3E3SGHURJKEAOI",
-    "GBNRRLDJEH7JPVZO6II5FTVJNHDLC56B3UJGIXOJHELNE5NZ6BR73H2Q",
-    "GBNRSUPWC25CVIDQ3NPKBD4A4GXUIJB6OKDZXOVTFTWKUJVRXSTSOQ2E",
-    "GBNS2U4TVTT5XKB6L3SRTQPOKWCNA32DEFEI4YRJNV3MPK654F4NENVI",
-    "GBNSMAP4ISRD7MDGZY3SAMMLAFT5I6LXDX4XXA4NY5HOUSCB5K5L4XGO",
-    "GBNSMBNQBAJFHVRDOFIQFR7DRI2ODFJ77US3MMHEXKEUP4A7PDTSZCLU",
-    "GBNT36LHLPMOCZIGVYQ43WDNVT77QY5LBRKCS3C4EYP3G3HWKSMHDAV6",
-    "GBNT7QK75ZOUREWJL2VOMHUE7GJUMJLS7YQ4T2UJL7KWRYA5G7XYJZFU",
-    "GBNTBPKJ4K5Y5WL7LVWRJKCCIC4KMZXJTJU4XSFPJSS27O6JBOHTD47X",
-    "GBNTO432SSO6W6SZZSEHWCKIZDRNBBV7TCBTONQEA3LBUNESFA3LFI2U",
-    "GBNTOCURT2653Y3UO4EYM3S7ENJ2XNBICG3K6NHMMLPPKJMVKVANIPE6",
-    "GBNTUYEFUPP5QNBDPFO6WUF4RICMWX4TGQEUPK64R3J6YN2WURNDTOMX",
-    "GBNTYCTOZP4FQZUOH4HHIGAZNOERHXYWNGVKN7Q35FLO6EL6TYMSLDEX",
-    "GBNUBGHS7OC3XTCJ6YR6PVIOVB5N3DQQ4NZDZQHBPLNBSYUGVA7T7735",
-    "GBNUFA6K6OHGD337OMGYOZ26IWXTI2Q5YEIVU5C6SN5B2WWM7FBYEZN7",
-    "GBNUIRSSPO7W5MV6T6LLS3RFAJ6X2CUOOG6PF43LQKL7ZFBNUTSALJ65",
-    "GBNUP6RMCYF2JOX7TLMWVYJJRVLGG33KLFONR7AZQNKDAA6V4PBOE5HI",
-    "GBNVO46VHTJ3GVPWBS3DPDYQMZPIIV5EGNI7ZEO253OBEYYYIMM47BCD",
-    "GBNVSBPH3DQ6L6BRPICWNQTNBXOEROOJTZXOFQIEPNXPBO6CKSRREEUD",
-    "GBNWQDIE7SJLC4LBYNJWOYFWKPFPQO7MVGAMQMPMMFYENL77VXGWJPMG",
-    "GBNXE4ABPKGD33ZQUVOGFCWPQAWEHNVESTTLAIAAUOE4SOGRBMJSCCSN",
-    "GBNXOFSGTVI5UYPQR743QJTJTU6TPMTRMHYU25D4LX5BNYQS4YQEZK5B",
-    "GBNXYXL2F467YDIPPIYINQI4NA3PXQXTUEHIUVE2NSYOGP6NTUPDW3E5",
-    "GBNYBOYYSF37TYGVRXKFJK4GASPCAKTO7MAO6EXOMSHYCKI65TDSPDSV",
-    "GBNYRTWQT66EEL5FWWTKCZYTXN2G2IYBWOZEH6YOGZJUJ37LKKFWGBAE",
-    "GBNZDJT5CPMVTHC46SZTPQX2QJHYNU4AN65TA4CEBRXUIHQRURZ6EBTC",
-    "GBNZHUV73UKD3KVKCV4BVWUO7K4EOXMNF6ESLQLK773P4TDM6DWHPZKU",
-    "GBNZSYU4HRIN5VAMA3LSRHFJENA747IJT3G27ADLJDXN5PBHJHPFC7ZC",
-    "GBO2CZBHKGZWUGK525CE4KB2VRRV3EDGN6XI7QHFGFB3T7ZX5TNR5UTR",
-    "GBO2KC7NR22BG5U5SVWHTLL6DMHKQRBBHZRRYPCSDYWI74B5YFJPRCZW",
-    "GBO2KP56GAEEFPQBGROLJDTHUMJH37Y2A7UR4IJXXWSVTMAQCIU36BTC",
-    "GBO3AU3E4OI47IA4UH3KXYUNZG6LVAUBVTQLQ3ZZCSIGM2F27JS4PX46",
-    "GBO3TRUXBQNYVZBVVTFTUOT5BVZ5DLX6G5YJOSAWT5VBV4MJHEBXMLAU",
-    "GBO3X363IKTY66CTAHSREBA7PQIHFNESPQZYHSWNURLPPDNAULBL323A",
-    "GBO43XMR6H7D6UOHALEJBVK3PWTJQ3SSIAEMXPCAOGECJ7MGQ7S2223R",
-    "GBO4MXCCUZXV45JF2QXKPAQ72X7T35LAHJ2ZB7XKMNPSOMEAU7KEM2O3",
-    "GBO4QDZ6WIKJYTX4ITUBLNVS4RW4CM454H2M2GGGRKP4STAE7APMQIDM",
-    "GBO54TRKUUYXIJV3RCLRFTVHQTRIL546QI5V2WKBNAEP6R2MDITQGNFT",
-    "GBO5HHDM4D7RWQDYQK464KUMA6PEM7ZZ4NIXUDX6XNFTM5PSWYAGAVN2",
-    "GBO5IONZ4VCQW6YDNPRIPHEFT2CETE6BO6PAP66AQZCFTAYA2M6CRO74",
-    "GBO5T3QELNUTYFTX7FVZUR2BLHTJJHDK4CA6SIFUIKRES6ZSBHVVACJC",
-    "GBO5WEBRJDT7AADV7XUUD7XERTK7AE6D7ZVMUN7OIQ2KUFQ67ZSFE5FV",
-    "GBO66PM56EEQB7IPT7W7ZO5BSLEKE2VJXITRWGXFWZCLRQNW7WXJLIJR",
-    "GBO6BYZ5LU4I3ZZDEWJY47UFVXDPEO6HGSKJNIR4UIAAUIQHAUWTEMAE",
-    "GBO6DNADFL34TDY2IGOFXQAVLUAXT6CP3DMDRP5EUWCIW4AXKRMQMANG",
-    "GBO6EOX4I7XM2AIJQBRLHSYIAGGCWSABVYCD6GOQLJHZ5ECYI2ZD7XRP",
-    "GBO6LGL4XXJQLWZ74WTS4RKGB5RRXOBOGMLF2R6KS763CWDMXQWV2V7C",
-    "GBO6QBZSYDGSHPKLS4VOSMK7WQCPPDPNVYIXNDVF7X3NGKG35V7EXHBJ",
-    "GBO72GZTYB4FNLNX343FM43NNTVEKHLS43OK6ONJNZAJ2QSDLDFASE2I",
-    "GBO7CFF3TCCDBTNM7NFSQXOU63JVMEKID4SKDRJOMP6C4QJQFZPESEWH",
-    "GBO7RCZJVZM2FFQASTPZC6DQ4DFGLCS27CSVGTXIO4JT4SEHNTUEBPBZ",
-    "GBOAGGRIHSWLLUQ5SBHD57YHEGKC3V7Q4KVSSHRXJZSEZPB2YWPCTLDH",
-    "GBOALDZUOYIU2MP2DSKGIQ5ZSZXUXFJXTYZL2QMABIGCTRUH4QHJHW3I",
-    "GBOAU7R3U6YNW7OVO3YXZ77JFZBTSXID4RW6X2DGIJXWYBYPJM2DZZ2P",
-    "GBOAVKJRTAWCTFDAZ7ODGVAP4QUEGWR5XIH6ULYOSKYLHNYX25IJ44JY",
-    "GBOBGZLFYWNIPIVHYXKSI4RHX7ZOXIVUMOXS4DVXEMZFFO4XXCGRKLDP",
-    "GBOBKHXD7LRVXMONCLC3GSKP3NKZXAFBVOBOZFUJIPV7JECHPELDRXIV",
-    "GBOBWWWEI5RF3JKZGRPZY3LK2O2W7SI6CKOWBXC7CBL6RI3H6VY7Z73A",
-    "GBOBY6M7HGM7J76ZMXCJXIV6YCCGZWSED4SCEJLXF5EQZAZRFDNHGGPK",
-    "GBOBYQGNLLRS3OB5HJ4H2CGCXGRFM4LDKPBHJ6NQWG7ILCJEMK6DFCHN",
-    "GBOCFX3HHBVJ523L2CVENTYGHWN6N7THX42UVDJUE572OHLTXTYZWU4G",
-    "GBODPUCMSM4ASEMDJIR7FL7AJEO6AKHNLHASIIGLL4MGVEPMR7IYYOCK",
-    "GBODQJAGWBB57XQWR27E475QNBBVBULGZFLQYJTVJ5DKBIAVRDYX56XN",
-    "GBOE3F5ZIS6CPAAF3U6T2HHTUBQ24LCDHSOGBIWOB6IAYQTSI6PFJ2QU",
-    "GBOEEVARKVASOQSSXCAHNTGJTVALJE2QM3AQQ2K3VXACQ6JJREQRJZKB",
-    "GBOFDFHR77ISZN3SGBS3AVBUPIMPAEPYZXKVDLZEDGVN6NBA7B4KIPJG",
-    "GBOFFYLHSTUX627VWWTIFMYW4POCBOI43EO65TN6DO4D2BMHBSQPZ5M6",
-    "GBOFXSAZZFLIBEWWBXF6U2HIQUBPZGWOAJ5VZH65NOML7KHLUIX73FF5",
-    "GBOFY33S5DRK2F2RRPG2RZZ6VIV2BOYDXQIOSW7EQFCYP4O4VM7WIM22",
-    "GBOG6ANQJC5MSN42T7NDDAV53CFYNACSNHXTK52MDP3MXZEZTHOCPYXA",
-    "GBOGBKAHMXIGYHA5JRPOILGM37SJLQ52DISXCKJECAVKOZQ3OQ3J47RO",
-    "GBOGKU7VWGIO6EFQB26MDXAJCYFHW5TKGDYKSPW7Z7MMGNZLBEA2ERJR",
-    "GBOGP5WHZFQCNXDXACKI7MA4MKKDUQZKZ7IBX7OMTQNHXEA6HWXZRMVL",
-    "GBOI5M66QOE6I5JQL672LEIGYEOPJDHKCRJDRAGX335A2DCR7Z76UTF3",
-    "GBOIXPCAHUTFC6ZUAJLY3VTP3VUXRPZTZ2HJVBHRRSVQONQSHF7TCM2U",
-    "GBOJ7KKGKXRARYPJVLVPSQ7KMUHAH47AE63YZDLIBGOOILUOAAY5WBTC",
-    "GBOJ7XVGZ2SWV7PQP6ZAFREXLBDFYVS6NILX7XGYMZTAEOV2IYANCX22",
-    "GBOJFVPA2JGVWJDRPUOYQDDNST5XWDFRGGBNGC4FEWZ3AKPUCROMSUC7",
-    "GBOJHWRMPH4RZO2U2BTCHXZWGUIFRZZUPTCQFZAVF3LRKDKQVM36NYGY",
-    "GBOJLFLDFDOS22AV6QN26I4VQZNZMWIAXWAE2O2SZH74677SUMIPVGTJ",
-    "GBOKIM2MFZXVYXZCFSE7SNVK3JFNI25V3UUB73HZ7STQ3HQJOMYS4DNS",
-    "GBOLT6K2AWM5QLLREWWPNUHNEKHJ77PBVVQ7IW7NZ4FGCEYYP7WUPLAT",
-    "GBOLUQCNL4PL5JYJIZDO4MIEVXVEPGL6GDZR4B56XKE5A647WIIP4WEH",
-    "GBOLYBA5F3NKJPRP7XKRT4GVWTMULKEK4MYZEEQSYL4TW5XNZGVVDBSH",
-    "GBOMXLW66FUTHBS4AGELKOSXGY7M3ZOLZ2MARXNHSIUH3YCKT27MLQ5N",
-    "GBON5SASBWHJVV3U4OLOVU5QUDA7G7AMMBPMGGDRPMG3NV5HD4KNNYSE",
-    "GBONH56SEN5HD45DHMIR2MP6X5H37WNHU7DY4XQYY3OJZS4KN7RYHSA4",
-    "GBONO5JS22TWMUJ3P764B7C2CVG3PQMIQA2I57S6XKECBHAD6SJAI7OB",
-    "GBONR6RPUA7ZB7OSGY5KDBF3GOZUKCRF44PCH3JDDBZS4WV5DDWX3XHI",
-    "GBONXMKOSNHR3UZQCRZ5I3X527LWP55LJME5TNCXKTGXCTI3YPGFLARE",
-    "GBOOELNEQRJCEPIBU24GIMGSLMKERTD6KBVJY6RPE5YBKQRWYDMLCMAS",
-    "GBOOEU4FDAKPGZIHWOQVJ5F2SHAWPPTRHLHKDQG7KSM6JYWDES74QXTB",
-    "GBOOVUZHHKZIAFIAF7F6OQYRGYJEFFL4QDOIVATDINBFELRG5K5L2GYJ",
-    "GBOPOLOU6Z57MC5SIOFYOJZX5UASYZALPM2F4U632RDFGDGNH4OPKM2R",
-    "GBOQTVHY6JFVKQ3TTGLC235WOV473VCABIPVQCAPUMV54RLZWSDJEBTC",
-    "GBOREOCBWMMW6IPA5TB2TSQT4CZXBRVDBKHSIOGY73FUSSLMX4TLTT3B",
-    "GBORKA7ZKF7K5XL5YJL7SDRI2WMSEP5D3O6QGFZXIBW7JBBXNFGYUBHY",
-    "GBOSBD7JDMHZCCCKKXDBZEAJ5NXPCDYVQTPEBONQXDG2YG3JIFO5YCRU",
-    "GBOSLOZENY4ERZFOW5RBBKU57BYJGVYW3OHGJU67GZ3K772MRZXAZ33C",
-    "GBOSM3IFSALQ6YNM2GJS75RHQZAM5RM7DGNVPNECY2OOGOQET4BOVS36",
-    "GBOSNSPDODHSTF7ZDPU5JTIBOANDDFVPRC5D6HTCRQ5TYU3VMKES3G2I",
-    "GBOSOMXJJJF4O7CH423LANNW56QC5Z6EFUKKBC4A7IIPEY5BJ65EYNH4",
-    "GBOSXXG2B2V4ETU6DBZOEBMUU32AW5NMIJW33VUYWIWT5NK2BNADV33I",
-    "GBOT4EHLSOJQCJMESLLBWORT5OAJET3RGBAOPZRSYPW3KTEH6Y3NNY3O",
-    "GBOTWWMBMVAXZSIXKZFBPTRRYOHNQWYPI5Y7FVSVJHX55I7KU42V6YQM",
-    "GBOUDTEAJUF724IUYHVRRAW4SHMI7J3IK7ZIH25OBTVG6JLJZHCIWZYP",
-    "GBOUJWOJNBIJ5IBPWFRT5PR476PI4VJPXZQIOK5CQLKTOWPDLQGW6EMU",
-    "GBOV6VYKLZDC4WQISHVFWBQ7DKDJKKANJA43DTCHXOZO4WILA3X6WHIP",
-    "GBOVL3FQSHKSVBNSEOGE5CUICVV2RW6VCZQQFKLDDOQO4BXIHE3ZUTSN",
-    "GBOVQ2JUSBYTJ4TPJ3B22GTABULRAJSZB2X3NE4Q4WHJ3LBIRY3HOG35",
-    "GBOXA632F33E2NCVJVTAFZBLBLL2I3CUKKYGWB25IAANVXSJKIV6DLXM",
-    "GBOXFFELBZXTBNP3FP6DQNBP2VY3NQX72XTHSKO2QITGWEFNMTJXTWEK",
-    "GBOXHMLD43HEMVTP2UL2TQ4ZK6QG2NSPH6E4XCW3EO3YQ4F56EQKDMMA",
-    "GBOXJFOGL4IPC7AAU3SEM2EEIAK533OYIZKBLJHW6HEN7OJPIGDXKSVO",
-    "GBOXMJN7S26GZVKKHOKNTVLYREHGFOTJOQOOFZGUDGPWTPBDOG2ENL3K",
-    "GBOXNWGBB7SG3NVIA7O25M7JIRSXQ4KKU3GYARJEFMQXSR3APF3KRI6S",
-    "GBOXYOHK6JXWMRNBCTRD2ANV6LCOX4R2IM7SBESIRXIGXCMS5H7V6YDL",
-    "GBOYFZB2WJXHIPKGP3SXOTAM4JFTQFWSR66SWXDIZYTLO443WLMVVTFW",
-    "GBOYLZETLMFMXJGB6DW62JVFTAROLTHVNJL5A7ZKPTOO7OO5HRX7AUC6",
-    "GBOYQNHOYOKP2RUSKQ7AJGUUVQHICSGZUSCOCXSLNZVM3OFR7D6DACHB",
-    "GBOZ2LX7TGRU5XWFKHJFIGVJHN5EFERATFMMJ3JEFD2FUNUJN6HUV2R2",
-    "GBOZ7REPDSFD3CADQUP32NLQYZ7ORIPSTRCFUMU55FOKOSC745EKDC5U",
-    "GBOZATSZBNN3JWSLEMSRBU6Z5D2BVZEX7UEDNJYEDSSJZBLONI2HLDEX",
-    "GBOZNMSDZXROE6KTU6KKKQALQC2LISXVGCLCC6KCWQ6HMVFR4UDQ3G6Z",
-    "GBOZVQHVLMFOBZZ7IRSOVPE4RS34KFCTV3G5V2KJYEW4QS4D4EMQ4WGM",
-    "GBP2CI35IXDCHUAFW5UZOSPO3DZOWEXNDRODDTX36UP37Z36S7LA3HSO",
-    "GBP2F2J2VMOF2SUAQ6KQM74YYUAL6TK7PJYG4PBK4O5CGKJ3E655GW7A",
-    "GBP2HAQIZNJXPZ3RG3WJCWHXFUSJQPNIJLWBNFJ6COEQPT5CDQZEKY5R",
-    "GBP3FSUSVYE7UIFURMBH43X42KM3VOB3RUDHF72SZNWJTN7G4TUENCPT",
-    "GBP3TDYQ5QJDNNOO4PFMNMUR6VKDLDN6MHEDV5O6XLSC26G55NJ2OK7X",
-    "GBP3V45MUEZYFYNK7GMYWUHM4YFGKWBLV5BTB5GKFSQFTFQNYEA453DH",
-    "GBP4SLBYZNNI2LTP6KYGKHKRPOUBOAGZSO4VOZ6WWAFZ2RUI5U7OLYZX",
-    "GBP4YS7LF2DE74BJXHEN3JJWAVLXAF32BCLLFBVAMNFLFZ256HONSKUW",
-    "GBP5FIPUIZKIGLZPYX5TGOHXRF74UQ53Q4AQNIDG4OMHC72LM4A25BIH",
-    "GBP5PAXM4ZDRX5NU7IZDAMQXTN5XZZEEBGNJ7WMUFV6HIHQ7SHZPYUPY",
-    "GBP5RJRWWSKRCB3H7ASCYCVW74ILXNSH52XKLGH4JN6JNI4E5USQ5GZ3",
-    "GBP5UQ3M7LQMS3OEZLOUG4RIOLP55Y3UU24UPYXMVM6L5VKWYBVMHSK4",
-    "GBP6BECATJCJT5UJSFP2DAQ5UKNA24YWNI3IOB62YILLKI6HCFHDC7QW",
-    "GBP75BYVRJJP25QXPK5R6FQ3VNAEWU6AUCFXXFPBIARB7GW2WTGEXXGB",
-    "GBP75GEVYQKDFK2IIC3MCL235YHYWWDE6AVIRFEQXULVH5G5OAVYJAPE",
-    "GBP77QZD4K2WC6TNOGPK4W37BDRGKI742P3IVDDDGHNJEO347P63KTKL",
-    "GBP7C7GPDITBQDIHFQ7LGRFQOFMIPVBQLA6ELCSY5LWVTUFFFPC6PT5W",
-    "GBP7E42TISB6XT7KTMKK5ZVKZPA4HA3VYJMIPO5RNCVFZFYHGNZXKMY5",
-    "GBP7NP37GMTJQBQQ75I4DA3DZ6RDQ2FRSNZIZGKDLNDVV6ZL5CUD5QDH",
-    "GBPA7USYCQBYZXVWUXOFIAJQ3DVZYSBI4IT7HT32FRWOONGZ2FDLNA6J",
-    "GBPAPKUJIROOWC7EGCIFB7LPYH37GE4EICHHNX7ALFJOLH6XP33NUJRZ",
-    "GBPBTF76PXI6CKJR3ANP2ZQZ5EWUAR64PYZKRB7Z6JD3YCYNTSZIK3CU",
-    "GBPBYP255UVWUM6ATP2GESJ62LSAXBBLEZ2UYNJ7PN5KWXDK64V2M3XC",
-    "GBPC2YEI56EVCNIRR6M44O2IIDJRS76JNUDHHJYQWWBBSEX254QWATIA",
-    "GBPCMAGCVBALAUBFERIXKRSZLZFOSFJHOFUMX33NWD6ZNL2ANPDGYSTZ",
-    "GBPCMUIX7IPZ5PWVFEB2FWFZVCCQW7237BGNINI3YEV5IKCX272N6H6Z",
-    "GBPDJSPMLWN6WVEWLZFQIL36YVAP62ZEXOHJR7W35P6KBETFLSVOIH4G",
-    "GBPDT6J4BIA3UGAAX5BAA2YYISDQ7WPAUUQH6EZ6FTA77XJZDIPA74X3",
-    "GBPDTIEY54QIGPI3CLDMTWGME7ZPOWOXCFAUU44COKWLXWIAARNZCAYS",
-    "GBPDUOFZBBRBDP5AO2JBO7XP26LT3KWEBDX76TWGXIB57E5WPRREBOFP",
-    "GBPE4PH2DH3EXJPVZK7IPIZ5Y2ZVR2F3YCVJN5AUC5WZL6Y4H6MYAN5L",
-    "GBPECLXJR6X3VT5DXMVB77MFNEZICUQSULYYJZCXU5DNMNJRN2AWAZ6I",
-    "GBPEJFDNIF55HTKWPCSDT6HHABVUMMGP2YM4Y3SJ7BFRJGQ3NEFMELON",
-    "GBPFEXPO5QD3VCKSIHBLVI7DOJP2B6JAZPMZPAV7RWX736KUBJQBGVJ6",
-    "GBPFUPZ2V24ZEGCYP7WUQSOKT74HJXY5RVVKC3OI5BYRP64RPD6NSHOT",
-    "GBPG6EPK77NYTE5ETIW2NWB6TNAPCURDTYGGZABZQOINT2TQHG42KDKE",
-    "GBPGXMNLIDTG3YYCHFAVDEMX7XW6MTUBZZOASI7ITIAYE6I2HE4NABDC",
-    "GBPHDV47FSHBTC6WHB2F6UPR5OK65YLC6BNU7MBS5Z4PEFNPD4DYBTCP",
-    "GBPHPK6F6UQMHB7GXXVAYJJHPQV5IXNVR7EO2EYI446U3QRUDYYC5ZXY",
-    "GBPHVCDS25BFEUMR6BZDQSQGW2G3C56362RQ5PH7VFL2QD7Z4DU2QETH",
-    "GBPIAFU2R2MGJIOHI6RJE3LWIE4SSIHFMWF2HO5E43EMXKOM3IYFUUOM",
-    "GBPIOMVUTQ2W5FNNG5L25CV2EBR2QNX2BWPD5PIRDKYPRZ2AQX3FE2GN",
-    "GBPIS3CHPWADUUSRWJVQDI5RAKSTWPYVWUZLOHOGK3OMRLRVL5UBIILS",
-    "GBPJG5EOZMPWX6LSBVHRQIIQFHGLB6AA5HTSTYK3WFZKJAA7YEPLSAVI",
-    "GBPKRMGD2LN2TI7DLYPBK6JCSNS4UKG4JYVK4DN4PFXSXM7HP2GCDVWY",
-    "GBPKUOWOW44SWSJFMX3KYKOLMBU3VTEBWB2EBUA4YLCGAMIFSAUCPNZO",
-    "GBPL45AAQZLXNFVGE7JJETBKJTZ5C3IDH2LAM5CAMVQUBJWMISZKGWF3",
-    "GBPLRRTPBTRUTHYYMS6PGZ3HGMRFMWCNP4XATKNFE6EPONTRJPMT5BRP",
-    "GBPLTHU4SX2D543HRRSD3RHTQSKDNIIGQROJ3TUVCQLXLWPHQLCDX5KV",
-    "GBPLTZ24NJTYC36NRUN4WOCFRJPGADVEVNOSETMMEUWUPIG2NC5JMMR5",
-    "GBPMA4YOMIQPVFWYSSEIJD5GMRZUNTMWO35444JVS5FJOM6ZV6NB3FDH",
-    "GBPMAS4TMTMNUK6NIVHDAGH2STYCM2ZVCWAOUNBDVWCRS6TUH6UKQL6F",
-    "GBPMXHBCZCKTPMD56JLVCNWJLI536NG4OFHLDQA5NOEWTYSCM5THKA5P",
-    "GBPMZ2CHX55JNM7E2ELJYPP7QCGXK5BTPDXVS4VVDOWTAFWUQVAC465S",
-    "GBPN3UDSALYLTB5WI5HJ6ERGU4TBLYDTWZWEZSI47AMVTA4DMTJ7Y3DR",
-    "GBPNKN2BT7UCARYWLAG332IMWVQTTNUVHGRSJ5D6ZQBJRZQOIRPC6G5J",
-    "GBPNKNBT62Y3ITHZUVDELEO7G24IF3IOVADNS73SMNLOBXLQ73WCWMJX",
-    "GBPNOKFUSHOTL5BOVMTAGFO4C7VTWKJ5K2E4MRYK5SDJYZE7CDVXMONG",
-    "GBPNZ7X7NJSQLB675ZPKOEY4FXRI25NBF3GFM6NNWTWRZZJH3S4RCWKI",
-    "GBPO3DRDMZI7H2XTQ625U5C75AKGCAQCUAKZ6PPCORZ2SAOGDLC7C6SI",
-    "GBPO3JZE6LEC7GWWZDJEQHFVP2S6WFM63C5HH2RD7QUM6VIF26E6AYSE",
-    "GBPOJRGECN3C75K2ZAMXYPHTETE4IO2Y5ZQGSASTO2CN5R5VOB4KGF37",
-    "GBPOKJLOF4C6YFLWVGWM7FB2NCI3A66BYY46NQPDO7XPVTRPI5X4GPZV",
-    "GBPOMKVZF6AH52QCJC624SSD5TPH5LWLVHJ4XTTJLCYLUZ5O2YTLFSGH",
-    "GBPOV2BSB6W27DISICAKOIUBENL7F2PACKROWUTIKU7N4YV4XQ4H4UQZ",
-    "GBPOWODRQTHEWCM627RCTYUZ4C24XYOQ2T435Z5NCUV4DMIX6AHOUTLF",
-    "GBPPB46FVBA4FMNDZMOLYMMGUMAZLFRDRZJJRZGUFKLRXVF2OCZTQCOM",
-    "GBPPI656LZWNS2D4QZR7J7OE5US4KZJKW3WKOWPZDETTUV54HYTAPBMW",
-    "GBPPJANORBJNMR63BECUISG47Y23PKGZEYE3CU3J3YM6MOERQBW3SELL",
-    "GBPPV2WBTEXQ4KYHUIKGFUNRZXH2TC57NM5HPUIFANLQFGV5IVOLHGEW",
-    "GBPQBPW4TMEESROAWH5UKZN2AKXZDSZESFJTXJABSWUS3SJQFYSVDAFY",
-    "GBPQBWAIK7G6BYUFNBZ24MOCYE3Q276CCZ6FKKMMSJMGDSSJRFOGGN5H",
-    "GBPQNDYKODR6NEJ64T7M2YRNAB5GQRUMP23Z3CS4HU57UMB24AVBITKX",
-    "GBPQPL3T3N335MYHFTEUMGWGXTKD3YPLNK6RYMBX3KY6AXBBTQNKP6YR",
-    "GBPQQ6BSXZFQD2NTHWIM2TKNATV5SNMGLGJ2TVM4OJQT7SWHN5ZTIQKA",
-    "GBPQXRXP77SMCIAQSEKPBFGLMF5P3Y7QX7TUKOSASV7JT7ORK5DA6WBY",
-    "GBPROWBUAWPZKFYB6NRIF7EOKAX54NMLY6CWPF5R4DFL3TUS757ZWZD7",
-    "GBPRXR6RVLUDBW4WD4WK2YI5UTLN22VOTGYQ7LFOLED3GEFLDLFCFY5W",
-    "GBPSJ2KCIJHDCCWNSF37MWJHR5HDPF546DRN6SAKD3NG6GYYT6PTBANK",
-    "GBPSLBVP2NSWYGQLVK7D44VEM377QQCPL22CDHXQXKTH7LDC77ZRTTHE",
-    "GBPT3NTALLJA3BM5RKWC6HMSOCLLOILI3CBQQQXMNIPEOJTXH5J76FMJ",
-    "GBPT4BQSOLJNOIG6Z4E5YVAUUV7OAS2EDXJMSXZTH2IRIKWXYIGAIHM4",
-    "GBPTFSPMDWLHLKAD2LGBNVL3RBUVIMQSIX4QC2KDSE3A7JUOBCS4L2PW",
-    "GBPTK7H6KF6E3XYMCEPHHNZT7DYJGOR45CZDTROOG2ABPPLG2AW5MHBO",
-    "GBPTPX5HDX7XYQ2SLJOSYB6IS2ID3CZ2IMBTZ7KN443MNJ3L7NTP2X7A",
-    "GBPTVTOJRYJ5Y2QWNQXJIJIG5JHFPEMGCIZW5INT2SXSOZRMJ6CVWCLW",
-    "GBPU6YZ6LKORDRGHPASPVNYYLO4CXSHCIT7GX5WR7UABYXSK7QDS6Q2I",
-    "GBPUBX2I3NWX7CXTPIB4TQ3UIJGITI5U724IT3UHO2Y4LJMBIAWCDISO",
-    "GBPUS7MJ6E7UFL2YT5DDYRLFYGIUVMBKWTXSUG6JP3OPA2MJGPEPQO55",
-    "GBPUSMGPMOMPVZNI5YAIK2DNFGQFICXNDNTKF7FPPHL6LDGP5OZKWH4S",
-    "GBPV4MT3JAANBW6T46GWETYUEY2FEDFVP7LONQKTTBHRGTCILFKNCLLC",
-    "GBPV4VTEY436O2S2THWDRIPZHRF2T76NYWQNOP7GBXKM7JPJX3D2UQV5",
-    "GBPVCCANEYVWAAB477AOW45BNNOEFRZJOGQFXVLLWN2ZAFV3ZXCRKLWW",
-    "GBPVFCPEQMIHF2W6NFN6RG47PAEVML5LR42GSLDQJRN4UQ3LBYJC5SD7",
-    "GBPVFXD6EVXKJJ3SKZYWXOF2ADGATQEB6BYQIVH2HYBRM7CEJEV6X6CR",
-    "GBPVSD3NVPPXK7MTICIZK5YUJPNZF4M4JGNJGUX3Z5IITL5SEU7VITOP",
-    "GBPVXC7WQVKRANYUV5POLJYAZHP5S7HNBE2TQ2LEKCJ3Q5Z3I7HLWMTL",
-    "GBPW3MQFFRSOT4JDGWC4T2K3WAPFKDWKV4EZUJ2XEKKIKVQH23PQVGZE",
-    "GBPWFAWRAAHTK3CAJOGIYLJI3YXOLAQRSAC7OOJNQ3AUTQBJBF3LMA5F",
-    "GBPWHUPFRXPTHYSY56B6QV6H36PR4YZH7LOHCRYO3AUDHSNU25YE2FI6",
-    "GBPWT2RJDZA5FGDMXJHCCNLAI3L5L6UNUHDJEO6PODB3GDAM2CHPEAME",
-    "GBPXCNDU3ZED4643PW42TSNVKSOAIH3C3CATDNP2TXVMUONZGWDV6VRH",
-    "GBPXRSXIX3SCF4HSQBYEPDVXZ6LFGJ3EQWRMINLM5SKCQQATIEXXT4Q3",
-    "GBPXW426M6UOYIPJR7UAP4SBKUGZWZXAN7AHT6R2KXHTOD2VZ57JTERM",
-    "GBPXXQCKIZ3XJRHXNWSDYTTV5LB4NTQSD6J46DT7TD2H53T2PZUPRUR6",
-    "GBPYEAMX6QYRXZEDU7IO57B7OILY5KBY23ZEPHWRTFH43LF6DA3LWBBX",
-    "GBPZCHSQVFUWBL4R6OLQUCP4KRXFZSCOA3BAMIXCYWUNP2SLQI7Z2ZFV",
-    "GBPZFOGNSGF6DHTS4DVHPQKVOHIGO377XGQ3Y2J6H4EAQKHOSUHRBOYX",
-    "GBPZGEEZWKZ2TPUM5D7QC6JKDVXLQRTKP3KLCYA2XSOHMYPCS3PDSY6F",
-    "GBPZPOCVG5DZIV2XUYRBD57GEXSOWOOX3JZ6NP5J422KABULD7QCMFDR",
-    "GBQ2M7BYIK4FPSXI3XFM67SBANSB6LYDOPO5SG72DK7DLHH5KHDISO2O",
-    "GBQ324EM6L6YAEAYRDPPC34K6NRE7PTY7VCIHBCIQXQE3V5YQEWXWGMQ",
-    "GBQ34WHXK3DMOIFE53BL46RJF4XPEFGJBUVKZYK74N6RJRGAMBS24HAT",
-    "GBQ3DLQL2IWD2IQH4TL4B7NIGX7TW75J5JHA6ORMH7NJZREEQ6SD2FVB",
-    "GBQ3EDI5CWS4M4UON66AC3HCMX3DLEF3L2BR6U2VG5IY44TDXR4DSTOQ",
-    "GBQ3NAHKO7LFCFS3VQXHGDXLB43A62PAWKNZEMYGJC4QUYE7IQ536RXU",
-    "GBQ4DO4OZJMTWWR4HNXRT6FOB6IOOAJERURPGFTDAQ5DL3Z2EHL6VCYA",
-    "GBQ543ULIIB74KWPKOCVW6GHQRRFDCU7G5JZ7LBYEGX4WXMATZB53OHM",
-    "GBQ55WDMBT6WYCQC255BSROVYK2M5EIR2H4QYDP74GEEFXHL3KO6OMAX",
-    "GBQ5GEIFMGCBQUVP3XMFELS2FUNACSHWORTIJYPXJSFGZUYIRC6LZOEB",
-    "GBQ5QWQVZI4HCN5ZT5L3UOFPI5F75Z3PTPYBH3VKPRUQZDVXWMTNUTC7",
-    "GBQ67RDGQJMJX7T4IVMDVI22SHMFKCHRKYM33IVQJOZRUUDYDK47QIMV",
-    "GBQ6INIOPMJGKG6PMSS2JNLTZZJKHS74YY5CHIKB7NJBNW762NMJ757Z",
-    "GBQ6PWBICEQAITSNPXLJHTEZHEHF4XHR6JWO7EXWYCRX4CKWLEN4UVMN",
-    "GBQ7CSFIPDQB6CYGSLRW3UZHL6WISMT4MGT7GHZGWMHNEC4EAVXWPALL",
-    "GBQ7R75ZSV67W2UVP3B27YPOF2ZJYD6HF32N7T4P4C5MIANIHU4QQFND",
-    "GBQ7SRTP7PNNSCNZFU3QMI4MJHC7CFAYITF4B2ZRLPUK7R62DJJQDP7K",
-    "GBQ7VMHBYKVYXEMRCWJKUSW4OP6EBORELPL42GXH735VHNFQVCBVF6ZU",
-    "GBQA5CHXLMFDMQUASLR2GWPFAHRYYC3K7JPWRTJ4T2GHOVAP6JNAAEVI",
-    "GBQA7GPMYAASWTR2EDZA2D3MOXVUQETOTY6FA36L7V5PHBEBDDODX2XB",
-    "GBQAFV27ZYJUMFTNMQA2PH4MGU6IUQ27KPCHV7OLWPMYYORCHN7HEPJJ",
-    "GBQAXFGS2HOTG5ZVF7XNVVYSMZCOH5AVJO6VHOKD2OYR7YPGXIWISO2O",
-    "GBQB4NTCSMBQD4ITD5ZVTJ7CG52SX5A2YAS5PWCI7LTIG56CCRZTJIND",
-    "GBQBCSODLC6VROH5TRPOIEPLNEIKRZYXJT5OUWQBWGX4JJ5MOGLPSFA6",
-    "GBQBFYL4HQIBSWX67DERAU2EEBYJO6MZBXSKT2PMTT7HD2IJFK7XXBWG",
-    "GBQBSDGRADOAOIRUGRGKXTPU7RUHETW3BOS53NAB6LKLC62BWMVGJDOG",
-    "GBQBW7X5YS43TYJR7GVNVYX23KVOYF6MQ3IWSV6RAQC54Q2YVBZP5ZHG",
-    "GBQBWKAFKOOFNR3D5TDB2FM6FWRHXCC2FYGKRUXDIQC7IJBI7NIZWX5V",
-    "GBQC5UDXQSZBDP2ETMKF36UEFAKZEQ7VZVDWDNAYMAIVGO7UUDC5ARQE",
-    "GBQCSCGCPM6KWIDZT7CCY5LYLG4HAMWOITKQKDAGZTWILGUNRD5OBB4I",
-    "GBQCSU7GVTPAAMPNQP46AXRJWUS3R3J4YF64GFMHCGVEMQGY4BOMCZB2",
-    "GBQCSZFWNGQWNXWD5GHSQFKHATASOTSAUMZGJKKT6STSHV7YRLNJHY5H",
-    "GBQCZ3O2ZAOTDULZPUK4A3GA2NT6SR3523NKJELUZAUGYKAMCC2LSCFH",
-    "GBQDA23C26SO4O554I56QYXWW7JYO4MKVDRARDJFDYIH73S56NXVR2IR",
-    "GBQDGLEMMHH5ZWIDZSE2XM6DVPIH73JBEU3M6SASRCYSOLTD4FJPDGGN",
-    "GBQDIUXKDLCBKI2JBQXH5DJKC4OZVL5XYTHMN6POHC2GANXV3MIHFKRR",
-    "GBQDPGWNWYGRI3YUAKDZHLQLLCTFXYTOGJRDNI7HZRATGYZKFBYYWX2U",
-    "GBQE2VQB7ML7UH6RE5ARU5SA3HDT3RH3OIHIBY4VFB5SLCHE4V52KKB4",
-    "GBQEX3MB2XFQJCN6CPAUGCYQCNXRGFVUTRBWM7ORXJEZXB2KPFNEWXXA",
-    "GBQEYMVVZH2NIVSCICWQZEWQ7T7XTF3AR4M23RANRBDN7GCFSEMKKIMT",
-    "GBQEZHY273RLVRAZCBRTO2NLLOZJG4EOQ5SIBS3TSGS3BY5OVSJXQD7P",
-    "GBQFNTNYOHOGRGHM4T2CCZFEPXTG5JVSEUYFAR35M4RINYHLFLVG2KFV",
-    "GBQH6DNLWOGTAXJR624SGCM25J752ZENOG2WHUOY2EHT4S7QRJ2JQ6QR",
-    "GBQHTZVXBBTKPQ7LZH2ZQ654HIEKJDVYEKQRSHPE7JVDVCWZNHGI2QQR",
-    "GBQIGITE3J6TSVY7S6MWJJOG6YXACWVHJ7ZLSAPUEU3NDKYXY45WCPAW",
-    "GBQILAR2GKJNVOCM5NUPZPGUDDVR2V5TBZJONLA4XXMTUFIUFOOMR42Z",
-    "GBQIS3C7AIJRN7FONIE7BY6ET7EG3ZGTDAPMETKCBVN5CF3AL64YKLTC",
-    "GBQIVY6GVRQNWEUX5VCRSLUX2WEFVUFAGQPF5MST53AQLHC77EYJQEBM",
-    "GBQJK2RJI4L6J4XIFNBHUA77KTSE2W2UGPYVGZXWPQG7N7R3RKJMZKGS",
-    "GBQKMAEYHOAZQQS76TAC5JAUYNE2UQAESAYOQNIUEVZ5WQWJK7QPLYBP",
-    "GBQL4O5YGPG24C47CYQDAWBTSYOEUCMC2OLUETLMDFIC2ST4LSWCRQEB",
-    "GBQL66RGYUPUY5CLKEHP6RFB4SZRK36QUTM2MC26HDNDFNWHMP2Y6GOR",
-    "GBQLF7PS6SSQEO4TAG3BRQK7B5WNEGY2GSV2RJ3V3S42MHL2VMDZYDLY",
-    "GBQMMZL4OPVGKPI34BTXG7WACJ3FADV6JYBWUS2JFTYU2XJR37JN73VG",
-    "GBQMSDL4V2FENVO6VBSMVSIRWFUWUOVHEODSTYEJLDREWCSX2UIIHZVH",
-    "GBQN7VHXWZ22K2QGEDQQHNFRHF4DZ63LPEYUWM2WL6N7HCEX5G7SWISS",
-    "GBQNAT2GP3JBDJNVHRKZ6HFSC2ADK2MGS53FNX3ISVNHXBDHDKUCDU7N",
-    "GBQNDVNAGT6YJ6PSY6MWZLFVTYKMS77YDCOGRO7XMMA3ZXNAP72HGGLQ",
-    "GBQNUA7Y6HPCMIIZGY3UKYXPQDHLJTSTXRUT2KCZRJYND6ARC33KLGN3",
-    "GBQOEIWAQUE3TGWHHJJFDQANLPQVP7YST55W337MCG3G5NI65C6ZRGAS",
-    "GBQOIGNS2ELXDGDWIW6OOJTIEYNI2FVYEPI24RDC2XKY2Y3VOZZEFI2M",
-    "GBQP64Z45EJ62JGJKTF5EH2SXSCXK7V6BZNINIPIEWGV6UQJ44HLBVQY",
-    "GBQPAX4TIOTZ2Z3KIPFKMVEWRWVZKSSSYKZQL4KDMZA23X3YIT42SDIA",
-    "GBQPXESLRFMFLA2NYW7NH446XIH6F4Z2D2A7NJ6JFKID3NMPU4PLE7KN",
-    "GBQQ2MKNO67XRNOLJZ5BYOMYRUGNM3K7GEALJVDSSU6HRCHTJCCCMP5Z",
-    "GBQQAZAFF3N6WQ55PSXIFGBJAQ545DMELS4CIGZWKPWUZBYNIX3YJXXT",
-    "GBQQCIQHJKCEXDUYYV2U2MBWZRUOGFWDH4XR2WNGKYXJ2345CXGZKZ7F",
-    "GBQQH7ROSY52ELERRYZ6HL52ZD7EJYU4GXB3BVQPMNUGEZ33536HZVWW",
-    "GBQQVIBYUKQE4QMXXR5AYIPBDQKOZQDCRRICFDBEEZK2VHCU7WJNQBNB",
-    "GBQR3MGH6JI72M23DLM6EHCXVDW3LLMVNE2C7QTNXBUSQQSKGX6524HF",
-    "GBQRA5ZFGKN6VQC6NBZTAIJLWBK5KWPWTSNWT6NQRTPSVWMHWX4ZDTF2",
-    "GBQRNQFYUMW7V7BFOZIHCAO57O6AWP7KFYHGMONMMIQEX2SFNEHVU7DC",
-    "GBQRPEASE7BINCSHICLS6JNLUG7X35P4PFVRIC2IGUVH5D3IOCBFBBIQ",
-    "GBQRZQK26Z66W5VT7BKVFTTTCRCPDN63OQOHF6FOZ5UTNNCMRJK7MA7M",
-    "GBQSHRPQQWIK3QHCPTTKKQ7Q3YUPIVUDVXSTIU54H3ORZKDTQMNBZDAV",
-    "GBQSKAEO4EDRB5GKMTXG7LMVLFFPYW7RF6K662DWF6MSNHRBNUODMS4G",
-    "GBQSOHPERDVUN5QKXGUYGMZEE3LX4SEHUDZL7RZQ7YC6W3D7NVSSFLYN",
-    "GBQSYFFIVOEDP3JNYUXMRMDZGXHYP44OSLZBPVZIK3GUTSIHIGBSVU4V",
-    "GBQTMDJG4MAKMT673NHGTXQHEL6KTUWE6VL3SBWTCMBHOJFEXCYHX2GE",
-    "GBQUB64DGZRMMXVNLNURIZQBKDNG6CUSE64DQCGNPRPHB422XHCKNL25",
-    "GBQUHCWMQVJQNE55LS5GAOZVNPIAQ2H7CYF4PLV7HUX777TYSM4LCKMC",
-    "GBQUMV7FAD5253PBDDIJAHH3TV2M3JDE4QVAKF3GH3HYVFMJAPOFIZ3P",
-    "GBQV5VATPSS2DRVOENIYET3RXZLHHVJKYOE4W24NZJ5CXGJOD5LEMXMA",
-    "GBQVJWERGZHVNLVHVV4O7MWMNJZ6JKQFILLDQJ52PE3CSTEBA5MFBRIC",
-    "GBQVQZUDR7LSS2XV7B373PZIUNKUF45DSI6J4XAHF2VC7PLSUB2FM5UF",
-    "GBQWA6DU6OXHH4AVTFCONQ76LHEWQVZAW7SFSW4PPCAI2NX4MJDZUYDW",
-    "GBQWF5IKLVJHHX6BRNVSRGJYP45443QNZQQZ5CNXXTK44JXQXFGPCFYV",
-    "GBQWIENRG4GKAUXAPK6PUI2XOJWFWLHGDZ5CXRXEKR67ZO6VQIFC4DYC",
-    "GBQWOIHNDWIMFFHGJYCNDI5Y5LJA5BM7IDCXONQFTTKEJ2WGDCONWHIP",
-    "GBQXSXQKYZ3RRCNJJ6TAG27T2HW4D5ZB4BNPNIZPFXOBLSHUO4JCFXT6",
-    "GBQZASV3AF7VJSN7CKQYXGHDFQ3C4FJZNJDE62BXI7NZLNMJENLDXVIJ",
-    "GBQZE76OTNPWWEUTYLJGLIWBOVXU3WA676VPM67IHUSKGS2HUL6U73YH",
-    "GBR25ZFFTVPS3VGUOU7AILNEDCTFTGMQ4YMU4PCJ4JJHQPP7IX563YX6",
-    "GBR2UABAUOPTNDNRBRM3XNW2CID22HFTJ3R3IGSXZ7HQQ5C344EUIPTL",
-    "GBR32HMGX4FMQQUDDJFPGGZURV4EXP356ODOTTI5ANHY6BPBTAGCBFEB",
-    "GBR3UF7WAYLR75HUV73IZXM2WRXQQ3UOTYTMFLS2XANRG54SH7SYOENJ",
-    "GBR3UWTJN4GATT6FNLTCFITEICNW5DZGHXZSZFSKR3PULIIQ2GJ7W5NG",
-    "GBR4MEH56AXGJ757RQJNT3OAY3ONJ6OKAACNE75EDKFOC34IJFWRWKWE",
-    "GBR4NZO7YNYJ2EWSAOQW3NM4C46SBQG3JQQWD7HITPY5LO7ZP4ORACCR",
-    "GBR4TLHBZNQVREMEXD57BBZ6LLO3STFROUK3ZAQSP6OCYIBMDNZPQIJC",
-    "GBR4YSVRDJKGT76LMR6IEXFVUQHVGG5NEH3PJR2CIH7DIBMOEGCWAPRM",
-    "GBR5BEKY6QTRFXPTEUNLBJXOFL6TT26WOJYW4Z2JJXTNIHSSP7I5FQOW",
-    "GBR5EGRB36AY6XAFCS32YMZ4BZDIOKU53LYXCB2MVUB5ES3ZEONGGOLD",
-    "GBR65WVTNO4VRPQOQ7KFFC24RV4NLWK7MHGHTZ7GI6D25YMLBDIIV2B2",
-    "GBR6MQMOFPCLVFTBRPZ47R2IZBGEZS255TO6T5AMAEMNJ4Z665D44WP3",
-    "GBR6WTPV4UUOUECXMKZXYE74IKM2ILVPSFIJDLG4CJB3F4Q5EW6OBMG5",
-    "GBR77U43VRDKUVCTZUCIQE27TOFXT23YRHS7JK4MW7ATLDTRFHNEMPFK",
-    "GBR7DNKGLSVGV65F7IU3PPD6QQQEUGUSQ6HNTZNDLZJOTJVA3SNQGMNY",
-    "GBR7KBJTYBRU2NDZYYN5XMX2TIGYIY57WAIXUIRYKXGQ2JTHZEGYIOTA",
-    "GBRA3FXCTDNLKJVJUOR7P6D6DJGHD7LYSYPOS77VDG3WAPNBQIZ5655Z",
-    "GBRAIN3OHCOBV23635EVWT6ZXKT6LFVZOXTPPZ7GJODKYYGTDTYYM22D",
-    "GBRALIRBYWTKYQQKYTEHYB5573PIGXNYPVD2L3A63FDAWYF5ZKUOREIT",
-    "GBRAUS35VC5YIFK4SUJ54HGYKU4KK5W55X5IGR5LQCNXV247Z2P4WDUK",
-    "GBRAWVLEHE4T5TAVXRFKOG6WAOORWNK2ICCDA3NTNEB33ITSQB2TBPB3",
-    "GBRB5654QQI7NPQI74GTDBJWHR7UPNKARHJEHXNBNDD244LBQZDIZG3H",
-    "GBRB5HTTQMXFM2CNCHX73M2MNKZ5VRZMCWMT5LHOHXXBMFF37WZIXBNB",
-    "GBRBBCLC4PEJP7SQDOQBAYPH42WIS53XF6D7IEDRM2CQQFUMB3PHVZM2",
-    "GBRBCWOKCK6LUBDHDAEGVCNCILUUWGV3RLJ4QRQWOUV7BDODZVNMP3GZ",
-    "GBRBDG6LKY3SY77BFB6JJKCETVPTLSXEY54C2SIURTOQ7MXSLPIRXJUF",
-    "GBRBJ7K6QDDPHYON55ZH2RNNOU67GK2NXIQTBO36S3XME2LCTJ3INDUS",
-    "GBRBZMQBDHAODIOB2XJWM46CXZCQ4WXS6XVZOTTXZ5XCHPGD63PPNQ2F",
-    "GBRDAW6FH5TGJRNXXCWNVKENUQYKYUEKY5H4EPLDZPXWLZQDRKLCXADS",
-    "GBRDC3ZQXGUVU7MEXVYNOKSOAC4YPOKGMQDRET337A2CKKVWVSGHQAYB",
-    "GBREA4QHRGCOCQJQB752BQADPEPWQZG3XEDFTGBKX3MMHLRCGNAUMK2W",
-    "GBREKO6RB23MMYGRIDQTN3GXRNNSJL5WCKIPTUVRNOO24DBV5W3F6BUY",
-    "GBREQVCCRDJXM7FSM74MQWJK5YC6ZRY2YZ6QIFV2UCNB2HIC6DI5L3ZZ",
-    "GBRERHKCZVJ6OIFGGHYEOX7HNRLO3BKBLNTQ4UCO272W2GVBEQCKZJXX",
-    "GBREYKDWEHR5CO57677NE5L5J2RJEWIQCIN5EPUKC6RMMPUO36S2NLSQ",
-    "GBRFV5MGJSZEL5BPNPL3F4DE4RZ5KJT4V3ARMSRBUS5SGOMINIQ7OHON",
-    "GBRGHDS3GWLIG4BZSHFLYXQKM7EXVB52VBRG6XUXHERNKH5QHTSUKYGL",
-    "GBRGHGBZA6CFR3IILCDTSOJXD54KNQUV7RCRVV5C7ORZ24DBXLJ5TNF7",
-    "GBRGHWYEB6NQ4XUEYFJ6VMC2GYKAZQ4Q3BIJYH3YHRSQYE6ZFH5IINSB",
-    "GBRGPEDSUCFBVJDQNLXR43YHRZ5L4YMPGNT4R67IIAT5IE7VG2DIOUSE",
-    "GBRGVTGPC6LELV4NUT2KHXZ5MN3TNQE2XMPKD2VES3OFKNNJZ2IWSYYD",
-    "GBRGXFQDQLW3WWBG66NMFEAYFCFHPZG2F6C72WHI5AVZRUB6EEJ7XOEX",
-    "GBRHJOZE6634REJRMRXPEWWEM4M2TQVSBR27S7XGK47HS3E6Z3MWGBMO",
-    "GBRJ3IQTM3KEF6RMSCJZN6X3DNF5DWQU52236GLKUEBRKTBWJBJKGPDK",
-    "GBRJTCIMGIPFKFUD7VWRYJKHZ5POTF6C7P355B2MBA3CQMUQU2A3HZ7X",
-    "GBRJXBKWXEBG6CUMUVN7GXHLHY5662JAO7DUE2IPCGDFWTJN7VJDALLM",
-    "GBRK27SAOEMPIS67KSAX7KATIZMEBD63A2FCRNXHZDRZYBXKW4LAWHIP",
-    "GBRKATGBPGYFIUDREKACKMUVZ26FTJDZQ5EU6L5AQHLXGBE4T4TWA72P",
-    "GBRKI7IT27P2WFLFKUFINOIGRNHWNKNETGW4YSJ7QT7SLAE7MZUVFP27",
-    "GBRKQTFKLCWV2ONFORVC23N5LE6MKII5ZZBPTUOMW2ZHLSDXAONQAGNI",
-    "GBRKT2LZGVUEXIBY6LE6XMXAX36YLP6FG2S2J5DUANJQGNG3RWRIVLJN",
-    "GBRLJVY4EYT7A3BFKZ4IPN5UOGT77FFTDTCIDYERSIBTYLGWI3EMOO7H",
-    "GBRLLDUOHPF3ZKOCYSVCXG2W7FWSJNYBTA2VKUJSTE2D55M4JDT4SPBJ",
-    "GBRLM5WFFAWTG3L653HBKBI6HF3UTP4OFFOOGDBAC3IKJVA64MXUMBYP",
-    "GBRLMLD7CX5APQCTDKSMZ25WJLP2ARJ56QOWRXY7CJA7TEDQTPS6CFC4",
-    "GBRMGZT6PKW2QAKI7JOUOH6DTBOEL7JUBO35SQJQ43B6AJZPPRLTUEXC",
-    "GBRMKPGHR2VTJMQDWLPRJVEHWBV4X2UV3RMVBTRTZADZEAC44WJFHUBS",
-    "GBRMOGJRBNZWGRABM5YQSQGSRBJWSTCGQHHENLGGNDH6LBRB2FAIYGIE",
-    "GBRNX4PVDQHYT3JE5OCRKC7JMYKQW3D4ZRU55EF37XMWG7AN4D4UOI4S",
-    "GBROC3JFZEJXIARFSGDPKMJD6EBP5PIJUKRTDBOWCNYQEYDA45TJKABM",
-    "GBROTA4Y44AKF2QPEICGTN3E332N7CHL3ANI3B3A62BG4FNM2KNASDAQ",
-    "GBROTTFQ32G3MBMOVDICOK753ATQVT2YRRSCQYXT6USBWNRTWT5HOGUT",
-    "GBRPFYI3SWNES45EFKL3VY7C6MAV5SCSOKIRMVFU5HBRGUUUTMVFZ46U",
-    "GBRPHSCOTMGKXSLNNQFD4MEE7BDCSHE2HY644YJMTHWZPKTCZBKUV6LL",
-    "GBRPSABJ4MOSJ2JFIB47MG4TFHUGTYM6YA2QVGUAD6DNVNXVYZ5SAUST",
-    "GBRPTWEZTUKYM6VJXLHXBFI23M2GSY3TCVIQSZKFQLMOJXH7VPDGKBDP",
-    "GBRRRWNLEJLTCOKJOB3JQONKGXZ4PYZD72KYS4ELQHYFURLGYXI7XTMX",
-    "GBRS5HGYYYBXHB43BQ4QTSWEUWWFWBX4J3OICKJHJANWERV44KA67T4L",
-    "GBRSTULSPA6LE2LRY6JJZTVZ72GHHV3BPCOJIROC6FM3J2OSM5U4QJUY",
-    "GBRTHSRDGNNNHISAOY4EKKCS7763I27CSH3DP2KYKBMPPFPYBKO6I7U7",
-    "GBRTQOM3ER2KRNHMQFUB4DAC4GQHUMXUEGSRF5HRV22XZ5HYKLTSWISS",
-    "GBRU6N732X7YW5FQ5ETWNIRG2ZFNW55ACH4G37NT5DBUZI3J3NBQXZ4M",
-    "GBRULJ4TQBAMOLGLRW24K3YKFGLQGZ2TPV3HKHIWRS3KLDDRX2YKAWZD",
-    "GBRW2CGUKFDYG3Q2EIPB6MPOHYW3DBHMSFMET5NB2RPODWPSO5NNZ2DA",
-    "GBRW7CG5SKEU7BZE3SPAOGDCXJ2BUCOH62OJ3BSHEOHONKPAC5TNHQIT",
-    "GBRWATNPMWU6BDGH2WY3ZUXIX5MAZ73EWH7XDRUIGVZH75HBMX3BNEXC",
-    "GBRWBDPCIPNTYVWJV3F2TDC36YI57GZA3H75UFFVGLCVWPQH4RV22RHR",
-    "GBRWDWEEEVXE272XCYKPSQRK4OHV5LDS26ON7RIN5ZNHWBDS5R6FXTRX",
-    "GBRWNBCYKXHP66KGY4QJ7ALTXC4UDTJWPK6TBPC3ZAUNXH5MJRL2KKYB",
-    "GBRWYGRTIQLFSQNWBRIDXAIIY4AHZI4EZ3YVHQEI3CGWDPFTYK4RWYO7",
-    "GBRX2YCMAY23UI2RXKWQIPROTQ4LFYQ7N77B7MOO2FRCCSTC6CDBVPTG",
-    "GBRX33RML4U7GDJRJ64SKN2Z2EKQZ3GKUWA5CSGDGZ4AFAEY452U5LUR",
-    "GBRX4QL3DMPU2CRQUCT5S33Y4J3KXUXS7LW5BAJQVJBFUTUIDGBJJL3W",
-    "GBRXQLXPS6WMN7D5Y2QCHG6GCBTCO2SA27H3UJMAKYQWMNRMMO4FOMUW",
-    "GBRXRWI7P5OEBWMKSUHQ5N6YMLJ2AZZFLMEHXASDRWIREIZOUBTKUSTN",
-    "GBRY5PQDBE3OSDPLVDDA4NBTGXEGXOTASF3IXPVGVSNPZLVBV4MMJZED",
-    "GBRYBTWWT6AZALRG7OJHKEFYJZ2PJWPRSVE2K5Y2PGR3TRWMXZ2PKGAB",
-    "GBRYENC3YVIZGWURUB5I4WZWA2HVR4NEU76U7FEVVW2IAJWPERXNOFRY",
-    "GBRYJ4ANDSISDODEQPWAQGI4PR3KUGYSKA62TGZBVTKTAVIXZOBTLXOY",
-    "GBRYUM4VAR3ZVR5UJREOEX5NQJRUD7CJD5EK7GWVFYI4FBD4JPZJ4EBK",
-    "GBRZ4OEBVTDVOQJINJLL6GGF56ZFTKRRDQVJ46N3UZ74CA2PSH5FESA6",
-    "GBRZ7Z5NIIXTTIKW62O22DJLONAHL7GULHL62ZA2JOV34RQMNZLH762N",
-    "GBRZHMHHS2NPYNIOZ5W5QDPE5OSGMCNXOEI2E2SQ2XFPPAZSI6CKWBTC",
-    "GBRZTSZZ5DIS4WY5MHSUCG6ZELA6DEILSTF75WQEDBZBN3NH32SN5ZGP",
-    "GBRZZQB7XUBP3FAFBWSCRFSSPHQRHQRHUVKQHYGESBNDESDB7ZWM4NNV",
-    "GBS25VJ2V5LE37WQJGC2MQYBKNTL54I32DEVMPGJJPKARZZGPY46CLSL",
-    "GBS2J3EZKO6MQ3PKPI6AUNYQZ54MLLN2MRN5J46AKN7T7LZ6Q6WPNDUS",
-    "GBS2QWKX75IOBRMWPJSRM2GOSCHEXESNSOYLMOMNCV3CL4SOH5EAXEMI",
-    "GBS2UZYPM5T7NZNATBEUUH6WPVI6BTGASWZQPIFIMT5W7JPW3TGKSWF3",
-    "GBS3MGSLCQ7EE73OGHLHXFNLTGTMTTKFVOSNEWDZLUFMMDOUV4IA4ZJC",
-    "GBS3TCDSAQC52OOYOZOVNWFSMGBMDMNI6KT5QMBSRU6KB73CC6D5EUSW",
-    "GBS3WVCGEPWBQRQKOV4W6WOT3UAXQWJF334Y44Q6F4EZHKBOQWJPPVM2",
-    "GBS3YAKY774ZWQKRRFJLMS6HWSAYHF67XN4HTOFW3IBUZACU4Y6PREGR",
-    "GBS4KB554ILRS2J5ROUBNSRWZ3WBPUO3PK32HLQ7JIUOWORTYK42JYMQ",
-    "GBS4NM3GIJH3GWV23QUSFCHCKHCUQRM6IIFDS7BUHA3VJUP4XAW4DC3Z",
-    "GBS4QDKN6NU5EYDSDVJVMQUDWINE72CURZLMWO24NTNBJ46DO5RYFQTR",
-    "GBS525Y3U5EEYFXDMIK6EPAM67Z25AE25HLMS2FONTLRGHEOQ57SKFGE",
-    "GBS547WTDRGUA3GEKZEIDFQA47MULVRUAHTESQJKNFL7J7MICNPLMTZR",
-    "GBS55FEY3LLOKD3AQ4SD6SFX443BCOI5HLIM4QLGRZGCOPIU6G3HKDBQ",
-    "GBS5CZ6M4YNMMYXPWVQ6NRKBYPGOXYQZACG5PBV7NV6DEH6ONRYY3SFC",
-    "GBS5NC3X74AXW2OJBSZ7YEWZJUHVKQYIYFTUL3B7XO5VWLIJB6OUFOVZ",
-    "GBS65BEYUUOV3X7AQWPZJGAXZJHO2FKVNNH3H6MOF3CXRYS3H2R5BAVX",
-    "GBS6EF4O42KXTCDZDAIHRYAKAIW4NG7URP5GYV7JWQMRTRZER46DOIXP",
-    "GBS6KKRFUE252LEPGLQ35AFMEUBZRXNHWQNWXIUI5M3YIZAECV35WUUQ",
-    "GBS7FN7PCC6BNGXGR67HZHL3IV6G7VKXJCJCBCM66BPJFOJVXYJ5PVDN",
-    "GBS7W4TJGRJHF6WMRARUWJOVZJNE66TPPUVUPBHWO63JSQLGOPLFMHNT",
-    "GBSA2O2GPVBSNCO2YG2XDB3BME7R4CWQVSRKSTOPYGN4TVWH5FUFHD2Y",
-    "GBSA4V42G32WYYRSPIKFDAPPSA5IR4L3ETHUWPILBQREXEZXWBAVKRNG",
-    "GBSAGFMIEESVVK3L25327SUOADBM457FDX4GPZT7WZ6CGIRKC3TYQ6PZ",
-    "GBSAISBOUHQ5N6YHEPDKIZZVAC2M7PZO5QNZWF4H4ML7PIHFCJ7KVHBP",
-    "GBSAJITWFLUSRHHZPHC4N6N5WFWDVFBF27EA6T2RWZQ5E7BYQKHJ2FLR",
-    "GBSC7CMD6QI3CGR5GQKKD3VN5XXFA7JET4KNUKMTO5IRRJSN5TFCRI2O",
-    "GBSDCC3NR2P6KFWITIO3LHVWF7MMIMEAREKE4PX7XAB63I65NLKTIEYD",
-    "GBSE25HVZTLP4GWJUEZDRE43LBZW5VSNLMZGU6BHDYJOOSXPNV5GBSNF",
-    "GBSEGBB577LJDU54IXYQHIYENQQOWWQKAZOJQFLKXFIWSDRSUNGAQN5Z",
-    "GBSEL2NOCUIKCRWPMGOOF5YP2ADM5YGJ4OYCKWASXC4QU7IGETTFL2FT",
-    "GBSERV3X5LAPJLGFUWFQZJW46YWXJNPWFBUISW526PVQYK4IRPL5ZLPM",
-    "GBSEWUBM2JHAFM4MIKPJWGBTOFQVAHALVWT4RHJWZJA54P4TUXNSTOCK",
-    "GBSFLPORDE6WWSE6S57EWWUEJZY2KQHF42RRXJJBXHVPUGDMUXLC4NQD",
-    "GBSFQCZ7MQ6I45SERV7HSZOH25S5II4CQEU4HDNYT2YCMUIOGUO2G5FR",
-    "GBSFV3ZL5WV7LXWZXCKHN25QKL6UQJTWACNZJRR3XUYD57ICMT4OM6O2",
-    "GBSFW3OLBJI6SC2AWHIFK2D55BH35EPC4KXJGMTEO32FSBAY37JAET46",
-    "GBSGJYFBPUW2DWPRQNAARMP5TJKESJJC7ICAKEGRKKLKNDNE6D53H5CR",
-    "GBSGKJRLKRFCY4KJG5Y6IMZSSAQPC5L5IQCII6EZ2PINXDPJJQTMGEUX",
-    "GBSH4J7PUPILCKMIMSL7GWFYBBMEGRRLLATXIGK7TLVPBVBDF3J3XESU",
-    "GBSHUFG74MQQH5S2ROICAW7ICG23YD4LM4QN3RHUG3JSI56VNSRHKBLK",
-    "GBSHVBIMSUNDZMDGW4SJJELRDTXH3K5NFNED24C3NKVTGKJ5TBRSE2KJ",
-    "GBSI7H2WCKA6F4WY7XFIBGBXEY7UOP3P4DX4ZXCBZDSFZPMMTYEHWBTC",
-    "GBSI7UZVNUNIFOV6UZHJRBVGKFA352GMZGWLAJ4FBTWZOJD4ZGELHIRS",
-    "GBSJESZCH4AGFNXTZMX3JREZA4MEU5VS7XRZEGU3ELJOIC4AU7HYIUHC",
-    "GBSJK5YW3TAIREYNN7GT62IZ5KT4KOJ7NB3H6D252VPE3VCZRXQDUYWP",
-    "GBSJTPLIBY4CKDQEWNTIQ2E7VLH7XWZ5W3ZBTFKTBETKRSPDXWDOIMOC",
-    "GBSJZSCBDMIPTWSU3R4MOC2IH5ATBDCQCWEEA2FTBUHVTKT66AVPG6VX",
-    "GBSK6FCKS36SAPSSX2JZ5D5QGGTBLIG5M3RGUUFSL6KVLE34SINENTCX",
-    "GBSKBCOJVRXSQQWR2XEP74KLETDQP6G572TU5JPRZ46IV5S25NTQCQ5O",
-    "GBSKGZD5FXSVBR7GADLJD3IFHAQUY2J4RUC7H3TFSSRFVBKJKKCPCGIJ",
-    "GBSKRSPT24IAQ3FOCHUZLYCT2D7MRI63XFJYMQMLWZIAKQI6ZPSHOXWC",
-    "GBSKSCYHNQTCSH4FGLNF7GBVHQVUQ7TZYXRE5F34U4MPYETGYLICKMQK",
-    "GBSL2YEKO2IWXAQ7QDFIS27EKZ4K3OWHPU2PMTAD2YRDUZGZJD6GAEKX",
-    "GBSLBPDHXHLNDEMD6XN4A2FWL7GD2OTRSXLECPXA3VUJNJEKGOXEMYHA",
-    "GBSLHEEW4DN2N5Y4I2OFQWYA2IV3FPWRMB5XRFOXP7ZLGIBF7PCUJPES",
-    "GBSLO3WV74AG64U5VJO6COZAB6KJEBOXOP3ENN5FLGNHGVIM72LQAD5A",
-    "GBSLONAWHDPXS7JKGO2MVKVNYSZR6JXDME7MQ5XEE4WSI2RQNHH545IS",
-    "GBSLR5UK76XYDPWB5G6KOJSKGJ4OUWDG4OYK2FG76CNPIZZRJLRW24TZ",
-    "GBSLRBZ3XY235SPWNV4MZ3AL5GVJIMSQ2B243ET7Z2DLFIBHTVNQKOMS",
-    "GBSLVQKPXP4WGWQJWBRVHNJ7MRIU5N7MJVHELLYSS5WXGUM5AU4BYPOG",
-    "GBSLXXPESB22SCJXWR7EDRYC5VJUGCO3URDXV3KJDFI2UHT75ELN3YRZ",
-    "GBSM64NSYGJYC4JHWC2SPL6L3OQ3IURS4NKUK7MOJAVGMNWRMUN76WHQ",
-    "GBSMMRJCSFTCX42Y2FJF7VESUXVG55KHZSJNFZNMYUAG3U3MR37XGLEN",
-    "GBSMNNHCXPSO6E7JTPO7T5EUEQPHRDSCYKVUYSVBKPNB5JUQVPTECC2B",
-    "GBSN3VB73HTHEQJ5YHVZGJ2A5AKRUVOMPFATKDHK6AMEB5OFFQUUEBZ4",
-    "GBSNHP2KCGZ2HSB6IOFQVS5RZ4V2YGVQLWMXH7NHJNWSOKAEMSHTGZYS",
-    "GBSNMVWEK37NDGSGERYPFBA4SU7G4J6WIF77ZRDHOAYNLA25NL4MWIYQ",
-    "GBSNP3LWBQS32KMITKEAMFITFKCY5EDY2R4A5WYAETGDMEPQWBRTLXZR",
-    "GBSNSTIVYK7H4XHIFXX6T6WMRJZEAU6DV2VZER3NAFZILQFCNA3L2FVZ",
-    "GBSOCG5R7XROXML66ODM4YNBIEZCN77MFLNAWC4CM2J7USP2BPME7MAV",
-    "GBSOXHDZ3SC7YZBEX7KJNGP6LH677YI3FVJESGLG7GTH4SSCH33RPUZU",
-    "GBSPIAFK2WUUWW4SXJCQPSFBOMRC2JZFRCGV442JKEDA57G67GKUBT6D",
-    "GBSQ2TKKWP7NVRTM7MUOENPZ63YNW3WBTZH5KSAD6AXORGEWLR2F2BEL",
-    "GBSQ3SMI64WBIRCFUQELWHI5WZVC5SZMYNISDIUWBUOXDGRODR5V3222",
-    "GBSQ3TNSWWJZ2HUVGJXXLV7U5XRK6PQRIIPNAPSXYP3OQL2VVVZ6RPPW",
-    "GBSQ3UILV3N7XBWWOYJTI6NC2T6L72RFXTTK3HJ75VMRUA2EMAYE4XPN",
-    "GBSQ6ZPN6EBYUPNYPW6UG46VMHGNYEKWGIN7X22DGM54BCYCFRROE2YU",
-    "GBSQVKG3KJVHX6MVMG5XUUBYLQAXDIAQFB2TX4HEOU6XVOTHBGZUKXRP",
-    "GBSSEIWEBVZDETLHV55Q3HY66FMT6VHWRMUTH4B6O25VFXXX4VIRO7QJ",
-    "GBSSFSRNES6B4YO4SOOZ3OC2JYJSKVGU5Q4I643FOFNCMIZBWETDM4QB",
-    "GBSSM52OIFUYBQ637RSZMCMCEIP3NJOBTKJE3SBUAPVYQQCNVLOZMRBA",
-    "GBSSO6TK6YXWZNUXUJ5KZ2LK6I25EKMYPDOJ6ZPWWL5LTYIPPIY63YMT",
-    "GBSSWHAVXC7T7D4KBPLLII46BSW4PBJO3KLDDSV2TRANAXY56QECXURS",
-    "GBSTFS4KGIPMQCJDHDQGNP22BTTHWO3PQ2IDFXUY5A7EDSWNMFD7WHIP",
-    "GBSTRH4QOTWNSVA6E4HFERETX4ZLSR3CIUBLK7AXYII277PFJC4BBYOG",
-    "GBSTZVNED6YCJJ2S7ESOJV2U54DQTHBTKZNEZTYTAPRJPJODUMQKLJOH",
-    "GBSU5PUUQ5C4DYMFKNLWAD65WEJDWLU72FXPDAGNC64SJZNBICUFW62Z",
-    "GBSUGKGMQF43J4HGTLZWNFFSLIS2WX65F7T2YIGLMO2JQTA2DJNNLG57",
-    "GBSUKA4XOD4DYVSFLMCAFZ3CGIBU5MCPJLH76KAWLLGYYWKCJYYCWZZD",
-    "GBSUMZISWUQ5K3JGYWYACHQMV5PTQR6PEMPSYFYCGDJQOPKUZQAWKL3K",
-    "GBSVSKYRBDA3HQG7XWORYXCT375JBDHS6WLT5R5GPGWN4ZUXHZN7H5BD",
-    "GBSVUMHI3DZH66POCLPLN5SRT3PXEDVBQEVZ7NVQUFWDNCBA5Z25TBZR",
-    "GBSW46STNMGSK3UUTPCZDO7ESXP2DRYQQ5OG3Z4ENKTGARBYWMH2OUUQ",
-    "GBSWQFQ442LCJD666FJJ2H6VNGRKIEIP5GEGRSJUZYLMVB35QYVRDDEX",
-    "GBSWUTLWYTCEVVZDQAZB6BYLVAFSDCKGNXW476BE3F5KJ44KIFM6EF4L",
-    "GBSXBUGKYQP3TTZVOKRPYSPLXCCU3SBIN7VSLZTR6WKOOEWMD2IJH3KO",
-    "GBSYCADDJMQINEYQBAD6GJZS7VX7AQUT4POEFHQNOLKJZMCHOREZHHPP",
-    "GBSYV6GBSTZPWEMA4YQYXGZ35M63ENLZTOSQSCSIKTYUKB3HO25NXEGX",
-    "GBSZ3STSFZLPTUTZH4M4QDNIXKYTLNH2CWVG5TT2O6F6KROZZD6SJVMP",
-    "GBSZJANV7BTDP22FB62SUVTN3JW6GQUSMLNKSA76FYNSW3WIDT2RYJ32",
-    "GBSZVS2CHBWZVO232NAAGRJ7Z6KR2ORITZ4JWKRUB5Z2QAE5S557QKVO",
-    "GBT2DJVZ6RRQURQ3QSL6NYFQHJRM4OO2OKKCC3FGQLFW22LYKXIPKQ5H",
-    "GBT2MREK5NYH3HEM3C26EDXBJSJIMFWQYQHJPGJGJWVI76JI66TMUTIZ",
-    "GBT2TGUSL43FGZSHFNP26J52NFCFRQXWC27GDHVENJUBLFDIUX6VM32Z",
-    "GBT3BHHFVIYVBHODFU2JISIXBHIN4QKWRYP7I2O7JLFVPXPCINCVHWJO",
-    "GBT3C754MCY7F27SZUJMWCLYKDFG74N6MZNRW2RX2JMGNYNDX2XKU4TP",
-    "GBT3LCLHJ3F6UERUZJKLJ3VXPTOYTHU4RDA7HF2HVOPSYSS7HHOHLBCH",
-    "GBT3UX2W7XDSLRWHGMRDONJKFM5CDZF7UG2FLC4R7CT2XJYOCP7E3IZO",
-    "GBT4E4RV7NVW6OBVT5OIIXA7XNXSOVOLG52QUEWJPCJ5YLGKL4NNZKNB",
-    "GBT4MGEIF6PTI53M5SKK7QPE4KTJ6C2FNHN3GUNJYNK4667JCYO5U2MA",
-    "GBT4TPCGYO5WO4KAFA3CFACPXO6MBVPG55KPMEOOPG4I6RXPXD5IWJQU",
-    "GBT5UPFISSRKJ3HLNURISPGEXSA2PQTLDW5L6LJNKU553NHM3ITDMLS7",
-    "GBT65QHPDBANKE5KWZDPOYIXHB3DBHDPAG4JSB5R4FVWLUWZIDGAU475",
-    "GBT6AJ45MB3YJET2J3IW74FY7BKM2H33ZR7MTFDZM7IFX6LDLJ4Z6F2F",
-    "GBT6LXCCIHHUJE234PMDPKXGQLXL5D4CDVF6KXHSX4P4YNRUVSRR3TDO",
-    "GBT6OUDIY6DS325XHZJNDMLVZERF6YHJZIGPTDGJNXIYJ3V5ZT3IBZRX",
-    "GBT6PKKXPFV6GPHM3QPEFYJP5EH3M56PIBKT43BYAPWJYLXIZ5IPI55T",
-    "GBT6TQCSCRV5IFCVYFGEIBKKIHV3NQSP3FSG7XS37LFR57Q5DPQNS6YO",
-    "GBT6XQYAF4PXX4C7DM22J3C42EHQ5XTZRT3IZJZS6ZIA2J5PLLVL33EZ",
-    "GBT6XT75TYI3NRE2DVLNBWKSFFYBTSLNPX7RX2HUYUMB6XPN3OB6BBOX",
-    "GBT7A3BQHLJE3QKEDCFYQI4LGMVVDGTTTKAUGY2OQAKVJ4EBSUEOSPGO",
-    "GBT7CNJDTNXEWMIC5EJ2DLR6W2S6ECMOLO27S4LNIMG4MV2G2HGGL6KH",
-    "GBT7G5T7A2EK3MK6BFHJZLAXB6F4MJEBF4KHU5O2V2IW26D3SZY4QPFO",
-    "GBT7N4D34HJWSDZ6BCNVJI2SFT3JUAUAS2KFIWC4LXBUGGSXXBHBRB3O",
-    "GBT7RPGON5A4NIKPNNHO5J2ZRR6QXSP3D43IOHXANAOBI622USYJT37V",
-    "GBTAFEFZQQFPECLWLM24NG5DMZWGZCHDDV4Y7SD7DWLB5UQ3E3335XA3",
-    "GBTAT7G4E3BCJQXQQCXQXPDQ2OTPW2P6YXWDEAINWQTVPDFHP7WDQ26G",
-    "GBTAU3SAGX57AGZ375JXMACMMMZSAXIO3CGR3XVZL6BAO2YAI6W3AYUA",
-    "GBTB4GHTUQAYUZJ7YLMMOFYUQHZ2Y43563DDS7T2KKO6EZKQF3SGT7OA",
-    "GBTBEKBDLNWDHAN37IO42BSGW6IF6JJHJRKNSH3TMWMF3TCOIA7U7SBA",
-    "GBTBLWU2RMZL5FR5MVVKIRY3MQ6L7NDSZAZ4ZK3T4FZ2IY74JAK3HGZ5",
-    "GBTBRNDD5B4HDFDUQILOMMLWIXUODNJPO2ZULP4YULPGFC47OP6R7TSS",
-    "GBTBVILDGCOIK26EPEHYCMKM7J5MTQ4FD5DO37GVTTBP45TVGRAROQHP",
-    "GBTC5624NJAOISYHSR5FYATYO7ZIOONO3CLKXVL23CHOYHBWINKMOIYW",
-    "GBTCBCWLE6YVTR5Y5RRZC36Z37OH22G773HECWEIZTZJSN4WTG3CSOES",
-    "GBTCEXU7UJSSBKLUWNI3S5IDRBF433O6REPWB7MYUYGJIIQOKVESYMNC",
-    "GBTD25CZHRTCIZDZD7QSEKGNH7IEHNPNFGH43VODYKU2ZAY6MRJRBSAH",
-    "GBTD5MEO7XT2B4XH7JF5A7ATF266RTIFR5OQOAH4JEON66EKSPWUTVBB",
-    "GBTDHSUGZM6CM6GEFFHJX4QO5MPVPWZ3KRSTZXPO2I5AKDKJEZK6GRAM",
-    "GBTDXVL54QJ5ISIDADUXJZ4LTH3TVKUJCOHPDESY47GNMUCIEPGJQXRP",
-    "GBTE3LLFWJTA333VAJLXG4UM54XHOP4I63U4CKVQV3VCA7KHUU35PJHE",
-    "GBTEMP5LMIWB7ARYLUGNI5UQWGQ655JGBRQYHZ5OG5DNWYTQMIYHJNRU",
-    "GBTERGN2BFKFHUOZMHS7A2CAFHZPRETAFRFAINERM32IGIS2SG7DMBCQ",
-    "GBTFJL7TIQMCZSDYSKSQ66YMIKUKJMTBYNXE5XMZHGWKN3DROD32OJIH",
-    "GBTFLM274BOC3NGFE47RHYDOCALBNJPC4YEMRUI5EUYZRT5WWY6FQ5EM",
-    "GBTFVMRGVM2ZX7JYSKTUGNNFGM2J3PZKK2S7EUKT6SPASFSJT6ZXW5TK",
-    "GBTG2N3JOWE66EHSFVBVKOVYJ3KIJS3YCLBUTRYRZDCVAHVZIA3NUY2K",
-    "GBTG4VCKTMXVQK7GXOSEIQWXU4WG4TULIG6AX2QDTHS4N7WRZBMGX7OK",
-    "GBTG7ILEU2RYJ3B27573QHP7PZ7Z33FGTE634JFJNI7PBU2UPU2ALILQ",
-    "GBTGPEF644VXHTUFRLYVMXHDRQAYIWZR2HWZY2H5GL6BSI7WHCWECATS",
-    "GBTGQQGUMLBT44JBTZKRO4NHXDG4BA2YYPQ6R6ORUEEYMFGGXKEQWFSP",
-    "GBTGQTQMWF64STBU5XPB6MXMYT35267CIMYBAFYCNPAT42MKVPSSWISS",
-    "GBTGS7OFHISVVDR2QYIO2VDT7CMVFDZYN34JPKQFS3QW3EESFVUBWLSR",
-    "GBTHRZFDPBP7ZBQFE2F2UV7TGZFC7YDLTT7Q5TCSBTG3BWSSL6DT6PGW",
-    "GBTHSOATUFSSX2FO4WKH2HITWP47QKY4PQ2Y4NOEJ4MWWKBECBDO6XLM",
-    "GBTHTMEBV4IFC4QV4QMWON532JP5RL6CVLCUOBE2BWGU6TKBWFZMQAOX",
-    "GBTHWY6HVAG2FBO6BE7FUBSB46CAACKKYUOLRJOO376JW2PMLWQDNZKZ",
-    "GBTITD7SGUS6AAWH6PMPYIVXETBA7DGOTKAVCCDRN2HMABDUGNNIFNGX",
-    "GBTIVNDNLY67457WX3K5WRQMNQF5RAS6FJOSTACP4JZSGLKWEOQN5XRP",
-    "GBTJENWXLBUFFETTSTHT4T5OOTPKKIBQR6MEUHUQYYLZD6OXJLAIF7BN",
-    "GBTJOOD3OYQWCNCPGIPPPYU6U5LMLOJH4S77CHTOHWJ4IZYV4YZHOFZN",
-    "GBTJXP6IZCCYYVYSRN3PENL57FHL6EEKHMPC47A6GF2BZRCOYKIGMRYM",
-    "GBTKNJG2RLCZVKYMOMLPJ42QKG6HZDURF5JGQDWADYNEDARESUUG5P7O",
-    "GBTKP463FOYJC6N72M6WNJLRPGZJDXKGCY7X34OCH6GMC6QRQFW64GWJ",
-    "GBTKVBR7TVZ2WYIJVZVDPZLNZJJUCEOIMTAORAGS6VVF2QWTDPJ6MFRX",
-    "GBTMAMFJYFWY2JWBEK2S5R5BHHARXFJGPC77OSNQJYHLGR6GQMOSV3O7",
-    "GBTMCKAVADXZCNKQQJLK23RKIAJU4HJSNSI4JSETF54U2SVFLN3ONBQR",
-    "GBTMFSIU2MBVFOPBIAT2I6WDRX4W4F4WTQ2KQVCFXPFMZYVMGN4MWJOM",
-    "GBTML6SULIEAIXJNMNFHQTWBHBVTLSC4JLO7TV65UN5JG6D37YY6QXDC",
-    "GBTN7INEGZPQPWHQPZNSV2AH7MZR2QUX5GAFKWHQSQ4364CHHEZJP67Z",
-    "GBTNIJJHJ2U4FFKIIXUSIOMDDD2FFB7LGAKRLB2TIL7JPCKNQYB4OCAT",
-    "GBTNNFCPIJNJ4ZLYVQBQ3EQEXRKRB6KXS3BU26AD4UPUJA4NFLTK6VYF",
-    "GBTO2LMAL2AMEADDT7FQAI73SU2LOOOHI5G6CD3D5BNUIAYDAAET7FJB",
-    "GBTOIVTNOZLP2WX7WL5WDEZ46UAOT5QBDNP3JYN3TATC2EMIHDR5YAIZ",
-    "GBTOKUIJBLQVTUS44JOUEXHSSRGMA5ZNF4BEBP3FL65JGDVDZR4SBRKB",
-    "GBTOOACVLQ5IZIKD25SZJ4566GOPXXFWA7D2IJRLW4GF3RCUZ45RMXMQ",
-    "GBTOODNVVFKMGD4J4KL74RNBV43IKGIM7EYTDLMSOIEGHDGBTPUAAW3N",
-    "GBTPFGGG6FPNHGPCF4SNSFANQK2545ZIMT4AYJCJFOXCA7PVDWUOCOTW",
-    "GBTPLXECWZOMNFQ4TG2FJXXQECAKK3JKYWQ53LVFBXBM3PL3T6OQWPM4",
-    "GBTPNG7B7AU7KQDORUJYEDN7AVUAWWN6NP5JJEVCMVLWBVGIJLEYWETH",
-    "GBTS7FVF64L2HXMC4XID72TH55WBNFIXBXVGCKXMHVHKXZS2YDAAC65D",
-    "GBTSABGZJGMGTQ7SP43K5WZ2S42U7EY7NQYWG5JPVKY5IAHYOJRVXC23",
-    "GBTSXWE47ZBD5CE3SK77W2DPRYPRTEFXIJFNNIMAUTIE4BOXI2B6FOJD",
-    "GBTTEYZKLZYIGL2NZJQRFDZGKCNCJNXXURYQ3QFPFKKZDTT27OYDACID",
-    "GBTTGC3ASGKKS3TNZNPWQ7LNYK4UYSMFK7VJOO3UVQY4D5YTVL6VXV3D",
-    "GBTTO4X6FBUX5ICGVICPXLMZO7NQWWPQDLTW2J35PPEPBJ5OSNKAGVYZ",
-    "GBTTUSDEN3BVLKXF67LYQUDIUQBOTL5MZSJA4MKJD3VUJOSA7ABP2AIQ",
-    "GBTU7KWNJOBZD7FBEJNSY5W5ZRHZH3FLMWYSDBWUWKSXOR5F72BOVDPA",
-    "GBTU7QZP6MLCPXOEKENRDGBNHNYNP2SZYQX37VPKQJBBLHXDVTSCLR7L",
-    "GBTV6MF4WBKOUFUURYOPWFVUBWGB6CIYLEDACCNADGR77RNKM2DHSAND",
-    "GBTVKXQRDRWUKBNMUHQBFCFCXSAYTAXGT5B6XVSUQG6YVX6JEB6B4IPZ",
-    "GBTWA5TDGD2OSTONXER6PTR2AIY7ZG5XPZMC67HRUZ5HWFGIIA5VSY5C",
-    "GBTWFIJ4YGDVJEFI4HGKQPBZ2EC5RXOXP2ATQJ2TDEZ62DMN4VP7TATE",
-    "GBTXKT2DUE2WUXWIVSSVLVVE3XU2DD2VFTTEFZRLY4AATG3KIXO7YSO4",
-    "GBTXYWHYY5JRJYEX7T4NPPIVQZXLILU4V5PZ3BXWZCDWLGEXTGBYKZCP",
-    "GBTYDGMUMQD6PO2RQ2PXSKO3HTS425WBYLYXXUHMZ7LJESVIWEOVIIMF",
-    "GBTZ265DTYEU7RWE5YIFDXX25PO2VWLN32R4DWLKCKBTS6WKR66TKMA6",
-    "GBTZGXU3XH2SI3DKJ4CFDK5M7GXFJS2S4IVB5IEU2X53TMZF6FEEUNIO",
-    "GBTZPNX2OJFF7IL7VQ7UFXD4DSOLV2KINJ7MD7DVWALGY3YTOS4BTM2N",
-    "GBU2EPM6HINM7NVH2CL6YIYFFE5Y2WE4S7HYW7OF3PSFJD4425NTBVV6",
-    "GBU2WVIPSPRETNGXUMVMH3T2TKRL6I5IYYTPE2XGLDGA6DWV5NY2LZLB",
-    "GBU33MLZWOI66FKQLMNXU2XICQZNEOV6LEQ7UERY244ZKG43NJLRZYJL",
-    "GBU3PQKFMINMBGY6NE6MSSR6GYBQEHVQKEJAUN6VT7O3UXKBELLG5G4Y",
-    "GBU447UOZU6GMEZXCCBRYGRFM6A23GDQWLGUL7BF3P7KDETRGVVF6XDV",
-    "GBU4DRONKPOZHIYIFCRVQLRX5KD7TYQGH2PHKWVU3Y2WPC6KZIPN4GWI",
-    "GBU4HMIRBJWHT3AZRZETSSDBCQZDJMCQ4BQG6LPPRJX2KIRCIINA5LDW",
-    "GBU4MCJQBRY2QNJWHLNNPVW77DFUIZYGMWTIMEPSFZGGQPTDK2IKSS3K",
-    "GBU4VUISWVAU3SZRFYDVK663K5OL7WQIAXB6GOTJY6NUBNW2Z75ZOBPE",
-    "GBU5BJEK53RXLO4NVGUBU5DWSR24HT3CYFLTHOXRFSNCJAQTLIQUFNZ6",
-    "GBU6GQ2VUPGV5NP6FB6QTJ4K3GQJD2U667XWR5BKAL6LPS54UBKBHB3X",
-    "GBU6H3CAMASMULGK4BENR5P7MC5T2N5PL732KBZHW4LX362SPP2MJH7G",
-    "GBU7DBGO62HUP6KWFJY6NDK2SU6A5R56UCQ3H6YV3V5TXDTIRFENB6NE",
-    "GBU7NEQ2SEXC6FHCEEDXHGNSS4AT6VRZOODPV2GHZM5XJL5XPKEYIXLM",
-    "GBU7ZIXCRYXTX3S4KQB4LLRLCHKIZNS4EL6SUSXQ66ENP4ZZWX4FW5AS",
-    "GBUA5V4H427KOTM5KMDK63QBEN3KAVXSF7ZFTHSAK6AHDCQKNWJEZ2JG",
-    "GBUAQ7RSTC4IPYRQMA7DNOPT25567USC3BHPU4FV32UCVTNUVE73RJDO",
-    "GBUASP5CKT5HGYWAWEDFWXJ37LW3LXFESNLTTEPEWROINEZPOEAEASRU",
-    "GBUASUO3I65MYFCYZV3YCJIM67UVWLXBEIS4TCVM5ECGSICABH4CDZFX",
-    "GBUAVXCI7JKH6D2ZHZ6KZ7Z5MHHCXHI4DSUUC4KOISDMRPVY3MRBF3OY",
-    "GBUB6KRFNO4ZWTTNDQMB6E5RBB2PBB75IWRWNQHPLTLVQFBSRL2UC5YR",
-    "GBUBBWQDIW7KPE3IS5NCCZPVDJNI5JOJEKH3R3KR26N5XVLMKRHHW6CJ",
-    "GBUCQ776K7ZQ45754QJJLFAYXPSET54A254FIAUHF44M5HWCZS6YHJXQ",
-    "GBUD3Z5RA26TN5KPAB4PCHLFY25YH4OW6PD5EZOPYGIE4UHVBDFPBIQV",
-    "GBUDHZE674RVSH54H46H47QQ26IHCTXPXKXIHDHOYLBGARXHUMS3COIN",
-    "GBUDIPMYUCA6BBVKVCVVSUPTMED6YFVI2UMSCRGQLH3QQQYJV6LSJGTI",
-    "GBUDJABFTAZ6M5TARVIIYFPIS3ME5ATNPSINSZIY36V2MHRS6NMU7J2K",
-    "GBUDJISAQ2WOWNE3U7V57OTE2H46LOTBIRQIH6X2YIJH25DYN6APGARN",
-    "GBUDRTE4GJC62BCOB5RXJ65RL7KLLPGSCHGVHSJFKVJ4N2K6HD7EY6XV",
-    "GBUDUVGV63VS4Q5KBIAAOEVPP2N2ATY5V6C5ZIBIJLHIBFI5OL3EA5Y2",
-    "GBUE2JM74UUDWRFLA3RTTAWUWI2RYANYH36Q7ARWZUHML6WWMEGURIQX",
-    "GBUEG7PY6OJ5OFHH2ALOSFIESC4RKY5PW5B2QBNLU3LFWY5JPB4EATFE",
-    "GBUEHPPHDQKPZ63YMDTAVQCNYU2XEX2Q4JGBBOM27PWK64P7OM2OQU5D",
-    "GBUEMCPY3WVVIDZEV2JNBT5OT2M7ZYEKCIMWG422DLLSTYWBAUHWNNMC",
-    "GBUEZASVPMMORBCKHQVA4YBC7IL45PHHKS7JSSZWJNCM667Y5R7AHHDU",
-    "GBUFBFJTSZSDZHU3J5KWIB4JYOY7PUXFHCRHR3WREUO4AXI63DX6H3BM",
-    "GBUFLOX5VJTO6EBW643P6SF67YHC4AYAL3DEOSFN4NUGZCEI63EAYLB7",
-    "GBUFRWJ6S2JSQWBMIG3A56DBOXLDKJ6JTJMD2HDEQQGHYOGN5GOGMATC",
-    "GBUGLHHMWYDE22NOL65JFM64WFYVPOTNZIN3AZH3N74CJFDULRKBWALM",
-    "GBUGMJ7FOPGRQCR32YPH7ILZCIYLYRJX7U7CQTLZXWGNTXNVNQ7ME3NH",
-    "GBUH2KFG4Y33ODP4CKSNXFANXAZBZEUTU6Y6HNXO754AUMDTB42ZROCK",
-    "GBUHLARPFAPYAPV2IIHNGVQC2UMV5CHU6W7TJYWZOWYST4WJPODOZSGV",
-    "GBUINFNBAFSEHY37DPVASAZTNSWOE46ESW3LFUOXZ7CIQPJCLWOTABMW",
-    "GBUK7QH77DQRH6RRVQLEJBFPXCRU6327MHYFK374WX4W56LLCAG4RWNY",
-    "GBUKF52LV5CKZYYVJGFLM6CDE7E5HOZO4BTBBOV2P6SXVDX7LB2S432L",
-    "GBULBQHTFTPCPC4PH6I6MY2WTWY72XALXYRMHIF7CBRD3P2BFDFUABVP",
-    "GBULORLSSGT2H2CDUNXC77KCUNYZH6UG7XGPYXBB65BO74ICXETVEMSF",
-    "GBUMCEQTCWB5F2WUUFVEFUWH2YSZLJ242SJSNU5PK7KPIMYSLYLKFZIB",
-    "GBUMGSADQQDUKMEBHBPD6IE743YXG7RNK5VXLOYE43MGTUAVEGCU4ZH7",
-    "GBUMIFABI3UZMVZEWD6Q3F5FWYRABLJFTV2MWSXWWWUZRII64XOT3BP4",
-    "GBUMJRKRN4QFKZLH4Q53FGG6YU32S4VABJQKJ6VVGDZ7BUMVQ37ZVXXX",
-    "GBUNO5JRKDZQOW4N2W2WHPZVGVPVR677O62FMPGBHCL5U5EMLMWYRKRC",
-    "GBUO6VRGRWI4O7RYIBGMZW3NYWWT23CBDSIHESKYBKXKIQC4VMKMTYN3",
-    "GBUOAGGNOZDOKQX2YFLR6OWPBCCVUR45O6CBIXUBHUN2QDV7RV7AMBPF",
-    "GBUOQWQXCZJCADM6MUOT4A62RFKNKNHM7PYBPMEIOE4HZ673O2SRF6TS",
-    "GBUOQZHEIYTMD7O7KQ2I2K6YBH2QQELCAACERTT4WRYWAUZPTDD2SHWL",
-    "GBUPBCMHC263XDJOGX5MLXUHL2B26UTL4FMZ4MEIMPDSSWG7TDNHHQGE",
-    "GBUPBF4JHDDRI5VE56FHIMLFNY6NIROWCSCADAEOZ5X2VARE4UM6XKPS",
-    "GBUPVXKXQWLKQO76UTL3T2DFJFEMESI7RTLMIXQOG4MVALMW5ZS3SEBG",
-    "GBUPYKLQTSPAVKTJ5WXKE3KCOSKKOQYNJBPXG7STDHZU34BL7NXHABUB",
-    "GBUQ3MQ2LZLJAV277R5HI3W66ROCDNH2MSMKMCTENCGYOGM53TJGLUDN",
-    "GBUQWP3BOUZX34TOND2QV7QQ7K7VJTG6VSE7WMLBTMDJLLAW7YKGU6EP",
-    "GBURTB7WCKN7YSQRIIYRRMUYRH2XIWZALA5UKAAHUTESZPWTYBDBQRQB",
-    "GBURXW3TKP4JH6O7P4UD3AQVNBNUCBKUHHHQCNXVD6435A77ICGDSX7R",
-    "GBUS6I3R2YGYSISMY7KXIW3S72PWF2DIJ6XKURHJVMPFQ5AOS2QT3CIC",
-    "GBUTIGDYNHPG7MP3NVCNLZP4TY6V5543DSRTCKH3MTFRE2KCU44MLDQ7",
-    "GBUUGFWWEPY7AHGYCZHTWELONWVYMG7W3KMQW7G5I2IOYQCCY4ANEXUS",
-    "GBUUV7KMY6I62TCRW2A3CRL2BP7IQCJGLNJ6YK3QW5BWVJ6S7MUJV5JA",
-    "GBUUYHNE3RETJ6KR7OEWIGXP7CK2IDG7JR3Z3DANS45YOX7B5HYA6GKI",
-    "GBUV5NOZUJA2NJGNZGOASL6VFGKOKI6HTWFXO3DXU6LNOOROZCWXKDGS",
-    "GBUVAXJHY6BLZLAKBRVONI2EGVU26BAROHH5BKKQJDCCQJUQJJQHCQFS",
-    "GBUVB7MLBPN7OY5VRJA7LEWY3MRJ4BHEEJEWNNY6RQFVSUNDMR26Y64G",
-    "GBUVQGBZTJ2JIGY2A27PICUK4D4RF5ML7NG4OT5R47MLXV6YIII7DEZ4",
-    "GBUW7Q3LISGGFF4NCGZQPTDPKZRLT2EKN5RUNAKXGGAPN3V5TN5MHHC6",
-    "GBUWJZTLAML5IH7D3F27RWLPTNZS3W23HLTSB6AJHC2YPBTSATB7SYAJ",
-    "GBUXAGDYDBV3NPWS3VNULVIRDRMOW2YM3TQJVCJKRUF5OPJOSA4X4DHF",
-    "GBUXHLMULM3B4IUWVTEOHICM7WT42MTBJTYAGJYBW3YIDCJILEMFTXDC",
-    "GBUXP564M25QZNM76RZZLPO46AI65BCMXDO55L3LCK4URUSS34QXRRM3",
-    "GBUXQOJIHUQ3U2HE3JJ5RIUNSY7UDVQDOHC25TIPISFWT3FKVUXTXHLL",
-    "GBUXSY2NO34MB57JUQVOYQU5T6WS5YF5YU3DBC42EQZDVKFRK4TAY3OU",
-    "GBUXZCZXHVJTG5SIB43LT7TZRTBC5GPURNWFDEQJ4XLPNXP6GQEZXGHO",
-    "GBUYEG2OGK2VAT4J4TNT7YCXS6OYHSH53J62I6RBFITB72THGHUKE3TA",
-    "GBUZMCOC45UCUBMPSMNGKJCW5V7QLW4DSMO7Y6NRFUDRRXPYR3JJJMZN",
-    "GBUZMESTDSTZN4S7UIJZMGJMKKPPNAXUKXZT4S55LV7FKVN5TOHUIXMG",
-    "GBUZND7LZSP4Z4V5DMSNHU34WEZMCMIKWOIXXFC2HLDIEX3ANS2N5BNK",
-    "GBUZP63CBP2N7ML6KIP6TNFO7TSR62HNU7RRIVMBZ3ZPVWSARVTGAJJE",
-    "GBUZPRRPDBJGLUH3YR5ZZASK643WUOSNKI367BAPS465GWC5W24UZNHC",
-    "GBUZPXS72HKPR6Y4IQWUELTB7UJDJ3HCF46MOLR3N5TMNFH2NCMAEETH",
-    "GBUZU2G563YS7SG6L43SKGWQDGUDWM6L7NR4AK5UTFC6TQV3H2F6RA7I",
-    "GBUZVNXBM2XDANDHVXEEI53H7H3K7X7MXRF7WXKHEOW34GD45LGP32Y4",
-    "GBV2QCGFPY7ZS6PSZZX2KF3ONFCKXZOELQTZGPO4S67NKBMCCH5LGJQS",
-    "GBV3BZNWMTEZUOXNTA3XOGJSNIJOZISZLNXQF3MD2SVKGNSVFFZST7TP",
-    "GBV3OXJR7ZNFB4NRJ7TKKKQJEYWQRBL5RBSJUK3JEZCQM6XDPKFGJVOF",
-    "GBV44D2T36PP3KY2EHN2LTRHI5I24TUCBFORSX5CY6UMFUMCEOZQMDKJ",
-    "GBV47SPB3POWGFKA4C3EEPN536E64DLZWVKTWEOQN5VPZFA22A6N5YEA",
-    "GBV4K3IENYAFGG7642UM6RH3F5TVLJSRMXMWKN5NTOO6NQPYZKJADOGE",
-    "GBV4NISN6Y4PIB3JRU7WSIBLGCEHXGIEKJCQPW3RGZOOU4WA4QFUVU3J",
-    "GBV4PVS6XGMMK3TXLYHNSPPXB7GN2SLCQF43IOJOVNWHSO34HQDEBYLF",
-    "GBV4SFPYYHHJ5HASK5DHTV72TNIR5L5OAV523CQEPTCFLKQCJEZEJK26",
-    "GBV4T4LVHCC3PXSTTOPAORXMW4C5RPYM27VVJSYFPUJDWNRRZZ3ICI72",
-    "GBV4YDDMUHPL6TVFW7MXN4PFE2D53IR5IWW2DF3CT3HVGJPBKB6IPFLR",
-    "GBV4ZDEPNQ2FKSPKGJP2YKDAIZWQ2XKRQD4V4ACH3TCTFY6KPY3OAVS7",
-    "GBV54MVEVK2KJCTVVWZZD24NRN4WEIWC2DJVM2GROZJGJBY6ZPJC2XLM",
-    "GBV5DFKTNZEY737IZIUN32LD6JULLGKH55TRUMWP5M6XIPEFQFS7WHIP",
-    "GBV5NDWWNS4QNC73XTHTC3BKUNGDNGQHCCWFI3DKLWXNBDFCNSRACVKR",
-    "GBV65SAE4DR7TQBSTVWL2U26EIMY74XVKALYUGTOJXYAS3SRKZ6YI6CC",
-    "GBV6GD7NBG7G3AS3Y23B7NXWHKO3IPFMYO7EXGPZ2WRNMZIW65RVCP6R",
-    "GBV6IXX3GX2M2BTJYXSL7KBAAINCPT2JBHBON6DGMW7GIFDFWP3JJUHQ",
-    "GBV6ODOUNQJ4VUSBP2L7HSYNIH247OJ3DENTPB7W6DSNQEXETPZB5XPL",
-    "GBV6Y4TTSAKGSFMKHFNX747C2CTUNEFJJ4PJRSEMQYCKEYDVZOJDL5MH",
-    "GBV75UWZJQWMPCJOPPKAAONAJGMUKBLWZAIXO5KZY4Q3F3FMBGQWTVR4",
-    "GBV77ZXDGTJXDTLSDALJBA7KDMG6F5JJOAYNSXHQSUHOET63AS4DYJGA",
-    "GBV7BHZWV2YUCR5ZP3WU4ZAUIZWGOJ5D6PQ65DSMXIL3BM36T56DJ32S",
-    "GBV7FDDML4BNIXOWLM23WFUYNB5KHFQ3RJO67YDH5T2L2T3MMGDPC5LN",
-    "GBV7GQXXUTU5LNJZHGXT2PNP7BLMORPW4I4CHT37W2GLMU7B34HCXAUS",
-    "GBV7WNOY2USGOYIW6UCQW3O5JJNM54CSK2ZLG5LPZHAB23RQVFQKGISO",
-    "GBVAALMA3NNJIP3QZNGHC44JPU5O4GSVM5YWZPDREDRKIKTVOOSX3EVJ",
-    "GBVAFJN7SGICG57SFW5KWE6RU3DHG4ZRRLUQYF2ZKSUZVCKENNBBJMZA",
-    "GBVAJCMUM45A5ZG2KEJ3IYAZ5JJ77S7ASUM5D6NCSVESEJLKRBWGTEMP",
-    "GBVAKPL5RR5QWBWYKGXW43GTTII3BZMDZ5TCOBSYKLJRQUB7COQVYU63",
-    "GBVAOIACNSB7OVUXJYC5UE2D4YK2F7A24T7EE5YOMN4CE6GCHUTOUQXM",
-    "GBVAOVGZPZXR7PAW7QBYUJMFOLEC4PI7R5EHNFNE26YEMQ4WF77YJEDE",
-    "GBVAUJSMY4OM5G6Q4AHZR6YZGVNKABKT5DIDJ3DSLOMIERUVQ2HMSI2X",
-    "GBVAVSRXBFV24ZMNAU3ITAQU3W677GUDAPM226PL3Z52PCB57TNIDAX3",
-    "GBVAXBEKVU5Y3TDDKZGJLPHBHHE4JVCLN54KBWA7K65OYNHBUO3SLEUC",
-    "GBVBI3KJIIVHKPALDA7QBAB5CNBGR2B3NVS3FBSAA2IABA4UXKHVRTYU",
-    "GBVCLPG6PSHGSRRHILJJENHXD7L24HBHTDWL6VED76X2MXLT4SV4QQNT",
-    "GBVCXXSZPQJDFWST4MDPO6EEU7SGJYYD3NPJ3YMNDGZ3OX5HNX5Y5UBQ",
-    "GBVDGMWNNZASNED73VJK6G432INYQT7ABYS7RDJWXVRAF64EQA7S3KVG",
-    "GBVDIMJ5X3MSZMMJTDTPIAOUZBJYLL3V4HRFVEWW7QDNDOOACKMI2QVL",
-    "GBVDTKJYMDUYAX7PE2DSATNTWAWFAS5V7H3TUYMK4MDISVSKFG7RMAJO",
-    "GBVESRNWUULJD2P27WSJOYXXZV2UR25HG5YHT5DWQP4WFCZY3WQENISO",
-    "GBVFH7IZ5ELCUD4RGEHTUX4QMD7YZVJIQG24IMKGEVJNRRN2LUWUZUVA",
-    "GBVFIS4OKY6ZG42DWXA4L73AOGYZTHLJ6GPWPBNZ4IVIAHBMFQCMOLCX",
-    "GBVGPBJCLII5HHDH2ST2VEIQSYDAIXE4XSGEWACRNCYITPUDF56BWXEK",
-    "GBVGWYKTJPXUZ3JMQPSC7ADTWM7SRRW6QNEBUA4ESWAVRFVK2FYV44KC",
-    "GBVH5G5FOLSSPDNZ6O7IWE2274ALKC2UGBJS7UF4MGEF6FZUSWD6WUBC",
-    "GBVH5OY2QEVOZYLZOM6YZC5ULEIEZCN5V5E6Q35TGRHSLL45X2HJKGCL",
-    "GBVH73WV7WR4YEGC4FKVGHWAN6APHTWNI3VDDWQ4KWNBATHKSKVBZRUV",
-    "GBVH7V7CLWQUMSBO4X56GSTBNMIA5LNKJ7OG5PKH6ILT6MTKAH2DT3Z6",
-    "GBVICCERMZGE6ZG43IB3KPMCKZVOGB7NXTSO2GBPDMMJIDYAGAWLPW6V",
-    "GBVICSM4BRF47JXSMCAVGWMU77B7MGD5JWUMT2BGW2AKIRU5SWZTQW3C",
-    "GBVJS2MJ5YFZUXX35C3YA3NRJUBLLR4BJMNYKHJN5AJH4Y636QYBF6AG",
-    "GBVJZNY6T44XG6TW5BCBT2GMRLQI34HXIEM6BZMEUFGUA73QKUYGOJEA",
-    "GBVK7ZNL57STS3PW2Z2C4VT4XDNV7FKQGX45DPIZ4VWEZ23DFMAEC6KJ",
-    "GBVKBY2IKO7MO27P3HP7NEXG3NXSSSRECVJ2VZCFFNW47NFGOSWBUA42",
-    "GBVKCLAK7TFVSQY7QMS27HSYL5BMD5E4XSHCEFLHHTZKKVTQVJILFCHN",
-    "GBVKWXRJSITLJXO4VMQBHUKDAANOYNDJD4YQ2DUM6UABJ226DQ5LUSDC",
-    "GBVLCHCMALEK7QNRNYOKRPTRUJXBESACHYSRBMHL5T4KCLMZNB4EVKOX",
-    "GBVLVNOJI2GC66PKFHCC7G54KXWJD6TPDTVAV53RDCOCZL2D75XU2KLJ",
-    "GBVMU5WR54XMFQRC2GLY4ZVE7PLHZPR5MX4RSAYGSNV2KUFZCU4A2ANL",
-    "GBVMYJEPKCMCTW4B7XKI2YUG4ON2YAR7NMXJEFKEJJ2ILPDW73OAOXOU",
-    "GBVN37ETVJPE2DPRQFTW4LKPY3URW24OOWX6AV7RX723X5S3URFCDYIJ",
-    "GBVN7EUNV5YZ3YU6GFFZUVIBHXG6TSAUDPTW5B4UPQ62JM66JL7S3UUR",
-    "GBVOQV5C6B6EJKUIDCSNGIWR52WS4POFUGW54CNRKL2X4GM65EB4V4O3",
-    "GBVORBXKZ2CHF335DCXUOKXXGF532VPQGV2XIIADDTKRDKJJJXKYDYK3",
-    "GBVOXZ3W3ECRPABUNXAW6UWZZ7D7MOW5RQZWBKW3NJZC33JEKO55LXXD",
-    "GBVPART64M2SVWB355QIGA5AFXBGOC77J2GF6FDXEYOAMMNX65RXMWTE",
-    "GBVPEHPMN3ERPWGNPDSZP6AIEPH5FWVCHY4ZU7F7CNE6Z24ZRSLDCV3A",
-    "GBVPLOYTDFQ5WI4R5ZMFLO2QDXXEINQIZ5PYAKNMJQ45BIHNPTABJE4V",
-    "GBVQ4FDSFSEF2Z6TRM7P54XCL5DFKCUGB5J7U2OVVGRRQFFPGU4HSFN4",
-    "GBVQAEWQTCE27UJJ3D5T7VCFJ3O7ES4HAYBEUJWVL3A4TYHYIRXPOSUS",
-    "GBVQHOWKHMMTZVOUIGK7PWN3C74SN6HUWMWQ5EVHK3FPMMIBGXH4GJDG",
-    "GBVQNWXRSJRXU7JLI3ZK7FTBG4AVGNWSVWYGJATFGE4CIMKAKJ7PDXUP",
-    "GBVR6HS6D2KBRWIY2K6TSU76BZHSUGQDT5FZ2X5RQFYFDCRZJZWO3NYD",
-    "GBVRPWKN63PZ6HIW6NA27I7ZB2KBKFNPJDJYTIEESAY63IJ3I67JKMK2",
-    "GBVSBIPIOYNMBBLQRM6UPW5HDERV42PP52UNH2UXKCMG2UCWQPDUTALR",
-    "GBVSFF7AEMZ2U3ALQGSD62GZI3PIDCVDK44XYEDZMSOA5OOIVWXWZIZR",
-    "GBVSNLG2JI2N7HRZ7VC7NXPCY2YKNTM4CFKYXB5NSDJSCSJBJL3PMY3L",
-    "GBVSPXBXQDZGBS62B7L675BBP4TMVNEMCSJXAR5C7OLCQELXT2F6D6TG",
-    "GBVSXI6FPTXVTXI4FEL6CHGI5TROSWFDDNGVBJSGCXGL3TYSDXW7QH56",
-    "GBVTXVVPCTZZM5JDMQPFW2TXSWX4CTK2BXLCND3HID5UKG3TULWOEJ3Z",
-    "GBVUDZLMHTLMZANLZB6P4S4RYF52MVWTYVYXTQ2EJBPBX4DZI2SDOLLY",
-    "GBVUFKA4SCLQINJRGZZ77UVVSIJE64OHFUAHKSHGPW7Z4KJBVMRJNLQ6",
-    "GBVUHCT2EC6NSKBGHTFMVL3LY6U4NPPTHAF33IYJ6EEQZ224JNOM6ILB",
-    "GBVUOPUUGOKZCBEUGGCYF2PURK64C4DOB6IGOVRNZOJ5Y5L7YZBHHJQM",
-    "GBVUYTD5BLCZPK2BGGT6NIJRO32HWXH3SFBD2HODNWZ7RP22CSHLJTZK",
-    "GBVV6XXTXRSIH7DR2GSY7MCR2XEZESSEGNR3QRDSUICZY3DI4TERVPC5",
-    "GBVVHBAU7NLDRNHODPGYLOFDWMDB3FDI2DV7BJKZGPZONR5UZE54YIMF",
-    "GBVVRNVH5HANROJFHGV3M3GCCOC7HEZUEZMEYTGY3B5FKZ3NWO4UC5YJ",
-    "GBVVTHX4NEMHTRPPSVYBAXLLPKYGAHZNTSNYPN7HCYPXLSGH2ZBINDUS",
-    "GBVVV6A7SB5RKLABYYBBGHNPJLTJS5XK7TWRL5MUZDRY2WJK7QI4AL2H",
-    "GBVVYO6IBHBZE47UVBA3ILCFC4IUD2XHSYDFFEQ7Y5Y527RKJEOOVE63",
-    "GBVWJ6ZPX24OR47KW3T45N25NFDCFM5KYG677OORY3QGZOR3JI334FS4",
-    "GBVWVRNZH2JHRS6CW4PDEIYUMY5G3DJKWUATXXARJEGMQ6POHNZXKSYY",
-    "GBVXWAK27TL5GVQRCJY2FY5UE7HYRGNR3P5X6WCKTRPV3DIUXV5ZC3KH",
-    "GBVY35ECQAWHIUYRITDQWLV6NACVSW2ROBVX5F5JTIM23ANB3TW6KLJ5",
-    "GBVYID7H2ZLHECRZXER5EQ6VGZSYXGAEUMAMZ5GV4SDHC4R3CHHZAURA",
-    "GBVYN7PN7FHB2W64VHSIG5NJ6TYANNMMKAZPOCZWI3J2JK5H4DGRHHNA",
-    "GBVYTWCNHWWEAIHC4FPZQBHHPUOA3SZ5JZUALV3DCHGDKZA6VO7KK6FY",
-    "GBVZ7S4FYZWBY5YXHTGKO545CUNUMXK4EJI64CNH7AEHYA52GL4U7ZSC",
-    "GBVZPSWKYGS4IK2BBHA6PC5KV3EEA7RDLNFMZICHDQQ6UAI7RPESLBTC",
-    "GBVZQ3U6X4WXWT6I6ARLCMV3N5SNBT22SF5KTHABHRQLXQR4X76NVZYG",
-    "GBW3EW6L66RM7MKDF5J23JV7SBYKHTU5IXZMXDMWXPHM7QQCZOPSPVUL",
-    "GBW3FSUOFAGCGZIYXVGHEWGQTGRE5AOZBWBGEKBBJBKM2I7QDKY7WYBV",
-    "GBW3JAGBMMSULGE4KOMGQ4J7RYQHUFBSD33RCTHPBJVYUHFJSTIFK7KD",
-    "GBW3WO3TI3W5UVONZBKGZNVCJPDRYKVGZ6RQZSF3BIYC6B2R7RUXFPUS",
-    "GBW3YPGLXPMNHN3QCNJAM5XAOTXO6K2QV5GS7BDW5VYXMGQORDXZX2TX",
-    "GBW4H7TXZZEZRQQVBOWS7MT4MCTMSXKNVLLEHFQLEHDQ5LNWH7JSRINB",
-    "GBW4J7RN5WTVFAXCPVLPSAGZ6QUBM35O6BN6UKSV6RLVIVLV2VKJV6LY",
-    "GBW4NWFAELE6ZJZY3JUV7OHJWVD7SMRB2AUTFGIGGPQAOFIYFY2PSTAR",
-    "GBW6ZTRJ44CM4BIXN5ZFHRUZZKYWYOZXA5LFQSVDDVXZ2PTHLGS35UA4",
-    "GBW755SOC6AYTHATWUVJDEG7K5GDTRMZISGG7LTUSIPXJIPAHGXAHIDQ",
-    "GBW7OSLAF4N6KZEQWS73DSWMOMK2ZTUHGPM4QW27OXS2XM3JMYLBXPLA",
-    "GBWAF7OF7VI5U3JKGTYNYZHIN32NA4AKJHJQHA6ZZNNI67PNQETBAKCB",
-    "GBWAPLP4NCW7HULXR77W6WS5GFDPVJANDXTPZCTICOOF3GGP5AWIDDLT",
-    "GBWBHPZJ7UGVEWDVMW4HZHGFQLSZSOVL35JR2FZPPPVOWT4UOPXCQSQD",
-    "GBWBHTWEKV3OHPYNGUG7BG7VWTM24QTKQIJZLJV6J7SA56JZTG6PEK3U",
-    "GBWBJG55GSYFLWAWUTK3ZMAVJRCKGACLD4W3LRZLUJOGCTQ2BJ6A2HND",
-    "GBWCB65LFQ2QBJGBWWVY6FVVR5BDELFEKAT37CTIZW475BGTFNL23A6E",
-    "GBWCF75VE7YLNBWAMZNQDH2SXBCZSF5Z6LUIIXPVL7435WH3M5AAQOA4",
-    "GBWCFGPEOATHS2O3WRBSX3JY7GB5LFYPLWF6ZVY6WLROZW4GVLJVT3JA",
-    "GBWCFQGDIUJ35X3VTUDB2LHCJFIPZJGDE3XOGMKPWA2D3EZPL2LBNBTC",
-    "GBWDDG7E53VBP72JKBFXJUF3VUTLDS26BTJ5MBJPLYQX2YPY6JAMZTU4",
-    "GBWDFZLN6PFHZFDGV4F7ZVYULLNZZCKZLCCWFHC2KRUMACT6NLSNMSFT",
-    "GBWDTE55EX2PYCJXTBOLHB545ZD4FHBM6TJLXTA3Y5QMGAZTB264NNPE",
-    "GBWEAWD4EXBKBENGPMKGYBMCWEOPTET2XVG6VIUNHA6E4SGH2CPRAFLR",
-    "GBWG5LMO26UXYD5YLDYLFBRT5O2TD5453YEPPOG4QKG3PO57OPK6WFCA",
-    "GBWGSDA57GAI5WC5EZXQSWEJIPR5GRTU6GJO5BVZHANS5XWJDRMEF7EC",
-    "GBWH6KNNVRXALWUUMM7YYF42ANX6A3YHKYGIB2SSEHULM3V5NP7XHCUM",
-    "GBWH6LQE4ABROE6GASCP6QEGIHPMOM2XBAO7T4C74T3T7EEHP24QLKL3",
-    "GBWHP3B3SKMRSGW7OEI4TJVPZ4T6ISBA6GECF5WLAY6RBNLJHSNYQW27",
-    "GBWHS2IBARHXWTNBOZ4CIGVSMKYG27PN7L332VMONII3RRGZV7HMZSGB",
-    "GBWHZDZS6GEO5GR4F2E2FFSFZ3FUO7HBIJD7H544AAFJWVPC6OE47L5L",
-    "GBWI2YZVVZ5XWWCEZNAV2FTOYJRYLOQK7FIV5EV53YK5JMCUEY2SJVCI",
-    "GBWIRLBTQM5CIYUOUD3MTBPFEPJ4TYBIF2AIOXFNGET5T4MA5TPT4RKR",
-    "GBWJGUIBHQRP5S4K6IE4G6U2537HPXWVABRS75525OZOT32DLBIDHGUH",
-    "GBWJTIQJGLSRNMYKSPA6XZLVCKICYYZ5DDYLQWSJGKUXP5DKYY4TIBXT",
-    "GBWK2ENDMJBFATZ4UEZPLXKDWSMSJSTOS5CTGHCQTUP2UQU6M2EFNXYK",
-    "GBWKSMDG7LIDERBDLJMV2CSGUXOYKSS422QRMZBO5FCJKWGG2REF2XYR",
-    "GBWL3KA22PIWVLF3QNU7BSJ3SOQW6PUS5LDQEJ7FR64GCPBYWJ3DWUTK",
-    "GBWL6DZTCBMOMOYBLRTJCPLAAKSAO4247LK55NNSPTMONIVTRSXJFUSD",
-    "GBWL7WGJY67XOVS2H5TM766BNOF33SPLG4S2VDISWWOCBZVMVEKIWDGO",
-    "GBWLG67O5BZO66H7H5FRMCUO3EVLC2ULA2TPGASGGFQKTNMJ6QUSPMIG",
-    "GBWLGY22JAJ6XAKKQUKWG6GWV7SDYFBZQYV4QYMOSIN3XKOF2DAYQSOL",
-    "GBWLZLLCSHAYCB6KAMRNL76J4Y476BBFFZEYVRM63PA23FTZPB7J7CS6",
-    "GBWMQPIRJZWOQ5G3OK5UFQZTFWZNRKMSTR4NIUSOFMBHMCI5HMAKV6HE",
-    "GBWMWQMMY3YX5KYE3QTHUEMTDLTKTM6F4IFM5JJSNUWSV6JXZYM3LOMZ",
-    "GBWN6HM76H4MD5O3IRMD2GS3XVD73G4FONI7D4UNTR42K4DFRG255BLK",
-    "GBWNE4Q2SPTWRJE6K4NCEAPZSAQVS4AT2BW4LW7HH5HIAFGWZNKBHJLA",
-    "GBWO3LUS325MGBU3DABVAGGDRKRPVTRPDJFBHKKVZ62NIH3FYNGHDFOV",
-    "GBWOOUBPAOGOWCXKCLFZLQ5Y4SRZFORMAXEBMUWAFYYK3UKERMLPBX3N",
-    "GBWOUXGXSQFVUPIXOQGTME2W2HL2UDQYUFQ4UR3BMVNWLCNG6FIJXQNT",
-    "GBWP3GDAFI7CANTQTM3K3U77UY4SO2J7DDS7546NIDXKTQIXJ7I6HUNP",
-    "GBWQ3WAB6A5UV7HEWTRLWE5RELKNKI6BN54KPLHSPXDI7BML6AY23N4D",
-    "GBWQ7HUF2JEVCT63X5SM7GIA24ZHAQ7S472V73DR4YSGWNNVIEDCX3WA",
-    "GBWQDVTX5DHUNBRPQWKDWOWZHT2YXVFGXNHY5I5E5PGLZEJOKSNDOO2S",
-    "GBWQE3QLSYLG5NWQBM5NVQ6CBTQR4AYVWAMSUZ7DBR7CRXMT2TQU7SLM",
-    "GBWQFZTUIJJWPQPPAZM2YXTJZ6JXMQJZLMJTR7X2XVCOFL7GPPLXE5ZG",
-    "GBWQL2SDATD3AWPXFQGCQDRLMLR5JECPJ6ICBA4SLMDBZPFXBV3QXRNB",
-    "GBWQPD7M536FYVJ2OJS3ZACNMNUCLDK4VCCGSK6G4XPKN2QKXYHR2WXJ",
-    "GBWQUBZDKLCH5VSTD4ZQXBWGX5IUKE74QI27WHA7FLC7KLRFKCJ377SE",
-    "GBWQYKNY2V5223OJXAXE7C6LLT7YPZJRFSNLXTKGBKQW2SVBDBI3YMNZ",
-    "GBWR2R4E75HMYBY63SJBJBXBYVQ4PIKPETLX2UZZ45EAABKKZ7JCG7QO",
-    "GBWRHCXTCZA4JLFPZXCW5MYPCJL3UBD2KOOGQLPHWRMW7VBEHFS37DWJ",
-    "GBWRKQSUGYPWF3WXXSRUOUHICGOYRAHEYTSWCN5BJWVTVKU3TAA6AKCV",
-    "GBWRVGJ4E47222F322T5YGLOGQQWNMHXYDVR6NE27YULLV6VQUTQC2YP",
-    "GBWSBA76UIAXJDRSFUR3DCLRZWWOEEQANIATUWCS6YWMMDGLDQEPOIOY",
-    "GBWSFIZG2RA4C6CZSPQLP6GAXMFBXWGKB3SBNHLSLFJKCEMKY3RFAM2M",
-    "GBWSGZPXFHG23OI5TTLE3YBGXGF74YGYSH6335JG3QB3PIBOCRBPPIW3",
-    "GBWSIJYEJN6O74KVGPAIA2UL32KI67VXY3TOXDKZ3LSA62HRB25OU2KK",
-    "GBWSNBYVPC3BSPLDOWL2PYYES5WEEDNQFY7FSTJ2WHNPCU7SBSWFQQHT",
-    "GBWSRSOVGGNHAYNHSTY2V4EENRPMYWZFLIC63EK7VRTDGT7FNCBCDLIU",
-    "GBWSWL5T755UAKRXQAA2WZL2WRUYBZOC7FKRHBDGMU2BCN22C4J2AKUZ",
-    "GBWT426EFNGCE2ENOHRTN2RE4KAN3F52Z4LI4DXQESNARVNI66VNFM3U",
-    "GBWTKLDNF32EKWSSW7JGZOSWZ7XOPXSVJTY4COSAKZY4Y4SSW53GOHRO",
-    "GBWTQAJVXTAMQGQ6L4F6DUQS4JO2BYJQKE5RY7YLY6DUCDILQ6GWQZSJ",
-    "GBWUE2J554ICL6RLX4SMDI2S63GY6TWAPM2LFNGEU24IZDTAVEJWNAOR",
-    "GBWUKPKUZB3DCWLTV43ELTCAGSQZSE4BFPW4PFCVQATSSZXPOUJXQOFI",
-    "GBWUM4BBUFPSDA4F2F2UBHFARJCAUQIVPL4GLLOQJDQRP6YO624U3MFF",
-    "GBWUMC33ZMUGP4UN5FWZYHGBN3IV5BU6DLJXKCTQLBJJOO4HG33EHOOK",
-    "GBWUNY2CLMWR7GVNVXD6Z5RMBFV6A3OLSV6MODT5CKM3Z5LIR4NAZ5EH",
-    "GBWVB4W5ANMPT34IFAAALFC3HHUGD5II7KIL2KK7HBN6ACXD2BFSTYWZ",
-    "GBWVUDMGNXEE4AXN267QISDGSOLOP3R5J2Y6KTOOHIWQBOA4IB7KF54F",
-    "GBWWRCJQBRQYGRXEV4MW2FMFCTFMIJT2EVARYWDPXT6CSMBNAS3XAOOJ",
-    "GBWXCL5GFXWENDJHH5RQBMI4NKHZBUPUKI6ZQFB2IFUB3RNGQ5LOZXI5",
-    "GBWXHGNEF4NRDTTFKYAXS6CLYKZOSKN32ZFHUV54MJ55V35PIHBA4CYO",
-    "GBWXJ3MNQZFUBEILYR4TUXFVOQWV3MIDZAFRHRVJYBWN2IOYQCAWLAMY",
-    "GBWY2D65TESEUGJJ2ZESZFHCYVVHRNQOR4FNMFBXEUAELYQHDR3GYU5P",
-    "GBWYC4QSLAIHTGLVD5RIM6FV6NRPREHAPABWISQU3JURHGWV3VTVKCEJ",
-    "GBWYY6POEP7HUTXBUCHD7LQDYQI223BPRQVQOVINBOBWSNGFHUX3ABRA",
-    "GBWZ26C6NM7NS6XG62E2JZ7HHBNNGLLQI6SHCETL3PDNK4CXJ235S54D",
-    "GBWZBK6RYO2K6CZUISFKDUZPGA3347KIOWT4GYXGNPDC2W6BIGHK2R2U",
-    "GBWZHAVWY23QKKDJW7TXLSIHY5EX4NIB37O4NMRKN2SKNWOSE5TEPCY3",
-    "GBWZITDUWXMB3ZZ6FHUGAD7IBXAYZTDTJINAFK7DKUXDGOESV7I3ARMJ",
-    "GBX27DUTNYCFFHG3TEL6CAUG6GGD2KGOXRAKYEUDZ66Z5XZFHN3VPKRD",
-    "GBX2NEP6X6X67FULJXG33CCZCKI3IFHXK2RVULP4VFHG6EVLJYDXXASY",
-    "GBX2PWVVBB7TXCPFPNLWJOLNPN6ANUJ7YAT4HCRAMDTPCIDGFSWV2XUV",
-    "GBX323MUYSQ5SVI5DJIV5SBIGFGHRWNIQHZ3KOEGBS2MPNLVKD2HQ7AE",
-    "GBX3A7GJUAZ7C5LCCAR2RUFPPUPVDSHFL4K3FREOZIOFCEHTUUMIHQLM",
-    "GBX3E6O4RNWZTIN3NCZ2KAPAYXLAHI47MMRRMB726N5I7TASVZRCIVWP",
-    "GBX3N7J47FVYD22QW6N5O5IJBNPHJYYZGOMXKEIIL45QKEBZTT7JWMCD",
-    "GBX55DZK3H2YXMVOF64QJFT3SKFVQPG724WCL5RXWY3CRPYC2YOP447H",
-    "GBX5GPYXELTBQ5LXAEELAVUIVFMBCUU4T7OBF6KXGIIPHCACJUBD6FIT",
-    "GBX5GT6ZIQ3NZHAKBIJND2EKT5RSAJE6DX27GMC7M4ZR7ITAO3MVIBTZ",
-    "GBX5HA6KLFTFGXACJU3T5FLP2UIZF4GOOQJ3CNM3WY2ML3V3FBX6JV2O",
-    "GBX6BZMFNKDQSTNJ7Z656D7VP433FJIHDYCWT7L3UOYKC545TI2ANFIT",
-    "GBX6Q2MD7U7DTMFRJWFTOWAGTQ2FUTPK2EHTOTEF25Z32NVTXZFWF2WC",
-    "GBX6SMYUEDP743LPMG74UDKM5OZ2QFSRAWPDNIDQSEUB3KFWU66LQJAG",
-    "GBX6ZX4TCH6GR6JD64J5ZCFSCPS7EZEJXJI7GT6ZW3YITXZB2BNOJJGB",
-    "GBX7B4OI7WWFG2TERS7HUMMK7Y23R25DVEU2XD4SCS7PU7CNNKRLZTJK",
-    "GBX7MFICFZ2VSXPD2MZCJHUPEOJP27ETZXX73A72NX6QWNBFOOEZ6OGO",
-    "GBXA26SBMNFMSVGINIS4WX3QRMGJPHDQUSF66GS6XH7XFVF3PMNO7KOG",
-    "GBXA6UXT2IN6V6OBE4JXSEXI7GYVH75XNSZH6IZVBOA7S5KQF2TEEQ3B",
-    "GBXADMTEPN42SKHJRWW2F5NENYBBYG5YE57YRPFPZHMASOV3K2BYJAOA",
-    "GBXB2XJSD4L4BW43ZAEQ5LSXKI6AMCLW5WW6AY6V2KK7KTACCHJ4NA3S",
-    "GBXBARP34GIWSVT2UHPTUJMCRQRFDSSWD7J4G42X4WRDSF7E2BO5I472",
-    "GBXBHWVOGKB2T5KPV4SP5W7EI7KLEQAMRPNIK4J4WSBI7LWS5BAAXH75",
-    "GBXBIWDTPO5WQSLCFTPEQJPWDCLDSSJER74ULZWFKEZ5ATMUAWIFDWVI",
-    "GBXC3SU7QYRY3TQVRELSYEKHKWAUST7TDDGJ7U7W2F5FBVDXPP475LG3",
-    "GBXCQJ7CDEIKDAPJVEL5ZWGXYCNVVXT5OZMSO23G7VRZRFRXZ2Q72Z5G",
-    "GBXDTS3Y67HXFPWOWJOCBX553QXBMRVMR5N6F4AMBIQ3EPSMA523DZBK",
-    "GBXEG7DZQQTYZCZWTH7B43KU6UNQEFAFE734KDJSZBUF2KETQDUJABDC",
-    "GBXEOQ5WOXNUMDCST5CTRE4ADQUONUEEIFT5JV4HLSMDAONGC344FQIV",
-    "GBXEUURJ5WOQZT6VPPXN35KP2HSHKVH6P7G32EPPKGTRTAZNPH4FPGYQ",
-    "GBXF3UP4GXGQNOUQQMF5KTRGQ4LPXHAH3X57EIAADURBZOA55SLSZX6H",
-    "GBXFMLVAI5QCNLBFHSFHJGFHUG27T7QTV4OE75D2OOGV7JJMEBUNRILH",
-    "GBXFMUU7EOVETB6PPSGNURCZAPPIXXD32DWLL5TTI3253WSLIEWTY2KH",
-    "GBXFPFJDE3A7FG3XQJUHODQ572STH6JWDVH44KAPP22AF3VYAAWR373L",
-    "GBXFSNUFFVHLLHHI2QWOBTS5V5RS2CQ3DIBBFNU57UVWAYDSV6VOGZG6",
-    "GBXG3M3W5XA46AJLKXKZV5QNXIL6HNVATOZ2IA6CS7MBUFNA6NDSYGKT",
-    "GBXG5KFLCB4D536GNNFDEHAV7SF7I7EHTWXJDW744XRFMQCQ7ZS235HZ",
-    "GBXG7EB5MFI76DPZT6NWOIA7BWNSSXFGTSZX5GCZQITWWANQ7S5WXNQH",
-    "GBXH6JAX6KQ6XYZPXCKVXCOEXT7IIOQ5444WHF3VBNYGVNIXLGROIKT5",
-    "GBXHMVIJNWSPUCFGJPP7JBEMDAPQBY3TRSXJZO34OXBADFRM6GD55CUO",
-    "GBXHOWEROKZYBDDDIWCE5AJVHRWABQDSCTGIBAAYEV4FUYFXSAEXGGIJ",
-    "GBXHQEYSVISGI3JJU6N2YC7D5MGNIIQOVCICOZRATJFN3SIT5VXZACID",
-    "GBXILSAY2IGGW2GTWXEE7EM3QNAHO2BVNBFIVO7P5SNG5X4OJTXLNXYK",
-    "GBXIPKK32AXYOS4DPA2NQ2QZVAISLYBPTEA4JZVBT2E5NI4CJ4X4QBXP",
-    "GBXIWGG3ZCP7SWT6SSWJTSEWJFZXXQAF6A2WT72MQ7QMELGINQNPXFIN",
-    "GBXJ4BCD5OBFG4HG364NUH7EAGVPMCNMX6XSCONWO4QLP2PSIFVH6LKH",
-    "GBXJXPF7I3OOT2UHA4XG7WER6S5KHG3ZKMIYGU36EBGZWPJ43N6H5UPD",
-    "GBXKD4CMEGNDD6NMKVC54AO23NVQEMBXGD6FVBQ2H2VBFWJ7NMCNP72N",
-    "GBXKTX3UB7BUAWTQ5VOCGR3AVGBY5S2DOHKM5SBFNUKXG6BEOUWAZXAI",
-    "GBXL2K757NBCP3WXUL2YNO2I5PIJT55OLYQHWUSORYJN4LVYOTZS5PIN",
-    "GBXL3ZMSTUKDA6NHZ7OKPXV7PMXU4HY56EC2YEFM7AHTYMM5XBRDPC2N",
-    "GBXL5UNCYUA72A2GTWZDPYYVIOPL4EEMVLNIEUWN6PQ672HWA3ELPVDY",
-    "GBXLR36ZATGCO7VG7KK6VPI5KAWI5BULDX2EWOZIPJTKF4ZY3HGMEMVH",
-    "GBXLY2V5HOMHTCAT3POSLK22LYSSTKHV6BVYZHCPY53OMEUHFRPNHCAV",
-    "GBXNOKMUDNT4QYMBYBSOP3JMLFWTIWNVJLICVYN6GBKHRB2ZFVJCNK7T",
-    "GBXO3PV5PSNE4YZRSWS7SXTF2ZHP2MAUS64PPU4M3HT4L33T3MAD4HM2",
-    "GBXOK6RTDAGSTIY2U5NA2JL6KGW4U2TPUF35WSUMYDREISPGXF6IBUSD",
-    "GBXOL3NHXAD33J2566ZJ6XJ77HTLGNJKUG5BX7TKFXSDNMOAG4CIOHXE",
-    "GBXOSTYZC56PPJ7WAOXR6BPODV2PVDIP2SIZ33XD7GIDE73GZTWMNF4X",
-    "GBXOT3X33GHFSHIP5EARR6ZBIQGT5ZFTV2ANEBDSCG4EHL4WGMH76YUM",
-    "GBXP5F3FAI7R3CRUK7XTUMCDV6EO3J72FJU7DA6ODJMRUUKXQ7BOABDC",
-    "GBXP7FGQ5OOMXLGF3O76XTS7S4WZJ4S5YACOP6EAS5SR5FPIXXDCOMOW",
-    "GBXPF4QV4VA4QLFAIGQOJHLJDLDUFJEVNMVXPWCMZYWJ3KYTVO6UXPKR",
-    "GBXPPS43ZRTGKGOXXGCPQTAUQZPH7SMP7BBYARD2XZHO3JVOPTHRGS2U",
-    "GBXPTI5UMUXKLYJYOXMPEOC7GQYM7QH5HXYIS6GKNHRUDUPGIWTVK63N",
-    "GBXPXPKSCZ7RADKQKO4SOCCIL72X7ZZKJPI5DFSSQ2COII6MHJEV52E7",
-    "GBXQ6PAGRMQ6OORR7U4KRBGIURO5OQGEJXZFMORLCRAVYGCDIX3K46G7",
-    "GBXQAA7X3TBSXA7BUOCAR6U2BY7VQZZJMAOFLCGT6EL3RL22X5VFNSV6",
-    "GBXQFAQTE3IYJ333JZGP2WRBAQZ55N3BCOQZX4GPF64JKBDYKBXT6YAV",
-    "GBXQJX3WF3ZNPAVI4EZ3ODFJOI5SATICC37BYTCAJCTVWV72P5GR5ACV",
-    "GBXQXORGWRLO6YG225NFL34Y62MKRNTEYHMWWXRBQ3AANFCEPY6MWUKA",
-    "GBXRILTEBNN2ETCJ4AJ5YUD6FWPESHW35TPFBVZR5PYZBYGDY6TUCXLM",
-    "GBXRTFRDVRJPILE2OEJB37MBJKP5SWSQJKFZD25DN3U6ACMJGGM55YLH",
-    "GBXRYHNPZBQSTZACAXF35LM5AAVISYIQOOJ2HSBYUS7ZT3376MPS2C6Z",
-    "GBXSLGOX62VJJF4YN2DEUB5OUHSBN7UFBUFL3URCBLVEQI7BBGZLBITX",
-    "GBXT3KCHOPAPQVRORZZHW2ZG2HMASRRXJHOSBBJCMK3MTNG47NAWUPKE",
-    "GBXT5QCN2HSS4GQO2PON2E4QYOARI7XZGLICY3322PSHXH2SRWDILCQA",
-    "GBXTBVF3ZUOMTZLR52EC7KVWQKOSL5JC5LI7ATR7SVVJLC3FHMQVFHMD",
-    "GBXTF7TJQRGV6L53LC5JIUZE3E5DDQ7IUWPOD67YCITTFNU3JFFRTECH",
-    "GBXTMLRJ67ZXA4CCOIK3TO2F2XANWS4NQBPGRQHMBDYZEMUGYZL5TJAM",
-    "GBXTOSZUNRLBQ7T27TLT7AHZKCWHPLFZMCINSH5J74UPPUSANLPGC4OP",
-    "GBXTSJWPELNTWLSWL7OHX5FLXTMKFVIT567EZG4F5YIQDTZAFSTP6RON",
-    "GBXTV5CJFSN2QKP3YT27HCIX2VWRWVGRUB3UZ6UYUACATYHPT3F2NQVM",
-    "GBXTZAOG6NBJKU3O7YMD4T6SIUNAHJT4DMZD2Q2JJBPLXGP32WQ3EUPN",
-    "GBXU5KVYLZPFOMED756RWI5CKWI7OB7OETBVFOZPL6PIKXAI57B55TGW",
-    "GBXU63WTQTJRXMOFNEABDV72EFJMZPJRAIIPQEF4LTG66ACW3622XQYA",
-    "GBXUAXHHPYFHDTKW2AI6HN5OLQLULXMHVCWNJFC2X7FB3BGSI7WCOMYX",
-    "GBXUI2AYG6PXFXKHRHKBVLVNA5MB5YJR4S3U6NPIKRSLJXLPG7DUZG5X",
-    "GBXUIJEB2SNJNZWTQRVT6RKDCKMWONOSVTLUVP6GSVNWEXF6HB3MH3QA",
-    "GBXVEGQK5VIIKRCFPDDOE447XK5RTVGENB4X5LZ3WB6RYA5V2DNFZGQS",
-    "GBXVHGWSCYGRBYXTP5326TAE52XRX5UXFXUBM2HFUIJ7AWNTRIZZO2RY",
-    "GBXVMUV4TYSNW2ZNAN7M5GDYMU3YYADCRJAZR7LJTMDTYHN5NMRYEXRP",
-    "GBXWATJEEJMX4NMTYX63X3N35MQL2BJ3VEPVOV3GA4RDDIT7G4UOWMK2",
-    "GBXWBMBUDSKZUKC22VOEUV7OYOEPMFX2O57XHRNHGYLJGAJU7KKE6BCH",
-    "GBXWE3NEQH656KIPWU7LE24TA75TAR75MSRVSZXLG7QDULPCUS7FLB6Y",
-    "GBXWFJKB5Q7AOUHUMLHYREFK3EAVDMMRSMKGTPYO6GMOF2OHQSC3ZUHA",
-    "GBXWKHPEONQERDVQBEJNSY6PL33DT6JQ67ATGZ3RWBDXDMQZL735QEHD",
-    "GBXWLRYTPTVPKGMHVWGGJB5MRUDINRAHN4XYF4EKDR6HTGGJSQ44WODF",
-    "GBXWMUAROYM42BCXXSOEL63IDJIQ223JU6KJIW3ALIQWIO6MGW7LAILG",
-    "GBXXAKDGA6KXDS65IG2D5VUBHPPS3IBUFTYWJVTMAPZHLGIQSJMQCTLV",
-    "GBXXC3EYMKKTIFM2UD3OQA3LRNN47HNL2B4QG6RVFUXBQFCX5T6D4KWI",
-    "GBXXDRKWYLQ47V43XSGNL6MFAHXJ5S2GFFRFY4VK7SLAL3YZKVAOBVZH",
-    "GBXXDW27D6MXTJWSNIL4JEY3T6SFCJK7GYXDB5GP5CGDXZP3LHZHXUCS",
-    "GBXXNWZGO6LVPPYLKI3SUWOFGJZSH3LV36L3PQ5PZ66VRBKVMNBWQLLC",
-    "GBXXSEMHF75XSVGUQEAJTTMRK4I4IJSFFSZULM25BYZTPGZZS27KFPH3",
-    "GBXXUBFEAHUYNDYKAASRB2WLRSMKLEDPCUDXO5FO7NZWZIDGLH67P3BF",
-    "GBXXVRNSATPSNLWBAVY4QITZQBVNENS2XCPGONAPKEGMRMDFIF2EX3KP",
-    "GBXXWPMKIKYUGVQNYDXVS5CAT52C6GDYJTLDBRVWK7FZYFSGM2E7PRZ3",
-    "GBXY4BRU3HYHTQ7KNIUTAN3WSWSAIENL2Y2MIPRJVQIBHULYRDUDQUSA",
-    "GBXYHAIXJY4HPIIRPIEV7IPNCNJZXDL7GSET4SOOG2KX3NRAK2GHC6L7",
-    "GBXYNF6PLXCP2U4KTQ5VJPIPTR5YDH5CKVNDLDCSOUR267Y2AZVUFKMC",
-    "GBXYWTL4W7GWOIOTZOME364LZ4SCVSFWT2TOXDVKTJI7GYZX6PORU7IF",
-    "GBXYY3N7XKRRBCQM7ACFM7MYOBFHOYQT7XA3Q24HP673IWVCP5ZZR6WK",
-    "GBXYZ5TONBLKVYZNMHUAP22P6NNHM7QFTGPVCA3IPAOYBROLLROOWHIP",
-    "GBXZ7KHTY5MKKY7WZMDFZUQKYCVN64OO5BBCZG3BQ7WYODTU6BEFSMAR",
-    "GBXZQNRCMPYEJPKMRLZT6NBNHEOIYXRORZ7QBNS6EUTT7QTZ25O2ATOM",
-    "GBY25WETKGV6IR7EX6WY5QUTDSWWUXOHSHFIJDC2T3BTAW63FKQRSHOT",
-    "GBY2HMNUOTMWOHTKFBBWLKZ6PYXRQ3JB3H5IM5FWPRLERX6JOUJB6L7A",
-    "GBY32B5D52YOAJGGQV66GR7F5CM3HG53VRIED24ZQ6K4UICNZ5DRTFRV",
-    "GBY3KBVQ7D75RWKGLN7LQCIF2FYD7TY36OPWFBHJUM2NIEBU4QMRDZBK",
-    "GBY3MLFJIXHTYBA7YNXCXC5LJ3LBYGQRRCGI3JVBY2Q6YYNLMEVLSJXC",
-    "GBY4TFSPM7CNQ5BSOXN2VMV6MXPCPD67NXJVYYYAYVYVLZZTNPUH6O2O",
-    "GBY4ZMWEKFEW3OAWRK4TD6QNF4SOBL4UHW7DBVHMQ4UNPOYO3WV3ABTC",
-    "GBY54KWGNULL6HV46B5UA72ZK3R4WLZ4ACMJDKDIZHKRKZ3RIGXEV23N",
-    "GBY5AOZRPYW76NT2X7LM7PYQNIPYLWFYDZQLUMNKKVP2I7INXTCCPXLM",
-    "GBY5NEWR7AOXIHQK6CNBPPCFEQFYPP2LTCBOEANHFC2PH3X2HPMZTFRN",
-    "GBY62Q44Q3Z7XRYFASUEXY7SGI7TXE5DP23S2USUEVAQBSY657Z5VEGY",
-    "GBY66AJ4O6R55S7EC7DIY2RUX25ZJR2RYBLZGJWJISCQIQ36WK6AVDND",
-    "GBY6GP5N4HKWH2F7HJ27JMIUIEI7NLLYVLQVUURGYTOI6KKWUJFFDLUU",
-    "GBY6SXP2YROLZP54ELOG3WZE5S3JXMWNDPQHYJV4TVQBW4UIKDKMGO7I",
-    "GBY77NEEMMDKYVPJA3KUONLMNXWJ7EF3W5QWTEY4L5DBPWK7N7KLL36S",
-    "GBY7AHR5I6X227XJIBFWQBVKKIBXB7CFI3GH342IHZOEVJXRJ7OUSGXU",
-    "GBY7IM5DHRG4WWZFXE6QIGGX6ZMLERDSZCIZ4YUZKEZMPT3BVB4BSQ3T",
-    "GBY7QKRPDSS4QCD4YXCMB6EOGW3M6QHWZGSFYQAIGZXYDHNFSGDF7YX3",
-    "GBY7W4ZNOG6AFZGWSS6J2ENKZRGJWFRSOQJG4AZDIH6BLXJYZA6S5DOG",
-    "GBYAD6ESMZMOZNOFCI2LHEKCNKZALDE26G67AQNKORCAGXXB2D7PXA2X",
-    "GBYADDHJFPUIQHR6SREMZCIZB4CY6LLCE5FIVGPBITRYC3L3GMNNN4OI",
-    "GBYAL5I3SAMI5O7NWKFGQMF73NKUR5JOWXI42TQRNB7YGDO3BP62PISO",
-    "GBYBJ6SORJAZCKBB4EFN3F6WDL4ZD3LYBV5MSSOJQADEV35Y7HEC3STG",
-    "GBYBRYDPQUQML7FQHECRWAD5KYWT4BZEHNLZRA6UTKPWWDFGNS4K6FKR",
-    "GBYBVR73OUAJVYPQEW7XCEZVWDEHPU5EBUFLNCTA4EKE3JQHOLEFQDYG",
-    "GBYBVWOOVC4EJVRIF4HMWG5B7POLCS7JRPY5KYR3BCLEK24IJQOGUARD",
-    "GBYBWP4XYKULMGOUCKXHYKJSRJF6YADAGA3COJ4ZR2TK4AECZ6VSTENX",
-    "GBYCRM2RBJGSD6DDSVRUSMZB4DIGPJUKGTKOVN6LE7QDTWSN2K2YFWXZ",
-    "GBYCU7LWX4VPAHFOR7ODOLRKDFBXQJX4B5EPKPAKAREEDLH3JT3CWVR6",
-    "GBYDLKFQMCIHADDA2PPJPQXG4R7B5WYPPRA3CVCPGFYJ4QZFUSRZMQJX",
-    "GBYEFD3F2TPEMH4QLLMVUHPOQXC3QVSNHGXQTVK4E6BDNGEMXV7HXMV2",
-    "GBYGUAQ66X2FE55A2CSRX33UCAUIUFKINTCOMIIFUCHNBWA7N235DRIR",
-    "GBYGZ7GN22HQP2MCI6OQTEXS6XB35JXO2Z2ZJE463TNODZSK6LPOUJ5E",
-    "GBYHD3G64DGIBLXYV353LZ5CX2TZULOGFLBBUDS24MTMMJOIGIK55XRP",
-    "GBYHEK5APQMW72XP5QY22XCYWF7GQ3H5YQONHS3UCPLZKWLQ3AXY6ZOL",
-    "GBYHGFFHRRXKNCSUDCK4N3MFDTQSLQGUIZTOMXRDDIFQJU7ABVGZAIFH",
-    "GBYHP2PRTG2PAAB3MIYIGJDTBJVVV3W5MMRQPDE6HUIYBQRON7OGTW6K",
-    "GBYI2HIQ5E5CCE44CESZUTRZ6PO4LORDEZ33GF2IBNJWPLR2LWXEKWS3",
-    "GBYI5CKF25G6QBHDSEPF6GQYQG7OZZ46CUR6JNU6QQOWKGG65B64AOCO",
-    "GBYIGSSAOKIHEGD75QKXQYOUCPRAYGCNGDOEYW3Q7XGS2SABHJEXAISO",
-    "GBYJEWATIWD3EIPP2S2H5EDMBSQNAQUZJEF2WTM64XNS3REZKYKLCIPS",
-    "GBYK4VUOX5M4FUXBMR63KU7YKO2L5MD3APK2XJ3XI7Q5ND4QGQQ3YX5V",
-    "GBYK6EQ7EXW5UGWXXKVZYMFRVXXYNVGW6F2BRPM3WOACUTVAFI3GPROJ",
-    "GBYK7N5QKBHRKYCILRVXQPMTFB4YBOI6LF5SIM7KH6J6PAAUBGEPBNKT",
-    "GBYKUWQGUHXZVC4HZWEB644YXGIUZGXGDSV43PCQ6TF2QIEB6TT6RSQY",
-    "GBYL65MRN5F6GLX7QJWK63D6AV5PRK5WKKEMG4LT7CJZDN5B2KFCOYRQ",
-    "GBYLD3OZQKWJ35HPJBCJ4K3DOM4S6Q4S7USIRBVTEKZTBQS5RWMM3MG4",
-    "GBYLMAZDCKULBUPFTPA55T2ZZMH6KFJKZOUXN2F3L44ILV5BJKZX54E3",
-    "GBYLWGKX4YNAAD5BFLXX6WR7RPQSNA475HBI6Z6SB6BBFT3GIH3LLFBG",
-    "GBYLXESUC3BBHVIEOGDV47ETRAJMMY22RKBNRZWOIKV3NMC3YFPCSPQQ",
-    "GBYLZER3BGIRPG4HY3FY3ODFYTDNMDKMGKJI4M5WCRVSUXMSOE7FQHZY",
-    "GBYMCY2QIGQDERP2LH2RN24W7OINSXV3Q3KOJWPUIATZCBGHNXJFVKA4",
-    "GBYMFSDE3F67EDLRYI77UCDEP6ICJDIFRTVRGZDYMFMAHNIPNEROG2BG",
-    "GBYMRGH6JMJXLDH5ETKT22FDFRULKNPI2JAHTSMIQLGZPIQ2BB6R7TAA",
-    "GBYN2NFM4ZIK5KTNCMSCRL74Z3WUHDBZYJSTQ3FZFHUQZDOOWDDSNMEA",
-    "GBYN5XLR4QDQFM64GKMQBXPS4MCFMOZKB7XMEFFONXR7OJC3RSLYQPFZ",
-    "GBYNRVVY6MTW5XKWVQXFRIP35DKDJMJYHPHY5PBHPY7DXYGJMA4B7RZW",
-    "GBYNZ7DULRJJSOG4JNJD7AWRR4VFWNTHKNUNS4DFPFP4LUYDYLOMOIBJ",
-    "GBYO22U52FMQC4SPUDW6KZFTPKF6A42TNHSRHHXCI4BYQH24ZRACNHFM",
-    "GBYOOPIOBGNTMUBWYKHZYSLQA6FOICPR2EBWR7TKLKUMPU65W26PWH4T",
-    "GBYOQKS6CKIR7G7FML4RBGPOEWI7XLSACJU3L6HKUHVNOEGEJLPJ7STY",
-    "GBYPPL5HJXU3JWTNPTWHKLTQHX6MPPOSSATPPOP6UQ7KDZMN4UEOCF5M",
-    "GBYPZXTFYYX36Y4AAHB4T3PJ23VGPXXW4VBRI6I55XM6HDIIMMMAWSAP",
-    "GBYQ3U35RXJLBQKQ7XXNMTW3FJDUQ6IGBKF4S77Q7KN62ZIUDDGYR7GB",
-    "GBYQ6XIS65TNN7XXC4BTLDJTZOHVYWDRF7VQHXJV2V6ZAOM6XLRLXAI5",
-    "GBYQZFBPFUYLDIYUN7YIUCPWYMN7OBDPWM3MKW3K45OZUXAKY2DUQSKS",
-    "GBYREQTJKAOBPEXC3YGUE3IV3DVGNAO27QRB7BALTIUQBEWBBHYKYTNY",
-    "GBYRFRA6HZ36OFHU2BZWFSLF5CEL3OSFEHQUQDN4XXNMKILWO5XJCEWR",
-    "GBYRR7AFUGXKPLZYP7BIK4YICVJ2OMKBC6HUEQBL7RBFAWAYAGGWZU4J",
-    "GBYRXNIXIG2ZAESRTBGUBD6E54T4LOOWCB64LRARBKFVQNJZVMAXZBNB",
-    "GBYS3HDXCP36HQDYWRCBND2EI7YUTC7RM5W2ZDDXEIGXQG2IKTI6RUGA",
-    "GBYSIEU6N3ON5VXVIBPTSC3OEPRUBUBBXWR3QRFHPNBTLEXURUD6RMTG",
-    "GBYSVCFSMXHIGALQSYZ6UL7S2JK5YVOGU3AJTLT2VXFDFGHAAU6QEY4X",
-    "GBYSVZCX3BX7QJEJ2BJAHLCS7F27OLP6VKDFFV4MF6UGWBWPGKNCAUSA",
-    "GBYSWJFVMD3IHE63PLMTTWGTJGJY47HEDU27CNJ5N332ST2I5PMA7PIU",
-    "GBYT47BLRES63ZRREFAALLUBBNH47CNGSWYBGCWHBE4X4PI4MK6MZSR2",
-    "GBYT6MO4T447YF66COK75IMUYLLUJXL6FUKDHKZTKDGB2W6FXSKLYUBN",
-    "GBYTOAJMDCDPWUYIJZS2Y6AZBGTETHUILE6SOHEVOKUUXHADOZG5NRLL",
-    "GBYTYGOEQZGYZQU2UCDXG6YM4D3OLVTOXWE76WLDEP6EWJGVS7VTEVN2",
-    "GBYU2RJZ7Q264DKCHFTYC443WNSFYMOFZYOAOIBMLOPKN3QC764KZE7N",
-    "GBYU2ZHZRVFPQMFYEUFV3CMSPW4T2DTH7GM6MNXHDMEHSIDRJSYNU7LN",
-    "GBYUDQMAMLEB3GCHT27SV6WTOHUBMPSZQUY4XF2RGUXPGOZUXQ6OAAG3",
-    "GBYUJAYAMJXNOLMFHXRG7MUPKC5QZYD6XTG2D6T3TSLWIQFD33XKSJ32",
-    "GBYUNEJRGCFQ7UJKVDYB2SIT2XH2KHZPJKFNJALWOEQW6Z4ATJUNKXDZ",
-    "GBYVCHAZGR5I6BB4G5GYJWVAZCWFBX54ORIUQFISNG7LR33Z24UWGUKI",
-    "GBYVEH4E4SNO3BLJI6RL32XQNW6G7E6PF3OPPTEOEAE7IPB2BAPR4VMK",
-    "GBYVYDMVHV2GWNOQ2ECMUJ2GT5YZTYKN5732TJG45ETWVCEL5TKLOOWU",
-    "GBYW36YGINE7K6TWDBF4LGXQ7SNDVAIUSFXJU777XXCPF5MHJZ3IM6C6",
-    "GBYWAG4USDYOVAYYZ3FTUXNAYPLYDAME6SHSWFMNTINK2HWP4TR6ZUXJ",
-    "GBYWHNVFJBFULHSW4H7TV7OLAHPZTDAGZQ4HZWXXXS326DH2HITHZOOJ",
-    "GBYWLJM5FN5JBWSCNCXXWLIGIWWHEOTETUQKRMUABQSLK332VWHJEADM",
-    "GBYWLQMN64XDMCQK7ZF4QHXHHFNV6UVZBIZU4XIPNWOD74RM6LVSEHXN",
-    "GBYX53WBX5EN6XQMGRA5S7DXM7QZXZJLFSDEM3KW2U5IS4GRQJUVZJPA",
-    "GBYXEGCEEJKFXJCAZHK7N7A5PAKLB6CIQVRF4DA7BK4ZQEK44CTXGYYM",
-    "GBYYHYPNMROXCY46IJGPFQQC3H7UZQIF65XKQEYRWW526BMX5P2ISEV4",
-    "GBYYMP2NRROSSDYO2NZ75F6MNZOBFCH5ZZS4H5UW7BRRCGSBXCOMGOLD",
-    "GBYYPXAF4ATBGY4TPB4ZVIEYF4DLFY5ESIPBGDZAJNR5OUXWT6COLFVF",
-    "GBYYQTZLG32M5U4A26AQVR7572B7QOEX6PXNI7K5ZPSL4XDITK3PJJRA",
-    "GBYYSO7Z5GK6JANDVAM5CFBVHGFI5BMUHBLRBVCKFBEYQ6BU7MINISQL",
-    "GBYYZ7V3GLOJRILCGSFCJVDKSMMJ3HGQZXCLQE6G6Z6VIQ25OE6DI2C3",
-    "GBYZLEJT65VIZ7PTRJVGON42XMSH7E7FAVJJZPY6L7PEJUOR3GGPAVAX",
-    "GBYZYHJRHOK2JR7T5YLVOMGALOAK5HQOEDPRB6NBRRX6USWFAALFGOLD",
-    "GBZ27YDNUDUXKYXAVAVVEMDJW6KPX7UQZMVSN7VTKL6RRNZ2L3H3WKST",
-    "GBZ2DUYEPYTVEJQQQ6NW7A624SC4AAE7WIHBXYOKDNYC6WC4U3KRQY6S",
-    "GBZ2EL4ES2JQ6DEH7PHBFLMNLRJJOGIDOJMQCYMF5OPNOH7VDXWLLQFS",
-    "GBZ2IXPKQKIE44IDHL6MN2C4LSGTESYMWKDNPIIBRCM7XDT4ESM573WZ",
-    "GBZ2UEZLDD2RBUVHKLFY23GTD2LAPVG3SER7CBQV4FBILLZEBEDR4GUF",
-    "GBZ2YUSO43GPTGR7OTSSELSIDL3GSNSYOGRVUNBYLXVF55OTAXGRQZTI",
-    "GBZ33Y6XQ2GKOZDZTQXCOGJLVWIGTPJOMLBV4SDGRTIUU5GA7HKDQADA",
-    "GBZ354J2C7OF7CBFJASNZMCGHGO6GJOIAXM7YRBAGX6TOJA6XXNILDI7",
-    "GBZ35ZJRIKJGYH5PBKLKOZ5L6EXCNTO7BKIL7DAVVDFQ2ODJEEHHJXIM",
-    "GBZ3AAEHA2N6QBA4ANZVLDOGT2OMUBCZAG3SSV2H5W2ESH4XN5H6WXWK",
-    "GBZ3AH3UTLPAULCITGCYNYKXKIV7VKY3RD5PSC43NTIQ6ADYW64HG3HO",
-    "GBZ3B2KKOTKFLYTKGU4I6Q7F626VYGFE4S6LC2JIDKZBVRWNH5LMVHMO",
-    "GBZ3ERDNDXBNPXAMA2NPXW32US7CFDRYMEJ6H36SNCNEA4GYWWCM5FGA",
-    "GBZ3KV5W4DCOGF6PPID3MCVDROH2W2MBNNFNC5G7I4P24YPYQEH5PUK3",
-    "GBZ3V6D52CYV3AODTDXJCILBRRU74BPOCEZKP7AXLEZ7GOAEZGO3CLK5",
-    "GBZ47JQGZP6GYX3PRCTBQUDWURG7M5IZXAGDKIP24QEEZZ7JGZLZEGTK",
-    "GBZ4R4SWIXPDY2XRQWX3L3ZCDBWEQLGMLKMVCBOPGR2U5XEKBOZZTCGA",
-    "GBZ4WYFN2PJYRS5ZI2E5URL3RSK6WWKBKKQFW3TCFFHGQTNTHOAI4TAR",
-    "GBZ562JT35KVRGUSIXZMXPTPIOVMS2VIYVXTKI3EOU65VY342SYH455G",
-    "GBZ5EHNIAG3Z3FDGHUMKMKOV5QGA57UGIYEZLPAKRE77TN72FYGHEXLM",
-    "GBZ5HER63YQ5CN4N3LJO2TYJOGDYGW6SMM246XVEEWG3SQJKYFC26USA",
-    "GBZ5S2GQ7TWXASGLE5XT4A5SUGDGTYP3F724MJGMYXG2N44XVOP5R2KJ",
-    "GBZ6IJ7RPZVWVZZMHGDH43DMLQT24QOFE7ICXIWX2CYMC5AN7JFOLEKR",
-    "GBZ6O6DJ67HTAW7I4PJF5HKZDZTTNQMQMLHQS6GU4P7PTGNXRGSJO2OM",
-    "GBZ6YMMJXVV75TFZHDC5NEWPTOKQOUONM76OZ2DDJAJE75FYXXORY42L",
-    "GBZ6ZLS54IRNEMBI3DVZPW7XIKIEG6DQ3TZDULKLGDIDRZIFLPBCUHSL",
-    "GBZ75HUW4UBBWZW7NXJSVFL2NOCSGW6B5FWNDEA5CKL4BPGAI3DG7NHR",
-    "GBZ75NV6L2LUA5XLMORCLQICJ5SCPJKICFRLIIRZOS6BCAHW7A7QLR4A",
-    "GBZ7PP36OI35XREY4HPZ3SYES5RDTIVYU6HB7WUQBIHN3VQ4TOS4AEJV",
-    "GBZA22PV3L6QITIHC4F55IOF4AFD2DXPKKX6OPVJBHX5RFDVK374GHFO",
-    "GBZA237FPCEA62NEIKTZ2IXVJ4W2KPULPW4YCLZGWTQVFHTTW5YYKJVV",
-    "GBZA65RX7KMREDRIBTEUFZYV7H67ENLXOVZGRXUTOENHAC5STFJZPF2F",
-    "GBZA6IIPW2VV6NV377E5ZDCZZ6A6LE4T3XA5ZUWSWDBM3ZT5L3H4ZQYU",
-    "GBZACUIZF5R6C3N5FQPL7WWUDIKCAF7SP2YRHAZJSNPG53HKJK7UNL5I",
-    "GBZAMCQMI6AJ6F2DKJUARYGBR7I4RZF5H24QWBQMGQY5RLKZESW6SFTG",
-    "GBZB6PBPSD7SWI2BPPFMHEELS5ZF76BKTBHM3Z7TRXMKCJUHEGGSTOCK",
-    "GBZCFP6ECFBN3HPHCMRF3TTZPHDZBNE4XUTB57QNLQF5JTNTTT4OWO3H",
-    "GBZCJKWDWT67EGM3QUF4CBILL7OMBMLNUMT4ALQ567Y5I4UDUPGRJKAW",
-    "GBZCMRF4WJRJJQBIUS3JAZRT3BST65XS5UANHZSSFHUWR2UA6LH6MERL",
-    "GBZDXS6ZB6N4BZMPWFMEGLSDCQOLWNXSGYOMJMUYXX23XSPHAUHRMXZ6",
-    "GBZEHKSBSO75JG6XBE7E2LICFI3MHUJHVYFXUALGN5RAVDZU67UYBFND",
-    "GBZEIEHAQCT7ZD2EJGDWHPUWNLGLSLKKU7PQGBQAUE6LJTUHWOP22NYT",
-    "GBZEM7YA5OQ7OTFGD6LXSBD2NNOZRDROJMKD2XTUYBFHCOJDBNLABGSQ",
-    "GBZEN2KIGXBMKPBXLYFM44HDGYZTC3LINZ4BPD3TDRMLGKRU2HCHQN2D",
-    "GBZEQ45ZWI55UB5CT42XHFO75UQFAZQO4MEL2VYDF32BU34QTOC4HRPG",
-    "GBZEQHIH2XPGY7VGIJWRGZ3QWTXT7P6H2OJK5R3642BG7CKNQ3EWKE7J",
-    "GBZF6YSJWS6EBNCIKNHVNMCMKSAQ2LBUYTEFXRB7LHUW5J22Y5APETHW",
-    "GBZGOP7E7VSGUYDEDKYNDTGO26ZDSCTZEJF5BUZQ2M74MCDAS7T4KJFT",
-    "GBZGRDXSN7HEBS67GIOIC4UPUUZJVLBC5E7VK45WNHEJFYLC73LVHCHF",
-    "GBZHAPOI3EADQY7ZWVUWRQA3PZO3S5NHQLKKE2SWNFHG3FBFT2YZ7TMR",
-    "GBZHFIKPZCRJQN5EIRDS64DIDI3REM2LC224GRHYRHGHTYRGMJXINDUS",
-    "GBZHIDCE3XRMFITNQBI5UPXBVHZBD7AKE7VCQS7OVKRTM3Y4TUND2H2W",
-    "GBZHIGN6X3BJNA6HJ4KCF5TG42MCA4SN6OEPFDTJCVSBGFWWFQITW32L",
-    "GBZHNPE7WJM7ZZXVVSDVJ6DQRF5DSQGULGTTXLX7PREHDRBOT3WGOXBG",
-    "GBZHNUWA5GMKHXALIHKIMOYPMTAD6RFANYPO5GEQ7KPKDFOJKUHWYCM4",
-    "GBZI6YZZV2OM5SG6OIH3NJBLTFP6L4NFPFXCTYNMVUFYZNZF5OUVYK6N",
-    "GBZIJD72LYEWT7Y5POW3JYCAILIAEU7ESYNE2OGAHHJ5QX47OOCHIV4H",
-    "GBZIPK73JU47ADZBETTOTYZ4SBDNQ2IKE3EQMOHIGH2FZ4V7BFIR4OKP",
-    "GBZIT7KR6TJZJH3Q3DWBCSOCNXGY4NA5U42WBXWMUIYEQIN5UWC3HGON",
-    "GBZIXIDX64TH4MYYO7XHPMDKHDNCAUGYCMIXIL5IU64XNVVRHZGI2NEI",
-    "GBZJ642NHUFFYEOZPOTYU3TJ3YTJIBLKYDD2QQZYYF4N76ON53HT7QTM",
-    "GBZJVFQPW2ZVRTHVL6VXXKF3DZPJUKUDOYBLL6PWF3VNHW24X3WCBZOR",
-    "GBZJWQZFDK5U3RCUFWOZIGKB2M6ZMJM5U5RNCH4DOTGKJSFCJVN24IKO",
-    "GBZJZRE4GV6F6M67DZQX32JPAWOKVMOQXXMUPQIQ6KUNMZSEM7Y4K4AA",
-    "GBZK6RHQT6RS3NLSEXTE26VTMRVUIZCNULA6UXLC2HKZEBTATJZCBITB",
-    "GBZKAPHW52IJEXC2PDHKOSI6TTFB2YEGBSB7EFPCXQXSABOYA5EGJPWE",
-    "GBZKAQWWI4RWMDDZC2KOMNKKBROUBZT65DNGDPXTZH2D5SE7PBKRLIZN",
-    "GBZKI4K2267H6DHUFGFUTNGHOM2EG3TBEX6VOFDQ2AOHTHNNJ7ORPCBD",
-    "GBZKIBZDLLVOME2OVSML4H4R4X5FYSUZZZZ2MUQAXLTW36QIQIQKGOGL",
-    "GBZKSYJFZHHI3IAFX7RQUIVHVDOS3DTEVVRQEWEV4GMW3AV7LZMDGWKP",
-    "GBZL3PXR3DHPLQDYTPNNNH5DGGXUQACPYORGRNDAMLQ5HBLZFWJ2MGOP",
-    "GBZMQVSWW55PCU6Z6VED6Z4UO4HRYETMWD2FTDBXWZLUR5KCXJGSR53I",
-    "GBZMY7H4B3S2YY5RSUFMBTYR6KEPAWCU5C6JBXPGTTAIXQQMYAJPMTF3",
-    "GBZMYKPGRP2G6X3TI4UFPCZK2E6TWUL2YCZ74VR55RPYYBLNHWZ26AXA",
-    "GBZN4PIGLZCCL7FSHDUNL6RMZCXS6THXMDFNEJ5LCFSW2S2VFS22WPZM",
-    "GBZN5CD3UFAFG7EYZBQ64TBKH6SOWDE7GGFRIHXY7KB7H7ICAZ4IAFND",
-    "GBZNTR6XHTWAF6TCFKXTYUCKEH4AKL7FQIIELZD5VBHQ4DJZQCE74POY",
-    "GBZOMVBP6KNP77VGXDGRFXGSKWIFVUGV6CYKCLYO63TJUDL7KB5IR7YE",
-    "GBZOST22REEAXRDH2DM5SNOD2ZB3KE6UGPEE2EO45EECE3RDBST3WRFN",
-    "GBZOT5YQUMZRRXUDALXEFZ2SEMWAASRXMBORLG6OK4BPQYV2SXCXRHY4",
-    "GBZP3HJ5DAVUX6C4OK4RIU4SY27TWCUE43VUFYNHKROMZMHDCGARY52Q",
-    "GBZP5UYJZMNLBOJDVTPJRSHJRJGQZYWWCFFDIURDO6MPPM4YXHY4ZNXD",
-    "GBZP7LMVNL5MDRSVTZTUFJNH6PEAM657BTJ2TZ2NUM2KKY2L2FTHZC4W",
-    "GBZPYJF4FMUXV4BK3L5TS3HLNFLQDK5TFT2IQCPK3PNPVEMQWTN4CDFY",
-    "GBZQ2MW2SRDY45TDRRNNXLLXZQ6NMSD4Q45P5M6XWTAYVIPD5GI77BUY",
-    "GBZR5UEWSN4TCJL4CEMC5ED36DLOBLFW62UWGFOOBRS4VVMNHJC7U7KX",
-    "GBZR6QPJ3NQE3WRAZCQCAHI5YSLWTS6WBK4YMY7T3FANSWTUPGUJNHOT",
-    "GBZRRS2ASFHPAZDR5OEFG72O53GLY6B4QNPJLCOJ6K675CZFAAEG3XOE",
-    "GBZRUR4TRRRBSUJJ4SN3EEW2YLTFQLCETGDRZ3BBLKT6TFPEOS3LJRLM",
-    "GBZS4L2M3G4DZ4T3HT6SQVUBA3RMPPQB575KZ57HTUVLVQYRG2HJEBUN",
-    "GBZSFMYLRFOSVZJSPXAARWSGCNEG66I6NSI3STFQB6O5NKFBLOQ5L422",
-    "GBZSRQZZONMKASXFQWXBR5BLNEQBTSD4I5A7HJJPZIGUHNDS472TWSIO",
-    "GBZSU4PHNAWB5WUFGWEY6YPQK63AZMHO43SC6DC3YLJ3QINUNCZD2H6S",
-    "GBZT4TBA6RRKVRNBHO2FXJNKMGZKXSXTBYLTQWWMML54WCFB3EVTIYYY",
-    "GBZT5XOY67OMTSGXX7652EEW7GHOCOVC5R6TSPDQQHKEVACUE7FXWCJF",
-    "GBZTHUQY5GJB7GRZJBM7JGP663QOGMQYQ7AL5P3MFXOBNXQNAFRLX3XA",
-    "GBZTQAVLDOGOSWAB744FQYKI62CMEB25FEDEF2UUGOVRKZD4WZ65Y7TE",
-    "GBZUFA5RWSORV2AMPIVFWCRSME2PIQQSJ3AV5NLQG6NSLPMQMTH6MNNR",
-    "GBZUKASYXOKP6HAHVKBIUXMOCMATNHUQACTXZYO3TMQ7VMCEZJKMKAM7",
-    "GBZWFY4O33NVVQMOCQB2IXEGY5AFU5O4BUNMIW4PUOQPCT6BMVSQATJQ",
-    "GBZWKE5XWAFJ3SJ3SQQVRZX3QU7G6RWSDNP5ROC7ICGGUK6OG34XTWBC",
-    "GBZWVWONQZEB2SGVU2CKALMLD7FPQP53L5ABXPQ7KC6TDGI6SX7Z5VHY",
-    "GBZXHV4X6EGW65YFOSRYIRVHHO5IMCYWQSUXZMRRUZPZAE5PKXRGXYPV",
-    "GBZXJ6PAX4PZCPA4BUOKU7OXJAPCI3I3SVPJOPPW35KQSWZX6MEGTOGX",
-    "GBZXKU4S6HQZ2F5MVYBG65MXTI6UEW5RX5SZOFMUINSQJPSG2FJLW2T7",
-    "GBZXSTSS2ZIUV7QVX4HCVEIKOKCEBEXR72UWAPEFNWCPCH7NRTOKHBAR",
-    "GBZY54K3QEM5NV7PCZEZ4EXCT7ISVZMOO5FO2YASZO2ISRPUHZNYSJTU",
-    "GBZYBX5SSNDRLXV74CRFNBRJWX5M7BZ5IZDM3RN4OT7QEQ4UVINJDOTV",
-    "GBZYWZEFJ3D3P3W6FWCSX7VVIZQCEG56HZN6ULKXEBMRYGUWTJ4IAOFC",
-    "GBZYXLGEGFFPB4WWCY5LOOLR7QGEPRVGOMIHK2QIEFB4Y3GDS4PYYWKM",
-    "GBZYXP37HPUNTGBHNY4T2XTV2YGXWJ3J67OEP2S7KSIIE67ZUWNTGIIT",
-    "GBZZAZ4F5XNHDGIVHK4MXXMOJ5YJE5RZVAUW5XUIIJBSAS62VYNCCCG4",
-    "GBZZMT6TJ6HYDLK2ZSGHT4FXRKP4RUVKJABRK7NFKKMQ4AJI2MR4IEGP",
-    "GBZZSI5HMUZP7ID5337NSZDQ7PDKSGJQAZYR5ZD3J6YBHIS33NQH7CPU",
-    "GBZZTVB57U4RN7FQYOOFKZAB4HR6KUFKQMRIRAEDVWTMXFWARSY53GYX",
-    "GBZZYMSPZ4CSEWSCDDYBMOHL2NMD22CBC4AOYOVQZ3HOMQYKP7JJJXE7",
-    "GC224LDACQ34QDJVCJRBEVHGG4H3GHFRINH336M5V44BZSKOIWWWMDT6",
-    "GC22Q3MWEWLFXCZOVKR7U7PERSHXBDA25NAEKDXTTRU67EX3ZIKSF4WX",
-    "GC22QAYP7XGVAMRIUBD43BZQQEBVGR2KMWCSJGEAZ2XPYZC3CMTWSEBQ",
-    "GC233QDU5OFVR2NWBRQJRNEIGL4TYIC5ZHNC5HQPYJELA6LVWJQDB4UJ",
-    "GC23AQCPI46V6NPBV5ELQIIZJ6AZTBNXAGDLKL7BFSFJPCAMQHMLZOUX",
-    "GC23DKWAFJDGP2ZB6IRXRJCAM7DXIZLLXIK6I5JL3R35ZPGCDGYPL4WW",
-    "GC23GFUP4JQFO7PYJYR7FZKACDTMHRA3LUOSWUA7WAED5R4ICPKIA4Z4",
-    "GC23ZKFWW2D5MAYOHJVZKV5PN5QCCRAP5OCLYENVBMHHHWNHS74R7PE5",
-    "GC24D4FIFNYV5VL5B2KY3EYSOTQ3I3J3WODXDVXQNAG4JO2B7IMUFVET",
-    "GC24WEUFTYY4OVWLCPSFO7NVRMGSVTSDVWUGOKGI6WXMD5KHHUGZRWGI",
-    "GC24Z77EGY7BBKNWSNRBIFVM5M26JTB57SWHL6RUSVLCBOBP6KIZ3UYQ",
-    "GC252IXMZIGVZN6AKJ4STFN4XHSA7RSCVYWBSZ3YRWPC3IXWJ3BJBAQL",
-    "GC25GM4WXV7CSZHMPAXHMIBEZ7N24EWCJNZB2OO6NIGM4HNNLMC6GO5X",
-    "GC25UXOQN3WMLMOGL74EXK3SEQL7X3TKPRBQ5WRP3GCZKRXFLXF7RNLJ",
-    "GC265HNXHP4LY6ZBSQXHXDI2P2LW3APNMLYOREMA32C5RSL5A67JDEFN",
-    "GC267GQG27JR3SELTFBWIPAAVDGITSNGL3UXQPQ2WPFFVN3B5KLMOWXE",
-    "GC26A3SYITYFJQB62HW7OYBW7ZQVMQ7GHGU3KKGZSR2XORBJU3WS7XF3",
-    "GC26Q6DEDCLMRYNOA55OPIRCZ7LWQSS3QIYB3NQ6QCYLCMB73O3735TM",
-    "GC2AJKWEMNGN6TCHIHDT7OVNG5CCCMO32I45WGDEEYUDW33TOOMK7OQW",
-    "GC2BCLCK7X7IZEISSBN47ODA3NWZEY4EIMHKWD3LGRUAGSBWTG4JYNCE",
-    "GC2BEAEUVE22AO4N4JDTQWIJZ4VQLLPL36WRAFRKZCTXNPSJOYF5RSAP",
-    "GC2BL27ZVVTXLH6N22WSGZODH4T5ZKLTJ5TBKPTMPYN3FBTIDA2YFXV2",
-    "GC2BSABX4SCXVNCUNWX2DW3SWR6I2742FZ36SN3OQBGP26FWEYCPVQKD",
-    "GC2BXC27ZW2MOY34PGSCJM7CQDUNHHBTEW4OQXT4MDU5V5HSWLRWQXA4",
-    "GC2CNEITAJKHU5C2YQPMEFTIYSNQSUDIOVUVLG54ZM6TCUQV7EK5JMF4",
-    "GC2D2GU3B2NMU2N3H7EWPB66OC4MUDKHTQNHQEXZKKGE5NXZ25DVFTRX",
-    "GC2DFUCXVRBKNN5XAEHNMF65RSHRO4JBYDBDZ2S3LOTQYGRLJITJJZ5E",
-    "GC2DJG4CK77ATZKAK2EPQK64PTLTY3FSOPS44NXVMDLGBVOI5BDIYSHX",
-    "GC2DL55QDXYNNACNHGVQRJ5ERQ2J2ANNC43Z74EH2LTTJ7NFFL7N6ZJK",
-    "GC2DZ26MUNGYISHE5QTKOLBYS44LTNVSK4SPO2KXYHALGXARPDNPBMKG",
-    "GC2EK2EEDH2TN5DDJHEBWOZ3BVKS3RH5PC2IBNS4PFZYOZTXFGEI73TL",
-    "GC2ELJ2TUROMBNUSU6BOYWASSVR5DKP7N27MUNAJJZN5JKMNX5OHW6A6",
-    "GC2EVRZJVVW2VYEPV4LH3M6QFF4BWHNHTCCY3SHKWMFA4HMXX4QNNQZL",
-    "GC2FCQ3P4ZAVSF24N35ESFA5G4FSLMS2T3KKY6FLWAPGNCGI4N3MSQPV",
-    "GC2FJFC72JXXCEAJMDV3OB3Z7DNZDNJ5G7XDVQOKJGG3H6T6HLV6FDSM",
-    "GC2FTDY7XRKGRHM3WFKE2LMRJMO2DRF3BQTOE4NDX7EQMYQXJTLNEX36",
-    "GC2FVCAOAR6JE3BIIOLCTI4LBE76YI7S4JWD7IMDU2ZEGRYANDQQR3Q5",
-    "GC2G22UMK4LQTCIWS4FRIEXQUK33MHEAFMEWL62AZ3DIJ5X2RWLQIEXC",
-    "GC2G3ZTJCQ4B7ZVENYSOJSPK7CABYHDQWQW2TV6UNF2SPTCMFECU5QFM",
-    "GC2GKC2CEOTOJO64HRASCXJNOZAVQH254ULYDMH57UGRVBD7CYPGPLAT",
-    "GC2GR52F4U6SS3VEOKR3IPRERZRN3RZOMWWFHAMYA3G76XFLDMO62KMD",
-    "GC2GTKPIFI5P3DMKNSMPOAOAYCGGOGHCBRA7YQFPBZFW6HJ2SSCGILRE",
-    "GC2I5SWXRJ4333HHL56QQINN4DNFXYOQ4244A3D32JJRNTQK2EN5HUJT",
-    "GC2I7WTLK4CUSMHYBYMM2JSUGIF2JXN37XAARF3OPXY3DDPHBK4VAEOC",
-    "GC2IFKDQYOOCV6F7P2H7PQAEW2A47RWU2Z24RUBBRWM3OT6WJOGUKSFJ",
-    "GC2J2MTSQTZ4THQ7OKCFXWG5KS5SV5KLVQHIKMGN3QGJPXYR5RR7SVFN",
-    "GC2J2ZALOEIFKGGGGOW5L3ZE2ILYJU52UKV5OWWYHMXEU6CHMFXCRX7W",
-    "GC2JGWAMDBW7EOER23S56JVRLZXLYSHPZF24AG2ZT3QWB66N4PWTT5DZ",
-    "GC2JLCAZL6H5ZTFF5IOWNBDKBGHWY4I2J7YOY2DTTWB676WVINKUMZKV",
-    "GC2JO5BJB4M7JEXOAQJTGZ3YFCESP3HWDI3EG5HVKMRJZUOJSTLVL5K5",
-    "GC2KBBZWTILF6FZORYY5MSM2JSHS54UVLXLO3TZ3XBYJ7Z5RMRFDKFYE",
-    "GC2KKTKGHPJZULOYR3OXZZ4RQFOMHEETOBIPFWKKVM6IRMAG5YK3UXOR",
-    "GC2KKUF7D6G3FIC3EHRFJHMXICSZI37KQXYLPFPXJ7HN53B5GRGMM56E",
-    "GC2KO254UVE3372YFZKVRCKOXMAFBIOZWJXHGL6XNE66Z22EXMYRLZSP",
-    "GC2KWCSOL6VEW5VUEPPYXGYK5QAWRDQNLCOF656GVG3BFYPPTR3GQ4SQ",
-    "GC2L2QRN4ADNSIN7KC2P4N2UMAE4S5QGEIAE5ZPVMHOHBTSNWZDB4VTS",
-    "GC2LBYM2YLEY4T2POUUK3TTMCV6VRLHDBMVUOES6URA63GIWDXTX2EUV",
-    "GC2LR6FYRPYIUEYK4ZFCYZXG2R53QNND5NC2H2THXPIW524KRX2ZELON",
-    "GC2LRCCZC27LQLZRNOAEBNGYDD3DNLQ6JADO56GFANIKR5326NVITIPX",
-    "GC2MYCQ7SAIEJYBXKSYNZXYCX7KSGYB2SDTTA7OQZ6TCNOSQBXPHME5F",
-    "GC2N6BTTO36PTLBGQFYQJEPOVL7FZ4KSM3RANOB4U5P7IDQX67R6AROM",
-    "GC2NSEL2YYTPXCLO56AIP2VTAI6GTM62QUHF4NL4ZRMRQEQ55KJK2N7F",
-    "GC2NVDDO2IEX6D2X2MDO6M2LZWKJDFMO2KQ363ENK3WG3AWU5XRF53WC",
-    "GC2NW2G5FRR5GDVESILMKNQO5XGBBDZGMJCE5UD25MUQWMY65LEDATPI",
-    "GC2O42LMIBZNDZBEKLC7UGEI72C5Y3527S4KFIKYQE7L36GMRQTTAQ2U",
-    "GC2O7NDIWV67AOMMBHIWDJS5ILM6G6D5TJND6NWXJXZHI3XLE27XXSHX",
-    "GC2OEAGDUZAKL7R7ZTWYZUE3ZN7Q6AUHJRYPH3P7VMNFU7UFLOEQBTCD",
-    "GC2OJCHF4ZC5J5FUBE5CYGYZFE6LBJVNBJF5UU3FDJTTHTEJW3GT3NEW",
-    "GC2OJU6AOGKFWX7RQT4IZV7OAS662IV5DAVLEPCEJDXJHTO7B7HMF4BZ",
-    "GC2OVJFKKJ4UN4H5TPQZLBZGZGUNVBWBHBURRZIFKNNYWT7PFEO3AH7W",
-    "GC2OZDCEE342XC72PWIQCAYKV4GFY7XTF2P3XQORMWQRQKHHBBHE4ZM6",
-    "GC2P6EMHBXMAWQIPQVCCOZTYMKVVHGYUNEZZYK32LHGJBCTFPZY6S6BA",
-    "GC2PCFT4GQZYHWLGR6P7HLQXQH5RM4BHGMWBUH6736SCVVLLTQUFNJL6",
-    "GC2PDUUX4IAEMVLTOP5L3KT5TCUFW22SQAF47X5CWCBX2PRGYW3OEALR",
-    "GC2PJ6J37QM3DOQ63DVGCXTRC5QOKAJUBQO2XKBXOJCWTCOCP75KWUDX",
-    "GC2PTN5KYFG2DMA3PVWJVB4MPYXB4MSWYEWD7HW7WVAYPTVKMBCZWXSL",
-    "GC2QCUG42SSNMA4A5OYJAMIA3L2SQECE5C2MGPBX7CP5JD6USPULRNHK",
-    "GC2QLNXRQR6IQ62PXMHTLDCNCIFGPF5KZOFT6KAIRG7V4J5IF66Z7EV5",
-    "GC2QMCOA4WVQWSGH5TQJM5VNGX6OFCMNALEOYU2GJGT3ZGUB4EM4KPZB",
-    "GC2QOTQINNRBHI6FCCJQIIRNJRVNEIP2GS5V55VQHBFCCPSRWGFKU2H4",
-    "GC2RAEYKGC3TMBBARO4PXHIWD45EYBNTAHZCJX3TV3BQK3FBYPZ7LLFW",
-    "GC2REQ4ML43VFQH2ER4VDHHCZCM57WYFFY6GZ7S3VIFJZVG5SD5PZLWU",
-    "GC2RTLD7643PFRWTWKPERGF7PFGLIJ3PZLUPEY4WKCAPNAY4OWXVVVOT",
-    "GC2RTOV3ULHKI673NKL3GIH4AMN6LUA3IJNUBGAVHUNMGQJBR4NDHXQU",
-    "GC2RTQBUOZH57SYKMISCY3ROIXXTCIRGNI72TX2M5QZZLRFLJIXDIXHX",
-    "GC2SH3P6AMP3DLAAJTWJRKKN6HLLVXRVO6MUBUTKWCHURU7OHO2BYIKE",
-    "GC2SH7EOTXREKAKONGZOCU3TX7FAZVJJIKDXWPBOADLQU2AHCDUJX3QL",
-    "GC2SJDRGX3OHQEGYFZEWTAUIOKURBY6BQ4RMLCWY5OWNOTQOCSKFJXLM",
-    "GC2SMWO3GYCDPEILRD5QUJ5TDON5TLXD2TBOKVTAE5IRBIW65HSZ5MQY",
-    "GC2SXC2W6GWTHLCRFBVJTJQGLLKJRQ2N2AQVHFAOG5YY3K52JOZ2AD7H",
-    "GC2T6LNH2AMWEVHRKP3P6QEXN4YIU7DTBFHYG3N33QR4CIZHM4KHGHDJ",
-    "GC2TIIUCU46UAQ6BPRRNSG3KYYANQ2PSIKNZD2LWRAHIVTN5C2H4BZ5Y",
-    "GC2TZ63MUMIF74YE2543DQY4DDABRAG4M4FJW5MX3HBWVXFN6VMPPBZY",
-    "GC2U2QNOEVIOJIEN4IRPDGKF5KD765ZAAAVPDH64D663HJ7F362SSHDS",
-    "GC2ULOIYKFWHTDLLCC35TLHN66ICAFES4QQP55FW5MYKSVGYU3YXAZJI",
-    "GC2UPJMDPYVNVEYIDQXYC4Q7R6N5SJZ66U46OTUYQZLVXT47ZREGELON",
-    "GC2VHYH47XOXXAKCPSHQRDT76LFEZRHBMIFRQ2MQ7OSPIRPROGW7XBLK",
-    "GC2VLIYANWUGSUSJDLVQVIOXQQ255SOCVKI6S3O4AQQ5OZ6LQLDBHRYW",
-    "GC2VUIZVXLL2LUDSNL73WDOFWQDK3ISZUAEE7GZ7EPMBISP5K3KEQUMO",
-    "GC2VZHILRX75CC7CLF3EYLCF6G3U7ANY3VW4OK7BVWAXKUVMKJLLOIEX",
-    "GC2VZISYJY2YEPR5UCWG44EB4MS5BLLO7VUCUKMDX7UR6UMOVJLWX3WC",
-    "GC2W5XMPTR3ZMJ4TBNCNAGRR22NPTYZEIYU4WUFWSQKMLVJSSVP3TFRX",
-    "GC2W5XPRX6WFVXJHQBBNBBOS2ZSXSI6RGQU55DEVZ2D33Q7BG3TY3IJ6",
-    "GC2WZZK4GZQCYYQPJPNSSSCWDOIETEBH6VT22MS5NTMI6REKDFX4H3DM",
-    "GC2XCCANS2ONAQVP6VQHMQBOKLONWKHI7X3CPZQ35KGVEEWR2TFWO4O5",
-    "GC2XKVNAD6KM7CCHBMYDANXRHCHCW2O3HQPLR4DZHLSRGWOB3OH6WIJ7",
-    "GC2XLW2NZYDNF234XE43AI572642H6QUJ5ABDKBN6MRPKKBS2IUIPTTG",
-    "GC2YMNOMSWUN6S6TNDFUS4UQAX7JZ3FHCIPLSJ5WANWBH3BCQIJXZZG7",
-    "GC2YXOJHUDDBKGOKWBIIH3NJWHKZJGEZ6CSOMNIJTWW4WWPN3DVTYJYJ",
-    "GC2ZISY27J7AM3ITSWXQM3WQBGXDSOL637MOEXYARRWXBE5OKXHRUTGQ",
-    "GC2ZNE4R5ZBCN6D3NZ46O2F2K6M6F3EKQNNHRSKEWHKSQDDVZXXEQFAZ",
-    "GC327EN6ZT6ZKJT35COLSW443AJNR6KQABGV7B6E2FYKFA6QB5AF5WSY",
-    "GC32WOKUMOFFGTZNWNA53YCCRPL4ZMIH7B2FFBLFDT4U73XFFXDXF6RO",
-    "GC32X7Q2NZV4GS6HCGZI2Z5CJY3POJOMK2HKFBHWV5ZRCKCVKQARJIOB",
-    "GC337WYSGEI3YH4GZNGP2GIZTBYRFVH76B2ZFFACJY5MXCPBE2QQJ6C5",
-    "GC33BFON55LDHQN72ZUTXHHVFJSQ4U7UHJ34XR65LGLQF2TQB2X6MUOC",
-    "GC33CVWRNQ5Q63OJ65CCSGXJNZFBFS5ICRJSE3RLNBF4NEWXU3WKNHOZ",
-    "GC33RPKIMQMV3KFEHJVS4A36BKJXXEYKA4LCX24OQXZNBGTXLCYAIQS2",
-    "GC347AR5BA2VLEBXVE64US2UPHG2PD6JARNCKVZFVJUIBBQOIYG7H2HJ",
-    "GC34H2ASDACCVPLB5EFKZ3NVTQSBJLCKAZO4CMIIUKUP5RP2FWCKPLIC",
-    "GC34PBKXODWJD5DVW2VHZCS2PQQPYPSVA2FLWVQEL5TXQJGT72JKB77C",
-    "GC34RTQVQQVDT3EM7ZNJK3PZR62YNG5X6NEGBET7P5F3G6UJQVJP3SUS",
-    "GC34UDKVF7D6OQE75Z2PMXSKNA5SUBVKYFELCTPSNEF67WHANBPTS4BI",
-    "GC3556T3U32TMJ2BUFWF4ZYFXJ6TSDUITOTLSGXTS6BCKBSY7ZF6WEAH",
-    "GC3657O4QPMRQNR3PQ26NCVDXRWDVL5W5ATYHB7ZX6E74JS5KOLQTK65",
-    "GC36JYAIHTT2BI62VP6JPK34QTA5PXDSJH6YUXCQW2FA5ZYCNR6OKHOK",
-    "GC36OT4TKYPDNIJAXMWXMUMHK2FJXJUC4V7X3TAAP4WDEKJB2DKQB77Y",
-    "GC373G7HCLOZQVUEEXORKRWAMRY7GGVDVC23CVEKLHB5QN26FCSZRJIH",
-    "GC37LTVZYCJ2M5ZJJAQSKP6FF7PQTQU2ZQHMJ5OG74YL3SSXWVOG2UO2",
-    "GC3A5UOP42RCE5NATVIAMUKF6LBENIMPEV2ETWUQMR6CS6L2QVAW6WFE",
-    "GC3AFALY6TVKNAQNFQNCJ6YUW4KH7JV7BGC2QFZ5OETBTNQGESONGWZT",
-    "GC3AOOX7Q3DBUUC2RHY54A53RVSK4FYVVF46ZUDSSI44VN7K5ONLENAI",
-    "GC3B2GD2WVRVQBKUVG6TQWWP6LRZUJF7JPWZGIUAUMH3DTICEZ5RHACK",
-    "GC3BFVM32T2EXIA6M5E2TNWIKZCRALMKZRFXKMCGJXIRJECS7AUUF6TA",
-    "GC3BWPNCNAJYEM735DCQ6I7XVWRBSJBRCC6KJRVWQRAEPH7F4BTTLBVI",
-    "GC3C5L4WE5OYP2UAQGWLTASVMQCKJ7OJQHVOTVD2APHKWYGGXYDPEBTC",
-    "GC3C7RF2DCUQ3UIRFB4GR5E6LG66PFPZKUJOPOWWVMA2P54HVFKKKPKV",
-    "GC3CF6AOA5YNNX6T5DLC3F76DFPRRXNSCZY2FNPHX7NTO72IHG4TX7IB",
-    "GC3CJ3RXODJF36IODTNWMEY7AYOKVGTDFUAGOIXERCCO4WYVT4KFJJAP",
-    "GC3CYJBWNRGOI2TKDJNAVP3ITAJ2BCZTURYF2VUTK23QUIMT54BLBYR4",
-    "GC3DJ6FAOKIPXAQLZQU7VQ4T6BXASOUR64VWG7NI35Z3GT3CSW6UFR7E",
-    "GC3DLNWZSSUBC7EFCNDX5MOIW2E4XOMDX4DDYIKEQE2D24TKZXPNYMDH",
-    "GC3DWQRVASIG4LE3MFDKIQXEY7HK4UT47GS4GMXHEDCBWJENMFFOZFRH",
-    "GC3EFYUWXK7RHDAFTRSVXMDWBEFNYUGJ2F25TESPOPEKVXZFKB4KCGLI",
-    "GC3EGL476Y3KDG7H6GJRXRAJD2RFQM7YF67TH6LVZRC3SSE5H6TNO3QB",
-    "GC3EIAQON4WNCKPYWO4M4NQS2OKP5QZTBPMYPXPZDZCV57IIV75ML3OC",
-    "GC3EK32UORBCAEXLNFRLM6MD43GKNJ3E7MICBMMVRJUJXMU3VQEWCTGX",
-    "GC3EZKB2WHYIL6NFANUCIATMXI4IPZUSOV75IXHYFLL2H6R5FRI4WL7F",
-    "GC3EZO5RBKBS5OPH264JOVRXLURRWA3DGEQOHNCPQ74APZZHCMOA3Y6M",
-    "GC3F4NWJLHTGIXQWTUFHYKZIRIYHP4AOIUY2YSC5AEVZI3GSVD75FVUY",
-    "GC3FI3NVZHPXT4Q4T7ZZHYTBXED2L564V6IXMDFZ6S2HJ2HL44FIGOLD",
-    "GC3FSF4ZDWPAVBLHBORNID6PTU6CYFVF27L24RTBI7CIIDVZ6DURFF3W",
-    "GC3FTZ46KELZO6VPHA6VMUTFA2CAWBNXVLSUQEVQGQ4MM5JU6NS7MJSV",
-    "GC3G5ELWH6UR34V3LOA63JYKJ3MILZILEOALADO4BWNDY3ERKGDVCSMT",
-    "GC3GZG5IQMSFHZMR4FOUYQFBIKX74LDMQEQKFOCKSGYZNNC2ZK2LV5BH",
-    "GC3H24R7CAZ5XZL4C66THEDT3CFY6SNJJ7AMXF42K227NCZHHOK7OCB4",
-    "GC3IGWJPD4LTR6C6NQ5YRA4WOTP5JNKEJBKKN46AD4T4SRPORSIZX5EI",
-    "GC3IPRFI2BLWQIVCKZ5IV3RO3QNMYH5ORP6F3QH24LSHNBJHNHW44ZON",
-    "GC3IVUVMQBDUA66KYYN42RT5RMI7WQWGDIDJA7INHOJMPC4LQD2BUOIQ",
-    "GC3J7K4NVZI4MQL6OIAVU6YG3JRXWBQSM5ZQNJ4XJ4BQCL4WPF6QGU3R",
-    "GC3JJP2OUM36T5ZP657RPTWEYGYPBBPGRSVZ4PYR223FLSJPAUN2Y4J6",
-    "GC3JKL4YTNBULUNPNPA6RWJOERF2WZ3QSNMJQO5GDDLZGU2TVJAQ62N2",
-    "GC3JU7MWUVFHF2JCRMFL3SGO2NZC33IX7XVB6O3BIHP62GXIQ37AEKVO",
-    "GC3K5QN2C4ZLZE44WGQOZ7VABK4ID25HELS3VEZCKEN22YV5KLXYUHIH",
-    "GC3KGI6RPC4QXYFFL375VPPVUUF6OJ6BOC6TADD45HHZXOFX5RIZAU5Z",
-    "GC3KLBKX4N3UBT4CT4UA7OMKLVQ5EDHAIOC2XB4NRW65QWRC3VYX7UC6",
-    "GC3KLRXKI5YKWD5L7CZSHCG2IPQPCMNKXM5XNBHOTIYBQZO4Z7NHEN5M",
-    "GC3KRSDFQKCPFT6KGPZVE54TIYW6TMTGAPQB6BYPOCRBJPUCDBMYSPRC",
-    "GC3KSWE44G4OFIK2M4FEHKKMBQXXEG56XUYJPW7LAUEMUZWC2MW4QMJJ",
-    "GC3L2FLR2QMUSOJ6QMQHKIWUKEXGOXSIN2KY72SDUHPC2KO6HOSQIXIF",
-    "GC3LD2AETDNAWPGYEPNYKANP7GSPOWX3QVMFGKDTHPOPKR5X6OYDLES7",
-    "GC3LHO2MWUKL5N2UYJJPLWEB6IXSMZ627TZCEPH4CNHRRSZAURHLK3Y7",
-    "GC3LIXRX2JXCQ2CVA3GC5NAUNBX32H7RVDIFVBZSFXUDJP2XPWJW7ZBE",
-    "GC3LYLPIIJLSNQRGROXPA4VSJJKK6ANYKV2JYHGAEHYEVJYQRWUYQOWR",
-    "GC3N3AV65MKPVE5BVAEZS67CIWKJVHY4ZMN3U3NMAQUMVLWGWOA375VB",
-    "GC3NPLXHCPEH6SFAULYVBETJZGMXBECUUZVKHINE63EZZM5GGD5JYJRV",
-    "GC3OEQGKLQKS2GTSLABDVSJJK4ARQTCPPBN5SOS64JE7WHBHQBAYXDIY",
-    "GC3OJJTAQ6VF3VA2XLCDUKQNKE7KZ7B7RHNQNNRN6TWJU35N74ZWCULK",
-    "GC3OUI3TTTKW3XSAOZG4GYN7ZUHV5UHRAM6A6TXXHDZUDV3BSVAKLXG2",
-    "GC3P5LDCTRGDESMEZB6MX5ACHXWZN42RK7EZTMDD56YNLFWEYWYJBPIX",
-    "GC3PSYK6IEFPBXWM4NNJA4LLJJQNJTF2H7LLM3DN4ISZWWNEJDFLBAYB",
-    "GC3PXP5K77HTVNOUBPETYV64JEOFOHCNEVQAFQXWNVNYVMJK63ZBT5AT",
-    "GC3QKIBGHDNIORWVKBVR5YMVD6VWXGCJAA3TYURMMOCPEQC7HWCFUGRA",
-    "GC3QPC36AYHK3BMZ3GODPC45F7UNX5PYL4NI2GW7RWBUFCPZW3XB2UJO",
-    "GC3QSMFEGPLPFUVIXWFIYX75RHFIV226QEV674R66YXDSVOJLKGDELON",
-    "GC3RCHDYULYJIRPQ6SWD4PKQLDNWEIVSD4OLOXUOUKELIQTI6AMFYZYQ",
-    "GC3RZ45HUQ5ERHSFEEIO46SSO34SSREBPQOUMET4RRTZP5VF77WTB75M",
-    "GC3S63KMANPKAYSTENGV2LQXA5T5WXMYEW2LMVKX4DXY54GSHBKNQF4V",
-    "GC3SCJGAK7QU5SG7LV4ZWDJUMXFGP67TDNFCBC6E6UDVE2VECGMWG2NX",
-    "GC3ST74Q2YNRERXLGEGYP5ECE6CM54GIRC24OMAXB76JRCAJJVEHQO4I",
-    "GC3T3BWSBCKFU6D36QX26FCS4OF35PJ7NE4U6SBIUAWTGI2GBXJTWTZM",
-    "GC3TA3WEMP7RE6PRAJNUXXTMIXCLMTCK52NVMHV4C4OSW3Q6EXTSEAUE",
-    "GC3TBHE5L4QZ2UTADAKO7V43ACNCOMH7KG2BST3KSHKDIICITPSQRSM2",
-    "GC3UG5KPN5ZONESTGC7HRTAC6B63OBSDUE4I6UFGJXQRMCX5NHM2XGUV",
-    "GC3UH7BS22Z4FUVVJJL3JBJGBM3MEGGYBTVLIS53EZMZXZCDHXO2D3FH",
-    "GC3UL2D7WALL75DK44YAYL4CHJCFMTFAHPJXD3K6DF4SV22SSJQVKXEU",
-    "GC3VAJ2ZOIWK76QL2RVOZAG3NWZXFP4TCCO3MHFAC7XKQAGREJI4M3OJ",
-    "GC3VJDRNTU334KNRYQ7VNX57JKH6YSAFNDJDNHP6IGC7WNDSK2FSB7MB",
-    "GC3VNS4BQCZZOASJLYGWFTWSTKQQ55FL2VSHXZ4COMWSG2BYFCLOEPOQ",
-    "GC3VTC4ERUQENCQQUMPP2JN4PKZO5BERANA67G7ZQIAGZDRL7CUY4DRD",
-    "GC3W63GQ3F5TJFROZT6MFBR55UB52LKKOE3J4TY47GTMGY6J4N3W54PA",
-    "GC3WCOUF52HP3JZHC3DPVMZ6V6OGXGBO4DNYUEUIZOT6KIMZCOOVZGGZ",
-    "GC3WUEB2VG24TWTJZD3DPB3TEYJPWLX7QFRCEZ4L3MNZFD3YCEQ2ZL2T",
-    "GC3X4GHCKSQEOXOKCIHBQT2HA34W7Z75HTW775OV7VOOXYJQWEDC6MPU",
-    "GC3X6TIA75PXPD4FYALT54VE4QQQ5ABJSFJEZXBZ3ULC5MHOMMS3SRAC",
-    "GC3XBFZNJAQGV7HRYTRH7AU5IASG5MHZITCXW56VAQANDDMRQYUKL3PE",
-    "GC3XG4GVCIU2LCLULFB25EPFO2F4TQ7NZF7RQRA6DPP34ZGI3A5KHY3T",
-    "GC3XUS2C32AVVDCRPIUGSORVPUARWXXDK2ZYUJYGSSH6HIGJPV26UUW2",
-    "GC3XYTBSV6FSVUQYV6TEB3PJFPEE2LWIBFRIBPS32GVXE2H4ELC6J3S6",
-    "GC3YC7DMGUPP5X7BQR2PKYS66IAMUH7DPCVMGMNZNDVDGA6UUFGCFUAI",
-    "GC3YGU357LDODMFUIF3NXVEHLV4UO7SFIEAODUB5MAZZ27OZIHKZQFIB",
-    "GC3YUPSKEFEJGTBDJ4VI2DBBWOJHBWT53MFHJIDYTUBPIG4QA7LGYUKK",
-    "GC3YYTAITQUI3CR7XQXZ333EXPW66ZJ5M2VETXLPOOBEARJKFA52G24W",
-    "GC3ZIKZDORCGHAZQ4NJIJ5P7JKGGSPJGE3G5H3VC5MD3T5ZYLDMMXFRM",
-    "GC42UGRQUCCZP3XESLPKB75XSKJMHTJZ6B4YYJPZFU5SM5ITOOY2SAMD",
-    "GC42WRTH5DYWA6QGSVPJSO5JP2XD6PY4ZUZIPGDN3FFBYIEDXKRGTGIH",
-    "GC445SYEKOEHFVBTPEVOC5M2YQ4VUZIC4KTVR2RYYIJPUSSNPS3WK76Z",
-    "GC44QCGG2JKJJQS2DHAJBRWSKT4HYYJLRD36X3XA5OCKYP7MA3KU2BNB",
-    "GC45237X5J6WCT75LICTBUK2FXRNAEUSQZ72UFH7UBXAKR5Z5ZS3W6RK",
-    "GC455HGVSULAK3CMSEWVZS7GEUXP5HVH5TFJOBTSULFJOSM7XP3SCTGX",
-    "GC45C6YMIJ66RY4WDMXXKXQLSCAZVEOWEBA4SHTVR7F5UWN77YOJRB5L",
-    "GC45NXE5QVDE3T5MKK234OD32GLGK66MUZ4FXQI67SCWV7C7LRQLNDUS",
-    "GC46C2XIP7MNCIAQLZX7D33V3VQBFJS3GAMY724R7J2PWN3XJMEIC3SL",
-    "GC46HLVLEWMJQUAQHE6N6HRH6CVBPD6UJ77K6O5A5VBUJV3JGL2TLINK",
-    "GC46PJQORITQGXBC5JFAVWFNBAZDNCOYQUPMZVYB4CLP6AXUEGZQ57HL",
-    "GC46VG7ZDPJ2MTQJSV2SEOLVMUZTUUDFLODWJ2GCGCND2AQ2BL3QB6TV",
-    "GC47IWM4KJJX3OAIXOQ4OMI7NMEPCE6TQQ5FM7CVSQWUKPCSFZAOCVYZ",
-    "GC4A6SDCIXYRUFQRJO7N47IEFVGFT62U2ZTRFZTKBFVG73AMN55HNB7S",
-    "GC4A6UT3N6IGDL3Z36PAI5GY5Q7FV6HPSDIMFPEQP72VFQMGL23BSUYJ",
-    "GC4A7XHRNOO6QMKT3BLG45DHTJOSFFBEXNJV6ANFVO2FVZ7TTV3JUO74",
-    "GC4AHAFUYCPGFLE2RUKE2P5K2ZTAQGQ4BBUBTIBXVCPZ4H3RAT6CRX7E",
-    "GC4ANXBXQ67M7HV4QQ35CR5BHNSV3PCAZYPMGN2DYJRE25NSG2UQGAL4",
-    "GC4B7LOFRC3FOYUQ4QPGENVDWRNYGAWKXWFJXCXYV5NNRST6XLNOJOHU",
-    "GC4BKODQOOGNOPAESBGSFG2D4G5OEAER7PWV2C7QXKBIJBD4ZD3CDZTL",
-    "GC4BML3MZAAR2RFVTNUS3AZYGPKELPG4JKYCVF6KQ6BBBH2G5MJSLNDL",
-    "GC4BQUW2KKQAWQRZVDIKHDUMJRSXJPSQEUAKUFNTXDDWXESSFEWPDD2Y",
-    "GC4BUAJKOCR4EWRBPSSHHDL3CZYDWKRJZ7VISMEU3IJL5V7JUAOFHXFS",
-    "GC4CCLHUFP4S6XA4J3X3GTEMSV4QZT7JVI6EW3M74VCHKREOFYWAJIZD",
-    "GC4CUXFDMFN37BVSATN463AFXQCCXSTQOBAPZWTKAZ4N56TUQ3JSBXC4",
-    "GC4DBXBKNL6IGDICNNEAQ2JYWR6IBFI7WSKMCYJOM7PSUP7F3DH2EI5R",
-    "GC4DLOR35NSZU2E6JX33I2H2I7HT6TLNJGLTNSU435E5JXFVDVD5IYXN",
-    "GC4E7QQP352B6QU2KAUUYDW6DS2IWRV3ZJZXBNJJANHZ6ZDBFBXGB7HU",
-    "GC4EBUYMIUQNIEDN7GG3KPJFOTXW7J7OK3O4YASKPCKWOCCDHDJ2CGPT",
-    "GC4ER52BLWIM2T53ZGJIH7JGHOLWTZNZTFERKJQD265475M5EPHCBISO",
-    "GC4F77DQ5YDWROCRJDYDSJ26W66I7GPFRPWHLUPGW3FWBOKRUO4OVIHH",
-    "GC4FAHQCGKFNSGYWLCHHPMTWBIYSGU2R2T7256TSIWI24CCGA65DCQXX",
-    "GC4FJOI7LGEZ2H4BRKBOJDZXWGJ5XBBZ63F3OU2BTJTV5KXEDJ5RATOM",
-    "GC4FLRPJ6LCY7KYSYYSYLFLGBPXO5DM4VRXJUXOJ5T5G3TMJ5KME3YOI",
-    "GC4FNW5ZWBIBMFX6UOCRPNGUMV27N5GSUVUK7ZYQUIVTJNVITTCTCOPP",
-    "GC4G2OKXK27CNNC3U4V5FJMYGSPK6JTJJU2R4DZU3QBSMSFHYLMUTQ5F",
-    "GC4GJTXN2GLMREZLW2UBKIFO37KFNLX7IKWPVISVPJV4577MMI3NPYW5",
-    "GC4GOFGH25SZLUJBL4RXGBNHJU2MENRQL7NCL6MDQIJ5OBK4FEGFOREX",
-    "GC4H4ALXQO6USSMCL3SV3T55CGTDDCBZUM7C5SPB5KF64UFKHUU5SLYK",
-    "GC4H4RCOXDJNUSUHVOSS6EFINGODJZEY2YCN4NZLRP7ZLPFEBT2Z4UNH",
-    "GC4HUYPP2ET5HQX6R6MHJAXXZY4CW7Z35OLZDZUOHUYWSRRXQHTNYBLY",
-    "GC4I6ZD4XCCC2V6BSGZDVWXRETUH7BKFXBFZ73NKM3QMGNMGDJ5O6H5V",
-    "GC4J7N7B4OZTFRCKAEKGQW7SNMQ32O2RJLEKWRZOOKJCN2C6KLVZTR27",
-    "GC4JTSPM7IPT4XM3YPKY366ZVMZHDH46ZTS6G7M5YLP5GKPYMFH7HIFB",
-    "GC4K3NXW2QAKDKPINPEIA7JKFI5JCBMCLLJS2HGOBKTHGBSKBWPNBRJM",
-    "GC4K7BWWR5LMAC4HAMXBSTLPXORRNEQMQFYLQ7AHIKQ424HUSOT3XEMB",
-    "GC4KDQ7BKAQFBGEMHY5CG4FVHZZ3KFGHNPZ2VQ7VFUXJV3GA2FLRMKNI",
-    "GC4KZUYK5D2D2WTAMQEFOD24FJQ6O54SESFH6Z2BH5G5TVH32POYBXDW",
-    "GC4LYRUW5VTEGTBZ6P2CLAHBQ5TFR5LZ3B3QFDA3QHVKER3BQQKT42HY",
-    "GC4MMSDXAFXQVNGEXMQZNMEIV5Z5C4MLNHJ2ECSVRWMS3RLSGPHVGG22",
-    "GC4MVMCQDNSMZ42IRLFB4SS3456A4LMHFLYKPTS43NSZSZWISHMK2GJ7",
-    "GC4NJR3LGGP36YVQAJLLEEEUSSGWLSIXIGLNENFCWPV5ATFYRW77QXLM",
-    "GC4OE2NB4MPMOXIHKVQAUZIMVJDRBWEBIXHQ3MBXCLXC3XEA76CZ4PC7",
-    "GC4OJNSLTH7OOXPPAGHNU6BXAB4YQLZWXQBGLYSI7I5ZRCZS3PPBYW5J",
-    "GC4ORA7XTRHR4JH6JVBT5MZ6BPXH5GFNSB3FYQMUG4NY6FPFOBSVEXLM",
-    "GC4P7VX6VBOA524WK6PLGIQNSRMJJE4YMKEH2WUFIVPGRBC6VPDFFBCH",
-    "GC4Q7X42QHBKFESKYRS5HZ3DQ2ZOQNMB7SSUYJ3QN4EWFCE7TRI7HCWD",
-    "GC4QCYIS3KMWWQF2XVMDTTEK6SMGMYKWRQMGY3JVTYJHWE7366EAPQJH",
-    "GC4QQWDEVK5HLZZ2VOP7OBCZPC2CWTZUQTJWOWC5FW34JSNTIPLU2BHP",
-    "GC4QTXR4MDCDUUPZAZYA5GRYDK4BO5CNKNG76RT73OZZ2Q6UJWZJKYTE",
-    "GC4RATGMGOMXIDEJEJ2F6HSXJP4KZIYQSDUFCNKXBP4O4JM7VQCKHOOX",
-    "GC4RJODD3O6Q7GQMZHWA5CKWWRGNPJ2GYCAXSC7UFPNAPBWA7JARDXRP",
-    "GC4RPFVVXMTPDXGPS5RYCOL2HYBH42GXMDK2G7B75WDWTVWM6HK3S5PD",
-    "GC4RQX3JOEBWCEZ4HDV35VGCPDWOZWXKASF55M7SFER334JDWRZZN3HI",
-    "GC4RV6HXPHDNI33E2NEFTB6COQX6MZ3FAWPWH6E6NWXZJRDLU6K6PZEC",
-    "GC4S56NT4A5DTPSQ3LJW4OLDBZD6HV5SANMXUIN3T5OZORUHXMIAOXWC",
-    "GC4SEDZWOUZKW6MF3HV7MBYCNEZA2BNNT3G6NB5JXPMCLBFTGDYPDJVY",
-    "GC4TH2IWNAOB7IEWF7TGRT7I62S4YT3UJA6DM2S53DA5OGIR4H4HRQ2B",
-    "GC4THW7DXRCY5B7OSX7BVZRJDB2ZR7TMH2WPSN4BSV65HLHL53MVZP22",
-    "GC4TKKOINXE47RQ6XCG652JBLWRAEAV66ZCROXSMTGT6LP55IU5ZXFEJ",
-    "GC4TTOPOLUCRTQDS6RQWU5RIIKWIWSNIJ6SLETYBPPBRWXMYKXLMAPJ6",
-    "GC4TTWMCQDAR7WO2DWM4O73GNF6Y6GMZH7U4R6SQZ4EKD5KWP4K5EYFT",
-    "GC4TXNYJREHBPUCRPIXOQIP3SZZH5BCQILF343UHVFJX5VXJE3ILTYNN",
-    "GC4V5MW7MDXMH55W4AB3DL72HXGIDCYHF5LSQF6QO4ITNEECH6XPDYXA",
-    "GC4VD4VFCMZ4CHS3YHUOS2DESK4OJREKODGJFFIJWZHULQBB6OQNLXUU",
-    "GC4VHUE63GIK35K74BLY5D5RLKWYYARJ7C4N6FLXFL2SKGM3PJF2T5G4",
-    "GC4VTECCDFAXTI5POGUIARMJ3264LQBZVYIZK4QTEQ2DHIME5JZT26ET",
-    "GC4VUA7M5QV26G3R7XAQWRBFDF4LPGVID4PJFYPWRQXYVK3CF5D57QFS",
-    "GC4WPCIQZEV4IBQ6UMFFVVKADJIZILOVNBI66FEKVHJW7KDF66NQPVYS",
-    "GC4WSIHS6STNBIT2G7ZWABG6E2EG52PRI4TRJUM2O4Y5YWKTV4PO2UOM",
-    "GC4WUB76FDOU6K2GNK5MGOCJPC3CTKSJDHLVHVYUXSYHYQ6BEMABIRLZ",
-    "GC4WUYN5EP6DAZ66WQMY3GH7KIXEIBW44POUUJDMQW6QAWW62ALQALOR",
-    "GC4WYYJRHIZ4POEB7X7COQVSQF2TRWCR5AOCC7O4MPVLR3GLJGZO3DWE",
-    "GC4XCK3EWO25IMFUPER6SQK77FMB2ANARPUPIPR2KYS43Z2EY4ZOIOPU",
-    "GC4XLMKCTRVU33U2F65GWJTHBYOBB42HKAZCHYXJRVPMBSPFNQBPXLSR",
-    "GC4XVXW7M5PCRB5D6CIQW44EUJKIWC4345JQVW2C7SUNOQEO3DTIT3IU",
-    "GC4YMNVVOMF2IOPKGCZSBMWAT2JTVJ4PLSCV7SOVDSNLMC73HMUDDRQY",
-    "GC4Z2TDXU4GXVLHOS5P5SU6HKBCP7NKN4TJ5ZGTVRBW7MCBZTU7SNUSA",
-    "GC4ZG23JWBCEW66GN2PJMMJ77MUL2W3RPR7FTUAJ3NYZBUJRHWBHMCTO",
-    "GC4ZPBCTSS5CAL6KYXOWJ6KUJXYUHK7UOVTCOBO5OB5EHGZTYWO3AE5D",
-    "GC52JR7S4D7QHHZQKTTVWQ2WXL7JUE5IUWEO2OJ2YXB2CL7FKUKIARFI",
-    "GC52KAOLARN2FLQX24XF6G3VXE6COWPF2H5A7HYMTFCSHHBLWAK5HAAE",
-    "GC52SQIYR42P5BKOP55KJO2GUXZCNECPQYYIDFUJX3TCBNNOCAAVTGTV",
-    "GC53BE64M5YYAFQ5PQEM5AZTMVPQJ2AKUUQ4GI5UJ63FLLFKJJVR6IRZ",
-    "GC53KF3YMXPUZ53T5JG6GUTIS54DCZJOZW6NG76PIKAFC2OJSWFPKXRP",
-    "GC54CCWNSVB2EF7LSTCQ35C4H7A4AIMHARQAPHZACBSRXLVI2UJAYUCX",
-    "GC54LKQQBP5UCFX5A7D6YFAVKSARHLTRLD4ZBQCQOWBRM7KM5O3FHWAG",
-    "GC54YD5EYCJ3PTZQN6IO6DKPD2MBAZLLDNDONMTPGZUZHBWRYRHYGY2X",
-    "GC55DUZR4DWUN2N5ZQIZKXD36N626WIOFYPMUUHVFKPLMCGKHUPWVCJY",
-    "GC55FGIBRQET3BJJDUCGDBSV7B7HCRK47ALAOH6TY37URZ2UWEVKG2H4",
-    "GC55PWRINKYUPX24T3ITB2TOEODO7PFAEKXWA2TNA2UFIBEIUARRBOJH",
-    "GC56G3LRMBRLPRWNERSEXVAZNRNA4CFBDAR7JBIN7MFHTAIMSORJAEMI",
-    "GC56JCMJJ6VZNGEIYDASWTZCXNK5TI3KQPLSV73DT7IHF6WDP5VPNOTN",
-    "GC56RU2UNREBETJ5KCW32GRKOPS67SIBMO6DY6F3CSZRMVUYBVX6HVYP",
-    "GC56WCFV3I2WUZBFCO4A7JSBLCMBFXKNKKNEUYF7L7NY2FL7QE7VPOOL",
-    "GC577H3ZZUK6MHWGFXA5A3JJL7HOD5YKPYQ2E7KA7QMBQWUS4YRSF3SF",
-    "GC57BBDV5ZR4PET5NMBY2XYKENUCW5U5MVHEE3VLONJ7LJBELWITY75C",
-    "GC57MREUJ2AYA5BJRXWMQ4PLIYIQEGF5VACTTONHPUE6M6XXE5JEEHU7",
-    "GC5AVCPPOALZFQIING3MBMX4ZTYRKQJZHEKBCNISNCTW5JGU3WFHROOG",
-    "GC5B274DPG2BHHLXARTM3SKDPDTURY62D7KTGHXCUXKLFVBL3735YSOL",
-    "GC5BFD44P2IJRQBJJWEAGMA5GBPZ4CNWKHALSBRVMQGADMIWSDGIU3JP",
-    "GC5BIAE2ED22CQDNJB3KTCPIJ7DWKJRN5B5PR5XUDPNN4QDUX5XYJXK4",
-    "GC5BIEAYK6NR5LPU2B533WTQMYHNZP5LUJXN6HIZESFQCLVSYZUYIOTA",
-    "GC5BLKY3RRMRWHN6CXEEZWGJLEC4DT7M6DVPVZPXHC3Q22W4NP6MYODB",
-    "GC5C2AKVN5HMTVY4XYUNU5HZS3BBGAMJ5FIEONLAVJAL5SPHTMB7RRHA",
-    "GC5C5K3WPRX6ZWBNAAUNRRW5KJ3HHVAGPCB5EWSH2BPZ4VJ4Q6HWFRFX",
-    "GC5CAP5LZI2GRLCRRCXFCPHJY5GQAJFJ4VV2POK5DH4TXLGDYMNMHBAR",
-    "GC5DD7ONPBJM3TH7K6OVQRBTY5UPSP6WIIX5HTQVGZU5EEZUV2X2XPXC",
-    "GC5DHDVXV4KSKJJOOQT6NQ3QW4JSMK3VWACPAEGEHM27ORPIY7GIIIA3",
-    "GC5DRW56FSRBODOL6ZC2LMWZR2STFMK3G6GYDJZUBKRDWMGE32J3LTHU",
-    "GC5DVLR7EJ3VETY7FMFGY3DRW3FYBDBWROD63Q7X2PZKBQ2Z3QOX3OXO",
-    "GC5DZD36RUZADL7ZHUPOOO64AW6L357RL4TNHHULYBJFSMPT2OBCIX53",
-    "GC5E67VZIA5QIEXSVVPFI5GLNFHDRXOP5XUYCKIGGF2U3IZRE7L42YTN",
-    "GC5EAKOX43MQ5JBJ4DZSO64TOYY3K3MYFLQRMWF3RXRVSXMVYTXTHJTW",
-    "GC5EB6BTYA5DO6TH5WITB2VI75TWKKY5B52BU6IBU6ZO3BU7FNEOBBFA",
-    "GC5EI4JK2GNTUHJL5PX73RKYUTQHRYXUAJBSAG4XZHCKV55ISEPLYHCX",
-    "GC5EM7M47MRVOMFXHJGEV46Q3WQDQ3NY63K3BNYPOJ7JA3ABLR7QJ6N7",
-    "GC5EPD6P4ZOAAG64WJS3RUVVI6YGRPKR2BRN3J6KDYOE234I3A4YXR6W",
-    "GC5EQG2TAWHWXIXWFYXMUTJLYELT2XZRGLCX2ZKEV234L3AJWPVCISOL",
-    "GC5ETACBJ4WVLAXGGVKIUILED6ELSO4NSMFL4PC5VDL4AAVWX2XNXUFH",
-    "GC5ETJUA2O2UVLX2QVREBUV3YBA2ILXJIFLXNEV42L7CDKY7U5SOF636",
-    "GC5EWKE7OC2V6YB72SF3F3KWBCRFXXJZFGGL6USQS2ZLCN6KSIWO25PI",
-    "GC5F32U3DAMDCBRJXT2TIRBLATVHYHRDIFLAORD7G44PONK72KESUXAC",
-    "GC5F5SWYGAGYXDB4BFUOX5F6Z27UXY3MF3ZPKC2PIS75WU3KXXXABBMH",
-    "GC5FESMGJKUPLTG4ORSN6TT7LO55U4UZJE2OUDEU7WVNFIFMEUT6VCAN",
-    "GC5FN3FHKVVM6O2IR4S6E2ZLZGZJXSPW55NTXVBW6ODVRBETEGL4N6CB",
-    "GC5FO7H33D6GMMEE7F2GQCCCAZWBMCATWR37VKZKYJVGBGKJ5GJ3CVPT",
-    "GC5FZC5DZNZKJTEZVEJQ53G4CBL755UHRREAW3S32O4VGVNTBWWJ4VSP",
-    "GC5GB5RBTBKYE4MFL6TEFPRM4VTFUTLQG6T4ZBTXXVPIJXNFI4MGL7VE",
-    "GC5GBB5RSJYCIIH55PP4CHDN4YEQD7USQWPNAXKAUSI3XCM2E2IQZJJU",
-    "GC5GDW4EUWPHBGNJKCFE2DIUGZY2S5ACQC2SPJ6VOO4P3MJH24L4WQBU",
-    "GC5GRENAUOBIZR7IYBY3TPCY4NKPGHBD2RADR3KJTXIHT5TIF64QHYVL",
-    "GC5GWBHZBGIWPNTFN422KAYOFUQTN2ED6I5M5L73VR2ELWCHFKWNCPOM",
-    "GC5H3FTLAIL4VUTTB7O3ZHP3LMLDDGRHWIZKB7SQQC3UTOVHTABU45IK",
-    "GC5I3JZXF5F33EGE3HMUMLD3H6GEDLUY77ZBWII7GXD7PFP6ZF6GVW53",
-    "GC5IFCA4WD6HOT3YQKZOTY63CFQ56OJFHJZUU4DGMHHGHMHEEIFB3KHN",
-    "GC5IG242XR45E53PB4PHBXA54EHKRXD2AVNW2Q4L5IU7WU4VIGQF2PVI",
-    "GC5IMZ3FFGFRB3DQAV2XVVPDNNW4NXDRTFVUHMJ7WFSFOUDDUDW3FCKU",
-    "GC5JEAB5OMGJS7SJNCWHVGKIDOIZET5KNOVPLWH6JCYKIYP2Z63QPXAI",
-    "GC5JFO2XSLNEMKWISUVSJGUGG27TTZE5A57DN3IV2BABQDJ5I6AQQVB3",
-    "GC5JTMNGG5U7MMKKCID3ZWXWSCTT4U2WDLKRVXZ7KEAUFSC5ZWWN362Y",
-    "GC5JUSTLUDMOPD7PUA6DR3VAKGBN7TUFUJEKSRRSR6RU6AXIIAYI5MAU",
-    "GC5K6KQGN3YFSDEOQ45OZYB3NHB4J3DA4DFQHDIF675XWJK24R3XVOF5",
-    "GC5KSL7BE5N7TOKTRM6JHT4IHQGIGF3GFVHUSDPXK2SNDWE2WE24ZEZO",
-    "GC5KZ32K5OAGWYHU73UPVR42RBL2GYYNAL2WNRMPIGZMBMGVHCJCTANZ",
-    "GC5L7OI67GYP7UJNUGCCHE5Q3EVSTOGDNOBQCA5GM2WVEE4HVMQVEXLM",
-    "GC5LEAEMVYL7ZCOMMDNDIY4WMEHUVXLVWCM6SHAW6TZ5RELAL6WRCZCD",
-    "GC5LHF6FT3GVRJM5MPLAL3MHYXYM7TPBRNZ4BHOD2LPETMM6FW5JEMI6",
-    "GC5LJ43BM5WKHSU2NZ43XNJH4YUYFXBZ6SZDXNWCFCT2E2ZD6O2KXONN",
-    "GC5MYHP6WU2NONFFKPXFJYZKQY6T5OLUAUR32OTAMJN6BFBDT4YEBBSZ",
-    "GC5MYO72F7OKIMNZ6YLB7FYTJCAWNMEZ5BWMA3BMCTDOJFPFX6WTALGO",
-    "GC5NAWUCQM7KLGP44RF334YR4SZNED4ZTJLPN7PD4WOY5Z3BPDB55PZ4",
-    "GC5NDK76QVA4FT6PHOL22VVFTNQMFDOXPAVTBXH5FG4VXSQ5FJZMVGWU",
-    "GC5NR3UXE2QYDQD5GKWPIDFW4MFJYYOOBUTDSUYCAZK7TYX3CLHMCU55",
-    "GC5NY2AVOFPJ5UFUWGREOBXBVTV5ZHO66F3NYNHHMQSYFM3VJSKHEVIS",
-    "GC5OKFTCCJRTLRUD7ZFXW4HXCH32YCNUYNVF2FVWLY7WABML7BZVBESZ",
-    "GC5OKTZKMK55R522ZWMQVODCSBGVEELI4URVFXSQUVZCS5N26NWWSXIM",
-    "GC5OSWNVMJWJVYU4SAXDWA6DWW6LSL27EGVDUCNGT22NH7XUQFKXY2XW",
-    "GC5PTPKOJTI3NXSHHQY3Q5MNOE7DGN2L7ZJNG7T3IITMJ3VQHE67J2HA",
-    "GC5QH3ZCJDQG5XYAUGUNIHZ2WWJ6Y4WULWDHYOFBFQNNU424LAV5FMCW",
-    "GC5QVY6EP5DKOB33D2WTWU5VCZEMMPGHYBYVSKRA4XZ4FFG6GSDPQTD6",
-    "GC5QW4LWZA4IJLQ75JGFMVBXKP6OEOQJCRY7SECAPHFEP2EFACZ7QZW5",
-    "GC5RYB4HDRQXL4IA4EKLO76HT5YZOZLU6CURZWIXFTTENPTU3F6TY64R",
-    "GC5S7E5GOODVBZVPHG5ZJ5DQFTQLGJANLRIXDO7YAXTD3MCZPM4YCVK6",
-    "GC5SBDT2KSSAWZ5OYH5NR4GROO2ERELISY7WGVZE3WN4VNVIY7HXNAIL",
-    "GC5SCPJLHX6WFLUG2GF2W57R4LQ4QUEJEXFOXSZTBSGNUD6RJW2ZX4D3",
-    "GC5SUIUUJXW7LP6CWWLNMJL44QL7VCF5GIWXO7L2A66IH6HDVRUYQETH",
-    "GC5T57QKBOUXP67QCJN5WE4CZWZWNFY7NGQ2QJLIMMVLMBZLLPYU2STD",
-    "GC5ULVNSKWDDY5UOLTXTE6TVPJJVQSG2F7QE7S737LPF4OBXZTUKG7BB",
-    "GC5URYCQXV536K3DVBGRFRANZDQYQW34ZSPZJAOYSPU4LMFZT4MMFIIF",
-    "GC5USE7F4ZHLEYXS4EXHUGSJ37KSC2OCD7C3ULPO633W6SKQGMOLV2GY",
-    "GC5V2MJLP3RBQ3K3BVXXHDZ5X7L7HQT4T3IZOGH5UPI5FEER35X7PV6B",
-    "GC5V2VOKC74CGKXEHBUXHF3LFVNQFAKGSXWX3CR3FLHAX2FFIHYJNQNT",
-    "GC5V6QQKGCSIMQBNQBJXSRWZ4PNGZODVEMBCCSMFPDPIZ4NX6JDETORO",
-    "GC5VSEIXZTSMJTXJS5GI43CQ4QCLLIFIOZGHQU2XJ2OFQ3WQL7VZJZAC",
-    "GC5WJ76ENL5TNEZ6X2YT4MP3EHERQ47SXFU6WWCZQVSXR5VGSYCMVGGA",
-    "GC5WLKUCJ3UH3V53QDOOLNEJZDOWTPFS3PGAE5SV5OFK4GN5AP5AHILY",
-    "GC5WROP23QBRJOGQKZO7IQ34XXDZXHQZ4YD5KMUHII6G6Z3G424U46GT",
-    "GC5X2P3LOSWT2GLJDVP25SN656H34VCNK5UK4R5HACJ3Y36W7TFMIZU5",
-    "GC5X46PECLPSF3YBBVZWNKFS44NAA3IQBMFYBY4G7WZMVIQJBSZROEEG",
-    "GC5XJLNN5TQQX34MQULXLCC67QWD63QRDL6PDVJGIGUIVOV7VQQ37YND",
-    "GC5XK5U2MMCPVT4HSXIQYCEPNFWW4ZNSUNXXXWIYTYC4ZHWHLJEXGEQT",
-    "GC5XYM76SEWZWNAFJMVLI5ACUIIAMKEL6RK2TQP7S2GVA325IZHOJPZZ",
-    "GC5Y5S4X74M3RON336TWAQGC5M7X5IHZMIPUPLDC7WEDV4FO2FZDOHML",
-    "GC5YGZAL52A7FZ4LSVPDZLWGM7GW7YCWPU3FMNESYN6JQ34S524WXSQX",
-    "GC5YPEY56M6Y77GAMPUTWGLKIS64ZJNJIAJAFKSRPIUPHUL3KBKI7UGU",
-    "GC5YRLSDII5DRN2CX6MUUQBCFNCLCNYOEG33PPA24AM4DCITWNET5JZJ",
-    "GC5YVK2SABXMZ3CX7BMX64FS52QSG3MMPUCWTCDVTQJRXXNIUL4AUF7H",
-    "GC5YVQPADJERPAA7HYVDKLAL5FUDY2OESXQUKLMTFSWTQ7DIIVV6YX3T",
-    "GC5ZKX5HPSU7FDRJ5DL66VYKZUVOACM5Y6IFFGW7HWZYYTPQPLSWUEME",
-    "GC64DAA7ACWK7XXMI4ADWJESLGLSGZ3APIOO5SUM36LTUHIUM4LIMSBX",
-    "GC64DFMHEKLN4TCTSWWO5WACULYFIKNGUIPGDGHPZ6XKYJYNQBK54ZIH",
-    "GC64G644DGC3TLYIVQ5B6KY22G7YYS3HCT34ERAUHEEBJJGOTVM6YNTG",
-    "GC64HGIDFDYLBBQCJC5WBUPTQIQT3WB4JAGR5LICHXW4NHSXYQJSDO3F",
-    "GC64LVXTN2VGZVMMQQBVYRAFMW6D5XPR4W2AR75ZDYSZ3NYFDQ3TX2XN",
-    "GC64XQKFJV6FEGAJJTNUDRDEYLI3PP74Y5F25RQA7RDZ42VQJ5SYELON",
-    "GC65KCTXRUMM7CVO4BMF53N7RAGWKRIDOTEQ44WB3VTXHQAZOHY6VN3D",
-    "GC664JAKKQPEVUDUHCD5Q4C3YX4JQ3RNDND3OSYB2JL32YAY3USAPHIL",
-    "GC66MT6DVMNRVFQTKGTHXVOCP4UVVDFDTLZF57YKMJJYAK4JMKVEJCJL",
-    "GC66QVI7ILY6ZUTUJ332PWFZDD5Z7XF23Q6FUKXD5QKBDR4Z4PUYBIPV",
-    "GC674UG24VT5LMUWHJRDPXLWBLYSSRZTENMXKLF22TF7ASHMDWNOKZAA",
-    "GC67HA7D5PUMTN5BPP4UU7RBDAHACFVTTS7KCTRFK2VD5D464AFCZTCG",
-    "GC67JQVH4WNS6UVCHIRUOJ4XU3XXF3UX4ZBQHQ23USDVQWZHPHJUX7FJ",
-    "GC67OCMNP4FRHC6HJQ3QXFP4J3NWXAR56R7UWN4EYR6B7VWP37A2USDC",
-    "GC6A7MUSD5MJYSUALYIGFFZJZJG7E4G7RSVISY2DX7CNTHLLFFNPSMCK",
-    "GC6AMGCIIJJR37JWM254GXAWXACRQA6Y7NCXEYEIUPZACJZJCA7TBEOY",
-    "GC6AR2SPXK6EEP65UH4NBL3AQHKLMU4PSPKPL3BA5TARTYUFV3WCOFJQ",
-    "GC6AXDVI4P7W4LFEB2SU3FN5DTE6RA7V6PHXNMVR56QMD6TUXDQEKUDB",
-    "GC6B5LOZYMEDYDONZPPVRXOWKP7O7QUPW2G4WYYJAAWKSEXXR7SRYTO7",
-    "GC6BF4SPFT42FZBIKXAXTY3DWROEB6WKIN4V2U7KI7NJGLHF2WRQGVTE",
-    "GC6BYAVZHRBN2PEYLIDPNVWJVEYEAUY3UBCED4VZXWTNJ5L4KDPV44SW",
-    "GC6C7XHK3OX3S3HBVJMXALCT7CGZTLX4O2ETLAHPPDA2X7PPLABOLVIT",
-    "GC6CM4DSNMHEWFDUUPLQXJJMTSJ7YIMM2QVSLEZKEP6RLA3LABQGTLE5",
-    "GC6DOHOH7ZMUSSVLSSYHYOSN3GXYKL7Y2SN5JZXBZNNHBMQRI3D4LWY5",
-    "GC6EAOJFGJOKM766YHIFKREOCLPCR4IPJBTZVDMIRLAP5GSHOIIG2GRC",
-    "GC6EAYPUCGNCHSFTHEAKZ5PTAQ3VMD4KHMOMRP52EDSXNXMFUQ5FDYCE",
-    "GC6EEP2BWYMD7WQGJ4S7PZU2JI6N72NBIHLRSHAUPTRW3GBXWMIP5JIV",
-    "GC6F3BWPXREHJHQEVTLDV3OCP4LAXD4VI6AKLRU6T5HTWH2J6GF3EDF4",
-    "GC6FB2GPEH3LIQGRJ5DXTAPEWKBJXDFP2XNQDBDFJUFGOPQHDYT4HJPS",
-    "GC6FJ6EII3FXOYSYLGVGQVRMKLGKCRLYS6NM25W2WWYQPDBOQRLADXWD",
-    "GC6FLMEQXIXKWMJIQC4HW5ZJCTBIZYGLWIXO62HVWURB4POHHAUE7EKS",
-    "GC6FQJTXKPXDHFQQKGDPPTUL4QLESUESOLUVMOXELQPNU42HIJ65IR3M",
-    "GC6G3XHDU6CAYPKLJWM2UJZITG7ARVRVEAD2AU73XMNR4QQPDO7BXI6W",
-    "GC6G7EYQLG3KTSXXXO7LCCS3AODIPEP2MRXI722KQ7EPW5AKWZAWQXDC",
-    "GC6H73UCX4WSQBSSS2RNM6YHE6D5OU7YRG2RWOCHG47CJAXG62GJ6GHK",
-    "GC6HAMK5TLI4GMSMU3LNA7CG3INAJK4KTFEQBCAWHNEEMDBDUHQJ4RNX",
-    "GC6HHT4CRLXN3MU4AX7NRB6UT3JIQUTEKKQOWV7VVY6M5RBIMCHDXXTN",
-    "GC6IEXATCZ7V7FHNJ46GTHSJLD2E2VGVWSTKPV5Q6MZMRTM5VJWY4DN5",
-    "GC6IMXMHKHJMLJVLDHJ25MJDMRDLMYPICF5ZFP3PJMBD3OP5RA3XDVFX",
-    "GC6IX5PLXDK45W74GNAFWV2YZU5R4A5USRDYDIAEEJEBAWW6EOE2WQNT",
-    "GC6KCIU2AYSLLHIWVEPXZMSWA5FRNDMRUOFF4Q6AXAJ4EJ2K3MMYE2ZL",
-    "GC6KDF6S6ZYCB7NWMR66JRDBTGK2T63L3DCMPFCPMNMSIFCK45EGQADA",
-    "GC6KJGH73S6X3CRG5D224J5CAIJWSJN7LYQYW4LP7JBHTFONT7PPAXAI",
-    "GC6L4U7H7OS34BCKMTPUJMVHRTCS4FNGAECC6UGA3GUYVJS6MLUGBZB2",
-    "GC6LMQ6UD5UTM55YIZ4GG552B5AVISE6PO3ORABED7ZWIBLSRLJVJVJH",
-    "GC6LRRTS5SSKTUD63FPJPN5L7W6SPTFGED7UEDDVQTQPQWBJTNBPRSNN",
-    "GC6LS5RA3KG5ON3XB74VVMFICUD7N6QMB2W7L5AUDZGHNO45LHCYQ3UF",
-    "GC6LSXVDNBZUFVPZOEIIT3IZRDPHDZZBKQEY2HD74V2PKGYBOJ4SELON",
-    "GC6LTPV4HM4IGAVXQFUVW2SZR6VRUFPB7WTWFPEL2NM7D7G2OPNPNN34",
-    "GC6MFYLYHQRQWPUQD5BQUTAXY3YEQ7MMOC75OFWCNHTJOODBVK4STOCK",
-    "GC6MGVQC72LVA4SE4A2KSPLYQAQJQO555TWXBRWHOOVCEOQD4UIPM7IW",
-    "GC6MSBMFQNUQHJNAN74BEB5OFFSQMWUOBAKDB3KZ7OUFRYW24ICLDS2E",
-    "GC6MZFJPHKJMBUKR34GWXRB5LB3WAUGXYVOULWTIZCLKI4AHSVIH7YAP",
-    "GC6N6Y7JIP7XZLITKCP7XTI7FCKJ36G5VPYKMOSJJTHJJ6UCLPCTEBB3",
-    "GC6N76HWAYR4GROSRS6ZYBKH6WCL5CRV7IPH3LSWMU62XKP3LXDOZKNB",
-    "GC6NJ3MKOTAWBDDC5BTLIGXXTHM4VMUDO7LXBQ3T7ICU6BBJEO5V2XTB",
-    "GC6NOUFAH7KCTSD7PQM5D4JAYG4H7R63R2JFV3H3SUPKZWVF63DWFZ64",
-    "GC6NRIZX2UIS6T5ZHZDY5Z7PA3VX6I4O6MNKX2UVGFRTDZ6YHULNW7L2",
-    "GC6NTN64CERDH7WEVGMCAGD4MHZF6IVGLZUY46SZOMWVUC3HF53NGTTG",
-    "GC6NUVNENAEWVAVXKX7X2AGSTGOKX7X5LPODFB372QQXYRO3UH4NFXPG",
-    "GC6NX4ORO235SGAFV3MCAHSE4XBAQNFAXREP2QOJWNELMK5U3JXW6XLM",
-    "GC6OA6B4RM6QC7VACOJNQA2ATMGITAYVO6BXTMSELI4K2VUSWIS24EPW",
-    "GC6OEWET4MVDUT4J4S4WAU27TASTCOPVHEBUAW727KECJFUJZL2Z32DA",
-    "GC6P3EDLX22HFAASDH5HI6WSAGU7BUYKFBKKQMSES5FIA7DV5X4OPI7G",
-    "GC6PJUP6MKNDKJUQZD4N4ZJ7O4QI7YG2Z5OG4HRNSXF2ERYWBSJAGYNQ",
-    "GC6PQOZVLB6X3GSMODKI5HGJ753PIAV5M7SAVQDP4SJOQQ7OWQ235UVP",
-    "GC6PSOITVG5KWEX65IATGG5YSBVAIWTXBRGFBQYXDXCRYRPBWLATVUAZ",
-    "GC6PYL6H3YTJRGJSJ4O5LJ2HGXB42ICOEXCTLL6TMHUAYAGBG53XLFZZ",
-    "GC6QAX7K4MHUAJ4K3NRI7CGBCQRRWNULYGUIIG6WCVFVVMC7YU56OOXE",
-    "GC6QHS7NP4RAF57PDHQWUN6VGGSCELPAJ6GWYS3SR6L6LL3RRIVJBARX",
-    "GC6QSKXMNMRYIT55SKMIANGHGPLE5ALVJC6GCWUAYIMAL7E6CRLXP3AH",
-    "GC6RNRCCXTOB7BSLNTV63HCLZUXCRLBOGWISKXV6RLVDM6JDOQDCNCPQ",
-    "GC6RS2ZM2JFERJUAXAB3JZXNGEKF6SJDTDLPPACRQGWRX3GP446QJJI3",
-    "GC6S7F6WD7UQLV6I37CVIKTWXVGCEQFMKXBCN5HJTIDDQWHCOKWBUYZP",
-    "GC6S7QDCY577VACZ57XRRBTHGODRYLR5XAQDMNDQG5ERMZ6VIMRFXA3Y",
-    "GC6SBB42JZY44NIFONRNSNOEVN4O47XN6JFBRL5IZDAZPNRJA7H455UD",
-    "GC6SE5VTDOIAY6PS6IOSFT2TN7566QL2MQJPVENFQ6BL4TEY73AT6G7Q",
-    "GC6SI2J4EDYEKBHP2MRR5SOKIP6OMKTP57EF6CNFNRZVQD4XDPELSJWW",
-    "GC6TT3E3YCBTLLFDASVKK5JZNMKTRZTM3AUFBHCGSYIIEFAXI7XHJH5V",
-    "GC6TYJ24P25A4QZ2HNBMHKAEQTJUWL47J5VSWYMTZKBMB22GKXY2FUKE",
-    "GC6VAIICTSUCOZKGHQ4SLQLPZ6RVDM4CYVLU7TVTD5RKULQYQH3DTL4D",
-    "GC6VFR4CS4P6BORZULUDWPPEGMI3SKSXUMX37EDNGKOJ2QRJOHSINDUS",
-    "GC6VSSVLXB5N7NGPJIRRNA4ZGYBJJ5LOMHFS5OTWNZ7D2QFKPOJJ6TWQ",
-    "GC6WNV2BDVFPBTUEU37NAW56BYB2FM4PYAOFIDFIGIQKQS2PD6GMWFND",
-    "GC6WWCA6IN5ZABS6RYYMXIOUJ6M2SO6ZKRDD7GJFKL6RAHHB4VPEPIQW",
-    "GC6X5L6XQN6JALZC6YKQEVCA67WLGXOB453I5C4HQK5L6KM3BB2R2DDQ",
-    "GC6XGBFUEDSEFZ2G4GKM4NPREHLXF7RGVRUN5KRVIA7EKVJ3HZPZ5YYW",
-    "GC6XIDK26C3TOBCF4AYHFHHBEP2RPKFJ5LFX7X7CTOGWXVRTHVZSKJHY",
-    "GC6Y3UUI2OEPRI6C55Q4QE3SZU2JAE2EMTV6MFIMFAHCXWP7EJM3LWWO",
-    "GC6Y4JECQDTXVHA5KOGKCP2TIIIXG34WONOIX3L5ZWF3RY3LKCCPU6IP",
-    "GC6YCGUYSVNSV7H4MTXGR4US6XASVUTDUUPL7XEXQRR7DME5VSXKX7O7",
-    "GC6YD5JD2NNMNC62UK34ZDJ7QQFXVNNJSMMPST5HLJWNFAZSNF4BTECH",
-    "GC6YHZUZGVNRG3U7JG7L3C544RXORHI54YRQXZ6WHE67664ND766T5GA",
-    "GC6YUCEN6IB2X4EW6QIQJ7EHG2QT3LUOSHPUCG6IZH4FVT25FCTSDOGE",
-    "GC6YWWPMIC4ZXFYYXAKT7NSZTHAE45EGRUWDXLSBWNQKI6FYCEI7RBTC",
-    "GC6Z4MGPT4DOFDTUV37CWXSOT74RKR7FBSXGP4SW2MBYSBSKWTDSTSLA",
-    "GC6Z6UJBLTJN2D2NAISC2EXTBZ7D5CITMKU72EM6YSEJDMCZ4DHB2DBW",
-    "GC7253ADATJYY2NW5PZ7HO7L4QNTHJOEVF426MYCZ2EMGPDI4KGTVH6P",
-    "GC72MN2IUJFJXPBCPFXVWM52XZLB34INSQDOV7XZFQOD56M5AAEGCFTC",
-    "GC72PNXA5HHDQKRYXL36I4VOO4ZISO7WZ2MCPGC6JR64HTTPVJKGJPRP",
-    "GC73CJ35EKIGWATQXWJS4UFDMGJNOXEOBK3QYXROFGY5EBK253TZIGPT",
-    "GC73RYU3HW4EI2MYRB3Q7FZI4BSNE6PMWJY2CZUVNMVRWPOC65ROQBTC",
-    "GC7463L52672MWVFQKOT6MVTL42THSM5JECIAC2U4VD3Y4ZGTLNAUV4L",
-    "GC74BLDY2YXM33AWANPY5ZUJWQC7UVZJE6OU6KT2D5KOJNETIEO2VPOE",
-    "GC74XDVV7AORIPYSLT6N7UEMRQOLUXPF4TGJNAIC2HZZS2HFCID45G55",
-    "GC75D5BXDCDDAAPS42RERWLN7YE6J64ITTPJP2N5HEET7HUCYLBHE3LU",
-    "GC75RU7U6DNMVS2VU23U7NW6U2YGXXE334ERHELLFN2YBKURIBF5YXXP",
-    "GC75WHUIMU7LV6WURMCA5GGF2S5FWFOK7K5VLR2WGRKWKZQAJQEBM53M",
-    "GC76IWB3PZQFALW2FSZOXUJK5XKFFXTSEWJTHYE4LH4NQNA6TGXYP4D4",
-    "GC76MFTZTRPPE2UWNG6ES5XFNJP3S2XPC4V5GFCHCDIFW7DEFPTQJIUB",
-    "GC76MXHCVOYBLEW7JJAIE6D4CLKPWHUT5HOQMN3WGL3RNDMFZTTL2BTC",
-    "GC77NVT5SPQVP6BZWYKMESIBSYCGOZD53NWZPBRQQYTZXTYO4T66TOFH",
-    "GC77QXVUQSS3UUZHOG457N5GUEYKGU4FKR22L3M4YFOGJQY2YPF4RA2W",
-    "GC77RFZT5LSJOB4ZOQOSSNM3ABMDVRWBXSELPQQ6U4FGXZBEVZISWNTX",
-    "GC7A2ZKRTPERLK2QTA6INSJTITYBHGEDWTQWRDBHNET6TKBGMEGNF6I5",
-    "GC7A3KX2ABS3W26ACGIMERYXFWBTUF7VXVHKJ4HEBYYWDBCUNH6SQSRM",
-    "GC7AB3EO2NEDAFU4HWM2N2FTQHTXYK2WXTGQXOARS6SRAQIREIBQRONW",
-    "GC7AB6U6LEVPES4O3JO7MMCWGZSM3RUOGPHWGY7UVRL4LQX5W5CDOMGD",
-    "GC7AOTJQ2UYYLK3EFNI7ARWLFSR3SWNORB7AD33TJ3Y5RZKPDYBC2EVC",
-    "GC7AP3OPWYM4KAKTSXGQZIIZSBWX7HEDK7BIEGCPUWHQB72N32EE6SQY",
-    "GC7AY55JUCX5QKZC2ONAZJHF3533HLM4ZB6BEU5EDK627UYMWIJAFCHN",
-    "GC7B3YGQFQN42D7VGNG7P4TTUQ3KLCYME4ASCCJIONDMGRYVLTSIBLUE",
-    "GC7BFTVV5C2VIUZPLR4IN2VV2XNJHX4XRFTWO7PGOGCJKFPQCEINRV5Y",
-    "GC7C4I5KEMQJTZVEHCONVVKMKDZ32OEJORETNJ3A4DCPXWZQ35JBQA24",
-    "GC7C6AIW67V3LB6OVAMQHBW36U35LFS5LRBEOTD6CGMKGPJSS7PEDYBI",
-    "GC7CX46YOYMNPDJQFU7WM6ONBPSQXNYCPKOP6LEYN3WIBZEMRHNAMQ5L",
-    "GC7D6M4NV7WLTYJEOI45V5SZVQXYW26XZ4IVA3NNGKFB2SKWLODKXQM7",
-    "GC7DKIIGTPIPOCRQCDM7ZL5E4A6X4JOW2TUHRB7RPMD3F4JHYJS72YMV",
-    "GC7DZUKU5QF7LUQWDCJEDYWU26J4I5QMJXI7X2PT7IOQQAB6FOQNETGM",
-    "GC7ED3GGUHPV32UHMAC5LDREOCB7F46OXMQDBS7DRIKLDSPLL4T2Q4LT",
-    "GC7EMBUVUIYRNBT3MMMEEUZ2DFRYK5PSKAFI6RONOC5DJJFADPWLHVIB",
-    "GC7EZS5WB2FP3HVHQANYAQAG2LN6TTOH2GDAAPV3YNNG5ZP6SWJ2OISO",
-    "GC7F6KY5FJCRUWLB54S5EL46TKDYRGJXVCJSDPWTM7AVLRC22HQEN4GY",
-    "GC7FABWS7OABOQHNH6TA3RNUUDGO5TWYYQUMCHSBQ5ZOOI2WNW2D2IBM",
-    "GC7FKZT5WEUDT3LNRZNPTTLMS3JBE42RDOGQOMC5QNNKPIPYOUNTHCPT",
-    "GC7FR2TOQ6VWOY7NPBB7ANYW6FU4UAPAMR6YGIFXTCP7RLYVREMU73IY",
-    "GC7GHAGKRNV22G5BOJ5FQUF7LKP7VZ5HBYALRSSPGRF3SOBJMNOKDEER",
-    "GC7HAXAYY56YLZNUSK5JYJNGZS3TO7BHVCOVOELEGGVPFALBMWUG6ZKJ",
-    "GC7HY5KHEHHQVVQNWNY2YUCJU2DZVVLXZIX2MKCGQLQQQ3YTHG42Y3A6",
-    "GC7HYZ2AVEQL5WNXEOKG4HZYQQIXHAVUPDLR7USVYREAFAODHPK5R3BU",
-    "GC7I3BNTCG6XZXONBSMMXFD4C4D7CDQKASX4YNRADS4KWFCLJOHMLGAS",
-    "GC7I7WKVT46CKOXVSAKCGYGUB4DU6GZCMSZZHSEJ5VVD7BR3M7NYK6MK",
-    "GC7JB46IMWMQTV5NZHBRU2BF57GP3J5LYZWBOO5XHPWI2W7JPHIRY6R3",
-    "GC7JNYXNKDBJIMW7ERCKX6M4HZFAQZ5GJ2NC7WHFFLXJ6W6Q4Q4NCAFE",
-    "GC7JOLRFLYM25JEKHCWF7GMNDBDIADOL3WYMK4HQI5TIR33LBMMPR5SH",
-    "GC7KA2KFITRUC632WKCX5MT22YORB3ABXIQDDM2XII43N454SMT2WJ5T",
-    "GC7KLJKNRP4GBAQ3BKTP66HUAUPGW6VJE4BGWS6KF3XSFIEONPMJDYHT",
-    "GC7KM4T22G2F2ZFBSNRWDAAUEEZHYDHPIHBOODJZKOJDA6RPK5FANJWD",
-    "GC7KZBDJSGG52H6RK3WXZC5H5EQGFC3VXQGOHAIK5CXMAL4OTUUNXPYU",
-    "GC7LBJGSTP6LGQ6A7K2PSU5V2JYFWQBSTODDIEETRE4HQANPU3ID46WY",
-    "GC7LY237U5GHP3IC7UHTAXEM6QUHDND52NZF2BW234PQYBN6Y7ZBYMVG",
-    "GC7MIEUWYSXTZFAXKC4VSYIYMYBD3DXOGNIARJK47UENI2UWQJH6GWEF",
-    "GC7MILN5I25AYFPUEJZH3PDAYQN5I364IMKSKEKLJ3HLWZI4AHXUIBTC",
-    "GC7NCSNEYGEWUV54WYWJCIFJYQYS7JYKASO3OYT4PWM7USOWO5OLHS4Q",
-    "GC7NIAPXE5UOU66ULVCIVFFSFVH74LFIP7CNM7S7QDPJLZGSBI7XSE45",
-    "GC7NQHBFYMXLFFUAYDTGCVVJ7YEH4LTCUELK3P5B6IJTNZ7S7ALBMDZJ",
-    "GC7NWADULFUD36ETL3JLLCYGATT3YC7BWOISPEWLECMBPVD4TP3BMZDD",
-    "GC7O6RAJXWYQ36KUZQE5RVWFANDUTDATXE5LQ6CA4TAYXHEF7I2WCJII",
-    "GC7OOIRBKWGVL7T5RXKI2XBHLS3TI4ES7LUCHCWNPR6DENDR6Y7QRECE",
-    "GC7PGFIXLS4RE4U4DWYUGLHJB2IGL7HWTF7LVF2HX3NZD6KI3T67TVP2",
-    "GC7PUYB6UUTQQF2YIWXL3UDN6AFQNR4EXHRQY5OW2Z54C2P4KULHE3IQ",
-    "GC7QQPG3OBASMGW474EYSOHPCQZWRTGZQJ53A3LIUIGHSQTWOOMPTS74",
-    "GC7R3B2T4MPZBPT6PANHFJA43FVNCCGZYPDBAJZYNOAQNWIXWHI23HG3",
-    "GC7R6H26QZSGPA7OD4TYHGWP755UO6LMY4DHFJJD6RCWLOIVMOVOUIKH",
-    "GC7RHTOFG34MC7ISVT2QLM5SM4AGRCVE7PIVT566UWV6QAUBYKXBTAUS",
-    "GC7RTTGOC3HG54NPSL5GUXK4SNBT6WHPW2KHHPJSB6DBE3FQXZ6QGOLD",
-    "GC7SHPZLWYTJPYO6IQ6WNHA5GGDA2MHF65Z5HJGWRPK3RCDZ2V6OWX7Q",
-    "GC7SKTBN4EOOMUNOGXQNJAYTIRCB2A43FYRQVRMEY2PKM52PNJXLVRJE",
-    "GC7SRPTOIKUMQJ4TIZ7RZD27WJ4WK3UGDNOUF3XOW24ABJNNXV5GXMFL",
-    "GC7SZKV5FZXZKVSQ3NVWGUEQKQ2DG7ODQTL7LXS7AGGRT6RIPK2HQCXL",
-    "GC7T6IQVSHQLSOFW5DYFMLLIQCYVFCQNV4WPYIW7M2X4BWU5U77XOBZH",
-    "GC7T7EWL3EX36NXSU7NOYXLDBHA7JP5XWT6RBX2HDQMPC2RQFPADJQB2",
-    "GC7TKEJEBDSETB562Z5ADM7VLDKYUE64I6JQSXFQECAOXLXZLPZ3IGQX",
-    "GC7TKLYY3WOUFSDBLGL2BSQQSFR2JKD5U4FOHZJCSNTXIYO5RKZSQUID",
-    "GC7TLT5UEQBLR3N5N6VMDMRXZF4DTUMXA4GDEXGX3GJMCLAUXSFDXFST",
-    "GC7TVGJSGG3A744LIBK5SAIKVWHEXMPO74NFQ53JGBCZKKCBGD3I6KL7",
-    "GC7UEM6PGOYIJLBNGVT74ADP5XKXINUFIFR2EL2EWJUFOF7LX35DBOTH",
-    "GC7UGIXYE4UZIBMCJCGO3WU3UJN277DJRT2IWVYDIRCGCXXRMKBKS2K5",
-    "GC7UT4UWRV5M75IQAC7O7KAUJH7XIYH5UIAVJVGSCC5CDOZZXHA5EQ5Q",
-    "GC7UVZLKLTJYXAK5LV3YX2PECZI7O7JR37L3E6HM7YLTFI3KXRJZXMOC",
-    "GC7UZI53Q4BDVYI2QKMSWQFZIQ5DFD64MKWEQ5O7NSX5VFTIW4YCUVVV",
-    "GC7VKRU6WOHB4WI42SERQLHTVUMOJLTEC65GVYN2BNSWJQ36GWABC24H",
-    "GC7VPGJ3TDQPN5NFEMS5XCK4YCIDAJD4A63SADKOCDYD35CBB766IG4B",
-    "GC7VQTCSD2MY63BSLJ5PB6WKMV3RRD5IJEDXTWG6BCJYDSRLTG25PROJ",
-    "GC7W26Q35NVMGTO67BLASJKW7UIH7C42BHUKVUHTZMGZIQ6BHAH6DOGE",
-    "GC7WF5ORVOUFWTH65VPKQTWTSBNFXM46IL5EBVKZ4VGKRZWBVV3YVVIT",
-    "GC7WQWRY5Q7LN5BBTL2AWL2RK6B26DCATPXIF6SHPWTOXOPN5CQTEKQE",
-    "GC7XKF3Z346HDYUNWEEKCNYIX2Z4OO35ETCIBNDTZLOMBXLI3RK3PPB2",
-    "GC7XZFXVCCRAF7RNP7XN4P66TQ2E7VS3ARFA2LTIQWQOHXRVI6REYNVE",
-    "GC7YBZ43MRA5WNX3QFP4VB725K76J7DBM4IFBRQZ2YKXN4FCQXKB2EOS",
-    "GC7ZH6JGHZ2N5VZZODVAWXGUZOGE2EIE3F5VEIYWCXFG7W37QEAZJDUF",
-    "GC7ZHSMHANCPGVISLIEY3PMKZ3ACVVBPYHOQZDBPTZF6Z5QMHRYFNZ6W",
-    "GC7ZM4HCKSNWMQRCCW4ZNOYGBQWRJPUQHNSJ6RMNLHPBABOUX3UAKDIQ",
-    "GCA23WNSMZKJQFHECMAMT3C5UGKXKTMJW5DN2G3L46FIU4476SWHGUN3",
-    "GCA33E6VBC6SMXPYOQLVHJWAB376VU55F2DTMYRQAEMIXFBZYEDEKBUD",
-    "GCA3E23XH5ARZZ4UBGPJFVJQ2URSODUXKCXJHUUSNT25LKRUQVGNZSL2",
-    "GCA3MSAPCXVF2JXTUPJ5NX36MHUVTPMHUJCSZAOERF67QFT75T4PVL2E",
-    "GCA4C3IELD36NEHEYOLKEN5UEBCWSJ7KJLVL5A3PNE7PKQVFNED7ENET",
-    "GCA4OLVH5NNYTZTSX574FWI3EBMOPW6BJ2M43TNQI5LKQ7T3M7ERBNDD",
-    "GCA5WFF7AXFCBMYL6MEYBJWEASP4A5SHZ43HBPHIZIDUMXDQLRYSWCQI",
-    "GCA6V6HZOEEAJLA6IG5G27PREJPZUPI65FXWKP6TH6FKN6OOHVIHO7ZH",
-    "GCA6YB23ZNODHSR5CR5XHJWNASZSXO2FELSII6GTDFVEIGYUIFI6SGSX",
-    "GCA72ICVPDICQNVGQK5REIIVA2VDWEH4F3JORWTOMKYPWGFCQVII4SD7",
-    "GCA7HMAVVHDXQAFHDMMF5GVNMAJ5QXGTPYC5CMQA5HVPRROWFDSBIVYF",
-    "GCA7JBFDHPHZFLUSYCLV3ZYX5EANL4WGMVLPCRTAX7VRWBCQJPD3ALGO",
-    "GCA7W2DXNMCLP3TLNZZ5AGX5HJEAKND3DON33WO5VCVXUKWTAFCR37YK",
-    "GCAAFBPZWLULR3ER5IPVTLLLY4OX3PFR46ZW52YL7YKKMZ47K6TTKVTP",
-    "GCAAIIBPN3SKIZYHVFM5HR7VFUCIPAO74GOHYYS6EVWFKLXTAZE4AVAX",
-    "GCAASRRQ3CCN5FGKLWWRBRKESPXZCPSOA6D4EHY3OS7ZYNO37VYHV2EL",
-    "GCAAUB2V45HPS7PMOBONHCXNJHHPM7RUFBEY5WM5HQGUTI5MXM72LVBE",
-    "GCAAUEINH5KICKCGN4LVQPFAHOI7YEX2T35C57PJTQT5IIMITC6GPXQV",
-    "GCAB4MUJU623LXX6OVHLRXZNXYOMGUHQJLJXQFTG5ADJ2VC3AAK2FWNL",
-    "GCACREHU3PTP7RRDCBINWQF62OLB22JBRXWLSU2MFETUJFGRPSKJ52OR",
-    "GCACUX4PDUFYA6WWTFT4MA4CGYFOOB4F4EBJUMSK2W5L6MW3YTRTZVAI",
-    "GCACXQAC5WPPRA6QLZULP5WM7GUKEYR7VHEROHJ656ZOPBQAYMY3JS2I",
-    "GCAD4YVHC52VDGPBFEXFAAGEF2D2JAEDHAKAXCOCN7BI44KINBWPBK24",
-    "GCADEPZGDLDQOJI4UY5QO7AO3TOLCCDXPYKOCADAAS2EJRLV3AIJTRST",
-    "GCADQ33SCRWWV7GTR4KTNIBJKU76EBQLJRLIDHWG5C5ZBZO3BAAEGXNM",
-    "GCAEKIIW224CL7GX4NWJ7QBVLPZPWH22IF4ERO2IGEX4NKTZPMO6I5EF",
-    "GCAFB2MWQ6J4IXNQGCBDTG3EPBEPBH7EAO2D4SVXTC4ZFJ6KOOQAC77H",
-    "GCAFBWR43LM26T5XHCLYMECWMCSYNP2XZYJ7AD25BAL5YACCIZTAWI2H",
-    "GCAFFDRMAGSFFWKNFGTT76FZDONANB5MXX2KODSS5OPRNA4RWTXQCTJ2",
-    "GCAFMPTDKO525DQ54S3B6FVIKYR6PFX5CHO6LPCEQEIHK6XFK2XEQMZ4",
-    "GCAFRBLPVAXNTBKZ4LMITMX5KJI27LFMYD2F73XTBLCYBBX3IYOEI7EP",
-    "GCAFRWJRXSCJYQ3O7YMBWZO73YSVXFWZ4YGHIQQXT44J627NYMQ4FIHV",
-    "GCAG7BORD5JS7GW4IQDG7FQJGMKRJZUZY6RNNUT46VU56KYOCUMXZ7D6",
-    "GCAGLRUFPJX6VZFRWU6YQYZRSYQJKJG5VLY7JHOZSJJI5GZHOGA43AOY",
-    "GCAH7ILHX36YZ5CISGCKFT4L35AY5LO222MHUELU26WBLVJ6XZHN4PNC",
-    "GCAHUEF525BL6UP4YUCGGK45KU3V6CTGRXMIGURGC3GTCGDMFSQJTZXE",
-    "GCAHVD43GB5ZL7C3VJ5YWLPVLWT46MW7RARCS2EXXCDFADVSNQVYFM4F",
-    "GCAI35NUXTCNHCBW43U4HDG65SETPH7BVC4G4ZI5MLCYPNFLS6W5YNZQ",
-    "GCAICGRTFMF7Z6XNFBHLA5O3RNNQWPDRUJOAIYABVCC26NEHOWGSEAEC",
-    "GCAIRLB3LA3QO25M3CQSCRUWMBOHCWALKNTTDKCL5G5GZFJ5T4QAMIFF",
-    "GCAIYCEOX3ZZDHMKWDAHZYAOHBK3X7QV3MBSOUU763KNVSSYFE67SRSR",
-    "GCAJDUCFOXKLBGCPRTDS7KAN3Z7DQB45ZU6WOV7R6HC46HQUDKFLMPDE",
-    "GCAJMXN7EHQIDSEJTRZYGSMM5AXJITQDO57X3QAMHZP4XZK2E7ARDROP",
-    "GCAJUZH3L64DD2XHQCMQDXD7FUYG5PPRJU45TOGQZB6KUHQKEGCUE7AB",
-    "GCAKLQYYTPFDBX62IKWEEF62LPNIOALHWAMIW2Y3O347K6SALBZQAU2V",
-    "GCAKM4DTBPYAIXOZYNACUQNYKP3BP7WJ6DQDJNM7QWLDKR3BGNUY37TU",
-    "GCAKV7SELHSYCRCRYAHX6P6IUHIIEJUW2JNFFOOZWFUHY4IGWE7GKO4T",
-    "GCAL5BMDMYKR4OHYN4YBTAUYH2CCW25G55YCCKXKMI77JSOOMBCF6BSK",
-    "GCAM24OUUVGHDT3HCI2I2JNTPGXPHQU44XHJJE2BQA7623IAO7MHUTOK",
-    "GCAM4RSLUBEIHTYRTTAZG3MLO3SQBH4ZSVSWIK3CV7MK62SJL5NFVNCX",
-    "GCAMDDH43JHDW3YOEAYW6DDMJ6GWOXY4R2BN3XS6NSTD66CVXDEXSAND",
-    "GCAMIKD54N72DMQXUOSXMJV4UVEKOLE2AW6DTLKXEEQ5ZZXWQLBQ4Z64",
-    "GCAMLZ32DBM5QTHSDZOC5LWEHYALI5ZWV6PHEWNOZOAVS4YCJLRA6AJL",
-    "GCANCLAPGTMDMLLWHTRCIBZ6XBJOQVHNFCNOP6W3SOIWGGR4JFSOQLLF",
-    "GCANGMIJ4GQNWDRCXVKGERUSS3HZKBJMQPUBPJXPMKQXHKXJARCCNEXB",
-    "GCANJHUOJPZAVAW3UD2QWJXYARJ3ZVRBEQSJSQ3DLYZ73DBYUGKYFZBG",
-    "GCAOASNUP5MWO7NZYSR3CPLG7V2MTOALSUORCDYFMIB2FQ3BUQZAVSY6",
-    "GCAOT4GVTWGQHOUAZOUPU2J3HFFNBPITGBH6DHYXZBT3W4AFVVUDRBZG",
-    "GCAPNYO3KMGPNOD6LQGRG32J7XDLHI3YSZYU3PLIKWGKRSUFJLT7HSWT",
-    "GCAPUDKFAHRI3M5P44MQBHJTDM4TC5OENYYFXVIGSJRRM7MT3XD6RKZP",
-    "GCAQJ4HCZCMUXKMRG6IR43KJOF5KQKOMQPVJK4IFVGUK7YWHQ27PE4KI",
-    "GCAQME774UL3N25H53RJTAJO7LWYYNDHJQKSWTSG7BEEFFS5NEWWNDHA",
-    "GCAQPVQFJCEC7P3IAZV6J6W4SQSERD4U2ZB5ECBIUCF74GWFS5DUPWWT",
-    "GCAQTLJ6RWABV2FUTJLNIKBQAVGM37SPG7TO7WS6AK33DPJYSWL46F5P",
-    "GCARDAWEO7D2FBO7LA54OKTPY74LUX5W3MZAV7X7ZP4ITMEBUEYYQWFX",
-    "GCAS7XL3G4QYSNW67GNCTB2MSA2BHFIDBYHPS35GIHM65SCKJBIQYRKC",
-    "GCASPJOOVVTQI4NPDQZMIZ6SO34PIYZLC4PBGBZC2TPFNPHJHHSXUBTC",
-    "GCASZS2WKRC7OEMXDLVZV3YAHRIYTW3PBNOZYDN2UJEHESRAVQYIKDAK",
-    "GCAT3FBNDOLWMJ4SMXB6QQATSCK3DUWLSGFFPTWIMXIVIRCVWGDDILDG",
-    "GCATGEJGH655XO3RAP5DEZ33QVFTGUPJWY2RJ74NQQFRYHHGXTTRQGU3",
-    "GCATON4ZMHTEF5C5PKX4AX3K4DQV4YQPMYWKQR6ZQHFZQ44UMZQDECUH",
-    "GCAUHKM2NQOXUZO7CYXJM2SB4JV6V4HEY6XOTBOV7IVNP2CLRWOGELTC",
-    "GCAUQY4IMVGPNHLFC2PR6ODXOHAC6LIVX7CZ2K42IBO4HHPMLACBXCRT",
-    "GCAVACORCHR7L5Z23YHCHYVCDYU7CYKD6XYNVCV7LINILMRBECZAMSUO",
-    "GCAVCAAIYQ7OFVHZUERYXI4XEQQRDAFFNXTDJHEO5G7MCX665AZEGLSD",
-    "GCAWAW3YJK44OLFWURCFMI27ONJMDAGGA56KLTOSUKV47BCNV4WMWOIW",
-    "GCAX4OQR7YHBODJN6L7JF6LV2YYEISD6X3OR5H6OAPV4K6FEAMW3ZEWS",
-    "GCAX5D7ET3PLX3VRJWMWIHNBD6OHCZY6GL6TZE5Z4RZ3WHSE42NOMPIE",
-    "GCAXCGIRCO2KB7A7QGJVDG5FJJ7CLAWRZXYOYBBZCHZDMSKR2T5M6YUQ",
-    "GCAXKS7DS3PRFWQI6ZH5YIX634DRNBX4M7A44XMJDLK6V2XTG4VVLGO5",
-    "GCAXOUW636DX52DOXIVEPRV7J4BKJMH2PFE4QYJFR4J7C33RVXCK2EYP",
-    "GCAXZCPOODVA4ZMWESCH5KBJZYSQ4TYCX7GLGVDFNK3RUO6W7NWADQBH",
-    "GCAY63CTHOQ4HHC5H7EB6I3LVJTR46DPZ3TNIC3BYAKAJRUBFHUSIKLW",
-    "GCAYG2ZUPIENCCKLV4OC6S2WWLRIDSD6L5NAIKJJA7IAID7RVPSWEEGV",
-    "GCAYV66PSIPXAISGEBAJ5VXHFWCVHCFL356UQ235JRD3EAR55PBZABFA",
-    "GCAZCGLGPRQZ4IGH5LVUPH6S37VVV22IFG2K47PUXM5SCMS25CRAFSW5",
-    "GCAZYTUCB2I5SPYLFYMPVMZL7BUSYUQ4C5XU4DZQGWSI7RGGFLAPN5AG",
-    "GCB2LRBGC3DZ34U3UJVYMG4WZODL3WDC3XLIYW5J4REOZVW2TNQGAMDH",
-    "GCB2SXEIRJIEGBYDXT3NL2JOAVCPVO2CDJX6627GQIFDUUOPDDHRGIAF",
-    "GCB2U66FK3S54OW7TN432WKXXS32RMN3RC4LQMYSRS4NCNCLTE6GINS3",
-    "GCB2XKUI5DEI5CSPJOYFQYSK2Z5IK6LV62EXO4P74UEVNI7UWF4MNBIS",
-    "GCB2ZFXJNR6FQS6YSVNUY3JA4HGU3GDFILRDHBL5RYY4QF5RQ4PJSPIA",
-    "GCB325O5MQXXK7AKI4XPFGCTBJSWEWSTRH56XS7MYUEUGVHXOYRACCX7",
-    "GCB32ZGJMZFUCP3BFTJ2EHTLNGLJDRZGBNCBQEG46PN7LVPTSNTQPWSD",
-    "GCB3D6CFOONDBTYDYDSRTYFMCDGO36RBQ6XB4GABVR2ZGZULYDGOMXVZ",
-    "GCB3YH3OU2RWKSBKK757HDY5IIOAKCBLKAG23ME4TOQ27BABVWDQWYCI",
-    "GCB44KGL2E3NQPRXDAO6DBDD72OHZO3AVFSYCJISWO4B6VS2BRFE7DF2",
-    "GCB4MXBLF6J6H2J4SOSHTO6YXUILSAPJ53X7KHP4TK36P7Z6SKO7I6SC",
-    "GCB4TB75LDSWQXMOWOYZTMFQIFDMAYLFLG44T557EOAL24UDNABKQUSA",
-    "GCB4XZM3BXKFWOGUC3VJ333EQTUE7CUPLHWCXGWFA2D775ABFCHEVXWQ",
-    "GCB5IUYRK7F5VLNGSGCEJATBGZJXKO7XAVZLFMZZLG7VERLPP4VYG7IR",
-    "GCB66WDFJBMGVUGWF64GI4WBPWUWNBRMPEAFEPKEGQK6AMEWDMTQRIZL",
-    "GCB6BV34CMDNDBF5FFGGZHKGDD2EGUZN7BBLHC66E27LV6K3FTUMQ4P6",
-    "GCB6ECR7X3O6ZM3TTGOTDA6PT7QF4TX66ROZYNKOVW7HT3Z7WA2BJGZT",
-    "GCB6ERR2LK7XODLZHY7GF5PRBBDQQK573D73U2J5VICN6IIAN5IQSVOB",
-    "GCB6HOSXWIE76RW27EFJBI662N7HJRVIMQBFTZUHLBJGM5IWTWXSVFNW",
-    "GCB6KGW4JTJIEPX5CGF67UXOQVSAE4LSH4TDP3YU66WQO5A57ZV5HF4S",
-    "GCB6RCN3CUUKOLBYF5B2B5CXPIGROL24DAF7SWVS4SLBSKJLCZBNYOLP",
-    "GCB6WY5F33KHCARYAMLEOIBX4JHECZH35OI5YORRU5U3JA2TR3KCIRGL",
-    "GCBA4MU2ZZDXLDQLON4KF7YEA3P5VZACG4KUBTOXBWD22VHJSIMHOOSL",
-    "GCBAAPRBXEFYZZZ4I7AILRTGKGQWVSZBLAPFXBFSECPF6JER4CYWHYPE",
-    "GCBAHDQKDIYI3IL67KZ5NVHN4CT7ZGVRQDGELL2AUIKNL5NPFVTRBQJB",
-    "GCBAHWR3BDRPRLFDDQRBC45JFPTQQDQXOIUN3QR74SUZH7IDMK3ETORO",
-    "GCBAM2WQB2477Y6NT7L2JECPNH3CP5FIII5YFDPLA5VHUSK2C4DNTINT",
-    "GCBBBY4H62OI3QBUMTFMETAA5WKMITWJPKYY3Z4CQVAM3CRI6IMLS3JN",
-    "GCBBGBTNFM4HCHS2M4NHUOK5GE74AACPWH5CJWEKM46UVVQATIMCCPAA",
-    "GCBBV76NYAQBZJ3A6ZHH2CY4M5GBPWFKXO3BJDHDWI6JCM24GTJSWISS",
-    "GCBC5BZ5WFLUJUHBX3STFIASVJRJZKN7QD4CZHYSM3YNAV2OGV66J2PR",
-    "GCBCA2SVRP4SJRJNOKI3N2DJYEPTMA4X7IGJKPHZ3JZCD75JR4L4FLHP",
-    "GCBCH7ROKSJDWNY36WSIDISFSRIHBNSTV5KXSGG3E2ZWYLTVSUVVZ4XX",
-    "GCBCTCXTUZRX4CXGQZMDZ2XXT3XWJPJ74XAEG4WNZSPIIPZGEKNZHISP",
-    "GCBDKMQJAL3IOGTGQ6HW3IJZ74CQD7RX5GXQNNWL4WNIXJIZLWTXLU7W",
-    "GCBDMCK4CE5Y6KQOKL2X2S7MPYYFRKE7KS6EBOD7LP43NEMGFTVPHRS7",
-    "GCBDOWSGVQLNJ2TZ3WOZWWDIWY7DNMM6HW72SO2LZUB3HND4HZ52WIAE",
-    "GCBDPB7YX45JVQUCIA2AYMRUFBQBNYPQEXV46NOXEQ3C6DFGKJFEWIAX",
-    "GCBFCLUFB67KU4KO5G7UTH3JDIMFZ6UWAB7LYIOBNQKM7KHKQDFBW2ET",
-    "GCBFIXPAMFNDF2MHGUCUPUSYD5G3P5WIWITEF7GLPOWZFURYOOCNY34Q",
-    "GCBFN6RWLFDAESJ6GTD5MRLXE2HX5SLRVOIRQS4AXKITPALJI7ILJQAC",
-    "GCBG44Y6LB4DGFP2KAROVMWOQQBCK6HU5KERGH6FMJPBJJBV27MN5HHB",
-    "GCBGBBL2RCSFTLBE3E6T4ZKGZOQBFUDUNRK3NOJ6NXESCTPPX2FX5532",
-    "GCBGHMUQR6MIRC3UKKLKIAKTCBBL7WSLDNMTVLIX4WQUL2L7UY3LLLXV",
-    "GCBGSMQ2IL6L5HMI2PWMNKZHNOWUOAISNPZ3I37ZIF5PIJ2NRTYDZ46L",
-    "GCBGUU2WFIU4HWMZ62WUDRPPZCOIUTLCLWG4S4HHWH3NPXFXK7X3NRX7",
-    "GCBH2BVBASAHCLSRVDZVE7TKKTPJIDPIFK7WLZ2AMCLOOLEXJKL4J635",
-    "GCBHHOAKE4AEGLGAWG2MCCJGTP2PGRB2GIKEZYJ7QH7XKRV33DPE3E3T",
-    "GCBHJTKOZCMPMKGAMSL2GGMLFIGB7XGBCHDZDJCSUUMW2HFOIWGN4FB4",
-    "GCBHLQYAL3AFKJ4JQUK46RHQCOPQE5PAO5TBZB36V4TRVPBTJLW2BN4K",
-    "GCBHPHPR27SRR6T7GDSK4XET6NDS5MIJPUZTILR43XQXVRZXK3MTE2DY",
-    "GCBI6XS5ES3QSEZZJPYLZARL5UVLCQ2YXJG4CTO65UH7KPNT54BM2IGX",
-    "GCBIDKCWLXRDQRRI26GKU2JCF3NJSFV3JGRLTP2RWA6AVJ73HXEKN5NN",
-    "GCBJCCQIDJUVPXCTYOEYAHRMPDK4XQ7HUPZNJFWTFTZRLWQ5QQXSLATV",
-    "GCBKKJXK3MN6ESXEPL6CRNGKJ6E3PBNRMAXJ4DFKQVZBBCGJXRSKFXRP",
-    "GCBKL3OK4JQVRI44FOY4HW3RGDUWCGICZAM3TK4VXYEQHA3NJRFSGINR",
-    "GCBKL4YDUDBAFDASY3SWQTNCFWDIVK2ZA4EK5STAXHHJGJSTZK4CYQYZ",
-    "GCBKOGSHM5VDOF3UTLXIMBBWVDYHALQPGHCH2GZJ3737KQ5US4X4CIPS",
-    "GCBKR4DGOJG47LTZ5YZR3S5EC5CM6TLJ3L5QDY6WRFIQO6JY2QUXNA24",
-    "GCBKWIW26VZZHMPBLPAC4HPHQ2JWII7U43M7KKGBHJPKMSFR7PGTELON",
-    "GCBL2QN7Y5M43OY3D4SI2FNQQ5YPGS75KFX6Z6P52PARXIQEOE7QP64D",
-    "GCBLLMP5VBGQCTGZMYIYVRO3V2SMCEO57SZGYOFVPEQB4ZMUIFPQUCD5",
-    "GCBLXAW644H7EM6H7BUK7ZQ67JDZFAUGYBCBVLZMUMKBA232E6VP5EQ6",
-    "GCBMHRJ4ZUBOACNNXUHPIJY3JMMAZKTIM3LRDPAMD672EO4ZYVLGMSHV",
-    "GCBMPQ43R2O2PDI7HCAWNOOPIX4NCVYXDER25CVCRR4IFHM3ZCYKSGWI",
-    "GCBMT4WE727ASVJUFXFMO5NRFZG5LVJBLBK3ET5MW26NOAA6LPLCPQQO",
-    "GCBN4INC7VMRUE3TKNJVM7AWM36CRA44FUVMEELJ3USCS2VIEXBI2GI7",
-    "GCBNVR5ABPZRQTVQBNEOBA6VO6XPM4334Q46V4FEWQCSJ5C5EYZT2OHJ",
-    "GCBO37SUYFDER5BXML2I5RS3T6CTTESE6ZSL34YCNJHX5A63MOMF5GVB",
-    "GCBOL4HE6ZNNEIUPYX5AW6HYLNZI5BRV5PP5WHFJQVGMMCS6E6JGJR5M",
-    "GCBOVDJVFQEWJHP3L2NLIS7NO6PIQOHPO6E2AFITDFCGACYVVR5E5R4F",
-    "GCBOYSTBVSNEPYYN27I3XUGMIEFGY66EXDXJGWZBVULD3TWUAPTL7OLB",
-    "GCBPB35HMVENM5QQKE4SDJGRBWI5R5WQ7KUHQLRHBA3USNSOERZS5YEO",
-    "GCBPETIQWZHWI7CG7PUGQ5EJAXTYD5BBNEXUR44DGEHCXTAO6BJRCOSL",
-    "GCBPS42BRIPVAD6QCKCB4GPYKD4HSD6PKYJQVNTTNIK5NSNR47PMIEXC",
-    "GCBPYFNCPFFYZFJKYFRTVFXKN72SLSMRGWZSDOBDO22526RNBK6SFYWM",
-    "GCBPYGYUSE25U7MG2T74GPSC6YZ4TRZTZ46JE7A6LWMJHNSUUMHPSN2M",
-    "GCBQ6AYRTKMIFEI77YNBPAFLJ3HC4IQOGXS2LW6XX6OLIYHUZQW5UGHT",
-    "GCBQ7OW722HCBTCB7PE4L5264MP4W7OEDJZY36HDGLYHA7MI7QWLGGNI",
-    "GCBQCGDSGSSSNND62YLKLSBZPIHKWXER7EABHD7J533EEIZMKKUASFOI",
-    "GCBQK67FLIPEPZNOBX366WFCGCWSQQZJNN76TJDQ6PW6MRUSZMZFVGTU",
-    "GCBQYTVBLKPSB6D2WSIQQPO7PXH4S3M3BEF5J4Q5OR2QAH2246JSYZUC",
-    "GCBRBUI3BKRYP5PU2BQEQRO56SGLTL5Y52OL3NAGEBZKVIMQAAOGZNDT",
-    "GCBRJF4YUEBFHF2GTGD4ARCQEEKS5PW7USBAFEF424ASRUDZBJW43LGC",
-    "GCBRTXFXYMUC4JDOTL5TNNRDTSJ4NT45ZOIURFYB6F7RGZPLK4VH2WW2",
-    "GCBRXEMIZ3CULLDQSYYTF7TMMCQZ5TZ62IRTLXLBACNET2PREW37AICE",
-    "GCBRYPQO62BPQZRTKIIG7DEVOTROFNA56GUJ6VOKPUISAATLNGQ7LV6Y",
-    "GCBS4PCBY6BLZTEBWTE7SXHXHZFX4N7VQ4DPGZACN42CS7XB5SXG4YZX",
-    "GCBSX2DV65JRGU3LCD76VRXI2XU2HQXKTFE6PXOLSU27V646LULPIKDX",
-    "GCBSXE4OSGIGCICY4OVFC4OLUEHAGPFRBGAZBFKNVLEYSZB5VR6EJBHN",
-    "GCBTXIP3WXYYCWF57RZKSPWEWUJY5DSE4U7ODYVTNPJWETTNDEXOBANK",
-    "GCBUJ4FIDDGXLSHIDPQ4EVPWZK37FGXXVIJQPMYM5ATUMDV2PWSWTQFR",
-    "GCBVKNHMKYB4DOPAQQINBXJHFX6HS5ED2HL4AV5U7XBIGKMZF2B7BL4O",
-    "GCBVQJTRFMXWWEHZKHXQ2KYG2FEC7U2OZAIQUNMWM4CUVJOI44LXS7EF",
-    "GCBWHCIUWJEJ4FSYOG5A6CXFTJRHCQU5KVCQ6GOSM55UBFVSDSJZZBTC",
-    "GCBWMZBZB2RPHJPSKQVTSAIWOTOXAU2RPWJJXKEGVNA4C2ZKDNR3WANH",
-    "GCBXEXU267ORPBCE2NLG7EC3PDTBBB3RQKTS3OHZ5REX4BYCGNYD3MTS",
-    "GCBXFCVLLYD53VBSUHZ5AJVNNM2AQSNSCSVKUPZ2XXHG3YKCHVWOQ25M",
-    "GCBXSQT4ABZVPIE6XEQIVSUSWB7Y23YW5VRBLWOPCFVH3OHG6LKYLI7V",
-    "GCBXZNW6WLL4NDENWSRIYFTJPDJKP5EONIV4S4X3L4EEP27CKCWYKIFA",
-    "GCBYI2YS4PSP5JH57ETYN3LSC3MLDNL5RTL5ULXFAJFNTGGQHNMZ6ZBR",
-    "GCBYI3GVWZ4BDN3HFBQ3UKE6BGPRBJ2CXKLOSIT53FTZSNLD6IE3UVXF",
-    "GCBYV3BXEHC4ROS2WXOPMA5535UI5QVGGTQUOUUM6QZDPKBYKJFM6QGM",
-    "GCBYW6CATZH5O6VEJEFVE3NG5S4CA5LTVJZZGI357P7A2STCIFK7W3HQ",
-    "GCBZ7ST2S2Q5UC22N2E5ZTBI5JDUJBFNDT5UCFPSCIOZO2G5SXKVS2WW",
-    "GCBZN4PJNQONLXXE23ZGVYZJGY3HFG5ZYN6H7UUJGEMW6Q3JDBGUEYFC",
-    "GCBZROZNYHLYQXHENQUWOW2NWVKP47CVWSJKJBMLHOMRP25UZXRIGEXC",
-    "GCBZX3MZXK7BSWNJU2JRL3JBIFMETF7HAIRFECWCRPR4GHSWE6CIGQA6",
-    "GCC23GR2WILH7C3TYJNV7X7VJRVXY2LMZUYVXOWIJKEZETPXLHWGXRYZ",
-    "GCC2AU3ZD3Y4Q26HOJZFUOHOG4AUWWQONESKJK6EAT45BHO3AG37OBMQ",
-    "GCC2HTHURQMVI43DO4Z4QXAVCNWBDEKS2FJXU2C4VPS7VZU62YPWTNJQ",
-    "GCC2KGBLHH6UUJYYH5L6TZHQIOOMGOKFZKLQNKZYFZFK2QXVQW4XWAVE",
-    "GCC2M6TXPZFIQXXUPQLNATAEGF2HGQK54YVLXDCSWVTGVLACN3ZXRNKF",
-    "GCC2RJVGRUTTPLTE35P2HID6NGR5RALJNBHA2VAKZGRMCRGDTAWYXF73",
-    "GCC4HMN4YELQD6QPBN3QB57P2633TXSGMGFG4TMIPW6YMI2FS5QO5DDE",
-    "GCC4ZPUJFNMGG3JPE72PW3MJV4UI4AT4D2KBKHAKPVPH7D3GBO7FRFWE",
-    "GCC5AXPUP5KMPYNHU7GV2H267723RRVW7OJIHK37YPQQYYXOI3QG5R6K",
-    "GCC5FDKTIHB7U4CHFQSDAMDXLTWJVALB3MBVGFSSIX4IHJFXR4MYGQAH",
-    "GCC5GMVLIYRHCA7W4AUGMBITW7IHE5B6UUWANCL53KVFLL46CCFF3TL4",
-    "GCC5LGPG4BHM2H4RSHUM35OMM2B6N6WZCQ5BUYQTMCNOTILWWQZNHEGD",
-    "GCC63ZY3WT4IOA4UF3LCVHOIEH6UCF6WF7AFUDAUDQ24XET4VRI2ITOP",
-    "GCC6CUE3F7D3YVFNGJH7POOGVYWMPOQLBUVDN3MELCTWNZ25JZQFGXSQ",
-    "GCC6IABA4ABFRC2I5O24QGGAXOS66N2WT72DVADX6BY56ZTY66GIBH4R",
-    "GCC6K2FAK3NH2O2VDDF2ULR6S7RL5ULW2E2C2MAC6BOTKJGBPUFHMM4B",
-    "GCC726RZPHOMDWHWNR4IQOTMTGZQYMFUVUILU55HMJ2FDZODC55PPDQP",
-    "GCC74GY4S3N4IZWBYH4GD5FTAWSAEAPNFE4HYM3O7MMKVQMROEITL74E",
-    "GCC77MNKJV2VJKDZV7AW4JYQXSPTHJ2YD2XIZGAJGBJTTMSF7AZCLAIM",
-    "GCC7AFWR7MQP4MCKGESHGRFXI5WIQQQCKE5NSH3LI6P4ASD2C7ALVKUX",
-    "GCC7TKH3IGYXE3HFMFKS2OSEH6BB5CH2B26KHXMVTGM2X4EH3TKWPAXG",
-    "GCC7WOYCFRWTG5N3UBCXQA563VKUWFLF23Q363B5VWETFB6JN2JXEB64",
-    "GCCA3AWRKPYW5ZI5WDMTC3ILA5WPK4XWCYTVNUZAU34Q5OW7VWHZ76LD",
-    "GCCA45KV2Z4OV7AYTGFO7KZWDN6AYVRUNMTDXH5Y4K6Z6NJVQQTFDXDC",
-    "GCCA5BZ32FUQKGZFV7V5K62YDNQBXGLZ4MQQQVUOB6FRMXLPCV7HIZNQ",
-    "GCCAGFSUQ2XCGLVFFXURMHJCY2NF7EBDIEFWDUEDPSKMEPWY5P4ZELC2",
-    "GCCBWW4XR24ZD2EGOGKEFW4FYUDLAVGGGZHYFEX4AKY6JDPWQ4WDNYZZ",
-    "GCCC5HTLL6AN32FDXUTKBEECQ5VMVSQUGLUBLO52SZRACNAHY7OIBLTH",
-    "GCCC7FUH5F2GDTD5UH45QR4FS57YQT54KO7RVSNDVQOMHMNSQJUIYAFA",
-    "GCCCLYO6DDSXSVYXTOBDX4SCJNDQ75AWJ3W2YIA5HDV7UJ7QKQDXTVEC",
-    "GCCCQJIFLUXB6GDYQMHEVBWZ7MU5G5YR7ELX23ISJLE3ZMX6WBQI4HFW",
-    "GCCDAJLITXN4WOPZFGUH5TJMAXGW3NEGSNOH7XCIBVS2OOYDCO6MXFFK",
-    "GCCDDQTJ2R5S2JARRXKV4OIRCXXJSIVFJIWWAAURNBJJKCPG3TYMVRTX",
-    "GCCDKQDSPGBFUN6VJA4IYEEWZAMT6AUG7AH5PJFBEGHT5HQMXWU5YMAX",
-    "GCCDTY7ZJUGAPA2GDKG5ZDF335OU6NQEQX2ZZQOH7QTQWNAWCPA3TJNK",
-    "GCCDUUAP3IOJRGZNPNGIM62SYLERWP4B6AN2J3TN6TU26CFGYVQ34QIG",
-    "GCCDVCGYYTS4BUMWGU3ABIUD6WQODPTJB2M5XPXM5YDICWGCVOQDBTCD",
-    "GCCE2DE44VWSJIKOHLL3C5ATWR6TZOGFVJZEPATQ54PZ7EQX3ELMPEPE",
-    "GCCE6BIIMALB4WNKH5O4ITJW5R74OP2ECTA22VU5YUB35FNZ27AGJH5V",
-    "GCCEE3FTQIJCI4RWYWOJRZ274UQZUWTBEROZ2B2IURT4AAQ5JARF4ZIT",
-    "GCCEJ7A7SED6YSXTVJW7Y7IRE7NE3EM6ZEQBJL43XSQTRXWHUSXNGAZB",
-    "GCCETEKUAQSK3EOH2KFGYCSOXFKIHU6XB22U357LZCF5MHIBGBUA7DXJ",
-    "GCCFK4NCDDOZMY6V7MW477IUQRO3C2TBQHEN2AQNL77XBLMXKGUPOSUN",
-    "GCCFRVZ7MMAIEKJ6J7TQPQXTBJ2T2LILZFV7NSHG575WMUV2KZJZOHHH",
-    "GCCG7OR6ZZL4HEBD5UAR2HMH35RT4A5GIT33EZNHN4QBPCICE6HC4HVV",
-    "GCCGAA55LDES3M2FWJYD6FJWZDLVOCM6BBDKUL2QYUUU726OESKZD5RM",
-    "GCCGQCVSGFU7JFWHKRXIB2Y3B3YZOMC7X4NCYKDCFBX2FD2GKCNKWSOL",
-    "GCCGZOLB6A4QJQL4QQ6NE2WMCEJB2ED3ZWUGL3I2A74TXYWUKRJJNN2M",
-    "GCCH6KNXHCG2ODB6HCIEWUOLQZ3ORRQ47DSO4JWNYAG343EPGU2P663N",
-    "GCCHFCODJCYZRPD4ZLOFOEJJ22AYN2LJAINJ5P6LD5OFF5WPCMTES6RG",
-    "GCCHWGDEKSJYJ75DCJGICLKD35L5HJBJ4PF5IUUAHQOJP2H256I4DZIT",
-    "GCCIEGIDMIUWEZPOYEVWLQKJDKJCAOHAX6FBY5YXVNNUZOAVROAFJUJ6",
-    "GCCIL2ACSE7U6VMNSDF2VHSTHO2GWYEG6RGIACXJTHZGESDXS6K7ALGO",
-    "GCCIRRM2GDY3VXEDMV5H2H4AOVKRAYB326CRAVSQROBBVRK4KY6ZS7SI",
-    "GCCK3X3KRXHZOO5OVQHGTJKMBWTTNRDTYB3F4NM3ZEUK37XFMYFCSCLX",
-    "GCCKXZRHEDXSJLQL3KN4ZE5JIC6I6ISC2ORGH52FAFCKW5WOHN2B7DYS",
-    "GCCKZ3J6HFO3TFGFU6PYKAQYZDM5BRX7YXTBPXYQEIIRRGD5ZVKALINE",
-    "GCCL7MPM6NT6U4BUOH22I6G34GWOSBFSZUK5CXB2SZ6D6ZW7E7BQHFZO",
-    "GCCLIVETCPB4LJZ5JK4IVKSCK6PMWK7UVBL32NCM33FAJS56CUVSWXZR",
-    "GCCM3ZU5CWF4QVX52X53ZU3GO2YH4NPPRBJRSYATTV5LYOGIICDX4MOM",
-    "GCCMGBFCUNY7ZNXKTBACKZSTNGS35Y2TFIACWRRT73X5GF46KMXROP2U",
-    "GCCMVGTBJPV25QLSRKKGXXTMC7UH7MCF3BHQGMCGAB6OMO57RRIIQHFW",
-    "GCCMZL6YETWJ6EJOKHT52VDO7WCFTU6EMBFPEX5CQY4PJKMRRYZ7IJS2",
-    "GCCNRP3KRP5AYB2K6AJBMR7H32MSG3EQNI5CXVQIQNNR5WOKH4HGRELI",
-    "GCCO4PM2GIPYT6BE7QKTRNRVZO3OB4PWA5V5PQUE4RP2JTKABA2DALGO",
-    "GCCOHR5U24R4LRPK2JOV7RAW7NGDZAAEJ7LKQCZYDZGEROY3OBNF6TN5",
-    "GCCOLOCYSCOPBBOPGIGZJLHDFFOVY2VGVVF54Y6UXM3GHVZOEMP75GHO",
-    "GCCPCXMX2QQNMP3LNNFJC3PYASCF52SP77CJFPGPYR6U4JW2S5ZHA6DT",
-    "GCCPD4EVTCWW4SSKWWPVA3F2HG5MGB4IQIAMUFANX2OPMS6VRKOPDSX7",
-    "GCCPMJG3JKXZHWVILDWSNQM2W5DAWRFRITUEGUNUXPY7CPDONPLI4KEY",
-    "GCCQ7VC3K2PTCTTMW4MXOTRRC3URORUNO6GBGTI4NXR3P7MNAEUNG3CN",
-    "GCCQBBDAS5QKULJFE5ID4OBSOO2SAKDQBFO5YHK6ZZJHRP6RDXFG4YJT",
-    "GCCQBJYY2P4U3DM6GNH6KKKJ7M33VDOXA34ZWMGINT4GG5KTFCNRDSHX",
-    "GCCQC5PRWLG2NPHEDLUJFZWESA2MHFS3GZVSCF6QNIFMZP73CHMJGOLD",
-    "GCCQCMNQHJPDKMZC3GVSYQV2C6WYIVU5OSZYTLEP2W7N4JJXJGRHADNT",
-    "GCCR4HUO2XAY4LZ7YYQYQJ5BLGSR7CD6BW3MZP6REJAB76AMTY6GV2ND",
-    "GCCRG4YTGSOPYC3FW5WM4D5LDYW4E67TIWDWGPCPD5OOFQUKW5WEKX7F",
-    "GCCRL75E6J36YSLZ66K3ABL4XIDQNBD5C3XWQCSUU3B52BZRR32PAOKM",
-    "GCCSMNAMIY5W623OZWC3CH7BPSNVG23BTAB3AWU32575V2KD2Z4DZFPV",
-    "GCCUGLA4U2PJAWTL6AZBVBMELBW5L3AHBM76EAE7YEH53JDBCSB4PCNT",
-    "GCCV3ADXNTIXYEZWUSAWXZDMFZ5WDNSRBHOZGB3RYFVDTPUXP77M33SZ",
-    "GCCVJGNOXX5PTSQLAK7LTOYTBLI57OCLF5AXA6DYTH3LCNKGPBUN627V",
-    "GCCVXINPIBAVNAONDVWNOLY2JXTWKDU64UMHEOVX67TGXCYAD6XHXEOP",
-    "GCCVY2C27WAYRAFYDDA3DO6W64MAIQD7EXHFWGFXYO7TKAR4PC3EXXOM",
-    "GCCW6GE4TTTW6UGFHBQ2OUE4FFBDT7DWHCRTD76JX6BP45VUUIKGDFMF",
-    "GCCWKFHKFEB2ISX4DEX77VKPTT3N56EMDYCBBSCWKRO5WOPGIMFZJNDS",
-    "GCCWTKKFZ3DRKIHO2J5ZXYKT3E2MX724QUVVTM4P6M3ORX4XO5UKHKWM",
-    "GCCWYWOSFN4JEKLGSNGOXLS5O4ITPOSQW7YTWZOVLHP5PDS2SMJRRYS3",
-    "GCCXBS5DOKUQYXUKU2SIXWQ4H2LNPXY3WGWQQ2HYPXSHZHFYF23MG65R",
-    "GCCXJTU3EV4WKLQI3OQVOS6AKJL453V4D4BBORZZ37UVOOESSNGDJKTE",
-    "GCCXUWQT5MVMX6SJE5DXSAJRV52FDXVJFE6D7YPDCQHASJJ7QVJKMZLR",
-    "GCCY36TDMUW3OPUFO23HILLRA2OCAOG2TFM22TBTAX357TMYCVD3JR3X",
-    "GCCYHTR5WEYKXH5WTWVZWVTQ6PI5M4FYVWML43T2EPK4FZZGBU7YBNDK",
-    "GCCYJVQJ5GWZIGV7D6SU7YSSUGLWRXNEPKLXHNCAVVJZ4DKYU55QSXJI",
-    "GCD22HCPSUAZKW47KR2PP456XRESCOSEVKWOH3JNTER56CVLOP4WCGEF",
-    "GCD2LMQMOOSUL6OK7PCEYEQXDHOYGN4DHJSXP7NEX76ORHCSZYRXELON",
-    "GCD2TXKHLTJDDO75M7WMYFEF7VGY4CT2MBLC4WHVMJZS67SQL4VAEOCB",
-    "GCD2ZPSPGHIVZXRLONJL2BYBQNHAPNE2ODXHEX5FH2AN5N4BSUU6BRNZ",
-    "GCD34MBLS6775CHOKGNKEKBAQ5ORK5HV576TKRYCXO6ECUWGRPSD7CED",
-    "GCD357HU2WEYSYGYPXLCKLWWBWX4YOPYX6U4HXYVR7Z5XGOP5FBJC3SQ",
-    "GCD3XSM2GEMKCXUUNSA3OSZOFDSRIY3E2Q3LMU2GNDA4JUYEDVCBFLXW",
-    "GCD47LT22YJ6KMNTKH5BZZ5TVJ2LWCICCOKM5ADY3HDXKAI7EAVXOFAI",
-    "GCD4AO7CCMUROSZ4XHUWXPYM6ZUOJIBK7YLUQW2B4L67KVFR4X637YLD",
-    "GCD4HSV6Y3KOCAP53IXM3FLPFNCDFQHTFDT6CHWGKYADCDEEMZB3SFTT",
-    "GCD5FP3S46ZCFAL7G7TRGOCQZRAP2ZG4BAVKISG4VMVAIGMPZDEVABCH",
-    "GCD64ABXDPVOZQOTALRIT3KDJRA2FX6T6Y27IAO77GXXF3W5QA6LPTP3",
-    "GCD6RPLIHXCWLUKUSPWTRPR2AAFHB7OMU5AJEHQCHRK7LJM2FFNTMZM4",
-    "GCD6XOVZZ4PUVDSP4N3RZWHKNJHEI5I5ME3H253NVLPECAGW2NC6ATD6",
-    "GCD75B27UJYVGKEEPBP7T3UOZVXP3PU2XERLUF7X7NZB7YY4RZXLWG3I",
-    "GCDAEHXKIWLU2TBHVXTRKPNPWKNWB5EIJHEISBN32DUNF4WYLKAHFTL5",
-    "GCDBA24OQHN6TPEUHOVPUQ5MG5YE546OF5ZFIXNXDVOXGUNCIFMVYOYZ",
-    "GCDBQ2K3CYSMJDIZS7MPIAD7W625DNUBFCPUNAUX4RPQBXRITMUYMPBJ",
-    "GCDBWHM3UJJAW5EGYJ5GLXBTK426H6UL7XBSYBDGTBQWXL634AAQ2OIS",
-    "GCDCHEPMAA56CGT7C7E3EBNFNSSAO3UWPS7XTKTYLKYC2O2YRHSROVER",
-    "GCDCL4HROCDRYOGRUOACIPYTJFTNP7U5UPC6SDYZ65IBIYQ6AVUWMHUF",
-    "GCDDNSML4OX3HI2OKENUHEFCN3QQF4MNJ7KNZFIJ7SW5LRLKLDS7K2QW",
-    "GCDDRDPL3SN7RBYLKZ2H3DB52FJJUNLETYVWMAO76QAI633S2V5OGURX",
-    "GCDDVBEYHECE2XTRDV6PS3OCZQDAACEP36ZKCKAEICJD7KQ2GRL3IKLK",
-    "GCDDWMWOHZSUDGOWAUIC6ABK7GBIRH7LKJBFGOQBVZSLQI4UR3JUPEG3",
-    "GCDEA3ZWEBLEEB6DRHWZJZMT35ILKKC64EOOSTMKJUCSPMJFX7SKJALG",
-    "GCDEOVVC3MHXCLSP7VN44XWRTE2AMUB4WTQALVG5IDQBBZPGPT275IHH",
-    "GCDEQ6CBGD477NUZTTSEPWYGQOHLSGUJXJTSJTRHXHMJHUBJBEPRCPAN",
-    "GCDER7YVUNIOCACC73QSPWH7TEV24PIDZBXGSG4BGOWYA23EIXIPJLJS",
-    "GCDEXT3XRLEUP3OPS7D3NVFST4PXW4HCVEI7AGVZUOMTLTFGPVBUTRAM",
-    "GCDEZA63RV2FB2NX6NTVZVFT7WFPVPYM4ZUHEXNF5PFHMJCUIKGJ4GRA",
-    "GCDF74AD7HYZGAXSPDBCFINK5IRUXQLJUBBB6Z5NFBA3H4TXLVYJWISY",
-    "GCDFX4AKWESP22VB5IRGMQKQCCAQK25RNTHOZPGTEUTIN6KQLS6N6VIY",
-    "GCDFZJPOWADY4HVQBO743LVRGRWBLDUFL2V4AULZAU4LOP4QPQSQRMEQ",
-    "GCDGDZYXGLI4QDY2WFUEQ5JAOEK2YLPETDLMKW2CYV566ZVPRRMB62DL",
-    "GCDH6IUMYZ7FJXZGUPXOIZV26IX7BH2RQDDUNR4HBS25MRTX6GPANSLW",
-    "GCDHJXAT4R2SINU47YSQ654WK4HNV2CTY2NYUY3FYH6CASYYUVS7OHX7",
-    "GCDINNX4ZGJN67VPXUQPQ3SZLE3NCB22PW5IX6Y6XD3M437V573MJU5C",
-    "GCDIOKXWAIY6YPZ7ZAHLF3TIEZ4HFDH3JNHNZSYEQWTNT27B2XDXQ4OD",
-    "GCDJH4RGVPUTQF422ZIFX7WS4I67QOATKAQK4FEXAHZGTUXYS5GAXMS2",
-    "GCDJHOEWDCKJS2QFQHW4BEO3EX7IAX5QTW5NIWON7X2YW2LSXJBDUTBP",
-    "GCDJJDQMUFUZRO3HSUWSV665KQB66MNAORUD64RM5N2M7AG42VZ7FYSP",
-    "GCDJUSRQ5QETNFV6N5TFLTMHH735XL4ZPNBA7QK3B3BZQEDCWXP6OXDH",
-    "GCDJWCUCZ4REFV2WJOJWYUE2R77A7ROYPPTXOSWCP3TYEJFXD7LZIRBW",
-    "GCDKDG5EINXRXZNH7SI5QF6ZQHTU6PBSGNHS3ZDO4WUTA66SPZJK4J3D",
-    "GCDKT3OKQDP2QMJN6Z4UYP4FF4SXV2MJKS6QGW2GGB4I5UZYYVTDFXZ7",
-    "GCDL4TJP7GSNJCMJDEVTZ7AK7ELQTISRTT67A5JGQSHDGWKDRWEVAVGX",
-    "GCDL7CRAEZDGXTQIXU63QGPNWSW5KRS2IPEGXUZUI3OYUMH4UUQX3PMY",
-    "GCDL7SE34MFWDMHZLUEKHZYUAKDPZHQRSWTPKQKLUH25O5O7QWESVGU6",
-    "GCDLYYMUPFTKMPHZN7AYZX4644QHTQPGJSHAM53UJCY7GPVZLH3OPV4W",
-    "GCDM6KYM3UOI7XCNILJNFHUZ6SY3GDWVKYNC62V4EP5M7BAKG2BFPDVR",
-    "GCDMAPSNA5UNBPJJDQ74ONGXWB46D3ZBN6C35RCUMQRFY3L4ADQE27KK",
-    "GCDNHIR3DCYHLAXVQP74I3RK54AAY77SUU4SYD5YFKTHZHTK5JR7BXLM",
-    "GCDNU7QKKR7D6UI5FVV7S33NZIIUNSBT4OOCIKVUXLVHFIA4HS5BUS3H",
-    "GCDO4FLAPUL3KHQQD6CDKJQ6VHIFVGKBU3OKC4A6ULNV4ADENWEM2BET",
-    "GCDOEVSLCWGVQVMJWHLQFRE3NR3WETQEZRXU3U7CMAI2GP5NZVXXCKA6",
-    "GCDOGVANHCOCNYHYWQYMTNSM2ULMU2U4BEF4AKZNGPHZIMTCEUTDWFRV",
-    "GCDOH6TXSMNLIHEQTSVHRAPFHUB5B7Q33M24WZQI5YQ6YKXMNSWXKINV",
-    "GCDOM3RD6DDW7GMG3UTF2XRTXQNZE64P6V6QIZCLHDVWPU6DRXCQQ6II",
-    "GCDOO75Y7373VBEQGSBXE45Q2HRMM2ML6ZQERXKUOMDTDLT2K7ZJW2CT",
-    "GCDOXXBFLGO6PBUQO3SFWNYNB2L6JMKY7GVTB2YQ2ILMZFHIXY3G36DF",
-    "GCDP4XK2HKYJAZB47UF3CW47TOJ2ADVT3P7RU3AMZPGOAILWIYPKWVSR",
-    "GCDPKYDVLEABQQ3BEFF6ELA3OQPTIZTMOCKCTKOWBSPGJFWJY6E6C7LO",
-    "GCDPP7D5G4QTGNCUOV5XCR3FW6DF6XEMMNEQ26ATH6VCRRHYFPLSH7PJ",
-    "GCDPYIF47NBPYUGFYV33MPWYGQM3LT7HZRWWDOXKZBWH3B6RY4UIYRWN",
-    "GCDQ34W4EN3Z4OW6KED6S262YWWFOMWWUIQ22AHEZYO7VNJM3NXPKR3Z",
-    "GCDQ7K7HP2LUNUONH5JWTITQCNLKO6RLK2ERFJTTE57T57TW5FWLA26P",
-    "GCDQJ7YYEFPTLAH4WJ7I3W62CSGQSDBQQZAZRDKHMITMCEEQFFNSNME5",
-    "GCDQPXCMC4UBCXEAO6RO3V5JJHUPVP7G5LSZZGLXS4Y4OJTYV5EJYI6B",
-    "GCDRRP3W26TWCFWPLIDDZIV5WC44Y5V6JJVJ6QSI3CE5CK4PAMU5XK5X",
-    "GCDRY33WIGBUJCQ3WGKUSBCNMPH7DU67FCRGS5JU3GXJ4U6G6SAFWQIA",
-    "GCDS4MEWITCC7HYKDZPQKQCW6GBNQR3KLUFNSZKTPKHO7JDBXNVK27FA",
-    "GCDSDEFECA5UAH36LA6J2QBTGMC6J3HTO5WIEZXV7XGDZHCC2JWLSKCR",
-    "GCDSHM4GYUYO23KNP5WSUJ4DA6YOWTMBNKGT5KMOZVEOGQMLGFUV7F3H",
-    "GCDSO6LQUHXOX45Q5MWAGZGKEGMJHTJBF3SCLXAOLEH45GG4LYQE4TSQ",
-    "GCDSZP5ADLMNKKL6TNMUQ2OJ5TIPGLRRGV2I6QUKORLNRYZSHE3NFNZF",
-    "GCDTLRHPDYUH5RFBORFRQ74ERO3CQ4NDJOSMKHJQCGBGQP5CD3Y6NDUS",
-    "GCDTTCJUOAK6JWDG3BGQOBYW5OVXW5SXME4OJUTDNRPBUNJJPDF2FVXC",
-    "GCDUGWUAALZWMN76SHEB4QWEJMPVEHVR2NVSTOMIT4HHZ52MWF5BLAER",
-    "GCDUJO7LB6HFCZLMTQWOVMB7UUTVRMIKSHLWBWBFQJNMRTMGJNCHOXNC",
-    "GCDUMCBTX7ZASPVGWRYKYQ4VEHONZEXXWHDVH2D6VCI6CQE2EGVLSIIG",
-    "GCDUWF34EQ7MDUOO37RP22ELS6SILM6ASTMEMEFRVZKQEZR3BFZ6RAS2",
-    "GCDV5QWVWGN7F7OPOZ7ETWPQH6SZS6QU7U37DT7LEPUBQU3AF7WHLKH4",
-    "GCDV7U6THFNQBRQ332NAS3I3QQB2FPC6FT4YVBKIKL3Z6CQ6DOHMZQMG",
-    "GCDVHWVOHLKUQFSQIN65VDIP4KBUMCZRYZTCRYIAKJVD3UGE3LIJ45J2",
-    "GCDVNBG5JPJA6URUJKNDHGSUHXZZTSBLXRYZTAGXU5AX7OXJA2NXU4ST",
-    "GCDVNWPKV3YSVVQKZIBA7KTV3LO5M7BC7KF6DFXD6EB7JP72A3IM3ANH",
-    "GCDVPM7VUPNUMKK6EPNP3WC6D3JAP5SBYZQOYXAFDH4FVU6O6KBIBRM5",
-    "GCDWIQCAVA22UZCYQEW5MPVLLCXWNHQO3H5XYMVET7EINIIEUB55IXXW",
-    "GCDWJYMOZE75IXAQLC7SQE73RJ764HB7AFWDLC3MNUEBBQWTNWXUTATE",
-    "GCDWP3OC377SEGIC7ACBT6SKM4O2O6EIFIN6NFOPOGKISSOUZ7XGJFSP",
-    "GCDWSKUDGYQC3FTQIHQLKF6RL6K4VMXZ3OWRYRKKLEPHMIR5E7HPIXN2",
-    "GCDWZYSZBJ46F2W443CFC3WP2YXP6S3SF4CJSW7XL55V5GQKROXG3KOH",
-    "GCDXI3WXRLI7C3LLJZ4TVQ2AN7GO5QQTGF4OCIBOMPJO5MXNAGXPJXK7",
-    "GCDXT3D6IGSI6IHXJO2UY5QRTNE6TVDVFKL6YKDRJTCQLDH3IGBSFMST",
-    "GCDYHNYDBHY4P5E3P6ULONCEP4QDBT2ZUTBOUXLAWG2FNUMW5JVZSJUX",
-    "GCDYOAE7XNHVT6JK3S6RJ7UTRU75IPENLJ4XNJ7C5NZBKI4Z63M7UFEN",
-    "GCDZ4ZHYONO2AYXAXE7ED6FCNFTQTBLULQ3EDI7NDEIOAFTRQBUVHMF4",
-    "GCDZBGSCHBGMPZ4NQATPUY2EAHR2V77TSDNJVMCPBYKMZSHTU7DTITN2",
-    "GCDZMZ7KHADQ5GL3VOTVIF4JTFFIDAQJOEEDIMCMXPKI35B75CXPETVY",
-    "GCDZZ7PZHXQ35J3L675Q3HSZGENGAL6W26YZSTPYGONBWFIJPHEA4API",
-    "GCE2R45FMHTVSUDU4PMGO76RI4CO65NPWNIDFUZIYAO2H327V2SQW662",
-    "GCE2T36P6CFAGSAMKHPHIVY42IOJTRPQMU2CDJ7BTKXZK3UD3TQVAHHY",
-    "GCE357A2IHNHKBFGKY6TJROFFWPNFWBO4IKVOF2ABDIFQDYCJSIF6HHF",
-    "GCE3QNRSTMUBW6ATW73TZGLFUO4WNMPOSAMBXJIRNSOXLYXLLKIV5SCS",
-    "GCE3RCXT5EPPHMT4ETM6I5MFCCXA2SKOAEQYIXNYOWNLL33H7KNOCFLR",
-    "GCE3Y7JOSI35NIHK6SFHJFT6QTNNM3RRF2MZKSCFVGYOYFA2EJU6FPWR",
-    "GCE55CY3HW7DYSA7L5P2CVT5MMR7EEREJKQATWHJNWUTCO5Z422XJU5O",
-    "GCE5IUHQ5NW62UMVB7EORMHBM3H47GY5XDFCIMOKMZWBKZTQBOGJYSER",
-    "GCE5RVH42H7XI2M476ZEIUGLKPMKLKQTWP74GQC4K6J67WEE4DHC6IXV",
-    "GCE64TWLSXNZTHQFWE65IC7GRR652W6V2UU7FYMACFC5SBAZ4IQ6NPVX",
-    "GCE6SAKFMUAVWRUMLZTDEJREDZXAKTWF7WPPNX37EIXVG6DWE2LPIGVN",
-    "GCE6STI3GCR7QZADUTYU7J52DGSNXF7HGJ42SMAO66NQK6ROLTKVV7PV",
-    "GCE6XFLTJNDWAQ3HLHOSEJGC2FP6OQSPJECZVHXJZBOJ7JYZOHVFQAFR",
-    "GCE6XNG5BNOVCANQFF6T4DOBQ74LKKH7U75UZGDWZJQYQYFPKXSINDUS",
-    "GCE7CZFJZ5UBXWD6ZTM7AV4A5JISKKWCAZQ3F2E6YUWTGKVG5G2TQL7X",
-    "GCE7DEJSZ7UHRLM6UTOZPRIXRLY7R4QHLSVSUJTSX75E6FBSQUK4DJ7A",
-    "GCE7WULTQWYVYLL7EKC4RREU3T545HBIPV3BI6ZRVU63L6UF7GGDKM7C",
-    "GCEABBRFV62QEABFDXUPUF65ZSFISSSDT7VBVZQX5GYSVWQIZL6GSORV",
-    "GCEAC2M2KWQUFVLBDEUNTN22YOMYZDP6N5JUORIBURWAVIXPQZ2WCM27",
-    "GCEAEGC5YF7LLCHJMEFC5K2ONEYEHWNZ7OSXWG7VVTC3A6GAGCRZYWVJ",
-    "GCEAETRUOWV3O4EC5YMY73VVVURDR6ZBBMVJMWTLGD3WQMQ3FC4MHKVV",
-    "GCEAMBGIMFRRLUMFZFL2UHC6BAZFHVXV4JEL6FZP5PDS4HJQYJNACPBO",
-    "GCEAUCOQING6ERPSBLVP3BS75DAZTT6XEYFFHSPWRISP4QZ6KTDUJ6T2",
-    "GCEB5JE6X3CM3I3F5M6XEBG2YSHEHEHZKM6MMC56M5WWD2K5PFHOMS5L",
-    "GCEBBMOC3SRIERQFHQKDQS35W64ZK6FEG7DOMKOQRBCHRE7N6U27WHRX",
-    "GCECGFUW5OJTTTDFPCWGXNE2NR4OWNRQC2E6YUKINJRVEMWG3U7OXXDC",
-    "GCECYZFVBCN5QP7PN3SNMKIRNXVHI2KIVBW3RTYGH3JBTWXGWQYZS7X4",
-    "GCEDP3ZW6X6W6LI2T6AVBNPWE7GEQKJNDBA2D66YOIM627FL6JU2LIUG",
-    "GCEDZSANQAS2GVH3BDEMEDKQERX6ULDOOAQLRXPALKC4V2BT2S33DPOV",
-    "GCEEBNH677Z4MGMSC5N3U7ETSZGPKAKRZORT4ZITU5FVB6QUJZZKZFLR",
-    "GCEEJJNJXASZZXA2HVSCX4P3G4F6DDIMSTPLI5KVP7JV4HZHU46ROE7I",
-    "GCEEL3MPIJIRCYHRHOGEYRSOWE4RQAT3MDPI2V6I6AEJVULWTLRUKIUS",
-    "GCEFAXGDLWWXPKAPZR5TTNTS3K3FLN3LTML342W3S7AVNOHT2UA63DS2",
-    "GCEFKEIUI4VX4YXBL4VHGJDVWUKFVCXZHZFWBDJBPGU5CGVPX5DJZ6LV",
-    "GCEFV3UN5HRIPCD6HLUKMTG7RDU45DSBLUQ7NCDQBWBFI4RJXLPMW4WJ",
-    "GCEFVQ6YVIGKKLJN624FM6P2R4VBKJYOO5YW3EOKMWKP3NQLPGXQY4ZA",
-    "GCEGDAO4T3UC4KP3I6U5BWD2OZU3SH6TTGARF34PTCITUI4M34SG34WX",
-    "GCEGERI7COJYNNID6CYSKS5DPPLGCCLPTOSCDD2LG5SJIVWM5ISUPERI",
-    "GCEGRGI7BIYMVSOPGSHERELVRXL4WOSXPV6R36XDN5PCW7JLMAKJIBMQ",
-    "GCEGY5SL37EYOF2ZV7EWBYIPHHCQMYXNYAUUT4O2E2URIKIH7MK76J4C",
-    "GCEH73VGFNFUWSK2AAA4IPTH2XT6HGFHHDRKVDJGEWFVBUYTWWP46QLU",
-    "GCEI3HMPCIBSLO3NPAFNSY5AS5UH747NCCLJQJLV5PPUI3ULADHHPAMP",
-    "GCEIJVBULZVRUBSVBRJ4HNLHPCT4NWYFE4UQ37ZT6Q2AMXQBFYWQ2GDJ",
-    "GCEIOIBXYE5CNJH7CHTIB4CHUZWDAOUSDU3AOOTZTGBGEO74ZMEX2TAK",
-    "GCEIOTMO7PRJB4G5UIX3O7CXIFSJ7JTA5KPN66P75WDXMGDCK37AMXGZ",
-    "GCEIVWM3HPRGVVLCNHE4QB6QPGUGSOFE4XPPLUACT7HY4SZVMPHIEXLM",
-    "GCEJLQNLG6LUM3NQLOUXMSZCENCGOHJOW74SFRUCHTDLPIQFXAQ2OBTC",
-    "GCEJMRQIV6MYKAHWVR32G7HR6VXFW4LO7EQHXWJXWS6ROBUHJ6HSELON",
-    "GCEJTQPPVM23ZX3I2YW57VUB45WWKPJ34ICN7UNFR7SU3ZYPZBKSU45V",
-    "GCEK726BF5YG57YVQMNGL72R3GXLIE57VNSK7MZCRLEWGBO5TBHS7GXK",
-    "GCEKEC5WNFJLOBSYVE3YUKPKPLF5ELBBE76LBXOMWM2XTVUCUTVTUANS",
-    "GCEKL3ESBTIQWZHLQFVWX2XSMVJ7GAEVPX37LMWBN53TOYFWAVEPA7YH",
-    "GCELCPIUOP2ETXQDUEXEGUWYWDDFKT6ITSUEF65YWN4TSFCO2FYUQK7H",
-    "GCELJ3UOB2CLXBB6CEAZEE7OIA7V4G3RTOTDZG2YQCXCNSIJOR44BPN6",
-    "GCELOZVORGFX4WZUXGYBIBGOX3DNDJWEMZ7RQK36UZ25UYENOPSL2EII",
-    "GCEM2ZHZ2AQ7DX3B73VQUANAFP7IFZYSSWMV3Z5HR2XPUSLKYNWUEVML",
-    "GCEMIXCFGGMSGWT6UWOFN3TY4HPBWQGJEDWMKO645YNF2GIW47VWCTBG",
-    "GCEMJMC7A6NNNMCO6FF4OIAXWMBMLL5OHRYYIGWQFK765R3T7VP7XNQP",
-    "GCEMPSV6QTYYPRZJUM5TNHNANKENIFW5QXS2T3FPE4W7VLLTF2LEQZRE",
-    "GCEMQSJOVXQVQLOW5CP5ZBRIFE35RJK2BPJ2OJW5QVS35CQHL7ZHONTZ",
-    "GCEMYS64X73RJFOKF6M3I572GLPADFR266N2AKQWU2RZBNVYZATEXVCP",
-    "GCEN23SLLR4CG5LUJSQ7AF45U3G6SGHJCCYBHKA2VP2LWMRZYFV3U4QP",
-    "GCENBEUW3R75PBCOS2NIWDX66O3FKCHABS5QDOIVVNXZHZ6G4CGTJ2PO",
-    "GCENE2PU6AFD5GTV56LHPTHWIZUPCZFGWOQ5EEA6RFOJHFX5X75EDKPN",
-    "GCEOLFP4T4WWIH3LK22FQ3HVX23GQJB3UZ5XWA6B4MVTNDYJCVGCO3WM",
-    "GCEONEZYLZ6U7YY2H3NSTCX5LP2HQMYFPAUAUZXX2Z4WXYIZGBZWLR4A",
-    "GCEPDAZF6QW64FSHGFWTHRWHLGTQ2C6WHQZH2KVO2QACCZSMX5QEJ3MR",
-    "GCEPISGUF2QIPMRQ2FFJDDNL4Z32CI34VOWYK3KJ3FAYIA3E2KVLUF7L",
-    "GCEPVJUOKBXZTNQ33KHWQI4XYCBAKJFKHS44UZ4IYYEI2FUQWC4QOT53",
-    "GCEQFS26AXNWDVVB74XRPVQFSMGHP54BQC7KKAU3JMWOUJPWEG4CJDNG",
-    "GCEQOVT3A2DKRE2D3KCXB4ACWUADLNWD676CULYFTB2ZPRBGBIYCEKBM",
-    "GCERGONQ7C4FL4CE55VYSXYUTJNFDBE2OO4FOUOB2UCMPNPXMT6AMTOP",
-    "GCERKQJ2JCZL5G4HPL3MIZ3LYIHDHVWWW7FOEGGNXRABJ2DM76OVZBSO",
-    "GCERUV2XIV3MIKOBBF2XW7E6MCHEKPJ2DTRWOOUFGF537J2F3RG5PLAT",
-    "GCES6ZZXTHDNYLJMLRZB65JNRUKCZWHPOONJBO7ZDMVTMPNDECF3AE4X",
-    "GCESH6MB7IEPBY3C4B53GNPNWKBFFKNI2BUGDE45W5PTEIH4C5R3KWTG",
-    "GCESYH56LIJLTLAEGNOS5WSEOMBV6DHAEWCRRWVQVJAFA2JVJIVSSTOK",
-    "GCESZ5BR4JYQJSJGAVD2ARLAT5GLMYTLOFEGYPHSRM2VSJH64S2PDNE4",
-    "GCET7K6DEAB4VRTLIFOJCZQG2QNZSIRU5YJQOPWIAQUC47LKTHW7K47T",
-    "GCETBUDXFLALN5TGXTF6WGYMWLB7B3HBRKBZ7IXFPC3ZVT7EV55G24HZ",
-    "GCETCVQ5WRA43XTTFNYLVFP33EAN2EYRIURLLVNYURKOTY67R4KIQ46O",
-    "GCETNV6WXNZZKKQVFE7HMKMCBSYSFXLCDFUN3I7BWLZ7QWVSGRSZBOFD",
-    "GCETSI2SEWRBAVUJ7XJWTQX6YV635MYFVMISSXW6QKZQIEP2FDZZARGO",
-    "GCEU3SFXZ4XUAGL7XRUV5UIKZ5Z2CU55KDBUPKHF6A3PADPMJZQ7522T",
-    "GCEUFPM4BASMZJYFO5KO6WQC2PCBAEHY6ZCCKXHDV4WGTLBP5ZP3D4ND",
-    "GCEUPMUEL5VMM7WPD3GOV7HSY43V4GN47AOI56WJL25EH4KMELTY3ZS4",
-    "GCEUXIKDSX653N4PY7TW2OJQEJQHB37EWKN36PDE2TOQJ7IKBFMCFS4B",
-    "GCEVCJFSSNEK4R2GXKVUF7PF3ZRCOIDQPVULJ6XS2HFWZJKIXL4ZV3O4",
-    "GCEVI42V5OCVNLLE5PHNZK3EFT3K5SDCJYBC2KGW7XI3I4TID6L6COCA",
-    "GCEVPKPYNWN7RQO3G6SDF7QBV2ILFHBNLUKQO7HHANBLXSQ3IYGK7UU4",
-    "GCEVVB34ULA7C6ZZXPX5U6WTGWSUG4OD2DSHNSKEPMMWFKIMFUQAVOHJ",
-    "GCEW3KKR7CLRHVCGQA5OE5IEZ42EOGAM3GJWU3XASFVEEKWFRLQOTATE",
-    "GCEW7TWJWERW4QMCR5BBQGUXDOIRWY4UUDITES2NSIIGUHCPAC7EUSDT",
-    "GCEXE2I6SSY3667CHWO3KG6T2H7VD7E5GKRD46GAKXS43EUTZEKSPOKX",
-    "GCEXRBMPGHH5RXIVW6DEBLNVTUAIEMDRR5IJOFHTWTFA5GA5R55Y7ZZB",
-    "GCEYIEI2CTZDUZGQWBZPVIHEL7MY67I65AUAWOPM2RLLSAVE4TCT6A7D",
-    "GCEYKXA3ELTKQSDM32VNFJQULE6JSQTZMN5BA7ZCQYVHG2SCJ4XU47TE",
-    "GCEYKYQN4LQ3Y6YTDZOJ6TYR3BDO4BRR5MG5EK3JVUF2HXDRJAMLGLA4",
-    "GCEYUFJUGPVXUSXAXDOLQFGRO4LBIZNA53SCN364L2YU7JF6OEGGGDG7",
-    "GCEYYWTEZFBAPXF3GZIFHCNFZQPB47SQMVM4QPM6RKQHR5ZOZ7UFGXLM",
-    "GCF27SN3VQWU7QBVQJJAWORO5BE4VR5XB455UKWLDG22S5QOS2PIUPEX",
-    "GCF2EZ772O3DHX7EHHJK5WNGO355ULYRPQDLBKTL4PLLS2KQR5445FIJ",
-    "GCF34GROPLW2TGQOWTXFSJBA5NV7BCDOAJI3R6UKXBSBG4YVIJUE4NZU",
-    "GCF34QCP3A6A2DVV23HTZU7NL5X6PWNGLVJV7UB2L5IHGIM5L3ABSTEP",
-    "GCF35WJYWIH4IOO2OAZLQ2S7ZZKWBR3HA32I6OGAX7A7ON7O7A4PDIXH",
-    "GCF3LKYZ2ICFELZGNY5YUW2QDWZ33WSVDERTSDEI3YWGWJVUEIV7PNDH",
-    "GCF3WZUSDMJYLK2RR2K76VLM33YRBH4N5T6L6LAZALFN7NG7NL4A67C7",
-    "GCF46XWS65GVISGGNDZNKO7JEKU7Y6ZHF7HMOZUEEBBDLTBLL4JXUEZP",
-    "GCF4ZH55FW4TSBMSACIURZSPFKH43HVTZLJO5POLBDYFWI6EU3BJBBFA",
-    "GCF57J2KL4V2ZZQZFU3S5QNELWUDBSB4JELS3ITPI3EC43OLTJ74UEIR",
-    "GCF5J2IPKQK6C6F4PLXLUR2YWATMWPTONMFYVCIJUAF2L6V5YPYJOEXC",
-    "GCF5T2ED4SCUEU3RGQYAOPTT2GXLHD73CTZADJCN3H6VFOHJF2GKFCFX",
-    "GCF66W46SRW7DKZSAS4GM3ZVDSJFXVNBBSIJFAB2UHFQ362CONYTDJXB",
-    "GCF6JWPZIEM3N5IVLPVI55GYS6NC7SGMJ5EOKJ7ZUYTYGYM6MXE7KWAX",
-    "GCF6NNWZABDNDTH4KEF7RZ5VOTUB4HRESZWN2U2PKVADMJZ52DMB3FXB",
-    "GCF6SD6ULSINV65FROKOHCHRADYAIW53XRXDQ7VAMPX452RE24CM7KZ7",
-    "GCF73EELU7AEJYUEIBAFJ25KDBNZQPVKNN4GHNB3WK55AZ2K5WD2Q67L",
-    "GCF74NPRLSZPYFPPNFO2M3L4EWYG56VDPPJWDMPH4TKLZOEB3HIN27IC",
-    "GCF7AKZKRKGFL6JPMQ4G3YL4AMKTGBWH5JYTGLY6S5KGDH6UOGBK4UYG",
-    "GCF7L2QGCZV6D2JVLW24NJGPCMUSVVJ7TLLE4EE6PL3HTRE5FPXGT3RO",
-    "GCF7VZBROHHVB2ZNV2NULQOI653CWPNBALRO4VGXMZQPBG3L5MXEE3OC",
-    "GCFAHDKHTHCC7JMZSP2GYAQETUGIGDNKRIG3RNOUJFHEN5Q2UJCXHK7M",
-    "GCFALBYLEXXQ4Z2O2J3KJZPYQH773MFA5WWOC57R3J6OCKC4TSK34MXV",
-    "GCFAQVKEX34RXM4FV7ZHJXOWXX64J4CHMAYLTVKQLWIHNA2GYKQSERCO",
-    "GCFB4ZVELWVGQRBKUQTTLACUNG3MCRH73IXCNUSFJAL2IYINDLYRM7PU",
-    "GCFBDLZTJM5HVSSZPCNG6X3LRZRSYVVCFXCTNAFZGLNTSIYXVRYUSSET",
-    "GCFBI5PKPPIUVHMLMVA4MG3EPJZSNNCUBZTWJQALZJOPGZZJVDBDKAOQ",
-    "GCFBR7KEAXPFU33RGI7N6QOZ5DMEC7P4H4VY7DW7T2PTVSEEO3LESUWD",
-    "GCFCODCNY3WI3AFIBI6ELRBU5M6NCWABESCGKKECSNUHSTWCDN5QTRLK",
-    "GCFD6RFRVSPPWA7GJT4W524K4FHGGUNGRWTZN27QWBVJ5MJWQHXHE5CX",
-    "GCFDDLRFOLJJPHSQESIEO5DGLLX3C34F47G4FH6CAV3C7PGX6PMJLEDG",
-    "GCFDFT2LAP345DIA3HVS2OP65KI2DDDUM54P4LZQCFJ6G3LQMKMLMPWG",
-    "GCFENG7SNQIW4RWVDPL33PTGD74ATRFQD35ERSEV33BL2AQNPKI5FWVG",
-    "GCFEQK4OMW7HO2YDZHU42ER5A27LZLTNH2LFAJUSHGEUXUDHSPK7PSJQ",
-    "GCFEUIXB5CQDHYCL4323U3MZ2XNNNPKJNGOHYO2PA5AJDQML6AHP7ZCE",
-    "GCFF4YWVOTZXAGBSBMIHPI4TXFQDFJOTJUMLLUJPV7XKNZMZ3BX4OZYM",
-    "GCFF6OE3JNWKYLNNQMWKEA3XLIIKFPOSDIQB3MCISMR2MWQTLIYLCARD",
-    "GCFFJBTTOJWXCKKENCY2WY572F5OLOGEBW5VUC7NVABGFKAE4QSTCTCH",
-    "GCFFMOEXFTY24PS3ZEGGM5UEUD3FXG7MIANQYVQKRCTND7CAGD4Z3JCN",
-    "GCFFRUT2GUIUZTQEPNZUAPMCIPV5LQC5QSH3VRC5K3TFNEO7SLGHOSBE",
-    "GCFGJMJ433WBKFDBZLU4XWUYS7SYNXIGLELQUEMO7WYR7ZMQB4FGEPK6",
-    "GCFGQBMMOYCEDHKYWB2TFJ4GTAIG4FXI2X6W5DSOLN4Z3B6KT3OROLPG",
-    "GCFGZA2MIHFFZW5NL6N6HAYNQC47JEF4CONGPOVXULNVCO63ZZNVVGIA",
-    "GCFH2LH4ZVMPACH6RWWAKATAAPXGPRIZM5ZGZZP3PAKXGGCUEOIY6IWD",
-    "GCFHAYEKOOAWP6GDOWI3BJ4EDYKKQLAHNZ6EYDMR4BZLXVTAT3PK7SXA",
-    "GCFHJTKUAVCIH7O5XSIC6RCMS6BC66ZFPUC2IGHDMBNEOEBN72OOMUY3",
-    "GCFIEGJSBFXVATT3G5ONQUL6W2UBBML6THI3RUTCSRP52KY7QW6CNPAT",
-    "GCFILVXLVR5CSRIIT7PFL3B42SCRZCSDQR6AT6I2TI7JNWRC4XGXNLFB",
-    "GCFJ53V4LRD42TOT2KXJFQNYUXNDTNVMEERNIOSP2ACHZULCI65IG64D",
-    "GCFJ5HFZFDXMF2EOCHM42PCLIASCP6A4GOF55PSTDRMP2RNMCWCFZBJD",
-    "GCFJMKQOKR2CTR3LER3QOMIWCEOGACG3ET3LDVXS3PQPQXJTGVZJLGUS",
-    "GCFJURIC7ODBMK7TZR26CZQBCUGCRJTQAEYSUTNLKVLNTHNARQXIIVSG",
-    "GCFKDPFBN5YJAY7JBUJACQE6SL3WBFTJ6QXCJGDRJWK3TSMCHQ76SUBT",
-    "GCFKGJ6GLA6G52ZVIGRGOAMAWD4TET4WPBULJ55DM3CBWXB2WJL6EGCQ",
-    "GCFKMFETRI42OJ3IL2D5NDIDI5AE2ASLZ4JUHIIWCDZQ6Y3OM544KJ4L",
-    "GCFKRNLUL3LEGEEQY6I7DTEGO3BGO36WYBCOKMJ4IM5YDLRXUMYS3PAQ",
-    "GCFKWCUGWF6WNUCFEJBVXIP7UWSUVJW3NOBAOUB7KHYFVMKVXNQ4FF2X",
-    "GCFLDH4FU23WOHQ63HCMGD6YAE7RRHDQQQKJL2QNIRMGC2RAJQJHC56V",
-    "GCFLOZRDL4YUWCK577D3IIT35L4Z3J67NLVUTKMRVNZOFDRLCN55ELON",
-    "GCFLUGSIAMX4JNRNO6SFE7YODZFRG5DLP5TCBZBRHAJ7O34VTKXW6IKI",
-    "GCFM6YC4LOS3QJTB4RSBYE6SNIT3ZABPW6DFOMYOUOOICL4LCXXP7H2A",
-    "GCFMO6TWOOQPDED4GFRA2YCZI7ICGML6PSLFAWB54JHUDOYLXOVSQGTX",
-    "GCFMOG7KSKVR2J7F2RI7NXSOISKCHIHMSNDINLI2GSETXWPMU5QSOR4G",
-    "GCFMTGM5N6FNJC5U2C7XF354ITV3IYSM7DLUXJ3CC4VSUPYSJ3YTCH3J",
-    "GCFMU3G7FP6DSFQW2542NE2QRFC5UCQIXXZTK6HU3VLS65BQUCCVEQ7U",
-    "GCFMUFXOSGZSGJXAW2UKC6EKJDAKXR5BF47QVGQSPMYV5MAZ272HWAUS",
-    "GCFNLIEYORGXLMFVNRBNHV36IXEU37GVHEECPVNPOXN4SJRS256QZOXY",
-    "GCFNMLOYHPLJ55T3YJXX6Z62UY76MDKLT5OF2VXGUGYKS5YAOXDQAUZX",
-    "GCFNUWFWAWTLQNKXGQK3C7CDAIYXO42RLHH7LEABRA4CAULH4SCYMUA3",
-    "GCFOAY35TR2OHJSD45NHXCACROGC7BJD2Y7ERCJDJC2SI7QJ7TANXWEI",
-    "GCFOSQZASHVKZQTRWXHTM6GRUEMERPKHDCL3ISPUKIC2ZZSWJ3U4EPAY",
-    "GCFP7YKIFROGVC3DDEOVSFDDV3FFM65C7NLQCZKQC6R2GP6X7HNHELON",
-    "GCFPARXBB2J3BPNVOELXSZEUNXLGRTTWH64HA46NIS3SR7ZCOKBUAUNZ",
-    "GCFPHUYJLV6GPY5LBYI5NINMTH326FAN7FK2SGRTMBNTCBZTO7F72PG5",
-    "GCFPW7462U634TF4VSTCLO2RENMDY7TAZGLMHSDSRJ2O2VR5XT6NGGPO",
-    "GCFQ4SSAN33TVGJKWVJVZ676Q5NRB7BKEHWUDAXSNJ2E6BBXXU5PGX4O",
-    "GCFQRSVCFNUMOOK2GZIB5R2WD3TRBOG2LLMMIFFYINPYVB37TKTLJFTW",
-    "GCFRQG3GZQ7YQE3BBCAN7B6I3BKIMWWE4J5YG4B66PNJ7LPHRQAJJHVV",
-    "GCFRWK7H53F4EEWUDBJLVKUZ3XWK6MLXVYUIXWPXIKEB3TJ67HAQP3JD",
-    "GCFSMWQSTMORYNEKFCZXMOVJ5XDU3APML4TRL3NSH7XVKVO2P25LEUVX",
-    "GCFSTL3UJI4M5TOVYVT4RZI5LXCTCL3UB3PMAOA5K5QHSJIMBGAXM5WZ",
-    "GCFT7DVLP2LIKOJNE2F4M73WTF4LHTYGNJ55KGRP5L2O4TA6RCZXXPYK",
-    "GCFTIJZFJ2BTV657G2ZQA4DXTEPIHRVTCAGXYNPZQN5KNSOQPHQ3QXRP",
-    "GCFTLO67O63YCQYFLP2GUSSAIRACDHCOEG7TKJMVMM2RM3G6R2TNK4ZB",
-    "GCFTYYEITSO5O6JK7JO73TCHAMH3KFCKBXHZARI6JNQSAKB733ETP5BA",
-    "GCFUO64A5SK54FY6ZTGUPK4VNEG3IUG7EL2TXOON65YOK6NANT3SRJUE",
-    "GCFUPNIHHNN546XB3IYFEJ2ASKGSJELRW3CKGEFR7J54FIPNWBRCY33R",
-    "GCFV2GJS3UGCK72L7KYTNKWRI5RWGY3GI7GYBJSTPDMRXY23XCVHNBUE",
-    "GCFV5FALMNQNMYKXTLEXQ7S34HELDXA6UPGUBARAWMCS4BYJFJKHSFJT",
-    "GCFVDBXWWLTQ6ZWLT7FMIKACBZCGJKRYPI6O75KPYNCNWULFEY6O5XWN",
-    "GCFVOIT22DI53C3MXRTRGCCUKT3KY3XMXED3QZBF25GHFCGVELOO5KUH",
-    "GCFVR4N6F7T5SKHHJ6OIXMYTAVZW7GHMKILFCV5WVYRFQAND5WOXCHS3",
-    "GCFVRQVK2RCOB6VOT6PEV4RSKRWCWTWT62ACUJVSYB3WKNVXPKH44YTE",
-    "GCFWANRHP3NCLRPD326YPJS4RRVPPZFKK42EX3ZPOYZOJUBCHIRQR63O",
-    "GCFWJJ77SR6UFW47PPYV5NQNBP3VXXDH5VKSS3TB4HR6QHA436LXGJHD",
-    "GCFWR7QSSD7VXXNRAO7FJDSQKFEUQADA3D4ULOLKIZOHDWTQ3WWIOBAY",
-    "GCFX26NMYIF6ACI7PUNQDN4DGCHG4WCWSFPD5P4SXYM6WH3A2L6XI6OG",
-    "GCFX7BTMOZ6S5GTUV7UUXMFZLLIREVG5QKG5DTXQ4VH22XTBUWRG6Y2V",
-    "GCFXBEPLXWGMELJCKXEFX23SQ5VCY5ISUELJLFHL7OH266LIUQOZFIY7",
-    "GCFXTSSDQU4HEFIOMCWE255MQZ452P2EHEJ6QJBVJHB2Z6YJSOUH6ZS5",
-    "GCFY725Z36F6UO7IYJ2UBAKURDRJVZGACVAYTOMYMJF552Y4TIW5ELON",
-    "GCFYKEF7564DHVHTGWTVQ5UZ74SO2YLQCIOA5NOEP34YIA5KZI7DGFJ5",
-    "GCFZ2OQGZHBMFJ4DAARFDGR42E72XJB43HOWAJ4TZP6RR5EAZ7MLXKQL",
-    "GCFZQFNEK5PUN6OSP6QXDA5IWYVRSQN3NQJSVRGLUXBMJZ6KXLR7PW7C",
-    "GCFZVSI3WFAUGUWHCKYBL2LA672AXEPSPUGLFSZ7TPQCF4SYOHDXYZXZ",
-    "GCG264F2ITM2OUWVZXXR72GGMAJZTRYTMYZN6IJNZ475EI6J24BRI5SB",
-    "GCG2HU3HYCQRM6CAODYXQQ3XMOXTTQFWUHHSEFLWUYKDJMBUYGL3WWAR",
-    "GCG2N3K3PF74XT7LURO2B2R2Q672RCSFNJR7BMPDW5I5MFSIDQSXWJZU",
-    "GCG2S6X3E3I5MP44ISKDGQ37TYKYPCOMN2WK33OAWAHFSZWAQPRB47H4",
-    "GCG2UHXZEGGOSKLCOKJUNOK4UTGIWAU4CPQNLQC2WKU5KKT5QBLNIGZT",
-    "GCG2YYHKADQQIVSTYNQ253EY2ENHGQKBIMILKN6TEF4C6RK77JHBWXIF",
-    "GCG3FXGVIGFOQEEWDBTYCC7ZADI6QBNQ26OCMUNGFIYKFE6BND5AMASN",
-    "GCG3ZCKJ6WAYTKZOSNOF4ECK5ZWWJS7JZWEMRR4Y3ONYXTEVUSBIU3A7",
-    "GCG4JWALHO5HIOWNVYLJG6RBDO62E2ROV34ZILB7UJJWPSJ6X3RGWHIP",
-    "GCG4K7EJ6RG3JCJUTHKSR66WILNUYWLW6YTEXNRNCAFZ643T72ZA63RT",
-    "GCG4ZGPPAYRRAEMDRVZRBLVJV7M5KJ3347WQTPQSQEAO65RPZGXGA47L",
-    "GCG52PRJDRVIQQM4XXFVFHX4DW2RNBP22FCLJXSYKNAMQTKTVDCZBRIC",
-    "GCG547DUFO5RG2WWQTYHIHURU3VF5N7EO7L34FBNFFPBHMRQVTHBY76U",
-    "GCG56HXLTV2XCS7LY4NHVFM2UESLOK6WZVI3C7V3GTHSZSUUE3KWK6BG",
-    "GCG5BDJCGFDWXAPAV27R6E4LBWHMSLQVO267YNGAQLSCUJ4D25UJUETH",
-    "GCG5POCNNNYX7SMJOES4QOQRI4FJV3FCNISJQK4WBLLO7WTKZGX7USDC",
-    "GCG5QHCPL6GHWLTO3XOL7USX5MC6OGXU36J7A3ATED4HDFCWPHYVHJJQ",
-    "GCG5XX4NUHP44L46FVVJRX32U7LIQNNBR5L4Y2EG7VSH54YLL2IZIRHO",
-    "GCG6I4ZODJEVMALYL4CKLQ6PRMAJFMLM25Z27QZ2KKXVMM5VYHNQWNA6",
-    "GCG6ZERHTS374SYXEHOHU5E5FC7N2J7JMVQUL6REYAET3ANUMJNGWIVM",
-    "GCG6ZPDNNYMY3RENJP5KPHCBCQLUBNRGLBGRUEQH67XJ3S2DLFUTWPFY",
-    "GCG7H6BSOYOX4VRWYBZK7G2FVS7IWONXWSFACWZKQAFFW7KZR4WSYRLK",
-    "GCG7MBBL6NTT3MNKS46OJXWAJ3EXXR2T7AEJTDJ7G5HJXFK6MXY2JROA",
-    "GCGAC7OUFMXFHRJI4562STY32CM5NYIVLCFXZOVGFCIFVPZGWFZAG7NV",
-    "GCGAFIIECXDR5PVRFAF2FTVX3REOF3NRCCSJB3KPEWZDOXCZTGS64MR4",
-    "GCGAGEPPKTKD6AAVRN564WO4AS22X2JKXGWM5V2RK62FXNXIOYGVTHLV",
-    "GCGB66RQMHCOMRGU6VCPM2AETRMCXX7KOPABGXVZO3T427XQMCC5PN3P",
-    "GCGC3SRZTZ3CP5Q7UM76KELBTP4DQZ4BUDEQ544X7H24M44BLLFZ3Z2P",
-    "GCGCT5W437XP2FOZZDE7TWBPBZ2MJ2QNQEYIKXE2EB2SPYYJW2UMFRGO",
-    "GCGCVKXVMX7DALK52CBM2GEGFKQBFD6F44N4SYF2HFI7VZCSKHIT2EO6",
-    "GCGCVOXDCNKDVGNEAB5NJKALXFLY3MGP6W6POTWQOEANUCAHDHZEJYGS",
-    "GCGD3ITMM2KEHXTLVQVEV6S37G3VZFBIFBBNYY4RZN3U347BV2FPOZ5Z",
-    "GCGDE6DOI5PMXI35CKJ6NRLIX3XNWW7PJ6PEDWTNSMI6IXZVNRCIJURA",
-    "GCGDSYO67O6FKRW2FCLFNID45JK23KZHA5SVUCNPQONWK2GOW577ULCX",
-    "GCGDWHFQ4XE4VUVQ3ZGHDWHO6ZC74ZU2K5KQK2BQJPS7MOI6HB6GU5JU",
-    "GCGE6IBMZNM5UBIEHMJLPFW2VDEGPNY4LCQJSIFDOLHJWUYMTM7TB5SO",
-    "GCGELGCE42AVZMCHUNF4RBI3RWYUVYYO6B22SASJ3FRW6OOICQNDS2C6",
-    "GCGEQJR3E5BVMQYSNCHPO6NPP3KOT4VVZHIOLSRSNLE2GFY7EWVSLLTN",
-    "GCGF6Z5D72G74O6MCNZF3Q4S4CP4MA27T6L3TLOKN2SH7TSXLPJJDOGE",
-    "GCGF72RYNOGSZF7ORD5UOMOVPHJYFMCYREDKK22Z6I4OPWNBOSOYDE7C",
-    "GCGFA6HJIAR6YWQPA3BS2CMA5KFQ2AT4TEQKJUC5RF6CLZIECFU52HB4",
-    "GCGFWG66S6D54QB6R2FASQHM5LYLUUVRTILUFVWKBRBXANQZKK335LSJ",
-    "GCGHI5PAS5V27FH4UKXPVTJIWAXDZUQCP5GYWSOZGTYK4KJV7MKHEQXE",
-    "GCGHTCEAAVSXZDQUCL656BA3LF7Y2QXV3HMPVWERSAWZSOJJRYHIQUSA",
-    "GCGIOWAHOJ6BVAHLZ7JG7HVCKPJICQBZUDKZPCUIP7ZKDIUCTTEOIBIT",
-    "GCGJ27TCH6RIGLK652TKWUU2Z2BUKIIRDWVJVMEDSCVATKCVNGSF4SLB",
-    "GCGJBBWUYIBQXQIDJCNFGY7SHTYFJ3OCIO72QTV534DQ6HIC4KHLQY2H",
-    "GCGJCKI6B5RQRZRJYZ7UDCQOI2JXNJKA464S5HZOAGZ52I4THYWY3YTE",
-    "GCGJEBNPGPGHTUIAEM3OCBH2SIKFIC2MPUL7RDP35BBX4RJTP4JRGXAX",
-    "GCGJPVJM5QG2TBJSXON55JXBOPUANWINDEP3ILV32P2ARZKC76H7X5RZ",
-    "GCGJRERHTG6HJBTWXGEMHNNRVLE4NKBNEVO34P3OBGOHIVO7VG6ETORO",
-    "GCGJWUFXHCFW7ECDO3G56MLIETBYNXE6WS3GOFZKAK27JVH54U4DTFRX",
-    "GCGKAIALTARMQZVPTUZC33F2ONVK3MCJL6TWY5TOEVBHFUPUMUGFMFLE",
-    "GCGKW6V4EJKXRIL2H6P5AFZFLX7GJXUTZC5DHBJ72ZPU4N2HLE2TDRCV",
-    "GCGL3EG2E4QBLB6SPTQVNAOEAVYAEFI26GFYONKH6VO5TFBOOPQ5FVCK",
-    "GCGLFDV5LAI2L3Y6PFZIQN7POGD6L5YY2SVJJZ4YJ47NMOGRFCPR2USA",
-    "GCGLL7NSGEEXZVTUJO4CKA5J7IWE5NZIFEFQ6KR4JDLF7F5ITQ5OQXMR",
-    "GCGM4UGHRPO3IWKMJUVMKZW4ZW4BKUJ6UDG2PTOPNRIZ77ROEKSZZWMI",
-    "GCGMEWKDYFTC3VN6B7T7K4BJBQGUECCWPNXGCEEOSOTWTTKGPI4LOF2A",
-    "GCGMVX6I22C5TQAEY5U4NW7QTTMBSKAJACT6GUXRAL7PKSHGBHVGCBDC",
-    "GCGMXRBPQ6OAYCUV5AIDUHGMJ7IIJFPIW72J6KSWD5E6FJ6ESBV4YM5L",
-    "GCGMYGCHMW7W3V3BS7SJ52XP3GU3VHBHVXEVXXIZUR6QZNUUSDNHCMMN",
-    "GCGMZK5MNEZB3J2QSAFZ7XQM5PYHDQOCOH3DMW4CXSIJREJTTOYBPE2J",
-    "GCGN5YME4GC5O6WTESRXJ77V2JIF4J4XHLFNO7OS2I3VGO5Q7XG5RYI4",
-    "GCGNNS7VHXDFGRKV54BYRZCWAADAHOCSMN5GOE2TKJ6T7XMSUH6V7Y3Q",
-    "GCGNNYWQD6VMGD7KX4ILBGLMKEDHDSJTMQEOE4SQK3EMTKU7HEAPCMTR",
-    "GCGNXQ6PM7WJ2QIEE7CGK2SJZMRMVMS3LV2V2LBVW3O6PBE2YAVODHVA",
-    "GCGNYHBYTRJTGFDWC3BDTDU2TBL4QS777WNZLJOITTSFKJFMBNZFY2R7",
-    "GCGOOR3NSU3AOB3ZT2HJVHIRVC73FNCBBKCXEK6LNPGSHUUAUVVFUKWJ",
-    "GCGPBJUAZDUV5PAWR4APPQROTXFHTSAW4TK3PCLJT54NVG6OQDEWQQFX",
-    "GCGPDNB5NXQRKP7WOWR6WYCRUY2KOROHNJEIWJMFCQJP5ACDMOZZGSGB",
-    "GCGPP6UX5BAJO2FKPWOXHMFIRSBIDXTXA3RY4M6FIYJ4W726UGJ3AXLM",
-    "GCGQAYKRMWJVOP4NDQAB2UOECJJLOJ4LTOH5IZZYBUPN46MXBBR6AAVE",
-    "GCGQFOGQ3H5S6EJ7AKMANKWTQ2MEQRW3HE4Y4UK4GX63AQ26YKQ7BXTS",
-    "GCGQHAJQLQPS44HIO5GIOEQ7INYKAZQTF5KRMNQVLMANN6AUAQJJ7OIC",
-    "GCGQJNE62LJXVMJMSDHLXC2WTXG5OYKAB7SLSOEGCCB6HSKOOTICTSZA",
-    "GCGQKDMKQHYN23OWWH5CVXC5HJESYP7JVHUWGS2HZERCFQUFYJRVH5AH",
-    "GCGQR26WQTESEW4YM4ISJYWHTY6PZYUQA6Z6VEXLDQJUJ6CSMDDDER3G",
-    "GCGQY23P5P4LGCUIKGNUD77UPBRMWISKSMS6VA3QSAU7A6PF2NONVCKY",
-    "GCGR64DTV5NMK2642EQ4B4VQN5YDDNGXA4WT6Q37HAA3YCTTBJOX2LJU",
-    "GCGRETF7WZCGDK4XLPU5VYKSZPY2QRTBHVGEOHX6KRGXFTFXJE3SCDQ3",
-    "GCGRKKJEWMBPG24XJY6OJOTTLAXWBGGVSU3FGHR7GHOVGPFHWXV42J6K",
-    "GCGRNDHNVZ27Y5MDLIXWLRNEUWTQUHW45YTM7RVXYNN3KUR5EBRMVWLM",
-    "GCGSENZMVE2NARGCCNADO2ECAJV6BAOO3OVFGYERNCKVB2KOF3V4622G",
-    "GCGSJDKYTKZC7WGGFKQFTGVWYYHZ2V6UNSPOA2CUFKTK5TFJHSYY6UPL",
-    "GCGSN5KEY2YXXBDCERPLIIDF23NSW5UUDD6EH6YWFVVH3S4CSS3IOO5F",
-    "GCGSZLR644AUCVM6WOJO2YFGIGQIN23UA2EE6DGGUEHQLGPLMIUSWOBP",
-    "GCGT6LMHKTAJXIGRMBBSCTD4E73B633ZNIWUU2DVMPNL4YB4NNFNCO5J",
-    "GCGTGX33YL6WBZ7TEK6FZFANLKN62CTOWWVA6IVD77AAR6KM3H5BVUQU",
-    "GCGTKRA5A3FYOYX4Y3GDHEXDSDY7Y3JABVCYFM7IP2YSWQ3LXJT2WFTJ",
-    "GCGTTRXBUYYMFGTLQM3MN6VREWBIF2THIOZAEOQG3QA6TI4H2VH2SWAP",
-    "GCGU3G4VUISDROIXQLFHCHQTD4SBDOOHGAPNKNVERD4O4APETUIXRRJ6",
-    "GCGUIDOCPHVBRB6PDEYQLWB6WQS2Q74G3P4TQ7QW65UMQBRVVKAUPPSN",
-    "GCGUKAHXUCN74EH4IA64GHOJB7WITKQIUSA5ZRNIMZS6B672VBFNKXVB",
-    "GCGUTHWXJSIVS4U4UWYCNJTBUYQ7OK3FZCPN2TTDBAYJBCV7H3HI6NG2",
-    "GCGV56VXCYLIURD3BWOZPWJH2DAGJS77JYS2HSALMCZQT2V3DE77HXXY",
-    "GCGV6UQVVKZO4IRH4V3ZN4HDGTNJHZC2HWMINNCBS3YM3SJLNAG3JBCH",
-    "GCGVCDRO5UQLQ7MNFKICKAH64JWW3YLPLDPW5GEZAKDSL627PTVRFTBO",
-    "GCGVGA2FANSSYUSN6647FIHUBDC3FX2L6PJYKGCMKB5C5E2DIOEWNMFC",
-    "GCGVH5S7PBMCNUDEYDKWCRJW2B4BEWYKSZF5FUAA3X4Y26JYOUCG7ZXG",
-    "GCGVJTI6AESJRIEPVWFHHGWKYQUYK63HNE5WPUZSO72S7HUEQIRDY5BQ",
-    "GCGVSNXGSPFVZO5KK6WWOBNWWEUBT6ASWY5XVQVIOJBM2IZ5XSU7SVLI",
-    "GCGWH7CC6MIE6WJAU4773BN5FFWYEBK66DEF6ALJPIXXHSMIIXB6PKOE",
-    "GCGWTMXV2EMEMLU4VZB2D6OSSFLD7G2QIWHHPZBSBYGKGNVD7ALJE6HO",
-    "GCGWWDSAF6KUBPE2BSSVZTJZIUQRGPY3GGATIR4GWNXEBGKGWDLEUF73",
-    "GCGWY52RKU4KE4ZX3TYG34ITX73VN45GMBKNAPTNGOOK27GWQCO3JSHX",
-    "GCGX2XT5ERWL3OO5XNY6CZQ7BQO5HG32NPGRUUHMALMSINTS7UFQ7RDV",
-    "GCGX6KSZFKYFZBKTQBQTPU2H5VOQMTTP2PJCFWDGFRLQO673TOKYZNGB",
-    "GCGYBZWHFGC2QL3ZO7XOCVMFR4R2NFL4ILGDXVATGNQETD4ZS4LKMZKT",
-    "GCGYHWORKPWDWEEBKZM4PBGFPAZREQO47LXDT6I754KIQSWORR6DRE6D",
-    "GCGZNIGKDZZKODURZCRXUSUQ6HKNSFIQQBXFI5AVVEJ2YYROJE2CPLIV",
-    "GCGZSEEVQBD6RBZMZKHAG23H4EWXZYML5OX4LDUIGAZDAATZVGSM4LWW",
-    "GCH24F6AUYW4JX4LCCAU25M6R3VT5XFYLOCW4UWQLIYVPQLDGIF2LJPG",
-    "GCH26G44N6Z6Y4RE53HE6OHOBZ2VKKDVR52W3XTNTOBHNDK53LKSL2CX",
-    "GCH2TY5UE7OWJYXA56T3EUKWWSIP3GASH5ZWZHJSWKRIA73KM65B7SJE",
-    "GCH3GUC4ZOJTGW7OE2DXLF4BICFEKUDMB4DLR252G6A2JV2M7LYYJ2JL",
-    "GCH3KA2KPW7JN2GJXDKEG4VQ7LM3ULTHHWLY6IIRFVB5FDT3BGW32TOL",
-    "GCH3MBG6XOBYNYIZIQX2G3675ERS4EY2HZUGJP6TKZOBYC57C4X5HCOQ",
-    "GCH3N3BLBQ7QSSVNLJAGGRQ7CDUFJQXGECODYDYE2VXS4WHC5W525MZE",
-    "GCH3XLRHE7IWDT7VEEKGJFGCIPYFP5O74WLSTY6JY33UJTB34KZE4J2D",
-    "GCH3Z6NQSITG3QQPNZ36G76RWMMURONLU3FXDRHBHXRTJM7IJXOHEJGH",
-    "GCH4EYIHTULDF4AUDKJANT7FOCRVR6LV6E7EWPLDIBPTWDE22JTY42CE",
-    "GCH4NALSTLHJG6GJSJJVMTCT2ZHP5BBDNNH23HDLHAOPJOK6XX7DWW4J",
-    "GCH4V6RRMQHZKVKYORV5QYECOFQI2HK55GRTJCS2VA2DMSI5P7WFFTIP",
-    "GCH5DVKXKAYAJS4L7C4KIPVDVCLHSKVJ64WSWPVSJB6TNFTZ7EQGHT7X",
-    "GCH5SC2ZHDUZU5FVHENQOMUIGZDF37MAUZVSPVHSTHE6Y43XO5YAHSDD",
-    "GCH6Q65GZPDNQJGOESGPPQFUSWE7NWOXEO2M4A746OKWU4MRIDT7CGTE",
-    "GCH6XLISXQ7XUC6LFAZBPGTPWR7XK3IHT6UBP45YJRDN6PN5KNL6IQRJ",
-    "GCH7A5T7MXSM22KHLEP47GIH7BEM5ZZLDTQDV3EK3XU7R4A2VJJ27E7S",
-    "GCH7N37DXMHTYKA5SYZWZJXWI74IFL6AXZYJBRASPO4PJIVUTT5OKJOJ",
-    "GCH7RKSZFCSCQBPFEA2RWJ7JEGH2PXO2HBJV24CU2JGSJP27XXD54DE5",
-    "GCHAHOYDKD7VFVVZHTPEFWV27SM7J5IKDIKLSYXJAZHPXG7Y7UN6OJJG",
-    "GCHAQE2WKSNRH57FJGBQVTAYBUKUS7EV7SP5ESLSES7RD7RGLOPPBDU3",
-    "GCHAYDGJVITPBDKWXYH64V62N4VENYXCJQEPBQA5OEV27AUR34ZD7LKU",
-    "GCHC2A4P63WYMB7F3IHNWTJVHGNB6RTVUUROTOIL2OUWDARBN45PE4R6",
-    "GCHCGHOIW3N2XIYON6MC7JNB2NITDVV6SC52OR3ZU6H3QVPP5GXYNBJI",
-    "GCHCPC7E2FDGAUICCNGGRG7B5EXQHHSRJUAK4KFZFG5OLBIP2G7NTZF3",
-    "GCHCQ5LEGTPS5FGCF6AD2E6GJDAGCAJSDE46UG4DHKXPYVBQL3K26I23",
-    "GCHDEKBQMF7O7RWCNYXK6DH6MX54OYEFSMDQOD4SNHL3IY4ONMK7JUSA",
-    "GCHDP67D4FIMBAQOWM2HNBC6NSXO3HS5N3EGZOIEIIXVSNQFOG6QPXDS",
-    "GCHEDJGJOLEJILWRHPPND5COVDTTVHTPYGRKMHZCOY5DCXOEAVL2GQYY",
-    "GCHEIID7TWUZAUMAXQKBJT4EI62DWISXYLFFCA6BF5ZKIVLRRV745TTU",
-    "GCHEQ2XBK4NV75WSSAQRTMCJL5M5QIBR5TW42QNGBEE5GI2HR5UFGSX6",
-    "GCHERIMMLALB5Y6JMM4GIRW6U53C4E42KNRRBMQY7WMLJ3IHFEWZHZE4",
-    "GCHEXB6FAB6PHFGJLCHMWQ32VU6XHO7LBPGLLF37AZ2JGWWM766YWWIB",
-    "GCHFIAAAR2TZJFCI6T6TTEYUFC6O5NGPEAOALDSUYMT6GQ55YZY2IKZU",
-    "GCHFSF4V7E4IXQVD72EOBFDTVCTUCW5TUPYV3WCHCG2TLDN7KUD5EITO",
-    "GCHFWMAMZTNJ2REFNH434EVGRN3Y63SO7PHAEJ2AC44UQ2ZEIKGRVAWR",
-    "GCHGB2Z66FAW53WSBMZNDJPPDUKQZ6BGS6OXY7P4FIQT2LBEOQMN22BH",
-    "GCHGQDWYQDYV64RX3ZI7WCIB6V4CGRAWLNVEXAOU2JOOYSENJL2P53KS",
-    "GCHGQF2X4LHOC3WHTGAZHVEDMKDZWRWU7DA5XSSOK2OPHNEDPM3FNQ4Z",
-    "GCHGUWWXDO43CLQ5PSZECJLWRDRGEDAOIDPGQCJNANMA54DIHOGD4GTQ",
-    "GCHGW4JGTYXDMJXDNHLAW6XMPORFZTTWHYAXKZOIO3ZGTQJABXCICUIW",
-    "GCHGZXXO7IFJJTMEFUQUJUKMMXRDXHTR6UVY5M5ULMPKFYN5HODPGOLD",
-    "GCHHH6ITGHZGIC2VIKPXSIOMSU6RM6G3D5HZ35ES66AFWRWPRQT44EDV",
-    "GCHHJAXMRNHVOSGPI4NPTPD6XIVPAZK5WEEN6574SNCWZ4PAKN4CCOMP",
-    "GCHHPR7FMZK23MOLPFX6GJU74VYSDNJYFCZCIBBIEBWOYEGQEY72RG6M",
-    "GCHHQN7YNAVLJJJ3OKCMHOQK4IJXOXNCPEYN7NVZCD7XCAFKPWHWFPM7",
-    "GCHIDL3XBXAVAIYDINBNLKY5FFVSR6I3ZOL54NJCV6S7PYZX7UJO6BBB",
-    "GCHIPJ5IOWOHYIBBAYHYG7VR3QOUOVPW6VTEEUM7DRBTFL2ZSXGZ2UST",
-    "GCHIYCQYW3JMREHQEKV5PICVRD4CR2HUMTN7ZI3S6O2ANDYVWIDXFL6F",
-    "GCHJ3O5T6PADM4I5L5IBJE4ODJYOJV52DAQTKBIY5GG4P2SKGLTTP72G",
-    "GCHJUKHOFKVGWFV7KZHO6WEZUOW7PTMQURUAQXACINCEJK3JEDU7OARU",
-    "GCHK46PUSN7WO5LJ2OWSMU4DERXJ3Z2TQ3NSCWP2EA2IZYW5HTWXIVCK",
-    "GCHKNYMDM3EDQECBMMIW6D6R6YRQOGS6B6FIV3YBTAMFIMYLPPS7QN5D",
-    "GCHLH3WXJ4OLKOSZKIKEDKW5S3WOJFZ72U2WLT5QGYH3ZJGFE7C3JDDJ",
-    "GCHLR4HL45UOF2TF6Z5CPHDW723X7H6NWCFOVCSNUTHIRP5GXIBGMETA",
-    "GCHM5UDOF63YCZCAV5RRFVHWLVLPR5NW2ROHTRINV7VBO4Z6FJKWES3Y",
-    "GCHM65RSCEL3GJ5WYVNFRELLI7L6TINNJS524XJ4XQOA2443QOZU4D7H",
-    "GCHM7FKA5SDMK2JJJOZWHONACNOKY5OXBYURBJWUOMYPL7BW76EG7RIR",
-    "GCHM7JUY7BJESWZDP6RRYYJBRIVAVIIQF7C37QIYQ3OMYNII5JCNAV72",
-    "GCHMFWHLZSJEEU6VTGL7Q6KEYSDXPOWVZWOR5TW2D662QPA2MKUOEW76",
-    "GCHN23OEPHOC7GSNZTQGQ7BUUAN2N2K6E7WKNZZMWO36FATZR346UTDO",
-    "GCHNDV5KGCUHNVC56WDZUGST5D2ML2JAZAGHMWZSGJE343FZ3QRBMCRO",
-    "GCHNXHS3SCAWN7FXOVTTX6KTYU7XO2UJLSELMXDZ35IC6TTZGCS2TZZ6",
-    "GCHOTOJVRZXWZMIAKJP5THR7EPNINNCO6FQT5GS3WGBYG7PCPBR4IJDN",
-    "GCHP2TANOCKCYCXP6QX2PFGAP3Y7O76DFDD5J7H27W26QIQV4COT3J5F",
-    "GCHPB2XO6XIDEU4X2L333AJEUHDC2XF7WYH2OACNKUXLRIQOUUXQPALL",
-    "GCHPJQUKS4MPB3LJSWRDJMDBVLX6TOS7RIPA5PUFTCFOT55STXJIFU7N",
-    "GCHPNGY4P2WYZJMTOA6XEBUJMMDMJYE6HXXRWFHPB2RDELL2JLQXDENE",
-    "GCHPQJUKDKVDQUDQZ3RSIO7TX5K2JYNDIF54YN7HMVBTTLYEUI6OFADA",
-    "GCHQ4JAFLANDZC3Z5UAUKG6OTM64SBR5LMNN3C2ILPLYJ7LGXGGT4SVK",
-    "GCHQLGG3FOZLR55TD65RTPLDWVW7DW4VAC4TERQSAKZNYZTGYJGONVZM",
-    "GCHQP2HEAONA5IRMBY6LONDQKBLCBYGXMRI2EJQ3TMH7XXVUZNVM3XTK",
-    "GCHRGKAGOYJSX7NWOLQQXW5R3JK55XAI4ZQFF2YQWG4AQODUGR6MHWMB",
-    "GCHRI2LMBBASEBONKOLGJAEAEQWIIZPC4CWME5YNR4GRZTAOPYO66GWS",
-    "GCHRKHBERUHV243YXQMSAATR6FFWMHP44WQF2SFWUFXUTKZRAB3AFCOD",
-    "GCHRKVZMLXT43HG3RPQSR7DINASSWBJBL4M7O2XHEB2ON6YJHZMOI22J",
-    "GCHRL54RDIZJWZGLCW3VFSK4FA2LR6WNVDHGLJTEXWKD2FNYFDSFHPJ6",
-    "GCHS7C5AMS4LUAYCS5NUFFKZPZT4FTXQ6ACPJ6IYRO4GBIJHPU6XEYUD",
-    "GCHSBJLAUACXL5KIU6AOAJN5I54EZQDHL7ULDM5VO4XRQCPIGVJ7SCTX",
-    "GCHSLPY7YFSW4IUCPDAKKHV5UZTY3VHPFLQGJNMDD3JT4MFQQ7HTMBED",
-    "GCHSTEXKL4VSHKGFCI7NTMY6XWEQJMKAAEPABQAIV5XJPJIJ6LDOJZVM",
-    "GCHSU546EJE7PURJYNV36OYD7PZL3FRV5FEPJRGJ2DVSXWFI4N6G3TJZ",
-    "GCHSUEHI66TUI7YJBKQMITW3J65GHAEIBGL6BXIVR4VX4MREZY5FNTZB",
-    "GCHSWMTSIJOH4HV5FTY3MMNLCGVEKFMUNGZ6CC55OIA4LDCEUUMHQ7SN",
-    "GCHSXMS2DF572BQ6GKDPLKLJ6UXTKJURFEO23YNTTYD7CG2FTHXYYETH",
-    "GCHTAB64JOAT2MYVVVPNNE5RN4PM7DWXVJN6RJMJEJ76TONTJSREWSM6",
-    "GCHTD2SWKBNRDQDTOAYSM5WXBD3UVOC4AIBMKLZU7IYEZ5D56EWFKHZR",
-    "GCHTW6ZKX4EBIOUNNTFVKIGMMFFRLTLFDLCHNGBBB4VUBDSEYVVMK5JI",
-    "GCHTWT2PCKXATHGLPQ7HMXLVXQEHE4O35EFJIGSW3NEO3FJM4NV33QER",
-    "GCHU53OPLTCZJ7RSCW77OXZUL73UC7LOMELDA7IX4RV3EQTD6AIAVIZZ",
-    "GCHUGCFZF3NZX6Q2KS7AGZXATN3TUJFLGLR5MPE2BTL6WATWJEARSSVW",
-    "GCHUKYGDZQI4NTFP2UYDZLFN24YIIS5WPYJGE7DNXJNC2AW26NOOUPP6",
-    "GCHV4USOGRPHWVRBSK4SUP3BY2NLFANZYD5W5U4NQY6IR2RDLXVDCIPS",
-    "GCHWJOTPTPQ3UOYYJME5YIMDDKIKNT2IKOZODTTI3LTPAV6ITK5A5JT4",
-    "GCHWO6PMXIX6TAXJMTEXL7MFZX7W2WB6CUWMYSXGB3KVYVLUDUN3VKPP",
-    "GCHWW3PLGGV2BZPZOAM5QNZEYEFWXSWJYAYHLURZZWJBX5UY3OMJXOOB",
-    "GCHXFI4F4SP2FPXSS6HNEJDIKFFVEC6VLGVDBADHK7WO5NWUWTYGPC4C",
-    "GCHYBCYAKUE5CYZHBYNGIGMZ7DIIXOGQKECIXPMNC5T2M3DB4GJCDSGB",
-    "GCHYFY7ZLCPBFHYWLIYW77IILSO6IREZJSTL37S2DVDEXDZ5C5S72WT4",
-    "GCHYSL6OUD4H73RRQUP5FPRVVRIVHO67WI2AUJHGF5QJRPR5BT6P5P7N",
-    "GCHYXSL437JT52GBGRYVZM4734QEAE5P7ODQYAJ45YD5ZUJJJRFJLIRI",
-    "GCHZ43LAU2J7VG3UWQNP75QPGQ7WH6ECLE3RMVO765EZYJAHVWMDZ3VC",
-    "GCHZM27M7ZQ5WXJMWC54TWLRCHYLCSSG6V3WEGWHUDV4ZDMQD5PABSBU",
-    "GCHZSCM2VAPCHI4JJKZYL745UDPCJT744Y4NIER5XFUD5R7VXNAUNDML",
-    "GCHZXJ3EFNXWY73GNSJWUAVWWRQRFUZKCDUQIXQX3T3A2JNLNSMJ5UBY",
-    "GCHZYZ6SKZXR6QSH4ITSYD72OFGFYPWBO7MS2MY4J3FRBWBV77KG3EOL",
-    "GCI2SPB336AKEF53YW5NM6BTWAN3WULXSZUWHBWRLMAVT5M2S5MCEM2Q",
-    "GCI2UE5EWQGY4BMKJ4KKVCLLKZKAU6OU4VJAZ3SIVAM5VBML7HST2XRP",
-    "GCI36AV3VSXT2X2DWL2WG5NYPHTNQVNT6AGF7J6PID2SVSGYQOQNFCHN",
-    "GCI3I33EXKOHXQIYCV32YYX3NSDG3RAXIFXFBWBUA65HDPRU7HTTFCEH",
-    "GCI3URCVL54L7WQ5TVS7ASONTG3M3BPBKFFXNLVWUYSCLDJHYZ4NJPQ4",
-    "GCI4OHJNZ2772B7TAGAU4JVR2NF444CDVW3XBDV5HOEFDFARM6EYEXLM",
-    "GCI4YJMQE5VORE6ZXFOCB7DHRH4GBFFAIDO6OTAFJ25WKXLOV5A7OKQ7",
-    "GCI4Z3N2JHRFBC2CUIBQU2WZAYWTI7KTIVTSIHCS6T7P4HTT2A627SKQ",
-    "GCI53MCMW4HJRQ3W4VXNKUEYQRYWJMJITEQTN46EUH6MN6IHFCH5U7WJ",
-    "GCI55ODEGUGZWJ272NJH2KWP6BRMJQRXRZNG3DTQY5ZJJPGLOEF5JSGH",
-    "GCI55ZLBX5DMPBAOZOMO5OXGEBACK636CY3D2ZTLGIQUGKIZ4VC2VELR",
-    "GCI5LLF7EQNLNDIXUEXQYJU3235D2UGUSYRAHBP4QYGR637XM4A5GJXC",
-    "GCI5PR7XGHG7FHZU7BKH7SSZMY65A644FO25LH3RRMRUSAH5GZZQ3AYG",
-    "GCI6E73PMLDJ64MO4FHJGHPHEYJXWJ53BPYZPUTS4SUUE2JGGZIFDROP",
-    "GCI6EPRQ6DHSBU522OAYPPTWZ5UIHK235K6DOKP23F75QB233SN7QWL6",
-    "GCI77Y44PPTNCXNIKAAACPY5OSS7P3XCJMQX2KKA4HQCE2W5GZEEESGX",
-    "GCI7DEFIUSGPK57IZIT2AGLNMO75YMA2D7GMX273HI7KQWPRJXQW73FI",
-    "GCIADKNYFC2PJ6EGRD2LXGWP6F6QYQKSYHLOICPAQTZDQUYOTP4TBWKK",
-    "GCIAEYE6AYQMVWSHWA7L23LKHO5KPDVWNKI4ZCCLZPGPW6AI23L7ZWA5",
-    "GCIAO4MKBUZBV5CA5LHNCBV7FXVKAIRJ7AKPVKPQYPOKNSXDWI6YIQYZ",
-    "GCIAQAHTTOSFW3VBSPTGAAOE3QOZ4VVM2LUIKKW4HVPH3IAB2RDDLNJX",
-    "GCIAT6S3TATNJLZWKYV47OCXMKTQOJPFI66WVFKUI4YA46IQPTPZOOED",
-    "GCIAU7SRQX2FNTZHVB7YSEHLAQXD7E2ZA2RABSUF7LTS4HNOA4GS7TQN",
-    "GCIAZN2Z6NFWHBFHIXDE4UKH5W7U5E5M6P426JXDUCIRJUAWSTVENVPS",
-    "GCIB44BNVLAKNGPB63OJ4KJSIJ3VX24RDC4GWWYTVE3QDYXVTCAUKKJU",
-    "GCIB6ASDXQI52NHROHAWFLJWTCA4N4DCXO6NKW2TPQINTUDK77WGPT44",
-    "GCIBRI3DBNXVP2ZZUNHYWJD5BMHU2IE4LFD6WGRIDPOGKCVXGSZOAUL5",
-    "GCIBYZZMUW2AG56S7MDDV5GHQIYGWIV7OBPKFHXRQLV6FKVHEOAL4GR6",
-    "GCIBZC7TVJXOBDWYLS26TQJQOCQ625QAVRGUOSUZFVFP2YC2Y72WFRB6",
-    "GCIC4ND4PVV2ZKLZJQR7LCKOTFMVEKTPH54FZ5HR23HYHNRQZVEPXXYZ",
-    "GCICFL3RVPWKUB4YFPUXBQA6TAQTKIOAJACWYGGDESLRO45MBGOCK445",
-    "GCICNWEI6QG24TO5JPJQUXEGXYHVSL3FRFOKLQNEAAHCGP4UAPJXM2CZ",
-    "GCICWFTLN3CYUUUBIEBUQO4UOBSOWNJDHJTF2D52NYQ6KR2UZRIXU7GR",
-    "GCICZ3K3ULISP6ENAIXDAR3JISE2IDZUQDQB5MQFGOCRXS36XWINK6I6",
-    "GCID353PFI7SES4H24XKU6OCHAUWW52LN24GR5BIX3NOI22TZNN5POJW",
-    "GCIDGTA3ZTLIU6PN4YAETTSUGXWDBAACHOQTR7BEWAILVKZF2WVXK3D5",
-    "GCIDJH7B57JRZVAVWCWH3DX7PF7E5JWRTPGICPIWTFV6ITWPLMBH4GFO",
-    "GCIDKJGVJSFQDFDH2WKFPVAH24OA3UG6GMZB7XJBCILTJQ3LEYMQJFZ2",
-    "GCIDRQZF6SEWQ7HX5ZMKDVXMMQWMUQBHPJ2BMCFBWBVDZJWAYKRYCJ4W",
-    "GCIDSCGBBWVNZSOSXH2WUIGZIBIUWNM4QR5K55BUBAZYZGY356OHV7SX",
-    "GCIE7GAAHJBD7U5EV5LIOWTPPEMZXOHDPYP6K3Y5Q4Q4WCOABF4TXCOA",
-    "GCIEEZIUG236N5FA5OX4FUK76KKLN5NGFSXLOYNMWX2GGXUTRMV3QVIK",
-    "GCIEF4G4KJ3PSQNV4PJ4HK6K27XCVIJBILSVKBZH7LTO2SBUV4ZEL3SF",
-    "GCIET6AB455IJH4PEGLCU6DY6MV6EBJE3OZAOM6JJHUKKKOLC4CQIXAT",
-    "GCIFG72FPTTZLKNWEQW653QMFQ6A5CRP7DZQDQJ3ZG6XY63RWFX4C33G",
-    "GCIFJ7Z7XE3WIECY5EDSA4MNPURC6XCVARWJXDDTZHRGX2C5WJPTWURO",
-    "GCIG2S74VSUX4H5LALYNK3NC5WL6G4TDAXNCN6WID7BFBNXIX4MH6NRK",
-    "GCIGO4KSEMAD4FUT6AISHFHTV7MB4UIHHPXQ77O47HR2BFUIUUIVXSLE",
-    "GCIGRS75U7ANFKT3NCUKYEUIUW74U5ZK4R6Z577FYDO42PZDBUJKGUQP",
-    "GCIH25GZWJV6GPAN6WDNMUPED2DT3LK3Z7GQ6BIMKUT6WTG6S3BHIF5E",
-    "GCIH3XRKEIKHZ7X4APHV65PBINETPP32FMNKSHA5KKZHOCZQHQYI6BQU",
-    "GCIHJZFZWGWCBI7K6GSIR2CH3DS5KJB6PZO5NKO3AFLD6TPBEHZGENEO",
-    "GCIHNUAJAKIVFCU2OLRKHAAGFUKSYL64EFWKRBLXIH4HCZVPPGGBXQFD",
-    "GCIIAKFKDW7ZAKYWIPWB5YJ7VJY7IOTNJQUPLG7WMI26GI77OJJVM5IV",
-    "GCIIBHBVILMMUVTWWIOTD4OGLZWTF7D35LVVWN6RUBIIBTEWORJ2H54O",
-    "GCIIGO5DTZCNAX3G4G2W5T3XHBB5Y3XTPHIZGXRBFQAYCMOU6DOZWCZ5",
-    "GCIIO4IKX7ZHZSBPQGZEJSQ4HQY3MFOQE2XBQUGF46UB4FJVTC2EP4XV",
-    "GCIIXETAU5TJHRJRPFNXMDUZ4EWSKWTLCF3RWRF62PZ75U3VDDWFBOS6",
-    "GCIKC46T7Z4KUT7ETBVQVCNCOJ3B2E2KQWLUG57H3WSAQRXDQLHR7NII",
-    "GCIKDLCF6B4QJVG3CL4ZZIYMLOVK75CAHOD3OH4T6UE5USDBQDSM2AWX",
-    "GCIKMYUCZMZDKXDMT5V6HOVQFGMAXVBKHVIR4IPWXAFZU7GXG2VWLLQZ",
-    "GCIKRMOJ522ZHDSEI63JHQNTCJAJZO7CHIZARSN6ZYJKD7HYPHRAATJQ",
-    "GCIKS4YVWPGKRQFE3IYAUJYTRMDVAIWWRDCGSUHWAWFXYUJHW53TCXOM",
-    "GCILNTRTTOMYO22YWAE45EEYI5BKO72UWTOAZLZK5XGW6D4E63DDW5LJ",
-    "GCILVDH7FYUBGA5LGOZ5OBB4TCXEDHAKJ7N2BOVE6RVFLZLNLITZRPSS",
-    "GCIM7MNL5QLZSLW773FCHJNKNRME7RUUQ5TTVNR2RZWFBM3M34JR7NCT",
-    "GCIMAGEYJYK34I2VABPFASKPQSV6TRNW2DY2JYPFK46FYKR54EBXLKEZ",
-    "GCINFECOUAS2V4WOAQCAYIZL6DYNVXAU74ZDJ5HK6774HA4LYF6GBP7S",
-    "GCINFNFN5X6E5VKIC73XTSALZWG5VYX7W6UJH7NOTYEUAP2HCFVGQK5G",
-    "GCINQRSH5EFSH7RSTTRHTQRML74BSS6AAIRAD57GOQVYY6KXUQ6BW27V",
-    "GCIO3A4CVC53CMVILPV7RUDS3QUJVJOF6UFIWK4GNRM3DJEIZ53OQUZ6",
-    "GCIO3GAWAEBJX2II53VHZR7ENEN7AUP235WZJDJTPZJ44JHRTVO7QOYK",
-    "GCIOBTVFCBOFGHL7XTWVTDXRQN6S3LSEAHZJVYHN64WSWEA4PXYLFOTW",
-    "GCIOBZVBJVXIULAPYOJO64HA3MPDQZ2BYCCKPNU6URFN6S6IVR5KQOZB",
-    "GCIOEJHWULV3LFRUJ4CWJNRZECBQQ3FQ3XJJSQCIH42RDSXXU2SFMTMF",
-    "GCIOXSX6L6RWS7PSKLNQRDNHUAY5ILV6OYK3WD3R5QOC4DNTBIERZAMD",
-    "GCIPISWMRTIBXZI6URFNDL66WBSESH3IJWPVPGPPH7AKHDNWBHCNFID6",
-    "GCIPO7GHYIINSZSEGDG6FGHPQTLLNBPGLSF73MPDHQRXV4ZNWRQHFGFP",
-    "GCIPOUXCRI3YTS2ACCUDDNHLUBVVUMFL6EGQQIL4R3DTYLR2X4RFLARE",
-    "GCIQFCQVAPD2ZTE5QZIIX2ZWOVSVMX4NQIDGJ4XE7A7O57BV5YYUUIUN",
-    "GCIQINMDAXVS5RUKXQYLRSV2X5HYF2FQG54GZYAC246BUH7FL62KGQMM",
-    "GCIQLLPLJWQPNDFP2LIEG5TA5ZWEPXIYKUVQ43ZU5W2IX2ATRYCGRDPP",
-    "GCIQZXQLWKUQF4BCEWGGY5N3T54EO7LDOB2RG2NRQM5E46MW5AHE7MGP",
-    "GCIRAE2CTZZ3UYPWSX3LVBTRZW6YNW6GKBUE7PQ37TEGBIRZSU4LWUSD",
-    "GCIRHYLBJ4UCOU7HHTTSTOH7FX4HFG24B2KEDVFUNSKFI6E55AKPDOGE",
-    "GCIRKJTYU7O5BFSRAQCSUUQE2XQY3B65MEH36PUGRCDRTQPNZ7APYPJQ",
-    "GCIRT2NFFZAGERU5QIUH27WTQOHJI2QZG5XZ7A3JC76WNALBX44XVX36",
-    "GCIRTA2ICHQDWXTTBPTTXHHAJOWLLXQ5WVLWTJPKKGO5KAUT7EONFYHZ",
-    "GCIRWHUOSUZCBCAT3J4B36BF6QU6KUJQ5CE4ZSGJE5H5CVEOLAIG6EAF",
-    "GCISLACTBXCAUWULABDO34PDSWG3KISUZFUKV5NTKG3C5GIX25VKLTFA",
-    "GCISMXFLTUMRVF7TOX5UK5DWVR5E44HBIUCTY5CYF5MKRRMMGQY5U5LW",
-    "GCISPRRCPYSPZGQ3SHWLCGCUENARDAUBICZMLIAQECY6OHVHHXZK6UTD",
-    "GCIUGB64JXZFI47REAUIQPJYRC256FNSUBLPEAIE2LJNXZYVDJZH4Z3U",
-    "GCIUZJSWAKNCT7PR6XXFM54JU57ZKVBJRC6XMILO5IUBRTI273E2WYB2",
-    "GCIV6T7WWYC7FU52JZCUM5ZTTJPLOKWGHDVHDOC6ZLMEFR35E7PKXQZ5",
-    "GCIW7JVLFDY647KDABJOINZ3EFBZFUVDNHT6FYK5VYJSQQLNDTOKMX7D",
-    "GCIWIKKGXSR224JMFQSL3L24NIXYMO5GA2UOMVE3F4NXSPT6J2W4GGFN",
-    "GCIWSB52ZXIZNKLBYJUDZ7UG52CMKIUAQ474MLKJM4KXBOKF6SS4SAND",
-    "GCIXH3SQJ3YZ4JQTSRS6OF7ENCNQRTKRMMDNHLNWXPQ2CS45Y2NESAMA",
-    "GCIXHK7FUVMDQMRS5PFARPIAL7TAXIAAMUJKLRCPOBECDK6UVITDDW5B",
-    "GCIXHOQOIL4JHDXLKUDVYJCOOR2M4X3XVNFPYFI7QRLITMVW5WJ6FFUP",
-    "GCIXI7UEJOOHXY5ZSY3IB3MSTAMQVGJJ2OT3HG5YSLDNCQ33V6GII7AD",
-    "GCIXIKZ7EWFYXXEBNKZWZYG6JN25KMQEDTYI7RHPNPURUEICW7RB7YME",
-    "GCIXSAED3QJ6G2ABLVNKPPE6A4DHE5LVT2VO37ESE7FUIVKMAQ6WSCOT",
-    "GCIXV5R7CGEHYW6JDZ42PPH4FCNKRIRT5LTXL5RCAKTWQOTNPT3KNRUZ",
-    "GCIYEKTXRKHD6WOMM6TCQAEBMDXY6WCDPYSHVSVJEPVZRO4O7IDHQHDX",
-    "GCIYEYDKPI6F7HPHAOJUUHGD3FMVLH63ITDNYHZJXINVZUWUZ4X26UXB",
-    "GCIYJQZW7JOED2YBVEWSI44HL3XAUZUP4LODN7BZUU5GJZXLMJRC37B4",
-    "GCIYPZT5XCJ4X7WVVV3CIEOBVZHXNE3XCQ2S6PCYJ6NLSFSFVFCNYOR7",
-    "GCIYQ6DRC2YYBMJE6LJVZZHSITDS4E4W2KM2CNQQKRY7W2POWCYGEKVX",
-    "GCIYVZ6HSB6UHBOOCCCAH5H4CE4QD4EOPGBTBWK6LPT3C7Y6F7PLDPER",
-    "GCIYXWX2KMJGACPI3U7Q2CQSIQK3S24SP57FKSZOW25BVM23NEUFJIEE",
-    "GCIZFJM7W4KGFUSSIOYK6BWIDRFOVLUXNFQLBX6ILZQZD6WAFKSV6FLR",
-    "GCIZLXACPEX6CSD2ZNTSRC3VEYQSO6635MDNQRRNPIR7OHDQ64VLKLBE",
-    "GCJ2ASKUSPTNZT3JI7XQMB7VKWLAEFJNU4S52JGJOSGFQQZ7YTNNG37Q",
-    "GCJ2EPYCB6BV37E5AJIABX7RJHKGX2FHHM4MW52OMPKWUU35PNBFSIEH",
-    "GCJ3HR2QIXBBNRQHCWAUYPMVW7XEGVZPU2LV3ICU46XUNFCTZWSQBSDE",
-    "GCJ3TYVKEINY6PCUGGQSF7TQT2AXZUDW36LF43M4WNWXYOXMED4GQZLI",
-    "GCJ3YO25TMG2HPIGVQ7PIQ3DGRR4WXY42XU5BQH5LEESLA4NBN4QYKL3",
-    "GCJ4SXAJB35GZTV2WJ6QWLEVBUFQQWIRRIAUQ72PSPVNYEC55OOSVSWG",
-    "GCJ4WADQD54SV4BTCJQK473LYHUK6WVOB2UNNF6V4DAVYGDD3EJ4VPVE",
-    "GCJ56BINPMPPIKB53ENNS3IVQGGP6KPQLEQCJZPZMG77TIXYNXB4YJWZ",
-    "GCJ5BX7QLJCQOIRMFQJVYH6ZOZEL4YM5QT7SJP4QVW53IJWFYWZO45AE",
-    "GCJ5DBB3H3LUFMOMM4LQ5QZOX5TS2AGPYZCET37LOC65DGB5V2XPFO3U",
-    "GCJ6EVK4NJ3AS6VOC2WIJHTRYK6VXIGPAMIJSYTGWRX757MQ6AVYBDYB",
-    "GCJ6JYKCAFXUQKNPXWEQQQFZNGZRJ4LIICV77NRBGZHCYTY76AGLXCDN",
-    "GCJ6K7BLGDYMCZ7NZCA4ONXMNQBXSFHT73KWHHDZVCS57FPSSNXANCMQ",
-    "GCJ6NG6TZOBYIKBKCBSKH5OYM6X6GD3PYZVH2Z3AG7NRPN3NHPEVXDLI",
-    "GCJ6OWK5GBFDDDUHZV2BRBPIUEYWJSDCCMK4MDWQDCHL4PG53NKDZCYZ",
-    "GCJ7CIX7ZRVUARSOEGVUMPBBSWXE224WU3AULC26PWMIR6WJHKNFE4WH",
-    "GCJ7QXQSG4K6OFSCFEUXNIODPFJXLERUYHTJCN2RDGP5KGQLSUZ7S2YW",
-    "GCJ7Y36BXOMUUPXRTAWKPTIK5LXU5VA62M2ZDWOH2S5CWLT5YEOF5SEX",
-    "GCJAIA63ZC2SV67EMH3FRZWG6VS6NLTH6WRYJWMOINPNNPB42MTXGUS2",
-    "GCJAZKTJIXJYKKP2IFHVY4UW3CCJ4C2YICYGA46REWE2OV22TINTBWAJ",
-    "GCJBDCTIPQXMQZK2SGAWZS44N5VZSW56S4MD42J6HEA36JRCWDBDRVGP",
-    "GCJBI4J7J2FWWJHITGP55XCI3ICBEVEOIBEO4ZHEM6W2Z2464F3I4HG7",
-    "GCJBU4C6EH6V757N37C26YSQC62J5BVK5ZO2PYJCJQXJ5WET45XJHAV2",
-    "GCJBWLODANUVH667QIVQT3APGFTVXGONLRUSVTMVSU2YX3JZ7HNBREXW",
-    "GCJCCYAIUXIJ7TDSJR3AWLQLWPA2H6XJSWNIC3SWQ33ECBM2ROMYAHRR",
-    "GCJCJFT2A6NFUFRZF3OKAVWT4IH75WB5WIQJ26EBPBWI2TTZQINQAYIF",
-    "GCJCSPXPQYHECLOPNXOE53DZBN2BMWGJ4QKTR6LSIC5UZQYMXFE7XP3H",
-    "GCJDEY4QKXQ6GXPMDOJ5N7BXKGWKWD6K6GVHBALNF2SNCCFLXXZA2YES",
-    "GCJDP4XGVYZUOTYYVXNCJPIWR2YKZO567MY7R2HAINKQMHKUDMIPZY6U",
-    "GCJDVLSIDHBMWHCMML4DRGB5BXXEVLVE74KTSVKEPTGSIT5XASGDYABC",
-    "GCJDYGMWRSBC7BS3I5E47SCZJQKCLWFQQQNNUQZV4V4APW4GB35MWQ4N",
-    "GCJFL6OOFUUZQBD6L3ZIICRNULWEFAVNKIWA7Q2FEKJND3LKZV3JWIT2",
-    "GCJGGDUCC6TIIK7YLVR5KFWUXGHO3FLCJYMPHO3UB5HVDCALSYFGBCBS",
-    "GCJGLDU6FQO4J2PPVILXDQYMT45QVG3PXJLHX3BW3AUUAHZIYHVWOQD5",
-    "GCJHHB4CGIWBDJR2FZ3GFTWB67MO6SOWSUN7N4ADYTBBP3PCGGV5YTNQ",
-    "GCJIAZTI44TI26B2TJCK3WGGCKSCFGREQ2SIVH73TD67GA27EPM7UAAA",
-    "GCJIS3GEBMISNNFPBYNJYOXGCEBQ4JLP5JUUGCFH7DWM4CURRBELVRH2",
-    "GCJJHIKDOGN6VVDBE6RZRN67M6DHMKVNMP3ER5CM4E7WNDUF4RERYWUN",
-    "GCJJJ3QWMOQ7BTTPDBIG5YFUHJA246SGMMWGX5FSBT2S3EGYOE47BTCS",
-    "GCJJO4OGREKNOAQTAQOOXFPZSY4BI47HGL6QOATZZZHRNA4GFV5SIGEF",
-    "GCJJURBYRTHROUOHN3TYGU7LV5OCNYFTEDX2KNKCBSBSQZA7OADQT54L",
-    "GCJJYEFZCJ2IJFIWSM6S545VQDDTJEEHKP2S4DXBE62V7RCBVLBDRY2G",
-    "GCJK4ICEDMQF2UGOBJKCDYR5EAUVS7VIJD23PLGV6WY7CIHJTH5T5MCC",
-    "GCJKB7PI4FOQ26WPRQBM6UDEDO7A27HTPXROFLMHM3KYPWX4TUYUEB7H",
-    "GCJKS6ZFJEEUXBYW5F6INKC7BNRJR6CYNSL4Z2KFHNE4CVDIFTVR4ZQY",
-    "GCJKSAQECBGSLPQWAU7ME4LVQVZ6IDCNUA5NVTPPCUWZWBN5UBFMXZ53",
-    "GCJLC4BJDTDQ4LGUOW2CPSFT5BHCYSLSNDIPR2VGIIZEOGEW7I4NMZ7Z",
-    "GCJLI7SE3OCCYWY3W3QHBLDBGJ7POW2NI4LTW7W7KICHDVNA4ZDJWXBW",
-    "GCJLPDWVJRB57B2URTCMFKDAB53LEV6UN3PDNQSWVYC7VGI3LNLI66KL",
-    "GCJLWCV2NWA6ISCZIPQZPZ3MEPDFHPKQPHYUZ2DTYYVPLVYV7TPTL46O",
-    "GCJMBLCVEVXX227RIMNTWDGPAAFD3AGZAMMICPXTVH27M4ELTQ77YDH5",
-    "GCJMMZXQBG7DWAZEF2KFZ3NPBC6SYWWXUL4PP4I5TWSRHP3YVMVXGLRV",
-    "GCJNDPBLPID3UHKTK3WLYOG2UTENI46VLLAZIRU4ZH7OELKP4XJANXYK",
-    "GCJNE3RJVFZPQWTXYW323IDS46CDMW5US2RVEHHNS3HHKQG6JB7S72RC",
-    "GCJNOTCOZKG7MHPDBQNN4AAQKCD6OD2ROEVABZVQJGUKKR2CF646DQHP",
-    "GCJNWVJIQWXXKWUFZR6QAPV7NWNUCDKPKV6WNKLSVO6DAHEBQPFNNF5X",
-    "GCJO5XIBBQX4WLJT7OD5AQMFSLC6OCWZ5J3NPQXRYZGOGQDMED3RCTAV",
-    "GCJODD5VQPSM3AYTRNKJETEHWSDUSF4LEJSO7LKLK4RXFOZN5ENDKXE7",
-    "GCJOQGLTWYEKQUBUUVSAJOL4H6ZRTGVWRNAKK4WSCVCSP5PLCZPWZ7K3",
-    "GCJOXRRZWS7KQU6QWG6KHSJR3RVRYPAQ3YZNO52EXNJCMJ67ERUISO2O",
-    "GCJPUDPLIBGAXYG6KLJNBDHSG4GB3SLNCCSTZHAJ4EQJNGEPHDGBVVV7",
-    "GCJROZUPEFY23LDOIMTX42UAYUCO5I6SXKKEF762YKYTW3DN7M3K3TWE",
-    "GCJRUUGB2PZB77FKCR7JFOJMZ4VLOFQI7BKJ5UDYZMUIZZWEZHWPHEEC",
-    "GCJRZLHUYKKQ2YNFJ7FHIO5MKBZ4V6QJC7XTGYR46QWKCCOMX6527JAQ",
-    "GCJSJKMV7GR2HPF5B3YM65URUZZGAPD7P4SO7PKXFUMGMARAF5EPCR3J",
-    "GCJSOSVIMRGULQVTH2RGWXM33EDESTA4IJDBFKH6TZXMGE6J5E3OBRNZ",
-    "GCJSUALYXDHOB4LMRNZAXIFH5VFSCWJGJ4TVK6TGGB6XUWAJXGJTMCUJ",
-    "GCJSVUYFFM4BTFA2HINXKGTOP7H2ARELTZHI4AJEJ23H5CCFSZMXQURS",
-    "GCJTAS43P4RMTDD4U53I45H57QNH5NJYXWR4YDWYUCEXAA2Q5GA6EZOR",
-    "GCJTBZWU2BACK3O4VXXKDFIDGWRYXHXCONJFWRWWMRDCRAA5BNUUJLGP",
-    "GCJTE22HDFI66A4ZFZQROO7HPHGNSGDGRTD5BGHLAFB46UJINRSTGBKC",
-    "GCJTL2WV4FKXEQOJC5KA7XZVSSUTNFVUE5FFPAZIS5LUIYL5EGBDMETL",
-    "GCJU36CY7OTF65SDXI3CBRUZJX4DMFCDCHEQBO3U6YVNDLCQMMVCI5MH",
-    "GCJUCRY6IV7IXJF3EWIP7RHOMUYS3HNLIQOLDUYIFUGCIIWDD4BX7BTC",
-    "GCJUQETQ4EKJO4RCRV5FV7VUJKKE3J7N2KQL46N5STJS4H4RWUCCTJTZ",
-    "GCJUU5X2EUOZX7GPPXJLEWBQEJOVLB57X5BR6N7LUWIIBCB2BKJMDKEF",
-    "GCJVDA7I53YELL4UTIC6BEQRPJQKJBZKN2ENSBNNEILGC3FQ2HBSTERM",
-    "GCJVT6U2EQ3YNPA4763DAMKBHDFWHVQ64Z2R3WPB4GPOBG2VVL3E7QSS",
-    "GCJVXL2QQA2266RRQTMLY4PZ6M27F3AAKIREYGCQJ63QWVRGQXB7ZSXJ",
-    "GCJVZW6WKBYALVO67YWK4ZLNS63ZGDACHTHWWOVY45SW7MFUD3BKZQFS",
-    "GCJWMAZ63O5XX43TYPAGOOWT7D3MV6OGOG27H4DXAWTDXE5YXVUPZJZK",
-    "GCJWRTNU32ZNAJMVJUO3LPWJTRXJDGJ224XIL47TPODEPUOWJ46UCLXE",
-    "GCJWUQIUQYMV2JBPKWDCIZ4TMJSU74EGC2AYK7Y2SZO3UTO6UUNF5L6I",
-    "GCJXF7GPXBR4GRLDQ3G3KKPSFWRHCKPRCMTQS7XG7KF6GVJNZQSHS3DI",
-    "GCJXR3JJS4LCZKKHUSHAXSXXAMSSNFPTUW4WEVDDB4FCVVORWSCSYOUW",
-    "GCJYMUX6UF4FA5IKK6C3ZYFSHCBUFBMG7JHGYXLZCQP4SOUKZGKFE5ZR",
-    "GCJYOMC54TNCRUPLTSKIXDOZ5VU5Q6FZM233RG2ZC4HQY2GRH6CA3U5Q",
-    "GCJYSSPS4JFXONS7DLEAHXBHHV3HO3PTJOS4NFTYIH36U2ZIHDD7N4HE",
-    "GCJZZNVK5SS3ZKYVG5GETA57PYX7HZZQEMOMXPU5EQ2BWBSDUZIBAFR5",
-    "GCK23GJU246RAKHHX4GC2VLDRNZKKC7KPJFAK32FBL27VVAV3PMGMUQX",
-    "GCK2464PMHMKMYYVLHCQVIUOGJ6BDXLSTAWC7FNR5566M4U5QMBYU35H",
-    "GCK2ELJQ27YKB26YTTA5KJIAO43DSJFSGNLZXBGZWSLYGDZ67J4IKRGC",
-    "GCK2GQAAKXLSOKPUUB5OSWJCQMNNNHJCEIMBDNIBGDMNE5QFQZ67NIF4",
-    "GCK34VGI2IK7RXS2U4PTIO2WPA2F2JSOEBQ25QCYFWDU4TSDHID7J3QA",
-    "GCK3OFV45TTLPOX5EZ2772XUN566PXFZCJGLKJH3K4RZQK3BQZBQUDHD",
-    "GCK4J2VMKLHGVH6ZFSIQGKKEQD3BWOEP7ZERR7AZZOJQML72AWHNDZMD",
-    "GCK4KZHOHUIHIH3F64S5TMOBVJBSWX6KPN25YP4ZK4U7YAZQYKN5MVDO",
-    "GCK4OQUJEGX2K7Q45HBD6W2RWVYBSOHLRQRRMIETSOFEHQXPTZB6I7AU",
-    "GCK5DA7TJSYQXNAVCXTA45FR6TLDI4A5KHUDZDCAWQWTQXWECG7SGZEZ",
-    "GCK67QMX5LOMUSPKTODY2CQSHAI74VHV7HRF6JPQN6QCAB6JW6XMGLRM",
-    "GCK6CDFPLHN2ZXK3DV36JEV3Y65IXRCCQJAWV3BTKCBJUTXVTRBOQKJJ",
-    "GCK6WC2QAVBNNG66Y2DPHMULRQHHTUPJUI3UFL2C3VFUCHFXM3TC6J2D",
-    "GCK7DBQUJGWLWRJP73FFU4BWETYMQ6WAVOO3XUYRTNCJQIGWOSRSIBR4",
-    "GCK7OHRQKXDQJTMT35ECEKLWCARJF3H6GZNAK5JSJLZI5LGGW6FGWROS",
-    "GCK7U5NFXNL63WBJ4ZT5GSGUS23UNMS6O5FKAMX3XM56JGCCMBLZLEAD",
-    "GCK7UUSLUNTL6222J7HKPTW53VOPC72PAZCSOUCVEQBXWCSX3OEPGE3H",
-    "GCK7YRNX6LSQAVUSZ5J2GZSO2CCCZ7BFAABA2LKUA7FQUMSMRE64GOLD",
-    "GCKAG6IDOXHKIANZYRVEDWBU2A2VW6X6JDJAVDPNIOHRHYWUZ3YEA4LY",
-    "GCKAGRWGRDEMBQEKH5UNKBNWCF2JC6PIL3YEOD4VR7SH4S6IZECD7VI5",
-    "GCKAHLEQCQELIJWRZUGPOGHFICFC64U42JLJB7RPYPX5QQU3BOS3ZUN2",
-    "GCKATC6BMBLI2DE4F7U6IL7KXG2AFUAKCRGOB5PTD6HPIV5Y32DCWDGW",
-    "GCKATDDFYRADJKZLWHSBTAEANP7TGOSHYFECFYX4NEWK77B2ZSYIFCWJ",
-    "GCKBJLMRSY4FAJR4MICHDGCH4MCAIFMGT7SRRRNCJUFAETFZSCJITTNZ",
-    "GCKBQXX5KAVB5ARXU6SIWXF5GR477RKXIAHC5GBSGT7NF65GMOKCUYC3",
-    "GCKBUB5SBZY5JIF4IYOCKBAZGJSGKEYE6HHPQDXHQDQLVO5DYSEVTWNF",
-    "GCKD2LU57HUS6WIERDC6CLG6KGFEZOKEWA3YMBMB26NKYR4DEDD3IGT5",
-    "GCKD7OBCR3M4PBIJCR6WJA3ZWEDQX7LYTQ7FJFLGG6FTT5IEYTVDC27C",
-    "GCKDGXICU7VOID44NV5JUNNNGYLU34XKJQ63PYADJ5EPNHP5AY7YYI4L",
-    "GCKDJDG55ZYXUR6AXN2RZ62JZHZH2KU27O4JUB6GMO6VCG62USE6W2CF",
-    "GCKDLU25BSAZDY57IX2RIFL7BNJGC3KF6EM7UMAZIKTQLEBVKMQCYPXS",
-    "GCKDNEIL5H7YYI27DPB7JS4BDWNMHKM5AM3ISXUZLSRSBK57UXNJT4LU",
-    "GCKDSCQVLAKOKK2N4FP4FFYJ7KJDIIHLZTOWKPHOEYMH53DMMPGICOIN",
-    "GCKE2JKJJLMGEFTR5BLQRSR5TSAHS5NKAEUPVNLNHFOMPTWBUXEIJWYB",
-    "GCKE7WWBKMAJKMWLWT6NL2OU33WL27JWPTV5X5O2NFR5DIMXGM6TVVPY",
-    "GCKEJTLPAW7J7VEMRY4QWN365H4YXL3BCJ6YFLVZJS6WEZH5A3N42ZQ6",
-    "GCKER3PVDNNY6X7RVEKVM73IUN4EIDQ3XVFPD3BRRV5RGXR5WWRFAVIV",
-    "GCKES23IXM6L6ZUTNZLBN43LFVK7AQ66I22HOVGXFC5JPJDALPCAAIO6",
-    "GCKFDHECHRORBEYZSIRGBR4X2APPFDZPJIIXO3SHVQGALNKRQXGZO7F6",
-    "GCKFORM4VC35ZZ3BIMKYTCTCG5FAVGCRETA4PJIUV5XAKTLWL5O6QICP",
-    "GCKG3EJNDSDI2UOB2PEO3CWPMYF4ZLOJ5QCXEDX4BTF67MHUL3AQNCQV",
-    "GCKG7PVCDOZXLQ6U5WNC4AIJ4I4GQVP2SCMCQ4FNF7WIAZAYH2IIOEX6",
-    "GCKGCW4PCQ56OE5MVOHCXT3FM7GAOYN4OQDSGUPPZPGSAGLLWITGGTAY",
-    "GCKH4WPB73TAVGHD4G4CRQJ2SN6BNWI5ENT2QQLNHYPLGLY7GKC73OWE",
-    "GCKHB7S2LAJ2XBUL7BYVBPGLH4INO3HOUFKLISBMW3NBHGDND333IPKD",
-    "GCKHC4SOU3OXFRT5NL7FFTE6U4QVXSDY27DX7PAW6J6U2WNJIVRIUI7E",
-    "GCKHC7E7Y3KKTB5RFBPTKAZRH22UU6SZ4PFZEOXHQ6OTWJ5YL2KR5LRZ",
-    "GCKHHOW4FRPKC2RMSVSSDZIUURCLZST2WHBLVBWW3RHAZHLAREE3T2VE",
-    "GCKILWX4IW2LKFWDAGG4YFNEPQEQONEECQQ7NBTMNKLHUFXPV55PRTLI",
-    "GCKIT3M7NRPAX67EV3LPPJ6L6G2FYWXWMX7B3XEUZJW2RAKPHMNLXUWL",
-    "GCKIZCU7T7DDFX53NVXEYYNKEI44RIL62B6PYTAVFUNBKORRSF64TLRB",
-    "GCKJFTCJVYUE23JSWP72VOJ2BXD3WU5BI7SOW5LOI5C37HR7DW4UCFND",
-    "GCKJFU3OHO5HLFTB33EK6W6I7V3CXJNVOKYYR5TCZXHTWTZ5GDLXP5WT",
-    "GCKJGTICFQR3RNHDR6MAIIS3CO2AWYL2KB752ZF7JATY6OGLRTFKA2WJ",
-    "GCKJHKZERESUXEKPBZO4EBP2RBDMAG2JZJP6QO43HTKPWGYIXNZG4446",
-    "GCKJOLEAU724YPTBLQ2BCQCJZZUO2XGNLTTVJXZOXKL2I6Q64I7VF6QP",
-    "GCKK4XZARRTGAQWFEWNW5SNWE5K2YJWCHVMZ5YFQFI3MW7QCK3RVAS47",
-    "GCKKDAFOQECNTMHD37BE6EB4TGFKB4EL73OUV4U3M22MWIGF6SWJBD7F",
-    "GCKKFGSWCKKXWLFP64D5JDCWP7NG2ORHPWLZSJ6VQPDGYWY3ROGKJAPG",
-    "GCKKKXB45FCJYOYKU4PN336SJ4HBQGKLC6TN5WLJFAUOOZT2S6G7EXRP",
-    "GCKLJD5UUX7RHAKPILCTJDMDFNSBCBW42RJNHSOYFAZPAGQRNOBPEVPT",
-    "GCKM3AJDFWOFXRR3BEE6JY7POIRN3M5A46RR5LLRXTKJL57KKCWRVKSE",
-    "GCKM43OWR5XB6FORPQHCLKH6ZVWNS3PDNUB5X6YOSHZVXZLGWNAJQTKN",
-    "GCKM6CL3OP3VVDKSRB3AIJRFHP3IISSAEJNWTWF5K6U676UXOH4QVB3I",
-    "GCKMQ7UPSQ6YIG5DYTKVMUGWLQVF2GNCTYF6AVSUGXSS63NVV3H5C722",
-    "GCKMQXK3JHJ4DLFBTICMK2BC3TCL7OA3WJLGCQATHKQF5T6I5VEJ4Q3F",
-    "GCKNFNHUEZ6YYDGZEHQBGUMDW2GSNCL4Q7JPRH2WZWY34LROX5G424QV",
-    "GCKNLDXIDQOEGSZPTNVGIPNDEKZDMW4JZQI6PIITM6BFM7NFZ2K2FTVH",
-    "GCKNMBOBXNFYPTFGBHFWJO5WDFL2NK2QNGMJL44UXHLIUJCF5A7BYODF",
-    "GCKNOQEKJKWM6LUZ65KHGAYZ46NKLYUWGB6GNMVIKO4WKV5SSPAZ3QCL",
-    "GCKNPT6OMBAMHBDC64Z4JMNYCT4S4HNK7Z56RHTLKL436SBILRYCZKBI",
-    "GCKOG2ZEXYBRCLWXTCWBAGLBNU7NVPI7OC7E4NOL2ZLKZIXZORZEA6WI",
-    "GCKOGP7IAHKMV4GE4T6TGLHNRB4MLT7SZFH54BRABQIQVNWH26PVJKB3",
-    "GCKOOC3IOCIYNWJKYXLBYA754YNVKWJ6W55V72733ETHU4VHRHFWAFBT",
-    "GCKOWYZ5YRJOJ2JR3RSTYWL24MX6ZIQAEPS7XLBA2WTMI53ZMK5YN4RO",
-    "GCKOXKFJ6N5WLWS6F747K663ALCUZWTBWHO4AV7B2TFOO7JACF65CSTX",
-    "GCKPDB4NNYS3SLA3N2HZVLK2EPL6JLIHQL47YPXQBUKUH635BQZILBDE",
-    "GCKPKKVJEASVLNCWHDGOEVKRXA6OAIX2XYG33V27XTYXFAF2POVHH5OV",
-    "GCKPMJRQYIPSTTIQPNBNVUR2ULT4JJNZ4BFNYUW7YPGQHNGAXRFPAF4U",
-    "GCKQABZ2NI52KEC2GRJC2UTPZDGOESCGO3H2DGYY74W32LLG2UIRHUQT",
-    "GCKQC3FYDN3QPNJE6XF4N2Q3SFCZVHRSBZTZZNG7F5CCGRYKZH6EUR3W",
-    "GCKQUR3OSPXIXAPD2H6ZDVFZIAF3ZKZJQ4OQQGPB4HRQP7GUVAPT5J5D",
-    "GCKQVJCGXOLCWJM7K77ZF66HISENQQOKGBBYLHKQKPZC6W4QDDM7ITLO",
-    "GCKR4LDFKJNQ237KVPV75YVXWDSW2B5GYOC25MA2T2AMM6YM64SVVSAP",
-    "GCKRMN4HB5JPNHRF7ECBOVBVX5ZHASJK7FNM7FDRQTC32KERX6KHZAM4",
-    "GCKRVD3GCEHXJE73CU65W52BDQUG7ISYRVDEQ3IOUNCICM4FKPVP7SYY",
-    "GCKRYOS2TWGUPU2N7BVXRWK7K6O2N7SIF2HOQA42WI7CTNWBHBEFOXLM",
-    "GCKSRVFGXCRI4A464FRKG2CRZISZR6FVSQQ2NT4Q4AI52XBVRPZL5P6V",
-    "GCKTVME6VCRLL5Y4N5PJNLXR7GFV5VGPJWOC4FXETRWFNQK5W6WNRXLR",
-    "GCKTXPW5XRCLJTCEG3BPPI53PHS5WIRKES7XQOCRTONFVSYPF2765BL3",
-    "GCKUCARC22Q2DY7ZHR4HZRZG2DDEYHWOWGVSQOMMZGJAUTAT7RUHBXLM",
-    "GCKUR4FJUOZKVS6M3SLUZTECFG5Y5UQHX2JLIQYXZWNLQBLUQPP6XQT3",
-    "GCKVB5X6PBNT2T4KMZXOWFJVX7CG2QESE65CHCIB3IVNXGKKMQYQE33Y",
-    "GCKVNYBNHLLT3Z4F7BN5XGJUOEUAD3UYK7K5FGEDGMLP6ZGMYTC2CKSD",
-    "GCKWBSZ4YM3SSVRHSCYI6VO6ZLREB4CQ5TZEWV2Z7TALNQRQL35UUXRD",
-    "GCKWN2OK2UYEWPQMXCLCONLIVR36X4XAHPFZGPLN44ODJJKB5BKW6FRX",
-    "GCKWNOKW2FO2MTGM6MU2G2TBFT6V4N6RIY2SD6K3UXXK6TZTK24BCP3D",
-    "GCKXAYD4LLODQH6PR54ZEH2IXUCEFS3GFTD3KUICEMFG2FUMIUKV7DHF",
-    "GCKXFVZGFCND5DNHVVFGVIZOUCCDMNPNRRTT3TI4Y7YXZZ63EMPWGQFS",
-    "GCKXKIEEX6BO7FJJW6M5ERFK2LAAWVLDS3RXMZRBCVVNV4PIPCEDNTCR",
-    "GCKXOETYHZZCBRFJSYLJ3EYLGDNGNBTDSKOZD6K7REZTAJGIYWPA4QHN",
-    "GCKXPGCNHLHZFDHZHHLEKJ4BSDJ2H7RHCU7VHH35UNTCQYH4BHJCSF4J",
-    "GCKYZQU3I5VORRUQD2U3GOMWB2LI3WDHR4NTXQSNF4VYIADNT7WFUO2U",
-    "GCKZDJPSS2GUKUBROEVKYLKSJO7TGRJFPFQ23WPJ3ARXXIKJ3P42LIXI",
-    "GCKZIOGOGHSNPJ6BFJ6OG7YGHTLFN32BHGORYINE65RTDCB2IW6KBYLV",
-    "GCKZVK3WYOR2KCPVHGAIVYZGZGXUXVI3TJFLU24B3SGBXRRZGF4HHA3Q",
-    "GCL2CXVIAIQCB7AD3FRFL7TSDDJW5LGWKGAJZZDLKIZJTX77M22XZIGT",
-    "GCL2EXSTHUTSDP75K6X6QIPHXNZ2Y55KC2H3BULW3FT53JGDVHYC4HFU",
-    "GCL2JTJWKXPOROGPVIJYSRYT7U5B4HE26FJZS3WQ4RRMWVXSK5PUET7Q",
-    "GCL2L6NAODI4QLNATA2CO36TBZ3J6J7XJNZG7Y5T665QM7R3Q3PSHMHK",
-    "GCL2P3W5GGYUXRGXYSBDBWHILG2IGCMIQYA3X7OJLMRUORGS4RVMQPVH",
-    "GCL32DPV5KVYCG2VSTMOWBWAHQ5VSAPGGZXO7AL72JTW23S32RPA3ZHS",
-    "GCL37TPIVCCUHP76QO6LHS4PVDP2OG2NU25UM5PPE6VUDOU4GEGHJSW3",
-    "GCL3KCCSOJOH44J7GSDT4LYDDFAEZJOHP4X3U4U5Y4L4QDYYAOUNVVVS",
-    "GCL3TPSU3D2XJHQANZJHXACP7AOPM5BT7KHQTZNBFKUUBDVOB33MDY46",
-    "GCL4DX2MFV2HCKW6BBJS6T65CDHHIV4JVG54WZJKOOTJI36BC4VRXNBF",
-    "GCL4WJ35J3XWPFNWBD732HQXH4KP5VXDYK2BNY455JDL7H5TS6VWVML4",
-    "GCL5L3SXOBGEWGI65Y5TSBVSOUJJ5MLCDJJRM6C5RG67ZAYZNIXCH5AG",
-    "GCL5SR4V7QWKGCVKDP66OROS2T6AF6AFBZYPCVIOO3WYTHGJHWVLNP2N",
-    "GCL647UMDYWPJJBOS3LUY3ECYQLGTLFHJS3GGCXZSVVG54F3XJMZLUGK",
-    "GCL6KZUW7CPUOSGKG6R6XPR3RYJA5L7P4M46WY5G4M2R5AQRDSF2R36Y",
-    "GCL6SDXLLHJKZAW7T3HRYVE6PKO62XH5F3BB5ETOBLRUS3OLI35GP7YY",
-    "GCL73AKAJCH3IYB6NAAQO7AKDOMJLFV2FQ6ZQJXBTE5LTFJV6L2QVLF7",
-    "GCL7ELGE3RRUVOK4A6DYHYI5SIWFGB6ORI22F6LX24PGCRX2XUCOBXQV",
-    "GCL7NLWKT4WJX7J6VQOG4LSE5FFRNEXEIXMM3VWEOUHJKJYDVO2IRX2P",
-    "GCL7UYSGZFLEMV7TEJEFCKK6HLPVZDHMFFLWG5EQVV6QZKKEPPLJKRKW",
-    "GCLACA5VF7MGDBMUAS6BAE62K4Q4OFMKLZI4NP7TEWV3IAWFV4AHEBMK",
-    "GCLACIBQFTHS3TPVUIPD4L3FZWQIQ5PSCYDFW5FC34DZP6RO7SKXIUXF",
-    "GCLAKZWDSMBCGVBIPY5TEUVNSL7K6OCPWFRPOTY5IZSVB4WIGURQ2HYA",
-    "GCLBAOQ65NV6FDC76H3OPQNDWOARTQWDBWHZQFHRXGQ7P5ZCLMDV7CHN",
-    "GCLBBDCOBGXEQRVOF3V6GXWNK6A2POCTAOA4WPLM3GTZCBEOACUMN6HW",
-    "GCLBFWAWIAVMS2IZDYPPMG7BZN6MINFM3JZMW52GXI24DOECOW7C2FY5",
-    "GCLCI26G52CNLBF5ETYS6PRQGAA2KHJSTQYHDY5OCXTMETHH3R3ND3UA",
-    "GCLCI54MA6NDG7CS6DSVKBNHY6TONXVYQ5HOBGMDAZ3CPS5DYHNHZTIK",
-    "GCLD4ZBKEE6RQRDSM74DSLI6OZ6XTSZOYJG433KJ2WBTXWUQETZAPROJ",
-    "GCLDMIJIPHCFRPSG5UCGFZPLPRGBSCOYHPKGYZKS64WDDYOKE7JVEIOZ",
-    "GCLDPU5Y7XN5YDMPYKS4CNXSWBOXPTVXIP4IA7LRBN3UNPBJMJJBBVTB",
-    "GCLDWJMIGDFLVSULNUHXGM3CP6UDLEI66B652JCHRRIOV6NXKVAOKQAM",
-    "GCLDWTRGG4UQSKOHFIZ22MHUCKEXBDSZ3VUMMMPSU3NUIQTQDHZ42ZMG",
-    "GCLE3FOIBAN4PUKWOWN6IUFWNBGFNWD6RETLUOGSLMY5WXBGAOSS4SWX",
-    "GCLEHMXLP7SEQZUFJLTFUODIXSH66GH7I5S75YKJSQKFVZ764JDQZZZI",
-    "GCLF23L7TY7T5WAYSAFRN7KITYSXVLAKOH7DQWWAETJMY5IAKDG5676B",
-    "GCLF6ARQ7WYOMA4IFZVCNBN75M5WGEMA75ALRE6ECAJ2GH37ALMKTYKV",
-    "GCLFF6RUVK2A4Q7BBZWMCSEL6EK66BQAIDV6JDDDA36EKP6HNK3HH7TA",
-    "GCLFP5JP6PQ5XKZOOYNZ4I5IV426FGWTWX3SIM4UKTHXLW6GVWWUYKDK",
-    "GCLFTG5TIQXBP7NTUPCKPMBBUGYVN3MSCFTSYZWTD4LHRG3ZB3CRWETH",
-    "GCLG55EYQNYGAFJLSKSVCCHSNXN3QCVK53AYEO5WHCJNUGBXKUG3CWJA",
-    "GCLGN7UQSB6JNVM774H465WJJVGXZZQ2X5HIP25IYI5QB7Y3GY34MAIN",
-    "GCLGSPVMHDBDZAES57IIG6VWYHD2RA5D75MW4GX7DTRURY65CH2FJ5HQ",
-    "GCLH5SFCITGVJMEROGB275QOTT2OZ3SEHWIA2EKGHQL7RNBSI2QCWEEX",
-    "GCLHCFRPTRNDDOAF3F7FFS5NCXGR3DDVFMEYTMQE7DVSIDSGPTJOQD2U",
-    "GCLHJ4WH64QTGHG7NGI2IDBQYZK2N7LN5NAMTVZGXFLW5KOZWIKXEC4A",
-    "GCLHM3I6EO363OL7Q7ZC6ZK4WOKK7JM2BOHWVEEVUI7FELC7NGI7QKED",
-    "GCLHTZHQB5F5G6ABWP3ARDTFXNSKL66RWGE252L6UDT67YVD5TJ5TOSS",
-    "GCLI2RUM6EBZTXYB6LDUUHCPYV2FPELG2QKREWWNUZZPTDFZHQT7YJRA",
-    "GCLISM4VDEOG2MQKEAGVVGJKCHHQ3I56WJAOM5KPDUSOFK63HLGB3L7E",
-    "GCLIYGATM672GDBXJ2SPBCOM2M7MJUUTIW3I4I5IRB6AURQ64HPATSLA",
-    "GCLJ2MTUEDUP2EJUNVQ3ZX6YMZF7PZL6S3DORGYNE76QLRDBIXIFELON",
-    "GCLJJXYA4JD73SMALNS7L75OOKKMXCFNBKI4FTEO4HMQ624TDPNDCAIY",
-    "GCLK4ZVXQJBXXHM3WE5JDYDTO57NOIMDKFTAOAQN6HEMMIVHIEJOCOPR",
-    "GCLKROOXVPCB4DAKOCF7Y3L25KBUVYTHVE3M2QUBCNSQDSK75QIIOTGI",
-    "GCLKSHSQPIEXBUAEAP6MIISHKDBOK32KG644DPSLCCB7OSZIPZO746QL",
-    "GCLKYM4K7OLKEFAXTYT5GBC5LXW6NLXJEKFGJTVQVI5BYCD2U3U3CXLM",
-    "GCLL6XG5CQH6UA3YDO6WR3HTAMQZCSEI2XQHHPSTT3JSIJQGB4STZ2CL",
-    "GCLL7YUKBUHMQ7KYQAFFEUS4GUG2JHH2MJCPVRSWGAP2Z4NBYD5TLIEC",
-    "GCLLGVDOQXUBAF3UJJJPOG2FRVNGNEII6T35L3TLVFBZZHEN6XD4EUYY",
-    "GCLLU2IKJQAX6DQGXJXVZDWMYWRXQ6LISC7O5SGEOS7BFNISWI6TEXLM",
-    "GCLLX5PH5CBTLFVEPGHUZGZY4GBVWOFESYRBFXR6UYZFLJF5DMEVBRF2",
-    "GCLMFNZ4YDBTVU3QWZ5ULAOMWNCWMPP4GRXBUQO4YXFKOGKMWIB7TXPU",
-    "GCLMJKFZHKP5VBLRBQFSBTX2GFLATR6UB6TGRENAZ5TKQQXDE3FN5POI",
-    "GCLMJKRHWXY65MBENTXIAZEP7QWM2OE3GMVVADSOHDCNN2V6JWPOFBZJ",
-    "GCLMKSN7VLUGNG2LHJGBFLZJ4TNRMK4LX7U55YUC5CEWOK4KOSZPAFQU",
-    "GCLMOUIAX3R5OFP7WDR3QE3NBMW6SFG6KAJKTD23OE2CJ2UHQNZHXLTF",
-    "GCLMZ23NBBPPKD6ZG6E57O7B7Y7IIDUAP773AKMMB5CSHO4IFGRUNYTH",
-    "GCLNGSFXHKOKMFNUQVGHARRECBZTGV5LN3QJEBQU22TPFVB2GOFOVPGD",
-    "GCLNK23AJNNVVUECPQ75AMO4XPJMY3IJCIYDP3WVV5WAIW3H63JMMKEQ",
-    "GCLNUTPUBIWJIDSK4U5YXPIPSEZD7GOB6MOFLIVSKAUP4XUBFLAJWXDC",
-    "GCLOOVIJTNLDSWGJDI5EMHLUL2YWUWD72ISE7DEI3TC2LQLFG45RSSZ4",
-    "GCLP463IZCDPLUDFF6G4ETAEUUQRRKUSGMVABGK3USTUGVGDCSUZOXLM",
-    "GCLP5U2SV5QXCJBHN5YQE4HB6CTW6MS6CXNBICWHSC37IZ7RUDAEJ7KQ",
-    "GCLPMW3OR2Y3ZYPAOO7VVIXXVBTNOPDGNDFV3YYW2HBJBDGXRCUXPROJ",
-    "GCLQALSZMPWBEUWSNX5LCY7GLIISKWV3MXIC7L2SVVUOSOZ2Y64B6VUR",
-    "GCLQMERQP5Z2EVXV5POZT4HZ7OHSGRVHXPN63VK5CFKLNH6FID23GG5N",
-    "GCLR2AMKV5NJS4PEULYESQWKSDURUOFUKS5JOPHS6CIQGRRS4LDQ5ORC",
-    "GCLRE6PGIR2N5RYRWEYTKHJ6I2E3KBR6KYZ7CG6Q4GGLOXT43GQCQ6CB",
-    "GCLRPLNBDEUCFD4SHWIW3CQC7OCQAWDZMKXCZNPEDB3TPZSKNJOUO5AC",
-    "GCLRRIFB6AOABNDTHJAMN6SUOWSMDAUOUYH6VWVV3BQVTDVURL7CLA43",
-    "GCLRUZDCWBHS7VIFCT43BARPP63BHR32HMEVKXYQODA5BU6SIGFK4HL2",
-    "GCLSG5766GIPPTFCFBVUL2GFVAXD5V43LLXJUXV37DI53U4QRVWZAP2X",
-    "GCLT7SWBDHPLOTPKINWJRYQLP6TXXOSRIBVXOAFDB62UOMJUXUASJVRQ",
-    "GCLTQ2YJSH6NICNJBFMKMOM62237CZPUFYBGBUICJPOSSGA45CGGPQDH",
-    "GCLTQGPLZQAAM2JBYSXCNGGOANZ7U2YPK5KN4K3V3DSO355NV7OEXWM5",
-    "GCLTX6PI22IHJVJXA64RTCGR3DMAWJJWWVMCN2VNDUKL7CBZXXHVELON",
-    "GCLUENC2U4LNUTMYGTKO4E46CF5UBP7IW36FTCTLA2VYX4I3WM5WQSWD",
-    "GCLUKGDO7CQJ2U25BC75LAYGZAQ2R35ZJUPRGLY4FE363S3WTHC2CDEJ",
-    "GCLUWGYIEYDKVHPKBN6IITUD5ZMI6ZO6NTP2VYH3CLV5FVOE6IMCTG3D",
-    "GCLVBAOC3BEX5ZKF76TZHY5Y4BMIQCDVF4DCOWSAS4BJBJ32MB2DLGHT",
-    "GCLVCCDJ25AJOMH25CG4WYWBIFJTAC6UE7NLVHC7MZ6MWZVZDQJ2PCQR",
-    "GCLVGLXO2R6SQLPTAPADMITKYK5EJ73ZROIIUZ7IWW2JDNEIMHHUJDOT",
-    "GCLVPZZW443PGPRRM7JZEIHNVTZDRS4VCODKT7RTAAZPOHKOKD3GW7HL",
-    "GCLWNNM3UGSDK5CCAZYGAUCYNG5OXY5FU456LBZA5KS4X2KU5OOQEWON",
-    "GCLWTX3WH5EPM7K5V6ZJO3UQII3KU6XLNB7E6J3BLKOO46MBZKV3FRGO",
-    "GCLXBHQUJJV7IPITSG6OA4FQKWBYAKM6VAV2XDG5WDIJJN6WGFVM6O6Y",
-    "GCLXOXP3Q4YA6GF7RR2Q3MU5UF4MJICHDEJCV66JPUNKER3STTOPD5VH",
-    "GCLXRUP2GX3OD2CXMMGO36UBQQNKQJVX46IVMDPAXZYVTFWSX7M37SAP",
-    "GCLXSWQ4VHVHOFZBGW52FBBRC3PXO7LHPLYVDPVK6SDZKDIYESB6P2WR",
-    "GCLYQYKTK5ZHCXCJS7DJDLFNZGSMQFADEBTGFKBEKKOVEGTRHTUBWVXT",
-    "GCLZ6JJXTOKQ5RE6IWIDZU366YXJ4BAX7UXF4A3AOZETYSUZBCFPQQO7",
-    "GCLZFRR7KXNQ2DJMNMJR2EHKVCVFLN3NRD5LY3CJZ7MUWVXS25VIYMKR",
-    "GCLZNZXCDCTV7THXOR5YH7LMKRNKXMBU6ONV7RRWSJ4XZZD7ZJ2GAASV",
-    "GCLZQ2OQO2N2R3MDZI53ESDFTHE32HQ4W66VV5WAUXZFT3NMK3QENVQC",
-    "GCLZXLFPPUBQ3UFFNMOCRI5DCP5P5MEXLED5OP7WIKFME576WWKPOBHV",
-    "GCLZY6RJ5MAFTUJNW5HYKKWKRKCI4OFISNSARHU6V6W3Y6CNA6O7J7Q4",
-    "GCM22W5IHATWRB5ZDLJOC53BNMK56R2YRX7FWMU46ERQHVE5UDXEFIP6",
-    "GCM2F4LJ7IHEBPPNKQYJXFM2DXS5J2D44GSBXPKOHJYJBS6BGYXKJIND",
-    "GCM2FMAXYCXM2LTI2VABS4YLD3YQGBU3T5FCHDAAMQWL2SPNDBKQBEF3",
-    "GCM2JQHK5EOHJ3LNJ2SS7A37RRKSSW2WYT6C655RPGUP4MUEFEVGHK6S",
-    "GCM2WE4BUAENJIMV54TXZCS7AUUPWYRUQV5V7AEO2AXJQ5N7M5XANICE",
-    "GCM32JQGV32DF7RVP4X73DAKD2ILDM4UNNHQZQF6GQVBEBP7O6QIOYIP",
-    "GCM3OVNAKSS6DGWJ6YJAUBUO5U33GPNZNB3W3YLTVNJHWI5WGYXMXTSV",
-    "GCM3PIR6WSBPDU5ERIL7ICHPDOBE73YUAEZGCSOV4M5ZGEZN5NNCIFVY",
-    "GCM3T52ZPMOFVDPMGC7J4Q63SOS2FST7PC42ZKR5NB552DBVSX77M7DF",
-    "GCM3UKPJDHIX2FC7L76N55XMYWZSFY6URLJG2TAB4DFGO2N4E4B2QRVU",
-    "GCM3ZXZUPMCZ6M4S56DEXMJAVYSUVH64BNWPJWSZXWQZSHWTRWNIO2EX",
-    "GCM4RSX6MEA64Q2N6ZNQQO5VVWXQI52U2GZWE2O74Z3RRXSGIMR44Q3C",
-    "GCM5MOD3O53UW4Z4WE3XHVLGCHEBSSOFT5VZNZJX22XTBGKMP22EJNQB",
-    "GCM5NOYFHI7W3BGBEWCPBGOKMTU2JU7LTWWB46KEKMQ5H2ITE365YRYR",
-    "GCM5P6WIYXABKGVCFQC5UD2JQ6KJW6XBOL4EIDZIOP4ZVBTDHOVU2I2N",
-    "GCM5X35A6HL5BSXLMKV7IPNUGRDVQQ4G3PEJRP3PZGLEPWTDJRSJGIO7",
-    "GCM6IYJEGQCJ77ALZVCIKCAMQEZRVC4U5STMVI2OXMIF3T5GUBJ542WF",
-    "GCM6UUVZR43NV6OJ76XUWJBVIIYZKKGOTBV4Z6CNBNOS6V7LETWP6UC5",
-    "GCM7EWSHLXIQ4E5GIBFXOABMQM7L6XHKX7L2SXPGFB5IE5TP6KCVNJWN",
-    "GCM7J674B6EEWEGMZX5ZDDMBCI7BHAE2RSS3HK7JDC5YJRBV3GUKQX34",
-    "GCM7QNCR3IPM7L3UGBQ3PPGMKPT4JJLUUGCDK3XRQYPATPRFCFZBLEJO",
-    "GCM7YIJ2XXZZZXJNKEYCI3SJATY3UCJQ6HA27W5RPWB5EEZE7TDOII6H",
-    "GCMAU7DCRIQYJGUH2P5EA4QDRT4DPEHYI24CYF5F7MNT2TLSCVUAKMBI",
-    "GCMBLPY5UDH6UWE3CUW2UTNAURRIDZBC6ITUVWMR72JX442NFYFSXYHY",
-    "GCMCEBH2PEYLX3GHRS2M66J6S5ZY5GBKHVBF4HEKUAYYXMBCDO43GJYK",
-    "GCMCQXRNS3NADC6YA762JPJ2Z5BRR3L3IYBMTECHXTRHI7SK244CLAIM",
-    "GCMCU4XAKJEMMFTIZOHWGAPTLHRV4AIARHSOY36HNXBSIVU33F4YQNET",
-    "GCMCUVLVLKA6SOKAQU4Y4SS42EIT2E2NWHBGUUTJJ254Y2YQ2HB53X7W",
-    "GCMDF7IA5TMAB5ISOEEBKPUEO5WH6YK6634MCFX2CMVECRRQOISVOGFY",
-    "GCMDPORTL6JQCKLLJ473GUF4ME2MNALFPED43AJQWNIJ2XH4L26FU3R3",
-    "GCMDYEOY4W3IQZJEUMYVCKBY56HCW4CRM2WYDOCQ5JSO3E5E5KILIU6S",
-    "GCMEEYXVYIBRK6LSJ5KNWWLBU74YX22LPXZRUZVIZIMHT7LFCQ7F7GED",
-    "GCMFGYPM7TX72YYFXL4TP2PVZ5UIJCSUSD5JVYBKM45VNTWVHIGTRUMP",
-    "GCMFIQDTFNF2NMJ7NJCHIWJZCAGGNLSWVTWCZSRNY45OAMCXWOXUM65B",
-    "GCMFJIXO7SG6EFVXFSFPWLCWFO335RRDCEGBKMCNGSANDPNHIITAWRJO",
-    "GCMFK5SNABMCSO7QV4WOOZDAPCHTJJH7YZNAHD2DI6KJ6XSXRIRP4VWH",
-    "GCMFQDSILBNM24JMOOULLLJGXRG5ZR64GO3J4J4RDORSTSVMPFFOXQSI",
-    "GCMGB5YYXLJBKDOVTDH3672JUKXIJC36ZWE24R7XXTJIWRF6VRS5ZGLR",
-    "GCMGYWPEC4OOPR5VIZLPOFT7AILJ6KUZANT56O4AH7NIHBOQHYGYZQTH",
-    "GCMH4H25OUMN4PN5FC7O5NAOYXPREAWBP33HEMUPYUTHZVU3JJMNS2LD",
-    "GCMH4STEKUAGTOEJA6U67Q36LU6EKT5O6PQNXIF35GYIGULENYNSUTOK",
-    "GCMH7XCXLHAQ543XOMXQFPDCPPAPDXITLXWYISRZLIJJ34PZNMR6HMES",
-    "GCMHA5OHFHHFIN3646O3ULLCOCBZE2I35KHGUTCXKVX7L7IQTRIJC4DQ",
-    "GCMHSXLSFVKPWXW2QFLG3LENFBWE6JZEFDLJLOJE5FNJS2CI5O73CINY",
-    "GCMHUJCEFTMBYH2LIGHQXO2VT23POTXINIOSLOYXKPINEGPYXOZGW2VB",
-    "GCMI3LW55TOR7CSO4I4BIIBCNE7TKRJSZKVYS53GCW25LNRLH6TKL322",
-    "GCMI4MHTZXMDW5PV43AEWEJ3EFNN2TKBIK5BSILK627ROU33EFODCXMR",
-    "GCMI6PGOEW7MKFBWJLI5QWOA55RB2ZLMTDPZ7FWGX77OOWEMLUY7IZU7",
-    "GCMIJ3L6FKI2KHT5IEUULXKQK4KAC5P6K6EHMJMYMIBMGA5JLCYRXXPN",
-    "GCMIW563O2SWMHR753WMEUOLAHTUH3BUVKSTVZ3J2UJY6EYEN7XXPNYM",
-    "GCMJH7RPVTETJNUQHO6RFXHGJ3ZHQTDCJB33GZAEQ4YXJMV5QHP54GDG",
-    "GCMKBD6TDZNPP42IKDXRW5XLEBEHZ6472XMVJU4W4SQ2YYB3NZWOENQG",
-    "GCMKFIRZTUMMWXZOYJTQQTBNXF4KGUEAJSG2M3UK57EMIS7ZHQGQTYK7",
-    "GCMKSXN4MLI5ZBZF5ULM6RB4YM5BXU352C5BJULDJ7VLVVYJYRAVOWK3",
-    "GCML3RPQWG25UXMBHJKXV6ESRAKGHMTSYP3X7ZBZT3O4JIGUJLSQ4SS5",
-    "GCMLCETASPGLUPPCJWAL5FJ7RRYCP577DBCSQX5CHOKSXJOHF556MWZT",
-    "GCMLMR7Z7ZBTXFCQMBMR7YIH3GOUI4OT6SUE25IDXEI44ELABFMXSJ3L",
-    "GCMNVRXS62IB3HWXZTXNNOBEKO3INB55SKYGX66H7X6HBQOZYX3QMORP",
-    "GCMNYHJSCVBFBCOC6N66QIWFU64FC2AMYWYB4XRJBBHUA2AP6LVRUTGZ",
-    "GCMP6HH2NGD753LDP5TMSEHPZQTPVIKQF2C5E3FG5K72STRW2CB7I5RU",
-    "GCMP6OFCTIKYKVYPCEQ6ZMGADW36PUP5AUEETGSCJ5P6YCX4J47QIZBC",
-    "GCMPEG2JMHCQXKUASEFV2HUREEYCNRW7UH7R5YK5HV6O4MHFGXWJV6BS",
-    "GCMPQLHEDDMXHW7ZLPTLZPXFXJ7EMOLQ7YPBQF24WQPR5ATMJ7SKUSAT",
-    "GCMQ7T3JTO2DQP2WTP7D2TVEWXX7PG262TF3GUTANKJGSIB3AAZJRKNI",
-    "GCMQCDKM2BOSDMXOAY26BT5T5CFD6THXONNSZRKFDZG44DWOAQXNH6P5",
-    "GCMR6VVRQ3O6QLZJWC2I6PABI66OYC2GQCAUD3IYNNYP6MD2JAYJAZZL",
-    "GCMREK246ZXIUQWNT55NSMQLWVG6JHCIO2DQM76KHROYXJGOPAUNX3SJ",
-    "GCMROKRCHVNNNM3J6OC3OZI2ZL4SIUDRUGBXOS2S5MNZUCN4D2CA2R5T",
-    "GCMRP6EZDSJZ4UT7FEI6VWEOFREJF4QDPNGH254X7UDY2GOGCYBHUX6A",
-    "GCMRRG5MHHZTZLJW5S3QFWOWM743BMOPN632HK24NCLGNM2LLBPDHQ2U",
-    "GCMRW6XFSRAKNJ6J7E64JNRRCR5N6QILEWX4GFYRFI6RSYORCPAVNGRD",
-    "GCMS2VO5ANJCESJBZVC3INFSETS3K4UWMU47W7KSQ2BYASEQAN4NUSTM",
-    "GCMS6ZFFYUE7J7B6QDGDT5XOZFGIOASXQWQUBG3GTPUNG6EUDZGXK5RU",
-    "GCMSLPI7MZ6HJYN4AVE6QHGLCMNCT5PIGRWEECQZMEH42QCHVHHMEXDC",
-    "GCMT5KC3TZ4V44PJRAYWIBIHQDT4NW2ATIFTLHFWMXPRCWPNSQUOPEMX",
-    "GCMTTCC6ERRQ4YWOVYZO4C2ZGPHMVPYY3RWZYMU552H6LYKYK3QSF57Y",
-    "GCMU26XW2VBOQYMSQVUFPQOAB4BQGO7EBWW4IPU36KDTIFBLW2QYXPWR",
-    "GCMU5X3OJSG6IMIOU6EPKYHCBNJWIS2PV3D3HPVQY7IBUXVULNNMHAGT",
-    "GCMUTSJB5JJNJZUGH7WI6WFMWVJTFRXAJL4W3KV2IXMX7PGBUGPVC2RD",
-    "GCMV4PJ2P5SBLI2DVANFG6OCCLY4YXYCZYALPPWVORFSFHD7JYMCT2WW",
-    "GCMVO4HN3HRJH4ZIYHRXAQKQOQ5EMKA2RYZU4DY4A7NVIQ7ZOM4CO45O",
-    "GCMVQJO6VOOZDUWAZN2W5MEHETWACUWBPFLZ4J3TZY7TLYREYU3DAJLI",
-    "GCMVU4CWADQISVTA5IJWU2UWSUNDEFALZYY24EWWAFA6WI327JTSNLLX",
-    "GCMVXOGFYGRWZBWRSFIVAH4HJWXSX464BT6FI2LAYXL6MZSRH6MDBWXL",
-    "GCMWDL72QGVQSE6VESCHRIQSW2UAJE7JSR7W4QG7OGIIFWFBBRKWWHIP",
-    "GCMWKC4TLN4OA75PVGPRKWVSIGMMIJNQRVS2II5GVK4JQCAW5EPQOW7N",
-    "GCMWREQKAAC5KAPYS26IVDV3NJMUW3WQZGHSFS33QIE52XWNEMB4BKAT",
-    "GCMX3IKSMBUOGYEX2NSNZ6LHIZGFTTWPFOCV4YSPKX2YBQ5WYUH5FG6Z",
-    "GCMXLA3GSOR6KUFRH7WTVPMUNTZ4KQNJSEPY2JBHTQUB7HTP6KLQ3CNP",
-    "GCMXXKM374MYFIHNUPN5UYXGBSQUSTM3DCNCT27ZBJUADW2MM4X5BVJZ",
-    "GCMXYHR6HDQZLVF77FTGZYYCLEPS3GOERLPOHT5PZ6O3ERPB2GULJAEC",
-    "GCMY7UBVJIQIGNPSYCLIKJ4ISVHSVKWCHKF3JBAXQBIBWN4FS5QSYHIR",
-    "GCMYUMWW4IJSLYB4LGB4IFPI4DTIDYS2GY6GYNO7J3KKCLO7D3K5HSHX",
-    "GCMZ5IGKGUV2WKLY2DO3RRFETYJXWV6ZWJTMCFTJX666FNOUCF2AZGI6",
-    "GCMZHTAJGLJDQ3M7KFS7ZBUVTDHCQQWQM7CRA4FRDR267QPT3RAWELQV",
-    "GCN2BTE7C42Z4QH6BU7OX2PKAJN62IRRTGQPMW5DIPORUIQLOGHZU4C5",
-    "GCN2ICRPKG2KVUGKWQITJ56LHVYVMAGRMCRJTNIVWTGFZOOEH5Y74SHX",
-    "GCN2N7NUM5LVSXPCE63LENMXBBBG53VWLDTBNZSIGJ6EUKAMDUEMDHBP",
-    "GCN2OKNR5LYQZYRFHO4IEW2KTG6JBOERSDK7IHQB54UGNDNKHCKUOOPE",
-    "GCN2YLLRIZE4GBALROTQLSG3DHOWII45RQR3RD2PRJLXPF7WLDFOGRAM",
-    "GCN34BRD2TN3JXMVC3CJQ4FCZDR6TLOM65XT7SI6I7NJ77I3BH7EWJIS",
-    "GCN3T5UWRHURREYSXMO7422XSSDPNIIXV3FOLLWSM7BR3VICYC4PZSWX",
-    "GCN46WNSHB7CX4E5FEOGNXHIQSBPOB7RC5GIMSKR3LYCBSLE42SJLLLO",
-    "GCN4P3PFEI63W4UBCXUQO7Z7LDZKEQILOMJCYHA6LRUKHPZU33WUR4UP",
-    "GCN4XXSPUCHT6FYQUF6ITPMGUBCNZCNGTORK6F7XDVUTF3X6RHTM5HOK",
-    "GCN5GL4LFLVXGD5XSQI7N3WLFSOXFKWN7C2AKDCUSCHTWT2PR4I5ZIAU",
-    "GCN5GRO3W2ECFB7FZP3BEHHUVA4LJ2XUDDU3PQLKSWPIDG4AYV5OIOTA",
-    "GCN5LMT4U7BYPAD7NJV52FNW57SXDDTQGMMFC7UITPNRXK5BMZFUNN2J",
-    "GCN65PH2QYG4TWIJA5GTVUDIR3WX6LXIBETZTSLZF4X5SI4OZ3T3BANK",
-    "GCN66ZDRSFJLE4H43O6PJ2GK64WLM7YQLDBLFRX5ESPPT4QI64TBTNHH",
-    "GCN6FBYMDOJK6KWUIN7MMTQNPWA6Q4SBLEPUL6IINE6O2VPXBJPCIUSX",
-    "GCN6FQPCQEQ7G7ISF4V6GXGYT2XVOIDNUQ3KUR6SUTSFG5LDXTDFYFQI",
-    "GCN6HLCFLYIR6A45GDASBEYK24GA34IIVZF54DS3E52P4LLRMRYSR3C5",
-    "GCN6MV7X4UPXYQROXMBG5MRX6AUU57J5OTKXX3A34QKRD67ECTDETKP4",
-    "GCN6TAWR3A33DKFV2Z6SWW2ZL4HKLC734C3L5CU55BW2WDIQGTXY2RUC",
-    "GCN7H4HNFVXZDRIVE77EBIO3B43H76MLOJL5KTPKWVZ5EGVVSH632BVS",
-    "GCN7K7PN2VJQOITQ2TRHGVYUYIBUL2U2WVNM7DEGGZ5XG2ZMXYRECMV3",
-    "GCNAAG4WBEOJTFPFMY6QZ2QOAFGCDV32ABU5T3BSZF5QF4XZP7RIHKBZ",
-    "GCNAOTN362SGNOWGRCVEJTFFPHO27TIVQU2R2RQRCGXLFANPWGME646U",
-    "GCNBISZAC7PV6AHKNNFZLKN33V2L4VMQULBYQJSAAFPBZ537Z7FU3OKE",
-    "GCNBLQGPR7PUC5FPZVMSNWULPL3GAOVJBAYWQ56KJGLXZNDHWA32XC52",
-    "GCNBP72EJH3UBVITQFP2RKPLCUDWPASXYGUKB3R2D3VKU73NTOCO7SR5",
-    "GCNBUGXDR7OEXOV7HSSMHV64DFQJ3HIZPSG7PHZIKQSSBJRC3EJFA5YH",
-    "GCNBXOOIROU5RS4HFLAX2VBBJN4K7MS4AI7NILXF7B4JSW2FL3S3QUAU",
-    "GCNCGKD2LZKJM3ACZ74A6SAGAUKC6GMWH7VZ3VHOI4IN3WJFE7V3HGWT",
-    "GCNCJUBAMHRYR277KSOFNBEBXKHPVU3OCRJWKIRCCUWZ63GTX7DDIJOT",
-    "GCNCLRDPEZQIFNODK2TDG2VYMY5JY27GNRH4MHSEDXES37THX4KSKXTB",
-    "GCNCQKHGJGEUQ6H2WLZD4DISTVJB642IICZZDU32FYQFOHJME4TR2OVF",
-    "GCNCS34MJSC52SD2HUFEFU3SEMNF2VIKPQEGKXZT5HIXSZTA66NEQDMB",
-    "GCNDDNVWNPLC2E3LTJANMEB2EP44JEH4YT4XFLMMR543OF5UI4YEUMUP",
-    "GCNE6ZUXGCI4XVYHYGF65UIA5DTZ4ND5TY27QPSGNJ3FBOF4UZLKA7V6",
-    "GCNELXGFR6XMWNXVGO5V66VOOBPQDV4CPSXIOWBB7JVOLOM6ZYBE5WW3",
-    "GCNERBGX2EVVMCMCX3AOLFHQWQ37SDVXFKSVXAXFVS6YAE4ZJ3EAV4VI",
-    "GCNF4NO3YYPKR5GNAQXHKUS3EZIPKYCVL4JAMW4ZYV6QVTLZDZGKQBBG",
-    "GCNFDMHTJCFL3XIKSGH5KQWR5C7I3W4UELVTHUYWAN5RXADLXCA2FCHN",
-    "GCNGBJPFYEAKTXCGQTU6R6KZDOP4JYONFK5V56EEALSNOI6AMVGHLUST",
-    "GCNGILU7LMICL3CNNZMN5GBDLAYPOYCBFX2GUH3YPIPO3HT6X4NL374J",
-    "GCNGJWDCJG76SDSNBWW5RXS5373VOPKPHRVAY4GPZZKDSLNFU7AGHLDG",
-    "GCNGYEFQAHPY5HQH6T76D7BWK4YTVRKSMDAEAV6OBBLYGTZQGZ2N2LDP",
-    "GCNH4BSGGWM4YFCBW7RADLLKLMBC2FGLWPZNSDJVD4XUJEABF6QYAQ37",
-    "GCNHYZLBCSVZHSQJ2DOIBHYBF4J24DJYGS5QKURX4AGSLBK6SDJOYWIN",
-    "GCNI65LYK2CFD4GXAC36XQXENAOMSQLCFTYA6FQU3KFOP5QNJSXPQIYS",
-    "GCNIPIQCBEN5UJO3GYAMSGBXZR5W5KJEQ4F324ZDAO4MHE2KVTFDBMFO",
-    "GCNJFXMMXA3DTC5YTMX4GRMU23AGM47ZOJXGE2LBMRJEOCJE53ZWHNVJ",
-    "GCNJSWAQFNRZ5ZMEPX6ZECVHXN7Q75G5MMN37EZXSMZ23W2FGMIO77RI",
-    "GCNK5OVJQJ3SFZ4SIDHSRHIIFFGPGD2WVID6BZMFGSJK4BPWAE22NHTO",
-    "GCNKDOM273XFZZY2ATEB42DXIYOTWSSZTLESXYJ5WBIDNOCVH4JUULEK",
-    "GCNKH4V4ZE44PU34CJIIPQN6ZNRQL4FSGBAPXNSTT22YSKGSB4GQJTK2",
-    "GCNKI62EJWP3NAIBLRRCUFSGDGSTZTJKA2FBYK6UNCOKQOXPUK4OUBRF",
-    "GCNKIEWT676PPTOKVC23B6BFK7OTQARPH3AAC2NHP4VSO3R2LMKPHVIT",
-    "GCNKIVLIMEMYDPP5H2VE67OYIGCU6ARU64UFN3B5KHUJQZWHQ4YPFJ5L",
-    "GCNKMASZNGBUVTN3D5EI7MY4MVMXWZOTZRCMWKUTFPLXTL4XSDC64FYB",
-    "GCNKQPFZFQACZ6RPYQIXEJIBY2DRTSTAMOMLRM7SEP2HTAORCZQQ225I",
-    "GCNKS3V7LN5SBHKKNSUHL536PMKQLOFPKQQVRKUI3QPE4YL2IWQV62XU",
-    "GCNKVBSZVNS6XOVVBHRDBJCHSLTXI67FBXZDVGFRAAAYHD236KYIWGLL",
-    "GCNLAHEQBKXQUMZNCCFXXGSFMMSG7JXQCNFYUB6FQOIMRGJWRIT2TJFC",
-    "GCNLCCDWBDUWFTXEW7RQIDG5KFGJQOURUB7FZ66LHBGYFBDY4PSB4IV3",
-    "GCNLHMSO5SNZFVPKQE2M7R2O5IUOVHDQ43FR6DJS25GL6YNBFT42QADA",
-    "GCNLKDYYIGK6KP3ZFZCQRABCIJHACXZ6RXWVIP3DGERCQ2H3MNRUPXDC",
-    "GCNLOE77YT2SBDGZLM4EG6BF3AIS3VYGVJYGMJW3KR2DVL6CT5GZUZ27",
-    "GCNLQKJVKFFQQBKK5SGMXIGIRWQJSD7WFT4GMV2TXJX5THPKJRKU6HHH",
-    "GCNLS4G7NDODVFYEA6KQ4BQBKGY2NWHLAVEM3QK3F5GH6YTB7IIMCREU",
-    "GCNLT6ZF4WJKSG4LAW5ABGHEFJ652AY4BCYYQGQWYATPXTANOSE2XTSN",
-    "GCNM4LGGIPHHXRBS5TFE2PFVAGCH7ZQZB34QRYOKWCQFIMPTYVEKETHS",
-    "GCNM7LDQ53YKMAQE3JU7F4ARHGDEWRMQJN7VHGHZPQGOHZE7T7DQ2UKQ",
-    "GCNMJWCYTEJFYNK43DIW6ODLHDEFODPXTPV3QIOYVUWSJIGH5YDT7KKS",
-    "GCNNAMNAH5UIX2OWOXN2ZPVZ2TDCM7FWXOAW2BEC4ETSZL6IYSXD7SXO",
-    "GCNNNUIKYQBJYSEE4PS6CKPUTYBWYJYL3XPJHLIAHITWYIHQDB3XD4ZR",
-    "GCNNT3PL2NPECCSOY5ULYL6L7NEUKESKLIAQLCAVKMEHQWK7XQCSRTEQ",
-    "GCNNTPXJTKZGJVXNKDIM4Z7FPH7C2B5NQA6BP6MIPH7D64LXCEDLB2XX",
-    "GCNNYBLUCN7TGDE4HXU5OKEZXCTER2VHPESFS467DBIAFOJ4FEFZDG5A",
-    "GCNNZK4HDUKHWNARDRNS4LMXG4JKQVYCPEX6JXJVXJSE5B47UGC7JDCW",
-    "GCNO6GWHBMSX2KGJJFEZGMS4DZSN6ZAL6D573ZZI5HAN5EKRHEAKI2WK",
-    "GCNOEUEISJKFI74HH7RNX6J4WY4HQQ35MK4NFAHMRX2X6NDOAF2P6MV2",
-    "GCNOGXRMPJ2VBIWL4G7LKZHPEHA6V37QTQTEHO3B7S4GZOPSUZL7R2UA",
-    "GCNOVRCWZNHFLBX2OFLRAJYN6IIBO65PJTGMY4FRHIUSUMGJN2MCEXNU",
-    "GCNOWXNGVQRQI7SGKSGGLS4TUOPA6BD3M2RUG6CLRZZQZMUMAYB5MAMV",
-    "GCNP4K6P4NEMDPZPIP2QMQ3ZCRUF2KVNYXHGJMWV3UK3EWEBVK2QELON",
-    "GCNPFHTAHKGDCW7DUK7MKNRRLTNMNY3SX2X2MY3RNULAZI34GWZCURBC",
-    "GCNPGPUXVM53JQPK6ERMQFDFRE43O7N3AMO2KCZULWD55RXCSQWFLARE",
-    "GCNPZ5QYKNZPNEECIV2UTO2RTVXP7BE63IOJYBG3I6ZL3ZRCSASIU3CW",
-    "GCNQBRDEEJQ5L4BPXG5BIJYJ4HRB3AAEOFVAIFWWFXJGA2P7AQCWK3BN",
-    "GCNQTYNIZYUTSNB4Q7YZPJEWWZYEX5UO6WQE6WYDOIU4UOHSR66ENVDA",
-    "GCNRC5WJCKBB3EZABAIKPMOLVJBU2JU6X3ADVLSRCBLW2SK6UCZW6PUP",
-    "GCNRISPFUBQ6UC5HAFKBZSHRU34KR5LGO2DPSCNPDEKT3S6LDSY4DZSO",
-    "GCNRJUR32AMYBDHL7QM3NZSNEFTLDQO4WA6WFGP4OSPHNMQ3PF5XIEPK",
-    "GCNRKMS3DQBL3MC5ZICYJPQUANRFO7VLUIL4ZCAINKQI2V4AJ4JNCDYJ",
-    "GCNRLSIZKDQAERGH7HJFF3RQH7JHGE2HKGKPRPDXFQ3JTRQOTKEMKLCB",
-    "GCNRLTRSYGE4Y6M6KXVLVO3KQ476LTK3VMSFQG2AQNBKCGDK6GBGFSGB",
-    "GCNROOH77MAO47DGDK47DL5KPSMZPWHFKDWTF25NO2SL6JKNEJ4AOLUR",
-    "GCNRVXK6N72D6U3FC2VRTK6UJ2CATP55WHFFZLK4BI5BBDPMRZB5ZZEN",
-    "GCNS3GTBIP456YBILWSMFB44CZ2T2NLERFKDLCWSGHT223HW6ARFWISK",
-    "GCNSHXXE6YBCF6FLPOKPG4OT6ZBJGSPW4SSB2ACUZGMFOW2G25IGLOPS",
-    "GCNSPOTYXWMMRZM6JSDGJY3B5Y67UYZ6555ENAXGT6KLVGTW2NL65NDK",
-    "GCNSTNGINTOKNMJLRWQZMAM2OYYJ6MXIKULXEALZGN5HNFIIHITKHRXO",
-    "GCNTBBVMODRM24TRYUJJ75IA622UYSBSF2AFVG64QAW7D3TNPV7TNUR4",
-    "GCNTTOUNVICYGLJFYKHI6KHNJ6DMMRGO64H6THHIF4QIEKBGV77UU6PN",
-    "GCNU37ANOWAVLHKJC5RFCW6RIADO4O5WDJQBJFBYVQOZUPF6HWO7TQ7N",
-    "GCNU3MXO5PZIQXVUHPARFCW3OGDORCAFMMTOOIFLQLAMZT7NBSPBJUUS",
-    "GCNU4GH66HXME4MUH4XXKPGOIRRJQLKB3TVQNT6FIEUSUP5GSFXI2CKA",
-    "GCNUHWWZV2A3HM4UBB3NDVYACMWXLI2ERPVKVD2N32YV2GTBGRIBISJU",
-    "GCNUZWU7ZOAQFVZEJOK4PH6RNGRVRIPH6WL24LG7NSDYVSZKBODTYELR",
-    "GCNVCQ5O55WSGM44HGH7VQCRUR4ZSNL3TL5ZBD2ASLPQQI74OQN5GWMR",
-    "GCNVDSF2PRG36S2ZZQLVXDMNQRVE35G4CUAH5JEFFSZE3PRERBEHYXXA",
-    "GCNX3QKPOLBNQU3QWPQVTNZOUROQVNEGF2MFPX43XFSI4C42F7Y45QCA",
-    "GCNX5DXHZUWCVS2GOPXX7TZCQ6KM6RXBCJF5M6TW5X5U47CTZ6WNV2UO",
-    "GCNXLNWSLOAHR6PV54UQZVSKJIU2GGWQTPHYG7OT5CFJB65P52AYRZ2Z",
-    "GCNXWBQ27ZFMLRHABBO4UXXMVY5J3CQEHQE46TDXKDUYVIDNXKR7XKUT",
-    "GCNXXTE33WYTVBWTB4GMZI63J4XCPHDPBLBBB7P2INWHUQZHZXCU44IJ",
-    "GCNY5OXYSY4FKHOPT2SPOQZAOEIGXB5LBYW3HVU3OWSTQITS65M5RCNY",
-    "GCNYW5P2SUJOBCKDKW5Y7A3DAJKYR5XYW4UYNPNAUOW2OUR3L3JNYDTU",
-    "GCNYXEQONYRVSN5VJYF3B7A2SE5B7FKWURSYUFKRS75UC7XEJN6YWT5O",
-    "GCNZGHKTLK5N7A3E7BMAVA5ERL3XO6QHRXECRUH47J273ZIZBLLRL2OZ",
-    "GCNZZMZWDSUYBJDD4KASTBYQYPSCUMEDJQ6TULIXF34MV6ZI3GFXIXIN",
-    "GCO2LTD4D3FT6CI7DWPPB6UTXAWULWFSFYRQ7XWY3X7KM627L45HXLLB",
-    "GCO2VYAMLMF74F5XRWI2NQWDU3YY6C36UDJ5I2ZJ4R6Q7CAF4CZ2562J",
-    "GCO3IGH6QJ6LDDZ653AWTM2TBI65D7Q2BURFCGF6JHUPFR7IVNKTMKI7",
-    "GCO4Y4IHIQV5543S7CD5YBH6YODO7SRNTTWM6QAK6GEK7GCRKPMPB2ZK",
-    "GCO4YLAWSEZ5TNL2UTP2GD4LBMTVK52ZJ7HKEI6Z4EJHMQZWOJD3AW7A",
-    "GCO5KB52QSDJUFRLTZFEVO256D5VPBMYIFBL7UUJP37JWD352VKJL26A",
-    "GCO5MV5F4MDMPS6NTVGVOB2PLWJW5QWD3H6VNE54BKZ5T4HFADAOCF2T",
-    "GCO6CKJM3NXK2AQIBSTHLJ2Z25BR5OQXJPI2KYKDEJOQSPKDRPBC2JHD",
-    "GCO6HVT63DVWVIAARKEO2LCAV2IPPSG22KIPLSOS5CMYJ6EJSQK34UC2",
-    "GCO6N4RNOH4J2TMXHJXBFBMW6M3CHMVRC23CZSP762TU5S36X7MP4WYZ",
-    "GCO6QSNLYK74GFEDWVBBE76N2HI6MCD3NSVTYX3D6XDNTX4WYY6K4662",
-    "GCO6YNP27BFIR5VLYYYD7LWNJQMDVJR64RLSAG3HMLOJPFUM4PGL5BRI",
-    "GCO76DDYKLPKKAZFN5D6I7NHKTQY4TFBEORGST5R5WQIT3MERZTIKSJ6",
-    "GCO7CVRGFE4YAB2DYEGN6M4LQARQSUPX3XZ6JTIUOOWDEJIBDHGEBK3Q",
-    "GCO7ERJRMBSKAIEIIM6BS7DBESA6OS5D7OBBAV24VETBP4RCVK4QTCD6",
-    "GCOA6BTZZCZ2MRXW23Q2Q7SKDWGVSKHNTMJXW5SVKYV72RQDFL4QJXHK",
-    "GCOADTRECBIJ2M4QCHZSVFMJN6G2TZMH334UN765XNXSLJQQUK3YG5Y5",
-    "GCOBB3QAXCJDJ72OIZ6C6IJIXMYI2HOAE3GP5A5TQ6KQ6DIKQOJCT4LZ",
-    "GCOBJWSF4H5QYPMGR7ZPK2TXTIP7QII7MXU27VTUGLFQRUNHQIO2VKMZ",
-    "GCOBKY7DLFQRWYWLE5IBXLP4DJ3MH535Y7VUGHMP65W7XQ2GZMARYKSO",
-    "GCOBRSND4L2J3JDXW3HZBBBU36MBED6YB44WRBCGZOVXB5II4YD7YX62",
-    "GCOBYEMMY7QX3SIFBIBUMHSGCJKRTK6456WQLXXS5HVWIZQHFSWD5LVC",
-    "GCOCBFRGXVI3C532AINJUGQZCX3TTOZS4ND2N2D6QC5H4ACXQIJG3Y3Q",
-    "GCOCINEU7L77WW4RSNWS6VOTHXYKGZH3M6XZANEPKO6SMPJRNXBSLS6U",
-    "GCOD24VIEJT7UVUDAL7GRX626D3VEIUFNDZW5M32SS5ZFGS42DSEPS6X",
-    "GCOD75LT2UODTU46H4NSSCAXEKS2QIPWACSQ6UZSWVWNRPDVUANRWOGH",
-    "GCODAUU5OUK6GWG6G64TA3YT6OJSF2I4O5SKOLVEDSHWZDFFL7D6H27S",
-    "GCODLNIHZDJPDNWGYSB456SRBIJX72JWAPDAPTOUAYLTRLOMQTCPX7WL",
-    "GCOERQH4JUTRZXLRZDGX4S5UQSHSF6N4YHBBKFD3WLDK7Z7BLY6CFI4D",
-    "GCOF2VCYSYP4NBFS2UKVOQCHV3VHMZNKARMCFLR4ERYTE3XNLLANHHP2",
-    "GCOFZ6L5XHFVRFACF7GKNM5YA5MW3PC2L3HLLJGKT6DMP65EE5YZBPFH",
-    "GCOGG2OPV7O2WXBT5P2MG7YQ3VFCZHLCMCJA7QCQUWA2R6MUSC5EY3EG",
-    "GCOGLN42IY4OCYUY4DMSGM676LZFVF7VT643QCIDEPGV5NLDAWJJ2N5H",
-    "GCOGPTRSRCBMVPRQOH37PONHYBKJZKFSFJMDH6OLNPFCB2NUEHJOUSJR",
-    "GCOGZCAL3HUB3WIZMMIFSUEZJGKOW7TUM553UBSBJGM77YJLUTZNVB6V",
-    "GCOGZOR7ZBBXH6UWZSE7VFMDXXP76U76SM6PLB43EV6CN4JRY6F4BNH5",
-    "GCOHFV7POKHUPGJMDLGULGVP3BMR35X35PVB2O2E3LC6LNKL4ZPBUEP5",
-    "GCOHZ6HCO2W2YBJIOVOWKXAK7NJRWBBCMMND5R4WD32EFDFVLTLQ6PD3",
-    "GCOIOEMTEH272SFPSZZ6EF5HGGOVM2BOIEVYUQHJWKL2J3BQ4SSAVJ35",
-    "GCOIVHVNDZGM65FRGNE4L3U3AIUOWTJACOPSHATBBLRJXPCVHOK6JTZH",
-    "GCOJEPB5E6PKMKX3RQT4P7U2YQQLB7D6GMC5CWAKTAACAT3SWFWQZ65W",
-    "GCOJJL4S5TVJWTI4TM5YODTLCHI63GI7H3IBHMCIQVDUYU4S3TLCSZLW",
-    "GCOJNIN77NFCB5RNOY3OX5XG4WCH3GEYXCSXQDBC5NQYAFTWCZWXGL4L",
-    "GCOKEZEHF2MBBODOBLGDACWJBENKDIC4VMMW6VNK3NJSW6HSLQMSEDV3",
-    "GCOKLVE2T6VKCS727GAMGRTENH7EHBAVF3P5K3KTLTVHW4ZTW75MZL3Y",
-    "GCOL374ZYF4YKX2X672NOGNRQCLSBRV5XRVP4MCBG3WBMYCA5MUNJL7A",
-    "GCOLB3HJO4Y3J4ZS4UYZRMZNBNBEDXDQVBVLKPN47HPNIF44TQC7NSYH",
-    "GCOLKZ53NC5HADUFXDMHDSKSKYQ4NPDZ5SSWGNEFVZGPI2NNOKNOEK4F",
-    "GCOMA2KLDFL7XTGIDMNKS3IV44J56M3XE7F3UUA6DW5IWNZHVMQISDRR",
-    "GCOMJ6QTTAON3MXKAYW43DFGYCJXQ4N2TXSE7LA4C55NAIZ6Y3PEJHYN",
-    "GCONGGMNVV4GL2MKGUS6ADLVNG4XG7O7Z5WOCND2WNNZWYZH2QAUQJMD",
-    "GCONKTAZWUKJE4TNLB4IAIXPRRAC4MIWZTXEWBRIN2DOFU2FJ4HXBBGN",
-    "GCOOKYAEVTBGUYSHJHJGKSARVANSN2DNNEC4S7Z5HYN7FWU5MCG2JMSD",
-    "GCOOPJAC3LTLL2OZ6NEQDNWW34WZ3VKJTBNV5C67642OMZMGVNUUIOTA",
-    "GCOOZILB2E7MMOKWETBBLRQWBSR4MVEXKNB2V3ROL76OOADITSAWW34C",
-    "GCOPBCIKZP3ZLLKNEQVSGCB4OHL42IIYR73SBTNELGI3FM3HRM2U35SS",
-    "GCOPI75K4NF4D5MK533GLVSFT4L56PSJ6GNPARJLDPDN7XNLHWQ4FQLM",
-    "GCOQTJDYVDXX4DSE6CUYZ74HFN6RZ7FW4WM6JSF3WCPMJS3P3DLXJXRB",
-    "GCOQY54LHFWOHTMAUAOE74NVMXEZGLFKYK46NTPSMLA6IKDPRZCZUMIP",
-    "GCORPME4BIG3SQ53HATZ77LFNOBJH5RN4TFKQZNPOXYBOVNAVJQQQTKN",
-    "GCOSGV34TWPEUBS5552NBBCFSK2ZPSEG4OY4NJ7V25KPUX6FCZBA74HZ",
-    "GCOSSGLTUNU25CSN56DMDSTS4HUOUVN75E6BTPADVKLLPYXFJDFHQWPR",
-    "GCOSXAWYBIJUKNJ5FHGHO4JU27S7OQUJJH6B43CM6C633FBJ7ZSSSAND",
-    "GCOTKAFBVG36RLF5IABG7ZSEUC5PERLVZOYOQGPTZCHKPY5TRKFSLTRA",
-    "GCOTT6P57MH6TMDO7NBLSQ2HQU5LJNPUMCBTMCH7KX2M7IU3AQD4O2FB",
-    "GCOTXWK4EQ7VGKJMRTIPWZCDVIHUCJIL32KQWHRL3HWJUQRIBJN52UC2",
-    "GCOV6BXSQRTNKKLSJM3O6QIGWYC3LM46K4OJ7DYLEVNS547EO3NVGFPM",
-    "GCOVFCGKLVOQGML7AMNE6E32Q746TRC6NVNFK2X2ZXSZYPNJKGTRCFJH",
-    "GCOVFGZIJS7U6XGWOBF4GFR6M5NSYKEO3HAYPUU36UXJC3SYXWKCHAIN",
-    "GCOVOAXRHDQWVQRK2C2OQAUBTWDY5QJVBD4OGCYV7ZSDLT3ZWFW2OUKI",
-    "GCOWKI3DZYJUG7CH5RBLVA4MORC25CXMKMLQUOE3UIP4DVDNY44XVFAE",
-    "GCOYDWTBQGVMHL4HZ7AJT4MAI4E6TGKZUFEM55BK7OUUVI23HLMSYCKX",
-    "GCOYKSDSHUENZQB2HCLO2LF6LK2C3HPI33QT4H4MO7Y65TNTQC6VG4KU",
-    "GCOYMP256CQCU2JBZ75AM6EO2TCIRGMEQJ7BXKMXBKAPRGNV2FO52M4I",
-    "GCOYZTB6R3X3PKR7UCZWPMFNCS7GUMOYOUYAHXVCJZJGZHRIWPZODVNR",
-    "GCP24NXB4YPYQ2OQM3W4HVJREIVIKEW5NKSSWDQWZ2IS7IBDMCWCBXGX",
-    "GCP2DC5TUYN27WSJ3XL5WBSZBALL2DTQOFFRECVJE4VHNAU33A75ELON",
-    "GCP2GYCFL3C5SCSCFL3CIUF53KJQ2VKMG63HTPOS5GP5QG6U7NUQQFRE",
-    "GCP3ENO3QM7HTOD6V4NNMRQXAF47YYECL52WH7YLNADUDNL4RDDKLDEX",
-    "GCP3GURF5CMKV3M5AXJV7IZMFVRHLRHL26FJCTBWK5URZK35FGP67S2D",
-    "GCP3MZIXV4QB3QLCI3PMUZL4ULXD3BHWKKLR7DP2HC5A4AZ6SLJ3TRS4",
-    "GCP3SCUTQ7VV3OPVLIWNXQIMHUNKSAO7LT6YXQUKOQUFOX4CRXHQLEOX",
-    "GCP54XGMARSVD7YXG4BZEVX37D4EVROEROET2BQD4OAJGR2BWDP5SSVW",
-    "GCP5F2UWQNGLIDLY6VSVXSNUW4NYFCXKAGC6VE3WRDDDZXMRPM43ZXRP",
-    "GCP5UUEB2UZ4WUVNJUR4V5VZRXTSZLIHDXUFLT6JWPXS3ZM4TWNATWXF",
-    "GCP5WNLYZ4MUYCEYXS5GTYYYPGY4BDTCN32JO6VFSQRXWD4EDEKYQETH",
-    "GCP6AJSJ773X3BXNKA6GDOK2L3NLKZOWJYBTJ2ONAHLX6XGH5UTNRQFS",
-    "GCP6CUMUVDYNASOAYT2ESCEUOFI5WVIPJTBACWKTJLR2RX24QVQSYXLM",
-    "GCP6GD2QQWJXIXKXWRI6HS3V3JBCOVEUQRFDEGGWPM6WTSXPDFQPF6UO",
-    "GCP7BNR4NGRBRUG55YXIVDYHSMTARPS2P6TMN6SSTAJ72TCWFJVWCS3L",
-    "GCP7HZYR4MLOCEDHO7FJNFMCBIFBL3NJOWCE7XHBPAL7KRUJM4I5JOLG",
-    "GCPA6XQ5W6DIMQHKIGDJFOG4KCBXQD63P4S4DVXFYX5JLEAVUHNTQ4IK",
-    "GCPACZ52WBR5455DXXEFQZAYGF7MVY2FHVHJYEGQRTPOYZRUPMXFY42E",
-    "GCPAD6UFO46SNZ4JIVRB3QNFES6KZG2J5L5X6FBSPL2XRLJGLLADPGYT",
-    "GCPAE6UVWXMPKAQKYWR4EIZTK3GQTGTVYX4TKERQMA2RM4HM6VYUYMXQ",
-    "GCPAOAVANRDVBUPWNDKNVJKZTATQHY6GIYM3DI5CZLNDEQOFXISGSQSY",
-    "GCPAPOIRDXCJLXU6NMZGEF4EWBO5TPY2R7BMIDG7ONYK6DFXACZRHCK2",
-    "GCPAQC2R2W3SJYHQM4GRCQDGGIF2VRAQS5QNEPNL25A4MKKG3XKLY6NP",
-    "GCPB4OUYV6UEFCNH2C4IKTOZPDESZRCIVFP27ZGSHXY4QZSP2NKPRAUR",
-    "GCPB4RZERNG2DQNX3SEH6IREF2BO73HKRFGVJG3ZQSAQN6JZVRVYHCTH",
-    "GCPB76UPHWE2NHJBZ6CNV4SQAPEDGDHN54PJVOLKWFRSOFK2GDGX4H7N",
-    "GCPBAGB4T7LK4VIVNTOCC7MKUPCXGBK36GEEYMOYM6TFVRNLSYDB66UW",
-    "GCPBCDTN6LLI56C6NQTNVKCKER75NZU422IPALY3XEWPUL4N7L2R3GX6",
-    "GCPBVHEW6PPP5MAGF5TCQSFEKXBF2JMCQWG2SH6UXMTT7J2HLWNM3FAI",
-    "GCPCGUQSEWXKKKYG6NL5BV5MBZXNHFLCDX7NRVNR4BILBIEMNI535BKO",
-    "GCPCS6NSPJFFNVVHZEHF73GGIQ6QDKJRDCHSGQAVRYDCFCQYOJ32SHIB",
-    "GCPCSCYX6TZ2QKQAXRTIEHPF2IDEUWOGX2XHSWYX6NIE43D556NWEOQT",
-    "GCPCWYSXV3OEST7MMBKY4KQNOJQ2IMGLIRS5JPCBBL5MFJVE75V3BXLM",
-    "GCPD2BHBPQ2XGTXTL5BJDPBN4W76DRE6TTIYRHDBWX7CWFLS7BBIO3GM",
-    "GCPDLXZPUMYJCREBRF6EOSBNM6W5FENTP4JPP2HTPQXITZOWVX6APTSA",
-    "GCPDMUUVMECUKBIHOCFZKHI6LKTLH2R4R5ZOLON7ZQEQWKCOBRG6UQUE",
-    "GCPDOVODVNN4WAEW247BSJ356HBRYGTFTEXWXFOOHEIIQXSVG4PRJCZX",
-    "GCPE375SXUWRO2HIAOY4TAFIPPWPM6KOT77LVE4EJYNATIXWB4BJHWLP",
-    "GCPE6U2WQD5I6QGUZMQUUWHF4NFNVAOBC7YG6R3WJEME4HIT6NMTRUMP",
-    "GCPEAPNHUVSL6Y6DNLI5YIXZN6JXYYDFIRZI7Z3ZS2UQOXN6VQW7QOU5",
-    "GCPEPXFJCC7XSLLXMS6FVPDQASFDMX6D65UNIJ6HYENHI5LEMVIDSLUZ",
-    "GCPEV6L5AIXZ56EM6QW6CGY6FFCTE3XZAARBDTAPCF7PTTAGIGO5OTP6",
-    "GCPEXGL2NAMN47XAUSWN6KGVDRUCNHICZSJCL2UUHBUOBPOXWJUPABDC",
-    "GCPFPC4WECYWRJDI3XDJZJCCA7BHKYVW7MMOL75QDNLCFS2Q3L5HWISS",
-    "GCPFUTTN2Q7CZVDTWJKJQOP7BXSWKA4KCEW32DDEGGURWWFB5R44HMES",
-    "GCPGZDFU5BOSTKATVSZ5XNT7WACGU3AO6C47VDYAB2LO46BPTJC6NVOZ",
-    "GCPHOY6APBXJ37S2DZE3OE6PE6YIEQYS625PQOU4VEN2W37UBPDGVEE6",
-    "GCPI37FKBZJ4L7TGBGJEBIRK2VVZN2P3THJUVCDN2DCX7Z4T33HPQASP",
-    "GCPIKHLG2F6XFTWSKNMAEMK4FC3QLGVUQZSXVDVKN2ZM33TY766IHQ6S",
-    "GCPJLDW5CDTYLUJPHLXVIUBSDD7CIWLEQOAMDHY33I6UVUVXNCI5AL73",
-    "GCPJOPEJ2QA3IHWDBPUTCIFP56SWMQWC5W5TPJLTQEX7NQVA6YC254X4",
-    "GCPK244MOCLDHJGIKX44L7DX3YF52Z2QDNB67C56C6RFEFUSLACCDVL6",
-    "GCPKIV4RA5YUXROZIOCNY4FEONBZ4ONIC4FOLXKWSFZ6RMZ3RKXVZD26",
-    "GCPKTP2L2M3F6KNNZL45V5WFWHTERCZIFYYQ2ECL2RTBN7KWUFXW6XPL",
-    "GCPL4XNR6K4MQK4JK2RADN26ZM6OGGTL7WQNHMV4JMNYODGMMSJNYSIQ",
-    "GCPLMZ5S75KPOGAOC2EXZDR6TQ5V6FQYJ6N5RYRY2BSL5RTEMMORBCBS",
-    "GCPM2CA5MPW2PNVGSEAY2XZN2T27C7VEOFE66IZTQV2ASGSEPXXVFUND",
-    "GCPM2FAVUDBJXMJUH3K7QHHTDYGQ2OBXGA2N2RZPGS2C7G3AFY3CHBAR",
-    "GCPM7MBNZ5N4X6NJZ47SZ4HHCX4NJAOHV6ZEYAHHK2Z545AWIIJSGW2B",
-    "GCPMCQI47RTSQ4OHC5WIAWK7QK4IWHSS5IFFHFFSPTLLLLUXHJXVL4QW",
-    "GCPMIMHMHH5GUCPWNUW62NU5L4OVRHAFO7U2SUY6I3MZ4Y2XRDBU2FTP",
-    "GCPMYHMVKRFAXJPHYLT55KRQC7Y3EZRXJMS7YHA7TYY7W76LX6EGAG4T",
-    "GCPND4ZKVYQCZX6FFNRDK6SFYLRIZIDRXNRMWMKKBNYC63XN7B6VIKND",
-    "GCPNTBOBGJMFFARVIRBUIQ52P4OWUAZDV5Z75HAZAATY3RDNU7NPVL5C",
-    "GCPNW3QWRJI74MTC5POARC7QUAGF3W2RZ3ASFUWZ7466M6PGK2ZNDNJV",
-    "GCPNZSVFDN2ZIALIQVVCHGX6KZCIA2YJBNA4RRD344FLIG2HPVNMYYVN",
-    "GCPPNK4JBIAKMFAVYYKT3UDZ4P6GRK2KT37IBESFSNXK4DEAM4NWWP47",
-    "GCPPVTIBPWFXO5KJML7G3JJHLVIQ7K3A2ZZJ6AMBZGQTAKNNIJGJ3BOZ",
-    "GCPQ7ON5KVO7FDF65ZWA2KGA7NDZPERCSAQR2ACT3M2Z2WTQOIEQEXLM",
-    "GCPQNIUGUAROKV6HRFLDV7YOLDI6B6BKKGE7ZFXQF6PGPFVVMD7D64FZ",
-    "GCPQPJ4VOHTGCHU6NAV7RTES2HV2GUZC5VBFD43Y2SKHNAVMXGOCYV3P",
-    "GCPQQXWHFOPEN443NYWSDGXLYFZKU3ALCH7T6DY6XD6KXY5664H6BIBM",
-    "GCPRRFRARAQJBS2SEGHCT6Q5O3I4RHA6Z2UKAMYPYAH6DCF4T3ART62O",
-    "GCPSZLSIYT7RS4N3KOOUES6BHGCJ3QBIFWD5AO2FYZGHU6QEVJSKDIMY",
-    "GCPTEF4CB22W4A5MJR55SG6CISQHFQLJRSUN7G3MYE5B2PECGZRME6QY",
-    "GCPTP3L4BZGHISAMKU4STJ2E27LM3OWOSCPUAB6W4G4OP2NUTOXGE2AR",
-    "GCPUEJ3HAXIJI7KLNMLAYFAX3TRAXKS6RWBYRGRUK3QZSZPUW24TW462",
-    "GCPUXKPHZA3W4FWIYSMXZZW5DPU5X4CSZR7HWH3AGSU3PK34DXVUK5SH",
-    "GCPUY73NPG755YCW7HQSTPE4XAU5ONYZ2OM5WR3HA2QCFAPU26YE3QDT",
-    "GCPV2MY4JHDGGUMDHLQRCMZK6OUDZWPPA4PRLIMHSEZKI7TOA3FLOURN",
-    "GCPVQ6A2IATHF4URJHWGYCTILIXV7QJGZPD6NRJTUYZS3VBYU7EPJ4RH",
-    "GCPVQMWO35QSCRGBISX57GW2MW657RWQ7TS2DPYR7N6WWSPVLX6U6YTY",
-    "GCPVYI322HCB3DG46CB33VYYHZZC4MWQCZIAUACW2PO4HGYZRGHAE7FS",
-    "GCPVZ2YSXZ3Y36N5HHKU3Z5BHGF3KGDHCEIGN5PFRPTV6OGFHFX6XZUI",
-    "GCPWLNXDKRQARFXFWJ7I2BTSCUTPROCKMN637GUF7FO7H75NJBABQ3H2",
-    "GCPWMQ2MFCEJYQBMZ5TRPRNELCRT57R6QUKE7AW77LEMO2ZEE52Z7K5D",
-    "GCPWQJU5SYPFVZINJXUY4P4RVKGXBRP6BP2DXY4G5DMCVUDFDEH2VBQG",
-    "GCPWVSXLPCEYGPUEJM4L6A6S2HKN4TRNEFIZWORQNIQH4263N7UEUBAH",
-    "GCPWXH276SPEM7B726AM3BX2CMSL5QG4XPCZLPCHBQDW5BAWMAANTVD7",
-    "GCPXCYLKT24YPKUF5U5OP6I2RHC243FCAN6MI5HGM4QSZ6LVQ4VBV4WW",
-    "GCPXE52U2S2KSMAMBEJWS7C3BCACMG52VWELBWUDNUEWOKOYC6HOWNPS",
-    "GCPXNU4C5BEXDPGZ5NLOD5RQXRQU62TKN5XDR6KDB4VL6PCNCU7T2MLD",
-    "GCPXTGXOUTEQUX5E3G3E34RGXOZF7EKUQWHAASLX5WVOGLZ5I4DRHRES",
-    "GCPXWQKK7U62AZESN24DAYG2QILI7VV2B4BA457AMDG46FC2GHYRUMTL",
-    "GCPY2J6FCU4WYW6JVAHNCLLKIWXPZEQI4RO5O2LVYRGZRA4CJJKLNV6Y",
-    "GCPYA2KM2IO4KFQEMDV2K6SEBWGETLVGK7XEFXGTFJQNKQAYBMQ7KIMZ",
-    "GCPYD2NG6GNNG635PPYE6OJ2TXGFU6KQV6OSRPIYIUJAPVQ57LYZ3ZUG",
-    "GCPYLBXUXQCWBRM627Z6AL4ETEVOHPCNDVL3B542H3YNQB5AQG4J4H25",
-    "GCPZFBIENYQVOBENRDS252F7MSJGSEVSDPW37JVURWOBWJHDRK7QIOTA",
-    "GCQ2FGAXLOMUXAEGD3JLMDFOYEAL7ZEFEVTV7RNWZT2HFIVGNZEJW3HZ",
-    "GCQ2UKQ4XIZ3EQLAL2RYOARYX3QFJZ3U2DXLU7EO3V464PHIKOKKF4MB",
-    "GCQ34ST4KBXVJTLXFDSKYACWBOPDO3W6E4JNG6RG7W3JTPFQL4UXE3C5",
-    "GCQ3ST47QCEEP2KPTTQROGTMGYPWOKSUJXXVUOVNIM7CRZQOQ5LO55AM",
-    "GCQ3SYIPPVJCV3FGFA3H3D4DWPHVRRJHAX2S7TO6RCGBG26CYNZMRULT",
-    "GCQ3WPU5XRWX6CPTRHALDR74Y4R7X2DPR3HYTPWMKREOBKQ26DFM6XCS",
-    "GCQ43MAK6SLL5JK2REDURYRCXJBYWJV6ATULM62WEHBWMM7CEZTT7YUM",
-    "GCQ4NMHYZQQIB5TG47OBEV7FBMTZ2KTOKQLWEE5KC75GUXY2HFUPYUOD",
-    "GCQ4WGLPQGDNKOD6YVOHHPVOLCXOKVGH3YPXCB75T7INUDF6SHCII3KX",
-    "GCQ5BT7RHORR4NJEO4DL5ZOBSYKYZOPLRCXT2RSZXOFVE2GQDFSFIPGC",
-    "GCQ5CAYC3G3RE24DHSO4TPNY6Z5L5WXKVC5OOJQPHCPEY4VIMA5PKBW6",
-    "GCQ5FABKRDCGI3P6F6TMCWTRBNHMDZDFSY7I4CW4ZZ5BLCD7GDWLM64A",
-    "GCQ6PXRHFKXA7P4ZQ4S74AC7DVQF73PHYZR6YQRDQM7UZ2YBUGGDUT5R",
-    "GCQ734RJFA4ZI4XU4DHBEMQT32HIQ4MXBB3JTKUEMI3PVVY7QWXMJU45",
-    "GCQ7QVGODBGH5ZSQLONPCKMUNCW67MYPXAWLMNCZOERZ65KCC4GTQPXT",
-    "GCQAWSHINSHBZWTODITWRPCWRVCKFQJUGNM2U66XSH6NHFCETMU3AOJN",
-    "GCQAX2PBFSFTAHY4OIGMMHS45WOGUD7KKZCWI7BY7K7UFS6WGDW5TTQC",
-    "GCQAXDD4S3ZOXN2NFJO7TRDQFKND6FB6WUVDHJW2NS4B4STQ7XBTMC45",
-    "GCQB3CUYN2ZJQ2PHEWNL3XXMSGOJTDTVWQDFBOSPOO2XVBDT4X5IKV6B",
-    "GCQB4SVDNKIM6T4BNOSBTMMLUBTVRNSH2YXZHI3QNZ6FU6NNY4MKMVRE",
-    "GCQB64HETEOURXDEF2WMZ7U5QKJSBB5ZNTJMKVOSW6V2JSES2BBDOM4Q",
-    "GCQBGEOTB3HZ2CFI6LX37G7N5TU57YXKHZ5OKALDLHDBB6F75Y5UPGVA",
-    "GCQBTMM5PP2RN464G4X2HRVC7HNMGTB7STP5AUEXXLEEFW5XLENCV4RN",
-    "GCQCERXULU6WM5B3YU3L6OBMLEOZKM6J4TS4LLS7YA226IOBUCFQC7FZ",
-    "GCQCHCYXJ4Q4IKYWKVRQ263SYPKZINMRKAKDUTLJR3ZGHMJ4OVZYQQNT",
-    "GCQCQXA7SRRADH7GTGXMQLZUI5J6JEMXPBNBR3MAVXBR4K6JDLTYORQN",
-    "GCQDHMHQZPLCQ2DFNBFZKLSDAGUXGTFEMZ7ZPG57JW7NJPE6Y7QLFUST",
-    "GCQDOXFZO23DBHEXCM2FZJP2L4SQNJHVKIZ3KRGNS7IQK24FVKN2UVEX",
-    "GCQDPKWRNI6S2HDMJJ7EJ6K6GMTBO4F2R6UIVZQCGRRXY62LFALINDUS",
-    "GCQEIHLHDSORB7NBXWPKKX4SZSMB7J734IDFMQJRTUXD3HLBHK2JCMIO",
-    "GCQEKCSJ7IQTEEOT57T5LXKEYPPEBZGODQZHUYOBW6HKNSXUXAMH376F",
-    "GCQEKUGYIBJ4ZF5XYKAKIFOPKRTHKBYQHO3ET3TFYW6ML2AFVHHXPCJ2",
-    "GCQFFV6XK2VHX3EUSSOTGG5KKFO7XFNNC65U2WJS234FVYYR4HDRGECO",
-    "GCQFHVJMCPHMTHZAKFM7OMRPESUZT6VI3VIQ73GPGCJJP4F3EA5GCERL",
-    "GCQFLGALZ4AGFZZ4MEKVCYNA5ILKMFUHM3D5QDKNULLHEWYRVGPOVF36",
-    "GCQG3SZFPWST4CCADTJF65X2YYZ3T4YVADIGJFOUH3I2JMSFUBGR7SSH",
-    "GCQGY3OE7MHNQWCTNDVTLQLIKVMOZMA73UK3WD6OK22UXHEVTQFEGE4D",
-    "GCQHE7252HKPREY3EI6VEURS5GCK5SZCMZWCXOU2PLSRW7KPJRRYG555",
-    "GCQHODMCQGKAMYWFCERFGTKPUOFE5IQGPCAXUZPTD5VJQ3C62BFINDUS",
-    "GCQHW2JWCQQXURMVGKT4KY7L6FHY42SHHGI6XCRTO4ZZCYBKSRU7NSTL",
-    "GCQHZRXEQQ3VQHA55OOLZHADR5U3R4A5CD5Z2FVS2Q5ZZTA37ZBIFFES",
-    "GCQI3BJSQ427APT6YB5ZX5C5Z6LN2W7TJBDUBMYTORM62437D5CSYKPZ",
-    "GCQIPXH3CMDRTMEY6YVDGISOP4VJJIPIUUZZKHCQKJ2UCP6V2CQMROSR",
-    "GCQJPVSW7WFMA5MAJ2USYDCCENRXLCL3VJUIGB37DQRS5DSYLQB7PZPR",
-    "GCQK3ZW6R4C6HCPKD5K4GBSRPSIFPQI7KQS27K557LQOETFT5EB4MYKT",
-    "GCQK5A5HADQO4XELULU73FGTCTC7BUMPKXPJUB4JPKBHCJD6MRLH6PC5",
-    "GCQKS63GWE4SA2HJNZ3RQCWS7ZAD4CWAGQOQQZUUSZPRFIMI7S5Y2ZPY",
-    "GCQKTVSQV3UYYTFYCB24FA54XAYBQUQU2ZAHMBURIP56QLCNSFA3QGBD",
-    "GCQKWKHRZRNZ5IJP4AQCADNE4OUPXYFLS3EA7EJJZDJWQF7JBJMJZ4FR",
-    "GCQKYXTZN3AWGHNRDSYP7ID7YED2WZOM2C6RCUINK7DZLIJOJT3W4MZX",
-    "GCQLBPW2YBBRUJBVWO6Y72AU3LLSU6ULCKWB5JUDPM4XSJ25PARNM5YN",
-    "GCQMKDNTO7VQXGCXMFPWIWHHAZX44JB2PK4Y3NPSP72V6TPFV5HAGWDX",
-    "GCQMRWUV5CABRGPO3C5NWV6OQS77PJBJ4MJVDDULHSOELOS465H7PV33",
-    "GCQN6QOYR7B7SB4Y4AOTOVICEEILEQ65PORHZSNUKYM43U5B6RAUIK6A",
-    "GCQN7EHPFQMPZFEWSTYXFD7JOA2P654QFA3LHDXZQBGARGKJTJ3OFHEL",
-    "GCQNAZ6F2UKRBCIICOUUUH64HR2GW5UNN4DGHQBNDR6SPELQWJFZGZ4F",
-    "GCQNMXEVRGAMDGER2SCEJQBFSYAGF3HVJUHLHBAC6O2WVYHZK5NP3HI2",
-    "GCQOBGVBYC243ZGTBDGF7J7AE7KSTBRMHRS7P4S4AYXC3NERNM47MMTH",
-    "GCQOQOUTH4E24ZCJBYAJTTW3GVOKMAXVVR6TV2SJGBOARAXWZUTKQURM",
-    "GCQP2SJZIGHYJB6NPMI2OCZSZ2X4AJPB4ERLZG3L3TNDMVXPALP2EBXB",
-    "GCQPKU67IYUX6MBNC22SQOGIQ54XE3KDSQVIGXQCN3I3GPF23FPKQQEJ",
-    "GCQPMRRWHYNNXNFE4JOJEPQJWNICYD5QTLX52MDJNTPY3UPS2DDVGS3A",
-    "GCQPQC7B3OV4EUWJRTUTNVY24NNGZDLKBFBLEZOA5WC765DMAXHOX7RC",
-    "GCQPY5CY6BCS4A32T3RIKZOLGU3IIGTDVN2R6R4RPURR5MQMMWU3OOZJ",
-    "GCQQG3S7CSFBKADVHAT4XMQ3XDXJDXOCWSUTABCATV3GIELVFM2MFZPL",
-    "GCQQKCS4YA7ZPPZ2Q2ANTRTTAALPWHYTSTZEOPNSTD5HMY3AWURFR244",
-    "GCQQTJNEZQFYN7SRUVDKVXAH76BPEZKGFLKF55NUHSU2VYQR6AMFIYBI",
-    "GCQQTKIEUL75OJVT3ZDVEV4E47SFPEO2U3WJMWHQYEY2KRRPU2LEUTT4",
-    "GCQQXBXOTPPST3EC3RRCPHPYVP6XGGMOLPDA3MJ4A3B2C2MKH56BJ6KL",
-    "GCQRDOHT5MQQX4PJGL66JVC6IURYTB4SJYTOTRLFTVRNS3QPBA7HXG4B",
-    "GCQRGZGRTFBXJCYEJW35HT3SXFEVNVAX2HLEVNW3Z27XVTUGLG27MXLM",
-    "GCQRJ55G64VULXYFG7J77IMYIH4UN3PW3GCNHOJJARWJYBT2CK6SR3KW",
-    "GCQRO62YASAUW7PD5JKCV3FVXWEHO5FTRAPLEDHLYDAEP6N467VNYSAA",
-    "GCQRS4BFAR3YDCIW2ME32V4NAHIMIPEBLZEHLJ3MPTBEUELFAEKBX3SJ",
-    "GCQRSF3DONEMVACK2PF5MIRG5NRL2PYWU3CMPQCX2OVOCB6RPCOMNZHI",
-    "GCQSEU4V7OSXYYCMG6NOEFII6VRKMKMI66APT3AH4V7E4FLVHWRUITRD",
-    "GCQSMIP7FV4OJPUI5K36YJ5K37FHOQY2DBQ5DC3O5UTPXLIFRUX3VHYP",
-    "GCQSVFUU5JAW7NLBNJVVKBMBRSHHNSCDFQHRPTRAAIMIMGBEUQ7WCLKQ",
-    "GCQSVULNVTPXHFVNNCVG5LUE3DS5JYVWNGGLS7HWPTNNK2LPQKM77H5C",
-    "GCQTEKTH7PXVRUHGUOWMSIOQV2YLXSUX7CXPYCVTPLU7O2S2VFSOQUSA",
-    "GCQTGZQQ5G4PTM2GL7CDIFKUBIPEC52BROAQIAPW53XBRJVN6ZJVTG6V",
-    "GCQTHWMFIQ7KH27WQNYNSX7SIG34FH56BGDQLYT4XTS4QWO6367OBWHP",
-    "GCQTME7DPRHGEXDO7TEYFAUMB7KQ2RZZDNIUWAHJCLYVPYE2AYSPBBNB",
-    "GCQTPOLVLH6FKMRUDSOZ4RJ2KUQWRPTQUBKSLZM672ELMPSKAPEYELON",
-    "GCQU2672HXPTVAHGOEOGTAIE2QKSME4S2W6MIN7SPUIEF2O7GSRNQYGQ",
-    "GCQU7DQF7S37L3CXKFAM345F7IBZGTFRTQ6UFLWON7YQH4ENXV3EM6YX",
-    "GCQUZ5KEEKNLWZWBQXZXUBJS3LL4PZ3WHKDDOSHUC7TXLIM6DC4E4DI5",
-    "GCQWHXMWZYVT42ZDXNXNCE5ICJL265MKRA5VS6IMRDPRPW6CTEVKRZKZ",
-    "GCQWJYOHNSJQ3NHOYR56DBNETWBZ2L2YJEAKGGGAYFBF4DXNFL65LXSM",
-    "GCQWPALQUGXEO2YCBQL6M7YOOQ7IIYHZOFCAYF3JJ7WNNONADW3RS3RP",
-    "GCQXDYMS35ETKSMMN2ZPF6J5KFNT7X2HC7FASUAMPN3X7YPX3L5RCUOO",
-    "GCQXLII6XSMC4JVMBO7XGN74HQ7LGO5ZT7SIQURD3THY7AIWLAYSDOGE",
-    "GCQXLKHWFQQAQ26M2AVGVYQIJNGATNSY6YILMP244DLGEKVYMMQVDFI3",
-    "GCQZRUVAPK6V4MJ75ZECVB24JAI7TQW3GQJFXBVCVIDB7Q5BIB774DOQ",
-    "GCQZSKZVMOOYOKUTCL247QF7BMHEMQPBTZI25CV7G7ATEUI5KLNOBVDH",
-    "GCQZXWGZ42QNKKSQY2ERFJWMSBGMWGOPFTQQ53FAAEFFHBPWA6AEIRXT",
-    "GCR2GR5ORLHK3RJVYGHCQCHTBSZINTJTFRRB57ERIHRTUHHKBDORZU3H",
-    "GCR2J3GAROTU54GXFCUZTAWRSSIP7LBD433NHMFE6Q5KJRBIWHKZWIDO",
-    "GCR2O5JIT22BGUHCZCO554AGVMOOS7YAHG2ONVFSTGXXDRF6TCARCOPR",
-    "GCR2ZCKMQTGVSSTJRLBZHO4JPPHAX7IFWESLVJ6NLQGIXOKE5UVH4EST",
-    "GCR3D2RGYRVOJX5K4R57TUUAECP7QGLFNHMMBUTPRPWVUW3KAJSZAQW3",
-    "GCR3EC37C5Q7IB3LL7UTN3IYNHIWCNSMIYKGVMNU4RIQKI64COSKPFOY",
-    "GCR3LTATBCYSVBG7RXOJ2ZCWKVNNHGZBJNZANBT3IIPI2HP5AL3VLGPT",
-    "GCR3MF6376TBWFCXSNGBR2UWAKX5LVF4DIHSH25BS54MXN2NKXKDGOLD",
-    "GCR3SL3ZHTLSQVQA3O5SZ37YTUGUV64ORRUCOB2XODEYBNK5EQEYMXLM",
-    "GCR423ZUJTM3KQQCRIN3DGA5HV6IDMMR7ENSO27GRLRNHIWP4OI6G4HB",
-    "GCR4E42JILDOZFS7IEFOPQBCB44M676GUJOSRXWVDZUFXMFACJ7QGECO",
-    "GCR4KJ2H2ZNXWTEYAULKOY6F6LVG6IJUYXJR6AGVWK4WGP2N2ODBAOJN",
-    "GCR4KRKHYDE6KCMTAJ5K5DJSTQILYBYL3KPOTVPRIUO4L5YJ2H5XNZWJ",
-    "GCR4WRHP2OWATRBBC7BQDTCIYOGQR4UQVSOMHELEXRYLYRTEPDM6VBLO",
-    "GCR5HDT5AASPMOIOWS5NHHVZNI7YYRKUY5K4HIKJCFBEB32HB6W7BBOX",
-    "GCR5J65AUIYPBNU7M6PRCUCIYZJAQTEWXL5YFZOGUMC4EKRAZK3MIZR3",
-    "GCR5SAE3FUNOOOMFN2WALSIINYMEOHGAROQOFWGJFF2HW74ES5DZTTL6",
-    "GCR67VGYXKQBVJRF3OD2IC5HHZ33STOXS4RORAX3RBE7KIWRFRX4YTGR",
-    "GCR7FLP4HMUPR7GBKR5ENOKSWQHZTX5BFXHYQIHVALPU7CMLSHIA2E4K",
-    "GCR7KPEW3YBPQO2LOWVVTPYIAP2NZYS6SH2D2X6WV2QFOBHFIMMSHIBA",
-    "GCR7TP4LXNDGP6YP4E55VRVZEIX5HFB6566Q5PY2GCWUZKXCX47SJWPK",
-    "GCRA6WQKSRJOHCCVOWKKNNAMGEK6KTYZRK5UBY56FN62THZ3VLPY2DFJ",
-    "GCRA7RDBZIN63C2GCDVEOTXGYMG7H25ZEK6PDXSZVL4Z7DSMNYCU5QIJ",
-    "GCRAQVHX6Z2T3YQL2KDXM4BORY4AWXVHWOJIKP5VMWPZNHKBWJJFN7GZ",
-    "GCRARSO6XAXHQCNVNUSDRPNB7QFNXGYVGN4CBQJ437D5HYQXS27JG5ID",
-    "GCRBEVFNTJ6LE6FREV2YCXOLJMHZ22ZI7RNPGKEQHRSHMMS42WMRORGR",
-    "GCRBGGKQZRYUQD5JWLCD7TXACAZVLUUKDV32267UCRI66TRISRY4UNET",
-    "GCRBH6G4TOA42AYGOQ5TYK2L4A2WIQ554AM233EQWS43TA6EFZMBOMQX",
-    "GCRBHTXWJNABHAQMHZ2PJPA3HIXCDHODAIKZMGVO4PGJLYGE34RQI24Z",
-    "GCRBSLLVHTHKYRNRF6B6CGXTKJLYUHLRW7JCV7S34DN55J4BXYWYHDJ3",
-    "GCRBWOJGIWSYUILRL4MVDV5DSCDDQUMZY7J3LFEELEOLBCTUQPEJUKFR",
-    "GCRC5J2Z5LFPVI6RLYMXZGBUTDYCB5PYOFCCCIFHKTYJV7GRZISTBXKA",
-    "GCRCD6D2VSNUUVBCMBWA4YL2RCBF5LDHCYW6B56RNLSDDL6ZTWPBCWNS",
-    "GCRCRQRD2KAVESUZ7NBASAPE2ML74ILY6IHD4CLLLWVZXDOD3LYR2E6N",
-    "GCRDANVRWF2O5NKYN67Z7332UM5HYDYF2MJ5E6S4WZTY2H6OQHLZARMY",
-    "GCRDSO7WG2LRIALD5WUBQY7YYZWSX4N4WPWEWMHMZP2JPV7KHJ3CMUD5",
-    "GCRETH3NS4J3RYCCDX5G5VJ2GHEMUVZDXFBGVTP6XH57F6ULQVQ2SURM",
-    "GCREVFORJFJDOHFLWYYBIAI27267NB6M2EEAIKW76CGMCRSBPM5EB6R3",
-    "GCRF42K2Z3S2Q4BKJGJCLJY3NBQBYLV6LYLT4ZU6B7CAPFLOSXVPO44T",
-    "GCRF66DV3EDQAYFIISKAIQA7IEH4NFSCASFBPDU6CKZX7CJKAWQB7ZKZ",
-    "GCRFW4AR7QMBKEDUK637ZC6P6TW5AB64M743IXRTWYXYNJM52AGYP5D4",
-    "GCRG2WZ6MZIUMI4JVF5KDIBX66VU5M6DJXFXQYZTR6H5XVXHTUUCEOYX",
-    "GCRGHMRDZJQEPCGKZAJHJAGJIO24CMVGGPXFLQNHZJNSOI6EZDUBFOON",
-    "GCRGQCLSTCVEGNX5X57XPRDO7BFLSQ6KKQIGPTF3765YD4O2VVPEBEMI",
-    "GCRGYWYPE722V4DAJBXRLKGPGINPZZP3NSTBPMYZPR7HZRKTYSNTAUA6",
-    "GCRGZLSZU4ZQHVLLF7YU73MJXWWQDF4C44MN3K3MUOVNAR27GV7IXJHJ",
-    "GCRH743SLR36TCLC5F3QC4QQZ4SPEJ4L6VEYIERCT4VIDKMET3QZXGW7",
-    "GCRHF5Q732PT322CMA3VTCKKFQJ7BJ6R2EBNLUUXWI7NUAU7MLYAIDZA",
-    "GCRHUEDK2WO2QJ6S5XDC46RANLN7VMEGCA7O4FOVFBX7JVN3E7J2KCG3",
-    "GCRIP6DGR7GCM6IE6IBRUNXW4GSGGCCNRLK6NTONW6ZODL3BIPWVGSZ3",
-    "GCRJ4EXNEJOQPAAJNI43J25I6UOTUKD2J73W5T2MLJQ2O3RWPBBE3EWI",
-    "GCRJG2BDBJ5PILCG5GCHVCOAT2M77DDFYLSCH7QIWMF5N25CLVDG7VQV",
-    "GCRJOSQ742OYCHB6KJYPGIHEB6VUMV4WHL7V5BITHDFD2MTMBK6QQDBY",
-    "GCRJSTPGWFOJQYTOZURB7UKUUSSZBR7JOW7I3IJP4TUDAWRDV25BGUT2",
-    "GCRKDEW2JICSN3IUFGO2J2X5NYP2XG6GFDGD3K2EDFZZEHDAYS6ZBSYW",
-    "GCRKRWOUCS6DS6CNEXDJYKLSBZYR7JOWOMNY4LK4SIRQ26CZUTLBO5YZ",
-    "GCRKT3Y7WVOJ4MDFCCL6OVWA3FITWWGFPVJNOO6CVRGMZJN3XIPMKPEI",
-    "GCRKTYIVV7DT6FYQPSD6ERKM6YBNHXUDEAGLLWVZHKUL4X7KUTQXNIZL",
-    "GCRL2JKJWVUV4T4XSSPTXEEU4BDPAJ32W5A7ARIYKBHEFVSH76E4CHMK",
-    "GCRLH6AQVPDUHYLIN66QNSMLDNEGHRA4EQBG5KK75J2KXTLEEP6UFSLX",
-    "GCRLQEIMAWH5S6ORE37KS6QI3UM4IZQRHQJAAOYTJVRHQEDSRWZKMAIN",
-    "GCRLUZQQKPUB64BA2LYZAMMXSNB5T7GDTHTVBP4XMHPFCXPKVZCPIS4K",
-    "GCRLZRYB6LATZDXOYS5NJCQP2QA76JHMDHEWCLKC7YB4Y4NWH5LLW7NE",
-    "GCRM2TCNJ46Y7YAIAQD44JSLHBU4MQ7MACOK3V6GQDJMH2T62NNA2DBW",
-    "GCRM6MR3P4JNHXIYMXEWBPQK7RR3GAON6ZGXGUXCC7V5CSV3JE4FORPP",
-    "GCRM6ONJP6EFW237HE7GNXHIDG3UNAFGJLMFRSSGLRST7TUREI2VEGRX",
-    "GCRMIONB7TWU4FU2KLJXFK6FQCIWLQBSVKPD5OC573OTX6IBQNADJYLB",
-    "GCRMJEH26SHGXUHMH47MINZTSOF5WGTVCD2JQRJOKL6RBFIAMGL4RKBC",
-    "GCRMRVPHMKA6R5GD4BCRH4YWMHYNPDZ5PV4MUCHEX7D4Q7V5L52PMQI6",
-    "GCRMVGCN7UGVGG3UBHHVLN54BHLVXI4Y5F4KT5237RJJYDOVWVHLHDG5",
-    "GCRNZGPSVIUDYJDQFQKP53GSJ77MPD36HQ7GKRMDKLSKJLD2AX3LDVOG",
-    "GCRO7ILHAD6SFUFBSCO4BE7B2ZUVFFBT6EQ3HEL4UE6PMNZPGGK2R4CV",
-    "GCROEEE5UV2HIBXJI3FNM6GRH3RWIUBDRAFXCFLARJY7P3UZATN3E6YZ",
-    "GCROGWTGIBGGPJDNFRBZWZTW3VI4SMVHPV3JNHLZHQHDHSBTKRVETO2J",
-    "GCROVIXKYXSTHGCKJEYZWZYZXQFCXSJYMCYAFWAZSIVSS3XLBTGBXSDE",
-    "GCRP4NOBOQY4CPLJP4PDGORINOIIFCNHGCZ7DQXZCFMCZUS2PIEZIOT2",
-    "GCRP65KT7KF4FEJHYG3MLYVQROPWNLSMYCFAIZHR6TVJOD4P4GYRPORT",
-    "GCRP7K2APWQZVP6T3ICPG4VE2OPWVIHI5M5IF3N6K2XKD54Q352EABGF",
-    "GCRP7KYSOIEWFBKLZM4R3PMH7KMR5G2FISQBC3P6WZQ5IEPHKOHRCX36",
-    "GCRPBFXYCYWDN2IMQFSLKRBBFDCG6ZWDRLQKHJQUCSM3A33M5P36DVF5",
-    "GCRPGTGQBHAA3346IPD2GJSWPAXFFJSFGJ5JNYWHLYGN6T7D3X7G2IFE",
-    "GCRPI2HXS2APAWNHULMDW5H2QDKMHGZ4RTAYO3DIA6T3C7K3OFKKPXLM",
-    "GCRPQTREUFE5LIGKYSFO24QMCQ44OCGVD6XYS7AVTZRPQIBCSXB2XWPN",
-    "GCRPUGN6UJHTYKHNI7YHODSFA5NDEGHDPVCPAFP7TEQM3F75FVL6Z2PJ",
-    "GCRPVNQ4NKLMK77V6HIS6PPHBW22VCEKOYIMVHQF5XJCLAPRLOORQ2OT",
-    "GCRQIUIYVJJG2LFXOVO4G537FYBJROQIYLDDW2TFZJLTPC3WGYIGO766",
-    "GCRQLRPPS6TZ45Q6M2BWZS4HBX7KZVGAOC7QDFOID6NKY6W2EGRMDROP",
-    "GCRS3D6EMQNDD4RORSEQE6M44YLFYS535UOVZEYHOP7XO6W26OFKBK7L",
-    "GCRSF47VCMQZNXLNQZK3CALUHDG5YA5OJPOKQ4GS6BBZ47STY2QE6TQK",
-    "GCRSGA2KOURLQGNSUP24ZE4BFAL6RKM5IYF2N4EHKNK2EQGWXJSJF3F5",
-    "GCRUQLI5IZ7DYQWRGUP273NCPI5VT264OBYAGAB5KU5E5JA5MQIXHVXX",
-    "GCRV5V6KLJKIC7GMWDZNDSGQBEPDWY5UQG37SNIPP7NIKV334YMNOE5R",
-    "GCRVK4PLZQCJXFSLYASSZBYIMAOFUI2GII2DY4I7JFIV6GKLXOTQHVMM",
-    "GCRVOKWZTTWZX2D44F2T5KU4OXDJ225A47RW6BFSHPW7T3KV7D5VQWX7",
-    "GCRVVESY6RDS7AMKNE3RXRF5457E4MMQC5J7PC6M4HYLZO3EUYFXH62L",
-    "GCRWOMGM7VMZRLOMDGK7JMCJDI5FIY73IM626ITAO7ZG6VMGTEXZA3DD",
-    "GCRWOZ7ODSTKQTSXJ5A4VAODFAPFGJOJT3KFZBTUMJGXQQKDGQFOSHOT",
-    "GCRWQLORP4ZLTNTHSIQO6UOFEEOLN2NUOHV6IWE4VDBPQ6IC3FPOVB63",
-    "GCRXM5K2WTUMGXOV5FFWOVMEQGOLKGPAYGMDEUCSBAIXARWE7WEARYGM",
-    "GCRXWJODBGKCX3ZYX4R4FRLAP2V4F2YCFGMBCOBXPHEXELFV6FTHCMIO",
-    "GCRY5RSKEX6PTCAEVVHYS5SVKW43W5UNXDFNXOHN46BFJQ6KOAFEV2NV",
-    "GCRYGYFHJBU4KCRQMOAJF6A3V3TI24QQVNKPQQ5UV32S5FXEPS2OU3T3",
-    "GCRYMRBB4PXMLURWR7JRHI4K5LBYBR2VZLLN4MZT6TAOEWUIU4K6NN35",
-    "GCRYZ35ZZLWI7OWUEJXCZDKLGSJXMJIZEGOGSXBOEQNWLPZ4S3UHASRF",
-    "GCRZ3HSZ7XHZUMMQBRZLP2FXDUX3XY67VJDSIOE5TXGVOTA5BEDT7FLX",
-    "GCRZGK7ISTURU6D554J5CEHDYOMLRYFYEUDK5K2DW6MRBWC2BPAHO6KE",
-    "GCRZOPWINZ72UAEQ7D2INE7XJQZU52NSZZ45WQQ5TNWC4TCNCEA6AV5X",
-    "GCRZXQR7CATTC5FI3CLEY4OSMK7QK62GM3FYTVKTWVGROQCLJCUJ7Q2F",
-    "GCRZZJVPJR4UQ2UBRPXP4OBKLQK5NQLLQWUXTH4SKJHFJFDZXCFRDB3U",
-    "GCS26Z5K6FT37EYZRUWD7EY6G6R5VF4HEHCFYFNRRT2YUDQ6T6RBFISO",
-    "GCS2EF5YH5LLNY4SIBR4IFMW3GW6LWPSNQPXY4OJH5NL7JPC6THVSEO6",
-    "GCS2P2VWSONWD35F7KREXWTTBJHFKQ2OM2RZAJGMA2VNGN3UMWLAXDXY",
-    "GCS2RBCLGTO7MKF3SGJQUK47W6ECJPMNYGHXZNHTY2ZIEDM2PZMOSTYJ",
-    "GCS2RYIBNEVK6KQBFWTOIRW7KVI7TTFGRQX4BZRAXLZM4NZBG5CWK3WB",
-    "GCS3562PL7Z4KCTVR5E454CQIF4M6OBKI4CCKGQ3LEGA6HPPX2MIQXMW",
-    "GCS3A3JNHRWFYAK557TBFEM3AGHUE32PKEOONSUHK2X6N7X5LGCQO4ZH",
-    "GCS3IOTSCKYRF335DA3CUBKLERV3UHPX23GSTTCEXZ4ITWSBSFQ7XVBA",
-    "GCS3VVXS3J4VAZYIQHUTIXIFU6COTQ2VNT3TYWQXM35IF5JT5U2XJPK6",
-    "GCS4ECKAQ347LNH3TPH2OP6OFGBYABR7K656FFMWQD4YHXLWWODNILBJ",
-    "GCS4FLTCP2WU4MLRU3ZMSPPLP3EZ6AYYJ3PEW42PO4ZJSX4Q2NWMDINO",
-    "GCS4IRJKMUZCNHM2M2JRVMBZFY6KLAZIGZNW2J7IBMEASATY7FNEEIML",
-    "GCS4NS4FQJ3JCRAJL434YBDRRVOFHHNLWMTSRQY3KWXKLKSVIUNDYRHQ",
-    "GCS4Y2V2HMQXTP4TXWH2RYPCL6HZDI2U7CKVC6LVZWN2HOXBKIRWEMKC",
-    "GCS52D3BSOC5RAIKQ32Q33VAO5KNYWVNUX76K3CCWU2WX7TVD6G7QTPC",
-    "GCS5DAASGNPBK4QCBB5CDFSDDHSQI2YCKXRZNBGUQNY27B6ZNFPJ3NLS",
-    "GCS5NEJH6LNKWCGXAEZLUJPYJXFFHCONET32B4CYVPUGJ2EZ7NXJVX4W",
-    "GCS5O4BYME6T2IT5WRIOGLPADVX3YODV7CU2QAOJ6LY77ZMFWCRZDEER",
-    "GCS5OPUEOZVFHCH624XWGBZKRX6JHXL26G6URYWFWCKQDM7MQELT75QY",
-    "GCS6KQBBUGKFYXQNMNKSCC7HXMSNKQKAGU2JDKUMIRA4LBZTQZBYNTXW",
-    "GCS6MTOS7EEHA35T7D6G7QYYUVWX5WQBF53NGZ6BVQAGFM376AWPZVEK",
-    "GCS77ZEXDT5P5NDAXAKY47PGPWMLDTF4L43WYJQHTSTMDG5QSUCSYXAU",
-    "GCS7HGNA37CK6TSUNSBX2WTNBDBTUMPSI4L6GFWGXGFI3QANCA63HVP5",
-    "GCS7LAYMYJ5PC3FGAIINMW66IMMMRIELNDMILTZM6LE4D2DYV7FSHOCF",
-    "GCSAABFCNK5SSNTAVCF3BFCDZKTB7FSFXHNEO27KKK3QS7H56C22NNWH",
-    "GCSAUH7B5YI2L2IC3WVFH4EKI24JIM3MQL4UCT37N2KPRNWHMDHNYA5D",
-    "GCSBP7CB4FLWHQWSF63DE4WSKZ2OM66CPYFUEC442Z3KKRBW67LKZZFY",
-    "GCSBV3PHPLD2LMTLW3JOVIQN3TQWUKJD7UTK3MSNYRE7KQM4RQPVQI3W",
-    "GCSC7SX774LKHJHDK5JOTTYG3U4FCTNPJVJVDTXQOIXWRK2ANCEKZ666",
-    "GCSCI2MU4IN5BWC2T2H6QFHX4YHH6XC6NRSOR4AGUDYVY3I4UB53PE5J",
-    "GCSDYFE2VPJON2II3YF2ZULCCGGRS74NK47NYAE6BIJ2U3KCHCRZF24F",
-    "GCSFMTUYWKTL2SMTV2EDSW6EA4LLNLXW7A6Y4EISJ6LPZPUADGFKT354",
-    "GCSFOZMXEG5JR32B43OHOUKF3RL54XZTTRRWTRX7PF6TKZJ7NLHS45IG",
-    "GCSG2JDFZARUP5QDWLATZUSSBJL5R63FQEEWNRAV2CUDWLNXRJJSMSBA",
-    "GCSGDSWRSJJRULCYRHHI2BWBIUC2ZTNYR6UDK6LXNSXV7HUPYQBN5BH7",
-    "GCSGDYI5MNL4IKUJPMMBXMLDXO5LPPLJWSS7Y5XRIUY4SIECU4GP6KY4",
-    "GCSGFGPH4QFG7L6DOSHBPGEDJOLH6AWCAQR7DCMNKXNYWK2Q2F4JB6LI",
-    "GCSGHL6GZXIRAGE26M3RGOF7KKRH56N3TCCXCQY3HX3HZ33VTO66FBN3",
-    "GCSGNVWCFQ63UZIUFVJ5GKWQMF5MGOOZS5RLHI2YYH44FDMLKZBEWBQ3",
-    "GCSGSQJWABV3NOAH4A2K2ILVZP37NAL7MKIU7R37ILIKJVRBGGYEGHGE",
-    "GCSGUORSIKAGRHQ2XTYV3CPUBTNFNH3RC45CDAWHEZ5DCW77NY2CTBE4",
-    "GCSH757GL5U7AZWUBRPQY4KUQDC3CVJPG7JGBN46KBXDHUWOIIPH46U2",
-    "GCSHL2DIXUDSWUPQ5SPYHYGM6SQQD22IIKPFE2LSOJ2SLHGLMFOAG2WW",
-    "GCSHP7D76U2SS2KRIIV2RVKTVQDGY3VQFGIMZIWMFOIOOE66O5VA6QNT",
-    "GCSIRGCMZ5NJ42M2XWRR2DEB4Q6UEEPVMMV5OXBGUROJBQUR4QB27ISO",
-    "GCSIRZ5ESCC5BLMK6LHSJ2X2FSFQ4KOXJG56UTLH4REPJLA2MJY4EJJ5",
-    "GCSIULXGHK6CVTKX5KZNB2YM66ZKSVQYBHVZVX55RII7GDMANLWN62OY",
-    "GCSJCNVHME7UHSHUDFW4PZLPLR3OM347DEI4I2OLNG43XJXWXULQNUQV",
-    "GCSJDQUGAJQH6ARRC723S5H6IZ3FX6MPDMU2F76UEZ2CNQJ7KBJMCCUP",
-    "GCSJHQQ2Y3WPS5FTGRJODRRME2TMKH636MJVV7OOWMZGIF7HUAMOSOLO",
-    "GCSJN6FJV6C3PSINF2E33ZLSQHBINSPB2YVMEHIJNJUH3CDTUOIEHGRC",
-    "GCSJY4FWRVHXC5FTAOAPQSGZ45JTSFCLVHM6WIRJKKTLJOYQV67VAEQV",
-    "GCSK4SFW3XKQ4YZFQL6UJSVWUSKJ6PIWRQY2ONEXFMDJEKEKW37N43BU",
-    "GCSKAUB7XFDOUCLFNS6LGG4EAR666KK5JHTCZJEXTDSJ2J63WD6OPRM2",
-    "GCSKBMGMWSVAG2ULKGDSY45YKVRGJUMAZLBBSBOKW4OZLRFAX7YEDX2J",
-    "GCSLFXXITDWGIMNZTSYXFFVSXKUICACPIDWBB4LEBBG4JENMRT2ACGZB",
-    "GCSLLWODMTHX6ALXDAZJCMCUQZIBU2VMHCHW54LISTQ23H3QGXAOYCX4",
-    "GCSMBUPJZDTEXUK7MUASW7ZANT5Q7A2YTXPGBBIJD3TOSNBAEUORYIND",
-    "GCSNDDHWFDUZCSD5B33RQVZS3YCGMUZPUL56AFVJY37QHIE4OBZZ3DZ2",
-    "GCSNHQDND3JIYNHMHL3ELNJAJXM4ATTM6TK5ZKYFQHH75JQKNB7GX6RD",
-    "GCSNLZPLNGUNDRWFDESGMI4IOTILYN7LSSIO7WERYPKFXXRWSVBZNORX",
-    "GCSNMWKPUQ34EXOPABGSWUPIR35CMWFRS3UHXBAQQGA3YCKXUYZZW5TN",
-    "GCSNXA33YHYPXNQJ7F6V4Z5RCYJQJWVF3QC3EFBFPV5HMZKNHJX4QLUZ",
-    "GCSO5MMP5G3HFQ3W2PXJNJN7OCIBB6VNKXEX2SJZ2EXT7T53H2GW2TUZ",
-    "GCSOIUPYDERKMKUYPTPL3N4UO2K3DANQ4VH4KBUFPW4ZYBIR762AR3VI",
-    "GCSOMINHF3IC2RJQLBD2SVHG4YWKPLQX2L4FKQIWCTRRW6FQTLAOXH2B",
-    "GCSOXWDWIEUKPK7UDGCNXKWW6VD5BVJFKMLYJTUGP2EFBTND4JNPN3F3",
-    "GCSPL3GL7MK6HPA3Y3S3QGGKD3C5PO3LRDZU6RNJCXAWY2JYM6QQNUSC",
-    "GCSPSGVCYYUM2W22HTPWO4FTN4MJ7WXFLTGHQGFTEOFOBWCHS77M2MYM",
-    "GCSQZQ4Q65YOEI75WC73EIQYE6IJFEDK5CFDO2PCOTDELYM4S5KXCKCD",
-    "GCSRRM4J7VNOHNSZRHFYYL35OEKFLWSAQLWGBIVMTA4SS4KD3WPEPNT5",
-    "GCSRS6RTYU67XNEGNLMBCRDYSPWJ2D6YETEHG33WNOMHF7WCYMISI52B",
-    "GCSSENN5NAK5EE7L4RIO4IL3EB6IU4YP7KZMC5GRYC7MBLWA775WNLRP",
-    "GCSTNDWQWZPNELVMJQ76IAVAHINVC4SRTBABEGU7XWYJ7MRWXDJCRGLQ",
-    "GCSTO4PNSJZ7WEWN4JD6MG22NEYNMDNN5SO2DICPVZX6EBPO5XXB6AOJ",
-    "GCSTRLTC73UVXIYPHYTTQUUSDTQU2KQW5VKCE4YCMEHWF44JKDMQAL23",
-    "GCSTXDZJLN2S755INFAW5WBV7ZIKRBUNLT4ZJQAJ4XGSTT5VBEPJKKKO",
-    "GCSU2LDR5FGJYWY45IUCIWOHU2TMXCLOYLFPKYX7O4U5RTVUKW5LMHS2",
-    "GCSUMSTVUXBR35KCGMQHJAAWHGTT2TOBPMSK7LSQGWFEFFPLKH2HAGPF",
-    "GCSURINBJO2W25SNQGWB6AHVF4W6B7YWJYUXCTBPPNXAG2ZXH2OSQC2N",
-    "GCSUZNIQLW7I6NLPE4FUXPWF77RUZQZF6KOXNR3J72FJ3UPIKGFIBBLK",
-    "GCSVF2RMV2FQNC462ZZV2BBRW6ECGR5JGSH35F3JOU3ZHOFSGTMBWYYB",
-    "GCSVVA7LECN6QHY2THLWVFC77JJ5PRM73Y3ROOKMIEGBNZ2E5WFQTLKT",
-    "GCSW6TQLT644EWKCPZOU2QRCC2V6Z6Y5FYG52IW5LQR2OLUGPKK4YRHY",
-    "GCSWCDPRY524UQTJYECSAGLZUWRUO4UMJ2V3VCEKGEYEHRG3ANEQMXDC",
-    "GCSWLIZEYPGYL36GUWTWI3GF3OIWFATD6EUZMY3OFXQQ5ILVJLGKLVYM",
-    "GCSWSRT2EW4MAIPAKRQO5FLRA2APCXLRZYGFNT233VX5IQRM5TRPOKAH",
-    "GCSXJ4RGIWBLS22RK7F4X7A23INIDGFKOPIWKOVYTA2L6MESVYPIJGZC",
-    "GCSYEL35LFYB35N2ANMCI3WRKFK2OFJCQ6JW43XXHC62A7XYAL2FYBGU",
-    "GCSYGIIWKNQVVPJKNATAIXY6ZXPWOVJ6RTVULHZF5BMTL4I2RKP5META",
-    "GCSYMYBBKYVASB72EATM56FZGIB5ND4A3T4H5QJZA34GIFBTRB3OCYMF",
-    "GCSYPBURE5BOIN3XR4222TNMVWHUYQNFH4FSMY3NKNLZNPD3C5LVM4D7",
-    "GCSZAM7BE6M2CXRWBC3USC66BYAVM4J2UUMMV7DBGT5NAMDV4TLAI6JO",
-    "GCSZKGIMORYXGWQZIBSX5ZSXKBV65IYSGV5JDPUPKL5R3ICHI2WNRYDK",
-    "GCSZLFVRK3422PSOQDMKMQ2NDFUTU4OR257ZEVSXRZDONOXPKX7ALEUV",
-    "GCT26DEKABEHQCFBBDKRDQDED3GV42672NBBGX5TDN4SACJWNU576E7D",
-    "GCT2R22MJLHG6SXZSQPXL4HOJ5U4RBL2KUWTECSIDBL53MSHMGTRDGYX",
-    "GCT34DPNSPZ3CB2SMVYPD7A63IKRIWLK54SXGLYBFUY4VQ2JJIU5KOQJ",
-    "GCT3I2HBVP432ZSXHV73YG5SCDS7BGOZ3QGP2B6EPNBQUTPZ35U6CARD",
-    "GCT3S57MZ5Z64YZIHF7OGVJV2BEH2OWVNQ53DW7AWJWVVCJNIOTKLBHV",
-    "GCT5LI42ILHCNDDEXTYBGBJ3326M2JE5G3FUVQ6V44NYTBGP7TK2QZ37",
-    "GCT6EPQFFAVS3VKTA47F2Z6V7YDSVIU664OFAKATSLNJAK4HESBXYT2U",
-    "GCT6VZCNGCMZ7JB6QWBICPDD3BXQDS7XLWBMCQD7DDFJEXKJ5EQ3RSNA",
-    "GCT6YRBZ2S7TYORUJPKB4KOEOOX3HBP6VFZ6DU6RKA74VEQ6BNKZT2PJ",
-    "GCT7EEKRYLHD2RD6LTLCXVBVOJCZBTUC3ZZ3P57FWXGI5OL5M4PRTXQN",
-    "GCT7XOCS5AMFWV7MTHNJCIHPZ7AS2DKC6B5ZFAOFOYW6DLSKD66A4L4Z",
-    "GCTA7LRAQCPF5IWAQMTIEWAGFJQXH4VRTGZVW7OUXD3LSQHMU2BNTYCO",
-    "GCTABO7ZUV536TZS3CD4COJ7SP2CLAEEIISCQ3G7MPTQ4P2YN4AOFL55",
-    "GCTAIUQIAFT6LFTTWZGCGXIWSXPDDLV3ASQOVLAS4V6QJBZHLXM3PF7S",
-    "GCTAU3XLI5HD235CHYPDEX3LP5R7ME3R67JENP74BZ2REXVFDHEMUYOI",
-    "GCTAVIGL3EIWBBSNEJZNQMMLBSOM6RHTD3HAVGA225GGOYPHSLR35L7N",
-    "GCTAWY73CNMLONWUID7TMJT55NW4MS2MHMW26O222UGG2PRFE6ZXEH7N",
-    "GCTAYBRZAQH6HWLQPUZ4QT5QGW5ZPJROTRENBFF64J3NM43N2V2ATEDW",
-    "GCTBFYFT4G6NNER32KAPUGEQSJSPTK7DISEZK3DRV3F63XSVO2TTNTUU",
-    "GCTBV4VJKAPQ7UDDC6TQJ6QSI7CAQUK7N5GYGI2QL6OGE4HDHLACB3DA",
-    "GCTC3K74VXJ3MVH7YYONAM4KGHUFNI6XPX6DR7DHP52B7WNJZ24MYNAY",
-    "GCTCBL27SXSHWBKEUJ66NX5ZCIR4G526IBSEBU77D7FK7HHWIT74KBNA",
-    "GCTCDNGRUYFFDFZ4HSDY7WZIBVU77UJAQOYDNHF3JAHN5TRENLDOSWIS",
-    "GCTDFPGBFZLKGQPXY34AQVJBOPGFNSQK64MOWWGTUO6CTOS5LWFK2HTZ",
-    "GCTDYFFN5JIXGHW2Y5L6WLBMIKE3MYWMUJV3U2FY27WYR6IVXQIAUCR3",
-    "GCTEJ4YG7352OVDEA4JHLM4WSUDA2PDE4GE23Q73VPK6KVACQJCIKPLX",
-    "GCTEWFHDZI54J2PQYB2OHPXKWHXC732CXGT2PSPVTK2D7CX3NP6I7OMV",
-    "GCTFFZ6J5UYGAK2DHLCKNGEZBZULQ5XKKQISIYY5SXC2JDDURTBJZMLI",
-    "GCTFIR4AJXIH4R7L3IX77BQGW62LD4SSPLDXERWOD2JVALAXYZ7NVEDA",
-    "GCTFPSPLOTANIHC2CQSJGHZZTZNWFB6GAFMZK36ECQAUB7V722DVAC7L",
-    "GCTFV3DHU6FOP4ROPE2HDVFUVRHRRWMJU3ZVCERXZY5FAKK2PB75H2QW",
-    "GCTGPLVX5SIOPWT46O7FTSAI2SUU4GR2L4FDAA57IMUTURSJ3AEOPOZQ",
-    "GCTH3DOS67ZTAPMKYMA5VVNQKTXVKJX5R5PUBRT2H5XHTHKCNZNW3AZ3",
-    "GCTH4NRSMVGA3KHY6QQKZL34UQG4QKEKDTVQZEI4ZWQQHVQO3NLWG4YQ",
-    "GCTHXZGCXQR43FMFKA4NZO2RQ2GPKOSUDZSF2B3XD4JCHFRQ6HT6QBQN",
-    "GCTIIJX55QG7SQBX2GDFT7S57N4XY4RJABU7FKGG7RRQBSI5SGVQ2T2S",
-    "GCTIL47ZD7FCY3LM3USYZKJMDLQUEHYUHZWKAGCIQXV3CEOO6VILD2P3",
-    "GCTIPIAPK6XCSTIEJL3NUAAB37Q5IJZWEA4QFDKK3KXXFJVS6LIL6ODS",
-    "GCTJ6Q7XNFB6NEAQDCWGTQET3KPZRPKEESKKFTFU3OIZZRTLKTNQF52Z",
-    "GCTJ745AMJXH7O76HCOIPPETITP5M2UJRFM4JJ75J3TWA57EOZFGAMFH",
-    "GCTJEEXBWYBZ47HX4ZCADWTO224DU3XS7H5QR5AZZTBE4D3XOWXLY5LW",
-    "GCTJSPOETZRIHOKKLM4SXLFQ4WBS5BMPAER44Z45IUVOEXWGZEVYLIR3",
-    "GCTJUWRPQYBMCQIE6LMUSN2NFQ46XCEA53UU64UMJF2KCN47B7WILXIV",
-    "GCTKD4USMPIPGAEP5PWR7OOM6NZDMKFG5MFCNBW2W5MDCXFOADVSAD35",
-    "GCTKDKNO2GL56G7O552Y6POYSQZYC3ZPKSHMGLHSNWZJSTXPQJOFKSKV",
-    "GCTKFUBCZJX3XU7DFBSLK7D6DD6AFMVLE3AE4IH3N347TSPCL75AMBFJ",
-    "GCTKHFDIHDCHQMKJ6CPDFKV7TLG7Z4VVN6B4Z3A5OYUPAIBCOQOTELON",
-    "GCTKUCLUN7BSRGHJYUDVYGGSGXASW4BNTYTU4WB46CPFZSGK4IOER3PQ",
-    "GCTL6LZZ5FJ7S7KCREC5GCPB63EADEEBUFLLT6GCVO7ASK5JGC57WLZN",
-    "GCTL6OISWJDFDRPJP7JORO6SAHC3XXHGUYSQMDUOCYI6M32PG5PTJ3MY",
-    "GCTM6CWKBETORIURGQ73DKQI7MXF3RBWSIEFZZOFHBKHG6W4TJ3SYSZX",
-    "GCTMB2A3KPBEACMVKJPMLGAJ4FSO3SJFSFDHELVIRTYGOVIE6KQJ4VIN",
-    "GCTMDNXACVM52WA25SRSHHAC6NAKWVZTEJFDDW2ZDJR3VGQ7TXOWIXLM",
-    "GCTMKKXNU7UHMQAJFVWEHVQMKOL6I2XR3ABOYMC5IJA4R2BHXWB6G6PY",
-    "GCTNA5MA2QNOEBBQUZ42WKJ7L5MV7VO73GLQDPEISYCM66PTYM2NXXUD",
-    "GCTNAK55ESQY6ZKSLJDCVYTW6GXYNVUQEO56LLBLLJ6E3D73CWEUVHHQ",
-    "GCTNQVHW7E5S5JFULQHJDX4ALSG5ZC5ZMP6AJULTSYQTVSSOLZFV5UPP",
-    "GCTNYNUUJ5QCGKHOK2VSBZ3RQZCOPUQ6CVIONYU6EVE5PNIQEAJECRPT",
-    "GCTO3XRKK2GL3BLVNWUFL75E5V7FOCAZRTIXFYPAQ6V6OSC5JQRR42TA",
-    "GCTO7WNOSP622SMEICR27RHY7NRVGZFD733FE3E25NLVPJOTKXZT5CL3",
-    "GCTOA4UWWTCQZMT4PYKIBS2ILVP3AMZYAEEYSC5UM2SWKIF25MSAKWBG",
-    "GCTOI5XALAN6DS2IG65BYPNTXLNYHULVANNBSIRJIHKISZ5JIYEMCVOE",
-    "GCTON627HQPNOWNT42D3EL7NEQQE66GKN5CMDLSD37NP4QXWUNWRDROP",
-    "GCTOSXKH7ZNRT3LHCN22YLZELX6ARIYUVDK3YUOO5TODPCHJXY4IEFB6",
-    "GCTOWYNWA5Z4CIPADRAV2EEWIQNSEHC5CWX53UDMUCG5CVFQ2J3C6SC5",
-    "GCTPCDLM5Z6MDBQZV5YVSF2F23KQTKS2GBYSYDGXTU2WEQ7X4T6KJAUX",
-    "GCTPU6GZ2THQ4V2XNVICLYX3VENUHXIJVZDJSA37DNGAEEA7TE654TES",
-    "GCTQ5CUMQWSVBGCTSTF2PCTJY7ANLOF3B6UALQ6BLIF2NIDB4NYAF6SU",
-    "GCTQQXMW3AL3Y4YOZDT4NUPJ5SLKO7PEN772EF2MR4VTQX5IQNDVZJEJ",
-    "GCTS4NN52EG4OVDMYOWMXMSFF6CWS4SNKOGBTZCCSIV6VSAO2LUVP2Z7",
-    "GCTSGAF2Z6YMYBDWPJUKC4G5NUNAGZITPJFBW4O4WCA2Q6JADKESWDOE",
-    "GCTSSR4RGFK3BEPAFMGVTYKANE3UPFEYV6PKNVWWFSV333IVAMI7HX52",
-    "GCTTEKTQKYE4545AH6JZ3KI7A565UQSBT3L3WBPI4BGIFOBI34ODFYGR",
-    "GCTTQDWMM2TYKKVYUJ5RABIPVPABBE3HIPJOC55DF2UCZ3YBFH5CRNER",
-    "GCTU3HANO735DDU57FTHM5CKBTPT7SVT3HUN4QWO6ZTWKTJY3WGUOWWK",
-    "GCTU5I6AG6XN54CQ7KSHYZXQ3645CB25QYT6Z6TUO7CUOERFADM3GOQU",
-    "GCTU5IGQ64IUUDPAXZV3WGTNYWIPTEX6TGNDEGUT4NXNCVMIAFQMVVTZ",
-    "GCTU5ZXTEOFSSPID6SD55X5EQBF2MA2KXKDRQXHPWF33G47FL46DGKKB",
-    "GCTUKEH4HMDZBEXA47W7VD36SB75UX5XIVXUXH6QHUIRZ3XIEF5Q7QWO",
-    "GCTUUGTAJ3B4AUGYT5AE4Z6W4WZNCAEDD4MRFOQ6KGEDXJP6QBHDOGET",
-    "GCTUWIUEVLRLV5P3WYA2OCN4RJWJBOZVCISGQNXRNG2QBLK2QOWC7ZA6",
-    "GCTV6IPNAMERU45D2KTWTJAZJTPIKXM5OYEO3H2Q6VKXKRAGV4VHQFCB",
-    "GCTWXFRWCTO4OU5U4EFRZEI52WRTGYKQBWN5XO32UGNFSOXCIFAIXYBQ",
-    "GCTXCYSVSR3ABZ3A3LSQKGUK47D5PTPWB7PTWLDCT5DULWEFDV3KSCKG",
-    "GCTXM2JD3W4JRL6HQKUMENI3QFG67FSVXRZ74DPPLQNH44MXZO2HG6AN",
-    "GCTXRQIYWEDP6G7OXZI6IXUSIXHHSN5NKYRB3NI5J4Z7G5JMIBHLLBTC",
-    "GCTXYCPUM5A44IT3XW5UL3GQGENEHQHNV56SJQIFAYYUBJIHGP7FOSUN",
-    "GCTZ6CRFU3RPOLXFERKVTLEETW6P7MCARY33KAWVO6OSENIARDMN6B5N",
-    "GCTZEI2KRXN27J4FKWIZCNMBBGRS4TZJYF7TINXSEO7X5CW2QCKFOFNY",
-    "GCTZEY6L7KKQF3H6RMWJG5MPHM4JKWHZPWWEG4647VGZPEYNLM4G7ZPY",
-    "GCTZQC6XZBKQKVBU4YA3M54RY5XQLWMX5K3CBA6NB4ISXNKVCUQMG5A2",
-    "GCU25576DS7WMZVHQXA64CUDVLPULX5W3FKJDMWQ3CTS3PQCML7M72F4",
-    "GCU2CIYM2RNIS2L5UYFQUJ62RK6VXDTX5256U2HBTB2YBQZZK5G4NC4S",
-    "GCU2I2C5MARJ2IYCR5KKVYWRSVU7HBAD5ZQG4DI5HFWCGUL4W3RYYPWO",
-    "GCU2PODXYCWHZOLT55UFFYPM4XKOBVEB2FAIETCRVNDL6QDE2DG5XTSU",
-    "GCU3HB4GGV5ZYJNDGPL6SKU4CQXJ7KUPCA34A4KNJU4Y6XD6P6OEEAZ2",
-    "GCU3U67XRMDBKZ42ACQSGELPLL3M2HB7UBLZFQ4G7OBIUJ2WANJ3ZLTB",
-    "GCU3Z3LVPCAPT4AKV7A3D6RFFH4SYLFC3EK7CF3NLUHXGOADDP225UTL",
-    "GCU42W3SGX7EXXJL7P2CUGPPEVSBN5CGW3GTUV4KCRA2XUJY5KMI76M4",
-    "GCU4D5DQ5UWUEZUNCMVPGF2S7UEEKJYOWOKJEX6DERQ7NRWRL4LJQSCH",
-    "GCU4QGTC425JMWYCG3Q2FQN5WTJVIVSTCMQ6QQULLA4IXIW7OELBPYBC",
-    "GCU4SZO67DDLXHPAJV4PDX3HD7PRQUOOPYHNSRPZY2NOBKNKL2MF2W3G",
-    "GCU4XNJEOCRCTGFMS2KQ2AUJNANONGDXSKOEABIAMY2SKXCOE6PJY4XF",
-    "GCU5FPRECUA36ERC5IDLO23SQVGEKS7DYFRCIU3YD6GTJN7RBRBSQWDX",
-    "GCU5RERUT645UZPXQ3PXKGARRVWXXMNY4GHGAGJYPHYBGL5KYA4GZ7WD",
-    "GCU62GNPKBEL65OZRNOAUYFMQJPCB7DOHD6DABRVF6CUQIBH25ZTKBJL",
-    "GCU653KNM32CAUQCA7UFIYRTBIMMDUA5VHU2RPU52LNONGDCZI5GKTOK",
-    "GCU6AOYI23L423JF4TOSXDA7ZKG37A6M7INVYFO62JU7DDW32BRGFNMS",
-    "GCU6ISW6ZU3AIYQCTFQ6AYYBVH36E6ZBXKQLYHDAHLZJWNUSUT6AC3MO",
-    "GCU6UGN7VDBGFKLVBJEOF74Q7X77MPJERSI4H3M2FVLBBI2AVB4R6VM2",
-    "GCU6UVNUDS6QD2V5YKRG3SZBI6EJD5ECZJQGLUCBMCRPA53KG4K5PITH",
-    "GCU7BECSH3ZUT7GC3U7MUH4JKAKQO4Q6THKKTNZEH6P6RWPPZJSPOBEH",
-    "GCU7CGDMBVAAEOTF4ZHT5XZPVDM7LMB6CPXAV3N3Y2P2C5YH4EJZUDEX",
-    "GCU7E2NAFW2SAOHYNAQA2PZ77YHPSKIWXME63UUHTD5RQF346GU3AHIQ",
-    "GCU7FMD6GX2FZLCBVRJBBUOY4WAAKEGDDHRHNBQ3PMEFT2UDJDBOTAHF",
-    "GCU7NDVPTGZ3HYFQPN5NANBK64UEMV4C7VKBKKFICIXYAUIOGA2VXWFA",
-    "GCU7PT25LWTKU4RJMSAI2F7S2FIQAANRA2BQOX2NGTZTEF26GM3L35FT",
-    "GCU7S2ZL2X7EDA25CB5V2TC6X53I5PSZKRWJZAUG7HIWTSDGN6ISNEIQ",
-    "GCUA5BNHU5G72LC3GUNOTN55USJFPUILTUKLWYOW3EFNEPE6DRCLNODE",
-    "GCUAGHAHSHZYQ7UBHPD722DCK3A7AZL4NJSRG47EVI2LKRFRFOGSFFEI",
-    "GCUAKEMYHDK3VS6UHR3HHX4QTNT6SF5NF4GCEGDZMBOCROSIFVI6G5TH",
-    "GCUAVEOLGF5RVU642QIURX4TJ7BUBSRQ2GKN64HTWGGXUJY5PL2KE6B5",
-    "GCUAZAQFGT2TD3MDRIYUVB2QWCLIFFKY4MXGTLBVPLQFWFS6E5ZVB33N",
-    "GCUBAMJCXXMPWKQFPGDE2M6RWIMH6ALFYDYY5RXTYF264UUROIIP6SFT",
-    "GCUBFFJGLMU3V5JXFTJXEU5VPWUUXOBXRTKDU4MY4NCH26GX4M3XGIAH",
-    "GCUBNYNG7SH427BFAYWBGUT467XRNS2YF4P647BSCWUOA2XMI7JI4U4R",
-    "GCUBOQM2F56JK7MYEKFIPPHOEEGBLMSAPP7Q76U5ZN7Y2HBDBK3HXNJD",
-    "GCUC5GCJBS4TKTK47FVZT7RNFNE3TQZAUFQIJYCI7BCBREAZAPWSDIPW",
-    "GCUCWAMNZO5F6M52F45BZXKW5C4HXDVUXLYYMJ3W7S2ZGNNG2YXWZN35",
-    "GCUCWLDYWVND2VAC2FS3HQ7QRXF3O2XQLXRNCDIPAUPBVG34I7D5F24E",
-    "GCUCZQJX2QUHKEO5OOTKTHKY3WGVPCPX6ZDAN4TLI4JK6RSH64KBW5JP",
-    "GCUD2YCRSQ6722TX6FHMD5MAHJIY2KB6HDZABYU5ASUI7YNOJZH74XBC",
-    "GCUDMSQLD55M46YST5MHU6FC5PBIK5LOJVKEGEBGGQTIC5EOSCW3QUSA",
-    "GCUDX7YG2QZH2J36DLLQ3MVC4SKXHO67FOY4LNBI3HMSPDU776QCSYK7",
-    "GCUDYNRZJJDDUHBGTY43P6UP7RNVHP22JUEBNRAMIJWIOGKW2BYHPVRE",
-    "GCUEJEQLXVKQ2SX6JVMA34WRW3HSHQNKQEZOT4LUSNCE7RBYQIHGLRLE",
-    "GCUEK3CTEPB6RQ2A4P36UREDIOQMWE3JWUF6EKSSW2NAM423BYK4TEHL",
-    "GCUEWDEA5PXEC6FAX3JGJNSHCAFTXK2QA6LNZ45WIIEIO7HY33X4TUTQ",
-    "GCUF4LDJ2VQGVTCI5MX7NT6DSZDGRMC6NPPD5B4H3N5HXXKHPXHAWHIM",
-    "GCUFPVEJ635Q2UAS35U54TT33TGYL7Q2S3B2BBGVFQALZXFJZTUC42HL",
-    "GCUGM2CR2V35YFUQ3IE3KL7STC4ZD7GNBJHY64E2VRJM42PUACWDBD46",
-    "GCUGMB3W34OUKGMDPZQLYOH3LSCHTKVEY5QNAKYAVJ7AXKMNWNG4DFAD",
-    "GCUHIMDB7GMPRDUFCG7TM53QHICZTLEJMWA762UMVTWDYHWWKOALOBST",
-    "GCUIDIWZ3A5GLQBF3R6B5R3LTPNRRKBC7KFWNE7TT45GBXYKUDE5KA7V",
-    "GCUINAPVN2TD6ASVVXANPJKA4OR6JK256AEB3U4RGLYPZS5637HPNX2F",
-    "GCUINUANAV2Z32JRZYJLPFQNAKRPIF72HAUWNPIOTGPMA6NQC3JVQCQS",
-    "GCUIPYCEFH5LAESVI6YX5S4RC2CGVPUXQUVJHQWUKGYAQBYLFGMVIS2Q",
-    "GCUISN62WINXPXWFX6A3VQVATAZZGWPUMIFKCV4NA346M2ZE7A6X7HIJ",
-    "GCUJ4I4KJBWLPY3DYBJWU4UPIDJLN4PIYWQ6DUTG7GHR52PFMFCGEXLM",
-    "GCUJ6QB23W6SPSD3K2UHZ6QHO6RNGQQ32344EPSDLLTQHJH4FMA5RCN2",
-    "GCUJNGC3ST23A4PCWR6XACEVPQXXKZKCII7MMYI2Z2CXV7NDU2V5TMAY",
-    "GCUKHGFUA3GP64NC2K2DTQV4DB3LAM6NLZCHSPEU4256YVVCVM3TR3PF",
-    "GCUKQRPN4X5SERBF4DP62BUCVKTBTVMRP4IAXZZMEZQE6LX7XAXUIPIH",
-    "GCUKVGUGU4Q5G445VX7PH3GJYR46MVJ5OX5YT4PIYKJW3T3NL5JAQXRP",
-    "GCULD5WGBMLWGFZOTQXFRPQQWPTWIVKI6XWE6YWBJF4YYTDEDFHSWKCP",
-    "GCULGE6ILIOMZCRCYPWMA6JYSQJYME23Q2CGPOG6PKB546CT7JMUW2KZ",
-    "GCULN7R37Y4LMKY5NMGMOXLACM3DO3BACM2ELGFU53RHRHTT7YBLLPKL",
-    "GCULSC52IY73SOWQVVQXJULZK4HUXQCGV7B37NBXMG3ZHOPSG65DIA44",
-    "GCULU5I5R5DTUEIEKUKEAET7JNSJ53TINRUXBB2UQK765YT5QUFA5ISO",
-    "GCULWRLT5A7XGQUMPUPX22H2KYPRX5H3YZJSAYD27DZBQ4RJ33CB5MY7",
-    "GCUM4CD6TDULKJNBJOYGMM5SSJQQ6FH3UNAPXSSQQHZTD5YXKHPKTSLA",
-    "GCUM6ADVU46DMHWDWR77VTW3UGUEKS7C4D4I33C4OXZZJ3NM2AU4CYKK",
-    "GCUM7WOX6IFRQL6VRPQTYP3GYCCZEAVMYGGEPO4HKQA65WWILNITV6LW",
-    "GCUN33342RRCOQBQIOOQXTJZTF65J6QMF22K5EEY36L2QSDM5DBK52EH",
-    "GCUNDCNISXJFJA5ETTUSRBXERVGRBH3T27NBLVYVHKPYTFB3EDASYVAY",
-    "GCUNFWTEMDJOGKOBLF3SVSVLUJPIXFPKFRJHH57JUQM5M4QFDOGOQWVJ",
-    "GCUNNOEWQH4BQGTG2MY5SVZG2MDU4D4WR6MJVT55CNSXTH5CWNQ34SOL",
-    "GCUNUENC5M4JF3ML3MYE5ADBAGAW7E22ZSHVA2M4ZFG3FBK6PSIIYPWD",
-    "GCUODJ4M3JZE7NM33D2PB3VZVD2K6R54PBWVHJ434HQQQJK7WQLE5XJV",
-    "GCUOJ5HZBDRD3V3XXQ26X7FIXV4DHRONKBV27PZ4P5N63IIP2B6FVCPY",
-    "GCUONACV7KHNNXDOMMBXXHJSJ3QKJXL26VOB7OTNAF6V5WRBN7BV4BHF",
-    "GCUOQIOXYC7CJUCX33NDNRAVNEVVID5Y2HDH2B3YHFLTSO7CTGXETSSE",
-    "GCUOYPIZYIOYIOIUVNCVNNZX4M2DX4N4Z3PPEAV5PSFS42HB5BBJ7EF5",
-    "GCUP66D4BQQCD3RZK5KD7NSL5YYVXGM72BJ6IO5VT76KBRZP3SYARORA",
-    "GCUPBPRH7BPDAN4R57OE7LM4CWNCOZGRARM7CP2CWGIRULSWF2PXSTOK",
-    "GCUPMMZBBK42DYLLLWIV4IQOFA2ETE4ZSQRJABIXHD2DAZQC2RA3EV5T",
-    "GCUPOBAG5ALOGPUS22MWTYBCKZSPZFACMM3OYBVINU2TCPFNLJUD6JMR",
-    "GCUQ2HOQZJ3RAWODQH3KHNKUXKXGBSYZFFBRPIXT3OMNHPTKH52CI6NL",
-    "GCUQNLZ7QOZAOX5JTMX4GXV7XQBNX7R6HAUMJNXJOUH7MNRMM7XUKYA7",
-    "GCUQONRHBFOVIXP2AZ4XBXATHWUZ6AM7GZBS7XJID33VA4RA26DZBHCQ",
-    "GCURPNCDKCYUVQPGVRKC2YBYQFSDQJBA2FV2MAVCXCBM3OWNGAKEOAHM",
-    "GCURRXCUHJQHURM5U3ECJTFA2GJ6WZNXNMF2GEKUW3IKVNZT4YR4VLFW",
-    "GCUS6J3KFDVMI3NAD5STKWFJN3FD7LBLK2HL2OAYK5V7XJO3D3ZZYQNT",
-    "GCUSJCVIZI3BUYWPOCCWICKCPMO2SWBFN55QOB73JRICYQFTID5EU26W",
-    "GCUSOJSC52LT2SSXK4H4PXWAUB6T7CAEAIOVRGH5OK74TAYSGWBRDILJ",
-    "GCUSQWWGSOQWWSO72E5VYWFULZZRD2ERRCVXUVPT7OPCHYTMU2RJRCSK",
-    "GCUSXLTGV5WPPI5HBFBOOM6FFTUMFPAP33LATZLJD5VXPGAPUIC5RN66",
-    "GCUSZRIAEAZDA22S6T43QYL6HG5CMIM2UQWZPR65K2S3VSZD2V3ABIH3",
-    "GCUT3TVHUROYWGGYIF3GKCQMHOWI3BSLPCD7GPWGXUOK5TXYNOWJRPFD",
-    "GCUT5PFR7QN43EHXAVD5ROGNI2YHFNPRTPWZODIDPOEIAA3ZKUKE5TTZ",
-    "GCUTCLILY3YKMVEK7KTR26MWHX3MQ3CPGRZFSI2FJU4ZZY7W6OOFU43D",
-    "GCUUP42OLWH5CVOHB4NYYOFHKZWM3O52MSARXYSEWMXBETPZST27B7YT",
-    "GCUV47TFGG2R54OQ3XOK7QTGHOF2WYYTXANQIPHZXUJXIJC6FK65MZ5R",
-    "GCUVDKZPSJCHCT6C3RPH7Y7BFKUR4C27GQH6XCC4FBAZMRRJMULDYM4B",
-    "GCUWC5BG6FE652THIKSA4KRRFWLXAIA67RYWD7SID4AUOHSRMFEDU554",
-    "GCUWFBM2WPZXZXZ7GLQVUOR3AYJ5SX73ZHJM66NBJ43ZQD6Y7ICNDPHR",
-    "GCUWFQLH4ELR6O2WA6S5PEGPOJELIQFTMSCB64W2ELQ4PK4AYOSWHGXN",
-    "GCUWI7VZI3EKMNGF22FUAOPHG63MACFGG7UWV3FMKWEQ67T3Z6ID5UWQ",
-    "GCUWSDARM2E4G7374HW4FMVVJILKAAIPAKYUSVVNYT3PU2XNQURUR67D",
-    "GCUWXPR56JD2R6YV3GY6ISJLYBAREO62UDLOQEPI3MBUVFZJDBYZIBKD",
-    "GCUWZQ7AM3MIRPG7DL7BRQEVX7R4TOXB3QSPGEDSH3ZAODZLRW3DWAVY",
-    "GCUX3CSHOHHEICPPADLR7G2L7KZZ3L5ZKKJLDDBUQPLDHUELCTZG6JYX",
-    "GCUXGTSQJK3YN6BMAG2FLGUTBZB3PI3D5OM63DWGFTL73FQ72JE5TD3R",
-    "GCUXO4DZMBSUYOY7YE3U4EAOGNVUCCYLNKQDKAS6A23BSLMH6YLFPL55",
-    "GCUXXCF74YBGGD2XVF4LJWTFMHTXM4C3PIZ54UXPM3YPZWRK7HI4F2W5",
-    "GCUXZDBYRN6VGM7CDRZQLREU5R4Y2EGOYUCQ2QRTLUULAPPA2MIJX6VB",
-    "GCUYD2KUZVGWJBBPUMH5UN2YP23A365BCDSJXNCHHYBTFRGXE3XDIXLM",
-    "GCUYHKAKCQUO6NZ5ZQRBNDMBD3RSPWZG2JGVFZDV2WL4E2HW2RGE5KC7",
-    "GCUYU225LWLI5WRGXMMKYTYR5FJAWWRP3M4HPAYBAFMQMIUW45VRYBJY",
-    "GCUZED23X5S2E2KCJTAXZP4UKQVZUOUBSXGLSR2O2N3ZQMGJXPE2OIXI",
-    "GCUZWHBFVBIBVWITH2HG2JUR3I3TOL3T6GZJWUKLP4H3AP7TEWVQDE2E",
-    "GCUZYJUYWU53B25HVS6EXVZPU2WU6ODZ3QR7X6YPYMRBREMB4HSHYSHV",
-    "GCV2N7APPF2LKYIT5XWGNASUG4ALWGO73XJF6VQEPVZPPR5UB6LNW7GD",
-    "GCV2NMTTX2PSSM5SJDHTUSPSFMDEMARCZLRWA6OB3QQJJPKA3UWZLSDT",
-    "GCV2P3HWJAO7GJR2RKGPDRZM6TZDVT6FNNADEOVT2JPBKBGQL4MRRXUI",
-    "GCV3DZVNULGEK7GKJI6FATVRFS73I7VSBOMHKJ7Y7JGB6QETKST5ZZDN",
-    "GCV3HXDZJPRH4EF3HUNGVYKBXF2PBB3YCIHEGT3HSNKRFMQPPLKAH6OC",
-    "GCV4LWP6HMOLSMNTSYH64QT7L5GKHO6MKRI7GGNXTZOUZG7E2VLGWI66",
-    "GCV5265NO5KWPPJOKMGLRVR4K6RPVZMYTNAHUCIBCTPYGWK2XHEJQTUM",
-    "GCV56AVFUJB3DCMVEX6MJDILBMN2XUKVBGXCQ5C5ZKHRJMH5CU3TVR4P",
-    "GCV5DMOTASGZEELHZKBRG6RREYFDOAPCY4C2G2ELVTZEBEPSUX5GFFIL",
-    "GCV5WBLKJCQTTBMKCW34JWQR4L4V22NLDDJNBFRS234ERFSS4EZUEPAX",
-    "GCV63EOSCE2GGCG3ISEQFWKDBNYM6LYTEGJJRNIBCBJCFAJLPXG3AFB7",
-    "GCV67QEWXT2CYEK6TFJDXKSM6HW5SBWLX4KUXO34UZB6Y435XMIVS2L3",
-    "GCV6XRQIJPMBFHLVTCTGL5BA5HBDMW7SQMSTV2BVCMC63SGL7JJ3VJGG",
-    "GCV7KRVOWB7TVHBTXLKAXSI3PBRSY2PSJNNB6KRA22ITBEGB35XPKZIN",
-    "GCV7LEMIMS74YEXULLXDGQOKGXPZ2CA3JUL37GEFOGGC4TWFPTZQR3Z6",
-    "GCV7PIJPLKHRU6LLM6B3JDAWLGIP3UYHGCLHSOJ3BCDZW6QCDAP3N4MN",
-    "GCV7XWMMTA3KXMCSBVCVC7PFA6UX2F4UIEXOE4LZZANTX6KUSMSKXWDB",
-    "GCV7Y6VTEM7N2SZAK6M3CIVTGR3SCFRNOZGR25PGUFLQO454SFPCLPSG",
-    "GCVANPUKX5EH33PRTLWF6THT2UG7WXN5DQMTVGH3WGMZTJZG7H27SNNQ",
-    "GCVBDGQ7IRCSAVKZ7VEWKHB3RDCLYXTNS2K4NBYVP7N67FFSFBOMPASB",
-    "GCVBDKFOSKJMXXZIX6OEOEVXVBKULWTRV3EXDXANPXZ6UEKNQQRK6XWW",
-    "GCVBFR543WNA7NWZN7QN5Q3REKBV54L5MXQMXIOM3WUKTAZSIJRBSLVR",
-    "GCVBJTMBNTR3BS4OE62P2XQFEQLEOEXTKXZ6QEJV4YFWKNXLKAEBB5FM",
-    "GCVBKG2IPLYUDERPHL2CMVKDQ2DRLG26VAPEL256AGQ4NNPZ7VYXLVNG",
-    "GCVBL3GOVYEDTGQMOJXMH6YZNUQSG5EYZ5DY72YDXCYMNYVVB6MCSB7R",
-    "GCVBOA6RJWMRTDJ4FWMRIGLJA7EU7YNCM7PFUUMUVOX22DG53QDGAN4V",
-    "GCVBUIXKKLH2DYHZRSLZUIZSVJUL74RTW6FVCCEYB2OE3RH7RVDBPCFG",
-    "GCVBWX62VYVLB7BYQFBE77PB7S7WFNEJCOOWIPUNXFA647DJCLDFELON",
-    "GCVD5QMXTYS6MITVL3PBNA4DYJSOK54RV5LDHEWKNUWJAWDUOESNZU23",
-    "GCVDG32M5RP4S6B7X6F5QS5EVYPJNWBMJTLQROYVHWQF62EHSE5Y7HSM",
-    "GCVEYZR5D6BRYVKRPZF234W3YV4AWH4XFK43XBXO6ADUNUPZWOJUPNDE",
-    "GCVF7QUVMXPL4DLGPKUQHWQVRYRPEGSNYOJJAMEAAULUXK7K6V6RRQR4",
-    "GCVFC7X3SBI7X4FGF3IV2ECUWQ6VK5UXIQMD7PJ4G2I2XADMDSP22YYE",
-    "GCVFEUT5O7H52CLKZMU6MNWRTEAOW65P3OWZMNYWALL2CXUQOLPPYTMA",
-    "GCVFKT2DTA6CV3HVPO4LBWVCD252WS4XQKZBB2B7NFIOTAWZZTVVSUJO",
-    "GCVFVMPMAOEJK5YLPMAPFZKQJRISHGKHE4UQI55PGVVAXM4CFIIZQDGW",
-    "GCVFZDUEWRGMWDZ7KKV4J33QZNO7ZL34WTDKYUSRVC6XBVYWGD3R2GCI",
-    "GCVGCPFRTZXKLKZYO4FUGBHNJQA3R6BEOGSZ7Z2ECYXXSU3ADS72YXOI",
-    "GCVGDVJ565LNRM46ZPGHPCURLOBWVLT3NT6ECT2IMVVZJT5AUNZXRMKE",
-    "GCVGZDOIXXDZDPXWYDXHO2AWRJS4JT4Y56IFSL3IQJP5T2AYQEOM53PH",
-    "GCVH3WSR5P4WNQVKJFSXEEPEWZOAKE3PTSRKNOEGVOIKXQXIO3RJ3JQZ",
-    "GCVHDVXNULXHB6ALCSWFXBYJOI4SJYLPDJ6F7X6Q7OYETP2TWFJHHAIG",
-    "GCVHEACMITT6SN3LS5PGSECFDPQWCDY74BVUCL7HA3UOBK52BWT67Q25",
-    "GCVHEPGJVFSAHUHQZPMNHV52NVS32BD3HPAA53TDX5Q5XY4TBYSSFSNP",
-    "GCVHHOGTVRTOABJQ7HTNBTLNXMLXZXHVZHENDUOASJBAL3UYL33VT766",
-    "GCVHR2Q7Z5SF4IOCLMLCKKSJHE4UG5Q6J7HWN3HQO3PVPR57AET2SUWB",
-    "GCVHR52UYBUAYHVMXDRJCYHKIFE5WA52KOS2GLZZAQD34LQ4OUVNOTYD",
-    "GCVHRVHJF3L4A6FQMAJE3BW3JURLB526SN4VBBH3SSOMS4YOBKW2BEVN",
-    "GCVHYUZVYSR7XCSTCVGGWGR4RTANSIZVSH3NYMZYWFRLYBWF226HG7GD",
-    "GCVIHNH7STEAFOKIQ4OLJBWURDNLDLLT6Y2BBCPOPXEYIFPSS2BTH2FM",
-    "GCVISCFKFKZ5UOBLP4QR7SNVCU6E35QO4KNNFEVHIVO2K7ZTQTMY5347",
-    "GCVIUJ6DHB54LJEEV34GG5KZVAMYP6QIGG4HEZQQUAMBTFSWKRHQHWEO",
-    "GCVIXXO74O7ENFU327JG6SKM333UDBJKNWOB6WFHLERRYHQFVBNNMMRH",
-    "GCVJ43SIM6QTO23WA5APQ76FSD7WF2CUXBNZAW55VEVKUPAA4NJHWHIP",
-    "GCVJP6IO4F6OUZ2HM346IABVPFNO7DXIAQ7RBLMOQCPKHTA3FYDKWX4F",
-    "GCVKWJGVCDDWJTTHRL5WJXFXCUZTMP4Z4OWLIZ7FHO4Q3O6IYWHAJABD",
-    "GCVL3JAZF4C33TJGLYIKZT7T2Y62AJNHDQRMB7SQADMTYWMA32RDE4EE",
-    "GCVLDUWCC5GLUSHYCNNELBJQWJVRLSOE5QAYJJXC6CK7IZHOABGJRWI2",
-    "GCVLOPCF2AD277HSX5LZ5EWWRLQ64SZOULALLGY76HJ3HWHTEWJ7KBHK",
-    "GCVLX4NODNE5YTFSDHZ4YKG5L6ZOH42QWMTJTT2E22JBQOD4TEM6HRQW",
-    "GCVMJVGNLTHVMERDFTXFN6PLC6VIDTPOQY2QPFYYOQJAWSOZNF4O2QMP",
-    "GCVMOIC27PPFFZU6UKJJTYYDJARR6LGAJL3GMSP5DPWYCFNLHIH3FX3A",
-    "GCVMRJYFADDWWS5I5BPVUOLVDDGSAPBJKMACKTAKUDJRAHPDS3Q63UPH",
-    "GCVMXMATYVTUMF3A7AVN7GMDMHZNGGPL7D43IMUVJ6D3ORZVNIXXSMJ3",
-    "GCVNJPJ5FJ3NR7GQCCM4Z5VISBHS4UZQWCIGUQBJ4SFKSFRUMOTMRPPB",
-    "GCVODN4OCJAM4TFVN6MGS33DPR3VWOG3N6IFG52A6ZDKXWVEEX57IFLL",
-    "GCVOJONUJMKFTY4FS5B2PQZKDL4HMRLRMPSAPKVDDZZNX2OOFTFF6AST",
-    "GCVOLPVNIWIBQFHWPKPBAKP6AQTMT4WF22AA3BJBKLIWPC3XCQSS2XPK",
-    "GCVOPGHDTD6ERFVI27TJB6YM3P4K4H2S74KXHEST5CSAIYKVBQPIK7S5",
-    "GCVP2IHUIQKSQVRUYTNQASVR76FAPOXGKLXRZTXMGPGGVDNEHQ2WSTAR",
-    "GCVPJEBACBUWD6JNXQIHLEBUIXYAZY67MDASQDXUFEH37IGLVKPWGCGD",
-    "GCVPVGE563GFNFYQCBV5XCIPQITPH3JE34YCAISV626PI5OMMRIIMUGE",
-    "GCVQ4KPOY7FFPXXOOPVTTZRJUGP2M77SHGHPP2MXZ6P6W2W25EZBO6DW",
-    "GCVQG7YZFFFWT4CT7TPVVA3VISZZA3BBZBUONNUN2XRFW7YKV7NRLINE",
-    "GCVQNMWBPLPDTICK2TKBPF3KJCR66XAD3TWYQZZLODW3MSMB27LNYDK7",
-    "GCVQOYQDCKBOMBIAV4KVQMQ2BACHFY7CNYUFUAVSLYP7OWDESOFQSFMS",
-    "GCVRR5PTNYQZ5JV5ZVJ7CFNFTWY2SQWJU5Z2VOVBDRYJONWCZVV73KEX",
-    "GCVSINCW6X42UHMHOGZ544UFGHF3TAF2WSXFVWAMBXOCMFCDUDGDWPKH",
-    "GCVSMG5PO63GVKT6XRQWUGVYXSA2GQE4SVPEMN4TMPBT6UZCTZWQGI27",
-    "GCVSP2EQXXNAER2ZGE2M4QYDZZFHOHEBGF25YEFZZD43ZFOTZ2KE4KPJ",
-    "GCVTHYXCWOK3K3QFRTLOQNIGDXQ3247OSHQ4K4FUIEHYDJAOFKUTPOLY",
-    "GCVU36YJOJCWIGLI32VYB35KVOZX7ZRX4G7NJVNE6RZGSQPIQUW5EDAM",
-    "GCVV726PYY4UGLUITTWS3BHORY7ESSZAVWAQB6TXDOWF6MXHZLPWRNPV",
-    "GCVVHE5ZRYYWOACSB2CZXAAJ7GSHPHJI4KMVRS3775OYVD3XGQEUQOTS",
-    "GCVVZFWKE7XR47N46PEE7YJ64N4FIRYOBC27763EHTUTRLJMOZNYT4BQ",
-    "GCVWC7PQISBOTCBGYRL3GGZCS7LZ7QNULXNB4N2GPKEJCRMMNYFSFXXX",
-    "GCVWEKIZ6O7UZOE777OWMMQFTAR7PLEGDGHFC6DGIJZUOLFNKMNQPHVP",
-    "GCVWMY67LPFMKUDDTGTJXK2AEZE5DB6FUYLVCW5HC67L42RCQA62USDC",
-    "GCVWPHAMZ3OCCTLJNED3UUXJWHBDTQSX37VE2OKF673A4KTBAC4T3WKR",
-    "GCVWQYT2FC7MVGNZL465QJBPYKQDN4EXRGQBR6DDSIQ2XA5AFYDJBP4A",
-    "GCVWTTPADC5YB5AYDKJCTUYSCJ7RKPGE4HT75NIZOUM4L7VRTS5EKLFN",
-    "GCVXBCDJE7HFUGXO6A5A56IGB7R3WRMQUSCYWDZZMJURLXU2OEHP32CR",
-    "GCVXI7ISL36JV2NOGEA2TGLYZGE2YPVYDIPNIGMQBLK3N25LBQE7O5WT",
-    "GCVXRTFN7BWPKWJMNLL5YQDCBIOFE57NMCX4LP4S6C7V2NTKLFKUS23Q",
-    "GCVXYNGDALVBR6INVI72YWJJE7JHWE2PQQYCRH3OH5JPNC2KV56BTECH",
-    "GCVXZRUFXYHMP4F6B5HV7AK7TZYXJCR2CCJ2MCHHOA7IS7FNXHYHV6CZ",
-    "GCVYD5CVLOJDGWGNZOX6VWYLMOFQG7E2EDR2CPCWGHXJHA4NMKBNVTPW",
-    "GCVYDIIIMBRFSQWR2J2G2S6LFUYYD5FSFQEQ7SG7K23TO27X2CKZWNG7",
-    "GCVYFMM42X6NGRHZR675U3UVPWWXTP7SHKH5KWM6X4BXMCQNP6T6GSEN",
-    "GCVYI6SOVU5NOBE2AIGGQOFPMQNAVUYJAPMJZZSPNT2VILHVR5NEZIW2",
-    "GCVYSILA2SAIXHKO5L4UH742PUZGRHC2QU6NCLHNHCFRXIFEO24LWUZK",
-    "GCVYYPPMH27OK4ZSFHMYEIKKG5LELH3476TPJFEROFVRGQ42FKPKRNHD",
-    "GCVZDNVAO4NXPEHPSHSD6MBIEUAENHSK74OTI2P2PIHDIELGUFRRNYVC",
-    "GCVZFOTJDAZOBG7S3L6V7VDRMKWRS4H7R7X6JXRLCVANALIVWI5QKPU6",
-    "GCVZFW36SDYN2AYJ5N7GCVX7YZ7JNSUMRDWJKQFVDS4GH6VYCOTEAXTB",
-    "GCVZH4IONMPSKXCYYXN26GQNO2AAAV676QUQSDKZVZSYY3SX3AC3TASS",
-    "GCW2I6P5GMG75DNRJFFYV4CZDOJVP45O5AJZLS5VPHZTA364NDCVJP3M",
-    "GCW2IKHSJQNGF4ZAKKL6W3AMAI72AZJPBS7SYL2YLH6XRGXJ2IQ4WSPA",
-    "GCW2JL2X7QLET4EJ2Q3MJYFXJ6GKMMLWCFNLMYS5J5KFGODNDLTKBLE6",
-    "GCW2TN6FYOBLC6A7E46YD5BOUFH3BF3FECM2KJSDDD6HHU4A6STBFNCM",
-    "GCW32DPKADWMZGJARR5YFB2KQJW4UMTYZ3SSS4L6M5WYEQWKMBWEWMCW",
-    "GCW37HGWLZV5UR7FXAN5ESWPA5ZBIRJ75NNBOD6KDMH6SNIZMY4SPQEV",
-    "GCW3RY7Y66BZKDFTW53WGECUGEPLV5TZH6MAWSUXUURUXUH775YUP2VD",
-    "GCW3WORA4RFKRLCMMUYUMVENQV7EFSZ2JUBL3O2S4BL33YTDFGIRGVON",
-    "GCW3YNHEQIA257CEWKEH5TEL2QKN7ZU7VSDE3MC34BGBLP6UYLLDHDN3",
-    "GCW447BRIF6SH36432XULFGKKXFIJ22YTRZO32HK5S4NWQ32AIULHCJK",
-    "GCW4TG3V7J27VJIKWFCUA2DURUXWHPP4XO5IQ4VX7GWUHFREHY2BVFR4",
-    "GCW6Y2GJXXZLNQYHRLUZVCXBYL2UBIDDTR22VWWJLZXO45V6AZLPM4RV",
-    "GCW76BERK3THZYVRUVDNGTPOK452XGL3YHYN27IU6T3UH57362HSJUSD",
-    "GCW7GQWV64RDR43M6J56Q5Y4XURICIGFGME4QTOKEORO4IDOGANEGNYO",
-    "GCW7LWOUADSFL6IY7QQAUDA2WH5WYMDAYQMZ3ISPBS65AKK2CG4HEB7M",
-    "GCW7WOOUIWA3EFIMKHTUOPSQX6TYP6W5ITSSAMV5FH4BC2VM5Q4X5SUN",
-    "GCWAUWQRJBAXHIPPT5ZVF6VDKQFC6MG4TTS7AI4UJGTZWJPGRPA4OK55",
-    "GCWAYI5OBML5DDD4NIS7H3V5EETF2FZAS6BNLQXKYQ5I2KJLKJK7ZRFN",
-    "GCWBDJL6XXEPCTDJYPMIUOSEKB6AJFQB4FETBAMLYIMPAKVXXNZOYZKU",
-    "GCWBLYBGEBHS4ZWIM6DQ353SSPLKFKHGPQPZ5IBQAXS3AIKBWUIBSPRO",
-    "GCWBPBAT4FXCMUFAHWE4FHMXD2QP7RPEJ2BSUKEGTHN3FWZBNVKGXBTC",
-    "GCWC27NW2UONCNUUTJ3TLK7NI5UX7I6CIFU5PGURJSRX35WUG3TP3CNG",
-    "GCWC3Y3UTG6GUQDE27G2GN5CRXSFVE6TLZ4YKCQON2GVP5RYEZVVO7WD",
-    "GCWCABJ6YTDVATPUJJ2Q3X5U3T7G55XUUPH3GFMO7QKCNMJCDFJTJGWF",
-    "GCWD4C43AXNCRJZ4YUQPZZ6TXKFWOC6UWJBU5GNZTQM3MZZGZHYKELON",
-    "GCWD5L66ZSDSCWZ6UBFDSXXXUPG44HIPLNILX2QDUDYRBMBBCS3CSAOR",
-    "GCWDB4NY42SJFGZGMOICCXCFXEUZKC5HUI54QPPUDRLTYR3IXQED2ISZ",
-    "GCWDDGCJ6IAQJHOSFVSYC256YIDYNM2UU5BDDFVO7QSZJGRIUE45SQ4X",
-    "GCWDQS2CEUFKHIUGPVB7BZIK4VG2FCFT4A6JL4DDKNGE6LUMWNEGMVFB",
-    "GCWDUAYSWTCNSMIIYGSA63PRMR5WH2ZAX5JNKLRMOWZGK2FVK3YY7USA",
-    "GCWDUW7V53GSWVRGLZCSJGFM56HCMZUVAG7MI46WKOARVAEALFX2JXNS",
-    "GCWFBFBEJRJDVRTLUIJY3WZI4IB3OCIGDKKROAS36CQ47KXDZDL3U4ZN",
-    "GCWFBWZ25L4AW54JPIEKLV6UATRDW4VSAJ2XKRK43JN2H6G5KHM3Q2F6",
-    "GCWFDOVXNIU3YA6KENMY5FJBCYRXWWB4AEDBZFFIPLVZW5HKHLTTII3O",
-    "GCWFL7PKUKLFTZI7OKCYV35AP7IECA7VL5P7AFPL65XYS3AMXVBTALGO",
-    "GCWFVRSZEXWRCMEJSNH7VVUSBZ67DVLAMPW2LDPA6LBLU4DW45FZFJH2",
-    "GCWG3DOMXKWJH2FCJHRABRSDUB3POY2VWSTILE3C37ZD5Q6GTRQXTCNH",
-    "GCWGI25LNK7GNTCVFFJRQC32KYDMZVOJBY5EZI7XFB7TD2TYQACBFX3A",
-    "GCWH5PEFAGLRMTTQOLPHXMQX53LA23XXD2HJ65RIZXKPAWIYBEGW5MYZ",
-    "GCWH5QOEIW3PD7D43OCFLBJN3QR7Z2LNNWIWDORMWOBGYYRDYGYCJ5VQ",
-    "GCWI2EURRBEB3PBINWID6ZGVGZ64K5TEPVQWBYXRWUBI6DAWK65ROE67",
-    "GCWIELHLDQA4XA6E3TWCKICRJHFG47DQENZSQWX5V7F7CYQWAKBOQBNB",
-    "GCWIL3BTCPNXKJAALSX2QPZKL7WFYOJB7O6RTU6WCAUCCEYO5EBBIPKF",
-    "GCWIWVS3DNRISGUUYXSIC4XE4CSOI6S37GX3AVU5KWG62BTNRUXAEEYA",
-    "GCWJE7VNAE4KPZPNHAH34UI433AYZ3OSX2CLUZUYIK6U5GJVCHVJXZIK",
-    "GCWJGV7QJG2A5HLKDKF6XTY2XWYLBFBDLCTUMMVCNZBIHNLEP37S7B5A",
-    "GCWJNOAM7ATBSXKPXNNMZHKPF3CTI4YTOLSCCBC44DGTVSC2A4XXKRM7",
-    "GCWJXSBGL2SIZRNADM47K6JZEY5PW6OXV43DCAO4C7QPGJFA77DW4MRR",
-    "GCWKR6BTQQGD6QWE44AZ5ML4FPKK7IBKSOWQSXM6KNTK4GVYO6JED3AM",
-    "GCWKV3RKIGGTELW2YRF4XCYF3IMENFZUELNLEYJ2KICTBERMND4YABDC",
-    "GCWLCCVUOJNUMON4IV2KGXJFAZ6GA4B424VM5JCO5ATQVRVAQTPNFSOQ",
-    "GCWLK6EP2UA366XLNZK7S6MIS3TVL7OOJM63NZSWQEKJYPG64QNVZNRE",
-    "GCWLMCA6J7HKMCTQ24BXZDMCDTD4RQUHZZE25HNWTSNTCTU2SSWBWA5Y",
-    "GCWLNABFSLEOQH5ZXTLCNAFL46FYRKNR4EMDPK3FTAGMZTXDCYXCOAEO",
-    "GCWMGKZJEJAICDKEKJT4QOHDQDRAQV3TDW6GZTYWLBXL6SLUWDNNYNZH",
-    "GCWMQ42C4QQQTJBLHPLUL3CFL6HLJ3SCW3SLR34I3CQFXYQLHAIKVO5W",
-    "GCWN2AUX4TVEC5S4MKVZPE43AUTA5AI7KE5BHZHWJMOFALPCNZG5O364",
-    "GCWNXKKXKEMKZCLAKW4OY3N5FPLGW6NSA2YDJRQKKNLUPRG3KITDNB4T",
-    "GCWNYIMGPVK2O7X2VW5DDEKGNMD462XHWYWWWL7LBUU5W4QHFT6H5BER",
-    "GCWOARKDGRUEILSTQ5KRRBMOAUNWBH3PIZ7IF22H73VMKC2IUFQ7VIRD",
-    "GCWOX6FKD3PIV6WVFLK35H3UEC5WOAPGVPEGEF5WLTBWFKBIJ4GDOJWA",
-    "GCWPZYL67TVXTLHSPZDPJ5PDSUHYN6NEACUSPIEYTDDG3Z4KOICLM3TK",
-    "GCWQ5PB3MKD4PWHQVKNKUQ3NOPBRRN4BKLKGZCCHZUHYWTCFZZ674EOQ",
-    "GCWQR7HLCYE2IIKNJRRZBXIOLYMNYMHW3K7ZYYD27VNOSA2PZU2GE3EX",
-    "GCWQSRUHHZSVEBMZSJE66JC6HNPLTLMM247KUGPLJASW3FFLDEXZAETC",
-    "GCWQVKQ7SOOGZWUEYWSKGF3HVS32EA5IL4JD2QLGA65MI6ZGFCNRGS3P",
-    "GCWR6YTDBOQIMINXLAHVINHQOJ7OZKR3FBWJR3KV2YVT3LZQWZGLAEMC",
-    "GCWRLBZCIM2S2KUIX62BRYS5Z2FDK7KUXX6XNF46RBZ2U2PEVQZJ4MIN",
-    "GCWRQUEW7C5YSAPOVEQYB5IRZ7G24HWXEPJOPIQBNSSOFAWJOEPKJNFK",
-    "GCWSOACOTZOB6MHPNEWFD352XMY3JGK7A7PIKZCX2GT3JQZ2SIZX3I2J",
-    "GCWSPJTOWX4CIY3OGFCX7D4QW74SQ7RHLXSUT5ZXQGZCRE5V7GISNXRP",
-    "GCWSRY4U6RGWJ7XUFTHT5E7ONS36DFZFMTDIMFVCYCAYXBUAXLKCG54D",
-    "GCWSYONPSZOK5L3UGUILGLTQ54UMQEP232UJFFUWJZW5HA6XTGO6RA46",
-    "GCWTD4XLFKL7ORFBFBQ732WGPZGGG5SDXKWFZYW2SXMO5ZB7V47YGDUM",
-    "GCWTV6XVJ3LV274WGJOISGQYOYNMXY3YYJQBV6MWT5OCHESPG3SDAZYU",
-    "GCWUCB4RCZWEABKIOFOYPIEXQOTDOAMCO6K6PQHU6DB3QZN2JTYJSHIB",
-    "GCWUCSAUIW34BBXGR4MSSQXB2HGTWZEWTU5WUDGFS7ZKO47KJS6SFY4J",
-    "GCWUNTCFKJFD366HAHUDLMR6XI3DEETVRVWM37ELN2KBKZJIXNETHLBQ",
-    "GCWUQWTV6G5ZNEGKL2TN2Y7P3ZLKQMRHZDREK4BGQ2ZY7KD5DUZEKTUM",
-    "GCWW6CR3VLYI6MLFXW4OUO275BHRGU6CDFKH3G44XP3JOJQXGHXUFCHN",
-    "GCWWCBQ5KPP6D7475NZZPDZWKN2BWANZHAW6TPJ7TQ7H5HFWIQ2D5JT3",
-    "GCWWZ6VJIZKRSCSWYL2TQNPTLDGH4EDEN6IMVGQG2SWKZXC24SB7GPAX",
-    "GCWX4SKPMUAOVUBXGZQABGDTHEPRMSHLWEUDKHSYZYUOXFPQVEBLCMTR",
-    "GCWXLOAFFGBFKMGQTAE7UQCRYPFVZLXMRF2RS4XCT5BL5QQWN3Y2FWTI",
-    "GCWXXU6OABUNBTAAKCNSUJZN3URC72KYMYBNVGOIAIW35B5LH4AARIOL",
-    "GCWXYF5PEQ4OZPUEUPYELZDJZZ4LV2HPUEJGMMN6LYZ6UEZ6E7K6Y3WH",
-    "GCWYBW5DVUSBOG5DNBXTJQIOMIRJN6VNP5DNMTMRUX57EJNYB7F2TTME",
-    "GCWZ4VXGUXAOIYRULQ3RIZGYCEXCBARJLSOVK3LZTQOPN7VJHSZ5TULU",
-    "GCWZQZ6P4GNTAPA3LEA4DPK6SFMJS3FSIYJSLIGTKPT35RTWSX2OLWM5",
-    "GCX2DQI5LCZG6UDPUWMRUUUXIBBKADFMNISIRBTJA7RQIFJENSJRER33",
-    "GCX2MAQM24LOC2RTOCVWD3526MPITDPJOMSZIHBMBWQ6R3T4LXZ2OXCJ",
-    "GCX2MGIUGY5DOBT4BXXRQWASISMUF2GJ6F4WKPFVOLDDZLKV4A5RXBPJ",
-    "GCX2R3WU2ZHJ4SUAKQFKYQJFK76P2SMWWBAU3ZR3H6YNM62746UFTTGG",
-    "GCX2S2TV47JQ4MASHVFO7VOCJO37654CAGGKSJHXB2ZG5HB3JOAZF2VU",
-    "GCX2SV3QHNJA2JESLWWDYTYS7UOLC6VRYOQSQF5JDXH4FUBXAKZX4KKZ",
-    "GCX3HOSCWZNVAKR3TRRLI4KC236NBBKSZ4I5DSJMVNMRVSDW3BMUZIJ2",
-    "GCX3QOAVKLPMUYKSC6RBHKTFLV6K67AORY7CRQCHIN2LYLEASVQY32SN",
-    "GCX4CI3USOOC3ENHPB6KTCVK7LVZIGF7TWOGKU5H34X2CEQKDU52ZV33",
-    "GCX4D2EKYUR4QBDFYJPZEDDSZ4XP3DD3WHE3LID7HRHG67JTY5ARHXEB",
-    "GCX4FILK6JDSMYISAILU5KQPEFVV4757UYKD5JCFEJAXRJQMETRT6EB5",
-    "GCX4IFMZWDWHMHLYCYK3ZCZMJHNNIAQ24XMYTR5SZRIIR36WCFECC4A3",
-    "GCX4NOBGEQS2HRVBGN65N4GPKKCVVHFTBNFVTATZ2CHOFESD2BMQTATE",
-    "GCX4STZP5LYZXO5ARCDXVJNA37XC4TDU6EIUGLFWOUAT7CINQNRPASOL",
-    "GCX5CGYVOUALIJNI3ZZOEXTGSEVE2KMFJQBIIH4RLAFV2EPG42QPWG4D",
-    "GCX5EVK52QGBFXZEE7NLSDQPRVNJY24JVN2EJWUYMUTYCD3I7LS5LEOA",
-    "GCX5OAHUZXV7PGVBISIRQKDULUFYM5ZXMS6K7KVM4ISJU37HBPLBHYMT",
-    "GCX67BTNQ5YKADVYSSNF2VN6N5BCDJROF52XQXERIPP7MW4RGLJZTDGC",
-    "GCX6QKIR4VHJCALA24XIKKQ57BXKMJJBEEHTKEF3KREE746ZKAXUSWOH",
-    "GCX6WLDS7V5YXAHLQHPYOFJVRFHFGQVGGS3CIRAK7E7WW3YKFTHMME5G",
-    "GCX6XHR7SI6QI4O2IGL7NAC76UYWBISEGSPD7TTTFCDFWS7WMDWWT6GR",
-    "GCX6YANPNKGF72K74FL7G7IBACYJ5ZQX25ZUHIQTWQNVYOZPYWHXLIX2",
-    "GCX757UJCTQLRSR4ATYYSMUY5H4S23UAF5FWWUFZPEBYCZKLRAIZJFRA",
-    "GCX77VOHLE5XAMYGCYFK4FEE7NFPOLTYTJR27BOLWUTMKDDB74PDQUUN",
-    "GCX7H4PKRLPFBTDJZ5YYECDQFBM2GY2D5SAFIE7NPR2KCHWRCYTOYF4A",
-    "GCX7PSNBTXJN4M2YXVHRIGD6A24SOEA53LVYSZ3AYCXR3VA3473H33AC",
-    "GCX7S36YL4JKCWICOTYNZSNSRDS3C6EIPAHOGHA4ROCKIVBQI66PX5LU",
-    "GCXASRDBC37QNVWAAR7SIRLLNSK5RE3AF52GLS6RGQ4FRTBZ7QB6VZMR",
-    "GCXAU5RPPJNMFAS6U4PA2EAVVQIMNNHBKUA23Y2WZVBOKV2MSY57UHAP",
-    "GCXAUNBBMDLKDVB7NQJZQM7CIYPUPR74QFDOXIXOCPYJM4FYJNYSD246",
-    "GCXBEOGXOVGCTQD2TJACKGNVYXQUBJMA7KR6MTSY7IJMBDYS7XBPOIHR",
-    "GCXBFSC4SCP64GWYOISK66SK4M4L7FEHUHXJ36OPJZ4UCULFZ33FWRHK",
-    "GCXBH3UTPFT3TQINDKEVFP6UXFHPZLJ2BHCDH3YZC7U6TXHOHMT65MP2",
-    "GCXBQTTPZ62RNMG4N3D6IIY7QOXFLLQWUMEHPBJBQJBJMCWNMOSYODHZ",
-    "GCXBUOTFKJGFABASITCJCDTX6LYY6T6DXFSEGRBDRZYYKEZAZ2LS6OFW",
-    "GCXCKGVTQXAIQKOOMZ2UIYIW6VT6TO5Q3GEKNOVZXTXXBXQ3Q34JXACD",
-    "GCXCNSJCAIO23LDGW3ZT7Z7FROK5ARKFIC6SYTLQPJBVO4JVYIOFXBTC",
-    "GCXDOB2UXX57OUTKFMXHWFEXIBJC4C44VS55UEDWHCZD6KUR3MQY7ACS",
-    "GCXE3MKCLEFV25QK6VN6TVIJN3E227LT6H2VJIY7C4N4KM4NCDIDSHOT",
-    "GCXEA5A525SMBR5SFS52J7L6RRHKCMJJXDCQKW7XQB4B6A44M4DOD42E",
-    "GCXED7RPNFBTNSKQXPEQ4FY64PN2JNZ74G3E6VQRKZR44OSAWKZZBQC2",
-    "GCXEW3UOPQUKPENZF4LAPOSWYD3OAQGO6LHDMTVFGYPKHR3OOZ7TSHIB",
-    "GCXEZSPTBRJAWPGC2Y75W6A5MYIBSGEVVLJUMWT4SKQ24IRFAFYC5UCW",
-    "GCXFGKFDBGBYYSPIGBSEF5VOVHMRZ6N73ZP3SUZGCL3XIB626POE4ZTT",
-    "GCXFKJOGLYI2HLWBB65XFCSIIFCGYT7Q72KZWKF6Q6MG5CIBKIFCARBM",
-    "GCXG2WE6T6747OFGBCWUFJYI2XMUUVHWLSLRUIRYQLLJAPDT6U4LTEMP",
-    "GCXG6BRCHTRVFJPWFT23XWRPBWRUKDY5BGCVLSLFNJ6SU3NWPOE6IMAC",
-    "GCXGJHAZMWD5AYFBNPCFT55LXIGQBAT6NHTFOIJSP6BZA3EEKAKZ2GOQ",
-    "GCXGPX3GYVRT7HF4A2K7LYGRCPU7DWWWAIOLBZDWNPKS7VHQ2ADZLGZN",
-    "GCXGSA2ZYCDCDJSCKUWP5P5K6E5N3BRT2NBWNMKPDQ22OFJBRIZBZUVM",
-    "GCXGYIYIMEYAWSIK3OSBEMNJLFXCFGVA2BGUITZC55Q3XMTBN455NNWL",
-    "GCXGYMPH5ATOOCFF2I5TAEQ2IRXVMPSMN3JJ2OZGHSMPCZRBLCAU2JSW",
-    "GCXH4A3OPG6GJJUGP3WXFWIZNQ4AYX5OBMS6J2GRWC5C66ZDLL4BOG4U",
-    "GCXHB6MERD2JLA3QL5FAQOQV4ZKMMV2YVPIKJEUW2TZHYHEPDC3LTCHC",
-    "GCXHJUWNL2GKJSZGD7O7KI35NZKJJPY4DXVP4ZJWYIA4X5YGYGQKLOEU",
-    "GCXHLBU4WMRV4WDGESRWEOAZZEM6NKZUPIU6TJMLEB7B3CRZ7K6WSLVC",
-    "GCXHTOQP4Z76GNJTLSVU3GPAPI436A5KMRTG2N4VPULTOTRS4EDCPIFB",
-    "GCXI5GLA4PKUUQA5DIRODPGIG2YYXSEDFXPD2KKBAI4YQEK3ZN2337SG",
-    "GCXJJ4ZHH6IWWL3DLKEQD7OPZRPMRCDOM2MT35FI7JDBXJU6RMTQBLXP",
-    "GCXJYBG6ER5QR3IDEJ6VFV4XIZDWVKOILK2IWI65U2GI2CBV2IB5XRT5",
-    "GCXKAMPSP56IYEU2G3QWCHTBQCB2ZSWRSS64HPUQYSBR37NNQZHME3WF",
-    "GCXKZQBKSEKQ5SCEKVHL7FPMIXLGPQFYPTQMSURMSGWJMDOL6A3GL3OR",
-    "GCXL2QRTUAY6J2E6XPZPX7R7ZVYGDFFGGCIVHL3DJ7OMK276D5ZSHM5C",
-    "GCXL446D52TD5Y7AMWZDHLXGA3JT46JSTRSTPWNGMEO2X25FD6MGFNUU",
-    "GCXLQ7UZX5TK3O6YX453FXFJOQF6XM7LGJOZJVAA4DQ5Q6Y7WDFTCLKC",
-    "GCXLUBQAIVXBDE5UXBT2AJV2IGSNTC5TY2W4LIS2HWD2SDVAYXDY2T2Q",
-    "GCXM2ZWM7DPVAKR7KBZXVQOH5I7IC3E3GYHWYOJOWIMTFNFDKT7EJVAY",
-    "GCXM4SKIA4QPCM4Z7LRNADDYDRAKWGJTQCQJGDG3NBE6QJJB7V53R36E",
-    "GCXNQCXCOJD4ETOLNLXINHS5NL5QVWB3RNYGMDZ7KEDWSKBRHIZHTPCU",
-    "GCXO7VZA4BQKN77ARF6MOTIDDWVXV7ZF53A7XH2HJNUEJ2UPBX2RGQCD",
-    "GCXOJK55F3UMCSRTQ7YMMMUUPTBOEW7JNMHVP5JAPXGILGSHC723O6Q4",
-    "GCXOSD5IQ5RTTPM7JUTJH7E542JPUOYXPCKLCXVDHUD2AHWFXNYUKVIT",
-    "GCXOXH6GDHO5Q3IMSZ36VKWOLOW3C6LEQ63EYL4MTU53MMN5DEUB2GLH",
-    "GCXPYXBOR2EJJUXIG4DODWE53MCAGEGMEZD26ZZDEC74VDMSZIMYXG7O",
-    "GCXQIQDKBM7OLWDHZ4BCFESOWYZVRAY7ELMPCI6QGBF3YBF5XDHP35XS",
-    "GCXQNT7W6S3W37JFNYYPLCE6GUJ57ZPKWACGOVO2B225MGMZTRS6C3A3",
-    "GCXTBPBCQMMA425FFY3EF7W6RYVTMMMQHNEBVZYLC2GDQT2VHMZXR4JY",
-    "GCXTS6OIIZ4GARXZ7Y5U2JXWNIJ2T6DWDS3RUTP6GYFCEZUK5E6PVTX2",
-    "GCXUQ4QGOZVLGUF5A6CQS7OWLG4RQ62HBNCBWG2LCRRQCPYECFQFA2OC",
-    "GCXUU2MBKGW5RA3AQI3Y3C6WQYESCEK437A2PT2WJSYZN6MNSC5V26TZ",
-    "GCXUUKY24DP4XB6IFRAY77Q5K4GU7BGRWXWF7WUBM2YIJJRE4KUZHOLB",
-    "GCXVEJNW54DOBQAJ6IEEWC34IA4D6MQH6QBZJWNWG4F3R5WHFSDODTJU",
-    "GCXVILKL6G4AVVNPASA5OCKVM5NUCOYCRBPRRLYUVQCKJKYTAQTEP22O",
-    "GCXVMB4RRTS57D53OYNLALCD7HOWOIJYDIEIWE44MUS5DYJHK7SVCNEA",
-    "GCXVY35DY6XTMASUN7Y64OUG7N2WSYGDJ5EQJCMYGR42B3VQU565SELL",
-    "GCXW4LCGMZCKT54SIS4CD2U7PLCHJGZYHN7FUN6CE6T74IAK6WVP4QQI",
-    "GCXW5726Y3P5RNN5MJASAZB6C26IBJIG322CKSMEZVACF3YGYL6RZEHS",
-    "GCXWTDIV3GDMBVPLHSUMYWHJU4GXMYI5SPJRDSVOYTRAOM5AQB4FVNKG",
-    "GCXWUTOWKBKELBUSOHDMDMEHG53W27BGMZYPFK2F4GMJWIFWOCSJQ6JA",
-    "GCXXEZH23BTWIUPDUA44N2DT3O6L3TUWKC5MUJCWRF5ZAAZBSQMYSBTC",
-    "GCXXPWZ36K2MA6U2TLLHJM7LSB2ED4YUKYXNF7YPUMTVS4BS6ZUR3FE4",
-    "GCXXW2PMIKI7EJFM4H22CLTGH2JOWATPQAM6CX7O7ZJXBKT6S7TNAXLZ",
-    "GCXXW4Z274LFFXR72Q7CTY4JNBGSZLP4DHQPGQAFM5GS2DB4HJURPVJX",
-    "GCXYA22MCSON67ZFNKIFTIDW2YAG4E6MCWARTA2XY4AEJGXP2ABW6J5Q",
-    "GCXYERUBF6QEYBBFKIXJL5KOZMOLEDGKDESK2KBPHADAUIJRA6JUDXLM",
-    "GCXYQRQJ5B54RRPVNVA3OVZQSVL3QYZOMLJY73MMKIHFYXYWVDXS7VBX",
-    "GCXYRDWLI2BCINI242SOVG5QVGWGSSS26GXD7FHKYF4EOVN7ZK3OMR3W",
-    "GCXYTPC6FUSHQEIBCBIZQO4IO7GI7BTEY3AK4JDLRZE2CISCNWHRECAY",
-    "GCXZBPMIEK3YI42TTVRTHK6LE4UFHVJG7U6NDHWU7E3PTIUWXXXB4TSP",
-    "GCXZXVX2PWZ5TOBIBIOYFTGRI2KTKVZWC6A7JGKFCHFLIM7BRWQQ3OHE",
-    "GCY25V4JC77YWKXFK7JC6VRESR7FOMQXTQOFSIXGPZMYUQ2FVHPZWRBB",
-    "GCY27VE66M62IZBTUJ7PGXIA6BSGX6YS5IEL5MTXMU5BHFDEVI72S26O",
-    "GCY2GFFYFZ73DESQTMEEGY2IQJTLVJB5CCWQJUFONDXYUJTNPFZYU7JP",
-    "GCY2PUVSIHCLDS4U26E43HS5FDIAU6WE5HTQVOEJC6HIDRCMNSBG47NM",
-    "GCY37KQHRKEDEW6GAOFLMTVO3UD6UGVFZJYA2BMG7NPHGFLZO5LOLEAD",
-    "GCY3AWGX4YLBCW2MNT5YEUGYDQ6RDBNWGY2V5P3BUTLDYLB4THGEKU53",
-    "GCY3HCISHTFYQRZB64ILH4ETPQWAVPUCL5A6LKP4NY3U3FKPADBLLNCL",
-    "GCY3IRWBYDQDIPZP253HAGA5SZPTV5DNQ2DKOWVPCJZ6PA7QDK5L7JK5",
-    "GCY3JV7BGQP2V7PFSBUXGLYWIXMGGDUDZIZVDTNWNG7ZRJ4KGRSKHIOD",
-    "GCY3R6QW2YIBZKTURHLWOWRV6G3BFKLJEMVC4OPLPIGKHXAOJO75M3ZP",
-    "GCY3T3ACZSN3V3HROBDO5SP7YDAF3NYBVAOXHY6VAOASSQBVBUTGUHL2",
-    "GCY3Y4TGHIQKFEIHFAZSCYN7LTSACOM2AYYM6O43634DJ44ZVW5L5JE5",
-    "GCY43IIWYTHLB6ROGJJ5SQFHYOXT5C6XHXQS44PTR7W6M36K2NNJFDSZ",
-    "GCY54XM7OQD2UON2MGADOA5BI56NGXRHAFXQFVDMHX7GMEQPSHDJWA5X",
-    "GCY5KW73PAK3MMDFFLLYHRCJKK2GFV6W4KRADEGPKVIEGJZWVSMHHUNI",
-    "GCY5UK27AAXRQ3KJL2GSC5ETRDAIYV667HMXYCUU3ZEZM63GFYLEIQSI",
-    "GCY6UPNCJNPYE4A74TU2CO25S5KDFR7H34BWT5MKKWH4IZB37V3YPB3J",
-    "GCY724DNIFQLRZGA3KOXAESEGXEQPDMKZ3IXKYUY3V2VTB5GBHTDQVMK",
-    "GCY74NNON2N3TFLE5RICRIDBRDPFRZ4NZ7REL3J4IYAINGSNKR2HTHFF",
-    "GCY7XHXYFXBJ2DXC5KBSPJ2UBJQJBYJ7QBB5KHXEPKQI2SBL77MARSOC",
-    "GCYA4Z2QL4DS6MOPO5LYGHA7O7TARHRX453QRTYTGDNBV6PRM4M73HI7",
-    "GCYAPXVBYKQRETG6D35VCWS46EVGFNYG2KOLHCZPYS55LBWAUMG4JVYX",
-    "GCYAVFWDVNO5S7TOZSCW7SIT34H6VBEPR3F4ASKOJPFQ5TQLJRQ7G2LP",
-    "GCYAWUZWDIKY2IZO46HXYVZDP5DKYL3TF5O2ZIKSBMK4HND6XDDZ52H6",
-    "GCYB62FNLOWXL2ZKPZS5CG2Q7DUJGYULLABNC62KG2V3MDDZKTSCW6LA",
-    "GCYB76O67HQVTU4YRC5BOU6LFWD6PCQCFUAE4TNWY7QCT77GW3J2IBAB",
-    "GCYBBWU5FIEEJQFNRJA42KXVE5YWMIHQBQASIKSPYECZXO5GRR744VRX",
-    "GCYBFBDOXYO77QCTCMLYTJHVDK5GDJCYE4UA2CODM3POGU5CFUT2SYCG",
-    "GCYBW5G5OGMMUS7FCIEVFL4VLOU7ZLRTQQHE6IXIA574CYNTPOS4FVAD",
-    "GCYBXCOWN4GEXGDGRVOXQQSXAYW3RJQFFBLCR3R4WPVPUPMIHBNIBOWS",
-    "GCYCEZNS3M3S4JJ6ITMRL46VPYV32LBRF2VEIUXVV5YBRSBZRBSXRGXZ",
-    "GCYCK4XP66BZLN3PRMORUVB5JTDBWZJPR75GS7GI6VQTOJLJNT2NSXTN",
-    "GCYCLMSN4JQK44WDQJMDIYSQIA2O42B67PUNNNQOUH2WSNVRZQVO5WR6",
-    "GCYCOGLLL4ZG54JPIM4BEHJUYH6BE35K3K62MMJZFQYLQDABXF7LQA2C",
-    "GCYCTX3BXBDGAIR5RYDBS35PPXGQ3PLZW75F4W7J6KIZVJIEJKTWSLVR",
-    "GCYCXQ4UTMUCAVZKAG3SCSNMNJC7DZIXDHUGSBLWFPFUSLN3JTESOSBI",
-    "GCYEMDC2ZGTXCTEJITWPP6I6OWDQSN7MHCS3MUR57JWPDNYUEWLGT6WJ",
-    "GCYF5PVMNZCHLQG76GFQUJHJYWCV4Y2LDCTSR65WYUPCPPUL5MUVUPAT",
-    "GCYFGLTASJZMRFGSCOIHSUYOUKYSM6QEHMEDTZ4H5HOROQYNDOMADATA",
-    "GCYFJBRTLB6NA7JVUN7DH3YXZVPNTPFATCXDAOPKJJ52QMXPZYUYIOTA",
-    "GCYGJNOTRMS6RSJNQNRJJ656TX3I6CARSTVARRSNAVTHFHRX2WV4RBTC",
-    "GCYH65PUM3IWS745KEZRCDJQDCC3LFJ3F2UTQMLUMYH7Z64QC5ZYQR5B",
-    "GCYHL4EJOTXXR43RAKY4ERUJHFHOKJCPEJQF22XKZF6X3FONYLEWPEPE",
-    "GCYHZQCSYYFNVU6USJQRQCO3QQGXFVTD45VEKTOVWYGWQEDWD7Z3PSCO",
-    "GCYIDIBDRFAH45W2VCJABPXDBXA2FUONLUUZKVEWDFBOAXXPJWNKV53O",
-    "GCYIPZ6YDK4IQZLKUNHCKONUDLMPKYVV7LBPY2RYNEU7FD33CPEAP5OG",
-    "GCYIRGNUYN42NJ2USFFIE7LUSF4FNK66BQ7IYQJCGCXX6WHYARAKUSDC",
-    "GCYJBIJ3N3Z4TQ3TJ5LRFCULSMGG6RY67GIQZCFQFA76NKV5MTCTJREQ",
-    "GCYJOXJ2JX3X2BFAI3NRM3Q5CG4ETIPVYWYF7WL4RDDYO45OVLBHEEBT",
-    "GCYKDVNDR5QXOV3ZXVTZLDLHTTABRJGF2KCLLTFJP5JNBGJ7DVYTCSHX",
-    "GCYKGLN34RMX53ZFFLHWB7QT74P6JOW6V7ZAZHS2SLCGUHNVRR5CCRDE",
-    "GCYKU2QBOCLJ6NMYZ7GNQJJR4KA5OUZXRRI4C2HI3KG6YO6YTV53OFD4",
-    "GCYLDAX6XLG2N3ULJE65J3VHYULVOY33EEIN64OH2QULMI6TBP5WIA62",
-    "GCYLLFLMSATX32YGZFNTJYJBJMYEVZHFMNM2CAHO2ANQ2FQOEU4ECYPE",
-    "GCYMDGKV4H4OOVCT3U2EOZB6XWNGHHIFE27C43TKHEO4HR74QAQAQ6ZL",
-    "GCYNEWCBDVCDCD2D6LVVLUWF7ZDITKS7FHJ3N3ZX5PGAN4Q3Q7TG2VGN",
-    "GCYNK23SDD4UWNLQROQ2U2A2MWNN7SMK7PB72Q4FD52LSMRXRYRTGL2M",
-    "GCYNLUPQ57HQT354QEPPD4UHBTJL5G3YTB2SC3XTBCYYSO4ZRQKKIPAY",
-    "GCYOBYLM3BGLFUFNMVTELO7JK6FF2GWR7MYSWG27X26DWEGZDOG2PSNJ",
-    "GCYOUTWAGRVC4E3A4JHKFRM2ELJQZLSGYWRDWM6E6IMIPBIC3BIF5NTJ",
-    "GCYP5CK43XDO3M5AIWEZK7FBW7C2OFMQ3UZKPTB2BZE7LBUXTQ3VIMOL",
-    "GCYPBDPL3R2LD5LH46FJEK6SVOYL5EA7UT4YPQDONFG7NZXNELDVVBTC",
-    "GCYPXWPG5HSIIBBCPL4NDBMWTPERYO3X2YK3FVKNGNDWY7N2W3LXQMFN",
-    "GCYQ7X2XOL7KB5RCCFAZAND4CXEMNKZZPNZNHFBZZJZQINFRB4P4KKNI",
-    "GCYQJWNP5Z73SRULAN73VL6TIRLNLGQE6Q6SKH6MPOOF7TS6ZCWJSFJT",
-    "GCYRO3R6LKPFZOG3N3ZC2ZWBQSVQY4MGMPMBICI4EEUXOFOULZNZMWZN",
-    "GCYRRWMG5W3J5Z6B2UC7SIF7JQUWMGC5MUSZLMJDG3QZJUC2NA44RZH3",
-    "GCYSBFIVEKWLEY23LO7NGGXKLHXI3OZTVJKHYV2ZJABYCF4ZTJXVWPTY",
-    "GCYSTKPV73COL5KBEZG4HXNMKQCLS5D7P5MKYWPLFAVHDXFXI2EWPX45",
-    "GCYT7RBZ3S6636LGVF3EKO7DQXVC6VKCRFG2OIKF74WVNZXB6I7KXQNT",
-    "GCYTJA3YQVFRDGAUYQA2EU27RYEGRISX6HO2XFCTAUMDHREL7LJXWT4P",
-    "GCYTMLEZQ7OO3STFNKIJVZT4G26S2PQDWJTM2H7HL2OOUEH3TUU2MSNA",
-    "GCYTNWEI5QODPNI6QTDUFKT2CA36KTAAOCB6PQ2UNMUE3TBNQNV5PIPX",
-    "GCYTUVXZG6NWLRB3NUBSVRPKJ5SNGWDNT26YTY5QKJH3TQGZ2ZEJCEZ6",
-    "GCYTYFJ5R4WOBS3FF7VOLLCBNF6V4HYADS6Y5OXH6D2EOUQGFQ5Y6Z5H",
-    "GCYU7M2DEERP7V7U6CKOZ2EPEGEVYM7IP33HBOTJFTT3H7VNLXLBCC6W",
-    "GCYUA6HDMYLUKW6MUNNV4AEHKVB4PFTDQPFS6ZJW4RPZWCHHTMFCR4DS",
-    "GCYW3CGFXWQL2RXRCIDCK37W53G2G2FU2VEVBG42NAVWHTBMTD4AG75R",
-    "GCYWADWUM5PNPG5UINPO6QAUMRTCL45IQ3NWCLURPUDLUQZ7AHWJWJMH",
-    "GCYWOFU6R4UPPTFVIUSQMFZ2DGYAO5DPXXEWIPSAH3FVPKBNH2ECCLJR",
-    "GCYWSRUEEAB5KODQYN3MD54HIGFS6VRKUVCIL25URN2JUAOAENW3IXRP",
-    "GCYWTDEOHOG54AEN4AGD3OJNIC4U55JFGPNSVGUFEQC47EOHOU2NAJ4A",
-    "GCYXM2O43HCDEOSPX4P5BUCVIDZPZSVQQGGQVIAX3FVGJWVNJHEIQHLB",
-    "GCYXXMYON2LZWH64VWSC5MJGASOGF67HYHCYSLTL2IBPYHSOUA57KFMX",
-    "GCYY5EHQP4WJW3LR3K4C35POCOP5D7EQ6M67RYVANXAMXVTZN3GU7YDJ",
-    "GCYYLWFNJBXA3X7FGWJRY4WEH7RVI5C25SQRO6MN6UE7TBWZC3MLOS7O",
-    "GCYYMLOQZSSJ3LG7QVOKOYGFIHBCTYZTM46YVOI7USY3RPIZUGDISTOK",
-    "GCYZBOAPTEQ6OLI3USD2MR4MAT2LN2A5FU3HDXARN2J6JM6LKUDVOPEA",
-    "GCYZVOL75V5R5Y3LAVFXQO4UGUP5ROHLGIGHYMLMKRKRG2IBHPKLMMAB",
-    "GCZ224UJM6CRTZO3NSPHU5RTIXBKU5GX744Q67MDSEDOVLH4FX4573XI",
-    "GCZ25LJ5ICJC3XO57WKSVLUHDJJNGIZXPEMXG32K6D7BRVRDVCFGS2XP",
-    "GCZ2AC2EFONB4ZZ7S253I2DS3JIKDQR63AKK6Q3P3ISGJUP5WOS6T4II",
-    "GCZ2HTVPYVEM4NXUITMTCNV2ZT2DMKUZICOMIU2YOTSQO2XCJUEZJJ3F",
-    "GCZ2MCNBXHEO3FYF2HMCBIFWGLUNT3Z7D5C4CA343OW7THHSMDHOLQRU",
-    "GCZ2RF4ANQVV64SLL2H6VTBPYE2X43BOJB2BNIXKMT3XO7TMFQLUVRTX",
-    "GCZ3AGQGAG6SGWI2XMAWSB5TXKAQALADVALU6H57TP2OD5PBDAIVMGAZ",
-    "GCZ3O7NGBDELKGNCOZ2PBV3TI657NKPDHJXEG4V4I2ZYF5EC64TFWR24",
-    "GCZ3OQCJR3KE7JFMLDQBBYDKFHOV6JYSEU2FRUTTD256FP7VGNHRCT5O",
-    "GCZ5ETHGBLXKQOMEEOTITWFSUVW7FFODNWJJYXXHOCNVOKFDB3B5G2KE",
-    "GCZ5TPZ37E7PP7N3K3ELGMLNELVQI6FAVH3XOKAFYNNQQOLKEOICMPUT",
-    "GCZ62B4RDVZEWIOD3ST3NZYIZ4TWORQ4DSETEMNZGKIYDQSBD2BADKRR",
-    "GCZ6LZT7EBCCOMMPJVKJYXD3VLQQBALUISF4C6A44I66DKOKJMIN25UY",
-    "GCZ6O6W56M3JAPEKTDF2QRYBLOQE6HTCBVJIYCUGNU6GATSSCAXQBFIA",
-    "GCZ73WXKEA7NRR6JJ5WH6PKQJV5VJITCSS2Z3UDBGHVKUOT24GOXERNE",
-    "GCZ76CHR7CUC4UONYSOH6TSPEUUHRMNZSUX7ZLLKLZSD6NWVIMZKXFOU",
-    "GCZAPZYNXOWQ3FWCKDX2ZUZO4I2GE36IUK7ORET6K46NYZ6FAOVZ27LQ",
-    "GCZAQP34IIP4IG3OGYEHTISULRAMJIJMULYSWGN2333FQ73MX3BKLE3G",
-    "GCZAZIJKGAMWT77GSACDIZBPA4AZDCKNYO7D4ZDY5MZAPULWZZYZ6CQA",
-    "GCZB5AAW72EYLAG5NEENOYCN24AATL3JZV7ODA7FPDVEYOKI3FKK2UM2",
-    "GCZB6Y3KTLCVX4GOO6WT4FZEJ7Y5ORN64KMQUTGKFGFCKE4KOLQ5FAPT",
-    "GCZB7MCC547DNJLIDZBI3WEIOHAO6SNPSEYEJUX5UJE6HSX4GG6XONNE",
-    "GCZBBM3QEKYB6NFNFMBNYJ5EYCQK53W3IUWLHITTDC4G2GBD7L6XX2OS",
-    "GCZCBI7Y5WLUPNUEUMVG3QNMKAXYFO3SLULJYXIU5YY5BCPCO4JZLBLK",
-    "GCZCMSGN7H2W4EVBRXAO76AARKRSC2FFHGJP4PF6YNJZGFTD7FJDU3OE",
-    "GCZD44NLINR6HMRIO4TAH3CH3ADCA4ZXZYOJ6OIP46NRMZWI3YUKTI47",
-    "GCZDBL6QKM4JG4RKONWMFFVU3WY6ZFQ6KY3H7WKLZSXY63DJCGL2EYDV",
-    "GCZDMZKFNSBJ4BJPW2M5SMD2EKXWS7XAKD3MJOZVZHFVIL23UX2UMGZG",
-    "GCZE3UMCUG6Y4BPA2267POPJ3R434KME3GFR2EKJ4MR4DJXVCMU7X5JX",
-    "GCZEGNEETNHF5KQQ5VLWYMLAY6IQCWCAWWHMO2MJIP2TJ44HG2UHNUUG",
-    "GCZEZFB7GSTZ2WFZQP5C24YYEHVQYHJJA3AZAUS5AOBP4X6YZ6SGZSYS",
-    "GCZF4LCV6LEC7MBZG775CSZPMMRXJA5JAM4GV2DEMWGPL4BH7YI5ZWJO",
-    "GCZFKLZ22CK475ZCE5XWDBPFTATJBKNPURXVZWRTW5AAUZHMO6HYSTOQ",
-    "GCZFM46QYZJQINEYWBUDWSS6NNAYDSAL4N35QWT3R5MY6DQ767W6JIAZ",
-    "GCZFNS7LG7N4NFZJJ3LH2RJBBYUUDTH4QFXNUWVWQXOSHU65O4FN4SPK",
-    "GCZGDE5NYLUP4PVUCHR2KCJAHJGYEM4D65AIVAKCZDTL32CJNC27RNDT",
-    "GCZGRDKXHBNZDM63WPQZ3Z2P2C3G4I3WSG56LHSNM4Q2SIHWHCYUZJPF",
-    "GCZHP6FAXSRCWOBK36PUVLNAJ36GSS2Z5JUZX454QKTC63N7UUGO2IW6",
-    "GCZHQUF42CQ7UZNU4FEKYD2QBN7HLA222NWDUSWJW4T2N4WL5YDQ3564",
-    "GCZILQHOXMBRBCVE377RWO7EPEI4IK2AYZ5NOK4PR457FFTYPNM2B4SP",
-    "GCZIYU34S2V3242V42NRUU3WSVVZAJORI7LYXIER74QJEUDX4Q5LOZV4",
-    "GCZJL27QAEV3ZYUSZAQPZI57YFWJ4ORG3RBYDXBN2UQVADK7WI72APJM",
-    "GCZJULQAZF5TUV3A3THA4ZEF2EA7RVQU6Y4N5IYW4AA2KF7WD5AUOFSS",
-    "GCZJWITUDCCWQ7QELJQISJJK5PWCRYPFCY5ZXYR247DQWBINYLQBV3KI",
-    "GCZK6FK6GXYO7ZBBGY2OJQD7ATRAYMWR3F7Q3F7J6TOVQU2X2ICV4DAY",
-    "GCZKDX4N5VX6743ETIIGBDI53M3MXEY7DA5NBZXQLS6SNN53SBVOOCCM",
-    "GCZKFAGXZA3IFSFPA5JKFBRAOQFIK7XNOCRJ3QUEYW5HDCVH6VZQQ5WA",
-    "GCZL6KDIQCDZ6MOLHCKLEINC762NKQAVR2FDI4Y5SDVOQAEL5QENUODE",
-    "GCZLIH5J3L3YYTQWMXHWPEJ2RDXGSBVUATE2AHZEDUKKA6H3LDH5DOGE",
-    "GCZLOB4CDA2PBETAELRAJU5WNLMYB6EOLNL5OVN6GSVJFBXF3ACBX62K",
-    "GCZLWPLQH22MJKEUQVRGQQ46D52YLQKMM2FCUV4RTOCBCVY5BFJ35EO4",
-    "GCZMNKP2L6LPEHXLTUBBPDN7T3JPQMZKQCH6CB42N25UKC5XJVXFONHW",
-    "GCZMVHNYAW2PXGROURRIFD4RSMXYNNC6UFN5L4ARYOIA4E65Z23U6NBM",
-    "GCZMWK66PKXUCMIPLFODSXF7RK2LM7K6ESYOLFH5B7BEEJXYPC4K5VFB",
-    "GCZN4YO6WJYZOWZ7UE4HTSYEFJZB3QSQSCVPPZNTCCPNU3KZRZUSQ5SI",
-    "GCZNF24HPMYTV6NOEHI7Q5RJFFUI23JKUKY3H3XTQAFBQIBOHD5OXG3B",
-    "GCZNMH2ZZFMVCONU6OXQEIYGXVYNACKTWPCSESKLMDAFEKQSQS4ZZCYP",
-    "GCZOD4F7JP3635ZSTO76OBPYBLGPKHMXCZ7ITHRQO73JE4R3Y244QDDE",
-    "GCZOHTGLFVWRUZFWAEIXZRX2IA67PLXFTEGI7MEXQVXLMZFE2GJZFK2P",
-    "GCZPCLWPV4F6XGBZ6EM47IKLAKWXTM7TBC3RLDOZI7YXC55J2EAEKV2C",
-    "GCZPGD6ABNPQBZTOEM7ZXH7NEJYM7D3DOCTEXYBHEQWJAQMVFPMGRC5O",
-    "GCZPIAT7AUIBFG2GR32CJE2FI75JKJ2QPVXWI3D3GIRAGCPS6YWFIINI",
-    "GCZPY25GPTEZFCIECHB6N6LGRTC3B6E7KNN6AN2FRVUSTTPCRABAMLSZ",
-    "GCZPYI4DNMULMZAQXR2L3LNR6SAFVCJKLQCMUD232BVULOFQBIENPM6P",
-    "GCZQCQJNPXCTNUMXUNTW2CWQD2WMCNT3L6JH6WG45CABOZ5SUDZ4ZG2C",
-    "GCZQGALA62IQZ5M37DH7ZLC6SEICWWYDAXNYVJF2ZVJF3NSLLN4Y7KX5",
-    "GCZQZFIZKX37RILJ2NBELAYPGCQKSZ3LEV6S6KE4F5GFIGYFHZ6PZI2K",
-    "GCZR65HOXKMTF2TO46YHNXYWUJS7SZL3QJY57JAT57ASW72BWPXR25B6",
-    "GCZRTXW6TXNWOV7G6F5PJKECIFZTRQCUK4SA5SS5WTWRUPIHGZSZAFZ3",
-    "GCZS2TQKUH4CYZ5O52ZRZMPH4MIKYYPWYQWEI3OPTU53IIECLGQIEX5E",
-    "GCZSPUQPN2J5HDDJCH2XYHXHGZPPHINIUGY2NB4UTLHSI7UIQPWCN6HV",
-    "GCZSRHDNJ3WXW2DVQ3OOECHNSBGZSYA6UVR4MEIBXBNXPSKWO4IWPEET",
-    "GCZT2IVU2PC7V62NXCKLSGL73HOWMKRPAEZ2XMXZJBJA3TQIP4RRQ3YI",
-    "GCZT2LQNKDNNNSZFW6MEJLDLUFOWS3OAEHFVFNWS4MWG72YHMB2CBDJR",
-    "GCZTDKWRRVFWJGWM572R3QPOU67K5RMVS2RKAEUWFEP6MOKD565A74RD",
-    "GCZTHDR2R2ZOPFICE3CMLGTMBXC7AZASVEVFLAEHZE4W6ERJX7NJGACX",
-    "GCZTJTSX6XKNSZJS7HWTSW4JPOOFO46XFNHFU2JQHX2DMLJT373UEXOV",
-    "GCZTL3TJ33D6MCTSOCCM5DEASE3WNACYVTV77XLN6WMTM7IKIPHGNDMH",
-    "GCZU7Y5CIUVD4PI4BJSHROJQOC2MPMFAKGWDL2VTEB32VMRMAOEVKOXH",
-    "GCZUE44CXAHFBOIQPIPT7VPQTCAYAMECXRI4YZBAMUVJFLVP6V4DQXRP",
-    "GCZVDAFYUFYH6QFZUVVWGA6HC4QRECFENG54S34L4WDJQ2CAQ3TYQEPZ",
-    "GCZVKH6SVITLV4M2GMLXNIMLBKZJ5HPARSF7QWC6MQF4SHCDY2SFD2S5",
-    "GCZVSF4CNQ35YS4WVCUGUWSASQCIZQCIFPDINUHR237FRKYKHBFQ4QVJ",
-    "GCZW65CDLAULMZK3ZOYXTJNV32MRIIIT4HS4N6YMDX5GIYK6KWNYK6PU",
-    "GCZWHFYT3PCB22DAGK7PIKWOJUWFRWMK5TTYW3WLJ7I6WAA2NRST6JHC",
-    "GCZWM4E4UA36FWYYFY626PKNWFAD3PERYJ2GCCNE2ONQGEC4PQKAUQSI",
-    "GCZWQVQCNAWMSZ4MFSM6B7CAPMEFRIW2DU6L4MEG2JS2LWTRMOS4VCR6",
-    "GCZWZ7YMAHPRP6GRMMBSCZBR3CFS3IBBJHQYRLEEHOY27QXZPAOU36T4",
-    "GCZXDRUA3E3ZHC4BSL7II5JM2YSZAOIODAHYQMLXUBUGTG3BD5DL32GZ",
-    "GCZY3P4K7W64MKAI7R2BA4DS7WAFIJMI7XE2S3FAGZLPH3DGQYQ6RXLM",
-    "GCZY7DJN76OFFZW3QOM4RDFXN24LYSZSB4XOTGMURZDBSC3NI2V4YSBI",
-    "GCZYS67ZXKEWYGCDN4D436KM4QLTEZPBGFSOBBENKRUPOMQ5WCUTYF76",
-    "GCZYZKVIUAA4WUQQA32OIPEW3SJ7TZBLT5O5BWYWDU7QL2Z2XRFOSPMH",
-    "GCZZ3ACUYLTVMFH4ZSNRIUXEMIXPFFKUFP7R6KTGLWONJDBLON5D5TST",
-    "GCZZMMTMYG3P6WGFDOEX6UHZQ2UWWJGS2YMAA2QYTM5YOAP3XJTAPODX",
-    "GCZZSE3HYD4BVII5YE7ODCSDPHPCDHTQCNYN7J6CYU5RPBFNMSD42H7F",
-    "GCZZSLDAXC3RSQVKOUJZZOY2MBD3CF7T3HDZKF4AR4QM4UX5SSAZHM56",
-    "GCZZT2QUOVKKI6C6RWYFY6PRJHRXPFRSFZELKAQ4EZHCP6ATLGGT76N5",
-    "GD227SK4XHVOCETKJ33PPW2ZYXFHC6HVRN4TLHL2WMLCYA37CE2T7AK7",
-    "GD22USCS7TUROM6YGPWDMRD47NMBDQEOD4KIDTBV3DSEYYMAE4WXNW7R",
-    "GD23AWG7WKXPMTXXUZUDWJPM2MPEAVZVG27QJNT5OPCHJHNIJZKNNO3I",
-    "GD23DNAAESKGKW2P32W7OMYHCVB63OQFTFRAGIX2M6SP3J25BOARWTHY",
-    "GD23OEO3CI347UILMGUJFSHGI6NFZRURWQS25ZI6VRL3JAQKPHEOSDSQ",
-    "GD23SZKONTA343NEVZZFCWNT7WDOZIVM7XYYMS4J5KZWR6BHUM7LISIT",
-    "GD23U7735XP6ZSDNWPPHASVHXSSFKSZXQ2ZP5OL4GVNSBLC6TY3WGXOP",
-    "GD244JC7AN2TUZ35RFVDUWHDL5YPXK5X4EMRQO3V4J4ZMFYIHYWSWISS",
-    "GD24DLPA7HRXRGVNPUL3FBYBAQ4LXJQUBQQ2PXJ7N4JBU744RH5EZKNB",
-    "GD24JERYHDFGBWXBAASIU7DX3HA77M5JOOYEVHHP33ADGZX5QT4LVVPV",
-    "GD24MNVX4ZQW6GCICUVVLFBMWENPHPP3UOCCTSDEH2SDON5KPSMZGFWK",
-    "GD256E7NIJXSQTBI4N3SMPSUQSRCNSJIRMFGPIP6VNWQI4PXL7QX55JQ",
-    "GD25FGOLXGDYDIGEFMPFD6DBAKJNDRSFKHSYFIUIANRLE5PN3726JA2L",
-    "GD267VWG2HS62VWOJIIIJDOTKIUKXKSTB2GTHRJFZLSIHVAIAIFANXXW",
-    "GD26CSOR4F4DT4IVJS3KB7SBO4Y6LMWWE5D4K7M6VMQF4TMRY7R54RHH",
-    "GD2734Z2RXGO3CYNPTANQBUXFV6PQGNBPNMYCWUNKDFACD2W5DAXBDEN",
-    "GD27BRWADMSSFHZOXSAXIR66BKDNQQ67GD6MKQQJKUYUPLQZBF2XOJWA",
-    "GD27ETRL2FBUOJAVXQRHWOKYTECXLDSTBWOQZBEKJI5Y24KN3FI2PN63",
-    "GD27PX3IKEI66FR7CXRELH42XPHENEAMZN6L4R4DX5N3PS4VUYJEWYNN",
-    "GD2A3IIKVW24TBB66465SE2BGY7A77NHGNHEIURKIUPUZKRJNZ3O2NEL",
-    "GD2A6MDOY775PRKSRRWIZCJPBHW4LDT5YQ7G5FVINOPB3CD4ZSXBHODL",
-    "GD2ACOFBZ6TN32Y6YP5K6QEKUQIAOBWBBPPRVXEFBL7DJC73OW53JWZS",
-    "GD2AV4ZPCFA25NLNHDVLX4RBRC4HDVOTB3JXQDXYZI2ECXJMOQOZEISO",
-    "GD2AYBVOY4MAA6YP3WB3OQNJIJZHTHOQ7D5DTD7MOTGJNE4FOROTSR24",
-    "GD2BVACV4JR3LLED7KTFQIX3UKC4WFP46VK7JHSQHROOSWYAXEOJD5O3",
-    "GD2C2MIZ7WVPEUBGTSBTXWG32P3232FM5WO4YATQSKLQIO6NE667BYPR",
-    "GD2C7U7ASXYTH4ZFZCLCJ3S6G3MQ4VNQNBT6WUEBGLKFALU3ZK34H4TJ",
-    "GD2CIH7IY5MIWMDLQ77UO6OGHLQOQOMOH3FGWVDWVWXRATTDTHEOV7UE",
-    "GD2CJ4MAPHOY6GCPEEWQVZ7GPUMT6WRXSSGOTX362FZFP5KJ4Y7PCRFX",
-    "GD2CLQORNMHGAFBY4VA7ENPKS6HOE4O4ZZW7TUKMXIIAMSFXYFJFBXLM",
-    "GD2DGNXZHMTRWZCV7VTZ5GIROVB2NNIYXHM3UIOCHJLJG2IV5ZRZ4HNT",
-    "GD2DV5G7AQHJONKR2YYQ3P6VZRW4OASMPCSBPFFRAKWDMHYAM7SV2WA6",
-    "GD2EULB62TMLKLWDUDH7OMTCZF7I5DQUC3XQGT4XHH2IRFTYYLONLXLM",
-    "GD2F4JH2EZ5QJKVA22ZNNDGXYZRDX6OZ5EQT6WFV4HH5F3QUF2NK2OFN",
-    "GD2FRTEQUWW5FQY62GVL7RDPPR5YOBP2TQOTFV234CEO6OAJYBUQKCMY",
-    "GD2GM6ZK6GXYYCBQMS4ATBXW24HCXC57HKKFVNGKHXQ64R7AA4DTHVIN",
-    "GD2H7EXSLBLJPJTQ5N7EQUYWY66F5IVCOM4NCUR7OMAO2YS7UHYYDMJB",
-    "GD2HOBAYJWQ6WO2VB3HLGYDISBWXOVTHBWHOP23OT2A2C5UVODJW7PM6",
-    "GD2IWSJFUVQQWI5YSZHI67KZVN4ETUGQTRUFXADRS3E6I7TNEMOP3ITQ",
-    "GD2IYBCLPB3GSN36YOPK45IY7BPORCPWGGHXPKVLETEQK45VZBIJXE22",
-    "GD2JLL4LTMOBNB7NEK35HZVFBNCJC5FSMCJRSBPNS6TJ7NW6K3V4AJSZ",
-    "GD2JOXLKPMLU57RSX77BDL4S3IVQ7GVZ6JNXDCDRJUZPFUKLMMVEETZL",
-    "GD2K7JBS5KUHDLH5RWQCCVV7JUNMDE4ELGJNKGVZN3TWUKHRFMM42BI7",
-    "GD2KKAXMLYYZBSKMJYXZLJUXMSIY6BKF4KQOYSWEURJFMQZFDPPUN2ZM",
-    "GD2L2K3KGAUOER7GO4MEPTQGL3LO5T7EHEBIEHEU2ZCM6SCRQYIEQ6L2",
-    "GD2L5S722K5QEDS7FSCNYLFOHLJ646GVYGTMNOA6H66KV5AY7YMCEJSD",
-    "GD2L74MFCZ4D54NADJ6FQZNCMZ5TRIZO5M6MVML27XFTA35X4YIIQLUA",
-    "GD2LFQCS7RMEYC62XJQER6Q3J4ZPGCZMAD5RD46IGSULLOJAJE5JW4RQ",
-    "GD2LILT44I6OV7GDS4EMJL6E66LZSOA4JRTKAFAVXT5NNS5I5UJUZ2GN",
-    "GD2LLJRPCAMZENW6OLDZ763UASORXKJ53BJYVS4DPAQJLUDWBFAAABDC",
-    "GD2LNDVHBOH4MBWNHJYVRK3KA3OZHZU7KYO662LRL32BSO7BNEX7USDT",
-    "GD2LRZ3KBAUHKYDGFNKOCWUY26ZRXO6B2BCC7N66ZMQOYZXHJIHTMXLM",
-    "GD2LYKPDASCLSIUOJ4ICHHURTVIKUHAUPGS5OSS6RJ7NM2MWYGK4ZXZ7",
-    "GD2MXMOR5XS66UQPM42OL5FI5KZHXZYO774366XFVK4CROFXGNGC2EMW",
-    "GD2NB2QOJKT4JOWKPNUW4T7R2JBNQSXI3XEIO7B4VTES44N4KSR26LSZ",
-    "GD2NDD3GLGIBBTIUH72NB3AAL367C6ZYULFO7QJQKW6NB7H4QORDHDLD",
-    "GD2OEEGM3H7HAJSVXCD7RGO4LIXW4L4UVVWHWFDHD4B3VMPWWVC5OAUD",
-    "GD2OPWBHB43MAKFFUUZORBXY4PXSRXGTHSVQYWKBLDUGBQWUKBFR5F2T",
-    "GD2OQHJHVVBSAOVTLTPHC4FK63IEFXHZITOCDVAXMNU7ZWVMYJYOMGWY",
-    "GD2OZMNGK2PKMAHP2EPGUDPUW6MI2MKYI4P5SU4R5SVJDVA2276Q4HS3",
-    "GD2PGBFP6UWVQ4VHGTCOUSWZAJL5B46GYYMHMRFD7RKKIBTKHHJJCTFB",
-    "GD2PGJ7Q6TKMSKBNCMWPXUKPD327NTS7MOZGOQSM3CHZWFHG6WPMTDZQ",
-    "GD2Q5GOP3P62OPS3YWHU6NYH7AN56Y4BGRLGY2IRQ4XFECHLGXW5WAVE",
-    "GD2QART3DFTQSLVBG3SPHIZMFIUHRXI6OKJDGJUV74VX6OIC3PAOUTFX",
-    "GD2QDJK7LE67PFHQF4TL4UQBJWZI2EJNY7UXFX24U2O6K57TU4EDE6HW",
-    "GD2R2JCEXFCQL43CAEFVQN4S7W5I2PKPTUOYOW445ZQV7LJ4GVXTAAZT",
-    "GD2RBXIMQ3ZRL2MXK4HKU3GGTYQ75KY2ZW56KTF6F7LR3DF54DMI3BLD",
-    "GD2RE3X4SAOPQ6BTZXU5PWGOPSQYUNE2R6VZY4P5DZXAWK6UKKBQMMJO",
-    "GD2RHMZNBEXX3HAF4ACWNT27LKITZWUH5GTJSZAAHAVDCORL6DLBZEE3",
-    "GD2RLQGFV6J76ME7VXETZY6V3STCQOYC2OWG7CFFKLSYPFFMBZ2UQSU2",
-    "GD2RRX6BKVTORZ6RIMBLWFVUOAYOLTS2QFJQUQPXLI3PBHR3TMLQNGZX",
-    "GD2RTFNNWWI5JOF5Y3B7EK2QADI7SUPKNB6IJGXEXBERXTAO5GJ6QNNY",
-    "GD2S2BS7PPYOLFPSMKS4NIJQFRTYX7DFF5N5QNQ3T6IJAQYNFIFT2HDM",
-    "GD2SI24TCWVHVP54ASDEPSTQTOA5GWLLA4OE7SBAMIJWGOL3MJT2QA23",
-    "GD2UV6NPOTGZZMMWCYGXD7UDATG6MTGBZW3WDE3YV4O5IODQ6UTSOYJN",
-    "GD2V4XRWOUX24IHU2BHRJMAB3XPH7YND6HKMZCJ24MPJXQJPJ4NE3DZJ",
-    "GD2VGQ3SZ3VQ4HQ7YDLEXZSUK4MS5NT7YOA4N33CPVRDDRYB7B6PAUQY",
-    "GD2VHPIXINDMNUOYKKOCFTS5GJH3UC2RZ4BA34WURMYEOCYMCPECBRAS",
-    "GD2VLSL5JEX7PEELU63FZT5AHNYJPINZCSCNFTUUMOPG2C7WAVQPEAO7",
-    "GD2VWVSQ5FYFY3WUF4M5AUHFY2YZX3PAOQXXQIYQKLWJJO5NT4BS2KKV",
-    "GD2W3WG73ETXQNYGPCA6RLXAVXTB66N7VGS4677BFTPLM6FF3Z3HG55J",
-    "GD2W6N5EL3AVPKD7ZQ7XWJLKCG5QJYY56GJQIRWJAUHWYHOUDIRPSJAP",
-    "GD2WAAWCK7VFDNMQ2A546IN6WESMQIERVIHITT3KTHJ7AN3452LWQGWL",
-    "GD2X2RAP76I67EIRVICZ53ZFHU7K6U4WFTEL553D7PPW2TXQY2BLUS6B",
-    "GD2X3CONVEW3DEHPFRAEF6QIXLPI7XCB7ZBLSJVMGL4D43BU3MLHQ7KK",
-    "GD2XASUIKPEADUKEXGDLC47OSAKTQTDMF4VD3DLJKOEMGDB5YFXOC7YE",
-    "GD2XBAQWGYK2NND34CBDDZJIF52NGLKLH7X6U63ETCOPIRMGQOPWAMFP",
-    "GD2XHZS6GVQI3FDQUSIPPQRZAUR5BMOQF6T6VE36NO4THGSSVTIQXRLK",
-    "GD2XKSZM5MLD6EFHVVP43YEZSOQ6HIU46PCYWV7FJAKGS4VIDREDNW37",
-    "GD2XKT6CHEUS57ZZG2MZ4RF6WO6E6D7AFI3OV2C3ZZKNCWDABG74WSJ2",
-    "GD2XPZQUYOAMDQORCJSGFMUPRINFRICQQSRVGQKBP4DVITWDK4XY6OZ3",
-    "GD2XS7KHBMTFTIDTF77DKSUKZLO4UCAA3ZVLZGVEX6OQLVRG7EFM2X6X",
-    "GD2Y5F4IQTLQPCQ7KX2D3G7TPSOKNJBLFEASENMYR46ZX4QRD66DLGPT",
-    "GD2YD32CVP4HYVXLGO42KXOFVSK56E4PBEN6W3HZGWIS7KECI3TMJAVO",
-    "GD2YQI3PFDWMDFUVYZO36AN5JDTP7YQLEAUWID2C3EBISWZQHUAZBFJR",
-    "GD2ZFRWEREJ4D67UTU6PX5XX7FIMHKZEWVNS4RXPIYSKKZJOPVX554C4",
-    "GD2ZKGBFPFRBGUWSTVSTTRMJ7AAAW2FJF4K3VL7C3GKSRDM2MRHSU4JA",
-    "GD325BZTUPEZGQ2EPI7FLQYAGTT5V4Q3Z552ON2YFXGPBPGPPNMYNKDJ",
-    "GD32R66IE5IMX3HU5UG3AW44KPHCC5ED3A4ZUWKNSLV2CB5NRZJ7YNME",
-    "GD32VQO2PRQQ3OKTFQLZIZ5G6NADA7DRZ2ZZIRZ47PDRY5N6L24M6Q3V",
-    "GD32Y7H4ITLJWJXNJOICXQGJLT6RE5TQTSG2EGL3TXEGOAVZVQ5PH4YH",
-    "GD34CA5KSZERZQFYB545XASMRKFNDUNWR6HCK2LLSOXN46JF2BQCJ2PM",
-    "GD34DOLV5V7LZGQUSF2BHQ6BATS7TFDL2AMZDQGICLK3CBG4QEEBOTLS",
-    "GD34EC3K3YAEMSVMNS4QOQFITWFGYQD4QD7KNA3SZTL2NF3ITSUULSGY",
-    "GD34KJMD7C2YNXYOQO5MLJYDLLVO72QOH42PAVOXQD5UXUG6J2ZIWEFF",
-    "GD34UZDIE3LOS2MPFARVXDOWUHBXGE7EXQFXJF5XAFT2YCAFMKFI5LTK",
-    "GD34YIWZQKONKXOUVYUPRBBYO5LSVBPOUG4CCMNWY2UEDNT3TMR72ICP",
-    "GD35ZU2F5LJZOV2UHDVIXGVWQW5QBAX2I6F24FPCYOJHO2ESLYH4MHE7",
-    "GD36J5DCDXVIIF2GVB2M6DTPHP6BJTQ7PJTDXE7DHRJMNNFBV6IHEHCV",
-    "GD36LLGBR7D7ZB4FAZYGESJWQ35AFALZGDCBVMNSMPU7MQAE42BDACEX",
-    "GD36OWFXRFKK5B2VOS2322PJVHNLGE6YDE5SF2ZLP54EH4MYTQ3L3YAI",
-    "GD37CWVM7APZXNDB5CNJNXYAWJVVJDFYFSGMWI2IEIUOZIKIZ7M2SINO",
-    "GD37FJ43OQWM6ZQOMKVIQGQNNCJAIQH6QJ36YZ5XBUYRSA7H56RARYH5",
-    "GD37NGHZJKKENJ5MT5QBGGFBVNNMC2WBYB2D5NF74FAWAKQ5I47IWMML",
-    "GD37V6BATDVVT67F5ISOXJTGX2QS6R6WKJEUYKSP4ZUYKTL63TDNC4HP",
-    "GD37ZBGZALL74JGTWP7T3DEMS7IZF2E3BYFWVISKUSESOWPXWZCDFLCA",
-    "GD3A3OVO7CNP23GETGSPUXLD6AOEHWFWXSNOCZMIQT3V7ZJMXWQR2STL",
-    "GD3A6O7NMQQ6WVQJIK77SCR2ESHDUOOBBB4CJ5UUU4YUK75KPDMEOURD",
-    "GD3AFDFBPEGOQO3L5NN3JYM6VI3TZXIAH3CZFO6AZUZIN6FSFA3PZQ6I",
-    "GD3APLLWCICO6D7NI4MA7HLEXU5AG4OWTLANOOMPJ5Q4XFVTIWWBDPCX",
-    "GD3BFPMMXZKB6M5ILCN3CLURHCP4OCIJPJHBWPZG3RDU2HIGFEEBAJR4",
-    "GD3BQHQBDDE52OSNRYJ2EPAGA6YLTAIRIAR4NPM5GO6N6DCJBQWXIBDI",
-    "GD3BX4Q2WMGEMG347PDWS2Q2AWEINAQ57YMYDC2EA7ZYM7QG52OLB7YG",
-    "GD3CAOV7KJZKOJAUBZLICC3ENW6KW4XCOEXPSHWZATQVBE7ILJKCGBA4",
-    "GD3CRNSK37P7LUJTP3XCQ2N634DSDH6ASJBAXSERXYMSUUFYONXDH2TC",
-    "GD3CURPFSQHUKMVRIEGE4DFQIRJUW3B3Y7I2MKL3UIY6UXQW7GB2O3MV",
-    "GD3CXUY77KQU7O2XL5AE6J72GBCZB7IFTBHK2WEGI7UVFCXRQONIG6PI",
-    "GD3CZFLHUPSWX4WDVITTX2GUEUWQEGNBOFSHGPMQ56OXCHHC5NQ6G7DE",
-    "GD3DKO5OFSNNHVGE2U6BI6XCW54R3J64VYKZKBFFURWHZ56U4JQN62JP",
-    "GD3EF272CQAA4QJPOGT7RJOVDVRPWVG42TTNHKAAXMRVZBOYI3EJDFHH",
-    "GD3EIKHOIBM2JNLBJRU5Q63PIPT5HY25KYO7TWJEWZB4Q6YNREQYXQK7",
-    "GD3EUJ7O2BTHKDZBLKALXT5PWPIBPVRMR3GVMZEEMAA46NSUBVXM3SE2",
-    "GD3FXCLZPL33YD72ZZJAVZ36GZXVH73R6KD2ERVN6XVR2EQN2I7TDXPH",
-    "GD3GS7RSEWXS5ITWEYM5G6HXJSDKNOCVDB4ZFUWN2MJZRCJY2WSULPTG",
-    "GD3GVTFMOELAVBAICMVSHHC3WYQBR6SLRTWZDJEPPEENKW4OSHEG4KAF",
-    "GD3HCVJRQUDJMQG2FE2UWYDH6AD4N4NH4WUFF4WQIV7TONHSENLVI464",
-    "GD3HJCGRRZJVBBHOINYFPHBZYWZJUO3AYWGUDRJCBRUCBRVKIHZXZQZM",
-    "GD3HLOV3TBNSUFN3MZE6YCQRJMYPFQKSVL7KG5VQ5SJ4CXZCY7ADEQK4",
-    "GD3HNZFIZNBDAWKCRAZ5MJX56WER6PMSHRMDVNPJCT6X3MGYHXASJIJW",
-    "GD3ILPYBJ26F6QS7MVOEDL4PDPKDMMBOEGHXL6OSR5DKDKNSKXDP36VN",
-    "GD3JAMAHA7SUEBHFVL5DLCSPUWPP7O4KOKC26UC7KODCPTCWON4SBCSY",
-    "GD3JLXSJTFK3NQEATPTPUQUCUOX64XSZKQIAHLWCFL2JF6K6KH54VZR6",
-    "GD3KZYDXCOWZZCRNEACFIVT7LXSP3URWGZQ6W625L3HR244RG64GCKIP",
-    "GD3L5YF7VJDWPDEV6KYJW3H26RUOY5PYEGXA6PJL7OOHPL76QLBAQQBM",
-    "GD3LXHJRFLAIGBVHVIXJUMIGZLN2NN654AMRZRYN6PV6QAW26BTD3WVW",
-    "GD3LYAKI2WY5FV3FXP6YK2Q7SHCJBCAJK3VI44LEJFOS42LX3CUJIEQS",
-    "GD3M25AEIFV5GAJJO5KDX4NVGY6H3FQMEFQYJ6TUYFBMKLO7MOCETDTY",
-    "GD3M4P2EF2VBNIK7AH3PP2ETXWORR46GTQNP45XID5CGEBITA36B367P",
-    "GD3MLZKWFPLRVLAHCEBU76GNI3CFLWNKCFTQHQGKBJ46UHH73IIUWW4M",
-    "GD3MUB6HMVCNL2DLESJH4TI75IZVRQSOAHPTGS3SN63PJRX2ZNV2CX47",
-    "GD3N2H2OBG4ZQQS2LCJYYNP75VKRK7BLGZ426YY7NUDQGET3O43QXAUS",
-    "GD3NB5UMLBAAL6YUASWQSWS2G6N65YWODV2RFH44DRDFLXLNRZA654VT",
-    "GD3NCPPLKJ365Y47IBINRWIJ3CBGNTADDVONNDX7NYQNEZGUZNSGFGYQ",
-    "GD3NRJHCSEPPN477NVSSHOGPDUJKPXBWMH5J5DG6ACBJS4ET43DARV6X",
-    "GD3O3KSUNGVHMGF3PL3YV5RL6DVYM7G5F3LQMM5FNA2LTC5DEUK7JQ6Z",
-    "GD3PQODSFZPUZJGDA46Y5NZVJVEHNJD7NO7OMIF7QX7QB4AUFHOHLAHE",
-    "GD3Q2MW6257CNOCPT7RRQDTQDQUZA4NYGWXAOB2V3AIBY7NWFGQP7SVL",
-    "GD3QCSSUIG2FPEDG4KOIPO4YPRX4HJRYZRGPBUKR37M5I6IRUHUND32D",
-    "GD3QT55Q62NCNBZRZ6TOFKALUQQJT7HUYW4O3342DGHXSLCHC4TTEI5C",
-    "GD3QUXAUFYIBZ5E3KR7RCEJ5AEGXJ7U4FYNUQ35OSDLV3HKHRWROEBPS",
-    "GD3S37CQSZO3JHAPDCFZCHM3GK2HUBCLWUF5D7MSD3SMDXSYXSHYUBER",
-    "GD3SEAQKSYX6NP7LMIPWMLYBMX27K6MT5A35RK5VQ3XPXNNRFLC6UOAB",
-    "GD3TEGJ2JNVLWVMCXCIF62PB5OH34H2XTXMFEFL3KNZWEZ7UKENDXCR6",
-    "GD3TIJYIXES3MHUIHHJB6JNK47LDCKTZ6GEY2SEUZDD2NZV6B2WQOCFK",
-    "GD3TQUM7H3OUACIUDU5WK7JSYOO74BUDK7HLSPNXAED57WRJW6H4Z2G6",
-    "GD3TWM6AT4YVBWGWJQ5T3JCXDSINIJUELWERVZX4ZD7NWJ3O5QMY4LVY",
-    "GD3U6PRKSY65A2IKC7B3DGCWHSCWTHJU24OL4T6IVNEEB5XLMJG64OFZ",
-    "GD3UE3BVBIG7TZ24KAA5S6NVRXKKXQLYCYVDFQTA2XFENSIKEQ5QCYYJ",
-    "GD3UWPOQRRG2VTRIY3AVBJ2ZY7VLSGW2DGLANU6P7KNIIANRNFWWMWXL",
-    "GD3UXGG76ZRHUAQJB7O2LDXNGIOIWVZUMTSXVJU4TCCEVCFQLH62RTD6",
-    "GD3VKBWNA57MMW5XEZSWDDSAZQDNX7YOUN6OO7DGK7GDXSQOGAQJUHRN",
-    "GD3VPDXGUPGXC4DSTZREYJMDNSQRSHAMR3PDGCOR5RZT74R5GHKIEBE3",
-    "GD3VQPGXCO5VY43Q3CD4CEFPX25C4CHUVYMG6DSE3EAERGWSVNTRZQGJ",
-    "GD3W7RKPXCHAGYE5P3Z5R5IGELILPOVNPK2UPPG2ZH6V3HVLPEBV7EOJ",
-    "GD3WMTSOXJUKUSMBC2HMR3HZO3FYF55AS74DLRW7DP2MNAVCRSJ5KH5M",
-    "GD3WO2W4J6FU3HB5OQ4BE3SYJHPLGUZ5SCZESK53CPLDHJO2RNY4UWCO",
-    "GD3XLH5WKW6HPTEVOTMQZ456ETLU3JAU677KVVXVA5F7JTIARBWD6QSI",
-    "GD3XOD5QPFMGCIM3ISK2BECML6LS3TTR4OHBE5OBKX4KTQL6M6AMEN36",
-    "GD3XTM32MHF4INKUAHLMGTG7U6NNT2MIZ4FROXLXP3XBJMEIYUPYATV5",
-    "GD3Y66BDI4VHD35HNVRRTQOLAGMIX3ODTKEV3XBQZIHK6F6BT3XQ4N7A",
-    "GD3YCCJ6F46MMGTVCTSAQDHXGBMJBCQEXE2DBHVAJZ26H226GGQLEPUW",
-    "GD3YDHVJWLVMHRDQDRVOK2FA6ONU2NMWAQVDPSH7VSYJOJGWLTAZ5ETH",
-    "GD3YKZ7LU7K7G2XLVLQFJAC5DRDBMMNOY2ODR7ZEGXCNQIIZBTDB2FLR",
-    "GD3YT37YUROKVLQTQKX7YS6JPVJC3HO2NKERX6IDG2FHJNGBPAXC5KQQ",
-    "GD3YTNSJD3UOLBTJKFMQKUWGHRQZM6IOBHP2MIPYJFEB2FTCKBVSK4Q6",
-    "GD3ZN7CA2UJPA2ACMAPLZKMXVBDMTZIGDOWHB66GROHFUYQZKF4QPHOX",
-    "GD4235P67APRUIDCTDE45UJL4Z27H55YFBICKBPCYSRETZQAZUCG2VTX",
-    "GD423PV5RVSPIFNRNMX6JTOQM54CDHG2H7JTVVGPSKJXKAVVWBGNCOPP",
-    "GD42GQASOMOKJ3YXPDOSCLQOVWJAQXZXYFAHSXRMRNHNT6COAOQDGGY7",
-    "GD42KRETYPP5GS3KCSYXEG4CEKUOFET5FQDQEL3JBHLRL5L5WTYQO7GU",
-    "GD433UCAXRVP4PSFGJOOU4TDLKPWWYO5CCIZFK6NC7G2MQRPMZ5MY6SK",
-    "GD433VZYCLHOQL2AZZGRXCDU7JGPMOY6NC732WQJJ7PN6JEWBYBWANQK",
-    "GD43CA7MS2Q6NAYX5XNZKZMKF3MUW5IAVHIJYULASQQPR35C5EMLM53A",
-    "GD43JCESM34FFC22FDJU62CIQWX5ZMV6IMXWHNWJSZFAXKNLD3GKCIUP",
-    "GD43UR2W6UWWPGWJJQJ6CCPPJMYXET4DPKVN5MRILM5VWRV7YIN4VYIC",
-    "GD444S4W574VODGOIRNIFZKMO7JIKJTDPHINMUROBPL77QSIBGCQ5TVZ",
-    "GD44GM37YRPTEUZANTIGBL4P3RSW7OPSUT2UTIJ4FXIGNO5M27UT5EG7",
-    "GD44LELWW5DC556R67OQLG5IFMF5ZRUONLSYWG552TTEA6SOC6Z24MQU",
-    "GD4523C44GUFAHKWRXJYMGNGYIHXVY5EC3TT73P5NL4F2O6Y4BN7HV5O",
-    "GD45VQ7L6AZORNV3TPCM4K246THORS42FKX73CATNHBUVF2ZXW2NSINB",
-    "GD45W3QFXNUAGOPEGYCDSOOCEZZ2QCKSWWREUYAE7YCWA7PDV7GO3XDC",
-    "GD4666ROOWAE5EKG2TS54BHD3UACMLQYRN3VAOKZJ5C2DVOH62OVAQA2",
-    "GD46DU6MUM2XJDKGRGVHFOOIYW674U4I5HRAZSK7ZQBWPDAHM2CO4TJH",
-    "GD46Q2B3BLWLSL4RZ2OS63SNNPVG3VU37BKYUK4BFAIHOIVKWZT7NQ6G",
-    "GD47VJXABSMBJA3576WC3MLA6IY6DWHZFZYTMLQQN55SV6YEHHFZVCGG",
-    "GD47ZL6EZ7PO2QVECKJWA3WJYTC6AKNZXWUXGYFPJUU7TYS3XDRYWWDF",
-    "GD4A3WU5XVI6FZFZJKIAEISP6JHPEU3ARLPK46KNXA27CEBWQRT4ZYWQ",
-    "GD4A7LU42NUE42QPSCBHMX6RMOIX6YLAUUH5J2JAKGM3OJGM256WLTHU",
-    "GD4AIAKENAHD2UGQGF74GTXRTRP3FZPJL7Z4T7OMZGJ6A7YMUMI2VCXW",
-    "GD4B3R3XJFVMYYI2HAV7TC2KW7HK7OVBEEKJDOVOSG2QZGVOK5MDRE3O",
-    "GD4BBB5GVFHKI3UZLAQTWHJSF7EKCXOHDCIRJOZX7DHPQ7UH5WOTT2J6",
-    "GD4BC4P7HTPKTX3LE3ZTYMQC5TSY7URFO46G32Z4P6MWZT6ZDFJXVRIC",
-    "GD4BFFUC3MSZFOUY4DTELOHIRIWE724DDPOAHBPGOCIPEVLZX3OW3IMW",
-    "GD4BG5VK2BWWIZWKOMOMSPIW7MFRP55BP6FSDRW7KRPEONKJ7JFPVW24",
-    "GD4BIOQXBRLMM3FTEIZROI36QAAZOY7O3C5CMV7536RJX2C64NRINODC",
-    "GD4DNLGEOGZYL77PBMGEOVYEPFWVYSVVN5OSGL36P5WL3N563GBNHQR6",
-    "GD4EGPQBHUBYDNAU6LS7OIBWV74FNWCM6LSP6MATX2AEBYML44ZBUPYV",
-    "GD4F3HXPZCFYYGAIQOTPGJZEB7QKOHXMK4RDAPJZXQC7U6QVCZWK6WFR",
-    "GD4FDIQ5DM6NZHP5WNOZK52NRJUO3D4USCAT3PBPZVG5HVZEL6HYY5T3",
-    "GD4FT5MLG6BGARKGDSB4Z2Z27S4JDLDP5W6BT4WDRBU6M22ZI7ZOKAEC",
-    "GD4FUUH2Y47XJZHVNP22SDUWXKGVPTE6WRI75JRCDISINS6ACYCQD7IW",
-    "GD4FXSRTQPEJLPWZ7R3WQ34RARO6Z5DLF4SLUT44DMG3ZCSANTJZSLVR",
-    "GD4G5UGQGDEP42DH2PCNOJ67S2NSDPBHEHSRVO6X5RPSAXPMCRNZ4CTQ",
-    "GD4G7XBELEGZTBQ3MPUPOK7YRF2FFJPZKQMNIHQDE57PDH74N2VI7XWG",
-    "GD4GIASPM32VD3EFWQK7X7IFNIEZZKGXX2RU5RCVG6GPNEXBOXPZH4HX",
-    "GD4GMS6ZYB4V3O76XSW7CK3F635FZ4NSBOOMNJ5F2WBUOT6DCT7SR3G4",
-    "GD4GVYXHA3YZUG4WZ7OQTEP2D3BAVAGOE5A6AOJBAC4NDOHNJRE2LSBQ",
-    "GD4H5PM4XVA7DDZXTRW6EW2OZI2JTB7ROQR7VMT6PSAEYRL5HJY4VB2U",
-    "GD4H5YMDYTVSIBP7PZLBM2FYPA2EVY3AJS6NTXCPINT6QCXJ7VM2ROWZ",
-    "GD4HCHTQV6QH7FXV5MPK3DCTN7TYVQQKQTBUD3RKHFTLZ4DHQEI5VKYD",
-    "GD4HEOGY7X6TWIHKTQWB22RJCSR65YQL4E2J3O6KJVZSLMZDIGCWAETH",
-    "GD4HOBJOGXFDH7G6JBG5ENYQNPDV2NUNISTT3ZNMZLWO5EGWK5QAZGKI",
-    "GD4HSWYTALQ2TDOYFS2RM6JQXA2JUPWUJ434XOLNBUF4I65SGHL7OERS",
-    "GD4HZVSRM22ZXWPC5GP7YA6DCOVRCMHLPWDJCGH7OBU5ISEBQRTS3WAM",
-    "GD4IL3RA7ZFPFIAHSNPSATKBCQ22R6FMOPTUYVSQFNSXEY4UWVHCHAGR",
-    "GD4IMKDC5GLVRCRFHPHPEPWPNWRKPAWKR2F4QY4RNY65VXWYJOQJ7YYL",
-    "GD4IYBXEMXEIYXTDQZZ4A7TW2TAU7CJZS2Y46IZWRMBN4GIK2UD7467U",
-    "GD4JNNRO42CNDNHLLH2BMUOKHLZCXQFYE7UT2STVOBYCNJK32Z4IWQBD",
-    "GD4JOJBEMBOQPMOK6V7PXORUOIF2ORK25SBB5NDGQTYK6WEFOQGHCYR2",
-    "GD4JTJJWII5V4SIKBN7R7MNAZOFMIBJUWWJ2OVFWF5JAPS5LLXYFGALA",
-    "GD4JXME35AZV5XNYRXJN6EEFB4EHYHW7ISB7GNXX2OUWWH4WKCBJK6DG",
-    "GD4K4475PI45TV2C4PV4XFTDNREVKWUYLWOAVUC2YXFHQ2KYPWBO36QO",
-    "GD4K4T2K6VHQINOQ227W5HPR2JWAQ5YEIKHGK4OMA7OJKQULIOLH3NFB",
-    "GD4K7E6EZDFJBYHXUMJMAKIARJJSQBK6TZAEOE5ATMUYUG6WMD22HWGV",
-    "GD4KMMSDK7ZTGXL23JQKSWEORQIYGHK5TQZKITARYSUUGAY3LWWLZPCG",
-    "GD4KUE64D45IGC3LBQRF46FXFYXZTOTBPUKFAC6SDZWIFI7SZHZJDIE5",
-    "GD4L2ECXQQJG3ETYFS46DNRPMKTHWGYZZQ33XNGOKZKMRJHSEXNGLWY7",
-    "GD4LIX3IDRKDFSHAV477CADCD2CEN6ML26F65ZIJZTXFTOQLER3M2ZU6",
-    "GD4LLZ3KBRG3MYFCIINRBIRXVCOXZUNK5ZRSGWFYJFI53NRV64V3OD3G",
-    "GD4LVYZECZA6WXT7ZJTDTNY5ZC7KPLRTNWV7SFIIVKTYGT4XVLOWIQCR",
-    "GD4LXFOXPR5RW53SXY2BHZOT4CJ5KCKP5MNGMI4TWSFNZCBQJ4PONPUF",
-    "GD4M3UQRQQ53PSL7ZPATJMBZEG7KKWF57NU2F3V6GQ2C2CTIMEKXTOGE",
-    "GD4MC4V3UEIQHAU5BZ32NG35WENYTWPFFQ76PIPQQ2N3F2K2KMI5ASOS",
-    "GD4MCJCQ6GVNHAEHWXCGJNF6SDSOSUW27SPKSUM6Z7CHDSXTJ7PZEYV4",
-    "GD4MDB7BDRLFFXGGLRLSKAZUB7YKMAYXALRPJHO6W5J67VIBEV6GCUFW",
-    "GD4N7QSRG5H7NYOMVANIMLEUDWICSWVQMNWFM5ZRRBUJXEPVNHEYUE3O",
-    "GD4NB347ISPGZLUQ4GKCCAZ7EFHEASIXVPUPOH47SYY275JP5HOHMUSD",
-    "GD4NNKULL2J2FRGMR6SIPU2UHC5JBI2GVX7LQ5DX4QL4LAIVAORGBEEH",
-    "GD4O2DIYVSZSPX5JMLEU246RELMZR3VDGGIWIQFWCGU2T3KMM2UIFELF",
-    "GD4OG4T7WS4FFHIW4WQYAWG5FDA3OBR553JYV4ZDKRUT3O3GI5Q2OILW",
-    "GD4OLAMOVDU7QJTNWYNCOASMDW2NMTBQM3UOASOJIQBULMWZ3MGI4TRD",
-    "GD4OPGKI4RWXI5L22KO4RQQ4WNPU6AZHESWXZ3D6VP4TER6VCQUJURB2",
-    "GD4P3TYF5644E6UAM47ZVYKIF4NN53TANSP3RCARIOCGYE6AX5CJLQSI",
-    "GD4PA7CB6IZLGD32AXW7O2NOBNW46KVAAMK5YTFHORDXRG3N32FLGZWL",
-    "GD4PI4VD2V3VH2ARH6AMV7JV2Y2WWP2UGSJC5GXX4FKJMY3QJTFBNDUS",
-    "GD4QM33U5AXL2AAAJ7EQAF5FIVHXUID6EVPCXGL4HSKH4RG2ZXYENYBF",
-    "GD4QV3OJ2N3YJAHIKK6KNQG3OVB6BZ37STILM2ECNRFXNRDJ7EAEZBHR",
-    "GD4R37AVZ7CTF5CAMAXQDI74GUAQ33CJE34LSEVMHWCWBTJPP7UO6O6U",
-    "GD4R4VWNCBHIOA2JUP5IN3K6D4FJLRALXO3JOPL2X6PDYDU2LOSWC7WO",
-    "GD4RZEXTX7WHSNZEZCDKWLTM473CNITXROLN3FDD6PRXSSI6QOOLHFMP",
-    "GD4SJAFGVGOSVMR2KDBCEYD3QFBDCYBW27ZSNROY2JWLKKPNAKFSIHHC",
-    "GD4SYHACYGP2YUY5J4BYM5I7KL2Q27ZHXGMLNAML7R2IXYSPZJLHD642",
-    "GD4T35DMXYDE7BJWYPUWK43VFJO5IBUQYG2YGMICPTWP4JTNWQELKAVA",
-    "GD4T6EBJCUKV3K2K2IQ6GPY5O7QCJWYL3E6HCT7ATXRYY467GLKWTNRJ",
-    "GD4TACDE6JCUTSDB5IIOOXVMFLJJ6HYCNFEPBEGC3QPEUS3IQHYW5CME",
-    "GD4U22UGSROU6DHZVHNSWWH62WTRN7KNIWL6YDBNK6KNTZTCJVDJUDBS",
-    "GD4U4VL3DJUDEQBGNGFTJNEYJE4AYIW24EKYFY3L5XMYQNEXYNL64TKG",
-    "GD4UMNSIAFWCFO6GMYHYZYJSFH4R6V7AODIR4C6L6VORUVBIKBOFOWQL",
-    "GD4V4EOOADW4PTCPJCGXKHFRQ3INTYM7LJHCUWVPHG3NL3WZA7SPSHAB",
-    "GD4VPVNZ7OZICIII7PJ32MBK2LEONA3R23GBDKF7WEL7YNICEVSELDEX",
-    "GD4VRU4ZBBMCWRZETHV7XJNWFM6L53EZAEJCN3ID3JA45FNX4BP3WLCX",
-    "GD4VYATKLV3GZPF6D46OZWHA5XZN74IZQWDHAN5SANEI7WK4RZQTUSTN",
-    "GD4WD5NGWEE52ARGDH6IRTTN7O3657JTGXQYFLRRVK7BBW5TEERHDAZU",
-    "GD4XLP5VCH53LH3CD6IYW6H4ZDPPY432ONPHFVBZ6THRDN3EFJH6PLAT",
-    "GD4XSP62QFLSR5IOUCG5VMZMMEH6D3TEMCNIIYE3WKDNHUQLJ3QPXGJ3",
-    "GD4YMDZFVLJCJYQTZYDWRIRUBBZ66WKZFYYUNEB6VNXBWBGQT6HMDFFJ",
-    "GD4YQADO3N2YX4E3STTGGCSAHMPFF4BWEF6J2EROVEGQXDEPMWFMWZIM",
-    "GD4Z6FDEZNUR5EBQ2IIVV4G7SKFXBC2RE4VIXC5BJRVXD7L4M6HHWY3T",
-    "GD4ZCSLBLKA66IGSL542FLYGK7JIMEUK2HFHAYEXXEHZ4XVRA7ZFVPAA",
-    "GD4ZDFSZFA4FVTL66OWOOK5OSK5V3KEN7SALYQHDEA7GQPLRUQ4JP3NG",
-    "GD4ZLIPUHTE4KCDFCPQOWVZJBTUXMWRSQ5HD3LAELLODRIH7XZZELDYM",
-    "GD4ZQX3VFI7F3TSHIFHBRBWTNWTACR23W45MUMAHMAODVAERFQ6FOROR",
-    "GD4ZUOBJ7LPT4TYYS32G2DFDIPXIZM6M5RRJ2ABOIRGGCNCOQZGUTVUS",
-    "GD52HBAYKBF7QSYE2DAOPXG3JODXDNLXOWN3O6MG444JPIHILN363AOT",
-    "GD52KJNBVFDZTHIE7XCIPZE5BXLSDFIZ332QJGUSG3TBYKMSC2B4PS44",
-    "GD5327E6XZD4AT4AZBPK4ZLG57FH5YGLRDRNV4Y7KWKUBAEYGRNQLJ7S",
-    "GD534N4GRR2FF5P4MXHT6ITVLZW6MFQ4FYTMFRKZLCAL2JNHQR64TG65",
-    "GD5372ISWF2IQTYI4QUBNKRBT6GBWQNACBBZHDJHER5BFMJSIZ4UXAXS",
-    "GD53FY4VOIJEHFEF555ZC7BE47EDTYITCNC5NWNLXZ4TU75G2LMJEGYS",
-    "GD53HO5DJRUMP4WX5LCND3K6VZMMBWPX3RZQIN7YL2QBRDP324H4SCC6",
-    "GD53ZSUSNFVRYRWVRGJAKJV74SWK2U4BZ4T4XWFH2FOZ463PDTMYTNP7",
-    "GD5466A2BVRHK4O2GKHZAFP4RHCPN4KISN3SMEOYJFT2CF5PYXTNZEJG",
-    "GD54RO3XBBCGY5PKXCJSI5VGJDYJWBNTHYIRYRPCTEM6RD3Q47R6W5HK",
-    "GD54ZC6E6QSHEMG5ASHOZFGBNMFVLGHHD5FRJLB7475FSXYA3QVPG26J",
-    "GD553U5J32O22YMQVOJ67ENHH4UQP5FIAXHNOBKHBJXBKSA5OUA6X6Y7",
-    "GD5556X4NAXTRAYYLCIBQO3G6MEXN4IF2HXDUZAU3SYLEJ236Q6W776W",
-    "GD55M25YHFNRUFCURXCFAILUKWQCQNADXINDAPOGJIKO4UO2GSDGISHX",
-    "GD55OHIFXHR6A7VV7Q7D52Y6UXHXX6BWFK26BZZ42G3AADR5SS6KAQAW",
-    "GD56PWOOBJ76CWJ4YA24YH7OBX76WI7BC4NDBK24D56K7K6WJ2KGKSLL",
-    "GD56XILQ4EGMDMSI6OQBPEGCECSPMNPC6WTCZQ73KN7WPPFCQMJPMFZV",
-    "GD57LIOPJ6DQNFBM2LLZNUDARWPAJCEGJ6O3QD6UPIV2RNEY6RMDJ3G3",
-    "GD57X6ZDTZ3OHKX6PAMX4FJJSPC42WT2TPEI24MSIWLS3MHVVMQMQVPQ",
-    "GD57ZKXHEG33IY3YSFI6CNVAKVJNTEZF5YHDEYW27ME3B5MLDSKAAHCD",
-    "GD5A4KI7VHRTLSGTBTNLWNARKWXKMZ3O4TDR4APJFSOCRCYCBDZYYCYB",
-    "GD5BBQBF5OYQ66HNS7FMYXQT42YHULQHLRWNVDE2XMH6WVUE5WSGXTOP",
-    "GD5BW3DLQYFVMSG72SNTTCYOMUWFCXVHDP46MCNLNAHL6TP7RHDYJ7LF",
-    "GD5BWJ5JXSXZB4WBNCBTTECC26RVJKTYJKHKN3K7DCZCVFTSDTBXKUGR",
-    "GD5CFB5INZ3AZUXWSXGMKFBQTRIVC5YTRTB6STUAPQP6YSB4LXLF7RAQ",
-    "GD5CKLAR5EGYUVGG2UIOMHUCPCB7M3MCAO2DWSTF5KNQTJ3TMRUIXDPP",
-    "GD5CSQGQ7GZJO74VS27C2AZSF3YHYWIYC5O2DINOSFCDT6G6P3OU7PAQ",
-    "GD5DQJJLANQZLNZSIMLFPUSXJSAKWYZ544BKUKVB63ZCJTZNWYCLBH3B",
-    "GD5DZAUTVENCHXRJOLIZ6GJBJFXFCWJVF4GZWKW2MDNI7CYQXD5OWTT4",
-    "GD5EEDUHKL572PXTNZCJKWUIQLC6ZURQTFWCZQTXAY2ML7UA7HTWLDEX",
-    "GD5ELMOHJ27WQPJBGW2P43A76DF5ERKV3ENTGRVEHO2UQLUU3X62CVUY",
-    "GD5EP2NHIOCORMXSPZ5OOXRKSKNALGRFAHVPEROA5IW4S2HV46Q5JB3P",
-    "GD5ESHIE6TGNH7VGZEFMM5P3UFE2IE2ODM5IWVQMH33ZULBZVKE2AUJH",
-    "GD5FUUCARQ7BLFXYPZU4EFDBVYXVTQVEB6QPICNZL65GXIEIJGWKRCYL",
-    "GD5FX4J5E33SBQC7K5FUTFXD52DXFTPJD5IUO6D5UFHFDSOWTR2FH3ZX",
-    "GD5G4TS4O6QAHFZ5LXCFE3DT6E4BRKLLIESFP7HD5YSGHDCJH3EUOQ3M",
-    "GD5G5KHNHXI7PKK73OC64OMPNIXYCK37RBTF6LEVLDMQE625YYFAVIBG",
-    "GD5GBSOO26PHSGL2LFI5FCIVYDSLHFXUN6TNBHTFWUZ5B743GOFQ2R7T",
-    "GD5GR4BXZYABKX344ETIAOWBJ24XA4TI3S5ZUJWVG5YHE6KYSUUEXLMX",
-    "GD5HQBCD52QQWQUOVUEAGV2O3NDJUKKNFA42GKDCNG5LNXT6TYHXEHYP",
-    "GD5HYPLEMVBGZVVYCNCJ74GOAPBGU7YJSDXG7JPET2ZM3N74AQ7BCXLQ",
-    "GD5IAYJVR5J3IFYVJO4DCBHDCGB6DPXZKH24VOG4FB7RCTBHVKUZTTDG",
-    "GD5IYGDJFZFCDYMQ5OCHYB4SL3AJOUGUAGBURLVSCIECW3DYZIKVR2YW",
-    "GD5IZZOSZI6E2PP45JHYNAZOQXPNZNRUY2TZHWIQXPQAA34QMFTQB3XR",
-    "GD5JHO6SAF6TE3DDPWJWMIQY6BVTQ7MZ4YB3567VSITAAOEU75B7V2NA",
-    "GD5JMLSCUXACSQHHZSEJKOPNKPG22MNGT3F7IK5JZH3EEPHE6SVNUU33",
-    "GD5KI3SQPJYZDLLUD3WNUQWCB2QB75ZTFOO4OYOSJCDRNO6NV4RN4NJI",
-    "GD5KRYHALA6E7XTYWCGIEP62EQLN4JNJYV6FOWXPGSWNNRNFLFB7ORAV",
-    "GD5KW35BWN7KDEOT2M3DGBM6MFXG24QHLPADILMGH7PMERXCTMPPBXXO",
-    "GD5L3YGOJSUXTL6VEKA4BXRQFX3XYUVG2JYGYHXDVNFIJF3YUO5OCBNU",
-    "GD5L5TF7AH2HH4ESJADNYY6IXDFE3J3BNB5ENSCKNUNYWQS4AF3NJ4WX",
-    "GD5LBD3YK6AJAUTKRJTRNKCBNF3KPOPFLSZ4PKR7SIH7SOSIJMNHKNKV",
-    "GD5LDDCG6MACNGCKIM3NKR3GHEF2LAJJULNP7DG7ADFRPY66WYSM7SZ3",
-    "GD5LKRBVELE2XPAXNXDT3Y6TFSKCUU45GCTVOYTTTUE3C5WEERSBASEA",
-    "GD5LN5KONK3CGDMUXEPAGMLXMV5GWKGNBGGLOH4QRK2FVS4BPV4ZSHIB",
-    "GD5M2SUM62VYQLKB2NVLZ5E4RMG7NAOEIWF3PVB6DFPIPVXSEUCF5A52",
-    "GD5M4NGIL276XON575OYQ4NU5LUBIOBLAFYEHU7MTIX7NVWMYISQKS4B",
-    "GD5MVNLHHM2IKDCIALVUKPK6BYAKG23LNJGY3OYN35VHGQW23LASEPB7",
-    "GD5N2PD757Z5URUSI7IJY5GS44PDTC64TVLSAQJRVNHZYUQY2JHHT276",
-    "GD5N34AM2IFG4SEN7DFCJK7MWBMMZRH6NZHVQCPK3ZLBEO7M6ULUDKVS",
-    "GD5N37F4W76AACJ26JPS3O66QGQTB5QBXGMK2RAUMLDB4V5XOMTWFDOT",
-    "GD5NCXNL34J2D2DYC4OX7IAVLNPO257DEFGPUL5C6CFJWUZETMMC3FUB",
-    "GD5NVJJNOTVJIXMFHRDLJAEZHYUVQFS7O2E6XYTQSNXD5ALAS72AA2XV",
-    "GD5OS3KV2VU4HQAKU573E2KGCZ4SSXSJAQAI5CM2D5Q6LHK4SGBETXRP",
-    "GD5P2RF7VNHORC6MZY63QZIR6JZEZ5HVOHQKGUMYWEQ7X5KSFENBBGER",
-    "GD5P63NX53RB7OXNDQFUAUNPVQKEM6II4NYZDLJKKG44OA6L6MIAQQZY",
-    "GD5PCXJ3EM7B6UQOP6ZI2JEAGBS5WOTIM5R45RCMGIFBUKCKS2DPQPMR",
-    "GD5PMCROYPG5TSFBTUXTTVXOL6A5ARXHKCNRNNWJ6VMTBA3VXDIVBQ6D",
-    "GD5PSEJVGQX5WY5EIPTYWKKNGXOLICOBERXZU6V5AK7H2ORO5OXAZXDC",
-    "GD5QKZ6EZ43MGZZHRNX47KDFJPJHNRJYYV3SF632J677D7KCE3KLIHAL",
-    "GD5QTG5IZ7QAFPR7EV3CWQOJYAQASNHQ42KBZDBHAZHVZFZ37GQ3GMPY",
-    "GD5QXXKWDNKQWIEEFEM53HKVTK5BC3JI6PHFEJF2H6ETTSGQVXXGSISB",
-    "GD5QYCFAEVYBU2TRT6SXHGM3WWYA5B4QXEHDOJ62HCLNAOMPRTVLP3GD",
-    "GD5RAZXVWUWHOQ3XUY3IZSHZM4PUA5TIPWLATGR3FKCFO3KICRARQADA",
-    "GD5RDTIJQQ4VGWWR2STFLUTVE5XQM7U4HSVYMQVGA3IT4GPBIPT5MOYM",
-    "GD5RG24PTHDGKUMWV5XTOAPMIVNZIK3NSSSXHSJFFUVYO6EGKNPSKZT7",
-    "GD5RXYA52BTYYTBZKXBYQMNDWQXQMVBKCGB2EDPYOPSUIK4ICKLFQAS4",
-    "GD5SKRISEEP4H2XPVRVA3PMFR6NEXQJMBTYCO5LC7SH3BHHKX57IHP37",
-    "GD5SMNDZEQZ2YXK3FM4RP7SQQ224C3KEINKPGWORKJ2ESCD3LKGF7U55",
-    "GD5SUDZERSEDMG5LKSB5VDEVS2BL5IVCGEK7OQ26VAZAXURP2C2FNUYV",
-    "GD5TAXUHV6HBO2GKTPYNOBA7OMLQBGTYLEM7PLLQQJPODSVH5AEVZWHA",
-    "GD5TEP4REI6CNICOBKRAYLXXDTHPYC4BNSWLL6EIUGBN4WXNDTEJVNYY",
-    "GD5TFLGDYGTJNKJHPG2OEWBDUALIGPGPEDDZOZXSOGUBRGE76GL4AEB5",
-    "GD5TWWIV24ZTMNDP6IHVP73EDTXM65UXSGYNPSEWIMMTJ22OQHRLJWVW",
-    "GD5TY6ICL2UDQWMYK6FIM337GQYHDEP6QTKJD3LZWHKLL6XHETAALYIB",
-    "GD5TYHKQZ2W3Y4Y5A7ACWZVC7N6ODOJVFB5MRWSE2SO5VRGMBCWGESHT",
-    "GD5U6YLB4GIB7XRLMS2EK5JP73Y5RBNV4YRQBJQFTG3ZIVJZT5NNKRER",
-    "GD5UTUV72AZT6GVS6O62HQ7FPBBHP76PX6WHTJMOPAPBMI6WGNRUE7T4",
-    "GD5UXHZ7O4TVE6T5VO2JLMA5VSBGP2TWLOWWRAJRXO3KH3IAZLF6HJVV",
-    "GD5V7CEXIOLDWW6TXFIA7NHURFDYD4VB7C4T4PCQAAFW2KRZRWMHJ2IZ",
-    "GD5VCL6M567RUMXCSBGPVMTL72DDLVN77C3FRBSPSTUWIUMFNWRTA7QS",
-    "GD5VGYOWUOJZZUCZUW4NE5S6CGW23Z4MXPDRX7YMVOHKXLYIMZWSJXED",
-    "GD5VMRUHLHY7LEE6PRKRFAD4WVNCV4A2Z4NC3EFFZSIG2XSCF2IGQN3Z",
-    "GD5W2MKMW5PBPL5XYSBMDWAJ6Q5FW4RGAMIWZD37MAOYLTKHBTEBRICS",
-    "GD5WZ2M3ZEPAXEOWT355UMQMGRKQ4URVWC7ZIIFVDVEPKBXJT2EDVFT5",
-    "GD5X4LVB74JQ6YOYR7CAGEIE5B6LP34NZBXAYRS34X2KDEFNIGR56XRP",
-    "GD5XIMDWUGXIA2RZXNHU2P24KURGPFCVJ2XMUVTWUMQRY233QRKRDGBP",
-    "GD5XJ6NJYE3WFVKZUPRVZIFVIXONYIR74TIAIMK6RQC2P57KGAC5TUAD",
-    "GD5XU4MGZHTA7CO2F5HQYDMVFW63SDJK7XEZEESMFNDYJSRWRFUSRRG7",
-    "GD5XVD2RDHQLS5D7ECNDXF23TVO3EAMAXABTMGEIGO5XBWEA7I25EJDY",
-    "GD5YAYO7Z4F4J7URWUFIUXDXJSXUG3BFN73IJ3ZXK4AVYRIIZ6X24USA",
-    "GD5YQWQOXCZXGN65TOAIXF44N2O4JMUVBGVYGEKM567Q3BFSUYKSUC3A",
-    "GD5Z7UW45PE3QWNL54OFVOD5HZ7KEWKD57WU5HVXS37RNPGKCGWWCEZG",
-    "GD5ZBJ6FIOP3363SIKZXDDPXMBHMI4D3URQQ5RZGIDKIVBXLB5AIIQSR",
-    "GD5ZO6BHKD4DT27G4MJ4V2VPD7BP4J44BY2OIBAFN4B75A4FKTZTBX72",
-    "GD5ZR26BJXEO2V42YS4FJVBZHPFKLKN2C2QVSNK5CAUA6RDG4IMTPLCX",
-    "GD624LV4GEU2B2L46REW7HLWEMTTBKIROTMSBJL3ALCKTHSYZ23RTERM",
-    "GD62PTBYUSKHV5YFZPQ3GBHZONHFB3V2K5N2NXKOSAAV2Y7NRHGZVQT4",
-    "GD62SE4SYVUMYETLKLDFIMX6XNZ2PJVKTM3FZ3FPNJGRGFFZJYPOOIHN",
-    "GD634SYZ6QUIXZGKN3EKIX2NNLIPNXY4TB2CJNKCXJAB6GG55Y5ULINE",
-    "GD6366D5OPUJQJHC2RPHDG23LLT5RXT7ZR36WBZNXP2L2G3CD2465FUO",
-    "GD63AGAFBXFADKDEIIRY634XGY3ZRY2RUI4HZWTH7P5TTDIKVHDEBWFN",
-    "GD63LO75NJGSQGAB36WFKBJTLJ4SEUOAZ5UZTNLL7MHXIOSI3RJFX2DH",
-    "GD63YXQW6RQUNENTKF7WLI6V5KVBGKOHDQ2NOKU6LQMDF2RNPAUO6FD2",
-    "GD643RWCAPBZOSKAWYTJHKBFFQLUMNR35RDVLSUAGTZJ77PWX7IZVEST",
-    "GD64BNKMSUNQUDNRCDUS4ZXGQSJ7QGGGHT6SEEUR3EMRRFD4VONXJ4FR",
-    "GD64J4CIAOKSEFFZTT5JRQXSNP5H4AJBUZKAR7BAWRRKFPA4XPNKKLJ4",
-    "GD653H3HCKBTITWASWDUJ3NXSKEAWZC7CQ6MYTOTNUJRCC4V7IPKSUNG",
-    "GD65BTTYDWK2OYFIPDYOWVTDOAHJSF2JAIBFZHB5DNM4O24XNEHJNQEP",
-    "GD65SKMU7C53PG6V3UAXPAM6CNZONBMKYPT6RYZWAXFLQ765ZQKYKY3W",
-    "GD66ASMBESSHPTNCR62XX7FNDSXWRWBF2A6BUG5R2RMJZZ6ZIFDVQY6C",
-    "GD66LNN5PLOUUUSERS5FIOEJRM5RWPUGV4DAU6N4ICTOA2T2O5ADEH2B",
-    "GD66Q6S33L6LDU56GD456XWZI2KK5EFCWZ67VLCXTL2M5QZLIL2SIOTA",
-    "GD66U6N43P24524ZWAYYMV22VIWBKK773KPB7XOZKLRMUW3VBIUHR2EW",
-    "GD67B3HXSUZI25H6YK5IOWKAEXJRS5B6O33FZJPUP3UAU7EOPVPNUVG5",
-    "GD67CI2DXVTTVK5NJK3LBBUEZAZKCVZ3MSDUV656XNYRLHJOLZ5LHUMB",
-    "GD67DAEGKXWHIWEOMPCTUB2JL7SQMOGU6KWMNAATASRLU4TVYH57USRZ",
-    "GD67U66M27ZSIY7DAYJDR77IF6CFU4HFT6PCOTFTAZV62K5D756SCF72",
-    "GD67UQV777JCXQAPGUZWJPDYLO44ZTPDHC7Y6QWUGB3EGFHHSZ5RO64U",
-    "GD6A3XT64ZVAUYPLZYC77ZXBS23EVNUYD5U4JVRS72O5ROFCNCSXPAUS",
-    "GD6A5NJFFXNWZYTRECGTPUR62O4XMFBI6PDK354GDZP64SGULFSHXAUS",
-    "GD6A7BZ36RR3MNWOERACBDUKHSBGUGRDGCYGXVEFQT35WEFNSHNSO77P",
-    "GD6A7LHTYV45S5IX5P2CAOCL5CQGKKN72EG4UDAWIR3FSEVC62IRWXDE",
-    "GD6AHEESDZ3MD4ZVKHJXHFDVYWO43MWJWPP6CLKC5QLRX4P67DDCYQNW",
-    "GD6AHPS6E2EP4GMBCU7YAL5XFL6K6PZZETLLN55OCL427VWPMRFGZPIE",
-    "GD6AQ6GS2X7PDW6QHUXZMY64HFZFPT7XCSL4BRXH3HJYM7K5OE7XLIGF",
-    "GD6ASKSY6J5CMP4MGBNTKCSGTTQCZIRE6VK5RTHMIA345PEGG75NGSC6",
-    "GD6AUIGXE2IEWGQZZ32CNQIZX76SSBYF5FCWIFVUU2R3Q2RQN2HLPWFP",
-    "GD6BFKYF3DC5JQEH2YPJ7HAAZSNX4CMVBAFLLZVODQFV6MTAZOW2MIE5",
-    "GD6BREHIVY54SBNUCPJABK5W6ANHRXKYZOQCX6RLLCV5LSEPGXA7CDAZ",
-    "GD6CG4VBHRY46HESV5FW7DM4362NXHPK4DUEPN5D74CZQ7CGKZJEIV2S",
-    "GD6CTKWXMH5JPIWDYCZ5EYYV7YJDDVUKN5QFYBRBMC26GP7WVNUAQGLD",
-    "GD6DBZ7MY77BJVENQXSN2VXA5NTDOJFCPDBP3P2MTHIDQHXWYBIFVZQU",
-    "GD6DL5M6W3JNVDUUQ4N6YJRU3COZ5A4RXUE257XG2AL5KXHSQGHOR7NY",
-    "GD6DN7KTNPTXNZOYHECFPOL63IR73UMQ2GX53S6N3M7UJJ3ZGLQISEYG",
-    "GD6EBZITTHEJJDMFY5CJ7MDSPPMCA3PYTSFRDCDUWY33XLJSXNYXQBTC",
-    "GD6ES2Q5SW3SCYYXUKFE22IDJVSJ7XA2UFUEGSRKISX6M34NLIWQWIJM",
-    "GD6FIXIN6XC43V2KYHYEL6GOUB4WWU6CY4ZHQFBJYKL5YKOWDZAVND5T",
-    "GD6FS555GERM2FUJGK3IMVAMN7RCKFGJIQANWQTHPMT5WPFEOQO2SHIB",
-    "GD6G3Z2CNB6UNQTL7JMWOHPEDO6O3HU7EONEUOAUPUP55433CRA2BXLM",
-    "GD6GCRU4ESAR32LSHUPB5VHEWBMDS4PNK4FKZKWXF6DKUIYUGREVNNZ4",
-    "GD6GFOCW3NHZNWETEC45A6FBMAFQLUOWTXSABJ2YAJGLDT7PYKZDWEFZ",
-    "GD6GITAIUOY7ALHFKOF4D6XP7RJX5U7FZVNBLX4GBB2427AXZF7EPXHV",
-    "GD6GJHFRHS2NOU3GEUIEHBEKLZV4VR6XIF7PQNYK6NMYZLMELDAY7TPZ",
-    "GD6GNHGV6M2PUF5KTK4WE6GCMAZX5XDYYWBIP2ULSQTVMV4FJBUZTYLU",
-    "GD6H2ZFYSYADX2EMALKTZNPE3MRUPZNJKVSL3KPMR6523LGDEYR2QXLM",
-    "GD6HAJ2SOMPUONGP4UKU4Z5NU2EPFCP236MSGFLMA5UVPBC622LOCBDC",
-    "GD6HGHIZGOK73435VPBZO253GVTLLNW7RTFA6W7QDB6B4VWGRYDDWRBY",
-    "GD6HUK4YIYFEBCCW2HPQYC5VFXPEQUSKOFQXRD3B42RDR4PMCWWPTDIJ",
-    "GD6HVGUHS55HOVHOVU7XNAVEYJAZFD7JRBVSVNKOSIJ2TIRJTOXPSM4W",
-    "GD6I27UT7AFOFXUHTHQYYC42ZVKBEC7JZ63K767QH5YZ5CPUPXTPGLTC",
-    "GD6IBEXPRGVBV7TEZKVRWTASR7U4HRXH423IP3NKW2ARXUWQJBFTGLTQ",
-    "GD6IC6TWM5JFZXV4XZHCBP3I3JOKBP5UVISKRX7WBP4VC2Z6ADBOGBH5",
-    "GD6IO6VVRWKZGVFHWNVMMFOAJK2WKYFCW66AFRHIJSF7TJ2EM6YNTNER",
-    "GD6IRSEC4YEIGKOPCFPQUWOBGWP377CTLNZHREPARC2KYX6JGTLEPASQ",
-    "GD6J3LODPRHV7OOKB5NCQK6IFQJKWSIB7RS72YH4J3P5PLJGE5WFIAFL",
-    "GD6JPK4N277HI64HPNOYI6K452QKHVKNUPGAHGDOMOMWDMJTODBRFHA3",
-    "GD6JZUOAJO6ZRYEW2JOIJXAV7C5KVQCYU6IZKAUQ7H2ZIQ4XJMGFXPTK",
-    "GD6K2OZO6HFQKS7GPCED2GEJ2N645MN5BFHITZR4GFCEUZEJQZQXBGZA",
-    "GD6KO42IMJWCIUO65RCX5CYWS62FONT4GEE5JZJUV66PP2ZU6GXT45YD",
-    "GD6KSIONVR4H7IL3XFJ72AXWICNFVHAWOP5Z7YEP5BOENUSJHGGIXP5S",
-    "GD6LL3MMZT6NXP7V5JEWTWXXTDOXTJ3H3VRMIBQC4EN456FIRBPCHAIN",
-    "GD6LUJJYRSVIK5CUPKWFI4V6UHMBIRQROUXDEUXR77RCAI5PIX5FJQFS",
-    "GD6M5AV6OCL2KPWYQTSS7TO6HAIXRI5BDAZO56LJY5P3KMWIZ3EFE6RI",
-    "GD6MR56UNGZ64VJ5NKUEWMTTYUGYXAUOOLTB7XRORXYZXDIE2MYBVWLD",
-    "GD6NBXX5TWO6V6SYHJ4KGOVQUAZUA2TT6ZEU3J63TOACCP633HA2BZP7",
-    "GD6O3BOV5PLS7EJX4NVNGHHPMF2C2ZSXIVWJPUEGECHHMCEASTBXSBBW",
-    "GD6O6BQXB33T5XCFLVH3P4H2F7KY356ZBA4MCWE32DUAIWGIN2OFI6ZK",
-    "GD6OFGQELGCUNLDVCFRO4DNPKH6FNFIHF3FO753DLG6R6ACPJAM5MHXI",
-    "GD6OM55PNVGSB56E4QAHWSKRKTGWR73SSEGX52XK2M3VXHIQJU6MV6FM",
-    "GD6PG5FSJ6CGOXSASZLER75CUGXRD6Z3I6OPEF2FKZX3EBBTY4PILRWO",
-    "GD6PJ4DVY2ERCN25TSGOOQ4OLTSC2VCI7VMPJVFCCRGITDMWWX5IQ25U",
-    "GD6Q3Q2VMQ5URDPSKO6CPSSOWNYUHPOX4JB2RD6PS4YLTFBZZEVEU6JR",
-    "GD6Q7U4Z655H66QPTHLNVIOL5MI43ZTDHP7A7M6Y53FTQTZBGG4ELTQN",
-    "GD6QCLMWUVJ4ZXKT2RKCUUCFN3GNSCQF7UU3C6DDNHYYHR352GSMKKP4",
-    "GD6QFAH44UMLZR2AML5WABUXHZKRFUQV4K4XVBEX77LI3NXHTE2E2SKX",
-    "GD6QJHZHHK6FWQSML67D2XEM4NIPLSIAJWLCHVKUOHPYOHLQY4J5A2JC",
-    "GD6QO7VXCO2XE6ZVQZ5DGC6CSJV26RGKBOX4T6FWKFWKU6KKOWAOS3DN",
-    "GD6QQCKZVFYD4LA3LPBAPHFWY46N7YRCYC6TIXD6H3C7MPL7NFYMQGQX",
-    "GD6QVHFURAZOHK5C4XXTO5BGFANVPJU6RHP6ABRUTUJAB4PMWIPLZW2F",
-    "GD6QXCPTAA42TDYGCJGZLO7PYTZAGSBRTSDHGYXLP6YOWWGIFZXDHJAV",
-    "GD6RB5INP25TV2TN6F5EHSQT7WU462BNLPLW65W7TRI4X2TBTTNK5R6Q",
-    "GD6RMKTCHQGEOGYWIKSY5G7QWXPZOAEZIKPKEVZUAXOQCZRVBRRFGLJM",
-    "GD6RONXOPYMA7YDRYA422NEOFJJDRS5CAH3XTRKEVSP6KT4BTNWQ4GMO",
-    "GD6SE45S7SSY5QIKR6HJ6KE3XMXO2XYM2UY2UF4G7YEUEOWPIPLWBHPS",
-    "GD6SMOQFSHPTOCEV6VECJVEGNZEN7UUX5CMJ3ZMLTEOGMCFTU6F4XPJB",
-    "GD6SPYPDRNE5AEHLZHRXHDCH4P6P6LY3OJRWHA2I4R6UOP4VIPJWVPR3",
-    "GD6SRGI2BKS6DS63Q6KCV7I3F76ARLMGJ2K3JFMBYR7T3CMOALPOIJ6B",
-    "GD6T46PPWBAEWEY7ICEPJZF27DFWACTK5WMPBNAR5WINSTZO4L5YP55S",
-    "GD6TIXUMWJYF4MNMJ5YBBJRJKH4AH3CKR3ESZEJ36PCIRN26SLQQJVF5",
-    "GD6TPOOPFRZA3KMK4L7QZ3DDEKTF64X7UORJY6Y4YHV34ZDOEBT3ICTX",
-    "GD6TS37ZZKCHTXOLTMEIXKHVMYUL46QXZ3343ULXZQTRRD3ZBI7UHJ4C",
-    "GD6UCGMYK2PTFT3UNJH7ZGZVNDN4URBSNHSRA3OLPMPKA4VTMZ7CLBYS",
-    "GD6UFCJKIB6WLYNXPYAITQGNNFEY2JXQLA4GHKDU652EU7WZM6YQXU3H",
-    "GD6UWLZDI7MOJ55VCZKDOG2DSZR43QFEBNNGQOIWM2STG6OY3T7L2JCR",
-    "GD6VQPV7ACNDRLRUN4VCQVVVPVHD6LNSM45KQWC66XVHGT2CNEFYE64A",
-    "GD6VRXL2KFX5BCWKE6RRKVWKAPWI7PAO55NWURLHJ5EI6S46UEWT6WC2",
-    "GD6WCQ5QOV7PJLDGOM3MFUG5IETHYTFXKEP5RZ6CUPJFJZIQZJL6MAUS",
-    "GD6WGMPJBXTFKRNGBEDBCE6737CEFTTEWHK3Q6RKGFIMA5OAKHWDHW32",
-    "GD6WH6FCEZN42KDU7CJCYP3Z2WYX4Z7Q3K2FJ46FAAEQDHA3N4BZZAAJ",
-    "GD6WVTZJGDAGF4XD3QGUIG75I2SHFE2HO46XHKICNYJIE6VCOBYLQEKQ",
-    "GD6WZERDUP2VEADK67HQXW6HAEVT5SOJEHNXQGMN2NQBY2T6RU3MQURT",
-    "GD6X23I3H5UOXQOBG7LLYSRKWUDDTLTR5JBSCWIRPOXSXR6DIKCIHQBI",
-    "GD6XEUDGH3UIITRGPZYR6HM63LA6NBNVKRHRD6V23F5L7DKIM5EKH6U3",
-    "GD6XMLY2I3WJ7XGENL73QQZWKVN3N6NQT4YOSFIA57RFWP2AGX3IQTUM",
-    "GD6YRAS5UV66FFUPDSSVT2Z3L4ZT3I6D6NGEGW42WJ6S7QD2WCUDRCAF",
-    "GD6Z5G4S5344Y3QLVAGZW2C45EG7AGKBSSFZ4PSPA5JX44A2YC5N5IWS",
-    "GD6Z5PFIWEAOCO6VW7NKURRYILPZBCLIWLWQGPA235ZH4O7DBQ2EPI2R",
-    "GD6ZFYM5EVJG5FKJDSCL4FLI6T5ZRGI2VLMI7BU3IPGHFSBAII6IPQHZ",
-    "GD6ZHYGDSPSYXSCBMFMZFEBNH4SGI6XW24TIL3Q7TVVVUTMK27WZXARX",
-    "GD6ZLHQLWOPKWSQBLWEKP42JZTRGBY2M6MDPC45YADEMFKOTC4IKBBIK",
-    "GD6ZSOPHDSBXHD3CXJ6TG7BS4ZIQV4FF5A6PY3EWP7KHMLWWACOVCSCT",
-    "GD6ZSRQEYAEWFBGYE65QHRYV7CVV2CCAVBJAOGRITJCSHQIBXD5LEWTV",
-    "GD72BLKILLE57MOFMB5YCB3IGZKSREQXOSZQ4UT7O5CV473S2F3TPBQA",
-    "GD72BPD3QGLS57U2FJJ5AFFFIQI5OCUBR2QDMHMZLPCXJAMRFW547RMA",
-    "GD73ASQIEB2Q5TJ56ANNMWSFV32AUPXYP63L7TY3JU3G43Y4VP5VVDLP",
-    "GD73I5MHDYKOWUOQPAZBEBYFBQBGGF46SJSKUBBQWSXS5CWCQGF2CVPY",
-    "GD742EYPT4RXASWD6IUSBNHZO734DTY6TE4HDWZBGYCXRETSJPE4R5EI",
-    "GD7545NMTPVI6USVBSTWJ6YJHVCO3UAU5EDEVG5PUOG3KZX3FVRDIBVE",
-    "GD75P5W2LDW5B4IIOG3ZYDWKTJ7E3WXAIOEEU4S7XUWDQ5RHSL6FR3A3",
-    "GD75SUBL7S5IJLKS45XBNHLL3LFNG3JY3GN5KLT2P3BZLFIVWNYANEXT",
-    "GD763BOYHMDLJ4ZHJGW6NVL5BTLPUQZFI3JSF5D2DM275PCX5P5AALGO",
-    "GD764FYE4ZPQNGGOFZSHTXUNX6SHO7Y3LXXM4GEGWRCIA3L3LWZEAHBF",
-    "GD76GIZ666I3TE3UXC5MHCICEF6D2JKBJ5J4KHUMR63DZMGQRW6MFRHY",
-    "GD76P6AIMEIJ4NAVGYYXQ52UAZZAOCY5D72NEKTHUY7P4IE3LQNRIXDN",
-    "GD76PXGYEN7ZC36LYY4XB45TDR2PDQGGDM25DVNEAGL4H2P7Z7KL4QCK",
-    "GD76RE24FDNM47MAFU5MUPGQZGZNWFSDTYIEFKJ636AVWMIZ6YOPARR6",
-    "GD76XAT4GGFEWGQYZOV6UQYE4GTE7SQTB76LD4UMMEYNETSCSQQ23VGY",
-    "GD77INBMSHUQR744REDB6LKCI63GZ3Y72HG5UGTY5Q7YBPNNNZL6QOEC",
-    "GD77XZMAG45BC6ZEPWZB7TMTYGDDI7QKDGTFTQRXZ5YSUN2CLNXNKDJW",
-    "GD7ABDRPAJECPHCWQ4EGRT3YSZUSRWKPEXRXAMLNOMYAPORMVOCJILU4",
-    "GD7AEPHYPM4IJIMFYMQAFVFHJSMKNXV2F3ERWCWHQISWXINY2SM5AWKM",
-    "GD7AMRH2TNC357XKMOMB4WLVIJVUK34WTX7X6MD7YK26I7PUSUQUHAJB",
-    "GD7ARQVTAOO4UHVV4BTQKY7AAYFQTU6CEVNKZC3C4X7J3MV2PGR4FE7O",
-    "GD7AX4C22IWDBCYIJX4V3ELQVPJENVSBJTDPCXJKABJTAMPAKCWEOSKJ",
-    "GD7BC66FQUNI4QXIZTOK6BZDU5YUUO22TKVEVRZGYWGC2ZGVEG5AWQFS",
-    "GD7BGAXGHUY7KIJKV2QYD2NGYMJU772GF66KADYZILJMTCVQ3MDSWISS",
-    "GD7BRTB5BOZZRDCMQSP2QK7SRWQRHEZIQ6W47QLGIAQQYDTO4R2DFHLP",
-    "GD7BT7VKE6SAHL7VUO6VZ2JX7XHFLOZDG4NLCV677O5LGADZJEZBNYAL",
-    "GD7BVGOYABYXBWESTR2AWBDBDZRKMZTN3VTJR335NIT2PVVNY6STUWVH",
-    "GD7C2AUMWBYJAYY75I3ZZYWM6CX26KUFFDRDYZ4JRHKYJ5AGSJUXZU5A",
-    "GD7C6RVLYLQNNWB3R7HSY6JKPF77M67VTMJTMHYGONPILKDVMHR5NOE3",
-    "GD7C7ZFS35GBNWUSQF6PWH2AUN4DKLCNRZKXNVHE7ECC7VK3D2NENHIH",
-    "GD7CBXIXKP5CTPUAIYBNZ2CXRT37UXRNCNSQ4NUSL66HYL33KUPOZWXD",
-    "GD7CUPBOPIBH737AZCA6VGAEN62D6WGY4P7TOQCTS43SAJAS5WTJMGN7",
-    "GD7D7EXXTGTVJEZYXVUFRZV7UDVTHKDMN3A5RM37KBXIFTMIIOXSAZZ4",
-    "GD7DEAXGUTHQADLBQMUXVOMSBH74JAFBFOPSCSZCLD25BV32FSK2KEOZ",
-    "GD7DLNOBZZSKOQ47LKXCMMJWLZSAUKSYK66RRJUXMAJKYKBNCUALZ2TB",
-    "GD7EDH7WGQGNS3IQ2AWC5JRJTX37ODTNXFYAXD6DMAAFWAT32QCKVA46",
-    "GD7EFOTPW2KRFFTVQ5AETNZ6APZBUXWCRT7F5I33N3IOB3AHGAN5GRAM",
-    "GD7ETL42QMZU7WGWRVAZMBRXOVOKINLK2LG64YPRNCWD6NKKFFOONPRJ",
-    "GD7F6GIWYGLZLCMBJAWZEKZD2ZFD57Y3DAOBQI2TOX3NDR3SMSRUPLLC",
-    "GD7FCDBF7UOZ5V7VKHS7GSHGPPBDC6BBNZPJROVO3U323MRNEXQXP6OR",
-    "GD7FO3ZV37BLQ6AL5NGI32PBOEWDU6W3B4RZGEWFBNHQ74SL6XOGNMYW",
-    "GD7FXCGS7SUCA4V6Q3DWXME63COM7YN6UCQSO7OKOZLIPC76M7CK54UT",
-    "GD7FXVJR6KMJXAZ7RKQYRM4BJNRYZIDIAMJBL6ORDKNSKAYEDRONEB5F",
-    "GD7G27T5YKCL4TUX3LZOHNAWOPWS4VWLNLCP4NQT5YZ54FWGK57IZSQM",
-    "GD7G6FMAO6AHLUXOFWIU7IAX7BJOSYOXJSOSXNXZ3B7VQUSHUDEZ25PK",
-    "GD7GA7CWLL62ECOBY6AEX7FWEJIXE4NLDCSDGASK7W5AON52HLOOA2FE",
-    "GD7GLW7B3HXARG4OAKXZZOEUNBDKUZ7D6JSGUHTNKQ3DLSDSEXYMNZTB",
-    "GD7GY3UHRO5H4FCJP2NYNCUT7EEJ2CLUCE5KEO6ZKIUUHTK3DZ33SHSE",
-    "GD7HBNPUAIK5QW7MLC7VKKHIQZCYZYCAC4YNRT3YOPYPQRK3G5ZGQJOS",
-    "GD7HLNMKW5XPXDNBUITGBBYFPBKBJME2CYZFHWMK72OENWAQ3PC7WAXE",
-    "GD7HLUGKAKMS7ATL2VXYUCIPFX3RCU2ZYKTJNZSWFOCOOIKUWJ3BUTEU",
-    "GD7HQX4PMLWP3N2MKB5BM7ZLG36CRZEMPJ4W2DUT7WQBXIHXIEI2CX2D",
-    "GD7HRFETF5Q2BSY4HTRFTJ2ZUAEVX5Y37SN5KY3FRX3GWXOBMUQ4BP5B",
-    "GD7HTXIBOAUP6ZCNXGCIHVYDNT5MEWBABAYCN4RJUVAGSHLQJOAQGCRO",
-    "GD7HXFPEZZ2T524ITMTLIFSYVXKVUWADY44BO7MXHHIJKF7ZW6WVLUO6",
-    "GD7ICIEIGYTSTU2FHREHJ3XELCX53ZB7YEPJGCGZCG6VEDXNZDYEHMTL",
-    "GD7IF5B5NJVZOXOVHNWDI2DEMSELFF544BEQUDCJKJOKDFJEBKKT42JE",
-    "GD7IFHKX6MY23KPOQIIOBL6FKQFQNWYEPGQGTMR65KKNGE72OT2ANYJ6",
-    "GD7ISBFMESEGDXEK62OGO7KDBEXSSSUKYUDSP5ZMOOYCORKDNUH4UTI6",
-    "GD7ITUDS2WSBWI3HA4V7FMES2KN4TJOOY3HEX44NFMV53ZUYEGPLQEQH",
-    "GD7IWXZQMLWP3SU6YLARUCTGHP7QFH33I6DQ4U7LJOXTLFRVJEXF3NV6",
-    "GD7IX7PD5MABHWP7VFYL3A63SCAFUGJXOJLVBOWQJCJGEJODVQKZOZZA",
-    "GD7K3LVDKUKHQ3PSBYJHG76XPY6NO7RB52IRHMFYX4CKSGJDDL2RZM5X",
-    "GD7KCT635NI6KXLRXJVWUC7L5WE6HQEZKZ52LGO66QUCGMZW6YVMELON",
-    "GD7KXIARAYZD3GWUHNGQWQLKUSIPACD4ZGUWJYMUZGEKGBD6A5MAVSDI",
-    "GD7L6XMLODOQ5QQDEYOOLBJTAIXXGAR47QX673H4EGAQROH4ZSMJGXLM",
-    "GD7LA2LGXARKKS72ZVJ5OIDXL5DZ4YWDECZFOY3K3ZKNAX5VSZ56LCUK",
-    "GD7LCLBJNZ7BKWJLKWMORAU3DQJWNW4XIYSIVHOAD7S344JTWLJ5ZDHN",
-    "GD7LLKXVRKVIPWALFMMVPRWV46CHEX77SB6YEJWGA3FZIYAJZ3NIC6GG",
-    "GD7LP4Z2UD73LR2CKURBBLMWOCDXPEJMHRAHEWI7JPPJA3EGUM2N6MUV",
-    "GD7MLEKTFCVX4MMNJT5UACOGT7PBPH3UNFEWUNNCJ62WBNJM45ETBCO7",
-    "GD7MNETSXMZM53Y65FSNL4NYBXH46RYZEBPDGWWVYOEAUZURL2CWLALH",
-    "GD7MRLD6VVUNYHKRU5IE7DGTJCDR525WZBXPY5SXVP35VVYKOBYIHPQC",
-    "GD7N2BFGNFMGPXIHT4TUDDQUL665BBGSRVINRPLHMTRNCFO3ZI3NBQFS",
-    "GD7N5CM32RR7GNQT4QPR5LZ5TA6SNNGBWSSU3DTCRPCSBLTOXFA3LJIS",
-    "GD7NLINRWK3HUB25HL45UWMKZ3U2TMNASEUHFAQJQTDJVZZRLH5L6CQD",
-    "GD7NV3CXBOATCRY5K5SRBRNQFNLD5SDL7DIYPZKZHHVGNRX3KT32XJPX",
-    "GD7O4GL5VPD4BZKQRVKH22M755VROYRLSZQ2MQXPMVVNSGU6HHFLEWDH",
-    "GD7O5OEVQ4Z3URSS7TE4AJDOFH524ROL4AMZSDJDAWGWIQHACEPUGASZ",
-    "GD7OAGDIX277IW6VVPBXTX3XUTLDMH5ECKSMOILBWRHZJMP6RYXNLAT7",
-    "GD7OBV3AE4OE3JND5KBXLBNJRAESCWXU2AHVW2X6DNA2E6TVZQHTJUSA",
-    "GD7OTZ3MHJ3YNUHTAIWMBMVE5LO53AFAO5KOQHKQC7FELW7D3EI6GEOS",
-    "GD7PO457ZY34J7OLRE7FXIFSXIE2KVQSHMOQ5OQFIF6NUF3FWRTDB44C",
-    "GD7PQAKNSPY2YRX5HMOVVQELF3K3LYCHMWOAIKTOVEGLYYWQB5H4J7RX",
-    "GD7QIMLHFPCHZL4XZYTW5IOANURLX3VSJDVHB3U73SEK7B6PGWTYJGQR",
-    "GD7QZZSZQ2MVNELBCSZZXQYHGUBIMZVW22FFYE64XZ5DATII3T7LDZCV",
-    "GD7R7LFWS2VAI2OWA4YYI5Q33V3FAGYYHAU3CWRGEQIZN4L4XIY57LII",
-    "GD7RDS6ZG3I3QE4JTYXD5Z5RDGMAPDSCQKHRC442MLKY5SAYGO2DDEJI",
-    "GD7RNBPY6GOQLKG6P52OQ5R7TVJCA4OFCAHJLZTDMNBDJ6DHDEFLTKUY",
-    "GD7RR765OM7RXVHCRE535EGEVC4EUUCKOUDQRILCK4B3IDEQF5ISWISS",
-    "GD7RSPTET25SQZGNROGYHV6I3WGPQAPMWAIZVZNY3D266EL6P3ROJURA",
-    "GD7RYLL42OC6JP52MSXUKVBLYFG24B7AIFS6O37OB4O7JBE5ZVVAMSIL",
-    "GD7SUX5Q7XLXJQCJQK7CMQNWZASOJXZT6KV4VD6M6H5B5QUTCPZXVJKP",
-    "GD7T4VZEP4TBDR34LV753ZELZ3UEAX7VASEH2IO6PEUFVISAOZVDUSTA",
-    "GD7TJVZZAUDCXL5WBSXYAHEK4FJ7M5TRLOT6E3NKKCT3DDTA2WFESQDR",
-    "GD7TK3BFZMMQ64BHORKHCNUR6XHVJSKOCJE7SKE7TUYHL2WBZED7MBTV",
-    "GD7TPKV2HCRSOVIFG3CK3HBUE2ZOTWIGD2KUTJRQXTJFIST2MM62V3MP",
-    "GD7TYL3BST2AWAHP6FT6DL4DFEWQYQELOZ53KA4SNXOH3Y5FRA4XTZNO",
-    "GD7UGM2N67NQUU7IPTUH5HMKP7E4CSJEE3WYGDQHGEYF37D5PAWUKWHL",
-    "GD7UVDDJHJYKUXB4SJFIC6VJDQ4YADQCMRN3KLHJFV4H6NIUAEREVCO7",
-    "GD7V2MHRH2CEIAE7Y654RKJ56KRBEGRVR6LYLWG2HI57QSUPF6IEPIZL",
-    "GD7VYPDUI5QPEXWTO4XTYLDGQSLDAQVEAPYXKLND46RA7LWCRY6FM3DI",
-    "GD7W53M6D6NJUMWFQUY7VECB42LJX6IYGBP7B2Y6NV7MNEY6YYBNWLRC",
-    "GD7WNP44E3RBLMOGBN3K3XU4H5TTLLZRMSNRKBCQDUJTT3DDQ7OHLMYO",
-    "GD7WO42XQ3ITXZNAYHFWRWZBQE5UUFXNFVK3ZL6KUEJUSR4WHXOZ67GJ",
-    "GD7XDP6GOLCFIDZVYEJ2D3BZRPTA22GO336KONMT66HMQTI3KD2NISHX",
-    "GD7XPWA2S7UNMO47ST66P3QJRKWYINDLFSX32FCZK62I56MCFIGRNPU6",
-    "GD7YZP7WIONP4LV7CMQMLIUAM4D7D33JOOK4IP5WH6EH5ZCOIHKXB3OP",
-    "GD7ZCIB6H7K74ULJAYYZ5AK4AQ2327KU2HKNQBCNTRMED755Y7QEHA4W",
-    "GD7ZFSR3G5WQKRI3R3PEAFSGCUBGYR2QJNQSP4KO7NV544YVFAJZXVIT",
-    "GD7ZHLNDL4I4AEALHYLFWWYS2TGD6U6MEUSZA7XOYVE7N74LCTQNUSUY",
-    "GD7ZINHJ4ZLESSIP7FYDS27UXPMS5ST67YK4LPWRMV4XBJE5J5BAYKXW",
-    "GD7ZODMGPNTQ6VR2WR5H2PQ6EQWEMXJ5B5NAAF6YSIJ4UY3W5LXXCUE5",
-    "GD7ZSEGSACFMYHKAZYAG4LLEJPMSJ2FH5JC3XHDU5W6CLKCGKVQGQ4P3",
-    "GDA26JSLJTMHAG5YJA3Q3B5JH6A7VMDNT65QLTGZLS5WH6BCMJJBLMYM",
-    "GDA27ZRY7KOH3ZL6TMY35GEPSNB7XMS2H6QUFXQMXRJG5ZYMXQ4AAHAZ",
-    "GDA2JPCEQKZPQO32ME6FHSE6JPPXLKNZMXNRNDIWC2Q5H5TA3RGFNRZC",
-    "GDA2VGRWZHPB4RME6GQLAM2BP6GH4WEEMY2JNW6YSOJWUMA4QQ34HDSI",
-    "GDA2XOVYZ6LW3OS5MVYKWJ7KL2XKLFF5R2L7PHRQWS6COVHQTMKHWFLR",
-    "GDA2XRHSBKH54AMJ3RB555Q5EQZSZMCLYHOGSZQ437NPHEPCJQX3Q3R4",
-    "GDA3PEQNXGNOMQFWGVGS7PJCE74WYJTT4R6QPLN4LP5S2ZD3DQ5KMBTC",
-    "GDA3RD3NPVPLUKFVYRTC6U5A3AV24K3DSURPLEFMAPX2SY3F6XAJQBRM",
-    "GDA4OSBS2FDKYPWZOPM2FRQ6FS2ZKK3EVK2TQHQJ64AHSG4DSNSYV6PB",
-    "GDA4SS4QDP3ZGAX4YQ7DKOIBPMZWNI2XRA7EH6X5RS5LW4Q6SWTKMDZN",
-    "GDA4VQPM555J5I7YF2XWZVBHD432RQQJC67BPMUUXPWLXZLKJO6HNA3Q",
-    "GDA4XBOMPPIFX27KXUJXHO3YHR74XPHOAXB3TQSJQ6OVGHFG475YWPHX",
-    "GDA52OVYKQXFSOMV6Y3H6RSL4MNJZPZQJHPWM4SLVBSLTLXVBAYECCJI",
-    "GDA6RHE6UB3UFTG5FSMCMSE4AXC4BQU4342COWKN5LJWYSUIGP2W6EDD",
-    "GDA76KCNYXNM7YTHR2WBG44EY7ODD3YTKE2IDJU6K37ATSCT3CCOQNGM",
-    "GDA7BYW5UEDLQWJSJUYVYASVGB56U6OT5UDIJSTTROB4UKAXQT3BSXKT",
-    "GDA7G545ZZB5PQ623J43WRCUPFT5F4UB6GO25NMITNIIBGXWO7S3CA24",
-    "GDA7ITXTTBVBDXRVY4AZT5QJ7PEFLBGHQVQSXGB2Y74G526A2YMK5CZO",
-    "GDA7V4F5ZD5U2YOPE47QEPFB4KDCL6QGVYGXOXWQHS4LWBWIKITCBPNM",
-    "GDA7ZYM73TYRIGSS2R3WPYCFGL6D6Z2VZGMQAQG7APZ3DK6SWXE4VHFS",
-    "GDAAEGO6NDQ2CWILMG4Q53XVVZJU7OCG4IHHOYADW6DI5OR7BECPGTBO",
-    "GDAAJB2O4SGDUZMV6LPGMO5QEQFDVCPNCSCXWHDXNKUPO5ZRTEJJJRQX",
-    "GDAAKK35F4RPESJXEHEIUJCGICZFFG7F7LUUJAJ4G2SUNR5JE7OYPDRQ",
-    "GDAATZNWG6HARLFXPJJFCIW25MIKQMKVRVT6OLC5YI6NGOH35TG2WQTC",
-    "GDABTBF7LJA43GETBURTZ57YURZW46SD4Z4FUU42JKAZIPHUCZN6M5TV",
-    "GDABTFX4IPZE6MTMP6RCAGBF6AYV2FRTI37YQT4A5XEWIYW6G2H65NPR",
-    "GDABYW4UUPRA4557DEGLWT7IEJES3UZBXVNNYVFOUELC7WGFFP5JHOGN",
-    "GDACCE3ATZVZRO4BTICHVFNNX64Z23KN23ILZ2E37KPF6CXC7LYA7EY6",
-    "GDACUYCK77ZMVEOCLMDB25WAKMJEHTOYWF7QFF2CVYI4X53D6E5XTRKT",
-    "GDACXCFUWOL3URRIKBTD4L3OZOMTHLBHGVL7GL4SLAHB23U5OIZUWHUY",
-    "GDAD3RIUDO2A2XFW53IJ7YYMCKO4GZLO77U2MWGVAQHV6BYEKPTVWHIP",
-    "GDADLPYFIAD4JJT6V3E2DMM72GGGUAAOOADFXHDA67HYNHHQKBHSDOGE",
-    "GDADMGVBVDVAJZNGD4YN56AYTOOIIPAJXT4SR2K7JKDENRFDVKMAXXVV",
-    "GDADP6NKYT7CV7EDTTV3NCR7LATFNHRAFBBT75N2GV7VVKXNVAYSG5QX",
-    "GDADRNZGTYHV22S4IT64J3KC53XZWJW3JM2CSUURAMPTM6YFCZNDVCF7",
-    "GDAECCXK6M4HTBYDH7ZAJ2KOLWD2ZP5F47QPKTDCLDII3Y6ZVWFM2NIG",
-    "GDAENONB5YGEX4XSZD4WTBBDCYXB2GMKVA2D5CBXFQ5BLSMX4CLXKQFO",
-    "GDAEPLEOY6ZGHFCRI7O3YGBO5OISDPKYO2WB4WB64E2ZI4HOPPIXRFCC",
-    "GDAEQR7PX2B3QWNQCHSWDEMBOKGBMUKVJHVQK4HE7GRPOS6X5B6EZDDU",
-    "GDAEYAYBCBW7DOYW2KN3JH5ZICMVCDW3ADX5D5KSKECTVW5PHYSZ6C4K",
-    "GDAFEQCGITJ45FC22SOHWUXJPHWBHSUDLJF722LO2FY75HSDPF6DILAB",
-    "GDAFO4HKCERTSVM52RG4JCD6GLAIAWWSRIJO2XGJDJW25WZWRWTJJH46",
-    "GDAG2J2CSSIKMMPNPIZVPU24TZBL3FVC5XGQEMTQGYPR3Y2ACPCGBKWZ",
-    "GDAGHHBUHZH5HNUB24BGEK425NKISOXI65SNBSIUWF22NBJ634EWJVVK",
-    "GDAGOXSDFNZ3TQXXZ4XRI5C7W5WA2ACLXLTVBTSBAGHEUB5C4WEWJQ2H",
-    "GDAHAIGOE4YT6EMP7SY5M66IE6DO6NG3PGLGX5XYE3PCJ6IUYUDDPXRP",
-    "GDAHAQORWPEYPKW356YTNZDWXVK2Y5H6WXD56JNTXI7FSO7HFRONOZWS",
-    "GDAHN7TQYNH4Q3VAO3SIEKD7G5U6GICCGJHSNUEO3GQSJLHDT6WIS2RU",
-    "GDAHZFA3TNANGBSYREDKCB62Z23P2B5YX7FWOR7CGZLYFF4OCVA273XI",
-    "GDAI7NAYJ4NQKU6XT2P7HIADVQDPVL4C2YTNSQRZXQYGT3WSBEOGXJ7E",
-    "GDAI7WSMIGRZQYSNATUQFLDLFN3K4DPM263HZ27XKVMMW2YKYWLRHN45",
-    "GDAJDFKDBZCLNRPQDXCO5JXSLQFWRKMLH5YJ7OGDR74UKYMB4XYWMZBM",
-    "GDAJRSR7FUA6BOZRP7Y6XKQW2H3YFZE2MS5WVZYDK6WAZLUZHMFA5ETX",
-    "GDAJWPHCNUNRTEXGHWXH5KR5CBI6AINYNI6L4CJIGZDCE6UXFNUHNJAE",
-    "GDAJZ726IUODMNWGTD3YJRMHHT2FBPDE7PRR475YAYCBT47FQ5CLT2YI",
-    "GDAK2GH7YD4DD7AUTV6JAX36IJPLWXVYR7ISNN5I3WILQKJG3G2GIDR5",
-    "GDAKCNNXHEL56K2VEUAAA2QRLU2UA7ZBU3QWTT5SCUSGVTJJACOUBSG3",
-    "GDAKHFCCJA3YBZG47XZCKQ2O3HEVU6CKRZMPO7LWFTZZGKOYH7B3WBCH",
-    "GDAKNLGNLVD4DPQAITB52HSSBDK72JUFM5LN5AGLN3WL43GSK3FD6VMO",
-    "GDAKS3RFYBVEQG2Z247URRF5UFM6BNXGV5Q6CQ3SRRQGXGJIJRW2CADP",
-    "GDAL4NWZKWFJOL55MD6IH3WNE3JWA5RZXOHCNUAGYMT3MA34AZGEBUSA",
-    "GDALCBRGRPLGA2OJWYJY5RZJJTYAP53D4J6KM4ZDR6TCZGSTUOHKTW2B",
-    "GDALTCP3CFUJKVLFBZBMPGMDXPQY4WKUFS4JKTAVZ2VPRKXTISX4R44L",
-    "GDALVZJKLNUTIPGDCGA6XOXTRPE2Z3YSLE3RDNDF4YBSQZQQOXCZNBG2",
-    "GDALYJ6MEQEW7HOD7SCG2AOIVLXBQFZ7BITHAOCOPUD3J4HB4UT2Z2AS",
-    "GDAM2SSJ4TVADP5VCQYX2SUS3EG5GYRGC5TOAKT7E4H4GLZQSLKXWKSW",
-    "GDAMABFABBYJT3WIOUJ2NREMGWAPV4YACVF36G6G5YAT3IJAJFEURR7T",
-    "GDAMELQRZTNXL7JFP3YPWU3PBV33E7NT3NR5FB22W3HIBU32VJ7CF2NW",
-    "GDAN6OJ5VOP2QT4IDRFHCEM3364PEXV7KRYSODGVL35PGHVAN7V7VIKW",
-    "GDANCN6LLAPXDQHVZNZKLGUABEHX6SUNRYVYJYIG256R4RW6NY46OXBV",
-    "GDAO6OG7FB2IQB2VBDPW4HCFJEI5AYZVI7YWKQHCTQAYJRFIG2BR5BVX",
-    "GDAOCE6VCJR67NLB43ECBWV2L2SGHMCN5MK4LKBJ5SHDHK5BGTUTXKDT",
-    "GDAOEJA3WRZQCVSGEPPUCHD62NAVXZ7I454RBCH5KMCPZWOUBJ3TA5DE",
-    "GDAOF6Y5IKPX4QCSEIR46CNDMIISHYHLNSKBVMJKH5T2RK4QQIBGAKVE",
-    "GDAOKTUJR5YDNJB65UOUZK3CTFIXNDX747B2H7USQQR3QDIFP6V4DESP",
-    "GDAOLQWLBTYZZEPVXOLKK3W2KPOAE7QRM6Q23VTPUSIFSKJADADY2VNO",
-    "GDAOOVF2OYWFKAQYAJCO6XZUSCCAUEFVZPUT5AURGK4ZGZDRP4UCGUWD",
-    "GDAOYSG3Q7MTKTPRYN2DH4UMSXF6NEAPZL4SN7Y42HP6TFTQ6WN7N7HA",
-    "GDAPJ5EVAXUU5ZLDHBUQF3MA5EV7S44T3AJPY42LMSD6ZZKQOQIAWSHX",
-    "GDAPSXIPDKDH5KJGFYF6FQHR4X264SDCX6BDSF7523VHKZ2AQRQWETPG",
-    "GDAQ4YF23EWJV4EUUXP5HIBF5SG4Q24YAM7MRKU7TXTRCMMPRJ4YGPGE",
-    "GDAR62AC4GN6SNBGCQYBZCAYJECUR2GFFOLP4IK5PTESO5MWFLF6SHOT",
-    "GDASOD3N7DCMZXRGMZBL22AB2CXR3DJV5ASXKDOAX3GSMCGGFP63YGTQ",
-    "GDAT6N3L5NACJQAQNIQ4TS6JILCKXFYEHQOW2WJ3GG4MQVD6OMWT2UVG",
-    "GDATEGT66J5BB6I35OUXHCR33YZZGUSGXCNRFMWACWUYY555YRTDH4UZ",
-    "GDATPMP6SUDD7A4MFLSEMII45B4GROQARN3NLVEEZIZPTMYDTU44F42E",
-    "GDAUBIOEH3LOWCD7H67W3ZGTDV6XWT275DLVG662CIHKPVS7VXLXNETH",
-    "GDAUFFMFD6BJNWOTX2CJGCIL6E63XMKHUFOJ3P2GZYDK26EJ7LAOSJFG",
-    "GDAULFCKPCLWQDGHNIHTYABXEGQYBHHREJYOFH6RWADNSWM5BXNFFIAR",
-    "GDAUP5VDKW3B7JSOUX5YEYSYPZVSYE4V22QT27QC3A3U4H3UJSTMUHVU",
-    "GDAUPUXPPBWDI5IWD6YITFO7PSBCZ2BBJB6YXDM6F6G32YDNWIZNVXVS",
-    "GDAUQ6JM3CFQ7AKE2IO4QLLKINYCQELZGBVZHI7QPDCTABAICZBHDYNC",
-    "GDAVE6ZAMVJF2QCA4MM7MYWF54BF5Y27PSBSCYSEG3WI4SZWKT24YPFH",
-    "GDAVH7DWM6S25MSH245QYQYWTFQHXYUD6RKYTV7ACP4RDGQ7XPH2TT7X",
-    "GDAVUOR3U3SXT2URCMIDWKDF6YBTBWCEAC3XCWKGUWVHDHQF3ADY2GD3",
-    "GDAWC7XHMPR2BRBKRRE6PZ32ECJMQ7RQXL44WCWJ2EEJD45XHOOEUSLF",
-    "GDAWMDLFTCBA73QPUMRVX7BHHQKCJBDUAIE43OTIFUVZ7NOL5MVOJG2M",
-    "GDAWOXMAP2A56LMHFCNK3RWXVI7KWGTKIEP2R662PEVBKZTXYGLMHZD7",
-    "GDAX4PHJHIZHVQW7TJORXMJTS6XJFAA4NXSIWJGOHACY7SDBBRYHJ7HP",
-    "GDAXUIYLAOMBRXIMBMXSGR5TXGZQ6JWOWTUDDUDD6JWVYE6FCZ76X32X",
-    "GDAXW5ALY24Q7PEGB4PL2BQBXAJZCNT3VN3C2CJMTLMNWJMWHCANFETH",
-    "GDAYK3GUY5LW2JJDMO3TNRIIUDGM2PDPSYG5UICOM6DPSOZH5TV3EOP4",
-    "GDAYKFKQHSIADVFPQRCMDVMHWIB4HDKLP2EZ47S7BZJZBEBQJ6GUETHS",
-    "GDAYY4WTU6X6LFLM556HX4FVWF37SM6ZF3U2KHU5LUBSCF54SGOZZ4N3",
-    "GDAZ3E5AZSMNIQNZ6LVA5BTFZHID35SDX3LBONBTDHIT3MODVGNYBHWX",
-    "GDAZEFKN5E2J5U2F33S7WVYV4S4UEGE6ZI24IFREQKGCISCNHOKYXETH",
-    "GDAZHOCX4R2B62AMN24KI367Q6SQBL2EORIEKR5WAHB5RFPGPCDGHEDJ",
-    "GDAZQBVQJ24JUKDQHCHRM5P6NJOODDOQBUFYMA2V3GUSOWFN4JNVTCAQ",
-    "GDAZXOMLIMLEVQLGKXDD453T7FZFLBP5UKNL36LA6YMLNJ2HG5NXW5U6",
-    "GDB22ZJPESBQJB2NSM3J2RRXKP6TMBZ4DQ63TY5LD5B7NMYWJ23A2VO3",
-    "GDB2SUDE63B2EKTVMHJNSDMJMX2OTX5OR4LIPWLGYPEVJNNCK6CAX7UJ",
-    "GDB37QWLRPCD66OUP7QHYR5Q2GT3LZNHNIX5H2L2LPGSFZADWAQXYWKM",
-    "GDB3D3COJ2YOMMSHOHYBBCKHILR2ZHIGK7FQUPUXAE3CTNCB3CLGQVWC",
-    "GDB3YRW5MS6HMILSICNVDCDJIPDK372HBBH6B5KEHPMYCC4SMHMR6CT5",
-    "GDB4DKND7TVWTUYPVADDRKEKCSVESOQQFBNFSC6KEI2BXPVDZUQBQBID",
-    "GDB4FY6UMFXORRMYIJP6K4MONAPBSR7ALHFOYNQFOPSL4WZA4RNA3FYH",
-    "GDB4PL6HE75PEEGMSMGEIS4SUHLVBJMSRG5SIWXPXN5NRHRO6T5NMURT",
-    "GDB4Y3QLLORJTLUBNIN6ZEXIL2FUK5KORBYGSNLMK4CHEGTL3XR5VBLK",
-    "GDB4YIHMR4SPVUBHWCE6GSDLTJFWTIBJEQOM2PW35233N3WNLUDZBGQX",
-    "GDB5HJJOMGFGJI2V6XKPNZ22AIEISNZHWPDADGHQAGRB64YSS5XUPFLA",
-    "GDB5M5PYIXRIBVLNXHJJMWUEFAMO4NU3YTSMR4PWJE2OCA5OQNV3DWGO",
-    "GDB5VCVV2SULFYKPZXKYXEUN5GLGC27LWL5SHCZYVWLSHNF27P4JDWI5",
-    "GDB5VDFOIU6RHJ7DW65FADQJA3DSTVTVQNWYGNHOZDKVDD3N2XX6ZAED",
-    "GDB62QHG62AKDP27YV7CABRD7LV2SQACF4RZXB54FSSRLEFA6O7LFNZK",
-    "GDB6OY6JXWM55M3KOYML6YX7ENRFIMR4Q7Z6MEIXP52ZVMZMTWGLMMGS",
-    "GDB6VFZ3RADNVZ5M2RKWGLJ3QHZNSTZ4GZUBWYWCLTDNDUMJTFALPALL",
-    "GDB7EFFNFLR4JGXGQ43CONNN6F4NBMUDQEFUEYEXUU4YROKEPR4VCCBS",
-    "GDB7IHCIJ7GBMG4Y7AOJIIT2NYBNHU4UZBSZTE72LOL3Z25SCRBMJKRD",
-    "GDB7KGCPOMOLWAFZMZCJE4OHKDYLHXJAFZWJFYZOJKHGLD2AVGNMN6TN",
-    "GDB7SEYGDO7RAKCWETBBQ6F7PY3UYP3AMHFQDLMIGRWYMYAXBNSYMX6M",
-    "GDB7VWU5D7KDSNTQMDD6H5JCHZV2HJZNW2OEHAIZHO3H6C6CBBA73F4L",
-    "GDB7ZIYLPBVNOYWQFKT5AINVCRND7TXMTHRP4FJ6IX4S67MEBREWPNLT",
-    "GDBAUZQ6QNSHQ45KJDA3VPRO5ZMFQEDB7OD7AL5GD4ADNSRV2BNI5SN3",
-    "GDBB4T2DA4CPHIUK3I6LV6SDQBDYZ2OQSOAZKY3WESIGJXEKISB5JFM6",
-    "GDBBDFC3ENEULBDSEJVAPFW2DYKA6GVQ7EX3USSQZGRTRVGWARONSIJA",
-    "GDBBPDKRNQS33PAVYWBW6MA47TW5GZ3NTAKZT5SUAXBJPTJ3N4O4MKFE",
-    "GDBBVFXW3ZEYVHCRPCAMCTTVMBEQC4QV6KNZWGYQHOZLK2KNSA66C4XB",
-    "GDBC57DHFLTWUFDKGO3MQVSKY5Z2ZKO3VJ3367T4R6LCI4O3RQIWLFXC",
-    "GDBC5EB3WNQV2RPGRTM6FEOP4A4DRV4GAPKFBCPWCBS7ZSEB4RFWBSBB",
-    "GDBCB4LPTVWSDBL6TMLP6VBLNK37XELWNP7EZH4E6TYFXC4EF2PAQHKD",
-    "GDBCE64XWH4ABYX3FSBVWCBSTRDO2QFUQJM3GZMEEHKCPKV57AJBTSHX",
-    "GDBCERLMC2ITWOPF5C2B223WPC4LJYIM4K5W5KNHKLZZCZQLS6PHBJRE",
-    "GDBCHKTHJUKDGSIQSTBUXFWVP3QVART5LED6KRZQ5X4Z5WLT4BGYXWCI",
-    "GDBCOH4P2OD7P7XLV4BK77SF6IKIHN4LLCWZAONRQIOQEDT5R6QLAVVI",
-    "GDBD4PSRK6FMADG7C66A2LCZ72N77IQ7QU7QVHMCQZG2PWY6L6JTEJG2",
-    "GDBDQW5LLIB3BM5ZZSHMDMAK27SMAUARSBTDWM3CNL6CCWMTPG7JI3SO",
-    "GDBDVMC2IUBW4UMTWSMSLVKUWC3Z6IBD25M6K42HSN5UZPAPJE5HYRLU",
-    "GDBDXIMRKE5U76V5V6LEF7DVAAO46JLF73EQJWGC7G56D4SPKLWLAOKX",
-    "GDBED3LC46BJFR3DT6BUK5EDIFFOP74YSMWGPR77E2HFXZ4JQ2MEO2PD",
-    "GDBEUXULEGHCUEAYBUL264OAZC5IIIXF7E4GKCTK2ZCOBV4UDEQ2KPJR",
-    "GDBEVJPKXDHLNJGRBAXZC7YUIXG6UZWMK7ML4BAM4D4VP3AWCAWHTT3P",
-    "GDBFTFYXXSCMH42RFL6G33VNMYHIKXYWP6YHGBTKOED65MY6T2Z63BCU",
-    "GDBFYOOBAHAQGOVFRIEYXBRZDVK2WTGKGYIFLQZIO2ZAMHK5KK6FM5LF",
-    "GDBGKXDTJOJZTD5644E6DEEFQHHBLBLLWKS353M77YJPJOVK3WC6DYS2",
-    "GDBGLDEHQABEF5ASLE6SV3YYDDJIIAZOD3IYT2QQ7Y6WO7QXLAHTFZ2W",
-    "GDBGMW6BSFAOY5EUA2E2T53BJKM2G5VNYK7EITD3LW6YGSS2YIZFPH7P",
-    "GDBGO5IE7CW6R6OCBS4XVVGV2URRKWOHYPR4A6KXHVZNDWOR5XEUXVWD",
-    "GDBGUC2GL4ULVRWB2K5ENBNQF7TIQOT4K7C2B7DUJA6Y45KMSJVH232K",
-    "GDBHEY2TGG4YHOEGZVHQTDJRALKJPGEZVLLOIZVC6W4DVS5T3TCTDQPN",
-    "GDBHJ3DTBEQ3ZY32Z4F3CA4MT7P447Y7I6BGZWDD43YZMIBXIZZRDBWW",
-    "GDBICXJEQ4OWQCYDFLOBSU2BCZ5WP72SHL34ZXG2S2FW2MTTWAFTZKH6",
-    "GDBIJKJWOKTVEXG3PWKTQQ75SZW7E53C5IHRBOP3EBHU4DS5EKMZZEM2",
-    "GDBJ2TW2P6APUGP2H5FM7O3HPPA66LXNTFJK7J3ITAY6FPMKMAAFLGEJ",
-    "GDBJXVP3FD5GTYKG3QTL2RAU5TSF23DZKESUHNINDUHUG4AZEDZZEE3W",
-    "GDBKOVAHFPZACJHADD34SZNHHZCA3ZEL4TWC4Z2EUJD4FY2SKJ4U2XT5",
-    "GDBL2T4E6LAA2O2OQQ5RFTP7OLMLS6W5NDTR5QF7J3JUT7GBUTJO2XF7",
-    "GDBLPAYRXTNDHT5XBVEH4P3VHCVXET5XS2QFHAF6HDXB3QL32UF566UQ",
-    "GDBLTBALK534C3G3N4OIHRXMW7PKNXZG5FRCWGLICJT5LW7LCK6DJZXF",
-    "GDBLTTN6QJ746F7CVJAVD45IJSK2X7PTIU7JTNMQTCMG6R4ZACKUBRIC",
-    "GDBMCINPKMYKH6QACTZR5WIQL6TME2EIDGN2XXNDWXCU3JUHU4ELX5HX",
-    "GDBML4NH7TO6VV55K6OYCCU7DA6EQRKTWMOIV7LWKBE7KZHLMUSJGERE",
-    "GDBN4WFUHHWBHG6ZCUVFUL2UVYDSXWAYTKPWQKBKDSXHK4DH434GH4HK",
-    "GDBN6JYUK4QXEF7SK74ALW2FRP33UPRL7JUHO4OT6EIMDF23B4CBAERD",
-    "GDBNQ4EDAEP4KKZLB7UK46MYREMBNAYHI5IDA3POLKZHLCKE3OGLKUIJ",
-    "GDBOF5G7VJALSJMHLUT2TV4HW2K5BORCDZRJB6ZGB7WGYKO67PIEU4A2",
-    "GDBOHBEMEAAO3NFU7NFCZV3SJQPHGLU2BZ7H6FPWI3Y3DP6TAVHKBHZ7",
-    "GDBOOKTT2TIDTVPYCOFZKCTLSBWUKEMY6TGEW7LOK7VWS54FRC7Y7MJR",
-    "GDBOZO2HV6JV7T6JVY2LHNKRAEKP2GJLXX5QXNZIL5K47YBR6BCPE46M",
-    "GDBP4ZQG3WDTOGVP5FLRI5M742UQFQ7BH2W6QV32CFVBXGGH2BYKZMAY",
-    "GDBPNFYBVWZTNC3N2JDUQAH5G2GEGT2PAJ55LKXG535RYDVR2B7DTBMR",
-    "GDBPP4BUJJPQIQJFUACBOR4S7LGUPK5YXCNSFX67CIZKNLSZXVIALATV",
-    "GDBPUQ3OHHQZJG2YKVHDPTCQNXZZYV4S43622MIULYB2OE4ZOBTKRQ23",
-    "GDBQHJXCSS7YUU2NYSAMGNDSM34BFJE2Z5MMB4QZY7OKYNQPKGA7I7ZT",
-    "GDBQHQ2QLYI2MHQIXAITN2ZHQ6WOZ67C4QPOG25FWF4FJ3YJNLPZPTSA",
-    "GDBQYRIVRMJDZZDPU25ZJSXKCLPVLQOREJNBJCX77RSZJ4BS4CU45JNH",
-    "GDBRIUEXNPINVQEV5T3P7QYXZIN75EXXQNFOEYJ7PX5TOA6KJ6AD4YZ2",
-    "GDBRW33RYT4LQKXACE6UQXZ7HWBXSW7QGG2ZX5Y6KXF4XBPO4YMJBTCG",
-    "GDBRWYVVSIVL2OFL3HX42GKGHX43TAPAUHPWWG4X4OKBYVQ6Z3XJYOL6",
-    "GDBSJBW4HNRLG2IIZKQP7AARJWFICCY2KBXGB24ECGHL63JNXHS2I6P6",
-    "GDBSW3BF3RI3ZCEXKBMLDU4PGMVBYR4S2JQZGPR5TNRPGKRIO6TXT7OJ",
-    "GDBTD7XNB63KYZNKCFBRYOTFQH6OIZ4YAKVOYDKQ5SM76QG5R7ONYAWX",
-    "GDBTEMVNAVODX4Y4YO7OPUOZ7NBJAKNTZQ3QJYI36TVCFLF47UDZQTUM",
-    "GDBTFSW3IJBOGETND5GZ3NIS7KX2CGNALLXK5XEJGLWVSDKLLM5JNNTV",
-    "GDBTIJX2BBVV3DUL2MC3IZGWAGN7NV446C3XHGNHDG4WM2M67YDLQ4V5",
-    "GDBTPJWJSLH5LOG6I4H636HEI6FKKL4QWVZL2OTWYQNDVLG4GYNRLPRT",
-    "GDBUB3FVCO624PQDD7LJIOEXB7HSSDGFAIJV4NOASMC76GVBTXZRWHIP",
-    "GDBUD3JSLCLBKHOTMNZ4H7RRWYEPC3BJ5C3IRR2J53VXBH2BN27OXYHJ",
-    "GDBUDXOA56PJWHTSSB7FXH6T6DJONGBADHCRIRLFRDIJJMQZ6Y42SNYK",
-    "GDBUGKSS6HTLI47UCUBFNDCS6I3KU7A33ICFHDRNXEZCK5HTOJE5PVAE",
-    "GDBULJ6GLO6E72VQ237MI56IAEUVUR3IFZGHF6YBB4AQJPJDFULA5NDM",
-    "GDBULJ6XMREBL7QQWMXQN74HEP3IO5QS4YMCNWBTQLDLGMLMC5ZZ5QXM",
-    "GDBW3564E7ERRSX4GRNQKN7CJCWMS7FEW2XCOSSIDUROT4F6TD5HEMYL",
-    "GDBW6Q7R3V5PO6QXSARUAD76N6CPX7K2UNLACNY67GAST7YAOVKVLN5D",
-    "GDBWKBZ6YOKPPRDSZXH22PZLT3L5B7H7FJU7QON2237KW234EYABNEOM",
-    "GDBWLARNCXWG77BJFF7D6BZ7LDY6NMXYHJXS65VJQZNRAES6XQRR6MBT",
-    "GDBWMS7N3DWCOMMMBDLYBF2C6YXN5G6M5XU44PYHYFPP7KBYJ33M62VU",
-    "GDBX32HS2PBJAWP5YQWQZTTZPIERSIQJPBYHAB7XET7BNISL3JWKEXOT",
-    "GDBXEPOHBFZGPDKX2LI5D4FAVSGO2MKKNMNDUCANCG5BAEGHJUGXEDSP",
-    "GDBXLRHQ2VTDJVTOBD4GL4VS4FGCDOHIATM5JIHXF33M4ZMYQMGH7M6Y",
-    "GDBXOUQK3XJOMNUMIHLLPC5J4MOAH4S25A4UMVEW3ZLVQ2ZRDLEIMG7V",
-    "GDBXSEIZQQLPRDKOG2ECLBJYVUTZYJNNBPCAKQ5FDY3IH5O64J3PUHBB",
-    "GDBXUH7733OAZ4AM5K73NO3YXHUUCEWIICOWYK4DCCKKNYKED7KVPR42",
-    "GDBXVEEY2AXRDDBN65TSDDKRQBJF63DWUAG2INYPKONDTLVUXLNM55JZ",
-    "GDBXYKUKSIK6F7T6W7QUGRZTRQIFGMTP5DQKK2AZRQLAW5AEHMV4JCMH",
-    "GDBZF4KWVEN3IT3BNQXZN5Y7AT5ONNUGNXWZJI3C6RCEJKDJSFDNIFMF",
-    "GDBZGQ6DLOTGYGUMO7K262452IGGMIQEBOT3C3UWVHGRA5V726NZDZRB",
-    "GDBZVIN7LVX3HEKELO4XCRKGMN7FBQ5ZKVTJ7BQ446QI6XSB5Z7QEQ7F",
-    "GDC2CQ6NNSHWW4HDI662NW5SQ5377J7G56N2YGE36JRPJ4PN7DSQBX3Z",
-    "GDC2LR5CWHS33J3LSL4NEPVJF5EPCS5VFV5SBABLNYTXFQB2QL5M3HGV",
-    "GDC2Q5FKNQHTOUDC5TLEJOHKAJP6G2DTXZLISIMIOZZNDVV7BGWBLXRP",
-    "GDC2XFROJNSYNJ4XQBLXOS4LYS3GLDG3TF7A64SI7C45LY6LKIUCIYZP",
-    "GDC3327GH4JN5DCBB6DPK23LCOX7J3PRBPMIG6B7RLS7EPUCLDNNS5IA",
-    "GDC3FZUYXL7Y3FSEFW4HISCJPLNLLTAK2IYQVW24X7BXBDZE5IEWG3L2",
-    "GDC45JEWZVAF7HZ37WQWKBPNG2AB4R5FEIXPMAX2JSBGHZOQOUGSNMKU",
-    "GDC4C5CVCYBVQOAUFTGYH32DYHEBHE7UYBW4XCJQCQ7E5PBGMSF7PSIL",
-    "GDC4L2DW2V2BMRVI7CJOBBIFWADW4OIXTUATKVOEYRUPH3UNBCH5FXRP",
-    "GDC4VUDNBD4QB5BUDMEKJJW446QHYNGNPNHGIVNX36GOCPSYSNVT6BQL",
-    "GDC4WWU2YFB3MPCPULWVKFXPSD47CXXIHRDM34IINOBOUO45CQZDAXWF",
-    "GDC55JCKC4AVLE6636BRTZKB2CBUWSSOB73AT4VADOB7ET75ESZS6XLM",
-    "GDC5O3QJCJJ2BHC574E2423VWSCZG7MAO6SQKGSSS7NXYRKSVX2PPEE7",
-    "GDC5UK2IN5OWQD2U45ZD6IMRTVG2GXUT5PIV5J6A3LOFM6YP355LNA3W",
-    "GDC5V55JGFOYGS7K4XOMYSLMGIML5JRD5QB64IC7G3YJRM5ZHRKSX2M5",
-    "GDC5XHEK5TT3DXBCGMWNYPN2LS4OYZSHUYRZJPTYN6OJLNHC6F2C2KYC",
-    "GDC6EIMP4YDIUEH5T2AIIR6M7LAPE2N2Y7HVP7FFLTEWZ2B4IXAJX6BL",
-    "GDC74XFU4JW52PHNGY26VJSIS4PQFGAAM5YU7G45N6SG5TSLJGTGBO4J",
-    "GDC76IBLSNQ32PP6JPLAMWF2X3MKW7HDRFJB5AE4N6AJPB6WUTNLZBM4",
-    "GDCAZT35JG4CVEMALEKNL5VXWUXRF6ZQFVLGLF4K477RT3DLNFI43OLC",
-    "GDCB2JXZ5BKMLRKNV47PBLIKX5R7RKBJN6RJTYQ4SSOENVE3ZBIUIA7W",
-    "GDCB5GMUDW7D5DXQCAUA6ZK2MF3BV2J4HBJG6DHS5LDUIYQSXQ6NLINE",
-    "GDCB5OEBHKS6RSYACFC2LUA3FZVUAANAJXGYF3PJK6R7L34LJIXSWP5W",
-    "GDCBCJNKKL2ZOQCNKXTPHTUCGBEEXZ6ASRXYJ3VQZB36J42FMZO44BAF",
-    "GDCBFLQMZ5JUEXU7IQUY2YZTMQ3KGAQP2MMY4YURNHJ3IKZNGE4KEAD3",
-    "GDCBHLHACYPDWFIYADKMVGZAVQAZFLWPLZBV5D6HVTJZL5Q37HL2DA2P",
-    "GDCBJY3P7HVJHWHUJS235J5GLQ45JPM7J4B7G3IGU4YXRTGS5Y4EPPDW",
-    "GDCCBVFNKRSK6L4QNQZDOUSJFU4XJOLDMKFCQOISVXOU46EXLE2CXGUL",
-    "GDCCIQT4ZUCQXAFEJAQQCBIATFRGHPYIQ3K5A4A7H25IKJFCTBTNWISO",
-    "GDCCVIOMCV5FPKLGLZ7BDHRO64VCMNBPMJSGPTNNKVUWLJTQHTT4USDT",
-    "GDCDA3CFBFAQCVOMDJ4DC7UYSCL3S7UMQYGORYJAJOZDRQEM5EEQVWJA",
-    "GDCDZQMHXBNEGTWNIJZPA7KWHF3OVYPTFEEMTQAEZP77FUORCPUUOZLZ",
-    "GDCENRMC7AMESRAEHNPF62O5TBU7QL4MDB3EVDR447AQWY7QG7B6Y3QX",
-    "GDCF2CIREMU66F3SIHGOMRPL3QO46SQTEXD2Y4HXVHLG2UCX5KUREO6I",
-    "GDCFJH5HKLFPXOJWHDA65DLASS3UVCNMSE6LQY7B5R57VKU6TM6QF6FD",
-    "GDCGAINCTWVBSZD6HYX67X2A36J5XJFBDJPJCM6PMLCDTQ3WIUSNAPDV",
-    "GDCGXNCQUTT6CDUCXAK5ZII2TX3U3ECMUYEFIHRYDQ7X7E2I3ILH4BXT",
-    "GDCH3KAG3UVQAVKTO4SPARH6RNRAGRINMIDFFKSIZZTOCG2Y2A5NBLE7",
-    "GDCH6UKLTLYD5AI6IGYKTAHZNNXW4WATZ3MDZM4PCEUC2LZFPRC2ZMO4",
-    "GDCHAXJ27VGSFR27ST5SZUPZU5L2J6TZXQ63M75O5FO3NWUOJFGVK565",
-    "GDCIAUVV55DZ6KHPM5XDENMTF7ERMKP5JBIOS33AIENBHEECDC3QBNHV",
-    "GDCIAWIE2LZ6B553EKX2S5CFF3YMJD66DPXS2VLMXBNVZEXJ6XJYOZIN",
-    "GDCJFEBBP4LJ2HE7XIDJJUJ4VQPO74LS47DN4YIXHZ4PLUNXUSTXHRVB",
-    "GDCJNDUE3F42ZOLTJAJ6KCUVMCG7PZKMQWF2ZYJTKJVRIB6WKGQTPUWX",
-    "GDCKFAEY25GDKIDNBUM7WVCXV6UAS5EWFLZPLBF2BVJR53XGIEAWGSIR",
-    "GDCKLLKPOHLOPLADKZY2WUJ2R4IIM2FLGBJPYTMEFWIB23S7ZTW7W6VL",
-    "GDCKPNIZ6BH7Q3KJLTMHEXMQPVVNCLTYK53K2IRIXB2KHR6UDXIUG4BX",
-    "GDCLCN4BAIDJ6I34M7WYCJMJVN3AJ7GMEL2GZLZB3K3XXZW5SHSAMXDC",
-    "GDCM2KZJWCCMPLBUZX5AR7HJBWIJNBFWVWF6NOQOGQFMH6YPSMPBO2II",
-    "GDCMLQAOWLWG4UNGVYBV6FEWFFJAGKUINLFAEKSHC4PVIZOJQST6FTGG",
-    "GDCMQALZQVKTH237ZC2FVLUPMLJ5GY34IE6H45D7NUIDQDKAXAWOUBTC",
-    "GDCMXDDE276II7GCIMWM66HMVOEM4A276PJESGNW4N4OPNGK3JU5ZVZA",
-    "GDCOCCN24U4BLLH5IZGRVNAG4CADZMMXJVNWSD7O4JQFZBA4PFKU6TZV",
-    "GDCOJ5JZC4WOTCVPEDMMTC22VOAUITUACIZFRMMMVFNC6F3AWKK3X53A",
-    "GDCOJSWMXTBOJR2PW2TLHVCX7W4RMBPCEWWMQ2HLWVOSTH5XGR6C5R56",
-    "GDCOSBWGSIOVA37SZP4Y5QVLEGJOCD3T5HJSKE3YVWVPZVLTSRGO4YN7",
-    "GDCQE4I3GOTWOF5PCWDBBPJPXABYTQZA6XQPZGSLQZMT2X7IOUU257XC",
-    "GDCQZCSX3D74F2QC2ZU5OZONVRU7A7YN4EM2AVLKZU4PMIUBZS42NDRY",
-    "GDCRCDFY47UOA5OG5IS665HPRWAHXQLK4A2RGFMALI74OXK4NNXUNW6P",
-    "GDCRQOP6TX3UASKOCW3TTRB4HLUOR56DJHOB7FUTONBVOJSQBV5W7XLM",
-    "GDCRUWEAYOIFJRUSOBSHIV65O7ZIPNPVESEMXVDJIDYIDHVKNCYKCHDW",
-    "GDCSWA2DM7I3VQN2WY5SUYMXWASJGK4TQ7TOFC4O5UAFNOJODBQEWUDI",
-    "GDCUCBFLOKBZQBFL5SZEGIQLV3I3KET655GTUIUMHLKUOMLTRWUWDV5H",
-    "GDCUL4KHPV4FYCBKOCFI76TLVNHMAWYGEFRR6TXLR5QVM677L6EE7CFU",
-    "GDCUZ7VCPG3XZF3YXRHW3VOF5QR6PRQC5WAAXE54UC3VATWQ5AUG3MIR",
-    "GDCV5JAFMPFNS4MXB7OBMXSMCQ73J4MV2CE4VP36ANWQXPCPVFEXQXLM",
-    "GDCVB4IQW22BSQAEYTAQACO44BWB2WRN5AJVNHTXLI4I3OGSB2WYXXLC",
-    "GDCVCPDDFL55A7F6BBXFHSEUVDXGI6WB344V7AQYN6WMFVFQ24FH7SMD",
-    "GDCVFKWRQ2DRPIRQEUPKU4PW7SUVOHDIJ2CJQEQIEWYKMQLOLTAATKCS",
-    "GDCVJ674JTQFN2SWDCZSEBEFQLW75F4HQV4RPI2RZCM4U7KIDM27EEWT",
-    "GDCVMDUYHAWYPWT7PQ6IPONGL6K6CJD6YARNADN2LK524WI6DNKP5VRO",
-    "GDCVUQJD6OY6MIUURESVUMZCFXGIYJCGWVHAHR46FZQQGTRPW3KYCXMK",
-    "GDCVZIY2XQYJU4JVHT2NJRNXVNLWLLCMTPIR6AO73QBRGU4QNVMTPSHX",
-    "GDCWIA5VUBCUWROCPENK45LGVBGY5NC36L4M7Z4KZZ2H6UGORHEADISK",
-    "GDCWJV4Y74C7PHETZU4W7TEU25JTEYAR2B64NTLXXNP43ODIIJTDH7A3",
-    "GDCWPK7BDCK7MEPFSGYXEWMU3DUGHZ5TR6Z5GOS6PZXZVQ3GDEX5IUPY",
-    "GDCWUKIXTTA2A46WX62FCDNPXNX4HI2XVQNU75DY7MKJUNKBHF4JPZG6",
-    "GDCXBBRNTYHLLYHGQLMHAHSIWIINZIB4UJU4DDXCIED64RODIJF7N47I",
-    "GDCYLJYLAWLEITOAAHAQOFPBFE6UGMPJMBH4TN6CT6OHWZVZF4A73AEM",
-    "GDCYQSVCLFCIWBHG6I64SOHPK6HTTCKBDQ3I57Z2JJ6T5Q5B67XEIPEB",
-    "GDCZCCK7LB5LWMI3XPMNR5GELNWK5KVONPR5FMUNJTSVUAZR4EOTT7ZC",
-    "GDCZIO67AD4HUBYPSIDKBJDAA5CVKI4LV7JI5BOQNLF32XRFDEFERXXK",
-    "GDCZK622NOTBSHEANI6JGNUZND44YL5R5A76VE3QIDA2QO62J2UIGOEO",
-    "GDCZPNAYUO6DKLTHKBO3X6YR75SPYHVJFVHUXSCJY4TDNCSAJK7M65IL",
-    "GDD2MPNMLE424LX3EHZVZQNLWPEACZXRPHXEIQNAUVCYWKOB2GO7IOTA",
-    "GDD2PWBLGV6X2ZSKKS2LQUSQH6MRRLSFD7Z4VGPBWROGZ5PXX6R5QKWL",
-    "GDD3A6YQL3ETOCOAYQHTPYINGKSNXMKHAHRVGFAGPZ6OE54S6VMS2TQJ",
-    "GDD3GCKPPLAQQ5HEXURJB6LKQUSAYTCAIUSTM26IBZ2SJMAPTRBJ445A",
-    "GDD3GHYJYGVOCMRCNYXZ5ZJYT2TBQDFSZ4YOS5QUDXUJGPVYNAENHT6V",
-    "GDD3GZVOTRGVG7MDH7HZ2FPZVZNMZPV7IAJJRXIX7B7K2RBSLTVSEAOU",
-    "GDD4EKLPMN4OILU3HOI3WD3CX47KYHKHIMRUJF4PY577TGES57SLTJ7E",
-    "GDD4XFLAF4AU5HZQRYFDJ5UKX2Z6TZFDCHQLVBRRMLBLQXAG3UX7NG66",
-    "GDD53QBUL22HC43NQZLGUCJQ5C2CAZ6IMKH7XHGGHKLOCMII3RUEH6GD",
-    "GDD53UGQBAQNV7KASGPMBQAHABEFMNFXALDNGGMVRSZZKZ5YCKIZZHFT",
-    "GDD5DJFSCURRSLU6KPBCSPQ5ERMDPRWKJ7QYFB6XJ3TMPFBPYM2NJJ35",
-    "GDD5ZH3QNOARUFNAZVQOKGS3I6OT736EGSHXWM2R35IFQXRFNV3WMH4H",
-    "GDD645GLRS2O5LFGT5EOUIHHOHU6MDIZGH7625MQIFVUHPONHMC3CPYL",
-    "GDD6ESYG752KCIP3TFQJ6SXPT4GJJZR5VYTK6IXXKR5SWQH3CNY4NCMQ",
-    "GDD6MJCJ4RLCQRTSCYG74O4B3TTHZDCFUKQDENH3S4MSPPHIZQKZM33L",
-    "GDD6WZHEADDXEW4WJJTZHA33YDT7CPRJUJZB2VWVOTHBHNF7Z4VCLCMZ",
-    "GDD73YLUO3Q4NKLU5Y7YT6M4ETKZ2XJCWPKN4ZMSKTMHUPNMNR6LZPOP",
-    "GDD7IO2NT3MK6CXFQA6K5WLBF3RPWDL5EIPFTPDK27D3GIHS24LNP7NG",
-    "GDD7RE324K3RPPW6FO7I7ZC2XZD3AXYXHUUGKTWAI2YJ6P5UDSZAFSV3",
-    "GDDA4KVZ7FZR5RSNPICAFZEQMDUSTWQX7ZKU5Z62WCKWDXIVILNLEOSL",
-    "GDDACN4WMF43EOMQZXR22WOGHNOAIQVUGC3TVQIOYYCSVTGOVF5HGWGX",
-    "GDDAMOBNFE66MQBRLEHJSQ7OWDU6CX6YJQLAFTMMDZ3JW3TOMOSODXVG",
-    "GDDAODATARWBBCPUUKQ7IFJLPBMGMBHXCODVZWSHEALKKYM7KVTYKEX4",
-    "GDDAS7MUFAZE6IRVMVZ3ESNQZBESFM64HYL6OOUPVZ6WEKFRZDRA4L5E",
-    "GDDB6H5DO7VC6AD5W3ZJMM4NIK3VLXRS6V4TDE3NZJRN6LDYW6XBM5SZ",
-    "GDDBAJSFKXSCXVAIRBMI3ERA22BOO6AYGYBEEPC3K7PJQ4N5OMW6AHYZ",
-    "GDDC3A7HST6BWCTJ2OJYVZR4O2RRYCUHKRMELBSFY5JKNYUAHWFWGPUS",
-    "GDDCFBB2ZX6BYFZO2CU4SYZBNR4GOXBRPQPHW6HYMREPQBK3THILO4UU",
-    "GDDCN6BIAAWSFN7JYR4HGPFBNJETZ3D26GBYKKNAK2SRNLYPKXV2NUHU",
-    "GDDD4LPBDQYTOFB55WFGGHLZD77A7NSF6ZH5GDA4U4UU24ES4MUBZBIH",
-    "GDDECLJX47HASTZKDZTRA2HW7NMXJY3K62L7YAQPS6MMR6MNOSQOJLZV",
-    "GDDFDO7WLKRLAS42G3S26F3F65L6PT37DSPWPWUM5IQCORBX5YHJZ7YC",
-    "GDDFILNKOGCCJQOVM24TZ2AL34GZ6VRV4BY6VZZL6BLBPLQ6QMRS3U3Q",
-    "GDDFSKDRLH46WYXRXAIBTNEY4QS3NDXW7Z2TK6HDS7U7XKWU4T3CFFYS",
-    "GDDFVLU7R6JQHPOZHGN6JA6E7Z4IS6I37SSIPP7BYQIHJPKRYIVG2GED",
-    "GDDFZ52TBO2KCNIA4KDGVQIXEN66E2HC4DDP3S23HF7TOJILSPFAYDEX",
-    "GDDGS2RRMDETHQIGMTDCRQIOWRB6POO7LHHCJN5M45QIMY56YDEZI57P",
-    "GDDGT6ZGBK4NYS4ZGTJGAXLIGQYIEPNRID5NI6E36ZZJ23IAAETU3MRM",
-    "GDDGUBOU4ORFKHGYOZGNZPSKHT2SNIJJ6UVGEH4RYRYLKZ5E6O7GJEX3",
-    "GDDGWMB5BPBAXFS5KCAI4VEIZMJYXPB7ECDWOG7XQ5DVQ47NJXRNJKKH",
-    "GDDH5JFUHBJHBLFJEZMMRN2ZX4JWSMWEFZ2YVRAKGSPXLBL5DPGLSTOP",
-    "GDDHA6Q65NHKJVHHGRHBJCEG7NPRS5BDUJY4HZJZLOKX3QLVCNH32QWZ",
-    "GDDI7ZIOXGFNURLPFRAGSU3AOFXX7FELHT6WWV2FWGW4FQFLDGEDO3EZ",
-    "GDDIAIMW2K5EANF5V2K65KMGCOSXZR6NORUKLEH45P6KWZSKZLHDJ5VW",
-    "GDDIQEI65HFYMNZGPDXXRLJLLJS35FVJ6A7YJW726IKYJQH5T4RIGY4X",
-    "GDDIRMOAZQDFSWKRAEJH2QNQLO2TWOKQGOD7PLHWPWBRUN2APTUJBFJ2",
-    "GDDIXG2OA2AEKAFFGWYEILRJQJ2IMQM3D55ZBQQD2KPIHLU3TI6TWK54",
-    "GDDJA7EW3SQFGU7QJLW4ZM5AF63UMYPH4AYHWFI5VSSDLMPDQGCSETOP",
-    "GDDJER4ZZXVTVXRUZBQATLQOUOFHPSHGZR65XCDQLOE53KFBJKXIZTUQ",
-    "GDDJHOGCY7WPMTKRV3BQUQBG2JK47Y7PV3RSUVBVNIQM76CD4FIJC6OA",
-    "GDDJUWI4I5VT3MO5TXIZYIORKXHH3GPHG4UZQ63ED65KB3K5D4VR3FMD",
-    "GDDKDC3JQVJGLS4UDP4VQTQ5V5C2KOPSDDYOSEVGKOLIOICXJRX6KCL7",
-    "GDDKHM2XSCVBGOEFKQWRSQLYXCD4BWYS6HPDEAHL5TMSOZYUJPMCDFD2",
-    "GDDKP2D62YSGF7XJRMOSYSBRXWK2BUKHXZ25EFBGPSBXMFSJURTJT5FS",
-    "GDDL7NS53FP7SRBPCP3TGG7SZBJD2E7HVPY73BQYRAEWI2PYQ6W2CEKN",
-    "GDDL7UFWOAMCE2SPWX7PX6GOERZIGD5K4S2DGAE3LHZSEASQLCFFFPWR",
-    "GDDLQASOIM3PNTPGQFWSLCYPLFGOYEE26PYQ6CWI3AQ3GBSU7P7PSUWS",
-    "GDDM3PKXKZJBQS6PNGYP7UDYNZVQUYVSJIHRASCVMCT65IYBEAWKABDC",
-    "GDDMCES2PRJWEEKYIQA2EHFYOMR5MG5IDVBIRPI6MNWLIYCSGBWBETSQ",
-    "GDDMMHL7CL7QWND7XAZWKR6BLKRLSZCRQSXHYI2Q57QPIWKZLIYBHWWZ",
-    "GDDMWQDJC7AQ35LXABOIBDGOZWDCLMPJMQ2BLSO3PDXNRKWDRAI5QG5M",
-    "GDDNSUI4FWVBGIBONYRDXYOQAE4NG722O2DFXLWV3KYYA7SUWUBYJNCS",
-    "GDDNSW4Z2NCFZPK55ASZOPDAEMCQUFRTT5WUEBNBNCTMBIKNDJ35CJB6",
-    "GDDO4YW4KSLDOJPM2373WEQGDBWYJYW4YAXMREARWOW57KCL6FBBODK7",
-    "GDDOA3EBWK4QOK4IALHY2QPARJ727EOYTZXAU4CVQAD32RCC2SVXVI67",
-    "GDDOFJ7AKGQU6F4F23DLKY7H6REUZ27TNXMQ53VEOWMZFK7AM3I76RY7",
-    "GDDOM5A7H2HVSSFC55AACCUXXW6MYK3BOSA6YQASASFU3MLDB5PUQM3A",
-    "GDDOXV5DN5CJ6HXX5OA3D3T2KBSS4MZ4IIGRTGNYK35JNFLYPP3XRABO",
-    "GDDPAXTE3JG5DC57LMRXBL2JAYCOQOYBZXGEO622L2NXDMSEXBCSY7ZU",
-    "GDDPKJ4QUAKQGPP4ZI6PQJHE6SVH54JKWLBXBBI34WQQD5TOQC2SJYMQ",
-    "GDDR32XIM74MSEBL2BFNBQF4LDDP4BS4A3WBZVICO5RSQUONUSH6NTSF",
-    "GDDS6JJYDWZZ2ASZFNMHPODDK3XKATKGBQFD7OKVCEO37CXIWPBHBUSD",
-    "GDDSEUILFFOLQ6M5IBJJCJVBLXXPOIBRLZNNABXRD2IBZDNBNDXUFHIQ",
-    "GDDSJZN55HSIXZPCMH3KBQGNJ7XSOV4KLNEQINXOZ6OZPJF62FRJBX3U",
-    "GDDSPCSGDPR6SBPQTJXPMMPOZGB5MEVVJXKWJOGPKXXITN73N5ZSP4S7",
-    "GDDSULUDFYMN2JBO4FB24L4HT34AVSTQZI7U2Z54XUDLYOZQ6XXQNP7A",
-    "GDDTDOX6UQPZOQ77NGU7WEIEESGX2762RT6UW5SZMJQ54Q2VFKXZWAVT",
-    "GDDU5CVB3R4VAW5DE4H4UKE55V7V7CW7KVQUE6YSM67VJ3V5KCBZDJEF",
-    "GDDUEJLZZH74UK6O4N6QHAVGAY5CJTXKATF4K5WY3K7UBPL6O5NPNDUS",
-    "GDDUNCYTGSQ4KV3VWICBABK4X3A2JFJ3QACC7XFTIDMOUVFB3DBVL23B",
-    "GDDV7XPE7ACAQCTPKB4YZJ67Y5MOBZ3LHBPKXPBMAAXNQXL7JRZ6PSYS",
-    "GDDVLKBIAIXHLBTAXNPYVF2AELSEVMEWZ7L75UJW3W46PX6SOGQRNBPC",
-    "GDDVOX7Q26AL32B6S5FVAOMUA6FGODTBDEVFXLLFJD57ZAW63AZV7YFO",
-    "GDDVVYRCTQEM32AEQLBARA3NOCRMH53TTEFS5ZK62TYWAGMNALZCBA7R",
-    "GDDVX57CGPFFAHIAIKU2BCJYDN5KZQEONXEVTOBSVXUFQWXNMR6CHAIN",
-    "GDDWD2MGYDDMTUMNNT5XEUBOAMGOFXRTXL477EETKJHOFNVHM7C6SJRH",
-    "GDDWSHVANSZAL2RXFJGUTIRXCA7DKSIELQNZ3AFLLHDXMDPU5CX45ISO",
-    "GDDXTC7W2UO5PKTDLSD2B7BR3T2OBUEPUPCVTLYLGYC5DXTGNEKQ764L",
-    "GDDXXCYRXS3YNZ2H2X625JFUQRZO4IWVNXT52CMVRF3QPW4O7YT4B4PF",
-    "GDDYMAR4ASD3PJFSCUUWYTH34PPXG4H6MZI5TTRTYACZNPIVF334BXIZ",
-    "GDDYTXU3DSYQZ2SSJ3DQNY5Y77M2NFZJQ2ZTBV5KZXKORN7AW3GB53NS",
-    "GDDZXWIYUYJL7XROGYG3UBIYILRBDGTKXJZNNBXZDYWM4UG6TMIFIIMW",
-    "GDE25RFJHV7AV7MPTQT4BOYFKM6MD7U6NOFSPJ4IFSDRHJGW6ZVDF6PJ",
-    "GDE2CIGZ7HARZJHILJ4KGXSDJWP3WJCQNLEDBDC3UHN5XKSMTE7TPDU4",
-    "GDE2PLRUBMNSH5E72I2RR2TMEMXKHEWIWGSZR4JA2LBFEW5GNJGAWUYP",
-    "GDE2QVPIWFQYKZHQCBVED4S5YWCZ4VZBFS5YEPHUAGEE55WKDZ6LOBST",
-    "GDE34WYIKTR2MDTPB6GQEGTHAVTNRE6Y2SMX65OXLHWGRIQJBDDMMZS7",
-    "GDE3ECZVLHZJYRHWOKQODLEZDHPZAHBZEDOZQQEQ5XRXW3YOSPR5MXII",
-    "GDE3I4XH5NMJXLUVEYTX5EHI6HSIHGIHAKN65HT5AKH5AZXKTMNZ2XQH",
-    "GDE3NPRCW6DNM3LYONDMEK2ZORYOQINLEB4RRL6DFHVPKDMGHT366ZOC",
-    "GDE3YXSSZIFFXCTWX3S6AL5ELSJIGDTB7EC5MGFCYTUTT2ZRYHNQNLSM",
-    "GDE4E7MQJQS6P64ZCHPW5WCN74WV5H6HZQ2UEMFDVET7EL3ZZ4NOIQJO",
-    "GDE4TPAJ5LTENHI22J7EAJZK2RS35B5QAE7VYVVFT7KHURX4OZKQKNLE",
-    "GDE5VPZNJ7TTKQ2Q3PDJHOL2KLCOBLTLRIPWZIDAGFFMUQVCYUZFG7LL",
-    "GDE6EAOXPKMBHIGD2IQBBYDVUKZQMBWHN2P3EVXJOJ6I35YHICZDFWTC",
-    "GDE6I7X4BKIVOHNIFAZDHLFWA5ZSQD7XABQKHGS3FIPAQMGBXUBNWGTM",
-    "GDE6M5JG4MFYVSPNLL6QZHNG7XAMWAGQY3X52DSDJEVYMVNJAO2DSZGB",
-    "GDE6NBJRQIUZPEEGQQBQPVZXV2QAXIHVPEL33UAQEALLRIREPZHZTGIO",
-    "GDE77F5GWZ6P5B2ICXOYWHJPAAD7D5OHSGEBRE2WZJZZD5OLEKQPUCPA",
-    "GDE7AH5HENT3RN3PMWENXT6KIKYO3ARXOEIGXHNGXQ3OJSYA22YEF3IT",
-    "GDE7C7VQNJUL7IL4WFFBZWWKH22JCQXMZC45LU75EZ7NZVH3MZ24JTMJ",
-    "GDE7TPEX7KY4S5RK4AMREBOZ37ZXTOEQZWOHY3JYQMED4RVSUHTOWWWT",
-    "GDE7USFTLDSX5G6AXEHHGX7ORURNDOSE3OHBIP7HLY6JZE7WVNGNIADV",
-    "GDEA42P3KQ3NGCBVYVAYXHPTZY3GST2MWXN5I7C6NN7HNOFYLBRTZSR7",
-    "GDEA5T42IO4DECTPZCTJQR4UMO2JHS3IWR377JOPHZEB22AIAXGG72OB",
-    "GDEAD5QB7FTAJ5UPVYNBOPD3BZW4EKTZIOVPAGAJP2SF4CHX2M6ECKDG",
-    "GDEBGVVXS2Y5F2T3ND25W57R5P3Y34ZW3LRGWMYC7TXBDV5UFCIVYY4N",
-    "GDECB5OHWBTHTJUUGGSFCPNZHGKDROEERCLGTPNUCYXSK6KRK7QQYCWQ",
-    "GDECD6HIPYHIX7OHTFOQARMLOJ6T4O7E7RM2OLNHJTFYSWDJS5APQGOZ",
-    "GDEEBC6G2JBINQSSZBX5ZE7P32K7X4I46ZZZBGPJFKGIDVM3OUBCPNFW",
-    "GDEEHINTK3YT2CUEFHWDDRNF6W2O2FYKBQHZMA2KFEBNO5XEOR4HGVHU",
-    "GDEFOLPRV3KEZGMNKVJRPOKDNOT73IXFZAINFZKGXBKRXG4K7MTDN32S",
-    "GDEFSZV2GW6ECUAIGYUPGMLSF4MLY2FPW4CYVKWISPUCM4QZHAOG32SV",
-    "GDEGN6GC3Y42TY5HSPNXXNEQVVTBAIFV3EUXHIDVYGVFDAJPP3QVY5G5",
-    "GDEH2DZA2TTRMZQEKZCWXLSUEYWSOC7SFTFQP4H4CYJJLRT2UEVEMYJH",
-    "GDEHBCDUYNEBRX7K5NBZ2ADDPI6YI76DACXWCKPCNYHYIWUPQXHQ3OPZ",
-    "GDEHUUM67DCVYXPJ7GAOL747R5PDHSSVQPXECHOQDHH6W2RNFMPESTEL",
-    "GDEICH3D2LEA3MDJ6P2ST537NONO7TBT55EFC642XZGBR4GT2VN6BRUS",
-    "GDEIS2ETSP77K3PXG23QAV4OMBKHWRWXPJQ3WE4IHAHPGIKARGIPXYIK",
-    "GDEIXVHDSG6KMYPGAULJXVHARR2WJLI7XWJJVCDLBVCBCC4QEOTZD3BQ",
-    "GDEJQTWRUCYPPM7GZZYL7K2C32E7ZEGYSTFKAIQZJRPVW5H3R7O67JUQ",
-    "GDEJZEMOUEVI4OVFAUKIUAXMWU4NTC3VNOHVBTGOIWZN3JHVKC2E7ARM",
-    "GDEKJ2IJRPJQIL4I45UX7QETOD543ZUIH3ONT6FDN5BD3I53W42UXXDC",
-    "GDEKLELJDP2JMENRMN33STGF4IUXEBID7HC4RQUQP65YSHFO4J5UJ2S6",
-    "GDEKQUMEJNNCDKO7IWGY4V7TWQKYH6N4A27R5QQIJX7LM2ZXKDJ3RL32",
-    "GDELDGW4TGQ5R2CYSCN2A2KPJHL7SVCOKA6N5HFEOEEBCS7THJ64ROCK",
-    "GDELIJLRTFSBNOTTGFMNW2DI7N23HHGR6FGRQRSY36NYZGIHTOIQNWGP",
-    "GDELLOOCYEZMW4XQWIKIFTS4ILPWD3NHVL2V3M3XZD4GNKV2NHLY7XRP",
-    "GDELP3DRBHZHUHGVEBVUYQ2CKWRFVSH4K2HXB4AAVG344RPKC7LEM3U2",
-    "GDELY5HJUWZY6D57ADWNYIUVRUWZJSXZ6AISWTK4W6S2ZXF647ZCG4XH",
-    "GDEMDZAMZ7FWI45BFOJHYFJ35GF5OHHMMP43JKL5BND2MKRZHQQQZR6F",
-    "GDEMF2ACRL26OC6V7W76HA4CLPJ5H3QE7P4B5LZ5ZCL5IBF72655MLTS",
-    "GDEMUJBSBJZHBQ2ZYHEJRMZXSQHP3IJLPSCLJRCCD46UQ4OMEIMGVOK5",
-    "GDENF62HKUJ43OSBWYONTYPEZEHYAR5S7THNFGU5R3W3SR6DHRSY36GH",
-    "GDENIHIROSWBHH3Z3E6STFHN7SY2BDL6HELUVRK64J6ADWSLHSX6FJ5L",
-    "GDENSRF6F2CFVRYIGOGW5NUXGCPMLMLHLP23YHIHNYW2XEKNWJVTENOC",
-    "GDENWF2BEK32FL4L5TJXHWZOA4CXMHV6QBINYKN4VWBWQINKD2RPA3FH",
-    "GDEOSQT4TMOWGYHHJO3DP4HZIZDIOSYT3TWDM67CTXPG4BZBLXVVXG5Z",
-    "GDEOTXHJVKOA3LOOTQVWD7I27MAILOYJHH5HWKTVEZH2FHOBQFUKJ36U",
-    "GDEQCZPMAWM46IREQDHYSW3BHPZAMHR4M7VJKNOVN5RLXC7U4SVKBY6S",
-    "GDEQNZFALJAAOOMMEB45FCEBTNAZEJIUL26TCYJFGKCC7S3YSSRESEQD",
-    "GDEQPLPHW3NL6EQEQH5M7EUQHEE2VR6K6RP336MUSRQMCX3G3WJGVXAF",
-    "GDEQSGHOO2TI3I7L2ADLTA7FJT4GWVB4WZUKC7UPLPU5FSNMO46GXY2D",
-    "GDEQVS55WYMLRDLUY5SAXMWLAMGCNRDAN5M673P5ZJLJ2MBNYQR26MGC",
-    "GDERTOR44MOXBY6SVIFYEJK55FEV4WXU7WJXHL65IB3B7FXNTPX2V4ZM",
-    "GDERV4X4OSVFELQDVQ4E7VNWV7CNIO4D72C5BNGO4WAPLGDWNQ7NCHEV",
-    "GDES2PQU2474BZLL7M62KVKIPAUUWE24TFRJSK6BZLKOSYSKK2SBLINE",
-    "GDES4XALC3QLQFVVKVDSKJN5TQE4BGHBDGSMRLR7SWPXK3JW6B4NGXHJ",
-    "GDESK2DFIK2M5MSZRYX6PCA6QGVGJPEHTLEM5QN7466N6MWCAGYXWNWI",
-    "GDESL5FQLH76KS3J5UIBEWPRQ3PMMURDNOAQJBVRTWFV6QNFE6CIOKUV",
-    "GDESL6EM2TOJFAI7E5QHA2TIARWUMQTLCZ3PR72EWFG7V76UWQDQKGNT",
-    "GDESUOMCOWLDL4JVV3PCOCHLZ3YBXTEMKM4XPI2SA3H43GBCDWLGT5SL",
-    "GDESV6L2V3HIHLFREQ5PO54CAX7ER7ZFTSTBYQVH2CVXGQ6MIIWTQLWQ",
-    "GDETKNA4DW6ABS4C6ZPDJVT2COYT65CEI2CEETYV7OUWLPYFKUKVW3HT",
-    "GDETVKSH3ZM56MSXOUFDVAAGRUTPMHMHCPAWMJ2ETXUYXAMVGWYXZALI",
-    "GDETVYT6S4FHRPQ5KDF4TAAEU7KKTYJ6SONFFSUTTH7WQ7EKMH6UXFNX",
-    "GDETVZUFUAUOPA72OVW3GHNRJ2I35RNBQLNI5XAV2QBN6OTZLX6K3V4T",
-    "GDEU2P7BBNRDRIC3LWD4S3GUDLQKK3ROMBVXBUGTP4KCXZLA72EBHO47",
-    "GDEUHGHUK2WXBOK3DZRTVGHXE362W4WS5ST4O7MRS6F3QLBJZK5SUQSI",
-    "GDEUMC4G3K5C7JMX7CCHOICF62CDWCAJX3N5WZAZRBGX7MDLYCVNTTDG",
-    "GDEUQ2MX3YXMITFOTC3CO3GW5V3XE3IVG7JKLZZAOZ7WFYIN256INDUS",
-    "GDEUUWP7PQOQO7WYEYBGDOY42AJYRZ4HQYB3AAYDFCOHD2IYAOOJQDXC",
-    "GDEUXPPHTSZGVQCWY2YT4AHTS3ZT3XA56LIWEMQDYQLIAZUZMFVKWWEI",
-    "GDEV7Y442LQF5N5SQNG6PPWUDSKO4UIJYRR3K5EDMG7WV57XZA72EL2R",
-    "GDEWFQWTUVGDIYDXDQWHZS34XT4PZCESNJY4CWLURYYBGFP6ELDRLCJE",
-    "GDEWTTPYUCMAS2RYLV2ZVQDYPN2RMUNZQVDIYVARGIY5J45ZKELP37NH",
-    "GDEX2OFTWZEP2HMZS4MHCI3IZMU3LUGK5M4OJL6GL27NXEE26PPGCVHP",
-    "GDEX5KY3ZRXZZCQZHYSCGOQJZMFR7I26QFZZBS6S3B6EVQ5WWTT6WHIM",
-    "GDEXEDNKRBP264VQHG4KH6OEPNVTZTKJPOKHHY2KKIYFBLEL3DJO7MHF",
-    "GDEXOAOIHIVL2UXSG4J7WA6GUWJSYOI4SMO4WP4NNW3WOVVG6VRC6XLM",
-    "GDEXTNUNA2V5FCV3Y362LPRMNICLCWJ5WEZ5HG3QBP6ESPM2FBQLSXLM",
-    "GDEXV6K4H2WSQJHMZN4JN3GILNK3BON6CYLEN6NLCVNWYN2G5NELRAQU",
-    "GDEXXIOOEXHDWBN2PKAZ3IM6DE6HJFE5TNEL55ZZTBNCCWLE5LU4KZIN",
-    "GDEXY74AXUCR6JS6SOPCS5ZZCRYVTRETIAFEWSBH3YDBIJMAI4VNLNR6",
-    "GDEY5GEQ3EMVPOJH3RBLOAYXREB6IC4GV3QLS33NTYU77SRANJFNELON",
-    "GDEYCUNQMPJAZFKW353OLXDSXNPZZNDAPKWPBJMGI25KNBT3GPBYPI7A",
-    "GDEYDRXR6EUDBCFFOZKWERTEVHKSQXJHBEXGYWN4I2FBVDSP2NOP4R2F",
-    "GDEYTCOR4IR6QTP6PEOXNPNUKUAF6LOQA7SWYW2HGXJMCOLXFGWIQYK5",
-    "GDEYVWC6DZFVJRHWRVPVQV5DH3RFRJBZBQS7JEBSPEGCJHTCVJ77TEAK",
-    "GDEZB3ZBQFIVZSEBPXVECIOXR74IQFXAFKNMEL27PU6F6LG3B7E6TATE",
-    "GDEZISFX67GMTJWE7YF34UWYU5L6RAEKLREQCDEEAUMUUECPAIGPKMN5",
-    "GDEZVE4ZSTRQZLV3BXIO2ZU5DLSXURBYQNU4TVDBUHC7WD2BKMUTWPBN",
-    "GDEZWLLKAARS6Q5ZUZZL26AGXAW6HVL75RKZYEYDHPJTX3BSYAW6EJMB",
-    "GDEZY27MC7O7STHNLK3ROCN4BI6XTBDJEBJR6LPMVQ2NNNGGPECJPROJ",
-    "GDEZZJPA6WNIHAHUENKR3Q3LUHKHUEHDTOIKY5G5XINXVPAJYPO6ZU4N",
-    "GDF25Y44QS5AUS5BJ4BDI34NAGPCVUMCGLVIVHOJJQ5OAZT7CT7SN6JF",
-    "GDF2DINQKURKIW633SNK6G7YVM3WGIQY27TOSC3RF6VQUFGB5SOUVJ7B",
-    "GDF2MKJAAMCAH2MKNNG5I5RXNNE75OZA3I2JP4OJ3KBW7OVNI4O5BCOT",
-    "GDF2SAR5TNEONHE7HATQUHXRTO43ZWKZQYIMG4BKTAUYUN2CEHFFIIHM",
-    "GDF2TPH5KJV7WN4LFMCINQTHZMCJIAX76B5CTF5GGGUJG6PYMJSLFW4O",
-    "GDF36MDMTL56QDCTXM5HGGHZ35GM3NYEKF272X5B2JE2N32ONBBY374Q",
-    "GDF3FYMVA46TFVA5NZKFHUEGT6PMXVSS4GNT7CTQSMGXNSB6FLWYLQJZ",
-    "GDF3SZC7XYZ6UWGHGSVMM2HCQ6KLLBC6RE2Q56SUZWEBQIK5P4CL6BXA",
-    "GDF4GFW2CVWVIBNNG4WI3ZXBJQ7SIOHORBIAMNL2CYR46EHCBZJOHUIE",
-    "GDF4OQZEXYXFKSML54DZ2ODQ7ZV6B2XZLFY5N26O5OUEOFWGUPNKHUMB",
-    "GDF4PMNTUMTYZHJXACGCUTLX3WPEWI4TPHVTPMUTM6GICTJJIZY2XJEI",
-    "GDF4SPFPLJQ3BSJB3MER6VUM6MYJ2ASIO4AYFTDBD2TLJ7PPTWSWYPJS",
-    "GDF4UER2C3PQCFCLDHUKMRR2Z45RKGQEILKJAUXRAJCBQR7NRS7FE2HC",
-    "GDF4X3ZF2EHGG3ES4P2LVQWNPAE35YUBFBGRT7G24M54NTSHOBLAIZYE",
-    "GDF4XTAW25P4WZ52CXBYM566NIQ5PXAD3Y3MAJIJV3TJ3UQWZ3QRBBFA",
-    "GDF52DEPC5J5QA7TAFRP4DKOCGVY4JZONE3Z6BLZYANWC3Q5K7PFVEZW",
-    "GDF54TKEPBLUIZB635XZJY42I75NERC7KJUIRKFSWPLCGGU4B5GVEBUF",
-    "GDF7L2MWDYKU5YSWMGJCMJIPFGKXPJHAEDREKRF6XETNHSZSTKICQP6N",
-    "GDFA5ILX4UJTN5T6AHL7MXVSBLVSHNUBPUXB3QNB2U36VC23ZEWRVXRP",
-    "GDFA6OEVY2TRZ5DMD3YWRRDORNYPRAAJGHWAHRIZZOTCJ44YFTVK4LX3",
-    "GDFAGXR4KDMNWMB7N3OQKF24QP7CME5SXZEVNQ4DKUHKNCJBA3RP3RG5",
-    "GDFAS3BDKZOQJWQZEDS4DJWKEAZVLNACXUGQXYEFFF336RMCJV2X7JYK",
-    "GDFBMG2X6ACNXEDBV5N5UYOJUW2JJGNJFRDKIXYDOWJM7IAJAEYX2YP7",
-    "GDFBN4736SLDHQAHBD4ST4PSEL4QXHOBIF74IZ6SGA7346TZTWMI2C6O",
-    "GDFBVJXPDMPCFCRJO6JKZDNF2X4R2EV7RTUGDSIU2W4WM3GVOAJLYQFE",
-    "GDFBVX3Y6VJS34VB6TI676GITN7EQ4FRY74O4NCZUQ4GOAXXJANPO47D",
-    "GDFBZC6BLR5TMLWTAV66FJZ6XVVTHVOFBH6IMWQHQKX67VR5YNACV55W",
-    "GDFC2SKBQRESQVFA4NJHMZF4RXLP7DD3FUUYNEU2OGESKE2DZEHUELEC",
-    "GDFCOHO4MAGFFJXKOGCLVH7PYEI6MBI62PXFDIJUGJVAKAA7AXU6LY2Q",
-    "GDFCQU5L7UMNT7XKW4Y4IRRDNPA33HQMJJ3W2VTLA5BLM7DFWCGER4LQ",
-    "GDFCR2CY6HODYQTZYW36F5565ZM4A7KYATLDMFKS6V2GSAK4MJV3E4SU",
-    "GDFCXRB4YTC4KHUQF57PEMTKNIT75GA22PRR6DJLMK37K4SFZHHMOWED",
-    "GDFDES65WBLVJOJITVFWQGSGC3TTNHKF63W276YHIFXFAXXTQO34DERM",
-    "GDFDVQNDCQJFIEZVGZ2M6FQQYFTSGNO6L7AXGJVHQCRAKHMMMY46VGCR",
-    "GDFDVYNEOXY5NT3UN7XWVUG4YCFXE7UEQQVCY2UKDUC7UCLLIZU4HKU2",
-    "GDFDYACTIH7YMIG5Y4F3KJ7X6GH2B6UXT7N72VYEESASHEKJBYRIEOKC",
-    "GDFECWCMBVCWYVN7X3SIIMKQH7Z7G7HI7UJO7T22TTDIQGWNOMZE7MUH",
-    "GDFEISHWNOWY7FTPNVBHNYRP47FDSY6ZSCTXOIQUQ7D35OS2JRWYDTHO",
-    "GDFF3ZOGGH7ZB7JF5G7ZHFCD3FJPJTSJUR5CGJCGOO6PUCRIFA4GWPIO",
-    "GDFFMPYRHY25ST2K6VYRER2FUBB4TS3ZLPERLTDV3CJKL4IDLQUYT4VJ",
-    "GDFFODOCEEIOIVCQBXWNZ6GS4TJ64IQUDAORWY2U55RCMHXHJVJVQTVL",
-    "GDFFZENJLFBIB5ONEGWJ5CFHTGPD2JXKUG2VHABPE4BI65BQDMVHU2D3",
-    "GDFGL2AA4FWOKTVCU4DDW4GK2XPDFAH5MAYHOUOU6YXYN5AZUSD3ELON",
-    "GDFGLAEOACVKNGTCGTZZJPMMRTOQUSS3SLL66SISYC4EX3KUDOI55OD4",
-    "GDFGUAGAWX5N23VENHYASZRPYH3H6ZR7OPZIACDWZJ7EYFL4GSEXU22Q",
-    "GDFGYLK2E6K7GGP4N4GA2H3EJKR6LQAJAYQDULAINXAWIXYRMH7EXJMT",
-    "GDFGZMZ4YCR5QQW2OVQDC74W5X2OT427HFZ6W53XV4VIPYNB6TL4AHSU",
-    "GDFH376U7AAEFFI72IYBEDJKTWYEVB57NYBRWGJ4OUSHUX36UCQSBPJZ",
-    "GDFH7UB3C57FKAMCYX2ZDPZ7MVIYDJ7BYEQ2UNQXA767V7HIAHRGBJ6Z",
-    "GDFHFLUJSHGUM4SWE4HRGMIYVOWLY4WGEUHNYBPX2DXQ3IDQSUMVSLCX",
-    "GDFHMOYWA7TWXTNEBX4W2YLP45Z4SFAB7YN54UMASSMD4X3R5FPJWFGO",
-    "GDFHRAUCVDMKFNMJXEA5ZCR63RMANJ3CPTEP4NXHK3VU3NHU7EZU6W7U",
-    "GDFHZZA3GG3V67X6IFWO6QV6P7VW25ZF3KZMQT5AWZZ5EGABLVCVYCFX",
-    "GDFIEESM4XJFPBCKS6GTQU4JRBWRDQ4IQAGFBSKJY4YSULECVL2KELPN",
-    "GDFIKGNU4IJVYDAOKF7XNNS4WS4EBC5BX336EXKUT2TSSK6ZIHTWCQYW",
-    "GDFIT6LIDCCWVD22HZVDCCCGCB6A5VQBGHD6J3H2YOD4WMU4HDYS2KKP",
-    "GDFJEG7DEMUSZMK7NHX2D5VH6WLAXUB4YDX3ZOL5PA3BRFCFJEROPWAA",
-    "GDFJEUWKFNSXCVFA5PM72AURW6D6Q2FG5IOFJGODBVWJVKFKYPFRR3CU",
-    "GDFJTH6GDPEKN3TJL43YMMRYMZ5PN2DYXGGGLLJY5LONCR4AHIEWKRIP",
-    "GDFK5YIKN3W2IMSBLNZCAUGKQPRD6RC5TTJNZME2YRXXE4NCIBQWQDUW",
-    "GDFKEVOWDZ2WTF5FME7OSVIOUHLMHE4FP6F3YBXERWAZEKUWVM6GDOGE",
-    "GDFKGBLZQ75VC6GVL6GHGFJTU2U2R5S53UNIVDTK2XPT7WSXMGIROBAR",
-    "GDFKPLCRFFIHPUHJEU7D3VJCSUJBL6B2VYC7WIUAY56IYVQBKMUHD5DZ",
-    "GDFLA265AYLAEFULIZZVYBJWWNEENICEB6CK7MJPTMNXTERTYU5IOACP",
-    "GDFLDELIPS3CDU2SMAE4N6EANTVORHUNOAXQDAZLWXKXF7T3KQQWZQOQ",
-    "GDFLFAVYO55UXFYPIUJ7LAWHEWGU3KCINBDYBNJ4INEWBUSUBYPJNBSL",
-    "GDFLSRFFRTKVJGCJB4IUAAMQHZX25OI5GMIDESZH4LHIVSX5BCMDHHZH",
-    "GDFLXSI6LR7MHMHLTCR32TJMCMHHRYVHK3MDPKUM67M37D7J5V3E77AQ",
-    "GDFLZ4TCBMVSDS5PBSUW4CKNO6HP5JGNXD5CXT4C7R3QRNOH7MS7HDKZ",
-    "GDFMP3ZKJUVGEJL4WBFMHGBUHUGOJP5MUOL3SQ2I7ZTCOLO3WB7CHAIN",
-    "GDFNMLMQZPZXSW45VAV3EC6GTRBH3RUX2IWD55H36K5H4OGZ6QIBRQKU",
-    "GDFNZC67TH7RPXVAYWE7SGDNA2JA2QEIFHDR5WK75W7ZKMMWUXMNC5Z4",
-    "GDFO6LTYMOXHFOU2GS7MQEWSAKHFZ62FGVQT2FYYLM62AN7YCW4DURKN",
-    "GDFOHR5KMSWTQ2TYI63URVYTZDKNR24EVBHMSM7XITIPKJG3VVNEXV54",
-    "GDFOKIL7QW2VVEFXIDZJG27KKA4K22BPJD2YUIRC4NMNWXRCFH7NOTKI",
-    "GDFOZZEC56W4522UXHPDBTN4STF57TFDJZUCI75IUTTWZFHZOLC2PQJN",
-    "GDFP4Z2PJE33AQGSRHHRCQHOWR3QFWHD2PLRY6ND2OBL53LE4HOMDHRM",
-    "GDFPFAQY2HXC2XVXTC534PMLFIWNRPU6LJ4ZHJH727U6RDKCZOWJCC7A",
-    "GDFPKDHXUQK7TPV4GTBLOQ4N3TTAL4FQKXRAQCW26V2SF3GNODQLUXRP",
-    "GDFPOGVAZXS2K5I255KYPIUEKF2VTZ75QQNCC73J3GPCOHPHKDG6QMDJ",
-    "GDFPON3L5FAO4YXB6TMGRSBEAMPDMOF3XO2PJY4KKJ6KZGL5HIINKPZS",
-    "GDFQKUF3W7J24LKERH3SEQJLT6EYJQQAK7O5FAH5JB5XU4UZQUQEAXRP",
-    "GDFRARR3AYC42AD65LHKQ6XAFLKR62SGU535YJU5RZ72CCFHMAJSIDZS",
-    "GDFRCZRY22RCGSEYD7ML4KBVGHKMOWAHASHBFAMLY6YABIH24E2BYR6M",
-    "GDFSG2Q7HE4OXVZYNEV5FCJNPHNLJGVWGSJC5Y5BBXGJ3OA3SWD3NYKH",
-    "GDFSJ5KKLXVNHGQTB43GTSPVG2GECYOUAHXKUCLISSSLAPUZQ47XCIRL",
-    "GDFSSFSJBMKHUNWEVTFEJJBQU6BFFT2O7K6EEEQNEWP5CGT5BEQETN6F",
-    "GDFT3AC5IEZKP5FAU7Q7JPDHQ2ZR7KFFOMMPIJ4IAH6SW762EQ2RXASP",
-    "GDFT7QPQYZOX6IKXEAUONVDOBCVZHOOSA44SOHOS5GU3GMJ27JNNZBFJ",
-    "GDFTC2TEZW7YLW2ZJMBRGTVBPOK3AFTFOF3DSZYI7B6NFXXVWY44FZK7",
-    "GDFTC2YHJJY6RLZZUINTPUMCIP26WZFJEUUE55QRQIPASTP4G4HEUSP4",
-    "GDFTH4VZOL4GQPNICHJSA6C4MZMW2JEHUE7OJTUMJVOQ3WJBGO6LUSDT",
-    "GDFTHAYPOCPUIWUNUOKY63WSBQAQSHXKCWICAJLGE3XC65BB3F3HVMQH",
-    "GDFTUYDXMUBYZGIMZMLCJKFZ4EINSPXDWOFAN7YBYD6AEZHZ4HC2JOCB",
-    "GDFU6MS6CH465MY5WEYIPK3N4NL6ER7LZ7ONAVD5MQMVVQMF3ABOJ5F3",
-    "GDFUIJQOVEOSIN3B27QEJ6IIUACUQ75IMN7WWKNGM4N6OHI2MJVFNTNJ",
-    "GDFUOAEXUM4DLR5Z6UOLB763EQUESQDTMEK5COTBGRZEZWOFQTYWN3KU",
-    "GDFUVIDWD4EGQ5VRST56OJO5J6Y6PFDAUS44GPRMHX3XUX5C2EJXUI6H",
-    "GDFUYQC3Z3PZM3X4NEDFSG6XUEK23R2JSXZ42E25T6BNPPSZMRK6LPWC",
-    "GDFV3Z4LELIDVOO22ZF4NCAOLVFCXISIPMV3OJ3UWMRLMFBNQQLQELON",
-    "GDFV52ZDZ55WJLKMQ4CUSTUVU5M4KXPJ6O6X34TLVOVTIYGBZISINDXY",
-    "GDFV5CQTWMFRP2MOTHLDY6CI4KN2BJPPNRKC2WRCUPCEXOZIJ5R2R5VF",
-    "GDFVJWNZLZWCHWDYXC3J4EABO3NPBIJ3HOJ7GSJDVXGPKQL67FD2VS7J",
-    "GDFVRWFLBFJJ6DB6P5Q2NFH6JTEKH6DMJ237KUJ5W7VXB57JYV26SH6G",
-    "GDFW3NNYHQE2KBRE3PMC2MSAWHPNRVRPEQJAYR43M7LRXWXXEH5UZFR3",
-    "GDFWOUVKSDOMURNOU6U7H6JRKATLS4KFAVMN5PK5HGJKVBH352Z7JAXP",
-    "GDFWPV4SJPESKZAHDC3OPW7RH3O7KL4XSFT5D4S3ZWSOHYMXP3ABEBTH",
-    "GDFWVP3M6DVBVFKGOYRSV56LXVZ275C4OBETF7P54JBRGMF6V34EMDSM",
-    "GDFX3OKXW3MBZXQHCGTSGXYLCUV5CSWH6YURGK2UYHG25HO6BO6GGJVD",
-    "GDFX56B3HKWA2EVEVPO3TDCIDKXP23UESLD6C5KDY5W2VQXJGY2KBLYQ",
-    "GDFXVK23GF6B6L24AP2Z7SEI457M25KHQXOMEF5TEYGK66T6RE7HI6KW",
-    "GDFXX43HPPIV2YJ7XENTK5TCIPPSZ7Y7CAN7BWKZUAOIPR35QXBNEFRV",
-    "GDFXXVJMRLHQO7JWD5KXDB4KB3OQO2U75PKGE5726ZFQGZIVY3O7HHMT",
-    "GDFYJXQMCUIP2WYKWBLVA35WZMVWLK52UMFVKGXBY2SX7QIYYOUOH6A3",
-    "GDFYSHHZSSMXCUJI6URA7F3BS5UIM2I75LOBYI5MBBPWBH4PUZZTMXKU",
-    "GDFZ62VWPZOXJP3JMG66BNTYI76MJ3436ZGCLN36SLQDPOTNAJ4QI4T3",
-    "GDFZ7OSH5DAEBAATCOAEKK3VGXLOAR5HYVY56LVXR6ABC477JGL5JVSS",
-    "GDG3424TDHGKTS3M7ZJ2RF3IZXGKDZRRYVNWBTAC55ZPL2MOKIGNODHF",
-    "GDG3BXIPZMQNBQQMLCGOPOECEUJYEDVIIN2HPJAZ4Z4WKNHDNIV7MYQK",
-    "GDG4BJZMSOKRLI25IFYH6QZKHWCWNCB7CSUS5G5HM2UW35G2YJM2OPOZ",
-    "GDG4KK457VUWADPG6YSKNK7FOUKT2CHY2TJZASHIBBHHRW3GF72NCMC4",
-    "GDG4KVMXRJIHQKXGUWN2MLA5UKJ7MXRJ7FVQGAJE5XNBGA73STO3L7GE",
-    "GDG4LE3MJRNTWOWWXIBLA354KQSNAND6VC7TBOZ537WD6GYYV6R6SXIE",
-    "GDG4SIDZYP72F7DRYJBEIRKTRR2Q27T4QHMDTBVEJNN22JBXMNQ43HL6",
-    "GDG4YS5QMFOC5NT2HNATHIB4BLGNEJ7OPWQTXAQ7NGJLWOVFJGSEKAL5",
-    "GDG53LGXA4HSUODXCAU3PD7ZQZ4BW4NTU4CKVL7MKBFNVP5XFXDKNLTG",
-    "GDG5V3DXKN6TC3JWO7NVZNOG2O32YHMARNTFDJEVW5RQW3GZKXZOLLFX",
-    "GDG5YYE7DZRCASJHJS54E7ZXI3DWE4YPG7FQRVEENUELWLN7345RMVLK",
-    "GDG6BMZQ2JYUDBQKLXWF5YAWBCJHQPBTWNHR63FCOP744PSA3KA2MZZR",
-    "GDG6CUYXOZJUOTMTBGXEBTDBPXGGKIDMB4H3TLMY4UX2KTZIT5IM3SLW",
-    "GDG6LLHSVISMNHRAE42C6CWLESVLV6P2CCU5C6RIQW635VPF7NQLNQNT",
-    "GDG74R3GQIUZZDDUHRQRPG7BA3M7HEOXMKNY7WXHUHYO25O7RMEDGOGL",
-    "GDG7CQVIMW2YBQ6EDMJCLJ42JLF2YLH7VVVTCI23XDRARZVWFAWXDR4H",
-    "GDG7VKOEHJBSQSMC2FHEQN44UNYPEGI2LJUEHXEAKLSE5V7BMN6B2VAD",
-    "GDG7ZEDBMP2N4RSSZVKVSCWAJ2PATXIC2DU4O6FP2GCTVIPFG73JSBUR",
-    "GDGA5VISC2OT643JWEYIFRM6ZPRELSUGT6SICMBRKFAH5L5YUOS5VISA",
-    "GDGADCSTTRFU2CTKGZAK4PE2VEMW7AAGPHH3YQ4UIIKDIPZK3F5GUFNN",
-    "GDGAOHK7M3TTEXHYYR3ZHJLGKD7C567Y4BIUVGLYQQOV7ATCULIRR6BJ",
-    "GDGAQ4DRQV2YGCK2GGH2NYQ5PEGWH72FJFVDFI7AX5OMQEC2LOOFNZXB",
-    "GDGARISXHEV6LFZWGQ6BIZ4EK2AL2PC7ZB4VGRTSYV2K2Q6TMSHMJB63",
-    "GDGB4OSTHCTWY2OSZB2ZGIMUKUWAIO5CJ44ZVEW2MFMPAVJSMFK7CS4A",
-    "GDGB7YVJTIKHFWA2O3JE5YKNZ2GYUILSIUGK5D236BSS4XFZMMBK3SFI",
-    "GDGBJJYFHCUW6UGQR57NXGO4MVYQ4VNSQSXWGXWBH3VHXFN6CZEFI7JO",
-    "GDGDECMFS34OVTQJKTCHORPE7LOYDH2XYNRMUHLGCJFP56VUN6AW5LCF",
-    "GDGDSGILJRKGDYCBVNEJS4RPMXRIZ6KY54MELLWRMJQJI4TSR3KSHAUG",
-    "GDGE5NAZCJ75LZU2VVQYMANCXZFIIKYMAJDJZMIRSFOAUVLHR3EBRICS",
-    "GDGEAE5Q45PZIK3SFGZH6F47EGU437IDEVDK73SGI6L3VWVGA2LWCCJ2",
-    "GDGEHGS55GAR2TQHLUQEPLWPEUAGGDJTLLDITVASE72TNXDZRYQFFY4P",
-    "GDGEXISKHMJSLI7IGWJYVOM6G4PPJKFNVJQ5GN6BP57T2S5DDFRZU7TX",
-    "GDGFA7MHB4DHGG2RSJAFSLYAV4GVUUC7I2ASMS6X5TCFDGIILQS7DLTE",
-    "GDGFVW56V352N4NRGWCLUYGCO2W34ZU2QHD3IBRBPMYLPIURH53ONUAT",
-    "GDGGQVXUQZNQDKJ6XROHKYNZCHOYWP5PWSMB54TPZPIFSEID3BZECEQG",
-    "GDGGR2X5E44PB7CECFOC5SUPBKV4HIOGBLGFRYUDPQYXY5MKOCSQ74RT",
-    "GDGGYFAK7G5G7SYYAMPYVDP43Y45DVQRGQURH5T5URENYFAJ6JTSYBCH",
-    "GDGHDOHBBW6FR24S725QKKYRJRDQCGQAFF3UGHACQ2CYRSBRKGOIWLSM",
-    "GDGHKSDCDDYANQW7J5SN3HVPJCSI6WA354W3H7MPC2QUKCMHW7SLFCAI",
-    "GDGHLXW6GNUKCMIHUMEVC2TAPBZZZG27FFZFLUOG57POOTZO4JUACOIN",
-    "GDGHWZ5ZVOQOP73LRU5SXWP575MT7LWY3CCAMVIIDBRNTMN7JYLTIZVH",
-    "GDGJG5BXVTGS5FLRPFC37SZD5FQ547WX4ZXFFPYA7OKQVD7JBWLZFXRP",
-    "GDGJP7XEX3JGOTCJ4VQAKN3PGFERVH4G5RO4TBKQUT2OULI5BZSKFKTS",
-    "GDGJQLPK3RDV6OST7ECZGGAWMNNO7X2GHSDPZNGYOOJQD4CAGSMAJBTC",
-    "GDGJWVA7P65NJ4PD45D2AAW6365OFNBTDSCHLMDKY7MHIBATAZVA2IC4",
-    "GDGKBRCPW4C3ENNC5C64PE6U33MG52GBKFXOK5P3OSWF74DAOXRXV6OJ",
-    "GDGKFSOZT7AASR372AUHEJCB5AGYHKPLRIUE3CWKKXQSVEBLWM3H5BTW",
-    "GDGKN3Y7MKHR37YEPRWD7OWL3G6BUYV6KKB2MA7TIROEZNP47HTOP3KA",
-    "GDGKZMBFYAC74DZ3W4A7Z5FY6N3NJDMGP362ESXC44PK6K2GNP4W6D6N",
-    "GDGL2UHB4MY355Q6PAWNG323JAOXKS4KTBLAFHP23RDV46IFAV2QM7UH",
-    "GDGLBYZ37OFEOIC7BFMHBPC5XINHMEFGGO5NGYTZP3JQ2BVA43CCUAYN",
-    "GDGLMJ6L3B7NXLLTWNIHYT24HTNYZM3MEWN3YSFNA22TQUQJ5RX7UW2V",
-    "GDGLZ3OBVN6E7MWKYV4XBWS2A4OOULP7YKJEOJ3ARXAZPIUZQYCIZXRP",
-    "GDGMTH2N6YYQWZKREEME7NWQ35RD7MEPBMRF3TUUYY5SV362TLOHKFQL",
-    "GDGMY5T7RXDPDISXVMYAMLVY2S2ZNCW5JIMVVPA5WAQSS6W6RWZTIIQR",
-    "GDGNLLVEGH3T226LIAB5GBVYFUJY3P7V4CDA4TSWR4OIHLABHLECTXRP",
-    "GDGO5MDGXINURIW44VMGU5DXFW7KHQ3PAJRIUCUUKKUQQNFQEYGO3M5X",
-    "GDGO6TGQLDCUYLQU3JOCSIU7CVCVUC2VHSTUUDPOUEDSZ2L5K3SWO76Y",
-    "GDGOCWUHZ4WB7CSPPSMGJK6KXKGI7IGXC7MEZPRTFDGBUHYQNAGNVCEX",
-    "GDGOJQVEQBFCWHPW5KHID72JD32ZVMLG7HUXB252M753K5747FFBP5HL",
-    "GDGOLV6HX6YOUKEXJ3IYD6UVAC4VYGEBSUDXAB5Q75WE5XL4UZE6A3OO",
-    "GDGOMGTBIJ7BNS32N7IKHOCWUAZSZOMNYOSRJAW5YZX6G2GWWMO3REDT",
-    "GDGOQC2ZO4V6MI7Z4HKBQE46ZDTXK3EADZW5ZHVQKBHFHSHWFJVR7Z66",
-    "GDGP2BLI7GFIKT27KRWDUBIE3NYTWKIHXYH6JDW75RUEYMMXAAX3BZP3",
-    "GDGP6Q4ZAOVCXKLLR6GWM7E7UTEBPNZO36QOG5UZS2ML4LEE43LWPRSA",
-    "GDGPH4IIWBPPY554SEOB6L7GEVRCDB3IVEGQ5HE7ZNAYDL4FXLWD3GNY",
-    "GDGPLQ2JP2574X3QIANLH2EMA74WHFMEWEJTZT635NRPVUYFNH7KH43G",
-    "GDGPW7BLKQPUM5QQUM3F4AZCKZFT52B7PLDKWMO2FMRXTTHTEAOCE5YZ",
-    "GDGQ4QX2KS4VA4TYEHZYZET7O4D5TWRLW5KLML7IP6EXGMWWZG4BA5MI",
-    "GDGQKMV4KFDBXSTCS7MJ7X5NN3CDTSOZ2UYMKKYZ2AQTZ7IN5IGGJJZH",
-    "GDGQVT3G4OIF3OAYHKZ2CBWYMYDRRRTC66YRZSJAGII6V3OB3RKCLXDC",
-    "GDGROL2PRQMUHNJT5T4ZQBDELYG7QH4AK3NANL6PX6CZZKNDJKOG3SUA",
-    "GDGS2ADUQ4UCCLE2FSVQQEMP7Q2VMAB7Q5CQV3B5H4B55ED26MA4W5Z2",
-    "GDGS3AFXGRGSJAJZA6QTHSJEKUR2NC7U4OIHC4HYRRVY6GMJN662WVKW",
-    "GDGS3YGQ6VPOS3UJMHLNL5FHQAZNOAO5YZBPXP5TZPEPCRPNH7IPYPZP",
-    "GDGSOT3IWGNMQ6SSFUXKE64LPCMJATM3POSXE6R4U772G2OZR2F4TWIT",
-    "GDGTCQCHO5I7O3M2NWGIAKQ3NF6NIHDJJF6IDXTWFG65KHKMJD57PROJ",
-    "GDGUFRXFRXSHJPF5AILB5ASEQCH67AIBXJACPI3HL5IQHMP7VQ4O2VVK",
-    "GDGUXIZSXEZAUH7TW4OM7AQQZYQH625SV3WXCD4VCJYRQFU5E26YB5RT",
-    "GDGVFUMFDZHBFGY5VVJVI2XLW4BZO6D2I4K7EX2LLOB5QHMF6TXP7RND",
-    "GDGVJHRNMCM5D564MNDDOXSYL56JUE4FSTEPWSOF5WVDCKQZE3WEPH3H",
-    "GDGVUAXQQF3VEWAZS4LAF7F2PNNZUDJ3UT6HB74CNOXHGFXTOXR2LLWB",
-    "GDGVUO4KG4AJQ623S4PXWJC7XBAQVQDNJ6JUERBHQDZ3KH2RZSSPSUKQ",
-    "GDGWLGQ67EDJJHXVOO64OXZO7JRHIOGXTSXZNNXGZ7FJCH2W3XZ5PWKH",
-    "GDGWS2AEPQ3SVRE7YV5KQLSWJ62F4X5526BEWPARCXV76IXJQMN7BFVP",
-    "GDGWWOP3OC2ERB4RPHPTUMQDWJIOLENDB7Q7PWQOLIPSOEJUPQ2XJMGH",
-    "GDGWY7X5NHBKJZTKAWBYWULQDXKUWNPMZQQNPH443AEMNEX2FQ6P72R6",
-    "GDGX5DE6WBIV6724TK3RWN47P7K4X6O53MJUSGISZQ5HL45JG5YHUQN7",
-    "GDGXCQU32HVO2XMBHTOHFLZ7HWL5NKIB3KUKDU2E2PFEF7DYHS3PGXDC",
-    "GDGXD75PLABJSPSDZD4T3L2JPAODSFAHJ67V32ZXXXKZU6H2J3DQFZJH",
-    "GDGYQT2R2T5UNNT4FWB3LC6KI3ND3TUYUHL3IYJSBYTYQIEUTRBLHSIB",
-    "GDGYWYXSZK72KQJIBC2HL2XJV3PFNS4MPQ2PNANUILJKD4RTYWUGODPI",
-    "GDGZDF5BFFRGLOKQZ4LOTIP7MJNE5U3CLMGSQC7PYDNFS3ZVZ5XI4PCJ",
-    "GDGZEIHVH477W6D4LHAT7RR2ZKHAICLXIU3MVVQCL4QB2CGMRJZ7AG3K",
-    "GDGZJATBFFQWXM5V3K6UMVIEIXZMI4IWAGMFBCSAYZL5L6YUBMVEJZEC",
-    "GDGZP7RVFCMDEKDIYCDDVNKZDJGBSTNGX2F4UHBHWDSKJOVO62WPVNR5",
-    "GDGZY6HNLC3JIQ2AG7JEKMJAZ3EVBJ4NZX3SKEXOU6BGVNNAM2PZ6KJL",
-    "GDGZZ3ZFRATI6VKYGDATIPKNJNQKFZF4V3W5G2BC27EBAFGDCSSWT7IW",
-    "GDH25GLGKNE2KWVTXRXCPCPR4HHLBJ6UHXQ3RKIVZLIZXSSWQ3LCUQR7",
-    "GDH2COWZJHZ7VF3IMCAMUWS2CSKMDAHTTN5SBBUCSGLP4OXNWHDB65DZ",
-    "GDH2F2X37DU2Q676ZAFCF5JIF5JNUG54CXIRQQTXF7KFZX67TN7QEJ6E",
-    "GDH2PFNB52NG4OEZULTTLXEGEYOZKNU3VXDALVDDKCTVHB4Q62SCJEWV",
-    "GDH2PSFFWR7MVRPHRBAJNO2G6BIGE62SLBFNTLMJEPKQ3IQ3R2OOCIPS",
-    "GDH2WFI2HQOKSRSKOCDT4BU26RNVTJ2S53G3XBSSEOAPBZLVTPPSWDUO",
-    "GDH2YZ7VUOMDCLLX6FTFF4NI3NAVM4F3L6PFC75XNLVXFFZXOMBE77TZ",
-    "GDH3FPWZKZCGXOJJW4XKU5H5OOUXLQYTRUOYAVW2FERSPDYDUU3PMITB",
-    "GDH3NP4K4MWUU4EAFVOAWNVOPHLBWVCROQ4XK2TTTQN6TXEZP6C5VWJ5",
-    "GDH3W5HMQOQEVFO3J7MCIAX3X7JNWOQO7FNGTDXLJKHFOOQODWGEVISO",
-    "GDH44REWSHKMMJTTY77SMDV6PGEXWUEJ2WNKRS6XENWIPZVEKUN5YHXP",
-    "GDH4KJOADEDSJ5BVLMAQ2VSQMI5GHEDCPOYV66GUVL3TWPW7JCAIZFSG",
-    "GDH4LJBSJNLODWHFIFAA2ZYYOORKPULCCG7HRGJBFIX57CYROI3UR5QX",
-    "GDH5B7J3J2ZVRELVRTSBZNFM3M7MPYHS7YMKCGP54YUUD2G4XDKINDUS",
-    "GDH5GBPOIFMNH3IMTVINEPTPCNF6MI4CXRSZNA2S3BEUY7KPBIPST6VU",
-    "GDH6457BVIBXB3QKCPWX5EIBEOGWJQDPJT43ZSH3NACMJLUVCRPJXCLW",
-    "GDH67OB5ATA4SS3W7PNQV2D7CAEPLAS2GSJOI4BICQE3XXPLOORGB63Q",
-    "GDH6IPXIPSM6C6ECQV6YHHVID6LYBWSI5N27XTGSXEIRSGYUZ52J7ACD",
-    "GDH6LRG2RPZMML3RMEHMS5PNYUFJCTOA67MJD2SLFETNJV7BP65CBOJH",
-    "GDH772OGNAXJEXKNUGR5M52YSML2Z3PR2OHQPGSA5I4UEB5DMHZBXSX2",
-    "GDH7FG76M3GKK6AB2DDEYF4WRJ6PJNOMA7Q372KOZBYXZKUDBWHNMPBN",
-    "GDH7LGWF2BCPFJNMK7Q7YHYCC47NW2M2P3GOZZQ2FS3DNNNBJU77VY6D",
-    "GDH7NEFUUHADV2MZ3DIKV7LWCZF4ZMRUJJZPHCKNKUDYZIZYWYNAGNZ3",
-    "GDH7OTD2Q35EM7XFMHNB4NHJBQ74WQYI4YHZZMSIDEDEYMON6OV3RUKD",
-    "GDH7RYRSZFR2HW2VPVJOX6EZFEJYI4OPJ3LK5JLEKPK3CGDFFIOH3HH4",
-    "GDHA3VY7DUOVBHS3OOZ7AFCKDZR3ZBORTXFDV7J5HCJFN3ZNDBVNQV5V",
-    "GDHA6WVXJHM6LEMDDDEIYYX3TT5X7BNT3NLDAUY7W672DMD3GBQI4BGB",
-    "GDHAA5DHQGBPM34NCH5HCLHZTLVUHMQ2K7LVWONT2QCQRODXDIEIDU2T",
-    "GDHAAZJEK73RFPE5TPLOEHB2SV3BSM7DAWMY2UAIVTVU3SVEVDU24L7X",
-    "GDHAIUYNDIQJ6EFZDMFDYMKXABPGJJXC6QWYHZUKXMFOOH2ZGDKHDC2N",
-    "GDHB7TPHO3GZS42UPS3UMV6LB7CDHDJ5CEABEGLD7CDO7SJYVS6GU5XW",
-    "GDHBVC7CKT3H6WIGBANJTQAIQYPET2ZKGAAAUGLXTRHHTQM5X25KPPTD",
-    "GDHC4VF4SQG7Q5TUVXTLDJGRQNFKUVESBN37ADU5T5IIY2TSE4VLSCBY",
-    "GDHC6UPLCLOQP73J7QZ7ZUIXQGLE4SJTVDX276UJTPYP4I2IPWH4V5JW",
-    "GDHCEBCFOLRQDPA3CHARZ7HAXPUXTO5UHYUPFGT6TXTGKJO6QMXOZIY3",
-    "GDHCKN5XSQG5Y2CPJP6VHKDI3FAN4ILOPTEEGXAGOAU2QWJQP6YHS55Q",
-    "GDHD3XIUOUEOZEGINKEJJPMQKPCQNRLPM2UTY7I7JT3TAT44EVO7FXBD",
-    "GDHD4NVFQDKHGQIKUFHY42NOFBDKBHYMCQJ4U6TWLJW6PTGIGHFHEQAE",
-    "GDHD6WPOLQUAAUKSFRDNDTLDNPAB4WX7ONX5IINAXFCX7HXY2PH2ABDC",
-    "GDHDRA6AAZDQKZBKAMQYTW45PNYZHYHJOURZYZJZFUUQGRXEI7AGPUBG",
-    "GDHDX22E7OISHJEFZBSS2REYFLJLRMYGDYUQ7ZOMGQD257SVXNCWROJE",
-    "GDHE4ZB4ULK2SB6JV4EJJKI4JFAFRH3WGQAG2TLZ3Q2RNSPP4AC2L65F",
-    "GDHE7BDHQSDP2LQ7M4L6IR7OCHHP6J3ZWLFKP5JA4LD2GPZJFUP5OGDY",
-    "GDHEEIM4UJ3HZ7THO3N6LR6ZU7HPOOOLOH5C7YWVBOH3QQQKC4WUHQUQ",
-    "GDHEJY55ICZXEHBKJEOGJC2UZ2FVII3UUQOBXAISZ6EATRQT7FCLFNVO",
-    "GDHERI5F53HL5QDJVYCXMTGMBS3E2ZIAWL33EHABDLTWUZACYUEWGM7T",
-    "GDHFYAWYIEETU4WAQ63T3IOF7NGG3JZ4HC5QNKUGTABWH543B6VVC6RZ",
-    "GDHG7GL6RUANBBZMBMMNOYX5ASAXWESYTNB653BZJFLVRUHQN7NBAECO",
-    "GDHGPDI7PNYVKETRO2HBJGV22HIWNIR32T6ESX4435E7I452632OMKCX",
-    "GDHGXKTYWW36NYMYJW6MC36PZSRKLAFPIZ53GCP2EDNNACPAHSXQ723E",
-    "GDHHG4VYHCC7YAJLKMGO6O5SXDC6FGXZACHTJHKPJEI35CDBDT6E3L5X",
-    "GDHHLDPQOPRQPLTJSXPRU5ZLOVHJJQNHLFZHC6OZAB2CS5EGG5DKKAUS",
-    "GDHHTGO2BT3VRQZYOASH7JBHNNQXNS74T6NE7LJUT6HLKYFEWGMC6JPY",
-    "GDHI3UJGE4JJPYRWAPAIE2B47D75QO2JAL7ACNDBUDAMWNL3QECYDDUS",
-    "GDHIF5EBQPLZSSLQ6JIYTOX3QT2A37PVCVHMFWE6E7ZYDXCTCMX2LI3B",
-    "GDHIH4PO2SAGZH32XCHT6FGUE3AHNBO32PEZM3MH2O4F6ULWZJRWQXME",
-    "GDHILKPT272RMML56QGMAQTIVL7QPRI5JGOE34YQH5WHCYZXVZ5N3HMA",
-    "GDHIMY7N2LDFEDQI5CKLX36PXRESW4JQ4OHXKAVASISGZAEPYBNICFHW",
-    "GDHINEST4UJDN5VUXJFQFZPIQQJH5Z6P7ZCT2HEYVJVHWG4X7VA6IKVE",
-    "GDHJ3J2VBT3FWP6ZWHA4JP4G64L2PJTLPM7KD44EKKW4CTUCXWZKUCUA",
-    "GDHJPIX7D37JKBHHL54HQL54KFJUVWHAGWJ36N564EKCZ45HGGSZTVEX",
-    "GDHJTYFHD5MPVNHR53DXELWPQ3WYUTZLUMEC3FH5ICP4UKAA2S2X2T2N",
-    "GDHJVCG56FHUI7NM7DVTY4VTMU3N55XHE7JIXM2AXDIFPXYHY7IU2X4O",
-    "GDHJZJOJUEFH4H7AVRCNPHJG6KPOSQFAOTYPDBWMKYCZYVKJKAPIAEJV",
-    "GDHL3UAZZGEL2E7ZES37WDEMGQL7JXKBQ647JBVE7VYOY223I2RTDQFI",
-    "GDHL6NJGHNRFGI7ZXO7BDBZMXQU4ZM2RRPR4YYF33ZPD5NXQNKY6OZ5F",
-    "GDHMHOEAXOLIROAIUYP2BPNZCUXHHQH7VEGZWTHSPPCKBTVJLEG23V7X",
-    "GDHMRQ55Q3PPSPC6SPPCABVJOZSVLY7K7VOVHY7MU6AZUMKGB5WAHFJA",
-    "GDHMWM35LXP3CHBXQRSJSHOB36HVXQ7TGDOUNSB4J4HIQGIN32EDTZ3E",
-    "GDHP6VW5RTTHUNMF4PMOFQCPXDBZ4HGZEOZVUU72NJSFRTXXHIYTE32C",
-    "GDHPEMDNE4EWCMJPCTCBMG6KWVLHTQVTXSXSDDG7ZCSNRL5DDJUFXOGR",
-    "GDHPMXPPFUETQACNM2VANWAL73W7PSAPRPCECWNZAUB77JKKUV43YBTC",
-    "GDHPRCYBX5MCSD4WLW373IMLWQWNYV23JDHY67JFJ6GOEBI2AAJNAD6B",
-    "GDHPS63XYA6OSQ4Q6MSAZIZHW3IQESFGMGY4OPE35XEK4FQWBODVFRQS",
-    "GDHPTCH5NBBYH7HAHIJY6K2RH4GQ3SPYMBQYDGPSWJXV5ZSDY76T7A6X",
-    "GDHPTSSRJ3FQPRM43NLDQ55SXXZD2GAXERYYIQULTT4VQORWGUXQIIHR",
-    "GDHPVC6VTZGSUUBQBRNH3G4QGCPXCUNLFVIYYNYLYBSQ4YUKL523GXNN",
-    "GDHPYG62KOEKDHKWZHIE4FUKN4Y42PIOXAZCYRVNL6FNQOLF7MH7D32U",
-    "GDHPZI5S7AQ4BIVO4KB3XYZVIYI4ESDTMZMARMV2FZMJKSKGNW2LUKST",
-    "GDHQAQBPFYWEO77FLT2NTFTA65JFP675JGVEONLRVHWFUIJGN5NI5JHW",
-    "GDHQII4KUE72ZIGN4VRJJW23DT6DTJ6GDBQ5XVWTBKLEO57OL3YKWEEX",
-    "GDHQIVRSUL5JFYSTK7AW45MFB77ZLWNAN66KNKPRIH5SRT7IQS5EVKUJ",
-    "GDHQNJTQATSPXL2OB2DGM7KVPRMSLXAI5FWXYPRUST3GGEKJ3K2WDO26",
-    "GDHQPDBN6XUAPFQ7TTU3V526534GOWDC3KZYSGZLUE6WK24LZHVXBOND",
-    "GDHQXB2HRQ3CYFDQ2XTIDAKFMMW6EOUGJZOYNZ2M2P5OJZVZSZJJSDB3",
-    "GDHRMX5VEQDJ74VJTOV734CS6NYVFK2XCLSOK24J3DJKKDLXYUBDTKL2",
-    "GDHRSPYQQ6SVCDVLHOOMEINBK7B6KWFWUO6D3QIPS2AVIJINRWY7PZ43",
-    "GDHSBPFVTWDRTXDJ7OMNZ3ANUI6LPZNFWBP6NUVOJVI36PRWEJJO5MWP",
-    "GDHSJPHXD3RV7BEBOCEG4N67JICZDZBYSLEJUTMG4NSYNNOTBMJ6CZ3R",
-    "GDHSW3BOZJDGM5CYPERKVNNVI4M57OLANPXZZSBUZNKBN6DUKB5DJS7M",
-    "GDHSXC6SLD3YEM2PSNFU34MOTNVOQVIVOHZ4D7KHNEZVEYHLEGUV423T",
-    "GDHTTR7MHMPYWDI5D53LRGNI2TEK73DS2AV5NE5VMC4O7UH7WZ2L2YAK",
-    "GDHUL6LKMOKCUWFOWGJDYE7OGYGEVTBS3YTNXFDLYDJNJWNA262RP42Z",
-    "GDHUWVY55T2MEHN5WQN4S3MCGE24J7TA4AXQQKQFTMNRJO2ZND6JZJ4J",
-    "GDHUYHQHEGZV672EBVHIXZ24BHNYUKXKKGBZQQDDDRHOYHOWNQXJ3YXN",
-    "GDHVSICICPZFILNHR4ZNDMXOC24YIZLBZK4DGDLPPGGAYVCYZGQ2FLNK",
-    "GDHVWRFNHLLTET3XT2LP2NMPVEMEDBUR2HCSFBABCTNZT7E4FMR5XP6M",
-    "GDHWA2IBEOWQSFZHB2CL2YU4S2RMXXGBOFUKVL4SYIFG2VWMUSLSUNIX",
-    "GDHWIMCM5LRHZ7Q3LL7M2YANYF6IWWPAGGTWKDDYHCYZCFQKDW7FATOM",
-    "GDHWJ75MBMMUMLMU7EJLLF7FUZKNHOUGAAXNZIG4OZ5RDR2ITMZGJ3LQ",
-    "GDHWPNR7GZXPALQOPME2PKISQYDS3FVTKDKLNU4VUQLDA3ZQAEP445ZR",
-    "GDHWUJZ6O33GF73QZJ22AFONKFDDHAKNUYS2PX6PVVJVOJZY3OKDW5PW",
-    "GDHX2TXUCR3XR2Z4KDMIAWY2KRGJC6UG2BJM2WDUAMWH3N3IDI6QZGRK",
-    "GDHX5G2XNSCOWZG7R7ZUZHUVFJCCNIBWMMURRQEBV7K2YSRIDJI35U5I",
-    "GDHY4NKLQOXIVHYPLX7ZCP55QLZ27PB7ZSNQMX2OKNCRUFEMW6OOP2KX",
-    "GDHYWQB6W6GIXRWHHHN2NZQL6NYJYYYBL2MRVP7GIEKH2BT44CTAO3XH",
-    "GDHZPYGBXHYTTQ436EM32HPZ3BKY3MLXG5VO5TJ7LRTWEWB4KKWFWKPE",
-    "GDHZUVGP2CQLMHPOHH36IEU4PBRXIP362USS7ROSQU5NZRRGT5J6TJX6",
-    "GDHZXVCWJD6JX2EIZU2EKU5DYIYILIH3RMZXE5WBRIO52RKM36ZZSGPH",
-    "GDI2AUG4PPBTFYLXFMNXHAGR3SEZOPRX46KMSXHOMXKY7EF4VQ2W2VB5",
-    "GDI2DTVIXSDR3XXZJYICBLPGTICNQD5YVKJEYIOURQLLJDE6BAIXGEMS",
-    "GDI2FEFXMKZOVIGYGINSMMHNKT3P6KCO4SZ7G2PUGIH7PD4MTGP5DTJ6",
-    "GDI2KEGPA2WFMJAP2RUFYTL4S5MAMWA4XH4245X5UMUMHA7BEEQ5WOEG",
-    "GDI2KXVPDBVFXSM6CC66ZWMCABGHN46UBNTD7IOYS6I6WNS25OZD646E",
-    "GDI2PSFIONW5JBLXVLPTF5TBPOPHGDV4JK5FVGSIP4NR4ROJUZ5M4ZHV",
-    "GDI35OTN4VXF4XVPECV7T7QMBFHF5MERTZ7UVM433DMR3CNFRCWL4BNB",
-    "GDI362WOJZHANYNWXWPXKTMPRXVJJQ5Y46BZTTOADLOYDMV3NCNMQNTM",
-    "GDI3CKHT7PAVWJZA2WJVTMWFU573TOB2ULO4PCHTKGSW7N4MRC7IBEER",
-    "GDI3DMUJR7TQ6AJLK4LXYJ67BYETBQPOXTYX3DRFX747JPQDC7DMI64I",
-    "GDI3G6MNH6YDOCARNWP442PHAFNIPSLLPX5OVMZNJJQGK3ZH6EOWINLU",
-    "GDI562PXFSYC2OCGTHSXMZBXLDYTIULGG5BAJ5GZBQTBOSLRV3G3UJFT",
-    "GDI5DJSOENWADGRN2YRAOV6U6XUQ5K2HHC3FTO3RZEFXW33NKZDDLNP2",
-    "GDI5E3BGMDFURV7MTEVCWHKK2RESLYAFPZP2RF5BIPJVSVHDYADWEIFF",
-    "GDI5FH6LMUDPSGZO7VYPWSCDS754GXGIDMLDSK63AWSFPIZDG2WFR67I",
-    "GDI5GCX3G7CVJYLD4ZXNDBPRMPWGUXZO6SUODRWPRB2G52GIQP33CFF2",
-    "GDI65HXLISIU4ZMHJWI5CQ34MHFAWHHN2ZPGNXUUBRSH5KFS7ZAFHN4G",
-    "GDI6F4C4N357NZQAEHNPSCWAFP3U76HWDEB7J4GZYZLYBZYF3M3P3J53",
-    "GDI6FHXYKDDRUQ5RNBXHZPYCH7P6VN75LX3E2KOMWBEGMTZRKZTDKVA2",
-    "GDI6HWVVRHKCIV2IF6FXCSBEAKCQ6S7C2FMW5N2W3F3FZNWLXZKMRHDL",
-    "GDI6QQ3P5RRFF3RTLLTNFKMH3NK5F55JRQDFE3A2JLVPI62UM2EDU667",
-    "GDI6U6B5KDKFXX55CX6Q6X2UH43BPUNEMJIUXUGDLGCVSQSDCLCISZNV",
-    "GDI6XVMI5GJSNZMGU5UGPQSVULHLJTXT57EZPU564B6G42BTKKHWTBRI",
-    "GDI7HRQOUUYQERTYRREHZI5LMUQEHSVR6D7EVRUNJ6GELFZEV6DFUQVR",
-    "GDI7HZ7L7QIQBHLZ2DII45RH72ECHKNK66HXTGRM6B3CYA7G7Y5WJALB",
-    "GDI7NL76GCCTSISZNSQD3ZVJJOUDRM3K7SAXQWGVZMHZFEOFSCXVRNCK",
-    "GDI7NW2HBJKFA3CWABEZVN3H5LEI32MXXMQHWWBOKIYRBDDRCRPTCBD7",
-    "GDI7OR3ST3KPKKZA5RXYSAW73WBQBVY3JYJQG7DYCOOTKB5XWXYALSGB",
-    "GDI7VIVV2ONHR5MQSPVELJDAFOJE45VBNJSY4BINIBE5ULXWD6ISO6CX",
-    "GDI7Y4PTZJCQONYIKVZHELGBEDIH6RZGKOMQD6WKGGTRONMAHEBDCGYJ",
-    "GDIABD7RRTG5G3SEWOG7V2TFNTJCR3O5RXWYP2HZVB6IW7NEY4FGQZSV",
-    "GDIASJG6WOLA52UAEV5FPD45LCR35JDNK25SHKWOAT6O6WTXW7R6CURR",
-    "GDIBDF5EIM345VODF6R72NYV4KP3GTR25B7EVK4YM6F3JCOZEYGSMI6Y",
-    "GDIBEWSABTNTSEXGEXKZRYYGHTFSQGDMWRJAG66T27QXMO3SRGQWXTCX",
-    "GDIBRQTMWJHEALZJP7KR6YHPIGMMTKF2IJU6RQIHMLDMZGQH6WTPIE5J",
-    "GDIC5NYFCN7VYSF5GNKZCUNMYDXBM64KR43G3M56VUGYZ4PHHRBD4X6Q",
-    "GDIC7AK6G5UNJUT5NKFSZGHEYMOK2EDNQAZJ6QTXO5IC2W7A2T5M3WJY",
-    "GDICI63A3HBI5DH63M3PS4IUA5WKBMF7H234AVBRA2VBA6LLHY3EBOTH",
-    "GDICLS7BCN4OFDFJCAKOPWC2JG5QJGONI3IBPUAOSX27PDKQPG77FIUL",
-    "GDICSXSK3BPYYUVWRENANYGB53T7XBHAPAFXOEG33SCQ2YTV7VNAEJFX",
-    "GDICTP2O4X27Q5IUYVQGGMTBQRF33ZQFFK3L7TSNA67MWBRX3GNOBG32",
-    "GDID33AWVIOP2YRAXMNC55QJDX2CGLNU4DWGH4VNTVMNJ7NW42VZUSZM",
-    "GDIDQ43TTLIH2CGQRRHJBMNQZJWWEZJS6U3MB6FZBJ56VAAVVAXB6CYK",
-    "GDIE2LBMSEEJB7FDYQLYGISN3WO2VY2KLMDYHJWAQGKJDSSY3PTTBDX7",
-    "GDIEAVXGQXAAFU6IVUGAER6XC6Z3JBENPXKQD4IE3UX2NHYI57LE5HGK",
-    "GDIEYR26TX7IUH2MXUTW4BFG74JNMEB4DWASDZTWULUL7YWUAUHE654N",
-    "GDIF7KU5COVPHJDMTCSKBBQOSYVD3I76JSR43VXDUPGV6VXT3EDMGF7J",
-    "GDIFQDKBMOVJX6YJQP7GHEHMHHPSNT7MY33XLKUXCIJFWEAHZVNHMS2F",
-    "GDIFUO7JX5KD4VMTHXYPSEH25WQEN4U44PYVANSGG5OAUUYVKMNU66HE",
-    "GDIG5A7FIK34V2VTHRYX56SLBBCVLO7QE2FXYTDF7NDHEM5QCIDFHJCX",
-    "GDIH3XNF7YGLUK6ZFI7BSCWKJOQRGMHHAN4ER2SEE43UJSGFQZJ3MQED",
-    "GDIH6YV7JCALZGTESREYNEXXB2ZAHBITBQNTOWGIPDTL5JJIUZANPPGV",
-    "GDIHHKX6AICV6LTKBXGDNW7PKQEDAJT33NLFUB2O5BJ2DNPQK2L2CX2V",
-    "GDII3M7BIGWPVYFBG35AML34333DR6RBMGW4RKLGOXYIUPA4QNOS5I62",
-    "GDIIFJ35QL5GMJ3GKHETOOJ6VQWK4B3G3U32FJMBMFEYXQXCDKYAMH5M",
-    "GDIIWZMSRSGFDATJGEYQO2SWKKJGYNCCANF5YDBG4MQP6MYDBAYSZ4JF",
-    "GDIJ77PFPEYJUP2EFIKEY4HM6GRBJMCXHJPWYAIW6FCEB2JOKD237E3P",
-    "GDIJADYNFUHGDVIUAE3E6J5LMOPJHCQCUAGYJAUHSLLX5V46IZSLOWH5",
-    "GDIJFCBM5CIH34PUG623IDPVJRMVRK3KLWMP2E3XNU6G2X5XRGMRVGI4",
-    "GDIK3YCYQWL56HKNJ4275M2RKNMIVJQL3XEM4WFHNJAMH3SHHSS7P4GK",
-    "GDIKDAP2GRRGFNXM4RV5B73XHPKCTFA5IQ66Y7NY6GERPQRWJAYPQOEP",
-    "GDIKR3FJPYJKZQMEZ3QMSXYUAVIJARMYR2V75LPGBDSU6TEI3VY5TWH6",
-    "GDIKSOBDFXVG3RH6OIJRB3QRDUV5WJN2M7XXD72J3LPMRCEJ6EOX6ED5",
-    "GDIKXXGR3FGBWHAP5MWR4XTCCZ4AMMQXSFSRB6EXRD7D6NMSKF5HEDOT",
-    "GDIL2ELZ2JUWAXJA552OC6WV7CS2IYSSFWBNGTBQTWAZ6MZNGMBBMX4N",
-    "GDIMVGTGDAEGB6BLCO3OSI7FGSUUCTONMKHYK7GQF6VZAMEHDNHETYTJ",
-    "GDIMZ664WIASFYDEYC63WZTZI5VUFZIKK4MOOLCLMP3HDX7IH53WF5LI",
-    "GDIOFYAO32GZLLY5UZIMTE6AO2AVLH3P5VZ5HOFFCH2SLLEY67AY2ZN5",
-    "GDIPSEWMKVVJYJ6GELZEIMFAI54JYO6LTNGHRMWYLCTSHWT4WZXS2EXZ",
-    "GDIQ2MJILWMMVPQMPV3HK47ASKKCY26XIWJTLYPRL6QP3NR5YOHCTPHD",
-    "GDIQ3ZSHRRZZIH6LQEHTZT5DNNBRY5GMGMMZEG7MVIPHTWFKQSFSKPGT",
-    "GDIQLZJKO7U6MED7TMZQVVWUSSVC3LXKK6JHNYHNBSENJOZ7LSE5DHTE",
-    "GDIQTIINPOLRSE2DTCORP7KWFNB7IM2UXG2UWR7CSTJEXXFPIVJXRMPX",
-    "GDIQVO2M33IJUNUOI4QHFKKLAYLNGT5QKPM5ZK77GRI5UT553PSPUPRK",
-    "GDIQXOU7QEFLKTZSBBDBLNFVO4L5SOUSZAURVYB44VIID6U4LX2TUTRP",
-    "GDIQY4HXUPOGUD7GINHUNK5ZEOPGNF7MJJNTRWKTVMM63ACNQHERWIOD",
-    "GDIRJF4BZMFYZV45WLFWEEP6ILUPLSNNNVWK4NELJIL5U4IFAPOQHPYC",
-    "GDISBL33GNYY3JWDLRSD6AWMNXGINRSRP2TEMTGTCOYX73WTSTBIQISO",
-    "GDITZOR2432CPZHZD5MQQPZBG3N5GLUFB6677QLSAZTLJ6CCBHISOFPE",
-    "GDIUCLXUZ2KLHAL54JJ7E2XVD6AC6MSRPJGNCKA5X4FHYZ5MUBNZQLG6",
-    "GDIUWKMOOEXQ4NG6P46AQ2XAGZIJPDSNWNVLPAZDQD543KL3Q5P2MACV",
-    "GDIV33QINBJDV374X3PWKNBDJTRVILZNQ3EEO76CQVPUPLFFBFAPKSP3",
-    "GDIVI7XEI4KDJUDHBEV3HRGBZNG3NRDZENTWYBPTZVQQZEE5U4A53YFI",
-    "GDIVJ7GRKQSLUYTFIDSEUITLYGK2P4CSXFHKJ2TSFBDQM46JIU7KID4U",
-    "GDIVS6WGCQRLN4XGQI4FBOUDYIJB6CTCVZ72GZBXHO4LFGYAGV7X3UQW",
-    "GDIWHBJCNPYMBWVSI434S4XY674LS523CZ5BEPQHZAAHSXPTDU3UXN4U",
-    "GDIWWZ4XIRI5J5WE5SDPLWFF7EWYVDIM5GOA5NITUKDA5DJMG43MDM32",
-    "GDIY3KTLTXWYY6FY3K63OGQXDOH3B5MYU56QXBBAYMXAJXK5JZNNSCHG",
-    "GDIY5T42TPCN76ZGSK7AGN732IB5QYERSZPJ4S62ZWS3TNPUFRM6FFZW",
-    "GDIYHCTN2CYGEJSA4DF5ZGOZ4C5DGLH2F6CNTOJYTSEGITP5E3TS5F3Z",
-    "GDIYMCT3ARIESLHKY4C7JDZZTNNEQQJQS7ZD4TSQSLP5GPOKS2NSHARE",
-    "GDIZBUR4HPLX7MWPEHYKZPMYWUYMM7KIRXQAMZLOGMRBKGMVW5PX7G3U",
-    "GDIZCSXRX7NVXCXCTIZCXT4SVS3MLEQVGOKQE5UTVJI3UMN4WIDDYPCY",
-    "GDIZEG4FKACI62K2EVFNDYJIYTPYRL4IEMVAQ6S7C4LDQPFQSGPGBUSD",
-    "GDIZFQNMAKUOEJJBPNZTPLXC2N7GXAWLAHW5DJ6V4XSUDDH4RZFPNPZS",
-    "GDIZIFKMCCRCHHVNUQS3RLJVIY5KBOVYYIIXPQSPYHLRBCK3EIMQKY37",
-    "GDJ2C4KZ74EZ3DPK7NITB3VSI42JVA33CEPMWNGFOM6BZWTNSVXTLNZQ",
-    "GDJ2VR4OE44UNRQUBQPR5RFSTT6P2YABDHRLJQC5J6QVBV6ZHV4KYVJX",
-    "GDJ2ZLWFVVBWKAVPWW6QKRZA7E3COZ3VWNUMZVOVBJNUQF77FCCNEJQE",
-    "GDJ33CJUIARE3NGQTLBSX3S2EHOVGQNJZYVEE46YHZJFPS4NQJ35FBA3",
-    "GDJ35QJJUVOXXWNM57KYDJFREYTBKCOOPFN34DEUXITQ75JVEHFMVISA",
-    "GDJ3DG33HNJFBE4X3TZ42K3IGJ4KICLZ75G3V33BUKGKJHFYQCT7NHMZ",
-    "GDJ4G4PROQJPQX7I2TETCMLHJMWCENB4D7IUS2BGAZVDG3JK6AXBN7Q5",
-    "GDJ4JDLFSWHKYGQ4AHC54GIFBFJKCJKNTYQ2ROOBXL3SYVU3JHJU4FEM",
-    "GDJ4K23PSWOEC32WRCT2QLJXDR7E7ZW3FKPSRWF5ZRUYZSTBMDDP6LN7",
-    "GDJ4QJAQVHFADL7MDRGLKEHQU4LIRCLFBSJRVPX6IUFYCWOKH5FAEGOZ",
-    "GDJ54WB5HJ7OIUKNEDZLRDRJQMSXTV4ICHTNB7B2P4UF4SU3GS5EMG2P",
-    "GDJ5DM5VJJMJKHLE7WSTXIIPBMZOVZTD44OHBWE76KGL36Y7X62T3SI3",
-    "GDJ5EXK2RS64W26WJNPBC2NSHB2Z457DRY36CXFOOKXFDZCZIPEATVQF",
-    "GDJ5S426B22J2QXFJPTWTKK7GIXYVFIJUSOKPP3K4XTOMXH4JWAGTNO3",
-    "GDJ5YUKJKZXFB7BXQVY2VZV4RS7KCXPDHS7IFAMDM35EGPCGMLOFKGBE",
-    "GDJ64QHJZDIAGNAILG7REGXJ4A3N6L2OLDYZMMRRHA47ZULBED7FUZNQ",
-    "GDJ65EKVSHC5TZKNJNKEJDIZBNHYJMLWMMISGGE4PBDXOQ2MF2KOE3CB",
-    "GDJ6JJ3Z46TQAJUR43THCIYDQXMEXDYHQMSZ3WS5CHETXURZZFF2IWV2",
-    "GDJ6WFUY63VV5HBDCSJEVSOP5KKIHEMTJ35LZBU25PWABIW5RS5JIQ6N",
-    "GDJ7D22XMPCSPCGET3POX3MGQDXXCXKZVFTQCMX4BF45OYKEXEYNXBK7",
-    "GDJ7EHZMMZQDBW6XBUATT3EYHQEZGZURIXYQADBWWWMFGAHSIZZJUEUX",
-    "GDJ7UTW6L75AOFMFJMHVVYMLFOOVP3DGEDWE7O4TH3ATQYBY46ZIWVEX",
-    "GDJA7HBH6HWPLQ3MSFXQ47RUH5DLNJIVPTDFAOBZFFXUVXK4QDQBXFLR",
-    "GDJAKY2YSW3LJULYPQXCCKQUAMTBTNVZLZ36OCN22S7TCAV6MDYLX2UI",
-    "GDJAL3M4KDUHQG24LUH4HFNQIVEEAYU4JVDZSKGKCV7BDW5QROEIFLDT",
-    "GDJALRSF7OUCYFCSLYVWJKI7V3JSLIE5U6E6YVMWBN45POUYRMVDJPYP",
-    "GDJASFUNIGEIZAQFPULSO2HWGXXYQPAZE2AGEW3YL3SRF7627I53TWIT",
-    "GDJB4LM6MSV5IAUXJSC2VCXBYQUWIXCAAMWCYKSVT343DUDVGL5AGTB4",
-    "GDJB5QRXTQQKDPPOZT6S5JVF443SP6W3DDFR54URSJN2WYTHBDIKWIH2",
-    "GDJB7U4W2LCGD7XEGPYO5Y3SC2Z2JZLUW53KHVJHIRKRWFLWXET5WNPS",
-    "GDJBLEWSCZNXK4XPAPGHL73SXZGIT7VNENT4HKC57RVR4CC4DFC6QPOP",
-    "GDJBNLR4PEQT4T4OI7B6CH3HZ3QKZCAZASOW3J6LW2CWYOQWFA5KNXSW",
-    "GDJBZKF74FFP7Y6J2XVWKX2TG6P6CI7GV327AVXKL3KTNL4OE5MB4AFM",
-    "GDJC5L6FMGOUOZJSHCKBXMELVY6TJ2G3OK2HPHARJLNRLF5NPMALTL7W",
-    "GDJCDLQY7MGAF55R7U7QI2KW6FLCUJO7XP6VK5E7UQKIRZMNQKRWYTVL",
-    "GDJCGSGVZLB4KECDW6RNZ6UMF32IMJK5BNVYDAPCBK2IWEYMELT7MEUB",
-    "GDJCSCAKZCHYFBFJ2EXD2ILUOZSU4DN4S4A6QLFLMS4BOHGZVOLZWHIP",
-    "GDJD4RMOSHSA3WYQIN5OCED4DCDYYCXMAWX3SA7FO3YNADKKI6EOFDD6",
-    "GDJDP5YUPYNDCANOVJXPJR4CF5NFY6JDKE556BSEZL523BO2WEHBJ5UV",
-    "GDJE3NJAW2V2RG423JPYMPK7IRTDILN7AXPGKZXWYFFWFZUIBOGNIAVW",
-    "GDJEC7745CEG255RURWTERPH6SSGFPARNUB2HS5N3F3UFJHCEE2YHXVD",
-    "GDJED5243NYLBUO5MEPWFXACADS34IXXCYLK4XSBVPGLO26OKN6TF6XL",
-    "GDJEJBNRNZW4VBB7EMF57A7BSJEU3C5TTJKBUOMO73UI5PDVTOKUBUSD",
-    "GDJFJSDES7HPX767VMFT3F7A7XV5JRMVLHH2TDED4JYIWA2QK5SSIDMW",
-    "GDJFMEUVHA4TQL3WNRL4O6OTM6FOYR5TCA3DL2RMXXE4QAOOWWR4FWGK",
-    "GDJFY5IQR4RO4N2MER37RTQW5U57AYN5ZVFX5UMKB5X5XWUW6MOJQAGZ",
-    "GDJFYMZFPCGPXSRLLSOPZTK5ZD6MCBOL3I4P7CGP3DRYWKBU7FEVTTRT",
-    "GDJFZNM4TCBJ7I565YZX4M6BPAG53KRBPMXKJK6PCYVEAVOTNWRM6DJM",
-    "GDJGYIPI3QHOG6KEWF663DAMGCUYUZKK7WCLPUTILGYFRF7ZSQ5WN6LU",
-    "GDJGZ22LOJ4FV4K7XXVFOCYMFVE45SU4D2IDWCDVZOBFDLQ7PNZFTETF",
-    "GDJH26Z25LZWASGFZJQLVG2WM4VFINALM7FCQS5HELU5FN5QCM6CVHMD",
-    "GDJHCIKBD7YDDE22E3U54W3KTVKFHRXDJ7B54U4BMLZQCJLK73A4ZJVA",
-    "GDJHMSBHXDDLBIAQIFQMANQQPLX4NCC2MEQHPL7N4VPWW6F47JR55SLH",
-    "GDJHQTKAUW36VYUYMMWSEMMLDZNFAOCPFGVU3UYAIZSMCEFEL6URDMUV",
-    "GDJHYVIRT7AQKV7U67ZLFSYNQKDNINELNPWWGGY6XJVX7S5IPKGIY4LT",
-    "GDJK755RNTX35I63OGCFSMCW5TCLLJVVUOFM2D2DRMNGWEDSF3JMPLYV",
-    "GDJKCA24VMXESTZZAXAXTLJOTAWEQOUD2X73Z4YKL7QTKUPALJK5P7WW",
-    "GDJKKA6JJFIGQVPGKHYLVLPHEJ7AJUHLAFAQVBIV7YLPAINQFYVEYEQS",
-    "GDJLMZPMAUBYCTPGGQUA7EKBONRNAEEEJU5LQWLSEACU6UQJDJGLICTJ",
-    "GDJLVJNGR4X7CRAZQF5ZFFFCYVFNSSSWVDXDOV5V66U6KKFWDNLEIBJQ",
-    "GDJMUWQT2YFGYDF2XYVPL242U5F7YMBNEHYKVDJ7EDSW4R2KR2CY6WYJ",
-    "GDJMYL6VG52AL5YBW7XW6DZ4CXDHW3XRLM6ZQO6PDCFLUR47H6BQ5NLX",
-    "GDJN5BDBEA3Y764JFLHT4AMZQ4Z2DX5JGWRJDC56L5KRDW34C5GFBZNP",
-    "GDJNLN6KEEOCOCAYD75JVEQJXQP7O3Z2ZWRH6WLIYVSRJXGT7QD7C4XC",
-    "GDJNPP6K4XFJ25UZURJZFU7BTXGAXVZFS7LSZ57TJOID25RW3PX27CUX",
-    "GDJO3ISV5SC2RE34GY6XQIOWYA3U4VFCTSC4VUBMXQJDCKFUX4L7BZRA",
-    "GDJOJ65LTMVWID5TJQRNUNNKDPTMGRQOLYPIXPGZO43M2R33YOEKRAKK",
-    "GDJOPLDINYU6ZVUM7V33VWIESVKNM2ZJ2QSSOJLFHSA3VIO563M6QJWD",
-    "GDJOXAJZ4LA327WBOKP44UHXWMVGAIOHLOBEFEP3W3GWIFITCMLUPOFR",
-    "GDJPE2GYJRE2IFICOSC5T23YUUPW435EADGT5SXPH2IXL5XT6LLQ4TUB",
-    "GDJPGX47GIEO7XDRPBJWH3LER3WU6RY2OQWCB3N77CY3VPTKAOMWWZOH",
-    "GDJPI2DYBA27VFMFSZSTAXBGD5NRSWAX6TKB2VJGVLKCE2REZJOMFSPE",
-    "GDJPM73QTEPR5HFU2PHT4746RNQPA7BC3LFOL7XR33EZKFKZLVHYHFQY",
-    "GDJPP4LVNRRPSP6OMCDTSSWFWWSFOBV7KKP6OAI5RE3MOX2PO4GSZCJE",
-    "GDJRL6QYVON2RI5UJMDI4NZ2AHQN2RSWB22FTWPH7SEZI45V3XD3UQNN",
-    "GDJSCIRHDGDGEI5SABB4COMUCJXM7S7HJQPHMF2AL3KX4Q4RHWGAQWGQ",
-    "GDJSIJFUXQXPVV2Z4GMBDA4HJV5WO7D7CYNZYO6QI6L3WHNAY3P7BPFI",
-    "GDJTHV47RVSFK5VQ4CDU44VXGIVGARCXR2SQWSCXEEBBHIUAZJHETSBI",
-    "GDJTZIDNBKMLJQMJF52AJM44XJC64FNB5VZJJZ6OCE6HAZXSRRB3QY4H",
-    "GDJUALXGRS76CGOX2X6ZCQGKKVWDRXLRHXSP2TRBCHESE6DTKJZVEWNC",
-    "GDJUSTBNW2SOWHFVOUX7N2AEUO6UFVD6PR6RG3XI36PC3UGYXNFPPLDG",
-    "GDJUW77NWDC4I6EVSBHRAMNUIOKTL2JUP5QLAOZ2ZWTCDUUME4HFBPFI",
-    "GDJVJSYJOLRCMP6IXZFN3LYTZS4WFXGPKUJQFKZJDB4CCKI3EAX27D5J",
-    "GDJVYP5IX6D5JM73EPR7GR2AUXR42OX65F7ZB5URKC4T4VV3RJZPZIFH",
-    "GDJWSVM3SENP5KXBEFRRCGVBFJPCN2GUATNYUTJJEJZ2QYDJMOMINODE",
-    "GDJWTSTPMK2FIGLTISYOJ54MYKHLOHUJA2E4ETV3PD7QM2X7BOZI7V7Q",
-    "GDJXJKL4RISCTM53JFQ7SGWPTGZVXR4ITJHG65UAG3Y6EZYRQLXCZSUV",
-    "GDJXRGJIRGYKLLLZTD653TKCDJRE7NOUN5HMBHT34VDRYHXCFKNA3USA",
-    "GDJXTIQ32SFALAPPVQR6NPHVUSBKAUXTAXC2G4ECFZZ6UBZIG4UV5CJL",
-    "GDJY3UYHKBOCLCLLN4X54AMBCXKQ727QRBEVZ6RVXZTH36KBIN5AJDKI",
-    "GDJYUQUBQLZ6Z74FRRIGIPEOMEZUO5MRLHPJJGMFJOKVUUJRU7BLACTA",
-    "GDJYYB3COSJFVJSB3JRTLA6YHXNJA3DNEAYWGMEQAXSEKKHKXBD73LYE",
-    "GDJZ3CRF2JAXYRZH2R4JNCBKS65DG32MRRV7OI6Y5V7RPWOKTBNZTM6G",
-    "GDJZ6FYORJMBFTTBFVMQOHAXYB43V374XNNPKQXERPBY4HH6SM7EUPIU",
-    "GDJZO5FJ2CVXVV57DCYYVWWYMQAN6MIW6GTMOWS32PXR2FGRVRCDFJ3V",
-    "GDJZZ2FUFCBNQFJZVECY4UHKF4E4LBYWTCTB3EMGVIHBR7QIIVFBLQA3",
-    "GDK2CXJGMY7OTRA4RV53BWZCHJGAKAX3F2TQQ2FYX4UYQKRDMKR5OZID",
-    "GDK2GCH7PU52F5IR5OIDCF5WAE6ZQN7SLOHF6MCMQ7LM6LE2LBIL2WST",
-    "GDK3AJSVLZHCRXDWWVE5VH5ID26GIILB2D5I7UOCRDMNIWYQ7DO6QASE",
-    "GDK4DPYYC6U3XNJNEAGRFWLT5U4JOQHGJHZCKJT3Q7BFPFJDJWIBEXLM",
-    "GDK4PEJQU2NXMSA3UFLXN26S3VZTNP4JGQAH2WRSQC3RNMKB73T72XLM",
-    "GDK4VLVGSIMTR7Z5772INKQV6XERQA25GFZ57KYXOUWNPTSS6UMZ2AV7",
-    "GDK57QZZ67WCZDCCYOPZJYLCAVBFZS2VBFH6I3VDGCRUWFOKVX4QCUON",
-    "GDK5QTOY27QFAH7OYLPEF3MT3N2GN52V4FRMHJLI6JYXALGPZSRQZNFJ",
-    "GDK62KXIDYSDOLOM6VX3DCCRTVODTYEWNH2BYFQQQQIMBENEWZ2X4P5H",
-    "GDK66ZRKYKSJWMC64WMPEERNPGBLLR3EGJECQBJ54LQTHMZRBNH6NORQ",
-    "GDK6CMMAKDQNTFLRS5QLSNAETYT2EFKG6LXCNEW2DESQXNXNGXP25LOK",
-    "GDK6FGOCN7UE4VKBZ6Q3EYVZBV322BKZEUFW4VLAIS7ZC3RYGSMNXLDX",
-    "GDK6UECHL2P3M4DQK5KWRI3U5HJU2IDC7UK4WDCLVPUQPL6GI575JCAJ",
-    "GDK6YRSDQ53ZX3O6YJZYUYHZ5AX2DWO5FVAE4OTDZJ6CCB3NLBJJ3TRY",
-    "GDK7AFOONUIUI6YKWRAVXQBE25OFUHRAUAZUZD4LSUY5PABVP5GM2TNV",
-    "GDK7F27MRNUZOD57XOQSAS4H2USFXCJVYM6WZHWWBDKNUY7XUMKDN56H",
-    "GDK7PPCGQVJ5VLXPAQJ3ETKIPWX3X3HW6ACS2RSU6W4TFYTDG3JAOTLA",
-    "GDKACPIHQJREDKXJKIN4ZSISWWXIJFKF2LZFDXIBSLJXKKTZERLD34KY",
-    "GDKALNNTJWX5M6JNBV46AYWN3TSUMLL6ZLBKBHTEYJ3GYZUIVAIHICKF",
-    "GDKANVPDAYKXRHL7WKRHYP7IZ4XBMTKXESISD2D7M4DVJ5AJB36MZTE4",
-    "GDKAPPZYZ34UAFRIJ3WX2RSWWSPEH3XKK7ODUFTUFZFSDFJRFWHZZL3C",
-    "GDKAY2CIX2C4RDXAVTOSCGGXMRHWBWYUHMJUINEJSXVPLNHFWC2PMPR2",
-    "GDKAY4WP64ABH62BHTSTNYWFEH54KRMKAK3URO2CUJ2BIW6RWGTLLINK",
-    "GDKB23URGMPKL3LNCZYBVU5OBLRQLLCNMN5GRQ35PNTQ7YTKCUCEBTCS",
-    "GDKB5KP4WEF2JZW42VKIYDIZBM2IFUVVAADOIUIJIC3BNFZ7C3HUBSRJ",
-    "GDKBBDPIMUXAJZ2N2OFEIWJQDC7UKAYTYBXXTN3PUZ7B4Q7IEFQABBG2",
-    "GDKBIU2WB35TBSNDFH74VXLNV6TXXAMAXELM4D53JKIUXQWQ2FOAHPAU",
-    "GDKBKRS7B2BWPQ5O7LAPABTD6RRMZUJOOREXXQ3JUKNPDGG3WP3RBLCX",
-    "GDKBQTXH7S5R2EKD7SPFHGNK2Z25LJGHLILQKLV4VTRMEYAOUASAUVGE",
-    "GDKBXGAM3BPOKJC3GYX2B7WRW3KKLYXXG6RGDQTQRL2V6TZE3YX4P6KA",
-    "GDKC4KKCNPDAKTCOCMDHF4OC6TE6KBF4QGN5JJJMUN22HH7YTK7YDZBK",
-    "GDKCE4GU4OAYKPS62RS3L3JJNQOJL65QEVHZPYZDS3TUGXD6HGSOUTQM",
-    "GDKCEGNB3MFBGZDVSURIH3NZVCI4LDIYZOY6Y2KZSSPK5IV3SSOSPETR",
-    "GDKCSVKRSKGMM626IJ7M5TLGILDGN5AO7INITQF3XV5VF7CGE2Z63R5U",
-    "GDKD2WU6UN4IAIIBP7JX765IGS3AKQZ3IKOOWIOBLZ34WMTLARPNCEB3",
-    "GDKDLY4WTV4LPSVXRR6GJHBQXFH3Z3GYRP7LRTBWEWJMZKMABRRSR5CB",
-    "GDKDMBQRG3BNBT4XHZKM7TUD64SJW2535X4AJFZRG5DMHYYWDX2HRTYL",
-    "GDKDQHCZLZC5FNJ5KBSI3I6QBCZQNOSMLND53SWGQOCNQPECV6LZXJU2",
-    "GDKDQVUGKNAAIGZP7K6N2E6PDTP2TUD5BCTU74QPZVIKL4OXLOU3UYVI",
-    "GDKDWEY6XYJH6NONR5EKZRN6P5UXLIFC636ALFNI7VXSGFRX5HFWBOJH",
-    "GDKDYL3MXO7HWKPWYYQS575M777UBQIPNXSJOKCTOQELEYU3CFQJMZ56",
-    "GDKEKSAHODXRQ5H7MCFGCOJLSVBMK55E2AJ74BQ7EN7GMNOQE6WSONFQ",
-    "GDKENZ7HSGR7EHOSZ5664YSKWAEPWZTDLUS37FAPDF2HQYV5MSL6XCSP",
-    "GDKEUMTUMYCFMGEW4TFEGOHYRNH6E4LYK5ND2LM76IJH5MZIRKIZEDCL",
-    "GDKEXTASBVWRNNZNK7GLPZ4AYQO7HTFGBHKCJNGVS57U4DQKHWRKV4WO",
-    "GDKFDABOG3UGFABNRIVNO2KJIJ64APV6JU6FDLO5NWPFWBJIDXZY42BV",
-    "GDKFQAEI627SFMACZ7GFFR5D3SVYE7VXBD4NVNZH4TLV4FJC63RXQLNA",
-    "GDKG57QQ5334X3QFQQTENUXTMXPR5Q3ZIJA76MFDMQ7F3A25XURDZGHA",
-    "GDKGGNCAMSCDLBBLAZC5DXK5QHFAUO23ALJA2XEKDO6V34YWBKZBTW2A",
-    "GDKGN7ORUNGMEG5XSGX5AR73WB7EBCHNXRAZGYNIAU4N2W7MO5PU6AST",
-    "GDKGR3D6UHBLRXQJZYRYORLM6JRDQRBN7T5K4OJW4HHZSTSYIDJ7CBQ2",
-    "GDKGZ6Q47OLFC7NLSIPWSMFI7LSOEXFGVR42KHWR7IMTO7JADPA4IWN7",
-    "GDKHW7TFASAIMD5QHT7T7OZ6VRC3TZ3GYO6MAJGKGZ4LSO4HBATJ6LQ3",
-    "GDKI24CYV2I2FCHQ24KMVGHJOGNA4W4EOKQG5K2A72WUCCW4QM6QFCHN",
-    "GDKIAYHR3VPFZJBGYRQECTNIO5MZI4WDI2AI7EHDKEMBSGODSLB2CKE6",
-    "GDKIJXVPVJQFUOWN36QSZNZZ4HJYK5GTZQNCZ3AZL2TWCXSZH2DJ5FLX",
-    "GDKJ4YQ75DKPIC6FMR667GKAFXRKKTMAJ63UH4S7OMGVE4CXL26KVRET",
-    "GDKJIDNRDBGGMBT7I6OORG7UC6VJA5ESEPPTDME45K2UDCDBBWRIMRZ6",
-    "GDKJJFMRBRSVRFZBVMSXPBX6665M3TFZQHODFDYSOMPCHDUKDDAJAYKA",
-    "GDKJMAUB77KZCA3VDOCCATDP24ODCKFRCIORDPQKIQU2AGWMZKZVKKHR",
-    "GDKJOD6RKM7QOYMXONWCCMTJBSLZUIIMGPJB7NIV4ZVZBQW36LH73QWA",
-    "GDKK37TUF4HRZ23IWIKPCFACREPKZULQXO6Z4QZUOFP7HBDC6ASDGALA",
-    "GDKKJSHEGMRYYV75JGVO43TURQAKFUXVB3GSM624BKA56TDHDHYPPLHB",
-    "GDKKN2HBB4F4FVBZAKPN34TE4EEPH6OQADGLQXO5AILOVXSJBOSA7QDE",
-    "GDKKOEN5ZW7LJSW5C5AGLMHKAIJNFUKCXB6VIXPTI3YEUFUXSUPF56P6",
-    "GDKKPUMCDHZP7LTHVEDMHB5OQ6ZWI4RM4VDFOSIC36SYIHN3C2WQVI2D",
-    "GDKLMHLYT5DAOTO2Z7Q7CGNMYIG4S5GXTYPO7R2M757HQOGP7LOD4VIH",
-    "GDKLTPRTGZHQRQHAZPN5TIR3G6SJ4SNUAJZKUINNU6HRFJLDMHJT7BDG",
-    "GDKM6YRDX2HSVXYLPATNWI7BJTPETFJG4UVGLGY5LSBCACSPV4AYNSZS",
-    "GDKMFRGGFFX5KPS24IJ3H7D3OY2GTUBPFDWY2UR5G64USBBS2PXYGMIU",
-    "GDKNFBFFNGMSQQNY34DH7L54VQZ7XCYCX5E3MEZUZPN7EPN2XWCODASH",
-    "GDKOAJOKA77E77LYR66EMJDBBX77YASBEADCWJQC63WPAIFOGVHVIOTP",
-    "GDKOFV42AQNLUWFEYGKQORGQAOHRX4OHMLOZAYHZHNXEMBGQYJD53LTC",
-    "GDKOZVNBGI7PTISNRPP6RKIBQBZIDF7AQ3LHE2ZG5EEWNQSLDGAQSMQQ",
-    "GDKP3WWUVXEGNWYHDTQD7NHEXSA3EKX2NQ3XRV4CXF2BPQWWK4LH5BYC",
-    "GDKPCEZVFFN7QOT62RDUQ2SIAQRZHNFNU6T52EBUBCIQWGNHGMA2UISO",
-    "GDKPK6AK4OUB3BJMNOBC7FH5KRC2X6LXMNL4PKPK5P5LLXCCURXUAVTS",
-    "GDKPOMMCZ4UJNNXNLTXZMXZAGO532TWUYPTQ3WMDANWOPU6ILSET5T3M",
-    "GDKPW2JLUN5YBWINXEVI2FIS4GHBIHTFN54AAZPSFAQJEBHQDNVCDOGE",
-    "GDKQHHKUULCQ3XJGXOZWCSRMGNBHYTX537BHC5TCCW47WJBUN5REUVLJ",
-    "GDKQIZQ4KWBPJZVAJ64H43BEDGPYPW6IGO4UTOUN5YYHPQESQZHAC2FN",
-    "GDKQMONNJXBTJDMJWQCW5E3JTGYK32MUTJOV6AZFQOONFQKFT7XORMRC",
-    "GDKQSZCMXSNJGQIKMILVUNRGN73UL5MGWQH3B5ZDZTWB37K2D6QR2MTL",
-    "GDKRCP6QOXIJZANLWHLCW2HZV6IEDSBOZDFEJEW67AD4KXSKNB2Z3S6F",
-    "GDKRP2WJCPSSICM26IHBYASCIOWQSJYCDGBA7YONSEUQKF6YDLFJQI2H",
-    "GDKRRJSLB23GG4YPU5SMXBFGMFP6CCHENA2EVPYMZB5DKE4XQBF4D56J",
-    "GDKRUIGTRM55RDHOOSBEDMIUE5B5DHKVJY3UIYKRDXWJWFXJBLA2WICE",
-    "GDKRZXHMPRKMQNPXK4ENTX7NF2PRWPRURJUCXVG4G2GX5UQJMI7XXBVI",
-    "GDKSFIOJT6ZLYKW6TKCVLSYRLDLOJVJIJMXARDMXTWTHEQRE4BECZPIF",
-    "GDKSW4EATHYAFKJKA5N7B5ZN5OO6ZZN3CEVULDPSLXEMTPSLWZGCOETZ",
-    "GDKT6O3UDDBM2DKZMLTLULHHF7BTV7RHLQDTLNM3Y6XC6A3NZFAA4NR7",
-    "GDKTZIR2G4TDAPOVYMWCEHD7BQAB3VEVRKWSEEUGLS2LBPAPND2XY5H7",
-    "GDKTZJ3CTKA3NJ4MTJCIA7C33AXCEWHPQZI7FIW4YNOPEBLWCWWCWXRC",
-    "GDKU6SKQ2OQ5XXU2FJRNCG4HF2FMCPDHTPAM56OVSHJVWNI27TWKCZBE",
-    "GDKUUSKAGHAHF52NNQCZWXRLXBUSEQYAZZLKNLHYXGEKH5DOM5SKD47A",
-    "GDKVRHPFCS6UPRNG6PJZVVJEUIESJ5YQAPYFORT235ASKR7EHR3ZROCK",
-    "GDKVTHWYAPUDZPXSJYRZ4KCBBSIWGV6U63KZGLLWLV5GJPWSJS5IBLIJ",
-    "GDKVU3FUTO76OTJ5YQSACGVOEMCCU6HDLEKMYZWRHBZGW777RJ3SZIRL",
-    "GDKVX2DIVVNDMTSCKSM4GAUTDE3736BFAZDSCVVEPP2QXF6IHBW6PC2X",
-    "GDKW3C4DW4BBPH6CBT36XRV4WGHINI2IRUTQD5KN7PWPCYW2M7QBNVDB",
-    "GDKW7526R3JF3QATKT3DILKLFQIZNAMSKLBQMVTNSX3S4JCVGIRWSVEF",
-    "GDKWAIDVK4J5GIHEMOBIOXNLSC322QN5UPXJKO5KZ6BURK2HXNE5EJ7Q",
-    "GDKWE7OTXKG7HEHJGAFG6P6DMVHFKFXNZ4MTLIHTJA7EDMIIUPVDWOCL",
-    "GDKX4B3CS6S226BTQK6CZREL2SDL2HCDINJBPGPZ3TGODEYWVX2ROOPO",
-    "GDKX7AKMMDFVE2DRME2ANNZ3VCL7CVFL7IVVBFYW4ATX3KK7TKEDAL4I",
-    "GDKXQYTUOLMUUAKMR6XXU4TXA2VMFBA3DPF63O5C6SW477OY7VMQKVSS",
-    "GDKY77CU4IQAWMRLPUOIWZ5U6EW5CQJUY3UMECYSQFZNUSLBBT5ILS5O",
-    "GDKYFAIPFGLENDC5KADC2SITPCAUAAETQE7LFB55HEA37FFPMG7KAWNI",
-    "GDKYFOEJRAYDQ4VURKYLDSFOHOFBKC7IC5GKUULEN4PVG34KGLOGNUT3",
-    "GDKYKCTJVDFWRTIBJMSOKALKUQ5GRFJHH4BXTT2FZQK74VBX67NUKFR4",
-    "GDKYMLIKSCA7LZIP76SLYPLCWRXWYGED7DZS3THEYOJTG2MAJXSLZRBB",
-    "GDKZN3JAZOUJPLIWBVUCXU5IJTI6G4PBNEDTV4JYHFPN4VMT2XO6L7XN",
-    "GDKZPZXRDOYJTSQEYJNUO4AMXLZOJJPCTQXUA2JWV6NONFEYOHRI52BV",
-    "GDL236XP2CBZER5LKDLCIO7CLSEAWPG2TS6UJMLE4YJJ537H7IWP2JFK",
-    "GDL25B532WSNIEUALYLBYY2MSBVO5PQ6CH3TIXEMSMG6FO366KQN5X7H",
-    "GDL2BFLJ7EWDFOMJG2RMB6P4KVN7BFXBCQXKMPL33WETCSLQYWUMGRAM",
-    "GDL2BK2TICGAMOZ3IKMCVXPYMJH33ALCN2KR6OWYF3CZGSBTT77SS4UH",
-    "GDL2BQQKM36T3L4JY5X7JKXMRKJRMGQ467MYZRE3AWNHM3RLHOG3RSKS",
-    "GDL2KMIX3FPZGWXEFXD6LIOQZHXD7JZPP5XH5CE5CQIWAYFUSBD6XV6B",
-    "GDL3CWA6HZ7FO52YOWJWFPWIHEWQ62JKAT3AXOFH2QLJJDASDTZNSF7G",
-    "GDL3OGULPYMFZZTT5Z2NXVYB5M6BWTTAN43MXCDT5SSG4BKTKBBVMV2P",
-    "GDL3SWU3O7CGUG7LUYWM4RK4CAV6H4FPJSDBHRTPEJJDCXYNH6N4VTKE",
-    "GDL3UX7Y6Q2XPKMX6MPRUYYUB3CSEIOAXJKXITHBIB6RDLRYRJWOD4CU",
-    "GDL4DZVE6P4Y5KISUE3LCGTG3TUEV5AEKHJRZT3VKR2VHQN2O7NCGXLM",
-    "GDL4IQWJCTP2KPCDBQCDROKELL2VDB4WKJ6W4RXF2SXSGYVVJUJTKXDZ",
-    "GDL4UHND5KOYJDWUOJXLSGMTQ5WEJ7XV2TMLXYMHID4CLWS6LVGFLOKI",
-    "GDL4ZQK7MI3XEEYEZRPCN5AUM7K2Y4HMNWTRCYYCIJ5KHRPZFAMOQAMA",
-    "GDL54WZPOZVZ2YYN753XUXUO6N7646JQTVNWOSGWXS4FWQORFL5RTRL2",
-    "GDL5METVQ6ILGH4BS5HB36SKKU56O3W5JEMOC2QFU4444X6IUMPQTBTX",
-    "GDL5NH7KNV6VDL5J7RPO44V7J6VMNNDYHHLTRLN5VKYXU4GESMAPXKAU",
-    "GDL5ZF5KNHJN5NSWZ23YKJCOKUU2DRNYGZ3RZOPCRC6KQVKNCDKV4FN7",
-    "GDL6JQYF4HALKOL6Z62THQIXDOPPDBNLUH4FOJPICA4ZIACW55BGIRID",
-    "GDL6OAWR4VAUU24IE7OEJP7DTU3RYMHXANGGW7BMXXBK7R6EUXEA4POE",
-    "GDL6PBNU3PS22Z2U4BZU2TZOSZRSJ6AK2UGUUB2R3OLMLRX6RVEMDROP",
-    "GDL6TAIKVBK6YKELVMPJOHRE3I3PLFA2EVDVLTIUV5LWVMR6JU4EZXLM",
-    "GDL745HPCKNOBF36WMDVKK3B7C25HVXY6SESKD35OTRFW4WOVLQZ67BQ",
-    "GDL75FCFEPPMSSOJEJXHZDYQ7A6BSF55VFXI3RHDDWB4RFLC5W6D7WNQ",
-    "GDL7CGFJ6YQIWOHQEMVS665C33QAT77B5SJLI3DYV423Z45UGFCMBUSA",
-    "GDL7CVDJAWPR6SJKSU44EIQWRLG6VPVX3OBJM5I7Q5MCOKJF4QHEO2ZX",
-    "GDL7DKY7GWJPD3KDGE5FNYWG2WUJBJHE53V2TDIW2R56W3JOH7X6P4K7",
-    "GDL7SG5VKRCGLH6GOYYLDMPYLTLHCCGNXAHAOTDX4DLLYS5FSTEA5VDS",
-    "GDLA473HYWKNHONXH2R2VDU3Q3MFCM2FMTZPDPOWTVGY2ME5DFKKWBCH",
-    "GDLAO6MVWMK63XSK5O2MSFI6FAKZ332U7A2DFFYBVSNI7QVUXHP4A7PW",
-    "GDLAQUWB5BXFCBCHZJEFDPPK6Q4JELMSFR5EMCBOFJ3P7GGR3RN75ETX",
-    "GDLAYOFYDGQ27GV33DGVMCWGQPQETC4VLZ7MX3ANQUK5XNBDGPMXFXPE",
-    "GDLBEQR322L2ENC2BYOCQZYIHK2ON23TTQJFB4EFAQP4QSWSKLCWIK32",
-    "GDLBFZA57YTS6MLBJN2QWA3V3H3TW3RMYTEHLAV5ZQ6MNLCBTRQGINTL",
-    "GDLBKKTWLCMJMOXBU27TH2AEZKDBLUQN7B7HPOP6VAIPOU2KMZU4SFKU",
-    "GDLBNBE63FNHRT5EH2QE6773VUMGPMNZV6IOSXBG3OE7MVZEDVILIKTT",
-    "GDLBZCCQ3ODT4UQ2URFJHKEY23PTCRGZB53PKCNSQFRQXXJHFCYDOAUQ",
-    "GDLC5MPFP2FNMA44XK24EO3NYHELFKCLPSL5FC2ANK5YXA7IFHT4LALI",
-    "GDLCAZTVXCLWO23RPGY5RHHEMXMNHVZ2KFGEVR4NIEFYMVZJ7AYBOTLB",
-    "GDLCDQBUOLBRX5AGITWY2XRTTX7OXQBTMGWYYBCD6GUJSWLFSJNVCOLD",
-    "GDLCKTRZV5KUE33FM6OBN6CDFZ3Q2BUHGFDQZNOF3NLRH35ZDYSZROCK",
-    "GDLCLRG5UZNBWRE7TUAK2ABPKZ6MVML34MO3WQJTUL5BFPNENMVS7RW5",
-    "GDLCNYELQ3OMH4FYCPUTPOAR6Z2V53PCCLPK5NNB2NXLTXGGBU3SFBOS",
-    "GDLD2HPVPT6PW42R6DIKUZRVUVQSMR47DQGOZJDYTJB5EU3I4EBNON6Y",
-    "GDLDSPWSTRRBJBXWLNVGMENC7ZYI2IXTCVXVZDCNO4TSK64ZM57J7LC2",
-    "GDLDXVENUCPO2ERPCFN6HC7QQD3UQ3GANA4DPGTEK5M4TGLRVGCSIXRP",
-    "GDLEDCWCNTQEKQHCNIZQESVRGBDYUD7UKX5AHSNZQPS6PTWRB7UQIPLB",
-    "GDLEJNH7K7PT5NXI6P6DGQMMSIDENLTC6WJATIBC65IN6SFCJ5JQ7IFV",
-    "GDLEOB3FQXOXZ6YD4PTXCIMGBF6C4WS4RGEEFRD7WXS3SOXH4YUJWG3Y",
-    "GDLFMD6UUKVRY3TUU3GBZDVK2SWPBSM3KZIUZR7EFESBKKKE7AFETRUU",
-    "GDLFPTEA6BZ4SRF467FYWTMYPZRHFXV7CNHHVD7226WREGO3HDFGHCWH",
-    "GDLFVDBJQLFTZAVCG67B3HV6E55SYY2GNWUR2QKMHR72OXFBISTG45H6",
-    "GDLFWUJAZ7O5QDVAWOGSKCFFCK4GAGVOWTT3KK3IBN35T7DNSWPN3J45",
-    "GDLG4J2IGB55HOJ5FW3UEBLOXA6WPRMT5VZRSCOS5KHWW6XAIZZEG4RX",
-    "GDLGU2AQFXFTXNO4CIGKI2STNZUNSO52Y54IX7WODY53EUEVX47OPVXZ",
-    "GDLGWIAOF3XAUAS3FYGHKNMQG5AJ3HNUBFYLICRHCX4GXPYTPSFVTKNM",
-    "GDLHFZXLVQYDZD64SJVYTJ6Z5PMPHBPWAIM5JZBGU326SAWI5LH3E3CA",
-    "GDLHKYJBYQ2OOYZPTUF3SXKYQY7SUW75A2EP7AGNJIOPVHKIJXLUJUDN",
-    "GDLHLRT4V53CYKSHTRJXBDZS7U7Z47YKBT7P7UPDLJ24CQ3BDIW2XFFL",
-    "GDLHZODDWJKRY6CEUHLXHQF4X7GQO433LKHY6THN747EJ5YFQZHDKCFN",
-    "GDLIAWTLMEPK773JDIOFFL5EWIFZ6C7NBRVHP4VERXLJDRN4KT53LUKQ",
-    "GDLII6HP36D2NMLD6UZLZ2LA3CTFMWCFKOZS6NAIAYG45PJEZJGXVBES",
-    "GDLIIWZ7T4WE4SZ4YLTFSHWLU3NXVFLWOPGASXXK4WLUD675C74M4GH3",
-    "GDLIU2ENX76JXOVVBFIHALOKCIR5KBQZ7RVBGXGDESJ2VRP2FL7NEF6D",
-    "GDLIVNGCSGHEVACF24AM5AIOWSR4UW2Z2J5FXX6F5SVA2DTC5AQ5S2YW",
-    "GDLJ5THZFQIPPE4WTQDAFZSEL455REKFCAJIAOIZMGYBYUPSDHTSGLRH",
-    "GDLJO3H6I6GRDDA5NGNVJRU2ZGK2JQV7U5BRSQVRADBNX7IFERPPHP2L",
-    "GDLJPGEL55Y3HNE467F7OAMZKVJH7QMD7FGGAQSKISO5K3RW7PMYLWXT",
-    "GDLJPTA72MXSYXW6N3NFUSQ3NCQM4V2KC6NYSZYD2NCUPZHIJFUL7XDC",
-    "GDLJVLGSPTTOOI754NFP6FRWTDKBCH3R3QRLHG75T7CC3Y6ZJMZSB67M",
-    "GDLKMNRE36AAJM755Q4CUOTUHY2VREMWTDHH5OQLBD4ENDLT2NUOFXLM",
-    "GDLL4C42B2MYI4XWDCK73R3VKPISRERP74PX7I2NNGBO57HFHJPDEKJV",
-    "GDLLVQRJ2RFSCPYDZS5GHKFUR7TJAIFO5KSWPTRH5P6V3345CUEL3GZZ",
-    "GDLM2ZIITAAPZAOHNCNGIMAFPOKTNT2F6VNVB65PKFEOFHRNDRB3LGPJ",
-    "GDLMFQURKDOZXRUMB3P2ISRUNZN3ODC5DQYSX6AKCKCS2NYD3VE3TFFM",
-    "GDLMLKAEMSMETGBU7T7336EPSREHWBDBH7GMEHBW7MNGOHVR7743WXXA",
-    "GDLNQNSXVX5LLP4DZWDPUVS2MQU3YULKQNOY6IEEZVU4A6LKEDOYC42B",
-    "GDLOVKLRDNIMDSHHETLHMCPSKJED7YMBWDNF7QRWTQ3JTEVG36QT4BOL",
-    "GDLOXOODKEIHBCAG6XDQPRVQZXS6MHKVMRK7CPAHZZLL7IQ4WYRXHEG7",
-    "GDLPLA2P6P7WEL3JFPOSVJUGK4CFXIVBSJ6FZJBXQQEINMLGMDLJLWH6",
-    "GDLQ6TA7UJK5ITLXKKRGWZ53B7CZQOGEVZE6Z4N7MAS2QNQPV2ZP3OPQ",
-    "GDLQA65DJ4ZCKWR3FS75H6ESLNYGNHX7PXZOMBIX7EHTU4AH7MZVUEGD",
-    "GDLQCELEIRVRWPOV2RZEGFMEFNJE25Y7UUQYABVMWWL5J262EWRRABCH",
-    "GDLQIWU6AYQR4XEI2ZPY2I3HY2Z6MCCIZKIQB7HMBMGKWJ7V3G56LDB3",
-    "GDLRJJQ64TTQBLN7DQBTJGEYUDMISM75CYVBFIKRM6EU5YERYBCAP3NW",
-    "GDLS4VTJRHGWWCERMWQCEDRAW6I3UX26D7RRCDCAKFY6NE536GNLPLNT",
-    "GDLSWEY7GEL6WRYHJFX36FJQWBVJLLFKCFKUVQLQX75VKKYYQC43VVPA",
-    "GDLT3YVB3B5M655OUED6O3C3LS65CJVG53MVCQU5WGZTD3EAOEMCZEKM",
-    "GDLU3QOGUTS2W3XBZX74NH74XI5ZYC2Q26YGZLNWJOESDILTTVP3DXEF",
-    "GDLUDZY2M5AB3XQNADDHK43ZFXIJUECOLF2Y2AMKGISYBZHGAQ5EW7QT",
-    "GDLV7KUPDG7MVA24UBLUDHAHFME6MGO5G7AXL4RD462AICNLFZ5CGEXC",
-    "GDLV7ZKXA3P32JDWLEWXXOUF6I6WDUWID3E5W3PWWOMSUFYRP3HDAPAR",
-    "GDLVQEJ5WGACRHZ67AYKJU5ZA37B5YWLW6GHULEXCS5NYQSZLA5W2DSQ",
-    "GDLVSZZGGHSSDOOOCBJZOMDVQYUAAPMU3MBCWFVS4U4XMU2DBIR2ZK76",
-    "GDLVWVSYDGAAIIJYY2H32BEN5TJJOV5WMFUYHVOOBPV4MC6LBO6AHWSB",
-    "GDLWK7FECBLZBSO53NELNR2UCB6TNXG5VIIW52VAR4P646GIKZ3IHNLQ",
-    "GDLWNMDDIJU764E2VJXIN7X7FZR7LWI3VQ4UOAYZUPIJXLHYNA6XN3G3",
-    "GDLWQZ36ULHL6ILUSTOC5EBXLIYXYA5VMFMQ2JHONWZ7E3SNBVDKAFDH",
-    "GDLWTWDOTAAXD73WB65TVCFNMVRB7DJR5GDJRB3RAI3L7O6ZPNFQELON",
-    "GDLWVQS5AZ7AL4CC632SBPWP6ZP5XNO6ULW37OLCIY5WJPWKY4TYCX72",
-    "GDLXDLBKXONNEYS3IEYCGEKGZDKNZ5WWOYEABACYJAZISQCKKUFMMJNB",
-    "GDLXP33OM5P3BSWNQQOOP7I4DOLOMCEY77B766TWDZH67RRGT7PCJOPR",
-    "GDLXQ57DD6FVCXCDTIPJOQRAKRHA7H2OP6GV3FIWFD6IVWBBLAPOGKQS",
-    "GDLXSWMTYO5DB5C3BOJECAD5BEKH7H36ACJJGCP4OWGMUZ4OLZOZGWWW",
-    "GDLXTCOSFTP26DWJN6IOBVHGTDBD3IQPEJNLVCXHP2ZXCD5NYTLOBA3V",
-    "GDLY2XNU57EQKYXK4XLN7N7ZKHH62FXYQZ2UPUJ6YD75AZYLUUO5QLST",
-    "GDLYBSKGH5PIGR4SYZABP6SQRE7QGKYNMMN6TADQYSQ2DS7C2EDTGZPW",
-    "GDLYF56PSESJPXFSSTPQOYPJYS2KWT2XZ3W4VDLRNLZ4TVZKY3ZZ5TGF",
-    "GDLYGAVSUIQFHNIZ7PC2EKHCXV2NCOARRWIJCWMC5RK2DN2FRMOTZILX",
-    "GDLYNUZDZ2FBERDWD3HOCF4XC7UVYCQLCTHE2N6EPRNDMXCC74AQ556B",
-    "GDLZVK4DMNJMINQBALP7Q4B6GWR4WOXDWOX2QBLJUZHMH2QXAQR5AGBB",
-    "GDLZWFYTDXXF2LLUMAN2Z37BKX62GMYAMI5CJX34EB4Y6EZU22BEB2ZS",
-    "GDLZWM5Q62H5GWRFIJEKFUMTZ4HYHY6WUAJU4Q442Z4H47KGHPUZFUAL",
-    "GDLZYJ2NGXE75FUSQ3ZKZQ76FQ4DBOPLNXE4KPQJQJJBWDVXTVMN4DSU",
-    "GDM2NWM76OKJ5VZRJK4CYNTVYEZ6M3BXWNEAGM7MBOFJAU2EZZSPHRD3",
-    "GDM2RWPYVZGCKML24TVIBTPFZO4W6YDWTZTCY2PHC6MH4KACZK7W3ZPP",
-    "GDM2TXG2IMRKH46GMBIOGLBUDP6CRS4457VCONVXTYGY4ZGZ33GLOYMU",
-    "GDM3DKMAWIFL262LKSGW4X34ADPT6HHTUBYTZWVHXJJ4NGFIEBUNXLHH",
-    "GDM3MMMFAV2ZSAWWJYGOKFERTM6ZLJVU6AHJZGJXK45P7EQTSZN5E2AY",
-    "GDM3OFGWYD5JINREZ4ZLAGNI366SVSEHO2ZADAFXU4F5BZVOTQH2ALGO",
-    "GDM4EQVTCNBUSULERFJXU76ETTM66Z4MS33II7YOFLVJM5TVCA2EXTA3",
-    "GDM4JMKD3WMTUEWZCBJKM5AJLR7KVTCGLNTLFTDWN7OL7ICPX7AKXXLM",
-    "GDM4SYDML3NKHT6RS6WMICWJJQCPGS4TZNCMUVDPNKGLCKGROCT2IFRO",
-    "GDM4XQHBBQ5N7DZMMRSZPYOFSYQZNLULEK5UN3GDJTAFXQ5DZR3J6UQ3",
-    "GDM5ANCDTBKFFMXVLY2EFWBUM64JSLDDPROK2CO5XSKFKKRJ4TKJS3DU",
-    "GDM5I2W625YE33WFEZBCJLS57RNPPAOBQP7K555J65F376ZOXSY5D2OD",
-    "GDM5NFRYHGVLVKHMTHBEPYCT6AJN4VC5F4Y5FYQ6XHGTUNS5TKJCFYLF",
-    "GDM5NRUU6MOLBNECHJQK3PCOC5ATKKFEJIVF4VGMTMEWLHGZ6QKEG77G",
-    "GDM5QBEBC6SMW52PIUQZNCOSOAOMDYTJY5F272HNZIKWSFKCOV3ZVVSM",
-    "GDM5YO3CL7I36GY7TWS545YT2FXY3P4I3TR4GETUJTULMIU6HC3VOMZQ",
-    "GDM6CWJ3VBPJQPKX3AXKZSL5NIF6USPJ62PK4CBDTIW57SW4I3UDVEGA",
-    "GDM6H6DO2OTY5QV5HSX7ZWC4QQ7NHJP4ZNXJOCWIAJMPB3HQTVNZ42I4",
-    "GDM7IFXPJUQKFGUQCBNQLZS6X3CQTOCHOYD6CJREJG7QRAJFO3ALZH5Z",
-    "GDM7NBQ3FBWQCUTWVJRTYG73KUSYZ6L5IDBVVFOCO23V64QBUPCYPLAT",
-    "GDM7OLYDHO5FHWHXDKVK3O3DSOV7RS6VTV2ZF6UYOXP3LL3RDEHPCC6Q",
-    "GDM7VMKGW6PHLPKUKOMMXVM6L7GIJVAVZ4PB5ERIXN46DNP33RE7VUED",
-    "GDMADA2NBPHBY2T3ZED7I3A4PRCPBLQLONG4T2WERHSUFMRSTP256N2C",
-    "GDMAF7AGPCR5HLMNP2WKJNOZW5EHQ7MN7M6NSD2N4RISB7VWEGYKOISN",
-    "GDMAR7ALOZNMYTXULVFUWLJOKKZPCKTRNQJTBJG7KLQIX3OSBQ223YCM",
-    "GDMBCGZDNQX7C2X2OV5AKQQ2BNZXQXIENZVVLMJLIOPOYXVRTQAQANON",
-    "GDMC3ULVWEB3IM6COSGERA3MT6DXJJQBCDPRRPBJRM356SONRWRBPQIC",
-    "GDMCGD3UUGB7Y3VKHUZRBAP6PJ3PNWJSEZOY3BSCVSTL22GLS4BRPOTX",
-    "GDMCJLLII3AKHZOF3JWTHURS7LCZES5MPYMXIE3W6FA7O6WVAHTEWNCL",
-    "GDMCYBYMO3XKBCAE7UD4QW3356OUM3QRUL7GDXKERBXFUQFRWIXKUIH4",
-    "GDMDTUVJMIWZTLBG6Z4NLQYMKDWFILNH4YZRIAKOBDL74HBM7HL7RHWK",
-    "GDMDZKP3JGMZ53RUK7NXIMMTAR5DBCHPP6ERHJBV4J4AX5KOC745ZQM4",
-    "GDMEJSO6CHVMJIXUMTERPCTVNVXHGT6MLPP47TI6AOFDWKUUD6AAA63F",
-    "GDMESJZEZNYQIYBCHERD4OZ6UDOCYXV3V7QSNYVAAKRP4HHBX3AHIIY7",
-    "GDMF4X6FG2U7NJYNUUVBHFTVXZKUWPMRHRH6QIN4T4L23TSJ3BZTDBDH",
-    "GDMF6AY77W5PKJQDCENMA7FMARH3GT4JIERNWF5J45L4CFF7SGCE2DD7",
-    "GDMFJQDBI7R4GVYB3BGHM7LJYBWGZVPVZ3OUZKIL6UT2NF23LQABUKRA",
-    "GDMFRBSWZOLUIAHDNRYYHXQKMNSJGUVERJMSS76Y4NF5B3EYEE7RVWOA",
-    "GDMFXV6KT2WPDSLAJU6D4XS6NE67U3OYFWM5OZZAYYX446JIER7QIRKI",
-    "GDMG3ECR5DT4PHVC4ZRII5AAELQR3W3KWF4BHMUTLGOWGTSNEJYR4XPL",
-    "GDMHBGEXYLQV6Y47BZVC6H5YLBL2Y7DSCE4ZMK4RZACG3DFQHZRQFCHN",
-    "GDMHJ2GHCY6DBEBDETXWBCZE7JY3YDJGQLMKPMLZD57OQO4OQMBB5NDQ",
-    "GDMHTHDMPLRZIAUVCF6RLISY4ZQNWBQOKB4GK4EAIJ6R6G2P7SGG2M5P",
-    "GDMJE6IB2EWJROPKGVOXQDNG3OHK5IXCPRF4HT5QDZ27HWJN47UCYJXU",
-    "GDMJIFTRBY4ZYOSVFXPVDU7EVYIIWT4XW5DYGKZL53XKF5YHVUQFTK7F",
-    "GDMJXEMEVHZABXUQVRLIUQUJUZK3Z4O2B3CETLHFKZLDEJFP2XLHZWDA",
-    "GDMK6WEYN2HHGCRXAUJT5NVS7MHVWMKQ7K6YDHS4XMHI7DYTIMC25NCL",
-    "GDMK7ATYOMNMWRGTK5SUXU2GII7OGNFMBIVQPGAJLK3M5ZRON5L2ELON",
-    "GDMLLS3XMJUQ6NA3WFSO2X43KGUTDQQ36FSS5JMLLQAON6DMLFE3LYIU",
-    "GDMLO2WENDQHGDQRSLMHDNL7CFOTO4BJEY42YDTBO4MLYMH3OMVMA4IZ",
-    "GDMLQETYRESCCJT5HNI52Y6DY5C5OF7FLPIST426JUZEQJIQJJB7ALT2",
-    "GDMLQY7Q3S2GDI54SXUPEXIKBZWVHGAGIF3DZX4V74MCGUNM34BYU264",
-    "GDMMFXT46D356PNJWU2EBKGLSAK6MJD4RWOQZYJ65727RB5DPMUBOCBL",
-    "GDMMPCURT3PCHVL3QIAKXVLY7PPQBPM452OE6E5CCR6AP2SET2IZRBKE",
-    "GDMMQFMHCWDE6P6K535VZNSFUKIDC4DBUXPWGWTYDZEY6A2VC6CBRWKH",
-    "GDMMT7UQ5IGLIIHQNI5NCU2TSR3MREKEIJBA7CBZC4U5S2AQVBV7SLKP",
-    "GDMMUVP5A2MC4B4TDJVFGTZ6GQRPHUE67RZCPJ5VEVNADO62RRPMGMKD",
-    "GDMNQHIKY672HONKWMFWTFXLRUBJSUXUII4SPNYGM3GDMMTXV225NL72",
-    "GDMOIVXFSPQQGEF5M5XQHKGEIDMHSLMWQJ4T4DI7X3P457VMUUZKCXHV",
-    "GDMPFUCMQY3BEGMHBAF5MRSPX3AEDFZTNEET56X2CBCE5RD6T2OROTCA",
-    "GDMQHO2PQHZIY7A5DG6K6MZUXI4TTBQNU7OSMMVRM2CZRS4RK7MYZFNE",
-    "GDMQR22ABZ5MWNPD4GAOOPANUB3VSFHYQD6IP5EYIFRV4WS6BSF66CHX",
-    "GDMRETXZ5QQFU23Z4YJ4DO7G5CSDD2A2CCCNZFY4YIMCRHLBTHOERXES",
-    "GDMROFMSU7AURXOWB7NO7TH6BYDQC7JALNHDZHDZLE7K3Q4PYAUKMMNN",
-    "GDMRVEXN6TO6NBKROF2LZB4JP2ZNE53VGOHNIA5TWVFZ442FL2TYJIOS",
-    "GDMRYDUD2HUBLJ7GWZZNAHMXI4EMFZWO3DFBNLBO5A55KPOFPRXOO6KR",
-    "GDMRZJUY4HTTPPGEV3DPJ6WQMEHFHVVUWUAUUHJC4WK2JSNN6PKEXQQL",
-    "GDMS6EECOH6MBMCP3FYRYEVRBIV3TQGLOFQIPVAITBRJUMTI6V7A2X6Z",
-    "GDMSM24O376LC575FMEMBQKQ7I6VHYHLWSIQQU4PCG6XSXUNSAUQ4QPE",
-    "GDMSO4B4VOARSWFPEN4N2O2NFCR3HVYWLOJ3EVWAHM45HQLZNIX3BOSS",
-    "GDMSPJPKP32H5J7VTTAXEATSKEN5ZZ3ZV2GUT3RQ5KIKDLBCKDTY64H5",
-    "GDMSPKNY5V5ZHIMKHL4XKPUB6MHLZZJ6AAXMCVLWL3GSI2DWZLFQALG2",
-    "GDMSZAONUUCIOH5F67MAD6AEFHFW74I5LUPDHVLCLICDOULYOTVFQ4UL",
-    "GDMT5JY2XGU3T7BNX4S7D4R64SGNJGMBU4YHUUI6LTWQ4G5IFLLFJWF5",
-    "GDMT666RPDPAEX7IFDHOJ2CXHJ4T5UIHPTC27CZLZGV3JDZ6HULEPAA3",
-    "GDMT7QCSCNCP3UCUDSTVVMXXVTJZV4VCGPZQRDPJMV3TGKY3W3OQ6JOG",
-    "GDMTKZJC7DHAOSKYCFV2IMQSY5GJJ35YT6ZPNFDTWV2W5KZOXMTXGOLD",
-    "GDMTWBZTQ624QN3FDEON4EWA2GSHIUHGQ6GCS6JLUIDQTOGLLTEBN2JS",
-    "GDMU4RH7RAPBARCSC72DGQZKHWL6B2CNGPLHIPUIPVZO2EP4ZHURJTDF",
-    "GDMUD2G5Q7BOO7Z4UGCDN5VLOHFPMRDNS42YILT2AYTBBUXHEIOZDXXA",
-    "GDMUQJLAUYPVOVASFRA5MW22MO4CBWPKFZIA3W2GSQCJZ3UAFVUQCYG2",
-    "GDMURRVI7AHQSJY2YYGDXUC3MW4436D7KDOF7ERB5H56YGZ75F4QTELL",
-    "GDMVXMFVPQCXNE7FDS7RSUHOMVPW4OITAYS44KA7YDXUD4TUDCSTZELD",
-    "GDMW3IVOMHSRCB4LOWRYP4D2WX7LMEIIMBASXGWY2AMKPOMHLLO6BROC",
-    "GDMX22JLTVKVAFBQ3SOAHPHWUOFSNUTUB4AYZT7PKQZXL4AH4A3YTYLM",
-    "GDMX6CIB2UCMEIBLIDKDJQUVN3SDYLVD354V6WO5WRHWLGTHZUJ4SIXK",
-    "GDMY2VS2IYYHAWQ2HMH2TXX2FFYYTAGS2SAMCXWEI3BNWSJEVPBSQALL",
-    "GDMYCRGYX3LKYW34MXL4Z7AJTKFCQTYOBZA3HVLKIFJQ34LGD6HUVIGH",
-    "GDMYFPFEL5ORWP3FLCY62DCX5LOETDXZIGPLOJEQNJSOSAJQE5RIGYIP",
-    "GDMYHC7PMQD57M6N4ROBDE6QIO6I2PR463YMZKSB446SBSXCPDUOHFSU",
-    "GDMZBTLCHPG6COO5JY552NN3244LZJGMODMIKTM3NC5SOTRUKCUMLTPV",
-    "GDMZIOAQCV54AHBP7X4VPKQ5SCREPHUQIXUPNHCBY26XUNTWXSP3QH3Y",
-    "GDMZWBVIEJ4M4NXY6DVDXUFZ7DTYVDLXDXCVH577E5FUDSDO6GYHUG2S",
-    "GDMZZRYAJC3QWUVGT4NCYR32JZPQ6W5Y5WOATSQFMXSNUDQGBIXYYATE",
-    "GDN25ABB5FHOMFLCDF2I62CZA43RAOXSILL3OUVISYTWYD2GYNQH7LS7",
-    "GDN2GNK2QMPR2CYSCFZLJDW2NJDJ4W5HJN2KHIF6MPKUE62QOPH4BTHT",
-    "GDN32IF2WFQ735PPFIP5EBFRMYFSNV4HOUUDQG5GVOHIDGEKK5PR4G6M",
-    "GDN364OTRZQPJI3SVKJ6SDLFSCGSAIJMUDR5EBIUMKSFAAP43LLIBIA6",
-    "GDN3IUP6PLOXQN2WWQ3PDHYF7VR463ZYZ4QBTJ3E2NAV4QQ2ASUYETA3",
-    "GDN3N76QRKELRO7GYBLUX4LAFTAADCJ47GZNYFYSRGTCRYXHUSB5EBNB",
-    "GDN4N3TC2YS5MO3C225TWWGUT4FIAZI4F3T7ASTVJCCBOTVWPJDTVTU2",
-    "GDN5DCZZIUHMYM5EJTIQMYKRKVNT6Z2JOTCRW2FHWZQESRIOWP2QKBQ3",
-    "GDN5FSDJMC3XSAJWSWFHPLEUZTCZXT6ZSKRM3CZ5ONX5PKIGXHHR4QTN",
-    "GDN5QG43EI3KZMPREH6RNVSL76CINBJKVRJF4VAJM7MQIAD2XL2HEDIF",
-    "GDN67OLYSB55EJSVEOPGHQULW4DNC3KLUPVJEWDKYSAMPBEKKKOJ2VWX",
-    "GDN6RP32LVP32RAY7ZLFZ3VAEWK3ECOTR46LS4EO4DIJRK32RVESD45L",
-    "GDN6S5U5XGRKSJHUSRYZL2654ZRWCFQQ7CQBC6L6TRQX4VGFQ4BDH222",
-    "GDN77CRTJMSTEJRVNQNBA2VYTAHQY2BM2IUUX2NXLTGDBCPC75LRJSIB",
-    "GDN7G4MLGINVXIY4U2JBWAYLXMOS6RV5EODLHN273YXONBAMKCDBQNYE",
-    "GDN7R7CBSPVBSD22HQCHVDC6H5ZZHSKFHNWUB4RUOFXLXQUT67IUJTNR",
-    "GDNAK2LZCJWOAUR53GD7REXYCXPCN4E6CVBMGTHOZVJOOAQKN3IE72GS",
-    "GDNAOLI7E5OMWOM53VBMDJZKQLBLFTAZTI4DEX4JP3PCLXX3XQEEJUQL",
-    "GDNAR2Z7CMGGEFSCS6S4WET6LEKEQHFNTW5NLIRAXA4I5QDZBOMQXBTH",
-    "GDNB24BPQS3PEWTDKPRRC3MP7THSMJCEFLVDASAOLFOOWWRKWWK5CVBX",
-    "GDNBKSYI7YMTLSWOCS4K2IPJMEFUVV63B3TQVEPBDPC7VTRSK7VMD74Z",
-    "GDNBWINKUG4YPCSNXZEWCY7D7PSMSNFJSRBK4EYN37EFCDDPO42AL5LT",
-    "GDNCI6JIJ5QS2WCRBALIGBKVOYSNXEQTHWDKNKRE2JZLGLYBJ3Z3PHB6",
-    "GDNCIRZGTYTPGDSTXR2H5U6XZZISD53ECE7MJFTKJACOXW4GOGFSH47W",
-    "GDNCWLQZ34N6L5OD2JFWUZFNSTUHI7I7VUGZ2Q2XIB6ECD7PFBRFNOQ3",
-    "GDND45I7SM2WS4HXY4OBIUS32TB3XLNC76VRS6QFJOJVFWS6MXP3WQFY",
-    "GDND6DVXPSZEJ5AFX4IPUDRFQATKIWIB4Y3UCQIY4HXGNSDI6OGPXIOL",
-    "GDNDLT6SSAEPODAWRSPDADFNDOG3ZFHY7RDF7D4NPVVW46FYAGPBPGAE",
-    "GDNDOKX6OE2BGUG6GF4A5HM5UAQZCROH5TSQCHGWATN6UWFL2VGC4YMY",
-    "GDNDQZXNELG3QRXGNEB4RXW37JINBIEAZ5FDEV442H4YHTYHZ6H24G3V",
-    "GDNDT3YJ7WUTBE3LNWHQFIU6Y5OLENXBUZDQNWGBX4D4Z7HHCUXPXBY4",
-    "GDNDV5GC7EYPGT67NONGL6B6TRG4FEJRJKCTYKYXHU57NCC437GPSEMT",
-    "GDNDYAJLEHASNYQB72O2YLN6C6MCUMBTXPOPGH2YB2WQ6BG4RA6PPSNB",
-    "GDNEEZ5TZ7RCZ7DEWZLFIIZFZMSHRY73ZZKUFWO25HIAB6XTC2VVV6UF",
-    "GDNF2KZ5H4GQB4WXJM3SFQ6JPIIOAXVXL2AXZA5T2P6YG24JP5545WQ3",
-    "GDNGXD2ZL2MOASYUOR3IRANIUXVK663R3MGVUVWXEEEPQU5NBOFI4D5B",
-    "GDNHCQLAQROLUGKG37MHGNR3WYYKBW2LS4UBFHVELXTVYMLRBHCPTAST",
-    "GDNHHVB27SZEOOUZN3YA42JBDDU62WQQMPZDIOGLTRRAA55JRQCAIIVP",
-    "GDNHYX5UWJPWKNS4NDO4JJ5CSNNCK4BWITZIPT5DN4UVAUQRVXJQMVDS",
-    "GDNI5YDPY5GTOI5HVQDSMOHG5LTZ2LKIENDHCNQQGGLFGYAVNAGB4KQQ",
-    "GDNIBENBAFVURCH3QRWOAC3O27CBLWIIYJWEMUUVMHHUKOKEBRJSM7OG",
-    "GDNIK535G3MICKRWZTXH2ULJOZYYEEGXNXPNUG4VH2B36MFP6ZH7YARY",
-    "GDNIOXPVGTWVW6NQ6MZZCHJ4TOGX4A7R2HDTVE2WM7RZVPM7TJZQI33G",
-    "GDNJAW6HRKIGAJTV4FD5Z4IBV6LJBLMBINXVE4YBDSUHTYPFFDJ26VL2",
-    "GDNJBMOED4QT4CGA4374ARPWEBSOEUWZWD6BEKBLJ3QVD5JOWEDSCXRP",
-    "GDNJE3TRNOYYXGIDFOQ7VTT3653DSNEW54FD64JVJEBCPWDDLGJKZ5B7",
-    "GDNJI7J5QAWIJ4KV5MBRNPLHLSROVHKAQNFODI2JUCK42IIEHUQGTDFJ",
-    "GDNKBKD5KWAAQ3DTPIRRY53ANXODGVDHMOUDEUKUWTXI5QICP3XOGU6T",
-    "GDNKFX7Z55JSKP3VPLF2AZ2VBXCLKLBGGJ3ZDHZ56CAAAXLBN2MR5O2P",
-    "GDNKMK5ECMVVMLP6XF7AD5GYOFN2Q5AFNBCKCETB56T5KWW2SMB24SHA",
-    "GDNL7PYLYIDT7YCZ7RXREHCZYMNV6LWSHFF6EV66J3XD3QO7VBZ5SAND",
-    "GDNLFU7BYP756OC6GNHBAEI7B42JAZ7OXBCMITLQ6YXWDZUSXTCNDT5B",
-    "GDNLM7QXEJ3DSDFCOKSPLB65XLZBD27VAOJPJJ7NLQDNC3OUXAG5RNDU",
-    "GDNM4E5ZA4J4U46XTUZ3X5ZNEBIEQOU6VE5FTDCTKE4YVN437OYEUC7U",
-    "GDNM53RGIHINJQ4IL5MOPTSPXOG2UY5IV6OQAK7TIJ2LGT27NVAJC3SF",
-    "GDNMUXOEINOHT7VZQLU2UTY2M7R5GC353AZF7SYXH2U373ROAEIGN35C",
-    "GDNN3TQVJJP7IKF6FEIIMF5CO2PNZTC3LLFWPXHDVR63ETYNKRFUN4SW",
-    "GDNN5CQ7M7YWQX7V76Y3OALWIPIARNBVKQ6FDKN7WTLLVTDBZ7TEEPXR",
-    "GDNNAMDOH47RBN42GGYSWITCTQQLRGA4RCGULMBPFIX6QZOQDKYIBMYC",
-    "GDNNVHZKGKZITL2VA5C2AELDKN457SSTNS65SXPYQQOAUAYWUDRLA526",
-    "GDNNWYRMUF6F6LCL7ZPYLPDM73IKLMUKH4M4WLEKTVLXLXMIKLW6XKWD",
-    "GDNOO775XXV2BOPFHPID7ACCEILK6FNVJP3GDC3S3Q24G7QX53PJBVSV",
-    "GDNP2N4MI3ZPSTA6KZOZDR4L6QABUNNFX7JEKTTXZE5EYXEKIWYZH566",
-    "GDNPINFWO2TUZKKH3CYI4UKDXSHU527UJXLC5X6MREFU25CR44P2EOSL",
-    "GDNPTKWZKGQG4YQ5EUB4MYQAHG6OAFQHKB4E6YM64OUXKRXMFGLQ22PD",
-    "GDNQL3DRFP5JCFTUPIOWQ4DGPK3VXNFZ75MZSRTC6GA4RXYIX3ABYPWV",
-    "GDNQSWNGA2DQ6QKYENJGUWHAAR5HZ6A3R4K744MMWJGJUFACORFLRJGH",
-    "GDNR3K44E7EPALZPN6S7TUM66ZEHIUBUF2TL6PE33UKQWWBUUR7FUNQN",
-    "GDNR6SLCJT2YYFM2N57F4RABJXWSDDXMCUSAL72XD6XI2I2G3WPXU2SB",
-    "GDNRFAGA4IUQBVZ4KHHMRFLQVTIJYD4M6CVTCADR7LLU5APORZSSLBLU",
-    "GDNRVVGEZNLSHUEVLI2IEBYHI2MKVY3LXFRXRLRGJBZUZTLZQPWC7NO5",
-    "GDNSOLACTPOEDHOOZVBTIXPHHIKFXISEKOL5BV7BK2ML7TEIVWALPHNZ",
-    "GDNSPAXRTC7PTXJCPTJDUCZXAPSXQWS2W3QYLGMZWLRIL573F7USTQVZ",
-    "GDNSRYLVMRDMUVT2ZQVUH4ZWY5P4UYIDHVBTWO7TX2VTGTEHEU64T7QD",
-    "GDNTDYXOBL7KURTEOA27FTEZUWJPDLSM3A3J7AU2VSKAESSKMK6PTEUA",
-    "GDNTPXULMOVZFINP562NLMD5UIQCMLFWBQAIUOAYZEPJSYWDYW6CNYWL",
-    "GDNTZ6V47QQATXKYRM2WIU45IOXBREIMMWQ6LM4WWT7AGCNKSFF4ZBDC",
-    "GDNUNPI7K3747BSXBG3BWWOTDNHNPSS5C3VE3U3UVM7BLD3TN54NM3VT",
-    "GDNUQFXPVDMDBPNE7ZTVGYIA6VM7LEOTGHAWLIQMFY7Z6DNPZIXRNWTF",
-    "GDNUSUAPQ6RKOEXWUQFVLK2BBWHZYXFDQAZVNZKTGQWI7NYIL4IRWQPB",
-    "GDNUZ232NEIFGUCGTKHQ5CYUXR3PSX6B7QOMXX4SIZRUYDMCP6TW5UGS",
-    "GDNVESJOFX365WWQUUMMQVCBALWYNFCUVLDK6TPN2ERM62MUFWJ2SADA",
-    "GDNVI4GR57I3FZOCICAUKTFM4G37MP3BMTCGUSOOGKOR2R7CZL23VPED",
-    "GDNVIE2VO54S2NTDNM4FSKC3S4U6PTBSNRYRAAMOY6XKC4ZY4YAOX6SM",
-    "GDNVJNOAWJX65ZRVDPMKBWQMVZO2LEBT73OPKKTYJUGI4Y3GTSD5VNOO",
-    "GDNVODXO23TVBR443PG2X47CQC2AV2PH6YKE45LHX2N5PRJRVMZQ3NP3",
-    "GDNVSUHMTAAVAA5CD2CO3LAKA7APWFV23OZHTAXD5ZA77WRTMUEKOL6Y",
-    "GDNVXQXU2WQEAZKH3MVL7NC2OUJW3UM5DALCHKZEPOLUDSTSCJHYZ2WA",
-    "GDNW3CLPZKEZ2EMBU25QT44SLQ5WFT7EISDCH5N6JF6WLYBWSX7B4RCH",
-    "GDNW6APMRMMZJM7MBSAVOMNYV6DURVBFTDI457ALXIXZAFAOX4VS3IQH",
-    "GDNW6FQER3BCLHMTH2FR6MIQWD7NGXQORP3A3BBCIBGOTQKWMF3Q3AX5",
-    "GDNWBTHZJMJMMZHBA56WVKK5Y7JSEN7BOAXE72RAQ5RRALXPY4SWTX67",
-    "GDNWKYSKI3ZV4OHZDS5EBYIQG42E53DIPQXNDZMY3OOFOIBFFMHB6BYA",
-    "GDNWMCX72GMDKXKLOAFUYT5B24J3UOGRUOOX3B5TMRGX355C52M5THQQ",
-    "GDNWRVMSCMHAHE7FPQTESDDBUMQY3TMACGS24BTVCM5ZKXU3KJR3NMDB",
-    "GDNX3ZX3Q4IVWXW5EL5CE4PJBZ6FYACZIHFKB3DMU72WLMIJ4RFSPNTP",
-    "GDNX4P34K7MON7I7UVDXXA3D5P5JYDWMZCH2IKGUBVOMPDXL46UXCNXC",
-    "GDNXSZSF7HIGVRL2LG6VWXN5PWV3KHI77DQTHLLPKNPLUZFKRRDQJBXP",
-    "GDNXTQ6QP5HR7MKKR7UHQIPOJM5UYEQZPCUIVPZYBCOIEDL4ZFL4DBPE",
-    "GDNXZZIMNBPAVPGRJ4MWLPRELTCDYBTME35BNTPEKREIKA55IAV7B7LO",
-    "GDNYOE7IIH4RSZQYR5D2SHS2MSU3QSXUFYGWMIKBCJH23QQGAI7MZV5S",
-    "GDNYSSOFCPAILHEHAKRIT3VR55FNJKGW7JCECLFSF7O37APLIEFB7ZAH",
-    "GDNYZQFFHTGIYLPXMM5VRXXTY53GVTFOKOKTF4VDW77OR6VY5SDT4IRD",
-    "GDNZ4JSMDQKXX2JUW6MZ7EAH3OXHKRJBTHGM3PQANWE3COXAZ47A763T",
-    "GDNZGUBPVDU57BIBBDMPF7YWPTWFM52E3GVTFUR5DTYGMCVEIG3HD3UT",
-    "GDO25HW733FUMKRGBOQMHTYJ2DLRIRDVHY7YX3UZNZCM5X2Q5K6KWYMQ",
-    "GDO25NKCLXSCXEVUUHXTHA5TBPSDBVTSAJ2QLKDQAIM2HLVXOHFAFHIL",
-    "GDO2C3DA3BMUTNUTZTMVWW33XN7KAHGFFXUZPS5VJF2WIHY4OYIK6MNN",
-    "GDO2OUYQXUKYCGU4DXUHYWU53JSMOJNZX53C535O5ASJDFUEFGAIUQ4T",
-    "GDO2UQG4BODQY2M5UFL7XJRX2F6UI6SRWFQ4P4MMAYGZO3ADJYRZZJEZ",
-    "GDO2Y5L53NM7ZJY6YO3ZKPEM6NCXE5WEWXHAGYLEWEQPPOA7GN2ZWB5Y",
-    "GDO3AWRYEJGGSQQXBV6CKWNELRB5GLE3LP6DQB72JL5H5CY2OELQQE3M",
-    "GDO3PA6AUBZX2VPNTRLIKTS5EV4XD4GBSC5BVESVTFEKB6QYT4PTLOVU",
-    "GDO3WJKOZCJJK3HRKU2GBCL2HVMODTK63FYJKOEGYUNGLYAYIVGYIGP6",
-    "GDO3YYKY7ITIIVAFXCHS4L4MUYCHH433RRJWKPK6O5L3UVWSZBFYBGIQ",
-    "GDO4EZZY33Y6EAQAT2VBPK5MNIHZV2SR3Q7AEBMLULZTISWCTP5AQVJV",
-    "GDO4LUIAGJF7B542IFDNJ5ZFA6A7Y6FLSZI2UDFRASWJ3UT3FSSA5U56",
-    "GDO4RRYFI7LJQWLYVOURRO3THMO5DP7FLXV2VCUC7X3L4NZYOZP3JOGN",
-    "GDO4SZRNRG7E5MYTR5OEMIUPRIKOLJVGBZWJC7ELSP7BQHXJL7CAKNWM",
-    "GDO4UN5KXKL25K5VDFEXCXWEXVQJ4IEH5QCHZQ3AWHIJCU7DR6LKFEPS",
-    "GDO5Q4VTGPEZ5BGHUEA7O6VGSEUYOVEDO2GXCJ7IKD7XYNJ2GYCDBRU2",
-    "GDO67YEI62IKH73GBBRRDJN3L6NKAPUHLUTJO5P2V4CASWCSWAD36IBY",
-    "GDO6IMC6H3KHVHN6MRFUPND7HI4VCBBTGQEM3GQPJ7IAF2X675LNFQ5K",
-    "GDO6NPABEQHGDOSKKM2LJ4GBPJLB72QLMZLCPOLGAANOSLFJYCZGSOPX",
-    "GDO6XVFPUPGHZTRSKAKSRZTYRN64VBQDGCMXS4F2RPLHTTETAEJMKLCD",
-    "GDO7DU4J3ETHGYJZ4PC3YXRYWH3UY7YHLXQXCFXFKOPZ3URGS2YSTOCK",
-    "GDO7X7NPYRZMAL2SPWSVCAHMR6B5GG7QKYYK3CXJ4W6CNNW4QIO7UQXC",
-    "GDOA4VMZOEETOVDLRIC3XKXMTIPHFO4GBL3FJJDT7HWAOEWBT5FAHOOK",
-    "GDOALXRXUS7QPBPX33U6SDG4NFTTS2QKXNHA3G454LISGCOHOND23POJ",
-    "GDOAMLKGEVT6CTBIDJ336BRRZXM2BCNCXBOIPQEAFYKUOWE4L744RDDB",
-    "GDOC7PMCX6FLYJRVISEDD2I5BTYWPEUNHATRWW3IRVOU3UFQKMJXZR2H",
-    "GDOCFDLV4HGFTOYLFS3EAPT76VFUG5D7JXCN3PPJN7XCYXFB35Y6HSJE",
-    "GDOCXDEZF7BQVZWM6OK5PVJ4YD66ZYNCIIMS5O75GWKRDH646HQCLFLR",
-    "GDOD3EW7SAJXVJB43R7LDR4FC4MFXES6KJ4YGGFTWSFID3XV6WL5W3LY",
-    "GDOD3GYBGXGIM4IURZEMLBSVIUYWPGL7TH7XSDSMAUJXFFLVUGSCBWOZ",
-    "GDOD7XJRAZPXP43QTGKJ4QWCZTNZWAVPKUP65SH3BR2UASJEZDQA3M55",
-    "GDODQ53XWIPOUBCJWNO2I6775732KUXXSIUO3DDCDKTQSR2K6NORZRHA",
-    "GDOE5SWYPKL75NTEF3GVHCV5QUILVPDJAFNXBY2CXQ3MVJPAMXTFC3Y6",
-    "GDOEBNHXRTPOVPV6W5VSPCY375DGIEILC7DM2YRH6KLAKU25ARXA4Y5U",
-    "GDOEDP2NK3Z3DK5D77TKQ756V34BTE4AJJ6AI7S2EEKLUDYZMRSCWORK",
-    "GDOEKTDS3PVPRZWY57UG3Y5DFER75DFAXFLHBIRFZ2GPEY6BA4CVXYSJ",
-    "GDOEVDDBU6OBWKL7VHDAOKD77UP4DKHQYKOKJJT5PR3WRDBTX35HUEUX",
-    "GDOEXEVQA3XPV2PFIUK5KYQX345FFZS3CKPP2YESRYNECZUTXTFQMB2C",
-    "GDOFH5ET6UCBSD6NYHCTJNARDXYF7KP35IDLGUJMP3R5OHWVAPH3WHIP",
-    "GDOFXL4SV6YNS7XHVPCJZONPEDTXLKOJEJB7UPKPGG7ZMLLDRVYGQS4K",
-    "GDOG2TYRCKT7BLYPQVQWBKEOSY5EYOFAMZZT5GAYWEPMULHJLONUHLMQ",
-    "GDOGCDBLRO2UQ53US7AEXEX4QG42S5SVEK5MPCKWZYZW5UNERPWA23XW",
-    "GDOGI2YV3QEJKT666OJVRSJYERATT2MBWWIHOJ2MFMICJA5XLROIW7P7",
-    "GDOGI6XAJTD5ZN72N3IYPCBFWXTLMV4VLTVFL6B44WA2KW4LJYPSNNCM",
-    "GDOGJNMEUPPOYHRUHBWR66IOBVWO6VKNHBM3SQ2DX7FHR76XQTRHGERB",
-    "GDOGKNL7JLXTUVZ3SVYGNVULFCNL6P6WM2JXTLFOABD2VUVFVQO6NHSI",
-    "GDOGLSLNGCYOIKCAB3F5JNCAC3RG6BOAJFZYS27A32JZI2F666FGSBTC",
-    "GDOH4XKIWWFF6Z5MM76GMYZGCC7FU3O45FTSV2XISC45LRJS4HP6D3D2",
-    "GDOHFTY7B46SRXRNDMAE7JM3C25JHER63YAHZOWM6SLAS3X2KPVABDEJ",
-    "GDOHFVKLIT4JIA72X6O3WYR76L3OUIWFHKKF25DGPGJG5F4W37NMLJY5",
-    "GDOHOX4S2SGHDIQDGA7I27AYBOIPQNR65LCBHHR6ZSOBMMDZ5Z7HMUGO",
-    "GDOHQOVTILUCJTGP5BMR4ZNBJACSDEUHPB5TYGKOTB2GAJFAGHRSGRDN",
-    "GDOI4OA2FWGUIB5DCJQQK6XQT7ZOKNZQR6ITDUEJC4WMGDT7OG25JFSV",
-    "GDOIFSWYJGG2CRGZKW4UHLSL3ZEUDQR47OH2FS7M2QBXTNHNG5ENE2ER",
-    "GDOISRM5PBXLZZ5ESJNZTD7TDJSQOKOPX5APIXX4NOPEVEGXBRHWYKKI",
-    "GDOIYQ57C4AFAEKMB5IPASQPOV2QQE5B27C5FAV4IWTVOIHOZQQ4HN35",
-    "GDOJ5PPG2HJ4OWRAO66NNR2TSXTGO5R2IKXKKVQORXHXBIUOBMMMYJ6Z",
-    "GDOJ6SSS5IILZOKTMDENKWSBP5CZZQBK3L6OCETSOZEUVPUSRACYMA74",
-    "GDOJDMNSAKOATM2V73YK4ASFHMTS4YCWZL2WB7CGZ5MYTSL4BF7VBC6T",
-    "GDOJMOMJT3WGTM4UK7I4UMGSJBQL4IWLW7D22D665PCVAYMVVPVF3AO3",
-    "GDOJRBLODHPUXBC6AB2L6AY7WJTRLKA5AN7PGOXVZFNWSZAEHUI7D7TY",
-    "GDOK2NNCI6XCUVYUWOOGGUFUKITXV6QVBPC5YGCWZPXEGM62APELBFLO",
-    "GDOK3GQ7RICVAEX4JPTIWJ5BH6QMWHR7GTJDXVO6GANUDQJTAWQANWID",
-    "GDOKEMPIIUNDCPMDA3BCFFOKTDLODNLYFFRZDS2HDN3ZXBR53LIQTUSA",
-    "GDOKHQFYO4GKS2ZMM2ZVYUVKYW54QH3APWYAAG4AZXEQRB3XXHWIEF5N",
-    "GDOKJF4RXANNAF3NEDIIWRXYLFVZYDAP4UWZ5VCBFG4ZDQAGVYORXNVT",
-    "GDOKJS6CY7IG2N7PORXPI542BDITM5AZ4A66NCDHPEWLZPMJK7BAJQUY",
-    "GDOKNSBSEMY4PC4N76W6YJTADRCHK6RQASYD7RQ74WMADYD634GC2OGB",
-    "GDOKWOF622XDAWRS6T7SHYGOLAEAB6DDFYE26AZZ4UOSRAR4HT5JRUBT",
-    "GDOLCACE4RYSB7YTVFRKVRXDZ7IFIEQUQE5CQHWYOCKGDNY3DSJA4Z56",
-    "GDOLNIK6BM7DDRNT5KTVRNAKKT65X2DUQ2E6KG4FVKYS7GBQBGKXVYLJ",
-    "GDOLUHXRQJY7E44TCQUSE2BCHBVIUIKAWOQSCVG2U2ZMH6DZRLKPP2RR",
-    "GDOMGZMQBPVMKNWFN6DCM2ZCYETL6Z5XRVE7BXVW46Q6777G5MXVKKVL",
-    "GDONHPEEATKSMHRF4HVFNIF4YLII2DU2X5QZQDTAL5EWSLY5IGFQXSBQ",
-    "GDONO24DSEHOFQO62RPGTBTMCTNGU7QMKWVVRFRWVSNSQGK7UWYYRJQI",
-    "GDONS2GR2CSBKE2F5JFYKWQDL7YYTFJTDIQDHVQNAZTKCDHABIAAHIFR",
-    "GDONTOJ7LQBMZSRFFC4DP6CQP4B62EP7GNH4SDJ6VRAZF2R5A7HEJDGN",
-    "GDOOFCZWTFXVB45DZ6VGB5AZA7JWX47RY6XRQ37JG765G4OGSJMLGIBL",
-    "GDOOKOTFA7SIMHF4Z2DPOABBMWFFS3H4ASU3BZ437U6TJ6QJH3ZAVOOQ",
-    "GDOOMDRXD3FW2GVHSHBRDCGJFCSFEQOXVXLQ4JG7U77H7R36HLBXISQ4",
-    "GDOOPA7XRWZ5TAXYS7USM2OGQUF5U62RHB2WYEQP532MTCAAWIHQETAY",
-    "GDOOQ3WKAV7P4USXCZ33IOCPSJOHO5MNLZW3RWZ5W4JRJOC3PYZ5RF5D",
-    "GDOOVTUHAYKQO7TLGHFD5ICH254FA4SEES7H7QTRSVM3WFTRTPCUCV3Q",
-    "GDOPNIO4DAWJTBQNKFFBWZ2XEXP6HXL3SQIXX4ZZSXKERH64WCC7HDSS",
-    "GDOPU6PAMPGJUUWAW2VRBR35FG6TKMPGJ3B2SFE5Q7P7XP777YBRB5KU",
-    "GDOQIC44V64GNUUY73IV4MSQIPCC6CBY67TEZJ2IE4US5VG73FYGDHQC",
-    "GDOQIOHSNOUVGV7DEEIZSRQX6DWBNXZKFJTJCKU6WOEQIMUPO6J2Q4B4",
-    "GDOQVHE4XKW7WBXDFWBHRQUSTHEFK4IONYSBM6PZ3LX242J7ZK3FTDQC",
-    "GDORJ535HDHGZMQ6WWXLMV5GQCVLPCOGXTQMMZWRMKQYLYNE3ZHEWCEC",
-    "GDORWM2WXL4IGRQE2MVCU52MMJOGKHNR4RPSFM7NJN6ERGCATUE4A5GE",
-    "GDOS7VY64C6JE6V2CJM5RTY4ESYEKTT3PIZVMQERFU34UDEFAMP3MAL4",
-    "GDOSRVHMQPZE26Y2RIF65XRIQWT4DLFUMDHDX4D4W257LE7VY3ELPPH3",
-    "GDOSW4STIQHODSGG6RTA3WGIWHDISLH7GVKP4GESLGN3PBTI74KCOCYP",
-    "GDOTAN3VLELUNR276DPPCN3DEINNFM6NMDXW3GYYMZMPP2ADK6LUSHOT",
-    "GDOUCDOWQCLNCASBY5ZR6T2HWKV4UQPLAMYWXSQLUDMEMXTEFDNOI74F",
-    "GDOUPPFFDT5PFUCSQLXMWCSAKT3E4CTPVINALHSOWGRA6S7U6OTHSBSM",
-    "GDOUXDDPRNEBUF4SNIPHJUVGB7H3462BZRRCAVHSC3MDN2R54YJ4TLWZ",
-    "GDOUYJVICLJFXNLR4B3FJUTBJ372BZIOBRYT3MNHTYGBZGZWH4EL2VV2",
-    "GDOV74VOBZYZH35BORTSHH5O4WD2H4U6A3QCCHP3FDXFHJLEQSMLJRAY",
-    "GDOVGT6HGGPFSZSLQDJEUT3YMKLA6HITJ4ZNQCKGW6OBZL3F27CZXXUO",
-    "GDOW3QLR3N3C6MPJEZZUMLWPXL4QVQZCWINU356RY4DN5D4CGA4ERIXU",
-    "GDOW72TNH4VWHQIJEMGSV3Z7F6KQOYHAJLG3FQHV2OQPKKHITAKJOFGO",
-    "GDOWCVPKITJKUHRZGKI3AJIZY55JJ6ASBSUN3GDEQOVHRK4SMS62KSCQ",
-    "GDOWZR5AFWQC37RSEN7HFDHMTM2EBVOXZMSZLGT3VKPQEQK6VIXN76EN",
-    "GDOWZUCL5JRTVSBTQZPRHLC2H6B76EFRB4YPQBUNRWWTXCVPZD2QGUSA",
-    "GDOXD3UGUQVOB2UX2FHORCRR3DFM4QTHPBMG2VEM6EKFMAJMUXRBASWQ",
-    "GDOXT4SJV5S4PVOCIJJC6WKZCSG5S7MWF6XUEC6GKBRMNKCL5TUIE6H7",
-    "GDOXUV3OQ2GIDNOPYIVKCFTTZEUDNIH4W6CRPSQJP5KEIYUAXXXQMXFH",
-    "GDOY4AGKMWAST7E64QGOXOBBGKEBDFVOLD5UUR5SPB3XIRIQBSDHCNTR",
-    "GDOYJIH5B7ZPAV46WUEBRWZ3MVD42UFNSPCP4G3DBQ6C47DIMVYPTQFS",
-    "GDOYLXGZORKHQPC7NHFMNUUV26BWOPXM4QLPEQZLUAGYWTZJT3GYT72R",
-    "GDOYM4MIZM5WQ3W2O4XBAVAIENH7KIARECT5UV2LQOOVIWHZ3T5L5SUY",
-    "GDOYNJQ2E755BUMBYOJ3GUGZZS5HODAJLZAS5C45Z5NANCTYZSVNWYJP",
-    "GDOYRBC4TKLJ4I3XVKTWVGQGEFTBAMNJZ5S2XNIUNFF5FXHZ2TILJJTC",
-    "GDOZVEY2UTTDBCBNHE4YTKBI5VPBZJF7JKIEV5FLU2PETVP6UL5I6DN3",
-    "GDOZYF2BVVU5Z2CVZCOC4QZROAFP4TGO5FLBWZ53NF7WTELNFQ2CVRGX",
-    "GDP2QJLOIV3TN7AC2E7I77J6GYBXYJLRFBKE4VIFXNPZ4OZB5ER42276",
-    "GDP2WGV5EDQK6TTNM5AZNJ6V7QQVHMFTJM4KI75UYVNTUMNR4MGF5ISM",
-    "GDP3FK3PGVJWDGMOAOMNK6ZBPDLUWEFLI43VKJM7DBCUH7JJP6LZR74E",
-    "GDP3KF3OK4TMOZ3A3AHGLXU54CXJPDLF2JZBHDXQM36MLOQZS7LGU5PQ",
-    "GDP44WXS5X52VGC7PCB23QOOVYT4KNU2DOQ33CE7VULIHKSDVMY66DGU",
-    "GDP4L2YJBCTR7BOQHDNRORHSEAZBKMTGFGZZQPTI2TSN7X2RMSTJIQ3W",
-    "GDP4Z5HZJ3WAE3T6YHQUPMQHJRV7W4NBJSJ3SKRFXJXPSIEYBQG2FXEW",
-    "GDP5G5WROJP3YWRT4IXTHRRVWTPK6FI347TOJQPVJGYGJSFBIW5P7ADK",
-    "GDP5JXV2I6WG5TWQA3RJWSV52QA3AJWBBQTBWLRUEUPOM7EQGEETFVC7",
-    "GDP6KDQKAJNRXMRARZ7I2PONSS45BBSEHXPEMGFGNO5TA3XLDF66ELON",
-    "GDP6NHG53Z4VC3TVPZQC5EEWHXURKPS44DC5I5E7QAQCW7ZZXWSFHERO",
-    "GDP6QVQQMVMZ452TMTOKWL56AFPMZURL7A5KP5MHN734OSEKGRGKNPY7",
-    "GDP6R2XAKNKJOUGEWSNNAZ3WXT4L335VXLNZ3J4VDPVSCEKDMWRDHBQN",
-    "GDP736VXKQBUI5AHU2DJB2FI7KUAMWADGZAQPET424TGJXL5N5JLQQ2R",
-    "GDP7QMOYUBSFE225AUP7DDY4QNB3UPIOLELQ7E2HR2A2QR2TOWG2DJNN",
-    "GDPA44LJ6VZGAKJYHKMRXIFR2G4UTR2XFTWAAPAEM2FKD3K3XP3GJSHX",
-    "GDPAOXSH5E56HF56RPQ4HBGJ5URB6NXS2AZJQPZVKFLWIJBENXYU5GOA",
-    "GDPB24FBA5M63FSI7LVHR7HT5UVLZRKLBTEWR7IOFLOGM2C3STY64BTC",
-    "GDPB3BGHJAHAKVIWUNLST7N6OGZ73W6AUAI7QDZJW26LEWL46VDAKBH6",
-    "GDPB4JL4MOVAERTJIXCFCC5YTQCMYPFCMBIMG4W7Q7UX5F66LWX2MEQS",
-    "GDPBCF3UZ6NQEOTFTBLUKHIW6AGH7TGCVLA3XMPV5XTOHBPCAYOU7GM5",
-    "GDPBTDEQ2GNV5DXHG5H3YLKV2JIA2OKY535G4EFXEMEQAA6KX6CWVQFS",
-    "GDPCMHVCBSNG3XXVNXPBWKNN4S5CDCUGSGUHPYTL55KZUEHPHL6CW5OG",
-    "GDPCWNZ4RFPOGAQDM43GHNZGEVFWRRP7VH5AM6XJSDO4KTIQB4CAC2A2",
-    "GDPCYRFMTUIGB37GJ76LNJP7NXUENBACS5QTBLMICFIOYAJNGMGHBJIU",
-    "GDPDJHP4CZSBKW5HVGVNLB2OHDYN5TJUWQUPEYWE7TGHG2IS2VIFFHNG",
-    "GDPDN62RIHIYWJHGVINC4QB7QTRGGPMFX2LL4C4LE3FPM6ACY7PCPIBM",
-    "GDPEHGFJ27AZT4CE3CZXJFHIW5KFE3V2BRV5G5YLEXXOEZ7VW7BXYKQR",
-    "GDPEMW7JJTOMXGP2OC7H4QJIUYNBWZ2SBJFTABHS4SWH24TNIMCEOIUU",
-    "GDPEVKRN7SGNSOX6G43LVZQK23YVVY27YJJXMOVK4BYAAFE4KAEUQIXI",
-    "GDPFDZNYWDOLUAWVNKGCOOEXYXVEDNOBELEIRWIGMWVQUG5NVUNNN7UM",
-    "GDPFLOJTZGRY5EIZBUANU4BNJHKM6QQAG322FAHRR3P3FXFESBQMWEST",
-    "GDPFLWBV25AM3CHFV2KH2JKAMVQ6D6TPMBKE2CCSBKXMRHMY3WALTZI3",
-    "GDPGFY2OXZUUQAGVY77ZMF5U34FJMO65IOQRC6OFIM2EYZHT6IOLXMLL",
-    "GDPGOLLVNIZSGSNBNID64NDCK4FUXWC3NCL4SQOMR4Q7SYWY3F2KZJOA",
-    "GDPHUJFCNU4TU2LD6CXFPHHPM5ABQIZ7WR7FOUHHJYZNG4KV7WV3LAQB",
-    "GDPHW2EG3UYKR2D7B7P7BCOYIA6DVEMYZVRF3AM7KBYJTF2HSCELSKSW",
-    "GDPHWIYIKUJ32NZSRBJM6QS2TTBSJJPONCPDLHQ2MRB5MJ2GHVKTWXE7",
-    "GDPHX7MJADNBJMKBAFTS7OQTKUNZTBEZ3UFBCC3WKQTQGUG64RWUUYVD",
-    "GDPI2FY7ITJ7LQAAMLXHOMSZ56GS2SYXZAHWILODYPK3C5MIOE2YPPPM",
-    "GDPIIQRBQEA5D6MEO766GAZX6TKBDF2TCARR7Z4V6YO4JLM32ASVMPAU",
-    "GDPIR4IV424AIA2WCQ7MJONAX2VLNKNUFDFSQ5HWBO7RA7GNKZMQSTK2",
-    "GDPIW63PH4K3HQ2F2O4FHSVHSTHHTCXV4HFR7QYR4K26RWR75ITP5NDK",
-    "GDPIXQER2K7UHPTYLOQ65J5CIGQOCUUPWQ3WBFRXSVJHQTOXHIP56MT2",
-    "GDPIYMG755S7U45EOFABOQ3DR3LE53ZUSEABEK53D6SPMZTCMDAHXQNT",
-    "GDPJBBMCIAYIXFIB4PE3NUJC5UXCKVCD26Y4UF7DCPS7OFLD7QQRRDSG",
-    "GDPJJ4OGX3SRSPJ5BFXXZVTIDNKW7P2DN55U5JDGVQ7T76T3EJRCQ55L",
-    "GDPJLOBZWTDSXSZH3M6PNCKMOZLFTY3EQGI3H2BNNUOJLRC57H7EWRQW",
-    "GDPK6Z6Z357EFP3EM24UTZJHE774HXCQTSWSCFFPLWMLTCFG5GRAGQ3P",
-    "GDPKAZ7IETX2F6XHIR7KJIAHP3CZ2S2RTNAUFL4V5OYQWC4W3IG7JLYT",
-    "GDPKFPGBWCZU6OX7QVEAJPJWJMZRDCT2PGLZ43LROGRUTPBRKZLH4VMS",
-    "GDPKKRI5LZESTRR7VFYCJ2HB6QBO73L2IPHVI3UEH5CFN6N56AMR2ISS",
-    "GDPKONQBFRW6IXWVZ6ZCQAFQPAC7YZQQTAAD54YE5XXOKGGNS6TLGGAL",
-    "GDPLCJM2VGMJTOQLKV6ZYOQSOAXD6R6NYED7FSUCD7GSKO7NXCPDLRJ7",
-    "GDPLQMQ4EDIUTRYKVE6MCOY5RKV65BHGO73IDQIN65STITVKANMGJSEJ",
-    "GDPM5D4ZYG3XHDVXUTLFNNQYMMX5HAM6ZVCVMV4FEEESQHD5GYANTU43",
-    "GDPMBNAEULY24X42IE54MOLTLGAQSRWBMJNULRQREDDYIN2655QBHNJN",
-    "GDPMGB7DHOUB3EG37TBW7NBT52WY3PNO4ENAMIMMG4HZLK7M7VYBNLCZ",
-    "GDPNFXA4URGOY22OHPUNT6CWE5YNW3WFJ46BZRJKOF7TQ3V7FPEHEGBF",
-    "GDPNIQWQQV7NGNESNOUHOCWS7KA747IF44GXUJFUDVTVKC4LCFBTWANV",
-    "GDPO4GG7ZJ6WNJM7V6BOAKWKBMWVQ2QCKN27BKJ7537FAXAHNJJRJK3K",
-    "GDPO4KBC5Q6NJ6UMYJA3SKN3ZUCWSDTNOLEXSAFMIX6VSYYKU37CA7WV",
-    "GDPO5DK522IFSTFWBHUXZNTAEAZ3DT5NQ6SHK3ELGP2MYPTTTPY72N7C",
-    "GDPO6755KTVTT73RAF5LQCZVQFAB33Q2HVBVUK4MG6UVHI6AA53JYJAY",
-    "GDPOHB4FETPCBOKHYFX3LPXHAK3D4RDJ3FY5RXVRGRPKBVNYCGPTM5I6",
-    "GDPOKREYDPPNULFAYBH5H3KHKFHRJYC5RBWSGBQ6NUHHRNL6FHVYYWVV",
-    "GDPOP3LLE3WMSNW76PFAQF2W5DVICPSNWZ6DRKRTAJKGAGG2LVS2TRBX",
-    "GDPOWPO4M6UJYX57SJYT4YDIQQP3HTOHPC5GXIJUAWU4NVBNP7FMTGQT",
-    "GDPPCV2XQPOLHJUSJ53SKW6TDCVUGVOKQFHT6VKYLCSTONIVCFRH4NX6",
-    "GDPPFNP3DTKB6S6VONZJVN3ZCZ7BCYSYFWN6PN3FWSWLZTVPPDK77XJR",
-    "GDPPQO2OSZC5TAG3FCJRZQ7IYW3QMJDG3IB5GOPJFKHLE5IRPMG6IPYF",
-    "GDPPS5HEH3HDZYRIUHHRX2P2FYYH6VMHO6DHBRME2HHZZ6CYLT5Q563D",
-    "GDPQCWDF2VB5DHRKB6BI7OZ63OGGPTFW7KPO2YHVGBNPEJNXAG5CHTIW",
-    "GDPQNSU6OGLQRXX4XETP7YFFLPRSA7OYYSSEK3L3LWELCG3AAE4DZC43",
-    "GDPQWQ37LPPLJJ4SWG5KMHEISATFMD4QTZFWN25UGGHFJ34BY5WTT3DN",
-    "GDPRA64MBCEQZZUIULZAAZQY2HUVF5AM56L5YV7L72HAJ3BOQ3TV7FVO",
-    "GDPRMURQ7I3WAKTG2ICKJXHFJ3UMM2BL43UOPWD6IM4LWKPGR65DPHQB",
-    "GDPS6XMHUJFCDDNK5Z3T4KSLCIHTVLEKBABNPDRXBILRBCE4525SL22R",
-    "GDPTIPWCCD3IQSKBXVZC53QXNFCGWKKENXTLNS5AF3CXZYZYXUTFA2DB",
-    "GDPTLWGASX7KOKUXOVBH5IKQEC63W74QDYAHOPFHKGTAPSJRNBQNISQZ",
-    "GDPTMQYYHIFNHLD2KNWRT34LHTPYZEDJQUFIWZSBZEOBWALX6ORSELNR",
-    "GDPU6Z5ZKXNEE6KCRAPPLIBGUGNY4IU6ZRIMNUELLBQCO5TQIGLG7XED",
-    "GDPUW4UCUFOA5BJMGMW5CKQ42RQFCKSCCRN7M56HQLJPBSTHPZSQPYVN",
-    "GDPVBS5HS4QZHLW5JKPFMOJE32GJAR54ZQQLCOF2QF6BMFW5S5VIGFKT",
-    "GDPVH6VO3R6P6SZ6NWUX63HUV2F57VB3U56K7FLTTQ6XM6HF53XVDWWK",
-    "GDPVT4KSFQRLNDWCRIMHHZXZVM7V22NWHY6DJ7TRCCMDU5XKSP7BAC44",
-    "GDPVWI7QGKVPKLILEGU3Q5TSWO4NUM3URCZICGT5CI7TVGZEO7W2T7E7",
-    "GDPWNHP2ZY66XKUKJKOOGPAY5UWWAS44JSN6WRRONH54CCMWGP2UMLDG",
-    "GDPWUP5VQXNMBKUFCCKECI7DIWT43TVKBOB2BM2VJIHZBYNNKFSLPROJ",
-    "GDPX3IRNYGQBG32B3X2HBR6VKHPZ5ATQQBYLIQQG6ZRZGYQOJZ6ZASHL",
-    "GDPX4PO7XJTASX7HGGSKB5XF4P22TJDVU45NVJLWWYJ4H6FZZLFLG6JS",
-    "GDPXB4J6DZEZSDQ62E7CWDIZI4BDOYB6GXMCOCHY6OS6UUSYHQ5NGUSA",
-    "GDPXU4B6ZH55Q4A62NHHV7SUNO7BQB6UMDH6MUX6YHILSY2MQHIAAEZP",
-    "GDPY223JDUTDPWE4RCUGE23AG5CAQ3LL6OWBFPB35D4BSDHSABNQEXRP",
-    "GDPY35KQXQVXMCZSNGYJBGXRU4NKQLL56JPOAYCCA4RRLX3XHNFE4H6S",
-    "GDPY7EY7LCDQUDBE4MNHO62OTIKUCFK7HVJY746VZL6XBLEPTGQCOA3J",
-    "GDPYKD2RZS7KRYMGHUHBX7YO4JGCRQN2VO6BVH4Z2H22QJ6AM5GLEBTC",
-    "GDPYWP7VSTQJDTX7WUKMMC4PVKZNRMJTRRFNK3LGMZ7L4XHT76WFPL2G",
-    "GDPZDAK7UKJD3APISUUMYZ33S7VWGLBHVBQXGLGDO6BM7V7QBXYL5WR7",
-    "GDPZIOUVLRE5JGNTAZY7WNACUVWIQ5K3QWIXBYBMJA5E2CQFAN7I7YVC",
-    "GDPZJNPRWSRCK7Y7J5MQKXDBZLMJNWWKHR4457WJEFDZSWTZPXIHKP2X",
-    "GDPZYT3AAPLJKJTNJBCSUPODC6KNK53HHZOGLC7VXKFGI2DM5DOTBURI",
-    "GDQ2OFCXBOSMZ6KZZKB3ZKJSOOVQKER4YUHPYMCDH4SQ7YGNH4V7FVXT",
-    "GDQ2RZ2ER6A7X6VVWSVVUILT6MILNPXLKZZ4TIWMDQYOYUJSL7DXUBER",
-    "GDQ36GJ5O5JCJA7WWOTX2LMX33LD2RISBBQGRCSNBD3FSLGEA6M475OU",
-    "GDQ3JIVFXTYE6DOMV4T6EKTEWOPBNZZYH3W46YGPDNH6GJJK4K7MDQNT",
-    "GDQ3TWYR4DH2VPZ6EKDFTILQO45EU6EK3KDCQVOOGCWUPS6TC3X2MH63",
-    "GDQ3WCFKTDYT6R3UUBYCFW4MWITCYL2KJ36FLH7JXPCU7YG3A2QIJPRO",
-    "GDQ3YJZYB4VDZPQYPQY6YV77WJI73RQ4HTUITZOTLPPAKJ2DUGLNTGFR",
-    "GDQ45KJMIIJIFQ77WCBBSTE5UYTVQ6HPC6DCXHI6QDJHIKZPHMJTPDEZ",
-    "GDQ524TI46NGUOC6B2IAEN36HUG5QGZXFJD3J62NQ6PTMJ5YTXSGGXHO",
-    "GDQ5E7ZDY5FN5NZ7T3RD5YRFBQBIBJWGOPGDNHVUVQLBRGVYO5YXXDZU",
-    "GDQ5FJ5VQZXXJFMIWWSKTN4N25CAX5NWP6PDVMACJ2NSNTZURG6NT3JP",
-    "GDQ64ENNTMOZRNGJZQLEQCRIG2MJY74CL6DYS67CEHPZYWRZ2UIAERVY",
-    "GDQ67VBX7CQSJXG3GYNCCHCZMCE76KSHYVKVR6F7UZEAZB2UTFVG7JVV",
-    "GDQ6Q4G7BOXZ6CR6XTM7I5UPENWKQKPLGJ2SIC5AXWILQL4MV2A3HSQB",
-    "GDQ6QYYP7ZCSO4ZW5HMTCPSP3ASKPL63FHFQIAVLAW5HLKE3H6L2R7MD",
-    "GDQ6UD4425ZY4UG6XLZBSWVGRRS3GBGQDCNRWJVL5VQJ3AJUCDF4MMH2",
-    "GDQ6UOL2TY6E55TFN7Y4H36LUQP5CES4VKKOHIMYNC6L7FFXQNQUPOYK",
-    "GDQ7IWM267USYSZ4EYOYSVKSEWVJU4E4WV4BT5HCQ26F2AI5UFVPT3ET",
-    "GDQ7JNS6YRCFLQDI4CAEQD33DCVSARRXCU7BNMCBWAZ45BHHPILDMJ5C",
-    "GDQ7UFC2XCA2CSIFEAUNQHQWUHQO5UM67K3N75IP4EPKOMDSOLG7DEG6",
-    "GDQA3VK7SJZSHMIAFELX7YIL6DDOZT5BJ2J67ISXFEQJUBHRTVR4SV7Q",
-    "GDQABQTZSYR7DNL3NN6HEHCDXMQHU3XCYA2QKTY5ILISW2UDQRJTPQBE",
-    "GDQAKWHLEXPVXD2Y56TEDHMNWHGCK3CMSPSQDQ2MLPTAGIXWGJMWTWXT",
-    "GDQBJHJDEJOXWSCOHEZUZ44E64PRM7G7U25K7PH4UPRM2JRHHMAZDZBK",
-    "GDQBQN6Z7OZOC4HCVD4AYXQAABZZICN63Z6ELTVELXZJCLILI3KYQXRP",
-    "GDQBWYHZEN5IBZEO2OSAKGPSWYC2VTE6BW5VXAUPPSFNLIOKZIRFUNET",
-    "GDQC4IG77XUJCUYKEI7T5ID4DLHH7TIOGTWDXISMNYCUM3TGBNSOMUVD",
-    "GDQCMTVJZIIWFB2Z4P6BVZNOOXHNM2RHOTT32I2KB4FRCFBZGHFZ6VOH",
-    "GDQCMZRUVEYCQXQ3Z5TDVSXROIH467P33FEOPRJMCPRV6LXHBLUXGGDM",
-    "GDQCN3IKERBLYBRJDC7IHJXXV3RAKZH7FGEPFTDOYAII2LI2WUKHFWGJ",
-    "GDQD7FB7C6GDOJ33XNLETM3AYNTYQVMTZFHA7BCERVCFWYBRZ65TVK2C",
-    "GDQDBCI3HMY75BTBXQ5EQYBAFGQQZVH2FB7CHK7UJLBIVKNXKD73CAHJ",
-    "GDQDORTD6CCDBJ47FZ2H5SFD26H7GE4GCUR2OYFWISJUH6R2G5JLMTAW",
-    "GDQDQOHLGBEPY7X2Y36ECUQQ5SNVH4AKYUEX23SYWWL7J6ZU4ZTA6GPB",
-    "GDQDTGDBIBH6QN7UXOLN5XTRX75HL5VUFM7FS3FPFMU27KKWISM6ETNH",
-    "GDQDYOYNTEDUTLKLFBOKQDCBSRRJG6S5JBBDDMRUCEYG7Y23W4AYSHEL",
-    "GDQE6W2I44KZUHLJM3LID5M3UROD4RXGV6MWLJM5BOOKYGIN5E6R3E3M",
-    "GDQE6WTJDQLKJT7FNTYIFRLK2RNA3QFWCJSS2OX4RY3SCRZZP7QKEA5F",
-    "GDQEMJHMQJMT4MHUIEFFFBXKSIXG76PNL3GIM3E6OBBCBE7LZWNIU7DW",
-    "GDQF36KISBB5K57D74ELKX67PSO2MZN44PIBMTQNEMJ256AYARRGGA5W",
-    "GDQGL7IDTV7ANIG5GJ7JEXUWWNDWZRZFLM45TIYD45ULFGCYAGNTVN4E",
-    "GDQGT2NQMCHPVPH7OGTXGIAKV7LZWXH2PRX5IBCFL5KBIPHFELIYVGDC",
-    "GDQHS4NINI7GP2ZZVU53BJCIJIWXOGNGNQELYYHX7LMJ2KG3PMXKGF3Q",
-    "GDQHW4EZ4FNPKRZF23RY6ZYFV5DFQMJG7KDK2RK5MKEKHY4Z3E6QXWM6",
-    "GDQHZCOH7P4PGEUXYE33S6XVRHCLHULV6STSDTXXR6QVAURCFH6XJZEE",
-    "GDQIDW4YNT4FM6OH43CUGOJZYDN3T545VXBJSHDIS2N47QJE7XMMCVH5",
-    "GDQJP2TZIX7FHYVSOQ6PNDFN2RVJT7ZMNBFSZWO2TWSET4ZR5WETUMMF",
-    "GDQJRYZOR3RYBXLBDL3VPI564QLRL7BIYKA6VLKQQAKUPKPRORK2MVF2",
-    "GDQJTJKO6NIR7JEBW3Z37JXVGN5OAKFFYGVFSQYVPUWJNFMPGB24WJID",
-    "GDQK4DGXF6HNZ2KJU6LA3SIMFTR4GFI6CYGGNTHIPIKPRF75LQHFCYKR",
-    "GDQKAFTVYJPBNTZ5Q5ERPMAE7OHZ5UH2L2KRWSZCFAZQVSL6TGFRJGOQ",
-    "GDQLB4GN5YMLSNUEUSXPG222AURBJCQKWQHB3KW36TLQSPISOVVQPROJ",
-    "GDQM6JLAS4EXKTYB73M7G3LFGQKK4NL5GS7ZXQD6P6IMB6HT5CBXSRA6",
-    "GDQMUKVRHAJFXTTOKNKT7ZNJNAL7IU6VBE6IS3CKN6M6RLSUHHS7PJND",
-    "GDQMYH6SKKZQRLAK4BCJ27TOKQXDJ2BE6QGGHKV4PVAIWEQ3XQODAMQJ",
-    "GDQN64JO3RHZ6GHPAZERO3Z6BO4URSNKU4ZCG6C4I27ABWYUV7MUCUNI",
-    "GDQNCUPBA5HOKC4T7OYLYHUNCWJ5CIEMSDIMNJMCQPRZE6MI5X2ZUICG",
-    "GDQNI4Y5333J77RYXT3YKGIADQO774JVL6QMQPXQK2QJH43MMO666BAY",
-    "GDQNIRJ7TZNLBXPZZEPUB34VRAJNZ4SIRJRJIHBFXVJJCGUFAIHTKTQV",
-    "GDQNUB644ENFOSKLQH25HT4RQ4N5HCXUPSPOVEB6VRDIJ5UFRLZRX6OW",
-    "GDQO7FOIIQVT7TJSXFEQLTGHLLNT5Q5HKMGOUYEBDUSIXCLVU3QZWEST",
-    "GDQOS4CKJF56DPQYAMMLCCRHI7RGJVDELA25TUFJ4VGGPGSIDMYKIZAE",
-    "GDQPNSMBBI4VPFFF7C3LERRYTH4HMGO75REMWBWPFDTBQEFM4UVCSTOK",
-    "GDQPQHL6IE4VLOFFAB2TMXBNAUICOGCABFJC3ARXOS3ACTIPNPQYO57C",
-    "GDQQIL5Z2L5CJILW5ZMYSVAOYZM5WLTHUZPXPADAWINAWSSN7IF6B44D",
-    "GDQQJ4K2QSWVXJ62TTE7OW7HGX52KGGLM5RGK6Z372KUI26ZKF2V3JHW",
-    "GDQQZ4W2CLCD2BAX23ZD2DBGZOFVURZ3S4CBRBTVA4L75SD7FSSIJGOQ",
-    "GDQR4NU7C6FFVEJZ4ZVNPUF2S5P66WH3O2CCLMMPHA5W5VMSCNNHIOOK",
-    "GDQR77PPAW5SZL7N3HTDQPZWKW2ANWJFZRG7K2BKDR3VFFAFRRCFYCT7",
-    "GDQRAF5QIZEXOWGURHVDYIEFSPAHQF5SRO7PIAB4GAZWK3OQ6MXF5U5P",
-    "GDQRGA25CR7DZMXDBINCC7YTSFLFVQSMHVOBIYWTMFBFAXTM5KVIEMRT",
-    "GDQRTYWFCKT2WU74ND6Z4FTK2VOPADWTBL3RZOU6ZOMSGBRNRGGWJA2F",
-    "GDQS25WYVZNK53TS64AV6OPULE52H3L366QSRKGS6TKSNCJLPGL6PAZJ",
-    "GDQS2UHVAVH6IPT25753OUKMEZ376O2RLZKIONO4HGWAZUUT6EOOVQGM",
-    "GDQSKB25T24N62MHTJGB5ONRURVWEDMZBOGII7B57RZ77CU5CLMWKTR6",
-    "GDQSL4TRXWLY7ZNI6BVKHIATNOB3LSEOT46CS4P3J6KFNVXI4L63JOVY",
-    "GDQSLFPQCGBVKTBPG44F22732HGASIYQAB4R7GUXWDXGY6MLIJFDCEER",
-    "GDQSMX363ETXH57S2XKQOJMENHDRUQ6XCY3DGYPJ4RXTSTZYKYECJO4L",
-    "GDQSWEFWH33EXN32B4BB7X7FX5TX3J4JEZVNO3ECK4FA44SN4OGC3PAA",
-    "GDQSWQJFOZDPNU2MICHK7O7FCGPPCKBJXWWTZJHSRUZ54UJNUKEBPRAG",
-    "GDQT7TCJQS2GRY7GNTMD2Q72GWJBLVREKJIZ44TRWP4H25KMPFLDKJTU",
-    "GDQTVOWAW4JSYXWMXVVCIIUAQVIEJV7TJMUV6BJLDMPL5WFLXHAEWE6U",
-    "GDQU6TVTFBCCMYFJ4ZNN7EKI4BALFYOQ4EFRBB64DQS3M3OGYX7ZPNYN",
-    "GDQUTJJRS4WLC6CW6RVUCOGW3GXBFO7ZTCMLBHYOT7C2XUFK7FMBCF6T",
-    "GDQUZU6R7U574OOA2BQMWC2FXPZ6BTACUK3JGLR64TK6NR7XDGH3TSOL",
-    "GDQVCSS7ELYBRUKAQCM2N2PJ4AJVTFKHAENYBV5MJO32JPEXJUDQSNGV",
-    "GDQVCXHAPYD47UOI6V6VLMNE6YOUJHE2BLQ23ZXRUHRLEAA4XF2I3JAP",
-    "GDQVCYBE5KGA3Q4ME3NCIARAF7CBIYVRJQTXDRRSCCVKCX4FNY6DMA3U",
-    "GDQVLCLPNCVFPGYPHFFCK2Z2PRBBS5PSCMVM6AGKD3FR7JUHB2JYXS6C",
-    "GDQW6P26EWMJVF6HC7XF7QIZ2M3QMXPWVHDQP2KY2IH74FPXGACI6AOP",
-    "GDQWY6KCRQ7D4HALZDQ5Y6RNPT45OSHLDQBEE3DAINTVW74FCNJW5P5J",
-    "GDQXVH44NAS6M35CTKL3VIFZWNVVDOTG4RB5IHJ6MC4CBBLKSRURR3FX",
-    "GDQYMWOYCUVI6XCTSYTYRLTK6XKU6D7YRQX74JL5OUPZB3RI4B7IDSPU",
-    "GDQYUFBI3INSG33QDNWSMNF5ZHMB2RGJND4ZOTKGV3O33RP6U7KJTNER",
-    "GDQZG6RABVK5EOVILIEWL6RAACDCQ2K2XZ53PHVNCWJLP4FHCYUNSFGB",
-    "GDQZXQPTREG2JQCRVSSBLSWFFEDAXIZ2AIG7PMBCE7SD3VWY2345424H",
-    "GDR2EDA5RUK4QOJ2OXILRYGTXS73DU2O5SHYJAXRHUIYDQVZREOM4LCD",
-    "GDR2LARRPK2ZGHVTPWASQDC6KEFFMJYURQS5DJFGEA2FWLVEBBK3OAI5",
-    "GDR2W3U4K2XS4LMEKIT6EUNVQY357CMK4MI666GBMGEDUZEP36GWZ5U7",
-    "GDR34R4TXJRI4E7KWHRPYR2EYVULJ3KS2FCLJQCTPKBDZEQT5DJNZVE6",
-    "GDR355NLM4BMMY3DL5A76TJKUGRYMRFNMWS6ZTVSA7JWGSXTFBHYBTCG",
-    "GDR3AIOIFXEEZK7J7VFM57PT62XURHOLWIHQIXD2CBWKYCLH6LL7S4FL",
-    "GDR3ENP3LBJKXWFNOESUWM3ABWSWD676EF5YZJYHILIITJAUZFOOFWEK",
-    "GDR3FAX4O2H3ONMV2TQO6WTFNHYUBNDXWRA4XKA2LJZDTIVAM646PQW6",
-    "GDR3MWORMCXBD2OSBFXOHJ2K7GWXW2BHZY2HRI6TFI3QIY7SJUCXQVAK",
-    "GDR3RXQYIIKVL4GWJ2CX2WLVAICVS6R6WWHQGKU256SMQPPT7UVKW3TI",
-    "GDR3UVRHZLJRHAUEGJQTL6K2ESNUKH3PMWJZBHBM5J2VDM2HT7QZREFH",
-    "GDR3Z3SZMSUQHO54JNLISFV7RBZNAKFO5XVDAWXT5KUA7UWKNNNRTVDE",
-    "GDR422NK6N6SPMTNA34HVFWVRTI3M4C42K7GK5HA7QHBABPUJZR7IUSA",
-    "GDR4KQBSLXGQBMEXID465KX72YONRW4E5Y464QC7ZSY6XV43REFUMLUY",
-    "GDR4S46GS6T26VU4RPPBTPLYGFAPWYWKDGJ7VF6N4CCOIUO2QL7BX5O6",
-    "GDR543ZNPEV2PVQAY6VEDCDTXMNJVBUXQHSQ5X36G4ZLTOUM77F77FQ3",
-    "GDR5E2CGSLLUODAZ6JV2PGS52Z6YXZVXA7WYPXUQMLCAYTDRS5SD6XFV",
-    "GDR5K2L2SNHOPVVF6I3ZBHYLNZDLMWHGT5MA2XTBIEWBRYXOAQBJNLXE",
-    "GDR6UQKFR636QGPTO3M2677WRXET6FBVDFNY34XO6QL64J5WDX4BCSAP",
-    "GDR7AO7KWDCJE75KLUOV7ABI36DZYNBXR7YBGGWX4WPIG7P6IEJANPT7",
-    "GDR7PGLJ6OSAHO2AL2XUQ4FNPSOM3CNYSPRZBLRL5D5OCM5YPLZL2VYH",
-    "GDR7TJPCQ6UP3SAXLWCR6SKOMIPQM6WTFOPY4PE6P6D33BPCJMARP4ZO",
-    "GDR7W5PY376RC6BMJVYTBIWV45BLPLMSAPLQ7Y74LQV5IEFO3247AGFH",
-    "GDR7YNS5T7LLUQ7YR2KVOVYL2Z3LBDWK4TVBCF47HG7GWJY4VXZXCYAM",
-    "GDR7YXQTYDCM2EVXVRQZ5Z2DXNHTWAAO42VWXZ4VN5BRFAI6YZGY523O",
-    "GDRAFJHM26J6GJLXW44ERCSEIIUJSMSM6555W3W353VOXOXY7AS2MUJR",
-    "GDRAJLIJQPCRKPGH2LNTCBPLUJO4VN55OPY6S7D6W6MUYENTRJP5TV7N",
-    "GDRAQWFL6FE5CAL6DM7ZV2BFLXW2U5R5OMYB2JWKYRU7ILE4ZGQ67JWH",
-    "GDRARYQFXV76Q3QNFU27NIWKJ4NA5QY56O3TJSMFQRKAS7YZPIE3F32P",
-    "GDRAS7LGDXJSJK3RK3RIDON4ODOADPPWVBZ5HXFPQOKGLQX5NO4DNK3N",
-    "GDRB6GUGLB5SCNBXBEW5PLWUEYSY2ZBYQ4M5NNAHZW6254GKRO7BE7TU",
-    "GDRB7HEGVLBFJLL6O7BMDUBJFHQ2PW5M7HAZCBQL6NXBA44J3GAJVPMI",
-    "GDRBPIADRJFZB6ZZYE6TCLZKNBEHQOZRWX6PT5RPC62GESA3VKLO6RAP",
-    "GDRCLCE5ALON7SOSQWNKLQADMUAV4L53HJG77IRFN2ECBZ3KR5X3X7JX",
-    "GDRCODFDPSPTRYRX77MV3IXTE6PQ2CPTLUAGGYZSPZ6Z64P533FDYG77",
-    "GDRCPAGLZS2N5Z275KBPEYULB7CDRGTOQWFCBNWBJYJXXWMHVW4PABDC",
-    "GDRDCSWGKARW2QGHBNAODBOJME2ZBBXPTBRGPQCWLWCA3GJX367P5CTT",
-    "GDRE6WFOKNJEBQ23R7LIEOBDGKUZZMOMGYTLL4MXZNQUZMSRBZNA4WYX",
-    "GDREVNBWZZE4THMC675OFCV2ZJQGM4BQ33M7BHIPFFBCGJVYVRERNKEL",
-    "GDRF2XXHHDBIJWFGSW5Q7TVTVIAMBRZDIZFOCJ7P5DB7EM3FAID2WARN",
-    "GDRF4GKWE7ZPQX7HJJ6VKY4ILCQUVRMVUHIKNUNUVNE5RRWTXXZAQNET",
-    "GDRFLPMEFT7JHLLFUA3URUDN6PD3DRG2VBYUZCIOPMA7OEIVPZBYVBB2",
-    "GDRFMDHYP7EIU7DLU4EWVZVBEGPVZZQTIJO6EETP2RKSLHDUR566JXYO",
-    "GDRG6V6M76QZK7AD2HUW5QSTZL7F4L6TQHZPM2SQ7MYINBG7O6K355IK",
-    "GDRGHA3WB4EU7LRVF6XXHRV44I53FHWBHZQGDKNKNLOP2SATJIYGYPUG",
-    "GDRH2JAZTFZWKOADYNSMAMTYRHP2UID5XYUOGNHN7UMGKMAVPFACA6RZ",
-    "GDRHDWGYAUD2FP56YEZDOXH765CHH25WALA5ULUO3MF6I6LO44NALLNW",
-    "GDRHFMRGOB2D4UIS4MXFMNTXSILALTCLAUHRAL2LEOHFZ5CKMNR6EGSR",
-    "GDRIVOBOTV4FYKG6ZJDZM6IJIXSI535DBZLW2KXTEP34GKA7E6DZFVMI",
-    "GDRJAY2JSSNEI4NEHWGLXLSEPMN56DIFVPYKPGRVWXCZA6SVBIRMPCWF",
-    "GDRJLQUKTK5VE7QTZJT6PTJLJ2SLBC6LBJIM3PPGWV3WIT2VVF24ICPC",
-    "GDRJRNPY3PEVTUYWLTA46QJEH5YZIR7ZB2TG2N326WBWFOGNAQUMT6OI",
-    "GDRJVGO2IA4ZJHFVQZKB5VFX2PDGTKWHLA2ZCQQL2P3VPFPGNLKR5RLM",
-    "GDRKKWB6R5XTWCUTFBEUTIAWIXKWP3PDAAUXJRY46O4TPQOJXDGQ5ZAL",
-    "GDRKODB4FNJCDHXRTO7P65Z4VPTJCU3IWJ6VHSXS77OZPTMVW46E2XRP",
-    "GDRKZUEYJ7ZI4UKAKEFW3UUE7FGHYARB7Q4N6URTGI25YWSZUOJVGYWF",
-    "GDRLOOSF247ADPJ7JV6FRSTASEAVQI3LFEETJ6IGAEOYM2KH6RFLR4WO",
-    "GDRLPRBKNYIB6ZODRWEZV6NSTARJR4XB56Z2SGZ5ZJUQDE6CQJ3LRCRQ",
-    "GDRLURWUAIXO32R2PCHV5CIRUZTST3ZI6OJIUYQWHXYK3M7Q45I3P3JB",
-    "GDRMD5JHNLHJAYDDARD4M7SK3EGIBG2RYTWFWNCOIKA54GB56Y2SJORT",
-    "GDRMVQ2KETR5GP555V4V6AHHDTTNX42C4PEZJO7MLEAKIWGZQ3HEHEXB",
-    "GDRND2IUXVMHZ4XTB2RZ4AJ3AOLON3WTAOC23XEASB56NHDFW3ED57TW",
-    "GDRNTMZDSBRUMEE243NBGFH6TAQZBFLJWMV6D2JDSW2BLFISTXYGBHBM",
-    "GDRNXR6IFEBL5EURAPYAN4TLCBLRUKTBDZBQNIHDSMMU2ON7GO3S3NBF",
-    "GDROF2AEAAP5OHBKKRGQM4KOGC2XTQ3DLJJPHVU2RASPSFWD6ZPYPGDN",
-    "GDRP4VEK753AFDLEFFDJVHONI37VSTCLCUQ6P6CGVPRXF35OMACWEXRP",
-    "GDRP5TQXR6BSXOIVP4VJ47A362L3FLRNJRP5NHDMAZV6GVFEBSYIBGUN",
-    "GDRPC35M7BTTAYF6IASXYYN4URWLSVM7LFRHFWXG5APNTAMDIRZZPDZL",
-    "GDRPFKBQ5RTD5LB4OFEGKMZI6R3CXNGU2QJD4J2HNUUT6V37YWFQ6334",
-    "GDRPGAKD6INLTANPIOAIGFZDKOOOXIDKFJHKZFYLHDI6GQFOMMYD3DTL",
-    "GDRPSACRDZZMT5JXSF64J5DCHGGF4AWR6GFEG2GT5GSJLJI3HO6HMJI5",
-    "GDRPT72K7HMZ6XBDBAXX3JVN4DG7XGCPKFHHR5DXT6R265IFDF5IUBKK",
-    "GDRQEZ72FWQBAB2SMVY4IWXZU23XWJTMSFQ6VOW3VNH2MAHAYPMOFIDW",
-    "GDRQIBZSEJPZ66PAAY4Y6HDET62SCPQ3TKO3HWYXYGGTJFSKVJ7PXYEB",
-    "GDRQS77G6SOFVASEHQ5WAORUAI7UUKRWMZSAEC2MHXFUKKRQJF6ZKQ3C",
-    "GDRQT5M562UQLUEZUC7RCCB5ICHBZLRFLBRJD7WJSBDBWEAUQEAXGEYC",
-    "GDRQVICCHOYCEVVDOIFDNNJABDLUKXZESZIJLC4BM6H6QLVEV7H3VTGO",
-    "GDRR2N3OV2MZW2E5COX6JPO6BVR6SRIM3SDMBOQIYQP57ZTCNFQYMCAI",
-    "GDRR773Z25LFPFFSKWROOWBLOXZ25BZO2HBT2PI5CG4OKKPAUGIV434J",
-    "GDRRF37YDE44K3HEI7WYKHISLSBO6UN53PVCLBPEHQM4CPDPVTKWASGT",
-    "GDRRNXQXCIDZRNDE2WZ2Z3SDTJAFU4Q5H5OTPZA52UAAMEMYARKUHHAB",
-    "GDRRUWDFUFW42ZGHV2LW2AZNQJGDWSOHEAWR4RBSZOSBGEIJHRK7BTUK",
-    "GDRSCTULMUZAKS6UYGQEKTWCN7O7JRLYWTTXYQT7ANXPGT4I6I56PI4I",
-    "GDRSPD76EEIJZXOREXMF4LMACZK5PWQED3THEB3EGDVLNSSNYCU5XRNQ",
-    "GDRSPXFT75HCFA7P5K3TZ7VMJNLWFI53IJEZJ3XT7PB5VB3GL33F7SDB",
-    "GDRSQRC47VWXHRMXHMDEIFUVKW7O3BGW7VGBPIREDDMSGQJ6LGVI4W2X",
-    "GDRSTTOIEAATKWZ3FQHOVUQS3LRTNQHN3B65OZLOUHQFM3QNBZQL2DVG",
-    "GDRSWSKJCIB6Z65UA7W5RG62A7M5K3A5IHMED6DYHLPLWLVQCOOGDQ7S",
-    "GDRTNSJF6D6ILTNAC46FCTY6ZQCAYQJRSVYZ5XHHMTMRL47KVKH5PEAZ",
-    "GDRTUOLV6UKVCIC7TENARSVDHAAUSBORX2LSBC4KMFQF22I5HU3Z2YPX",
-    "GDRU72YEGKHKN37N7Q3WWFQM6LPKJRVV5HWS6N56IW4LXHE5O2FWDRF7",
-    "GDRUNW7QONIYXZN554NMYEUEKNDN4S4H6FZB5IOP2KHCEWRAWM45RZL4",
-    "GDRWC6GH5TSINQ26CUVUI32I3IUYE4OG7WVNISDPZUZJMAUCOXSMFEI3",
-    "GDRX3MRGHPH3TSOEZUAWIDH4S447OXCMNOQK5ULE4HN23VSKSVMQQEQS",
-    "GDRXCXZOYKSFH4H5WWYLEKYX76KWIFO3MU2GVE55OS6OD7I4KE5NQV4N",
-    "GDRXFMQFHOZ5MZY6X5MHGVMT54WCDIRE5CQ6RXZXLUEXXJ76QHEPN2IH",
-    "GDRXP6RZUPFU3F4ZU4F62PUKMPFMET6DZSEU25EPOG5ED5K67VP333UC",
-    "GDRXWJQWZAQUPAGFE5DP534KZAQQMEL4QOCYIHYBC4ENTMVZAR23SGMB",
-    "GDRY3AP6YT2ZOCVS57Q6FKLMWGXK5AOPU7YJD3EZV4NAONHCGRWGM2B3",
-    "GDRYRW7WKUHLL5VH6N6XBTVEUQKKOJWVMEXKZFNOXJHDOG336S7PPYVN",
-    "GDRYYKB6UU22PTFWHP6W3WF7OGNXMDPMF4R5LE5LTMHB73X7QBGNXVHT",
-    "GDRZ5XD2OVNZTYGCGT3QIMIJAMU5LIBBVF3F34ZPIX23Q43IGYYPOQUD",
-    "GDRZ74PESLR7RV3WXFFOZTB4EGTNOXS3ZTHVNKPEJBY3FVFTZALV22RH",
-    "GDRZM47XO4SHONF2C2MRIISRO6HXL7H6NA2IWO32BQ3EBPRPUJJGTBRA",
-    "GDRZNV6ZMY5CRAKZUBX7XRLYF3OB6L2F6UXFQRVN2PXSFQ3VPNO5QPHE",
-    "GDRZTDIJFXE7WUJ2P67O3ET5FTYMJJ2KWGA32IPK7SPZJ67QYL3ZPXZ2",
-    "GDS22EG7EDZ43NYQP4F4MQ2XOUYC24XGI5NQLPQUMZ36ENWRGMWBUG2C",
-    "GDS2EASUDTQ5EZMASXV7IKF46JQNAC3WOYDNNSHRDRNADNSQGINVARQZ",
-    "GDS2JTCUKLKBF2WB7X2FH5BTBLSPNQANNBE4RMSND6OXDIEU7JCE2GN2",
-    "GDS3XDJAA4VY6MJYASIGSIMPHZ7AQNZ54RKLWT7MWCOU5YKYEVCNLVS3",
-    "GDS4AMVSLZCTGVEUH7XZZ2CHK64J5IKAOEWIM7YGJX56RWTBNP634ETH",
-    "GDS4B2JYEMB7D3EKGUHM6PLM2MIM5GD2W5N24PXYJXJPYYDI3Q6Y4LTC",
-    "GDS52CWMARVA5FCQRLAUCLIQOXUZRG2RN7WGUPE5VTST5WEOZ7BPXQNT",
-    "GDS5ACULE7RE2SGGDJRS3CK4YFZFR2NNXAO324TTIOWWPJBNSJK7GVPO",
-    "GDS5Z6PKGEX26ZZAE4MOLXIWVGBRAWB54BTISJRCVWVSVQUHVIUGS2KZ",
-    "GDS6KNXZJSDXXL6POLIKF4BCOJKIE475IPYD3CQB3WKFGYS6QXFUTTI4",
-    "GDS7B4WZENTF6JZ3MZSUINFN5ILFBWQAYSPHDDZW3TKM5HLPSG25K7UA",
-    "GDS7HSLNYOP6ETRGX7IPKBNKXLVHSMEIDCMJ5WSPOR46XOZATBUHQSHO",
-    "GDSAJ3SQUEK742DXMGJTV4FGZPLX5OEJ5UPPS6IS7G7CHS2T5LDHPAAH",
-    "GDSBDEQOJDEJOSZY3T5MMY4SPAVY5LD54SXZSEHXODLXVAH7Z6ODDTS7",
-    "GDSBRCZ2YIXJPRYGIK357ATFSNS2SIC6QGU524L6CLYI6TEZHABTISDA",
-    "GDSC5PPOGKNMB5WWE4UQRULGTAAWBZDWAJVVBNKWJSPYCI427Z6V2M2N",
-    "GDSC7TAUPTVPFIIJD2BVRX4DR7SOZAOEXEPIWVD2US3IHUGCNYMDAXLM",
-    "GDSCUU3KUJATQEF6M5PLXP543O2ITDAMAW2HR7X2A5AE5PC3IZCHGNDX",
-    "GDSDCJYXH4VRB7CXGOMUKREM5HU3EV3ECYDD2HDD5HJUVQWGM2MGA33T",
-    "GDSDFGF3UTZLBKHAHGKXXQILMCKOHRKFHHEOA5D5XBQGMQTTW5H4NK2X",
-    "GDSDMHF2B7DXBHLZI2442SBL7LTPUTCQFNVKGWBCB7KFNQLY7CMUIXRP",
-    "GDSDOCUFTQO5N4S7GJUAHTI35NLQ733VY4FDNOWY3SHKD77STDZ6VTBX",
-    "GDSEDZFH4I4QG6IDCIV6IAO2GZ2LN7BG2EDKLTRGSNTMTUZEIVD6WHIP",
-    "GDSEOJ6PDZ2GQPPPHBMLCT6XMQZGHJQYJMQMWICUK4GFV7YNCWPGCM7X",
-    "GDSFH2W3HMXV4UV7TZG7UEDGPJRV3E62SBGWWRV5JYAWI4XF7M6Q4WZE",
-    "GDSG574OD4ZR5BCPGBKXZC2ROAHIPVVCTM3LOWLTMDHZBUS22TZ57NOW",
-    "GDSGBDUQY2YILFEGTOCHWAMKO5WM6KJOLTGAQN3IQTGWO5H5XDKDP7YN",
-    "GDSGCXVRVKLAEORHBPTNR2RTFS6F2TC6M6C2J7I453ARHQBMF2MRBTCQ",
-    "GDSGKAZPTKBPKCJCR2OBVRLEN77PJMOHULKKY4RAUBSIRJD3HUYHOQO4",
-    "GDSGVFQYVC7H3LNW4PXUVQGTOAHHFIOYS3CCSLZTNQIYS3ZDG4GIV2EF",
-    "GDSGZTCXQT3TCFJPF4KGZ3DCNF7WQQYUNSJR5GK6FPQWAQ76POPJXHW7",
-    "GDSH6HANIL2JLPFGLKEPOMDOFFWIKCYL5S73CUJM7PFWCYB5WQDF7JSA",
-    "GDSHFSXCKWLFEU55MZURPYUYSNBN66LGRK2ZRJXE4AL3RD4SVEYKVX62",
-    "GDSI7ETO2HQLWKDJR7YFMVGEG7DUUNDLIY2EMD7NO3NIHWL72YDLBZYX",
-    "GDSIE6CNGUESDJRFYU3C2BWKPA4HJLZH5PYJCNYK6PC3VO6IAXGEHIEU",
-    "GDSIGYSPGI2QU6TLGFUWOYE4F5PKXFINV4GNQ3LAM5LBL2HHQVOTL6HI",
-    "GDSIPJYSI4FFIGJCQWAOLM5DPXHYYDJT63KIE6MUKRGQMMGBFFT5P62W",
-    "GDSISP4FREWE2AEEGEE3YV2ZJD562V3FW25F2V3CVVKDLHJ6BA24K7JM",
-    "GDSJAUF2XEVQGV5PG3K4GJOVGH37QTQFHBCQMF3NWE25SB2E4WAE2SSS",
-    "GDSJEF4UQOCP3QUNZD5466Y32INZKZSG56Z45MLGU3ECYOO2JHDMM3OM",
-    "GDSJL3S3ADQ33P6HGKSXIV3JRB7R7IFNLCKCNNIPSPSIULAHJBBPJWWE",
-    "GDSJMMWWN6E3N37LZUCWP4POIBCRBLMECMG6XDN35YXVE5QZN6W35ANH",
-    "GDSJZ3DIN7DC4R4ZUZK5BEGHGTBAQXZZ3T6RPYQDGCKOY4MBQUE7X4FI",
-    "GDSKER2ZGEHYMCY2MANEOAK2KP3GIQEFESD3DV5ZSIIRTQTQG25VFOHV",
-    "GDSKIVOF3UHQN5GZI36J7PSIZ4WZUCFFNZ5OVA7X4KKVM4VLV4DQJBDC",
-    "GDSKTCFVD3JBQCKSAEI3C2A3DYA7VBCCL7HVDT7Y37GGSIW3BRIVZYN2",
-    "GDSKX5XWPYJKG5NK2DUSBVX2XUZVVPOC5MLZPEGWULIRYFDTIU5PBRQH",
-    "GDSL3A2YOLOOADRT5PUGPODW4O7FQWAA622QJUMDTDEP7SY52ZW3NJ7O",
-    "GDSLFV67WEQR2VO3UVIVZBQ5BUQFLPG7ABALPFIJIXVP2PSCMUDMUK7V",
-    "GDSLL7PFZ4W666CQ2HWFO5A3D4TETAB5RVMBGROTTK7CGIDRBXLGSPIA",
-    "GDSLVEYO7TIEWH2QNFQEW777V5IJ54RXOQSNV7LNYF5EW2RMUTDDURCG",
-    "GDSM5WP7QLUHQ5WVKWOLNCWBM4WOSM5M7AD4FNG6N4LKBKEWIR476TVG",
-    "GDSMBXTGNXVAI3LFLPNAXJJX42LPRF2DN4DBOOO3PJOGKCM23NUOCPXG",
-    "GDSMLW5YZAOGX53QMBQUJ7DWWXOE37S5P54NCYVYOY4FQT4XSC4TACV7",
-    "GDSMQCE6P6OLYOTZYMAPIU5XWYHC4HCV3QEBRQXHBJ43PQGJUQLDS5SR",
-    "GDSNU3R4RJGOAMVTUA65YXVRYZJXOPIPFNTD2MP2CYQ2VJQLHO5WPJ22",
-    "GDSOAKGAED6U6MAB7I4TN5CLGZVJQB3AGIQJRC4JQJEEWVA62CG6SQXK",
-    "GDSP6KDDZWMKK6WSKLDBT676DOHBLXLP5HOZS4HU7UMEFBTJGFHX32RZ",
-    "GDSPFXN5J3KUNQZXX2LEVK2QJOQ2QAD4NT7SRZPJ6FV2SF7VMDED2H7W",
-    "GDSPKOWH7MNPTMRYKCLQYYRYKPIPFSYT65BMBZHMSA4OGEU7AR3RKMKH",
-    "GDSPTTTVMUFZF7XOMLGIRJ4MEZNCUZMMSSBF6ILENOUV5JOHG6THWRNU",
-    "GDSPUMDRTO46C4NYWS55LP6RN3AYQW7QZW6A2CE4OWS5MUQCJOONKJN2",
-    "GDSPZEKSGHDTGIB74UPQTDZMVJU2CRNJNSHJ24GVIWQ6DFHUPPFLWSA5",
-    "GDSQ2NHJPK7K3DEGOB443V2IGKKKBESK3EUPDEE2AOQCVH6XL4SFDKIC",
-    "GDSQ3E6IEKVTPJ7GRVSNZVJPJ5MO4XT26RYD3JPEPM3KUYPQIEYJTQN6",
-    "GDSQBVUWQVKZYOVK64LLWV7Q5YYTQGQO5EFWNUI2L2IBSXORWVOAUSDC",
-    "GDSQJUQFT6IXYG4WECRGB7DRFEGTYNQHEWZVPRSDG44XHP5ZXKYJ57OC",
-    "GDSQRTHB6JMKZBCZAPIWM7IW6XVBXTH4NQCVBGLUPRR27WUI7ONCDO2U",
-    "GDSQTR7MMT6WM4F3I7CDMXM55FASCQ52VP767P7F67NQDXUVM7677NGX",
-    "GDSRCV5VTM3U7Y3L6DFRP3PEGBNQMGOWSRTGSBWX6Z3H6C7JHRI4XFJP",
-    "GDSRKBRTSDPPYYDADBAJ3NXYJ5VRW5MJSBRB3SSAK7ZF6K4ZLUVMJR24",
-    "GDSSCVJSQOR5NNYT3N35LFJKX4HGAZFQXCGEJLBTPG4QPG5BJR4EUNIO",
-    "GDSSDQHOPWOSECPOSFGRP5HTRBTABAKVBOQV4YVZKARRACCEMEVQN2J7",
-    "GDSSLUPR6V6TI5L3J7QUJRGHFKVEVAJG4DLFJCFKC2HVC2WPVZFVZQ2L",
-    "GDSSSVNW5BIY5XGFSCRY6TB6OIPME3ZFJWXGOTIBRLJC4LVZNWW6DXMX",
-    "GDSUEQ622TSJVFAZKXOLVRIII7Z6NSCW5UU6IRE3SJ7SFI2MGTVDFK7Y",
-    "GDSUGZWGHDWG2P7ZFE7PXUTKEMWMWB4PWARUKJ2QAXHNU224F6BYTV2I",
-    "GDSUUMY77EN6SNQLTAFLERGQUY32767OL44F3AVTVEOXGQA73ZI7HBNB",
-    "GDSWASP67VIVDDBK2R5CY4MLEFP7QDHM3FHUWNUIQE4KU6SG4SOX6NAM",
-    "GDSWCBRN34AQDP3BHET2AAESXZOGWUXFBD4RZOYFH6PJK5BFCLFP3DJT",
-    "GDSWCTEV66ENIM4CDZB6TDPYJCFSUE5MFZR7XLV3AF53HGUKEQNXQBUL",
-    "GDSWNPXICKADELONRMATMXBYYKUWL3KICLBSOCJGNPJOLK2TCGQQPXHB",
-    "GDSWROC3L54S2BUFGDUZO2M2NEWYZULOH4QW2NESXVVMF6UBQHPELUK4",
-    "GDSWYD4AV5J27DBPKDQE3RQDRBKUPHSRI6VSGKSAYM3Y3FTNHAZZYOHD",
-    "GDSWZFCPXUOGCWULDCVWL3UXXWQPZXA4BHKJ3R65UDMJR7H6RMWNFVXR",
-    "GDSX463GE3T3IBKKYDUBJ3AD67HA2WC57H5KYIKTEYRCCPI3HV3KNOY2",
-    "GDSXHCMZ3FTCA244EKXTI57WSZDKOXVFXRSQ47CW5NGEVHTZYKMX2WPN",
-    "GDSXXU56VN2UMBQGQ2RQPRDLJYOKSC36KA3SRR336FJUSFX5WG7WPI7R",
-    "GDSXYYTN5O3KCD5KB5I7QM35UZZNZNI46RRHHMWKQTAE4EYUH7CENEXT",
-    "GDSYUV4WHN47YNZEQAUKH53JOOI3Z6Y666BACRBEJRFKG3OVA5SALVJP",
-    "GDSYYCAQW4FRUW7RZ6AIJP5GPQ7QLCP5JQPPR65QGERHPKXKODEMHK3P",
-    "GDSZAQCWKFQICJUDVXBLJHJQ6Q3KM2B6WBW2NLFQUYJI3RBITZ6Q2P64",
-    "GDSZFUCI5H2TYZUD3IXIUZY5FSSQOEHTUY4YV7VNKMP2AWZNJDROKDU2",
-    "GDSZMPQQTWJFUY2BSORTKO4P5FJ4DMBJ67FW7BH77N77R2TDP6J3DHGK",
-    "GDT2SLHYYN7CUSVN4UB7KED7UFTELZ6TRN5OJRE3Q5VVFIVYUSLXBGLX",
-    "GDT3766GZWLMN3UZK3OG5YBY6P2NL5VNHRC26V4S6F3KHTEPTKXCNALG",
-    "GDT3GHZTAEALCYU47ONKOTN7UFGDMREE7WIVS56BKGXIN6AYRTCNRPAH",
-    "GDT3MBLLYRDJXR43ILVWUHLXJ5LXKDWJBOKMCGI3L4MVMH3E3YQUGXRE",
-    "GDT3U6RG233S3W4W4TYB2FGY3IQSQW2DZBWFM2BRFSDGE6CJIUJCQNDC",
-    "GDT3UWH376KT5R7DISB3BMXNOQ7GEAO3Q7SQ6LXZJWB2HG4HACVCIUAP",
-    "GDT4KCFNWASJCZBV545E2H7C57MI7GSYIQBBFVWRWBHHBM4H2KQVYAF6",
-    "GDT4LAWPLNFQ6VVZAU5ABKWYT3WLTY2CLYB3NK6AGWS7KRIQD4KV3UMI",
-    "GDT4RLNLFQ4ZQN44GMRR6PJUFJ4F7QXKYQZDTSZC7WK33GWSLIIQCMPP",
-    "GDT4VIPEAHTGEQROBZ45WDLPGGHM5WABAPSUZJDL4AM7NK525BBLIEVY",
-    "GDT5BWSGHVYA7CFDADYRQVGX4JXFP5NZ5OHOWBHOTSKOMDAVRLBJ3QB3",
-    "GDT5BZPWG2QV4QOTGKT3UQRJBS5TNMJS5QDYL6CIATCLODSFKB3FQ3YP",
-    "GDT5I7SYJ7EMQMGXEBKXMPSRDAJGGQL7Z2NHUZDM3PQTHPQCA74NRD7W",
-    "GDT6XGVHE6HUDSFDGFDGODT5WZI2ODCATEPJL6F3TJOWGU6ULKAQYSHX",
-    "GDT73J5YY7QEXFM2DIIU736VGPBSGU3QTC23PLSDVETJ5WAGDTEY3GEB",
-    "GDTANDPN3VHCPY6V3FYFDQ4BAO7YHZYI2OQ2O43W236SUNDE6LRVBE5H",
-    "GDTAXWL3MKSI5MQ43MB3VKNSBALCFGIGFHBPZPONMVXXQJRJT3CSENII",
-    "GDTAZQSJGHLOYZOXHDTSHJITVS5TE26MCUUK2DHKVST74ZROD3RO3XRL",
-    "GDTBIHRLDFDXUQWOL5TQCZLNDBSJ6KZ2CYYVNFBBSAOZSUEKOLIXWQOS",
-    "GDTBSDQTHT3YAE64NSP4TW5DD7DVN2EW3FPPMNPSGCUE7IEARZWGE5IA",
-    "GDTBUZFXF6SDDKTTZP5XECL5I7XWBEHA7YBINDYYQS2LQWPONHX3FJ4U",
-    "GDTBV2KTHTO2NJQEPUSHAHK7U2N3NOPKAI7M4WQP33UZ2OFTL67FHXOP",
-    "GDTCAGQXRNRGRNVISDZXQA23XWHAPDKK4VUWIRWLLD5BARBEXS7F7M7H",
-    "GDTCEM6JLHGFT7IQYD4ZGRNETB2ZK2LNYUE7OGJA7ZOPXIEOYNZSDIIH",
-    "GDTCKSCOKTSCU77T3IMHTRX6VXY756KVFOBIHLS43UW7PT22S6TINTWB",
-    "GDTCZHJTWKBI3AM7XH2SMUETLHL67K7JJWPLTCZWKJ4RT7A6BCBC6664",
-    "GDTD6SKXYHOKWOSFU2NMPS4SIURAHR73Z5NDQ7RQBDFO3V2KG5SBYJ46",
-    "GDTD7RJGRTLEOBT2A53VW6STTK4GFUUX4ZM4253CZSXMR2TQIE34SMYS",
-    "GDTEF42FQYS2NVVGZP3BOWDSGZ276ADSZ43ZV3H2YUQHZ4BEKCA2YK4E",
-    "GDTENL6YQAYAYFXMBKXMYQA5PG46VDAUKOFAC6PDUJAZBZDTBYML2HSQ",
-    "GDTEPCJ5SBWEFUG7XM6Z5PX4RQIY6FXPZEF5FGTMK62WJBPGJKKCSGXJ",
-    "GDTFCMK5XOFKYCCKZJJNYIBB4V7MGS7EO3XJTDOL64Y3K42OMTD2D5VG",
-    "GDTFCP7RPTMT4X3LGTO6LVXVVPHN3ORLCFQMV3FOPCRO7MQZO7YPXNSL",
-    "GDTFUOCSX2GEQKPC6UAUCZ5JAYPPVMELB35SJMAVFJQXFTGXBPIZRRES",
-    "GDTGE43B2E7TFRPVMGOMRB67UYIO3LEFVRS4MDUOQT5EEAIY54TRTERM",
-    "GDTGWHQBFGWG7ULGX7Z7J6TGVWKEPGKSUA5X74GS3FYHFTIZR4I4AURM",
-    "GDTGXIWV4D37TSJ3YM6WECMKUNEO5PCUACDA2DXKK2OFBAB2DBFC5TY5",
-    "GDTH4SKU3TKQAC5Z4ZI6PDWQAPLI2TCIYDS53LTR26V2MY2PDHKV7LTC",
-    "GDTHOTBCBKM5DDPERKRSCLASKW33UOM5A5LN6X47K6OTIP3JMOMNJCIW",
-    "GDTHP66BYIBM5XIT4LK5O663MHMT45ALCE27KE4CYX5GSLJXPOL344Y5",
-    "GDTIC354HSU6BX2F6NQVSRTAV77OKPUAHXJR2OLB54T2UDUYCHV5IXDC",
-    "GDTIEVPB6TQGSLGC44UH3TMVOGKXAQDTZBWG5JBBPH327J5I53ICPGVO",
-    "GDTIOHOASREBON7HJUX7XBYVH7A5SLEFRI2WBQRIZL6PUL467VTYKJKZ",
-    "GDTJAH7E2A3IYTBCHOM2BIOEJKCBRCPWVXAZXIULUINWMJHCQT5IBXBX",
-    "GDTJHOMNKKT7A2GDPBDEWNK6ONW35OUYVUK7D44OHGJNCLNHDYMMYVBM",
-    "GDTKTWP4GBL7ZJ23T66EJA7HFHRB3FKMGZCRSYPBREFAUXFYMOG4IQGW",
-    "GDTKU4ZFGDHVY2CERDRNK5RJOUSH6B45536GAEZKQ5MI4S7PJ3DKWBBS",
-    "GDTL7IEERUQBMESTPE7APERILW27ASXV27AJAGUG4JYJGBF46ZRDZIRH",
-    "GDTLTAG32D4QIS5OOFRHUFSQHZXQ4U7WHFAXF4WBIXTLBQ2E3ZNVWGGY",
-    "GDTLZWHW3GD5IYMJZ4HHBGQ4RDLOSHC42M27YWKPV2SZ6IF7UCENVPCV",
-    "GDTM2URAIICANYOI4466ZC36QDALQCQCJH3MT6UKP4LJHWPFLUEYALGO",
-    "GDTMB5JSQRSS3SPRU2SM7WLZ2K6CXECZWTY5CKLWBI6ATSBT4VEKTIOW",
-    "GDTMYCADN4NRLSR6KCDTI7UNJEAEM6476OM4BVO7FVGSQ4RPLU6SNODE",
-    "GDTN5VIKKRIYFJSS3ARZBNECCCBQBB6LRNJF52I2SMOY2ORX2LEE5454",
-    "GDTNBRXC3JDYRHFXXCGJIODP76ISPUBN6MUCQJLB322CLWT4VWEWZASM",
-    "GDTO4C67LWUXARRPZH5WFWZLT4GPLY4QZELJDXSRX3B2O62QDGDE2ITA",
-    "GDTOE7U2VRGBTLYL52WQZ3DHQ23EO27JXJMJHZUINAF7TYEMPMQ6DRZX",
-    "GDTOL6ZR3ZTAXU2MPD37YQWK6SSOTSOLYP2N7HZK64CTTAQWNX7HTGRZ",
-    "GDTPOGJBNDTWUNCVQZ4W2T6CUV5Y7KDODLOLMGB4WKPVVGBDLSD3E6HW",
-    "GDTPSREHNHWZPP5XPUW3B34XY5XH72PPF4CGWGZMNDTMS43XVOAUHH6D",
-    "GDTPURKNV725ONMTDP73KZZDTDP2LMJSKARQEHNQFTTSJWHK4ZBMBSVG",
-    "GDTQK3WSMLC22DWUZVOLM3RDAL34VWWYOKXXIFJSIQM2KKERAC7JC4O3",
-    "GDTRC2N4VTH5ZVYYJJQVHH2LHH2GBCSBZ634KOJ6OEO76ZP3BLKSV23M",
-    "GDTROCCKBZHNE7SYHY7IP5IZI3RSNLHPNBU2XOSMP3WL7SQO4V5JVEEI",
-    "GDTRP2JHLGM4BF2N7YGTZP2HR5EXMOXD4GCHIXNZ2EYFMJZTRCMC5ENH",
-    "GDTRQ6TETA5Y4LK434FUHCCZVT3S5PWLL5FYNBKOWZI4CETSCPZMLXLM",
-    "GDTRWX27BCLACFCVQEWGGXBXE5VNPBZRQMK75NNKYV7NE3OG2UHXWJDB",
-    "GDTRZFFOH3S4YXXTQANVGRZ5FOKVSUQQEMUR4LAJL3J723CRPP2GB5KQ",
-    "GDTSDVL7VP42LEFOF6F6RKFSHW56FXKP25GFZ3JGKMXKEPIQIIFI5PBV",
-    "GDTSL7TNRDVEBYDAZCBXZY2PNOVSGLPDNQYTEDVQLOKNV6O3SG3VJ55C",
-    "GDTUCVR6JWYXJDLBM6ZB4RPO75G4MM4NTY23XRVKT6D3ZAX4QBPZEXRP",
-    "GDTUMK3EPJWSXJPUWQ5PWSVOTI7SWHEBXCN2DBBV4DRH7JD3BKZFLEE7",
-    "GDTV7X7AKCZGKWL2KUPFXSEYU6INRHV2PMNLGINNWLGPETCC54RDH2ED",
-    "GDTVB5W6BKCAW5NDXR763LASGC34UPVMAPLVF7O4OOEPXTY2Q6LKX4JM",
-    "GDTVDFG7BH736ODJD45KXYT5754RUBCXMZBBDLUOTVLMWW4OEUIB6KCE",
-    "GDTVGV5B4NTAJCDK25SA5BREAA4SODNVBPYGTWZJ5GZ5B3OSXN5KIC4G",
-    "GDTVLYV7PP3PLDZNIB2DQQLAMI5HM5F5JTPZOTQARJTIGSOAVZPOA5J7",
-    "GDTVOKBSRWURUD745QGVDUADSWJBH6C3QD6KBW2ZNUUGPSNFSSY34ITQ",
-    "GDTWDUGHEHU2ZJ7JJGU7LLZUNQ7HE4MBZRKETHP5SPHIPTSJHW565K2H",
-    "GDTWWYSVLF6TTR2HZGLZ5MLWVKBBJRLJ2GK6ZZQMQUISSNELP5DQ5Q34",
-    "GDTWXAIGPUVGZ5NBJJXZROQOQR3PYR2ONFMFJ2HOFXI2NLDOYJGCNSC3",
-    "GDTX7E6SQLHMNAQCQOEXOLTG6QKPBZLWFLG6AOJVW5NSHAASANDBPYYF",
-    "GDTXHSSSE2F3524LNL37RKCJG7O5PNE4TJNDZ4RT4WWTBZNI43AOZ3A3",
-    "GDTXOTOZCKUUUZ73WHYKFYX56RMLTVWJ7H4PJKI6E3B5ER2XE6L24OZU",
-    "GDTXZICJHLM5LF337NQNQMRX45FCABEVVFGORGJSPQQXFVTP3XEKS2SO",
-    "GDTY5UNPU7DP4WIZMHS7HD4ZQNSGCRHWYNOQCRP26K2XN34EXLXC7UHS",
-    "GDTYP5ACEAYDZVF3J3ZRDQMOWWCPH5J2JYGKJVVKNDOUOJCBQUN6IT4N",
-    "GDTYVWZ7HVTSUL644W2L5DPNEJKSBKVK435VMPIWCQH4HVTMVUCGE5JJ",
-    "GDTYXMHCNDE54Z5AHLJN5ICI5TQ52UZI6Z5GM6CGLMS2RBKVJEVGPRAG",
-    "GDTZC76ZKSHKI7LMQBJFBXKN6MXQLWRVDDL3BYV6JJPJP54J463NYI5J",
-    "GDTZS2DNI7ACJNLLHNWWQMOMA7TP5MERBJYBH4L5AXTMH2CEM4H6WXO2",
-    "GDU24ZXIGV26XQODH7ZZS2K5225ZAICVSEW7YDUBCLGAM2AQMASKRRIR",
-    "GDU25OT3HGCVUMCZDU7YEYEFD5Y7JTXIPA2FXBCUPTFEXNRIK7U5BQCV",
-    "GDU2FEL6THGGOFDHHP4I5FHNWY4S2SXYUBCEDB5ZREMD6UFRT4SYWSW2",
-    "GDU2LLLAFOEBMCKTJZFPQQXQ2XFWULNQZ3MF4WAB466EP643QRN7MIZJ",
-    "GDU2QEE7MQ7OF7JW6VYY274KB3LVIG6UBGM7HYW7O6YGT2IJJCAJ4TUS",
-    "GDU2TCE2P52USPJJF7VT7NNV2AXN5244OHLY5SU7RL7NYWHWV53ZIHRM",
-    "GDU42TUFTF5WODIE6U5RJ2S44MHWJKQ2WTI57UHESEFIXBSMJ5VSBCKT",
-    "GDU4HGVNDP2XC7OPFL5EWSLKSCKQ2BF4PF5XLTYA3WJE7IUL2W3RXQGN",
-    "GDU4RZPS3RNTLX6Z4OR2LSCFFUCB3JRC7IGQ2CRHEA34XBJWKXIZNMCA",
-    "GDU57HXF64YGRBT6BYN373U37QMDHFOVUYQJAM3WEUZKYXKJVHNRE3YB",
-    "GDU5ACJTPJJOBQHZKHXXZYWNNXYVX3W5WJ65ZNYQ6EUGNSKSWTZ62ZJZ",
-    "GDU5ADGQXJKT4WUL6JZLDMEE3CFRD47CUSIPSX5QMCZXI2K5YDVQL6M6",
-    "GDU5LZWCO4IPCYHF2SOQZFEU4EOHCFJFKXTRWVKSA2RCUVG3QRE6MF6I",
-    "GDU5NJWUJJCVZ25ET5ISGKZR5HQSUB6ZA6KOQ3474S3BS2LUKW7SSSXD",
-    "GDU6CJVCBWL4WPFKOXQIIRNTNO4E5ZFQSM4DE4IVILTJEBMSA7CWXW7Z",
-    "GDU6XDTL4ZGBFW5SL6V2UT7MPPXOD3NQJ2ZEB7YK5IVASTR3HUX77JKV",
-    "GDU74DQO2WOLTDMUZBN7BHLS3AN3BJPUB53XM226QIDRMUJXGSRIN5ZG",
-    "GDU7564YRPTGAU5DOLNWXJ323QGBU7OFNSGHXTBTFICQSRE5QY4LMMH2",
-    "GDU77SO6ATLPRGIELE7UMEXS2IS3O6OZK4XZOEIJSEVILJ4UVJDPLOMB",
-    "GDU77WI2AU7DUK42EMS7EINJ747SSAGAGN56ZRR5AYD33WIXWC6RO3LP",
-    "GDU7FZSZGCRCKSSS2TMGHI67GDEWMGOD4T4YU7AZ25KI36MVEQ36455Y",
-    "GDU7IJGWGSDJTC66CKSUS4PZZIQSLF4CT2RNHULGXFOIQHOZNRAUUCOF",
-    "GDU7SORZ6HL75VEHQQS7STG5DILY2HAXDGMNIZZ6EPB4C7RFVP3RFV4Q",
-    "GDUB6PRKP5ED66M3IHCTXU67QDLPDPARQRASIVPDMQLIMXI25GOEXA4A",
-    "GDUBCOLPL3VZQNZMBLNQCAWUOPENITLZU6E5TETWJPQH2JT5EJ7IGLDF",
-    "GDUBCXN64GN7YNXYU5FTBE5574M2V7ENPPHG3V3WVBC7KZEP7KBSWISS",
-    "GDUBDS2GYJKB5ILD6JCFF4H23HNIFKUNO6WYOYTOPUQ27POQUPAGWOGI",
-    "GDUBEONZVYWF7SJKHSGIAWQNSQRWGCRADTSNAAF6EUJLUPJEHRTJPPOF",
-    "GDUBXI4FLMGPRI3KNZ2GGK66DVO6W4CMZ27ZWVCGPGAKIUK74QAMCTGX",
-    "GDUCKPLSEJWENI3WSRO6UQK5PQPARLFHW7QCTMZNJ7OE4WIBTK5SKIIK",
-    "GDUCTZEHPNGDCX2NBUIKTEWUWCUMMSVDI7WBDWXPJ2DPLXQMZYWNHBAY",
-    "GDUDJQRE3YYTU5M6GPYSBL5E2D6UQ4KP3UBDTND6QRNO5XN2VY2FNXI7",
-    "GDUDLYZZ5U23EVUV5F3SPZYYZNLCLXZWPKZFRVOBSKJRA4W6ZE6V7LS5",
-    "GDUDNR53DG2LS4OHF5667J3TK2RUYAIL5QWHCRHEFC2B764J7ZCGJRFB",
-    "GDUEBAIU4UHSJ2U4V7KOGJ4GNDCECI27QV2JA4UZ6C6J6IQPIULHFJF4",
-    "GDUESBPL34DITW7WXGRWRTDMB4ANNJCYKSKHVPFOGJWCODXQDZA6HJ3V",
-    "GDUEXWPHWEAOMBORSKUS4VSVA5QNHFXAX5VJ64SXUTWSWFLLGUL33EXC",
-    "GDUFAMVS2HTVURWREHXF4ZNAXV7SGKS55BBKELZYPMPQKRJEZBF5YJIB",
-    "GDUFM6KMACREHKF3VDCHF56K6QUVOEJOKNJ7L42TZXRONJLG664SQMNG",
-    "GDUFQGHWK32HEYWADGF3RLZRGZNP4GYB74VBWZEBYBIPA3V5MSHMK6Y7",
-    "GDUGGR264PCRQGY52KCOUFCCCBENCF44B5PBZDJJL6SJEI5OY22SHU4Z",
-    "GDUH3ND5A6KEWXK5D3CF7HKI7RMNU74ANKK7WM35J5EVDPDGZXIXZQZA",
-    "GDUHIQQFYL2UPTIMI2NFXS3JLMJP6N3OXC6LFMQX32A6GVD2GB2E6INT",
-    "GDUHTN3E3NS5BXYIF5KWCWHIY6ZEWMF3MFCFJL7X3DV2MRZVNELOQXLM",
-    "GDUHWW4VPSP62VQA5M2R7CB4EXDYSHZQDF3UKLHJ32IBDP2PODBM3BZO",
-    "GDUHXG4CE3UIOBY3BMUDNKAHBK5H4WF3DMZAMRGOBV57OULR2Y54OX3G",
-    "GDUIJC5Z5LKZ2D5W4WYFLYDM5FQ6F5QKWYTMTPCWMCTGITQEVJSBUX3Q",
-    "GDUIMTKBM4QMOY2UYYISBWD4UMPRNNXEHHZPLQSDC4FEPIWZSIZJZZVA",
-    "GDUIUEWRJVBY7BGCCNU6C35TAFARVN6KGTINWI4PUNFXXAQL5LJUGULT",
-    "GDUJ6SEWISSPR7VAMCESEH6VUGFDGLVRHQDT6ISATYJZUQ7FSXVHNXNM",
-    "GDUJ6XNFQVNTDHN3QT6TA4VOLE66AB3L6RDD7ST3LPINMIFERC52UGDK",
-    "GDUJGN4YMXWCHMQSEPFKDT3JYCWFDXNLWLYD2ESEWLSNHGZ6ONWTISEG",
-    "GDUJTK7O6P2HFELUOOQNKZ2Q3BL2GURLVS2BOPAXD74QZPH2HUIM4RNA",
-    "GDUKD7TIWU3YN6SGCBEVHUS4WRZPU76PNEM64O7S4KXVNHWLEKEZXOIX",
-    "GDUKI5Y635ENDG2KZQUEOZDV6V7IPV2W7BDLP7YAXJ7PESNWVH4ZEBTC",
-    "GDUKUGSJ2QBXLDEFLF73O5UN6DUJ76GDJ65UXZMF2WDUUOD3VSDROPTI",
-    "GDUKULSLQGSVDJW2PS3IBSBTLSH3OS2SJ7KXBWRKBAXMTH5QZXOURD5C",
-    "GDUL7W3EEVKHLBWTZHM6MOAS3K5WTIW5NP4OZ4YEC4LKA26X3KQB7UNK",
-    "GDULN7MMND62ZCP3NQA7ORZBEFBF273BR3EDN6BLAGQ6JAIKYQ5YR6ZK",
-    "GDULR6MAXGQEMLPQGE5PTY2GMR7JFES2C72IWVGXWFAYIUSPLZU5WKPR",
-    "GDULZIBTXEVRPSWXDFV6AFEPBVGAU2MZG7QJF7U3P2QJSKQMEVC25JHC",
-    "GDUM2BEV4OOQHLGCDMJDZBBKE46INOHBHP3Q7GGTO2VWZDZ36PGDDE5Y",
-    "GDUM3NZCSO5A2BF6T3N7A3LTMM2ITLXMVY3GG7U5ESSHNPH22I2LU5D3",
-    "GDUML46BYQZP4HVY7C2LS3JN2HGQGQPF5AYVP2YDBWVMP4B47FNGUSDT",
-    "GDUMR7PIZELWCV33RPQBNVVRL45YXDQKJ53OHL2IMJAVUS6SDM6H7CW6",
-    "GDUNEZLEMICCMJXXKQW7A5HTNENPPEPMMOSVUSCXFVVI6U2T7QJCSPIQ",
-    "GDUNXRDTZDZPKZJNH7B4AQSLUZBBSFCLCG37PQ6ABML6TJ3XY7AEHFX5",
-    "GDUO5ASQZD7EYGM4D7DAZCZHRIMEMMRXDJEISQIK3TF3765F7KREGLPD",
-    "GDUOCCXQA62EIVH4GNS2J6DM5AK26BQ7HAA236GDJKY7LNW44KFJYWYO",
-    "GDUODRAKY3KRJC6OQI2IDULZHKCKTTGGLLLFGWSWDSH3MLXUM4UWLWM5",
-    "GDUOFNYN7EYBAPFWWYX56ESESMJ6DHYV67VCMTEKJVXV6H4WDRGONBKL",
-    "GDUOI3OQJDLEKVKGRTEXQDUGQVOQTZMRLCU3UB5Q543DIPV4LXIKFAYX",
-    "GDUOJM3NDQGQYFSQPFSJTJHOOSBKT7XMETYXQXOJW5NZDONAGBGRZSMB",
-    "GDUOJNWXB5NPOXP4BGGGFFMRSMGNHPKIVQWUAT5P35MSHONBGINABARK",
-    "GDUP5EQ4I7GBJQ3J4X6EELWKLUJVI5I4GTMXG4K4MOXEBLH5MPR6W74J",
-    "GDUP6USTKSLQQ2DSUI3ZI7KBVVKWUJYBADQQWOQ7ZWUVUBMUTIXL2URU",
-    "GDUPMV6NJJKNFETC75XBZA4HTL4M6JHBCLSDW2EROUYAWPCGTV447UFN",
-    "GDUPZRRSALKO4LRWDTLJYICS7C6KJX7YDJBGVHOYUZHQR7KKDNMFLARE",
-    "GDUQH4YG35ERO5HN2AIVNJMYBO2IYVKA3TS4VKK6Z6A7IAZR72A37WM4",
-    "GDUQT7PMGKUQTZLIGWTW7UF3RGFSZCEMPG2SNDJ2AGQ6QX7BXEFNRUY6",
-    "GDUQTHTVYF6P5XGZ72IOJ4FAAGZSGP5J6FLCN5R3EQ22JJHVTYTNCDXH",
-    "GDUQTQQZF646N6CIEEGIKA6DIWIXUERIMILLRB2IG7VW32YQ4QZSCQOS",
-    "GDURHAE5KQWWS3DXHW62BQ3HC2LRNUWWHCQPNT4XJWGY4WDW7LGSH4XV",
-    "GDURO3JASRPOTNF4P56WNN7WNU5CG33HEYFRX3Q7Z7TRKXMZQGW6QXDC",
-    "GDUS6JX7XMKNXMX45WNXGN5THHLTAGG2YZ54OKIXW6YKG72SHGVCQ63H",
-    "GDUT4QKFGA7SRHO2R4QNSM7GGU57X33UITHXUING5FSZEDCGBXIF2FJI",
-    "GDUTRXXXB2B3VSOYSKBKDO3ZNFXCF7YG2BUQGBDETAEGSBMZ2NGZ5MZW",
-    "GDUTUQEB333UVHVZH7CLLKDXUACIZX6SCCNNYW7HCB65PH3CG3HSWZGA",
-    "GDUVB3R5HPGILSYJB4IW6NNQZL34TAEMWQXKDMYMYTYX2E3WAGYDF7NC",
-    "GDUVC2PB5ZZNDQUA64KPSLXMGR34WHE55B3OXEAGK7XV6Y3NRXZ7OTYG",
-    "GDUVHMSUO3VRZLVKEUQUTR3ERTFQU5OWQKQ6PG52BX73AZH2LYEVZTJA",
-    "GDUVI7LWMPDTNDELPFQUXN337JCJFAE5Q6UVVYNSZA64FCK75TKQ3IW6",
-    "GDUVJJMXMOHBVIU6UCDHKIHLYINMQBVUGPRGG4LR5WWOYIRMKTCV3GSC",
-    "GDUVVEJF55TCJN4OCPJYJLT6KTZ7ID4PTMEVWDRFIV4PIS7SD35VOR4R",
-    "GDUWOMKQD3LI7DMNJLMCH26O7ACGC6BNUPMVPUMMVNIIQWJSHDBIPGUE",
-    "GDUXM3GZWAIM6SKQA7AZ6C2ZKAM3IAZ6DD33B3BNRMRI54J45GXGKMSN",
-    "GDUXQBHOEHNZRCCMH4LOVPKQ2INK3554OGY3BSRLZM77QTJMT3KNLXDC",
-    "GDUYDBPBC6QN2M6BIMIOPL3J7K4EWVZME5L7YAYRBPPPWNMR34X2RUSI",
-    "GDUYVAEYT5EEJJEMFYUOAJEVKVNTYO4NDZL3WX6GYV6AGF6HXXJF2J3T",
-    "GDUZ2L45GL4EFQFMZDHEXOZE4WCRI522LTVVLWGIMWRWIMPVFPAPDBCV",
-    "GDUZ6I73WUDRHM4SV2SUWD42XCS5LVZAN5JJ4H7BHULQCOIW6USDYCLZ",
-    "GDUZ74WKPFXRZRZIXCS3OSHND3YN4BNQ7EIILP3SVLLMN24Z4AQB3LXP",
-    "GDUZAQOOSHNOEJH2IDWA4MNCLIPCGNBLX3QT7A3IMAWEB5KT6PLLYXLM",
-    "GDUZDTU36MREY2KYUKGKZ2DLF2OISSLJZ43MNKHGIXQUO2GTDVLOUD7J",
-    "GDUZNB3MIN6SEE3IQX65LJTCNF3DATRVS6EGIEAPNUSR22DEUNFKPIIQ",
-    "GDUZOZMSEJR4T7WFBJI5LKIPITRUZXEACSJNIHVN3IPJJWGB5MHXDPTW",
-    "GDUZXK2FIGNQIZLSMEDMKZLPA3CXW3FXEGU2GSVPO6KOR5WVJHHOQUSA",
-    "GDUZZYXCULTADYHZ3L5O5AEQCL7V4NMJF3VNADDHFQ7KV3JVB3WEEJ3R",
-    "GDV23D6HLCSVXWBE4CHT5L3PELLEFNV4XB5N7USYA4BQDK54PPUI2KBL",
-    "GDV252BFI3EPUKLKA6QJT74FEPKXBNVAUXTXALBTOHX5YYZSWGFZSSM6",
-    "GDV2KNMUMOIJXDLUTH7X55KDZUJ4LLHYGEMPZ3BGKPNEWNWXXGY76AAI",
-    "GDV2R2MOB7WHLF6BP424U75JYIHLQ3JL3RWNSI2UB6QFZIX5XWBD2DVW",
-    "GDV3OKJLIJ4T7UVJMHXC6WEV5SF6O3W6CC4PX3QZDY77H2RQE5U2IR2V",
-    "GDV44K4KR7WDKADVHSBEI64ATJDYWVHUXFONGNJMF2RFMPRC75VYRP3W",
-    "GDV4E7BI6EH3MD4RV376JAKV767KUGFZWSG2ZRJKK5R7TRBRDPQANQPA",
-    "GDV4K56GDXWKBYJORWISJJMSM2R242QZ76Z5OQAVVOHCOV2RWLSTTKG4",
-    "GDV4RERV4ATVBK3QAJC574RO226BVEPUCLTTSGGJX6RGBMQ2TYIWFHIG",
-    "GDV562NFLU6ZVDE45WI3WGKRFCHMURMN2DEKUK4WIGPIY56KSZIF5QP2",
-    "GDV5MAXHAE7QOIYYY2PKYFCIPD6NVFESRP5KJTW2G3OSQHEOFRFZSQNY",
-    "GDV5P2H2ZUF64OWPTNXEFOO2FWUWPWX7SVESYEDT7CYXBYJ4O3ZQDI6U",
-    "GDV6L3KHWGYDJVUYOLF2ZN5T6YA53R3XXAIPS2QWZRFWXNMFOABQ6T6M",
-    "GDV6LGNCDHXWOY756QP3AKSKCSFL5KPH7DH4X3HVAIUR44JM4GFSJNDA",
-    "GDV6QROR6YDUXDENJCEX7ZC5JVPEZ4HY2ASRH3OUS7UNKQPPWW3TEYYQ",
-    "GDV77PYZGOLLFPNFGHODKOKWLOIZISNN2UDSZRFMN2H65VYKEZNPOQJX",
-    "GDVAG3AXJUWVCBERVSREHG3ZIBMZ2ETZJ3RYCOYCOQIC7YUYQS7AR33Z",
-    "GDVANMUJNURK4WXYTPPBJQSWTWAX3POJVN5GH3EQBV343YNL6BU7AH3Z",
-    "GDVANZP5HQLQ7IOHAG5QMZFRMYEPGAZGKPNHALLXRUWCRN4PKBHPAR76",
-    "GDVAQK37DE64N2E5742BOPU75MB24WVMKPUYZOV6O7MVCOGZRHRAUSDC",
-    "GDVB5XC37JP2PPOC22AOHBJNOKXI5WMRFSJXXB2F3XLL4QJBYOPW2PAY",
-    "GDVC4DPQOTHKJU56E5K7W2RXFBEWMDTW7VUPCGDGQCUEUBV6OC4J4OIA",
-    "GDVDZIEAVQAZKX3KGIEP25HFITZJVGSPJN2WL7MOOF2RXVDBCY6CUTRX",
-    "GDVEN7E2UVI6YNHTU3Q2V2YLRE6R2O4MBUDZPNCIR2OWRQF7HBGIK3VD",
-    "GDVEX4PQLDC5JGGGBSCPAC2UKUEYGQ54L3GH6EFN3FDXPKX4EX6VNA4M",
-    "GDVFAWPISAN3WAYWGMKPS356SJ4IEWCJJN4DMIULA7X7A2F33TIGESGB",
-    "GDVFRXUDPPTLO45RUET3I2RM5ZKXZ47XD24HDH4T4CM7VGVNFBCOTVY6",
-    "GDVG7XP44LPB7N5NI5C7FYAYC7OEAYTLMQLRQGG5VYA3P4L4B2PDWHIP",
-    "GDVGK7ELUHI7H6HKUKQ4HKBXQL642JH75KBHE2WNZEKY6AG7IECSDVLT",
-    "GDVGM6GAHRSUEKRYPJGBQG57WZARUVQSFP6ZX5QZELR2BFQPFYS7HQPN",
-    "GDVGWYJGPYEPC7LAIJN6XREW5QSROJFRNYDH5TYJ3FGGEVOAUMD3VULG",
-    "GDVHA6OOXCPYXCHAMTV4T7ZTML32BXQV35UQ5ZVJJFDDDGVX4BJX5QBH",
-    "GDVHCGLOWVQ22B2HZAMFLIICCSVZK6KAJDPMUJ3V6PMOJDMV7ME7T3SK",
-    "GDVHY3YFEJHUUPFJEETRUQVAPYJ6WBC2WDIVXFG66S6I6PZDQHZZWM2K",
-    "GDVHYPEKHYZOPPYYIKXKHQXRSMX346CYJAWBHCR45BJ4FY3KOYGOWUHP",
-    "GDVHYYQZFNNYCMAKGL26TC7VPBSOTEOMGDX5K6N6JKDNYCVAVD7UB5KC",
-    "GDVIUTG7YU72RQ4OBESZTO4N4EKSPFHTXUCZEJX6572DLZTLTOCSBMXE",
-    "GDVIVPENQOYELLZOCMBKZS2KRBJJMEJCYB6EBAJOLH6WOKZMDQHEUUYL",
-    "GDVJGLU73MXV7POTEVGPHS6E5CB7SB4FNBFIRVKVVK37R5NGCYU576N4",
-    "GDVJRDYGUZN3QIFJQXRS55UUIEEHI2PVHXFWGUFMEL3TIXOBBYR3XRVN",
-    "GDVL76JOVQM7JBNKJOFZG7B5U6UIORH5PCEWI4J7VAMPX5NIEWBIK6AL",
-    "GDVLH5F4GLBZXXPTWYHCZPCI7S7CDJYMCFUYOJKTWEUHWMGZABKNHFRX",
-    "GDVLJHN44CCYQDNA5V34SMGKUDUR6IKD5SMF7Z4S6P4DSMED2FB2PEN2",
-    "GDVLS4VH2XN5ZX4YWYCUM3TQJTMM4PB2IZDL555LDDCQZU6GFJDKVT3F",
-    "GDVLUKELY5734N7777WVXC2NK52TFJFS7YLXL2NUFTYLWUQ4SJO45ZZ6",
-    "GDVMYCR2Z3QW2QK4FRAI3YYMUPM3GMRFABC2TMXIN2XHMJAWTZKC66QV",
-    "GDVN6W5LERZT63IAACD7QJATBSW57UOLZ6CBIKGL3SOHHZM46KFT7C7B",
-    "GDVNAXYI532JJGE2SJIGWIOCGM26QPFZ6LWGCM3YAZUWNSMIAOWNTY2U",
-    "GDVNYMCNHHKPUORYMUEXNPK3PED2WOETAR3AGOEN47IISBZTYCFQAF6H",
-    "GDVO3GQWQWIL35MBQE4342YNB3R3HX5R4DGFGVBU3VTK4QJFT7QR3WR3",
-    "GDVO3RHGFBSVWZ7HL2Q45JNRN6255LA67PVTAO4RWGH5OBIKGVEKGPFI",
-    "GDVO5PI736TUHLOTR7GB6BLBWHQ5QQ3EJOLCLGHH5V6YROMOZHNRBAYT",
-    "GDVOPMX2FLCGXYEUPFWB5W7ZWEDT2MKXA4PDLZGNW47POPLY2BTM4W2M",
-    "GDVOSWHEHL3IKKSLZUTD47DC2GYPYANYM3T43BVIJ4IYNKZO7L2K6ETH",
-    "GDVPKB5ANOAOGD6VFATTQU6ECIT7SBSWPUKJU5H7XT2IZ76OL46SKHRN",
-    "GDVQ5LRGRYHFNXKFPVDT2KNUWUWYUGLTXB4KGAQASJ6HN25MFFGFZ4NG",
-    "GDVQE3LJOGUA6OR52QDJSZRQCI3INAEBTGDE4WHFOOFNNOJDEOBCBE24",
-    "GDVQJVAC3GR7W3T373H4LWTCJUHAWLF3O45X444FRIWUTMRZVSEKWM5L",
-    "GDVQLS4W5G2T7QKDNSGP2Y4UQXP6P7NCF5UNZQCXJL2BMXFPECN4GBAP",
-    "GDVR5OJNIDGXW3S7G7JTMXZQK7JIF2VC5KF44M3YZFDDE4TSO7FBS4UW",
-    "GDVR7IVJSNU23Y647ABN46FOCF6MAVR2JVWN3NQ4J7VQH6BYWBUUVDTF",
-    "GDVRILAEU4HXOH5LCA7Z3C7DMRCBRAD5TQ3WUYFVTC5X3J724I4XZO7D",
-    "GDVRKGOXY7NPURZONAHJTOCQZ56SBAEVM4OYVLVPUHIK3L5KBNWPXQNT",
-    "GDVRZI2IQUWCCOMIAWGZA4DLZNYYS7TAD2G2EHYFSGP3LVOVRCJLOBBJ",
-    "GDVS3FWAPLICDUFMA3YIYFWKRTJTE43ORKAQSSZSC4QLXEUY2XYLBQTR",
-    "GDVSKOEVBYTMDDQ27OL6X2ELEIQYVUXE3LDO3LB5DCRLXTM2N7JHTWRS",
-    "GDVSRNCW6D476INUXYI5QIXCJ3J4AY5RFETBJKVHARSSOE7AHP5PDYZL",
-    "GDVSTMOKDOQ44LPUAFZDTZJODR6S22N2GPHC4E2FD52YNW6TYEUEBOJH",
-    "GDVUDCM5XFXOUZ3OWQLL6BRJU46TBEUESZ37YSNUPAFW2SKB2Z2CUSDC",
-    "GDVVFGNIBC2ZJ6CJSSDNAUQVZABKGORR6GS3MAOJPULWSNZVIARBSBWN",
-    "GDVVIUKD5PABQSYCSK6MOWAVLPYMH2LWIKSN5EBEBY6DOJU7DNMJH5EH",
-    "GDVVJ3EZBSWHLAXY2V6H7W4PM7G5BBWRRXSMTSZOFXMU272XUEIMB4JD",
-    "GDVWCEHE4DJ3Q2S36G36MMUBOCOR4MTUDF3YYS2JOSD32XUWZMIA776V",
-    "GDVWCVZAN3G3BKA43VGUZT3IQGCF2TVKBDJOUBJUEKCFMVK4A6IJ2YSF",
-    "GDVWJRPJFEGQS6LLZ6QO7L4HNPHDHK7LBKLPNMXCTDZ5HW7PH5FF5AXS",
-    "GDVWO64ZVNSYDNE33GVUPBMPOVZQOIAX6DUF5XOSZ7OADTZCAQTHEC6T",
-    "GDVWPEOTJOPRT433PX6LULEPML5AN6QJO4GCFVFK43IPRQ23NPQAUSDF",
-    "GDVWQ4WQOSWRNA7FU7HEWWMMKNBGY666TZNLQUAL2FLAEBLOY35LFVRT",
-    "GDVX4KEIYNNQSOWGRFQ5VRK3M6JVALV7RCZPGRPFP4CPUDKO2I6CJ64M",
-    "GDVXKTTBDHSYA4PY3TSI4SSVGECH4SGL2VCOYWE327HHBBMFQHSJZKWQ",
-    "GDVXPS4GOJHP6CDIKT6M7L4CJDWCUHBF5U56CPH4EGPNBOSLT7PFGC22",
-    "GDVXYU6SDBYRI7H2WD6YMWSNQIHGQLALYWTVL6NCJB6SOFDLZ5AKSC5P",
-    "GDVY6I3OTHOEXVCR7O3SYDGVPDVJPRWU7JTNFBUA6AKK6FROX5MY7SBI",
-    "GDVYUDLKQTOZGBX445URFSNJQPLNGMFAETQXQ3SE363SPXJEJOIU6T3F",
-    "GDVZFO7CE7UQZOEL5JM6BO6RLNRWWMAEG6CKVFZANHNPYSCWBDVTDL3T",
-    "GDVZHHQR5MCQ2QZSMQ4QTKAGYUW7CLAJHK42R6VRIPTBC4AWJ2DCCTTL",
-    "GDVZIVKXEWMBP6GH3DMTLXTK7XGW6AQP2OANFJY5TPEWWWNGCISHWCCH",
-    "GDVZVUETY5XSXEVPWVHFDEZ3YX5A6VLAQJCN6XBRGPYQFOGGHAJMKZZQ",
-    "GDW2F5Z622SMR745HXVFEKOFZ5TYLJ5LO55F7C55K5KTBIJPCDA2XNYJ",
-    "GDW2W46KHDE26EIX5PPOW5ZERCVSJS5JK7IRWMT2KWVAEEGSZNPRYSJJ",
-    "GDW374Z2U7PBBUV2PPOPG65QGJJVTFHYDUNZE6PIDP73H7JDB7FGEKE6",
-    "GDW3CN5MAJQBH2XY66LQCUISHIABLXVLTYB3MAJBGTU5SPNFNPIRPAMG",
-    "GDW3OKD7LT6H4QZG64BSEJ3WBGEIEPUEOT4OS6IOT3AZ5UV4FXJHTT26",
-    "GDW4LTOAWDBJEKDHVWYIJGI3RJDMN3RTBLL4RXP2T53XS6V7D46AX5ZC",
-    "GDW4XMFSBYZBGPJFDRXHK5D34J34C5FE5EP444IVPW4SKW6WBUCL63XI",
-    "GDW5ARPSJFNR4Y2G7MGM6G7BNOQTXEKAQEYO7PS23CDOYE7OBHV3UHZO",
-    "GDW5B4R7MRKVSDZQQVR4HJF7UFNEMGBJ4KBFHIDIYXEW533ZIYLBNPTD",
-    "GDW5OGKSSC35FQLOM6637L3DNSHWPP3MQMPKQOCNSDZRETBVTF2KS2PK",
-    "GDW5Z67F6NZ6BD2CSDEY6TPM6CKGP4V5X2XLKYJMNJFEELQZ7WHZAT2L",
-    "GDW6SAO2T57MGXEQ4QFV7XSIM67XKYJO4UJ5ARXOTPXUFPYYPUOLCISO",
-    "GDW73VLTG4DXGLZPO4PNZ6OZIF7I55NVQ72LMIO6AOH5A2SMQLZU6JC6",
-    "GDW7CI6AVRE3DNW26JEODAIKYAZPM53KLH24A7V7RJRENIKKVENNZM2T",
-    "GDWA6AARXU6ROW53XVKXDY5C6JP6UG5WXIQEHCKBTKC64VEGQWCCCH5T",
-    "GDWA77TWJNSFKRG5KBKSLVMFQ7PDCW3DEXKUFCBGP2ET6TRI7XK5IL6P",
-    "GDWAFX4GPD5ROSJNF4TJJNNF5VEJSZXEKUX2WVCPL72SM6YKEF53M7OD",
-    "GDWAQS4W3UNEDT2OCM6TJOVP25DDHOQRG2SU4FG3QQB3I7V2OEYW5LG3",
-    "GDWAQVE3W3H3QVPZL6QGXIXTLZV24QEPU66EULOKJ5UWKJWM4HPBWWKG",
-    "GDWBN6VW2QM5LL6IOROPFJA4HUZJYWV2WSHAQTPNZVHI73CHO64Z2ORO",
-    "GDWBNUVKYRVC4E3LIGL7UJCDAGKJHHGJ76ISY5C3CBZ4RCJ5ODK42AVE",
-    "GDWBT5EPWOJH26L3DK524NNKPOFV4LCSV5RLO3R77GJO4GKU27XQBRKB",
-    "GDWC32RUQX4DCLIFT6CR7NELFHNWCTDXMAGOBZHWIBU3F3HPFHE4Q32G",
-    "GDWDAWC46GLD6UNLWIZRPXVLVMCWXIS5MQNQSC33UG2LNZNK2ZSU33CJ",
-    "GDWDMQIEIVTYHAEOJFO3E6QV4JFVH3Y5VURXLXPEELAE4KQ7MSZDAFOM",
-    "GDWDQ3EPZ4X6IMSEQ2GVYFSV5E5DHCCLI54EATQER4MHXX5G7ROCXRNX",
-    "GDWDUHTD4TBVKZFZGV4LIIHCYAU6477AFHG73HHJQ3V5MZ2XPOX3A5GM",
-    "GDWDVESIGOS6I72MKLR25HZXEEUI744DPGHQM5SCPAS7X3IYRT3HJAYI",
-    "GDWDZASS2GFXBY62TL33BK55E67QLTSLJNO3VP7AV5NCMSQ57SQWKO5U",
-    "GDWE3LO43FSW2RILXLGLGV6B3H7JKUZ7HIREPLGJXRFYCWASOP5TSBIW",
-    "GDWE6AZVLWMRJSURWTIMCMWBGMZ72ZV5EDUOZ3HVFERB23QC3Y6OPMYA",
-    "GDWECDP5ENQUI3X4L3HOMAJN4HRAK2BSIHY6VKASU33HWVJIBWHP3MD6",
-    "GDWEKSHGXIZ5KQ74FU3VQICSOGGKVU2QWNFXEAHZYYXSNRK6N5MSFFLR",
-    "GDWEU7CHJVMJSDI5Q4A23ENGZNDZS764TVQIQRADMUUXCPMTT6ZGOS2R",
-    "GDWEV54SJOPAEJHFYDLEEMLDJX3DZHZVSVO4K5SHXL3XG7X6MJVG6CUZ",
-    "GDWF5LTDGYARLHZXLJBUVIH5Y4YGLRGSKLVMTFSD2H6CYNSN5YR4ETRU",
-    "GDWFD4VUDO7BSNYQ57PEI4PLOGDD7J3YN7O7O6TK6MCSAF6H2D5IOSFX",
-    "GDWFFWG4WKCI3XYGBJQNC4L2SCOUHJJ7OA55WZ3IODUMSPM6EO23MEGA",
-    "GDWFLXHM44UDVDSN4K67PJSPJ4GENIN2IDP2RCEXM4NFMMNFXP7PS2TZ",
-    "GDWFMKQ2UXAJJHFXNZ3TU5TTPVAASW75BEGZDONJONOXRCZYKEOLX6KB",
-    "GDWFNOJOJHGM7ERQ4RDWPIMHN7MZPKFOBBJ455Z2QR774XJZPV73UZSW",
-    "GDWFVA7JPBMU7PPLMMWM32IMKLMPLYU5JXYO6SMYAHYLER4757QA4B3I",
-    "GDWG4JVKSHR4JT3L3MMCLQHS7WIXEXW5SIX2TN3GNJZRFU32D3BAKBFL",
-    "GDWGAD7ZW372WR2HSB7IKOBGUNNBZ4DZJUDDJGKNRQHAFFY56HFZFCIQ",
-    "GDWGQ3KTLKL57FMUEWPE7GSR2EHT64724ST4J35GEFIOHCBZIM4ZF6VB",
-    "GDWHKUXPNJA6K2HU6TK5D66ISHIMK35WCED33XDS54TJ7TEPABWAHEWR",
-    "GDWI7WXKYEIRRVSIFPBSVLU6LPBIKISACUJXZA477U2CVHMBAG5CLAIM",
-    "GDWIKURPER7ABL5L6L5XXPLYSA7M3OH3ZVRNKSWHTO2NW7P4U6ABSDYE",
-    "GDWISWUU2LB2EUNGEVNU44CXMO7RROUKQXJIEVIW2JKHZTDNWYOKTW57",
-    "GDWIYBIOYX5MJ2KFVSSZQ2V35765TWYNEKVV35QWMWRLE2BYSUEINDUS",
-    "GDWJ2NEOV2JBJ7KJMM2FPILBOQMO3GE5SCCYFRVD3Y6DE35FN6XYMERC",
-    "GDWJEMYET63C6K267NY3IKQNYT4XLQVTRF2WGLA6MNW3V5AO7SLQ3XWD",
-    "GDWJQIKLBVW5QFEA3XZ2MCYSOJXCXMDGJUUA6VYAITRFU5H5SJSDLTJY",
-    "GDWJU236MNVYQ5YBQVF77JUEF7GGVO3DIK5LJSSPFC27LIN3EMFHYXXA",
-    "GDWK2EOCRHIT225FPV67ZLDBP2JHUQY4UVH4TLEBA7LZC3AUVAWW3JWG",
-    "GDWK2P5SOLWPWTVMXRQUOU32HSBHBS54SK6BAJHHGPI6EOTVB2FIUGC6",
-    "GDWK62Z6LOJVNRBODGJXHDXWCTRD2ZGBZIAAGG5MK4REW2IJJFTVUEDF",
-    "GDWKS7O7RQY4ZRHPHLLMP63CGGNWCK72MP5J6FMVANA45ZI5WBJLQFHN",
-    "GDWKVUUGEBGMJBAJRXU4KAQDIKHKNKPH6QM6QQLVP5NJIUCVKQFSFMW6",
-    "GDWKWFMVSUKNH7OUKHCMJAU3Y73M7CENWPJ3MEYTSJUKOPHBT5MZSTIA",
-    "GDWL2LFFREUQOXFMGQPHZYRQG5OHI7CHYCZCCOZHXCN52XU3KCM4X3SV",
-    "GDWLBUXIA46EVRGCY7HE5LBZWLXWUMIUKKXMJZNYOZMHOUUDFPIQIC4T",
-    "GDWLDGMXDGR64EZNMY4YRNBPHFY3ITMIMNSE6L37CQTSGHPIQS3W7OM5",
-    "GDWLKKR5SGPE5YFZEM76MGHAI4SCM6GYYRUJERJZCV7NEXYRX3QQNRPA",
-    "GDWLKR3OHTUREN7FK4JFESXUJ3E376PBT7KXBAHHALPZDV3IBTGIQISO",
-    "GDWLQP2RSWHXTNLCJQOKRIMNJVPQIHDQ2DHKY4R3DXRM4SHWADPTU63K",
-    "GDWLRMA7KRIXK3DDT3R2N4Q4NJETJTJH7D5LOOUCCA65E52DPGXQJLZF",
-    "GDWMD24C42PHJBEXOZG7RWYVD7QILV3NTK5ZZPHVXSLQZH2Z6B6IOAFR",
-    "GDWMOWMMRM4GSPULFL2HLVO5TLMVRYONQYHM6ZKGK5QRTLITU37EBFLR",
-    "GDWN7HYWM3NULFG5YGSMUMLKPSWPXYM7YCFOASUIXWXVZIOZ7DX6PORT",
-    "GDWNCEVHMWCO7V4MSHMWGGRE7PYU4KMYBROP2R7FEGIGC4MGKREBKHWI",
-    "GDWO65EHZKZTE6IFOPEH7ASJOEU7XFE6CGHE3ZRUWA7HRA2QDZHKDOOA",
-    "GDWOQ7GZQZG4GBMOKFF4DD2QXKIBTG2IT4IJAIUI3VBB4G7XHSOSHIFP",
-    "GDWP3UM6IIWJUE2ODK2Q2PD27CSEPTUSPZPN6LB2U67D5TZC3WYDK46Y",
-    "GDWP5VZB44AKMH7LCH5GAMM7RHFIVLWBC6X6QHCYIYA7LYYL6O25YZUC",
-    "GDWP5X2TDSOQ4FE6V4OJ4RT7D632JVLOP6SCHWNCTBZD6KWFKZ3VMZ32",
-    "GDWP7HTMRYWD45MJWNWVA7DOB6HHMRX5HRHEBAMPJZU65MNNLL6CLRGF",
-    "GDWPPBRHVE4DCYYDDRDSVHIQ6P525ZTEZ4B5WLTO7CRY6KMCMF5KAYVU",
-    "GDWQ663ZY5R5A2I7Z6OS2GJRWNX7WJZBETM4K4JNRFZZQBTHXLYE4OMK",
-    "GDWQWI37OTBCZYGN4JCCIMGSDIQX2JOUZPMPOLNCYPFEK5S6ELQ5IIXX",
-    "GDWRFB43YU4IOWZX24OF5HMOZXPIREX7JBRY7FVI4T5JRVR2I4ONRYUQ",
-    "GDWRPJA5DIOV352M3ZSFFLP3PMGTOSAJELCQO6ZSBVNDYNAHK3RFOPB6",
-    "GDWRTHDUIOMEWDEZLHX36H5NFVAFJ2F4GDLDO7AOS2IFWRQNGW5UYSAG",
-    "GDWRTJYB4NNYRQL3LW4RVBDDIVDLQJBUWVGJGBHJDNZAIRFZ35AM4SPN",
-    "GDWS3WN3SK6JUX6YI2HJHCKA2VSVKO2YFRX7VZRJL42X66VX2VJ5PSAP",
-    "GDWSA3ZIJJI5R5TGRB3ATZFNKTGUZ4EYAOVULU5XZAXMG2V4HK7UBA76",
-    "GDWSQOYZ46GGKRE44FAIXS5U4ASETGV42TUY6ZX67YPBPZKECEY6SYTY",
-    "GDWT5YSEJZ54CNIU7N4WJPCSGE3EC7DKBDNQAIOR5QVKZA5USXKJ6RGV",
-    "GDWTRC3WZLNT32YSJU6W5CRAE52UBARTGLTQDKZEFZ3V4RWM2HZIGJAY",
-    "GDWTZ5DHUG7HXSJLVNQB5DMCP3PGBBUC43WTDJLKW4BWBWNEHQ5ZMAIN",
-    "GDWTZMA4OHMIQR3OO4IH2XLXZM2UM4WTCIF6FHKKG6NIHJKIX5FBDPFX",
-    "GDWU4M3HZUCAT663KMMDCIVJYJG6HSRTOUIWREDAZU3WULMG5QZFC4BO",
-    "GDWUK2F5DKSKXOCVG2FNRZDPBJ7NEYKRHGKGBODMQVRHJYKDNBNVND5T",
-    "GDWULMNBWBSADNLDVALXGOATN2LCVWBBQ3DUYWF4JZBEIMLWRAYYFZKP",
-    "GDWUWPDV4RITCPAFLWJSJS36WKJP4EGYYPH2YLY5HNQQWHBZBZGURY23",
-    "GDWVA3QQXBIEZS47EULWM6O4NA5SOMHS4RHLGYEJ23EFUPOY7D2J63N6",
-    "GDWVC2LM3M7YV7V3AKKJYTJQWHVSSP2XHTP4Q3JRDBOZZ7Q6ANDTFP4A",
-    "GDWVC5L4YNGD22FTJRSQOOIUOVUGZW7XBUL6FXTDOJH4PBRPVZMWKOSL",
-    "GDWVNILJSN7Y2PI7UZZMEX7Q6WI4JRDRSP66TSVJAKPIOPMZ3GGFXHDU",
-    "GDWVYO4HGZAC4VP3I574PIMMQFXY5753WHDJTPFVLJXXUJM7EN53CYP5",
-    "GDWW7UBRSDUPCSXI5OJMQKRFLW2Q6L4WGYTNUKR64TQXQFELRECBDK3P",
-    "GDWWPZNXKMQNAP2IT7ZIBLP6YHUSLCJWKBXNHGSYJ4FJHP33V3V75A2V",
-    "GDWX6DA5X55TE4N24D43CPMVJHFV67MPM3XREKP5LP56AJRABUL4THSP",
-    "GDWXG6MRFMUYNPNZJO7JEV52CR7FLBQEIPRG6GYIDWRFB3PUL34RKAPC",
-    "GDWXO4MQJ7VZYJREB42H6H7KX5ZJ6TVYXG6DIIL6A7JOVFJIVBPQT3PP",
-    "GDWXRW6YE3KISFBWEOCFQYRC2FVTNXFGMW5KSVMQJ4BH4HIUTELF7HE5",
-    "GDWXT5BDGCQ6ETGX5VOKV4ZWYP7N6RVUYN2BOCFT5VLYEO25LXZWVYDE",
-    "GDWYXWX2EQXQVAH53AFCD7IMXMYBVW42M4FODXEQ6D4YKZBJIR4YIIND",
-    "GDWZ7XLFNMS7V3UQCWVMEGFWTZ4IEPO5AOSDEBMG5N6NWAIWZ5ZLA3J6",
-    "GDWZASDTBR6OJ6BWRLW52P6MNHYXL7G7RAMQO7XDBEVVSDGXOW4BC3BM",
-    "GDWZDUPMQ7TEM4ZZH3CGR3R6GTWW3E3EBCOSE4AH5KAGE3CWK4NACXAU",
-    "GDWZL44LYQTQOODBWEY5H2VOBGZ7H6VGCLAY425HHFJIWCNLQHAVUU3N",
-    "GDX2AOJAOSKWK3Y4JVIUDTLIKDXIUR3DE35R2BLFNJNLPD7ZGNE3CKWX",
-    "GDX2IC7UOAKTEPZZ7NFYSPHTN5BJQOEQ7MXNUL3N7YIBWXDBD5PPVYEF",
-    "GDX3NYMNCJVYPW26AOPUBPA7SSN4SG7IR3HEX5Z3JTSGMWBR3527GWDS",
-    "GDX43Y3FOXXMYOZCNA6J6PLJKXKQUQHKECRUK7HIFEQ4G5OH6Q3TB2IQ",
-    "GDX4A3V6LZ5XSAP3G2DKPGR2WEAHP4QP35JEBGGEFZKPUPX4GAIKZTYQ",
-    "GDX4GNKBTL3RX5Y34SFETPPNAQEFYRRL554SRZREPUVTD5XAH6WUHUSA",
-    "GDX5QRZXWEJEIEULJV4ECSLJRCLEU3VTW6656FKJPGJFZKMDV5LYMKJU",
-    "GDX5WYBAR5LNPVBE6L6Z6OB7GQY34Z7NDT2ZUQFFY7UMU2HG3EWCCP5Y",
-    "GDX63KWIT4H6T2UAJYSP24WZ3LE65XNY6HEMZREBVZ6HPGYITYNWTUSL",
-    "GDX6KJHRXSN3WXWZTPZBJTORCT2ARFFCEKYRGZPZCY4JFG2ORANJP3UD",
-    "GDX6NCBUTCAVCEGK2HHE4Y2AIASMRBLZLIBYKPRHIZTEZWVNN2M5KBMQ",
-    "GDX6QY4IHBYCUTQD4H2YSVOGROEJ537SUNJ3IJE2C3ERFNNK3GQ6LI5B",
-    "GDX72WUBVLOPDNTUHQEYV5GKXY5CKLUJMRWCR6ZDAZKTFHUB5PAFAU6K",
-    "GDX7KQ4XYX7ERRW7OFO2CQTAICR4MGCHIKNCNV7GR5MCXILNOPCMVRBS",
-    "GDX7RXDXNURGLMWBQ5OJD6PQ3QKCIULKAERPXNPT4IYFZU5VQE6NQOH2",
-    "GDX7YFSJODKHTQFYHOAY5LM4E4BJRONH5XTUXLXM75G3O4UVNVTYY57E",
-    "GDXASZV6OZXPKOXO2AZXKTMONY2H23U4LGIKOGMMPCQJYQQYTAH6RUSA",
-    "GDXB7E2UTCA2ZDST6XKKOI2WFBHAHS7ULDFDYOXR6KNVANXN5Q75B2NU",
-    "GDXBHPKWVU7VT5ZOHK5JQDOZS5XMKWOLKBXXXX5JOSVEAIDZFDS6ABDC",
-    "GDXBJDNATPVZKWRFEFBEXY7EVPO5W6Z26LLVWLSPJ63NAJZ24R2EDVQ4",
-    "GDXBLO4IHDYLE42NND2QRZNCK2PXUM2CDIAUZCMDJOPPKTQMRCWKWBTC",
-    "GDXBPNKHQMR2AZKZXRETWT7GSZMCZHGBU55J54LDHVSOJRBV3K7MOZDW",
-    "GDXC2J4KYYEFQ7CNTXWUEBE42GALUUTSBUFM7UBJD5OOKWHH7HWB23G5",
-    "GDXCOHPJGW75XF653ASXSUX2RY6D5C4SKUIWU7LVQICVKNB5URFXUSOY",
-    "GDXD5BKRNJWPMDGG3GM6AHQNVQJ2RRPOVBA3LEO3SVDG7ODDLS7QCDLL",
-    "GDXDQULRE7ZKVZKVCFXDHTKQIOFEAABDD34HJJ6ZBUGMPNOIVSZ4HJWU",
-    "GDXDT3GZKB2D3RIAN5KT26HLXM32D752VDZRXOXOWWOJCFRQKHTNGNZ7",
-    "GDXDWSWD42SNZAY242AORXYPBEJUPJY2A3HEM7HA7YHHXB5YSE5NL3TH",
-    "GDXE2YVK5ABMTGWDUE3PTPCCLH3GTADY2ZF33OBBVDSEW5I3BGQKBOLZ",
-    "GDXE4XUOOT2WUM6GTYNJYE6KGKAMEV2OKRQRP2UKYBYAEFTUF5WSD5PS",
-    "GDXEC7MN7VUPWVJXFBJMPSW56PHST5QNS7FP2JB7EM3SXXJVWOEF5BCH",
-    "GDXECO7OKBRK7IAYJMLD6T422ASZQ74FEIJC2PIVS7GCCC2EKIL5XEMJ",
-    "GDXEHVSSA3CYNGDVEMGLHE3MAKKBCHEJF7HZFHYNOCZB2OQLLRPSBZ6R",
-    "GDXEIZN7DL4RWDIYFOCJ3YKON6BIPG2LDUUBRI2ZA5EX552I4Y7OQMHO",
-    "GDXEN3LLYXX6K2KXDMU32MCOCEWMO3SAZTJGHTXHDJA7I3OJFZK32RZ2",
-    "GDXF5P76FQ5YWWZWFXI3IPOPMI3ZNH6G3UG3QFMWXOZT7MUWSYNJLEF2",
-    "GDXFEVXP5ULO5V4SVQQP5PEEMLCRZQ27RIQD7ZMVKOCPCMRU4F7TDHIF",
-    "GDXGBWUCU5CTDB5W5GFGV2GKYLBQRZZTPRLDGUQ7TTQYQD33KQHFLARE",
-    "GDXGFOKIMRYQM4CCNZW2ZWGON5IDM46OQHHEBJC3VC6IWSLH2JU67MAT",
-    "GDXGKTHHLC636FU5J75U6GRFNNWIKSESY3W6IFTMGP7YWCYJZXNIGNWK",
-    "GDXGRIROVMJRC6LGNSHID74YX625IMDN36R3JL5Q5KV3S3CQXKYM6O4P",
-    "GDXGVIYM4ALCOSAV2WKDSYFAWPDLIIZDOBJFXQAE7DV2H26HXQUICTE7",
-    "GDXHCXJ6HMWHBEIWZPWAATCVWR5LZLGTNLMIZX3TSYVNPPEBXO3EPZ7S",
-    "GDXHMZD2BIDKVGEAKQ6MJLMJRUSGA3BHELJMTGJU3RFKPWLSUG3W5EDU",
-    "GDXI2Y6JAUU7MAFAKFC5NL7JBASUTRO33FFGKGDPPYSZWJCZ7TWWGJDJ",
-    "GDXI4IOCNEUMHEUIZJVYHTKKO2VG4ZPOUXM6N6Z3NE3BL5457SPH7XTB",
-    "GDXICO36OVSRKW4AE2P6JUCWX5Z7FGSMHTTYV5OW722QU4HKYZOLEQOZ",
-    "GDXICSB24GT7DKNVWM6LWX5YE5T75OHEXAJKRALFTQMX5RTKGLZJSUJJ",
-    "GDXIECP4T3E75I6CWX4ZATCUL75NCIIWPWLZRVHKSJG6WRUSBPWPCIJW",
-    "GDXIPWFOBX42MBERJSRZUHVC7LVX56VMCMSXS52UFLNMN52RUURUUIGI",
-    "GDXIWF4J2JMVZLGQRNMW5HC6JFN5PMFS5QKTZQ6KMF5EOZGFSRTISZYT",
-    "GDXJ2FNHPR6TMXCHTYMIM7GK6WSN2H7NDDK7YGRP3WT7QT3254PCOPVP",
-    "GDXJ4P3CR3O34ONACWWLA3L5LYW7F7RWDEFPZHHCMFCYPHLZ5YWKWTIN",
-    "GDXJCWSL6YWRZIRBQHEJTL6BXTS2QKQGWNOZHWTFHZCHGVM64JBRD6HU",
-    "GDXJKTH5AN5NHQNZW2IIBIP4D57IM2YYJOGJLULFTSRQUNX63MZXCZC6",
-    "GDXJL74VPKNVRYHGYIPE2JYUKFL447Q7KLLO5KW3SNG6UX476SN7WXAU",
-    "GDXJS23YTNS3TXPIBVYTPZABYZQI75CTVW6YU5ZBUJQB23OSB73VPJ7A",
-    "GDXKCZDZXPLO3YD6VZHEUHZIYHJLVUWE6UFOWJDQCO727QQEMZPI7OBZ",
-    "GDXKDBLIS7VSC2Y6GBNJWAED2VW2IR2ZVRUP3VOJLV7VITSLACQINDUS",
-    "GDXKNG2DE4Y3HTTFLI3RFSJCJ57VAI3TLAFS6KEJVBTKG4LTSI2ELJ5X",
-    "GDXKPFGKAGM6BKAE4ZKE6X7EEPF6RA2444VG7UHRLUKCNLYYQTGUZUMJ",
-    "GDXKUYGDGOVXUZSOWXCY6KJ3Z3MSYGVWNBXCBCRQ5G56VLY2OYSI7V53",
-    "GDXL2M2AGHGSNH2DQLYI7GWBPGY7O5AB5XN2H5SQBUCWCOK77Z5AAA27",
-    "GDXLI7YRMLWPO6P5XEZWQOFZDLUPIVDISYAAWRAPXQYGZD42JQMIP26K",
-    "GDXLXSMEXA4HKEHMKSJD2XV3QGAY5YHNTZBQYKEMBCBAAXJEQYV6ELON",
-    "GDXMBAP7QZYJAPH6R3IURPSZTAUWCQYLRIGUVQMYYYAH6XCMZG62SHEL",
-    "GDXMUHIRMTPJOQ25RKQHAOOO6GK5SYAF3VKZSJYKAIXM56S2SB3LZYP3",
-    "GDXNBLFO55PPOVZHJPOHPAGYRFP2G3V7546LVHH2Y7DK33XXYJOOM6C4",
-    "GDXNHP7LUVJZOC6SDLDVORACGCY6GJXUPJRWWH23Z6NYHCDXYACXHQRG",
-    "GDXNRLVNJJKTPPIX6NCAKKGVMFR2HNKHYXC4NV54B4DFASFBRS72IT5N",
-    "GDXO4C33IEXGF4TE4RRZFCJ2N4RVV65UAGTYHRMFC4KAP4RFD2Q3IBMQ",
-    "GDXO7K4GJP4KFFHICBBHJCYZT6JFIZLQSGBPKEWIQHUWPPK657DTRLZY",
-    "GDXOALCAF2CE3LZZSQ65PBJUZVEY3A2ULFBTC7Y554GJSKWNS274EXLM",
-    "GDXOKYOIARDUUZTI2ZPIOQKRUH2VPRG4QMXMPCC5ZMBGSME4FA3BKICO",
-    "GDXOM7HZ6JO4RLHCO3BFWIYK7CEDAD5IUORNT4OHIEDEDFF4CWP77BRX",
-    "GDXOW5UNH2V3VLGPECYW2JLRKXRK27HYDCISRM5IC25NO5SLVO56PURA",
-    "GDXPD5PEECQQTONEN6GM2T3FJM2TXNP3TGHE3TOTPIINWWDLRJ77DMD7",
-    "GDXPXDC2ZHXM3BGFA3BQAKCEXBOL4ZFPDTMDBN22HII7525KV3KOEDNA",
-    "GDXQ3UCTZN7X4OPFJR6QP3Y3TUUSBNQ66XE7RUULZHENO3JLIFDAXNYG",
-    "GDXQ6QR5NJHQWYQYBRXWT76EMT7OGVOE3LJS4WR3QSJJ7KZ7FR2BG3LP",
-    "GDXQA77D5KU5OLM2JAWX2GGHVYZNYDIJ2PJ5BL5XGLEN7FM5IKGM4BTJ",
-    "GDXQGJS5DQSBXXST2EDX4B2QGXCHPKW2VYDCL5SYOPJE6YRX7H6GNANO",
-    "GDXQKEYJVPP5ZGNRMI4XGY5EFEUQ53OG6DMIGBYWBFO346HZ4WBIAMLA",
-    "GDXQODWBHCYBJF65QI5CVQEQ6WY43IJBU2QNY46TYMW5XFZ5547XXABP",
-    "GDXRHZRSOHLT6QLM32XWCY6XL4SDXJJ6JKFOQTH7OYM3ZDOQ472Y6HZ2",
-    "GDXRJS2DALMIIZISB76563OE6BIFFFZZOBPLEMVAGNFZTAQ7NL33GRAM",
-    "GDXSF4ZY6SHZVJZ3F7SRJJ3FW6MQWUUAZUTANQUF23VJ7PESX4HDITYX",
-    "GDXSSWANRASDZYZJG5H7H2L2RJJJGPUYBC7ZZ64P72TPYX6PWTA3B4AQ",
-    "GDXSTVITV3K37XBHFQUXAMFISEPI6EGYKTXDB47SOWEARQEJS7C4KFW6",
-    "GDXTIPYFQGROAGY6A3BIGNME7IDY2FLLOHNDDYR6UBE2ZRGCZTEUACER",
-    "GDXTJEK4JZNSTNQAWA53RZNS2GIKTDRPEUWDXELFMKU52XNECNVDVXDI",
-    "GDXTJHPQ4MYN5FB3VSQOCNMUVPBYUAS7I7P7P5OQL635ROGBNB3P5BEK",
-    "GDXUBWZK6E6FK7NU23COZIYJOFIQWUWFYVIYZFT2YJXQLD6NAJLLBFCG",
-    "GDXUGMZW66BRXM65W3JHAOTY7766EM4RULCXFBSHG7BSWQEHE5URGE2U",
-    "GDXUGUXPBTDUL27VATP2OPGPMS3BJ7I72SQBOHA4JGALKFFFKNQG5NB3",
-    "GDXUJG2CDK56E57S4ATVQUUBO5TYYQNUNXS27WTZE4WJCGTJMME4NI74",
-    "GDXUMXZNFQX5ETUE7TEMIKF4UMVZ2XMRGQRWCZ2FUDPEVMDT44TDBCBS",
-    "GDXURHTAT6LW3766WGW6IIXU63TBEPDXDFCCRWJ54HP3FE4LE5U3XLXH",
-    "GDXUVXKDLLTBISGSQRRO2DXJEWSRXVCNPDQFYMQTJNNCZ7LJSXZXDAYG",
-    "GDXV2B6VOUKDANXTEE5O4XOKQOSNHYIRAVIEPIWQP6QVRJRNBKO6CCCZ",
-    "GDXV4LXQK52OPWHYSBABVY23ELGP6JJCG2U74BVO2P6PXEKT43NJRJ2E",
-    "GDXVPQDPADV6CEY6XZIF7DLHPAMFQ4FSAEWMNAXDPP7IVRZTJ6E23RVI",
-    "GDXVW74N7FUHSTXITF77ORUX45YHIK7V3TGGYKT7R5SBUHXK7ZKQVD6B",
-    "GDXVYBGDSUEOWTOWA4SEXKIU5CRJUSON6ACNIKQL7AJ3O6XSDXWZWPOL",
-    "GDXWFHCUSU52OIBUIPPVCI2732NLSWHDSG2ALBFCSJTF6SHWTXHPAQWC",
-    "GDXWVKM5E3R7WUUT73TN7TKDYBIR6I4JOEDMBHJM54YLXBKMA2YYSDJL",
-    "GDXX5NWDBWYLRK53RRQFTMZRMHTOO2Z556E4KIZXOXR2KSX5IFZPQ5Y7",
-    "GDXXF455BR6K4GUVKAP2JP6GZI3CKP7NOBMSEZPCUWQ6GPO2DZZY7ZSV",
-    "GDXXTEW7PIWSXEIR3FMLD7YPTR7VC6M2ZZ7JOPKFUUTHAI57ZM7LATOM",
-    "GDXZB43KXGDAJR72WQRXXEFBBATCFDF5W6YH5MIHW5LZDKYMTZAJ52UR",
-    "GDXZCV52QTJP6BEMUFSPH22KIGADLONOE7GB34TKS3A5RQBF5OQLL5FW",
-    "GDXZHCVJMGFCBMC2VI6RJNFX4DHSYVHQ3KRMNGZJMAAZACXYZOOOORYL",
-    "GDXZI2O4YZPYIZ3BF67IL7PRH46642IUMLBMWGBXS6CYAISXZXYOUCTX",
-    "GDXZLPOZE3ETN4VBKM6MSYBLSQ4FNP4PESURMIISVYK46T5S3O2WNBTC",
-    "GDXZPPQMDTLB7SA2ZIRIS5FGAMVRPZPROHAST2KG7CPNS4C43B3NGOLD",
-    "GDY24QH52U5ASH27KAAYANNM2T4QYBBBCZZZKNJMQ2EFHZUM7MEC2HZ7",
-    "GDY2BJVO6PORWPO53OT3QD5FFFN375SLOBIMJ4M3HXDHP42E2GJDUETL",
-    "GDY2CSZGP4RX4IEDLDDKTKGYO6WQIRSYK5UH6AEHPG2NDTNOBCZMTBTC",
-    "GDY2GKM2AD4CFADYQKXC3C47WNLSFZERFNPM2H4IQWG4QXGFRF7V5MX2",
-    "GDY2OF43IJTVZ64DVZYRDRNET7E4S55HDYL2OBBGQ7YR7WLEFBQZTMZ7",
-    "GDY2RUHIR5LKPRJHSN4JTXL4YFAZT6L6TNVQJQKT7QQJUWHOLFOYQF5D",
-    "GDY35SSEFQPUQCB746AINBQLMVLCK3XWZVB7FATHH4R32EW7XHXDHXKR",
-    "GDY3J6EVME6BDYVT7OAUNNKKEHPEP2FPMDAPHV3IUCAAOZUD4TDODNEZ",
-    "GDY3ZFHZZ3LRVGXPJKB3OOXGFK6THCCQEGANRBWJC243NGWUJ27ZANHX",
-    "GDY43QYAQNWJRCNK44FEQKKLZ3GDHZRXUDMHPJKPUDEY67N4JMYHDANA",
-    "GDY44QBSEYWJ37T2HDYM53J34JPHSGADHWOMHBLI3X2F66AHIIE6CXDC",
-    "GDY4G6EITBPMYN3BN4UCGFKCXDQ2QFRRYS4BL6OOMPRG5ZZFFSSORBWR",
-    "GDY4T5QSMKABF7ZJBEH35V766DFS53KUVNTXCY42U5IQTU7533BGCECB",
-    "GDY4WOYPQ5TUC4PRHDWXMLGVBRWLIWUSWT5JULYV5DV6OPVJJM46LN24",
-    "GDY4X4QEVSC4EGMVXV7PWUMESNGBDD2T6UF5ETC2FF6O7DFNL2TSIMHB",
-    "GDY5EY5RHRCJX647RXY2FASYSO4NWOUUGSEGBJOA7SVCIW7M3L62SWIS",
-    "GDY5ID7IMI6WZLQKNTXE5HHKXBC6XC2VHKSK64NOQGXCIHXUXNSNS7O6",
-    "GDY5Q2VSV2ZVKN32WNYO6ILTHRYAJAL3CKV4D3VJQ752N5KVR6P2GNK3",
-    "GDY5SH37RDK6HT65SWA4ENZXPLNF57YXUZRHKPRK5ZROLC77USEHKMRF",
-    "GDY5UQ6I5LXH5OP52YIJY57EEPXOVYQDZF77PK67CQ33LX5ZOSGRLQFS",
-    "GDY6C4KFB24EPDKWADKO7VOMSGZPEDR4T7KS7HUNKQ5KT2RFMPFJHTBZ",
-    "GDY6EFU237SZDBDBXEN47WPLS5O4HVSLGLYZXYPK2NYIXG633SJEOKPJ",
-    "GDY6U3HW2W75V3Y2JDHCSOSYTH3TTWUVMRD6G4L2XFKU3J4HFQBBWNGK",
-    "GDY6ZARDNLUAWDX4IBSRFI5OJH24VGV55UCVSYKUIU2QI3K43WNXWEGQ",
-    "GDY7HN64RP24NXGQGGB3XCZJZZJGWBH7TG6MVDIDEPXVC2EMFR3RW7ZJ",
-    "GDY7HZXYFGPPPEMOD7T6MSKCCS3UR74SOW7A5GAW5VWGAPIDSRUKIRP7",
-    "GDY7LCU3O5N2OLQNXEZ7RD53QJMHAB3FFJBGKJ65YK4D43EMJKP45BMU",
-    "GDY7TE4CZ7ZCJPAKDTCVFVZIKWQUBUPRCXT6XMUEYIWHFC5VNTVVF7QS",
-    "GDYA5X5CUWTTF5MF6YHXYBNXZZKIB3OCVCF22YR7SNWOXPGIHOEUU3JQ",
-    "GDYAQCJIQFOL6GR6W3JKUXLYZRMBKINYR2E5LKJCVC32PMHJMFLB3FKY",
-    "GDYARHZKRE7WNILCSONPMTODITBOFRIHEUC3ZM6UBLF4TKMJPRWWXADJ",
-    "GDYAZQ5QTQRC7S2RT7Y5FZIC2OPXURU6KNVXZ34EKXMXOFHFPZECN3HL",
-    "GDYBJFOJJK4N7ZONQTGCY3BGD65CFYAK4XHEDOU672H4J7UDQ23XKBAB",
-    "GDYBLENZA6TPX2W5M2NE63BUXD4UDLJLCM6Q5WGFRQSKOSK67XEGV2S5",
-    "GDYBR5JTAJPUFW4IWSHBMSRIDAOQDSWZTYNUCVUGICTLIIWKCXBJPARA",
-    "GDYC6KCO53R2EYMLQOB7DFSWRE2ZBEDNB4U3YV3ZOLP77L2Y4OTGGI6Z",
-    "GDYC6OUUONDGSSKVNVUX7DL6NGQZ2A2GH77RDZUZP7BZ5EYFHEI6XQ5H",
-    "GDYDA3CF4UHT27GFIH4R5VYMIJHLAAQ3Z3ESNUGJEJ7OBQVT6GQLRIW6",
-    "GDYDKYBI5AZLYWTQ3F2YGBJVPUXJCFMMTCF5TQLQGWYHIB5VX3ADGBCA",
-    "GDYEFZNIJWV3KZ7UJOQFM5CBNYXCM7LB5SV5TA4VX4QLD56FNFRG7KH2",
-    "GDYENHUQBYVDNTEBMOUQT7PZDTESIMNUFCK2YLCTUZA5TMVOC7UZPLNZ",
-    "GDYESYQJ5QNPDA6A2HNYR3L6X5XUJB2FHOHYSERI4AT55ZJBTH6RYY43",
-    "GDYF5HWWR4EANTWKWA4CIBJ5RSUIBUWLSKQF43LSMADYRZIOWR5QZNQJ",
-    "GDYF6BGVKBV26ERPEKPNNGHSMQVRL5KWI4CUKYSSSTTSBMZYTW4GSISO",
-    "GDYFNYF76YZ4MQJ5OOHWG6A5AXTS3TM53QY2ZVUMCQHOU24YSJDTIAOA",
-    "GDYFPWF4PK5474NAH7NLOWQBFG77GUXCD3FJTHIX4GCMAWTMY7FOHBAR",
-    "GDYFT2Y6B3KQRV75CB3MYQDQIPEXCIP72CI26KHBW3BTRCX2NV6KGCNG",
-    "GDYG5SMN4OFFTZFQZSBNC7XWVHJY7Z7BDZI4Y3AGSV5EX4ZMJTZLROCK",
-    "GDYI6THBXFEVGJCALRGY24MJVUQK45FADZZ4XVTRATDZFLA5HJS3PAUS",
-    "GDYID3TPS6PKPG5YVGOUICXNGNCXRIOHX6SAGPECFPXJVO7ZMYUB2B6Z",
-    "GDYISQMM2MB4GQWU3UZXFCKLZYJMPXKLVFS4R35UF6JXGMUJBUGNSY2C",
-    "GDYITPZSA2XIZB4X6QMAKQBOVDWWQA3OLMXVYTF6D3HAA5G6YDSC7NQC",
-    "GDYITXZHH4JFO6TW2LMSRY3NQXIZYZJADPDKUTQKN7A2UQM3ZPVGTN7O",
-    "GDYJULLRHXATVFJ53X6RHDXJIL35ISEREXQSTOVROGEHDOZZ7EI6G6XM",
-    "GDYJWYC664SQ3OO6JZ72BY6JK2O3Y24X532M5P6DCJHUE6WUOODB45YB",
-    "GDYKIV7DR6QWXTN32KWXLWLRL7TTBKZZB3C3RR4YSCCUHRSTVHUEZGBI",
-    "GDYKKXZV3DA3LX6PCFZD5QRIMDZSFSF3FRZ4NUNNTUN2RHX26RFZHS2C",
-    "GDYKSYLJAJFTYT6M7XCX4RZRCUOYBWHT56VOB3VHH5FDV6RHEK7JFCWQ",
-    "GDYKZJ4N3DB4VB5QCITSKYBEK7A3BFSGMFB5MVJU7IP4BQ3BN2XYPCYK",
-    "GDYL7QB3A5X6735V5HPVR5N3GGBYDONWYBGIILXCTT2QMSNAQANZUGAS",
-    "GDYLE43YA53OVOXGP4MZFXEGRZB2PLFMOEKXVBIFWBGK47GQ6WXKRKTI",
-    "GDYLNZYAILDAOSQHL4X4SANK44PXCXFPKIEBL3KK5MW7RRDT3MYKML32",
-    "GDYLPNUKKD6C6IOJEK7ADGSQE6ZIEPZRO7NKGHOBA3BMMHTM3GGHEXEH",
-    "GDYLUZNPMED2LLJYX3UDJ7XAG2WU3H77Z3NQKLAGVGJL5HNW6TQWX3JZ",
-    "GDYLV45IU7QUGAHRBILAG4EBD4VMTNCSFFBFMWQCRQSH4CKEWKJZN3NN",
-    "GDYLX776LJ3SSTSADZ5HZDCFZSOU6NQEGTOXZ3BS6QI6VMBWNEF7CONN",
-    "GDYMOCWDZEF6PKNGSFAOI2BIHF3ON3345G346OHMEN4WMTZONIQFNNOW",
-    "GDYMOYSX5ESHK25R4HMP6QVAE3RWPEXEFTRFWE73RH4RYXGC7VDWA6AU",
-    "GDYN3MAZ5LTYZK55N5JJ4M4SDXON6VUOWRRACBLL7TGWULT4Y5V6OPM4",
-    "GDYN4ODF2HL4SZ4DLG7F32PBA3GMJT2X4HRNSSYJDS6LMRBFLVVILKH6",
-    "GDYNLVFJVVYI6FM2YSKS4WFFZ2ZGP3GGLGREEZMCNOFOZMI2DD7QAB3P",
-    "GDYNSCTD7FJO7WJK4V5F474R7CDZLKFLLPRYMYBRPCBQCIL5OXYE6E5S",
-    "GDYNSGR3CJ4I76FFF6KDY2VRJBIWYEFINUEIGFNPRYTPDCEUK2I7RAMP",
-    "GDYNY63M5PCLAE33U7QY6FCDOC5ZGTADPJ5GAUL6KZBJPHNKW66TXJPA",
-    "GDYOCMB4RB7M2THLGNHBXQGDBLDP6WLA54BNIXPWMSQWNA2JABCPARZD",
-    "GDYOG4DYWWJ5JZHTIUVRDODSOALXH2AGETBFADX6QXAZZOFKFZKVNGRD",
-    "GDYOQ4KP3T7YBQOWVCGO6NJLGTTXIAJBVCDTQIPKEYG7MVGIJTSZBAIB",
-    "GDYP2E4DPNW5YGTAJTZMGRHR2QCCLUQFIUYAB5P2MMO7MITEFRRFFYHA",
-    "GDYPX5JOH43JGFD4RQMVGXAUZMUWSXM7BC3XA5NL2VIMNHKV6DQN5T55",
-    "GDYQDYJKSTCFANCSXMNX63K6C6ZEAHMWQMPRLGLCGBYKI7XUWI7CX4S7",
-    "GDYQLKA2IEC3GQ72IIBT4UVOHXQPTM4GGIA6OSU6UGCTVXH5B2UKLAJH",
-    "GDYRHMWNWUTC32HWABBDDTQHBA5LUVJE3B3VKMTLVVC2ZIXU6VNBWW3L",
-    "GDYRQTRC4OYXKG2ATYN7QMUT7MSY76B3A3S5OCA5COY3LO7C5YPIYFBS",
-    "GDYRZ6IDVK7CDDOLTUTXVDIGXPAPHTVLPABY6C2BDCT5757OUSZB266Z",
-    "GDYS3AYDXBAYZKHNKKIMVVGXVBYRZJHASWXM3KI63X55UQZYMJM33IOW",
-    "GDYS4EFVSV6SSXEFWIVT6JJ255RHPI4PQQKIQQTGKC3APBNWIOT5OSRO",
-    "GDYS6BHRQCNFQXS2YJICSCQNUPSAS3FX6C7HDNHP5XFITLWK3QMY3JHC",
-    "GDYSGFCTWIXMA6SKPJIJZZARPVVUEHJV3IK4DM36QQVR5VC5ZMYKQE47",
-    "GDYSVRKK3BK6RNRFFNCGCIP6MPQSYUCTEEIXT6NNQXGVNC2RCUIMIFFG",
-    "GDYT3EEHMOT7Y3PYTN2KL3PVU7F26UPGACDRV5FQDETTC2NX3HLQS4IG",
-    "GDYT645SQNUWBENSX4I2E56QGAHBHB6NPFS74M7XHA7RUKMX7SXRGUOV",
-    "GDYT6SM6LJLFAQUQLGX7SECVPZRA5SU4H3WW6DMMCVH7HNTJO74FAD5L",
-    "GDYTGHZSUA2DWAZ422MZGPL4TAX6ZJELABC6HW7PT6I6FYERMTNIHWFN",
-    "GDYTKD5LN3N7RE2KZAEV6MIZ45DE5BIPDB6H7XJYW4ISA65GQPZCXYZ5",
-    "GDYTRIQKW4VBGSBWXKONJCMTR7X27AR7FORFVLG2XXQFZTOS5XN6SSOL",
-    "GDYTVFK6ZHPYJEEW3HCHTXSAJWRUAYUS5IQQU543R7KMFKNPAU67MXEX",
-    "GDYTZV3TOKNAHV4BUMNIS3D3RKXKFALKN54M2V74BLND4ML43A3IDZBK",
-    "GDYV5ZV2XTSQFMYDLJEQOROVG5FZCMUNTNGV3F4PFAFDLGL3C6GEEX4L",
-    "GDYVGZ6HNXBHEH4BPC53W6MORG52JHUQWHFI3CU2XFVEIP3C75OCXADA",
-    "GDYVOYQI2TZD4FM3JRBIHMVGAWZJIPAD23FCE2QQS7HRYEPMGXZWPYDM",
-    "GDYVTP5ZMF5QDIDTCOKY4PGZTS4Z7LK6ODESIML5L6JVPLOZQ472EVEE",
-    "GDYW4N3FTRN7CESTJGGCFTVSGET3PKULDAQD4SVIUKQJCAZ6KR3NHAY3",
-    "GDYWAUAGG3NBTKQX6TQA6WHAOWCJ4D53EFMUSIABJWNZ43BTFYAYFXRP",
-    "GDYWWX4RF72FYD5DTQLFXMY7N3ZQ4KKWYTY27E7MDVBMXCFG2C2Z6AHV",
-    "GDYX6L7BXKS6RCIPRX2PLE7YALV6C7LQFTK7PMILWARMGUFU3I3WQETH",
-    "GDYXFRKJXSBT6W52KJFFRHJ2HLPX3UYC6QLYYSXPVV7HDGLJDCU4GOAT",
-    "GDYXR4XJ7BPHC2KQJUGBHY4PBQVQ2CPG6IILOYHFF3OGTV2ZV6ZM2ORA",
-    "GDYYGSFLXSSMSYLJLAQD7WJSOHWVDHH2KUD5U42O5OQG2GA5EP7UUSDT",
-    "GDYYNBQQXSHM6Q5PKHDNAVUOMZAFEMNPBS5ZXYCB63GUO7R5DGGLHKBU",
-    "GDYYSQIZZSRLSG3JV43CQA57VKZ6YQX7GPYWUTULHBXAOAVLPSAVGXGZ",
-    "GDZ22DMB7K3ZCG3RYCCYNHRNIXROZHLB2WN6VAZXOOD7YJ6K47QXXBYR",
-    "GDZ2ERXAFA5TVRJMMAJOGVOTRCUCQEYYBNNB4BLTEBXIDSQFFX2PZPEA",
-    "GDZ2JJLRCR2LMJQGBRSNVU4435LXZNXU6ZBEQWVECKSLU2ON57KMQB4L",
-    "GDZ2JYTLOVH4LGAC6RDTVPQZF7OBWMNMGWNDQOJGDZSLGV7KOEA4ANE3",
-    "GDZ2O5ERT6I5QWTK5ZQFFIRC22KU4TFTAEBV64RHYU7ZVCQWRTOBDPLO",
-    "GDZ33NTXNGARZH5J7FRPDPHJT3CNYGTKG4WUJMIQWTR6I235W6NQCNXC",
-    "GDZ3ECQJ24O6XII4WVQ3DUFNARDPRGEHYYNA22BB7RIUP53AFHMB2BQA",
-    "GDZ3PJ6N3FKX4VPW63KEENV5IFBJDWJYQTDVTXFBIFG7JZY5VIKYVZF5",
-    "GDZ456T5GJ7XU6LRYXVOKRSJ7SMBNZIKXWSHOCRY6OXGIYYRRRUU5JW4",
-    "GDZ4ATHX7DRCLSJD3GLJR4H6JEOLRLBJENWGN47FJ644LN5O3GKULXGE",
-    "GDZ4CUD6FDO5YFNLPQOB5GO6MO7UARHCTF25FZNOPS6DH5PY26XR47ZG",
-    "GDZ4D6KFLZM3R7POEOLP2YFPPMWCUO3XM75JLLC2RLED677IJVUIO6JA",
-    "GDZ4KXPW667JY3OFHL3JEDBEZPMLUQAQG5NE477D2TGPBXKATBPNYYMO",
-    "GDZ4OEV5WNPT46WOTX5BSOAXWBIMMFCIR6GWPPUWKUYYXW5AT5KEHTJU",
-    "GDZ577ZFLTHBOAYL4HK3I7NYPOVOSBWZHCDFEX4EHSL666FFNXMNDAWN",
-    "GDZ5EMPEAATDRBFL77INXIZ3EJYO56Y2Z6R4ICLN7RPBON22AILTUYAA",
-    "GDZ5GHH7GAY3MONDHA74FFNRME5R6UYADVCQUH6MP2ERDLEKZGSEWXRP",
-    "GDZ5JXX25DC3WLIWAWCTFX74JGPLXCTF6CVJPCDKW6VBCPJW2B5CGVYW",
-    "GDZ5LWI6NEGMLK2G2OMFP6BC2QSUQNF4SOCWDU25HYVDMNWC77HRUZII",
-    "GDZ65KDNYE4Y6QQPHZKC5LJ43723Y7W4CFZXSV7PJRDIRWSUHJJPZEAT",
-    "GDZ6LM5D5ZPX7YMMF6NWIPWDRP34PTKZ646RNOZNEFEYHXA6UB6UUZTJ",
-    "GDZ6PMWSOFLVUDPEWOOK46PCTOMVTJND3YSBJCMIL4FJTPDOUFBSSSW2",
-    "GDZ6V2MGPHBWFEP6XSIA6CYCRXOLCQIUD5TZOFVOBLH2IOGXFCUYXZC4",
-    "GDZ6XFQG7JHDSIKOR5P3SYV7DF6ADZ2RUBLOUABGHTZGYXPZUCBOGUTS",
-    "GDZ72JQQCFF6QEQIYOQ7XZN32NVQXJS76AFJLLPL2CU2F3GNKWBCAPAA",
-    "GDZ7CIBO24TIFAJLJ3DVTFSBSUGMYXCXBD7DGLVSFW5CTFBRSOVE2ZIT",
-    "GDZ7CYWQXQ6ZNFKLJ4K7GQMICVL2R4SZLPJM4GTFM2NO7XGDCOXKOTVO",
-    "GDZ7DH655V56APSIRV6TSI6BHGZSKMWWO52ABSIUEZLAORROFYOON5VU",
-    "GDZA3D7NO2SA4LVBGU5VY5FEJAXRN4Y7WZRLN5VMSUOOPEIWA5XGIQS3",
-    "GDZAQR3KR4BIUCFUY2SOYZMTQ4TPBAPCJJONTCSIDAA2WDIMFFBINDUS",
-    "GDZAT6SQA2Z2VQNEKKDRBGYO6ZSBKT46MD6XBOYQD6GNJUCAMEKVIHUZ",
-    "GDZBWGAQEZQTP3X3EJRZQBHFQFBNBORFYVUUOEL3ZI6HULPO3YPMBCMH",
-    "GDZBWJIYSDO3CALJTFNV3NTZH6PWPJ6RND3TEQXKAE2L5AQ6YUQUEXLM",
-    "GDZC6OANE6BAE6OMCC7FBIXIKJOS7YNPYIGYO3VIWMRT2CCYAVQFSQIU",
-    "GDZCAOIVCS4EXMA42BI57CSFUXHD2EDGMPD57NPQKX77535J4VJZBCBS",
-    "GDZCD4733DRRZBUTF2J3D6676IC7KA6RBYGJP5E26FX4MRQVEO7UPQ4T",
-    "GDZCEWJ5TVXUTFH6V5CVDQDE43KRXYUFRHKI7X64EWMVOVYYZJFWIFQ2",
-    "GDZCIKLCRURRKYVOVJCD3GKXGA5DYUA4QCUDXLQYY7BGDUT4FTUTIGIG",
-    "GDZCW7JLW76CDIQIFUMMCWQCDDFZVX5CL3CXU32STW6HXYY3RXE2U6K5",
-    "GDZE5EKMSI63PLZUIA72PWAEIPBH3WQR7WVH2RTDQXOQZD4P3V7X7NTY",
-    "GDZEJGT274XX52V3V4NXXBCMYL62F46TS3HB6IAQMGHAPYBMJBLSLGUF",
-    "GDZESTYYOYQFLCWT4GVVF3O7DNXVTVHHJFWAOR3GCYCKCPAIPJ55KPB4",
-    "GDZEVBJZHRTS5UOKYKNWT5QXFGBSAO6DWHSO3HM7TEWZDDYVGPXP6EQ6",
-    "GDZF5ULZ435W2GQ36RAG2QG4FEV534A4KQ4XN2EXE3ZA6SSKT6STBUSG",
-    "GDZFCT77AWTIMMDB537WNYSSWNMR26QM7XHXNM4C7MNRJK7XLMTAJJJU",
-    "GDZFDUYWPMSC5GRPGEP735R4X6YJMOGLBXDZTRO5KPX2RWLUXZLJGCCI",
-    "GDZFWW6A7KLEF46QKAR4Y33XXWTTPATCOKL4CGFH5BXMOPT3PZ5PHN2F",
-    "GDZFXMZGQO2YMK7ED3QIHBKDPXU4EM3IXTD4QPT6OM6V7H35U7POUVZR",
-    "GDZFZTWPTGG77I7E6C7EREBCNMJRF6WPW6IYF6FID7GASS2ABMLZOLIE",
-    "GDZGDDQREBBOMK3OGG3IRLFDXW226CWKFQ4W5HNQKP54NQQE3GJBIXLM",
-    "GDZGFO2FIFD42DLDH4GJXGK2XWUTIYIEVQ5KRTVMGLFX64EFECBKN7GO",
-    "GDZHVNXVRW46NYRPEJ3272FFEE3BZRFTGDAGW53LXGYCUNOMRRPOTKHZ",
-    "GDZI3HBRLFA655GGSEDPZAR2SOQ2C4N25XNP5GJZZPGCAPSBNQLPMWPP",
-    "GDZICOWZWO3ZM4FTA7SK5KSK2T64P6LGEWLWDMOZZFT57ZUWZYA2ZXMR",
-    "GDZIFYPFZJQSLL535NWHUWRX4YEOREFRDOHBZ4XYJBI3FS2WBNXGZXRP",
-    "GDZIMUJH5SJWPCVUDUZNBZD2AA4SELFK4Q3JS5KSRB7AWHY4X5ISWISS",
-    "GDZJ7BT44IBPFKYK622GP5QAFIK7RCF4FH55PEDPW243ATKBU7QGGZ4G",
-    "GDZK7EODRYTJXLYDB3GXBK53HLPJQH54LMP2PWDT77NRY3M76URCL2KP",
-    "GDZKERN75FG3GANAOFQIKIT5EC3DRWEIXNH24PCMYB3ZKJ6IMG35XCRP",
-    "GDZLFJFNCTUKBOJXLBY5BWGQFDKMK3EG3QS5XEOMTTOPB4QSCCRHRQNT",
-    "GDZLT5IWY3TG644KVJNNYXBVEZIB2KQMPPWG4OWXNXL7H4WG7J3YFKG6",
-    "GDZLZOLVTRLYYKFZV3D7SR2PZEN4DCR4BGWGQRJIXSUXTK2DSEKVIF5E",
-    "GDZMA6ROR4TKXVVXCLLDJ6TJVMZPUM3FJS6NKWGO3HXRFJPC3SQYBZOE",
-    "GDZNDSSGWMLYD4724SDB7G6SEYIFCZ4WWD6K2RKU5SHMG3JW5Y2ITAVX",
-    "GDZO2S6UBDBGZAHQIDTRGJBEBYOJPT4FAQEUBYQ6KWHNJWG2XRCOAADZ",
-    "GDZOIZKTPI4LN4XPMSKFKXNS5U2EB5UP7H3OENEAQ2E7OJEEI5IXSXAT",
-    "GDZPPM5F2WNJNGEDKMZDLHDIY4OL5X6UCVX7M6OH7RWDNR655U64CQFS",
-    "GDZPPN3VEQGTFLJ7JOERZNNXSO4NWEBZC6O2CCYIDHCXMUOK526EYSBR",
-    "GDZPPQCAJORMGBPXD3LQ5GIQRVRTENZHX4RFAIN2JOFPBYA76STTKQUB",
-    "GDZPQBLYBSMGBFI5J2U7CQKA2SCHKQI2UJEGQ7MKN7DLUHFGXKPLU44B",
-    "GDZPWB7XCYTQ5QVZEPTD5U7PHHE6K5WBIKMUWL55MXQAT3RON4STLRCH",
-    "GDZQ4AFTMA4T4Q735MMQJ2L45S6CWIHH5EOEL2RZJJRZCWMIYTOVMLJR",
-    "GDZQ7FYDV2AGGTBTPYMEWCUR35QIOT7LBOQCWC7PT3XARORKOTGIMV2B",
-    "GDZQENKWW2KE64WSV7IR5IUKH7PITOCB2QQNTPWJ2TZ6U6INQU444LQU",
-    "GDZQET52VIILUBRP6VXR5KIR56NZQ3WUH3ODMKQ3T5BKJRG35RAXDAOR",
-    "GDZQNNBMLPWYTSJJXQ3V7ZS22KKOBPTUQP7MZRNWLC2UZ37QUHOJCQFS",
-    "GDZQQH76SS3JWPL7K72STAJ2C6GNVOLGSUSUAPRR5345CVFCAIJJELON",
-    "GDZRAG76HGSMYEKVGFA53HIH4XVYQKROLZKIG5T7SW5FVLMRUXU3PLTR",
-    "GDZRJGD6XZP2WVSRDVULAZSMKZQ65SWXRDPUQNHSD6GYDRLGX26ZGGJB",
-    "GDZRO53MWUBHJN4JLWFYIGHVWIXBPHPDVECSJSVJQIJKXAJSH7DHZJMM",
-    "GDZSI4DM3VJNBF5MG2NFW6P3TQSVJHSIWFNYG73N54SSZ4ZL5D35ZJ66",
-    "GDZSMRQH3LMZXXCGG53ROCVQBBEK7LVODJPWDON2T56EM5CE7IIATHBL",
-    "GDZSPH55LKGNOLFCJ6BA5ETCUXK4DERKKCGDTGPDOZBPHQOXHSXOUXXC",
-    "GDZSPRMIXC36QBCR55DNP6YTPRLPVQRT374ZF4VYP55ZBBHGK5H4LATV",
-    "GDZSQNXIUUZO4IVB52YXHW7CUF7DK7CCZZYMZCIYXSTGV3Q2GD44ELON",
-    "GDZSUECWQO3ARS6EIXUOTDW72FOFMJULA625HQGWEJDOJQKUYYXSHARE",
-    "GDZSVCDFUNGEGP6ONQQ62CCJI2VUIMOGMVPHPC2SFOTIGKQVETJFZWJA",
-    "GDZSWZFGOUGWV2IYSL6HD7FG4ISN4GVCSXJQBNUL2N27ZFMK6D7JXBUK",
-    "GDZSZFFYLSLSMNSLAGYTRQA46RP2P7NFMTTVRIIXQHSKHQXPE4GVJCHF",
-    "GDZT2M24ZSD2Y6GDCWOG3B2GT2WDLGDGQKPH2FQXJPGS7VZ3ZKESRU5H",
-    "GDZT4KMUSRJVUC2JKM7EQOV3KRYOXVDMR6TISS6DANAXTREVUNQUKL3F",
-    "GDZTPRILPME7FEVOX6XOVGBU7TFWH5DQKAKWUDCET3FNK44AETLYEB3Z",
-    "GDZU7TJTFTYS4MT2NX2DKGJFSULVBS5QDIWH5MC4WFYPPJ3ZSZILR4JQ",
-    "GDZUQLEZAT46PFY6ORP7RYOEM26DJ7VEPNSC2KQBTR3HFADJJL5URBL4",
-    "GDZUVJOLOLVW5KQ5BN3PDEB3WDVKSPNC6VFPMNRKDN6P46MZ7T5ME3VX",
-    "GDZVU36FJJAKCB4RRQLJG7HGC4ER4GION2LEDWKVLKAGW7VEJ4FRUBZB",
-    "GDZWAODNEG4Q5FRLTK6MWG2HDWLTFJD6QIAVFWN5NF2RZVQ2OAU7LINE",
-    "GDZWEQIFMJDIV37QQ2KEPQYSDXXBCPDTFFVBAORVKCAOV55IMZSZR3AA",
-    "GDZWIX24B7QKKMENYW4YLEBEVRZR6WFVTREQN5MIRV7UH7UCNBVYZ3IC",
-    "GDZWVB72Y4NHKYYWDMQAJCPKJRU2DWO7KLVFS6DWZIDTJ3Q76FVNB3XI",
-    "GDZX6GS3MSMTXW2RGKLUACR737BPVGUS3I3YOIAXVQFF4FK5M3ZYFCHN",
-    "GDZXDOUVW4WW7R4FQ62DFJ4SIVWL6YBOJDUEBRDY4RQFBR5NLCHMFHK5",
-    "GDZXFA6VSUUZYYRK7TYMTAMNZ5SH6D2QGJMH3MMZTSHTIRGHHIQLYI26",
-    "GDZXGBUFBRL6PFVLTHOELOKCNMDU5RFQP54U7AVL7HV3G7AVLUG5SBTC",
-    "GDZXGET77RCXBL6Z5YX5W5C4MTI5UB3PAX6H7W73HQBPBYTNAH7XP7PP",
-    "GDZXTDUDEPNACPIL2OKQKQ5EV5UNCFSIGRYD5DTXMZ2AW6WXHVFHSMON",
-    "GDZXUD45BEBHKAB7LOTYLOMISGWT7EXLUSZEXFEWYQTO65YJJUWZ4VN4",
-    "GDZYAGP6JITNTCVIHP52P3L45C3IUMNZ3EPQLMQMOXRGM2MMFILKAHPN",
-    "GDZYEXGRQHKTAW4F7IAJZQM452NT6BEBFWWBSFBNN77KSQRZJDI2Y4RT",
-    "GDZYFNOQJIMDNZQYIWZOWLOLAIOMY2VNX24VKLSMJXL2HF33SCHFLARE",
-    "GDZYGHTGHM473J6554XSNOBIKZ4KVOXR7DX6L3JGCEEG4TJ3MB2VCNYV",
-    "GDZYO6TNYMZEZV5437QQ6NMO2FFWXQIAWOAC5IZO5IHSPZPHCED6LVEV",
-    "GDZZEJPAY2M4BU5EZ3H2V3HPNYHQUQLUUHKR4OQAM2RM453FRDZUOZJF",
-    "GDZZPUCFCLGKF5C3LPGSKKOVQSLZHPBCQLOLLMLQUEH4MFDKKV7K3OPR",
-];
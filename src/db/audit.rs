@@ -0,0 +1,569 @@
+use std::collections::{BTreeMap, HashMap};
+
+use zephyr_sdk::{DatabaseInteract, EnvClient};
+
+use super::{
+    exchange_rate::{calculate_exchange_rates, latest_revisions, RatesDbRow, VOID_ALGORITHM},
+    savepoint::{active_savepoints, Savepoint, RETIRED_INTERVAL},
+    swap::{SwapAmounts, SwapDbRow},
+};
+use crate::config::DEFAULT_RATE_STRATEGY;
+
+/**
+ * The kinds of problem an audit looks for. Each can be repaired on its own.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Issue {
+    // Copies of a swap, which share its ledger and position, as when a ledger
+    // is ingested twice. Swaps saved before they had an identity cannot be
+    // told apart from genuine trades of the same amounts, so they are not
+    // checked
+    DuplicateSwaps,
+    // Rates that are zero or negative, which is what NaNs were migrated to
+    InvalidRates,
+    // Rates of windows in which their asset has no swaps
+    UnbackedRates,
+    // More than one savepoint for a rate window, which stalls its rates
+    MultipleSavepoints,
+    // Swaps without USDC, which have no price
+    ZeroDenominators,
+    // Swaps and rates dated after the latest ledger
+    FutureTimestamps,
+}
+
+impl Issue {
+    pub(crate) const ALL: [Issue; 6] = [
+        Issue::DuplicateSwaps,
+        Issue::InvalidRates,
+        Issue::UnbackedRates,
+        Issue::MultipleSavepoints,
+        Issue::ZeroDenominators,
+        Issue::FutureTimestamps,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Issue::DuplicateSwaps => "duplicate_swaps",
+            Issue::InvalidRates => "invalid_rates",
+            Issue::UnbackedRates => "unbacked_rates",
+            Issue::MultipleSavepoints => "multiple_savepoints",
+            Issue::ZeroDenominators => "zero_denominators",
+            Issue::FutureTimestamps => "future_timestamps",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Issue::ALL.into_iter().find(|issue| issue.name() == name)
+    }
+}
+
+/**
+ * What an audit found. Rates are only checked in their latest revision, since
+ * the ones they supersede are no longer served.
+ */
+pub(crate) struct Audit {
+    // The close time of the latest ledger, which later dates are compared to
+    pub(crate) checked_at: u64,
+    pub(crate) swaps_scanned: usize,
+    pub(crate) rates_scanned: usize,
+    // One of each group of copies of a swap, with the number of copies
+    pub(crate) duplicate_swaps: Vec<(SwapDbRow, usize)>,
+    pub(crate) invalid_rates: Vec<RatesDbRow>,
+    pub(crate) unbacked_rates: Vec<RatesDbRow>,
+    // The savepoints of each interval that has more than one
+    pub(crate) multiple_savepoints: Vec<(u64, Vec<u64>)>,
+    pub(crate) zero_denominators: Vec<SwapDbRow>,
+    pub(crate) future_swaps: Vec<SwapDbRow>,
+    pub(crate) future_rates: Vec<RatesDbRow>,
+}
+
+impl Audit {
+    pub(crate) fn count(&self, issue: Issue) -> usize {
+        match issue {
+            Issue::DuplicateSwaps => self.duplicate_swaps.len(),
+            Issue::InvalidRates => self.invalid_rates.len(),
+            Issue::UnbackedRates => self.unbacked_rates.len(),
+            Issue::MultipleSavepoints => self.multiple_savepoints.len(),
+            Issue::ZeroDenominators => self.zero_denominators.len(),
+            Issue::FutureTimestamps => self.future_swaps.len() + self.future_rates.len(),
+        }
+    }
+}
+
+/**
+ * Check the swaps and rates dated within a range, from (inclusive) to
+ * (exclusive), along with every savepoint and anything dated after the latest
+ * ledger. Voided rows are left out, as they are what repairs leave behind.
+ */
+pub(crate) fn run_audit(
+    client: &EnvClient,
+    from: u64,
+    to: u64,
+    checked_at: u64,
+) -> Result<Audit, String> {
+    let rates = latest_revisions(
+        client
+            .read_filter()
+            .column_gt("timestamp", from.saturating_sub(1))
+            .column_lt("timestamp", to)
+            .read::<RatesDbRow>()
+            .map_err(|sdk_error| sdk_error.to_string())?,
+        None,
+    );
+    let rates_scanned = rates.len();
+    let rates = rates
+        .into_iter()
+        .filter(|row| !row.is_void())
+        .collect::<Vec<_>>();
+
+    // The windows of the first rates may start before the range, and their
+    // swaps are needed to tell whether the rates are backed
+    let swaps_from = rates
+        .iter()
+        .map(|row| row.timestamp.saturating_sub(row.interval))
+        .fold(from, u64::min);
+    let all_swaps = client
+        .read_filter()
+        .column_gt("creation", swaps_from.saturating_sub(1))
+        .column_lt("creation", to)
+        .read::<SwapDbRow>()
+        .map_err(|sdk_error| sdk_error.to_string())?
+        .into_iter()
+        .filter(|swap| !swap.is_void())
+        .collect::<Vec<_>>();
+    let swaps = all_swaps
+        .iter()
+        .filter(|swap| swap.creation >= from)
+        .collect::<Vec<_>>();
+
+    let mut copies = BTreeMap::<_, (&SwapDbRow, usize)>::new();
+    for swap in &swaps {
+        if let Some(identity) = swap.identity() {
+            copies.entry(identity).or_insert((swap, 0)).1 += 1;
+        }
+    }
+    let duplicate_swaps = copies
+        .into_values()
+        .filter(|(_, count)| *count > 1)
+        .map(|(swap, count)| (swap.clone(), count))
+        .collect();
+
+    let zero_denominators = swaps
+        .iter()
+        .filter(|swap| swap.usdcamnt <= 0)
+        .map(|swap| (*swap).clone())
+        .collect();
+
+    // The times of the priced swaps of each asset, to look up the windows of
+    // its rates
+    let mut swap_times = HashMap::<(&str, &str), Vec<u64>>::new();
+    for swap in all_swaps.iter().filter(|swap| swap.usdcamnt > 0) {
+        swap_times
+            .entry((&swap.floatcode, &swap.fltissuer))
+            .or_default()
+            .push(swap.creation);
+    }
+    swap_times.values_mut().for_each(|times| times.sort());
+
+    let (invalid_rates, valid_rates): (Vec<_>, Vec<_>) = rates
+        .iter()
+        .partition(|row| row.fixedrate <= 0 || row.fixedvol <= 0);
+    let unbacked_rates = valid_rates
+        .into_iter()
+        .filter(|row| {
+            let window_start = row.timestamp.saturating_sub(row.interval);
+            swap_times
+                .get(&(row.floatcode.as_str(), row.fltissuer.as_str()))
+                .is_none_or(|times| {
+                    let first = times.partition_point(|time| *time < window_start);
+                    times.get(first).is_none_or(|time| *time >= row.timestamp)
+                })
+        })
+        .cloned()
+        .collect();
+
+    let mut savepoints = BTreeMap::<u64, Vec<u64>>::new();
+    for savepoint in active_savepoints(client) {
+        savepoints
+            .entry(savepoint.interval)
+            .or_default()
+            .push(savepoint.savepoint);
+    }
+    let multiple_savepoints = savepoints
+        .into_iter()
+        .filter(|(_, savepoints)| savepoints.len() > 1)
+        .collect();
+
+    // Before the first ledger is ingested, there is no time to compare with
+    let (future_swaps, future_rates) = if checked_at == 0 {
+        (vec![], vec![])
+    } else {
+        let future_swaps = client
+            .read_filter()
+            .column_gt("creation", checked_at)
+            .read::<SwapDbRow>()
+            .map_err(|sdk_error| sdk_error.to_string())?
+            .into_iter()
+            .filter(|swap| !swap.is_void())
+            .collect();
+        let future_rates = latest_revisions(
+            client
+                .read_filter()
+                .column_gt("timestamp", checked_at)
+                .read::<RatesDbRow>()
+                .map_err(|sdk_error| sdk_error.to_string())?,
+            None,
+        )
+        .into_iter()
+        .filter(|row| !row.is_void())
+        .collect();
+
+        (future_swaps, future_rates)
+    };
+
+    Ok(Audit {
+        checked_at,
+        swaps_scanned: swaps.len(),
+        rates_scanned,
+        duplicate_swaps,
+        invalid_rates: invalid_rates.into_iter().cloned().collect(),
+        unbacked_rates,
+        multiple_savepoints,
+        zero_denominators,
+        future_swaps,
+        future_rates,
+    })
+}
+
+/**
+ * Fix the given kinds of issue an audit found, and return how many rows of
+ * each were fixed. There is no way to delete rows, so:
+ * - Swaps are voided by zeroing their amounts. All the copies of a duplicate
+ *   swap are voided, and one is saved again.
+ * - Rates are superseded by a new revision, recomputed from the swaps of their
+ *   window, or voided if the window yields no valid rate for their asset.
+ *   Rates dated in the future are voided.
+ * - The savepoints of an interval are all retired, and the latest one is saved
+ *   again, so that no window is saved twice.
+ */
+pub(crate) fn repair_issues(
+    client: &EnvClient,
+    audit: &Audit,
+    issues: &[Issue],
+) -> Result<Vec<(Issue, usize)>, String> {
+    // Each issue is repaired once, however many times it was asked for
+    Issue::ALL
+        .into_iter()
+        .filter(|issue| issues.contains(issue))
+        .map(|issue| {
+            let repaired = match issue {
+                Issue::DuplicateSwaps => {
+                    let swaps = duplicates_to_repair(audit, issues);
+                    for swap in &swaps {
+                        void_swap(client, swap)?;
+                        swap.put(client);
+                    }
+                    swaps.len()
+                }
+                Issue::InvalidRates | Issue::UnbackedRates => {
+                    let rows = rates_to_repair(audit, issue, issues);
+                    recompute_rates(client, &rows, audit)?;
+                    rows.len()
+                }
+                Issue::MultipleSavepoints => {
+                    for (interval, savepoints) in &audit.multiple_savepoints {
+                        let latest = savepoints.iter().copied().max().unwrap_or_default();
+
+                        client
+                            .update()
+                            .column_equal_to("interval", *interval)
+                            .execute(&Savepoint {
+                                savepoint: latest,
+                                interval: RETIRED_INTERVAL,
+                            })
+                            .map_err(|sdk_error| sdk_error.to_string())?;
+                        Savepoint {
+                            savepoint: latest,
+                            interval: *interval,
+                        }
+                        .put(client);
+                    }
+                    audit.multiple_savepoints.len()
+                }
+                Issue::ZeroDenominators => {
+                    for swap in &audit.zero_denominators {
+                        void_swap(client, swap)?;
+                    }
+                    audit.zero_denominators.len()
+                }
+                Issue::FutureTimestamps => {
+                    for swap in &audit.future_swaps {
+                        void_swap(client, swap)?;
+                    }
+                    let rows = rates_to_repair(audit, issue, issues);
+                    for row in &rows {
+                        supersede_rate(client, row, None, audit.checked_at);
+                    }
+                    audit.future_swaps.len() + rows.len()
+                }
+            };
+
+            Ok((issue, repaired))
+        })
+        .collect()
+}
+
+/**
+ * The rates an issue supersedes. A rate dated in the future can also be
+ * invalid or unbacked; when future timestamps are repaired too, it is only
+ * voided with them, so that no two new revisions of a rate share a number.
+ */
+fn rates_to_repair<'a>(audit: &'a Audit, issue: Issue, issues: &[Issue]) -> Vec<&'a RatesDbRow> {
+    let rows = match issue {
+        Issue::InvalidRates => &audit.invalid_rates,
+        Issue::UnbackedRates => &audit.unbacked_rates,
+        Issue::FutureTimestamps => return audit.future_rates.iter().collect(),
+        _ => return vec![],
+    };
+    let voids_future_rates = issues.contains(&Issue::FutureTimestamps);
+
+    rows.iter()
+        .filter(|row| {
+            !voids_future_rates
+                || !audit
+                    .future_rates
+                    .iter()
+                    .any(|future| is_same_revision(row, future))
+        })
+        .collect()
+}
+
+/**
+ * The duplicate swaps to repair. Those that another issue voids are left to
+ * it, so that no copy of them is saved again.
+ */
+fn duplicates_to_repair<'a>(audit: &'a Audit, issues: &[Issue]) -> Vec<&'a SwapDbRow> {
+    let voided = |swaps: &[SwapDbRow], issue: Issue, swap: &SwapDbRow| {
+        issues.contains(&issue)
+            && swaps
+                .iter()
+                .any(|other| other.identity() == swap.identity())
+    };
+
+    audit
+        .duplicate_swaps
+        .iter()
+        .map(|(swap, _)| swap)
+        .filter(|swap| {
+            !voided(&audit.zero_denominators, Issue::ZeroDenominators, swap)
+                && !voided(&audit.future_swaps, Issue::FutureTimestamps, swap)
+        })
+        .collect()
+}
+
+fn is_same_revision(row: &RatesDbRow, other: &RatesDbRow) -> bool {
+    (
+        &row.floatcode,
+        &row.fltissuer,
+        row.interval,
+        row.timestamp,
+        row.revision,
+    ) == (
+        &other.floatcode,
+        &other.fltissuer,
+        other.interval,
+        other.timestamp,
+        other.revision,
+    )
+}
+
+// Copies of a swap share its identity, so they are all voided. Swaps saved
+// before they had one are matched on every column.
+fn void_swap(client: &EnvClient, swap: &SwapDbRow) -> Result<(), String> {
+    let mut update = client.update();
+    if swap.identity().is_some() {
+        update
+            .column_equal_to("ledger", swap.ledger)
+            .column_equal_to("txindex", swap.txindex)
+            .column_equal_to("opindex", swap.opindex)
+            .column_equal_to("claimidx", swap.claimidx);
+    } else {
+        update
+            .column_equal_to("creation", swap.creation)
+            .column_equal_to("floatcode", swap.floatcode.clone())
+            .column_equal_to("fltissuer", swap.fltissuer.clone())
+            .column_equal_to("usdcamnt", swap.usdcamnt)
+            .column_equal_to("fltamnt", swap.fltamnt)
+            .column_equal_to("fltdecs", swap.fltdecs);
+    }
+
+    update
+        .execute(&SwapAmounts {
+            usdcamnt: 0,
+            fltamnt: 0,
+            fltdecs: swap.fltdecs,
+        })
+        .map_err(|sdk_error| sdk_error.to_string())
+}
+
+// Each window is recomputed once, however many of its rates are repaired
+fn recompute_rates(client: &EnvClient, rows: &[&RatesDbRow], audit: &Audit) -> Result<(), String> {
+    let mut windows = BTreeMap::<(u64, u64), Vec<&RatesDbRow>>::new();
+    for row in rows {
+        windows
+            .entry((row.interval, row.timestamp))
+            .or_default()
+            .push(row);
+    }
+
+    for ((interval, window_end), rows) in windows {
+        let rates = calculate_exchange_rates(
+            client,
+            window_end.saturating_sub(interval),
+            window_end,
+            DEFAULT_RATE_STRATEGY,
+        )?;
+
+        for row in rows {
+            let rate = rates
+                .get(&(row.floatcode.clone(), row.fltissuer.clone()))
+                .filter(|(rate, volume)| *rate > 0 && *volume > 0);
+            supersede_rate(client, row, rate.copied(), audit.checked_at);
+        }
+    }
+
+    Ok(())
+}
+
+// The row is the latest revision, so the next one supersedes it
+fn supersede_rate(client: &EnvClient, row: &RatesDbRow, rate: Option<(i128, i128)>, computed: u64) {
+    let (fixedrate, fixedvol, algo) = match rate {
+        Some((rate, volume)) => (rate, volume, DEFAULT_RATE_STRATEGY.algorithm()),
+        None => (0, 0, VOID_ALGORITHM),
+    };
+
+    RatesDbRow {
+        fixedrate,
+        fixedvol,
+        revision: row.revision + 1,
+        computed,
+        algo: algo.to_string(),
+        ..row.clone()
+    }
+    .put(client);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate(code: &str, timestamp: u64) -> RatesDbRow {
+        RatesDbRow {
+            timestamp,
+            floatcode: code.to_string(),
+            fltissuer: "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA".to_string(),
+            fixedrate: 5,
+            fixedvol: 1,
+            interval: 3600,
+            winstart: timestamp - 3600,
+            revision: 0,
+            computed: timestamp,
+            algo: "volume_weighted/2".to_string(),
+        }
+    }
+
+    fn audit(unbacked_rates: Vec<RatesDbRow>, future_rates: Vec<RatesDbRow>) -> Audit {
+        Audit {
+            checked_at: 7200,
+            swaps_scanned: 0,
+            rates_scanned: unbacked_rates.len(),
+            duplicate_swaps: vec![],
+            invalid_rates: vec![],
+            unbacked_rates,
+            multiple_savepoints: vec![],
+            zero_denominators: vec![],
+            future_swaps: vec![],
+            future_rates,
+        }
+    }
+
+    fn swap(ledger: u64, claimidx: u64) -> SwapDbRow {
+        SwapDbRow {
+            creation: 3600,
+            floatcode: "AQUA".to_string(),
+            fltissuer: "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA".to_string(),
+            usdcamnt: 10_000_000,
+            fltamnt: 5_000_000,
+            fltdecs: 7,
+            ledger,
+            txindex: 3,
+            opindex: 0,
+            claimidx,
+        }
+    }
+
+    fn codes(rows: Vec<&RatesDbRow>) -> Vec<&str> {
+        rows.into_iter().map(|row| row.floatcode.as_str()).collect()
+    }
+
+    #[test]
+    fn a_rate_in_two_classes_is_superseded_once() {
+        let audit = audit(
+            vec![rate("AQUA", 3600), rate("yXLM", 10_800)],
+            vec![rate("yXLM", 10_800)],
+        );
+        let issues = [Issue::UnbackedRates, Issue::FutureTimestamps];
+
+        assert_eq!(
+            codes(rates_to_repair(&audit, Issue::UnbackedRates, &issues)),
+            ["AQUA"]
+        );
+        assert_eq!(
+            codes(rates_to_repair(&audit, Issue::FutureTimestamps, &issues)),
+            ["yXLM"]
+        );
+    }
+
+    #[test]
+    fn a_future_rate_is_recomputed_unless_future_timestamps_are_repaired() {
+        let audit = audit(vec![rate("yXLM", 10_800)], vec![rate("yXLM", 10_800)]);
+
+        assert_eq!(
+            codes(rates_to_repair(
+                &audit,
+                Issue::UnbackedRates,
+                &[Issue::UnbackedRates]
+            )),
+            ["yXLM"]
+        );
+    }
+
+    #[test]
+    fn swaps_saved_before_their_identity_have_none() {
+        assert_eq!(swap(0, 2).identity(), None);
+        assert_eq!(swap(54_975_124, 2).identity(), Some((54_975_124, 3, 0, 2)));
+    }
+
+    #[test]
+    fn a_duplicate_voided_by_another_issue_is_not_saved_again() {
+        let mut audit = audit(vec![], vec![]);
+        audit.duplicate_swaps = vec![(swap(100, 0), 2), (swap(100, 1), 3), (swap(200, 0), 2)];
+        audit.zero_denominators = vec![swap(100, 1)];
+        audit.future_swaps = vec![swap(200, 0)];
+        let ledgers_and_claims = |swaps: Vec<&SwapDbRow>| {
+            swaps
+                .into_iter()
+                .map(|swap| (swap.ledger, swap.claimidx))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ledgers_and_claims(duplicates_to_repair(&audit, &Issue::ALL)),
+            [(100, 0)]
+        );
+        assert_eq!(
+            ledgers_and_claims(duplicates_to_repair(&audit, &[Issue::DuplicateSwaps])),
+            [(100, 0), (100, 1), (200, 0)]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("composite")]
+/**
+ * Assets from several issuers that stand for the same underlying asset, e.g.
+ * the many BTC anchors, priced together in composite mode. Members are kept
+ * as "code:issuer" pairs separated by commas; a composite without members is
+ * disabled.
+ */
+pub(crate) struct CompositeAsset {
+    pub(crate) code: String,
+    pub(crate) members: String,
+}
+
+impl CompositeAsset {
+    pub(crate) fn new(code: &str, members: &[(String, String)]) -> Self {
+        CompositeAsset {
+            code: code.to_string(),
+            members: members
+                .iter()
+                .map(|(code, issuer)| format!("{code}:{issuer}"))
+                .collect::<Vec<_>>()
+                .join(","),
+        }
+    }
+
+    pub(crate) fn members(&self) -> Vec<(String, String)> {
+        self.members
+            .split(',')
+            .filter_map(|member| member.split_once(':'))
+            .map(|(code, issuer)| (code.to_string(), issuer.to_string()))
+            .collect()
+    }
+}
+
+pub(crate) fn composite_asset(
+    client: &EnvClient,
+    code: &str,
+) -> Result<Option<CompositeAsset>, String> {
+    client
+        .read_filter()
+        .column_equal_to("code", code.to_string())
+        .read::<CompositeAsset>()
+        .map(|rows| rows.into_iter().next())
+        .map_err(|sdk_error| sdk_error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn members_are_kept_as_code_and_issuer_pairs() {
+        let members = vec![
+            (
+                "BTC".to_string(),
+                "GDPJALI4AZKUU2W426U5WKMAT6CN3AJRPIIRYR2YM54TL2GDWO5O2MZM".to_string(),
+            ),
+            (
+                "BTC".to_string(),
+                "GATEMHCCKCY67ZUCKTROYN24ZYT5GK4EQZ65JJLDHKHRUZI3EUEKMTCH".to_string(),
+            ),
+        ];
+        let composite = CompositeAsset::new("BTC", &members);
+
+        assert_eq!(
+            composite.members,
+            "BTC:GDPJALI4AZKUU2W426U5WKMAT6CN3AJRPIIRYR2YM54TL2GDWO5O2MZM,\
+             BTC:GATEMHCCKCY67ZUCKTROYN24ZYT5GK4EQZ65JJLDHKHRUZI3EUEKMTCH"
+        );
+        assert_eq!(composite.members(), members);
+    }
+
+    #[test]
+    fn a_composite_without_members_is_disabled() {
+        assert!(CompositeAsset::new("BTC", &[]).members().is_empty());
+        assert!(CompositeAsset {
+            code: "BTC".to_string(),
+            members: "BTC,ETH".to_string()
+        }
+        .members()
+        .is_empty());
+    }
+}
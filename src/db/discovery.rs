@@ -0,0 +1,85 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+use crate::{
+    config::{ESTABLISHED_AGE, ESTABLISHED_TRADES, USDC},
+    fixed_point::to_decimal_string,
+    utils::parse_date,
+};
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("discovery")]
+/**
+ * A classic asset that was traded against USDC but is not in the registry, so
+ * its swaps were not saved. The totals accumulate from the first time we saw
+ * it, and help decide which assets are worth onboarding. Rows without trades
+ * are void: they were merged into another when their code was repaired.
+ */
+pub(crate) struct DiscoveredAsset {
+    pub(crate) floatcode: String,
+    pub(crate) fltissuer: String,
+    // USDC traded, in stroops
+    pub(crate) usdcvol: i128,
+    pub(crate) trades: u64,
+    pub(crate) firstseen: u64,
+    pub(crate) lastseen: u64,
+}
+
+impl DiscoveredAsset {
+    pub(crate) fn volume_string(&self) -> String {
+        to_decimal_string(self.usdcvol, USDC.decimals)
+    }
+
+    pub(crate) fn first_seen_iso8601(&self) -> String {
+        parse_date(&(self.firstseen as i64))
+    }
+
+    pub(crate) fn last_seen_iso8601(&self) -> String {
+        parse_date(&(self.lastseen as i64))
+    }
+
+    pub(crate) fn is_void(&self) -> bool {
+        self.trades == 0
+    }
+
+    /**
+     * Whether the asset has been traded against USDC often enough, and for
+     * long enough, to have a track record.
+     */
+    pub(crate) fn is_established(&self) -> bool {
+        self.trades >= ESTABLISHED_TRADES
+            && self.lastseen.saturating_sub(self.firstseen) >= ESTABLISHED_AGE
+    }
+}
+
+// Every invocation of the program runs in a fresh instance, so this caches the
+// table for a single ledger close or function call.
+static DISCOVERIES: OnceLock<HashMap<(String, String), DiscoveredAsset>> = OnceLock::new();
+
+/**
+ * What discovery knows of an asset we do not index, if anything.
+ */
+pub(crate) fn discovered_asset(code: &str, issuer: &str) -> Option<&'static DiscoveredAsset> {
+    DISCOVERIES
+        .get_or_init(|| {
+            EnvClient::empty()
+                .read::<DiscoveredAsset>()
+                .into_iter()
+                .filter(|asset| !asset.is_void())
+                .map(|asset| ((asset.floatcode.clone(), asset.fltissuer.clone()), asset))
+                .collect()
+        })
+        .get(&(code.to_string(), issuer.to_string()))
+}
+
+/**
+ * A swap of USDC for an asset we do not index. Only what discovery needs is
+ * kept.
+ */
+#[derive(Clone, Debug)]
+pub(crate) struct UnlistedSwap {
+    pub(crate) code: String,
+    pub(crate) issuer: String,
+    pub(crate) usdc_amount: i128,
+}
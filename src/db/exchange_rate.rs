@@ -3,46 +3,212 @@ use std::collections::HashMap;
 use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
 
 use super::swap::SwapDbRow;
-use crate::{config::CONVERSION_FACTOR, utils::parse_date};
+use crate::{
+    config::{RATE_DECIMALS, USDC},
+    fixed_point::{ratio, rescale, to_decimal_string},
+    utils::{interval_name, parse_date},
+};
 
-type UsdVolume = f64;
-type WeightedSum = f64;
-type ExchangeRate = f64;
-pub(crate) type ExchangeRateMap = HashMap<String, (ExchangeRate, UsdVolume)>;
+// Floating coin per USDC, multiplied by 10^RATE_DECIMALS
+type ExchangeRate = i128;
+// USDC swapped, in stroops
+type UsdVolume = i128;
+type FloatingVolume = i128;
+// Keyed on the code and issuer of the floating asset
+pub(crate) type ExchangeRateMap = HashMap<(String, String), (ExchangeRate, UsdVolume)>;
+
+// The algorithm of the revisions that withdraw a rate, written when a replay
+// or an audit repair finds it should not exist. They have a rate of zero,
+// which the API never serves.
+pub(crate) const VOID_ALGORITHM: &str = "void";
 
 #[derive(Clone, DatabaseDerive)]
 #[with_name("rates")]
 pub(crate) struct RatesDbRow {
+    // The rate covers the swaps from `winstart` (inclusive) to `timestamp`
+    // (exclusive); both are aligned to the length of the window.
+    pub(crate) timestamp: u64,
+    pub(crate) floatcode: String,
+    pub(crate) fltissuer: String,
+    pub(crate) fixedrate: ExchangeRate,
+    pub(crate) fixedvol: UsdVolume,
+    pub(crate) interval: u64,
+    pub(crate) winstart: u64,
+    // Rows computed on ledger close are revision 0. Replaying a window writes
+    // new rows with the next revision, keeping the ones they supersede.
+    pub(crate) revision: u64,
+    // When the row was computed, as the close time of the latest ledger
+    pub(crate) computed: u64,
+    // The strategy and its version, so corrections can be traced to a change
+    pub(crate) algo: String,
+}
+
+/**
+ * Rates saved before they became fixed-point integers. The floating-point
+ * columns are kept until every row has been migrated to the new ones.
+ */
+#[derive(Clone, DatabaseDerive)]
+#[with_name("rates")]
+pub(crate) struct LegacyRatesDbRow {
     pub(crate) timestamp: u64,
     pub(crate) floatcode: String,
     pub(crate) fltissuer: String,
     pub(crate) rate: f64,
     pub(crate) volume: f64,
+    pub(crate) interval: u64,
+    pub(crate) revision: u64,
+}
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("rates")]
+pub(crate) struct RateAmounts {
+    pub(crate) fixedrate: ExchangeRate,
+    pub(crate) fixedvol: UsdVolume,
+}
+
+// Just the asset code, to repair the codes saved without their digits
+#[derive(Clone, DatabaseDerive)]
+#[with_name("rates")]
+pub(crate) struct RateCode {
+    pub(crate) floatcode: String,
 }
 
 impl RatesDbRow {
     pub(crate) fn timestamp_iso8601(&self) -> String {
         parse_date(&(self.timestamp as i64))
     }
-}
 
-impl From<(&String, &(f64, f64))> for RatesDbRow {
-    fn from((floating_asset, (rate, volume)): (&String, &(f64, f64))) -> Self {
-        let (floatcode, fltissuer) = floating_asset.split_once('_').unwrap();
+    pub(crate) fn window_start_iso8601(&self) -> String {
+        parse_date(&(self.winstart as i64))
+    }
 
+    pub(crate) fn computed_iso8601(&self) -> String {
+        parse_date(&(self.computed as i64))
+    }
+
+    pub(crate) fn interval_name(&self) -> &'static str {
+        interval_name(self.interval)
+    }
+
+    pub(crate) fn rate_string(&self) -> String {
+        to_decimal_string(self.fixedrate, RATE_DECIMALS)
+    }
+
+    pub(crate) fn volume_string(&self) -> String {
+        to_decimal_string(self.fixedvol, USDC.decimals)
+    }
+
+    pub(crate) fn is_void(&self) -> bool {
+        self.algo == VOID_ALGORITHM
+    }
+}
+
+impl From<(&(String, String), &(ExchangeRate, UsdVolume))> for RatesDbRow {
+    fn from(
+        ((floatcode, fltissuer), (rate, volume)): (&(String, String), &(ExchangeRate, UsdVolume)),
+    ) -> Self {
         RatesDbRow {
             timestamp: 0,
             floatcode: floatcode.to_string(),
             fltissuer: fltissuer.to_string(),
-            rate: *rate,
-            volume: *volume,
+            fixedrate: *rate,
+            fixedvol: *volume,
+            interval: 0,
+            winstart: 0,
+            revision: 0,
+            computed: 0,
+            algo: String::new(),
+        }
+    }
+}
+
+/**
+ * The ways we know of to distill the swaps of a window into exchange rates.
+ * Ledger closes use the default one from the config; replays may pick another.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum RateStrategy {
+    // The mean of the swap prices, weighted by their USDC volume
+    VolumeWeighted,
+}
+
+impl RateStrategy {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "volume_weighted" => Some(RateStrategy::VolumeWeighted),
+            _ => None,
+        }
+    }
+
+    // Bump the version whenever the results of a strategy change, so that
+    // each revision records which variant produced it.
+    pub(crate) fn algorithm(&self) -> &'static str {
+        match self {
+            RateStrategy::VolumeWeighted => "volume_weighted/2",
+        }
+    }
+
+    fn calculate(&self, swaps: Vec<SwapDbRow>) -> ExchangeRateMap {
+        match self {
+            RateStrategy::VolumeWeighted => calculate_rates(swaps),
         }
     }
 }
 
-pub(crate) fn calculate_exchange_rates(client: &EnvClient, savepoint: u64) -> ExchangeRateMap {
-    // We query the DB only for the swaps that happened after the savepoint
-    let swaps = read_swaps(client, savepoint);
+/**
+ * Keep only the latest revision of the rate of each asset in each window. If a
+ * time is given, revisions computed after it are ignored, so we get the rates
+ * as they were known then.
+ */
+pub(crate) fn latest_revisions(rows: Vec<RatesDbRow>, as_of: Option<u64>) -> Vec<RatesDbRow> {
+    rows.into_iter()
+        .filter(|row| match as_of {
+            Some(as_of) => row.computed <= as_of,
+            None => true,
+        })
+        .fold(HashMap::new(), |mut acc: HashMap<_, RatesDbRow>, row| {
+            let key = (
+                row.floatcode.clone(),
+                row.fltissuer.clone(),
+                row.interval,
+                row.timestamp,
+            );
+
+            match acc.get(&key) {
+                Some(latest) if latest.revision >= row.revision => {}
+                _ => {
+                    acc.insert(key, row);
+                }
+            }
+
+            acc
+        })
+        .into_values()
+        .collect()
+}
+
+/**
+ * The rates we publish: the latest revision in each window, unless it is void.
+ * Some NaN exchange rates crept into the DB during development. The
+ * fixed-point migration turns them into zeros, which we filter out after
+ * picking the latest revision, so that an invalid correction hides its window
+ * rather than bringing back the rate it superseded. Voided rates are zero too.
+ */
+pub(crate) fn published_rates(rows: Vec<RatesDbRow>, as_of: Option<u64>) -> Vec<RatesDbRow> {
+    latest_revisions(rows, as_of)
+        .into_iter()
+        .filter(|row| row.fixedrate > 0)
+        .collect()
+}
+
+pub(crate) fn calculate_exchange_rates(
+    client: &EnvClient,
+    window_start: u64,
+    window_end: u64,
+    strategy: RateStrategy,
+) -> Result<ExchangeRateMap, String> {
+    // We query the DB only for the swaps that happened within the window
+    let swaps = read_swaps(client, window_start, window_end)?;
 
     client.log().debug(
         format!(
@@ -53,18 +219,24 @@ pub(crate) fn calculate_exchange_rates(client: &EnvClient, savepoint: u64) -> Ex
         None,
     );
 
-    calculate_rates(swaps)
+    Ok(strategy.calculate(swaps))
 }
 
-fn read_swaps(client: &EnvClient, savepoint: u64) -> Vec<SwapDbRow> {
+fn read_swaps(
+    client: &EnvClient,
+    window_start: u64,
+    window_end: u64,
+) -> Result<Vec<SwapDbRow>, String> {
+    // Timestamps are whole seconds, so this is creation >= window_start
     client
         .read_filter()
-        .column_gt("creation", savepoint)
+        .column_gt("creation", window_start.saturating_sub(1))
+        .column_lt("creation", window_end)
         .read::<SwapDbRow>()
-        // This could panic; it is crucial to us that it doesn't. One reason
-        // it would is if there is too much data. To address that, we adjust
-        // the interval between savepoints to something the DB can handle.
-        .unwrap()
+        // One reason this fails is if there is too much data. To address
+        // that, we adjust the interval between savepoints to something the
+        // DB can handle.
+        .map_err(|sdk_error| format!("Could not read the swaps of the window: {sdk_error}"))
 }
 
 fn calculate_rates(swaps: Vec<SwapDbRow>) -> ExchangeRateMap {
@@ -72,18 +244,26 @@ fn calculate_rates(swaps: Vec<SwapDbRow>) -> ExchangeRateMap {
         .iter()
         .fold(HashMap::new(), extract_amounts)
         .into_iter()
-        .filter_map(|(key, (weighted_sum, total_amount))| {
-            let rate = weighted_sum / total_amount;
-            if rate.is_nan() {
-                EnvClient::empty().log().error(
-                    format!(
-                        "Found NaN exchange rate for asset {key}. Numerator is {weighted_sum} and denominator is {total_amount}."
-                    ),
-                    None,
-                );
-                None
-            } else {
-                Some((key, (rate, total_amount)))
+        .filter_map(|((code, issuer), (floating_total, floating_decimals, usdc_total))| {
+            // The mean of the swap prices weighted by their USDC volume is
+            // exactly the total floating coin over the total USDC.
+            match ratio(
+                floating_total,
+                floating_decimals,
+                usdc_total,
+                USDC.decimals,
+                RATE_DECIMALS,
+            ) {
+                Some(rate) => Some(((code, issuer), (rate, usdc_total))),
+                None => {
+                    EnvClient::empty().log().error(
+                        format!(
+                            "Could not calculate the exchange rate for asset {code} ({issuer}). Numerator is {floating_total} (with {floating_decimals} decimals) and denominator is {usdc_total}."
+                        ),
+                        None,
+                    );
+                    None
+                }
             }
         })
         .collect::<ExchangeRateMap>()
@@ -91,33 +271,92 @@ fn calculate_rates(swaps: Vec<SwapDbRow>) -> ExchangeRateMap {
 
 // We calculate the total amounts of USDC and floatcoin swapped.
 fn extract_amounts(
-    mut counts: HashMap<String, (WeightedSum, UsdVolume)>,
+    mut counts: HashMap<(String, String), (FloatingVolume, u32, UsdVolume)>,
     row: &SwapDbRow,
-) -> HashMap<String, (WeightedSum, UsdVolume)> {
-    let usdc_swapped: UsdVolume = row.usdc_amnt as f64 / CONVERSION_FACTOR;
-    let swap_exchange_rate: f64 = row.numerator as f64 / row.denom as f64;
-
-    // We group asset codes and issues into a single value for DB compatibility
-    let floatcoin = format!("{}_{}", row.floatcode, row.fltissuer);
-
-    // For XLM swaps, we sometimes get weird values, so we don't include them
-    if swap_exchange_rate != 1e-7 {
-        // Update the entry with a running sum of (weighted_sum, total_volume)
-        counts
-            .entry(floatcoin)
-            .and_modify(|(floatcoin_total, total_volume)| {
-                // The addition assignment += cannot overflow because the maximum
-                // value of f64, 1.8e308, is ridiculously larger than the maximum
-                // value of i64, 9.2e18.
-                //
-                // The trade-off, though, is that for values larger than 2^53 we
-                // lose precision. This should not be a problem for calculating
-                // exchange rates due to large volumes.
-                *floatcoin_total += usdc_swapped * swap_exchange_rate;
-                *total_volume += usdc_swapped;
-            })
-            .or_insert((usdc_swapped * swap_exchange_rate, usdc_swapped));
+) -> HashMap<(String, String), (FloatingVolume, u32, UsdVolume)> {
+    let floatcoin = (row.floatcode.clone(), row.fltissuer.clone());
+
+    // For XLM swaps, we sometimes get weird values, where the raw amounts
+    // give a price of exactly 1e-7, so we don't include them. Ingestion now
+    // rejects them, but older windows still hold some.
+    let is_weird = row.fltamnt.checked_mul(10_000_000) == Some(row.usdcamnt);
+
+    // Swaps without USDC have no price, and voided swaps have no amounts
+    if is_weird || row.usdcamnt <= 0 {
+        return counts;
+    }
+
+    // Update the entry with a running sum of (floating_total, usdc_total).
+    let (floating_total, floating_decimals, usdc_total) =
+        counts.entry(floatcoin).or_insert((0, row.fltdecs, 0));
+
+    // Should the decimals of a token ever be corrected, its swaps in the
+    // window are summed with the larger number of decimals.
+    let decimals = (*floating_decimals).max(row.fltdecs);
+    let totals = rescale(*floating_total, *floating_decimals, decimals)
+        .zip(rescale(row.fltamnt, row.fltdecs, decimals))
+        .and_then(|(total, amount)| total.checked_add(amount))
+        .zip(usdc_total.checked_add(row.usdcamnt));
+
+    // An i128 holds about 1.7e38, far beyond any realistic volume, so a swap
+    // that overflows the totals is bogus and left out, like malformed items
+    // during ingestion.
+    match totals {
+        Some((floating, usdc)) => {
+            *floating_total = floating;
+            *floating_decimals = decimals;
+            *usdc_total = usdc;
+        }
+        None => EnvClient::empty().log().error(
+            format!(
+                "Skipped a swap of {} ({}) saved at {}: its amounts overflow the totals of the window.",
+                row.floatcode, row.fltissuer, row.creation
+            ),
+            None,
+        ),
     }
 
     counts
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate(timestamp: u64, revision: u64, fixedrate: ExchangeRate) -> RatesDbRow {
+        RatesDbRow {
+            timestamp,
+            floatcode: "AQUA".to_string(),
+            fltissuer: "GBNZILSTVQZ4R7IKQDGHYGY2QXL5QOFJYQMXPKWRRM5PAV7Y4M67AQUA".to_string(),
+            fixedrate,
+            fixedvol: 1,
+            interval: 300,
+            winstart: timestamp - 300,
+            revision,
+            computed: timestamp + revision,
+            algo: VOID_ALGORITHM.to_string(),
+        }
+    }
+
+    #[test]
+    fn a_voided_revision_hides_the_rate_it_superseded() {
+        let rows = vec![rate(600, 0, 5), rate(600, 1, 0), rate(900, 0, 7)];
+
+        let published = published_rates(rows, None);
+
+        assert_eq!(published.len(), 1);
+        assert_eq!((published[0].timestamp, published[0].fixedrate), (900, 7));
+    }
+
+    #[test]
+    fn rates_as_of_a_time_ignore_later_revisions() {
+        let rows = vec![rate(600, 0, 5), rate(600, 1, 0), rate(600, 2, 6)];
+
+        let published = published_rates(rows.clone(), Some(600));
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].fixedrate, 5);
+
+        assert!(published_rates(rows.clone(), Some(601)).is_empty());
+        assert_eq!(published_rates(rows, None)[0].fixedrate, 6);
+    }
+}
@@ -0,0 +1,38 @@
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("lastledgr")]
+/**
+ * The last ledger we ingested. If the next ledger we see is not its direct
+ * successor, on_close was not called for the ones in between, e.g. because
+ * the program was redeployed or stalled.
+ */
+pub(crate) struct LastLedger {
+    pub(crate) sequence: u64,
+    pub(crate) closetime: u64,
+}
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("gaps")]
+/**
+ * A run of ledgers we never ingested. The times are the close times of the
+ * ledgers on either side of the gap, so no swaps were recorded strictly
+ * between them. The first ledger is 0 when it is not known, after a gap that
+ * spans a force-deploy.
+ */
+pub(crate) struct Gap {
+    pub(crate) firstseq: u64,
+    pub(crate) lastseq: u64,
+    pub(crate) gapstart: u64,
+    pub(crate) gapend: u64,
+}
+
+impl Gap {
+    pub(crate) fn first_ledger(&self) -> Option<u64> {
+        (self.firstseq > 0).then_some(self.firstseq)
+    }
+
+    pub(crate) fn overlaps(&self, start: u64, end: u64) -> bool {
+        self.gapstart < end && self.gapend > start
+    }
+}
@@ -0,0 +1,300 @@
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+use super::swap::{Rejection, Swap, SwapPosition};
+use crate::{config::JOURNAL_CAPACITY, utils::parse_date};
+
+/**
+ * Where ingestion read a swap from.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum JournalSource {
+    // The claim atoms of a classic operation result
+    ClaimAtom,
+    // A swap event of the Soroswap router
+    SoroswapEvent,
+}
+
+/**
+ * Where a swap took place.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Venue {
+    OrderBook,
+    LiquidityPool,
+    Soroswap,
+}
+
+impl JournalSource {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            JournalSource::ClaimAtom => "claim_atom",
+            JournalSource::SoroswapEvent => "soroswap_event",
+        }
+    }
+}
+
+impl Venue {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Venue::OrderBook => "order_book",
+            Venue::LiquidityPool => "liquidity_pool",
+            Venue::Soroswap => "soroswap",
+        }
+    }
+}
+
+/**
+ * A swap as ingestion found it, and what became of it. Assets are named by
+ * their code and issuer; Soroban tokens we do not know have no code, and
+ * their contract address stands in for the issuer.
+ */
+pub(crate) struct SwapRecord {
+    pub(crate) source: JournalSource,
+    pub(crate) venue: Venue,
+    pub(crate) position: SwapPosition,
+    pub(crate) sold: (String, String),
+    pub(crate) amount_sold: i128,
+    pub(crate) bought: (String, String),
+    pub(crate) amount_bought: i128,
+    pub(crate) outcome: Result<Swap, Rejection>,
+}
+
+impl SwapRecord {
+    /**
+     * An event that should have been a swap, but that we could not read.
+     */
+    pub(crate) fn malformed(source: JournalSource, venue: Venue) -> Self {
+        SwapRecord {
+            source,
+            venue,
+            position: SwapPosition::default(),
+            sold: (String::new(), String::new()),
+            amount_sold: 0,
+            bought: (String::new(), String::new()),
+            amount_bought: 0,
+            outcome: Err(Rejection::MalformedEvent),
+        }
+    }
+
+    /**
+     * Place the swap in its ledger, once the transaction it is in is known.
+     */
+    pub(crate) fn at(self, position: SwapPosition) -> Self {
+        SwapRecord { position, ..self }
+    }
+}
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("journal")]
+/**
+ * The swaps that ingestion saved or rejected. Rows cannot be deleted, so the
+ * journal keeps the latest entries up to its capacity, and a new entry takes
+ * over the row of the oldest one once that one falls out of it.
+ */
+pub(crate) struct JournalEntry {
+    pub(crate) entry: u64,
+    pub(crate) ledger: u64,
+    pub(crate) closetime: u64,
+    pub(crate) source: String,
+    pub(crate) venue: String,
+    pub(crate) sellcode: String,
+    pub(crate) sellissr: String,
+    // Amounts are in the smallest unit of their asset
+    pub(crate) sellamnt: i128,
+    pub(crate) buycode: String,
+    pub(crate) buyissr: String,
+    pub(crate) buyamnt: i128,
+    // "accepted" or "rejected"
+    pub(crate) outcome: String,
+    // The code of the rejection, or empty if the swap was accepted
+    pub(crate) reason: String,
+}
+
+// The number of the next journal entry, the oldest entry that still has a
+// row, and how many of the latest entries the journal keeps
+#[derive(Clone, DatabaseDerive)]
+#[with_name("journalhd")]
+pub(crate) struct JournalHead {
+    pub(crate) next: u64,
+    pub(crate) oldest: u64,
+    pub(crate) capacity: u64,
+}
+
+impl JournalHead {
+    /**
+     * The first entry the journal keeps; those before it are stale, even if
+     * their rows were not taken over yet.
+     */
+    pub(crate) fn first_kept(&self) -> u64 {
+        self.next.saturating_sub(self.capacity)
+    }
+
+    /**
+     * Take the next entry number. Returns the entry whose row it takes over,
+     * or None if it needs a row of its own.
+     */
+    fn advance(&mut self) -> Option<u64> {
+        let taken_over = (self.next - self.oldest >= self.capacity).then_some(self.oldest);
+        if taken_over.is_some() {
+            self.oldest += 1;
+        }
+        self.next += 1;
+        taken_over
+    }
+}
+
+/**
+ * Read the head of the journal, if any entry was ever written or its
+ * capacity set.
+ */
+pub(crate) fn read_journal_head(client: &EnvClient) -> Option<JournalHead> {
+    client.read::<JournalHead>().into_iter().next()
+}
+
+/**
+ * Write the head of the journal, replacing the previous one if any.
+ */
+pub(crate) fn save_journal_head(
+    client: &EnvClient,
+    previous: Option<&JournalHead>,
+    head: &JournalHead,
+) -> Result<(), String> {
+    match previous {
+        Some(previous) => client
+            .update()
+            .column_equal_to("next", previous.next)
+            .execute(head)
+            .map_err(|sdk_error| sdk_error.to_string()),
+        None => {
+            head.put(client);
+            Ok(())
+        }
+    }
+}
+
+impl JournalEntry {
+    fn new(record: &SwapRecord, entry: u64, ledger: u64, closetime: u64) -> Self {
+        JournalEntry {
+            entry,
+            ledger,
+            closetime,
+            source: record.source.name().to_string(),
+            venue: record.venue.name().to_string(),
+            sellcode: record.sold.0.clone(),
+            sellissr: record.sold.1.clone(),
+            sellamnt: record.amount_sold,
+            buycode: record.bought.0.clone(),
+            buyissr: record.bought.1.clone(),
+            buyamnt: record.amount_bought,
+            outcome: match record.outcome {
+                Ok(_) => "accepted",
+                Err(_) => "rejected",
+            }
+            .to_string(),
+            reason: record
+                .outcome
+                .as_ref()
+                .err()
+                .map_or("", |rejection| rejection.code())
+                .to_string(),
+        }
+    }
+
+    pub(crate) fn closetime_iso8601(&self) -> String {
+        parse_date(&(self.closetime as i64))
+    }
+}
+
+/**
+ * Append the swaps of a ledger to the journal. While the journal holds fewer
+ * rows than its capacity, each entry gets a row of its own with `put`; after
+ * that, it takes over the row of the oldest entry. Rows left over after the
+ * capacity was lowered are taken over too, and are not served meanwhile.
+ */
+pub(crate) fn save_journal(client: &EnvClient, records: &[SwapRecord]) {
+    if records.is_empty() {
+        return;
+    }
+
+    let previous = read_journal_head(client);
+    let mut head = previous.clone().unwrap_or(JournalHead {
+        next: 0,
+        oldest: 0,
+        capacity: JOURNAL_CAPACITY,
+    });
+    let ledger = client.reader().ledger_sequence() as u64;
+    let closetime = client.reader().ledger_timestamp();
+
+    for record in records {
+        let entry = head.next;
+        let row = JournalEntry::new(record, entry, ledger, closetime);
+
+        match head.advance() {
+            None => row.put(client),
+            Some(oldest) => {
+                if let Err(sdk_error) = client
+                    .update()
+                    .column_equal_to("entry", oldest)
+                    .execute(&row)
+                {
+                    client.log().error(
+                        format!("Could not write journal entry {entry}: {sdk_error}"),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+
+    if let Err(error) = save_journal_head(client, previous.as_ref(), &head) {
+        client
+            .log()
+            .error(format!("Could not move the journal head: {error}"), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn head(next: u64, oldest: u64, capacity: u64) -> JournalHead {
+        JournalHead {
+            next,
+            oldest,
+            capacity,
+        }
+    }
+
+    #[test]
+    fn entries_get_rows_until_capacity() {
+        let mut journal = head(0, 0, 3);
+        let taken_over = (0..5).map(|_| journal.advance()).collect::<Vec<_>>();
+
+        assert_eq!(taken_over, vec![None, None, None, Some(0), Some(1)]);
+        assert_eq!((journal.next, journal.oldest), (5, 2));
+        assert_eq!(journal.first_kept(), 2);
+    }
+
+    #[test]
+    fn raised_capacity_adds_rows() {
+        let mut journal = head(10, 7, 3);
+        journal.capacity = 5;
+
+        assert_eq!(journal.advance(), None);
+        assert_eq!(journal.advance(), None);
+        assert_eq!(journal.advance(), Some(7));
+        assert_eq!(journal.first_kept(), 8);
+    }
+
+    #[test]
+    fn lowered_capacity_hides_stale_rows() {
+        let mut journal = head(10, 5, 5);
+        journal.capacity = 2;
+
+        // The rows of entries 5 to 7 are left, but no longer served
+        assert_eq!(journal.first_kept(), 8);
+        assert_eq!(journal.advance(), Some(5));
+        assert_eq!(journal.first_kept(), 9);
+        assert_eq!(journal.oldest, 6);
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+use crate::{
+    config::{MAX_LINEAGE_HOPS, RATE_DECIMALS},
+    fixed_point::{multiply, to_decimal_string},
+    utils::parse_date,
+};
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("lineage")]
+/**
+ * An asset continued by another from a given time, e.g. after its anchor
+ * moved it to a new issuer account or redenominated it. Each unit of the
+ * previous asset is worth `ratio` units of the next one.
+ */
+pub(crate) struct AssetLink {
+    pub(crate) prevcode: String,
+    pub(crate) previssr: String,
+    pub(crate) nextcode: String,
+    pub(crate) nextissr: String,
+    pub(crate) since: u64,
+    // Scaled by 10^RATE_DECIMALS, like rates
+    pub(crate) ratio: i128,
+}
+
+impl AssetLink {
+    pub(crate) fn ratio_string(&self) -> String {
+        to_decimal_string(self.ratio, RATE_DECIMALS)
+    }
+
+    pub(crate) fn since_iso8601(&self) -> String {
+        parse_date(&(self.since as i64))
+    }
+}
+
+/**
+ * Every link between assets. The table is small, so it is read whole, once
+ * per call.
+ */
+pub(crate) fn read_links(client: &EnvClient) -> Vec<AssetLink> {
+    client.read::<AssetLink>()
+}
+
+/**
+ * The links an asset is part of: the asset it continues, and the one that
+ * continues it.
+ */
+pub(crate) fn asset_links<'a>(
+    links: &'a [AssetLink],
+    code: &str,
+    issuer: &str,
+) -> (Option<&'a AssetLink>, Option<&'a AssetLink>) {
+    let predecessor = links
+        .iter()
+        .find(|link| link.nextcode == code && link.nextissr == issuer);
+    let successor = links
+        .iter()
+        .find(|link| link.prevcode == code && link.previssr == issuer);
+
+    (predecessor, successor)
+}
+
+/**
+ * The chains of links an asset is part of, nearest first: the assets it
+ * continues, and those that continue it. Each chain stops after
+ * MAX_LINEAGE_HOPS links, or before going back to an asset it went through.
+ */
+pub(crate) fn lineage_chains(
+    links: &[AssetLink],
+    code: &str,
+    issuer: &str,
+) -> (Vec<AssetLink>, Vec<AssetLink>) {
+    let chain = |backward: bool| {
+        let mut chain = Vec::<AssetLink>::new();
+        let mut visited = HashSet::from([(code, issuer)]);
+        let (mut code, mut issuer) = (code, issuer);
+
+        while chain.len() < MAX_LINEAGE_HOPS {
+            let (predecessor, successor) = asset_links(links, code, issuer);
+            let Some(link) = (if backward { predecessor } else { successor }) else {
+                break;
+            };
+
+            (code, issuer) = if backward {
+                (&link.prevcode, &link.previssr)
+            } else {
+                (&link.nextcode, &link.nextissr)
+            };
+            if !visited.insert((code, issuer)) {
+                break;
+            }
+            chain.push(link.clone());
+        }
+
+        chain
+    };
+
+    (chain(true), chain(false))
+}
+
+/**
+ * The ratio across each link of a chain and all the links before it: the
+ * number of units of the later asset per unit of the earlier one, from the
+ * asset the chain starts at. Stops at the first ratio that overflows.
+ */
+pub(crate) fn chain_ratios(chain: &[AssetLink]) -> Vec<i128> {
+    let one = 10_i128.pow(RATE_DECIMALS);
+
+    chain
+        .iter()
+        .scan(one, |ratio, link| {
+            *ratio = multiply(*ratio, link.ratio, RATE_DECIMALS)?;
+            Some(*ratio)
+        })
+        .collect()
+}
+
+/**
+ * Whether linking an asset to a successor would close a cycle, i.e. whether
+ * the successor already leads back to the asset.
+ */
+pub(crate) fn closes_cycle(
+    links: &[AssetLink],
+    code: &str,
+    issuer: &str,
+    next_code: &str,
+    next_issuer: &str,
+) -> bool {
+    let mut visited = HashSet::new();
+    let (mut current_code, mut current_issuer) = (next_code, next_issuer);
+
+    while visited.insert((current_code, current_issuer)) {
+        if current_code == code && current_issuer == issuer {
+            return true;
+        }
+
+        match asset_links(links, current_code, current_issuer) {
+            (_, Some(link)) => (current_code, current_issuer) = (&link.nextcode, &link.nextissr),
+            (_, None) => return false,
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(prev: &str, next: &str) -> AssetLink {
+        AssetLink {
+            prevcode: prev.to_string(),
+            previssr: "GISSUER".to_string(),
+            nextcode: next.to_string(),
+            nextissr: "GISSUER".to_string(),
+            since: 0,
+            ratio: 10_i128.pow(RATE_DECIMALS),
+        }
+    }
+
+    fn codes(chain: &[AssetLink], backward: bool) -> Vec<&str> {
+        chain
+            .iter()
+            .map(|link| {
+                if backward {
+                    link.prevcode.as_str()
+                } else {
+                    link.nextcode.as_str()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chains_are_followed_to_their_ends() {
+        let links = [link("A", "B"), link("C", "D"), link("B", "C")];
+        let (predecessors, successors) = lineage_chains(&links, "B", "GISSUER");
+
+        assert_eq!(codes(&predecessors, true), ["A"]);
+        assert_eq!(codes(&successors, false), ["C", "D"]);
+    }
+
+    #[test]
+    fn chains_stop_at_the_hop_limit() {
+        let codes = (0..=MAX_LINEAGE_HOPS + 2)
+            .map(|index| format!("T{index}"))
+            .collect::<Vec<_>>();
+        let links = codes
+            .windows(2)
+            .map(|pair| link(&pair[0], &pair[1]))
+            .collect::<Vec<_>>();
+        let (predecessors, successors) = lineage_chains(&links, "T0", "GISSUER");
+
+        assert!(predecessors.is_empty());
+        assert_eq!(successors.len(), MAX_LINEAGE_HOPS);
+    }
+
+    #[test]
+    fn chains_stop_before_a_saved_cycle() {
+        let links = [link("A", "B"), link("B", "C"), link("C", "A")];
+        let (predecessors, successors) = lineage_chains(&links, "A", "GISSUER");
+
+        assert_eq!(codes(&predecessors, true), ["C", "B"]);
+        assert_eq!(codes(&successors, false), ["B", "C"]);
+    }
+
+    #[test]
+    fn ratios_accumulate_along_the_chain() {
+        let one = 10_i128.pow(RATE_DECIMALS);
+        let chain = [
+            AssetLink {
+                ratio: 100 * one,
+                ..link("A", "B")
+            },
+            AssetLink {
+                ratio: one / 4,
+                ..link("B", "C")
+            },
+            AssetLink {
+                ratio: i128::MAX,
+                ..link("C", "D")
+            },
+        ];
+
+        assert_eq!(chain_ratios(&chain), [100 * one, 25 * one]);
+    }
+
+    #[test]
+    fn links_back_to_an_earlier_asset_close_cycles() {
+        let links = [link("A", "B"), link("B", "C")];
+
+        assert!(closes_cycle(&links, "C", "GISSUER", "A", "GISSUER"));
+        assert!(closes_cycle(&links, "B", "GISSUER", "A", "GISSUER"));
+        assert!(closes_cycle(&links, "A", "GISSUER", "A", "GISSUER"));
+        assert!(!closes_cycle(&links, "C", "GISSUER", "D", "GISSUER"));
+        assert!(!closes_cycle(&links, "D", "GISSUER", "A", "GISSUER"));
+    }
+}
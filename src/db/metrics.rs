@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+use super::journal::SwapRecord;
+use crate::config::{METRICS_INTERVAL, METRICS_RETENTION, USDC};
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("metrics")]
+/**
+ * A counter of ingestion, added up over a metrics window. Each combination of
+ * labels of a metric is a row of its own. Labels are `key=value` pairs,
+ * separated by commas. Rows cannot be deleted, so once a window is older than
+ * the retention, the series of new windows take over its rows.
+ */
+pub(crate) struct MetricDbRow {
+    pub(crate) winstart: u64,
+    pub(crate) metric: String,
+    pub(crate) labels: String,
+    pub(crate) value: i128,
+}
+
+/**
+ * What a metric counts. Values are integers, scaled by 10^decimals.
+ */
+pub(crate) struct Metric {
+    pub(crate) name: &'static str,
+    pub(crate) help: &'static str,
+    pub(crate) decimals: u32,
+}
+
+pub(crate) const LEDGERS: Metric = Metric {
+    name: "ledgers",
+    help: "Ledgers ingested",
+    decimals: 0,
+};
+
+pub(crate) const SWAPS: Metric = Metric {
+    name: "swaps",
+    help: "Swaps found, by source, venue and outcome",
+    decimals: 0,
+};
+
+pub(crate) const REJECTIONS: Metric = Metric {
+    name: "rejections",
+    help: "Swaps rejected, by reason",
+    decimals: 0,
+};
+
+pub(crate) const USDC_VOLUME: Metric = Metric {
+    name: "usdc_volume",
+    help: "USDC volume of the swaps accepted, by venue",
+    decimals: USDC.decimals,
+};
+
+pub(crate) const METRICS: [&Metric; 4] = [&LEDGERS, &SWAPS, &REJECTIONS, &USDC_VOLUME];
+
+/**
+ * Count the swaps of a ledger into the metrics of the current window. The
+ * counters of the ledger are added up first, so each one costs a single
+ * write. A series new to the window takes over a row of an expired window if
+ * there is one, so the table holds at most the series of the windows within
+ * the retention, plus one.
+ */
+pub(crate) fn save_metrics(client: &EnvClient, records: &[SwapRecord]) {
+    let timestamp = client.reader().ledger_timestamp();
+    let winstart = timestamp - timestamp % METRICS_INTERVAL;
+
+    let mut counts = BTreeMap::new();
+    counts.insert((LEDGERS.name, String::new()), 1);
+    for record in records {
+        let outcome = if record.outcome.is_ok() {
+            "accepted"
+        } else {
+            "rejected"
+        };
+        let labels = format!(
+            "source={},venue={},outcome={outcome}",
+            record.source.name(),
+            record.venue.name()
+        );
+        *counts.entry((SWAPS.name, labels)).or_insert(0_i128) += 1;
+
+        match &record.outcome {
+            Ok(swap) => {
+                let volume = counts
+                    .entry((USDC_VOLUME.name, format!("venue={}", record.venue.name())))
+                    .or_insert(0);
+                *volume = volume.saturating_add(swap.usdc_amount);
+            }
+            Err(rejection) => {
+                *counts
+                    .entry((REJECTIONS.name, format!("reason={}", rejection.code())))
+                    .or_insert(0) += 1;
+            }
+        }
+    }
+
+    let previous = match window_metrics(client, winstart) {
+        Ok(previous) => previous,
+        Err(message) => {
+            client.log().error(message, None);
+            return;
+        }
+    };
+
+    // Expired rows are only read when a series is new to the window
+    let mut expired = None;
+
+    for ((metric, labels), count) in counts {
+        let existing = previous
+            .iter()
+            .find(|row| row.metric == metric && row.labels == labels);
+        let row = MetricDbRow {
+            winstart,
+            metric: metric.to_string(),
+            labels,
+            value: existing.map_or(0, |row| row.value).saturating_add(count),
+        };
+        let target = match existing {
+            Some(existing) => Some(existing.clone()),
+            None => take_over(
+                expired.get_or_insert_with(|| expired_metrics(client, winstart)),
+                &row,
+            ),
+        };
+
+        let Some(target) = target else {
+            row.put(client);
+            continue;
+        };
+        if let Err(sdk_error) = client
+            .update()
+            .column_equal_to("winstart", target.winstart)
+            .column_equal_to("metric", target.metric)
+            .column_equal_to("labels", target.labels)
+            .execute(&row)
+        {
+            client.log().error(
+                format!("Could not update the {metric} metric: {sdk_error}"),
+                None,
+            );
+        }
+    }
+}
+
+/**
+ * The rows of the windows that fell out of the retention, as of the window
+ * that started at a time. Rows that cannot be read are not taken over.
+ */
+fn expired_metrics(client: &EnvClient, winstart: u64) -> Vec<MetricDbRow> {
+    client
+        .read_filter()
+        .column_lt("winstart", winstart.saturating_sub(METRICS_RETENTION))
+        .read::<MetricDbRow>()
+        .unwrap_or_default()
+}
+
+/**
+ * Pick the expired row a new series takes over: one of the same series if
+ * any, so that rows keep their metric and labels, or else any of them.
+ */
+fn take_over(expired: &mut Vec<MetricDbRow>, row: &MetricDbRow) -> Option<MetricDbRow> {
+    let index = expired
+        .iter()
+        .position(|expired| expired.metric == row.metric && expired.labels == row.labels)
+        .or((!expired.is_empty()).then_some(0))?;
+
+    Some(expired.swap_remove(index))
+}
+
+/**
+ * The metrics of the window that started at a time.
+ */
+pub(crate) fn window_metrics(
+    client: &EnvClient,
+    winstart: u64,
+) -> Result<Vec<MetricDbRow>, String> {
+    client
+        .read_filter()
+        .column_equal_to("winstart", winstart)
+        .read::<MetricDbRow>()
+        .map_err(|sdk_error| format!("Could not read the metrics of {winstart}: {sdk_error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(winstart: u64, metric: &Metric, labels: &str) -> MetricDbRow {
+        MetricDbRow {
+            winstart,
+            metric: metric.name.to_string(),
+            labels: labels.to_string(),
+            value: 1,
+        }
+    }
+
+    #[test]
+    fn a_new_series_takes_over_its_own_expired_row_first() {
+        let mut expired = vec![
+            row(0, &LEDGERS, ""),
+            row(0, &REJECTIONS, "reason=scam_issuer"),
+            row(300, &REJECTIONS, "reason=scam_issuer"),
+        ];
+
+        let taken = take_over(&mut expired, &row(7200, &REJECTIONS, "reason=scam_issuer"));
+
+        assert_eq!(
+            taken.map(|row| (row.winstart, row.metric)),
+            Some((0, "rejections".to_string()))
+        );
+        assert_eq!(expired.len(), 2);
+    }
+
+    #[test]
+    fn a_new_series_takes_over_any_expired_row() {
+        let mut expired = vec![row(0, &LEDGERS, "")];
+
+        let taken = take_over(&mut expired, &row(7200, &USDC_VOLUME, "venue=soroswap"));
+
+        assert_eq!(taken.map(|row| row.metric), Some("ledgers".to_string()));
+        assert!(take_over(&mut expired, &row(7200, &SWAPS, "")).is_none());
+    }
+}
@@ -0,0 +1,407 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use zephyr_sdk::EnvClient;
+
+use super::{
+    discovery::DiscoveredAsset,
+    exchange_rate::{LegacyRatesDbRow, RateAmounts, RateCode},
+    swap::{LegacySwapDbRow, SwapAmounts, SwapCode, SwapCreation},
+};
+use crate::{
+    config::{RATE_DECIMALS, USDC},
+    fixed_point::from_decimal_string,
+};
+
+/**
+ * Fill in the fixed-point columns of the swaps and rates saved within a range
+ * of time from their legacy columns. Running it twice over the same range
+ * writes the same values, so an interrupted migration can simply be resumed.
+ * Returns the number of swaps and rates migrated.
+ */
+pub(crate) fn migrate_fixed_point(
+    client: &EnvClient,
+    from: u64,
+    to: u64,
+) -> Result<(usize, usize), String> {
+    let swaps = client
+        .read_filter()
+        .column_gt("creation", from.saturating_sub(1))
+        .column_lt("creation", to)
+        .read::<LegacySwapDbRow>()
+        .map_err(|sdk_error| sdk_error.to_string())?;
+
+    for swap in &swaps {
+        // Legacy swaps have no identifier, so we match on every column
+        client
+            .update()
+            .column_equal_to("creation", swap.creation)
+            .column_equal_to("floatcode", swap.floatcode.clone())
+            .column_equal_to("fltissuer", swap.fltissuer.clone())
+            .column_equal_to("usdc_amnt", swap.usdc_amnt)
+            .column_equal_to("numerator", swap.numerator)
+            .column_equal_to("denom", swap.denom)
+            .execute(&SwapAmounts::from(swap))
+            .map_err(|sdk_error| sdk_error.to_string())?;
+    }
+
+    let rates = client
+        .read_filter()
+        .column_gt("timestamp", from.saturating_sub(1))
+        .column_lt("timestamp", to)
+        .read::<LegacyRatesDbRow>()
+        .map_err(|sdk_error| sdk_error.to_string())?;
+
+    for rate in &rates {
+        // Floats are displayed without exponents, so their decimal expansion
+        // parses as is. NaN rates become zeros, which the API never serves.
+        let amounts = RateAmounts {
+            fixedrate: from_decimal_string(&rate.rate.to_string(), RATE_DECIMALS)
+                .unwrap_or_default(),
+            fixedvol: from_decimal_string(&rate.volume.to_string(), USDC.decimals)
+                .unwrap_or_default(),
+        };
+
+        client
+            .update()
+            .column_equal_to("timestamp", rate.timestamp)
+            .column_equal_to("floatcode", rate.floatcode.clone())
+            .column_equal_to("fltissuer", rate.fltissuer.clone())
+            .column_equal_to("interval", rate.interval)
+            .column_equal_to("revision", rate.revision)
+            .execute(&amounts)
+            .map_err(|sdk_error| sdk_error.to_string())?;
+    }
+
+    Ok((swaps.len(), rates.len()))
+}
+
+/**
+ * An asset whose code was saved without its digits, and how many swaps, rates
+ * and discovered assets were saved under the stripped code.
+ */
+pub(crate) struct CodeRepair {
+    pub(crate) stored_code: String,
+    pub(crate) code: String,
+    pub(crate) issuer: String,
+    pub(crate) swaps: usize,
+    pub(crate) rates: usize,
+    pub(crate) discoveries: usize,
+}
+
+/**
+ * A code several assets of an issuer may have been stripped to, with every
+ * candidate, whether known or found in the discovery table.
+ */
+pub(crate) struct AmbiguousCode {
+    pub(crate) stored_code: String,
+    pub(crate) issuer: String,
+    pub(crate) codes: Vec<String>,
+}
+
+/**
+ * Asset codes used to be saved with everything but letters stripped, so that
+ * "USDC2" was saved as "USDC". Given the assets that may have been affected,
+ * this restores their codes in the swaps, rates and discovery tables.
+ *
+ * Rows saved under a stripped code can only be repaired if a single asset of
+ * the issuer may have been stripped to it. Besides the known assets, the
+ * discovery table can reveal others, e.g. an unregistered asset whose code
+ * really is the stripped one. When there is more than one candidate, the rows
+ * are left alone and the code is returned with every candidate.
+ */
+pub(crate) fn repair_stripped_codes(
+    client: &EnvClient,
+    assets: &[(String, String)],
+    dry_run: bool,
+) -> Result<(Vec<CodeRepair>, Vec<AmbiguousCode>), String> {
+    let mut candidates = BTreeMap::<(String, String), BTreeSet<String>>::new();
+    for (code, issuer) in assets {
+        candidates
+            .entry((stripped_asset_code(code), issuer.clone()))
+            .or_default()
+            .insert(code.clone());
+    }
+
+    let mut repairs = Vec::new();
+    let mut ambiguous = Vec::new();
+    let mut discoveries = BTreeMap::<String, Vec<DiscoveredAsset>>::new();
+
+    for ((stored_code, issuer), codes) in candidates {
+        if !discoveries.contains_key(&issuer) {
+            let issuer_discoveries = client
+                .read_filter()
+                .column_equal_to("fltissuer", issuer.clone())
+                .read::<DiscoveredAsset>()
+                .map_err(|sdk_error| sdk_error.to_string())?;
+            discoveries.insert(issuer.clone(), issuer_discoveries);
+        }
+        let discovered = discoveries[&issuer]
+            .iter()
+            .filter(|asset| {
+                !asset.is_void() && stripped_asset_code(&asset.floatcode) == stored_code
+            })
+            .collect::<Vec<_>>();
+
+        if codes.len() > 1 {
+            ambiguous.push(AmbiguousCode {
+                stored_code,
+                issuer,
+                codes: all_candidates(&codes, &discovered),
+            });
+            continue;
+        }
+
+        let code = codes.first().cloned().unwrap_or_default();
+        if code == stored_code {
+            continue;
+        }
+
+        // Only worth reading if the discovery table has the stripped code
+        let first_saved = if discovered
+            .iter()
+            .any(|asset| asset.floatcode == stored_code)
+        {
+            client
+                .read_filter()
+                .column_equal_to("floatcode", code.clone())
+                .column_equal_to("fltissuer", issuer.clone())
+                .read::<SwapCreation>()
+                .map_err(|sdk_error| sdk_error.to_string())?
+                .iter()
+                .map(|swap| swap.creation)
+                .min()
+        } else {
+            None
+        };
+
+        let stripped = match stripped_discovery(&code, &stored_code, &discovered, first_saved) {
+            Some(stripped) => stripped,
+            None => {
+                ambiguous.push(AmbiguousCode {
+                    stored_code,
+                    issuer,
+                    codes: all_candidates(&codes, &discovered),
+                });
+                continue;
+            }
+        };
+
+        let swaps = client
+            .read_filter()
+            .column_equal_to("floatcode", stored_code.clone())
+            .column_equal_to("fltissuer", issuer.clone())
+            .read::<SwapCode>()
+            .map_err(|sdk_error| sdk_error.to_string())?
+            .len();
+        let rates = client
+            .read_filter()
+            .column_equal_to("floatcode", stored_code.clone())
+            .column_equal_to("fltissuer", issuer.clone())
+            .read::<RateCode>()
+            .map_err(|sdk_error| sdk_error.to_string())?
+            .len();
+
+        if !dry_run && swaps > 0 {
+            client
+                .update()
+                .column_equal_to("floatcode", stored_code.clone())
+                .column_equal_to("fltissuer", issuer.clone())
+                .execute(&SwapCode {
+                    floatcode: code.clone(),
+                })
+                .map_err(|sdk_error| sdk_error.to_string())?;
+        }
+
+        if !dry_run && rates > 0 {
+            client
+                .update()
+                .column_equal_to("floatcode", stored_code.clone())
+                .column_equal_to("fltissuer", issuer.clone())
+                .execute(&RateCode {
+                    floatcode: code.clone(),
+                })
+                .map_err(|sdk_error| sdk_error.to_string())?;
+        }
+
+        if let (false, Some(stripped)) = (dry_run, stripped) {
+            let existing = discovered
+                .iter()
+                .find(|asset| asset.floatcode == code)
+                .copied();
+            repair_discovery(client, stripped, existing, &code)?;
+        }
+
+        repairs.push(CodeRepair {
+            stored_code,
+            code,
+            issuer,
+            swaps,
+            rates,
+            discoveries: usize::from(stripped.is_some()),
+        });
+    }
+
+    Ok((repairs, ambiguous))
+}
+
+/**
+ * Tell whether the rows saved under a stripped code are those of the only
+ * known asset stripped to it, given the discovery rows of the issuer whose
+ * codes strip to it. Any other code among them is another candidate. So is
+ * the stripped code itself, unless it was last seen before the asset was
+ * first saved under its full code: then it is the asset as discovery saw it
+ * before codes were decoded. Returns that discovery row, if any, or `None` if
+ * the code is ambiguous.
+ */
+fn stripped_discovery<'a>(
+    code: &str,
+    stored_code: &str,
+    discovered: &[&'a DiscoveredAsset],
+    first_saved: Option<u64>,
+) -> Option<Option<&'a DiscoveredAsset>> {
+    let mut stripped = None;
+
+    for asset in discovered {
+        if asset.floatcode == code {
+            continue;
+        }
+
+        let is_stripped = asset.floatcode == stored_code
+            && first_saved.is_some_and(|first_saved| asset.lastseen < first_saved);
+        if !is_stripped {
+            return None;
+        }
+        stripped = Some(*asset);
+    }
+
+    Some(stripped)
+}
+
+fn all_candidates(codes: &BTreeSet<String>, discovered: &[&DiscoveredAsset]) -> Vec<String> {
+    codes
+        .iter()
+        .cloned()
+        .chain(discovered.iter().map(|asset| asset.floatcode.clone()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/**
+ * Move a discovered asset saved under a stripped code to its full code. If
+ * the asset was also discovered under its full code, the two are merged and
+ * the stripped row is voided, since rows cannot be deleted.
+ */
+fn repair_discovery(
+    client: &EnvClient,
+    stripped: &DiscoveredAsset,
+    existing: Option<&DiscoveredAsset>,
+    code: &str,
+) -> Result<(), String> {
+    let update = |code: &str, row: &DiscoveredAsset| {
+        client
+            .update()
+            .column_equal_to("floatcode", code.to_string())
+            .column_equal_to("fltissuer", stripped.fltissuer.clone())
+            .execute(row)
+            .map_err(|sdk_error| sdk_error.to_string())
+    };
+
+    match existing {
+        Some(existing) => {
+            let merged = DiscoveredAsset {
+                usdcvol: existing.usdcvol.saturating_add(stripped.usdcvol),
+                trades: existing.trades.saturating_add(stripped.trades),
+                firstseen: existing.firstseen.min(stripped.firstseen),
+                lastseen: existing.lastseen.max(stripped.lastseen),
+                ..existing.clone()
+            };
+            let void = DiscoveredAsset {
+                usdcvol: 0,
+                trades: 0,
+                ..stripped.clone()
+            };
+
+            update(code, &merged)?;
+            update(&stripped.floatcode, &void)
+        }
+        None => update(
+            &stripped.floatcode,
+            &DiscoveredAsset {
+                floatcode: code.to_string(),
+                ..stripped.clone()
+            },
+        ),
+    }
+}
+
+// How codes were saved before they were decoded as the protocol defines them
+fn stripped_asset_code(code: &str) -> String {
+    code.chars()
+        .filter(|char| char.is_ascii_alphabetic())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn discovered(code: &str, lastseen: u64) -> DiscoveredAsset {
+        DiscoveredAsset {
+            floatcode: code.to_string(),
+            fltissuer: "GISSUER".to_string(),
+            usdcvol: 1,
+            trades: 1,
+            firstseen: 0,
+            lastseen,
+        }
+    }
+
+    #[test]
+    fn codes_are_stripped_to_their_letters() {
+        assert_eq!(stripped_asset_code("USDC2"), "USDC");
+        assert_eq!(stripped_asset_code("1INCH"), "INCH");
+        assert_eq!(stripped_asset_code("AQUA"), "AQUA");
+    }
+
+    #[test]
+    fn no_discovery_is_no_evidence() {
+        assert!(matches!(
+            stripped_discovery("USDC2", "USDC", &[], None),
+            Some(None)
+        ));
+    }
+
+    #[test]
+    fn the_full_code_in_discovery_is_the_same_asset() {
+        let full = discovered("USDC2", 500);
+
+        assert!(matches!(
+            stripped_discovery("USDC2", "USDC", &[&full], None),
+            Some(None)
+        ));
+    }
+
+    #[test]
+    fn another_code_in_discovery_is_ambiguous() {
+        let other = discovered("USDC3", 50);
+
+        assert!(stripped_discovery("USDC2", "USDC", &[&other], Some(100)).is_none());
+    }
+
+    #[test]
+    fn the_stripped_code_seen_before_the_full_one_is_the_asset() {
+        let stripped = discovered("USDC", 50);
+        let found = stripped_discovery("USDC2", "USDC", &[&stripped], Some(100));
+
+        assert!(matches!(found, Some(Some(asset)) if asset.floatcode == "USDC"));
+    }
+
+    #[test]
+    fn the_stripped_code_still_traded_is_another_asset() {
+        let stripped = discovered("USDC", 150);
+
+        assert!(stripped_discovery("USDC2", "USDC", &[&stripped], Some(100)).is_none());
+        assert!(stripped_discovery("USDC2", "USDC", &[&stripped], None).is_none());
+    }
+}
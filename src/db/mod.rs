@@ -1,100 +1,610 @@
+pub(crate) mod audit;
+pub(crate) mod composite;
+pub(crate) mod discovery;
 pub(crate) mod exchange_rate;
+pub(crate) mod ingestion;
+pub(crate) mod journal;
+pub(crate) mod lineage;
+pub(crate) mod metrics;
+pub(crate) mod migration;
+pub(crate) mod risk;
 pub(crate) mod savepoint;
+pub(crate) mod scam;
 pub(crate) mod swap;
+pub(crate) mod token;
+
+use std::collections::HashMap;
 
 use zephyr_sdk::{DatabaseInteract, EnvClient};
 
-use exchange_rate::RatesDbRow;
-use savepoint::Savepoint;
-use swap::{Swap, SwapDbRow};
+use discovery::{DiscoveredAsset, UnlistedSwap};
+use exchange_rate::{latest_revisions, RateStrategy, RatesDbRow, VOID_ALGORITHM};
+use ingestion::{Gap, LastLedger};
+use journal::{JournalEntry, SwapRecord};
+use savepoint::{active_savepoints, Savepoint};
+use swap::SwapDbRow;
 
-use crate::config::RATE_UPDATE_INTERVAL;
+use crate::{
+    config::{DEFAULT_RATE_STRATEGY, MAX_CATCH_UP_WINDOWS, RATE_INTERVALS},
+    utils::{interval_name, window_end},
+};
 
-pub(crate) fn save_swaps(client: &EnvClient, swaps: &[Swap]) {
-    let timestamp = client.reader().ledger_timestamp();
+/**
+ * We compare the current ledger with the last one we ingested. If any ledgers
+ * were skipped, we record the gap so that the API can tell missing data apart
+ * from a lack of trades.
+ */
+pub(crate) fn track_ingestion(client: &EnvClient) {
+    let current = LastLedger {
+        sequence: client.reader().ledger_sequence() as u64,
+        closetime: client.reader().ledger_timestamp(),
+    };
+
+    let Some(last) = client
+        .read::<LastLedger>()
+        .into_iter()
+        .max_by_key(|l| l.sequence)
+    else {
+        // A force-deploy loses the last ingested ledger, but not the data
+        // saved before it, so the gap starts at the latest of that data.
+        if let Some(last) = last_saved_ledger(client) {
+            record_gap(client, &last, &current);
+        }
+        current.put(client);
+        return;
+    };
+
+    record_gap(client, &last, &current);
+
+    if let Err(sdk_error) = client
+        .update()
+        .column_equal_to("sequence", last.sequence)
+        .execute(&current)
+    {
+        client.log().debug(
+            format!(
+                "Sequence {} failed to update the last ingested ledger: {sdk_error}",
+                current.sequence
+            ),
+            None,
+        )
+    };
+}
+
+/**
+ * Record the ledgers between the last one we ingested and the current one, if
+ * any.
+ */
+fn record_gap(client: &EnvClient, last: &LastLedger, current: &LastLedger) {
+    let Some(gap) = ingestion_gap(last, current) else {
+        return;
+    };
+
+    let ledgers = match gap.first_ledger() {
+        Some(first) => format!("ledgers {first} to {}", gap.lastseq),
+        None => format!("ledgers up to {}", gap.lastseq),
+    };
+    client.log().error(
+        format!("Ingestion gap: {ledgers} were not processed."),
+        None,
+    );
+    gap.put(client);
+}
+
+/**
+ * The ledgers skipped between the last one we ingested and the current one.
+ * When we only know when the last one closed, any time since counts.
+ */
+fn ingestion_gap(last: &LastLedger, current: &LastLedger) -> Option<Gap> {
+    let skipped = if last.sequence == 0 {
+        current.closetime > last.closetime
+    } else {
+        current.sequence > last.sequence + 1
+    };
 
-    swaps
+    skipped.then(|| Gap {
+        firstseq: if last.sequence == 0 {
+            0
+        } else {
+            last.sequence + 1
+        },
+        lastseq: current.sequence - 1,
+        gapstart: last.closetime,
+        gapend: current.closetime,
+    })
+}
+
+/**
+ * The latest ledger we can tell we ingested from the data it saved. The
+ * journal and the swaps saved with an identity know ledger sequences;
+ * savepoints and older swaps only give a time, in which case the sequence is
+ * 0. Nothing before the latest savepoint is read, as the swaps since then are
+ * bound to be later.
+ */
+fn last_saved_ledger(client: &EnvClient) -> Option<LastLedger> {
+    let savepoint = active_savepoints(client)
         .iter()
-        .for_each(|swap| SwapDbRow::new(swap, timestamp).put(client));
+        .map(|savepoint| savepoint.savepoint)
+        .max()?;
+
+    let journaled = client
+        .read_filter()
+        .column_gt("closetime", savepoint.saturating_sub(1))
+        .read::<JournalEntry>()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| LastLedger {
+            sequence: entry.ledger,
+            closetime: entry.closetime,
+        });
+    let swaps = client
+        .read_filter()
+        .column_gt("creation", savepoint.saturating_sub(1))
+        .read::<SwapDbRow>()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|swap| LastLedger {
+            sequence: swap.ledger,
+            closetime: swap.creation,
+        });
+
+    journaled
+        .chain(swaps)
+        .chain(std::iter::once(LastLedger {
+            sequence: 0,
+            closetime: savepoint,
+        }))
+        .max_by_key(|ledger| (ledger.closetime, ledger.sequence))
+}
+
+pub(crate) fn save_swaps(client: &EnvClient, records: &[SwapRecord]) {
+    let ledger = client.reader().ledger_sequence() as u64;
+    let timestamp = client.reader().ledger_timestamp();
+
+    for record in records {
+        if let Ok(swap) = &record.outcome {
+            SwapDbRow::new(swap, ledger, record.position, timestamp).put(client);
+        }
+    }
+}
+
+/**
+ * Add the swaps of assets we do not index to the running totals of the
+ * discovery table. Swaps are grouped by asset first, so each asset costs one
+ * read and one write per ledger.
+ */
+pub(crate) fn save_discoveries(client: &EnvClient, unlisted_swaps: &[UnlistedSwap]) {
+    let timestamp = client.reader().ledger_timestamp();
+
+    let totals = unlisted_swaps.iter().fold(
+        HashMap::new(),
+        |mut acc: HashMap<(&str, &str), (i128, u64)>, swap| {
+            let (volume, trades) = acc.entry((&swap.code, &swap.issuer)).or_default();
+            *volume = volume.saturating_add(swap.usdc_amount);
+            *trades += 1;
+            acc
+        },
+    );
+
+    for ((code, issuer), (volume, trades)) in totals {
+        let previous = client
+            .read_filter()
+            .column_equal_to("floatcode", code.to_string())
+            .column_equal_to("fltissuer", issuer.to_string())
+            .read::<DiscoveredAsset>();
+
+        let previous = match previous {
+            Ok(previous) => previous.into_iter().next(),
+            Err(sdk_error) => {
+                client.log().debug(
+                    format!("Failed to read discovered asset {code} ({issuer}): {sdk_error}"),
+                    None,
+                );
+                continue;
+            }
+        };
+
+        match previous {
+            Some(previous) => {
+                // A void row starts over, as if the asset was new
+                let discovered = DiscoveredAsset {
+                    usdcvol: previous.usdcvol.saturating_add(volume),
+                    trades: previous.trades.saturating_add(trades),
+                    firstseen: if previous.is_void() {
+                        timestamp
+                    } else {
+                        previous.firstseen
+                    },
+                    lastseen: timestamp,
+                    ..previous
+                };
+
+                if let Err(sdk_error) = client
+                    .update()
+                    .column_equal_to("floatcode", code.to_string())
+                    .column_equal_to("fltissuer", issuer.to_string())
+                    .execute(&discovered)
+                {
+                    client.log().debug(
+                        format!("Failed to update discovered asset {code} ({issuer}): {sdk_error}"),
+                        None,
+                    )
+                };
+            }
+            None => DiscoveredAsset {
+                floatcode: code.to_string(),
+                fltissuer: issuer.to_string(),
+                usdcvol: volume,
+                trades,
+                firstseen: timestamp,
+                lastseen: timestamp,
+            }
+            .put(client),
+        }
+    }
 }
 
 pub(crate) fn save_rates(client: &EnvClient) {
-    let savepoints = client.read::<Savepoint>();
+    let savepoints = active_savepoints(client);
+
+    RATE_INTERVALS
+        .iter()
+        .for_each(|(_, interval)| save_rates_for_interval(client, &savepoints, *interval));
+}
+
+fn save_rates_for_interval(client: &EnvClient, all_savepoints: &[Savepoint], interval: u64) {
+    let savepoints = all_savepoints
+        .iter()
+        .filter(|savepoint| savepoint.interval == interval)
+        .collect::<Vec<_>>();
 
     // We only create a savepoint if none is found, and update
-    // otherwise. This means we should never have more than one.
+    // otherwise. This means we should never have more than one per window.
     if savepoints.len() > 1 {
-        client
-            .log()
-            .error("Error: more than one savepoint found.", None);
+        client.log().error(
+            format!("Error: more than one savepoint found for interval {interval}."),
+            None,
+        );
         return;
     }
 
-    let current_timestamp = client.reader().ledger_timestamp();
-    let latest_savepoint = savepoints
-        .first()
-        .map(|s| s.savepoint)
-        .unwrap_or(current_timestamp - RATE_UPDATE_INTERVAL);
+    let latest_savepoint = savepoints.first().map(|savepoint| savepoint.savepoint);
+    let timestamp = client.reader().ledger_timestamp();
 
-    // We check if it is time to save the exchange rates.
-    let is_time_to_save_rates = if savepoints.is_empty() {
-        // When we force-deploy there is no savepoint, so we create one.
-        first_savepoint(client, current_timestamp);
+    match window_update(latest_savepoint, timestamp, interval) {
+        None => {}
+        Some(WindowUpdate::FirstSavepoint(window_end)) => {
+            first_savepoint(client, window_end, interval)
+        }
+        Some(WindowUpdate::Save {
+            savepoint,
+            window_ends,
+        }) => {
+            update_savepoint(
+                client,
+                savepoint,
+                latest_savepoint.unwrap_or_default(),
+                interval,
+            );
+            window_ends
+                .into_iter()
+                .for_each(|end| save_window_rates(client, end - interval, end, interval));
+        }
+    }
+}
 
-        // In that case we always save the rates.
-        true
-    } else {
-        // If there is a savepoint, we only save fresh rates if
-        // the existing ones are stale.
-        let are_rates_stale = current_timestamp - latest_savepoint > RATE_UPDATE_INTERVAL;
+/**
+ * What a ledger close does to the savepoint of a window length.
+ */
+#[derive(Debug, PartialEq)]
+enum WindowUpdate {
+    // There is no savepoint yet, so one is created at the end of the last
+    // complete window, without saving its rates
+    FirstSavepoint(u64),
+    // The savepoint moves forward, and the windows ending after the old one,
+    // up to and including the new one, are saved
+    Save {
+        savepoint: u64,
+        window_ends: Vec<u64>,
+    },
+}
 
-        if are_rates_stale {
-            update_savepoint(client, current_timestamp, latest_savepoint);
-        }
+/**
+ * Work out what a ledger closing at the timestamp does to the savepoint of a
+ * window length, if anything. Each length has its own savepoint, so the
+ * lengths are saved side by side.
+ */
+fn window_update(savepoint: Option<u64>, timestamp: u64, interval: u64) -> Option<WindowUpdate> {
+    let window_end = window_end(timestamp, interval);
 
-        are_rates_stale
+    let Some(savepoint) = savepoint else {
+        // When we force-deploy there is no savepoint, so we create one. The
+        // window in progress is incomplete, so no rates are saved for it.
+        return Some(WindowUpdate::FirstSavepoint(window_end));
     };
 
-    if is_time_to_save_rates {
-        // Whether the savepoint was defined or not, we calculate the rates
-        // for the interval defined as RATE_UPDATE_INTERVAL.
-        let rates = exchange_rate::calculate_exchange_rates(client, latest_savepoint);
-
-        rates.iter().for_each(|item| {
-            let mut row = RatesDbRow::from(item);
-            row.timestamp = current_timestamp;
-            row.put(client);
-        });
+    // The savepoint holds the end of the last window we saved rates for. We
+    // only save fresh rates once the ledger has crossed the next boundary.
+    if window_end <= savepoint {
+        return None;
     }
+
+    // If ledgers were skipped, several windows may have ended since the
+    // savepoint. We catch up on them from the stored swaps, a few at a time
+    // so that a long outage does not overwhelm a single ledger close.
+    let last_window_end = window_end.min(savepoint + MAX_CATCH_UP_WINDOWS * interval);
+
+    Some(WindowUpdate::Save {
+        savepoint: last_window_end,
+        window_ends: (1..=(last_window_end - savepoint) / interval)
+            .map(|index| savepoint + index * interval)
+            .collect(),
+    })
+}
+
+/**
+ * Save the rates of a window. If its swaps cannot be read, the window is
+ * skipped, so that the other windows and intervals are still saved; it can be
+ * replayed once the problem is fixed.
+ */
+fn save_window_rates(client: &EnvClient, window_start: u64, window_end: u64, interval: u64) {
+    let rates = match exchange_rate::calculate_exchange_rates(
+        client,
+        window_start,
+        window_end,
+        DEFAULT_RATE_STRATEGY,
+    ) {
+        Ok(rates) => rates,
+        Err(message) => {
+            client.log().error(
+                format!(
+                    "Skipped the {} window ending at {window_end}: {message}",
+                    interval_name(interval)
+                ),
+                None,
+            );
+            return;
+        }
+    };
+
+    rates.iter().for_each(|item| {
+        let mut row = RatesDbRow::from(item);
+        row.winstart = window_start;
+        row.timestamp = window_end;
+        row.interval = interval;
+        row.computed = client.reader().ledger_timestamp();
+        row.algo = DEFAULT_RATE_STRATEGY.algorithm().to_string();
+        row.put(client);
+    });
+}
+
+/**
+ * Recompute the rates of a past window from the stored swaps. Each new row
+ * gets the next revision number for its asset and window, so the rows it
+ * corrects are kept. Assets that had a rate and no longer get one, e.g. as
+ * their swaps are now filtered out, get a void revision. Returns the number
+ * of rows written.
+ */
+pub(crate) fn replay_window_rates(
+    client: &EnvClient,
+    window_start: u64,
+    window_end: u64,
+    interval: u64,
+    strategy: RateStrategy,
+) -> Result<usize, String> {
+    let previous_rows = client
+        .read_filter()
+        .column_equal_to("interval", interval)
+        .column_equal_to("timestamp", window_end)
+        .read::<RatesDbRow>()
+        .map_err(|sdk_error| sdk_error.to_string())?;
+
+    // We stamp the new revisions with the latest ledger we ingested
+    let computed = latest_close_time(client);
+
+    let rates =
+        exchange_rate::calculate_exchange_rates(client, window_start, window_end, strategy)?;
+
+    rates.iter().for_each(|item| {
+        let mut row = RatesDbRow::from(item);
+        row.winstart = window_start;
+        row.timestamp = window_end;
+        row.interval = interval;
+        row.revision = previous_rows
+            .iter()
+            .filter(|previous| {
+                previous.floatcode == row.floatcode && previous.fltissuer == row.fltissuer
+            })
+            .map(|previous| previous.revision + 1)
+            .max()
+            .unwrap_or(0);
+        row.computed = computed;
+        row.algo = strategy.algorithm().to_string();
+        row.put(client);
+    });
+
+    let voided = latest_revisions(previous_rows, None)
+        .into_iter()
+        .filter(|previous| {
+            !previous.is_void()
+                && !rates.contains_key(&(previous.floatcode.clone(), previous.fltissuer.clone()))
+        })
+        .map(|previous| {
+            RatesDbRow {
+                fixedrate: 0,
+                fixedvol: 0,
+                revision: previous.revision + 1,
+                computed,
+                algo: VOID_ALGORITHM.to_string(),
+                ..previous
+            }
+            .put(client)
+        })
+        .count();
+
+    Ok(rates.len() + voided)
+}
+
+/**
+ * The close time of the latest ledger we ingested. Functions do not run on a
+ * ledger close, so this is the closest thing they have to the current time.
+ */
+pub(crate) fn latest_close_time(client: &EnvClient) -> u64 {
+    client
+        .read::<LastLedger>()
+        .iter()
+        .map(|ledger| ledger.closetime)
+        .max()
+        .unwrap_or_default()
 }
 
-fn first_savepoint(client: &EnvClient, current_timestamp: u64) {
+fn first_savepoint(client: &EnvClient, window_end: u64, interval: u64) {
     let savepoint = Savepoint {
-        savepoint: current_timestamp,
+        savepoint: window_end,
+        interval,
     };
 
     savepoint.put(client);
-    client
-        .log()
-        .debug(format!("First savepoint: {current_timestamp}"), None);
+    client.log().debug(
+        format!("First savepoint for interval {interval}: {window_end}"),
+        None,
+    );
 }
 
-fn update_savepoint(client: &EnvClient, current_timestamp: u64, latest_savepoint: u64) {
+fn update_savepoint(client: &EnvClient, window_end: u64, latest_savepoint: u64, interval: u64) {
     let savepoint = Savepoint {
-        savepoint: current_timestamp,
+        savepoint: window_end,
+        interval,
     };
 
     if let Err(sdk_error) = client
         .update()
+        .column_equal_to("interval", interval)
         .column_equal_to("savepoint", latest_savepoint)
         .execute(&savepoint)
     {
         client.log().debug(
             format!(
-                "Sequence {} failed to update savepoint: {sdk_error}",
+                "Sequence {} failed to update savepoint for interval {interval}: {sdk_error}",
                 client.reader().ledger_sequence()
             ),
             None,
         )
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 3600;
+
+    #[test]
+    fn a_first_run_only_creates_the_savepoint() {
+        assert_eq!(
+            window_update(None, 10 * HOUR + 125, HOUR),
+            Some(WindowUpdate::FirstSavepoint(10 * HOUR))
+        );
+    }
+
+    #[test]
+    fn each_window_length_moves_its_own_savepoint() {
+        let midnight = 100 * 24 * HOUR;
+        let timestamp = midnight + 10 * HOUR + 30;
+        let savepoints = [midnight + 10 * HOUR - 300, midnight + 9 * HOUR, midnight];
+        let updates = RATE_INTERVALS
+            .iter()
+            .zip(savepoints)
+            .map(|((_, interval), savepoint)| window_update(Some(savepoint), timestamp, *interval))
+            .collect::<Vec<_>>();
+
+        // The day has not ended yet, so only the shorter windows are saved
+        assert_eq!(
+            updates,
+            [
+                Some(WindowUpdate::Save {
+                    savepoint: midnight + 10 * HOUR,
+                    window_ends: vec![midnight + 10 * HOUR],
+                }),
+                Some(WindowUpdate::Save {
+                    savepoint: midnight + 10 * HOUR,
+                    window_ends: vec![midnight + 10 * HOUR],
+                }),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn a_ledger_on_the_savepoint_saves_nothing() {
+        assert_eq!(window_update(Some(10 * HOUR), 10 * HOUR, HOUR), None);
+        assert_eq!(window_update(Some(10 * HOUR), 11 * HOUR - 1, HOUR), None);
+    }
+
+    #[test]
+    fn a_ledger_on_the_next_boundary_saves_its_window() {
+        assert_eq!(
+            window_update(Some(10 * HOUR), 11 * HOUR, HOUR),
+            Some(WindowUpdate::Save {
+                savepoint: 11 * HOUR,
+                window_ends: vec![11 * HOUR],
+            })
+        );
+    }
+
+    #[test]
+    fn catching_up_stops_at_the_window_limit() {
+        let Some(WindowUpdate::Save {
+            savepoint,
+            window_ends,
+        }) = window_update(Some(10 * HOUR), 40 * HOUR + 5, HOUR)
+        else {
+            panic!("the windows since the savepoint should be saved");
+        };
+
+        assert_eq!(savepoint, (10 + MAX_CATCH_UP_WINDOWS) * HOUR);
+        assert_eq!(window_ends.len() as u64, MAX_CATCH_UP_WINDOWS);
+        assert_eq!(window_ends.first(), Some(&(11 * HOUR)));
+        assert_eq!(window_ends.last(), Some(&savepoint));
+
+        // The next ledger picks up where this one stopped
+        assert_eq!(
+            window_update(Some(savepoint), 40 * HOUR + 10, HOUR),
+            Some(WindowUpdate::Save {
+                savepoint: savepoint + MAX_CATCH_UP_WINDOWS * HOUR,
+                window_ends: (1..=MAX_CATCH_UP_WINDOWS)
+                    .map(|index| savepoint + index * HOUR)
+                    .collect(),
+            })
+        );
+    }
+
+    fn ledger(sequence: u64, closetime: u64) -> LastLedger {
+        LastLedger {
+            sequence,
+            closetime,
+        }
+    }
+
+    #[test]
+    fn consecutive_ledgers_leave_no_gap() {
+        assert!(ingestion_gap(&ledger(100, 500), &ledger(101, 505)).is_none());
+    }
+
+    #[test]
+    fn skipped_ledgers_are_recorded_as_a_gap() {
+        let gap = ingestion_gap(&ledger(100, 500), &ledger(110, 550)).unwrap();
+
+        assert_eq!((gap.first_ledger(), gap.lastseq), (Some(101), 109));
+        assert_eq!((gap.gapstart, gap.gapend), (500, 550));
+    }
+
+    #[test]
+    fn a_gap_after_a_force_deploy_starts_at_the_saved_data() {
+        let gap = ingestion_gap(&ledger(0, 500), &ledger(110, 550)).unwrap();
+
+        assert_eq!((gap.first_ledger(), gap.lastseq), (None, 109));
+        assert_eq!((gap.gapstart, gap.gapend), (500, 550));
+        assert!(ingestion_gap(&ledger(0, 550), &ledger(110, 550)).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap, HashMap},
+    sync::OnceLock,
+};
+
+use stellar_strkey::ed25519::PublicKey;
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+use super::{swap::SwapAsset, token::issuer_tokens};
+use crate::utils::parse_date;
+
+// Issuer account flags, as defined by the protocol
+const AUTH_REQUIRED: u32 = 0x1;
+const AUTH_REVOCABLE: u32 = 0x2;
+const AUTH_IMMUTABLE: u32 = 0x4;
+const AUTH_CLAWBACK_ENABLED: u32 = 0x8;
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("assetrisk")]
+/**
+ * What a holder of a registered asset risks from its issuer: whether the
+ * issuer can freeze or claw back holdings, and whether it has. Flags belong to
+ * the issuer account, so all its assets share them.
+ */
+pub(crate) struct AssetRisk {
+    pub(crate) floatcode: String,
+    pub(crate) fltissuer: String,
+    pub(crate) flags: u32,
+    // When the flags were last seen to change, or 0 if they never were
+    pub(crate) flagsat: u64,
+    pub(crate) clawbacks: u64,
+    pub(crate) lastclawb: u64,
+    pub(crate) freezes: u64,
+    pub(crate) lastfreez: u64,
+}
+
+/**
+ * Something that happened to a registered asset or its issuer in a ledger.
+ */
+pub(crate) enum RiskEvent {
+    Flags { issuer: [u8; 32], flags: u32 },
+    Clawback(&'static SwapAsset),
+    Freeze(&'static SwapAsset),
+}
+
+impl AssetRisk {
+    pub(crate) fn auth_required(&self) -> bool {
+        self.flags & AUTH_REQUIRED != 0
+    }
+
+    pub(crate) fn auth_revocable(&self) -> bool {
+        self.flags & AUTH_REVOCABLE != 0
+    }
+
+    pub(crate) fn auth_immutable(&self) -> bool {
+        self.flags & AUTH_IMMUTABLE != 0
+    }
+
+    pub(crate) fn clawback_enabled(&self) -> bool {
+        self.flags & AUTH_CLAWBACK_ENABLED != 0
+    }
+
+    pub(crate) fn flags_changed_iso8601(&self) -> Option<String> {
+        (self.flagsat > 0).then(|| parse_date(&(self.flagsat as i64)))
+    }
+
+    pub(crate) fn last_clawback_iso8601(&self) -> Option<String> {
+        (self.lastclawb > 0).then(|| parse_date(&(self.lastclawb as i64)))
+    }
+
+    pub(crate) fn last_freeze_iso8601(&self) -> Option<String> {
+        (self.lastfreez > 0).then(|| parse_date(&(self.lastfreez as i64)))
+    }
+}
+
+/**
+ * Record the risk events of a ledger. Only the rows of the assets involved are
+ * read. An asset without a row gets one, with the flags its issuer has on the
+ * ledger.
+ */
+pub(crate) fn save_risk_events(client: &EnvClient, events: &[RiskEvent]) {
+    let timestamp = client.reader().ledger_timestamp();
+    // The rows of the assets involved so far, whether each is saved already,
+    // and whether it changed
+    let mut rows = BTreeMap::<(&str, &str), (AssetRisk, bool, bool)>::new();
+
+    for event in events {
+        let assets = match event {
+            RiskEvent::Flags { issuer, .. } => issuer_tokens(issuer),
+            RiskEvent::Clawback(asset) | RiskEvent::Freeze(asset) => vec![*asset],
+        };
+
+        for asset in assets {
+            let (row, _, changed) = match rows.entry((&asset.code, &asset.issuer)) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => match read_asset_risk(client, asset) {
+                    Some((row, is_saved)) => entry.insert((row, is_saved, false)),
+                    None => continue,
+                },
+            };
+
+            match event {
+                RiskEvent::Flags { flags, .. } if row.flags != *flags => {
+                    row.flags = *flags;
+                    row.flagsat = timestamp;
+                    *changed = true;
+                }
+                RiskEvent::Flags { .. } => {}
+                RiskEvent::Clawback(_) => {
+                    row.clawbacks += 1;
+                    row.lastclawb = timestamp;
+                    *changed = true;
+                }
+                RiskEvent::Freeze(_) => {
+                    row.freezes += 1;
+                    row.lastfreez = timestamp;
+                    *changed = true;
+                }
+            }
+        }
+    }
+
+    for (row, is_saved, changed) in rows.values() {
+        if !is_saved {
+            row.put(client);
+        } else if *changed {
+            let result = client
+                .update()
+                .column_equal_to("floatcode", row.floatcode.clone())
+                .column_equal_to("fltissuer", row.fltissuer.clone())
+                .execute(row);
+
+            if let Err(sdk_error) = result {
+                client.log().error(
+                    format!(
+                        "Could not update the risk of {}: {sdk_error}",
+                        row.floatcode
+                    ),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+/**
+ * Start following the risk of registered assets, so that the flags of their
+ * issuers are known before they first change. Assets already followed, and
+ * custom Soroban tokens, which have no issuer account, are skipped.
+ */
+pub(crate) fn seed_asset_risks(client: &EnvClient, tokens: &[SwapAsset]) {
+    for token in tokens {
+        if let Some((row, false)) = read_asset_risk(client, token) {
+            row.put(client);
+        }
+    }
+}
+
+// The saved row of a registered classic asset, or a new one with the flags its
+// issuer has on the ledger, and whether it is saved
+fn read_asset_risk(client: &EnvClient, asset: &SwapAsset) -> Option<(AssetRisk, bool)> {
+    let issuer = PublicKey::from_string(&asset.issuer).ok()?;
+    let saved = client
+        .read_filter()
+        .column_equal_to("floatcode", asset.code.to_string())
+        .column_equal_to("fltissuer", asset.issuer.to_string())
+        .read::<AssetRisk>();
+
+    match saved {
+        Ok(saved) => match saved.into_iter().next() {
+            Some(row) => Some((row, true)),
+            None => {
+                let flags = client
+                    .read_account_from_ledger(issuer.0)
+                    .ok()
+                    .flatten()
+                    .map_or(0, |account| account.flags);
+                let row = AssetRisk {
+                    floatcode: asset.code.to_string(),
+                    fltissuer: asset.issuer.to_string(),
+                    flags,
+                    flagsat: 0,
+                    clawbacks: 0,
+                    lastclawb: 0,
+                    freezes: 0,
+                    lastfreez: 0,
+                };
+                Some((row, false))
+            }
+        },
+        Err(sdk_error) => {
+            client.log().error(
+                format!("Could not read the risk of {}: {sdk_error}", asset.code),
+                None,
+            );
+            None
+        }
+    }
+}
+
+// Every invocation of the program runs in a fresh instance, so this caches the
+// table for a single function call.
+static ASSET_RISKS: OnceLock<HashMap<(String, String), AssetRisk>> = OnceLock::new();
+
+/**
+ * The risk of an asset, if it is followed, i.e. registered.
+ */
+pub(crate) fn asset_risk(code: &str, issuer: &str) -> Option<&'static AssetRisk> {
+    ASSET_RISKS
+        .get_or_init(|| {
+            EnvClient::empty()
+                .read::<AssetRisk>()
+                .into_iter()
+                .map(|row| ((row.floatcode.clone(), row.fltissuer.clone()), row))
+                .collect()
+        })
+        .get(&(code.to_string(), issuer.to_string()))
+}
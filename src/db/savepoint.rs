@@ -1,12 +1,29 @@
 use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
 
+// Savepoints an audit repair found in excess are moved to this interval, which
+// no rate window has
+pub(crate) const RETIRED_INTERVAL: u64 = 0;
+
 #[derive(Clone, DatabaseDerive)]
 #[with_name("savepoint")]
 /**
  * On deployment, we save the current ledger timestamp. Then on every ledger
  * close, we check if enough time has passed since the last savepoint to save
- * the exchange rates. This window is defined in the config file.
+ * the exchange rates. There is one savepoint per rate window; the windows are
+ * defined in the config file.
  */
 pub(crate) struct Savepoint {
     pub(crate) savepoint: u64,
+    pub(crate) interval: u64,
+}
+
+/**
+ * Read the savepoints of the rate windows, leaving out the retired ones.
+ */
+pub(crate) fn active_savepoints(client: &EnvClient) -> Vec<Savepoint> {
+    client
+        .read::<Savepoint>()
+        .into_iter()
+        .filter(|savepoint| savepoint.interval != RETIRED_INTERVAL)
+        .collect()
 }
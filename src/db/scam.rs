@@ -0,0 +1,256 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::OnceLock,
+};
+
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+use stellar_strkey::ed25519::PublicKey;
+
+use crate::{
+    config::SCAM_ACCOUNT_IDS,
+    utils::{parse_date, sorted_ids_contain},
+};
+
+// Where the compiled list of scam issuers comes from
+const COMPILED_SCAM_SOURCE: &str = "StellarExpert (compiled)";
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("scams")]
+/**
+ * Changes to the compiled list of scam issuers. An issuer with a row is
+ * flagged or not as the row says; any other issuer is flagged if it is in the
+ * compiled list. Rows are updated rather than removed, so each keeps the last
+ * time it was added and removed.
+ */
+pub(crate) struct ScamDbRow {
+    pub(crate) issuer: String,
+    pub(crate) flagged: bool,
+    pub(crate) reason: String,
+    pub(crate) source: String,
+    // Timestamps of the imports that last flagged and unflagged the issuer, or
+    // 0 if none did
+    pub(crate) added: u64,
+    pub(crate) removed: u64,
+}
+
+impl ScamDbRow {
+    pub(crate) fn added_iso8601(&self) -> Option<String> {
+        (self.added > 0).then(|| parse_date(&(self.added as i64)))
+    }
+
+    pub(crate) fn removed_iso8601(&self) -> Option<String> {
+        (self.removed > 0).then(|| parse_date(&(self.removed as i64)))
+    }
+}
+
+// Every invocation of the program runs in a fresh instance, so this caches the
+// table for a single ledger close or function call. Rows are keyed on the raw
+// account ID, like the compiled list.
+static SCAM_CHANGES: OnceLock<HashMap<[u8; 32], ScamDbRow>> = OnceLock::new();
+
+fn scam_changes() -> &'static HashMap<[u8; 32], ScamDbRow> {
+    SCAM_CHANGES.get_or_init(|| {
+        EnvClient::empty()
+            .read::<ScamDbRow>()
+            .into_iter()
+            .filter_map(|row| Some((account_id(&row.issuer)?, row)))
+            .collect()
+    })
+}
+
+fn account_id(issuer: &str) -> Option<[u8; 32]> {
+    PublicKey::from_string(issuer)
+        .ok()
+        .map(|public_key| public_key.0)
+}
+
+pub(crate) fn is_compiled_scam(account_id: &[u8; 32]) -> bool {
+    sorted_ids_contain(SCAM_ACCOUNT_IDS, account_id)
+}
+
+/**
+ * What we know about an issuer: the row that changed it, or a description of
+ * the compiled entry if it is only in the compiled list.
+ */
+pub(crate) fn scam_entry(issuer: &str) -> Option<ScamDbRow> {
+    let account_id = account_id(issuer)?;
+
+    match scam_changes().get(&account_id) {
+        Some(row) => Some(row.clone()),
+        None => is_compiled_scam(&account_id).then(|| ScamDbRow {
+            issuer: issuer.to_string(),
+            flagged: true,
+            reason: "Flagged as malicious".to_string(),
+            source: COMPILED_SCAM_SOURCE.to_string(),
+            added: 0,
+            removed: 0,
+        }),
+    }
+}
+
+/**
+ * The check run on every asset we ingest, so it works on the raw account ID.
+ */
+pub(crate) fn is_scam_account(account_id: &[u8; 32]) -> bool {
+    match scam_changes().get(account_id) {
+        Some(row) => row.flagged,
+        None => is_compiled_scam(account_id),
+    }
+}
+
+/**
+ * All issuers currently flagged, from the compiled list and the table.
+ */
+pub(crate) fn flagged_issuers() -> Vec<String> {
+    let changes = scam_changes();
+
+    SCAM_ACCOUNT_IDS
+        .chunks_exact(32)
+        .filter_map(|account_id| <[u8; 32]>::try_from(account_id).ok())
+        .filter(|account_id| !changes.contains_key(account_id))
+        .map(|account_id| PublicKey(account_id).to_string())
+        .chain(
+            changes
+                .values()
+                .filter(|row| row.flagged)
+                .map(|row| row.issuer.clone()),
+        )
+        .collect()
+}
+
+/**
+ * The issuers an import flags and unflags, given those flagged now. Only the
+ * issuers it names for removal are unflagged, unless it replaces the list, e.g.
+ * with a fresh export from StellarExpert, in which case every issuer missing
+ * from it is.
+ */
+pub(crate) fn scam_import_changes(
+    flagged: &[String],
+    issuers: &[String],
+    unflag: &[String],
+    replace: bool,
+) -> (Vec<String>, Vec<String>) {
+    let current = flagged.iter().map(String::as_str).collect::<HashSet<_>>();
+    let new = issuers.iter().map(String::as_str).collect::<HashSet<_>>();
+
+    let mut added = new.difference(&current).copied().collect::<Vec<_>>();
+    let mut removed = if replace {
+        current.difference(&new).copied().collect::<Vec<_>>()
+    } else {
+        unflag
+            .iter()
+            .map(String::as_str)
+            .filter(|issuer| current.contains(issuer) && !new.contains(issuer))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect()
+    };
+    added.sort();
+    removed.sort();
+
+    (to_strings(&added), to_strings(&removed))
+}
+
+/**
+ * Flag and unflag issuers. Only these are written, so the table holds just the
+ * changes to the compiled list.
+ */
+pub(crate) fn save_scam_changes(
+    client: &EnvClient,
+    added: &[String],
+    removed: &[String],
+    source: &str,
+    reason: &str,
+    timestamp: u64,
+) -> Result<(), String> {
+    for issuer in added {
+        let row = ScamDbRow {
+            issuer: issuer.to_string(),
+            flagged: true,
+            reason: reason.to_string(),
+            source: source.to_string(),
+            added: timestamp,
+            removed: account_id(issuer)
+                .and_then(|account_id| scam_changes().get(&account_id))
+                .map_or(0, |row| row.removed),
+        };
+        save_scam_row(client, row)?;
+    }
+
+    for issuer in removed {
+        let row = match scam_entry(issuer) {
+            Some(previous) => ScamDbRow {
+                flagged: false,
+                removed: timestamp,
+                ..previous
+            },
+            None => continue,
+        };
+        save_scam_row(client, row)?;
+    }
+
+    Ok(())
+}
+
+fn save_scam_row(client: &EnvClient, row: ScamDbRow) -> Result<(), String> {
+    let is_saved =
+        account_id(&row.issuer).is_some_and(|account_id| scam_changes().contains_key(&account_id));
+
+    if is_saved {
+        client
+            .update()
+            .column_equal_to("issuer", row.issuer.clone())
+            .execute(&row)
+            .map_err(|sdk_error| sdk_error.to_string())
+    } else {
+        row.put(client);
+        Ok(())
+    }
+}
+
+fn to_strings(issuers: &[&str]) -> Vec<String> {
+    issuers.iter().map(|issuer| issuer.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issuers(names: &[&str]) -> Vec<String> {
+        to_strings(names)
+    }
+
+    #[test]
+    fn import_only_unflags_named_issuers_by_default() {
+        let flagged = issuers(&["A", "B", "C"]);
+        let (added, removed) = scam_import_changes(
+            &flagged,
+            &issuers(&["D", "A"]),
+            &issuers(&["C", "E"]),
+            false,
+        );
+
+        assert_eq!(added, issuers(&["D"]));
+        assert_eq!(removed, issuers(&["C"]));
+    }
+
+    #[test]
+    fn import_keeps_issuers_both_flagged_and_unflagged() {
+        let flagged = issuers(&["A"]);
+        let (added, removed) =
+            scam_import_changes(&flagged, &issuers(&["A"]), &issuers(&["A"]), false);
+
+        assert!(added.is_empty());
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn replacing_import_unflags_missing_issuers() {
+        let flagged = issuers(&["A", "B", "C"]);
+        let (added, removed) = scam_import_changes(&flagged, &issuers(&["C", "D"]), &[], true);
+
+        assert_eq!(added, issuers(&["D"]));
+        assert_eq!(removed, issuers(&["A", "B"]));
+    }
+}
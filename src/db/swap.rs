@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{borrow::Cow, fmt::Display};
 
 use zephyr_sdk::{
     prelude::*,
@@ -7,56 +7,158 @@ use zephyr_sdk::{
 };
 
 use crate::{
-    config::{CONVERSION_FACTOR, USDC, XLM_ADDRESS},
+    config::{xlm, CLASSIC_DECIMALS, RATE_DECIMALS, USDC},
+    fixed_point::{ratio, to_decimal_string},
     utils::build_nonnative_swap_asset,
 };
 
+/**
+ * The price of a swap is the amount of floating coin per unit of USDC, so the
+ * two amounts and their decimals are all we need to store it exactly. USDC
+ * always has the same decimals, so only those of the floating coin are kept.
+ * A swap is identified by its ledger and its position in it, which tells a
+ * copy of it apart from a genuine trade of the same amounts.
+ */
 #[derive(Clone, DatabaseDerive)]
 #[with_name("swaps")]
 pub(crate) struct SwapDbRow {
     pub(crate) creation: u64,
-    pub(crate) usdc_amnt: i64,
     pub(crate) floatcode: String,
     pub(crate) fltissuer: String,
-    pub(crate) numerator: i64,
-    pub(crate) denom: i64,
+    pub(crate) usdcamnt: i128,
+    pub(crate) fltamnt: i128,
+    pub(crate) fltdecs: u32,
+    // Swaps saved before their identity have a ledger of 0
+    pub(crate) ledger: u64,
+    pub(crate) txindex: u64,
+    pub(crate) opindex: u64,
+    pub(crate) claimidx: u64,
+}
+
+/**
+ * Where a swap is in its ledger: the transaction, its operation, and the
+ * claim atom of the operation result. A Soroswap swap is placed by its event
+ * among the Soroban events of the transaction, and the hop of its path.
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct SwapPosition {
+    pub(crate) transaction: u64,
+    pub(crate) operation: u64,
+    pub(crate) claim: u64,
 }
 
 impl SwapDbRow {
-    pub(crate) fn new(swap: &Swap, timestamp: u64) -> Self {
+    // Ingestion rejects zero amounts, so a swap without any has been voided by
+    // an audit repair
+    pub(crate) fn is_void(&self) -> bool {
+        self.usdcamnt == 0 && self.fltamnt == 0
+    }
+
+    pub(crate) fn new(swap: &Swap, ledger: u64, position: SwapPosition, timestamp: u64) -> Self {
         Self {
             creation: timestamp,
-            usdc_amnt: swap.usdc_amount as i64,
             floatcode: swap.floating_asset_code.clone(),
             fltissuer: swap.floating_asset_issuer.clone(),
-            numerator: swap.price_numerator,
-            denom: swap.price_denominator,
+            usdcamnt: swap.usdc_amount,
+            fltamnt: swap.floating_amount,
+            fltdecs: swap.floating_decimals,
+            ledger,
+            txindex: position.transaction,
+            opindex: position.operation,
+            claimidx: position.claim,
         }
     }
+
+    // The ledger and position of the swap, unless it was saved before swaps
+    // had an identity
+    pub(crate) fn identity(&self) -> Option<(u64, u64, u64, u64)> {
+        (self.ledger > 0).then_some((self.ledger, self.txindex, self.opindex, self.claimidx))
+    }
+
+    // The price of the swap, multiplied by 10^RATE_DECIMALS
+    pub(crate) fn price(&self) -> Option<i128> {
+        ratio(
+            self.fltamnt,
+            self.fltdecs,
+            self.usdcamnt,
+            USDC.decimals,
+            RATE_DECIMALS,
+        )
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+/**
+ * Swaps saved before amounts became 128-bit integers. The columns are kept
+ * until every row has been migrated to the new ones.
+ */
+#[derive(Clone, DatabaseDerive)]
+#[with_name("swaps")]
+pub(crate) struct LegacySwapDbRow {
+    pub(crate) creation: u64,
+    pub(crate) usdc_amnt: i64,
+    pub(crate) floatcode: String,
+    pub(crate) fltissuer: String,
+    pub(crate) numerator: i64,
+    pub(crate) denom: i64,
+}
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("swaps")]
+pub(crate) struct SwapAmounts {
+    pub(crate) usdcamnt: i128,
+    pub(crate) fltamnt: i128,
+    pub(crate) fltdecs: u32,
+}
+
+// Just the asset code, to repair the codes saved without their digits
+#[derive(Clone, DatabaseDerive)]
+#[with_name("swaps")]
+pub(crate) struct SwapCode {
+    pub(crate) floatcode: String,
+}
+
+// Just the time, to tell when an asset was first saved under a code
+#[derive(Clone, DatabaseDerive)]
+#[with_name("swaps")]
+pub(crate) struct SwapCreation {
+    pub(crate) creation: u64,
+}
+
+impl From<&LegacySwapDbRow> for SwapAmounts {
+    // The legacy denominator was always the USDC amount, and every asset was
+    // assumed to have 7 decimals.
+    fn from(row: &LegacySwapDbRow) -> Self {
+        SwapAmounts {
+            usdcamnt: row.denom as i128,
+            fltamnt: row.numerator as i128,
+            fltdecs: CLASSIC_DECIMALS,
+        }
+    }
+}
+
+/**
+ * Registered assets borrow their details from the compiled registry, while
+ * those accepted in open coverage own theirs.
+ */
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) struct SwapAsset {
-    pub(crate) code: &'static str,
-    pub(crate) issuer: &'static str,
-    pub(crate) contract: &'static str,
+    pub(crate) code: Cow<'static, str>,
+    pub(crate) issuer: Cow<'static, str>,
+    pub(crate) contract: Cow<'static, str>,
+    pub(crate) decimals: u32,
 }
 
 impl TryFrom<&Asset> for SwapAsset {
-    type Error = String;
+    type Error = Rejection;
 
     fn try_from(asset: &Asset) -> Result<Self, Self::Error> {
         match asset {
-            Asset::Native => Ok(SwapAsset {
-                code: "XLM",
-                issuer: "Native",
-                contract: XLM_ADDRESS,
-            }),
+            Asset::Native => Ok(xlm().clone()),
             Asset::CreditAlphanum4(AlphaNum4 { asset_code, issuer }) => {
-                build_nonnative_swap_asset(asset_code.as_slice(), issuer.to_string()).copied()
+                build_nonnative_swap_asset(asset, asset_code.as_slice(), issuer)
             }
             Asset::CreditAlphanum12(AlphaNum12 { asset_code, issuer }) => {
-                build_nonnative_swap_asset(asset_code.as_slice(), issuer.to_string()).copied()
+                build_nonnative_swap_asset(asset, asset_code.as_slice(), issuer)
             }
         }
     }
@@ -64,79 +166,141 @@ impl TryFrom<&Asset> for SwapAsset {
 
 #[derive(Debug)]
 pub(crate) struct SwapData {
-    pub(crate) amount_bought: i64,
-    pub(crate) amount_sold: i64,
-    pub(crate) asset_bought: Option<SwapAsset>,
-    pub(crate) asset_sold: Option<SwapAsset>,
+    pub(crate) amount_bought: i128,
+    pub(crate) amount_sold: i128,
+    pub(crate) asset_bought: Result<SwapAsset, Rejection>,
+    pub(crate) asset_sold: Result<SwapAsset, Rejection>,
+}
+
+/**
+ * Why ingestion did not save a swap, as recorded in the journal.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Rejection {
+    // The issuer of one of the assets is flagged as a scam
+    ScamIssuer,
+    // An asset code that breaks the protocol's rules
+    InvalidCode,
+    // A classic asset calling itself XLM
+    NonNativeXlm,
+    // A classic asset that is not in the registry
+    UnknownAsset,
+    // A Soroban token that is not in the registry
+    UnknownToken,
+    // Neither side of the swap is USDC
+    NotUsdc,
+    // One side of the swap is empty, which gives no price
+    ZeroAmount,
+    // The raw amounts give a price of exactly 1e-7, which we have seen for
+    // XLM swaps that are not real trades
+    DustPrice,
+    // An event we could not make sense of
+    MalformedEvent,
+}
+
+impl Rejection {
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            Rejection::ScamIssuer => "scam_issuer",
+            Rejection::InvalidCode => "invalid_code",
+            Rejection::NonNativeXlm => "non_native_xlm",
+            Rejection::UnknownAsset => "unknown_asset",
+            Rejection::UnknownToken => "unknown_token",
+            Rejection::NotUsdc => "not_usdc",
+            Rejection::ZeroAmount => "zero_amount",
+            Rejection::DustPrice => "dust_price",
+            Rejection::MalformedEvent => "malformed_event",
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct Swap {
     pub(crate) created_at: Option<u64>,
-    pub(crate) usdc_amount: f64,
+    pub(crate) usdc_amount: i128,
+    pub(crate) floating_amount: i128,
+    pub(crate) floating_decimals: u32,
     pub(crate) floating_asset_code: String,
     pub(crate) floating_asset_issuer: String,
-    pub(crate) price_numerator: i64,
-    pub(crate) price_denominator: i64,
 }
 
 impl Display for Swap {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let timestamp = self
             .created_at
-            .map(|ts| {
-                let datetime = time::OffsetDateTime::from_unix_timestamp(ts as i64).unwrap();
+            .and_then(|ts| {
+                let datetime = time::OffsetDateTime::from_unix_timestamp(ts as i64).ok()?;
                 let format = time::format_description::parse(
                     "[year]-[month]-[day] [hour]:[minute]:[second]",
                 )
-                .unwrap();
+                .ok()?;
 
-                format!("[{}]", datetime.format(&format).unwrap())
+                Some(format!("[{}]", datetime.format(&format).ok()?))
             })
             .unwrap_or("(date error)".to_string());
 
+        let price = ratio(
+            self.floating_amount,
+            self.floating_decimals,
+            self.usdc_amount,
+            USDC.decimals,
+            RATE_DECIMALS,
+        )
+        .map_or("(price error)".to_string(), |price| {
+            to_decimal_string(price, RATE_DECIMALS)
+        });
+
         write!(
             f,
-            "{timestamp}: {} USDC for {} ({}) at {}",
-            self.usdc_amount / CONVERSION_FACTOR,
+            "{timestamp}: {} USDC for {} ({}) at {price}",
+            to_decimal_string(self.usdc_amount, USDC.decimals),
             self.floating_asset_code,
             self.floating_asset_issuer,
-            self.price_numerator as f64 / self.price_denominator as f64
         )
     }
 }
 
 impl TryFrom<&SwapData> for Swap {
-    type Error = String;
+    type Error = Rejection;
 
     fn try_from(swap_data: &SwapData) -> Result<Self, Self::Error> {
-        if swap_data.asset_sold.is_none() || swap_data.asset_bought.is_none() {
-            return Err("Invalid asset in swap".to_string());
-        }
-
-        let asset_sold = swap_data.asset_sold.unwrap();
-        let asset_bought = swap_data.asset_bought.unwrap();
+        let asset_sold = swap_data
+            .asset_sold
+            .as_ref()
+            .map_err(|rejection| *rejection)?;
+        let asset_bought = swap_data
+            .asset_bought
+            .as_ref()
+            .map_err(|rejection| *rejection)?;
 
-        if asset_sold == USDC {
-            Ok(Swap {
+        let swap = if *asset_sold == USDC {
+            Swap {
                 created_at: None,
-                usdc_amount: swap_data.amount_sold as f64,
+                usdc_amount: swap_data.amount_sold,
+                floating_amount: swap_data.amount_bought,
+                floating_decimals: asset_bought.decimals,
                 floating_asset_code: asset_bought.code.to_string(),
                 floating_asset_issuer: asset_bought.issuer.to_string(),
-                price_numerator: swap_data.amount_bought,
-                price_denominator: swap_data.amount_sold,
-            })
-        } else if asset_bought == USDC {
-            Ok(Swap {
+            }
+        } else if *asset_bought == USDC {
+            Swap {
                 created_at: None,
-                usdc_amount: swap_data.amount_bought as f64,
+                usdc_amount: swap_data.amount_bought,
+                floating_amount: swap_data.amount_sold,
+                floating_decimals: asset_sold.decimals,
                 floating_asset_code: asset_sold.code.to_string(),
                 floating_asset_issuer: asset_sold.issuer.to_string(),
-                price_numerator: swap_data.amount_sold,
-                price_denominator: swap_data.amount_bought,
-            })
+            }
         } else {
-            Err("Swap does not involve USDC".to_string())
+            return Err(Rejection::NotUsdc);
+        };
+
+        if swap.usdc_amount <= 0 || swap.floating_amount <= 0 {
+            Err(Rejection::ZeroAmount)
+        } else if swap.floating_amount.checked_mul(10_000_000) == Some(swap.usdc_amount) {
+            Err(Rejection::DustPrice)
+        } else {
+            Ok(swap)
         }
     }
 }
@@ -145,38 +309,11 @@ impl From<&SwapDbRow> for Swap {
     fn from(row: &SwapDbRow) -> Self {
         Swap {
             created_at: Some(row.creation),
-            usdc_amount: row.usdc_amnt as f64,
+            usdc_amount: row.usdcamnt,
+            floating_amount: row.fltamnt,
+            floating_decimals: row.fltdecs,
             floating_asset_code: row.floatcode.to_string(),
             floating_asset_issuer: row.fltissuer.to_string(),
-            price_numerator: row.numerator,
-            price_denominator: row.denom,
-        }
-    }
-}
-
-#[derive(Clone, DatabaseDerive)]
-#[with_name("soroswaps")]
-pub(crate) struct Soroswap {
-    pub(crate) swap: String,
-}
-
-impl Soroswap {
-    pub(crate) fn save(swap_data: &SwapData) {
-        let asset_bought = swap_data.asset_bought.as_ref().unwrap();
-        let asset_sold = swap_data.asset_sold.as_ref().unwrap();
-
-        if *asset_bought == USDC || *asset_sold == USDC {
-            let soroswap = Self {
-                swap: format!(
-                    "Swap data: {} {} for {} {}",
-                    swap_data.amount_sold,
-                    asset_sold.code,
-                    swap_data.amount_bought,
-                    asset_bought.code
-                ),
-            };
-
-            soroswap.put(&EnvClient::new());
         }
     }
 }
@@ -0,0 +1,290 @@
+use std::{borrow::Cow, collections::HashMap, sync::OnceLock};
+
+use stellar_strkey::{ed25519::PublicKey, Contract};
+use zephyr_sdk::{prelude::*, DatabaseDerive, EnvClient};
+
+use crate::{
+    config::{soroswap_tokens::SOROSWAP_TOKENS, xlm},
+    db::{risk::seed_asset_risks, swap::SwapAsset},
+};
+
+#[derive(Clone, DatabaseDerive)]
+#[with_name("tokens")]
+/**
+ * The token registry: the assets we index and certify. It is seeded from the
+ * compiled list and edited with the admin functions. Tokens are disabled
+ * rather than removed, so their history stays explained.
+ */
+pub(crate) struct TokenDbRow {
+    pub(crate) code: String,
+    pub(crate) issuer: String,
+    pub(crate) contract: String,
+    pub(crate) decimals: u32,
+    pub(crate) enabled: bool,
+}
+
+impl From<&SwapAsset> for TokenDbRow {
+    fn from(asset: &SwapAsset) -> Self {
+        TokenDbRow {
+            code: asset.code.to_string(),
+            issuer: asset.issuer.to_string(),
+            contract: asset.contract.to_string(),
+            decimals: asset.decimals,
+            enabled: true,
+        }
+    }
+}
+
+impl From<TokenDbRow> for SwapAsset {
+    fn from(row: TokenDbRow) -> Self {
+        SwapAsset {
+            code: Cow::Owned(row.code),
+            issuer: Cow::Owned(row.issuer),
+            contract: Cow::Owned(row.contract),
+            decimals: row.decimals,
+        }
+    }
+}
+
+pub(crate) struct TokenRegistry {
+    tokens: Vec<SwapAsset>,
+    // Indices into `tokens`, keyed on raw issuer account IDs and codes, and on
+    // raw contract IDs, so that ingestion never formats a strkey to look up
+    // an asset
+    pub(crate) by_issuer_and_code: HashMap<([u8; 32], String), usize>,
+    by_issuer: HashMap<[u8; 32], Vec<usize>>,
+    by_contract: HashMap<[u8; 32], usize>,
+    // False until the table has been seeded
+    from_table: bool,
+}
+
+impl TokenRegistry {
+    pub(crate) fn new(tokens: Vec<SwapAsset>, from_table: bool) -> Self {
+        let by_issuer_and_code = tokens
+            .iter()
+            .enumerate()
+            .filter_map(|(index, token)| {
+                let issuer = PublicKey::from_string(&token.issuer).ok()?;
+                Some(((issuer.0, token.code.to_string()), index))
+            })
+            .collect::<HashMap<_, _>>();
+        let mut by_issuer = HashMap::<[u8; 32], Vec<usize>>::new();
+        for ((issuer, _), index) in &by_issuer_and_code {
+            by_issuer.entry(*issuer).or_default().push(*index);
+        }
+        let by_contract = tokens
+            .iter()
+            .enumerate()
+            .filter_map(|(index, token)| {
+                let contract = Contract::from_string(&token.contract).ok()?;
+                Some((contract.0, index))
+            })
+            .collect();
+
+        TokenRegistry {
+            tokens,
+            by_issuer_and_code,
+            by_issuer,
+            by_contract,
+            from_table,
+        }
+    }
+
+    pub(crate) fn classic_token(&self, issuer: &[u8; 32], code: &str) -> Option<&SwapAsset> {
+        let index = self.by_issuer_and_code.get(&(*issuer, code.to_string()))?;
+
+        self.tokens.get(*index)
+    }
+}
+
+// Every invocation of the program runs in a fresh instance, so this caches the
+// table for a single ledger close or function call.
+static REGISTRY: OnceLock<TokenRegistry> = OnceLock::new();
+
+fn registry() -> &'static TokenRegistry {
+    REGISTRY.get_or_init(|| {
+        let rows = EnvClient::empty().read::<TokenDbRow>();
+
+        if rows.is_empty() {
+            TokenRegistry::new(SOROSWAP_TOKENS.to_vec(), false)
+        } else {
+            let tokens = rows
+                .into_iter()
+                .filter(|row| row.enabled)
+                .map(SwapAsset::from)
+                .collect();
+            TokenRegistry::new(tokens, true)
+        }
+    })
+}
+
+/**
+ * The enabled tokens of the registry. Until the table is seeded, these are
+ * the ones compiled into the program.
+ */
+pub(crate) fn registered_tokens() -> &'static [SwapAsset] {
+    &registry().tokens
+}
+
+/**
+ * Look up a registered classic asset by the raw account ID of its issuer.
+ */
+pub(crate) fn registered_classic_token(
+    issuer: &[u8; 32],
+    code: &str,
+) -> Option<&'static SwapAsset> {
+    registry().classic_token(issuer, code)
+}
+
+/**
+ * Whether a raw account ID issues any registered classic asset.
+ */
+pub(crate) fn is_registered_issuer(issuer: &[u8; 32]) -> bool {
+    registry().by_issuer.contains_key(issuer)
+}
+
+/**
+ * The registered classic assets of an issuer, by its raw account ID.
+ */
+pub(crate) fn issuer_tokens(issuer: &[u8; 32]) -> Vec<&'static SwapAsset> {
+    let registry = registry();
+
+    registry
+        .by_issuer
+        .get(issuer)
+        .into_iter()
+        .flatten()
+        .filter_map(|index| registry.tokens.get(*index))
+        .collect()
+}
+
+/**
+ * Look up a registered token by its raw contract ID.
+ */
+pub(crate) fn registered_contract_token(contract: &[u8; 32]) -> Option<&'static SwapAsset> {
+    let registry = registry();
+    let index = registry.by_contract.get(contract)?;
+
+    registry.tokens.get(*index)
+}
+
+/**
+ * The registered token an asset impersonates, if any: one whose code looks
+ * the same but which has another issuer. Codes are compared regardless of
+ * case and of the digits that pass for letters, so "usdc" and "U5DC" from an
+ * unknown issuer both impersonate USDC, while "BLND" does not impersonate
+ * BIND. Registered tokens never impersonate
+ * each other, even if several issuers of the same code are registered.
+ */
+pub(crate) fn impersonated_token(code: &str, issuer: &str) -> Option<&'static SwapAsset> {
+    let tokens = registered_tokens();
+
+    if issuer == xlm().issuer
+        || tokens
+            .iter()
+            .any(|token| token.code == code && token.issuer == issuer)
+    {
+        return None;
+    }
+
+    std::iter::once(xlm())
+        .chain(tokens)
+        .find(|token| token.issuer != issuer && codes_look_alike(&token.code, code))
+}
+
+// Whether two codes look the same to a reader skimming a wallet or explorer.
+// A lowercase L passes for both an I and an L, but an uppercase L only for
+// itself.
+fn codes_look_alike(code: &str, other: &str) -> bool {
+    code.chars().count() == other.chars().count()
+        && code.chars().zip(other.chars()).all(|(char, other)| {
+            lookalike(char) == lookalike(other)
+                || (char == 'l' && lookalike(other) == 'I')
+                || (other == 'l' && lookalike(char) == 'I')
+        })
+}
+
+// What a character looks like regardless of case, with the digits that pass
+// for letters read as those letters
+fn lookalike(char: char) -> char {
+    match char.to_ascii_uppercase() {
+        '0' => 'O',
+        '1' => 'I',
+        '5' => 'S',
+        '8' => 'B',
+        upper => upper,
+    }
+}
+
+/**
+ * Fill the registry table with the compiled list if it is empty, so that
+ * edits start from the tokens we already index, and start following the risk
+ * of its assets. Returns whether it did.
+ */
+pub(crate) fn seed_registry(client: &EnvClient) -> bool {
+    if registry().from_table {
+        return false;
+    }
+
+    SOROSWAP_TOKENS
+        .iter()
+        .for_each(|token| TokenDbRow::from(token).put(client));
+    seed_asset_risks(client, &SOROSWAP_TOKENS);
+    client.log().debug(
+        format!(
+            "Seeded the token registry with {} tokens",
+            SOROSWAP_TOKENS.len()
+        ),
+        None,
+    );
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_look_alike_regardless_of_case_and_digits() {
+        let pairs = [
+            ("USDC", "USDC"),
+            ("USDC", "usdc"),
+            ("USDC", "U5DC"),
+            ("AQUA", "AQUA"),
+            ("BOND", "B0ND"),
+            ("BTC", "8TC"),
+            ("XLM", "xlm"),
+            ("VELO", "VEL0"),
+            ("IBTC", "1BTC"),
+            ("IBTC", "lBTC"),
+            ("BLND", "BlND"),
+            ("BIND", "BlND"),
+            ("BIND", "B1ND"),
+        ];
+
+        for (code, other) in pairs {
+            assert!(codes_look_alike(code, other), "{code} {other}");
+            assert!(codes_look_alike(other, code), "{other} {code}");
+        }
+    }
+
+    #[test]
+    fn distinct_codes_do_not_look_alike() {
+        let pairs = [
+            ("BLND", "BIND"),
+            ("BLND", "B1ND"),
+            ("XLM", "XIM"),
+            ("USDC", "USD"),
+            ("USDC", "USDCC"),
+            ("USDC", "EURC"),
+            ("AQUA", "AQVA"),
+            ("SHX", "SHY"),
+        ];
+
+        for (code, other) in pairs {
+            assert!(!codes_look_alike(code, other), "{code} {other}");
+            assert!(!codes_look_alike(other, code), "{other} {code}");
+        }
+    }
+}
@@ -1,13 +1,23 @@
 use zephyr_sdk::soroban_sdk::xdr::{
-    ContractEvent, ContractEventBody, OperationResultTr, ScVal, TransactionResultMeta,
-    TransactionResultResult,
+    AccountEntry, AccountId, AllowTrustOp, AssetCode, ClawbackOp, ContractEvent, ContractEventBody,
+    FeeBumpTransactionInnerTx, LedgerEntryChange, LedgerEntryData, MuxedAccount,
+    MuxedAccountMed25519, Operation, OperationBody, OperationResultTr, PublicKey, ScVal,
+    SetTrustLineFlagsOp, TransactionEnvelope, TransactionMeta, TransactionResultMeta,
+    TransactionResultResult, TrustLineFlags, Uint256,
 };
 
 use crate::config::SOROSWAP_ROUTER;
-use crate::db::swap::{Soroswap, Swap, SwapData};
+use crate::db::{
+    discovery::UnlistedSwap,
+    journal::{JournalSource, SwapRecord, Venue},
+    risk::RiskEvent,
+    swap::{Rejection, Swap, SwapData, SwapPosition},
+    token::is_registered_issuer,
+};
 use crate::utils::{
-    extract_claim_atom_data, extract_transaction_results, get_address_from_scval,
-    get_claims_from_operation, get_swap_asset, hash_to_strkey, scmap_get,
+    claim_atom_record, extract_transaction_results, extract_unlisted_swap,
+    get_claims_from_operation, get_contract_from_scval, get_swap_asset, hash_to_strkey,
+    registered_asset_code, registered_classic_asset, scmap_get, token_name,
 };
 
 /**
@@ -21,11 +31,132 @@ use crate::utils::{
  * An operation can have no swaps if it is a create account, create contract, etc.
  * If its result is an Offer or PathPayment type, it can have multiple swaps.
  */
-pub(crate) fn swaps(transaction_results: Vec<TransactionResultMeta>) -> Vec<Swap> {
-    transaction_results
+pub(crate) fn swaps(
+    transactions: &[(&TransactionEnvelope, TransactionResultMeta)],
+) -> Vec<SwapRecord> {
+    transactions
         .iter()
-        .filter(is_transaction_successful)
-        .flat_map(swaps_from_transaction)
+        .map(|(_, meta)| meta)
+        .enumerate()
+        .filter(|(_, meta)| is_transaction_successful(meta))
+        .flat_map(|(transaction, meta)| swaps_from_transaction(transaction as u64, meta))
+        .collect()
+}
+
+/**
+ * We also follow what issuers of registered assets do to their holders:
+ * changes to their account flags, which tell whether they can freeze or claw
+ * back holdings, and the clawbacks and freezes themselves. Flags are read from
+ * the account entries a transaction changed, so that we catch them however
+ * they were set. Freezes are trustlines losing their authorization, through
+ * either SetTrustLineFlags or the older AllowTrust.
+ */
+pub(crate) fn risk_events(
+    transactions: &[(&TransactionEnvelope, TransactionResultMeta)],
+) -> Vec<RiskEvent> {
+    transactions
+        .iter()
+        .filter(|(_, meta)| is_transaction_successful(&meta))
+        .flat_map(|(envelope, meta)| {
+            let (source, operations) = envelope_operations(envelope);
+
+            operations
+                .iter()
+                .filter_map(|operation| operation_risk_event(source, operation))
+                .chain(issuer_flag_changes(meta))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn envelope_operations(envelope: &TransactionEnvelope) -> ([u8; 32], &[Operation]) {
+    match envelope {
+        TransactionEnvelope::TxV0(envelope) => (
+            envelope.tx.source_account_ed25519.0,
+            &envelope.tx.operations,
+        ),
+        TransactionEnvelope::Tx(envelope) => (
+            muxed_account_id(&envelope.tx.source_account),
+            &envelope.tx.operations,
+        ),
+        TransactionEnvelope::TxFeeBump(envelope) => match &envelope.tx.inner_tx {
+            FeeBumpTransactionInnerTx::Tx(inner) => (
+                muxed_account_id(&inner.tx.source_account),
+                &inner.tx.operations,
+            ),
+        },
+    }
+}
+
+fn muxed_account_id(account: &MuxedAccount) -> [u8; 32] {
+    match account {
+        MuxedAccount::Ed25519(Uint256(id)) => *id,
+        MuxedAccount::MuxedEd25519(MuxedAccountMed25519 {
+            ed25519: Uint256(id),
+            ..
+        }) => *id,
+    }
+}
+
+fn operation_risk_event(source: [u8; 32], operation: &Operation) -> Option<RiskEvent> {
+    let authorized = TrustLineFlags::AuthorizedFlag as u32;
+
+    match &operation.body {
+        OperationBody::Clawback(ClawbackOp { asset, .. }) => {
+            registered_classic_asset(asset).map(RiskEvent::Clawback)
+        }
+        OperationBody::SetTrustLineFlags(SetTrustLineFlagsOp {
+            asset, clear_flags, ..
+        }) if clear_flags & authorized != 0 => {
+            registered_classic_asset(asset).map(RiskEvent::Freeze)
+        }
+        // AllowTrust names the asset by its code alone: the issuer is whoever
+        // runs the operation
+        OperationBody::AllowTrust(AllowTrustOp {
+            asset, authorize, ..
+        }) if authorize & authorized == 0 => {
+            let issuer = operation
+                .source_account
+                .as_ref()
+                .map_or(source, muxed_account_id);
+            let code = match asset {
+                AssetCode::CreditAlphanum4(code) => &code.0[..],
+                AssetCode::CreditAlphanum12(code) => &code.0[..],
+            };
+
+            registered_asset_code(code, &issuer).map(RiskEvent::Freeze)
+        }
+        _ => None,
+    }
+}
+
+fn issuer_flag_changes(meta: &TransactionResultMeta) -> Vec<RiskEvent> {
+    let operations = match &meta.tx_apply_processing {
+        TransactionMeta::V0(operations) => operations,
+        TransactionMeta::V1(meta) => &meta.operations,
+        TransactionMeta::V2(meta) => &meta.operations,
+        TransactionMeta::V3(meta) => &meta.operations,
+    };
+
+    operations
+        .iter()
+        .flat_map(|operation| operation.changes.iter())
+        .filter_map(|change| match change {
+            LedgerEntryChange::Created(entry) | LedgerEntryChange::Updated(entry) => {
+                match &entry.data {
+                    LedgerEntryData::Account(AccountEntry {
+                        account_id: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(id))),
+                        flags,
+                        ..
+                    }) if is_registered_issuer(id) => Some(RiskEvent::Flags {
+                        issuer: *id,
+                        flags: *flags,
+                    }),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
         .collect()
 }
 
@@ -36,85 +167,173 @@ fn is_transaction_successful(transaction: &&TransactionResultMeta) -> bool {
     )
 }
 
-fn swaps_from_transaction(transaction: &TransactionResultMeta) -> Vec<Swap> {
-    let operations = extract_transaction_results(transaction);
-    operations.iter().flat_map(swaps_from_operation).collect()
+fn swaps_from_transaction(transaction: u64, meta: &TransactionResultMeta) -> Vec<SwapRecord> {
+    let operations = extract_transaction_results(meta);
+    operations
+        .iter()
+        .enumerate()
+        .flat_map(|(operation, result)| swaps_from_operation(transaction, operation as u64, result))
+        .collect()
 }
 
-fn swaps_from_operation(operation: &OperationResultTr) -> Vec<Swap> {
-    let claims = get_claims_from_operation(operation);
+fn swaps_from_operation(
+    transaction: u64,
+    operation: u64,
+    result: &OperationResultTr,
+) -> Vec<SwapRecord> {
+    let claims = get_claims_from_operation(result);
 
     claims
         .iter()
-        .filter_map(|claim| Swap::try_from(&extract_claim_atom_data(claim)).ok())
+        .enumerate()
+        .filter_map(|(claim, claim_atom)| {
+            claim_atom_record(claim_atom).map(|record| {
+                record.at(SwapPosition {
+                    transaction,
+                    operation,
+                    claim: claim as u64,
+                })
+            })
+        })
+        .collect()
+}
+
+/**
+ * We also look for classic swaps of USDC for assets that are not in the
+ * registry, which we do not index but keep track of in the discovery table.
+ * Unknown Soroswap tokens are not tracked, as we cannot tell their issuer.
+ */
+pub(crate) fn unlisted_swaps(
+    transactions: &[(&TransactionEnvelope, TransactionResultMeta)],
+) -> Vec<UnlistedSwap> {
+    transactions
+        .iter()
+        .map(|(_, meta)| meta)
+        .filter(is_transaction_successful)
+        .flat_map(extract_transaction_results)
+        .flat_map(|operation| get_claims_from_operation(&operation))
+        .filter_map(|claim| extract_unlisted_swap(&claim))
         .collect()
 }
 
 /**
  * We 'fish' every Soroswap swap from each ledger close. This function focuses
- * only on Soroswap swaps; classic swaps are handled separately.
+ * only on Soroswap swaps; classic swaps are handled separately. Each hop of a
+ * swap's path is a swap of its own. Events are read from the transaction
+ * metas, so that each swap is placed in its transaction.
  */
-pub(crate) fn soroswap_swaps(soroban_events: Vec<ContractEvent>) -> Vec<Swap> {
-    soroban_events
-        .into_iter()
-        .filter_map(soroswap_event)
-        .flat_map(swaps_from_event)
+pub(crate) fn soroswap_swaps(
+    transactions: &[(&TransactionEnvelope, TransactionResultMeta)],
+) -> Vec<SwapRecord> {
+    transactions
+        .iter()
+        .enumerate()
+        .flat_map(|(transaction, (_, meta))| {
+            soroban_events(meta)
+                .iter()
+                .enumerate()
+                .filter_map(|(event, contract_event)| {
+                    Some((event, soroswap_event(contract_event)?))
+                })
+                .flat_map(move |(event, data)| {
+                    swaps_from_event(data)
+                        .into_iter()
+                        .enumerate()
+                        .map(move |(hop, record)| {
+                            record.at(SwapPosition {
+                                transaction: transaction as u64,
+                                operation: event as u64,
+                                claim: hop as u64,
+                            })
+                        })
+                })
+        })
         .collect()
 }
 
-fn soroswap_event(event: ContractEvent) -> Option<ScVal> {
+fn soroban_events(meta: &TransactionResultMeta) -> &[ContractEvent] {
+    match &meta.tx_apply_processing {
+        TransactionMeta::V3(meta) => meta
+            .soroban_meta
+            .as_ref()
+            .map_or(&[], |soroban_meta| soroban_meta.events.as_slice()),
+        _ => &[],
+    }
+}
+
+fn soroswap_event(event: &ContractEvent) -> Option<ScVal> {
     let event_contract = event.contract_id.as_ref().map(hash_to_strkey)?;
-    let ContractEventBody::V0(body) = event.body;
+    let ContractEventBody::V0(body) = &event.body;
 
     let is_swap = body
         .topics
         .iter()
         .any(|topic| matches!(topic, ScVal::Symbol(s) if s.to_string() == "swap"));
 
-    (event_contract == SOROSWAP_ROUTER && is_swap).then_some(body.data)
+    (event_contract == SOROSWAP_ROUTER && is_swap).then(|| body.data.clone())
 }
 
-fn swaps_from_event(event: ScVal) -> Vec<Swap> {
-    let ScVal::Map(Some(map)) = event else {
-        return vec![];
+// A malformed event is recorded and skipped, so that it never stops the other
+// swaps of the ledger from being saved
+fn swaps_from_event(event: ScVal) -> Vec<SwapRecord> {
+    let malformed = || {
+        vec![SwapRecord::malformed(
+            JournalSource::SoroswapEvent,
+            Venue::Soroswap,
+        )]
     };
 
-    let path = scmap_get(&map, "path".to_string()).map_or(vec![], |sc_vec| sc_vec.0.to_vec());
-    let amounts = scmap_get(&map, "amounts".to_string()).map_or(vec![], |sc_vec| sc_vec.0.to_vec());
+    let ScVal::Map(Some(map)) = event else {
+        return malformed();
+    };
+    let (Some(path), Some(amounts)) = (
+        scmap_get(&map, "path".to_string()),
+        scmap_get(&map, "amounts".to_string()),
+    ) else {
+        return malformed();
+    };
 
-    swaps_from_path_and_amounts(path, amounts)
-}
+    if path.len() != amounts.len() {
+        return malformed();
+    }
 
-fn swaps_from_path_and_amounts(assets: Vec<ScVal>, amounts: Vec<ScVal>) -> Vec<Swap> {
-    assets
-        .windows(2)
+    path.windows(2)
         .zip(amounts.windows(2))
-        .filter_map(swap_from_amounts_and_assets)
+        .map(|hop| {
+            swap_from_amounts_and_assets(hop).unwrap_or_else(|| {
+                SwapRecord::malformed(JournalSource::SoroswapEvent, Venue::Soroswap)
+            })
+        })
         .collect()
 }
 
-fn swap_from_amounts_and_assets((assets, amounts): (&[ScVal], &[ScVal])) -> Option<Swap> {
-    let (ScVal::I128(n1), ScVal::I128(n2)) = (&amounts[0], &amounts[1]) else {
+fn swap_from_amounts_and_assets((assets, amounts): (&[ScVal], &[ScVal])) -> Option<SwapRecord> {
+    let ([asset_sold, asset_bought], [ScVal::I128(n1), ScVal::I128(n2)]) = (assets, amounts) else {
         return None;
     };
 
-    // Classic network amounts are 64 bits, while Soroswap uses 128. This will
-    // only fail if the most significant 64 bits are not all 0. We accept that
-    // chance of failure because most swaps are assumed to involve smaller amounts.
-    let amount_sold: i64 = (((n1.hi as i128) << 64) + n1.lo as i128).try_into().ok()?;
-    let amount_bought: i64 = (((n2.hi as i128) << 64) + n2.lo as i128).try_into().ok()?;
-    let asset_sold = get_swap_asset(get_address_from_scval(&assets[0])?)?;
-    let asset_bought = get_swap_asset(get_address_from_scval(&assets[1])?)?;
+    // Classic network amounts are 64 bits, while Soroswap uses 128. We store
+    // all amounts as 128 bits, so nothing is lost here.
+    let amount_sold = ((n1.hi as i128) << 64) | n1.lo as i128;
+    let amount_bought = ((n2.hi as i128) << 64) | n2.lo as i128;
+    let contract_sold = get_contract_from_scval(asset_sold)?;
+    let contract_bought = get_contract_from_scval(asset_bought)?;
 
     let swap_data = SwapData {
         amount_bought,
         amount_sold,
-        asset_bought: Some(*asset_bought),
-        asset_sold: Some(*asset_sold),
+        asset_bought: get_swap_asset(contract_bought).ok_or(Rejection::UnknownToken),
+        asset_sold: get_swap_asset(contract_sold).ok_or(Rejection::UnknownToken),
     };
 
-    // Only save the swap if it involves USDC
-    Soroswap::save(&swap_data);
-
-    Swap::try_from(&swap_data).ok()
+    Some(SwapRecord {
+        source: JournalSource::SoroswapEvent,
+        venue: Venue::Soroswap,
+        position: SwapPosition::default(),
+        sold: token_name(contract_sold, &swap_data.asset_sold),
+        amount_sold,
+        bought: token_name(contract_bought, &swap_data.asset_bought),
+        amount_bought,
+        outcome: Swap::try_from(&swap_data),
+    })
 }
@@ -0,0 +1,236 @@
+//! Exact arithmetic on amounts and rates.
+//!
+//! Amounts are integers in the smallest unit of their asset, e.g. stroops for
+//! classic assets, with as many decimals as the asset declares. Rates are
+//! integers scaled by a power of ten. Both are i128, which holds any
+//! Soroban amount, and are only turned into decimal strings for display.
+
+/**
+ * Divide two non-negative integers and scale the quotient by 10^decimals,
+ * rounding down. We do long division so that the intermediate values never
+ * exceed the denominator times ten, and only fail if the result overflows.
+ */
+pub(crate) fn divide(numerator: i128, denominator: i128, decimals: u32) -> Option<i128> {
+    if numerator < 0 || denominator <= 0 {
+        return None;
+    }
+
+    let integer_part = (numerator / denominator).checked_mul(10_i128.checked_pow(decimals)?)?;
+    let mut remainder = numerator % denominator;
+    let mut fractional_part: i128 = 0;
+
+    for _ in 0..decimals {
+        remainder = remainder.checked_mul(10)?;
+        fractional_part = fractional_part * 10 + remainder / denominator;
+        remainder %= denominator;
+    }
+
+    integer_part.checked_add(fractional_part)
+}
+
+/**
+ * Divide two amounts that have their own number of decimals, e.g. units of an
+ * 18-decimal token by USDC stroops, and scale the quotient by 10^decimals.
+ */
+pub(crate) fn ratio(
+    numerator: i128,
+    numerator_decimals: u32,
+    denominator: i128,
+    denominator_decimals: u32,
+    decimals: u32,
+) -> Option<i128> {
+    if numerator_decimals >= denominator_decimals {
+        let denominator = rescale(denominator, denominator_decimals, numerator_decimals)?;
+        divide(numerator, denominator, decimals)
+    } else {
+        let numerator = rescale(numerator, numerator_decimals, denominator_decimals)?;
+        divide(numerator, denominator, decimals)
+    }
+}
+
+/**
+ * Multiply two non-negative integers and divide the product by 10^decimals,
+ * rounding down, e.g. to apply a scaled ratio to a scaled rate. The integer
+ * and fractional parts of the first factor are multiplied separately, so that
+ * the product only needs to fit once it is scaled down.
+ */
+pub(crate) fn multiply(value: i128, factor: i128, decimals: u32) -> Option<i128> {
+    if value < 0 || factor < 0 {
+        return None;
+    }
+
+    let scale = 10_i128.checked_pow(decimals)?;
+    let integer_part = (value / scale).checked_mul(factor)?;
+    let fractional_part = (value % scale).checked_mul(factor)? / scale;
+
+    integer_part.checked_add(fractional_part)
+}
+
+/**
+ * Express an amount with more decimals, e.g. 1.5 as 150 instead of 15.
+ */
+pub(crate) fn rescale(value: i128, from_decimals: u32, to_decimals: u32) -> Option<i128> {
+    value.checked_mul(10_i128.checked_pow(to_decimals.checked_sub(from_decimals)?)?)
+}
+
+/**
+ * Render a scaled integer as an exact decimal string, without trailing zeros.
+ */
+pub(crate) fn to_decimal_string(value: i128, decimals: u32) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    let decimals = decimals as usize;
+
+    let padded = format!("{digits:0>width$}", width = decimals + 1);
+    let (integer_part, fractional_part) = padded.split_at(padded.len() - decimals);
+    let fractional_part = fractional_part.trim_end_matches('0');
+
+    if fractional_part.is_empty() {
+        format!("{sign}{integer_part}")
+    } else {
+        format!("{sign}{integer_part}.{fractional_part}")
+    }
+}
+
+/**
+ * Parse a non-negative decimal string into a scaled integer. Digits beyond
+ * the scale are truncated. Anything else, like "NaN" or "inf", is rejected.
+ */
+pub(crate) fn from_decimal_string(value: &str, decimals: u32) -> Option<i128> {
+    let (integer_part, fractional_part) = value.split_once('.').unwrap_or((value, ""));
+
+    let is_valid = |part: &str| part.chars().all(|char| char.is_ascii_digit());
+    if integer_part.is_empty() || !is_valid(integer_part) || !is_valid(fractional_part) {
+        return None;
+    }
+
+    let fractional_digits = fractional_part
+        .chars()
+        .chain(std::iter::repeat('0'))
+        .take(decimals as usize)
+        .collect::<String>();
+
+    format!("{integer_part}{fractional_digits}").parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divide_rounds_down() {
+        assert_eq!(divide(1, 3, 4), Some(3333));
+        assert_eq!(divide(2, 3, 4), Some(6666));
+        assert_eq!(divide(10, 4, 0), Some(2));
+        assert_eq!(divide(0, 7, 18), Some(0));
+    }
+
+    #[test]
+    fn divide_rejects_negative_and_zero_operands() {
+        assert_eq!(divide(-1, 3, 4), None);
+        assert_eq!(divide(1, 0, 4), None);
+        assert_eq!(divide(1, -3, 4), None);
+    }
+
+    #[test]
+    fn divide_only_fails_when_the_quotient_overflows() {
+        // The intermediate remainders never exceed ten times the denominator
+        assert_eq!(
+            divide(i128::MAX / 10, i128::MAX / 10, 18),
+            Some(10_i128.pow(18))
+        );
+        assert_eq!(divide(i128::MAX, 1, 1), None);
+        assert_eq!(divide(1, 1, 39), None);
+    }
+
+    #[test]
+    fn ratio_aligns_the_decimals_of_both_amounts() {
+        // 1 unit of an 18-decimal token for 2 USDC
+        assert_eq!(ratio(10_i128.pow(18), 18, 20_000_000, 7, 2), Some(50));
+        // 3 units of a 2-decimal token for 1.5 USDC
+        assert_eq!(ratio(300, 2, 15_000_000, 7, 3), Some(2000));
+        assert_eq!(ratio(1, 0, 0, 7, 18), None);
+        assert_eq!(ratio(i128::MAX, 0, 1, 7, 0), None);
+    }
+
+    #[test]
+    fn multiply_rounds_down() {
+        // 1.5 * 2.5 with one decimal
+        assert_eq!(multiply(15, 25, 1), Some(37));
+        assert_eq!(multiply(10_i128.pow(18), 7, 18), Some(7));
+        assert_eq!(multiply(0, i128::MAX, 18), Some(0));
+    }
+
+    #[test]
+    fn multiply_only_needs_the_scaled_product_to_fit() {
+        let value = i128::MAX / 10;
+        assert_eq!(multiply(value, 10, 1), Some(value));
+        assert_eq!(multiply(i128::MAX, 2, 0), None);
+        assert_eq!(multiply(-1, 2, 0), None);
+        assert_eq!(multiply(1, -2, 0), None);
+        assert_eq!(multiply(1, 1, 39), None);
+    }
+
+    #[test]
+    fn rescale_only_adds_decimals() {
+        assert_eq!(rescale(15, 1, 3), Some(1500));
+        assert_eq!(rescale(15, 1, 1), Some(15));
+        assert_eq!(rescale(15, 3, 1), None);
+        assert_eq!(rescale(i128::MAX, 0, 1), None);
+    }
+
+    #[test]
+    fn to_decimal_string_trims_trailing_zeros() {
+        assert_eq!(to_decimal_string(12_500_000, 7), "1.25");
+        assert_eq!(to_decimal_string(10_000_000, 7), "1");
+        assert_eq!(to_decimal_string(5, 7), "0.0000005");
+        assert_eq!(to_decimal_string(-5, 2), "-0.05");
+        assert_eq!(to_decimal_string(0, 18), "0");
+        assert_eq!(to_decimal_string(42, 0), "42");
+        assert_eq!(
+            to_decimal_string(i128::MIN, 0),
+            "-170141183460469231731687303715884105728"
+        );
+    }
+
+    #[test]
+    fn from_decimal_string_truncates_extra_digits() {
+        assert_eq!(from_decimal_string("1.25", 7), Some(12_500_000));
+        assert_eq!(from_decimal_string("1", 7), Some(10_000_000));
+        assert_eq!(from_decimal_string("0.123456789", 7), Some(1_234_567));
+        assert_eq!(from_decimal_string("7.", 2), Some(700));
+        assert_eq!(from_decimal_string("007", 0), Some(7));
+    }
+
+    #[test]
+    fn from_decimal_string_rejects_malformed_input() {
+        for input in [
+            "", ".5", "-1", "+1", "1e5", "NaN", "inf", "1.2.3", " 1", "1,5",
+        ] {
+            assert_eq!(from_decimal_string(input, 7), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn from_decimal_string_rejects_overflow() {
+        assert_eq!(
+            from_decimal_string("170141183460469231731687303715884105727", 0),
+            Some(i128::MAX)
+        );
+        assert_eq!(
+            from_decimal_string("170141183460469231731687303715884105728", 0),
+            None
+        );
+        assert_eq!(from_decimal_string("1", 39), None);
+    }
+
+    #[test]
+    fn decimal_strings_round_trip() {
+        for value in [0, 1, 12_345_678, i128::MAX] {
+            assert_eq!(
+                from_decimal_string(&to_decimal_string(value, 7), 7),
+                Some(value)
+            );
+        }
+    }
+}
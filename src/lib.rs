@@ -2,44 +2,65 @@
 //!
 //! Harnesses the Mercury indexer to identify asset swaps involving the USDC
 //! stablecoin. Each ledger close is scanned for Soroban DEX events and classic
-//! path payment transactions to identify swaps. Every hour, swaps are
-//! distilled into a single exchange rate per asset. Mercury serverless
+//! path payment transactions to identify swaps. At the end of each rate window
+//! (five minutes, one hour and one day), swaps are distilled into a single
+//! exchange rate per asset. Mercury serverless
 //! functions allow querying the DB for single exchange rates or batches.
 
 #![warn(missing_docs)]
 
 mod api;
+#[cfg(test)]
+mod bench;
 mod config;
 mod db;
 mod filter;
+mod fixed_point;
+mod trust;
 mod utils;
 
-use db::swap::Swap;
 use zephyr_sdk::EnvClient;
 
 /// Processes events from the latest ledger close to track and calculate asset exchange rates.
 ///
 /// This function:
-/// 1. Retrieves transaction and Soroban event data from the latest ledger
-/// 2. Extracts swap events from both classic and Soroswap transactions
-/// 3. Saves the swap data to the database
-/// 4. Periodically calculates and saves exchange rates based on accumulated swap data
+/// 1. Records any ledgers skipped since the previous call, and seeds the token
+///    registry on the first one
+/// 2. Retrieves the transactions of the latest ledger, with their Soroban events
+/// 3. Extracts swap events from both classic and Soroswap transactions
+/// 4. Saves the swap data to the database, journals the swaps it saved or
+///    rejected, counts them in the ingestion metrics, and keeps track of the
+///    unindexed assets that were traded against USDC
+/// 5. Follows the flags, clawbacks and freezes of the issuers of registered assets
+/// 6. Periodically calculates and saves exchange rates based on accumulated swap data,
+///    catching up on any windows missed during an ingestion gap
+///
+/// The program aborts on panic, so this path returns errors instead of
+/// unwrapping: malformed events are journaled and failed reads are logged, and
+/// both are skipped so that they never stop the rest of the ledger from being
+/// saved.
 ///
 /// Called automatically by the Mercury indexer on each ledger close.
 #[no_mangle]
 pub extern "C" fn on_close() {
     let client = EnvClient::new();
-    let results = client.reader().tx_processing();
-    let soroban_events = client.reader().soroban_events();
-    let swaps = filter::swaps(results);
-    let soroswap_swaps = filter::soroswap_swaps(soroban_events);
+    db::track_ingestion(&client);
+    db::token::seed_registry(&client);
 
-    let all_swaps = &swaps
-        .clone()
+    // Transaction metas are decoded once, along with their envelopes, and
+    // Soroban events are read from them
+    let reader = client.reader();
+    let transactions = reader.envelopes_with_meta();
+    let unlisted_swaps = filter::unlisted_swaps(&transactions);
+    let records = filter::swaps(&transactions)
         .into_iter()
-        .chain(soroswap_swaps.clone())
-        .collect::<Vec<Swap>>();
+        .chain(filter::soroswap_swaps(&transactions))
+        .collect::<Vec<_>>();
 
-    db::save_swaps(&client, all_swaps);
+    db::save_swaps(&client, &records);
+    db::journal::save_journal(&client, &records);
+    db::metrics::save_metrics(&client, &records);
+    db::save_discoveries(&client, &unlisted_swaps);
+    db::risk::save_risk_events(&client, &filter::risk_events(&transactions));
     db::save_rates(&client);
 }
@@ -0,0 +1,109 @@
+//! How much an asset can be trusted, from what we know of it and its issuer.
+//!
+//! Tiers are ordered from least to most trusted, so that the API can filter on
+//! a minimum tier. They are computed whenever a response is built, so changes
+//! to the token registry, the scam list and the discovery table apply right
+//! away.
+
+use crate::db::{
+    discovery::discovered_asset,
+    scam::scam_entry,
+    token::{impersonated_token, registered_tokens},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum TrustTier {
+    // The issuer is flagged as a scam
+    Scam,
+    // The asset impersonates a registered token
+    Suspicious,
+    // Nothing is known about the asset or its issuer
+    Unverified,
+    // The asset is not registered, but has long been traded against USDC
+    Established,
+    // The asset is not registered, but its issuer has a registered token
+    VerifiedIssuer,
+    // The asset is in the token registry
+    Certified,
+    // XLM
+    Native,
+}
+
+const TIER_NAMES: [(TrustTier, &str); 7] = [
+    (TrustTier::Scam, "scam"),
+    (TrustTier::Suspicious, "suspicious"),
+    (TrustTier::Unverified, "unverified"),
+    (TrustTier::Established, "established"),
+    (TrustTier::VerifiedIssuer, "verified_issuer"),
+    (TrustTier::Certified, "certified"),
+    (TrustTier::Native, "native"),
+];
+
+impl TrustTier {
+    /**
+     * The tier of an asset, as stored in the rates table. A scam flag trumps
+     * everything else, since the registry may lag behind the scam list. The
+     * discovery table is only read for assets that would be unverified.
+     */
+    pub(crate) fn of(code: &str, issuer: &str) -> Self {
+        let tokens = registered_tokens();
+
+        if issuer == "Native" {
+            TrustTier::Native
+        } else if scam_entry(issuer).is_some_and(|entry| entry.flagged) {
+            TrustTier::Scam
+        } else if tokens
+            .iter()
+            .any(|token| token.code == code && token.issuer == issuer)
+        {
+            TrustTier::Certified
+        } else if impersonated_token(code, issuer).is_some() {
+            TrustTier::Suspicious
+        } else if tokens.iter().any(|token| token.issuer == issuer) {
+            TrustTier::VerifiedIssuer
+        } else if discovered_asset(code, issuer).is_some_and(|asset| asset.is_established()) {
+            TrustTier::Established
+        } else {
+            TrustTier::Unverified
+        }
+    }
+
+    /**
+     * Soroswap-certified assets and native XLM, which responses still single
+     * out as `soroswap_certified_asset`.
+     */
+    pub(crate) fn is_certified(self) -> bool {
+        self >= TrustTier::Certified
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        TIER_NAMES
+            .iter()
+            .find(|(tier, _)| *tier == self)
+            .map_or("unknown", |(_, name)| name)
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        TIER_NAMES
+            .iter()
+            .find(|(_, tier_name)| *tier_name == name)
+            .map(|(tier, _)| *tier)
+    }
+}
+
+/**
+ * Parse the minimum tier of a request. Everything is accepted by default.
+ */
+pub(crate) fn parse_min_tier(name: &Option<String>) -> Result<TrustTier, String> {
+    match name {
+        Some(name) => TrustTier::parse(name).ok_or_else(|| {
+            let names = TIER_NAMES
+                .iter()
+                .map(|(_, name)| *name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("Invalid trust tier '{name}'. Please use one of: {names}.")
+        }),
+        None => Ok(TrustTier::Scam),
+    }
+}
@@ -1,17 +1,35 @@
+use std::{borrow::Cow, collections::BTreeMap, sync::Mutex};
+
+use sha2::{Digest, Sha256};
 use stellar_strkey::{Contract, Strkey};
 
 use time::{format_description::well_known::Iso8601, OffsetDateTime};
-use zephyr_sdk::soroban_sdk::xdr::{
-    ClaimAtom, ClaimLiquidityAtom, ClaimOfferAtom, ClaimOfferAtomV0, Hash, ManageBuyOfferResult,
-    ManageSellOfferResult, OperationResult, OperationResultTr, PathPaymentStrictReceiveResult,
-    PathPaymentStrictReceiveResultSuccess, PathPaymentStrictSendResult,
-    PathPaymentStrictSendResultSuccess, ScAddress, ScMap, ScVal, ScVec, TransactionResultMeta,
-    TransactionResultResult,
+use zephyr_sdk::{
+    soroban_sdk::xdr::{
+        AccountId, AlphaNum12, AlphaNum4, Asset, AssetCode12, AssetCode4, ClaimAtom,
+        ClaimLiquidityAtom, ClaimOfferAtom, ClaimOfferAtomV0, ContractExecutable,
+        ContractIdPreimage, Hash, HashIdPreimage, HashIdPreimageContractId, Limits,
+        ManageBuyOfferResult, ManageSellOfferResult, OperationResult, OperationResultTr,
+        PathPaymentStrictReceiveResult, PathPaymentStrictReceiveResultSuccess,
+        PathPaymentStrictSendResult, PathPaymentStrictSendResultSuccess, PublicKey, ScAddress,
+        ScContractInstance, ScMap, ScMapEntry, ScVal, ScVec, TransactionResultMeta,
+        TransactionResultResult, Uint256, WriteXdr,
+    },
+    EnvClient,
 };
 
 use crate::{
-    config::{scam_addresses::SCAM_ADDRESSES, soroswap_tokens::SOROSWAP_TOKENS, XLM_ADDRESS},
-    db::swap::{SwapAsset, SwapData},
+    config::{
+        xlm, AssetCoverage, ASSET_COVERAGE, CLASSIC_DECIMALS, NETWORK_PASSPHRASE, RATE_INTERVALS,
+        USDC,
+    },
+    db::{
+        discovery::UnlistedSwap,
+        journal::{JournalSource, SwapRecord, Venue},
+        scam::is_scam_account,
+        swap::{Rejection, Swap, SwapAsset, SwapData, SwapPosition},
+        token::{registered_classic_token, registered_contract_token},
+    },
 };
 
 /**
@@ -60,6 +78,115 @@ pub(crate) fn get_claims_from_operation(path_payment_result: &OperationResultTr)
  * We extract only the data we need from the various types of ClaimAtoms.
  */
 pub(crate) fn extract_claim_atom_data(claim_atom: &ClaimAtom) -> SwapData {
+    let (asset_sold, amount_sold, asset_bought, amount_bought) = claim_atom_parts(claim_atom);
+
+    SwapData {
+        asset_sold: SwapAsset::try_from(asset_sold),
+        amount_sold: amount_sold as i128,
+        asset_bought: SwapAsset::try_from(asset_bought),
+        amount_bought: amount_bought as i128,
+    }
+}
+
+/**
+ * Journal a ClaimAtom, if it involves USDC. Other trades are never candidates
+ * for our rates, and there are far too many of them to journal.
+ */
+pub(crate) fn claim_atom_record(claim_atom: &ClaimAtom) -> Option<SwapRecord> {
+    let (asset_sold, amount_sold, asset_bought, amount_bought) = claim_atom_parts(claim_atom);
+
+    if !is_usdc(asset_sold) && !is_usdc(asset_bought) {
+        return None;
+    }
+
+    Some(SwapRecord {
+        source: JournalSource::ClaimAtom,
+        venue: match claim_atom {
+            ClaimAtom::LiquidityPool(_) => Venue::LiquidityPool,
+            ClaimAtom::V0(_) | ClaimAtom::OrderBook(_) => Venue::OrderBook,
+        },
+        position: SwapPosition::default(),
+        sold: asset_name(asset_sold),
+        amount_sold: amount_sold as i128,
+        bought: asset_name(asset_bought),
+        amount_bought: amount_bought as i128,
+        outcome: Swap::try_from(&extract_claim_atom_data(claim_atom)),
+    })
+}
+
+fn is_usdc(asset: &Asset) -> bool {
+    SwapAsset::try_from(asset).is_ok_and(|asset| asset == USDC)
+}
+
+/**
+ * The code and issuer of a classic asset, whether we index it or not. Invalid
+ * codes are kept as they are, so that the journal shows them.
+ */
+fn asset_name(asset: &Asset) -> (String, String) {
+    let (asset_code, issuer) = match asset {
+        Asset::Native => return (xlm().code.to_string(), xlm().issuer.to_string()),
+        Asset::CreditAlphanum4(AlphaNum4 { asset_code, issuer }) => (asset_code.as_slice(), issuer),
+        Asset::CreditAlphanum12(AlphaNum12 { asset_code, issuer }) => {
+            (asset_code.as_slice(), issuer)
+        }
+    };
+
+    let code = decode_asset_code(asset_code).unwrap_or_else(|| {
+        String::from_utf8_lossy(asset_code)
+            .trim_end_matches('\0')
+            .to_string()
+    });
+    (code, issuer.to_string())
+}
+
+/**
+ * The code and issuer of a Soroban token. Those we do not know are named by
+ * their contract address alone.
+ */
+pub(crate) fn token_name(
+    contract: &Hash,
+    asset: &Result<SwapAsset, Rejection>,
+) -> (String, String) {
+    match asset {
+        Ok(asset) => (asset.code.to_string(), asset.issuer.to_string()),
+        Err(_) => (String::new(), hash_to_strkey(contract)),
+    }
+}
+
+/**
+ * If a ClaimAtom swaps USDC for a classic asset that is not in the registry,
+ * we extract what we need to keep track of that asset.
+ */
+pub(crate) fn extract_unlisted_swap(claim_atom: &ClaimAtom) -> Option<UnlistedSwap> {
+    let (asset_sold, amount_sold, asset_bought, amount_bought) = claim_atom_parts(claim_atom);
+    let (asset, usdc_amount) = if is_usdc(asset_sold) {
+        (asset_bought, amount_sold)
+    } else if is_usdc(asset_bought) {
+        (asset_sold, amount_bought)
+    } else {
+        return None;
+    };
+
+    let (asset_code, issuer) = match asset {
+        Asset::CreditAlphanum4(AlphaNum4 { asset_code, issuer }) => (asset_code.as_slice(), issuer),
+        Asset::CreditAlphanum12(AlphaNum12 { asset_code, issuer }) => {
+            (asset_code.as_slice(), issuer)
+        }
+        Asset::Native => return None,
+    };
+
+    // Scam assets and the like are rejected for other reasons
+    match build_nonnative_swap_asset(asset, asset_code, issuer) {
+        Err(Rejection::UnknownAsset) => Some(UnlistedSwap {
+            code: decode_asset_code(asset_code)?,
+            issuer: issuer.to_string(),
+            usdc_amount: usdc_amount as i128,
+        }),
+        _ => None,
+    }
+}
+
+fn claim_atom_parts(claim_atom: &ClaimAtom) -> (&Asset, i64, &Asset, i64) {
     match claim_atom {
         ClaimAtom::V0(ClaimOfferAtomV0 {
             asset_sold,
@@ -81,57 +208,142 @@ pub(crate) fn extract_claim_atom_data(claim_atom: &ClaimAtom) -> SwapData {
             asset_bought,
             amount_bought,
             ..
-        }) => SwapData {
-            asset_sold: SwapAsset::try_from(asset_sold).ok(),
-            amount_sold: *amount_sold,
-            asset_bought: SwapAsset::try_from(asset_bought).ok(),
-            amount_bought: *amount_bought,
-        },
+        }) => (asset_sold, *amount_sold, asset_bought, *amount_bought),
     }
 }
 
 /**
- * We highlight Soroswap-certified assets, as well as native XLM.
- */
-pub(crate) fn is_certified_asset(floatcode: &str, fltissuer: &str) -> bool {
-    fltissuer == "Native"
-        || SOROSWAP_TOKENS
-            .iter()
-            .any(|asset| asset.code == floatcode && asset.issuer == fltissuer)
-}
-
-/**
- * Build a SwapAsset from a non-native asset code and issuer.
+ * Build a SwapAsset from a non-native asset code and issuer. This runs for
+ * every asset of every claim, so the issuer is only formatted as a strkey
+ * once the asset is accepted.
  */
 pub(crate) fn build_nonnative_swap_asset(
+    asset: &Asset,
     asset_code: &[u8],
-    issuer: String,
-) -> Result<&'static SwapAsset, String> {
-    if SCAM_ADDRESSES.contains(&issuer.as_str()) {
-        return Err("Scam asset".to_string());
+    issuer: &AccountId,
+) -> Result<SwapAsset, Rejection> {
+    let AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(issuer_id))) = issuer;
+
+    if is_scam_account(issuer_id) {
+        return Err(Rejection::ScamIssuer);
     }
 
-    let code = format_nonnative_asset(asset_code);
+    let code = decode_asset_code(asset_code).ok_or(Rejection::InvalidCode)?;
 
     if code == "XLM" {
-        return Err("Non-native XLM asset".to_string());
+        return Err(Rejection::NonNativeXlm);
     }
 
-    SOROSWAP_TOKENS
-        .iter()
-        .find(|token| token.code == code && token.issuer == issuer)
-        .ok_or_else(|| "Asset not found in Soroswap tokens".to_string())
+    if let Some(token) = registered_classic_token(issuer_id, &code) {
+        return Ok(token.clone());
+    }
+
+    match ASSET_COVERAGE {
+        AssetCoverage::Registry => Err(Rejection::UnknownAsset),
+        AssetCoverage::Open => Ok(SwapAsset {
+            code: Cow::Owned(code),
+            issuer: Cow::Owned(issuer.to_string()),
+            // Hashing a valid asset cannot fail
+            contract: Cow::Owned(
+                stellar_asset_contract(asset).map_err(|_| Rejection::InvalidCode)?,
+            ),
+            decimals: CLASSIC_DECIMALS,
+        }),
+    }
 }
 
-fn format_nonnative_asset(asset_code: &[u8]) -> String {
-    bytes_to_string(asset_code)
-        .chars()
-        .filter(|char| char.is_ascii_alphabetic())
-        .collect()
+/**
+ * The registered token a classic asset is, if any.
+ */
+pub(crate) fn registered_classic_asset(asset: &Asset) -> Option<&'static SwapAsset> {
+    let (asset_code, AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(issuer_id)))) = match asset {
+        Asset::Native => return None,
+        Asset::CreditAlphanum4(AlphaNum4 { asset_code, issuer }) => (&asset_code.0[..], issuer),
+        Asset::CreditAlphanum12(AlphaNum12 { asset_code, issuer }) => (&asset_code.0[..], issuer),
+    };
+
+    registered_asset_code(asset_code, issuer_id)
 }
 
-fn bytes_to_string(bytes: &[u8]) -> &str {
-    std::str::from_utf8(bytes).unwrap_or("Unreadable")
+/**
+ * The registered token with a raw asset code and issuer account ID, if any.
+ */
+pub(crate) fn registered_asset_code(
+    asset_code: &[u8],
+    issuer: &[u8; 32],
+) -> Option<&'static SwapAsset> {
+    registered_classic_token(issuer, &decode_asset_code(asset_code)?)
+}
+
+/**
+ * Build a classic asset from its code and issuer, as given to the API.
+ */
+pub(crate) fn classic_asset(code: &str, issuer: &str) -> Result<Asset, String> {
+    let issuer = stellar_strkey::ed25519::PublicKey::from_string(issuer)
+        .map_err(|_| format!("Invalid issuer '{issuer}'."))?;
+    let issuer = AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(issuer.0)));
+
+    let is_valid = code.chars().all(|char| char.is_ascii_alphanumeric());
+    match code.len() {
+        1..=4 if is_valid => {
+            let mut padded_code = [0; 4];
+            padded_code[..code.len()].copy_from_slice(code.as_bytes());
+            Ok(Asset::CreditAlphanum4(AlphaNum4 {
+                asset_code: AssetCode4(padded_code),
+                issuer,
+            }))
+        }
+        5..=12 if is_valid => {
+            let mut padded_code = [0; 12];
+            padded_code[..code.len()].copy_from_slice(code.as_bytes());
+            Ok(Asset::CreditAlphanum12(AlphaNum12 {
+                asset_code: AssetCode12(padded_code),
+                issuer,
+            }))
+        }
+        _ => Err(format!("Invalid asset code '{code}'.")),
+    }
+}
+
+/**
+ * Derive the contract ID of the Stellar Asset Contract of a classic asset.
+ * It is the hash of the asset and the network ID, so it exists even if the
+ * contract was never deployed.
+ */
+pub(crate) fn stellar_asset_contract(asset: &Asset) -> Result<String, String> {
+    let preimage = HashIdPreimage::ContractId(HashIdPreimageContractId {
+        network_id: Hash(Sha256::digest(NETWORK_PASSPHRASE).into()),
+        contract_id_preimage: ContractIdPreimage::Asset(asset.clone()),
+    });
+    let preimage = preimage
+        .to_xdr(Limits::none())
+        .map_err(|xdr_error| format!("Could not derive the asset contract: {xdr_error}"))?;
+
+    Ok(hash_to_strkey(&Hash(Sha256::digest(preimage).into())))
+}
+
+/**
+ * Decode an asset code as the protocol defines it: ASCII letters and digits,
+ * padded with trailing NULs. AlphaNum4 codes have 1 to 4 characters and
+ * AlphaNum12 codes 5 to 12. Anything else is not a valid code.
+ */
+pub(crate) fn decode_asset_code(asset_code: &[u8]) -> Option<String> {
+    let length = asset_code
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(asset_code.len());
+    let (code, padding) = asset_code.split_at(length);
+
+    let valid_length = match asset_code.len() {
+        4 => (1..=4).contains(&length),
+        12 => (5..=12).contains(&length),
+        _ => false,
+    };
+
+    (valid_length
+        && padding.iter().all(|byte| *byte == 0)
+        && code.iter().all(u8::is_ascii_alphanumeric))
+    .then(|| String::from_utf8_lossy(code).into_owned())
 }
 
 /**
@@ -146,28 +358,99 @@ pub(crate) fn hash_to_strkey(hash: &Hash) -> String {
 }
 
 /**
- * Given a contract address, return a SwapAsset with the asset code and issuer.
+ * Given a contract ID, return a SwapAsset with the asset code, issuer and
+ * decimals. The decimals in the token's own metadata take precedence over
+ * those in the registry.
  */
-pub(crate) fn get_swap_asset(contract_address: String) -> Option<&'static SwapAsset> {
-    if contract_address == XLM_ADDRESS {
-        Some(&SwapAsset {
-            code: "XLM",
-            issuer: "Native",
-            contract: XLM_ADDRESS,
-        })
+pub(crate) fn get_swap_asset(contract: &Hash) -> Option<SwapAsset> {
+    if hash_to_strkey(contract) == xlm().contract {
+        Some(xlm().clone())
     } else {
-        SOROSWAP_TOKENS
-            .iter()
-            .find(|asset| asset.contract == contract_address)
+        registered_contract_token(&contract.0).map(|asset| SwapAsset {
+            decimals: read_token_decimals(contract.0).unwrap_or(asset.decimals),
+            ..asset.clone()
+        })
+    }
+}
+
+/**
+ * Check whether a raw 32-byte ID is in a list of them, concatenated and
+ * sorted, as generated by build.rs.
+ */
+pub(crate) fn sorted_ids_contain(sorted_ids: &[u8], id: &[u8; 32]) -> bool {
+    let (mut low, mut high) = (0, sorted_ids.len() / 32);
+
+    while low < high {
+        let middle = (low + high) / 2;
+        match sorted_ids[middle * 32..(middle + 1) * 32].cmp(id) {
+            std::cmp::Ordering::Less => low = middle + 1,
+            std::cmp::Ordering::Greater => high = middle,
+            std::cmp::Ordering::Equal => return true,
+        }
     }
+
+    false
+}
+
+// The decimals read from each token contract. The program is instantiated
+// for each ledger close, so every contract is read at most once per ledger.
+static TOKEN_DECIMALS: Mutex<BTreeMap<[u8; 32], Option<u32>>> = Mutex::new(BTreeMap::new());
+
+fn read_token_decimals(contract: [u8; 32]) -> Option<u32> {
+    // A poisoned lock only means a panic elsewhere; the map is still sound
+    let mut cache = TOKEN_DECIMALS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    *cache
+        .entry(contract)
+        .or_insert_with(|| read_contract_decimals(contract))
+}
+
+/**
+ * Read the decimals of a token from its contract instance. Stellar Asset
+ * Contracts always have 7; custom tokens following SEP-41 keep theirs in a
+ * METADATA map in instance storage.
+ */
+fn read_contract_decimals(contract: [u8; 32]) -> Option<u32> {
+    let instance = EnvClient::empty().read_contract_instance(contract).ok()??;
+
+    let ScVal::ContractInstance(ScContractInstance {
+        executable,
+        storage,
+    }) = instance.val
+    else {
+        return None;
+    };
+
+    if executable == ContractExecutable::StellarAsset {
+        return Some(CLASSIC_DECIMALS);
+    }
+
+    let storage = storage?;
+    let metadata = storage.iter().find_map(|entry| match entry {
+        ScMapEntry {
+            key: ScVal::Symbol(key),
+            val: ScVal::Map(Some(metadata)),
+        } if key.to_string() == "METADATA" => Some(metadata),
+        _ => None,
+    })?;
+
+    metadata.iter().find_map(|entry| match entry {
+        ScMapEntry {
+            key: ScVal::Symbol(key),
+            val: ScVal::U32(decimals),
+        } if key.to_string() == "decimal" => Some(*decimals),
+        _ => None,
+    })
 }
 
 /**
- * Given a ScVal, return the contract address if it is a contract address.
+ * Given a ScVal, return the contract ID if it is a contract address.
  */
-pub(crate) fn get_address_from_scval(val: &ScVal) -> Option<String> {
+pub(crate) fn get_contract_from_scval(val: &ScVal) -> Option<&Hash> {
     match val {
-        ScVal::Address(ScAddress::Contract(contract)) => Some(hash_to_strkey(contract)),
+        ScVal::Address(ScAddress::Contract(contract)) => Some(contract),
         _ => None,
     }
 }
@@ -191,11 +474,122 @@ pub(crate) fn scmap_get(map: &ScMap, key: String) -> Option<&ScVec> {
 }
 
 /**
- * Return the string representation of a timestamp.
+ * Return the string representation of a timestamp. Timestamps outside of the
+ * range of dates we can format are returned as they are, rather than failing
+ * the whole response or ledger.
  */
 pub(crate) fn parse_date(timestamp: &i64) -> String {
     OffsetDateTime::from_unix_timestamp(*timestamp)
-        .unwrap()
-        .format(&Iso8601::DEFAULT)
-        .unwrap()
+        .ok()
+        .and_then(|datetime| datetime.format(&Iso8601::DEFAULT).ok())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/**
+ * Return the name of a rate window, as used by the API, given its length.
+ */
+pub(crate) fn interval_name(interval: u64) -> &'static str {
+    RATE_INTERVALS
+        .iter()
+        .find(|(_, length)| *length == interval)
+        .map_or("unknown", |(name, _)| name)
+}
+
+/**
+ * The end of the last rate window that is complete at a timestamp. Windows are
+ * aligned to whole multiples of their length in UTC, so an hourly window
+ * always runs from one o'clock sharp to the next.
+ */
+pub(crate) fn window_end(timestamp: u64, interval: u64) -> u64 {
+    timestamp - timestamp % interval
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alphanum4(code: &[u8]) -> [u8; 4] {
+        let mut padded = [0; 4];
+        padded[..code.len()].copy_from_slice(code);
+        padded
+    }
+
+    fn alphanum12(code: &[u8]) -> [u8; 12] {
+        let mut padded = [0; 12];
+        padded[..code.len()].copy_from_slice(code);
+        padded
+    }
+
+    #[test]
+    fn decode_asset_code_keeps_letters_and_digits() {
+        assert_eq!(decode_asset_code(b"USDC").as_deref(), Some("USDC"));
+        assert_eq!(decode_asset_code(&alphanum4(b"X")).as_deref(), Some("X"));
+        assert_eq!(
+            decode_asset_code(&alphanum4(b"yU5")).as_deref(),
+            Some("yU5")
+        );
+        assert_eq!(
+            decode_asset_code(&alphanum12(b"USDC2")).as_deref(),
+            Some("USDC2")
+        );
+        assert_eq!(
+            decode_asset_code(b"ABCDEFGHIJ12").as_deref(),
+            Some("ABCDEFGHIJ12")
+        );
+    }
+
+    #[test]
+    fn decode_asset_code_rejects_lengths_outside_the_asset_type() {
+        assert_eq!(decode_asset_code(&[0; 4]), None);
+        assert_eq!(decode_asset_code(&alphanum12(b"USDC")), None);
+        assert_eq!(decode_asset_code(&[0; 12]), None);
+        assert_eq!(decode_asset_code(b"USD"), None);
+        assert_eq!(decode_asset_code(b"USDCUSDCUSDCU"), None);
+        assert_eq!(decode_asset_code(b""), None);
+    }
+
+    #[test]
+    fn native_xlm_contract_is_derived_from_the_network() {
+        // The well-known address of the native asset contract on the public
+        // network
+        if NETWORK_PASSPHRASE == "Public Global Stellar Network ; September 2015" {
+            assert_eq!(
+                xlm().contract,
+                "CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA"
+            );
+        }
+
+        let contract = Contract::from_string(&xlm().contract).unwrap();
+        assert_eq!(get_swap_asset(&Hash(contract.0)).as_ref(), Some(xlm()));
+    }
+
+    #[test]
+    fn windows_end_on_utc_boundaries() {
+        // 2024-12-16T03:02:11, in the windows ending at 03:00 and at midnight
+        let timestamp = 1_734_318_131;
+        let ends = RATE_INTERVALS.map(|(_, interval)| window_end(timestamp, interval));
+
+        assert_eq!(ends, [1_734_318_000, 1_734_318_000, 1_734_307_200]);
+    }
+
+    #[test]
+    fn a_timestamp_on_a_boundary_ends_the_window() {
+        // 2024-12-16T00:00:00, which ends a window of every length
+        let midnight = 1_734_307_200;
+
+        for (_, interval) in RATE_INTERVALS {
+            assert_eq!(window_end(midnight, interval), midnight);
+            assert_eq!(window_end(midnight - 1, interval), midnight - interval);
+        }
+    }
+
+    #[test]
+    fn decode_asset_code_rejects_malformed_bytes() {
+        assert_eq!(decode_asset_code(b"US\0C"), None);
+        assert_eq!(decode_asset_code(b"\0USD"), None);
+        assert_eq!(decode_asset_code(b"US-C"), None);
+        assert_eq!(decode_asset_code(b"US C"), None);
+        assert_eq!(decode_asset_code(&alphanum4(&[0xC3, 0xA9])), None);
+        assert_eq!(decode_asset_code(&alphanum12(b"USDC\n")), None);
+    }
 }